@@ -0,0 +1,696 @@
+# Lattice message catalog: English (en)
+#
+# This is the reference catalog. Every message key must exist here; other
+# locales may translate any subset and fall back to English for the rest.
+#
+# Syntax is a subset of Project Fluent (https://projectfluent.org):
+#   key = value              one message per key
+#   { $name }                placeholder filled in by the caller
+#       indented line        continues the previous message on a new line
+#   # comment                ignored
+#
+# To add a locale, copy this file to `locales/<tag>.ftl` (e.g. `de.ftl`),
+# translate the values, and register it in `CATALOGS` in `src/ui/i18n.rs`.
+
+## Output labels (ui::output)
+
+output-error = error: { $message }
+output-warning = warning: { $message }
+output-hint = hint: { $hint }
+output-fix = fix: lattice doctor --fix { $fix_id }
+
+## Contextual hints (ui::hints)
+
+hint-dismiss = (run 'lattice hints --dismiss { $name }' to stop showing this)
+hint-init = Create your first stacked branch with 'lattice create <name>'.
+hint-create = Run 'lattice submit' to push '{ $branch }' and open a PR, or 'lattice create' again to stack another branch on it.
+hint-submit = When a PR merges, run 'lattice sync': it deletes the merged branch and moves the rest of the stack onto trunk.
+hint-conflict = Finish with 'lattice continue', not 'git rebase --continue', so Lattice can update its metadata and the rest of the stack.
+
+## Confirmation prompts
+
+# Answer accepted as "yes" (case-insensitive).
+prompt-yes = y
+prompt-aborted = Aborted.
+prompt-continue = Continue? [y/N]
+prompt-init-reset = This will delete all branch metadata. Continue? [y/N]
+prompt-reorder-apply = Apply this reorder? [y/N]
+prompt-edit-apply = Apply this edit? [y/N]
+prompt-undo-apply = Undo these operations? [y/N]
+prompt-completion-install = Write { $shell } completions to { $path }? [y/N]
+prompt-submit-apply = Submit these PRs? [y/N]
+prompt-doctor-fix-all = Apply { $count } fix(es)? [y/N]
+
+## restack
+
+restack-emptied = '{ $branch }' has no commits after restack; its changes already landed in '{ $parent }'.
+restack-emptied-delete = Delete '{ $branch }'? [y/N]
+restack-emptied-delete-pr = Delete '{ $branch }' (and close PR #{ $pr })? [y/N]
+restack-emptied-hint = Run 'lattice delete { $branch }' to remove it.
+restack-emptied-hint-pr = Run 'lattice delete { $branch }' to remove it (and close PR #{ $pr }).
+
+## sync
+
+sync-description-prompt = The description of PR #{ $pr } for '{ $branch }' was edited. Pull it into the branch description? [y/N]
+sync-description-edited = The description of PR #{ $pr } for '{ $branch }' was edited; run 'lattice sync' interactively to pull it into the branch description.
+sync-remote-branches-gone = Run 'lattice doctor' to delete or re-submit these branches.
+sync-merged-prompt = '{ $branch }' was { $how } into { $trunk }. Delete it and move its children onto its parent? [y/N]
+sync-merged-found = '{ $branch }' was { $how } into { $trunk }; run 'lattice delete { $branch }' to remove it.
+sync-closed-hint = { $count } branch(es) have PRs that were closed without merging; run 'lattice sync --prune' to clean them up.
+sync-closed-list = Branches whose PRs were closed without merging:
+sync-closed-mark-prompt = Prune which? (numbers or ranges, e.g. 1 3-5; empty for none):
+sync-closed-action-prompt = Delete the branches, or only untrack them? (delete, untrack):
+sync-closed-unknown-action = Unknown action '{ $action }'; choose delete or untrack.
+sync-closed-needs-force = Not pruning without confirmation; run 'lattice sync --prune --force' to delete them.
+
+## Paused operations (engine::runner)
+
+paused-lattice-operation = Note: '{ $command }' operation is paused (op { $op_id }). Showing current state; run 'lattice continue' or 'lattice abort' to finish it.
+paused-git-operation = Note: a git { $git_state } is in progress. Showing current state; finish or abort it with git before running mutating commands.
+
+## Trunk drift (cli::commands::trunk_drift)
+
+drift-stack-behind = Stack '{ $branch }' is { $commits } commit(s) behind '{ $trunk }' ({ $days } day(s)). Run 'lattice sync' and 'lattice restack' before conflicts pile up.
+
+## Conflict pauses (cli::commands::conflict_summary)
+
+conflict-branch = Branch:     { $branch } (on { $parent })
+conflict-branch-untracked = Branch:     { $branch }
+conflict-commit = Replaying:  { $commit } { $subject }
+conflict-commit-progress = Replaying:  { $commit } { $subject } (commit { $current } of { $total })
+conflict-files = Conflicted files:
+conflict-sides-rebase = In conflict markers, "ours" (HEAD) is '{ $parent }' plus the commits replayed so far; "theirs" is '{ $branch }' at { $commit }.
+conflict-sides = In conflict markers, "ours" (HEAD) is '{ $branch }'; "theirs" is { $commit }.
+conflict-remaining = Still to replay afterwards: { $branches }
+conflict-next-steps = Resolve the conflicts and stage the files with 'git add', then run one of:
+conflict-option-continue = lattice continue          finish this commit and resume 'lattice { $command }'
+conflict-option-skip = lattice continue --skip   drop { $commit } from '{ $branch }' and resume
+conflict-option-abort = lattice abort             restore every branch to before 'lattice { $command }'
+
+## Accessible output (output.accessible)
+#
+# Joined with ", " into one description per branch, e.g.
+# "branch feature-b, current branch, child of feature-a, 2 commits, needs restack"
+
+accessible-branch = branch { $branch }
+accessible-current = current branch
+accessible-child-of = child of { $parent }
+accessible-commits-one = { $count } commit
+accessible-commits = { $count } commits
+accessible-needs-restack = needs restack
+accessible-frozen = frozen
+accessible-pr = PR #{ $number }
+accessible-untracked = untracked
+
+## Attaching to a running duplicate (cli::commands::attach)
+
+attach-prompt = Another 'lattice { $command }' with the same arguments is already running (pid { $pid }). Follow its progress instead? [y/N]
+attach-finished = The other 'lattice { $command }' finished.
+attach-rolled-back = The other 'lattice { $command }' failed and was rolled back.
+attach-paused = The other 'lattice { $command }' paused on a conflict. Run 'lattice continue' or 'lattice abort' from its worktree.
+attach-abandoned = The other 'lattice { $command }' exited before finishing. Run 'lattice doctor' to inspect the repository.
+
+## Batch actions (cli::commands::batch)
+
+batch-needs-interactive = 'lattice log --select' reads the branches to act on from the terminal; run it interactively.
+batch-mark-prompt = Mark branches (numbers or ranges, e.g. 1 3-5):
+batch-action-prompt = Action (restack, submit, freeze):
+batch-nothing-marked = Nothing marked.
+batch-invalid-mark = '{ $mark }' is not a branch number or range between 1 and { $count }.
+batch-unknown-action = Unknown action '{ $action }'; choose restack, submit, or freeze.
+batch-confirm-restack = Restack { $branches }? [y/N]
+batch-confirm-submit = Submit { $branches } (and their ancestors)? [y/N]
+batch-confirm-freeze = Freeze { $branches }? [y/N]
+
+## Submit branch selection (cli::commands::submit)
+
+submit-select-needs-interactive = 'lattice submit --select' reads the branches to submit from the terminal; run it interactively or name them with --branches.
+submit-select-prompt = Submit which branches? (numbers or ranges, e.g. 1 3-5):
+submit-select-ancestors = Also submitting { $branches }, which the selection is stacked on.
+submit-not-in-stack = '{ $branch }' is not a tracked branch in the current stack.
+submit-since-not-downstack = '{ $branch }' is not the current branch or a tracked branch below it.
+
+## Branches pushed from elsewhere (cli::commands::submit, cli::commands::sync)
+
+submit-diverged-header = These branches were pushed from somewhere else since this repository last submitted them:
+submit-diverged-branch =   { $branch } ({ $remote_name }): { $local } commit(s) only here, { $remote } only on the remote
+submit-diverged-dry-run = Submit will ask how to reconcile each of them before pushing.
+submit-diverged-needs-interactive = Pushing would discard the commits that are only on the remote. Run 'lattice submit' interactively to choose, for each branch, whether to keep the local branch, take the remote one, or skip it.
+submit-diverged-prompt = '{ $branch }': [k]eep local (overwrite the remote), [t]ake remote, [s]kip, or [a]bort?
+submit-diverged-unknown = Unknown answer '{ $answer }'; choose k, t, s, or a.
+submit-diverged-aborted = Submit aborted; nothing was pushed.
+submit-diverged-took = Reset '{ $branch }' to the remote tip. Run 'lattice restack' to move the branches above it.
+sync-remote-diverged = Run 'lattice submit' to reconcile them before pushing.
+
+## Stack browser (ui::tui, cli::commands::ui_cmd)
+
+tui-title = Stacks
+tui-keys = ↑/↓ move · enter checkout · r restack · s submit · q quit
+tui-needs-interactive = 'lattice ui' needs an interactive terminal.
+tui-nothing-tracked = No tracked branches to browse. Track some with 'lattice track' or 'lattice create'.
+
+## Error hints (cli::error)
+
+hint-lattice-op-in-progress = Run 'lattice continue' or 'lattice abort'.
+hint-not-initialized = Run 'lattice init' first.
+hint-needs-repair = Run 'lattice doctor' to review the available fixes.
+hint-repo-identity-mismatch = The config may have been copied from another clone, or --cwd points at the wrong repository. If this is the right repository, run 'lattice config set identity <url-or-owner/repo>'.
+hint-internal = Run 'lattice doctor' to inspect the repository.
+hint-lock-failed = Another Lattice command may be running; retry when it finishes.
+hint-repo-changed = The repository changed while the command ran; run it again.
+hint-worktree-occupied = Switch the other worktree to a different branch, or run the command there.
+hint-worktree-occupied-dirty = Commit or stash the changes in the other worktree, then re-run the command.
+hint-branch-locked = Wait for the lock to expire, or ask its owner to run 'lattice unlock'. 'lattice unlock --force' breaks it.
+hint-not-a-repo = Run inside a Git repository, or pass --cwd <path>.
+hint-git-op-in-progress = Complete or abort the Git operation first.
+hint-dirty-worktree = Commit or stash your changes first.
+hint-auth = Run 'lattice auth login'.
+hint-network = Check your connection and try again.
+hint-timeout = Check your connection, or raise the limits under [network] in your config.
+hint-rate-limited = Wait for the rate limit to reset and try again.
+hint-untracked = Track it with 'lattice track <branch>'.
+hint-delete-untracked = Use 'git branch -d' for untracked branches.
+
+## Absorb (cli::commands::absorb)
+
+absorb-nothing-staged-absorb = Nothing staged to absorb. Stage changes first, or use -u.
+absorb-absorbing-hunk-commit = Absorbing { $hunks } hunk(s) into { $count } commit(s):
+absorb-left-staged = Left staged:
+absorb-nothing-absorb = Nothing to absorb.
+absorb-absorbed-hunk-commit = Absorbed { $absorbed_hunks } hunk(s) into { $absorbed_commits } commit(s), rewriting { $branches }
+absorb-restacked-descendant = Restacked { $count } descendant(s): { $branches }
+absorb-skipped-frozen-branch = Skipped { $count } frozen branch(es): { $branches }
+absorb-could-restack-descendant = Could not restack { $count } descendant(s) without conflicts: { $branches }. Run 'lattice restack' to update them.
+absorb-deferred-restack-descendant = Deferred restack of { $count } descendant(s): { $branches }. Run 'lattice restack' to update them.
+
+## Auth (cli::commands::auth)
+
+auth-requesting-device-code = Requesting device code from { $host }...
+auth-authenticate-visit = To authenticate, visit:
+auth-enter-code = And enter this code:
+auth-could-open-browser = Could not open browser automatically: { $error }
+auth-please-open-url = Please open the URL manually.
+auth-waiting-authorization = Waiting for authorization...
+auth-authenticated = Authenticated as { $login } for { $host }.
+auth-host = Host: { $host }
+auth-user-id = User: { $login } (id: { $id })
+auth-access-token-expires = Access token expires: { $expires } ({ $remaining } remaining)
+auth-access-token-expired = Access token: expired (will refresh automatically)
+auth-refresh-token-expires = Refresh token expires: { $expires } ({ $remaining } remaining)
+auth-refresh-token-expired = Refresh token: expired (re-authentication required)
+auth-status-authenticated = Status: authenticated
+auth-status-expired-run = Status: expired - run 'lattice auth login' to re-authenticate
+auth-not-authenticated = Not authenticated for { $host }.
+auth-run-lattice-auth = Run 'lattice auth login' to authenticate.
+auth-logged-out = Logged out from { $host }.
+
+## Cached PR (cli::commands::cached_pr)
+
+cached-pr-warning-remote-unreachable = Warning: remote unreachable ({ $reason }); using cached PR state.
+
+## Changelog (cli::commands::changelog)
+
+changelog-tagline = A Rust-native CLI for stacked branches and PRs.
+changelog-repository = Repository: https://github.com/lattice-cli/lattice
+changelog-recent-changes = ## Recent Changes
+changelog-phase-core-local = - Phase 1: Core local stack engine
+changelog-stack-graph-metadata = - Stack graph and metadata management
+changelog-branch-tracking-parent = - Branch tracking with parent relationships
+changelog-restack-conflict-handling = - Restack with conflict handling
+changelog-operation-journaling-crash = - Operation journaling for crash safety
+changelog-see-full = For full changelog, see: https://github.com/lattice-cli/lattice/blob/main/CHANGELOG.md
+
+## Checkout (cli::commands::checkout)
+
+checkout-select-branch-check = Select branch to check out:
+checkout-enter-number = Enter number:
+checkout-already-checked-out = '{ $target }' is already checked out in worktree at { $existing }
+checkout-checked-out-new = Checked out '{ $target }' in new worktree at { $path }
+
+## Checkpoint (cli::commands::checkpoint)
+
+checkpoints-saved = Saved checkpoint '{ $name }' ({ $count } branch(es)).
+checkpoints-restored = Restored checkpoint '{ $name }' (saved { $created_at }). Run 'lattice undo' to revert.
+checkpoints-no-checkpoints = No checkpoints saved.
+checkpoints-list-entry = { $name }  { $created_at }  { $count } branch(es)
+checkpoints-deleted = Deleted checkpoint '{ $name }'.
+
+## Completion (cli::commands::completion)
+
+completion-wrote = Wrote { $path }.
+completion-new-shells = Completions take effect in new shells.
+
+## Config (cli::commands::config_cmd)
+
+settings-set = Set { $key } = { $value }
+
+## Create (cli::commands::create)
+
+create-branch-name = Branch name:
+create-select-child-insert = Select child to insert before:
+create-enter-number = Enter number:
+create-staged-changes-exist = Staged changes exist. Use --message or run interactively to commit.
+create-created-parent-base = Created '{ $branch_name }' with parent '{ $parent }' (base: { $parent_tip })
+create-reparented-under = Reparented '{ $child }' under '{ $branch_name }'
+create-linked-issue = Linked issue #{ $number }: { $title }
+
+## Delete (cli::commands::delete)
+
+deletion-will-delete = Will delete { $count } branch(es):
+deletion-complete = Delete complete. Removed { $count } branch(es).
+deletion-pr-closed-next = PR #{ $number } will be closed on the next 'lattice sync'.
+
+## Edit (cli::commands::edit)
+
+edit-no-commits = No commits to edit.
+edit-opening-editor = Opening editor to edit { $commits } commit(s) in { $count } branch(es)...
+edit-no-changes = No changes to commits.
+edit-rewriting = Rewriting: { $branches }
+edit-rewrote-branches = Rewrote { $count } branch(es): { $branches }
+edit-restacked-descendant = Restacked { $count } descendant(s): { $branches }
+edit-skipped-frozen-branch = Skipped { $count } frozen branch(es): { $branches }
+edit-deferred-restack-descendant = Deferred restack of { $count } descendant(s): { $branches }. Run 'lattice restack' to update them.
+edit-complete = Edit complete.
+
+## Edit branch (cli::commands::edit_branch)
+
+edit-branch-editing = Editing '{ $target }'.
+edit-branch-amend-lattice-modify = Amend it with 'lattice modify' or 'git commit --amend', then run 'lattice edit --done' to restack its descendants and return to '{ $current }'.
+edit-branch-your-other-changes = Your other changes didn't reapply cleanly; they are still in the stash. Run 'git stash pop' to bring them back.
+edit-branch-finished-editing-back = Finished editing '{ $branch }'; back on '{ $return_to }'.
+edit-branch-after-lattice-continue = After 'lattice continue' finishes, run 'lattice edit --done' to return to '{ $return_to }'.
+
+## Fold (cli::commands::fold)
+
+fold-complete = Fold complete.
+
+## Freeze (cli::commands::freeze)
+
+freeze-already-frozen = '{ $branch }' is already frozen
+freeze-already-unfrozen = '{ $branch }' is already unfrozen
+freeze-freezing = Freezing '{ $branch }'
+freeze-unfreezing = Unfreezing '{ $branch }'
+freeze-selected-freezing = Freezing { $changing } of { $count } selected branches; { $unchanged } already frozen.
+freeze-selected-unfreezing = Unfreezing { $changing } of { $count } selected branches; { $unchanged } already unfrozen.
+
+## Get (cli::commands::get)
+
+get-fetching-pr-logged = Fetching PR #{ $pr_number } (not logged in, reading without a token)...
+get-fetching-pr = Fetching PR #{ $pr_number }...
+get-fetching-branch = Fetching branch '{ $branch_name }'...
+get-fetched-track-frozen = Fetched '{ $branch_name }'. Run 'lattice track' to track it (frozen by default).
+get-fetched-track-unfrozen = Fetched '{ $branch_name }'. Run 'lattice track --force' to track it (unfrozen by default).
+get-branch-already-tracked = Branch '{ $branch_name }' is already tracked.
+get-tracked-branch-parent = Tracked branch '{ $branch_name }' with parent '{ $parent }' (base: { $base })
+get-branch-frozen-default = Branch is frozen by default.
+get-branch-unfrozen-default = Branch is unfrozen by default.
+get-work-branch-create = To work on this branch, create a worktree:
+get-dry-run-nothing = Dry run: nothing was changed.
+
+## Hints (cli::commands::hints)
+
+hints-every-hint-shown = Every hint will be shown again.
+hints-dismissed-hint = Dismissed { $count } hint(s).
+hints-off = Hints are off ('hints = false' in the global config).
+
+## Info (cli::commands::info)
+
+info-branch = Branch: { $target }
+info-tracked-yes = Tracked: yes
+info-tracked-no = Tracked: no
+info-summary = Summary: { $summary }
+info-parent = Parent: { $parent_name }{ $parent_type }
+info-base = Base: { $oid }
+info-frozen-yes = Frozen: yes
+info-freeze-reason = Freeze reason: { $reason }
+info-frozen-no = Frozen: no
+info-owner = Owner: { $owner }
+info-origin = Origin: { $describe } ({ $recorded_at })
+info-pr-linked = PR: linked
+info-pr-number = PR number: { $number }
+info-pr-url = PR URL: { $url }
+info-pr-status-fetched = PR status: { $label } (fetched { $age })
+info-pr-none = PR: none
+info-issue = Issue: #{ $number } ({ $url })
+info-created = Created: { $created_at }
+info-updated = Updated: { $updated_at }
+info-files-changed = Files changed: { $count }
+info-changes-base-stat = --- Changes from base (stat) ---
+info-changes-base = --- Changes from base ---
+info-tip = Tip: { $oid }
+info-commits = Commits: { $count }
+info-warning-could-fetch = Warning: could not fetch '{ $target }' from '{ $remote }'; comparing against the last fetched head ({ $error })
+
+## Init (cli::commands::init)
+
+init-lattice-already-initialized = Lattice is already initialized in this repository.
+init-use-reset-reconfigure = Use --reset to reconfigure.
+init-warning-failed-delete = Warning: failed to delete metadata for { $branch }: { $error }
+init-warning-failed-read = Warning: failed to read metadata for { $branch }: { $error }
+init-cleared-all-branch = Cleared all branch metadata.
+init-select-trunk-branch = Select trunk branch:
+init-enter-number = Enter number [1]:
+init-initialized-lattice-trunk = Initialized Lattice with trunk: { $trunk_name }
+init-found-open-pr = Found { $count }{ $suffix } open PR. Run `lattice doctor` to import it.
+init-found-open-prs = Found { $count }{ $suffix } open PRs. Run `lattice doctor` to import them.
+
+## Integrations (cli::commands::integrations)
+
+integrations-wrote-actions = Wrote { $path } ({ $count } actions).
+
+## Lock (cli::commands::lock)
+
+lock-locked-until = Locked lock on '{ $target }' until { $expires_at }.
+lock-renewed-until = Renewed lock on '{ $target }' until { $expires_at }.
+lock-warning = Warning: { $error }
+lock-unlocked = Unlocked '{ $target }'.
+lock-no-branches-locked = No branches are locked.
+
+## Log (cli::commands::log_cmd)
+
+log-degraded-view-branches = Degraded view - no branches are tracked yet
+log-trunk = trunk: { $trunk }
+log-trunk-not-configured = trunk: (not configured - run 'lattice init')
+log-start-tracking-branches = To start tracking branches, run:
+log-lattice-track-branch = lattice track <branch>     - track a single branch
+log-lattice-doctor-discover = lattice doctor             - discover bootstrap opportunities
+log-no-local-branches = No local branches found (besides trunk).
+log-untracked-local-branches = Untracked local branches:
+log-untracked-count = ({ $count } branch(es) not tracked by Lattice)
+log-parent = parent: { $parent }
+log-base = base: { $oid }
+log-frozen-yes = frozen: yes
+log-owner = owner: { $owner }
+log-from = from: { $describe }
+log-pr-linked = pr: linked
+log-files = files:
+log-no-tracked-branches = No tracked branches.
+log-untracked-branches = Untracked branches:
+log-untracked = { $prefix }{ $branch }  (untracked)
+
+## Merge (cli::commands::merge)
+
+merging-warning-could-refresh = Warning: Could not refresh reviews: { $error }
+merging-using-reviews-pr = Using reviews of PR #{ $number } { $stale }.
+merging-re-requested-review = Re-requested review of PR #{ $number } from { $reviewers }.
+merging-would-add-pr = Would add { $count } PR(s) to the merge queue:
+merging-would-merge = Would merge { $count } PR(s) using { $merge_method } method:
+merging-would-merge-when-green = Would merge { $count } PR(s) using { $merge_method } method, each once its checks pass:
+merging-pr-merged-before = { $merged_count } PR(s) merged before stopping. Run 'lattice sync' to update state.
+merging-pr = Merging PR #{ $number } ({ $branch })...
+merging-merged-successfully = Merged successfully.
+merging-failed = Failed to merge: { $error }
+merging-stopping-run-lattice = Stopping. Run 'lattice sync' to update state.
+merging-pr-merged-run = { $merged_count } PR(s) merged. Run 'lattice sync' to update local state.
+merging-blocked = blocked: { $requirement }
+merging-pr-queued-before = { $queued } PR(s) queued before stopping; they stay in the queue.
+merging-pr-merge-queue = { $queued } PR(s) in the merge queue. Run 'lattice sync' once they merge.
+merging-waiting-checks-pr = Waiting for checks on PR #{ $number }: { $checks }
+
+## Shared command output (cli::commands)
+
+cmd-changed-refs = Changed refs:
+cmd-note-deep-remote = Note: --deep-remote requested but forge not available
+cmd-note-analyzing-potential = Note: Analyzing { $max_synthetic_heads } of { $count } potential synthetic heads (budget: { $max_synthetic_heads })
+cmd-note-could-list = Note: could not list remote branches: { $error }
+cmd-note-reading-github = Note: reading GitHub without a token ({ $limit }). Run 'lattice auth login' for a larger budget.
+cmd-github-api-quota = GitHub API quota:
+cmd-note-could-read = Note: could not read the GitHub API quota: { $error }
+cmd-fixes-apply = Fixes to apply:
+cmd-skipped-several-fixes = Skipped (several fixes; choose one with --fix):
+cmd-skipped-no-fix = Skipped (no fix available):
+cmd-left-alone-issue = Left alone: { $count } issue(s) above severity '{ $max }'.
+cmd-repository-healthy = Repository is healthy - no issues found.
+cmd-no-fixes-apply = No fixes to apply.
+cmd-warning-failed-record = Warning: failed to record DoctorProposed event: { $error }
+cmd-no-changes-needed = No changes needed.
+cmd-repair-plan = Repair plan:
+cmd-warning-failed-record-doctorapplied = Warning: failed to record DoctorApplied event: { $error }
+cmd-successfully-applied-fix = Successfully applied { $count } fix(es).
+cmd-imported-stack = Imported stack:
+cmd-all-targeted-issues = All targeted issues resolved.
+cmd-warning-issue-may = Warning: { $count } issue(s) may not be fully resolved. Run 'lattice doctor' to check.
+cmd-repair-paused-conflict = Repair paused: conflict on branch '{ $branch }' ({ $git_state }).
+cmd-repair-aborted = Repair aborted: { $error }
+cmd-warning-step-were = Warning: { $count } step(s) were applied before failure.
+cmd-run-lattice-doctor = Run 'lattice doctor' to check repository state.
+
+## Modify (cli::commands::modify)
+
+modify-created-commit = Created commit on '{ $branch }'
+modify-amended-commit = Amended commit on '{ $branch }'
+modify-restacked-descendant = Restacked { $count } descendant(s): { $branches }
+modify-skipped-frozen-branch = Skipped { $count } frozen branch(es): { $branches }
+modify-deferred-restack-descendant = Deferred restack of { $count } descendant(s): { $branches }. Run 'lattice restack' to update them.
+modify-complete = Modify complete.
+
+## Move (cli::commands::move_cmd)
+
+move-already-child-aligned = '{ $source_branch }' is already a child of '{ $onto_branch }' and aligned.
+move-moving-onto-child = Moving '{ $source_branch }' onto '{ $onto_branch }' (was child of '{ $current_parent_name }')...
+move-moved-onto = Moved '{ $source }' onto '{ $onto }'
+move-restacked-descendant = Restacked { $count } descendant(s): { $branches }
+move-skipped-frozen-branch = Skipped { $count } frozen branch(es): { $branches }
+move-complete = Move complete.
+
+## Navigation (cli::commands::navigation)
+
+navigation-already-top-stack = Already at top of stack ({ $target })
+navigation-already-bottom-stack = Already at bottom of stack ({ $target })
+navigation-multiple-children-select = Multiple children, select one:
+navigation-enter-number = Enter number:
+
+## Ops log (cli::commands::ops_log)
+
+ops-log-no-operations = No operations recorded yet.
+
+## Pending ops (cli::commands::pending_ops)
+
+pending-ops-failed-request-reviewers = Failed to request reviewers on PR #{ $number }: { $error }
+pending-ops-flushing-queued-action = Flushing { $count } queued action(s)...
+pending-ops-applied = Applied: { $describe }
+pending-ops-still-offline-keeping = Still offline ({ $reason }); keeping remaining actions queued.
+pending-ops-dropped-queued-action = Dropped queued action ({ $describe }): { $reason }
+
+## Pop (cli::commands::pop)
+
+pop-popping-changes-uncommitted = Popping '{ $current }' (changes will be uncommitted on '{ $parent_name }')...
+pop-no-changes = No changes in branch.
+pop-complete = Pop complete. Changes are staged on '{ $parent_name }'.
+pop-warning-could-apply = Warning: Could not apply changes cleanly: { $stderr }
+pop-branch-been-deleted = The branch has been deleted but changes may be incomplete.
+pop-applied-changes-uncommitted = Applied changes as uncommitted files.
+
+## PR (cli::commands::pr)
+
+browser-open-failed = Could not open browser: { $error }
+
+## Recovery (cli::commands::recovery)
+
+recovery-continuing-git-operation = Continuing git operation...
+recovery-conflicts-remain = Conflicts remain.
+recovery-aborting = Aborting { $command }...
+recovery-operation-aborted = Operation '{ $command }' aborted.
+recovery-warning-partial-rollback = Warning: Partial rollback - some refs could not be restored:
+recovery-repository-may-inconsistent = The repository may be in an inconsistent state.
+recovery-run-lattice-doctor = Run 'lattice doctor' for guidance on resolving this.
+recovery-warning-git-failed = Warning: git { $command } may have failed
+recovery-warning-could-load = Warning: Could not load journal: { $error }
+recovery-skipping-ref-rollback = Skipping ref rollback.
+recovery-warning-operation-included = Warning: This operation included remote changes that cannot be undone:
+recovery-local-refs-restored = Local refs will be restored, but remote branches remain as-is.
+recovery-you-may-need = You may need to force-push or manually revert changes on the remote.
+recovery-resuming-remaining-steps = Resuming { $command } with { $count } remaining steps...
+recovery-conflict = Conflict on '{ $branch }'.
+recovery-operation-completed = Operation '{ $command }' completed.
+
+## Relationships (cli::commands::relationships)
+
+relationships-not-tracked = Branch '{ $current }' is not tracked
+
+## Remote (cli::commands::remote)
+
+remotes-override = { $target } -> { $remote } (override)
+remotes-default = { $target } -> { $remote } (default)
+remotes-branch-pushed = Branch will be pushed to '{ $remote }'.
+remotes-override-cleared = Remote override cleared; branch will be pushed to '{ $remote }'.
+
+## Rename (cli::commands::rename)
+
+rename-renamed = Renamed '{ $old_name }' to '{ $new_name }'
+rename-updated-parent-references = Updated parent references in { $children_updated } branch(es)
+rename-pushing = Pushing '{ $new }' to '{ $remote }'...
+rename-kept-old-branch = Kept '{ $old }' on '{ $remote }' because not every PR could be moved; delete it with 'git push { $remote } --delete { $old }' once they are.
+rename-deleted = Deleted '{ $old }' from '{ $remote }'
+rename-warning-could-delete = Warning: could not delete '{ $old }' from '{ $remote }': { $stderr }
+rename-opened-pr-replacing = Opened PR #{ $new_number } for '{ $new }', replacing #{ $number }: { $url }
+rename-warning-could-comment = Warning: could not comment on PR #{ $number }: { $error }
+rename-warning-could-close = Warning: could not close PR #{ $number }: { $error }
+rename-retargeted-pr = Retargeted PR #{ $number } ('{ $child }') to '{ $new }'
+rename-warning-could-retarget = Warning: could not retarget PR #{ $number }: { $error }
+
+## Reorder (cli::commands::reorder)
+
+reorder-need-least-branches = Need at least 2 branches to reorder. Stack has { $count } tracked branch(es).
+reorder-opening-editor-reorder = Opening editor to reorder { $count } branches...
+reorder-no-changes = No changes to branch order.
+reorder-all-branches-already = All branches already in correct position.
+reorder-new-order = New order:
+reorder-parent = { $branch } (parent: { $parent })
+reorder-predicted-conflicts = Predicted conflicts:
+reorder-complete = Reordered { $branches_reordered } branch(es). Reorder complete.
+
+## Restack (cli::commands::restack)
+
+restack-all-branches-already = All branches are already aligned.
+restack-preview = Restacking { $count } branch(es) would go like this:
+restack-no-conflicts-expected = No conflicts expected. Nothing was changed.
+restack-would-conflict = { $conflicts } of { $count } branch(es) would stop on a conflict. Nothing was changed.
+restack-complete = Restack complete.
+
+## Revert (cli::commands::revert)
+
+revert-creating-revert-branch = Creating revert branch '{ $branch_name }' for commit { $short_sha }...
+revert-complete = Revert complete.
+revert-created-reverting-commit = Created '{ $branch_name }' reverting commit { $short_sha }
+
+## Split (cli::commands::split)
+
+splitting-no-commits = Branch '{ $current }' has no commits to split.
+splitting-branch-only-commit = Branch '{ $current }' has only 1 commit. Nothing to split.
+splitting-into-branches = Splitting '{ $current }' into { $count } branches...
+splitting-complete-created = Split complete. Created { $count } branches:
+splitting-parent = { $branch } (parent: { $parent })
+splitting-complete = Split complete.
+splitting-updated-remaining-changes = Updated '{ $current }' with remaining changes
+splitting-by-files = Splitting '{ $current }' by files: { $files }...
+splitting-created-with-files = Created '{ $new_branch_name }' with changes to { $files }
+splitting-stack = Stack: { $parent_name } -> { $new_branch_name } -> { $current }
+
+## Squash (cli::commands::squash)
+
+squash-branch-commit-nothing = Branch '{ $current }' has { $commit_count } commit(s). Nothing to squash.
+squash-squashing-commits = Squashing { $commit_count } commits on '{ $current }'...
+squash-squashed-commits = Squashed { $commits_squashed } commits on '{ $branch }'
+squash-restacked-descendant = Restacked { $count } descendant(s): { $branches }
+squash-skipped-frozen-branch = Skipped { $count } frozen branch(es): { $branches }
+squash-deferred-restack-descendant = Deferred restack of { $count } descendant(s): { $branches }. Run 'lattice restack' to update them.
+squash-complete = Squash complete.
+
+## Stack (cli::commands::stack)
+
+stacking-no-commits = No commits in { $start }..{ $end }.
+stacking-created-branches = Created { $count } branches:
+stacking-parent = { $branch } (parent: { $parent })
+
+## Stack comment ops (cli::commands::stack_comment_ops)
+
+comment-warning-edits-stack = Warning: Edits to the stack section of PR #{ $number } ('{ $branch }') conflict with the update; left unchanged.
+comment-remove-stack-section = Remove the stack section from the PR description to let Lattice rewrite it.
+comment-warning-updating-pr = Warning: Not updating PR #{ $number } ('{ $branch }'): { $describe }.
+comment-updated-stack-comment = Updated stack comment for PR #{ $number } ({ $branch })
+comment-warning-could-update = Warning: Could not update stack comment for PR #{ $number }: { $error }
+comment-warning-could-fetch = Warning: Could not fetch PR #{ $pr_number } for '{ $branch }': { $error }
+comment-warning-could-find = Warning: Could not find PR for '{ $branch }': { $error }
+comment-warning-could-fetch-pr = Warning: Could not fetch PR #{ $number } for '{ $branch }': { $error }
+
+## Submit (cli::commands::submit)
+
+submit-excluding-snapshot-branch = Excluding { $count } snapshot branch(es) from submit scope:
+submit-these-branches-represent = These branches represent historical snapshots and cannot be submitted.
+submit-warning = Warning: { $warning }
+submit-warning-couldn-fetch = Warning: couldn't fetch branch locks: { $error }
+submit-warning-lfs-check = Warning: could not check for Git LFS objects: { $error }
+submit-fix-bottom-stack = Fix the bottom of the stack first; upstack CI results may be misleading.
+submit-warning-could-record = Warning: could not record the push of '{ $branch }': { $error }
+submit-warning-could-record-pushes = Warning: could not record the pushes: { $error }
+submit-waiting-submit-pid = Waiting for the submit{ $location } (pid { $pid }) of { $branches } to finish...
+submit-submitting-branches = Submitting { $count } branch(es) in { $stacks } stack(s):
+submit-plan = Submit plan:
+submit-plan-create = { $branch } - create PR
+submit-plan-create-state = { $branch } - create PR { $state }
+submit-plan-update = { $branch } - update PR
+submit-plan-update-state = { $branch } - update PR { $state }
+submit-all-stacks-up = All stacks are up to date.
+submit-would-submit-branch = Would submit { $count } branch(es):
+submit-skipping-untracked-branch = Skipping untracked branch '{ $branch }'
+submit-skipping-submitted-another = Skipping '{ $branch }' (submitted from another worktree)
+submit-skipping-pushed-elsewhere = Skipping '{ $branch }' (pushed from elsewhere)
+submit-pushing = Pushing '{ $branch }'...
+submit-pushing-to-remote = Pushing '{ $branch }' to '{ $remote }'...
+submit-failed-push = Failed to push '{ $branch }': { $stderr }
+submit-refreshing-stack-comments = Refreshing stack comments...
+submit-updated-pr-description = Updated { $updated } PR description(s)
+submit-queued-remote-action = Queued { $count } remote action(s). Run 'lattice sync' when back online to apply them.
+submit-submitted-stack = Submitted { $count } stack(s):
+submit-warning-remote-unreachable = Warning: remote unreachable ({ $reason }); queueing remaining remote actions.
+submit-updated-base-metadata = Updated base metadata for { $count } branch(es) (no history changes).
+
+## Sync (cli::commands::sync)
+
+sync-fetching-origin = Fetching from origin...
+sync-fetching = Fetching from { $remote }...
+sync-warning-git-fetch = Warning: git fetch { $remote } failed
+sync-warning-couldn-fetch = Warning: couldn't fetch branch locks: { $error }
+sync-no-remote-trunk = Remote trunk not found. Nothing to sync.
+sync-trunk-up-date = Trunk '{ $trunk }' is up to date.
+sync-queued-action-remain = { $remaining } queued action(s) remain and will be retried on the next sync.
+sync-warning-pr-not-found = Warning: Could not check PR #{ $number } for '{ $branch }': not found
+sync-pr-state = PR #{ $number } for '{ $branch }' is { $state }.
+sync-warning-could-check-prs = Warning: Could not check PRs: { $error }
+sync-updating-stack-comments = Updating stack comments...
+sync-updated-pr-description = Updated { $updated } PR description(s)
+sync-restacking-branches = Restacking branches...
+sync-complete = Sync complete.
+sync-offline-fetching-updating = Offline: not fetching, updating trunk, or checking PRs.
+sync-would-offer-delete = '{ $branch }' was { $describe } into { $trunk }; would offer to delete it.
+sync-would-restack-branches = Would restack branches after syncing.
+sync-dry-run-nothing = Dry run: nothing was changed.
+sync-complete-offline = Sync complete (offline).
+sync-cached-pr-states = Cached PR states:
+sync-warning-can-prune = Warning: can't prune branches in a bare repository.
+sync-pruned = Pruned '{ $branch }' ({ $name }).
+sync-warning-could-delete = Warning: could not delete '{ $branch }': { $error }
+sync-warning-could-prune = Warning: could not { $action } '{ $branch }': { $error }
+sync-fast-forwarding = Fast-forwarding { $trunk } to { $remote_ref }...
+sync-rebasing-local-commit = Rebasing { $local } local commit(s) on { $trunk } onto { $remote_ref }...
+sync-resetting-diverged-dropping = Resetting { $trunk } to { $remote_ref } (diverged, dropping { $local } local commit(s))...
+sync-warning-git-fetch-failed = Warning: git fetch { $remote } failed: { $error }
+sync-warning-could-link = Warning: could not link the replayed PRs: { $error }
+
+## Track (cli::commands::track)
+
+track-branch-already-tracked = Branch '{ $target }' is already tracked
+track-select-parent-branch = Select parent branch for '{ $target }':
+track-enter-number = Enter number:
+track-tracking-parent-base = Tracking '{ $target }' with parent '{ $parent_branch }' (base: { $base })
+track-frozen = (frozen)
+
+## Undo (cli::commands::undo)
+
+undo-undoing-operations = Undoing { $count } operations:
+undo-redoing = Redoing: { $command } ({ $op_id })
+undo-redo-complete = Redo complete. { $refs_reapplied } ref(s) reapplied.
+undo-no-operations = No operations recorded.
+undo-undoing = Undoing: { $command } ({ $op_id })
+undo-warning-operation-included = Warning: This operation included remote changes that cannot be undone:
+undo-local-refs-restored = Local refs will be restored, but remote branches remain as-is.
+undo-you-may-need = You may need to force-push or manually revert changes on the remote.
+undo-no-ref-changes = No ref changes to undo.
+undo-complete = Undo complete. { $total_restored } ref(s) restored.
+
+## Unlink (cli::commands::unlink)
+
+unlink-no-pr = Branch '{ $target }' has no PR linkage.
+unlink-unlinked-pr-branch = Unlinked PR from branch '{ $target }'.
+
+## Untrack (cli::commands::untrack)
+
+untrack-not-tracked = Branch '{ $target }' is not tracked
+untrack-branch-descendant-also = Branch '{ $target }' has { $count } descendant(s) that will also be untracked:
+untrack-untracked = Untracked '{ $branch }'
+untrack-branch-already-untracked = Branch '{ $branch }' was already untracked
+
+## Workspace (cli::commands::workspace)
+
+workspace-synced = Synced { $total } repositories.
@@ -0,0 +1,391 @@
+//! api
+//!
+//! Stable library facade for embedding Lattice in other Rust tools.
+//!
+//! # Stability
+//!
+//! Everything exported from `latticework::api` follows semver: breaking
+//! changes only happen in a major release. All other modules (`engine`,
+//! `core`, `cli`, ...) are public for the `lt` binary and the test suite,
+//! but are internal and may change in any release.
+//!
+//! To leave room for growth, the structs and enums here are
+//! `#[non_exhaustive]`: read their fields freely, but don't construct them
+//! or match them exhaustively outside this crate.
+//!
+//! # Architecture
+//!
+//! The facade is a thin layer over the normal command lifecycle. Reads go
+//! through `run_gated`, restacks through `run_command_with_scope`, and
+//! submit through the same entry point as `lattice submit`. Every call
+//! rescans the repository, so a [`Repository`] never holds stale state.
+//!
+//! Calls never prompt: the facade runs with a quiet, non-interactive
+//! [`Context`]. Operations that would need input (for example a restack
+//! conflict) report that state instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use latticework::api::{RestackOutcome, Repository};
+//!
+//! let repo = Repository::open("/path/to/repo")?;
+//!
+//! let stack = repo.stack()?;
+//! for branch in &stack.branches {
+//!     println!("{} (on {})", branch.name, branch.parent);
+//! }
+//!
+//! let plan = repo.plan_restack(Some("feature-a"))?;
+//! if !plan.is_empty() {
+//!     match repo.restack(Some("feature-a"))? {
+//!         RestackOutcome::Completed => println!("restacked"),
+//!         RestackOutcome::Paused { message } => println!("{}", message),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok::<(), latticework::api::ApiError>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::cli::commands::{self, RestackCommand};
+use crate::core::types::BranchName;
+use crate::engine::gate::requirements;
+use crate::engine::plan::PlanStep;
+use crate::engine::runner::{run_command_with_scope, run_gated, RunError};
+use crate::engine::{Command, CommandOutput, Context};
+use crate::git::Git;
+
+/// Errors returned by the library API.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ApiError {
+    /// The path is not inside a Git repository.
+    #[error("failed to open repository: {0}")]
+    Open(String),
+
+    /// A branch name is not valid.
+    #[error("invalid branch name: {0}")]
+    InvalidBranch(String),
+
+    /// The repository has problems that `lattice doctor` must repair first.
+    #[error("repository needs repair: {0}")]
+    NeedsRepair(String),
+
+    /// The operation failed.
+    #[error("{0}")]
+    Failed(String),
+}
+
+impl From<RunError> for ApiError {
+    fn from(e: RunError) -> Self {
+        match e {
+            RunError::NeedsRepair(bundle) => ApiError::NeedsRepair(bundle.to_string()),
+            other => ApiError::Failed(other.to_string()),
+        }
+    }
+}
+
+/// A tracked branch and its place in the stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StackBranch {
+    /// Branch name.
+    pub name: String,
+    /// Parent branch (trunk for the bottom of a stack).
+    pub parent: String,
+    /// Commit the branch is based on.
+    pub base: String,
+    /// Current tip, or `None` if the local branch is missing.
+    pub tip: Option<String>,
+    /// Whether Lattice is forbidden from rewriting the branch.
+    pub frozen: bool,
+    /// Linked pull request number, if any.
+    pub pr_number: Option<u64>,
+    /// Remote override, if the branch doesn't use `origin`.
+    pub remote: Option<String>,
+}
+
+/// All tracked branches in a repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stack {
+    /// Configured trunk branch.
+    pub trunk: Option<String>,
+    /// Branch checked out in the working directory.
+    pub current_branch: Option<String>,
+    /// Tracked branches, sorted by name.
+    pub branches: Vec<StackBranch>,
+}
+
+impl Stack {
+    /// Find a tracked branch by name.
+    pub fn branch(&self, name: &str) -> Option<&StackBranch> {
+        self.branches.iter().find(|b| b.name == name)
+    }
+
+    /// Tracked branches whose parent is `name`, sorted by name.
+    ///
+    /// Pass the trunk name to get the bottom branch of every stack.
+    pub fn children(&self, name: &str) -> Vec<&StackBranch> {
+        self.branches.iter().filter(|b| b.parent == name).collect()
+    }
+}
+
+/// A branch that a restack would rebase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RestackStep {
+    /// Branch to rebase.
+    pub branch: String,
+    /// Current base commit.
+    pub old_base: String,
+    /// Parent tip the branch will be rebased onto.
+    pub new_base: String,
+}
+
+/// Preview of a restack, in execution order (parents before children).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct RestackPlan {
+    /// Branches that will be rebased.
+    pub steps: Vec<RestackStep>,
+}
+
+impl RestackPlan {
+    /// Whether every branch in scope is already aligned.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Result of executing a restack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RestackOutcome {
+    /// All branches in scope were restacked.
+    Completed,
+    /// A conflict paused the restack; resolve it and run `lattice continue`
+    /// (or `lattice abort`).
+    Paused {
+        /// Instructions for resolving the conflict.
+        message: String,
+    },
+}
+
+/// Options for [`Repository::submit`].
+///
+/// Defaults match `lattice submit` without flags.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct SubmitOptions {
+    /// Also submit descendants of the current branch.
+    pub stack: bool,
+    /// Create new PRs as drafts.
+    pub draft: bool,
+    /// Mark existing draft PRs ready for review.
+    pub publish: bool,
+    /// Show what would happen without pushing.
+    pub dry_run: bool,
+    /// Push with `--force-with-lease`.
+    pub force: bool,
+    /// Only update branches that already have PRs.
+    pub update_only: bool,
+    /// Skip restacking before submit.
+    pub no_restack: bool,
+    /// Queue pushes and PR updates instead of contacting the remote.
+    pub offline: bool,
+    /// Comma-separated reviewers to request.
+    pub reviewers: Option<String>,
+    /// Comma-separated team reviewers to request.
+    pub team_reviewers: Option<String>,
+}
+
+impl SubmitOptions {
+    /// Options equivalent to `lattice submit`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also submit descendants of the current branch.
+    pub fn stack(mut self, stack: bool) -> Self {
+        self.stack = stack;
+        self
+    }
+
+    /// Create new PRs as drafts.
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = draft;
+        self
+    }
+
+    /// Show what would happen without pushing.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Queue remote work instead of contacting the remote.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+}
+
+/// Handle to a Lattice-managed repository.
+#[derive(Debug, Clone)]
+pub struct Repository {
+    path: PathBuf,
+}
+
+impl Repository {
+    /// Open the repository containing `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ApiError> {
+        let path = path.as_ref().to_path_buf();
+        Git::open(&path).map_err(|e| ApiError::Open(e.to_string()))?;
+        Ok(Self { path })
+    }
+
+    /// Path the repository was opened from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn git(&self) -> Result<Git, ApiError> {
+        Git::open(&self.path).map_err(|e| ApiError::Open(e.to_string()))
+    }
+
+    fn context(&self) -> Context {
+        Context {
+            cwd: Some(self.path.clone()),
+            debug: false,
+            quiet: true,
+            interactive: false,
+            verify: true,
+            offline: false,
+            read_only: false,
+        }
+    }
+
+    /// Read every tracked branch and its relationships.
+    pub fn stack(&self) -> Result<Stack, ApiError> {
+        let git = self.git()?;
+        let stack = run_gated(&git, &self.context(), &requirements::READ_ONLY, |ready| {
+            let snapshot = &ready.snapshot;
+            let mut branches: Vec<StackBranch> = snapshot
+                .metadata
+                .iter()
+                .map(|(name, scanned)| {
+                    let metadata = &scanned.metadata;
+                    StackBranch {
+                        name: name.to_string(),
+                        parent: metadata.parent.name().to_string(),
+                        base: metadata.base.oid.clone(),
+                        tip: snapshot.branches.get(name).map(|oid| oid.to_string()),
+                        frozen: metadata.freeze.is_frozen(),
+                        pr_number: metadata.pr.number(),
+                        remote: metadata.remote.clone(),
+                    }
+                })
+                .collect();
+            branches.sort_by(|a, b| a.name.cmp(&b.name));
+
+            Ok(Stack {
+                trunk: snapshot.trunk.as_ref().map(|t| t.to_string()),
+                current_branch: snapshot.current_branch.as_ref().map(|b| b.to_string()),
+                branches,
+            })
+        })?;
+        Ok(stack)
+    }
+
+    /// Preview restacking `branch` (default: current) and its descendants.
+    pub fn plan_restack(&self, branch: Option<&str>) -> Result<RestackPlan, ApiError> {
+        let git = self.git()?;
+        let cmd = restack_command(branch)?;
+
+        let plan = run_gated(
+            &git,
+            &self.context(),
+            RestackCommand::REQUIREMENTS,
+            |ready| {
+                let plan = cmd.plan(ready)?;
+                let steps = plan
+                    .steps
+                    .iter()
+                    .filter_map(|step| match step {
+                        PlanStep::WriteMetadataCas {
+                            branch, metadata, ..
+                        } => {
+                            let name = BranchName::new(branch).ok()?;
+                            let old_base = ready
+                                .snapshot
+                                .metadata
+                                .get(&name)?
+                                .metadata
+                                .base
+                                .oid
+                                .clone();
+                            Some(RestackStep {
+                                branch: branch.clone(),
+                                old_base,
+                                new_base: metadata.base.oid.clone(),
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                Ok(RestackPlan { steps })
+            },
+        )?;
+        Ok(plan)
+    }
+
+    /// Restack `branch` (default: current) and its descendants.
+    pub fn restack(&self, branch: Option<&str>) -> Result<RestackOutcome, ApiError> {
+        let git = self.git()?;
+        let cmd = restack_command(branch)?;
+        let target = branch
+            .map(BranchName::new)
+            .transpose()
+            .map_err(|e| ApiError::InvalidBranch(e.to_string()))?;
+
+        match run_command_with_scope(&cmd, &git, &self.context(), target.as_ref())? {
+            CommandOutput::Success(_) => Ok(RestackOutcome::Completed),
+            CommandOutput::Paused { message } => Ok(RestackOutcome::Paused { message }),
+            CommandOutput::Failed { error } => Err(ApiError::Failed(error)),
+        }
+    }
+
+    /// Push the current branch (and its ancestors) and create or update PRs.
+    ///
+    /// Requires GitHub authentication (`lattice auth`) unless `offline` is set.
+    pub fn submit(&self, options: &SubmitOptions) -> Result<(), ApiError> {
+        let opts = commands::SubmitOptions {
+            stack: options.stack,
+            draft: options.draft,
+            publish: options.publish,
+            dry_run: options.dry_run,
+            force: options.force,
+            update_only: options.update_only,
+            reviewers: options.reviewers.clone(),
+            team_reviewers: options.team_reviewers.clone(),
+            no_restack: options.no_restack,
+            offline: options.offline,
+            ..Default::default()
+        };
+        commands::submit(&self.context(), opts, None)
+            .map_err(|e| ApiError::Failed(format!("{:#}", e)))
+    }
+}
+
+fn restack_command(branch: Option<&str>) -> Result<RestackCommand, ApiError> {
+    let target = branch
+        .map(BranchName::new)
+        .transpose()
+        .map_err(|e| ApiError::InvalidBranch(e.to_string()))?;
+    Ok(RestackCommand::new(target, false, false, true))
+}
@@ -174,7 +174,7 @@ impl AuthCache {
     }
 
     /// Get the cache file path.
-    fn cache_path(paths: &LatticePaths) -> PathBuf {
+    pub fn cache_path(paths: &LatticePaths) -> PathBuf {
         paths.repo_cache_dir().join("github_auth.json")
     }
 }
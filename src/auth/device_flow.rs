@@ -155,7 +155,7 @@ impl DeviceFlowClient {
     /// * `host` - GitHub host (e.g., "github.com")
     pub fn new(host: &str) -> Self {
         Self {
-            client: Client::new(),
+            client: crate::core::net::client(),
             host: host.to_string(),
             client_id: GITHUB_APP_CLIENT_ID.to_string(),
         }
@@ -163,11 +163,11 @@ impl DeviceFlowClient {
 
     /// Create a device flow client with a custom client ID.
     ///
-    /// For testing or GitHub Enterprise with a different app.
-    #[cfg(test)]
+    /// GitHub Enterprise Server instances register their own copy of the
+    /// app, so logging in or refreshing there needs that app's client ID.
     pub fn with_client_id(host: &str, client_id: &str) -> Self {
         Self {
-            client: Client::new(),
+            client: crate::core::net::client(),
             host: host.to_string(),
             client_id: client_id.to_string(),
         }
@@ -331,12 +331,12 @@ impl DeviceFlowClient {
             .form(&request)
             .send()
             .await
-            .map_err(|e| PollResult::Error(AuthError::Network(e.to_string())))?;
+            .map_err(|e| PollResult::Error(e.into()))?;
 
         let body = response
             .text()
             .await
-            .map_err(|e| PollResult::Error(AuthError::Network(e.to_string())))?;
+            .map_err(|e| PollResult::Error(e.into()))?;
 
         // Try to parse as success
         if let Ok(tokens) = serde_json::from_str::<TokenResponse>(&body) {
@@ -438,7 +438,7 @@ impl DeviceFlowClient {
     ///
     /// A [`UserInfoResponse`] with the user's ID and login.
     pub async fn fetch_user_info(&self, access_token: &str) -> Result<UserInfoResponse, AuthError> {
-        let url = format!("https://api.{}/user", self.host);
+        let url = format!("{}/user", super::installations::api_base_url(&self.host));
 
         let response = self
             .client
@@ -532,6 +532,10 @@ mod tests {
             client.token_url(),
             "https://github.example.com/login/oauth/access_token"
         );
+        assert_eq!(client.client_id, GITHUB_APP_CLIENT_ID);
+
+        let client = DeviceFlowClient::with_client_id("github.example.com", "Iv1.enterprise");
+        assert_eq!(client.client_id, "Iv1.enterprise");
     }
 
     #[test]
@@ -131,7 +131,7 @@ impl From<crate::secrets::SecretError> for AuthError {
 
 impl From<reqwest::Error> for AuthError {
     fn from(err: reqwest::Error) -> Self {
-        AuthError::Network(err.to_string())
+        AuthError::Network(crate::core::net::describe(&err))
     }
 }
 
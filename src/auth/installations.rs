@@ -121,7 +121,7 @@ pub async fn check_repo_authorization<T: TokenProvider>(
     repo: &str,
 ) -> Result<Option<RepoAuthResult>, AuthError> {
     let token = token_provider.bearer_token().await?;
-    let client = reqwest::Client::new();
+    let client = crate::core::net::client();
     let base_url = api_base_url(host);
 
     // Step 1: Get user installations
@@ -141,8 +141,11 @@ pub async fn check_repo_authorization<T: TokenProvider>(
     Ok(None)
 }
 
-/// Get the API base URL for a GitHub host.
-fn api_base_url(host: &str) -> String {
+/// Get the REST API base URL for a GitHub host.
+///
+/// github.com is served from `api.github.com`; GitHub Enterprise Server
+/// serves its API under `/api/v3` on the instance's own host.
+pub fn api_base_url(host: &str) -> String {
     if host == "github.com" {
         "https://api.github.com".to_string()
     } else {
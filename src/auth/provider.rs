@@ -47,7 +47,7 @@ use chrono::{DateTime, Utc};
 use super::device_flow::DeviceFlowClient;
 use super::errors::AuthError;
 use super::lock::{AuthLock, DEFAULT_LOCK_TIMEOUT};
-use super::token_bundle::{TokenBundle, TokenInfo, UserInfo};
+use super::token_bundle::{TokenBundle, TokenInfo, UserInfo, GITHUB_APP_CLIENT_ID};
 use super::TokenProvider;
 use crate::secrets::SecretStore;
 
@@ -157,8 +157,8 @@ impl GitHubAuthManager {
             return Err(AuthError::Expired(self.host.clone()));
         }
 
-        // Perform refresh
-        let client = DeviceFlowClient::new(&self.host);
+        // Perform refresh with the app that issued the tokens
+        let client = DeviceFlowClient::with_client_id(&self.host, &bundle.client_id);
         let token_response = client.refresh_token(&bundle.tokens.refresh_token).await?;
 
         // Create updated bundle with new tokens
@@ -214,7 +214,20 @@ impl GitHubAuthManager {
         user: UserInfo,
         tokens: TokenInfo,
     ) -> Result<TokenBundle, AuthError> {
-        let bundle = TokenBundle::new(&self.host, user, tokens);
+        self.store_tokens_for_client(user, tokens, GITHUB_APP_CLIENT_ID)
+    }
+
+    /// Store a new token bundle issued to the app with `client_id`.
+    ///
+    /// Used after logging in to a GitHub Enterprise Server instance through
+    /// its own app registration.
+    pub fn store_tokens_for_client(
+        &self,
+        user: UserInfo,
+        tokens: TokenInfo,
+        client_id: &str,
+    ) -> Result<TokenBundle, AuthError> {
+        let bundle = TokenBundle::new(&self.host, user, tokens).with_client_id(client_id);
         self.save_bundle(&bundle)?;
         self.update_cache(bundle.clone());
         Ok(bundle)
@@ -401,6 +414,31 @@ mod tests {
         assert_eq!(info.login, "newuser");
     }
 
+    #[test]
+    fn enterprise_tokens_keep_their_host_and_app() {
+        let store = Box::new(MockSecretStore::new());
+        let manager = GitHubAuthManager::new("github.example.com", store);
+
+        let user = UserInfo {
+            id: 7,
+            login: "enterprise-user".to_string(),
+        };
+        let tokens = TokenInfo::new(
+            "ghu_ent".to_string(),
+            3600,
+            "ghr_ent".to_string(),
+            15_552_000,
+        );
+        manager
+            .store_tokens_for_client(user, tokens, "Iv1.enterprise")
+            .expect("store tokens");
+
+        let bundle = manager.load_bundle().unwrap().expect("bundle stored");
+        assert_eq!(bundle.host, "github.example.com");
+        assert_eq!(bundle.client_id, "Iv1.enterprise");
+        assert_eq!(manager.secret_key(), "github_app.oauth.github.example.com");
+    }
+
     #[test]
     fn delete_tokens_clears_auth() {
         let bundle = make_test_bundle();
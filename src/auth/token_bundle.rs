@@ -152,6 +152,15 @@ impl TokenBundle {
         }
     }
 
+    /// Record the client ID of the app the tokens were issued to.
+    ///
+    /// Refreshes must use the same app, which on GitHub Enterprise Server
+    /// is the instance's own registration rather than [`GITHUB_APP_CLIENT_ID`].
+    pub fn with_client_id(mut self, client_id: &str) -> Self {
+        self.client_id = client_id.to_string();
+        self
+    }
+
     /// Get the SecretStore key for a host.
     ///
     /// # Example
@@ -349,6 +358,14 @@ mod tests {
         assert_eq!(bundle.user.login, "octocat");
     }
 
+    #[test]
+    fn with_client_id_overrides_default_app() {
+        let bundle = make_test_bundle().with_client_id("Iv1.enterprise");
+        assert_eq!(bundle.client_id, "Iv1.enterprise");
+        let refreshed = bundle.with_refreshed_tokens(make_test_bundle().tokens);
+        assert_eq!(refreshed.client_id, "Iv1.enterprise");
+    }
+
     #[test]
     fn serialize_deserialize_roundtrip() {
         let bundle = make_test_bundle();
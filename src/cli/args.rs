@@ -10,8 +10,12 @@
 //! - `--cwd <path>`: Run as if in that directory
 //! - `--debug`: Enable debug logging
 //! - `--interactive` / `--no-interactive`: Control prompts
+//! - `--yes` / `-y`: Answer yes to every confirmation
 //! - `--verify` / `--no-verify`: Control git hook execution
 //! - `--quiet` / `-q`: Minimal output
+//! - `--json`: Machine-readable output and errors
+//! - `--read-only`: Disable every command that changes the repository
+//! - `--offline`: Don't contact the remote or forge; use cached PR state
 
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
@@ -41,6 +45,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_interactive: bool,
 
+    /// Answer yes to every confirmation, even without a terminal
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
     /// Enable git hook verification (default behavior)
     #[arg(long, global = true, conflicts_with = "no_verify")]
     pub verify: bool,
@@ -49,14 +57,35 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_verify: bool,
 
+    /// Machine-readable output; errors are reported as JSON on stdout
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Disable every command that changes the repository
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// Don't contact the remote or forge; use cached PR state and queue
+    /// remote work
+    #[arg(long, global = true)]
+    pub offline: bool,
+
     #[command(subcommand)]
     pub command: Command,
+
+    /// Name of the subcommand as clap knows it (aliases resolved)
+    #[arg(skip)]
+    pub command_name: String,
 }
 
 impl Cli {
     /// Parse command-line arguments.
     pub fn parse_args() -> Self {
-        Parser::parse()
+        let matches = <Self as clap::CommandFactory>::command().get_matches();
+        let mut cli =
+            <Self as clap::FromArgMatches>::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+        cli.command_name = matches.subcommand_name().unwrap_or_default().to_string();
+        cli
     }
 
     /// Determine if interactive mode is enabled.
@@ -127,6 +156,9 @@ WORKFLOW EXAMPLES:
     # Apply a specific fix
     lt doctor --fix orphan-meta-1
 
+    # Bootstrap scripts: apply every unambiguous fix for warnings and info
+    lt doctor --fix-all --max-severity warning --yes
+
 COMMON SCENARIOS:
     After a failed rebase or interrupted operation:
         lt doctor              # diagnose what went wrong
@@ -138,6 +170,17 @@ COMMON SCENARIOS:
         #[arg(long = "fix", value_name = "FIX_ID")]
         fix_ids: Vec<String>,
 
+        /// Apply the fix of every issue that has exactly one
+        ///
+        /// Issues with several fixes are skipped and listed so one can be
+        /// chosen with --fix. Asks for confirmation; pass --yes in scripts.
+        #[arg(long, conflicts_with_all = ["fix_ids", "list"])]
+        fix_all: bool,
+
+        /// Only fix issues at or below this severity (with --fix-all)
+        #[arg(long, value_enum, requires = "fix_all", default_value = "error")]
+        max_severity: SeverityArg,
+
         /// Show what would be done without making changes
         #[arg(long)]
         dry_run: bool,
@@ -177,11 +220,24 @@ WORKFLOW EXAMPLES:
     # See all tracked branches across all stacks
     lt log --all
 
+    # Wide stacks: collapse other branches more than one level deep
+    lt log --all --depth 1
+
+    # Mark several branches and restack, submit, or freeze them together
+    lt log --all --select
+
+    # Render every stack with Graphviz
+    lt log --all --export dot | dot -Tsvg > stacks.svg
+
+    # What has Lattice done to this repository? (--json to export it)
+    lt log --ops
+
 READING THE OUTPUT:
-    * feature-c       <- you are here
-      feature-b       <- parent of feature-c
-      feature-a       <- parent of feature-b
-      main            <- trunk (root of the stack)"
+      main                         <- trunk (root of the stack)
+      └── feature-a (2 children)   <- parent of feature-b and feature-c
+          ├── feature-b
+          │   └── … 3 branches     <- collapsed by --depth 1
+    *     └── feature-c            <- you are here"
     )]
     Log {
         /// Short format (branch names only)
@@ -203,15 +259,40 @@ READING THE OUTPUT:
         /// Reverse display order (oldest first)
         #[arg(short, long)]
         reverse: bool,
+
+        /// Collapse branches more than N levels off the current branch's line
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+
+        /// Number the branches, then restack, submit, or freeze the ones you mark
+        #[arg(long, conflicts_with_all = ["short", "long"])]
+        select: bool,
+
+        /// Print the stack graph with PR links for a visualization tool
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FORMAT",
+            conflicts_with_all = ["short", "long", "reverse", "depth", "select"]
+        )]
+        export: Option<GraphFormat>,
+
+        /// List the operations recorded in the event ledger instead of branches
+        #[arg(
+            long,
+            conflicts_with_all = ["short", "long", "stack", "all", "depth", "select", "export"]
+        )]
+        ops: bool,
     },
 
     /// Show tracking status, parent, freeze state for a branch
     #[command(
         name = "info",
         long_about = "Show detailed information about a branch's tracking status.\n\n\
-            Displays the parent branch, base commit, freeze state, and PR linkage for \
-            a branch. Use --diff, --stat, or --patch to see the changes this branch \
-            introduces relative to its parent.",
+            Displays the parent branch, base commit, freeze state, PR linkage, and the \
+            commits unique to a branch. Use --diff or --stat to see the changes this \
+            branch introduces relative to its parent, --files to list the files it \
+            adds, modifies, or deletes, or --patch to see each commit's patch.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # See info for current branch
@@ -225,7 +306,16 @@ WORKFLOW EXAMPLES:
     lt info --diff           # full diff from parent
 
     # Detailed review workflow
-    lt info --patch          # see full patch for code review"
+    lt info --patch          # walk the branch commit by commit
+
+    # Shape of the whole stack: per-branch and cumulative vs trunk
+    lt info --stack --stat
+
+    # Files each branch of the stack touches, before reordering
+    lt info --stack --files
+
+    # What changed locally since the last push (before submitting)
+    lt info --remote-diff"
     )]
     Info {
         /// Branch to show info for (defaults to current)
@@ -239,9 +329,23 @@ WORKFLOW EXAMPLES:
         #[arg(long)]
         stat: bool,
 
-        /// Show full patch from base
+        /// Show each commit's patch after it in the commit list
         #[arg(long)]
         patch: bool,
+
+        /// List the files changed from base, marked A, M, or D
+        #[arg(long)]
+        files: bool,
+
+        /// Show stats or files for every branch in the stack (with --stat
+        /// or --files)
+        #[arg(long)]
+        stack: bool,
+
+        /// Fetch the branch's remote head and range-diff it against the
+        /// local commits
+        #[arg(long, conflicts_with_all = ["diff", "stat", "patch", "files"])]
+        remote_diff: bool,
     },
 
     /// Print parent branch name
@@ -281,6 +385,33 @@ WORKFLOW EXAMPLES:
     )]
     Children,
 
+    /// Browse the stack in a full-screen terminal UI
+    #[command(
+        name = "ui",
+        long_about = "Browse all tracked branches in a full-screen terminal UI.\n\n\
+            Draws the stack tree as `lt log --all` does, with the current branch \
+            selected and a description of the selected branch below. Move with the \
+            arrow keys (or j/k), then press Enter to check out the branch, r to \
+            restack it, or s to submit it. The action runs exactly as the matching \
+            command would; q or Esc leaves without doing anything.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Find a branch in a wide stack and jump to it
+    lt ui                  # arrows to select, Enter to check out
+
+    # Restack one branch without remembering its name
+    lt ui                  # select it, press r
+
+KEYS:
+    ↑/k ↓/j      move between branches
+    g/Home G/End first / last branch
+    Enter or c   check out
+    r            restack
+    s            submit
+    q or Esc     quit"
+    )]
+    Ui,
+
     /// Display or set the trunk branch
     #[command(
         name = "trunk",
@@ -305,6 +436,75 @@ WORKFLOW EXAMPLES:
         set: Option<String>,
     },
 
+    /// Show cycle-time stats for a branch
+    #[command(
+        name = "stats",
+        long_about = "Show how long a branch took to move through its lifecycle.\n\n\
+            Lattice records when each branch is created, first submitted, restacked, \
+            and merged. This command reads that history and reports time to submit, \
+            time in review, total cycle time, and restack count. Stats stay available \
+            after a merged branch has been deleted.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Stats for the current branch
+    lt stats
+
+    # Stats for a branch that has already merged
+    lt stats --branch feature-auth
+
+    # Export for dashboards or spreadsheets
+    lt stats --branch feature-auth --json"
+    )]
+    Stats {
+        /// Branch to report on (defaults to current)
+        #[arg(long)]
+        branch: Option<String>,
+    },
+
+    /// Report on repository hygiene without changing anything
+    #[command(
+        name = "audit",
+        long_about = "Report on repository hygiene without changing anything.\n\n\
+            Combines the scan and doctor's checks into one report: tracked and untracked \
+            branches, metadata problems, which branches have linked PRs, stacks that have \
+            fallen behind trunk, and frozen branches with their reason and tip author. \
+            Nothing is fixed or written; run 'lt doctor' to repair what the audit finds.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Markdown report, ready to paste into a hygiene review
+    lt audit > audit.md
+
+    # JSON for scripts and dashboards
+    lt audit --json"
+    )]
+    Audit,
+
+    /// Inspect and reset Lattice's caches
+    #[command(
+        name = "debug",
+        long_about = "Inspect and reset Lattice's caches.\n\n\
+            Lattice keeps ancestry answers (merge bases, is-ancestor checks, commit counts), \
+            PR and check status, and GitHub App authorization in files under \
+            <common_dir>/lattice/cache/. 'cache-stats' shows each cache's entries, file \
+            size, and hit rate, to diagnose slow commands in large repositories and tune \
+            [cache] ancestry_entries and [prefetch] interval_secs. 'clear-cache' deletes \
+            them; they fill again as commands run.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # How well are the caches working here?
+    lt debug cache-stats
+
+    # Machine-readable, for comparing before and after a config change
+    lt debug cache-stats --json
+
+    # Start over with empty caches
+    lt debug clear-cache"
+    )]
+    Debug {
+        #[command(subcommand)]
+        action: DebugAction,
+    },
+
     // ========== Phase B: Setup Commands ==========
     /// Authenticate with GitHub using OAuth device flow
     #[command(
@@ -312,12 +512,19 @@ WORKFLOW EXAMPLES:
         long_about = "Authenticate with GitHub using OAuth device flow.\n\n\
             Lattice uses GitHub App OAuth to authenticate. This is more secure than \
             personal access tokens and supports automatic token refresh. Your browser \
-            will open to authorize the Lattice GitHub App.",
+            will open to authorize the Lattice GitHub App.\n\n\
+            Tokens are stored per host. For GitHub Enterprise Server, pass --host with \
+            the instance's host name and --client-id with the client ID of the app \
+            registered on that instance; repositories whose remote is on a host you \
+            have logged in to are then handled as GitHub repositories.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Authenticate (opens browser automatically)
     lt auth
 
+    # Authenticate with a GitHub Enterprise Server instance
+    lt auth --host github.example.com --client-id Iv1.0123456789abcdef
+
     # Authenticate without opening browser
     lt auth --no-browser
 
@@ -343,6 +550,10 @@ HOW IT WORKS:
         #[arg(long, default_value = "github.com")]
         host: String,
 
+        /// Client ID of the GitHub App to authorize (for GitHub Enterprise Server)
+        #[arg(long)]
+        client_id: Option<String>,
+
         /// Show current authentication status
         #[arg(long)]
         status: bool,
@@ -412,14 +623,58 @@ WORKFLOW EXAMPLES:
         action: ConfigAction,
     },
 
+    /// List the hints that suggest next commands, or turn them off
+    #[command(
+        name = "hints",
+        long_about = "List the hints Lattice shows after common situations, or turn them off.\n\n\
+            After commands like 'lt create' or a conflict pause, Lattice suggests the \
+            next command on stderr. Each hint is shown at most three times; dismissing \
+            one stops it for good. Set 'hints = false' in ~/.lattice/config.toml to turn \
+            them all off.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # See which hints are still shown
+    lt hints
+
+    # Stop one hint, or all of them
+    lt hints --dismiss create
+    lt hints --dismiss all
+
+    # Show every hint again
+    lt hints --reset"
+    )]
+    Hints {
+        /// Stop showing this hint ('all' for every hint)
+        #[arg(long, value_name = "NAME")]
+        dismiss: Option<String>,
+
+        /// Show dismissed and retired hints again
+        #[arg(long, conflicts_with = "dismiss")]
+        reset: bool,
+    },
+
     /// Generate shell completion scripts
     #[command(
         name = "completion",
         long_about = "Generate shell completion scripts for tab-completion.\n\n\
             Outputs a completion script for the specified shell. Add the output \
-            to your shell's configuration to enable tab-completion for Lattice commands.",
+            to your shell's configuration to enable tab-completion for Lattice commands.\n\n\
+            The bash, zsh, and fish scripts also complete tracked branch names for \
+            'lt checkout', 'lt delete', 'lt move --onto', and 'lt track --parent', \
+            read from the repository each time you press tab.\n\n\
+            'lt completion install' writes the script to your shell's completion \
+            directory instead, and prints the line to add to your shell's startup \
+            file if one is needed.",
+        args_conflicts_with_subcommands = true,
+        subcommand_negates_reqs = true,
         after_help = "\
 WORKFLOW EXAMPLES:
+    # Install for the shell in $SHELL
+    lt completion install
+
+    # Install for a specific shell
+    lt completion install zsh
+
     # Bash (add to ~/.bashrc)
     lt completion bash >> ~/.bashrc
 
@@ -434,8 +689,24 @@ WORKFLOW EXAMPLES:
     )]
     Completion {
         /// Shell to generate completions for
-        #[arg(value_enum)]
-        shell: Shell,
+        #[arg(value_enum, required = true)]
+        shell: Option<Shell>,
+
+        #[command(subcommand)]
+        action: Option<CompletionAction>,
+    },
+
+    /// Print branch names for shell completion
+    ///
+    /// Called by the scripts from `lt completion`.
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// Command being completed (checkout, delete, move, track)
+        command: String,
+
+        /// Text typed so far
+        #[arg(default_value = "", allow_hyphen_values = true)]
+        prefix: String,
     },
 
     /// Show version and changelog
@@ -539,7 +810,13 @@ WHEN TO USE:
         long_about = "Mark a branch as frozen to prevent Lattice from modifying it.\n\n\
             Frozen branches are tracked but never rebased or modified by Lattice. \
             This is useful for branches you've fetched from others or branches that \
-            have been merged and should remain stable.",
+            have been merged and should remain stable.\n\n\
+            By default the branch and its downstack ancestors are frozen. --upstack \
+            freezes the branch and its descendants instead, and --stack its whole \
+            stack. A glob pattern such as 'release/*' (quote it from the shell) \
+            selects every tracked branch it matches; '*' stays within one \
+            '/'-separated segment and '**' spans segments. All selected branches \
+            change in one operation, which can be undone.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Freeze current branch
@@ -552,18 +829,32 @@ WORKFLOW EXAMPLES:
     # Freeze just this branch, not downstack ancestors
     lt freeze --only
 
+    # Freeze an imported stack in one go
+    lt freeze --stack
+
+    # Freeze every release branch
+    lt freeze 'release/*' --only
+
 WHEN TO USE:
     - You fetched someone else's branch and don't want to rebase it
     - A branch has been merged and should stay as-is
     - You want to preserve exact commit history"
     )]
     Freeze {
-        /// Branch to freeze (defaults to current)
+        /// Branch or glob pattern to freeze (defaults to current)
         branch: Option<String>,
 
         /// Only freeze this branch, not downstack
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["upstack", "stack"])]
         only: bool,
+
+        /// Freeze this branch and its descendants instead of downstack
+        #[arg(long, conflicts_with = "stack")]
+        upstack: bool,
+
+        /// Freeze every branch in this branch's stack
+        #[arg(long)]
+        stack: bool,
     },
 
     /// Unmark a branch as frozen
@@ -571,7 +862,9 @@ WHEN TO USE:
         name = "unfreeze",
         long_about = "Unmark a frozen branch so Lattice can modify it again.\n\n\
             After unfreezing, the branch will participate in restack operations \
-            and can be modified by other Lattice commands.",
+            and can be modified by other Lattice commands. Branches are selected \
+            as for 'lt freeze': downstack by default, --only, --upstack, --stack, \
+            or a glob pattern.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Unfreeze current branch
@@ -582,15 +875,117 @@ WORKFLOW EXAMPLES:
     lt restack         # now it will rebase onto parent
 
     # Unfreeze just this branch
-    lt unfreeze --only"
+    lt unfreeze --only
+
+    # Unfreeze a teammate's stack you've taken over
+    lt unfreeze --stack"
     )]
     Unfreeze {
-        /// Branch to unfreeze (defaults to current)
+        /// Branch or glob pattern to unfreeze (defaults to current)
         branch: Option<String>,
 
         /// Only unfreeze this branch, not downstack
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["upstack", "stack"])]
         only: bool,
+
+        /// Unfreeze this branch and its descendants instead of downstack
+        #[arg(long, conflicts_with = "stack")]
+        upstack: bool,
+
+        /// Unfreeze every branch in this branch's stack
+        #[arg(long)]
+        stack: bool,
+    },
+
+    /// Lock a branch so collaborators don't restack or submit it
+    #[command(
+        name = "lock",
+        long_about = "Take an advisory lock on a branch.\n\n\
+            While the lock holds, Lattice refuses to rewrite or submit the branch for \
+            anyone but its owner (git user.email). Locks expire after --ttl minutes; \
+            locking a branch you already hold renews it. With sync_metadata_refs = true \
+            in the repo config, locks are shared through the configured remote, so \
+            collaborators see each other's locks. Plain git ignores them.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Hold the current branch while you restack and resubmit it
+    lt lock
+    lt restack && lt submit
+    lt unlock
+
+    # Lock a branch for half an hour
+    lt lock auth-ui --ttl 30
+
+    # See who holds what
+    lt lock --list"
+    )]
+    Lock {
+        /// Branch to lock (defaults to current)
+        #[arg(conflicts_with = "list")]
+        branch: Option<String>,
+
+        /// Minutes until the lock expires
+        #[arg(long, default_value_t = 120, value_parser = clap::value_parser!(u64).range(1..=43_200))]
+        ttl: u64,
+
+        /// List locks and their owners instead of locking
+        #[arg(long)]
+        list: bool,
+    },
+
+    /// Release a branch lock
+    #[command(
+        name = "unlock",
+        long_about = "Release an advisory branch lock taken with 'lattice lock'.\n\n\
+            Only the lock's owner can release an unexpired lock unless --force is \
+            given. Expired locks can be released by anyone.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Done with the branch
+    lt unlock
+
+    # Break a lock left by a collaborator who is away
+    lt unlock auth-ui --force"
+    )]
+    Unlock {
+        /// Branch to unlock (defaults to current)
+        branch: Option<String>,
+
+        /// Release the lock even if someone else holds it
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Show or override the remote a branch is pushed to
+    #[command(
+        name = "remote",
+        long_about = "Show or override the git remote a branch is pushed to and fetched from.\n\n\
+            By default every branch uses 'origin'. An override applies to one branch \
+            only and is honored by submit, sync, and get. The remote must already \
+            exist in git; configure its push URL with 'git remote set-url --push'.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Show the effective remote for the current branch
+    lt remote
+
+    # Push a sensitive fix to the security fork
+    git remote add security git@github.com:org/repo-security.git
+    lt remote --set security
+
+    # Go back to the default remote
+    lt remote --unset"
+    )]
+    Remote {
+        /// Branch to inspect or update (defaults to current)
+        branch: Option<String>,
+
+        /// Remote to push and fetch this branch with
+        #[arg(long, conflicts_with = "unset")]
+        set: Option<String>,
+
+        /// Clear the override and use the default remote
+        #[arg(long)]
+        unset: bool,
     },
 
     // ========== Phase D: Navigation Commands ==========
@@ -617,6 +1012,9 @@ WORKFLOW EXAMPLES:
     # Pick from current stack only
     lt checkout --stack
 
+    # Work on a branch in its own worktree
+    lt checkout feature-auth --worktree ../feature-auth
+
 NAVIGATING YOUR STACK:
     lt up      # go to child branch
     lt down    # go to parent branch
@@ -634,6 +1032,11 @@ NAVIGATING YOUR STACK:
         /// Filter selector to current stack
         #[arg(long)]
         stack: bool,
+
+        /// Check out in a worktree at this path, creating it if the branch
+        /// isn't already checked out in one
+        #[arg(long, value_name = "PATH")]
+        worktree: Option<PathBuf>,
     },
 
     /// Move up to a child branch
@@ -641,7 +1044,9 @@ NAVIGATING YOUR STACK:
         name = "up",
         long_about = "Navigate up the stack to a child branch.\n\n\
             Moves from the current branch to a branch that is stacked on top of it. \
-            If there are multiple children, shows a picker in interactive mode.",
+            If there are multiple children, Lattice follows the child you last \
+            navigated through, or shows a picker in interactive mode. Use --child \
+            to choose explicitly.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Move to child branch
@@ -654,12 +1059,19 @@ WORKFLOW EXAMPLES:
     lt bottom           # start at base
     lt up               # review first branch
     lt up               # review second branch
-    # ... continue up the stack"
+    # ... continue up the stack
+
+    # Stack forks: pick the line leading to a branch
+    lt up --child feature-b"
     )]
     Up {
         /// Number of steps to move (default 1)
         #[arg(default_value = "1")]
         steps: u32,
+
+        /// Branch to head towards when the stack forks
+        #[arg(long)]
+        child: Option<String>,
     },
 
     /// Move down to the parent branch
@@ -693,7 +1105,8 @@ WORKFLOW EXAMPLES:
         name = "top",
         long_about = "Jump to the top (leaf) of the current stack.\n\n\
             Navigates to the branch at the end of the stack - the one with no \
-            children. If there are multiple leaf branches, shows a picker.",
+            children. At forks, follows the line you last navigated or shows a \
+            picker; --child chooses explicitly.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Jump to top of stack
@@ -702,9 +1115,16 @@ WORKFLOW EXAMPLES:
     # Common workflow: start review from bottom, end at top
     lt bottom            # go to base of stack
     # ... review each branch ...
-    lt top               # or jump straight to the end"
+    lt top               # or jump straight to the end
+
+    # Stack forks: pick the leaf to end on
+    lt top --child feature-b-tests"
     )]
-    Top,
+    Top {
+        /// Branch to head towards when the stack forks
+        #[arg(long)]
+        child: Option<String>,
+    },
 
     /// Move to the bottom of the current stack (trunk-child)
     #[command(
@@ -752,6 +1172,12 @@ WORKFLOW EXAMPLES:
     # Restack current branch and ancestors (rare)
     lt restack --downstack
 
+    # Pin the branch to an older trunk commit
+    lt restack --onto main~3
+
+    # See which branches would conflict before restacking
+    lt restack --check
+
 HANDLING CONFLICTS:
     If a rebase conflicts, Lattice pauses:
     1. Resolve conflicts in your editor
@@ -772,6 +1198,14 @@ HANDLING CONFLICTS:
         /// Restack this branch and its ancestors
         #[arg(long)]
         downstack: bool,
+
+        /// Rebase onto this commit in the parent's history instead of its tip
+        #[arg(long, value_name = "COMMIT", conflicts_with = "downstack")]
+        onto: Option<String>,
+
+        /// Predict which branches would conflict, without changing anything
+        #[arg(long, conflicts_with = "onto")]
+        check: bool,
     },
 
     /// Continue a paused operation after resolving conflicts
@@ -780,7 +1214,9 @@ HANDLING CONFLICTS:
         long_about = "Continue a paused operation after resolving conflicts.\n\n\
             When a rebase or other operation encounters conflicts, Lattice pauses \
             and waits for you to resolve them. After fixing conflicts and staging \
-            the resolved files, run this command to continue.",
+            the resolved files, run this command to continue.\n\n\
+            With --skip, the commit git stopped on is dropped from the branch \
+            instead of applied, and the operation resumes with the next one.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # After resolving conflicts
@@ -790,6 +1226,9 @@ WORKFLOW EXAMPLES:
     # Stage all changes and continue in one step
     lt continue --all
 
+    # Drop the conflicting commit (e.g. it already landed upstream)
+    lt continue --skip
+
 TYPICAL CONFLICT WORKFLOW:
     lt restack                   # conflicts!
     # ... edit files to resolve conflicts ...
@@ -805,6 +1244,10 @@ IF YOU WANT TO GIVE UP:
         /// Stage all changes before continuing
         #[arg(long, short)]
         all: bool,
+
+        /// Drop the commit git stopped on instead of applying it
+        #[arg(long, conflicts_with = "all")]
+        skip: bool,
     },
 
     /// Abort a paused operation and restore pre-operation state
@@ -826,13 +1269,17 @@ WORKFLOW EXAMPLES:
     )]
     Abort,
 
-    /// Undo the last completed operation
+    /// Undo the last completed operation, or every operation back to one
     #[command(
         name = "undo",
-        long_about = "Undo the last completed Lattice operation.\n\n\
+        long_about = "Undo completed Lattice operations.\n\n\
             Reverses the effects of the most recent operation (restack, create, \
-            modify, etc.). This is a safety net for when an operation didn't \
-            produce the results you expected.",
+            modify, etc.) that hasn't been undone yet, so running it again goes \
+            further back. This is a safety net for when an operation didn't \
+            produce the results you expected.\n\n\
+            With an operation ID from 'undo --list', undoes every operation back \
+            to and including that one. Each older operation is checked first: if \
+            a ref it changed was moved outside Lattice since, nothing is undone.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Undo a restack that went wrong
@@ -843,11 +1290,95 @@ WORKFLOW EXAMPLES:
     lt create my-branch
     lt undo                      # branch is gone
 
+    # Go back several operations at once
+    lt undo --list               # recent operations, newest first
+    lt undo 3f2a9c1e             # undo everything back to this one
+
 NOTE:
-    Only the most recent operation can be undone. Undo is not a full
+    Remote changes (pushes, PRs) are never undone. Undo is not a full
     version control system - for that, use git reflog."
     )]
-    Undo,
+    Undo {
+        /// Undo back to and including this operation (a unique ID prefix)
+        op_id: Option<String>,
+
+        /// List recent operations instead of undoing
+        #[arg(long, conflicts_with = "op_id")]
+        list: bool,
+    },
+
+    /// Reapply the operation most recently undone
+    #[command(
+        name = "redo",
+        long_about = "Reapply the operation most recently reverted by 'undo'.\n\n\
+            Sets every ref the operation changed back to the value it left, using \
+            the operation's journal. Repeated redos reapply earlier undos in turn, \
+            and an undo after a redo reverts the operation again.\n\n\
+            Refuses if any of those refs moved since the undo, for example because \
+            of a new commit or another operation, since reapplying would discard it.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Change your mind about an undo
+    lt restack
+    lt undo                      # back to before the restack
+    lt redo                      # restack result is back
+
+    # Step back and forth through recent operations
+    lt undo && lt undo           # revert the last two operations
+    lt redo                      # reapply the older of the two"
+    )]
+    Redo,
+
+    /// Save or restore named checkpoints of the whole stack
+    #[command(
+        name = "checkpoint",
+        long_about = "Save and restore named checkpoints of the whole stack.\n\n\
+            A checkpoint records every tracked branch's tip and metadata under \
+            refs/lattice/checkpoints/<name>. Restoring moves branches back to their \
+            saved tips, recreates deleted branches, rewrites metadata, and untracks \
+            branches tracked since the save. Unlike undo, checkpoints are created \
+            explicitly and kept until deleted. A restore can itself be undone.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Save a restore point before a risky reorganization
+    lt checkpoint save before-reorg
+    lt reorder
+    lt move --onto main
+
+    # Not what you wanted? Go back
+    lt checkpoint restore before-reorg
+
+    # Manage checkpoints
+    lt checkpoint list
+    lt checkpoint delete before-reorg"
+    )]
+    Checkpoint {
+        #[command(subcommand)]
+        action: CheckpointAction,
+    },
+
+    /// Generate editor integration files
+    #[command(
+        name = "integrations",
+        long_about = "Generate editor configuration that runs Lattice commands.\n\n\
+            Writes tasks (VS Code) or user commands (Neovim) for viewing the stack tree, \
+            submitting the current stack, restacking, syncing, and reading branch stats \
+            and doctor issues in machine-readable form.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Add Lattice tasks to this repository's .vscode/tasks.json
+    lt integrations install vscode
+
+    # Add :LtLog, :LtSubmit, ... to Neovim
+    lt integrations install nvim
+
+    # Replace a tasks.json with comments, or a hand-written lattice.lua
+    lt integrations install vscode --force"
+    )]
+    Integrations {
+        #[command(subcommand)]
+        action: IntegrationsAction,
+    },
 
     /// Create a new tracked branch
     #[command(
@@ -876,6 +1407,9 @@ WORKFLOW EXAMPLES:
     # Insert a branch between current and its child
     lt create hotfix --insert   # becomes parent of current's child
 
+    # Start work on an issue (branch named from its title; the PR closes it)
+    lt create --from-issue 123
+
 BUILDING A STACK:
     lt create feature-part-1 -a -m \"first part\"
     lt create feature-part-2 -a -m \"second part\"
@@ -905,6 +1439,15 @@ BUILDING A STACK:
         /// Insert between current branch and its child
         #[arg(short, long)]
         insert: bool,
+
+        /// Name the branch after this forge issue and close it from the PR
+        #[arg(long, value_name = "NUMBER")]
+        from_issue: Option<u64>,
+
+        /// Name the branch after the commit message, writing it in the
+        /// editor first if --message is not given
+        #[arg(long, conflicts_with_all = ["name", "from_issue"])]
+        slug_from_message: bool,
     },
 
     // ========== Phase 3: Advanced Rewriting Commands ==========
@@ -914,7 +1457,8 @@ BUILDING A STACK:
         long_about = "Amend the current branch's commit and automatically restack descendants.\n\n\
             This is the safe way to amend commits when you have branches stacked on top. \
             After amending, Lattice automatically restacks all descendant branches to \
-            incorporate your changes.",
+            incorporate your changes. The affected branches, with a merge-tree estimate \
+            of which will conflict, are listed before anything is rewritten.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Amend with all current changes
@@ -933,6 +1477,10 @@ WORKFLOW EXAMPLES:
     # Edit commit message in editor
     lt modify --edit
 
+    # Amend now, restack descendants later
+    lt modify -a --no-restack
+    lt restack
+
 RESPONDING TO CODE REVIEW:
     # Reviewer requested changes to an earlier branch
     lt checkout feature-auth     # go to that branch
@@ -965,6 +1513,10 @@ RESPONDING TO CODE REVIEW:
         /// Open editor for commit message
         #[arg(short, long)]
         edit: bool,
+
+        /// Do not restack descendants (run 'lattice restack' later)
+        #[arg(long)]
+        no_restack: bool,
     },
 
     /// Reparent branch onto another branch
@@ -1018,11 +1570,19 @@ WORKFLOW EXAMPLES:
     # Typical workflow: realize you want a better name
     lt create temp-name -a -m \"wip\"
     # ... work on it, realize what it should be called ...
-    lt rename auth-token-refresh"
+    lt rename auth-token-refresh
+
+    # Rename on the remote too, moving the PR and its children's PRs
+    lt rename auth-token-refresh --push"
     )]
     Rename {
         /// New name for the branch
         name: String,
+
+        /// Also push the new name, move the branch's PR to it, retarget
+        /// child PRs, and delete the old name from the remote
+        #[arg(long)]
+        push: bool,
     },
 
     /// Delete a branch
@@ -1097,6 +1657,10 @@ BEFORE SUBMITTING:
         /// Open editor for commit message
         #[arg(short, long)]
         edit: bool,
+
+        /// Do not restack descendants (run 'lattice restack' later)
+        #[arg(long)]
+        no_restack: bool,
     },
 
     /// Fold current branch into parent
@@ -1179,6 +1743,123 @@ WHEN TO USE:
     )]
     Reorder,
 
+    /// Edit the commits of the current branch or stack in one todo
+    #[command(
+        name = "edit",
+        long_about = "Rewrite commits with an interactive-rebase style todo.\n\n\
+            Opens your editor with the commits of the current branch, oldest \
+            first. With --stack, every branch in the current stack is listed, \
+            each branch's commits under a 'branch <name>' line. Reword, drop, \
+            fixup, or reorder commits, or move commit lines past a 'branch' \
+            line to move them to that branch. The result runs as one rebase \
+            per branch, from the lowest changed branch up, in a single \
+            operation that can be continued, aborted, or undone.\n\n\
+            With a branch name, checks that branch out so you can amend it; \
+            'lt edit --done' then restacks its descendants and returns to the \
+            branch you started on. With --patch, the staged changes (or hunks \
+            picked with 'git add --patch') are amended into the branch and \
+            you are brought back in one step.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Edit the current branch's commits
+    lt edit
+
+    # Edit every branch in the stack at once
+    lt edit --stack
+
+    # In the editor, you'll see something like:
+    #   branch feature-a
+    #   pick 1a2b3c4 Add parser
+    #   pick 5d6e7f8 Fix typo in lexer
+    #
+    #   branch feature-b
+    #   pick 9a8b7c6 Use parser in CLI
+    #
+    # Move the typo fix up to feature-b and reword the parser commit:
+    #   branch feature-a
+    #   reword 1a2b3c4 Add expression parser
+    #
+    #   branch feature-b
+    #   pick 5d6e7f8 Fix typo in lexer
+    #   pick 9a8b7c6 Use parser in CLI
+
+    # Fix something two branches down, then come back
+    lt edit feature-a
+    lt modify -a
+    lt edit --done
+
+    # Amend the hunks you pick into feature-a without leaving this branch
+    lt edit feature-a --patch
+
+WHEN TO USE:
+    - A commit landed on the wrong branch of the stack
+    - You want to clean up history across several branches at once
+    - A branch lower in the stack needs a fix (lt edit <branch>)
+    - Use 'lt reorder' instead to reorder whole branches"
+    )]
+    Edit {
+        /// Check out this branch to amend it, then come back with --done
+        #[arg(conflicts_with_all = ["stack", "done"])]
+        branch: Option<String>,
+
+        /// Edit every branch in the current stack, not just the current one
+        #[arg(long)]
+        stack: bool,
+
+        /// Finish a branch edit: restack its descendants and return
+        #[arg(long, conflicts_with = "stack")]
+        done: bool,
+
+        /// Amend staged (or interactively picked) changes into the branch
+        #[arg(long, requires = "branch")]
+        patch: bool,
+
+        /// Do not restack descendants (run 'lattice restack' later)
+        #[arg(long)]
+        no_restack: bool,
+    },
+
+    /// Fold staged changes into the stack commits they belong to
+    #[command(
+        name = "absorb",
+        long_about = "Fold staged changes into the commits of the current stack.\n\n\
+            Each staged hunk goes into the newest commit of the current branch \
+            or its ancestors that last changed the lines it touches, found with \
+            blame. Hunks that cannot be attributed stay staged, as do added, \
+            deleted, and binary files. The rewritten commits and the branches \
+            above them are rebuilt without touching the working tree, and \
+            descendants are restacked, in a single operation that can be undone.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Fix a typo introduced two branches down, then absorb it
+    git add src/lexer.rs
+    lt absorb
+
+    # See where each hunk would go without changing anything
+    lt absorb --dry-run
+
+    # Stage every modified tracked file and absorb
+    lt absorb -u
+
+WHEN TO USE:
+    - Review feedback touches code from several commits in the stack
+    - You want fixups without finding each commit by hand
+    - Use 'lt modify' to amend only the current branch's last commit"
+    )]
+    Absorb {
+        /// Stage modified tracked files first (git add -u)
+        #[arg(short, long)]
+        update: bool,
+
+        /// Show where each hunk would go without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Do not restack descendants (run 'lattice restack' later)
+        #[arg(long)]
+        no_restack: bool,
+    },
+
     /// Split current branch
     #[command(
         name = "split",
@@ -1208,6 +1889,35 @@ WHEN TO USE:
         by_file: Vec<String>,
     },
 
+    /// Build a stack from existing commits
+    #[command(
+        name = "stack",
+        long_about = "Build a stack from commits that already exist.\n\n\
+            'lt stack create' takes a linear run of local commits and creates a \
+            tracked branch for each one, every branch the parent of the next. \
+            Branches are named from their commit subjects. Existing branches and \
+            the working tree are left alone.",
+        after_help = "\
+WORKFLOW EXAMPLES:
+    # Turn the commits on a big local branch into a stack on main
+    lt stack create --from main..big-feature --per-commit
+
+    # Stack the commits since main, ending at HEAD
+    lt stack create --from main.. --per-commit
+
+    # Continue an existing stack with commits made on a detached HEAD
+    lt stack create --from auth-api..HEAD --per-commit
+
+WHEN TO USE:
+    - You committed a whole feature on one branch and want reviewable PRs
+    - Before the first submit; for a branch that's already tracked, use
+      'lt split --by-commit'"
+    )]
+    Stack {
+        #[command(subcommand)]
+        action: StackAction,
+    },
+
     /// Create a revert branch for a commit
     #[command(
         name = "revert",
@@ -1239,6 +1949,23 @@ WORKFLOW EXAMPLES:
             branches, creates PRs for branches that don't have them, and updates \
             existing PRs. PR descriptions automatically include a stack visualization \
             showing how PRs relate to each other.\n\n\
+            When re-pushing over downstack PRs with failing required checks, submit \
+            warns so you can fix the bottom of the stack first. Set \
+            `submit.require_green_downstack = true` to refuse instead.\n\n\
+            Set `submit.body_generator` to a command that prints the body of new \
+            PRs from branch and commit JSON on stdin.\n\n\
+            Set `submit.pre_push` to a command (such as `cargo test`) that must pass \
+            on each branch, checked out in a temporary worktree, before anything is \
+            pushed. `--no-verify` skips it.\n\n\
+            To submit only part of the current stack, use --select to pick branches \
+            from a numbered list, or --branches to name them. The ancestors a \
+            selected branch is stacked on are submitted with it, except with \
+            --update-only, which updates exactly the selected PRs. \
+            `--update-only --since <branch>` updates the PRs from <branch> up to \
+            the current branch (and above it with --stack).\n\n\
+            `--all` submits every stack in the repository with a branch that has \
+            no PR yet or changed since it was last pushed, stack by stack, and \
+            summarizes the result for each stack.\n\n\
             NOTE: Synthetic snapshot branches (created by `lattice doctor` from closed PRs) \
             are automatically excluded from the submit scope.",
         after_help = "\
@@ -1259,10 +1986,30 @@ WORKFLOW EXAMPLES:
     # Preview without making changes
     lt submit --dry-run
 
+    # Check the resulting PR tree (bases, drafts, reviewers) before submitting
+    lt submit --stack --confirm
+
     # Request reviewers
     lt submit --reviewers alice,bob
     lt submit --team-reviewers backend-team
 
+    # No connectivity: queue pushes and PR updates for the next sync
+    lt submit --offline
+
+    # Submit part of a large stack (ancestors are included)
+    lt submit --select
+    lt submit --branches auth-api,auth-ui
+
+    # Update the lower PRs while the branches above are still WIP
+    lt submit --update-only --since auth-api
+    lt submit --update-only --branches auth-api,auth-ui
+
+    # Review each new PR's templated description before it is opened
+    lt submit --edit
+
+    # After a repo-wide sync and restack, push every stack that changed
+    lt submit --all
+
 TYPICAL WORKFLOW:
     # After finishing a feature
     lt submit                    # create/update PRs
@@ -1286,7 +2033,7 @@ TYPICAL WORKFLOW:
         #[arg(long)]
         publish: bool,
 
-        /// Prompt for confirmation before each action
+        /// Show the resulting PR graph and ask before submitting
         #[arg(long)]
         confirm: bool,
 
@@ -1321,6 +2068,27 @@ TYPICAL WORKFLOW:
         /// Open PR URLs in browser after submit
         #[arg(long)]
         view: bool,
+
+        /// Pick the branches of the current stack to submit from a list
+        #[arg(long, conflicts_with_all = ["stack", "branches", "since"])]
+        select: bool,
+
+        /// Submit only these branches of the current stack (comma-separated),
+        /// plus their ancestors unless --update-only
+        #[arg(long, conflicts_with_all = ["stack", "since"])]
+        branches: Option<String>,
+
+        /// Update PRs from this branch up to the current branch only
+        #[arg(long, value_name = "BRANCH", requires = "update_only")]
+        since: Option<String>,
+
+        /// Edit each new PR's body in $EDITOR before creating it
+        #[arg(long, conflicts_with = "update_only")]
+        edit: bool,
+
+        /// Submit every stack in the repository that needs it
+        #[arg(long, conflicts_with_all = ["stack", "select", "branches", "since"])]
+        all: bool,
     },
 
     /// Sync with remote (fetch, update trunk, detect merged PRs)
@@ -1329,7 +2097,13 @@ TYPICAL WORKFLOW:
         long_about = "Synchronize with the remote repository.\n\n\
             Fetches from origin, updates your local trunk to match remote, and \
             detects which PRs have been merged. This is how you pull in changes \
-            from teammates and keep your stack up to date.",
+            from teammates and keep your stack up to date.\n\n\
+            Any pushes or PR operations queued by an offline submit are replayed \
+            once the fetch succeeds.\n\n\
+            When local trunk has commits origin doesn't, [sync] trunk_strategy \
+            decides what happens to them: 'ff-only' (default) refuses, \
+            'rebase-local' rebases them onto origin's trunk, and 'reset' drops \
+            them. --force always resets.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Start of day: sync with remote
@@ -1342,6 +2116,12 @@ WORKFLOW EXAMPLES:
     # Force update trunk even if it diverged
     lt sync --force
 
+    # Back online after 'lt submit --offline': queued actions are replayed
+    lt sync
+
+    # See what sync would change without changing anything
+    lt sync --dry-run
+
 TYPICAL DAILY WORKFLOW:
     lt sync                      # pull latest changes
     lt restack                   # update your stack
@@ -1360,6 +2140,54 @@ TYPICAL DAILY WORKFLOW:
         /// Skip restacking after sync
         #[arg(long)]
         no_restack: bool,
+
+        /// Show what would be done without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Offer to delete or untrack branches whose PRs were closed without
+        /// merging (with --force, delete them all without asking)
+        #[arg(long)]
+        prune: bool,
+    },
+
+    /// Run sync or log across the repositories of a workspace
+    #[command(
+        name = "ws",
+        long_about = "Run sync or log in every repository of a workspace.\n\n\
+            A workspace file (lattice-workspace.toml) lists related repositories \
+            whose stacks you maintain together, as [[repo]] entries with a path \
+            (relative to the file) and an optional name. It is found in the current \
+            directory or a parent, or given with --file or LATTICE_WORKSPACE.\n\n\
+            Each repository is handled as if lattice had been started there, under a \
+            heading with its name. A repository that fails doesn't stop the others; \
+            the command fails at the end if any did.",
+        after_help = "\
+WORKSPACE FILE (lattice-workspace.toml):
+    [[repo]]
+    path = \"api\"
+
+    [[repo]]
+    path = \"../shared/proto\"
+    name = \"proto\"
+
+WORKFLOW EXAMPLES:
+    # Start of day across every repository
+    lt ws sync --restack
+
+    # See each repository's stacks
+    lt ws log
+
+    # Use a workspace file somewhere else
+    lt ws --file ~/work/platform.toml log"
+    )]
+    Ws {
+        /// Workspace file to use instead of searching for one
+        #[arg(long, global = true)]
+        file: Option<PathBuf>,
+
+        #[command(subcommand)]
+        action: WsAction,
     },
 
     /// Fetch a branch or PR from remote
@@ -1383,6 +2211,12 @@ WORKFLOW EXAMPLES:
     # Fetch and restack onto it
     lt get 1234 --restack
 
+    # See which refs and metadata would change
+    lt get 1234 --dry-run
+
+    # Fetch by PR URL (public GitHub repos work before 'lt auth login')
+    lt get https://github.com/owner/repo/pull/1234
+
 REVIEWING A TEAMMATE'S PR:
     lt get 1234                  # fetch their PR
     lt log                       # see where it fits
@@ -1393,7 +2227,7 @@ BUILDING ON SOMEONE'S WORK:
     lt create my-addition        # stack your work on top"
     )]
     Get {
-        /// Branch name or PR number to fetch
+        /// Branch name, PR number, or PR URL to fetch
         target: String,
 
         /// Only fetch this branch (not upstack)
@@ -1421,6 +2255,10 @@ BUILDING ON SOMEONE'S WORK:
         /// modify working directory. Prints worktree creation guidance.
         #[arg(long)]
         no_checkout: bool,
+
+        /// Show what would be done without making changes
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Merge PRs via GitHub API
@@ -1429,7 +2267,22 @@ BUILDING ON SOMEONE'S WORK:
         long_about = "Merge the current branch's PR via the GitHub API.\n\n\
             Triggers a merge on GitHub for the PR associated with the current \
             branch. After merging, run 'lt sync' to update your local state \
-            and clean up merged branches.",
+            and clean up merged branches.\n\n\
+            With --when-green, each PR is merged only once its checks pass: \
+            Lattice polls the forge every [merge] poll_interval_secs (default 30) \
+            and moves up the stack after each merge. It stops when a check fails \
+            or a PR's checks are still pending after [merge] wait_timeout_secs \
+            (default 3600). Only required checks count when branch protection \
+            marks any.\n\n\
+            With --queue, the PRs are added to GitHub's merge queue bottom-up \
+            instead of merged directly, for repositories whose trunk requires \
+            it. PRs above the bottom are retargeted to trunk first, and each \
+            PR's queue position and state is reported.\n\n\
+            Before each merge, the PR's reviews are checked against its base \
+            branch's protection: required approvals, requested changes, and \
+            code owner reviews. A PR that falls short stops the merge, naming \
+            each unmet requirement. With --rerequest-stale, reviewers whose \
+            approval is of an older commit are asked to review again.",
         after_help = "\
 WORKFLOW EXAMPLES:
     # Merge the current branch's PR
@@ -1442,6 +2295,15 @@ WORKFLOW EXAMPLES:
     # Preview what would be merged
     lt merge --dry-run
 
+    # Merge the stack bottom-up as CI turns green
+    lt merge --when-green
+
+    # Trunk uses a merge queue: enqueue the stack instead
+    lt merge --queue
+
+    # Blocked on outdated approvals: ask for fresh reviews
+    lt merge --rerequest-stale
+
     # Merge with confirmation prompt
     lt merge --confirm
 
@@ -1461,6 +2323,18 @@ AFTER MERGING:
         /// Merge method (merge, squash, rebase)
         #[arg(long, value_enum)]
         method: Option<MergeMethodArg>,
+
+        /// Wait for each PR's checks to pass before merging it
+        #[arg(long)]
+        when_green: bool,
+
+        /// Add the PRs to the merge queue instead of merging them
+        #[arg(long, conflicts_with_all = ["method", "when_green"])]
+        queue: bool,
+
+        /// Ask reviewers who approved an older commit to review again
+        #[arg(long)]
+        rerequest_stale: bool,
     },
 
     /// Open PR URL in browser or print it
@@ -1468,7 +2342,13 @@ AFTER MERGING:
         name = "pr",
         long_about = "Display or open the pull request URL for a branch.\n\n\
             Shows the GitHub PR URL for the current branch or a specified branch. \
-            If your system supports it, can open the URL directly in your browser.",
+            If your system supports it, can open the URL directly in your browser.\n\n\
+            Each URL is followed by the CI state of the branch tip (passing, \
+            pending, or failing). The state is cached in branch metadata, so \
+            the last known state is shown when the forge can't be reached.\n\n\
+            'lt pr open' opens the URLs in the browser even outside an \
+            interactive terminal, and prints them when no browser is available.",
+        args_conflicts_with_subcommands = true,
         after_help = "\
 WORKFLOW EXAMPLES:
     # Show PR URL for current branch
@@ -1480,6 +2360,9 @@ WORKFLOW EXAMPLES:
     # Show PR URL for specific branch
     lt pr feature-auth
 
+    # Open the PRs of the whole stack in the browser
+    lt pr open --stack
+
 QUICK ACCESS:
     # After submitting, quickly open in browser
     lt submit
@@ -1492,6 +2375,9 @@ QUICK ACCESS:
         /// Show URLs for entire stack
         #[arg(long)]
         stack: bool,
+
+        #[command(subcommand)]
+        action: Option<PrAction>,
     },
 
     /// Remove PR linkage from branch metadata
@@ -1518,6 +2404,15 @@ WHEN TO USE:
         /// Branch to unlink (defaults to current)
         branch: Option<String>,
     },
+
+    /// Fetch a branch's PR and check status into the cache
+    ///
+    /// Started in the background by navigation commands.
+    #[command(name = "prefetch", hide = true)]
+    Prefetch {
+        /// Branch whose PR status to fetch
+        branch: String,
+    },
 }
 
 /// Merge method for PRs
@@ -1531,6 +2426,28 @@ pub enum MergeMethodArg {
     Rebase,
 }
 
+/// Issue severities for `doctor --max-severity`, least severe first
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeverityArg {
+    /// Informational issues only
+    Info,
+    /// Warnings and informational issues
+    Warning,
+    /// Every issue, including blocking ones
+    Error,
+}
+
+/// Graph formats for `log --export`
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// JSON Graph Format
+    JsonGraph,
+}
+
 /// Config subcommands
 #[derive(Subcommand, Debug, Clone)]
 pub enum ConfigAction {
@@ -1550,6 +2467,148 @@ pub enum ConfigAction {
     List,
 }
 
+/// Checkpoint subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum CheckpointAction {
+    /// Save the current stack state
+    Save {
+        /// Checkpoint name
+        name: String,
+        /// Overwrite an existing checkpoint with the same name
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Restore the stack to a saved checkpoint
+    Restore {
+        /// Checkpoint name
+        name: String,
+    },
+    /// List saved checkpoints
+    List,
+    /// Delete a saved checkpoint
+    Delete {
+        /// Checkpoint name
+        name: String,
+    },
+}
+
+/// Stack subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum StackAction {
+    /// Create a tracked branch per commit in a range
+    Create {
+        /// Commits to stack, as <start>..<end> (<start> is trunk or a
+        /// tracked branch; <end> defaults to HEAD)
+        #[arg(long, value_name = "RANGE")]
+        from: String,
+        /// One branch per commit (currently the only grouping)
+        #[arg(long, required = true)]
+        per_commit: bool,
+    },
+}
+
+/// PR subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum PrAction {
+    /// Open PR URLs in the browser, printing them if none is available
+    Open {
+        /// Branch to open the PR of (defaults to current)
+        target: Option<String>,
+
+        /// Open the PRs of the entire stack
+        #[arg(long)]
+        stack: bool,
+    },
+}
+
+/// Completion subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum CompletionAction {
+    /// Write the completion script to the shell's completion directory
+    Install {
+        /// Shell to install for (detected from $SHELL by default)
+        #[arg(value_enum)]
+        shell: Option<Shell>,
+    },
+}
+
+/// Integrations subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum IntegrationsAction {
+    /// Write integration files for an editor
+    Install {
+        /// Editor to configure
+        #[arg(value_enum)]
+        editor: Editor,
+        /// Replace files that can't be merged or weren't generated by Lattice
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+/// Debug subcommands
+#[derive(Subcommand, Debug, Clone)]
+pub enum WsAction {
+    /// Sync every repository in the workspace
+    Sync {
+        /// Force reset trunk to remote even if diverged
+        #[arg(long, short)]
+        force: bool,
+
+        /// Restack after syncing
+        #[arg(long)]
+        restack: bool,
+
+        /// Show what would be done without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Offer to delete or untrack branches whose PRs were closed without
+        /// merging
+        #[arg(long)]
+        prune: bool,
+    },
+    /// Show the stacks of every repository in the workspace
+    Log {
+        /// Short format (branch names only)
+        #[arg(short, long)]
+        short: bool,
+
+        /// Long format with full details
+        #[arg(short, long)]
+        long: bool,
+
+        /// Filter to each repository's current stack only
+        #[arg(long)]
+        stack: bool,
+
+        /// Show all tracked branches
+        #[arg(short, long)]
+        all: bool,
+
+        /// Reverse display order (oldest first)
+        #[arg(short, long)]
+        reverse: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum DebugAction {
+    /// Show entries, size, and hit rate of each cache
+    CacheStats,
+    /// Delete all cached data
+    ClearCache,
+}
+
+/// Editors with generated integrations
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+pub enum Editor {
+    /// Visual Studio Code tasks (.vscode/tasks.json)
+    Vscode,
+    /// Neovim user commands (nvim/plugin/lattice.lua)
+    Nvim,
+}
+
 /// Supported shells for completion
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
 #[allow(clippy::enum_variant_names)]
@@ -1581,4 +2640,29 @@ mod tests {
         let cli = Cli::parse_from(["lt", "log"]);
         assert_eq!(cli.verify_flag(), None);
     }
+
+    #[test]
+    fn json_flag_is_global() {
+        let cli = Cli::parse_from(["lt", "stats", "--branch", "feature", "--json"]);
+        assert!(cli.json);
+        assert!(matches!(cli.command, Command::Stats { .. }));
+    }
+
+    #[test]
+    fn pr_open_is_a_subcommand_beside_the_target() {
+        let cli = Cli::parse_from(["lt", "pr", "open", "feature", "--stack"]);
+        assert!(matches!(
+            cli.command,
+            Command::Pr {
+                action: Some(PrAction::Open { target: Some(ref t), stack: true }),
+                ..
+            } if t == "feature"
+        ));
+
+        let cli = Cli::parse_from(["lt", "pr", "feature"]);
+        assert!(matches!(
+            cli.command,
+            Command::Pr { target: Some(ref t), action: None, .. } if t == "feature"
+        ));
+    }
 }
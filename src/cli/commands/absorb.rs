@@ -0,0 +1,571 @@
+//! absorb command - Fold staged changes into the stack commits they belong to
+//!
+//! Per SPEC.md 8D.14:
+//!
+//! - Splits the staged changes into hunks and blames the lines each hunk
+//!   touches at HEAD, across the current branch and its tracked ancestors
+//!   down to the nearest frozen branch
+//! - Each hunk goes into the newest commit that last changed its lines
+//!   (see [`crate::core::absorb`]); hunks that cannot be attributed, and
+//!   added, deleted, renamed, or binary files, stay staged
+//! - The changed commits and everything above them are rebuilt in memory,
+//!   like `git rebase --autosquash` with the fixups already in place, so
+//!   the working tree is never checked out; refs then move through CAS
+//! - Descendants outside the stack are restacked the same way; one that
+//!   would conflict is left for `lattice restack` (`--no-restack` or
+//!   `restack.descendants = false` defers them all)
+//! - `--dry-run` only reports where each hunk would go
+//!
+//! # Integrity Contract
+//!
+//! - Must never rewrite frozen branches
+//! - Nothing moves unless every rewritten commit replays cleanly and the
+//!   new tip holds exactly HEAD plus the absorbed hunks, so the index and
+//!   working tree stay consistent with it
+//! - Metadata updated only after refs succeed
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::process::Command as ProcessCommand;
+
+use anyhow::Context as _;
+
+use crate::cli::commands::restack::{
+    get_ancestors_inclusive, get_descendants_inclusive, topological_sort,
+};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::absorb::{apply_hunks, attribute};
+use crate::core::config::Config;
+use crate::core::metadata::schema::{BaseInfo, BranchMetadataV2};
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
+use crate::engine::command::{Command, CommandOutput};
+use crate::engine::exec::ExecuteResult;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::runner::run_command;
+use crate::engine::scan::scan;
+use crate::engine::Context;
+use crate::git::{CommitInfo, DiffHunk, Git};
+use crate::ui::i18n;
+
+/// Result of absorb command
+#[derive(Debug)]
+pub struct AbsorbResult {
+    /// Branches whose commits were rewritten
+    pub rewritten: Vec<BranchName>,
+    /// Descendants that were restacked
+    pub restacked: Vec<BranchName>,
+}
+
+/// A branch that moves to a rebuilt tip.
+pub struct BranchRewrite {
+    /// Branch name
+    pub branch: BranchName,
+    /// Current tip, for CAS
+    pub old_tip: Oid,
+    /// Rebuilt tip
+    pub new_tip: Oid,
+    /// New base, or `None` if the base stays
+    pub new_base: Option<Oid>,
+    /// Current metadata ref OID for CAS
+    pub metadata_ref_oid: Oid,
+    /// Current metadata
+    pub metadata: BranchMetadataV2,
+}
+
+/// Absorb command implementing Command trait
+pub struct AbsorbCommand {
+    /// Stack branches to rewrite, bottom-up
+    rewrites: Vec<BranchRewrite>,
+    /// Descendants to restack, parents first
+    restacks: Vec<BranchRewrite>,
+}
+
+impl Command for AbsorbCommand {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING;
+    type Output = AbsorbResult;
+
+    fn plan(&self, _ctx: &ReadyContext) -> Result<Plan, PlanError> {
+        let mut plan = Plan::new(OpId::new(), "absorb").with_step(PlanStep::Checkpoint {
+            name: "before-absorb".to_string(),
+        });
+
+        for rewrite in self.rewrites.iter().chain(&self.restacks) {
+            plan = plan.with_step(PlanStep::UpdateRefCas {
+                refname: format!("refs/heads/{}", rewrite.branch),
+                old_oid: Some(rewrite.old_tip.to_string()),
+                new_oid: rewrite.new_tip.to_string(),
+                reason: format!("absorb into {}", rewrite.branch),
+            });
+
+            if let Some(base) = &rewrite.new_base {
+                let mut updated_metadata = rewrite.metadata.clone();
+                updated_metadata.base = BaseInfo {
+                    oid: base.to_string(),
+                };
+                updated_metadata.timestamps.updated_at = UtcTimestamp::now();
+
+                plan = plan.with_step(PlanStep::WriteMetadataCas {
+                    branch: rewrite.branch.to_string(),
+                    old_ref_oid: Some(rewrite.metadata_ref_oid.to_string()),
+                    metadata: Box::new(updated_metadata),
+                });
+            }
+        }
+
+        Ok(plan)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        match result {
+            ExecuteResult::Success { .. } => CommandOutput::Success(AbsorbResult {
+                rewritten: self.rewrites.iter().map(|r| r.branch.clone()).collect(),
+                restacked: self.restacks.iter().map(|r| r.branch.clone()).collect(),
+            }),
+            ExecuteResult::Paused {
+                branch, git_state, ..
+            } => CommandOutput::Paused {
+                message: format!(
+                    "Paused while absorbing into '{}' ({}).",
+                    branch,
+                    git_state.description()
+                ),
+            },
+            ExecuteResult::Aborted { error, .. } => CommandOutput::Failed { error },
+        }
+    }
+}
+
+/// A branch of the stack absorb can rewrite.
+struct StackBranch {
+    branch: BranchName,
+    base: Oid,
+    tip: Oid,
+    /// Commits after the base, oldest first
+    commits: Vec<CommitInfo>,
+    metadata_ref_oid: Oid,
+    metadata: BranchMetadataV2,
+}
+
+/// Replay `commits` onto `onto` in memory, folding in each commit's fixup.
+///
+/// Returns the new tip, or `None` if a commit or fixup conflicts.
+fn replay(
+    git: &Git,
+    commits: &[CommitInfo],
+    mut onto: Oid,
+    fixups: &HashMap<Oid, Oid>,
+) -> CliResult<Option<Oid>> {
+    for commit in commits {
+        // Commits below the first fixup stay as they are
+        if !fixups.contains_key(&commit.oid) && git.commit_parents(&commit.oid)? == [onto.clone()] {
+            onto = commit.oid.clone();
+            continue;
+        }
+        let Some(tree) = git.cherry_pick_tree(&commit.oid, &onto)? else {
+            return Ok(None);
+        };
+        let mut rebuilt = git.recommit(&commit.oid, &tree, &onto)?;
+        if let Some(fixup) = fixups.get(&commit.oid) {
+            let Some(tree) = git.cherry_pick_tree(fixup, &rebuilt)? else {
+                return Ok(None);
+            };
+            rebuilt = git.recommit(&commit.oid, &tree, &onto)?;
+        }
+        onto = rebuilt;
+    }
+    Ok(Some(onto))
+}
+
+/// Fold staged changes into the commits of the current stack.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `update` - Stage modified tracked files first (git add -u)
+/// * `dry_run` - Only report where each hunk would go
+/// * `no_restack` - Leave descendants for a later `lattice restack`
+pub fn absorb(ctx: &Context, update: bool, dry_run: bool, no_restack: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    // =========================================================================
+    // PRE-PLAN: Staging, attribution, and in-memory rewrite
+    // =========================================================================
+
+    if update {
+        let status = ProcessCommand::new("git")
+            .args(["add", "-u"])
+            .current_dir(&cwd)
+            .status()
+            .context("Failed to run git add -u")?;
+
+        if !status.success() {
+            return Err(CliError::git("git add -u failed"));
+        }
+    }
+
+    let snapshot = scan(&git).context("Failed to scan repository")?;
+
+    let trunk = snapshot
+        .trunk
+        .clone()
+        .ok_or_else(CliError::not_initialized)?;
+
+    let current = snapshot
+        .current_branch
+        .as_ref()
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
+        .clone();
+
+    if !snapshot.metadata.contains_key(&current) {
+        return Err(CliError::untracked(&current));
+    }
+
+    // The current branch and its ancestors above the nearest frozen one
+    let mut range = Vec::new();
+    for branch in get_ancestors_inclusive(&current, &snapshot)
+        .into_iter()
+        .rev()
+        .filter(|b| *b != trunk)
+    {
+        let scanned = snapshot
+            .metadata
+            .get(&branch)
+            .ok_or_else(|| CliError::untracked(&branch))?;
+        if scanned.metadata.freeze.is_frozen() {
+            if branch == current {
+                return Err(CliError::conflict(format!(
+                    "Cannot absorb into frozen branch '{}'. Use 'lattice unfreeze' first.",
+                    current
+                )));
+            }
+            break;
+        }
+        let base = Oid::new(&scanned.metadata.base.oid).context("Invalid base OID")?;
+        let tip = snapshot
+            .branches
+            .get(&branch)
+            .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", branch)))?
+            .clone();
+        let mut commits = git.commits_between(&base, &tip)?;
+        commits.reverse();
+        range.push(StackBranch {
+            branch,
+            base,
+            tip,
+            commits,
+            metadata_ref_oid: scanned.ref_oid.clone(),
+            metadata: scanned.metadata.clone(),
+        });
+    }
+    range.reverse();
+
+    let staged = git.staged_hunks()?;
+    if staged.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("absorb-nothing-staged-absorb"));
+        }
+        return Ok(());
+    }
+
+    // Stack commits oldest first, with the branch each belongs to
+    let commits: Vec<(usize, &CommitInfo)> = range
+        .iter()
+        .enumerate()
+        .flat_map(|(i, entry)| entry.commits.iter().map(move |c| (i, c)))
+        .collect();
+    let index_of: HashMap<&Oid, usize> = commits
+        .iter()
+        .enumerate()
+        .map(|(i, (_, c))| (&c.oid, i))
+        .collect();
+
+    let head = snapshot
+        .branches
+        .get(&current)
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", current)))?
+        .clone();
+    let oldest = range.first().map(|entry| entry.base.clone());
+
+    // Target commit index -> (path, hunk)
+    let mut targets: BTreeMap<usize, Vec<(&str, &DiffHunk)>> = BTreeMap::new();
+    let mut left: Vec<(String, &'static str)> = Vec::new();
+    for file in &staged {
+        if !file.modified {
+            left.push((file.path.clone(), "is not a modified text file"));
+            continue;
+        }
+        let blame: Vec<Option<usize>> = match &oldest {
+            Some(oldest) => git
+                .blame_lines(&file.path, &head, oldest)?
+                .iter()
+                .map(|oid| index_of.get(oid).copied())
+                .collect(),
+            None => Vec::new(),
+        };
+        for hunk in &file.hunks {
+            match attribute(hunk, &blame) {
+                Ok(target) => targets.entry(target).or_default().push((&file.path, hunk)),
+                Err(reason) => left.push((
+                    format!("{}:{}", file.path, hunk.old_start.max(1)),
+                    reason.describe(),
+                )),
+            }
+        }
+    }
+
+    if !ctx.quiet {
+        if !targets.is_empty() {
+            let hunks: usize = targets.values().map(Vec::len).sum();
+            println!(
+                "{}",
+                i18n::t_args(
+                    "absorb-absorbing-hunk-commit",
+                    &[("hunks", &hunks), ("count", &targets.len())],
+                )
+            );
+            for (target, hunks) in &targets {
+                let (branch, commit) = commits[*target];
+                let mut paths: Vec<&str> = hunks.iter().map(|(path, _)| *path).collect();
+                paths.dedup();
+                println!(
+                    "  {} {} ({}): {}",
+                    commit.oid.short(7),
+                    commit.summary,
+                    range[branch].branch,
+                    paths.join(", ")
+                );
+            }
+        }
+        if !left.is_empty() {
+            println!("{}", i18n::t("absorb-left-staged"));
+            for (location, reason) in &left {
+                println!("  {}  {}", location, reason);
+            }
+        }
+    }
+
+    if targets.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("absorb-nothing-absorb"));
+        }
+        return Ok(());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    // One fixup commit per target on top of HEAD, holding only its hunks
+    let head_tree = git.commit_tree(&head)?;
+    let fixup_tree = |hunks: &[(&str, &DiffHunk)]| -> CliResult<Oid> {
+        let mut by_path: BTreeMap<&str, Vec<&DiffHunk>> = BTreeMap::new();
+        for (path, hunk) in hunks {
+            by_path.entry(path).or_default().push(hunk);
+        }
+        let mut files = Vec::new();
+        for (path, hunks) in by_path {
+            let original = git.file_content(&head, path)?.unwrap_or_default();
+            files.push((path.to_string(), apply_hunks(&original, &hunks)));
+        }
+        Ok(git.update_tree_files(&head_tree, &files)?)
+    };
+
+    let mut fixups: HashMap<Oid, Oid> = HashMap::new();
+    for (target, hunks) in &targets {
+        let (_, commit) = commits[*target];
+        let tree = fixup_tree(hunks)?;
+        let fixup = git.write_commit(&tree, &[&head], &format!("fixup! {}", commit.summary))?;
+        fixups.insert(commit.oid.clone(), fixup);
+    }
+    let all_absorbed: Vec<(&str, &DiffHunk)> = targets.values().flatten().copied().collect();
+    let expected_tree = fixup_tree(&all_absorbed)?;
+
+    // Rebuild from the lowest branch with a target up to the current branch
+    let first = commits[*targets.keys().next().expect("targets is not empty")].0;
+    let mut rewrites: Vec<BranchRewrite> = Vec::new();
+    for entry in &range[first..] {
+        let onto = rewrites
+            .last()
+            .map(|r| r.new_tip.clone())
+            .unwrap_or_else(|| entry.base.clone());
+        let Some(new_tip) = replay(&git, &entry.commits, onto.clone(), &fixups)? else {
+            return Err(CliError::conflict(format!("Cannot absorb: the changes do not replay cleanly onto '{}'. Nothing was changed; use 'lattice modify' or 'lattice edit' instead.",
+                entry.branch)));
+        };
+        rewrites.push(BranchRewrite {
+            branch: entry.branch.clone(),
+            old_tip: entry.tip.clone(),
+            new_tip,
+            new_base: (onto != entry.base).then_some(onto),
+            metadata_ref_oid: entry.metadata_ref_oid.clone(),
+            metadata: entry.metadata.clone(),
+        });
+    }
+
+    let new_head = &rewrites.last().expect("range includes current").new_tip;
+    if git.commit_tree(new_head)? != expected_tree {
+        return Err(CliError::conflict("Cannot absorb: replaying the stack would change more than the absorbed hunks. Nothing was changed."));
+    }
+
+    // Descendants outside the stack follow their rewritten parents
+    let rewritten: HashSet<&BranchName> = rewrites.iter().map(|r| &r.branch).collect();
+    let outside: Vec<BranchName> = rewrites
+        .iter()
+        .flat_map(|r| get_descendants_inclusive(&r.branch, &snapshot))
+        .filter(|b| !rewritten.contains(b))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let restack = !no_restack
+        && Config::load(Some(&cwd))
+            .map(|r| r.config.restack_descendants())
+            .unwrap_or(true);
+
+    let mut restacks: Vec<BranchRewrite> = Vec::new();
+    let mut frozen_to_skip = Vec::new();
+    let mut deferred = Vec::new();
+    let mut conflicted = Vec::new();
+    for branch in topological_sort(&outside, &snapshot) {
+        let scanned = snapshot
+            .metadata
+            .get(&branch)
+            .ok_or_else(|| CliError::untracked(&branch))?;
+        if scanned.metadata.freeze.is_frozen() {
+            frozen_to_skip.push(branch);
+            continue;
+        }
+        let parent = BranchName::new(scanned.metadata.parent.name())?;
+        let Some(parent_tip) = rewrites
+            .iter()
+            .chain(&restacks)
+            .find(|r| r.branch == parent)
+            .map(|r| r.new_tip.clone())
+        else {
+            // Parent stays where it is, so this branch is still on top of it
+            continue;
+        };
+        if !restack {
+            deferred.push(branch);
+            continue;
+        }
+
+        let base = Oid::new(&scanned.metadata.base.oid).context("Invalid base OID")?;
+        let tip = snapshot
+            .branches
+            .get(&branch)
+            .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", branch)))?
+            .clone();
+        let mut branch_commits = git.commits_between(&base, &tip)?;
+        branch_commits.reverse();
+        match replay(&git, &branch_commits, parent_tip.clone(), &HashMap::new())? {
+            Some(new_tip) => restacks.push(BranchRewrite {
+                branch,
+                old_tip: tip,
+                new_tip,
+                new_base: Some(parent_tip),
+                metadata_ref_oid: scanned.ref_oid.clone(),
+                metadata: scanned.metadata.clone(),
+            }),
+            None => conflicted.push(branch),
+        }
+    }
+
+    // =========================================================================
+    // EXECUTE: Run command through unified lifecycle
+    // =========================================================================
+
+    let absorbed_hunks = all_absorbed.len();
+    let absorbed_commits = targets.len();
+    let cmd = AbsorbCommand { rewrites, restacks };
+
+    let output = run_command(&cmd, &git, ctx)?;
+
+    // =========================================================================
+    // POST-EXECUTE: Display results
+    // =========================================================================
+
+    let list = |branches: &[BranchName]| {
+        branches
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match output {
+        CommandOutput::Success(result) => {
+            if !ctx.quiet {
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "absorb-absorbed-hunk-commit",
+                        &[
+                            ("absorbed_hunks", &absorbed_hunks),
+                            ("absorbed_commits", &absorbed_commits),
+                            ("branches", &list(&result.rewritten)),
+                        ],
+                    )
+                );
+                if !result.restacked.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "absorb-restacked-descendant",
+                            &[
+                                ("count", &result.restacked.len()),
+                                ("branches", &list(&result.restacked)),
+                            ],
+                        )
+                    );
+                }
+                if !frozen_to_skip.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "absorb-skipped-frozen-branch",
+                            &[
+                                ("count", &frozen_to_skip.len()),
+                                ("branches", &list(&frozen_to_skip)),
+                            ],
+                        )
+                    );
+                }
+                if !conflicted.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "absorb-could-restack-descendant",
+                            &[
+                                ("count", &conflicted.len()),
+                                ("branches", &list(&conflicted)),
+                            ],
+                        )
+                    );
+                }
+                if !deferred.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "absorb-deferred-restack-descendant",
+                            &[("count", &deferred.len()), ("branches", &list(&deferred))],
+                        )
+                    );
+                }
+            }
+        }
+        CommandOutput::Paused { message } => {
+            println!();
+            println!("{}", message);
+        }
+        CommandOutput::Failed { error } => {
+            return Err(CliError::failed(format!("Absorb failed: {}", error)));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,264 @@
+//! cli::commands::attach
+//!
+//! Follow an identical command that is already running.
+//!
+//! # Design
+//!
+//! When the same command is started twice (say, `lattice restack` in two
+//! terminals), the second copy would stop at the gate with "operation in
+//! progress". Before dispatching, Lattice reads the op-state instead: if the
+//! operation is still running, its holder keeps the repository lock, and it
+//! was started from this worktree with the same arguments, an interactive
+//! session is offered to attach.
+//!
+//! Attaching prints the running operation's journal steps as they are
+//! recorded and exits with the other run's outcome: success when it commits,
+//! an error when it rolls back, pauses on a conflict, or dies.
+//!
+//! Non-interactive runs, and a declined prompt, keep the usual
+//! "operation in progress" error.
+
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::ops::journal::{Journal, OpHolder, OpPhase, OpState};
+use crate::core::ops::lock::RepoLock;
+use crate::core::paths::LatticePaths;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
+
+/// How often the running operation is polled for progress.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Offer to follow an identical command that is already running.
+///
+/// Returns `true` if this invocation attached and the other run finished,
+/// in which case there is nothing left to do.
+pub fn follow_duplicate(ctx: &Context) -> CliResult<bool> {
+    if !ctx.interactive && !prompts::assume_yes() {
+        return Ok(false);
+    }
+
+    // Outside a repository there is nothing to attach to
+    let cwd = match ctx.cwd.clone() {
+        Some(cwd) => cwd,
+        None => std::env::current_dir()?,
+    };
+    let Ok(info) = Git::open(&cwd).and_then(|git| git.info()) else {
+        return Ok(false);
+    };
+    let paths = LatticePaths::from_repo_info(&info);
+    let Some(op_state) = OpState::read(&paths).ok().flatten() else {
+        return Ok(false);
+    };
+    let Some(holder) = op_state.holder.as_ref() else {
+        return Ok(false);
+    };
+    if !is_duplicate(&op_state, &paths.git_dir, &OpHolder::current()) || !lock_held(&paths) {
+        return Ok(false);
+    }
+
+    let prompt = i18n::t_args(
+        "attach-prompt",
+        &[("command", &op_state.command), ("pid", &holder.pid)],
+    );
+    if !prompts::confirm(&prompt, Severity::Minor, ctx.interactive)? {
+        return Ok(false);
+    }
+
+    follow(&paths, &op_state, ctx.quiet)?;
+    Ok(true)
+}
+
+/// Whether `op_state` is a running operation started by another process
+/// from the same worktree with the same arguments as `current`.
+fn is_duplicate(op_state: &OpState, git_dir: &Path, current: &OpHolder) -> bool {
+    op_state.phase.is_active()
+        && op_state.origin_git_dir == git_dir
+        && op_state
+            .holder
+            .as_ref()
+            .is_some_and(|holder| holder.is_duplicate_of(current))
+}
+
+/// Whether another process holds the repository lock.
+fn lock_held(paths: &LatticePaths) -> bool {
+    matches!(RepoLock::try_acquire(paths), Ok(None))
+}
+
+/// Print the operation's journal steps as they land until it finishes.
+fn follow(paths: &LatticePaths, op_state: &OpState, quiet: bool) -> CliResult {
+    let command = &op_state.command;
+    let mut shown = 0;
+    let mut show_new_steps = || {
+        // A journal being rewritten may not parse; the next poll catches up
+        if let Ok(journal) = Journal::read(paths, &op_state.op_id) {
+            for step in journal.steps.iter().skip(shown) {
+                if !quiet {
+                    println!("  {}", step.kind.describe());
+                }
+            }
+            shown = shown.max(journal.steps.len());
+        }
+    };
+
+    loop {
+        show_new_steps();
+
+        let running = |state: &OpState| state.op_id == op_state.op_id;
+        match OpState::read(paths) {
+            Ok(Some(state)) if running(&state) => {
+                if state.phase.is_resumable() {
+                    return Err(CliError::new(
+                        ErrorCategory::OperationInProgress,
+                        i18n::t_args("attach-paused", &[("command", command)]),
+                    ));
+                }
+                // The holder clears the op-state before releasing the lock, so
+                // a free lock with the op-state still present means it died.
+                if !lock_held(paths)
+                    && OpState::read(paths)
+                        .ok()
+                        .flatten()
+                        .is_some_and(|state| running(&state) && state.phase.is_active())
+                {
+                    return Err(CliError::failed(i18n::t_args(
+                        "attach-abandoned",
+                        &[("command", command)],
+                    )));
+                }
+            }
+            Ok(_) => break,
+            Err(_) => {}
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    show_new_steps();
+    let phase = Journal::read(paths, &op_state.op_id).map(|journal| journal.phase);
+    if let Ok(OpPhase::RolledBack) = phase {
+        return Err(CliError::failed(i18n::t_args(
+            "attach-rolled-back",
+            &[("command", command)],
+        )));
+    }
+    if !quiet {
+        println!(
+            "{}",
+            i18n::t_args("attach-finished", &[("command", command)])
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn running_op(paths: &LatticePaths, args: &[&str]) -> OpState {
+        let journal = Journal::new("restack");
+        let mut state =
+            OpState::from_journal(&journal, paths, None, "sha256:test".to_string(), vec![]);
+        state.holder = Some(OpHolder {
+            pid: 1,
+            args: args.iter().map(|a| a.to_string()).collect(),
+        });
+        state
+    }
+
+    fn current(args: &[&str]) -> OpHolder {
+        OpHolder {
+            pid: 2,
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn detects_same_arguments_from_same_worktree() {
+        let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
+        let op = running_op(&paths, &["restack"]);
+
+        assert!(is_duplicate(&op, &paths.git_dir, &current(&["restack"])));
+        assert!(!is_duplicate(
+            &op,
+            &paths.git_dir,
+            &current(&["restack", "--force"])
+        ));
+        assert!(!is_duplicate(
+            &op,
+            Path::new("/other/.git"),
+            &current(&["restack"])
+        ));
+    }
+
+    #[test]
+    fn ignores_paused_and_own_operations() {
+        let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
+        let mut op = running_op(&paths, &["restack"]);
+
+        let same_process = OpHolder {
+            pid: 1,
+            args: vec!["restack".to_string()],
+        };
+        assert!(!is_duplicate(&op, &paths.git_dir, &same_process));
+
+        op.phase = OpPhase::Paused;
+        assert!(!is_duplicate(&op, &paths.git_dir, &current(&["restack"])));
+
+        op.phase = OpPhase::InProgress;
+        op.holder = None;
+        assert!(!is_duplicate(&op, &paths.git_dir, &current(&["restack"])));
+    }
+
+    #[test]
+    fn follow_returns_when_operation_commits() {
+        let temp = TempDir::new().unwrap();
+        let git_dir = temp.path().join(".git");
+        let paths = LatticePaths::new(git_dir.clone(), git_dir);
+
+        let mut journal = Journal::new("restack");
+        journal
+            .append_ref_update(&paths, "refs/heads/feature", None, "abc1234def")
+            .unwrap();
+        let mut op = OpState::from_journal(&journal, &paths, None, "sha256:t".into(), vec![]);
+        op.holder = Some(current(&["restack"]));
+        op.write(&paths).unwrap();
+        let lock = RepoLock::acquire(&paths).unwrap();
+
+        let finisher = {
+            let paths = paths.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(300));
+                journal.commit();
+                journal.write(&paths).unwrap();
+                OpState::remove(&paths).unwrap();
+                drop(lock);
+            })
+        };
+
+        follow(&paths, &op, true).unwrap();
+        finisher.join().unwrap();
+    }
+
+    #[test]
+    fn follow_reports_abandoned_operation() {
+        let temp = TempDir::new().unwrap();
+        let git_dir = temp.path().join(".git");
+        let paths = LatticePaths::new(git_dir.clone(), git_dir);
+
+        let journal = Journal::new("restack");
+        journal.write(&paths).unwrap();
+        let op = OpState::from_journal(&journal, &paths, None, "sha256:t".into(), vec![]);
+        op.write(&paths).unwrap();
+
+        // Nobody holds the lock, so the holder is gone
+        let err = follow(&paths, &op, true).unwrap_err();
+        assert!(err.to_string().contains("exited"));
+    }
+}
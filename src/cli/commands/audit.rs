@@ -0,0 +1,430 @@
+//! cli::commands::audit
+//!
+//! Produce a repository hygiene report.
+//!
+//! # Architecture
+//!
+//! This is a read-only command that implements `ReadOnlyCommand` and uses
+//! `requirements::READ_ONLY`, so it runs even when the repository needs
+//! repair. It flows through `run_readonly_command` to ensure proper gating.
+//!
+//! # Design
+//!
+//! The audit composes what other read-only paths already know into one
+//! document for periodic review:
+//! - Tracked and untracked local branches (the scan)
+//! - Metadata consistency (the issues `lattice doctor` reports)
+//! - PR linkage coverage (cached metadata; the forge is not contacted)
+//! - Stale stacks (stacks past the `[drift]` thresholds)
+//! - Frozen branches, with why, since when, until when, and who authored the
+//!   frozen tip
+//!
+//! It proposes nothing: doctor fixes are left out, and nothing is written.
+//! The report is markdown, or JSON with `--json`.
+//!
+//! # Example
+//!
+//! ```bash
+//! # Markdown report, ready to paste into a review issue
+//! lattice audit > audit.md
+//!
+//! # JSON for scripts and dashboards
+//! lattice audit --json
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::trunk_drift::{self, DriftThresholds};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::metadata::schema::{FreezeScope, FreezeState, PrState};
+use crate::doctor::Doctor;
+use crate::engine::command::ReadOnlyCommand;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::PlanError;
+use crate::engine::runner::run_readonly_command;
+use crate::engine::scan::RepoSnapshot;
+use crate::engine::Context;
+use crate::git::Git;
+use anyhow::Context as _;
+
+/// A doctor issue, without its fixes.
+#[derive(Debug, Serialize)]
+struct AuditIssue {
+    id: String,
+    severity: String,
+    message: String,
+}
+
+/// A tracked branch's PR link.
+#[derive(Debug, Serialize)]
+struct PrLink {
+    branch: String,
+    /// PR number, or `None` when the branch has no linked PR
+    number: Option<u64>,
+    url: Option<String>,
+}
+
+/// A stack past the drift thresholds.
+#[derive(Debug, Serialize)]
+struct StaleStack {
+    root: String,
+    /// Trunk commits missing from the stack's base
+    behind: usize,
+    /// Days since trunk first moved past the base
+    days: u64,
+}
+
+/// A frozen branch and who it belongs to.
+#[derive(Debug, Serialize)]
+struct FrozenBranch {
+    branch: String,
+    scope: String,
+    reason: Option<String>,
+    frozen_at: String,
+    /// Author of the branch's tip commit, as `Name <email>`
+    tip_author: Option<String>,
+}
+
+/// Machine-readable audit report (`--json`).
+#[derive(Debug, Serialize)]
+struct AuditReport {
+    trunk: Option<String>,
+    generated_at: String,
+    tracked: Vec<String>,
+    untracked: Vec<String>,
+    issues: Vec<AuditIssue>,
+    prs: Vec<PrLink>,
+    stale_stacks: Vec<StaleStack>,
+    frozen: Vec<FrozenBranch>,
+}
+
+impl AuditReport {
+    /// Build the report from a snapshot.
+    fn new(
+        git: &Git,
+        snapshot: &RepoSnapshot,
+        thresholds: DriftThresholds,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let trunk = snapshot.trunk.as_ref();
+
+        let mut tracked: Vec<_> = snapshot.metadata.keys().collect();
+        tracked.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let mut untracked: Vec<String> = snapshot
+            .branches
+            .keys()
+            .filter(|b| !snapshot.metadata.contains_key(*b) && Some(*b) != trunk)
+            .map(|b| b.to_string())
+            .collect();
+        untracked.sort();
+
+        let issues = Doctor::new()
+            .diagnose(snapshot)
+            .issues
+            .iter()
+            .map(|issue| AuditIssue {
+                id: issue.id.to_string(),
+                severity: issue.severity.to_string(),
+                message: issue.message.clone(),
+            })
+            .collect();
+
+        let prs = tracked
+            .iter()
+            .map(|branch| match &snapshot.metadata[*branch].metadata.pr {
+                PrState::Linked { number, url, .. } => PrLink {
+                    branch: branch.to_string(),
+                    number: Some(*number),
+                    url: Some(url.clone()),
+                },
+                PrState::None => PrLink {
+                    branch: branch.to_string(),
+                    number: None,
+                    url: None,
+                },
+            })
+            .collect();
+
+        let tracked_names: Vec<_> = tracked.iter().map(|b| (*b).clone()).collect();
+        let stale_stacks = trunk_drift::stack_roots(snapshot, &tracked_names)
+            .iter()
+            .filter_map(|root| trunk_drift::stack_drift(git, snapshot, root))
+            .filter(|drift| drift.exceeds(thresholds, now))
+            .map(|drift| StaleStack {
+                root: drift.root.to_string(),
+                behind: drift.behind,
+                days: drift.days(now),
+            })
+            .collect();
+
+        let frozen = tracked
+            .iter()
+            .filter_map(|branch| {
+                let metadata = &snapshot.metadata[*branch].metadata;
+                let FreezeState::Frozen {
+                    scope,
+                    reason,
+                    frozen_at,
+                } = &metadata.freeze
+                else {
+                    return None;
+                };
+                let tip_author = snapshot
+                    .branches
+                    .get(*branch)
+                    .and_then(|tip| git.commit_info(tip).ok())
+                    .map(|c| format!("{} <{}>", c.author_name, c.author_email));
+                Some(FrozenBranch {
+                    branch: branch.to_string(),
+                    scope: match scope {
+                        FreezeScope::Single => "single",
+                        FreezeScope::DownstackInclusive => "downstack_inclusive",
+                    }
+                    .to_string(),
+                    reason: reason.clone(),
+                    frozen_at: frozen_at.to_string(),
+                    tip_author,
+                })
+            })
+            .collect();
+
+        Self {
+            trunk: trunk.map(|t| t.to_string()),
+            generated_at: now.to_rfc3339(),
+            tracked: tracked.iter().map(|b| b.to_string()).collect(),
+            untracked,
+            issues,
+            prs,
+            stale_stacks,
+            frozen,
+        }
+    }
+
+    /// Number of tracked branches with a linked PR.
+    fn linked_prs(&self) -> usize {
+        self.prs.iter().filter(|p| p.number.is_some()).count()
+    }
+}
+
+/// Render the report as markdown.
+fn format_markdown(report: &AuditReport) -> String {
+    let none = "None.\n";
+    let mut out = String::from("# Lattice audit\n\n");
+    out.push_str(&format!(
+        "Trunk: {}. Generated {}.\n\n",
+        report
+            .trunk
+            .as_deref()
+            .map_or_else(|| "not configured".to_string(), |t| format!("`{}`", t)),
+        report.generated_at
+    ));
+
+    out.push_str("## Summary\n\n");
+    out.push_str(&format!("- Tracked branches: {}\n", report.tracked.len()));
+    out.push_str(&format!(
+        "- Untracked branches: {}\n",
+        report.untracked.len()
+    ));
+    out.push_str(&format!("- Metadata issues: {}\n", report.issues.len()));
+    out.push_str(&format!(
+        "- PR linkage: {} of {} tracked branches ({}%)\n",
+        report.linked_prs(),
+        report.tracked.len(),
+        (report.linked_prs() * 100)
+            .checked_div(report.tracked.len())
+            .unwrap_or(0)
+    ));
+    out.push_str(&format!("- Stale stacks: {}\n", report.stale_stacks.len()));
+    out.push_str(&format!("- Frozen branches: {}\n", report.frozen.len()));
+
+    out.push_str("\n## Untracked branches\n\n");
+    if report.untracked.is_empty() {
+        out.push_str(none);
+    }
+    for branch in &report.untracked {
+        out.push_str(&format!("- `{}`\n", branch));
+    }
+
+    out.push_str("\n## Metadata consistency\n\n");
+    if report.issues.is_empty() {
+        out.push_str(none);
+    }
+    for issue in &report.issues {
+        out.push_str(&format!(
+            "- **{}** `{}`: {}\n",
+            issue.severity, issue.id, issue.message
+        ));
+    }
+
+    out.push_str("\n## PR linkage\n\n");
+    if report.prs.is_empty() {
+        out.push_str(none);
+    } else {
+        out.push_str("| Branch | PR |\n|---|---|\n");
+        for pr in &report.prs {
+            let link = match (pr.number, &pr.url) {
+                (Some(number), Some(url)) => format!("[#{}]({})", number, url),
+                _ => "-".to_string(),
+            };
+            out.push_str(&format!("| `{}` | {} |\n", pr.branch, link));
+        }
+    }
+
+    out.push_str("\n## Stale stacks\n\n");
+    if report.stale_stacks.is_empty() {
+        out.push_str(none);
+    } else {
+        out.push_str("| Stack root | Trunk commits behind | Days behind |\n|---|---|---|\n");
+        for stack in &report.stale_stacks {
+            out.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                stack.root, stack.behind, stack.days
+            ));
+        }
+    }
+
+    out.push_str("\n## Frozen branches\n\n");
+    if report.frozen.is_empty() {
+        out.push_str(none);
+    } else {
+        out.push_str(
+            "| Branch | Scope | Frozen at | Tip author | Reason |\n|---|---|---|---|---|\n",
+        );
+        for frozen in &report.frozen {
+            let or_dash = |s: &Option<String>| s.clone().unwrap_or_else(|| "-".to_string());
+            out.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                frozen.branch,
+                frozen.scope,
+                frozen.frozen_at,
+                or_dash(&frozen.tip_author),
+                or_dash(&frozen.reason)
+            ));
+        }
+    }
+    out
+}
+
+/// Command to produce the audit report.
+pub struct AuditCommand<'a> {
+    git: &'a Git,
+    thresholds: DriftThresholds,
+    json: bool,
+}
+
+impl ReadOnlyCommand for AuditCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::READ_ONLY;
+    type Output = ();
+
+    fn execute(&self, ready: &ReadyContext) -> Result<Self::Output, PlanError> {
+        let report = AuditReport::new(self.git, &ready.snapshot, self.thresholds, Utc::now());
+        if self.json {
+            super::print_json(&report)?;
+        } else {
+            print!("{}", format_markdown(&report));
+        }
+        Ok(())
+    }
+}
+
+/// Run the audit command.
+///
+/// # Arguments
+///
+/// * `ctx` - Engine context
+/// * `json` - Emit a JSON report instead of markdown
+pub fn audit(ctx: &Context, json: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let cmd = AuditCommand {
+        git: &git,
+        thresholds: DriftThresholds::load(&cwd),
+        json,
+    };
+
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> AuditReport {
+        AuditReport {
+            trunk: Some("main".to_string()),
+            generated_at: "2026-01-01T00:00:00+00:00".to_string(),
+            tracked: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            untracked: vec!["scratch".to_string()],
+            issues: vec![AuditIssue {
+                id: "orphaned-metadata:old".to_string(),
+                severity: "warning".to_string(),
+                message: "metadata for missing branch 'old'".to_string(),
+            }],
+            prs: vec![
+                PrLink {
+                    branch: "a".to_string(),
+                    number: Some(10),
+                    url: Some("https://github.com/o/r/pull/10".to_string()),
+                },
+                PrLink {
+                    branch: "b".to_string(),
+                    number: None,
+                    url: None,
+                },
+                PrLink {
+                    branch: "c".to_string(),
+                    number: None,
+                    url: None,
+                },
+            ],
+            stale_stacks: vec![StaleStack {
+                root: "a".to_string(),
+                behind: 60,
+                days: 21,
+            }],
+            frozen: vec![FrozenBranch {
+                branch: "c".to_string(),
+                scope: "single".to_string(),
+                reason: None,
+                frozen_at: "2025-12-01T00:00:00+00:00".to_string(),
+                tip_author: Some("Alice <alice@example.com>".to_string()),
+            }],
+        }
+    }
+
+    #[test]
+    fn markdown_summarizes_every_section() {
+        let md = format_markdown(&report());
+
+        assert!(md.contains("Trunk: `main`."), "{}", md);
+        assert!(md.contains("- PR linkage: 1 of 3 tracked branches (33%)"));
+        assert!(md.contains("- `scratch`"));
+        assert!(md.contains("- **warning** `orphaned-metadata:old`: metadata for missing"));
+        assert!(md.contains("| `a` | [#10](https://github.com/o/r/pull/10) |"));
+        assert!(md.contains("| `b` | - |"));
+        assert!(md.contains("| `a` | 60 | 21 |"));
+        assert!(md.contains(
+            "| `c` | single | 2025-12-01T00:00:00+00:00 | Alice <alice@example.com> | - |"
+        ));
+    }
+
+    #[test]
+    fn empty_sections_say_none() {
+        let mut report = report();
+        report.tracked.clear();
+        report.untracked.clear();
+        report.issues.clear();
+        report.prs.clear();
+        report.stale_stacks.clear();
+        report.frozen.clear();
+        let md = format_markdown(&report);
+
+        assert!(md.contains("- PR linkage: 0 of 0 tracked branches (0%)"));
+        assert_eq!(md.matches("None.").count(), 5, "{}", md);
+    }
+}
@@ -27,12 +27,24 @@
 //!
 //! # Remove stored authentication
 //! lattice auth logout
+//!
+//! # GitHub Enterprise Server, through the app registered on the instance
+//! lattice auth --host github.example.com --client-id Iv1.0123456789abcdef
 //! ```
-
-use crate::auth::{DeviceFlowClient, GitHubAuthManager, TokenBundle, TokenInfo, UserInfo};
+//!
+//! Token bundles are keyed by host, so github.com and any number of
+//! Enterprise Server instances can be logged in side by side. Forge calls
+//! pick the bundle for the host of the repository's remote.
+
+use crate::auth::{
+    DeviceFlowClient, GitHubAuthManager, TokenBundle, TokenInfo, UserInfo, GITHUB_APP_CLIENT_ID,
+};
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
 use crate::engine::Context;
+use crate::forge::ForgeProvider;
 use crate::secrets;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 use chrono::Utc;
 
 /// Default GitHub host.
@@ -44,6 +56,7 @@ const DEFAULT_HOST: &str = "github.com";
 ///
 /// * `ctx` - Engine context with interactive flag
 /// * `host` - Host to authenticate with (default: github.com)
+/// * `client_id` - Client ID of the app to authorize, for GitHub Enterprise Server
 /// * `no_browser` - Do not attempt to open browser
 /// * `status` - If true, show authentication status instead of login
 /// * `logout` - If true, remove stored authentication
@@ -51,8 +64,19 @@ const DEFAULT_HOST: &str = "github.com";
 /// # Security
 ///
 /// This function NEVER prints token values. It only confirms success/failure.
-pub fn auth(ctx: &Context, host: &str, no_browser: bool, status: bool, logout: bool) -> Result<()> {
-    // Normalize host
+pub fn auth(
+    ctx: &Context,
+    host: &str,
+    client_id: Option<&str>,
+    no_browser: bool,
+    status: bool,
+    logout: bool,
+) -> CliResult {
+    // Normalize host, accepting a pasted URL
+    let host = host
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/');
     let host = if host.is_empty() || host == "github" {
         DEFAULT_HOST
     } else {
@@ -71,16 +95,32 @@ pub fn auth(ctx: &Context, host: &str, no_browser: bool, status: bool, logout: b
 
     // Default: login with device flow
     let rt = tokio::runtime::Runtime::new().context("Failed to create async runtime")?;
-    rt.block_on(do_login(ctx, host, no_browser))
+    rt.block_on(do_login(ctx, host, client_id, no_browser))
 }
 
 /// Perform device flow login.
-async fn do_login(ctx: &Context, host: &str, no_browser: bool) -> Result<()> {
-    let client = DeviceFlowClient::new(host);
+async fn do_login(
+    ctx: &Context,
+    host: &str,
+    client_id: Option<&str>,
+    no_browser: bool,
+) -> CliResult {
+    // The Lattice app only exists on github.com; an Enterprise Server
+    // instance has its own registration
+    let client_id = match client_id {
+        Some(id) => id,
+        None if host == DEFAULT_HOST => GITHUB_APP_CLIENT_ID,
+        None => return Err(CliError::usage(format!("{} is a GitHub Enterprise Server host. Pass --client-id with the client ID of the GitHub App registered on it.",
+            host))),
+    };
+    let client = DeviceFlowClient::with_client_id(host, client_id);
 
     // Step 1: Request device code
     if !ctx.quiet {
-        println!("Requesting device code from {}...", host);
+        println!(
+            "{}",
+            i18n::t_args("auth-requesting-device-code", &[("host", &host)])
+        );
     }
 
     let device_code = client
@@ -90,10 +130,10 @@ async fn do_login(ctx: &Context, host: &str, no_browser: bool) -> Result<()> {
 
     // Step 2: Display instructions
     println!();
-    println!("To authenticate, visit:");
+    println!("{}", i18n::t("auth-authenticate-visit"));
     println!("  {}", device_code.verification_uri);
     println!();
-    println!("And enter this code:");
+    println!("{}", i18n::t("auth-enter-code"));
     println!("  {}", device_code.user_code);
     println!();
 
@@ -101,15 +141,18 @@ async fn do_login(ctx: &Context, host: &str, no_browser: bool) -> Result<()> {
     if !no_browser {
         if let Err(e) = open::that(&device_code.verification_uri) {
             if !ctx.quiet {
-                eprintln!("Could not open browser automatically: {}", e);
-                eprintln!("Please open the URL manually.");
+                eprintln!(
+                    "{}",
+                    i18n::t_args("auth-could-open-browser", &[("error", &e)])
+                );
+                eprintln!("{}", i18n::t("auth-please-open-url"));
             }
         }
     }
 
     // Step 4: Poll for token
     if !ctx.quiet {
-        println!("Waiting for authorization...");
+        println!("{}", i18n::t("auth-waiting-authorization"));
     }
 
     let token_response = client
@@ -141,18 +184,24 @@ async fn do_login(ctx: &Context, host: &str, no_browser: bool) -> Result<()> {
 
     let manager = GitHubAuthManager::new(host, store);
     manager
-        .store_tokens(user.clone(), tokens)
+        .store_tokens_for_client(user.clone(), tokens, client_id)
         .context("Failed to store tokens")?;
 
     // Step 7: Success message
     println!();
-    println!("Authenticated as {} for {}.", user.login, host);
+    println!(
+        "{}",
+        i18n::t_args(
+            "auth-authenticated",
+            &[("login", &user.login), ("host", &host)],
+        )
+    );
 
     Ok(())
 }
 
 /// Show authentication status.
-fn show_status(host: &str, quiet: bool) -> Result<()> {
+fn show_status(host: &str, quiet: bool) -> CliResult {
     let store = secrets::create_store(secrets::DEFAULT_PROVIDER)
         .context("Failed to initialize secret store")?;
 
@@ -166,8 +215,14 @@ fn show_status(host: &str, quiet: bool) -> Result<()> {
             if quiet {
                 println!("authenticated");
             } else {
-                println!("Host: {}", bundle.host);
-                println!("User: {} (id: {})", bundle.user.login, bundle.user.id);
+                println!("{}", i18n::t_args("auth-host", &[("host", &bundle.host)]));
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "auth-user-id",
+                        &[("login", &bundle.user.login), ("id", &bundle.user.id)],
+                    )
+                );
                 println!();
 
                 // Show expiry info
@@ -178,32 +233,45 @@ fn show_status(host: &str, quiet: bool) -> Result<()> {
                 if access_expires > now {
                     let remaining = access_expires - now;
                     println!(
-                        "Access token expires: {} ({} remaining)",
-                        access_expires.format("%Y-%m-%d %H:%M:%S UTC"),
-                        format_duration(remaining)
+                        "{}",
+                        i18n::t_args(
+                            "auth-access-token-expires",
+                            &[
+                                ("expires", &access_expires.format("%Y-%m-%d %H:%M:%S UTC")),
+                                ("remaining", &format_duration(remaining)),
+                            ],
+                        )
                     );
                 } else {
-                    println!("Access token: expired (will refresh automatically)");
+                    println!("{}", i18n::t("auth-access-token-expired"));
                 }
 
                 if refresh_expires > now {
                     let remaining = refresh_expires - now;
                     println!(
-                        "Refresh token expires: {} ({} remaining)",
-                        refresh_expires.format("%Y-%m-%d %H:%M:%S UTC"),
-                        format_duration(remaining)
+                        "{}",
+                        i18n::t_args(
+                            "auth-refresh-token-expires",
+                            &[
+                                (
+                                    "expires",
+                                    &(refresh_expires.format("%Y-%m-%d %H:%M:%S UTC"))
+                                ),
+                                ("remaining", &format_duration(remaining)),
+                            ],
+                        )
                     );
                 } else {
-                    println!("Refresh token: expired (re-authentication required)");
+                    println!("{}", i18n::t("auth-refresh-token-expired"));
                 }
 
                 // Check overall validity
                 if bundle.is_valid() {
                     println!();
-                    println!("Status: authenticated");
+                    println!("{}", i18n::t("auth-status-authenticated"));
                 } else {
                     println!();
-                    println!("Status: expired - run 'lattice auth login' to re-authenticate");
+                    println!("{}", i18n::t("auth-status-expired-run"));
                 }
             }
         }
@@ -211,8 +279,11 @@ fn show_status(host: &str, quiet: bool) -> Result<()> {
             if quiet {
                 println!("not_authenticated");
             } else {
-                println!("Not authenticated for {}.", host);
-                println!("Run 'lattice auth login' to authenticate.");
+                println!(
+                    "{}",
+                    i18n::t_args("auth-not-authenticated", &[("host", &host)])
+                );
+                println!("{}", i18n::t("auth-run-lattice-auth"));
             }
         }
     }
@@ -241,7 +312,7 @@ fn format_duration(duration: chrono::Duration) -> String {
 }
 
 /// Remove stored authentication.
-fn do_logout(host: &str, quiet: bool) -> Result<()> {
+fn do_logout(host: &str, quiet: bool) -> CliResult {
     let store = secrets::create_store(secrets::DEFAULT_PROVIDER)
         .context("Failed to initialize secret store")?;
 
@@ -249,7 +320,7 @@ fn do_logout(host: &str, quiet: bool) -> Result<()> {
     manager.delete_tokens().context("Failed to remove tokens")?;
 
     if !quiet {
-        println!("Logged out from {}.", host);
+        println!("{}", i18n::t_args("auth-logged-out", &[("host", &host)]));
     }
 
     Ok(())
@@ -275,31 +346,102 @@ pub fn has_github_token() -> bool {
 /// # Errors
 ///
 /// Returns an error if not authenticated or if the token cannot be loaded.
-pub fn get_github_token() -> Result<String> {
+pub fn get_github_token() -> CliResult<String> {
+    get_github_token_for(DEFAULT_HOST)
+}
+
+/// Get the stored token for a GitHub host.
+///
+/// Like [`get_github_token`], for github.com or a GitHub Enterprise Server host.
+///
+/// # Errors
+///
+/// Returns an error if not authenticated for `host` or if the token cannot be loaded.
+pub fn get_github_token_for(host: &str) -> CliResult<String> {
     let store = secrets::create_store(secrets::DEFAULT_PROVIDER)
         .context("Failed to initialize secret store")?;
 
-    let key = TokenBundle::secret_key(DEFAULT_HOST);
+    let login = login_command(host);
+    let key = TokenBundle::secret_key(host);
     let json = store
         .get(&key)
         .context("Failed to read secret store")?
-        .ok_or_else(|| anyhow::anyhow!("Not authenticated. Run 'lattice auth login' first."))?;
+        .ok_or_else(|| {
+            CliError::new(
+                ErrorCategory::Auth,
+                format!("Not authenticated. Run '{}' first.", login),
+            )
+        })?;
 
     let bundle = TokenBundle::parse(&json).context("Failed to parse token bundle")?;
 
     if !bundle.is_valid() {
-        anyhow::bail!("Authentication expired. Run 'lattice auth login' again.");
+        return Err(CliError::new(
+            ErrorCategory::Auth,
+            format!("Authentication expired. Run '{}' again.", login),
+        ));
     }
 
     Ok(bundle.tokens.access_token)
 }
 
+/// The GitHub host a remote lives on, github.com if it can't be read.
+pub fn github_host(remote_url: &str) -> String {
+    crate::forge::github::parse_github_remote(remote_url)
+        .map(|remote| remote.host)
+        .unwrap_or_else(|| DEFAULT_HOST.to_string())
+}
+
+/// The command that logs in to `host`.
+fn login_command(host: &str) -> String {
+    if host == DEFAULT_HOST {
+        "lattice auth login".to_string()
+    } else {
+        format!("lattice auth --host {}", host)
+    }
+}
+
+/// Environment variable holding a GitLab personal access token.
+#[cfg(feature = "gitlab")]
+pub const GITLAB_TOKEN_ENV: &str = "GITLAB_TOKEN";
+
+/// Check if a token is available for `provider` at the remote's host.
+///
+/// GitHub uses the stored App token for the remote's host; GitLab reads
+/// `GITLAB_TOKEN`.
+pub fn has_forge_token(provider: ForgeProvider, remote_url: &str) -> bool {
+    match provider {
+        ForgeProvider::GitHub => crate::auth::has_github_auth(&github_host(remote_url)),
+        #[cfg(feature = "gitlab")]
+        ForgeProvider::GitLab => std::env::var(GITLAB_TOKEN_ENV).is_ok_and(|t| !t.is_empty()),
+    }
+}
+
+/// Get the token for API calls to `provider` at the remote's host.
+///
+/// # Errors
+///
+/// Returns an error if no token is available for the provider.
+pub fn get_forge_token(provider: ForgeProvider, remote_url: &str) -> CliResult<String> {
+    match provider {
+        ForgeProvider::GitHub => get_github_token_for(&github_host(remote_url)),
+        #[cfg(feature = "gitlab")]
+        ForgeProvider::GitLab => std::env::var(GITLAB_TOKEN_ENV)
+            .ok()
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| {
+                CliError::new(ErrorCategory::Auth, format!("Not authenticated. Set {} to a GitLab personal access token with the api scope.",
+                    GITLAB_TOKEN_ENV))
+            }),
+    }
+}
+
 /// Get an auth manager for the default host.
 ///
 /// Used by commands that need to make authenticated API calls with
 /// automatic token refresh via the `TokenProvider` trait.
 #[allow(dead_code)] // Will be used when commands migrate to TokenProvider
-pub fn get_auth_manager() -> Result<GitHubAuthManager> {
+pub fn get_auth_manager() -> CliResult<GitHubAuthManager> {
     let store = secrets::create_store(secrets::DEFAULT_PROVIDER)
         .context("Failed to initialize secret store")?;
     Ok(GitHubAuthManager::new(DEFAULT_HOST, store))
@@ -314,6 +456,25 @@ mod tests {
         assert_eq!(DEFAULT_HOST, "github.com");
     }
 
+    #[test]
+    fn github_host_comes_from_remote() {
+        assert_eq!(github_host("git@github.com:owner/repo.git"), "github.com");
+        assert_eq!(
+            github_host("https://github.example.com/team/app.git"),
+            "github.example.com"
+        );
+        assert_eq!(github_host("not a remote"), "github.com");
+    }
+
+    #[test]
+    fn login_command_names_enterprise_host() {
+        assert_eq!(login_command("github.com"), "lattice auth login");
+        assert_eq!(
+            login_command("github.example.com"),
+            "lattice auth --host github.example.com"
+        );
+    }
+
     #[test]
     fn format_duration_days() {
         let duration = chrono::Duration::days(5) + chrono::Duration::hours(3);
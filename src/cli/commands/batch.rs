@@ -0,0 +1,176 @@
+//! cli::commands::batch
+//!
+//! Apply one action to several branches marked in `lattice log --select`.
+//!
+//! # Design
+//!
+//! `lattice log --select` numbers the branches it draws. The user marks some
+//! (`1 3-5`), picks an action, and confirms once; the action then runs as a
+//! single operation instead of one command per branch:
+//!
+//! - `restack` rebases exactly the marked branches, parents first, in one
+//!   journaled plan (`lattice undo` and `lattice abort` treat it as one)
+//! - `freeze` freezes exactly the marked branches in one metadata plan
+//! - `submit` pushes the marked branches, plus their ancestors whose PRs
+//!   they are based on, in one submit
+//!
+//! Marks and the action are read from stdin, like the child picker used by
+//! `lattice up`, so `--select` needs an interactive session.
+
+use std::io::{self, Write};
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::types::BranchName;
+use crate::engine::Context;
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
+
+/// An action applied to every marked branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchAction {
+    /// Restack the marked branches
+    Restack,
+    /// Submit the marked branches
+    Submit,
+    /// Freeze the marked branches
+    Freeze,
+}
+
+impl BatchAction {
+    /// Parse an action name, accepting unambiguous prefixes (`r`, `sub`).
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+        [Self::Restack, Self::Submit, Self::Freeze]
+            .into_iter()
+            .find(|action| action.name().starts_with(&input))
+    }
+
+    /// The action's name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Restack => "restack",
+            Self::Submit => "submit",
+            Self::Freeze => "freeze",
+        }
+    }
+}
+
+/// Parse marks like `1 3-5` or `2,4` into zero-based indices, sorted and
+/// deduplicated.
+///
+/// # Errors
+///
+/// Returns the offending mark when it isn't a number or range within
+/// `1..=count`.
+pub fn parse_marks(input: &str, count: usize) -> Result<Vec<usize>, String> {
+    let mut marked = Vec::new();
+    for mark in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|m| !m.is_empty())
+    {
+        let (start, end) = mark.split_once('-').unwrap_or((mark, mark));
+        match (start.trim().parse::<usize>(), end.trim().parse::<usize>()) {
+            (Ok(start), Ok(end)) if 1 <= start && start <= end && end <= count => {
+                marked.extend(start - 1..end);
+            }
+            _ => return Err(mark.to_string()),
+        }
+    }
+    marked.sort_unstable();
+    marked.dedup();
+    Ok(marked)
+}
+
+/// Ask which of `branches` to act on and what to do, then do it.
+///
+/// `branches` are numbered from 1 in the order `lattice log --select`
+/// printed them.
+pub fn run(ctx: &Context, branches: &[BranchName]) -> CliResult {
+    println!();
+    let input = read_answer(&i18n::t("batch-mark-prompt"))?;
+    let marked = parse_marks(&input, branches.len()).map_err(|mark| {
+        CliError::usage(i18n::t_args(
+            "batch-invalid-mark",
+            &[("mark", &mark), ("count", &branches.len())],
+        ))
+    })?;
+    if marked.is_empty() {
+        println!("{}", i18n::t("batch-nothing-marked"));
+        return Ok(());
+    }
+    let selected: Vec<BranchName> = marked.into_iter().map(|i| branches[i].clone()).collect();
+
+    let input = read_answer(&i18n::t("batch-action-prompt"))?;
+    let Some(action) = BatchAction::parse(&input) else {
+        return Err(CliError::usage(i18n::t_args(
+            "batch-unknown-action",
+            &[("action", &input.trim())],
+        )));
+    };
+
+    let names = selected
+        .iter()
+        .map(|b| b.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let key = match action {
+        BatchAction::Restack => "batch-confirm-restack",
+        BatchAction::Submit => "batch-confirm-submit",
+        BatchAction::Freeze => "batch-confirm-freeze",
+    };
+    if !prompts::confirm(
+        &i18n::t_args(key, &[("branches", &names)]),
+        Severity::Minor,
+        ctx.interactive,
+    )? {
+        println!("{}", i18n::t("prompt-aborted"));
+        return Ok(());
+    }
+
+    match action {
+        BatchAction::Restack => super::restack::restack_branches(ctx, &selected),
+        BatchAction::Submit => super::submit::submit_branches(ctx, &selected),
+        BatchAction::Freeze => super::freeze::freeze_branches(ctx, &selected),
+    }
+}
+
+/// Print `prompt` and read one line of input.
+pub(crate) fn read_answer(prompt: &str) -> CliResult<String> {
+    print!("{} ", prompt);
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_accept_numbers_and_ranges() {
+        assert_eq!(parse_marks("1 3-5", 5), Ok(vec![0, 2, 3, 4]));
+        assert_eq!(parse_marks("4,2, 2", 5), Ok(vec![1, 3]));
+        assert_eq!(parse_marks("  ", 5), Ok(vec![]));
+    }
+
+    #[test]
+    fn marks_out_of_range_are_rejected() {
+        assert_eq!(parse_marks("0", 3), Err("0".to_string()));
+        assert_eq!(parse_marks("2-4", 3), Err("2-4".to_string()));
+        assert_eq!(parse_marks("3-2", 3), Err("3-2".to_string()));
+        assert_eq!(parse_marks("x", 3), Err("x".to_string()));
+    }
+
+    #[test]
+    fn actions_accept_prefixes() {
+        assert_eq!(BatchAction::parse("restack"), Some(BatchAction::Restack));
+        assert_eq!(BatchAction::parse(" Sub\n"), Some(BatchAction::Submit));
+        assert_eq!(BatchAction::parse("f"), Some(BatchAction::Freeze));
+        assert_eq!(BatchAction::parse(""), None);
+        assert_eq!(BatchAction::parse("delete"), None);
+    }
+}
@@ -0,0 +1,252 @@
+//! cli::commands::body_generator
+//!
+//! Generate new PR bodies with an external command.
+//!
+//! # Design
+//!
+//! `submit.body_generator` names a shell command (for example
+//! `scripts/pr-body.sh`) that `lattice submit` runs, from the repository
+//! root, for each PR it creates. The command receives a JSON description of
+//! the branch on stdin and prints the PR body on stdout, so teams can add
+//! their own sections (test plans, risk labels) without patching Lattice.
+//!
+//! The input carries the body Lattice would have used (`default_body`, from
+//! the `pr_template` or the branch description), so a generator can extend it
+//! rather than start over. Empty output means "no body". The stack comment is
+//! still added afterwards, and existing PRs keep their bodies.
+//!
+//! A generator that fails or exits non-zero doesn't stop the submit: the PR
+//! is created with the default body and the failure is reported.
+//!
+//! # Example input
+//!
+//! ```json
+//! {
+//!   "branch": "feature-b",
+//!   "parent": "feature-a",
+//!   "parent_pr": 41,
+//!   "trunk": "main",
+//!   "base": "<oid>",
+//!   "head": "<oid>",
+//!   "description": "Adds the widget",
+//!   "default_body": "Adds the widget",
+//!   "commits": [{ "oid": "<oid>", "subject": "Add widget", "message": "...",
+//!                 "author_name": "...", "author_email": "..." }]
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::types::{BranchName, Oid};
+use crate::engine::scan::RepoSnapshot;
+use crate::git::Git;
+
+/// What a body generator receives on stdin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BodyGeneratorInput {
+    /// Branch the PR is for
+    pub branch: String,
+    /// Parent branch (the PR's base)
+    pub parent: String,
+    /// The parent's PR number, if it has one
+    pub parent_pr: Option<u64>,
+    /// Trunk branch
+    pub trunk: Option<String>,
+    /// Base commit the branch's commits sit on
+    pub base: String,
+    /// Branch tip
+    pub head: String,
+    /// Branch description
+    pub description: Option<String>,
+    /// The body Lattice would use without a generator
+    pub default_body: Option<String>,
+    /// The branch's commits, oldest first
+    pub commits: Vec<BodyGeneratorCommit>,
+}
+
+/// A commit in [`BodyGeneratorInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BodyGeneratorCommit {
+    /// Commit OID
+    pub oid: String,
+    /// First line of the message
+    pub subject: String,
+    /// Full message
+    pub message: String,
+    /// Author name
+    pub author_name: String,
+    /// Author email
+    pub author_email: String,
+}
+
+/// Build the generator input for `branch`.
+///
+/// # Arguments
+///
+/// * `git` - Git interface
+/// * `snapshot` - Repository snapshot
+/// * `branch` - The branch the PR is for
+/// * `created` - PRs created earlier in this submit
+/// * `default_body` - The body used without a generator
+pub fn generator_input(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    created: &HashMap<BranchName, u64>,
+    default_body: Option<&str>,
+) -> CliResult<BodyGeneratorInput> {
+    let metadata = &snapshot
+        .metadata
+        .get(branch)
+        .ok_or_else(|| CliError::untracked(branch))?
+        .metadata;
+    let parent = metadata.parent.name();
+    let parent_pr = BranchName::new(parent).ok().and_then(|p| {
+        snapshot
+            .metadata
+            .get(&p)
+            .and_then(|m| m.metadata.pr.number())
+            .or_else(|| created.get(&p).copied())
+    });
+
+    let base = Oid::new(&metadata.base.oid)?;
+    let head = snapshot
+        .branches
+        .get(branch)
+        .with_context(|| format!("'{}' has no local ref", branch))?;
+    let mut commits: Vec<BodyGeneratorCommit> = git
+        .commits_between(&base, head)?
+        .into_iter()
+        .map(|c| BodyGeneratorCommit {
+            oid: c.oid.to_string(),
+            subject: c.summary,
+            message: c.message,
+            author_name: c.author_name,
+            author_email: c.author_email,
+        })
+        .collect();
+    commits.reverse();
+
+    Ok(BodyGeneratorInput {
+        branch: branch.to_string(),
+        parent: parent.to_string(),
+        parent_pr,
+        trunk: snapshot.trunk.as_ref().map(|t| t.to_string()),
+        base: base.to_string(),
+        head: head.to_string(),
+        description: metadata.description.clone(),
+        default_body: default_body.map(str::to_string),
+        commits,
+    })
+}
+
+/// Run `command` with `input` on stdin and return the body it prints.
+///
+/// Returns `Ok(None)` when the command prints nothing.
+///
+/// # Errors
+///
+/// Fails if the command can't be started or exits non-zero; the error
+/// includes its stderr.
+pub fn run_body_generator(
+    command: &str,
+    work_dir: &Path,
+    input: &BodyGeneratorInput,
+) -> CliResult<Option<String>> {
+    let json = serde_json::to_vec(input)?;
+    let mut child = shell(command)
+        .current_dir(work_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run body generator '{}'", command))?;
+
+    // A generator that ignores its input may exit before reading it all
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(&json);
+    }
+    let output = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to run body generator '{}'", command))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CliError::failed(format!(
+            "body generator '{}' failed ({}): {}",
+            command,
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    let body = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!body.is_empty()).then_some(body))
+}
+
+/// A command that runs `script` through the platform shell.
+fn shell(script: &str) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", script]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn input() -> BodyGeneratorInput {
+        BodyGeneratorInput {
+            branch: "feature".to_string(),
+            parent: "main".to_string(),
+            parent_pr: None,
+            trunk: Some("main".to_string()),
+            base: "a".repeat(40),
+            head: "b".repeat(40),
+            description: Some("Adds the widget".to_string()),
+            default_body: Some("Adds the widget".to_string()),
+            commits: vec![],
+        }
+    }
+
+    #[test]
+    fn generator_receives_input_on_stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        let body = run_body_generator("cat", dir.path(), &input())
+            .unwrap()
+            .unwrap();
+        let echoed: BodyGeneratorInput = serde_json::from_str(&body).unwrap();
+        assert_eq!(echoed, input());
+    }
+
+    #[test]
+    fn empty_output_means_no_body() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            run_body_generator("true", dir.path(), &input()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn failure_reports_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = run_body_generator("echo no template >&2; exit 3", dir.path(), &input())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("no template"), "{}", err);
+    }
+}
@@ -0,0 +1,158 @@
+//! cli::commands::cached_pr
+//!
+//! PR state cached in branch metadata, for working offline.
+//!
+//! # Design
+//!
+//! Whenever `sync` or `submit` learns a PR's state from the forge, it
+//! records it in the branch's metadata (`pr.last_known`, stamped with
+//! `pr_cached_at`), so the state travels with the metadata refs. The
+//! writes go through the executor as one journaled operation per command.
+//!
+//! Offline (`--offline`, or once a command finds the remote unreachable;
+//! see [`go_offline`]), `log`, `pr`, `submit --dry-run`, and `sync`
+//! show cached states instead of asking the forge, each labeled with when
+//! it was fetched so stale data never passes for current.
+//!
+//! Recording is best-effort, like the CI cache: a failed metadata write
+//! leaves the previous state in place.
+
+use chrono::{DateTime, Utc};
+
+use crate::core::metadata::schema::{BranchMetadataV2, PrState, PrStatusCache};
+use crate::core::metadata::store::MetadataStore;
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, UtcTimestamp};
+use crate::engine::plan::{Plan, PlanStep};
+use crate::engine::Context;
+use crate::forge::PullRequest;
+use crate::git::Git;
+use crate::ui::i18n;
+
+/// Cache each PR's state in its branch's metadata, for branches that link
+/// to it.
+///
+/// `command` names the journaled operation. Failures are ignored.
+pub(crate) fn record(git: &Git, ctx: &Context, command: &str, prs: &[(BranchName, PullRequest)]) {
+    let store = MetadataStore::new(git);
+    let now = UtcTimestamp::now();
+    let mut plan = Plan::new(OpId::new(), command);
+    for (branch, pr) in prs {
+        let Ok(Some(entry)) = store.read(branch) else {
+            continue;
+        };
+        let mut metadata = entry.metadata;
+        let PrState::Linked {
+            number, last_known, ..
+        } = &mut metadata.pr
+        else {
+            continue;
+        };
+        if *number != pr.number {
+            continue;
+        }
+        *last_known = Some(PrStatusCache {
+            state: pr.state.to_string(),
+            is_draft: pr.is_draft,
+        });
+        metadata.pr_cached_at = Some(now.clone());
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+    if !plan.is_empty() {
+        let _ = super::execute_follow_up(git, ctx, &plan);
+    }
+}
+
+/// The cached state of the branch's PR, e.g. `#12 open`, or `None` when
+/// nothing was cached.
+pub(crate) fn label(metadata: &BranchMetadataV2) -> Option<String> {
+    let PrState::Linked {
+        number,
+        last_known: Some(status),
+        ..
+    } = &metadata.pr
+    else {
+        return None;
+    };
+    let state = if status.is_draft && status.state == "open" {
+        "draft"
+    } else {
+        status.state.as_str()
+    };
+    Some(format!("#{} {}", number, state))
+}
+
+/// [`label`], marked with when it was fetched, e.g. `#12 open (cached
+/// 2026-03-01 09:30 UTC)`.
+pub(crate) fn stale_label(metadata: &BranchMetadataV2) -> Option<String> {
+    let label = label(metadata)?;
+    Some(match &metadata.pr_cached_at {
+        Some(at) => format!("{} {}", label, stale_suffix(*at.as_datetime())),
+        None => format!("{} (cached)", label),
+    })
+}
+
+/// Mark data fetched at `fetched_at` as cached.
+pub(crate) fn stale_suffix(fetched_at: DateTime<Utc>) -> String {
+    format!("(cached {})", fetched_at.format("%Y-%m-%d %H:%M UTC"))
+}
+
+/// Switch to offline mode for the rest of the command after finding the
+/// remote unreachable, announcing it the first time.
+pub(crate) fn go_offline(offline: &mut bool, reason: &str) {
+    if !*offline {
+        *offline = true;
+        eprintln!(
+            "{}",
+            i18n::t_args(
+                "cached-pr-warning-remote-unreachable",
+                &[("reason", &reason)],
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(last_known: Option<(&str, bool)>) -> BranchMetadataV2 {
+        let mut metadata = BranchMetadataV2::new(
+            BranchName::new("feature").unwrap(),
+            BranchName::new("main").unwrap(),
+            crate::core::types::Oid::new("a".repeat(40)).unwrap(),
+        );
+        metadata.pr = PrState::Linked {
+            forge: "github".to_string(),
+            number: 12,
+            url: "https://github.com/o/r/pull/12".to_string(),
+            last_known: last_known.map(|(state, is_draft)| PrStatusCache {
+                state: state.to_string(),
+                is_draft,
+            }),
+        };
+        metadata
+    }
+
+    #[test]
+    fn label_names_the_fetch_time() {
+        let mut cached = metadata(Some(("open", true)));
+        cached.pr_cached_at = Some(UtcTimestamp::from_datetime(
+            "2026-03-01T09:30:00Z".parse().unwrap(),
+        ));
+        assert_eq!(label(&cached).as_deref(), Some("#12 draft"));
+        assert_eq!(
+            stale_label(&cached).as_deref(),
+            Some("#12 draft (cached 2026-03-01 09:30 UTC)")
+        );
+        assert_eq!(
+            stale_label(&metadata(Some(("merged", false)))).as_deref(),
+            Some("#12 merged (cached)")
+        );
+        assert_eq!(stale_label(&metadata(None)), None);
+    }
+}
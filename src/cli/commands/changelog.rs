@@ -1,26 +1,25 @@
 //! changelog command - Show version and changelog
 
-use anyhow::Result;
+use crate::cli::error::CliResult;
+use crate::ui::i18n;
 
 /// Show version and changelog.
-pub fn changelog() -> Result<()> {
+pub fn changelog() -> CliResult {
     println!("lattice {}", env!("CARGO_PKG_VERSION"));
     println!();
-    println!("A Rust-native CLI for stacked branches and PRs.");
+    println!("{}", i18n::t("changelog-tagline"));
     println!();
-    println!("Repository: https://github.com/lattice-cli/lattice");
+    println!("{}", i18n::t("changelog-repository"));
     println!();
-    println!("## Recent Changes");
+    println!("{}", i18n::t("changelog-recent-changes"));
     println!();
-    println!("- Phase 1: Core local stack engine");
-    println!("  - Stack graph and metadata management");
-    println!("  - Branch tracking with parent relationships");
-    println!("  - Restack with conflict handling");
-    println!("  - Operation journaling for crash safety");
+    println!("{}", i18n::t("changelog-phase-core-local"));
+    println!("  {}", i18n::t("changelog-stack-graph-metadata"));
+    println!("  {}", i18n::t("changelog-branch-tracking-parent"));
+    println!("  {}", i18n::t("changelog-restack-conflict-handling"));
+    println!("  {}", i18n::t("changelog-operation-journaling-crash"));
     println!();
-    println!(
-        "For full changelog, see: https://github.com/lattice-cli/lattice/blob/main/CHANGELOG.md"
-    );
+    println!("{}", i18n::t("changelog-see-full"));
 
     Ok(())
 }
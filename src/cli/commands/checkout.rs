@@ -4,15 +4,31 @@
 //!
 //! Uses `requirements::NAVIGATION` - this command reads stack structure
 //! and checkouts out branches, requiring a working directory.
+//!
+//! Like the navigation commands, it starts a background fetch of the
+//! target's PR status after switching (see `prefetch`).
+//!
+//! # Worktrees
+//!
+//! A branch checked out in another worktree can't be checked out here too;
+//! the error names that worktree. `--worktree <path>` checks the branch out
+//! in a worktree of its own instead: the existing one if the branch already
+//! has one, otherwise a new one created at `<path>` with `git worktree add`.
+//! Stack commands run from any worktree of the repository.
 
+use crate::cli::error::{CliError, CliResult};
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 use std::io::{self, Write};
+use std::path::Path;
 use std::process::Command;
 
 /// Check out a branch.
@@ -23,16 +39,24 @@ use std::process::Command;
 /// * `branch` - Branch to check out
 /// * `trunk_flag` - Check out trunk
 /// * `stack` - Filter selector to current stack
+/// * `worktree` - Check the branch out in a worktree at this path instead
 ///
 /// # Gating
 ///
 /// Uses `requirements::NAVIGATION`.
-pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bool) -> Result<()> {
+pub fn checkout(
+    ctx: &Context,
+    branch: Option<&str>,
+    trunk_flag: bool,
+    stack: bool,
+    worktree: Option<&Path>,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
 
     run_gated(&git, ctx, &requirements::NAVIGATION, |ready| {
         let snapshot = &ready.snapshot;
@@ -43,20 +67,11 @@ pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bo
             snapshot
                 .trunk
                 .as_ref()
-                .ok_or_else(|| {
-                    RunError::Scan(crate::engine::scan::ScanError::Internal(
-                        "Trunk not configured".to_string(),
-                    ))
-                })?
+                .ok_or(PlanError::TrunkNotConfigured)?
                 .clone()
         } else if let Some(name) = branch {
             // Direct branch name
-            BranchName::new(name).map_err(|e| {
-                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                    "Invalid branch name: {}",
-                    e
-                )))
-            })?
+            BranchName::new(name).map_err(PlanError::from)?
         } else if ctx.interactive {
             // Interactive selection
             let candidates: Vec<_> = if stack {
@@ -76,7 +91,7 @@ pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bo
                 )));
             }
 
-            println!("Select branch to check out:");
+            println!("{}", i18n::t("checkout-select-branch-check"));
             for (i, b) in candidates.iter().enumerate() {
                 let current_marker = if snapshot.current_branch.as_ref() == Some(b) {
                     " (current)"
@@ -90,7 +105,7 @@ pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bo
                 };
                 println!("  {}. {}{}{}", i + 1, b, current_marker, trunk_marker);
             }
-            print!("Enter number: ");
+            print!("{} ", i18n::t("checkout-enter-number"));
             io::stdout().flush().map_err(|e| {
                 RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                     "Failed to flush stdout: {}",
@@ -135,6 +150,24 @@ pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bo
             )));
         }
 
+        if let Some(path) = worktree {
+            let path = checkout_in_worktree(snapshot, &cwd, &target, path, ctx.quiet)?;
+            super::prefetch::spawn_after_navigation(&path, &paths, snapshot, &target);
+            return Ok(());
+        }
+
+        if let Some(path) = snapshot.checked_out_elsewhere.get(&target) {
+            return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
+                format!(
+                    "Branch '{}' is checked out in worktree at {}. Work there, or run 'lattice checkout {} --worktree {}'.",
+                    target,
+                    path.display(),
+                    target,
+                    path.display()
+                ),
+            )));
+        }
+
         // Run git checkout
         let status = Command::new("git")
             .args(["checkout", target.as_str()])
@@ -153,14 +186,77 @@ pub fn checkout(ctx: &Context, branch: Option<&str>, trunk_flag: bool, stack: bo
             )));
         }
 
+        super::prefetch::spawn_after_navigation(&cwd, &paths, snapshot, &target);
         Ok(())
     })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
+    .map_err(CliError::from)
+}
+
+/// Check out `target` in a worktree of its own and return that worktree's path.
+///
+/// Uses the worktree that already has `target` checked out if there is one;
+/// otherwise creates one at `path` (relative to `cwd`).
+fn checkout_in_worktree(
+    snapshot: &RepoSnapshot,
+    cwd: &Path,
+    target: &BranchName,
+    path: &Path,
+    quiet: bool,
+) -> Result<std::path::PathBuf, RunError> {
+    if let Some(existing) = snapshot.checked_out_elsewhere.get(target) {
+        if !quiet {
+            println!(
+                "{}",
+                i18n::t_args(
+                    "checkout-already-checked-out",
+                    &[("target", &target), ("existing", &existing.display())],
+                )
+            );
         }
-        other => anyhow::anyhow!("{}", other),
-    })
+        return Ok(existing.clone());
+    }
+    if snapshot.current_branch.as_ref() == Some(target) {
+        return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
+            format!(
+                "Branch '{}' is checked out in this worktree; switch to another branch first",
+                target
+            ),
+        )));
+    }
+
+    let path = cwd.join(path);
+    let output = Command::new("git")
+        .arg("worktree")
+        .arg("add")
+        .arg(&path)
+        .arg(target.as_str())
+        .current_dir(cwd)
+        .output()
+        .map_err(|e| {
+            RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
+                "Failed to run git worktree add: {}",
+                e
+            )))
+        })?;
+    if !output.status.success() {
+        return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
+            format!(
+                "git worktree add failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        )));
+    }
+
+    if !quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "checkout-checked-out-new",
+                &[("target", &target), ("path", &path.display())],
+            )
+        );
+    }
+    Ok(path)
 }
 
 /// Get all branches in the same stack as the given branch.
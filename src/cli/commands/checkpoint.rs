@@ -0,0 +1,288 @@
+//! cli::commands::checkpoint
+//!
+//! Save and restore named checkpoints of the whole stack.
+//!
+//! # Design
+//!
+//! `lattice undo` only reverses the most recent operation. Checkpoints give
+//! users coarse-grained restore points they control: `save` records every
+//! tracked branch's tip and metadata under `refs/lattice/checkpoints/<name>`
+//! (see `core::checkpoint`), and `restore` puts the stack back exactly as it
+//! was.
+//!
+//! # Architecture
+//!
+//! `restore` implements the `Command` trait with `requirements::MUTATING`.
+//! It plans CAS ref and metadata updates that flow through the executor, so
+//! a restore is journaled and can itself be reverted with `lattice undo`.
+//!
+//! Restore semantics:
+//! - Recorded branches are moved back to their saved tips (recreated if
+//!   deleted) and their metadata is rewritten
+//! - Branches tracked since the checkpoint are untracked, but their git
+//!   branches are kept
+//! - If the checked-out branch moves, the working tree is reset to its
+//!   restored tip, so it must be clean
+//!
+//! # Example
+//!
+//! ```bash
+//! lattice checkpoint save before-refactor
+//! lattice checkpoint list
+//! lattice checkpoint restore before-refactor
+//! lattice checkpoint delete before-refactor
+//! ```
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::checkpoint::{Checkpoint, CheckpointBranch, CheckpointStore};
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, UtcTimestamp};
+use crate::engine::command::{Command, CommandOutput, SimpleCommand};
+use crate::engine::exec::ExecuteResult;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::runner::{run_command, run_gated, RunError};
+use crate::engine::scan::{RepoSnapshot, ScanError};
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// Capture every tracked branch in the snapshot as a checkpoint.
+fn capture(name: &str, snapshot: &RepoSnapshot) -> Result<Checkpoint, String> {
+    let mut branches = Vec::with_capacity(snapshot.metadata.len());
+    for (branch, scanned) in &snapshot.metadata {
+        let tip = snapshot
+            .branches
+            .get(branch)
+            .ok_or_else(|| format!("Tracked branch '{}' has no local ref.", branch))?;
+        branches.push(CheckpointBranch {
+            name: branch.to_string(),
+            tip: tip.to_string(),
+            metadata: scanned.metadata.clone(),
+        });
+    }
+    branches.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Checkpoint {
+        name: name.to_string(),
+        created_at: UtcTimestamp::now(),
+        trunk: snapshot.trunk.as_ref().map(|t| t.to_string()),
+        current_branch: snapshot.current_branch.as_ref().map(|b| b.to_string()),
+        branches,
+    })
+}
+
+/// Plan the ref and metadata updates that restore a checkpoint.
+fn plan_restore(checkpoint: &Checkpoint, snapshot: &RepoSnapshot) -> Result<Plan, PlanError> {
+    let reason = format!("checkpoint restore {}", checkpoint.name);
+    let mut plan = Plan::new(OpId::new(), "checkpoint-restore");
+    let mut moves_current = false;
+
+    for recorded in &checkpoint.branches {
+        let branch = BranchName::new(&recorded.name)
+            .map_err(|e| PlanError::InvalidState(format!("Invalid branch in checkpoint: {}", e)))?;
+        let current_tip = snapshot.branches.get(&branch).map(|oid| oid.to_string());
+
+        if current_tip.as_deref() != Some(recorded.tip.as_str()) {
+            if current_tip.is_some() && snapshot.current_branch.as_ref() == Some(&branch) {
+                moves_current = true;
+            }
+            plan = plan.with_step(PlanStep::UpdateRefCas {
+                refname: format!("refs/heads/{}", recorded.name),
+                old_oid: current_tip,
+                new_oid: recorded.tip.clone(),
+                reason: reason.clone(),
+            });
+        }
+
+        let scanned = snapshot.metadata.get(&branch);
+        if scanned.map(|s| &s.metadata) != Some(&recorded.metadata) {
+            plan = plan.with_step(PlanStep::WriteMetadataCas {
+                branch: recorded.name.clone(),
+                old_ref_oid: scanned.map(|s| s.ref_oid.to_string()),
+                metadata: Box::new(recorded.metadata.clone()),
+            });
+        }
+    }
+
+    // Untrack branches that were not tracked when the checkpoint was saved
+    let mut untracked: Vec<_> = snapshot
+        .metadata
+        .iter()
+        .filter(|(b, _)| checkpoint.branch(b.as_str()).is_none())
+        .collect();
+    untracked.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    for (branch, scanned) in untracked {
+        plan = plan.with_step(PlanStep::DeleteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: scanned.ref_oid.to_string(),
+        });
+    }
+
+    if moves_current {
+        if snapshot.worktree_status.is_dirty() {
+            return Err(PlanError::InvalidState(
+                "Restoring this checkpoint moves the checked-out branch. \
+                 Commit or stash your changes first."
+                    .to_string(),
+            ));
+        }
+        let branch = snapshot.current_branch.as_ref().map(|b| b.to_string());
+        plan = plan.with_step(PlanStep::RunGit {
+            args: vec![
+                "reset".to_string(),
+                "--hard".to_string(),
+                "HEAD".to_string(),
+            ],
+            description: "Sync working tree to restored branch".to_string(),
+            expected_effects: branch
+                .map(|b| vec![format!("refs/heads/{}", b)])
+                .unwrap_or_default(),
+        });
+    }
+
+    Ok(plan)
+}
+
+/// Command to restore a checkpoint.
+pub struct RestoreCheckpointCommand<'a> {
+    checkpoint: &'a Checkpoint,
+}
+
+impl Command for RestoreCheckpointCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING;
+    type Output = ();
+
+    fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
+        plan_restore(self.checkpoint, &ready.snapshot)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        self.simple_finish(result)
+    }
+}
+
+impl SimpleCommand for RestoreCheckpointCommand<'_> {}
+
+fn open_git(ctx: &Context) -> CliResult<Git> {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    Ok(Git::open(&cwd).context("Failed to open repository")?)
+}
+
+/// Save the current stack state as a named checkpoint.
+///
+/// # Arguments
+///
+/// * `ctx` - Engine context
+/// * `name` - Checkpoint name
+/// * `force` - Overwrite an existing checkpoint with the same name
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY`; only checkpoint refs are written.
+pub fn save(ctx: &Context, name: &str, force: bool) -> CliResult {
+    let git = open_git(ctx)?;
+
+    let checkpoint = run_gated(&git, ctx, &requirements::MUTATING_METADATA_ONLY, |ready| {
+        capture(name, &ready.snapshot).map_err(|e| RunError::Scan(ScanError::Internal(e)))
+    })?;
+
+    CheckpointStore::new(&git).save(&checkpoint, force)?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "checkpoints-saved",
+                &[("name", &name), ("count", &checkpoint.branches.len())],
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Restore the stack to a named checkpoint.
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING` via `Command` trait.
+pub fn restore(ctx: &Context, name: &str) -> CliResult {
+    let git = open_git(ctx)?;
+    let checkpoint = CheckpointStore::new(&git).load(name)?;
+
+    let cmd = RestoreCheckpointCommand {
+        checkpoint: &checkpoint,
+    };
+    let output = run_command(&cmd, &git, ctx)?;
+    output.into_result().map_err(CliError::failed)?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "checkpoints-restored",
+                &[("name", &name), ("created_at", &checkpoint.created_at)],
+            )
+        );
+    }
+    Ok(())
+}
+
+/// List saved checkpoints.
+pub fn list(ctx: &Context) -> CliResult {
+    let git = open_git(ctx)?;
+    let store = CheckpointStore::new(&git);
+    let names = store.list()?;
+
+    if names.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("checkpoints-no-checkpoints"));
+        }
+        return Ok(());
+    }
+
+    for name in names {
+        match store.load(&name) {
+            Ok(cp) => println!(
+                "{}",
+                i18n::t_args(
+                    "checkpoints-list-entry",
+                    &[
+                        ("name", &name),
+                        ("created_at", &cp.created_at),
+                        ("count", &cp.branches.len()),
+                    ],
+                )
+            ),
+            Err(e) => println!("{}  ({})", name, e),
+        }
+    }
+    Ok(())
+}
+
+/// Delete a saved checkpoint.
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY`; only checkpoint refs are written.
+pub fn delete(ctx: &Context, name: &str) -> CliResult {
+    let git = open_git(ctx)?;
+
+    run_gated(&git, ctx, &requirements::MUTATING_METADATA_ONLY, |_ready| {
+        Ok(())
+    })?;
+
+    CheckpointStore::new(&git).delete(name)?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args("checkpoints-deleted", &[("name", &name)])
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! cli::commands::ci_status
+//!
+//! CI status badges for branch tips.
+//!
+//! # Design
+//!
+//! The forge reports checks per commit, so a branch's CI state is the state
+//! of its tip. `lattice pr` and background prefetches ask the forge for the
+//! checks on each tip and cache the outcome in the branch's metadata (`ci`),
+//! written through the executor like any other metadata change.
+//! `lattice log --long` and an offline `lattice pr` show the last known
+//! state from there. A state cached for a commit other than the current tip
+//! is marked outdated rather than hidden. A request that finds the forge
+//! unreachable turns on offline mode, and offline badges say when the state
+//! was fetched.
+//!
+//! Fetching and caching are best-effort: a failed request or metadata write
+//! leaves the previous state in place. Nothing is cached in read-only mode.
+
+use serde::Serialize;
+
+use crate::core::metadata::schema::{CiState, CiStatus};
+use crate::core::metadata::store::MetadataStore;
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
+use crate::engine::plan::{Plan, PlanStep};
+use crate::engine::Context;
+use crate::forge::status_cache::CheckCounts;
+use crate::forge::Forge;
+use crate::git::Git;
+use crate::ui::output::{paint, Color};
+
+use super::pending_ops::is_offline_forge_error;
+
+/// A branch's CI state, as shown in badges and `--json` output.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CiReport {
+    state: CiState,
+    /// Commit the state was fetched for
+    oid: String,
+    /// Whether the branch has moved since
+    outdated: bool,
+    /// When the state was fetched
+    checked_at: UtcTimestamp,
+}
+
+impl CiReport {
+    /// Describe `ci` relative to the branch's current tip.
+    pub(crate) fn new(ci: &CiStatus, tip: Option<&Oid>) -> Self {
+        Self {
+            state: ci.state,
+            oid: ci.oid.clone(),
+            outdated: tip.is_some_and(|tip| tip.as_str() != ci.oid),
+            checked_at: ci.checked_at.clone(),
+        }
+    }
+
+    /// [`Self::badge`], marked with when the state was fetched.
+    pub(crate) fn stale_badge(&self) -> String {
+        format!(
+            "{} {}",
+            self.badge(),
+            super::cached_pr::stale_suffix(*self.checked_at.as_datetime())
+        )
+    }
+
+    /// Colored badge, e.g. `passing` in green, marked `(outdated)` when the
+    /// branch has moved since.
+    pub(crate) fn badge(&self) -> String {
+        let color = match self.state {
+            CiState::Pending => Color::Yellow,
+            CiState::Passing => Color::Green,
+            CiState::Failing => Color::Red,
+        };
+        let mut badge = paint(&self.state.to_string(), color);
+        if self.outdated {
+            badge.push_str(" (outdated)");
+        }
+        badge
+    }
+}
+
+/// Fetch the CI state of each branch tip.
+///
+/// Returns the states that could be fetched. Tips CI never saw and failed
+/// requests are left out; a request that finds the forge unreachable
+/// sets `offline` and stops.
+pub(crate) async fn fetch(
+    forge: &dyn Forge,
+    tips: &[(BranchName, Oid)],
+    offline: &mut bool,
+) -> Vec<(BranchName, CiStatus)> {
+    let mut fetched = Vec::new();
+    for (branch, tip) in tips {
+        let checks = match forge.check_runs_for_ref(tip.as_str()).await {
+            Ok(checks) => checks,
+            Err(e) if is_offline_forge_error(&e) => {
+                super::cached_pr::go_offline(offline, &e.to_string());
+                break;
+            }
+            Err(_) => continue,
+        };
+        let Some(state) = CheckCounts::from_checks(&checks).ci_state() else {
+            continue;
+        };
+        let status = CiStatus {
+            state,
+            oid: tip.to_string(),
+            checked_at: UtcTimestamp::now(),
+        };
+        fetched.push((branch.clone(), status));
+    }
+    fetched
+}
+
+/// Cache `fetched` states in the branches' metadata, skipping those it
+/// already records.
+///
+/// `command` names the journaled operation. Failures are ignored.
+pub(crate) fn record(git: &Git, ctx: &Context, command: &str, fetched: &[(BranchName, CiStatus)]) {
+    let store = MetadataStore::new(git);
+    let mut plan = Plan::new(OpId::new(), command);
+    for (branch, status) in fetched {
+        let Ok(Some(entry)) = store.read(branch) else {
+            continue;
+        };
+        if entry
+            .metadata
+            .ci
+            .as_ref()
+            .is_some_and(|ci| ci.state == status.state && ci.oid == status.oid)
+        {
+            continue;
+        }
+        let mut metadata = entry.metadata;
+        metadata.ci = Some(status.clone());
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+    if !plan.is_empty() {
+        let _ = super::execute_follow_up(git, ctx, &plan);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oid(c: char) -> Oid {
+        Oid::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    #[test]
+    fn report_is_outdated_once_the_tip_moves() {
+        let ci = CiStatus {
+            state: CiState::Failing,
+            oid: oid('a').to_string(),
+            checked_at: UtcTimestamp::now(),
+        };
+
+        let current = CiReport::new(&ci, Some(&oid('a')));
+        assert!(!current.outdated);
+        // Tests don't run on a terminal, so badges are plain
+        assert_eq!(current.badge(), "failing");
+
+        let moved = CiReport::new(&ci, Some(&oid('b')));
+        assert_eq!(moved.badge(), "failing (outdated)");
+    }
+}
@@ -1,29 +1,440 @@
 //! completion command - Generate shell completion scripts
+//!
+//! # Dynamic Completion
+//!
+//! Clap's generated scripts only know the static command line: subcommands,
+//! flags, and value enums. Branch names depend on the repository, so the
+//! bash, zsh, and fish scripts get an extra wrapper that asks the binary for
+//! them at tab time via the hidden `lt __complete <command> <prefix>`.
+//!
+//! `__complete` prints one tracked branch per line and never fails: outside
+//! a repository, or with unreadable metadata, it prints nothing and the
+//! shell falls back to its default completion. It reads metadata refs
+//! directly instead of running a full scan, so it stays fast on large repos.
+//!
+//! PowerShell keeps the static script.
+//!
+//! # Installing
+//!
+//! `lt completion install [shell]` writes the same script to the place the
+//! shell looks for completions, detecting the shell from `$SHELL` when none
+//! is named:
+//!
+//! - bash: `$XDG_DATA_HOME/bash-completion/completions/lt` (default
+//!   `~/.local/share`), loaded on demand by bash-completion
+//! - zsh: `~/.zfunc/_lt`, sourced from `~/.zshrc` after `compinit`
+//! - fish: `$XDG_CONFIG_HOME/fish/completions/lt.fish` (default `~/.config`),
+//!   loaded on demand by fish
+//! - PowerShell: `lt.ps1` in the user config directory, dot-sourced from
+//!   `$PROFILE`
+//!
+//! It asks before writing and then prints the line to add to the startup
+//! file, if the shell needs one. It never edits startup files itself.
 
 use crate::cli::args::{Cli, Shell};
-use anyhow::Result;
-use clap::CommandFactory;
+use crate::cli::error::CliResult;
+use crate::core::config::Config;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
+use anyhow::Context as _;
+use clap::{CommandFactory, ValueEnum};
 use clap_complete::{generate, shells};
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Name of the installed binary, which the scripts register completions for.
+const BIN_NAME: &str = "lt";
+
+/// Bash wrapper that completes branch names and defers everything else to
+/// the generated `_lt`.
+const BASH_DYNAMIC: &str = r#"
+# Branch names from the repository (lt __complete)
+_lt_dynamic() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" prev="${COMP_WORDS[COMP_CWORD-1]}"
+    local cmd="" want="" i=1
+    while [[ $i -lt $COMP_CWORD ]]; do
+        case "${COMP_WORDS[i]}" in
+            --cwd) i=$((i + 2)); continue ;;
+            -*) ;;
+            *) cmd="${COMP_WORDS[i]}"; break ;;
+        esac
+        i=$((i + 1))
+    done
+    case "$cmd" in
+        checkout|co|delete|d) [[ "$cur" != -* ]] && want="$cmd" ;;
+        move) [[ "$prev" == "--onto" ]] && want="$cmd" ;;
+        track) [[ "$prev" == "--parent" || "$prev" == "-p" ]] && want="$cmd" ;;
+    esac
+    if [[ -n "$want" ]]; then
+        COMPREPLY=($(lt __complete "$want" "$cur" 2>/dev/null))
+        return 0
+    fi
+    _lt "$@"
+}
+complete -F _lt_dynamic -o bashdefault -o default lt
+"#;
+
+/// Zsh wrapper, same shape as the bash one.
+const ZSH_DYNAMIC: &str = r#"
+# Branch names from the repository (lt __complete)
+_lt_dynamic() {
+    local cur="${words[CURRENT]}" prev="${words[CURRENT-1]}"
+    local cmd="" want="" i=2
+    while (( i < CURRENT )); do
+        case "${words[i]}" in
+            --cwd) (( i += 2 )); continue ;;
+            -*) ;;
+            *) cmd="${words[i]}"; break ;;
+        esac
+        (( i += 1 ))
+    done
+    case "$cmd" in
+        checkout|co|delete|d) [[ "$cur" != -* ]] && want="$cmd" ;;
+        move) [[ "$prev" == --onto ]] && want="$cmd" ;;
+        track) [[ "$prev" == (--parent|-p) ]] && want="$cmd" ;;
+    esac
+    if [[ -n "$want" ]]; then
+        local -a branches
+        branches=(${(f)"$(lt __complete "$want" "$cur" 2>/dev/null)"})
+        compadd -a branches
+        return
+    fi
+    _lt "$@"
+}
+compdef _lt_dynamic lt
+"#;
+
+/// Fish completions, added alongside the generated ones.
+const FISH_DYNAMIC: &str = r#"
+# Branch names from the repository (lt __complete)
+complete -c lt -n "__fish_seen_subcommand_from checkout co" -f -a "(lt __complete checkout (commandline -ct) 2>/dev/null)"
+complete -c lt -n "__fish_seen_subcommand_from delete d" -f -a "(lt __complete delete (commandline -ct) 2>/dev/null)"
+complete -c lt -n "__fish_seen_subcommand_from move" -l onto -x -a "(lt __complete move (commandline -ct) 2>/dev/null)"
+complete -c lt -n "__fish_seen_subcommand_from track" -s p -l parent -x -a "(lt __complete track (commandline -ct) 2>/dev/null)"
+"#;
 
 /// Generate shell completion scripts.
-pub fn completion(shell: Shell) -> Result<()> {
+pub fn completion(shell: Shell) -> CliResult {
+    std::io::stdout().write_all(&script(shell))?;
+    Ok(())
+}
+
+/// Install the completion script for `shell`, or the shell in `$SHELL`.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `shell` - Shell to install for; detected when `None`
+pub fn install(ctx: &Context, shell: Option<Shell>) -> CliResult {
+    let shell = match shell {
+        Some(shell) => shell,
+        None => std::env::var("SHELL")
+            .ok()
+            .and_then(|path| detect_shell(&path))
+            .context(
+                "Could not detect your shell from $SHELL. \
+                 Name it instead: lt completion install <shell>",
+            )?,
+    };
+    let home = dirs::home_dir().context("Could not determine the home directory")?;
+    let target = install_target(shell, &home, |name| {
+        std::env::var_os(name)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    });
+
+    let prompt = i18n::t_args(
+        "prompt-completion-install",
+        &[
+            ("shell", &shell_name(shell)),
+            ("path", &target.path.display()),
+        ],
+    );
+    if !prompts::confirm(&prompt, Severity::Minor, ctx.interactive)? {
+        println!("{}", i18n::t("prompt-aborted"));
+        return Ok(());
+    }
+
+    if let Some(dir) = target.path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(&target.path, script(shell))
+        .with_context(|| format!("Failed to write {}", target.path.display()))?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args("completion-wrote", &[("path", &target.path.display())])
+        );
+        println!("{}", target.hint);
+        if let Some(line) = &target.rc_line {
+            println!("    {}", line);
+        }
+        println!("{}", i18n::t("completion-new-shells"));
+    }
+    Ok(())
+}
+
+/// The full completion script for `shell`.
+fn script(shell: Shell) -> Vec<u8> {
     let mut cmd = Cli::command();
-    let name = cmd.get_name().to_string();
+    let mut out = Vec::new();
+
+    match shell {
+        Shell::Bash => {
+            generate(shells::Bash, &mut cmd, BIN_NAME, &mut out);
+            out.extend_from_slice(BASH_DYNAMIC.as_bytes());
+        }
+        Shell::Zsh => {
+            generate(shells::Zsh, &mut cmd, BIN_NAME, &mut out);
+            out.extend_from_slice(ZSH_DYNAMIC.as_bytes());
+        }
+        Shell::Fish => {
+            generate(shells::Fish, &mut cmd, BIN_NAME, &mut out);
+            out.extend_from_slice(FISH_DYNAMIC.as_bytes());
+        }
+        Shell::PowerShell => {
+            generate(shells::PowerShell, &mut cmd, BIN_NAME, &mut out);
+        }
+    }
+
+    out
+}
 
+/// The shell named by a `$SHELL` path, if supported.
+fn detect_shell(path: &str) -> Option<Shell> {
+    // Split on both separators: $SHELL may be a Windows path
+    let name = path.rsplit(['/', '\\']).next()?;
+    let name = name.strip_suffix(".exe").unwrap_or(name);
+    match name {
+        "bash" => Some(Shell::Bash),
+        "zsh" => Some(Shell::Zsh),
+        "fish" => Some(Shell::Fish),
+        "pwsh" | "powershell" => Some(Shell::PowerShell),
+        _ => None,
+    }
+}
+
+/// The name of `shell` as typed on the command line.
+fn shell_name(shell: Shell) -> String {
+    shell
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Where a shell's completion script goes, and how the shell picks it up.
+#[derive(Debug)]
+struct InstallTarget {
+    /// File to write the script to.
+    path: PathBuf,
+    /// What the user needs to do after installing.
+    hint: &'static str,
+    /// Line to add to the shell's startup file, if one is needed.
+    rc_line: Option<String>,
+}
+
+/// The install target for `shell`, given the home directory and a lookup
+/// for non-empty directory environment variables.
+fn install_target(
+    shell: Shell,
+    home: &Path,
+    env_dir: impl Fn(&str) -> Option<PathBuf>,
+) -> InstallTarget {
     match shell {
         Shell::Bash => {
-            generate(shells::Bash, &mut cmd, &name, &mut std::io::stdout());
+            let data = env_dir("XDG_DATA_HOME").unwrap_or_else(|| home.join(".local/share"));
+            let path = data.join("bash-completion/completions").join(BIN_NAME);
+            InstallTarget {
+                rc_line: Some(format!("source {}", path.display())),
+                path,
+                hint: "bash-completion loads it automatically. Without bash-completion, \
+                       add this line to ~/.bashrc:",
+            }
         }
         Shell::Zsh => {
-            generate(shells::Zsh, &mut cmd, &name, &mut std::io::stdout());
+            let path = home.join(".zfunc").join(format!("_{}", BIN_NAME));
+            InstallTarget {
+                rc_line: Some(format!("source {}", path.display())),
+                path,
+                hint: "Add this line to ~/.zshrc, after compinit:",
+            }
         }
         Shell::Fish => {
-            generate(shells::Fish, &mut cmd, &name, &mut std::io::stdout());
+            let config = env_dir("XDG_CONFIG_HOME").unwrap_or_else(|| home.join(".config"));
+            InstallTarget {
+                path: config
+                    .join("fish/completions")
+                    .join(format!("{}.fish", BIN_NAME)),
+                hint: "Fish loads it automatically; no startup file changes are needed.",
+                rc_line: None,
+            }
         }
         Shell::PowerShell => {
-            generate(shells::PowerShell, &mut cmd, &name, &mut std::io::stdout());
+            let config = dirs::config_dir().unwrap_or_else(|| home.join(".config"));
+            let path = config.join("powershell").join(format!("{}.ps1", BIN_NAME));
+            InstallTarget {
+                rc_line: Some(format!(". '{}'", path.display())),
+                path,
+                hint: "Add this line to your PowerShell profile ($PROFILE):",
+            }
         }
     }
+}
 
+/// Print the branch names that complete `prefix` for `command`.
+///
+/// `command` may be an alias (`co`, `d`). Unknown commands print nothing.
+pub fn complete(ctx: &Context, command: &str, prefix: &str) -> CliResult {
+    let command = Cli::command()
+        .find_subcommand(command)
+        .map(|c| c.get_name().to_string())
+        .unwrap_or_default();
+
+    let Some((tracked, trunk)) = read_branches(ctx) else {
+        return Ok(());
+    };
+    for name in candidates(&command, tracked, trunk.as_deref(), prefix) {
+        println!("{}", name);
+    }
     Ok(())
 }
+
+/// Tracked branches that still exist locally, plus the configured trunk.
+fn read_branches(ctx: &Context) -> Option<(Vec<String>, Option<String>)> {
+    let cwd = match &ctx.cwd {
+        Some(cwd) => cwd.clone(),
+        None => std::env::current_dir().ok()?,
+    };
+    let git = Git::open(&cwd).ok()?;
+    let local: BTreeSet<String> = git
+        .list_branches()
+        .ok()?
+        .into_iter()
+        .map(|b| b.to_string())
+        .collect();
+    let tracked = git
+        .list_metadata_refs()
+        .ok()?
+        .into_iter()
+        .map(|(b, _)| b.to_string())
+        .filter(|b| local.contains(b))
+        .collect();
+    let info = git.info().ok()?;
+    let trunk = Config::load(info.work_dir.as_deref())
+        .ok()
+        .and_then(|r| r.config.trunk().map(str::to_string));
+    Some((tracked, trunk))
+}
+
+/// Sorted candidates for `command` that start with `prefix`.
+///
+/// Trunk is offered wherever it is a valid answer: as a checkout target,
+/// a new parent, or a `--onto` target. It is never offered for `delete`.
+fn candidates(
+    command: &str,
+    tracked: Vec<String>,
+    trunk: Option<&str>,
+    prefix: &str,
+) -> Vec<String> {
+    let with_trunk = match command {
+        "checkout" | "move" | "track" => true,
+        "delete" => false,
+        _ => return Vec::new(),
+    };
+    let mut names: BTreeSet<String> = tracked.into_iter().collect();
+    if let Some(trunk) = trunk {
+        if with_trunk {
+            names.insert(trunk.to_string());
+        } else {
+            names.remove(trunk);
+        }
+    }
+    names
+        .into_iter()
+        .filter(|name| name.starts_with(prefix))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracked() -> Vec<String> {
+        ["feature-b", "feature-a", "fix-login"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn candidates_filter_by_prefix_and_command() {
+        assert_eq!(
+            candidates("checkout", tracked(), Some("main"), "fe"),
+            vec!["feature-a", "feature-b"]
+        );
+        assert_eq!(
+            candidates("move", tracked(), Some("main"), ""),
+            vec!["feature-a", "feature-b", "fix-login", "main"]
+        );
+        assert_eq!(
+            candidates("delete", tracked(), Some("main"), "m"),
+            Vec::<String>::new()
+        );
+        assert!(candidates("submit", tracked(), Some("main"), "").is_empty());
+    }
+
+    #[test]
+    fn detect_shell_from_path() {
+        assert!(matches!(detect_shell("/bin/zsh"), Some(Shell::Zsh)));
+        assert!(matches!(
+            detect_shell("/usr/local/bin/fish"),
+            Some(Shell::Fish)
+        ));
+        assert!(matches!(
+            detect_shell("C:\\Program Files\\PowerShell\\7\\pwsh.exe"),
+            Some(Shell::PowerShell)
+        ));
+        assert!(detect_shell("/bin/tcsh").is_none());
+        assert!(detect_shell("").is_none());
+    }
+
+    #[test]
+    fn install_targets_follow_xdg_dirs() {
+        let home = Path::new("/home/me");
+        let unset = |_: &str| None;
+        let xdg = |name: &str| Some(PathBuf::from(format!("/xdg/{}", name)));
+
+        let bash = install_target(Shell::Bash, home, unset);
+        assert_eq!(
+            bash.path,
+            Path::new("/home/me/.local/share/bash-completion/completions/lt")
+        );
+        assert_eq!(
+            install_target(Shell::Bash, home, xdg).path,
+            Path::new("/xdg/XDG_DATA_HOME/bash-completion/completions/lt")
+        );
+
+        let zsh = install_target(Shell::Zsh, home, unset);
+        assert_eq!(zsh.path, Path::new("/home/me/.zfunc/_lt"));
+        assert_eq!(zsh.rc_line.as_deref(), Some("source /home/me/.zfunc/_lt"));
+
+        let fish = install_target(Shell::Fish, home, xdg);
+        assert_eq!(
+            fish.path,
+            Path::new("/xdg/XDG_CONFIG_HOME/fish/completions/lt.fish")
+        );
+        assert!(fish.rc_line.is_none());
+    }
+
+    #[test]
+    fn scripts_call_the_completion_helper() {
+        for script in [BASH_DYNAMIC, ZSH_DYNAMIC, FISH_DYNAMIC] {
+            assert!(script.contains("lt __complete"));
+        }
+    }
+}
@@ -1,13 +1,15 @@
 //! config command - Get, set, or list configuration values
 
+use crate::cli::error::{CliError, CliResult};
 use crate::engine::gate::requirements;
 use crate::engine::scan::scan;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 
 /// Get a configuration value.
-pub fn get(ctx: &Context, key: &str) -> Result<()> {
+pub fn get(ctx: &Context, key: &str) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -15,8 +17,8 @@ pub fn get(ctx: &Context, key: &str) -> Result<()> {
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
     // Pre-flight gating check (READ_ONLY - just reading config)
-    crate::engine::runner::check_requirements(&git, &requirements::READ_ONLY)
-        .map_err(|bundle| anyhow::anyhow!("Repository needs repair: {}", bundle))?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::READ_ONLY)
+        .map_err(CliError::needs_repair)?;
 
     let snapshot = scan(&git).context("Failed to scan repository")?;
 
@@ -26,12 +28,20 @@ pub fn get(ctx: &Context, key: &str) -> Result<()> {
             .as_ref()
             .map(|t| t.to_string())
             .unwrap_or_default(),
+        "identity" => snapshot
+            .repo_config
+            .as_ref()
+            .and_then(|c| c.identity.clone())
+            .unwrap_or_default(),
         _ => {
             // Try to get from repo config
             if snapshot.repo_config.is_some() {
-                bail!("Unknown configuration key: {}", key)
+                return Err(CliError::usage(format!(
+                    "Unknown configuration key: {}",
+                    key
+                )));
             } else {
-                bail!("Repository not initialized. Run 'lattice init' first.");
+                return Err(CliError::not_initialized());
             }
         }
     };
@@ -46,12 +56,17 @@ pub fn get(ctx: &Context, key: &str) -> Result<()> {
 }
 
 /// Set a configuration value.
-pub fn set(ctx: &Context, key: &str, value: &str) -> Result<()> {
+pub fn set(ctx: &Context, key: &str, value: &str) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    // Pre-flight gating check (MINIMAL - writes config, so not in read-only mode)
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::MINIMAL)
+        .map_err(CliError::needs_repair)?;
+
     let git_dir = git.git_dir();
     let _config_path = git_dir.join("lattice/config.toml");
 
@@ -62,7 +77,7 @@ pub fn set(ctx: &Context, key: &str, value: &str) -> Result<()> {
     let mut config = config_result
         .config
         .repo
-        .ok_or_else(|| anyhow::anyhow!("Repository not initialized. Run 'lattice init' first."))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     // Set the value
     match key {
@@ -71,21 +86,33 @@ pub fn set(ctx: &Context, key: &str, value: &str) -> Result<()> {
             crate::core::types::BranchName::new(value).context("Invalid branch name")?;
             config.trunk = Some(value.to_string());
         }
-        _ => bail!("Unknown configuration key: {}", key),
+        "identity" => {
+            // An empty value removes the check
+            config.identity = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        }
+        _ => {
+            return Err(CliError::usage(format!(
+                "Unknown configuration key: {}",
+                key
+            )))
+        }
     }
 
     // Write config
     crate::core::config::Config::write_repo(git_dir, &config).context("Failed to write config")?;
 
     if !ctx.quiet {
-        println!("Set {} = {}", key, value);
+        println!(
+            "{}",
+            i18n::t_args("settings-set", &[("key", &key), ("value", &value)])
+        );
     }
 
     Ok(())
 }
 
 /// List all configuration values.
-pub fn list(ctx: &Context) -> Result<()> {
+pub fn list(ctx: &Context) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -93,8 +120,8 @@ pub fn list(ctx: &Context) -> Result<()> {
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
     // Pre-flight gating check (READ_ONLY - just reading config)
-    crate::engine::runner::check_requirements(&git, &requirements::READ_ONLY)
-        .map_err(|bundle| anyhow::anyhow!("Repository needs repair: {}", bundle))?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::READ_ONLY)
+        .map_err(CliError::needs_repair)?;
 
     let snapshot = scan(&git).context("Failed to scan repository")?;
 
@@ -107,8 +134,10 @@ pub fn list(ctx: &Context) -> Result<()> {
     }
 
     // Add other config values as they're implemented
-    if let Some(ref _config) = snapshot.repo_config {
-        // Future config values go here
+    if let Some(ref config) = snapshot.repo_config {
+        if let Some(ref identity) = config.identity {
+            println!("identity = {}", identity);
+        }
     }
 
     Ok(())
@@ -0,0 +1,257 @@
+//! cli::commands::conflict_summary
+//!
+//! Explain a conflict pause in stack terms.
+//!
+//! # Design
+//!
+//! Lattice captures the output of the git commands it runs, so when a plan
+//! stops on a conflict the user sees this summary instead of git's. It is
+//! built from the paused operation's journal and the repository state:
+//!
+//! - the stack branch being replayed and the parent it is being moved onto
+//! - the commit git stopped on (`REBASE_HEAD` and friends), with rebase
+//!   progress
+//! - the conflicted files, with "ours" and "theirs" named as branches: in a
+//!   rebase "ours" (HEAD) is the parent plus the commits replayed so far, and
+//!   "theirs" is the branch's own commit
+//! - the exact `lattice continue`, `lattice continue --skip`, and
+//!   `lattice abort` commands and what each one does
+//!
+//! Every command that can pause prints it after its own headline, as do
+//! `lattice continue` (when conflicts remain or a later step conflicts) and
+//! `lattice doctor --fix`.
+
+use crate::core::metadata::store::MetadataStore;
+use crate::core::ops::journal::{Journal, OpState};
+use crate::core::paths::LatticePaths;
+use crate::core::types::BranchName;
+use crate::git::{CommitInfo, ConflictedFile, Git, GitState};
+use crate::ui::hints::{self, Hint};
+use crate::ui::i18n;
+
+/// Everything the user needs to resolve a paused operation.
+#[derive(Debug, Clone)]
+pub struct ConflictSummary {
+    /// Lattice command that paused
+    pub command: String,
+    /// Stack branch being replayed
+    pub branch: String,
+    /// The branch's parent, when tracked
+    pub parent: Option<String>,
+    /// The in-progress git operation
+    pub git_state: GitState,
+    /// Commit git stopped on
+    pub commit: Option<CommitInfo>,
+    /// Paths with unresolved conflicts
+    pub files: Vec<ConflictedFile>,
+    /// Branches still to process after this one
+    pub remaining: Vec<String>,
+}
+
+impl ConflictSummary {
+    /// Gather the summary for the paused operation, if there is one.
+    ///
+    /// Returns `None` when no Lattice operation is paused or git has no
+    /// operation in progress.
+    pub fn gather(git: &Git) -> Option<Self> {
+        let git_state = git.state();
+        if !git_state.is_in_progress() {
+            return None;
+        }
+        let paths = LatticePaths::from_repo_info(&git.info().ok()?);
+        let op_state = OpState::read(&paths).ok()??;
+        let journal = Journal::read(&paths, &op_state.op_id).ok()?;
+        let branch = journal.paused_branch()?.to_string();
+
+        let parent = BranchName::new(&branch)
+            .ok()
+            .and_then(|name| MetadataStore::new(git).read(&name).ok().flatten())
+            .map(|entry| entry.metadata.parent.name().to_string());
+        let commit = git
+            .stopped_commit()
+            .ok()
+            .flatten()
+            .and_then(|oid| git.commit_info(&oid).ok());
+
+        Some(Self {
+            command: op_state.command.clone(),
+            parent,
+            git_state,
+            commit,
+            files: git.conflicted_files().unwrap_or_default(),
+            remaining: journal
+                .remaining_branches()
+                .iter()
+                .filter(|b| **b != branch)
+                .cloned()
+                .collect(),
+            branch,
+        })
+    }
+
+    /// Render the summary, one line per entry.
+    pub fn render(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        lines.push(match &self.parent {
+            Some(parent) => i18n::t_args(
+                "conflict-branch",
+                &[("branch", &self.branch), ("parent", parent)],
+            ),
+            None => i18n::t_args("conflict-branch-untracked", &[("branch", &self.branch)]),
+        });
+
+        let short = self.commit.as_ref().map(|c| c.oid.short(7).to_string());
+        if let (Some(commit), Some(short)) = (&self.commit, &short) {
+            lines.push(match self.git_state {
+                GitState::Rebase {
+                    current: Some(current),
+                    total: Some(total),
+                } => i18n::t_args(
+                    "conflict-commit-progress",
+                    &[
+                        ("commit", short),
+                        ("subject", &commit.summary),
+                        ("current", &current),
+                        ("total", &total),
+                    ],
+                ),
+                _ => i18n::t_args(
+                    "conflict-commit",
+                    &[("commit", short), ("subject", &commit.summary)],
+                ),
+            });
+        }
+
+        if !self.files.is_empty() {
+            lines.push(i18n::t("conflict-files"));
+            for file in &self.files {
+                lines.push(format!("  {}: {}", file.kind.label(), file.path));
+            }
+        }
+
+        let commit = short.as_deref().unwrap_or("?");
+        lines.push(match (&self.git_state, &self.parent) {
+            (GitState::Rebase { .. }, Some(parent)) => i18n::t_args(
+                "conflict-sides-rebase",
+                &[
+                    ("parent", parent),
+                    ("branch", &self.branch),
+                    ("commit", &commit),
+                ],
+            ),
+            _ => i18n::t_args(
+                "conflict-sides",
+                &[("branch", &self.branch), ("commit", &commit)],
+            ),
+        });
+
+        if !self.remaining.is_empty() {
+            lines.push(i18n::t_args(
+                "conflict-remaining",
+                &[("branches", &self.remaining.join(", "))],
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(i18n::t("conflict-next-steps"));
+        let mut options = vec![i18n::t_args(
+            "conflict-option-continue",
+            &[("command", &self.command)],
+        )];
+        if self.can_skip() {
+            options.push(i18n::t_args(
+                "conflict-option-skip",
+                &[("commit", &commit), ("branch", &self.branch)],
+            ));
+        }
+        options.push(i18n::t_args(
+            "conflict-option-abort",
+            &[("command", &self.command)],
+        ));
+        lines.extend(options.into_iter().map(|option| format!("  {}", option)));
+
+        lines
+    }
+
+    /// Whether `lattice continue --skip` can drop the stopped commit.
+    fn can_skip(&self) -> bool {
+        self.commit.is_some() && skip_args(&self.git_state).is_some()
+    }
+}
+
+/// The git command that skips the stopped commit, if the operation has one.
+pub fn skip_args(git_state: &GitState) -> Option<[&'static str; 2]> {
+    match git_state {
+        GitState::Rebase { .. } => Some(["rebase", "--skip"]),
+        GitState::CherryPick => Some(["cherry-pick", "--skip"]),
+        GitState::Revert => Some(["revert", "--skip"]),
+        GitState::ApplyMailbox => Some(["am", "--skip"]),
+        GitState::Merge | GitState::Bisect | GitState::Clean => None,
+    }
+}
+
+/// Print the summary for the paused operation, if there is one.
+pub fn print(git: &Git) {
+    if let Some(summary) = ConflictSummary::gather(git) {
+        println!();
+        for line in summary.render() {
+            println!("{}", line);
+        }
+    }
+    hints::show(Hint::Conflict, &[]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Oid;
+    use crate::git::ConflictKind;
+
+    fn summary(git_state: GitState) -> ConflictSummary {
+        ConflictSummary {
+            command: "restack".to_string(),
+            branch: "feature-b".to_string(),
+            parent: Some("feature-a".to_string()),
+            git_state,
+            commit: Some(CommitInfo {
+                oid: Oid::new("abcdef1234567890abcdef1234567890abcdef12").unwrap(),
+                summary: "Add b".to_string(),
+                message: "Add b\n".to_string(),
+                author_name: "Test User".to_string(),
+                author_email: "test@example.com".to_string(),
+                author_time: chrono::Utc::now(),
+            }),
+            files: vec![ConflictedFile {
+                path: "shared.txt".to_string(),
+                kind: ConflictKind::BothModified,
+            }],
+            remaining: vec!["feature-c".to_string()],
+        }
+    }
+
+    #[test]
+    fn rebase_names_sides_as_branches() {
+        let text = summary(GitState::Rebase {
+            current: Some(1),
+            total: Some(2),
+        })
+        .render()
+        .join("\n");
+
+        assert!(text.contains("feature-b (on feature-a)"), "{}", text);
+        assert!(text.contains("abcdef1 Add b (commit 1 of 2)"), "{}", text);
+        assert!(text.contains("both modified: shared.txt"), "{}", text);
+        assert!(text.contains("\"ours\" (HEAD) is 'feature-a'"), "{}", text);
+        assert!(text.contains("feature-c"), "{}", text);
+        assert!(text.contains("lattice continue --skip"), "{}", text);
+        assert!(text.contains("before 'lattice restack'"), "{}", text);
+    }
+
+    #[test]
+    fn merge_offers_no_skip() {
+        let text = summary(GitState::Merge).render().join("\n");
+        assert!(!text.contains("--skip"), "{}", text);
+        assert!(text.contains("lattice abort"), "{}", text);
+    }
+}
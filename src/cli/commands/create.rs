@@ -15,15 +15,32 @@
 //! 4. (If insert) WriteMetadataCas: Update child's parent reference
 //!
 //! Note: Interactive prompts and staging happen BEFORE the plan phase.
+//!
+//! # Issues
+//!
+//! With `--from-issue <N>`, the issue is fetched from the forge first. The
+//! branch is named `<N>-<slug of the title>` unless a name is given, and the
+//! issue link is stored in metadata so `lattice submit` can add `Closes #N`
+//! to the new PR's body.
+//!
+//! # Naming
+//!
+//! Names derived from a commit message or issue go through the configured
+//! `create.branch_template` (e.g. `{user}/{date}/{slug}`), if any. With
+//! `--slug-from-message` and no `--message`, the commit message is written
+//! in the editor first so the name can be derived from it.
 
 use std::io::{self, Write as IoWrite};
 use std::process::Command as StdCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::schema::{render_branch_template, BranchTemplateVars};
+use crate::core::config::Config;
 use crate::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeState, ParentInfo, PrState, Timestamps,
-    METADATA_KIND, SCHEMA_VERSION,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeState, IssueLink, ParentInfo, PrState,
+    Timestamps, METADATA_KIND, SCHEMA_VERSION,
 };
 use crate::core::ops::journal::OpId;
 use crate::core::types::BranchName;
@@ -33,7 +50,10 @@ use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
 use crate::engine::Context;
+use crate::forge::Issue;
 use crate::git::Git;
+use crate::ui::hints::{self, Hint};
+use crate::ui::i18n;
 
 /// Create a new tracked branch.
 ///
@@ -46,6 +66,10 @@ use crate::git::Git;
 /// * `update` - Stage modified tracked files before committing
 /// * `patch` - Interactive patch staging
 /// * `insert` - Insert between current branch and its child
+/// * `from_issue` - Forge issue to name the branch after and link to it
+/// * `slug_from_message` - Write the commit message in the editor first when
+///   `message` is not given, and name the branch after it
+#[allow(clippy::too_many_arguments)]
 pub fn create(
     ctx: &Context,
     name: Option<&str>,
@@ -54,7 +78,9 @@ pub fn create(
     update: bool,
     patch: bool,
     insert: bool,
-) -> Result<()> {
+    from_issue: Option<u64>,
+    slug_from_message: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -68,57 +94,98 @@ pub fn create(
     let trunk = preliminary_snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     // Get current branch (will be the parent)
     let parent = preliminary_snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
+    // Fetch the issue before anything is created
+    let issue = match from_issue {
+        Some(number) => Some(fetch_issue(&git, number)?),
+        None => None,
+    };
+
+    // Get the message to name the branch after before anything is created
+    let message = match message {
+        Some(msg) => Some(msg.to_string()),
+        None if slug_from_message => {
+            if !ctx.interactive {
+                return Err(CliError::usage(
+                    "--slug-from-message needs --message when not interactive",
+                ));
+            }
+            Some(edit_commit_message(&git)?)
+        }
+        None => None,
+    };
+    let message = message.as_deref();
+
+    // Derived names go through the configured template
+    let template = Config::load(Some(&cwd))
+        .ok()
+        .and_then(|r| r.config.create_branch_template().map(String::from));
+    let derive = |slug: String| -> CliResult<BranchName> {
+        let name = match &template {
+            Some(template) => render_branch_template(template, &branch_template_vars(&git, slug)?),
+            None => slug,
+        };
+        Ok(BranchName::new(&name)?)
+    };
+
     // Determine branch name (interactive prompt if needed - BEFORE plan)
     let branch_name = if let Some(n) = name {
         BranchName::new(n)?
+    } else if let Some(issue) = &issue {
+        derive(issue_branch_name(issue))?
     } else if let Some(msg) = message {
-        // Derive from message
-        let slug = slugify(msg);
-        BranchName::new(&slug)?
+        // Derive from message subject
+        derive(slugify(msg.lines().next().unwrap_or_default()))?
     } else if ctx.interactive {
         // Prompt for name
-        print!("Branch name: ");
+        print!("{} ", i18n::t("create-branch-name"));
         io::stdout().flush()?;
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
         let input = input.trim();
         if input.is_empty() {
-            anyhow::bail!("Branch name required");
+            return Err(CliError::usage("Branch name required"));
         }
         BranchName::new(input)?
     } else {
-        anyhow::bail!("Branch name required. Use --message to derive from commit message.");
+        return Err(CliError::usage(
+            "Branch name required. Use --message to derive from commit message.",
+        ));
     };
 
     // Check if branch already exists
     if preliminary_snapshot.branches.contains_key(&branch_name) {
-        anyhow::bail!("Branch '{}' already exists", branch_name);
+        return Err(CliError::usage(format!(
+            "Branch '{}' already exists",
+            branch_name
+        )));
     }
 
     // Handle insert mode - determine child to reparent (interactive if needed - BEFORE plan)
     let child_to_reparent = if insert {
         let children = preliminary_snapshot.graph.children(&parent);
         match children {
-            None => anyhow::bail!("No child branch to insert before"),
-            Some(kids) if kids.is_empty() => anyhow::bail!("No child branch to insert before"),
+            None => return Err(CliError::usage("No child branch to insert before")),
+            Some(kids) if kids.is_empty() => {
+                return Err(CliError::usage("No child branch to insert before"))
+            }
             Some(kids) if kids.len() == 1 => Some(kids.iter().next().unwrap().clone()),
             Some(kids) if ctx.interactive => {
                 // Prompt for selection
-                println!("Select child to insert before:");
+                println!("{}", i18n::t("create-select-child-insert"));
                 let kids_vec: Vec<_> = kids.iter().collect();
                 for (i, child) in kids_vec.iter().enumerate() {
                     println!("  {}. {}", i + 1, child);
                 }
-                print!("Enter number: ");
+                print!("{} ", i18n::t("create-enter-number"));
                 io::stdout().flush()?;
 
                 let mut input = String::new();
@@ -126,23 +193,23 @@ pub fn create(
                 let idx: usize = input
                     .trim()
                     .parse()
-                    .map_err(|_| anyhow::anyhow!("Invalid selection"))?;
+                    .map_err(|_| CliError::usage("Invalid selection"))?;
                 let idx = idx.saturating_sub(1);
 
                 if idx >= kids_vec.len() {
-                    anyhow::bail!("Invalid selection");
+                    return Err(CliError::usage("Invalid selection"));
                 }
 
                 Some(kids_vec[idx].clone())
             }
             Some(kids) => {
-                anyhow::bail!(
+                return Err(CliError::usage(format!(
                     "Multiple children. Run interactively to select: {}",
                     kids.iter()
                         .map(|b| b.to_string())
                         .collect::<Vec<_>>()
                         .join(", ")
-                );
+                )));
             }
         }
     } else {
@@ -158,7 +225,7 @@ pub fn create(
             .context("Failed to run git add")?;
 
         if !status.success() {
-            anyhow::bail!("git add failed");
+            return Err(CliError::git("git add failed"));
         }
     } else if update {
         let status = StdCommand::new("git")
@@ -168,7 +235,7 @@ pub fn create(
             .context("Failed to run git add")?;
 
         if !status.success() {
-            anyhow::bail!("git add failed");
+            return Err(CliError::git("git add failed"));
         }
     } else if patch {
         let status = StdCommand::new("git")
@@ -178,7 +245,7 @@ pub fn create(
             .context("Failed to run git add")?;
 
         if !status.success() {
-            anyhow::bail!("git add failed");
+            return Err(CliError::git("git add failed"));
         }
     }
 
@@ -195,7 +262,7 @@ pub fn create(
 
     // If staged but no message in non-interactive mode, warn
     if has_staged && message.is_none() && !ctx.interactive && !ctx.quiet {
-        println!("Staged changes exist. Use --message or run interactively to commit.");
+        println!("{}", i18n::t("create-staged-changes-exist"));
     }
 
     // If interactive with staged changes but no message, we'll do interactive commit
@@ -210,14 +277,13 @@ pub fn create(
         interactive_commit,
         child_to_reparent: child_to_reparent.clone(),
         verify: ctx.verify,
+        issue: issue.as_ref().map(|issue| IssueLink {
+            number: issue.number,
+            url: issue.url.clone(),
+        }),
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(()) => {
@@ -229,23 +295,125 @@ pub fn create(
                     .map(|o| &o.as_str()[..7])
                     .unwrap_or("unknown");
                 println!(
-                    "Created '{}' with parent '{}' (base: {})",
-                    branch_name, parent, parent_tip
+                    "{}",
+                    i18n::t_args(
+                        "create-created-parent-base",
+                        &[
+                            ("branch_name", &branch_name),
+                            ("parent", &parent),
+                            ("parent_tip", &parent_tip),
+                        ],
+                    )
                 );
                 if let Some(child) = child_to_reparent {
-                    println!("Reparented '{}' under '{}'", child, branch_name);
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "create-reparented-under",
+                            &[("child", &child), ("branch_name", &branch_name)],
+                        )
+                    );
+                }
+                if let Some(issue) = &issue {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "create-linked-issue",
+                            &[("number", &issue.number), ("title", &issue.title)],
+                        )
+                    );
                 }
             }
+            hints::show(Hint::Create, &[("branch", &branch_name)]);
             Ok(())
         }
         CommandOutput::Paused { message } => {
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
     }
 }
 
+/// Fetch issue `number` from the repository's forge.
+fn fetch_issue(git: &Git, number: u64) -> CliResult<Issue> {
+    let forge = super::origin_forge(git)?;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    Ok(rt
+        .block_on(forge.get_issue(number))
+        .with_context(|| format!("Failed to fetch issue #{}", number))?)
+}
+
+/// Branch name for an issue: its number, then its slugified title.
+fn issue_branch_name(issue: &Issue) -> String {
+    let slug = slugify(&issue.title);
+    if slug.is_empty() {
+        format!("issue-{}", issue.number)
+    } else {
+        format!("{}-{}", issue.number, slug)
+    }
+}
+
+/// Gather template values for `slug` from the repository and the clock.
+fn branch_template_vars(git: &Git, slug: String) -> CliResult<BranchTemplateVars> {
+    let user = git
+        .user_email()
+        .map(|email| slugify(email.split('@').next().unwrap_or_default()))
+        .filter(|user| !user.is_empty());
+    Ok(BranchTemplateVars {
+        slug,
+        user,
+        date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+    })
+}
+
+/// Let the user write the commit message in their editor
+/// (`create --slug-from-message`).
+///
+/// Lines starting with `#` are dropped, as git does.
+fn edit_commit_message(git: &Git) -> CliResult<String> {
+    let path = git.info()?.git_dir.join("LATTICE_CREATE_MSG");
+    std::fs::write(
+        &path,
+        "\n# Write the commit message. The new branch is named after its first line.\n",
+    )
+    .context("Failed to write message file")?;
+
+    let editor = std::env::var("LATTICE_TEST_EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let status = StdCommand::new(&editor)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to open editor '{}'", editor));
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    if !status?.success() {
+        return Err(CliError::failed(
+            "Editor exited with error; no branch was created",
+        ));
+    }
+    let edited = edited.context("Failed to read commit message")?;
+    let message = edited
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let message = message.trim();
+    if message.is_empty() {
+        return Err(CliError::usage(
+            "Empty commit message; no branch was created",
+        ));
+    }
+    Ok(message.to_string())
+}
+
 /// Convert a string to a branch-name-safe slug.
 pub fn slugify(s: &str) -> String {
     s.chars()
@@ -282,6 +450,8 @@ pub struct CreateCommand {
     child_to_reparent: Option<BranchName>,
     /// Whether to run git hooks.
     verify: bool,
+    /// Issue the branch was created from.
+    issue: Option<IssueLink>,
 }
 
 impl Command for CreateCommand {
@@ -359,7 +529,7 @@ impl Command for CreateCommand {
         };
 
         let now = crate::core::types::UtcTimestamp::now();
-        let metadata = BranchMetadataV1 {
+        let metadata = BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -375,6 +545,14 @@ impl Command for CreateCommand {
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: self.issue.clone(),
+            provenance: None,
+            ci: None,
         };
 
         plan = plan.with_step(PlanStep::WriteMetadataCas {
@@ -412,3 +590,25 @@ impl Command for CreateCommand {
 }
 
 impl SimpleCommand for CreateCommand {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(number: u64, title: &str) -> Issue {
+        Issue {
+            number,
+            url: format!("https://github.com/org/repo/issues/{}", number),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn issue_branch_name_uses_number_and_title() {
+        assert_eq!(
+            issue_branch_name(&issue(123, "Login fails on Safari!")),
+            "123-login-fails-on-safari"
+        );
+        assert_eq!(issue_branch_name(&issue(9, "???")), "issue-9");
+    }
+}
@@ -0,0 +1,218 @@
+//! cli::commands::debug_cmd
+//!
+//! Inspect and reset the caches under `<common_dir>/lattice/cache/`.
+//!
+//! # Design
+//!
+//! `lattice debug cache-stats` reports, for each cache, how many entries it
+//! holds, the size of its file, and how often it answered a lookup:
+//!
+//! - `ancestry`: merge bases, is-ancestor checks, and commit counts
+//!   ([`crate::git::ancestry_cache`]), sized by `[cache] ancestry_entries`
+//! - `forge-status`: PR and check status for navigation prefetches
+//!   ([`crate::forge::status_cache`]), refreshed per `[prefetch]
+//!   interval_secs`
+//! - `authorization`: GitHub App installation checks
+//!   ([`crate::auth::cache`]), which don't count lookups
+//!
+//! A low ancestry hit rate with a full cache means the limit is too small
+//! for the repository; a low forge-status hit rate means the interval is
+//! shorter than the time spent on a branch.
+//!
+//! `lattice debug clear-cache` deletes the cache directory. Every cache is a
+//! hint, so this only costs the lookups needed to fill them again. Neither
+//! command needs an initialized repository or touches refs, so both run in
+//! read-only mode.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use serde::Serialize;
+
+use crate::auth::cache::AuthCache;
+use crate::cli::error::CliResult;
+use crate::core::cache_stats::HitStats;
+use crate::core::config::Config;
+use crate::core::paths::LatticePaths;
+use crate::engine::Context;
+use crate::forge::status_cache::ForgeStatusCache;
+use crate::git::ancestry_cache::AncestryCache;
+use crate::git::Git;
+
+/// One cache in the `cache-stats` report.
+#[derive(Debug, Serialize)]
+struct CacheReport {
+    name: &'static str,
+    path: PathBuf,
+    entries: usize,
+    /// Size of the cache file, 0 when it doesn't exist
+    bytes: u64,
+    /// `None` for caches that don't count lookups
+    hits: Option<u64>,
+    misses: Option<u64>,
+    hit_rate: Option<f64>,
+}
+
+impl CacheReport {
+    fn new(name: &'static str, path: PathBuf, entries: usize, stats: Option<HitStats>) -> Self {
+        let bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Self {
+            name,
+            path,
+            entries,
+            bytes,
+            hits: stats.map(|s| s.hits),
+            misses: stats.map(|s| s.misses),
+            hit_rate: stats.and_then(|s| s.hit_rate()),
+        }
+    }
+}
+
+/// Open the repository at the context's directory and find its caches.
+fn open(ctx: &Context) -> CliResult<(PathBuf, LatticePaths)> {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    Ok((cwd, paths))
+}
+
+/// Run `lattice debug cache-stats`.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `json` - Emit a JSON report instead of a table
+pub fn cache_stats(ctx: &Context, json: bool) -> CliResult {
+    let (cwd, paths) = open(ctx)?;
+
+    let ancestry = AncestryCache::load(&paths);
+    let forge = ForgeStatusCache::load(&paths);
+    let auth = AuthCache::load(&paths);
+    let caches = [
+        CacheReport::new(
+            "ancestry",
+            AncestryCache::cache_path(&paths),
+            ancestry.len(),
+            Some(ancestry.stats()),
+        ),
+        CacheReport::new(
+            "forge-status",
+            ForgeStatusCache::cache_path(&paths),
+            forge.len(),
+            Some(forge.stats()),
+        ),
+        CacheReport::new(
+            "authorization",
+            AuthCache::cache_path(&paths),
+            auth.len(),
+            None,
+        ),
+    ];
+
+    if json {
+        super::print_json(&serde_json::json!({ "caches": caches }))?;
+        return Ok(());
+    }
+
+    println!(
+        "{:<14} {:>8} {:>10} {:>8} {:>8} {:>9}",
+        "Cache", "Entries", "Size", "Hits", "Misses", "Hit rate"
+    );
+    let count = |n: Option<u64>| n.map_or_else(|| "-".to_string(), |n| n.to_string());
+    for cache in &caches {
+        println!(
+            "{:<14} {:>8} {:>10} {:>8} {:>8} {:>9}",
+            cache.name,
+            cache.entries,
+            format_size(cache.bytes),
+            count(cache.hits),
+            count(cache.misses),
+            cache
+                .hit_rate
+                .map_or_else(|| "-".to_string(), |r| format!("{:.1}%", r * 100.0)),
+        );
+    }
+
+    let config = Config::load(Some(&cwd))
+        .map(|r| r.config)
+        .unwrap_or_default();
+    println!();
+    println!(
+        "Limits: [cache] ancestry_entries = {}, [prefetch] interval_secs = {}",
+        config.ancestry_cache_entries(),
+        config.prefetch_interval_secs()
+    );
+    Ok(())
+}
+
+/// Run `lattice debug clear-cache`.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+pub fn clear_cache(ctx: &Context) -> CliResult {
+    let (_, paths) = open(ctx)?;
+    let dir = paths.repo_cache_dir();
+
+    let (files, bytes) = dir_usage(&dir);
+    if files == 0 {
+        if !ctx.quiet {
+            println!("No cached data to clear.");
+        }
+        return Ok(());
+    }
+    fs::remove_dir_all(&dir).with_context(|| format!("Failed to remove {}", dir.display()))?;
+    if !ctx.quiet {
+        println!(
+            "Cleared {} cache file{} ({}) from {}",
+            files,
+            if files == 1 { "" } else { "s" },
+            format_size(bytes),
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
+/// Count the files directly in `dir` and their total size.
+fn dir_usage(dir: &Path) -> (usize, u64) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (0, 0);
+    };
+    entries
+        .filter_map(|e| e.ok()?.metadata().ok())
+        .filter(|m| m.is_file())
+        .fold((0, 0), |(files, bytes), m| (files + 1, bytes + m.len()))
+}
+
+/// Format a byte count for people, e.g. `96.3 KiB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_use_binary_units() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(98_611), "96.3 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+}
@@ -19,7 +19,9 @@
 //!
 //! - Deletes local branch and metadata
 //! - Re-parents children to deleted branch's parent
-//! - Does not close PRs or delete remote branches
+//! - Does not delete remote branches
+//! - Closes PRs only for branches marked merged-empty by restack; the close
+//!   is queued and applied by the next `lattice sync`
 //! - --upstack deletes descendants too
 //! - --downstack deletes ancestors (never trunk)
 //!
@@ -29,13 +31,14 @@
 //! - Must re-parent children before deleting
 //! - Metadata updated only after refs succeed
 
-use std::io::{self, Write};
-
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
 use crate::cli::commands::restack::get_ancestors_inclusive;
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
+use crate::core::ops::pending::{PendingAction, PendingQueue};
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::command::{Command, CommandOutput, SimpleCommand};
 use crate::engine::exec::ExecuteResult;
@@ -45,6 +48,8 @@ use crate::engine::runner::run_command;
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
 
 /// Delete a branch.
 ///
@@ -61,7 +66,7 @@ pub fn delete(
     upstack: bool,
     downstack: bool,
     force: bool,
-) -> Result<()> {
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -80,7 +85,7 @@ pub fn delete(
     let trunk = preliminary_snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     // Resolve target branch
     let target = if let Some(ref name) = target_name {
@@ -88,20 +93,17 @@ pub fn delete(
     } else if let Some(ref current) = preliminary_snapshot.current_branch {
         current.clone()
     } else {
-        anyhow::bail!("Not on any branch and no branch specified");
+        return Err(CliError::usage("Not on any branch and no branch specified"));
     };
 
     // Cannot delete trunk
     if &target == trunk {
-        anyhow::bail!("Cannot delete trunk branch");
+        return Err(CliError::usage("Cannot delete trunk branch"));
     }
 
     // Check if tracked
     if !preliminary_snapshot.metadata.contains_key(&target) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'git branch -d' for untracked branches.",
-            target
-        );
+        return Err(CliError::untracked(&target).with_hint(i18n::t("hint-delete-untracked")));
     }
 
     // Determine branches to delete based on flags
@@ -112,17 +114,20 @@ pub fn delete(
     for branch_name in &to_delete {
         if let Some(meta) = preliminary_snapshot.metadata.get(branch_name) {
             if meta.metadata.freeze.is_frozen() {
-                anyhow::bail!(
+                return Err(CliError::conflict(format!(
                     "Branch '{}' is frozen. Unfreeze it first with 'lattice unfreeze'.",
                     branch_name
-                );
+                )));
             }
         }
     }
 
     // Show what will be deleted
     if !ctx.quiet {
-        println!("Will delete {} branch(es):", to_delete.len());
+        println!(
+            "{}",
+            i18n::t_args("deletion-will-delete", &[("count", &to_delete.len())])
+        );
         for b in &to_delete {
             println!("  - {}", b);
         }
@@ -130,46 +135,74 @@ pub fn delete(
 
     // Confirm unless --force (interactive confirmation BEFORE command lifecycle)
     if !force && ctx.interactive {
-        print!("Continue? [y/N] ");
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("Aborted.");
+        let confirmed = prompts::confirm(
+            &i18n::t("prompt-continue"),
+            Severity::Destructive,
+            ctx.interactive,
+        )?;
+        if !confirmed {
+            println!("{}", i18n::t("prompt-aborted"));
             return Ok(());
         }
     }
 
+    // PRs of merged-empty branches have nothing left to review
+    let prs_to_close = merged_empty_prs(&to_delete, &preliminary_snapshot);
+
     let cmd = DeleteCommand {
         target: target.clone(),
         upstack,
         downstack,
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(()) => {
             if !ctx.quiet {
-                println!("Delete complete. Removed {} branch(es).", to_delete.len());
+                println!(
+                    "{}",
+                    i18n::t_args("deletion-complete", &[("count", &to_delete.len())])
+                );
+            }
+            if !prs_to_close.is_empty() {
+                let paths = LatticePaths::from_repo_info(&git.info()?);
+                let mut queue =
+                    PendingQueue::load(&paths).context("Failed to read pending actions")?;
+                for number in &prs_to_close {
+                    queue.enqueue(PendingAction::ClosePr { number: *number });
+                    if !ctx.quiet {
+                        println!(
+                            "{}",
+                            i18n::t_args("deletion-pr-closed-next", &[("number", &number)])
+                        );
+                    }
+                }
+                queue
+                    .save(&paths)
+                    .context("Failed to save pending actions")?;
             }
             Ok(())
         }
         CommandOutput::Paused { message } => {
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
     }
 }
 
+/// PR numbers linked to branches that restack marked as merged-empty.
+fn merged_empty_prs(to_delete: &[BranchName], snapshot: &RepoSnapshot) -> Vec<u64> {
+    to_delete
+        .iter()
+        .filter_map(|b| snapshot.metadata.get(b))
+        .filter(|scanned| scanned.metadata.merged_empty.is_some())
+        .filter_map(|scanned| scanned.metadata.pr.number())
+        .collect()
+}
+
 /// Compute which branches to delete based on flags.
 fn compute_branches_to_delete(
     target: &BranchName,
@@ -245,9 +278,7 @@ impl Command for DeleteCommand {
         let snapshot = &ctx.snapshot;
 
         // Get trunk
-        let trunk = snapshot
-            .trunk()
-            .ok_or_else(|| PlanError::MissingData("trunk not configured".to_string()))?;
+        let trunk = snapshot.trunk().ok_or(PlanError::TrunkNotConfigured)?;
 
         // Cannot delete trunk
         if &self.target == trunk {
@@ -258,10 +289,7 @@ impl Command for DeleteCommand {
 
         // Check if tracked
         if !snapshot.metadata.contains_key(&self.target) {
-            return Err(PlanError::InvalidState(format!(
-                "Branch '{}' is not tracked",
-                self.target
-            )));
+            return Err(PlanError::UntrackedBranch(self.target.to_string()));
         }
 
         // Compute branches to delete
@@ -0,0 +1,960 @@
+//! edit command - Stack-aware interactive rebase
+//!
+//! Per SPEC.md 8D.13:
+//!
+//! - Opens editor with the commits of the current branch, or with `--stack`
+//!   of every branch in the current stack, oldest first, each branch's
+//!   commits under a `branch <name>` line
+//! - Each commit line starts with `pick`, `reword` (the rest of the line
+//!   becomes the new subject), `fixup` (meld into the commit above it in
+//!   the same branch), or `drop`; deleting a line also drops the commit
+//! - Commit lines may be moved within a branch or across `branch` lines,
+//!   which moves the commit to the other branch
+//! - Validates the result: the same branches in the same order, only
+//!   commits from the todo, no duplicates
+//! - Compiles it into one `git rebase -i` per branch, from the lowest
+//!   branch that changed upward, each fed its own part of the todo
+//! - Restacks descendants outside the edited branches (`--no-restack` or
+//!   `restack.descendants = false` defers them)
+//! - Journals the rebases as one operation; conflicts pause
+//!
+//! # Integrity Contract
+//!
+//! - Must never rewrite frozen branches
+//! - Must validate edit result
+//! - Metadata updated only after refs succeed
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command as ProcessCommand;
+
+use anyhow::Context as _;
+
+use crate::cli::commands::phase3_helpers::format_restack_preview;
+use crate::cli::commands::restack::{
+    get_ancestors_inclusive, get_descendants_inclusive, topological_sort,
+};
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::config::Config;
+use crate::core::metadata::schema::{BaseInfo, BranchMetadataV2};
+use crate::core::ops::journal::{OpId, OpState};
+use crate::core::paths::LatticePaths;
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
+use crate::engine::command::{Command, CommandOutput};
+use crate::engine::exec::ExecuteResult;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::runner::run_command;
+use crate::engine::scan::{scan, RepoSnapshot};
+use crate::engine::Context;
+use crate::git::{CommitInfo, Git};
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
+
+/// Result of edit command
+#[derive(Debug)]
+pub struct EditResult {
+    /// Branches whose commits were rewritten
+    pub edited: Vec<BranchName>,
+    /// Descendants that were restacked
+    pub restacked: Vec<BranchName>,
+}
+
+/// What to do with a commit in the todo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TodoAction {
+    /// Keep the commit as is
+    Pick,
+    /// Keep the commit with a new subject line
+    Reword(String),
+    /// Meld the commit into the one before it, keeping that one's message
+    Fixup,
+}
+
+/// One kept commit of the edited todo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TodoLine {
+    action: TodoAction,
+    commit: Oid,
+}
+
+/// A branch and the commits it ends up with, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BranchTodo {
+    branch: BranchName,
+    lines: Vec<TodoLine>,
+}
+
+/// A branch in the edited range, as it is now.
+struct StackBranch {
+    branch: BranchName,
+    /// Commits after the base, oldest first
+    commits: Vec<CommitInfo>,
+    /// Base OID
+    base: String,
+    /// Current metadata ref OID for CAS
+    metadata_ref_oid: Oid,
+    /// Current metadata
+    metadata: BranchMetadataV2,
+}
+
+impl StackBranch {
+    /// The todo that leaves the branch unchanged.
+    fn unchanged(&self) -> BranchTodo {
+        BranchTodo {
+            branch: self.branch.clone(),
+            lines: self
+                .commits
+                .iter()
+                .map(|c| TodoLine {
+                    action: TodoAction::Pick,
+                    commit: c.oid.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One rebase of the compiled edit.
+pub struct BranchRebase {
+    /// Branch being rebased
+    pub branch: BranchName,
+    /// What to rebase onto: the base OID for the lowest edited branch, the
+    /// parent's name for branches above it
+    pub onto: String,
+    /// Current base OID
+    pub old_base: String,
+    /// Todo file fed to `git rebase -i`, or `None` for a plain restack
+    pub todo_file: Option<PathBuf>,
+    /// Current metadata ref OID for CAS
+    pub metadata_ref_oid: Oid,
+    /// Current metadata
+    pub metadata: BranchMetadataV2,
+}
+
+/// Pre-computed data for edit command
+pub struct EditPrecomputed {
+    /// Rebases of the edited branches, bottom-up
+    pub edits: Vec<BranchRebase>,
+    /// Restacks of descendants outside the edited branches, parents first
+    pub descendants_to_restack: Vec<BranchRebase>,
+    /// Branch to check out once the rebases are done
+    pub return_to: BranchName,
+}
+
+/// Edit command implementing Command trait
+pub struct EditCommand {
+    /// Precomputed data
+    precomputed: EditPrecomputed,
+    /// Whether to run git hooks
+    verify: bool,
+}
+
+impl EditCommand {
+    /// Plan steps for one rebase and its metadata update.
+    fn rebase_steps(&self, mut plan: Plan, rebase: &BranchRebase) -> Plan {
+        plan = plan.with_step(PlanStep::Checkpoint {
+            name: format!("before-edit-{}", rebase.branch),
+        });
+
+        let mut args = Vec::new();
+        if let Some(todo) = &rebase.todo_file {
+            // git hands the todo path to the sequence editor, which
+            // replaces it with the compiled todo
+            args.extend([
+                "-c".to_string(),
+                format!(
+                    "sequence.editor=cp {}",
+                    shell_quote(&todo.display().to_string())
+                ),
+                "rebase".to_string(),
+                "-i".to_string(),
+            ]);
+        } else {
+            args.push("rebase".to_string());
+        }
+        if !self.verify {
+            args.push("--no-verify".to_string());
+        }
+        args.extend([
+            "--onto".to_string(),
+            rebase.onto.clone(),
+            rebase.old_base.clone(),
+            rebase.branch.to_string(),
+        ]);
+
+        plan = plan.with_step(PlanStep::RunGit {
+            args,
+            description: if rebase.todo_file.is_some() {
+                format!("Rewrite {}", rebase.branch)
+            } else {
+                format!("Rebase {} onto {}", rebase.branch, rebase.onto)
+            },
+            expected_effects: vec![format!("refs/heads/{}", rebase.branch)],
+        });
+
+        plan = plan.with_step(PlanStep::PotentialConflictPause {
+            branch: rebase.branch.to_string(),
+            git_operation: "rebase".to_string(),
+        });
+
+        let mut updated_metadata = rebase.metadata.clone();
+        updated_metadata.base = BaseInfo {
+            oid: rebase.onto.clone(),
+        };
+        updated_metadata.timestamps.updated_at = UtcTimestamp::now();
+
+        plan.with_step(PlanStep::WriteMetadataCas {
+            branch: rebase.branch.to_string(),
+            old_ref_oid: Some(rebase.metadata_ref_oid.to_string()),
+            metadata: Box::new(updated_metadata),
+        })
+    }
+}
+
+impl Command for EditCommand {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING;
+    type Output = EditResult;
+
+    fn plan(&self, _ctx: &ReadyContext) -> Result<Plan, PlanError> {
+        let pre = &self.precomputed;
+        let mut plan = Plan::new(OpId::new(), "edit");
+
+        for rebase in pre.edits.iter().chain(&pre.descendants_to_restack) {
+            plan = self.rebase_steps(plan, rebase);
+        }
+
+        // Each rebase leaves its branch checked out
+        plan = plan.with_step(PlanStep::Checkout {
+            branch: pre.return_to.to_string(),
+            reason: format!("Return to {}", pre.return_to),
+        });
+
+        Ok(plan)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        match result {
+            ExecuteResult::Success { .. } => CommandOutput::Success(EditResult {
+                edited: self
+                    .precomputed
+                    .edits
+                    .iter()
+                    .map(|r| r.branch.clone())
+                    .collect(),
+                restacked: self
+                    .precomputed
+                    .descendants_to_restack
+                    .iter()
+                    .map(|r| r.branch.clone())
+                    .collect(),
+            }),
+            ExecuteResult::Paused {
+                branch, git_state, ..
+            } => CommandOutput::Paused {
+                message: format!(
+                    "Conflict while editing '{}' ({}).",
+                    branch,
+                    git_state.description()
+                ),
+            },
+            ExecuteResult::Aborted { error, .. } => CommandOutput::Failed { error },
+        }
+    }
+}
+
+/// Render the todo the editor opens with.
+fn render_todo(stack: &[StackBranch]) -> String {
+    let mut out = String::from(
+        "# Edit commits, oldest first. Lines starting with # are ignored.\n\
+         #\n\
+         # Commands:\n\
+         #   pick <commit>            keep the commit\n\
+         #   reword <commit> <text>   keep the commit, with <text> as its subject\n\
+         #   fixup <commit>           meld into the commit above it in the same branch\n\
+         #   drop <commit>            remove the commit (deleting the line also does)\n\
+         #\n\
+         # Move commit lines to reorder commits, or past a 'branch' line to move\n\
+         # them to that branch. Do not add, remove, or reorder 'branch' lines.\n",
+    );
+    for entry in stack {
+        out.push_str(&format!("\nbranch {}\n", entry.branch));
+        for commit in &entry.commits {
+            out.push_str(&format!(
+                "pick {} {}\n",
+                commit.oid.short(7),
+                commit.summary
+            ));
+        }
+    }
+    out
+}
+
+/// Parse the edited todo against the branches it was rendered from.
+fn parse_todo(text: &str, stack: &[StackBranch]) -> CliResult<Vec<BranchTodo>> {
+    let commits: Vec<&Oid> = stack
+        .iter()
+        .flat_map(|entry| entry.commits.iter().map(|c| &c.oid))
+        .collect();
+    let mut todos: Vec<BranchTodo> = Vec::new();
+    let mut seen = HashSet::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+
+        if command == "branch" {
+            let expected = stack.get(todos.len()).ok_or_else(|| {
+                CliError::usage(format!("Invalid edit: unexpected 'branch {}' line", rest))
+            })?;
+            if rest != expected.branch.as_str() {
+                return Err(CliError::usage(format!("Invalid edit: expected 'branch {}', found 'branch {}'. Do not add, remove, or reorder branch lines.",
+                    expected.branch,
+                    rest)));
+            }
+            todos.push(BranchTodo {
+                branch: expected.branch.clone(),
+                lines: Vec::new(),
+            });
+            continue;
+        }
+
+        let (id, text) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        // `None` drops the commit
+        let action = match command {
+            "pick" | "p" => Some(TodoAction::Pick),
+            "reword" | "r" => {
+                if text.trim().is_empty() {
+                    return Err(CliError::usage(format!(
+                        "Invalid edit: 'reword {}' needs a subject",
+                        id
+                    )));
+                }
+                Some(TodoAction::Reword(text.trim().to_string()))
+            }
+            "fixup" | "f" => Some(TodoAction::Fixup),
+            "drop" | "d" => None,
+            other => {
+                return Err(CliError::usage(format!(
+                    "Invalid edit: unknown command '{}'",
+                    other
+                )))
+            }
+        };
+        if id.is_empty() {
+            return Err(CliError::usage(format!(
+                "Invalid edit: '{}' needs a commit",
+                command
+            )));
+        }
+
+        let mut matches = commits.iter().filter(|oid| oid.as_str().starts_with(id));
+        let commit = match (matches.next(), matches.next()) {
+            (Some(oid), None) => (*oid).clone(),
+            (Some(_), Some(_)) => {
+                return Err(CliError::usage(format!(
+                    "Invalid edit: commit '{}' is ambiguous",
+                    id
+                )))
+            }
+            (None, _) => {
+                return Err(CliError::usage(format!(
+                    "Invalid edit: commit '{}' is not in the todo",
+                    id
+                )))
+            }
+        };
+        if !seen.insert(commit.clone()) {
+            return Err(CliError::usage(format!(
+                "Invalid edit: commit '{}' is listed more than once",
+                id
+            )));
+        }
+
+        let todo = todos.last_mut().ok_or_else(|| {
+            CliError::usage(format!(
+                "Invalid edit: commit '{}' is above the first branch line",
+                id
+            ))
+        })?;
+        let Some(action) = action else {
+            continue;
+        };
+        if action == TodoAction::Fixup && todo.lines.is_empty() {
+            return Err(CliError::usage(format!(
+                "Invalid edit: 'fixup {}' must follow a commit in branch '{}'",
+                id, todo.branch
+            )));
+        }
+        todo.lines.push(TodoLine { action, commit });
+    }
+
+    if todos.len() != stack.len() {
+        return Err(CliError::usage(format!(
+            "Invalid edit: missing 'branch {}' line. Do not add, remove, or reorder branch lines.",
+            stack[todos.len()].branch
+        )));
+    }
+    Ok(todos)
+}
+
+/// Compile one branch's todo into the todo `git rebase -i` runs.
+///
+/// A reword picks the commit, then amends its message from a file written
+/// to `dir`, keeping the body of the original message.
+fn compile_git_todo(
+    todo: &BranchTodo,
+    messages: &HashMap<Oid, &CommitInfo>,
+    dir: &Path,
+    verify: bool,
+) -> CliResult<String> {
+    if todo.lines.is_empty() {
+        // git refuses an empty todo; a noop leaves the branch at its new base
+        return Ok("noop\n".to_string());
+    }
+    let mut out = String::new();
+    for line in &todo.lines {
+        match &line.action {
+            TodoAction::Pick => out.push_str(&format!("pick {}\n", line.commit)),
+            TodoAction::Fixup => out.push_str(&format!("fixup {}\n", line.commit)),
+            TodoAction::Reword(subject) => {
+                let body = messages
+                    .get(&line.commit)
+                    .and_then(|c| c.message.split_once('\n'))
+                    .map(|(_, body)| body.trim())
+                    .unwrap_or("");
+                let message = if body.is_empty() {
+                    format!("{}\n", subject)
+                } else {
+                    format!("{}\n\n{}\n", subject, body)
+                };
+                let path = dir.join(format!("{}.msg", line.commit));
+                fs::write(&path, message).context("Failed to write commit message")?;
+                out.push_str(&format!(
+                    "pick {}\nexec git commit --amend --only --allow-empty{} -F {}\n",
+                    line.commit,
+                    if verify { "" } else { " --no-verify" },
+                    shell_quote(&path.display().to_string())
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Quote `s` for the shell git runs editors and `exec` lines with.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// The branches `edit` rewrites: the current branch, or with `stack` its
+/// tracked ancestors and the descendants up to the first fork.
+fn edit_range(current: &BranchName, snapshot: &RepoSnapshot, stack: bool) -> Vec<BranchName> {
+    if !stack {
+        return vec![current.clone()];
+    }
+    let trunk = snapshot.trunk.as_ref();
+    let mut range: Vec<BranchName> = get_ancestors_inclusive(current, snapshot)
+        .into_iter()
+        .filter(|b| Some(b) != trunk)
+        .collect();
+    let mut top = current.clone();
+    while let Some(children) = snapshot.graph.children(&top) {
+        let tracked: Vec<_> = children
+            .iter()
+            .filter(|c| snapshot.metadata.contains_key(*c))
+            .collect();
+        let [only] = tracked.as_slice() else {
+            break;
+        };
+        top = (*only).clone();
+        range.push(top.clone());
+    }
+    range
+}
+
+/// Edit the commits of the current branch or stack in one todo.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `stack` - Edit every branch in the current stack, not just the current one
+/// * `no_restack` - Leave descendants outside the edit for a later `lattice restack`
+pub fn edit(ctx: &Context, stack: bool, no_restack: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+
+    // A paused edit still reads its todo and message files
+    if OpState::exists(&paths) {
+        return Err(CliError::new(
+            ErrorCategory::OperationInProgress,
+            "An operation is in progress. Run 'lattice continue' or 'lattice abort' first.",
+        ));
+    }
+
+    // =========================================================================
+    // PRE-PLAN: Editor interaction (must happen before unified lifecycle)
+    // =========================================================================
+
+    let snapshot = scan(&git).context("Failed to scan repository")?;
+
+    if snapshot.trunk.is_none() {
+        return Err(CliError::not_initialized());
+    }
+
+    let current = snapshot
+        .current_branch
+        .as_ref()
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
+        .clone();
+
+    if !snapshot.metadata.contains_key(&current) {
+        return Err(CliError::untracked(&current));
+    }
+
+    let mut range = Vec::new();
+    for branch in edit_range(&current, &snapshot, stack) {
+        let scanned = snapshot
+            .metadata
+            .get(&branch)
+            .ok_or_else(|| CliError::untracked(&branch))?;
+        if scanned.metadata.freeze.is_frozen() {
+            return Err(CliError::conflict(format!(
+                "Cannot edit: branch '{}' is frozen. Use 'lattice unfreeze' first.",
+                branch
+            )));
+        }
+        let base = Oid::new(&scanned.metadata.base.oid).context("Invalid base OID")?;
+        let tip = snapshot
+            .branches
+            .get(&branch)
+            .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", branch)))?;
+        let mut commits = git.commits_between(&base, tip)?;
+        commits.reverse();
+        range.push(StackBranch {
+            branch,
+            commits,
+            base: base.to_string(),
+            metadata_ref_oid: scanned.ref_oid.clone(),
+            metadata: scanned.metadata.clone(),
+        });
+    }
+
+    if range.iter().all(|entry| entry.commits.is_empty()) {
+        if !ctx.quiet {
+            println!("{}", i18n::t("edit-no-commits"));
+        }
+        return Ok(());
+    }
+
+    let dir = paths.repo_edit_dir();
+    if dir.exists() {
+        fs::remove_dir_all(&dir).context("Failed to clear edit directory")?;
+    }
+    fs::create_dir_all(&dir).context("Failed to create edit directory")?;
+
+    let todo_path = dir.join("TODO");
+    fs::write(&todo_path, render_todo(&range)).context("Failed to write todo file")?;
+
+    let editor = std::env::var("LATTICE_TEST_EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "edit-opening-editor",
+                &[
+                    (
+                        "commits",
+                        &(range.iter().map(|e| e.commits.len()).sum::<usize>())
+                    ),
+                    ("count", &range.len()),
+                ],
+            )
+        );
+    }
+
+    let status = ProcessCommand::new(&editor)
+        .arg(&todo_path)
+        .status()
+        .with_context(|| format!("Failed to open editor '{}'", editor))?;
+
+    if !status.success() {
+        return Err(CliError::failed("Editor exited with error"));
+    }
+
+    let edited = fs::read_to_string(&todo_path).context("Failed to read edited todo")?;
+    let todos = parse_todo(&edited, &range)?;
+
+    // =========================================================================
+    // PRE-PLAN: Compile the todo into per-branch rebases
+    // =========================================================================
+
+    // Everything from the lowest changed branch up is rewritten, since each
+    // branch above it gets a new parent tip
+    let Some(first_changed) = range
+        .iter()
+        .zip(&todos)
+        .position(|(entry, todo)| entry.unchanged() != *todo)
+    else {
+        if !ctx.quiet {
+            println!("{}", i18n::t("edit-no-changes"));
+        }
+        return Ok(());
+    };
+
+    let messages: HashMap<Oid, &CommitInfo> = range
+        .iter()
+        .flat_map(|entry| entry.commits.iter().map(|c| (c.oid.clone(), c)))
+        .collect();
+
+    let mut edits = Vec::new();
+    for (i, (entry, todo)) in range.iter().zip(&todos).enumerate().skip(first_changed) {
+        let todo_file = dir.join(format!("{}.todo", i));
+        fs::write(
+            &todo_file,
+            compile_git_todo(todo, &messages, &dir, ctx.verify)?,
+        )
+        .context("Failed to write todo file")?;
+
+        edits.push(BranchRebase {
+            branch: entry.branch.clone(),
+            onto: if i == first_changed {
+                entry.base.clone()
+            } else {
+                range[i - 1].branch.to_string()
+            },
+            old_base: entry.base.clone(),
+            todo_file: Some(todo_file),
+            metadata_ref_oid: entry.metadata_ref_oid.clone(),
+            metadata: entry.metadata.clone(),
+        });
+    }
+
+    // Descendants of rewritten branches that are outside the edit
+    let edited: HashSet<&BranchName> = edits.iter().map(|r| &r.branch).collect();
+    let outside: Vec<BranchName> = edits
+        .iter()
+        .flat_map(|r| get_descendants_inclusive(&r.branch, &snapshot))
+        .filter(|b| !range.iter().any(|entry| &entry.branch == b))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let mut descendants_to_restack = Vec::new();
+    let mut frozen_to_skip = Vec::new();
+    for branch in topological_sort(&outside, &snapshot) {
+        let scanned = snapshot
+            .metadata
+            .get(&branch)
+            .ok_or_else(|| CliError::untracked(&branch))?;
+        if scanned.metadata.freeze.is_frozen() {
+            frozen_to_skip.push(branch);
+            continue;
+        }
+        let parent = BranchName::new(scanned.metadata.parent.name())?;
+        // Only branches whose parent was rewritten or restacked move
+        if !edited.contains(&parent)
+            && !descendants_to_restack
+                .iter()
+                .any(|r: &BranchRebase| r.branch == parent)
+        {
+            continue;
+        }
+        descendants_to_restack.push(BranchRebase {
+            branch: branch.clone(),
+            onto: parent.to_string(),
+            old_base: scanned.metadata.base.oid.clone(),
+            todo_file: None,
+            metadata_ref_oid: scanned.ref_oid.clone(),
+            metadata: scanned.metadata.clone(),
+        });
+    }
+
+    let restack = !no_restack
+        && Config::load(Some(&cwd))
+            .map(|r| r.config.restack_descendants())
+            .unwrap_or(true);
+    let deferred: Vec<BranchName> = if restack {
+        Vec::new()
+    } else {
+        descendants_to_restack.drain(..).map(|r| r.branch).collect()
+    };
+
+    if !ctx.quiet {
+        let branches = edits
+            .iter()
+            .map(|r| r.branch.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{}",
+            i18n::t_args("edit-rewriting", &[("branches", &branches)])
+        );
+        if !descendants_to_restack.is_empty() {
+            let branches: Vec<BranchName> = descendants_to_restack
+                .iter()
+                .map(|r| r.branch.clone())
+                .collect();
+            print!("{}", format_restack_preview(&branches, None));
+        }
+    }
+
+    if ctx.interactive && !prompts::confirm(&i18n::t("prompt-edit-apply"), Severity::Minor, true)? {
+        println!("{}", i18n::t("prompt-aborted"));
+        return Ok(());
+    }
+
+    // =========================================================================
+    // EXECUTE: Run command through unified lifecycle
+    // =========================================================================
+
+    let cmd = EditCommand {
+        precomputed: EditPrecomputed {
+            edits,
+            descendants_to_restack,
+            return_to: current,
+        },
+        verify: ctx.verify,
+    };
+
+    let output = run_command(&cmd, &git, ctx)?;
+
+    // =========================================================================
+    // POST-EXECUTE: Display results
+    // =========================================================================
+
+    let list = |branches: &[BranchName]| {
+        branches
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    match output {
+        CommandOutput::Success(result) => {
+            if !ctx.quiet {
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "edit-rewrote-branches",
+                        &[
+                            ("count", &result.edited.len()),
+                            ("branches", &list(&result.edited)),
+                        ],
+                    )
+                );
+                if !result.restacked.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "edit-restacked-descendant",
+                            &[
+                                ("count", &result.restacked.len()),
+                                ("branches", &list(&result.restacked)),
+                            ],
+                        )
+                    );
+                }
+                if !frozen_to_skip.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "edit-skipped-frozen-branch",
+                            &[
+                                ("count", &frozen_to_skip.len()),
+                                ("branches", &list(&frozen_to_skip)),
+                            ],
+                        )
+                    );
+                }
+                if !deferred.is_empty() {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "edit-deferred-restack-descendant",
+                            &[("count", &deferred.len()), ("branches", &list(&deferred))],
+                        )
+                    );
+                }
+                println!("{}", i18n::t("edit-complete"));
+            }
+        }
+        CommandOutput::Paused { message } => {
+            println!();
+            println!("{}", message);
+            super::conflict_summary::print(&git);
+        }
+        CommandOutput::Failed { error } => {
+            return Err(CliError::failed(format!("Edit failed: {}", error)));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::metadata::schema::BranchMetadataV2;
+
+    fn oid(c: char) -> Oid {
+        Oid::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    fn commit(c: char, message: &str) -> CommitInfo {
+        CommitInfo {
+            oid: oid(c),
+            summary: message.lines().next().unwrap_or("").to_string(),
+            message: message.to_string(),
+            author_name: "Test".to_string(),
+            author_email: "test@example.com".to_string(),
+            author_time: chrono::Utc::now(),
+        }
+    }
+
+    fn entry(name: &str, commits: Vec<CommitInfo>) -> StackBranch {
+        let branch = BranchName::new(name).unwrap();
+        StackBranch {
+            metadata: BranchMetadataV2::new(
+                branch.clone(),
+                BranchName::new("main").unwrap(),
+                oid('0'),
+            ),
+            branch,
+            commits,
+            base: oid('0').to_string(),
+            metadata_ref_oid: oid('9'),
+        }
+    }
+
+    fn stack() -> Vec<StackBranch> {
+        vec![
+            entry(
+                "a",
+                vec![commit('a', "Add parser"), commit('b', "Fix lexer typo")],
+            ),
+            entry("b", vec![commit('c', "Use parser\n\nDetails here.")]),
+        ]
+    }
+
+    fn pick(c: char) -> TodoLine {
+        TodoLine {
+            action: TodoAction::Pick,
+            commit: oid(c),
+        }
+    }
+
+    #[test]
+    fn rendered_todo_parses_unchanged() {
+        let stack = stack();
+        let todo = render_todo(&stack);
+        assert!(todo.contains("branch a\npick aaaaaaa Add parser\npick bbbbbbb Fix lexer typo\n"));
+        assert!(todo.contains("branch b\npick ccccccc Use parser\n"));
+
+        let parsed = parse_todo(&todo, &stack).unwrap();
+        let unchanged: Vec<_> = stack.iter().map(|e| e.unchanged()).collect();
+        assert_eq!(parsed, unchanged);
+    }
+
+    #[test]
+    fn commits_move_across_branches() {
+        let parsed = parse_todo(
+            "branch a\nr aaaa Add expression parser\n\nbranch b\npick bbbb\npick cccc\n",
+            &stack(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed[0].lines,
+            vec![TodoLine {
+                action: TodoAction::Reword("Add expression parser".to_string()),
+                commit: oid('a'),
+            }]
+        );
+        assert_eq!(parsed[1].lines, vec![pick('b'), pick('c')]);
+    }
+
+    #[test]
+    fn drop_and_deleted_lines_remove_commits() {
+        let parsed = parse_todo("branch a\ndrop aaaa\nbranch b\n", &stack()).unwrap();
+        assert!(parsed[0].lines.is_empty());
+        assert!(parsed[1].lines.is_empty());
+    }
+
+    #[test]
+    fn invalid_edits_rejected() {
+        let stack = stack();
+        let err = |text: &str| parse_todo(text, &stack).unwrap_err().to_string();
+
+        assert!(err("branch b\nbranch a\n").contains("expected 'branch a'"));
+        assert!(err("branch a\n").contains("missing 'branch b'"));
+        assert!(err("branch a\nbranch b\nbranch c\n").contains("unexpected 'branch c'"));
+        assert!(err("pick aaaa\nbranch a\nbranch b\n").contains("above the first branch"));
+        assert!(err("branch a\npick dddd\nbranch b\n").contains("not in the todo"));
+        assert!(err("branch a\npick aaaa\npick aaaa\nbranch b\n").contains("more than once"));
+        assert!(err("branch a\nsquash aaaa\nbranch b\n").contains("unknown command"));
+        assert!(err("branch a\nreword aaaa\nbranch b\n").contains("needs a subject"));
+        assert!(err("branch a\nbranch b\nfixup cccc\n").contains("must follow a commit"));
+    }
+
+    #[test]
+    fn compiled_todo_rewords_through_message_file() {
+        let stack = stack();
+        let messages: HashMap<Oid, &CommitInfo> = stack
+            .iter()
+            .flat_map(|e| e.commits.iter().map(|c| (c.oid.clone(), c)))
+            .collect();
+        let dir = tempfile::TempDir::new().unwrap();
+        let todo = BranchTodo {
+            branch: BranchName::new("b").unwrap(),
+            lines: vec![
+                TodoLine {
+                    action: TodoAction::Reword("Use the parser".to_string()),
+                    commit: oid('c'),
+                },
+                TodoLine {
+                    action: TodoAction::Fixup,
+                    commit: oid('b'),
+                },
+            ],
+        };
+
+        let compiled = compile_git_todo(&todo, &messages, dir.path(), false).unwrap();
+        let lines: Vec<_> = compiled.lines().collect();
+        assert_eq!(lines[0], format!("pick {}", oid('c')));
+        assert!(
+            lines[1].starts_with("exec git commit --amend --only --allow-empty --no-verify -F '")
+        );
+        assert_eq!(lines[2], format!("fixup {}", oid('b')));
+
+        let message = fs::read_to_string(dir.path().join(format!("{}.msg", oid('c')))).unwrap();
+        assert_eq!(message, "Use the parser\n\nDetails here.\n");
+
+        let empty = BranchTodo {
+            branch: BranchName::new("a").unwrap(),
+            lines: Vec::new(),
+        };
+        assert_eq!(
+            compile_git_todo(&empty, &messages, dir.path(), true).unwrap(),
+            "noop\n"
+        );
+    }
+
+    #[test]
+    fn shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("/tmp/it's"), r"'/tmp/it'\''s'");
+    }
+}
@@ -0,0 +1,420 @@
+//! edit <branch> - Guided edit of a branch lower in the stack
+//!
+//! Per SPEC.md 8D.13 (Guided branch edit):
+//!
+//! - `lattice edit <branch>` checks out the branch and records an edit
+//!   session, then stops so the user can amend it (`lattice modify` or
+//!   `git commit --amend`)
+//! - `lattice edit --done` restacks the branch's descendants and checks the
+//!   original branch out again, ending the session
+//! - `lattice edit <branch> --patch` does it all in one go: the staged
+//!   changes, or hunks picked with `git add --patch` when nothing is
+//!   staged, are amended into the branch's last commit; the rest of the
+//!   working tree is stashed for the round trip and restored afterwards
+//! - A restack that stops on a conflict keeps the session: after
+//!   `lattice continue`, `lattice edit --done` finishes the return
+//!
+//! # Integrity Contract
+//!
+//! - Gated as a mutating command before any checkout, so read-only mode
+//!   and a paused operation refuse both halves of the edit
+//! - Must never amend or restack frozen branches
+//! - One session at a time, finished from the worktree that started it
+//! - All rewrites go through `modify` and `restack`, so they are journaled
+//!   and can be undone
+//! - A `--patch` edit that fails partway puts the branch, the stash, and
+//!   the picked changes back the way they were
+
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcessCommand;
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+
+use super::navigation::checkout_branch;
+use super::phase3_helpers::{check_freeze, is_working_tree_clean};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
+use crate::core::ops::journal::OpState;
+use crate::core::paths::LatticePaths;
+use crate::core::types::BranchName;
+use crate::engine::gate::requirements;
+use crate::engine::runner::run_gated;
+use crate::engine::scan::RepoSnapshot;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+
+/// A guided edit waiting for `lattice edit --done`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct EditSession {
+    /// Branch being edited
+    branch: String,
+    /// Branch to return to
+    return_to: String,
+    /// Worktree the session was started in (its git dir)
+    git_dir: String,
+    /// Whether descendants are restacked when the edit is done
+    restack: bool,
+    /// Whether the rest of the working tree was stashed for the round trip
+    stashed: bool,
+}
+
+impl EditSession {
+    /// Load the session in progress, if any.
+    fn load(paths: &LatticePaths) -> CliResult<Option<Self>> {
+        let path = paths.repo_edit_session_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path).context("Failed to read edit session")?;
+        let session = serde_json::from_str(&content).context("Failed to parse edit session")?;
+        Ok(Some(session))
+    }
+
+    fn save(&self, paths: &LatticePaths) -> CliResult {
+        fs::create_dir_all(paths.repo_lattice_dir())
+            .context("Failed to create lattice directory")?;
+        let content = serde_json::to_string_pretty(self)?;
+        Ok(fs::write(paths.repo_edit_session_path(), content)
+            .context("Failed to save edit session")?)
+    }
+
+    fn clear(paths: &LatticePaths) -> CliResult {
+        Ok(fs::remove_file(paths.repo_edit_session_path())
+            .context("Failed to clear edit session")?)
+    }
+}
+
+/// Check out `branch` for editing, or with `patch` amend the staged (or
+/// interactively picked) changes into it and return.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch to edit
+/// * `patch` - Amend changes from the working tree instead of stopping
+/// * `no_restack` - Leave descendants for a later `lattice restack`
+pub fn start(ctx: &Context, branch: &str, patch: bool, no_restack: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let info = git.info()?;
+    let paths = LatticePaths::from_repo_info(&info);
+
+    if let Some(session) = EditSession::load(&paths)? {
+        return Err(CliError::conflict(format!(
+            "Already editing '{}'. Run 'lattice edit --done' to return to '{}' first.",
+            session.branch, session.return_to
+        )));
+    }
+
+    let target = BranchName::new(branch)?;
+    let current = gate(&git, ctx, |snapshot| {
+        let current = snapshot
+            .current_branch
+            .clone()
+            .ok_or_else(|| CliError::usage("Not on any branch"))?;
+        if !snapshot.metadata.contains_key(&target) {
+            return Err(CliError::untracked(&target));
+        }
+        check_freeze(&target, snapshot)?;
+        Ok(current)
+    })?;
+
+    let mut session = EditSession {
+        branch: target.to_string(),
+        return_to: current.to_string(),
+        git_dir: info.git_dir.display().to_string(),
+        restack: !no_restack
+            && Config::load(Some(&cwd))
+                .map(|r| r.config.restack_descendants())
+                .unwrap_or(true),
+        stashed: false,
+    };
+
+    if !patch {
+        if target != current {
+            checkout_branch(&cwd, &target)?;
+        }
+        session.save(&paths)?;
+        if !ctx.quiet {
+            println!(
+                "{}",
+                i18n::t_args("edit-branch-editing", &[("target", &target)])
+            );
+            println!(
+                "{}",
+                i18n::t_args("edit-branch-amend-lattice-modify", &[("current", &current)])
+            );
+        }
+        return Ok(());
+    }
+
+    // Pick the changes to move, then take them out of the working tree
+    if !has_staged_changes(&cwd)? {
+        run_git(&cwd, &["add", "--patch"], true)?;
+    }
+    let changes = git_output(&cwd, &["diff", "--cached", "--binary"])?;
+    if changes.is_empty() {
+        return Err(CliError::usage(format!(
+            "No changes picked to amend into '{}'.",
+            target
+        )));
+    }
+    let patch_path = paths.repo_lattice_dir().join("edit-session.patch");
+    fs::write(&patch_path, &changes).context("Failed to save picked changes")?;
+    let set_aside = set_aside(&cwd, &patch_path);
+    match set_aside {
+        Ok(stashed) => session.stashed = stashed,
+        Err(e) => {
+            let _ = fs::remove_file(&patch_path);
+            return Err(e);
+        }
+    }
+
+    // From here on the working tree holds nothing but the picked changes,
+    // so any failure puts everything back the way it was
+    if let Err(e) = amend(ctx, &cwd, &paths, &session, &target, &patch_path) {
+        let _ = EditSession::clear(&paths);
+        put_back(&git, &cwd, &current, session.stashed, &patch_path)?;
+        let _ = fs::remove_file(&patch_path);
+        return Err(CliError::new(
+            e.category,
+            format!(
+                "Failed to amend '{}'; your changes were put back: {}",
+                target, e
+            ),
+        ));
+    }
+    let _ = fs::remove_file(&patch_path);
+
+    // Head back unless the amend or restack paused on a conflict
+    if OpState::exists(&paths) {
+        print_resume_hint(&session);
+        return Ok(());
+    }
+    finish(ctx, &git, &cwd, &paths, &session)
+}
+
+/// Take the picked changes out of the index and working tree and stash
+/// everything else. Returns whether anything was stashed.
+///
+/// On failure nothing is left changed: the picked changes are staged again.
+fn set_aside(cwd: &Path, patch_path: &Path) -> CliResult<bool> {
+    run_git(cwd, &["reset", "--quiet"], false)?;
+    if let Err(e) = apply(cwd, patch_path, &["--reverse"]) {
+        apply(cwd, patch_path, &["--cached"])?;
+        return Err(e);
+    }
+    let stashed = match is_working_tree_clean(cwd) {
+        Ok(true) => Ok(false),
+        Ok(false) => run_git(
+            cwd,
+            &["stash", "push", "--quiet", "--message", "lattice edit"],
+            false,
+        )
+        .map(|()| true),
+        Err(e) => Err(e),
+    };
+    if stashed.is_err() {
+        apply(cwd, patch_path, &["--index"])?;
+    }
+    stashed
+}
+
+/// Check out the edited branch, stage the picked changes there, and amend
+/// them in through `modify`, which restacks descendants unless the session
+/// says not to.
+fn amend(
+    ctx: &Context,
+    cwd: &Path,
+    paths: &LatticePaths,
+    session: &EditSession,
+    target: &BranchName,
+    patch_path: &Path,
+) -> CliResult {
+    if session.return_to != target.as_str() {
+        checkout_branch(cwd, target)?;
+    }
+    apply(cwd, patch_path, &["--index"])
+        .with_context(|| format!("The picked changes don't apply to '{}'", target))?;
+    session.save(paths)?;
+    super::modify::modify(
+        ctx,
+        false,
+        false,
+        false,
+        false,
+        None,
+        false,
+        !session.restack,
+    )
+}
+
+/// Undo [`set_aside`] and whatever [`amend`] got to: back on `current`
+/// with the stashed changes restored and the picked ones staged.
+///
+/// Only the picked changes were in the working tree, so discarding it
+/// loses nothing.
+fn put_back(
+    git: &Git,
+    cwd: &Path,
+    current: &BranchName,
+    stashed: bool,
+    patch_path: &Path,
+) -> CliResult {
+    run_git(cwd, &["reset", "--hard", "--quiet"], false)?;
+    if git.current_branch()?.as_ref() != Some(current) {
+        checkout_branch(cwd, current)?;
+    }
+    if stashed {
+        run_git(cwd, &["stash", "pop", "--quiet"], false)?;
+    }
+    apply(cwd, patch_path, &["--index"])
+}
+
+/// Restack the edited branch's descendants and return to the original
+/// branch.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `no_restack` - Leave descendants for a later `lattice restack`
+pub fn done(ctx: &Context, no_restack: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let info = git.info()?;
+    let paths = LatticePaths::from_repo_info(&info);
+
+    let Some(session) = EditSession::load(&paths)? else {
+        return Err(CliError::usage(
+            "No branch edit in progress. Start one with 'lattice edit <branch>'.",
+        ));
+    };
+    if Path::new(&session.git_dir) != info.git_dir {
+        return Err(CliError::conflict(format!("The edit of '{}' was started in another worktree ({}). Run 'lattice edit --done' there.",
+            session.branch,
+            session.git_dir)));
+    }
+    gate(&git, ctx, |_| Ok(()))?;
+
+    if session.restack && !no_restack {
+        super::restack::restack(ctx, Some(&session.branch), false, false)?;
+        if OpState::exists(&paths) {
+            print_resume_hint(&session);
+            return Ok(());
+        }
+    }
+    finish(ctx, &git, &cwd, &paths, &session)
+}
+
+/// Check the repository is ready for a mutating command, then run `f` on
+/// the scan.
+///
+/// Both halves of an edit check out branches and rewrite them, so they are
+/// gated like any mutating command: read-only mode or a paused operation
+/// stops them before the working tree is touched.
+fn gate<T>(
+    git: &Git,
+    ctx: &Context,
+    f: impl FnOnce(&RepoSnapshot) -> CliResult<T>,
+) -> CliResult<T> {
+    run_gated(git, ctx, &requirements::MUTATING, |ready| {
+        Ok(f(&ready.snapshot))
+    })
+    .map_err(CliError::from)?
+}
+
+/// Check the original branch out again, restore stashed changes, and end
+/// the session.
+fn finish(
+    ctx: &Context,
+    git: &Git,
+    cwd: &Path,
+    paths: &LatticePaths,
+    session: &EditSession,
+) -> CliResult {
+    let return_to = BranchName::new(&session.return_to)?;
+    if git.current_branch()? != Some(return_to.clone()) {
+        checkout_branch(cwd, &return_to)?;
+    }
+    EditSession::clear(paths)?;
+
+    if session.stashed && run_git(cwd, &["stash", "pop", "--quiet"], false).is_err() {
+        println!("{}", i18n::t("edit-branch-your-other-changes"));
+    }
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "edit-branch-finished-editing-back",
+                &[("branch", &session.branch), ("return_to", &return_to)],
+            )
+        );
+    }
+    Ok(())
+}
+
+fn print_resume_hint(session: &EditSession) {
+    println!();
+    println!(
+        "{}",
+        i18n::t_args(
+            "edit-branch-after-lattice-continue",
+            &[("return_to", &session.return_to)],
+        )
+    );
+}
+
+fn has_staged_changes(cwd: &Path) -> CliResult<bool> {
+    let status = ProcessCommand::new("git")
+        .args(["diff", "--cached", "--quiet"])
+        .current_dir(cwd)
+        .status()
+        .context("Failed to check staged changes")?;
+    Ok(!status.success())
+}
+
+/// Run git, failing on a non-zero exit; `interactive` passes the terminal
+/// through.
+fn run_git(cwd: &Path, args: &[&str], interactive: bool) -> CliResult {
+    let mut command = ProcessCommand::new("git");
+    command.args(args).current_dir(cwd);
+    let status = if interactive {
+        command.status()
+    } else {
+        command.output().map(|o| o.status)
+    }
+    .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !status.success() {
+        return Err(CliError::git(format!("git {} failed", args.join(" "))));
+    }
+    Ok(())
+}
+
+fn git_output(cwd: &Path, args: &[&str]) -> CliResult<Vec<u8>> {
+    let output = ProcessCommand::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !output.status.success() {
+        return Err(CliError::git(format!("git {} failed", args.join(" "))));
+    }
+    Ok(output.stdout)
+}
+
+fn apply(cwd: &Path, patch: &Path, flags: &[&str]) -> CliResult {
+    let patch = patch.display().to_string();
+    let mut args = vec!["apply"];
+    args.extend_from_slice(flags);
+    args.push(&patch);
+    run_git(cwd, &args, false)
+}
@@ -29,8 +29,9 @@
 //! - Must re-parent children before deleting
 //! - Metadata updated only after refs succeed
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{BaseInfo, BranchInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::BranchName;
@@ -41,6 +42,7 @@ use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Fold current branch into parent.
 ///
@@ -48,7 +50,7 @@ use crate::git::Git;
 ///
 /// * `ctx` - Execution context
 /// * `keep` - Keep the current branch name by renaming parent
-pub fn fold(ctx: &Context, keep: bool) -> Result<()> {
+pub fn fold(ctx: &Context, keep: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -60,26 +62,22 @@ pub fn fold(ctx: &Context, keep: bool) -> Result<()> {
         verify: ctx.verify,
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(()) => {
             if !ctx.quiet {
-                println!("Fold complete.");
+                println!("{}", i18n::t("fold-complete"));
             }
             Ok(())
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
     }
 }
 
@@ -99,9 +97,7 @@ impl Command for FoldCommand {
         let snapshot = &ctx.snapshot;
 
         // Get trunk
-        let trunk = snapshot
-            .trunk()
-            .ok_or_else(|| PlanError::MissingData("trunk not configured".to_string()))?;
+        let trunk = snapshot.trunk().ok_or(PlanError::TrunkNotConfigured)?;
 
         // Get current branch
         let current = snapshot
@@ -111,10 +107,7 @@ impl Command for FoldCommand {
 
         // Check if tracked
         if !snapshot.metadata.contains_key(current) {
-            return Err(PlanError::InvalidState(format!(
-                "Branch '{}' is not tracked",
-                current
-            )));
+            return Err(PlanError::UntrackedBranch(current.to_string()));
         }
 
         // Get current metadata
@@ -146,9 +139,10 @@ impl Command for FoldCommand {
         }
 
         // Get parent metadata
-        let parent_meta = snapshot.metadata.get(&parent_name).ok_or_else(|| {
-            PlanError::InvalidState(format!("Parent '{}' is not tracked", parent_name))
-        })?;
+        let parent_meta = snapshot
+            .metadata
+            .get(&parent_name)
+            .ok_or_else(|| PlanError::UntrackedBranch(parent_name.to_string()))?;
 
         // Check freeze on parent
         if parent_meta.metadata.freeze.is_frozen() {
@@ -10,7 +10,19 @@
 //! 1. Plan metadata updates for target branch(es)
 //! 2. Execute via the single transactional executor with CAS semantics
 //! 3. This ensures consistency and enables proper journaling
+//!
+//! # Selecting branches
+//!
+//! The target is the named branch (default: current) or, when the name
+//! contains `*` or `?`, every tracked branch matching it as a glob. A
+//! [`FreezeRange`] widens each target: downstack ancestors (the default),
+//! nothing (`--only`), descendants (`--upstack`), or the whole stack
+//! (`--stack`). Everything selected changes in one plan, so a whole
+//! imported stack or family of release branches toggles, and undoes, at
+//! once; the plan ends with a summary when it covers several branches.
 
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::schema::glob_match;
 use crate::core::metadata::schema::{FreezeScope, FreezeState};
 use crate::core::ops::journal::OpId;
 use crate::core::types::BranchName;
@@ -18,16 +30,47 @@ use crate::engine::command::{Command, CommandOutput, SimpleCommand};
 use crate::engine::exec::ExecuteResult;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::{Plan, PlanError, PlanStep};
-use crate::engine::runner::{run_command, RunError};
+use crate::engine::runner::run_command;
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// Which branches around each target a freeze or unfreeze covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FreezeRange {
+    /// The branch and its tracked ancestors
+    #[default]
+    Downstack,
+    /// Just the branch
+    Only,
+    /// The branch and its descendants
+    Upstack,
+    /// Every tracked branch in the branch's stack
+    Stack,
+}
+
+impl FreezeRange {
+    /// The range selected by `--only`, `--upstack`, and `--stack`.
+    pub fn from_flags(only: bool, upstack: bool, stack: bool) -> Self {
+        if only {
+            Self::Only
+        } else if upstack {
+            Self::Upstack
+        } else if stack {
+            Self::Stack
+        } else {
+            Self::Downstack
+        }
+    }
+}
 
 /// Command to freeze a branch.
 pub struct FreezeCommand<'a> {
     ctx: &'a Context,
     branch: Option<&'a str>,
-    only: bool,
+    range: FreezeRange,
 }
 
 impl Command for FreezeCommand<'_> {
@@ -35,7 +78,7 @@ impl Command for FreezeCommand<'_> {
     type Output = ();
 
     fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
-        plan_freeze_state(ready, self.branch, self.only, true, self.ctx)
+        plan_freeze_state(ready, self.branch, self.range, true, self.ctx)
     }
 
     fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
@@ -49,7 +92,7 @@ impl SimpleCommand for FreezeCommand<'_> {}
 pub struct UnfreezeCommand<'a> {
     ctx: &'a Context,
     branch: Option<&'a str>,
-    only: bool,
+    range: FreezeRange,
 }
 
 impl Command for UnfreezeCommand<'_> {
@@ -57,7 +100,7 @@ impl Command for UnfreezeCommand<'_> {
     type Output = ();
 
     fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
-        plan_freeze_state(ready, self.branch, self.only, false, self.ctx)
+        plan_freeze_state(ready, self.branch, self.range, false, self.ctx)
     }
 
     fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
@@ -67,20 +110,82 @@ impl Command for UnfreezeCommand<'_> {
 
 impl SimpleCommand for UnfreezeCommand<'_> {}
 
-/// Plan freeze state changes for a branch (and optionally its ancestors).
+/// Command to freeze exactly a set of branches.
+pub struct FreezeBranchesCommand<'a> {
+    ctx: &'a Context,
+    branches: &'a [BranchName],
+}
+
+impl Command for FreezeBranchesCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING_METADATA_ONLY;
+    type Output = ();
+
+    fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
+        if let Some(untracked) = self
+            .branches
+            .iter()
+            .find(|b| !ready.snapshot.metadata.contains_key(*b))
+        {
+            return Err(PlanError::UntrackedBranch(untracked.to_string()));
+        }
+        plan_freeze_branches(ready, self.branches, true, self.ctx)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        self.simple_finish(result)
+    }
+}
+
+impl SimpleCommand for FreezeBranchesCommand<'_> {}
+
+/// Plan freeze state changes for the selected branches.
 fn plan_freeze_state(
     ready: &ReadyContext,
     branch: Option<&str>,
-    only: bool,
+    range: FreezeRange,
     frozen: bool,
     ctx: &Context,
 ) -> Result<Plan, PlanError> {
     let snapshot = &ready.snapshot;
 
+    let mut branches_to_update: Vec<BranchName> = Vec::new();
+    for target in resolve_targets(snapshot, branch)? {
+        for branch in select_range(snapshot, &target, range) {
+            if !branches_to_update.contains(&branch) {
+                branches_to_update.push(branch);
+            }
+        }
+    }
+
+    plan_freeze_branches(ready, &branches_to_update, frozen, ctx)
+}
+
+/// The tracked branches named by `branch`: the branch itself (default:
+/// current), or every tracked branch matching it when it is a glob.
+fn resolve_targets(
+    snapshot: &RepoSnapshot,
+    branch: Option<&str>,
+) -> Result<Vec<BranchName>, PlanError> {
+    if let Some(pattern) = branch.filter(|name| name.contains(['*', '?'])) {
+        let mut matched: Vec<BranchName> = snapshot
+            .metadata
+            .keys()
+            .filter(|b| glob_match(pattern, b.as_str()))
+            .cloned()
+            .collect();
+        if matched.is_empty() {
+            return Err(PlanError::InvalidState(format!(
+                "No tracked branch matches '{}'",
+                pattern
+            )));
+        }
+        matched.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        return Ok(matched);
+    }
+
     // Resolve target branch
     let target = if let Some(name) = branch {
-        BranchName::new(name)
-            .map_err(|e| PlanError::InvalidState(format!("Invalid branch name: {}", e)))?
+        BranchName::new(name).map_err(PlanError::from)?
     } else if let Some(ref current) = snapshot.current_branch {
         current.clone()
     } else {
@@ -91,21 +196,22 @@ fn plan_freeze_state(
 
     // Check if tracked
     if !snapshot.metadata.contains_key(&target) {
-        return Err(PlanError::InvalidState(format!(
-            "Branch '{}' is not tracked",
-            target
-        )));
+        return Err(PlanError::UntrackedBranch(target.to_string()));
     }
+    Ok(vec![target])
+}
 
-    // Get branches to update
-    let branches_to_update = if only {
-        vec![target.clone()]
-    } else {
-        // Include all ancestors (downstack)
-        let mut branches = vec![target.clone()];
+/// `target` widened by `range`, keeping only tracked branches.
+fn select_range(
+    snapshot: &RepoSnapshot,
+    target: &BranchName,
+    range: FreezeRange,
+) -> Vec<BranchName> {
+    // Tracked ancestors, nearest first; trunk isn't tracked, so this stops there
+    let ancestors = || {
+        let mut branches = Vec::new();
         let mut current = target.clone();
         while let Some(parent) = snapshot.graph.parent(&current) {
-            // Stop at trunk (which isn't tracked)
             if !snapshot.metadata.contains_key(parent) {
                 break;
             }
@@ -114,11 +220,44 @@ fn plan_freeze_state(
         }
         branches
     };
+    let descendants = || {
+        let descendants = snapshot.graph.descendants(target);
+        snapshot
+            .graph
+            .topological_order()
+            .into_iter()
+            .filter(|b| descendants.contains(b) && snapshot.metadata.contains_key(b))
+            .collect::<Vec<_>>()
+    };
 
+    match range {
+        FreezeRange::Only => vec![target.clone()],
+        FreezeRange::Downstack => std::iter::once(target.clone()).chain(ancestors()).collect(),
+        FreezeRange::Upstack => std::iter::once(target.clone())
+            .chain(descendants())
+            .collect(),
+        FreezeRange::Stack => {
+            let mut stack = ancestors();
+            stack.reverse();
+            stack.push(target.clone());
+            stack.extend(descendants());
+            stack
+        }
+    }
+}
+
+/// Plan freeze state changes for exactly `branches`.
+fn plan_freeze_branches(
+    ready: &ReadyContext,
+    branches: &[BranchName],
+    frozen: bool,
+    ctx: &Context,
+) -> Result<Plan, PlanError> {
+    let snapshot = &ready.snapshot;
     let action = if frozen { "freeze" } else { "unfreeze" };
     let mut plan = Plan::new(OpId::new(), action);
 
-    for branch in &branches_to_update {
+    for branch in branches {
         // Get current metadata
         let scanned = snapshot.metadata.get(branch).ok_or_else(|| {
             PlanError::InvalidState(format!("Metadata not found for '{}'", branch))
@@ -127,8 +266,12 @@ fn plan_freeze_state(
         // Skip if already in desired state
         if scanned.metadata.freeze.is_frozen() == frozen {
             if !ctx.quiet {
-                let state = if frozen { "frozen" } else { "unfrozen" };
-                println!("'{}' is already {}", branch, state);
+                let key = if frozen {
+                    "freeze-already-frozen"
+                } else {
+                    "freeze-already-unfrozen"
+                };
+                println!("{}", i18n::t_args(key, &[("branch", &branch)]));
             }
             continue;
         }
@@ -150,11 +293,35 @@ fn plan_freeze_state(
         });
 
         if !ctx.quiet {
-            let action_past = if frozen { "Freezing" } else { "Unfreezing" };
-            println!("{} '{}'", action_past, branch);
+            let key = if frozen {
+                "freeze-freezing"
+            } else {
+                "freeze-unfreezing"
+            };
+            println!("{}", i18n::t_args(key, &[("branch", &branch)]));
         }
     }
 
+    if branches.len() > 1 && !ctx.quiet {
+        let changing = plan.steps.len();
+        let key = if frozen {
+            "freeze-selected-freezing"
+        } else {
+            "freeze-selected-unfreezing"
+        };
+        println!(
+            "{}",
+            i18n::t_args(
+                key,
+                &[
+                    ("changing", &changing),
+                    ("count", &branches.len()),
+                    ("unchanged", &(branches.len() - changing)),
+                ],
+            )
+        );
+    }
+
     Ok(plan)
 }
 
@@ -169,23 +336,13 @@ fn plan_freeze_state(
 /// # Gating
 ///
 /// Uses `requirements::MUTATING_METADATA_ONLY` via `Command` trait.
-pub fn freeze(ctx: &Context, branch: Option<&str>, only: bool) -> Result<()> {
-    let cwd = ctx
-        .cwd
-        .clone()
-        .unwrap_or_else(|| std::env::current_dir().unwrap());
-    let git = Git::open(&cwd).context("Failed to open repository")?;
-
-    let cmd = FreezeCommand { ctx, branch, only };
-
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
-
-    output.into_result().map_err(|e| anyhow::anyhow!("{}", e))
+pub fn freeze(ctx: &Context, branch: Option<&str>, only: bool) -> CliResult {
+    set_frozen(
+        ctx,
+        branch,
+        FreezeRange::from_flags(only, false, false),
+        true,
+    )
 }
 
 /// Unmark a branch as frozen.
@@ -199,21 +356,62 @@ pub fn freeze(ctx: &Context, branch: Option<&str>, only: bool) -> Result<()> {
 /// # Gating
 ///
 /// Uses `requirements::MUTATING_METADATA_ONLY` via `Command` trait.
-pub fn unfreeze(ctx: &Context, branch: Option<&str>, only: bool) -> Result<()> {
+pub fn unfreeze(ctx: &Context, branch: Option<&str>, only: bool) -> CliResult {
+    set_frozen(
+        ctx,
+        branch,
+        FreezeRange::from_flags(only, false, false),
+        false,
+    )
+}
+
+/// Freeze or unfreeze the selected branches in one operation.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch or glob pattern (defaults to current)
+/// * `range` - Which branches around each target to include
+/// * `frozen` - Freeze when true, unfreeze when false
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY` via `Command` trait.
+pub fn set_frozen(
+    ctx: &Context,
+    branch: Option<&str>,
+    range: FreezeRange,
+    frozen: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
-    let cmd = UnfreezeCommand { ctx, branch, only };
+    let result = if frozen {
+        run_command(&FreezeCommand { ctx, branch, range }, &git, ctx)
+    } else {
+        run_command(&UnfreezeCommand { ctx, branch, range }, &git, ctx)
+    };
+    let output = result.map_err(CliError::from)?;
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    output.into_result().map_err(CliError::failed)
+}
+
+/// Freeze exactly `branches` (not their ancestors) in one operation.
+///
+/// Used by batch actions (`lattice log --select`).
+pub fn freeze_branches(ctx: &Context, branches: &[BranchName]) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let cmd = FreezeBranchesCommand { ctx, branches };
+
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
-    output.into_result().map_err(|e| anyhow::anyhow!("{}", e))
+    output.into_result().map_err(CliError::failed)
 }
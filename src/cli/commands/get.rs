@@ -5,12 +5,23 @@
 //! # Design
 //!
 //! Per SPEC.md Section 8E.4, the get command:
-//! - Accepts branch name or PR number
+//! - Accepts branch name, PR number, or PR URL
 //! - Fetches from remote
 //! - Determines parent from PR base or trunk
 //! - Tracks fetched branch (frozen by default)
 //! - Optionally restacks after fetching
 //!
+//! When the remote is on github.com and the user hasn't logged in, the PR is
+//! read through GitHub's public API without a token, so reviewers can pull
+//! down a public stack before setting up auth. Gating then skips the auth
+//! capabilities (`requirements::REMOTE_PUBLIC`), and get warns when little
+//! is left of GitHub's small budget for such requests. The PR read is
+//! cached for `log` and `info` either way.
+//!
+//! With `--dry-run`, get resolves the PR, reads the branch tip from the
+//! remote, and downloads its objects, then prints the ref and metadata
+//! changes it would make instead of applying them.
+//!
 //! # Architecture
 //!
 //! The get command implements `AsyncCommand` per the Phase 6 command migration.
@@ -33,30 +44,42 @@
 //! # Fetch by PR number
 //! lattice get 42
 //!
+//! # Fetch by PR URL
+//! lattice get https://github.com/owner/repo/pull/42
+//!
 //! # Fetch unfrozen (editable)
 //! lattice get feature-branch --unfrozen
 //!
 //! # Fetch in bare repo (no checkout)
 //! lattice get feature-branch --no-checkout
+//!
+//! # Show what would be fetched and tracked
+//! lattice get 42 --dry-run
 //! ```
 
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
 use crate::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeScope, FreezeState, ParentInfo, PrState,
-    Timestamps, METADATA_KIND, SCHEMA_VERSION,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeScope, FreezeState, ParentInfo, PrState,
+    Provenance, ProvenanceSource, Timestamps, METADATA_KIND, SCHEMA_VERSION,
 };
 use crate::core::metadata::store::MetadataStore;
 use crate::core::ops::journal::OpId;
-use crate::core::types::{BranchName, UtcTimestamp};
+use crate::core::paths::LatticePaths;
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
 use crate::engine::command::{AsyncCommand, CommandOutput, PlanFut};
 use crate::engine::exec::ExecuteResult;
 use crate::engine::gate::requirements;
 use crate::engine::gate::{ReadyContext, RequirementSet};
 use crate::engine::modes::{GetMode, ModeError};
 use crate::engine::plan::{Plan, PlanStep};
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
-use crate::forge::PullRequest;
+use crate::forge::github::{parse_github_pr_url, parse_github_remote};
+use crate::forge::status_cache::{CachedPrStatus, ForgeStatusCache};
+use crate::forge::{ForgeError, PullRequest};
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 use std::process::Command;
 
 /// Result of a get operation.
@@ -87,6 +110,8 @@ pub struct GetArgs {
     pub no_checkout: bool,
     /// Quiet mode.
     pub quiet: bool,
+    /// Print the planned changes without making them.
+    pub dry_run: bool,
 }
 
 /// The get command for normal (with checkout) mode.
@@ -107,16 +132,8 @@ impl AsyncCommand for GetWithCheckoutCommand {
 
     fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
         Box::pin(async move {
-            // Build a plan with ForgeFetch step
             // The actual fetch and tracking logic will be executed after gating
-            let mut plan = Plan::new(OpId::new(), "get");
-
-            plan = plan.with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: Some(self.args.target.clone()),
-            });
-
-            Ok(plan)
+            Ok(fetch_plan(&self.args))
         })
     }
 
@@ -152,17 +169,7 @@ impl AsyncCommand for GetNoCheckoutCommand {
     type Output = GetResult;
 
     fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
-        Box::pin(async move {
-            // Build a plan with ForgeFetch step
-            let mut plan = Plan::new(OpId::new(), "get");
-
-            plan = plan.with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: Some(self.args.target.clone()),
-            });
-
-            Ok(plan)
-        })
+        Box::pin(async move { Ok(fetch_plan(&self.args)) })
     }
 
     fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
@@ -180,10 +187,31 @@ impl AsyncCommand for GetNoCheckoutCommand {
     }
 }
 
+/// Build the gating plan: a `ForgeFetch` step, or nothing for a dry run.
+///
+/// A PR's branch is only known once the PR is read, so for a PR target the
+/// whole remote is fetched.
+fn fetch_plan(args: &GetArgs) -> Plan {
+    let plan = Plan::new(OpId::new(), "get");
+    if args.dry_run {
+        return plan;
+    }
+    plan.with_step(PlanStep::ForgeFetch {
+        remote: "origin".to_string(),
+        refspec: (!names_pr(&args.target)).then(|| args.target.clone()),
+    })
+}
+
+/// Whether a target names a PR, by number or URL, rather than a branch.
+fn names_pr(target: &str) -> bool {
+    target.parse::<u64>().is_ok() || parse_github_pr_url(target).is_some()
+}
+
 /// Run the get command.
 ///
 /// This is a synchronous wrapper that uses tokio to run the async implementation.
 /// It uses mode dispatch for bare repository handling per SPEC.md §4.6.7.
+#[allow(clippy::too_many_arguments)]
 pub fn get(
     ctx: &Context,
     target: &str,
@@ -192,7 +220,8 @@ pub fn get(
     restack: bool,
     unfrozen: bool,
     no_checkout: bool,
-) -> Result<()> {
+    dry_run: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -205,21 +234,19 @@ pub fn get(
         ModeError::BareRepoRequiresFlag {
             command,
             required_flag,
-        } => {
-            anyhow::anyhow!(
-                "This is a bare repository. The `{}` command requires a working directory.\n\n\
+        } => CliError::usage(format!(
+            "This is a bare repository. The `{}` command requires a working directory.\n\n\
                  To fetch and track the branch without checkout, use:\n\n\
                      lattice get {} {}\n\n\
                  After tracking, you can create a worktree to work on it:\n\n\
                      git worktree add <path> {}",
-                command,
-                required_flag,
-                target,
-                target
-            )
-        }
+            command, required_flag, target, target
+        )),
     })?;
 
+    // Refuse another repository's PR before fetching anything
+    target_pr_number(&git, target)?;
+
     let args = GetArgs {
         target: target.to_string(),
         downstack,
@@ -228,6 +255,7 @@ pub fn get(
         unfrozen,
         no_checkout,
         quiet: ctx.quiet,
+        dry_run,
     };
 
     let rt = tokio::runtime::Runtime::new()?;
@@ -240,41 +268,56 @@ pub fn get(
 /// Async implementation for WithCheckout mode.
 ///
 /// Uses run_async_command for proper gating, then executes fetch and tracking.
-async fn get_with_checkout_impl(git: &Git, ctx: &Context, args: GetArgs) -> Result<()> {
-    use crate::engine::runner::run_async_command;
+async fn get_with_checkout_impl(git: &Git, ctx: &Context, args: GetArgs) -> CliResult {
+    use crate::engine::runner::run_async_command_with_requirements;
 
     let command = GetWithCheckoutCommand::new(args.clone());
+    let requirements = if reads_anonymously(git) {
+        &requirements::REMOTE_PUBLIC
+    } else {
+        GetWithCheckoutCommand::REQUIREMENTS
+    };
 
     // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+    let result = run_async_command_with_requirements(&command, git, ctx, requirements).await;
 
     match result {
         Ok(output) => match output {
+            CommandOutput::Success(_) if args.dry_run => preview_get(git, &args).await,
             CommandOutput::Success(_) => {
                 // Gating passed, now execute the actual fetch
                 execute_get_fetch(git, ctx, &args).await?;
                 Ok(())
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Get failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
 /// Async implementation for NoCheckout mode.
 ///
 /// Uses run_async_command for proper gating, then executes fetch and tracking.
-async fn get_no_checkout_impl(git: &Git, ctx: &Context, args: GetArgs) -> Result<()> {
-    use crate::engine::runner::run_async_command;
+async fn get_no_checkout_impl(git: &Git, ctx: &Context, args: GetArgs) -> CliResult {
+    use crate::engine::runner::run_async_command_with_requirements;
 
     let command = GetNoCheckoutCommand::new(args.clone());
+    let requirements = if reads_anonymously(git) {
+        &requirements::REMOTE_PUBLIC_BARE_ALLOWED
+    } else {
+        GetNoCheckoutCommand::REQUIREMENTS
+    };
 
     // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+    let result = run_async_command_with_requirements(&command, git, ctx, requirements).await;
 
     match result {
         Ok(output) => match output {
+            CommandOutput::Success(_) if args.dry_run => preview_get(git, &args).await,
             CommandOutput::Success(_) => {
                 // Gating passed, now execute fetch and handle no-checkout tracking
                 let pr_info = execute_get_fetch(git, ctx, &args).await?;
@@ -284,10 +327,13 @@ async fn get_no_checkout_impl(git: &Git, ctx: &Context, args: GetArgs) -> Result
                 handle_no_checkout_mode(ctx, git, &branch_name, pr_info.as_ref(), args.unfrozen)
                     .await
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Get failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -300,38 +346,88 @@ fn resolve_branch_name(target: &str, pr_info: Option<&PullRequest>) -> String {
     }
 }
 
-/// Execute the actual fetch operation.
-///
-/// This is called after gating succeeds. Returns PR info if target was a PR number.
-async fn execute_get_fetch(
-    git: &Git,
-    _ctx: &Context,
-    args: &GetArgs,
-) -> Result<Option<PullRequest>> {
-    use crate::cli::commands::auth::get_github_token;
+/// Whether the `origin` remote is read without a token.
+fn reads_anonymously(git: &Git) -> bool {
+    git.remote_url("origin")
+        .ok()
+        .flatten()
+        .is_some_and(|url| super::reads_anonymously(&url))
+}
 
-    let cwd = git
-        .info()?
-        .git_dir
-        .parent()
-        .unwrap_or(&git.info()?.git_dir)
-        .to_path_buf();
+/// The PR a target names: a number, or the URL of a PR on `origin`.
+///
+/// Fails for the URL of a PR in another repository.
+fn target_pr_number(git: &Git, target: &str) -> CliResult<Option<u64>> {
+    if let Ok(number) = target.parse::<u64>() {
+        return Ok(Some(number));
+    }
+    let Some((pr_repo, number)) = parse_github_pr_url(target) else {
+        return Ok(None);
+    };
+    let origin = git.remote_url("origin")?;
+    let same_repo = origin
+        .as_deref()
+        .and_then(parse_github_remote)
+        .is_some_and(|origin| {
+            origin.host.eq_ignore_ascii_case(&pr_repo.host)
+                && origin.owner.eq_ignore_ascii_case(&pr_repo.owner)
+                && origin.repo.eq_ignore_ascii_case(&pr_repo.repo)
+        });
+    if !same_repo {
+        return Err(CliError::usage(format!(
+            "PR URL '{}' is for {}/{}, not the 'origin' remote ({}).",
+            target,
+            pr_repo.owner,
+            pr_repo.repo,
+            origin.as_deref().unwrap_or("not configured")
+        )));
+    }
+    Ok(Some(number))
+}
 
-    // Determine if target is a PR number or branch name
-    let (branch_name, pr_info) = if let Ok(pr_number) = args.target.parse::<u64>() {
-        // It's a PR number - fetch details from API
-        let token = get_github_token()?;
-        let remote_url = git
-            .remote_url("origin")?
-            .ok_or_else(|| anyhow::anyhow!("No 'origin' remote configured."))?;
+/// Explain a failed PR lookup made without a token.
+fn anonymous_lookup_error(number: u64, err: ForgeError) -> CliError {
+    match err {
+        ForgeError::RateLimited => CliError::new(ErrorCategory::Forge, "GitHub's budget for requests without a token is spent. Run 'lattice auth login', or try again later."),
+        ForgeError::NotFound(_) => CliError::usage(format!("PR #{} not found. Without a token only public repositories can be read; run 'lattice auth login' if this one is private.",
+            number)),
+        other => other.into(),
+    }
+}
 
-        let forge = crate::forge::create_forge(&remote_url, &token, None)?;
+/// Resolve the target to a branch name, looking up the PR if it's a number
+/// or URL.
+///
+/// Fails if the branch already exists locally and `--force` wasn't given.
+async fn resolve_target(git: &Git, args: &GetArgs) -> CliResult<(String, Option<PullRequest>)> {
+    // Determine if target is a PR or branch name
+    let (branch_name, pr_info) = if let Some(pr_number) = target_pr_number(git, &args.target)? {
+        // It's a PR - fetch details from API
+        let forge = super::origin_forge_for_reading(git)?;
+        let anonymous = reads_anonymously(git);
 
         if !args.quiet {
-            println!("Fetching PR #{}...", pr_number);
+            if anonymous {
+                println!(
+                    "{}",
+                    i18n::t_args("get-fetching-pr-logged", &[("pr_number", &pr_number)])
+                );
+            } else {
+                println!(
+                    "{}",
+                    i18n::t_args("get-fetching-pr", &[("pr_number", &pr_number)])
+                );
+            }
         }
 
-        let pr = forge.get_pr(pr_number).await?;
+        let pr = match forge.get_pr(pr_number).await {
+            Ok(pr) => pr,
+            Err(e) if anonymous => return Err(anonymous_lookup_error(pr_number, e)),
+            Err(e) => return Err(e.into()),
+        };
+        if anonymous {
+            super::warn_anonymous_quota(args.quiet);
+        }
         (pr.head.clone(), Some(pr))
     } else {
         // It's a branch name
@@ -343,22 +439,57 @@ async fn execute_get_fetch(
     let exists_locally = git.resolve_ref(&local_ref).is_ok();
 
     if exists_locally && !args.force {
-        bail!(
+        return Err(CliError::conflict(format!(
             "Branch '{}' already exists locally. Use --force to overwrite.",
             branch_name
-        );
+        )));
     }
 
+    Ok((branch_name, pr_info))
+}
+
+/// Remote to fetch `branch_name` from.
+///
+/// A tracked branch with a remote override is fetched from that remote.
+fn fetch_remote(git: &Git, branch_name: &str) -> String {
+    BranchName::new(branch_name)
+        .ok()
+        .and_then(|b| MetadataStore::new(git).read(&b).ok().flatten())
+        .and_then(|entry| entry.metadata.remote)
+        .unwrap_or_else(|| "origin".to_string())
+}
+
+/// Execute the actual fetch operation.
+///
+/// This is called after gating succeeds. Returns PR info if target was a PR number.
+async fn execute_get_fetch(
+    git: &Git,
+    _ctx: &Context,
+    args: &GetArgs,
+) -> CliResult<Option<PullRequest>> {
+    let cwd = git
+        .info()?
+        .git_dir
+        .parent()
+        .unwrap_or(&git.info()?.git_dir)
+        .to_path_buf();
+
+    let (branch_name, pr_info) = resolve_target(git, args).await?;
+    let remote = fetch_remote(git, &branch_name);
+
     // Fetch the branch from remote
     if !args.quiet {
-        println!("Fetching branch '{}'...", branch_name);
+        println!(
+            "{}",
+            i18n::t_args("get-fetching-branch", &[("branch_name", &branch_name)])
+        );
     }
 
     let fetch_status = Command::new("git")
         .current_dir(&cwd)
         .args([
             "fetch",
-            "origin",
+            &remote,
             &format!("{}:{}", branch_name, branch_name),
         ])
         .status()?;
@@ -367,15 +498,18 @@ async fn execute_get_fetch(
         // Try fetching without creating local branch, then create it
         let fetch_ref_status = Command::new("git")
             .current_dir(&cwd)
-            .args(["fetch", "origin", &branch_name])
+            .args(["fetch", &remote, &branch_name])
             .status()?;
 
         if !fetch_ref_status.success() {
-            bail!("Failed to fetch branch '{}' from origin.", branch_name);
+            return Err(CliError::git(format!(
+                "Failed to fetch branch '{}' from {}.",
+                branch_name, remote
+            )));
         }
 
         // Create local branch tracking remote
-        let origin_ref = format!("origin/{}", branch_name);
+        let origin_ref = format!("{}/{}", remote, branch_name);
         let mut branch_args = vec!["branch"];
         if args.force {
             branch_args.push("-f");
@@ -389,21 +523,29 @@ async fn execute_get_fetch(
             .status()?;
 
         if !branch_status.success() {
-            bail!("Failed to create local branch '{}'.", branch_name);
+            return Err(CliError::git(format!(
+                "Failed to create local branch '{}'.",
+                branch_name
+            )));
         }
     }
 
+    // Let log and info show the PR without asking the forge again
+    if let Some(pr) = &pr_info {
+        let paths = LatticePaths::from_repo_info(&git.info()?);
+        let mut cache = ForgeStatusCache::load(&paths);
+        cache.set(&branch_name, CachedPrStatus::from_forge(pr, None));
+        cache.save(&paths);
+    }
+
     // For WithCheckout mode, just print guidance (no auto-tracking)
-    if !args.no_checkout {
-        let freeze_note = if args.unfrozen { "unfrozen" } else { "frozen" };
-        if !args.quiet {
-            println!(
-                "Fetched '{}'. Run 'lattice track {}' to track it ({} by default).",
-                branch_name,
-                if args.unfrozen { "--force" } else { "" },
-                freeze_note
-            );
-        }
+    if !args.no_checkout && !args.quiet {
+        let key = if args.unfrozen {
+            "get-fetched-track-unfrozen"
+        } else {
+            "get-fetched-track-frozen"
+        };
+        println!("{}", i18n::t_args(key, &[("branch_name", &branch_name)]));
     }
 
     Ok(pr_info)
@@ -424,23 +566,22 @@ async fn handle_no_checkout_mode(
     branch_name: &str,
     pr_info: Option<&PullRequest>,
     unfrozen: bool,
-) -> Result<()> {
+) -> CliResult {
     use crate::engine::scan::scan;
 
     let snapshot = scan(git).context("Failed to scan repository")?;
-
-    // Get trunk for fallback parent
-    let trunk = snapshot
-        .trunk
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?;
-
     let branch = BranchName::new(branch_name).context("Invalid branch name")?;
 
     // Check if already tracked
     if snapshot.metadata.contains_key(&branch) {
         if !ctx.quiet {
-            println!("Branch '{}' is already tracked.", branch_name);
+            println!(
+                "{}",
+                i18n::t_args(
+                    "get-branch-already-tracked",
+                    &[("branch_name", &branch_name)],
+                )
+            );
         }
         return Ok(());
     }
@@ -449,9 +590,68 @@ async fn handle_no_checkout_mode(
     let branch_tip = snapshot
         .branches
         .get(&branch)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found after fetch", branch_name))?
+        .ok_or_else(|| CliError::git(format!("Branch '{}' not found after fetch", branch_name)))?
         .clone();
 
+    let metadata = tracking_metadata(git, &snapshot, &branch, &branch_tip, pr_info, unfrozen)?;
+
+    // Write metadata (new branch, no expected old value)
+    let plan = Plan::new(OpId::new(), "get").with_step(PlanStep::WriteMetadataCas {
+        branch: branch.to_string(),
+        old_ref_oid: None,
+        metadata: Box::new(metadata.clone()),
+    });
+    super::execute_follow_up(git, ctx, &plan).context("Failed to write metadata")?;
+
+    // Print success and worktree guidance
+    if !ctx.quiet {
+        let base_oid = &metadata.base.oid;
+        println!(
+            "{}",
+            i18n::t_args(
+                "get-tracked-branch-parent",
+                &[
+                    ("branch_name", &branch_name),
+                    ("parent", &metadata.parent.name()),
+                    ("base", &(&base_oid[..7.min(base_oid.len())])),
+                ],
+            )
+        );
+        println!(
+            "{}",
+            i18n::t(if unfrozen {
+                "get-branch-unfrozen-default"
+            } else {
+                "get-branch-frozen-default"
+            })
+        );
+        println!();
+        println!("{}", i18n::t("get-work-branch-create"));
+        println!("    git worktree add <path> {}", branch_name);
+    }
+
+    Ok(())
+}
+
+/// Build tracking metadata for a fetched branch at `branch_tip`.
+///
+/// The parent comes from the PR base (or trunk), the base is
+/// merge-base(branch_tip, parent_tip), and the branch is frozen unless
+/// `unfrozen` is set. The remote, PR, and PR author are recorded as the
+/// branch's provenance.
+fn tracking_metadata(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    branch_tip: &Oid,
+    pr_info: Option<&PullRequest>,
+    unfrozen: bool,
+) -> CliResult<BranchMetadataV2> {
+    let trunk = snapshot
+        .trunk
+        .as_ref()
+        .ok_or_else(CliError::not_initialized)?;
+
     // Determine parent from PR base or trunk
     let parent_name = determine_parent(pr_info, trunk);
     let parent_branch =
@@ -463,21 +663,19 @@ async fn handle_no_checkout_mode(
         .get(&parent_branch)
         .or_else(|| snapshot.branches.get(trunk))
         .ok_or_else(|| {
-            anyhow::anyhow!(
+            CliError::usage(format!(
                 "Neither parent '{}' nor trunk '{}' found",
-                parent_name,
-                trunk
-            )
+                parent_name, trunk
+            ))
         })?
         .clone();
 
     // Compute base as merge-base(branch_tip, parent_tip)
-    let base_oid = git.merge_base(&branch_tip, &parent_tip)?.ok_or_else(|| {
-        anyhow::anyhow!(
+    let base_oid = git.merge_base(branch_tip, &parent_tip)?.ok_or_else(|| {
+        CliError::usage(format!(
             "No common ancestor between '{}' and parent '{}'",
-            branch_name,
-            parent_name
-        )
+            branch, parent_name
+        ))
     })?;
 
     // Determine if parent is trunk
@@ -503,9 +701,8 @@ async fn handle_no_checkout_mode(
         }
     };
 
-    // Create metadata
     let now = UtcTimestamp::now();
-    let metadata = BranchMetadataV1 {
+    let metadata = BranchMetadataV2 {
         kind: METADATA_KIND.to_string(),
         schema_version: SCHEMA_VERSION,
         branch: BranchInfo {
@@ -519,31 +716,92 @@ async fn handle_no_checkout_mode(
         pr: PrState::None,
         timestamps: Timestamps {
             created_at: now.clone(),
-            updated_at: now,
+            updated_at: now.clone(),
         },
+        merged_empty: None,
+        remote: None,
+        description: None,
+        pr_cached_at: None,
+        submitted: None,
+        issue: None,
+        provenance: Some(Provenance {
+            source: ProvenanceSource::Get,
+            remote: Some(fetch_remote(git, branch.as_str())),
+            pr: pr_info.map(|pr| pr.number),
+            owner: pr_info.and_then(|pr| pr.author.clone()),
+            recorded_at: now,
+        }),
+        ci: None,
     };
 
-    // Write metadata (new branch, no expected old value)
-    let store = MetadataStore::new(git);
-    store
-        .write_cas(&branch, None, &metadata)
-        .context("Failed to write metadata")?;
+    Ok(metadata)
+}
 
-    // Print success and worktree guidance
-    let freeze_status = if unfrozen { "unfrozen" } else { "frozen" };
-    if !ctx.quiet {
-        println!(
-            "Tracked branch '{}' with parent '{}' (base: {})",
-            branch_name,
-            parent_branch,
-            &base_oid.as_str()[..7.min(base_oid.as_str().len())]
-        );
-        println!("Branch is {} by default.", freeze_status);
-        println!();
-        println!("To work on this branch, create a worktree:");
-        println!("    git worktree add <path> {}", branch_name);
+/// Print what a get would change without changing anything.
+///
+/// Resolves the PR, reads the branch tip from the remote, and downloads its
+/// objects, then prints the local ref update (and, with `--no-checkout`,
+/// the metadata write) as a plan preview. No ref or metadata is written.
+async fn preview_get(git: &Git, args: &GetArgs) -> CliResult {
+    use crate::engine::scan::scan;
+
+    let (branch_name, pr_info) = resolve_target(git, args).await?;
+    let remote = fetch_remote(git, &branch_name);
+    let branch = BranchName::new(&branch_name).context("Invalid branch name")?;
+
+    let tip = git
+        .remote_heads(&remote)?
+        .remove(&branch_name)
+        .ok_or_else(|| {
+            CliError::usage(format!("Branch '{}' not found on {}.", branch_name, remote))
+        })?;
+    git.fetch_objects(&remote, Some(&branch_name))?;
+
+    let refname = format!("refs/heads/{}", branch_name);
+    let current = git.try_resolve_ref(&refname)?;
+    let mut plan = Plan::new(OpId::new(), "get");
+    if current.as_ref() != Some(&tip) {
+        plan = plan.with_step(PlanStep::UpdateRefCas {
+            refname,
+            old_oid: current.map(|oid| oid.to_string()),
+            new_oid: tip.to_string(),
+            reason: format!("fetch from {}", remote),
+        });
     }
 
+    let mut notes = Vec::new();
+    if args.no_checkout {
+        let snapshot = scan(git).context("Failed to scan repository")?;
+        if snapshot.metadata.contains_key(&branch) {
+            notes.push(format!("Branch '{}' is already tracked.", branch_name));
+        } else {
+            let metadata = tracking_metadata(
+                git,
+                &snapshot,
+                &branch,
+                &tip,
+                pr_info.as_ref(),
+                args.unfrozen,
+            )?;
+            notes.push(format!(
+                "Would track '{}' with parent '{}' ({}).",
+                branch_name,
+                metadata.parent.name(),
+                if args.unfrozen { "unfrozen" } else { "frozen" }
+            ));
+            plan = plan.with_step(PlanStep::WriteMetadataCas {
+                branch: branch_name.clone(),
+                old_ref_oid: None,
+                metadata: Box::new(metadata),
+            });
+        }
+    }
+
+    println!("{}", plan.preview());
+    for note in notes {
+        println!("{}", note);
+    }
+    println!("{}", i18n::t("get-dry-run-nothing"));
     Ok(())
 }
 
@@ -570,6 +828,51 @@ mod tests {
         assert!("feature-branch".parse::<u64>().is_err());
     }
 
+    #[test]
+    fn pr_targets_fetch_the_whole_remote() {
+        let mut args = GetArgs {
+            target: "https://github.com/o/r/pull/42".to_string(),
+            downstack: false,
+            force: false,
+            restack: false,
+            unfrozen: false,
+            no_checkout: false,
+            quiet: true,
+            dry_run: false,
+        };
+        assert!(names_pr(&args.target));
+        assert!(matches!(
+            fetch_plan(&args).steps.as_slice(),
+            [PlanStep::ForgeFetch { refspec: None, .. }]
+        ));
+
+        args.target = "42".to_string();
+        assert!(names_pr(&args.target));
+        args.target = "feature".to_string();
+        assert!(!names_pr(&args.target));
+    }
+
+    #[test]
+    fn dry_run_plans_no_fetch() {
+        let mut args = GetArgs {
+            target: "feature".to_string(),
+            downstack: false,
+            force: false,
+            restack: false,
+            unfrozen: false,
+            no_checkout: false,
+            quiet: true,
+            dry_run: false,
+        };
+        assert!(matches!(
+            fetch_plan(&args).steps.as_slice(),
+            [PlanStep::ForgeFetch { .. }]
+        ));
+
+        args.dry_run = true;
+        assert!(fetch_plan(&args).is_empty());
+    }
+
     #[test]
     fn determine_parent_with_pr() {
         use crate::forge::PrState as ForgePrState;
@@ -584,6 +887,7 @@ mod tests {
             state: ForgePrState::Open,
             body: None,
             node_id: None,
+            author: None,
         };
         let trunk = BranchName::new("main").unwrap();
 
@@ -0,0 +1,356 @@
+//! cli::commands::graph_export
+//!
+//! Render the stack graph for visualization tools (`lattice log --export`).
+//!
+//! # Formats
+//!
+//! - `dot`: a Graphviz digraph, trunk at the top, edges from parent to child.
+//!   The current branch is drawn bold, frozen branches dashed, and branches
+//!   with a PR link to it.
+//! - `mermaid`: a `graph TD` flowchart for Markdown docs. Node ids are
+//!   generated (`n0`, `n1`, ...) because branch names may contain characters
+//!   Mermaid doesn't allow in ids; the names are the labels.
+//! - `json-graph`: a [JSON Graph Format](https://jsongraphformat.info/)
+//!   document, with each branch's state and PR in the node's `metadata`.
+//!
+//! The export covers the branches `log` would show (`--stack`, `--all`),
+//! plus the roots they hang from (trunk, or a missing parent). PR status
+//! comes from the forge status cache; nothing is fetched.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::cli::args::GraphFormat;
+use crate::core::metadata::schema::PrState;
+use crate::core::types::BranchName;
+use crate::engine::scan::RepoSnapshot;
+use crate::forge::status_cache::ForgeStatusCache;
+
+use super::log_cmd::{cached_pr_label, needs_restack};
+
+/// The stack graph in a form every export format can render.
+#[derive(Debug)]
+pub(crate) struct GraphExport {
+    /// Roots first, then branches with parents before children.
+    nodes: Vec<ExportNode>,
+    /// `(parent, child)` pairs.
+    edges: Vec<(String, String)>,
+}
+
+/// One node of the exported graph.
+#[derive(Debug, Clone, Default, Serialize)]
+struct ExportNode {
+    #[serde(skip)]
+    name: String,
+    trunk: bool,
+    tracked: bool,
+    current: bool,
+    frozen: bool,
+    needs_restack: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pr: Option<ExportPr>,
+}
+
+/// A node's linked PR.
+#[derive(Debug, Clone, Serialize)]
+struct ExportPr {
+    number: u64,
+    url: String,
+    /// Cached status label, e.g. `#12 open, checks passed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+}
+
+impl GraphExport {
+    /// Collect `branches` and the roots they hang from.
+    pub(crate) fn new(
+        snapshot: &RepoSnapshot,
+        branches: &[BranchName],
+        forge_status: &ForgeStatusCache,
+        offline: bool,
+    ) -> Self {
+        let mut sorted = branches.to_vec();
+        sorted.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let sorted = super::restack::topological_sort(&sorted, snapshot);
+
+        let mut roots = Vec::new();
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for branch in &sorted {
+            let Some(entry) = snapshot.metadata.get(branch) else {
+                continue;
+            };
+            let metadata = &entry.metadata;
+            let parent = metadata.parent.name().to_string();
+            if !sorted.iter().any(|b| b.as_str() == parent) && !roots.contains(&parent) {
+                roots.push(parent.clone());
+            }
+            edges.push((parent, branch.to_string()));
+            nodes.push(ExportNode {
+                name: branch.to_string(),
+                tracked: true,
+                current: snapshot.current_branch.as_ref() == Some(branch),
+                frozen: metadata.freeze.is_frozen(),
+                needs_restack: needs_restack(snapshot, branch),
+                pr: match &metadata.pr {
+                    PrState::Linked { number, url, .. } => Some(ExportPr {
+                        number: *number,
+                        url: url.clone(),
+                        status: cached_pr_label(snapshot, forge_status, offline, branch),
+                    }),
+                    PrState::None => None,
+                },
+                ..Default::default()
+            });
+        }
+
+        roots.sort();
+        let roots = roots.into_iter().map(|name| ExportNode {
+            trunk: snapshot.trunk.as_ref().is_some_and(|t| t.as_str() == name),
+            current: snapshot
+                .current_branch
+                .as_ref()
+                .is_some_and(|c| c.as_str() == name),
+            name,
+            ..Default::default()
+        });
+        Self {
+            nodes: roots.chain(nodes).collect(),
+            edges,
+        }
+    }
+
+    /// Render in `format`.
+    pub(crate) fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.dot(),
+            GraphFormat::Mermaid => self.mermaid(),
+            GraphFormat::JsonGraph => self.json_graph(),
+        }
+    }
+
+    /// Graphviz digraph.
+    fn dot(&self) -> String {
+        let mut out = String::from("digraph lattice {\n    rankdir=TB;\n    node [shape=box];\n");
+        for node in &self.nodes {
+            let mut attrs = vec![format!("label=\"{}\"", dot_escape(&node.label()))];
+            let mut styles = Vec::new();
+            if node.current || node.trunk {
+                styles.push("bold");
+            }
+            if node.frozen {
+                styles.push("dashed");
+            }
+            if !styles.is_empty() {
+                attrs.push(format!("style=\"{}\"", styles.join(",")));
+            }
+            if let Some(pr) = &node.pr {
+                attrs.push(format!("URL=\"{}\"", dot_escape(&pr.url)));
+            }
+            out.push_str(&format!(
+                "    \"{}\" [{}];\n",
+                dot_escape(&node.name),
+                attrs.join(", ")
+            ));
+        }
+        for (parent, child) in &self.edges {
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                dot_escape(parent),
+                dot_escape(child)
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart.
+    fn mermaid(&self) -> String {
+        let ids: BTreeMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.name.as_str(), format!("n{}", i)))
+            .collect();
+
+        let mut out = String::from("graph TD\n");
+        for node in &self.nodes {
+            let label = node.label().replace('"', "#quot;").replace('\n', "<br/>");
+            out.push_str(&format!("    {}[\"{}\"]\n", ids[node.name.as_str()], label));
+        }
+        for (parent, child) in &self.edges {
+            out.push_str(&format!(
+                "    {} --> {}\n",
+                ids[parent.as_str()],
+                ids[child.as_str()]
+            ));
+        }
+        for node in &self.nodes {
+            let id = &ids[node.name.as_str()];
+            if let Some(pr) = &node.pr {
+                out.push_str(&format!("    click {} \"{}\"\n", id, pr.url));
+            }
+            if node.current {
+                out.push_str(&format!("    class {} current\n", id));
+            }
+            if node.frozen {
+                out.push_str(&format!("    class {} frozen\n", id));
+            }
+        }
+        out.push_str("    classDef current stroke-width:3px\n");
+        out.push_str("    classDef frozen stroke-dasharray:4\n");
+        out
+    }
+
+    /// JSON Graph Format document.
+    fn json_graph(&self) -> String {
+        #[derive(Serialize)]
+        struct Document<'a> {
+            graph: Graph<'a>,
+        }
+        #[derive(Serialize)]
+        struct Graph<'a> {
+            directed: bool,
+            label: &'static str,
+            nodes: BTreeMap<&'a str, Node<'a>>,
+            edges: Vec<Edge<'a>>,
+        }
+        #[derive(Serialize)]
+        struct Node<'a> {
+            label: &'a str,
+            metadata: &'a ExportNode,
+        }
+        #[derive(Serialize)]
+        struct Edge<'a> {
+            source: &'a str,
+            target: &'a str,
+            relation: &'static str,
+        }
+
+        let document = Document {
+            graph: Graph {
+                directed: true,
+                label: "lattice",
+                nodes: self
+                    .nodes
+                    .iter()
+                    .map(|node| {
+                        (
+                            node.name.as_str(),
+                            Node {
+                                label: &node.name,
+                                metadata: node,
+                            },
+                        )
+                    })
+                    .collect(),
+                edges: self
+                    .edges
+                    .iter()
+                    .map(|(parent, child)| Edge {
+                        source: parent,
+                        target: child,
+                        relation: "parent",
+                    })
+                    .collect(),
+            },
+        };
+        let mut json = serde_json::to_string_pretty(&document).unwrap_or_default();
+        json.push('\n');
+        json
+    }
+}
+
+impl ExportNode {
+    /// The branch name, with its PR (and cached status) on a second line.
+    fn label(&self) -> String {
+        match &self.pr {
+            Some(ExportPr {
+                status: Some(status),
+                ..
+            }) => format!("{}\n{}", self.name, status),
+            Some(pr) => format!("{}\n#{}", self.name, pr.number),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Escape a string for a double-quoted DOT id or attribute.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// main -> a (PR #12, current) -> b (frozen)
+    fn export() -> GraphExport {
+        GraphExport {
+            nodes: vec![
+                ExportNode {
+                    name: "main".into(),
+                    trunk: true,
+                    ..Default::default()
+                },
+                ExportNode {
+                    name: "a".into(),
+                    tracked: true,
+                    current: true,
+                    pr: Some(ExportPr {
+                        number: 12,
+                        url: "https://github.com/o/r/pull/12".into(),
+                        status: None,
+                    }),
+                    ..Default::default()
+                },
+                ExportNode {
+                    name: "b".into(),
+                    tracked: true,
+                    frozen: true,
+                    ..Default::default()
+                },
+            ],
+            edges: vec![("main".into(), "a".into()), ("a".into(), "b".into())],
+        }
+    }
+
+    #[test]
+    fn dot_links_prs_and_marks_state() {
+        let dot = export().render(GraphFormat::Dot);
+        assert!(dot.starts_with("digraph lattice {\n"));
+        assert!(dot.contains(
+            "\"a\" [label=\"a\\n#12\", style=\"bold\", URL=\"https://github.com/o/r/pull/12\"];"
+        ));
+        assert!(dot.contains("\"b\" [label=\"b\", style=\"dashed\"];"));
+        assert!(dot.contains("\"main\" -> \"a\";\n    \"a\" -> \"b\";"));
+        assert_eq!(dot_escape("x\"y\\z"), "x\\\"y\\\\z");
+    }
+
+    #[test]
+    fn mermaid_uses_generated_ids() {
+        let mermaid = export().render(GraphFormat::Mermaid);
+        assert!(mermaid.starts_with("graph TD\n"));
+        assert!(mermaid.contains("    n1[\"a<br/>#12\"]\n"));
+        assert!(mermaid.contains("    n0 --> n1\n    n1 --> n2\n"));
+        assert!(mermaid.contains("    click n1 \"https://github.com/o/r/pull/12\"\n"));
+        assert!(mermaid.contains("    class n1 current\n"));
+        assert!(mermaid.contains("    class n2 frozen\n"));
+    }
+
+    #[test]
+    fn json_graph_follows_jgf() {
+        let json: serde_json::Value =
+            serde_json::from_str(&export().render(GraphFormat::JsonGraph)).unwrap();
+        let graph = &json["graph"];
+        assert_eq!(graph["directed"], true);
+        assert_eq!(graph["nodes"]["main"]["metadata"]["trunk"], true);
+        assert_eq!(graph["nodes"]["a"]["metadata"]["pr"]["number"], 12);
+        assert_eq!(graph["nodes"]["b"]["metadata"]["frozen"], true);
+        assert_eq!(graph["edges"][1]["source"], "a");
+        assert_eq!(graph["edges"][1]["target"], "b");
+    }
+}
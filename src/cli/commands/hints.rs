@@ -0,0 +1,101 @@
+//! cli::commands::hints
+//!
+//! List contextual hints, dismiss them, or bring them back.
+//!
+//! # Design
+//!
+//! Hints ([`crate::ui::hints`]) keep their state in `~/.lattice/hints.toml`,
+//! outside any repository, so this command needs no repository and runs in
+//! read-only mode. Listing shows each hint's name, whether it is still
+//! shown, and its text; `--dismiss` turns one (or `all`) off and `--reset`
+//! forgets what was shown and dismissed.
+//!
+//! # Example
+//!
+//! ```bash
+//! lattice hints
+//! lattice hints --dismiss conflict
+//! lattice hints --reset
+//! ```
+
+use anyhow::Context as _;
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
+use crate::engine::Context;
+use crate::ui::hints::{Hint, HintState, MAX_SHOWS};
+use crate::ui::i18n;
+
+/// List hints, or dismiss or reset them.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `dismiss` - Name of a hint to stop showing, or `all`
+/// * `reset` - Show every hint again
+pub fn hints(ctx: &Context, dismiss: Option<&str>, reset: bool) -> CliResult {
+    let path = HintState::default_path().context("Could not determine the home directory")?;
+    let mut state = HintState::load(&path);
+
+    if reset {
+        HintState::default()
+            .save(&path)
+            .context("Failed to save hint state")?;
+        if !ctx.quiet {
+            println!("{}", i18n::t("hints-every-hint-shown"));
+        }
+        return Ok(());
+    }
+
+    if let Some(name) = dismiss {
+        let dismissed: Vec<Hint> = if name == "all" {
+            Hint::ALL.to_vec()
+        } else {
+            match Hint::parse(name) {
+                Some(hint) => vec![hint],
+                None => {
+                    return Err(CliError::usage(format!(
+                        "Unknown hint '{}'. Hints: {}, or all.",
+                        name,
+                        Hint::ALL
+                            .iter()
+                            .map(|hint| hint.name())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )))
+                }
+            }
+        };
+        for hint in &dismissed {
+            state.dismiss(*hint);
+        }
+        state.save(&path).context("Failed to save hint state")?;
+        if !ctx.quiet {
+            println!(
+                "{}",
+                i18n::t_args("hints-dismissed-hint", &[("count", &dismissed.len())])
+            );
+        }
+        return Ok(());
+    }
+
+    let enabled = Config::load(ctx.cwd.as_deref())
+        .map(|r| r.config.hints())
+        .unwrap_or(true);
+    if !enabled {
+        println!("{}", i18n::t("hints-off"));
+    }
+    for hint in Hint::ALL {
+        let status = if state.is_dismissed(*hint) {
+            "dismissed".to_string()
+        } else if !state.should_show(*hint) {
+            format!("retired after {} showings", MAX_SHOWS)
+        } else {
+            format!("shown {} of {} times", state.times_shown(*hint), MAX_SHOWS)
+        };
+        println!("{:<9} {}", hint.name(), status);
+        let text = i18n::t_args(hint.message_key(), &[("branch", &"<branch>")]);
+        println!("          {}", text);
+    }
+    Ok(())
+}
@@ -5,24 +5,127 @@
 //! This is a read-only command that implements `ReadOnlyCommand` and uses
 //! `requirements::READ_ONLY`. It flows through `run_readonly_command` to
 //! ensure proper gating.
+//!
+//! For a tracked branch it lists the commits unique to the branch (those
+//! after its base), newest first, like a short `git log`. `--patch` adds
+//! each commit's own patch; `--diff` shows the combined diff instead.
+//!
+//! With `--stack --stat`, it prints a diffstat for every tracked branch in the
+//! target's stack (each measured from its base) plus a cumulative stat for
+//! each stack tip versus trunk.
+//!
+//! `--files` lists the files the branch changes relative to its parent
+//! (from its base), each marked `A`, `M`, or `D` for added, modified, or
+//! deleted. With `--stack`, it lists them for every branch in the stack and
+//! then the files more than one branch changes, which are the ones to watch
+//! when reordering.
+//!
+//! With `output.accessible = true`, a tracked branch also gets a one-line
+//! summary in words (see `log_cmd::describe_branch`).
+//!
+//! A branch brought in from a remote (by `lattice get` or a doctor import)
+//! shows its owner and where it came from, so it is clear why it is frozen
+//! and whose it is.
+//!
+//! A linked PR's status is shown from the forge status cache when navigation
+//! has prefetched it (see `prefetch`).
+//!
+//! A tracked branch whose stack has drifted far behind trunk gets a warning
+//! (see `trunk_drift`).
+//!
+//! With `--remote-diff`, it fetches the branch from its remote and shows a
+//! `git range-diff` of the pushed commits against the local ones: what
+//! changed locally that reviewers of the PR haven't seen yet. When the fetch
+//! fails, the last fetched head is used.
+//!
+//! With `--json`, the same facts are printed as one JSON document, with
+//! `files` added by `--files`; `--diff`, `--stat`, `--patch`, `--stack`,
+//! and `--remote-diff` can't be combined with it.
 
-use crate::core::types::BranchName;
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
+use crate::core::metadata::schema::{IssueLink, PrState, Provenance};
+use crate::core::paths::LatticePaths;
+use crate::core::types::{BranchName, Oid};
+use crate::engine::capabilities::Capability;
 use crate::engine::command::ReadOnlyCommand;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::PlanError;
-use crate::engine::runner::{run_readonly_command, RunError};
+use crate::engine::runner::run_readonly_command;
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
-use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::forge::status_cache::ForgeStatusCache;
+use crate::git::{ChangedFile, CommitInfo, DiffStat, FileChange, Git};
+use crate::ui::i18n;
+use crate::ui::output::Verbosity;
+use anyhow::Context as _;
+use serde::Serialize;
 use std::path::PathBuf;
 
+use super::log_cmd::PrJson;
+use super::trunk_drift::{warn_drifted_stacks, DriftThresholds};
+
+/// Machine-readable `info` output (`--json`).
+///
+/// Fields describing metadata are `null` for untracked branches.
+#[derive(Debug, Serialize)]
+struct InfoReport {
+    branch: String,
+    tracked: bool,
+    current: bool,
+    tip: Option<String>,
+    parent: Option<String>,
+    parent_is_trunk: Option<bool>,
+    base: Option<String>,
+    frozen: Option<bool>,
+    freeze_reason: Option<String>,
+    pr: Option<PrJson>,
+    issue: Option<IssueLink>,
+    provenance: Option<Provenance>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+    /// Commits after the base, newest first
+    commits: Vec<CommitJson>,
+    /// Files changed since the base, with `--files`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<FileJson>>,
+}
+
+/// A changed file in `--json` output.
+#[derive(Debug, Serialize)]
+struct FileJson {
+    path: String,
+    /// `added`, `modified`, or `deleted`
+    change: &'static str,
+}
+
+/// A commit in `--json` output.
+#[derive(Debug, Serialize)]
+struct CommitJson {
+    oid: String,
+    summary: String,
+    author_name: String,
+    author_email: String,
+    author_time: String,
+}
+
 /// Command to show tracking status, parent, freeze state for a branch.
 pub struct InfoCommand<'a> {
+    git: &'a Git,
     cwd: PathBuf,
     branch: Option<&'a str>,
     diff: bool,
     stat: bool,
     patch: bool,
+    files: bool,
+    stack: bool,
+    remote_diff: bool,
+    accessible: bool,
+    drift: DriftThresholds,
+    forge_status: ForgeStatusCache,
+    offline: bool,
+    verbosity: Verbosity,
+    json: bool,
 }
 
 impl ReadOnlyCommand for InfoCommand<'_> {
@@ -34,10 +137,14 @@ impl ReadOnlyCommand for InfoCommand<'_> {
 
         // Resolve target branch
         let target = if let Some(name) = self.branch {
-            BranchName::new(name)
-                .map_err(|e| PlanError::InvalidState(format!("Invalid branch name: {}", e)))?
+            BranchName::new(name).map_err(PlanError::from)?
         } else if let Some(ref current) = snapshot.current_branch {
             current.clone()
+        } else if snapshot.has_lattice_op_in_progress() || snapshot.has_git_op_in_progress() {
+            // HEAD is usually detached mid-rebase
+            return Err(PlanError::InvalidState(
+                "Not on any branch while an operation is paused; pass a branch name".to_string(),
+            ));
         } else {
             return Err(PlanError::InvalidState(
                 "Not on any branch and no branch specified".to_string(),
@@ -52,12 +159,54 @@ impl ReadOnlyCommand for InfoCommand<'_> {
             )));
         }
 
+        if self.json {
+            return super::print_json(&self.report(snapshot, &target)?);
+        }
+
+        if self.stack {
+            if self.stat {
+                print!("{}", self.stack_stat(snapshot, &target)?);
+            }
+            if self.files {
+                if self.stat {
+                    println!();
+                }
+                print!("{}", self.stack_files(snapshot, &target)?);
+            }
+            return Ok(());
+        }
+
+        if self.remote_diff {
+            print!("{}", self.remote_diff(snapshot, &target)?);
+            return Ok(());
+        }
+
         // Check if tracked
         let metadata = snapshot.metadata.get(&target);
         let is_tracked = metadata.is_some();
 
-        println!("Branch: {}", target);
-        println!("Tracked: {}", if is_tracked { "yes" } else { "no" });
+        println!("{}", i18n::t_args("info-branch", &[("target", &target)]));
+        println!(
+            "{}",
+            i18n::t(if is_tracked {
+                "info-tracked-yes"
+            } else {
+                "info-tracked-no"
+            })
+        );
+        if self.accessible && is_tracked {
+            let is_current = snapshot.current_branch.as_ref() == Some(&target);
+            println!(
+                "{}",
+                i18n::t_args(
+                    "info-summary",
+                    &[(
+                        "summary",
+                        &(super::log_cmd::describe_branch(self.git, snapshot, &target, is_current))
+                    )],
+                )
+            );
+        }
 
         if let Some(m) = metadata {
             // Parent
@@ -67,47 +216,125 @@ impl ReadOnlyCommand for InfoCommand<'_> {
             } else {
                 ""
             };
-            println!("Parent: {}{}", parent_name, parent_type);
+            println!(
+                "{}",
+                i18n::t_args(
+                    "info-parent",
+                    &[("parent_name", &parent_name), ("parent_type", &parent_type)],
+                )
+            );
 
             // Base commit
-            println!("Base: {}", &m.metadata.base.oid);
+            println!(
+                "{}",
+                i18n::t_args("info-base", &[("oid", &&m.metadata.base.oid)])
+            );
 
             // Freeze state
             match &m.metadata.freeze {
                 crate::core::metadata::schema::FreezeState::Frozen { reason, .. } => {
-                    println!("Frozen: yes");
+                    println!("{}", i18n::t("info-frozen-yes"));
                     if let Some(r) = reason {
-                        println!("Freeze reason: {}", r);
+                        println!("{}", i18n::t_args("info-freeze-reason", &[("reason", &r)]));
                     }
                 }
                 crate::core::metadata::schema::FreezeState::Unfrozen => {
-                    println!("Frozen: no");
+                    println!("{}", i18n::t("info-frozen-no"));
                 }
             }
 
+            // Where the branch came from, and whose it is
+            if let Some(provenance) = &m.metadata.provenance {
+                if let Some(owner) = &provenance.owner {
+                    println!("{}", i18n::t_args("info-owner", &[("owner", &owner)]));
+                }
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "info-origin",
+                        &[
+                            ("describe", &provenance.describe()),
+                            ("recorded_at", &provenance.recorded_at),
+                        ],
+                    )
+                );
+            }
+
             // PR linkage
             match &m.metadata.pr {
                 crate::core::metadata::schema::PrState::Linked { number, url, .. } => {
-                    println!("PR: linked");
-                    println!("PR number: {}", number);
-                    println!("PR URL: {}", url);
+                    println!("{}", i18n::t("info-pr-linked"));
+                    println!("{}", i18n::t_args("info-pr-number", &[("number", &number)]));
+                    println!("{}", i18n::t_args("info-pr-url", &[("url", &url)]));
+                    if let Some(status) = self.forge_status.get(target.as_str(), *number) {
+                        let age = (chrono::Utc::now() - status.fetched_at).num_seconds();
+                        println!(
+                            "{}",
+                            i18n::t_args(
+                                "info-pr-status-fetched",
+                                &[("label", &status.label()), ("age", &format_age(age))],
+                            )
+                        );
+                    }
                 }
                 crate::core::metadata::schema::PrState::None => {
-                    println!("PR: none");
+                    println!("{}", i18n::t("info-pr-none"));
                 }
             }
+            if let Some(issue) = &m.metadata.issue {
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "info-issue",
+                        &[("number", &issue.number), ("url", &issue.url)],
+                    )
+                );
+            }
 
             // Timestamps
-            println!("Created: {}", m.metadata.timestamps.created_at);
-            println!("Updated: {}", m.metadata.timestamps.updated_at);
+            println!(
+                "{}",
+                i18n::t_args(
+                    "info-created",
+                    &[("created_at", &m.metadata.timestamps.created_at)],
+                )
+            );
+            println!(
+                "{}",
+                i18n::t_args(
+                    "info-updated",
+                    &[("updated_at", &m.metadata.timestamps.updated_at)],
+                )
+            );
+            warn_drifted_stacks(
+                self.git,
+                snapshot,
+                std::slice::from_ref(&target),
+                self.drift,
+                self.verbosity,
+            );
+
+            self.print_commits(snapshot, &target, &m.metadata.base.oid)?;
 
-            // Show diff/stat/patch if requested
-            if self.diff || self.stat || self.patch {
+            if self.files {
+                let files = branch_changed_files(self.git, snapshot, &target)?;
+                println!();
+                println!(
+                    "{}",
+                    i18n::t_args("info-files-changed", &[("count", &files.len())])
+                );
+                for line in format_changed_files(&files, "  ") {
+                    println!("{}", line);
+                }
+            }
+
+            // Show diff/stat if requested
+            if self.diff || self.stat {
                 let base_oid = &m.metadata.base.oid;
                 println!();
 
                 if self.stat {
-                    println!("--- Changes from base (stat) ---");
+                    println!("{}", i18n::t("info-changes-base-stat"));
                     let output = std::process::Command::new("git")
                         .args(["diff", "--stat", base_oid.as_str(), "HEAD"])
                         .current_dir(&self.cwd)
@@ -118,8 +345,8 @@ impl ReadOnlyCommand for InfoCommand<'_> {
                     print!("{}", String::from_utf8_lossy(&output.stdout));
                 }
 
-                if self.diff || self.patch {
-                    println!("--- Changes from base ---");
+                if self.diff {
+                    println!("{}", i18n::t("info-changes-base"));
                     let output = std::process::Command::new("git")
                         .args(["diff", base_oid.as_str(), "HEAD"])
                         .current_dir(&self.cwd)
@@ -133,7 +360,7 @@ impl ReadOnlyCommand for InfoCommand<'_> {
         } else {
             // Not tracked - show basic info
             if let Some(oid) = snapshot.branches.get(&target) {
-                println!("Tip: {}", oid);
+                println!("{}", i18n::t_args("info-tip", &[("oid", &oid)]));
             }
         }
 
@@ -141,6 +368,482 @@ impl ReadOnlyCommand for InfoCommand<'_> {
     }
 }
 
+impl InfoCommand<'_> {
+    /// Collect what `info` shows about `target` for `--json`.
+    fn report(
+        &self,
+        snapshot: &RepoSnapshot,
+        target: &BranchName,
+    ) -> Result<InfoReport, PlanError> {
+        use crate::core::metadata::schema::FreezeState;
+
+        let metadata = snapshot.metadata.get(target).map(|m| &m.metadata);
+        let tip = snapshot.branches.get(target);
+        let commits = match (metadata, tip) {
+            (Some(m), Some(tip)) => {
+                let base = Oid::new(&m.base.oid).map_err(|e| {
+                    PlanError::InvalidState(format!("Invalid base for '{}': {}", target, e))
+                })?;
+                self.git.commits_between(&base, tip).map_err(|e| {
+                    PlanError::InvalidState(format!("Failed to list commits: {}", e))
+                })?
+            }
+            _ => Vec::new(),
+        };
+
+        let files = if self.files && metadata.is_some() {
+            let files = branch_changed_files(self.git, snapshot, target)?;
+            Some(
+                files
+                    .into_iter()
+                    .map(|f| FileJson {
+                        path: f.path,
+                        change: match f.change {
+                            FileChange::Added => "added",
+                            FileChange::Modified => "modified",
+                            FileChange::Deleted => "deleted",
+                        },
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        Ok(InfoReport {
+            branch: target.to_string(),
+            tracked: metadata.is_some(),
+            current: snapshot.current_branch.as_ref() == Some(target),
+            tip: tip.map(|oid| oid.to_string()),
+            parent: metadata.map(|m| m.parent.name().to_string()),
+            parent_is_trunk: metadata.map(|m| m.parent.is_trunk()),
+            base: metadata.map(|m| m.base.oid.to_string()),
+            frozen: metadata.map(|m| m.freeze.is_frozen()),
+            freeze_reason: metadata.and_then(|m| match &m.freeze {
+                FreezeState::Frozen { reason, .. } => reason.clone(),
+                FreezeState::Unfrozen => None,
+            }),
+            pr: PrJson::new(snapshot, &self.forge_status, self.offline, target),
+            issue: metadata.and_then(|m| m.issue.clone()),
+            provenance: metadata.and_then(|m| m.provenance.clone()),
+            created_at: metadata.map(|m| m.timestamps.created_at.to_string()),
+            updated_at: metadata.map(|m| m.timestamps.updated_at.to_string()),
+            commits: commits
+                .into_iter()
+                .map(|c| CommitJson {
+                    oid: c.oid.to_string(),
+                    summary: c.summary,
+                    author_name: c.author_name,
+                    author_email: c.author_email,
+                    author_time: c.author_time.to_rfc3339(),
+                })
+                .collect(),
+            files,
+        })
+    }
+
+    /// Print the commits unique to the branch, newest first.
+    ///
+    /// With `--patch`, each commit is followed by its own patch.
+    fn print_commits(
+        &self,
+        snapshot: &RepoSnapshot,
+        target: &BranchName,
+        base: &str,
+    ) -> Result<(), PlanError> {
+        let Some(tip) = snapshot.branches.get(target) else {
+            return Ok(());
+        };
+        let base = Oid::new(base).map_err(|e| {
+            PlanError::InvalidState(format!("Invalid base for '{}': {}", target, e))
+        })?;
+        let commits = self
+            .git
+            .commits_between(&base, tip)
+            .map_err(|e| PlanError::InvalidState(format!("Failed to list commits: {}", e)))?;
+
+        println!();
+        println!(
+            "{}",
+            i18n::t_args("info-commits", &[("count", &commits.len())])
+        );
+        let now = chrono::Utc::now();
+        for commit in &commits {
+            println!("  {}", format_commit(commit, now));
+            if self.patch {
+                let output = std::process::Command::new("git")
+                    .args(["show", "--format=", "--patch", commit.oid.as_str()])
+                    .current_dir(&self.cwd)
+                    .output()
+                    .map_err(|e| {
+                        PlanError::InvalidState(format!("Failed to run git show: {}", e))
+                    })?;
+                print!("{}", String::from_utf8_lossy(&output.stdout));
+                println!();
+            }
+        }
+        Ok(())
+    }
+
+    /// Render per-branch and cumulative diffstats for the target's stack.
+    fn stack_stat(
+        &self,
+        snapshot: &RepoSnapshot,
+        target: &BranchName,
+    ) -> Result<String, PlanError> {
+        let stack = stack_branches(snapshot, target);
+        if stack.is_empty() {
+            return Err(PlanError::UntrackedBranch(target.to_string()));
+        }
+
+        let stat = |base: &Oid, tip: &Oid| {
+            self.git
+                .diff_stat(base, tip)
+                .map_err(|e| PlanError::InvalidState(format!("Failed to compute diff: {}", e)))
+        };
+
+        let mut rows = Vec::with_capacity(stack.len());
+        for branch in &stack {
+            let (Some(entry), Some(tip)) =
+                (snapshot.metadata.get(branch), snapshot.branches.get(branch))
+            else {
+                continue;
+            };
+            let base = Oid::new(&entry.metadata.base.oid).map_err(|e| {
+                PlanError::InvalidState(format!("Invalid base for '{}': {}", branch, e))
+            })?;
+            rows.push((
+                branch.clone(),
+                entry.metadata.parent.name().to_string(),
+                stat(&base, tip)?,
+            ));
+        }
+
+        // Cumulative stat for each tip of the stack, measured from where it
+        // forked off trunk
+        let trunk_tip = snapshot
+            .trunk
+            .as_ref()
+            .and_then(|t| snapshot.branches.get(t));
+        let mut cumulative = Vec::new();
+        if let Some(trunk_tip) = trunk_tip {
+            for leaf in stack.iter().filter(|b| {
+                snapshot
+                    .graph
+                    .children(b)
+                    .is_none_or(|children| children.iter().all(|c| !stack.contains(c)))
+            }) {
+                let Some(tip) = snapshot.branches.get(leaf) else {
+                    continue;
+                };
+                let fork = self.git.merge_base(trunk_tip, tip).map_err(|e| {
+                    PlanError::InvalidState(format!("Failed to find merge base: {}", e))
+                })?;
+                if let Some(fork) = fork {
+                    cumulative.push((leaf.clone(), stat(&fork, tip)?));
+                }
+            }
+        }
+
+        Ok(format_stack_stat(
+            &rows,
+            &cumulative,
+            snapshot.trunk.as_ref(),
+        ))
+    }
+}
+
+impl InfoCommand<'_> {
+    /// List each stack branch's changed files, then the files that more
+    /// than one of them changes.
+    fn stack_files(
+        &self,
+        snapshot: &RepoSnapshot,
+        target: &BranchName,
+    ) -> Result<String, PlanError> {
+        let stack = stack_branches(snapshot, target);
+        if stack.is_empty() {
+            return Err(PlanError::UntrackedBranch(target.to_string()));
+        }
+
+        let mut rows = Vec::with_capacity(stack.len());
+        for branch in &stack {
+            let Some(entry) = snapshot.metadata.get(branch) else {
+                continue;
+            };
+            rows.push((
+                branch.clone(),
+                entry.metadata.parent.name().to_string(),
+                branch_changed_files(self.git, snapshot, branch)?,
+            ));
+        }
+        Ok(format_stack_files(&rows))
+    }
+
+    /// Range-diff the branch's remote head against its local commits.
+    fn remote_diff(
+        &self,
+        snapshot: &RepoSnapshot,
+        target: &BranchName,
+    ) -> Result<String, PlanError> {
+        let (Some(entry), Some(tip)) =
+            (snapshot.metadata.get(target), snapshot.branches.get(target))
+        else {
+            return Err(PlanError::UntrackedBranch(target.to_string()));
+        };
+        let remote = entry.metadata.remote_or("origin");
+        let tracking = format!("refs/remotes/{}/{}", remote, target);
+
+        // The PR shows whatever the remote branch holds right now. Fetching
+        // updates the tracking ref, so read-only mode uses the last fetch.
+        let writes_allowed = snapshot
+            .health
+            .capabilities()
+            .has(&Capability::WritesAllowed);
+        let refspec = format!("+refs/heads/{}:{}", target, tracking);
+        let fetched = if writes_allowed {
+            self.git.fetch_ref(remote, &refspec)
+        } else {
+            match self.git.try_resolve_ref(&tracking) {
+                Ok(Some(oid)) => Ok(oid),
+                _ => {
+                    return Err(PlanError::InvalidState(format!(
+                    "Branch '{}' has not been fetched from '{}' (read-only mode does not fetch)",
+                    target, remote
+                )))
+                }
+            }
+        };
+        let remote_head = match fetched {
+            Ok(oid) => oid,
+            Err(e) => match self.git.try_resolve_ref(&tracking) {
+                Ok(Some(oid)) => {
+                    eprintln!(
+                        "{}",
+                        i18n::t_args(
+                            "info-warning-could-fetch",
+                            &[("target", &target), ("remote", &remote), ("error", &e)],
+                        )
+                    );
+                    oid
+                }
+                _ => {
+                    return Err(PlanError::InvalidState(format!(
+                        "Branch '{}' is not on '{}': {}",
+                        target, remote, e
+                    )))
+                }
+            },
+        };
+
+        let shown = match &entry.metadata.pr {
+            PrState::Linked { number, .. } => format!("{}/{} (PR #{})", remote, target, number),
+            PrState::None => format!("{}/{}", remote, target),
+        };
+        if remote_head == *tip {
+            return Ok(format!(
+                "'{}' matches {}; nothing to show reviewers.\n",
+                target, shown
+            ));
+        }
+
+        // The pushed commits start where the remote head forked from the
+        // current base, which is the old base when the branch was restacked
+        let base = Oid::new(&entry.metadata.base.oid).map_err(|e| {
+            PlanError::InvalidState(format!("Invalid base for '{}': {}", target, e))
+        })?;
+        let remote_base = self
+            .git
+            .merge_base(&remote_head, &base)
+            .map_err(|e| PlanError::InvalidState(format!("Failed to find merge base: {}", e)))?
+            .unwrap_or_else(|| base.clone());
+
+        let result = self
+            .git
+            .run_command(&[
+                "range-diff".to_string(),
+                "--no-color".to_string(),
+                format!("{}..{}", remote_base, remote_head),
+                format!("{}..{}", base, tip),
+            ])
+            .map_err(|e| PlanError::InvalidState(format!("Failed to run git range-diff: {}", e)))?;
+        if !result.success {
+            return Err(PlanError::InvalidState(format!(
+                "git range-diff failed: {}",
+                result.stderr.trim()
+            )));
+        }
+
+        Ok(format!(
+            "--- Local changes not on {} (range-diff) ---\n{}",
+            shown, result.stdout
+        ))
+    }
+}
+
+/// Format one commit as `<short sha> <subject> (<author>, <age>)`.
+fn format_commit(commit: &CommitInfo, now: chrono::DateTime<chrono::Utc>) -> String {
+    format!(
+        "{} {} ({}, {})",
+        commit.oid.short(7),
+        commit.summary,
+        commit.author_name,
+        format_age((now - commit.author_time).num_seconds())
+    )
+}
+
+/// Format an age in seconds like git's relative dates ("3 days ago").
+fn format_age(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (count, unit) = match secs {
+        0..60 => return "just now".to_string(),
+        60..3_600 => (secs / 60, "minute"),
+        3_600..86_400 => (secs / 3_600, "hour"),
+        86_400..604_800 => (secs / 86_400, "day"),
+        604_800..2_592_000 => (secs / 604_800, "week"),
+        2_592_000..31_536_000 => (secs / 2_592_000, "month"),
+        _ => (secs / 31_536_000, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{} {}{} ago", count, unit, plural)
+}
+
+/// Tracked branches in the target's stack, ordered from trunk outward.
+fn stack_branches(snapshot: &RepoSnapshot, target: &BranchName) -> Vec<BranchName> {
+    if !snapshot.metadata.contains_key(target) {
+        return Vec::new();
+    }
+
+    let mut stack = snapshot.graph.ancestors(target);
+    stack.reverse();
+    stack.push(target.clone());
+
+    let descendants = snapshot.graph.descendants(target);
+    stack.extend(
+        snapshot
+            .graph
+            .topological_order()
+            .into_iter()
+            .filter(|b| descendants.contains(b)),
+    );
+
+    stack.retain(|b| snapshot.metadata.contains_key(b));
+    stack
+}
+
+/// Format the stack diffstat table.
+fn format_stack_stat(
+    rows: &[(BranchName, String, DiffStat)],
+    cumulative: &[(BranchName, DiffStat)],
+    trunk: Option<&BranchName>,
+) -> String {
+    let width = rows
+        .iter()
+        .map(|(b, _, _)| b.as_str().len())
+        .chain(std::iter::once("Branch".len()))
+        .max()
+        .unwrap_or(0);
+    let parent_width = rows
+        .iter()
+        .map(|(_, p, _)| p.len())
+        .chain(std::iter::once("Parent".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!(
+        "{:<width$}  {:<parent_width$}  {:>6}  {:>8}  {:>8}\n",
+        "Branch", "Parent", "Files", "Added", "Deleted"
+    );
+    for (branch, parent, stat) in rows {
+        out.push_str(&format!(
+            "{:<width$}  {:<parent_width$}  {:>6}  {:>8}  {:>8}\n",
+            branch.as_str(),
+            parent,
+            stat.files_changed,
+            format!("+{}", stat.insertions),
+            format!("-{}", stat.deletions),
+        ));
+    }
+
+    if !cumulative.is_empty() {
+        out.push('\n');
+        let trunk = trunk.map(|t| t.as_str()).unwrap_or("trunk");
+        for (leaf, stat) in cumulative {
+            out.push_str(&format!(
+                "Cumulative vs {} at '{}': {} file(s), +{} -{}\n",
+                trunk, leaf, stat.files_changed, stat.insertions, stat.deletions
+            ));
+        }
+    }
+    out
+}
+
+/// Files a tracked branch changes relative to its parent, measured from
+/// its base. Empty for a branch without a tip.
+pub(crate) fn branch_changed_files(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+) -> Result<Vec<ChangedFile>, PlanError> {
+    let (Some(entry), Some(tip)) = (snapshot.metadata.get(branch), snapshot.branches.get(branch))
+    else {
+        return Ok(Vec::new());
+    };
+    let base = Oid::new(&entry.metadata.base.oid)
+        .map_err(|e| PlanError::InvalidState(format!("Invalid base for '{}': {}", branch, e)))?;
+    git.changed_files(&base, tip)
+        .map_err(|e| PlanError::InvalidState(format!("Failed to compute diff: {}", e)))
+}
+
+/// One line per file, `indent` then its `A`/`M`/`D` marker and path.
+pub(crate) fn format_changed_files(files: &[ChangedFile], indent: &str) -> Vec<String> {
+    files
+        .iter()
+        .map(|f| format!("{}{} {}", indent, f.change.marker(), f.path))
+        .collect()
+}
+
+/// Format each stack branch's changed files, then the files changed by more
+/// than one branch.
+fn format_stack_files(rows: &[(BranchName, String, Vec<ChangedFile>)]) -> String {
+    let mut out = String::new();
+    for (branch, parent, files) in rows {
+        out.push_str(&format!("{} (on {})\n", branch, parent));
+        if files.is_empty() {
+            out.push_str("  (no changes)\n");
+        }
+        for line in format_changed_files(files, "  ") {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+
+    let mut paths: Vec<&str> = rows
+        .iter()
+        .flat_map(|(_, _, files)| files.iter().map(|f| f.path.as_str()))
+        .collect();
+    paths.sort_unstable();
+    paths.dedup();
+    let overlaps: Vec<(&str, Vec<&str>)> = paths
+        .into_iter()
+        .filter_map(|path| {
+            let branches: Vec<&str> = rows
+                .iter()
+                .filter(|(_, _, files)| files.iter().any(|f| f.path == path))
+                .map(|(branch, _, _)| branch.as_str())
+                .collect();
+            (branches.len() > 1).then_some((path, branches))
+        })
+        .collect();
+    if !overlaps.is_empty() {
+        out.push_str("\nChanged by more than one branch:\n");
+        for (path, branches) in overlaps {
+            out.push_str(&format!("  {}: {}\n", path, branches.join(", ")));
+        }
+    }
+    out
+}
+
 /// Show tracking status, parent, freeze state for a branch.
 ///
 /// # Arguments
@@ -149,18 +852,36 @@ impl ReadOnlyCommand for InfoCommand<'_> {
 /// * `branch` - Branch to show info for (defaults to current)
 /// * `diff` - Show diff from base
 /// * `stat` - Show stat from base
-/// * `patch` - Show full patch from base
+/// * `patch` - Show each commit's patch
+/// * `files` - List the files changed from base
+/// * `stack` - Show stats or files for the whole stack instead of one branch
+/// * `remote_diff` - Range-diff the remote head against the local commits
+/// * `json` - Print a JSON document instead of text
 ///
 /// # Gating
 ///
 /// Uses `requirements::READ_ONLY` via `ReadOnlyCommand` trait.
+#[allow(clippy::too_many_arguments)]
 pub fn info(
     ctx: &Context,
     branch: Option<&str>,
     diff: bool,
     stat: bool,
     patch: bool,
-) -> Result<()> {
+    files: bool,
+    stack: bool,
+    remote_diff: bool,
+    json: bool,
+) -> CliResult {
+    if json && (diff || stat || patch || stack || remote_diff) {
+        return Err(CliError::usage(
+            "--json can't be combined with --diff, --stat, --patch, --stack, or --remote-diff",
+        ));
+    }
+    if stack && !stat && !files {
+        return Err(CliError::usage("--stack needs --stat or --files"));
+    }
+
     let cwd = ctx
         .cwd
         .clone()
@@ -168,17 +889,76 @@ pub fn info(
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
     let cmd = InfoCommand {
+        git: &git,
         cwd: cwd.clone(),
         branch,
         diff,
         stat,
         patch,
+        files,
+        stack,
+        remote_diff,
+        accessible: Config::load(Some(&cwd))
+            .map(|r| r.config.output_accessible())
+            .unwrap_or(false),
+        drift: DriftThresholds::load(&cwd),
+        forge_status: ForgeStatusCache::load(&LatticePaths::from_repo_info(&git.info()?)),
+        offline: ctx.offline,
+        verbosity: Verbosity::from_flags(ctx.quiet, ctx.debug),
+        json,
     };
 
-    run_readonly_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_age_uses_largest_unit() {
+        assert_eq!(format_age(-5), "just now");
+        assert_eq!(format_age(59), "just now");
+        assert_eq!(format_age(60), "1 minute ago");
+        assert_eq!(format_age(2 * 3_600 + 5), "2 hours ago");
+        assert_eq!(format_age(3 * 86_400), "3 days ago");
+        assert_eq!(format_age(14 * 86_400), "2 weeks ago");
+        assert_eq!(format_age(90 * 86_400), "3 months ago");
+        assert_eq!(format_age(800 * 86_400), "2 years ago");
+    }
+
+    #[test]
+    fn stack_files_point_out_overlaps() {
+        let file = |path: &str, change| ChangedFile {
+            path: path.to_string(),
+            change,
+        };
+        let rows = vec![
+            (
+                BranchName::new("api").unwrap(),
+                "main".to_string(),
+                vec![
+                    file("src/lib.rs", FileChange::Modified),
+                    file("src/new.rs", FileChange::Added),
+                ],
+            ),
+            (BranchName::new("docs").unwrap(), "api".to_string(), vec![]),
+            (
+                BranchName::new("cleanup").unwrap(),
+                "docs".to_string(),
+                vec![
+                    file("src/lib.rs", FileChange::Modified),
+                    file("src/old.rs", FileChange::Deleted),
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            format_stack_files(&rows),
+            "api (on main)\n  M src/lib.rs\n  A src/new.rs\n\
+             docs (on api)\n  (no changes)\n\
+             cleanup (on docs)\n  M src/lib.rs\n  D src/old.rs\n\
+             \nChanged by more than one branch:\n  src/lib.rs: api, cleanup\n"
+        );
+    }
 }
@@ -5,6 +5,7 @@
 //! Uses `requirements::MINIMAL` (just `RepoOpen`) - this is the command that
 //! sets up trunk configuration, so it can't require `TrunkKnown`.
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::config::{Config, RepoConfig};
 use crate::core::metadata::store::MetadataStore;
 use crate::core::types::BranchName;
@@ -12,7 +13,10 @@ use crate::engine::gate::requirements;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::hints::{self, Hint};
+use crate::ui::i18n;
+use crate::ui::prompts::{self, PromptError, Severity};
+use anyhow::Context as _;
 use std::io::{self, Write};
 
 /// Initialize Lattice in this repository.
@@ -27,7 +31,7 @@ use std::io::{self, Write};
 /// # Gating
 ///
 /// Uses `requirements::MINIMAL` since trunk may not be configured yet.
-pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Result<()> {
+pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -41,25 +45,32 @@ pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Res
 
     if already_initialized && !reset {
         if !ctx.quiet {
-            println!("Lattice is already initialized in this repository.");
-            println!("Use --reset to reconfigure.");
+            println!("{}", i18n::t("init-lattice-already-initialized"));
+            println!("{}", i18n::t("init-use-reset-reconfigure"));
         }
         return Ok(());
     }
 
     // Handle reset - this needs gating
     if reset {
-        if !force && ctx.interactive {
-            print!("This will delete all branch metadata. Continue? [y/N] ");
-            io::stdout().flush()?;
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
-            if !input.trim().eq_ignore_ascii_case("y") {
-                println!("Aborted.");
-                return Ok(());
+        if !force {
+            match prompts::confirm(
+                &i18n::t("prompt-init-reset"),
+                Severity::Destructive,
+                ctx.interactive,
+            ) {
+                Ok(true) => {}
+                Ok(false) => {
+                    println!("{}", i18n::t("prompt-aborted"));
+                    return Ok(());
+                }
+                Err(PromptError::NotInteractive) => {
+                    return Err(CliError::usage(
+                        "Use --force to reset in non-interactive mode",
+                    ))
+                }
+                Err(e) => return Err(e.into()),
             }
-        } else if !force {
-            bail!("Use --force to reset in non-interactive mode");
         }
 
         // Delete all metadata refs
@@ -70,20 +81,32 @@ pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Res
             match store.read(&branch) {
                 Ok(Some(scanned)) => {
                     if let Err(e) = store.delete_cas(&branch, &scanned.ref_oid) {
-                        eprintln!("Warning: failed to delete metadata for {}: {}", branch, e);
+                        eprintln!(
+                            "{}",
+                            i18n::t_args(
+                                "init-warning-failed-delete",
+                                &[("branch", &branch), ("error", &e)],
+                            )
+                        );
                     }
                 }
                 Ok(None) => {
                     // Already deleted
                 }
                 Err(e) => {
-                    eprintln!("Warning: failed to read metadata for {}: {}", branch, e);
+                    eprintln!(
+                        "{}",
+                        i18n::t_args(
+                            "init-warning-failed-read",
+                            &[("branch", &branch), ("error", &e)],
+                        )
+                    );
                 }
             }
         }
 
         if !ctx.quiet {
-            println!("Cleared all branch metadata.");
+            println!("{}", i18n::t("init-cleared-all-branch"));
         }
     }
 
@@ -116,11 +139,11 @@ pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Res
                 )));
             }
 
-            println!("Select trunk branch:");
+            println!("{}", i18n::t("init-select-trunk-branch"));
             for (i, branch) in branches.iter().enumerate() {
                 println!("  {}. {}", i + 1, branch);
             }
-            print!("Enter number [1]: ");
+            print!("{} ", i18n::t("init-enter-number"));
             io::stdout().flush().map_err(|e| {
                 RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                     "Failed to flush stdout: {}",
@@ -189,7 +212,7 @@ pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Res
         })?;
 
         if !ctx.quiet {
-            println!("Initialized Lattice with trunk: {}", trunk_name);
+            println!("{}", i18n::t_args("init-initialized-lattice-trunk", &[("trunk_name", &trunk_name)]));
         }
 
         Ok(trunk_name)
@@ -200,13 +223,11 @@ pub fn init(ctx: &Context, trunk: Option<&str>, reset: bool, force: bool) -> Res
         if !reset && !ctx.quiet {
             show_bootstrap_hint_sync(&git, &trunk_name);
         }
-    })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
+        if !reset {
+            hints::show(Hint::Init, &[]);
         }
-        other => anyhow::anyhow!("{}", other),
     })
+    .map_err(CliError::from)
 }
 
 /// Show a hint about open PRs that can be imported via `lattice doctor`.
@@ -253,34 +274,35 @@ async fn maybe_show_bootstrap_hint(git: &Git) {
 /// - Remote URL cannot be resolved
 /// - Remote is not a GitHub URL
 /// - API call fails (network, auth, rate limit, etc.)
-async fn try_show_bootstrap_hint(git: &Git) -> Result<()> {
+async fn try_show_bootstrap_hint(git: &Git) -> CliResult {
     use crate::auth::{has_github_auth, TokenProvider};
-    use crate::forge::github::{parse_github_url, GitHubForge};
+    use crate::forge::github::{parse_github_remote, GitHubForge};
     use crate::forge::{Forge, ListPullsOpts};
 
-    // Check if GitHub auth is available (quick local check, no network)
-    if !has_github_auth("github.com") {
-        return Ok(()); // No auth, skip silently
-    }
-
     // Get remote URL (prefer "origin")
     let remote_url = git
         .remote_url("origin")?
-        .ok_or_else(|| anyhow::anyhow!("no origin remote"))?;
+        .ok_or_else(|| CliError::usage("no origin remote"))?;
+
+    // Parse the GitHub host and owner/repo from remote URL
+    let remote =
+        parse_github_remote(&remote_url).ok_or_else(|| CliError::usage("not a GitHub remote"))?;
 
-    // Parse GitHub owner/repo from remote URL
-    let (owner, repo) =
-        parse_github_url(&remote_url).ok_or_else(|| anyhow::anyhow!("not a GitHub remote"))?;
+    // Check if GitHub auth is available for that host (quick local check, no network)
+    if !has_github_auth(&remote.host) {
+        return Ok(()); // No auth, skip silently
+    }
 
     // Create forge with TokenProvider for automatic refresh
     use std::sync::Arc;
 
     let store = crate::secrets::create_store(crate::secrets::DEFAULT_PROVIDER)?;
-    let auth_manager = crate::auth::GitHubAuthManager::new("github.com", store);
+    let auth_manager = crate::auth::GitHubAuthManager::new(&remote.host, store);
     let provider: Arc<dyn TokenProvider> = Arc::new(auth_manager);
 
     // Create forge and check for open PRs (small limit for quick detection)
-    let forge = GitHubForge::new_with_provider(provider, owner, repo);
+    let forge = GitHubForge::from_enterprise_url_with_provider(&remote_url, provider)
+        .ok_or_else(|| CliError::usage("not a GitHub remote"))?;
     let opts = ListPullsOpts::with_limit(10);
     let result = forge.list_open_prs(opts).await?;
 
@@ -288,12 +310,15 @@ async fn try_show_bootstrap_hint(git: &Git) -> Result<()> {
     if !result.pulls.is_empty() {
         let count = result.pulls.len();
         let suffix = if result.truncated { "+" } else { "" };
-        let pr_word = if count == 1 { "PR" } else { "PRs" };
-        let pronoun = if count == 1 { "it" } else { "them" };
+        let key = if count == 1 {
+            "init-found-open-pr"
+        } else {
+            "init-found-open-prs"
+        };
 
         println!(
-            "Found {}{} open {}. Run `lattice doctor` to import {}.",
-            count, suffix, pr_word, pronoun
+            "{}",
+            i18n::t_args(key, &[("count", &count), ("suffix", &suffix)])
         );
     }
 
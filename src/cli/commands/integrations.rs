@@ -0,0 +1,344 @@
+//! cli::commands::integrations
+//!
+//! Write editor configuration that drives Lattice from the editor.
+//!
+//! # Design
+//!
+//! `lattice integrations install <editor>` generates the glue users would
+//! otherwise hand-roll: entries that run porcelain commands (`lt log`,
+//! `lt submit --stack`, ...) and the machine-readable ones (`lt stats --json`,
+//! `lt doctor --list`). Both editors get the same set of actions.
+//!
+//! - `vscode`: tasks in the repository's `.vscode/tasks.json`. An existing
+//!   file is merged: tasks labelled `Lattice: ...` are replaced and all other
+//!   tasks and settings are kept. A file that isn't plain JSON (VS Code
+//!   allows comments) is only replaced with `--force`.
+//! - `nvim`: user commands (`:LtLog`, `:LtSubmit`, ...) in
+//!   `$XDG_CONFIG_HOME/nvim/plugin/lattice.lua` (default `~/.config`).
+//!   A previously generated file is updated; any other file at that path is
+//!   only replaced with `--force`.
+//!
+//! # Example
+//!
+//! ```bash
+//! lattice integrations install vscode
+//! lattice integrations install nvim
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::cli::args::Editor;
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// How an editor presents an action's output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presentation {
+    /// Read-only output in a scratch buffer.
+    Show,
+    /// JSON output, decoded for display where the editor can.
+    Json,
+    /// An interactive terminal, for commands that may prompt.
+    Terminal,
+}
+
+/// An editor entry point for one Lattice command.
+struct Action {
+    /// Task label suffix and command description.
+    label: &'static str,
+    /// Neovim user command name.
+    nvim_command: &'static str,
+    /// Shell command to run.
+    command: &'static str,
+    presentation: Presentation,
+}
+
+const ACTIONS: &[Action] = &[
+    Action {
+        label: "View stack tree",
+        nvim_command: "LtLog",
+        command: "lt log --all",
+        presentation: Presentation::Show,
+    },
+    Action {
+        label: "Submit current stack",
+        nvim_command: "LtSubmit",
+        command: "lt submit --stack",
+        presentation: Presentation::Terminal,
+    },
+    Action {
+        label: "Restack",
+        nvim_command: "LtRestack",
+        command: "lt restack",
+        presentation: Presentation::Terminal,
+    },
+    Action {
+        label: "Sync with remote",
+        nvim_command: "LtSync",
+        command: "lt sync",
+        presentation: Presentation::Terminal,
+    },
+    Action {
+        label: "Branch stats (JSON)",
+        nvim_command: "LtStats",
+        command: "lt stats --json",
+        presentation: Presentation::Json,
+    },
+    Action {
+        label: "Doctor issues",
+        nvim_command: "LtDoctor",
+        command: "lt doctor --list",
+        presentation: Presentation::Show,
+    },
+];
+
+/// Prefix of every generated VS Code task label.
+const TASK_LABEL_PREFIX: &str = "Lattice: ";
+
+/// First line of the generated Neovim plugin, used to recognize it.
+const NVIM_HEADER: &str = "-- Generated by `lt integrations install nvim`; re-run it to update.";
+
+/// Install editor integration files.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `editor` - Editor to configure
+/// * `force` - Replace files that can't be merged or weren't generated
+pub fn install(ctx: &Context, editor: Editor, force: bool) -> CliResult {
+    let (path, content) = match editor {
+        Editor::Vscode => {
+            let path = vscode_tasks_path(ctx)?;
+            let existing = read_existing(&path)?;
+            (path, vscode_tasks(existing.as_deref(), force)?)
+        }
+        Editor::Nvim => {
+            let path = nvim_plugin_path()?;
+            let existing = read_existing(&path)?;
+            if existing
+                .as_deref()
+                .is_some_and(|e| !e.starts_with(NVIM_HEADER))
+                && !force
+            {
+                return Err(CliError::conflict(format!("{} exists and was not generated by Lattice. Re-run with --force to replace it.",
+                    path.display())));
+            }
+            (path, nvim_plugin())
+        }
+    };
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "integrations-wrote-actions",
+                &[("path", &path.display()), ("count", &ACTIONS.len())],
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Read a file if it exists.
+fn read_existing(path: &Path) -> CliResult<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(CliError::new(
+            ErrorCategory::Io,
+            format!("Failed to read {}: {}", path.display(), e),
+        )),
+    }
+}
+
+/// `.vscode/tasks.json` in the repository's working directory.
+fn vscode_tasks_path(ctx: &Context) -> CliResult<PathBuf> {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let work_dir = git
+        .info()?
+        .work_dir
+        .context("VS Code tasks need a working directory; this is a bare repository.")?;
+    Ok(work_dir.join(".vscode").join("tasks.json"))
+}
+
+/// `plugin/lattice.lua` in the Neovim config directory.
+fn nvim_plugin_path() -> CliResult<PathBuf> {
+    let config = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => dirs::home_dir()
+            .context("Could not determine the home directory")?
+            .join(".config"),
+    };
+    Ok(config.join("nvim").join("plugin").join("lattice.lua"))
+}
+
+/// Build `tasks.json`, merging the Lattice tasks into `existing`.
+fn vscode_tasks(existing: Option<&str>, force: bool) -> CliResult<String> {
+    let mut root = match existing.map(serde_json::from_str::<serde_json::Value>) {
+        None => serde_json::json!({ "version": "2.0.0" }),
+        Some(Ok(value)) if value.is_object() => value,
+        Some(_) if force => serde_json::json!({ "version": "2.0.0" }),
+        Some(_) => {
+            return Err(CliError::conflict(
+                ".vscode/tasks.json is not plain JSON (comments are not supported). \
+             Re-run with --force to replace it.",
+            ))
+        }
+    };
+
+    let mut tasks: Vec<serde_json::Value> = root
+        .get("tasks")
+        .and_then(|t| t.as_array())
+        .cloned()
+        .unwrap_or_default();
+    tasks.retain(|task| {
+        !task
+            .get("label")
+            .and_then(|l| l.as_str())
+            .is_some_and(|l| l.starts_with(TASK_LABEL_PREFIX))
+    });
+    tasks.extend(ACTIONS.iter().map(|action| {
+        // Commands that may prompt take focus so the prompt can be answered
+        serde_json::json!({
+            "label": format!("{}{}", TASK_LABEL_PREFIX, action.label),
+            "type": "shell",
+            "command": action.command,
+            "problemMatcher": [],
+            "presentation": {
+                "reveal": "always",
+                "panel": "dedicated",
+                "focus": action.presentation == Presentation::Terminal,
+            },
+        })
+    }));
+    root["tasks"] = serde_json::Value::Array(tasks);
+
+    let mut out = serde_json::to_string_pretty(&root)?;
+    out.push('\n');
+    Ok(out)
+}
+
+/// Build the Neovim plugin defining one user command per action.
+fn nvim_plugin() -> String {
+    let mut out = String::from(NVIM_HEADER);
+    out.push_str(
+        r#"
+
+local function scratch(lines)
+  vim.cmd("botright new")
+  local buf = vim.api.nvim_get_current_buf()
+  vim.bo[buf].buftype = "nofile"
+  vim.bo[buf].bufhidden = "wipe"
+  vim.api.nvim_buf_set_lines(buf, 0, -1, false, lines)
+  vim.bo[buf].modifiable = false
+end
+
+local function show(cmd)
+  scratch(vim.fn.systemlist(cmd))
+end
+
+local function json(cmd)
+  local out = vim.fn.system(cmd)
+  local ok, data = pcall(vim.json.decode, out)
+  scratch(vim.split(ok and vim.inspect(data) or out, "\n"))
+end
+
+local function terminal(cmd)
+  vim.cmd("botright split | terminal " .. cmd)
+end
+
+"#,
+    );
+
+    for action in ACTIONS {
+        let runner = match action.presentation {
+            Presentation::Show => "show",
+            Presentation::Json => "json",
+            Presentation::Terminal => "terminal",
+        };
+        out.push_str(&format!(
+            "vim.api.nvim_create_user_command(\"{}\", function() {}(\"{}\") end, {{ desc = \"Lattice: {}\" }})\n",
+            action.nvim_command, runner, action.command, action.label
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(tasks_json: &str) -> Vec<String> {
+        let value: serde_json::Value = serde_json::from_str(tasks_json).unwrap();
+        value["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["label"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn vscode_tasks_from_scratch() {
+        let out = vscode_tasks(None, false).unwrap();
+        let labels = labels(&out);
+        assert_eq!(labels.len(), ACTIONS.len());
+        assert!(labels.contains(&"Lattice: Submit current stack".to_string()));
+        assert!(out.contains("\"version\": \"2.0.0\""));
+    }
+
+    #[test]
+    fn vscode_tasks_merge_keeps_user_tasks() {
+        let existing = r#"{
+            "version": "2.0.0",
+            "tasks": [
+                { "label": "build", "type": "shell", "command": "make" },
+                { "label": "Lattice: Old task", "type": "shell", "command": "lt old" }
+            ]
+        }"#;
+
+        let out = vscode_tasks(Some(existing), false).unwrap();
+        let labels = labels(&out);
+        assert_eq!(labels[0], "build");
+        assert!(!labels.contains(&"Lattice: Old task".to_string()));
+        assert_eq!(labels.len(), ACTIONS.len() + 1);
+
+        // Installing again is stable
+        assert_eq!(vscode_tasks(Some(&out), false).unwrap(), out);
+    }
+
+    #[test]
+    fn vscode_tasks_with_comments_need_force() {
+        let existing = "// my tasks\n{ \"version\": \"2.0.0\" }";
+        assert!(vscode_tasks(Some(existing), false).is_err());
+        assert_eq!(
+            labels(&vscode_tasks(Some(existing), true).unwrap()).len(),
+            ACTIONS.len()
+        );
+    }
+
+    #[test]
+    fn nvim_plugin_defines_commands() {
+        let plugin = nvim_plugin();
+        assert!(plugin.starts_with(NVIM_HEADER));
+        for action in ACTIONS {
+            assert!(plugin.contains(&format!("\"{}\"", action.nvim_command)));
+        }
+        assert!(plugin.contains("json(\"lt stats --json\")"));
+    }
+}
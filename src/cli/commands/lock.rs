@@ -0,0 +1,241 @@
+//! lock command - Advisory branch locks for collaborators
+//!
+//! # Design
+//!
+//! When two people work on one stack, `lattice lock <branch>` records that
+//! one of them is about to restack or submit it (see `core::branch_lock`).
+//! Until the lock expires or is released, Lattice refuses to rewrite or
+//! submit the branch for anyone else.
+//!
+//! - Locking is keyed on git `user.email`; re-locking your own branch
+//!   renews the lock.
+//! - An expired lock is replaced silently. `lattice doctor` offers to delete
+//!   expired locks nobody replaced.
+//! - `lattice unlock --force` breaks someone else's lock.
+//!
+//! # Architecture
+//!
+//! Lock refs are written directly, like checkpoints, rather than through
+//! the executor: they are coordination records, not stack state, and undo
+//! must not resurrect them. Gating uses `requirements::MUTATING_METADATA_ONLY`
+//! so read-only mode refuses them.
+//!
+//! With `sync_metadata_refs = true`, locks are shared through the configured
+//! remote. Each command fetches the remote's locks first and pushes its
+//! change with a lease; if someone else's change lands in between, the
+//! local change is reverted and the command fails.
+//!
+//! # Example
+//!
+//! ```bash
+//! lattice lock auth-ui --ttl 30
+//! lattice lock --list
+//! lattice unlock auth-ui
+//! ```
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::branch_lock::{BranchLock, BranchLockStore};
+use crate::core::config::Config;
+use crate::core::types::{BranchName, UtcTimestamp};
+use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
+use crate::engine::read_only;
+use crate::engine::runner::run_gated;
+use crate::engine::scan::RepoSnapshot;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// Resolve the target branch, defaulting to the current one.
+///
+/// Only tracked branches can be locked; any branch can be unlocked, so a
+/// lock outliving its branch can still be removed.
+fn resolve_target(
+    snapshot: &RepoSnapshot,
+    branch: Option<&str>,
+    require_tracked: bool,
+) -> Result<BranchName, PlanError> {
+    let target = if let Some(name) = branch {
+        BranchName::new(name)?
+    } else if let Some(ref current) = snapshot.current_branch {
+        current.clone()
+    } else {
+        return Err(PlanError::InvalidState(
+            "Not on any branch and no branch specified".to_string(),
+        ));
+    };
+
+    if require_tracked && !snapshot.metadata.contains_key(&target) {
+        return Err(PlanError::UntrackedBranch(target.to_string()));
+    }
+    Ok(target)
+}
+
+fn open_git(ctx: &Context) -> CliResult<Git> {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    Ok(Git::open(&cwd).context("Failed to open repository")?)
+}
+
+/// The remote locks are shared through, if metadata refs are synced.
+fn shared_remote(ctx: &Context) -> Option<String> {
+    let config = Config::load(ctx.cwd.as_deref()).ok()?.config;
+    config
+        .sync_metadata_refs()
+        .then(|| config.remote().to_string())
+}
+
+/// Whether read-only mode is on, so even a lock fetch must be skipped.
+fn read_only(ctx: &Context) -> bool {
+    let config = Config::load(ctx.cwd.as_deref()).ok().map(|r| r.config);
+    read_only::is_active(ctx.read_only, config.as_ref())
+}
+
+/// Gate, then resolve the target branch.
+fn gated_target(
+    git: &Git,
+    ctx: &Context,
+    branch: Option<&str>,
+    require_tracked: bool,
+) -> CliResult<BranchName> {
+    run_gated(git, ctx, &requirements::MUTATING_METADATA_ONLY, |ready| {
+        Ok(resolve_target(&ready.snapshot, branch, require_tracked)?)
+    })
+    .map_err(CliError::from)
+}
+
+fn fetch_locks(store: &BranchLockStore, remote: &str) -> CliResult {
+    Ok(store
+        .fetch(remote)
+        .with_context(|| format!("Failed to fetch branch locks from '{}'", remote))?)
+}
+
+/// Lock a branch, or list locks.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch to lock (defaults to current)
+/// * `ttl_minutes` - How long the lock holds
+/// * `list` - List locks instead of locking
+///
+/// # Gating
+///
+/// Locking uses `requirements::MUTATING_METADATA_ONLY`. Listing is ungated;
+/// in read-only mode it shows local locks without fetching the remote's.
+pub fn lock(ctx: &Context, branch: Option<&str>, ttl_minutes: u64, list: bool) -> CliResult {
+    let git = open_git(ctx)?;
+    let remote = shared_remote(ctx);
+    let store = BranchLockStore::new(&git);
+
+    if list {
+        if let Some(remote) = remote.as_ref().filter(|_| !read_only(ctx)) {
+            if let Err(e) = fetch_locks(&store, remote) {
+                eprintln!(
+                    "{}",
+                    i18n::t_args("lock-warning", &[("error", &format!("{:#}", e))])
+                );
+            }
+        }
+        return list_locks(ctx, &store);
+    }
+
+    let target = gated_target(&git, ctx, branch, true)?;
+    let owner = git.user_email().ok_or_else(|| {
+        CliError::usage("Set git user.email to lock branches; locks are recorded under it.")
+    })?;
+
+    if let Some(remote) = &remote {
+        fetch_locks(&store, remote)?;
+    }
+    // Clap bounds the TTL well inside i64
+    let ttl = chrono::Duration::minutes(ttl_minutes as i64);
+    let lock = BranchLock::new(target.clone(), owner, ttl);
+    let old = store.acquire(&lock, &UtcTimestamp::now())?;
+    if let Some(remote) = &remote {
+        if let Err(e) = store.push(remote, &target, old.as_ref()) {
+            store.restore(&target, old.as_ref())?;
+            return Err(e.into());
+        }
+    }
+
+    if !ctx.quiet {
+        let key = if old.is_some() {
+            "lock-renewed-until"
+        } else {
+            "lock-locked-until"
+        };
+        println!(
+            "{}",
+            i18n::t_args(
+                key,
+                &[("target", &target), ("expires_at", &lock.expires_at)],
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Release a branch lock.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch to unlock (defaults to current)
+/// * `force` - Release someone else's unexpired lock
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY`.
+pub fn unlock(ctx: &Context, branch: Option<&str>, force: bool) -> CliResult {
+    let git = open_git(ctx)?;
+    let target = gated_target(&git, ctx, branch, false)?;
+    let store = BranchLockStore::new(&git);
+    let remote = shared_remote(ctx);
+
+    if let Some(remote) = &remote {
+        fetch_locks(&store, remote)?;
+    }
+    let me = git.user_email();
+    let old = store.release(&target, me.as_deref(), force, &UtcTimestamp::now())?;
+    if let Some(remote) = &remote {
+        if let Err(e) = store.push(remote, &target, Some(&old)) {
+            store.restore(&target, Some(&old))?;
+            return Err(e.into());
+        }
+    }
+
+    if !ctx.quiet {
+        println!("{}", i18n::t_args("lock-unlocked", &[("target", &target)]));
+    }
+    Ok(())
+}
+
+/// Print every lock with its owner and expiry.
+fn list_locks(ctx: &Context, store: &BranchLockStore) -> CliResult {
+    let locks = store.list()?;
+    if locks.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("lock-no-branches-locked"));
+        }
+        return Ok(());
+    }
+
+    let now = UtcTimestamp::now();
+    for stored in locks {
+        let lock = stored.lock;
+        let state = if lock.is_expired(&now) {
+            "expired"
+        } else {
+            "until"
+        };
+        println!(
+            "{}  {}  {} {}",
+            lock.branch, lock.owner, state, lock.expires_at
+        );
+    }
+    Ok(())
+}
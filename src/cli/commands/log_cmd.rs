@@ -8,15 +8,89 @@
 //! This is a read-only command that implements `ReadOnlyCommand` and uses
 //! `requirements::READ_ONLY`. It flows through `run_readonly_command` to
 //! ensure proper gating. Supports degraded mode when metadata is unavailable.
+//!
+//! # Tree Layout
+//!
+//! The default format draws the stack as a tree rooted at trunk, children
+//! sorted by name, so wide fan-outs stay legible:
+//!
+//! ```text
+//!   main
+//!   ├── api (3 children)
+//!   │   ├── api-auth
+//! * │   ├── api-billing
+//!   │   └── … 4 more branches
+//!   └── web
+//! ```
+//!
+//! A branch with several children shows how many. With `--depth N`, branches
+//! that are neither ancestors nor descendants of the current branch (its
+//! cousins) are drawn at most `N` levels below the current line; deeper ones
+//! collapse into a count. `--reverse` draws trunk at the bottom.
+//!
+//! Branches whose PR status was fetched in the background (see `prefetch`)
+//! show it from the cache, e.g. `feature (#12 open, checks passed)`, or
+//! else the state `sync` or `submit` last recorded in metadata; nothing is
+//! fetched while drawing. Offline, each status says when it was fetched
+//! (see `cached_pr`).
+//!
+//! `--select` numbers the branches drawn and then applies a batch action to
+//! the ones the user marks (see `batch`).
+//!
+//! The long format (`--long`) also shows, for branches brought in from a
+//! remote, whose branch it is and where it came from, the CI state of each
+//! branch tip as last cached in metadata (see `ci_status`), and the files
+//! each branch changes relative to its parent, marked `A`, `M`, or `D`
+//! (see `info --files`).
+//!
+//! Stacks shown whose base has fallen far behind trunk get a warning on
+//! stderr (see `trunk_drift`).
+//!
+//! With `--json`, the branches that would be drawn are printed as one JSON
+//! document instead, parents before children, each with its parent,
+//! children, tip, base, freeze state, restack state, and PR. `--all` adds
+//! the untracked branches. Layout flags (`--short`, `--long`, `--reverse`,
+//! `--depth`) don't apply.
+//!
+//! With `--export dot|mermaid|json-graph`, the same branches are printed as
+//! a graph for Graphviz, Markdown docs, or dashboards (see `graph_export`).
+//!
+//! # Accessible Output
+//!
+//! With `output.accessible = true` in the global config, each branch is
+//! described in words instead of markers, so the list reads well through a
+//! screen reader:
+//!
+//! ```text
+//! branch feature-b, current branch, child of feature-a, 2 commits, needs restack
+//! ```
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::cli::args::GraphFormat;
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
+use crate::core::metadata::schema::PrState;
+use crate::core::paths::LatticePaths;
+use crate::core::types::{BranchName, Oid};
 use crate::engine::command::ReadOnlyCommand;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::PlanError;
-use crate::engine::runner::{run_readonly_command, RunError};
+use crate::engine::runner::run_readonly_command;
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
+use crate::forge::status_cache::ForgeStatusCache;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use crate::ui::output::Verbosity;
+use anyhow::Context as _;
+use serde::Serialize;
+
+use super::cached_pr;
+use super::ci_status::CiReport;
+use super::graph_export::GraphExport;
+use super::info;
+use super::trunk_drift::{warn_drifted_stacks, DriftThresholds};
 
 /// Check if log should display in degraded mode.
 ///
@@ -36,32 +110,43 @@ fn is_degraded_mode(snapshot: &RepoSnapshot) -> bool {
     no_tracked || trunk_not_configured
 }
 
+/// Horizontal rule framing the degraded mode banner.
+const BANNER_RULE: &str = "---------------------------------------------------------------";
+
 /// Print the degraded mode banner with guidance.
-fn print_degraded_banner(snapshot: &RepoSnapshot) {
-    eprintln!("---------------------------------------------------------------");
-    eprintln!("  Degraded view - no branches are tracked yet");
-    eprintln!("---------------------------------------------------------------");
+///
+/// Accessible output drops the decorative rules.
+fn print_degraded_banner(snapshot: &RepoSnapshot, accessible: bool) {
+    if !accessible {
+        eprintln!("{}", BANNER_RULE);
+    }
+    eprintln!("  {}", i18n::t("log-degraded-view-branches"));
+    if !accessible {
+        eprintln!("{}", BANNER_RULE);
+    }
     eprintln!();
 
     // Show trunk status
     if let Some(trunk) = &snapshot.trunk {
-        eprintln!("  trunk: {}", trunk);
+        eprintln!("  {}", i18n::t_args("log-trunk", &[("trunk", &trunk)]));
     } else {
-        eprintln!("  trunk: (not configured - run 'lattice init')");
+        eprintln!("  {}", i18n::t("log-trunk-not-configured"));
     }
     eprintln!();
 
     // Show call to action
-    eprintln!("  To start tracking branches, run:");
-    eprintln!("    lattice track <branch>     - track a single branch");
-    eprintln!("    lattice doctor             - discover bootstrap opportunities");
-    eprintln!();
-    eprintln!("---------------------------------------------------------------");
+    eprintln!("  {}", i18n::t("log-start-tracking-branches"));
+    eprintln!("    {}", i18n::t("log-lattice-track-branch"));
+    eprintln!("    {}", i18n::t("log-lattice-doctor-discover"));
     eprintln!();
+    if !accessible {
+        eprintln!("{}", BANNER_RULE);
+        eprintln!();
+    }
 }
 
 /// Print untracked branches in degraded mode.
-fn print_untracked_branches(snapshot: &RepoSnapshot) {
+fn print_untracked_branches(snapshot: &RepoSnapshot, accessible: bool) {
     // Get all local branches that are not tracked and not trunk
     let trunk_name = snapshot.trunk.as_ref().map(|t| t.as_str());
 
@@ -75,47 +160,644 @@ fn print_untracked_branches(snapshot: &RepoSnapshot) {
     untracked.sort_by(|a, b| a.as_str().cmp(b.as_str()));
 
     if untracked.is_empty() {
-        println!("No local branches found (besides trunk).");
+        println!("{}", i18n::t("log-no-local-branches"));
         return;
     }
 
-    println!("Untracked local branches:");
+    println!("{}", i18n::t("log-untracked-local-branches"));
     println!();
 
     for branch in &untracked {
         let is_current = snapshot.current_branch.as_ref() == Some(*branch);
-        let prefix = if is_current { "* " } else { "  " };
-        println!("{}{}", prefix, branch);
+        if accessible {
+            println!("{}", describe_untracked(branch, is_current));
+        } else {
+            let prefix = if is_current { "* " } else { "  " };
+            println!("{}{}", prefix, branch);
+        }
     }
 
     println!();
-    println!("({} branch(es) not tracked by Lattice)", untracked.len());
+    println!(
+        "{}",
+        i18n::t_args("log-untracked-count", &[("count", &untracked.len())])
+    );
+}
+
+/// Describe a tracked branch in words, for accessible output.
+///
+/// Lists the branch, whether it is checked out, its parent, its commit
+/// count, and any state that other formats show as a marker.
+pub(crate) fn describe_branch(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    is_current: bool,
+) -> String {
+    let mut parts = name_parts(branch, is_current);
+    if let Some(scanned) = snapshot.metadata.get(branch) {
+        let metadata = &scanned.metadata;
+        let parent = metadata.parent.name();
+        parts.push(i18n::t_args("accessible-child-of", &[("parent", &parent)]));
+
+        let base = Oid::new(&metadata.base.oid).ok();
+        let tip = snapshot.branches.get(branch);
+        if let (Some(base), Some(tip)) = (&base, tip) {
+            if let Ok(count) = git.commit_count(base, tip) {
+                let key = if count == 1 {
+                    "accessible-commits-one"
+                } else {
+                    "accessible-commits"
+                };
+                parts.push(i18n::t_args(key, &[("count", &count)]));
+            }
+        }
+
+        if needs_restack(snapshot, branch) {
+            parts.push(i18n::t("accessible-needs-restack"));
+        }
+        if metadata.freeze.is_frozen() {
+            parts.push(i18n::t("accessible-frozen"));
+        }
+        if let Some(number) = metadata.pr.number() {
+            parts.push(i18n::t_args("accessible-pr", &[("number", &number)]));
+        }
+    }
+
+    parts.join(", ")
+}
+
+/// Whether `branch`'s parent has moved past its recorded base.
+pub(crate) fn needs_restack(snapshot: &RepoSnapshot, branch: &BranchName) -> bool {
+    let Some(scanned) = snapshot.metadata.get(branch) else {
+        return false;
+    };
+    let parent_tip = BranchName::new(scanned.metadata.parent.name())
+        .ok()
+        .and_then(|p| snapshot.branches.get(&p));
+    parent_tip.is_some_and(|tip| tip.as_str() != scanned.metadata.base.oid)
+}
+
+/// Describe an untracked branch in words, for accessible output.
+fn describe_untracked(branch: &BranchName, is_current: bool) -> String {
+    let mut parts = name_parts(branch, is_current);
+    parts.push(i18n::t("accessible-untracked"));
+    parts.join(", ")
+}
+
+/// Branch name and, if checked out, a note saying so.
+fn name_parts(branch: &BranchName, is_current: bool) -> Vec<String> {
+    let mut parts = vec![i18n::t_args("accessible-branch", &[("branch", branch)])];
+    if is_current {
+        parts.push(i18n::t("accessible-current"));
+    }
+    parts
+}
+
+/// Branches laid out as a tree for the default log format.
+struct TreeView<'a> {
+    /// Labels drawn at the top level: trunk, or a missing parent.
+    roots: Vec<&'a str>,
+    /// Shown children of each branch (or root), sorted by name.
+    children: HashMap<&'a str, Vec<&'a str>>,
+    /// All children of each branch, shown or not, for sibling counts.
+    child_counts: HashMap<&'a str, usize>,
+    /// The current branch, if any.
+    current: Option<&'a str>,
+    /// The current branch, its ancestors and its descendants; never collapsed.
+    expanded: HashSet<&'a str>,
+    /// Frozen branches.
+    frozen: HashSet<&'a str>,
+    /// Cached PR status of each branch, from the forge status cache.
+    pr_status: HashMap<&'a str, String>,
+    /// How many levels of cousins to draw (`--depth`).
+    depth: Option<usize>,
+}
+
+impl<'a> TreeView<'a> {
+    /// Lay out `branches` using the parent links in `snapshot`.
+    fn new(
+        snapshot: &'a RepoSnapshot,
+        branches: &'a [BranchName],
+        forge_status: &ForgeStatusCache,
+        offline: bool,
+        depth: Option<usize>,
+    ) -> Self {
+        let edges = branches
+            .iter()
+            .filter_map(|b| Some((b.as_str(), snapshot.graph.parent(b)?.as_str())));
+        let current = snapshot
+            .current_branch
+            .as_ref()
+            .map(|c| c.as_str())
+            .filter(|c| branches.iter().any(|b| b.as_str() == *c));
+        let frozen = branches
+            .iter()
+            .filter(|b| {
+                snapshot
+                    .metadata
+                    .get(*b)
+                    .is_some_and(|m| m.metadata.freeze.is_frozen())
+            })
+            .map(|b| b.as_str())
+            .collect();
+
+        let mut view = Self::from_edges(edges, current, frozen, depth);
+        // Count children outside the shown stack too
+        for (node, count) in view.child_counts.iter_mut() {
+            if let Some(kids) = BranchName::new(*node)
+                .ok()
+                .and_then(|b| snapshot.graph.children(&b))
+            {
+                *count = kids.len();
+            }
+        }
+        view.pr_status = branches
+            .iter()
+            .filter_map(|b| {
+                Some((
+                    b.as_str(),
+                    cached_pr_label(snapshot, forge_status, offline, b)?,
+                ))
+            })
+            .collect();
+        view
+    }
+
+    /// Lay out `(branch, parent)` edges.
+    fn from_edges(
+        edges: impl IntoIterator<Item = (&'a str, &'a str)>,
+        current: Option<&'a str>,
+        frozen: HashSet<&'a str>,
+        depth: Option<usize>,
+    ) -> Self {
+        let parents: HashMap<&str, &str> = edges.into_iter().collect();
+
+        let mut by_parent: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (&branch, &parent) in &parents {
+            by_parent.entry(parent).or_default().push(branch);
+        }
+        for kids in by_parent.values_mut() {
+            kids.sort_unstable();
+        }
+        let roots = by_parent
+            .keys()
+            .copied()
+            .filter(|p| !parents.contains_key(p))
+            .collect();
+
+        let mut expanded = HashSet::new();
+        if let Some(current) = current {
+            // Ancestors, guarding against cycles
+            let mut node = current;
+            while expanded.insert(node) {
+                match parents.get(node) {
+                    Some(parent) => node = parent,
+                    None => break,
+                }
+            }
+            let mut stack = vec![current];
+            while let Some(node) = stack.pop() {
+                for &kid in by_parent.get(node).into_iter().flatten() {
+                    if expanded.insert(kid) {
+                        stack.push(kid);
+                    }
+                }
+            }
+        }
+
+        Self {
+            roots,
+            child_counts: by_parent.iter().map(|(p, kids)| (*p, kids.len())).collect(),
+            children: by_parent.into_iter().collect(),
+            current,
+            expanded,
+            frozen,
+            pr_status: HashMap::new(),
+            depth,
+        }
+    }
+
+    /// Render the tree, one string per line, trunk first.
+    fn render(&self) -> Vec<String> {
+        self.rows().into_iter().map(|(_, line)| line).collect()
+    }
+
+    /// Render the tree, pairing each line with the branch it draws (none for
+    /// roots and collapsed counts).
+    fn rows(&self) -> Vec<(Option<&'a str>, String)> {
+        let mut rows = Vec::new();
+        for root in &self.roots {
+            rows.push((None, format!("  {}{}", root, self.fan_out(root))));
+            self.render_children(root, 0, "", &mut rows);
+        }
+        rows
+    }
+
+    /// Render the children of `node`, which is `level` levels below the
+    /// current line.
+    fn render_children(
+        &self,
+        node: &str,
+        level: usize,
+        prefix: &str,
+        rows: &mut Vec<(Option<&'a str>, String)>,
+    ) {
+        let kids = self.children.get(node).map(Vec::as_slice).unwrap_or(&[]);
+        let (shown, hidden): (Vec<&'a str>, Vec<&'a str>) = kids.iter().partition(|kid| {
+            self.expanded.contains(*kid) || self.depth.is_none_or(|depth| level < depth)
+        });
+        let hidden_count: usize = hidden
+            .iter()
+            .map(|kid| 1 + self.descendant_count(kid))
+            .sum();
+
+        for (i, kid) in shown.iter().enumerate() {
+            let last = i + 1 == shown.len() && hidden_count == 0;
+            let marker = if self.current == Some(*kid) {
+                "* "
+            } else {
+                "  "
+            };
+            let frozen = if self.frozen.contains(kid) {
+                " [frozen]"
+            } else {
+                ""
+            };
+            let pr_status = self
+                .pr_status
+                .get(kid)
+                .map(|label| format!(" ({})", label))
+                .unwrap_or_default();
+            rows.push((
+                Some(*kid),
+                format!(
+                    "{}{}{}{}{}{}{}",
+                    marker,
+                    prefix,
+                    if last { "└── " } else { "├── " },
+                    kid,
+                    frozen,
+                    pr_status,
+                    self.fan_out(kid)
+                ),
+            ));
+
+            let kid_level = if self.expanded.contains(kid) {
+                0
+            } else {
+                level + 1
+            };
+            let kid_prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+            self.render_children(kid, kid_level, &kid_prefix, rows);
+        }
+
+        if hidden_count > 0 {
+            let noun = if hidden_count == 1 {
+                "branch"
+            } else {
+                "branches"
+            };
+            let more = if shown.is_empty() { "" } else { " more" };
+            rows.push((
+                None,
+                format!("  {}└── … {}{} {}", prefix, hidden_count, more, noun),
+            ));
+        }
+    }
+
+    /// Sibling count shown after a branch with several children.
+    fn fan_out(&self, node: &str) -> String {
+        match self.child_counts.get(node) {
+            Some(&n) if n > 1 => format!(" ({} children)", n),
+            _ => String::new(),
+        }
+    }
+
+    /// Number of branches below `node`.
+    fn descendant_count(&self, node: &str) -> usize {
+        self.children
+            .get(node)
+            .into_iter()
+            .flatten()
+            .map(|kid| 1 + self.descendant_count(kid))
+            .sum()
+    }
+}
+
+/// The tree `lattice log` draws for `branches`, one row per line, paired
+/// with the branch each line draws (none for roots and collapsed counts).
+pub(crate) fn tree_rows(
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    forge_status: &ForgeStatusCache,
+    offline: bool,
+) -> Vec<(Option<BranchName>, String)> {
+    TreeView::new(snapshot, branches, forge_status, offline, None)
+        .rows()
+        .into_iter()
+        .map(|(branch, line)| (branch.and_then(|b| BranchName::new(b).ok()), line))
+        .collect()
+}
+
+/// The cached forge status of `branch`'s linked PR, if any.
+///
+/// Prefers the background prefetch's status and falls back to the state
+/// last recorded in metadata. `offline`, the label says when it was fetched.
+pub(crate) fn cached_pr_label(
+    snapshot: &RepoSnapshot,
+    forge_status: &ForgeStatusCache,
+    offline: bool,
+    branch: &BranchName,
+) -> Option<String> {
+    let metadata = &snapshot.metadata.get(branch)?.metadata;
+    let number = metadata.pr.number()?;
+    match forge_status.get(branch.as_str(), number) {
+        Some(status) if offline => Some(format!(
+            "{} {}",
+            status.label(),
+            cached_pr::stale_suffix(status.fetched_at)
+        )),
+        Some(status) => Some(status.label()),
+        None if offline => cached_pr::stale_label(metadata),
+        None => cached_pr::label(metadata),
+    }
+}
+
+/// A linked PR in `--json` output.
+#[derive(Debug, Serialize)]
+pub(crate) struct PrJson {
+    number: u64,
+    url: String,
+    /// Cached status label, e.g. `#12 open, checks passed`
+    status: Option<String>,
+}
+
+impl PrJson {
+    /// `branch`'s linked PR, if any, with its cached status.
+    pub(crate) fn new(
+        snapshot: &RepoSnapshot,
+        forge_status: &ForgeStatusCache,
+        offline: bool,
+        branch: &BranchName,
+    ) -> Option<Self> {
+        match &snapshot.metadata.get(branch)?.metadata.pr {
+            PrState::Linked { number, url, .. } => Some(Self {
+                number: *number,
+                url: url.clone(),
+                status: cached_pr_label(snapshot, forge_status, offline, branch),
+            }),
+            PrState::None => None,
+        }
+    }
+}
+
+/// Machine-readable `log` output (`--json`).
+#[derive(Debug, Serialize)]
+struct LogReport {
+    trunk: Option<String>,
+    current: Option<String>,
+    /// Shown branches, parents before children
+    branches: Vec<BranchJson>,
+    /// Untracked branches, with `--all`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    untracked: Option<Vec<String>>,
+}
+
+/// A tracked branch in `--json` output.
+#[derive(Debug, Serialize)]
+struct BranchJson {
+    name: String,
+    parent: String,
+    children: Vec<String>,
+    current: bool,
+    tip: Option<String>,
+    base: String,
+    frozen: bool,
+    /// Forge login of the branch's owner, for branches brought in from a PR
+    owner: Option<String>,
+    needs_restack: bool,
+    pr: Option<PrJson>,
+    /// CI state of the tip as last fetched
+    ci: Option<CiReport>,
+}
+
+/// Draw the tree upside down, for `--reverse`.
+fn reverse_tree(mut lines: Vec<String>) -> Vec<String> {
+    lines.reverse();
+    lines.into_iter().map(|l| l.replace('└', "┌")).collect()
 }
 
 /// Command to display tracked branches in stack layout.
 pub struct LogCommand<'a> {
     ctx: &'a Context,
+    git: &'a Git,
+    accessible: bool,
     short: bool,
     long: bool,
     stack: bool,
     all: bool,
     reverse: bool,
+    depth: Option<usize>,
+    select: bool,
+    export: Option<GraphFormat>,
+    json: bool,
+    drift: DriftThresholds,
+    forge_status: ForgeStatusCache,
+}
+
+impl LogCommand<'_> {
+    /// Collect `branches` (and untracked ones, with `--all`) for `--json`.
+    fn report(&self, snapshot: &RepoSnapshot, branches: &[BranchName]) -> LogReport {
+        let mut sorted = branches.to_vec();
+        sorted.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let branches = super::restack::topological_sort(&sorted, snapshot)
+            .into_iter()
+            .filter_map(|branch| {
+                let metadata = &snapshot.metadata.get(&branch)?.metadata;
+                let mut children: Vec<String> = snapshot
+                    .graph
+                    .children(&branch)
+                    .into_iter()
+                    .flatten()
+                    .map(|c| c.to_string())
+                    .collect();
+                children.sort();
+                Some(BranchJson {
+                    name: branch.to_string(),
+                    parent: metadata.parent.name().to_string(),
+                    children,
+                    current: snapshot.current_branch.as_ref() == Some(&branch),
+                    tip: snapshot.branches.get(&branch).map(|oid| oid.to_string()),
+                    base: metadata.base.oid.to_string(),
+                    frozen: metadata.freeze.is_frozen(),
+                    owner: metadata.provenance.as_ref().and_then(|p| p.owner.clone()),
+                    needs_restack: needs_restack(snapshot, &branch),
+                    pr: PrJson::new(snapshot, &self.forge_status, self.ctx.offline, &branch),
+                    ci: metadata
+                        .ci
+                        .as_ref()
+                        .map(|ci| CiReport::new(ci, snapshot.branches.get(&branch))),
+                })
+            })
+            .collect();
+
+        LogReport {
+            trunk: snapshot.trunk.as_ref().map(|t| t.to_string()),
+            current: snapshot.current_branch.as_ref().map(|c| c.to_string()),
+            branches,
+            untracked: self.all.then(|| {
+                untracked_branches(snapshot)
+                    .into_iter()
+                    .map(|b| b.to_string())
+                    .collect()
+            }),
+        }
+    }
+
+    /// Print `branches` with a number before each, for `--select`.
+    ///
+    /// Returns the numbered branches in order.
+    fn print_numbered(&self, snapshot: &RepoSnapshot, branches: &[BranchName]) -> Vec<BranchName> {
+        let (mut keys, mut lines): (Vec<Option<BranchName>>, Vec<String>) = if self.accessible {
+            branches
+                .iter()
+                .map(|branch| {
+                    let is_current = snapshot.current_branch.as_ref() == Some(branch);
+                    (
+                        Some(branch.clone()),
+                        describe_branch(self.git, snapshot, branch, is_current),
+                    )
+                })
+                .unzip()
+        } else {
+            TreeView::new(
+                snapshot,
+                branches,
+                &self.forge_status,
+                self.ctx.offline,
+                self.depth,
+            )
+            .rows()
+            .into_iter()
+            .map(|(branch, line)| (branch.and_then(|b| BranchName::new(b).ok()), line))
+            .unzip()
+        };
+        if self.reverse {
+            keys.reverse();
+            lines = if self.accessible {
+                lines.into_iter().rev().collect()
+            } else {
+                reverse_tree(lines)
+            };
+        }
+
+        let mut numbered = Vec::new();
+        for (branch, line) in keys.into_iter().zip(lines) {
+            match branch {
+                Some(branch) => {
+                    numbered.push(branch);
+                    println!("{:>3} {}", numbered.len(), line);
+                }
+                None => println!("    {}", line),
+            }
+        }
+        numbered
+    }
+
+    /// Print one branch in the short, long, or accessible format.
+    fn print_branch(&self, snapshot: &RepoSnapshot, branch: &BranchName) {
+        let is_current = snapshot.current_branch.as_ref() == Some(branch);
+        let prefix = if is_current { "* " } else { "  " };
+
+        if self.accessible {
+            if self.short {
+                println!("{}", name_parts(branch, is_current).join(", "));
+            } else {
+                println!(
+                    "{}",
+                    describe_branch(self.git, snapshot, branch, is_current)
+                );
+            }
+        } else if self.short {
+            println!("{}{}", prefix, branch);
+        } else {
+            // Long format with details
+            let parent = snapshot.graph.parent(branch);
+            let metadata = snapshot.metadata.get(branch);
+
+            println!("{}{}", prefix, branch);
+            if let Some(p) = parent {
+                println!("    {}", i18n::t_args("log-parent", &[("parent", &p)]));
+            }
+            if let Some(m) = metadata {
+                println!(
+                    "    {}",
+                    i18n::t_args("log-base", &[("oid", &m.metadata.base.oid)])
+                );
+                if m.metadata.freeze.is_frozen() {
+                    println!("    {}", i18n::t("log-frozen-yes"));
+                }
+                if let Some(provenance) = &m.metadata.provenance {
+                    if let Some(owner) = &provenance.owner {
+                        println!("    {}", i18n::t_args("log-owner", &[("owner", &owner)]));
+                    }
+                    println!(
+                        "    {}",
+                        i18n::t_args("log-from", &[("describe", &provenance.describe())])
+                    );
+                }
+                if let Some(label) =
+                    cached_pr_label(snapshot, &self.forge_status, self.ctx.offline, branch)
+                {
+                    println!("    pr: {}", label);
+                } else if m.metadata.pr.is_linked() {
+                    println!("    {}", i18n::t("log-pr-linked"));
+                }
+                if let Some(ci) = &m.metadata.ci {
+                    let report = CiReport::new(ci, snapshot.branches.get(branch));
+                    println!("    ci: {}", report.badge());
+                }
+                let files =
+                    info::branch_changed_files(self.git, snapshot, branch).unwrap_or_default();
+                if !files.is_empty() {
+                    println!("    {}", i18n::t("log-files"));
+                    for line in info::format_changed_files(&files, "      ") {
+                        println!("{}", line);
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl ReadOnlyCommand for LogCommand<'_> {
     const REQUIREMENTS: &'static RequirementSet = &requirements::READ_ONLY;
-    type Output = ();
+    /// With `--select`, the numbered branches in display order.
+    type Output = Vec<BranchName>;
 
     fn execute(&self, ready: &ReadyContext) -> Result<Self::Output, PlanError> {
         let snapshot = &ready.snapshot;
 
         // Check for degraded mode FIRST (no tracked branches)
+        if let (Some(format), true) = (self.export, is_degraded_mode(snapshot)) {
+            print!(
+                "{}",
+                GraphExport::new(snapshot, &[], &self.forge_status, self.ctx.offline)
+                    .render(format)
+            );
+            return Ok(Vec::new());
+        }
+        if self.json && is_degraded_mode(snapshot) {
+            super::print_json(&self.report(snapshot, &[]))?;
+            return Ok(Vec::new());
+        }
         if is_degraded_mode(snapshot) {
             if !self.ctx.quiet {
-                print_degraded_banner(snapshot);
-                print_untracked_branches(snapshot);
+                print_degraded_banner(snapshot, self.accessible);
+                print_untracked_branches(snapshot, self.accessible);
             }
-            return Ok(());
+            return Ok(Vec::new());
         }
 
         // Normal mode: show tracked branches
@@ -137,95 +819,104 @@ impl ReadOnlyCommand for LogCommand<'_> {
             }
         };
 
-        if self.reverse {
-            branches.reverse();
+        if self.json {
+            super::print_json(&self.report(snapshot, &branches))?;
+            return Ok(Vec::new());
+        }
+
+        if let Some(format) = self.export {
+            let export =
+                GraphExport::new(snapshot, &branches, &self.forge_status, self.ctx.offline);
+            print!("{}", export.render(format));
+            return Ok(Vec::new());
         }
 
         if branches.is_empty() {
             if !self.ctx.quiet {
-                println!("No tracked branches.");
+                println!("{}", i18n::t("log-no-tracked-branches"));
             }
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        // Display tracked branches
-        for branch in &branches {
-            let is_current = snapshot
-                .current_branch
-                .as_ref()
-                .map(|c| c == branch)
-                .unwrap_or(false);
-            let prefix = if is_current { "* " } else { "  " };
-
-            if self.short {
-                println!("{}{}", prefix, branch);
-            } else if self.long {
-                // Long format with details
-                let parent = snapshot.graph.parent(branch);
-                let metadata = snapshot.metadata.get(branch);
-
-                println!("{}{}", prefix, branch);
-                if let Some(p) = parent {
-                    println!("    parent: {}", p);
-                }
-                if let Some(m) = metadata {
-                    println!("    base: {}", m.metadata.base.oid);
-                    if m.metadata.freeze.is_frozen() {
-                        println!("    frozen: yes");
-                    }
-                    if m.metadata.pr.is_linked() {
-                        println!("    pr: linked");
-                    }
-                }
+        let mut numbered = Vec::new();
+        if self.select {
+            numbered = self.print_numbered(snapshot, &branches);
+        } else if !self.accessible && !self.short && !self.long {
+            // Default format: tree rooted at trunk
+            let lines = TreeView::new(
+                snapshot,
+                &branches,
+                &self.forge_status,
+                self.ctx.offline,
+                self.depth,
+            )
+            .render();
+            let lines = if self.reverse {
+                reverse_tree(lines)
             } else {
-                // Default format
-                let parent_str = snapshot
-                    .graph
-                    .parent(branch)
-                    .map(|p| format!(" (on {})", p))
-                    .unwrap_or_default();
-                let frozen = snapshot
-                    .metadata
-                    .get(branch)
-                    .map(|m| {
-                        if m.metadata.freeze.is_frozen() {
-                            " [frozen]"
-                        } else {
-                            ""
-                        }
-                    })
-                    .unwrap_or("");
-                println!("{}{}{}{}", prefix, branch, parent_str, frozen);
+                lines
+            };
+            for line in lines {
+                println!("{}", line);
+            }
+        } else {
+            if self.reverse {
+                branches.reverse();
+            }
+            for branch in &branches {
+                self.print_branch(snapshot, branch);
             }
         }
 
+        warn_drifted_stacks(
+            self.git,
+            snapshot,
+            &branches,
+            self.drift,
+            Verbosity::from_flags(self.ctx.quiet, self.ctx.debug),
+        );
+
         // In --all mode, also show untracked branches (mixed mode)
         if self.all {
-            let trunk_name = snapshot.trunk.as_ref().map(|t| t.as_str());
-            let mut untracked: Vec<_> = snapshot
-                .branches
-                .keys()
-                .filter(|b| !snapshot.metadata.contains_key(*b))
-                .filter(|b| Some(b.as_str()) != trunk_name)
-                .collect();
-
-            untracked.sort_by(|a, b| a.as_str().cmp(b.as_str()));
-
+            let untracked = untracked_branches(snapshot);
             if !untracked.is_empty() {
                 println!();
-                println!("Untracked branches:");
+                println!("{}", i18n::t("log-untracked-branches"));
                 for branch in untracked {
                     let is_current = snapshot.current_branch.as_ref() == Some(branch);
-                    let prefix = if is_current { "* " } else { "  " };
-                    println!("{}{}  (untracked)", prefix, branch);
+                    if self.accessible {
+                        println!("{}", describe_untracked(branch, is_current));
+                    } else {
+                        let prefix = if is_current { "* " } else { "  " };
+                        println!(
+                            "{}",
+                            i18n::t_args(
+                                "log-untracked",
+                                &[("prefix", &prefix), ("branch", &branch)],
+                            )
+                        );
+                    }
                 }
             }
         }
 
-        Ok(())
+        Ok(numbered)
     }
 }
 
+/// Untracked branches other than trunk, sorted by name.
+fn untracked_branches(snapshot: &RepoSnapshot) -> Vec<&BranchName> {
+    let trunk_name = snapshot.trunk.as_ref().map(|t| t.as_str());
+    let mut untracked: Vec<_> = snapshot
+        .branches
+        .keys()
+        .filter(|b| !snapshot.metadata.contains_key(*b))
+        .filter(|b| Some(b.as_str()) != trunk_name)
+        .collect();
+    untracked.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    untracked
+}
+
 /// Display tracked branches in stack layout.
 ///
 /// # Arguments
@@ -236,11 +927,16 @@ impl ReadOnlyCommand for LogCommand<'_> {
 /// * `stack` - Filter to current branch's stack
 /// * `all` - Show all tracked branches (includes untracked in mixed mode)
 /// * `reverse` - Reverse display order
+/// * `depth` - Levels of cousins of the current branch to draw in the tree
+/// * `select` - Number the branches and apply a batch action to marked ones
+/// * `export` - Print the graph in this format instead of the tree
+/// * `json` - Print a JSON document instead of the tree
 ///
 /// # Gating
 ///
 /// Uses `requirements::READ_ONLY` via `ReadOnlyCommand` trait. Supports
-/// degraded mode when metadata is unavailable.
+/// degraded mode when metadata is unavailable. Honors `output.accessible`.
+#[allow(clippy::too_many_arguments)]
 pub fn log(
     ctx: &Context,
     short: bool,
@@ -248,28 +944,53 @@ pub fn log(
     stack: bool,
     all: bool,
     reverse: bool,
-) -> Result<()> {
+    depth: Option<usize>,
+    select: bool,
+    export: Option<GraphFormat>,
+    json: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    if select && json {
+        return Err(CliError::usage("--select can't be combined with --json"));
+    }
+    if export.is_some() && json {
+        return Err(CliError::usage("--export can't be combined with --json"));
+    }
+    if select && !ctx.interactive {
+        return Err(CliError::usage(i18n::t("batch-needs-interactive")));
+    }
+
+    let accessible = Config::load(Some(&cwd))
+        .map(|r| r.config.output_accessible())
+        .unwrap_or(false);
 
     let cmd = LogCommand {
         ctx,
+        git: &git,
+        accessible,
         short,
         long,
         stack,
         all,
         reverse,
+        depth,
+        select,
+        export,
+        json,
+        drift: DriftThresholds::load(&cwd),
+        forge_status: ForgeStatusCache::load(&LatticePaths::from_repo_info(&git.info()?)),
     };
 
-    run_readonly_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    let numbered = run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)?;
+
+    if select && !numbered.is_empty() {
+        super::batch::run(ctx, &numbered)?;
+    }
+    Ok(())
 }
 
 /// Get all branches in the same stack as the given branch.
@@ -286,12 +1007,78 @@ pub fn get_stack_branches(
         current = parent.clone();
     }
 
-    // Walk down to leaves (simplified - just immediate children)
-    if let Some(children) = snapshot.graph.children(branch) {
-        for child in children {
-            result.push(child.clone());
-        }
-    }
+    // Walk down to leaves
+    let mut descendants: Vec<_> = snapshot.graph.descendants(branch).into_iter().collect();
+    descendants.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    result.extend(descendants);
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// main -> api -> {api-auth, api-billing, api-x -> {api-x-1, api-x-2}}, main -> web
+    const EDGES: &[(&str, &str)] = &[
+        ("api", "main"),
+        ("api-x", "api"),
+        ("api-billing", "api"),
+        ("api-auth", "api"),
+        ("api-x-2", "api-x"),
+        ("api-x-1", "api-x"),
+        ("web", "main"),
+    ];
+
+    fn view(current: Option<&'static str>, depth: Option<usize>) -> Vec<String> {
+        TreeView::from_edges(EDGES.iter().copied(), current, HashSet::new(), depth).render()
+    }
+
+    #[test]
+    fn children_sorted_with_counts() {
+        assert_eq!(
+            view(Some("api-billing"), None),
+            vec![
+                "  main (2 children)",
+                "  ├── api (3 children)",
+                "  │   ├── api-auth",
+                "* │   ├── api-billing",
+                "  │   └── api-x (2 children)",
+                "  │       ├── api-x-1",
+                "  │       └── api-x-2",
+                "  └── web",
+            ]
+        );
+    }
+
+    #[test]
+    fn depth_collapses_cousins() {
+        assert_eq!(
+            view(Some("api-billing"), Some(0)),
+            vec![
+                "  main (2 children)",
+                "  ├── api (3 children)",
+                "* │   ├── api-billing",
+                "  │   └── … 4 more branches",
+                "  └── … 1 more branch",
+            ]
+        );
+
+        let one = view(Some("api-billing"), Some(1));
+        assert!(one.contains(&"  │   └── api-x (2 children)".to_string()));
+        assert!(one.contains(&"  │       └── … 2 branches".to_string()));
+    }
+
+    #[test]
+    fn depth_keeps_descendants_of_current() {
+        let lines = view(Some("api"), Some(0));
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines.last().unwrap(), "  └── … 1 more branch");
+    }
+
+    #[test]
+    fn reverse_draws_trunk_last() {
+        let lines = reverse_tree(view(None, Some(0)));
+        assert_eq!(lines, vec!["  ┌── … 7 branches", "  main (2 children)"]);
+    }
+}
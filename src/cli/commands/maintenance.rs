@@ -0,0 +1,101 @@
+//! maintenance - Background git maintenance after large operations
+//!
+//! Big restacks rewrite many commits and big fetches bring in many objects.
+//! Both leave loose objects and a stale commit-graph behind, which slows
+//! down later scans. After such an operation, commands call
+//! [`after_large_operation`] to start incremental `git maintenance` tasks
+//! in the background, so the repository stays fast without user action.
+//!
+//! Maintenance is skipped when:
+//! - fewer than [`LARGE_OPERATION_REFS`] refs changed,
+//! - Lattice's `maintenance.auto` config is `false`, or
+//! - git's `maintenance.auto` is `false` (this includes repositories
+//!   registered for scheduled `git maintenance`).
+
+use std::collections::HashMap;
+
+use crate::core::config::Config;
+use crate::core::types::Oid;
+use crate::engine::Context;
+use crate::git::{Git, MaintenanceTask};
+
+/// Number of changed refs that makes an operation "large".
+pub(crate) const LARGE_OPERATION_REFS: usize = 10;
+
+/// Tasks run after a large operation.
+const TASKS: &[MaintenanceTask] = &[MaintenanceTask::CommitGraph, MaintenanceTask::LooseObjects];
+
+/// Record the refs under `prefix` and where they point.
+///
+/// Returns an empty map if the refs can't be listed.
+pub(crate) fn ref_tips(git: &Git, prefix: &str) -> HashMap<String, Oid> {
+    git.list_refs_by_prefix(prefix)
+        .map(|refs| {
+            refs.into_iter()
+                .map(|entry| (entry.name.to_string(), entry.oid))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Count refs that were created or moved between two recordings.
+pub(crate) fn changed_refs(before: &HashMap<String, Oid>, after: &HashMap<String, Oid>) -> usize {
+    after
+        .iter()
+        .filter(|(name, oid)| before.get(*name) != Some(*oid))
+        .count()
+}
+
+/// Start background maintenance if `changed` refs make this a large operation.
+pub(crate) fn after_large_operation(ctx: &Context, git: &Git, changed: usize) {
+    if changed < LARGE_OPERATION_REFS {
+        return;
+    }
+
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+    let lattice_enabled = Config::load(Some(&cwd))
+        .map(|r| r.config.maintenance_auto())
+        .unwrap_or(true);
+    if !lattice_enabled || !git.auto_maintenance_enabled().unwrap_or(false) {
+        return;
+    }
+
+    match git.spawn_maintenance(TASKS) {
+        Ok(()) if ctx.debug => {
+            eprintln!(
+                "[debug] {} refs changed; started background git maintenance",
+                changed
+            );
+        }
+        Ok(()) => {}
+        Err(e) if ctx.debug => eprintln!("[debug] git maintenance not started: {}", e),
+        Err(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oid(c: char) -> Oid {
+        Oid::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    #[test]
+    fn counts_new_and_moved_refs() {
+        let before = HashMap::from([
+            ("refs/heads/a".to_string(), oid('a')),
+            ("refs/heads/b".to_string(), oid('b')),
+            ("refs/heads/gone".to_string(), oid('c')),
+        ]);
+        let after = HashMap::from([
+            ("refs/heads/a".to_string(), oid('a')),
+            ("refs/heads/b".to_string(), oid('d')),
+            ("refs/heads/new".to_string(), oid('e')),
+        ]);
+        assert_eq!(changed_refs(&before, &after), 2);
+    }
+}
@@ -10,6 +10,33 @@
 //! - Stops on first failure
 //! - Suggests running `lattice sync` after
 //!
+//! With `--when-green`, each PR is merged only once its checks pass. Before
+//! each merge the command polls the PR's checks every `[merge]
+//! poll_interval_secs` (default 30): it merges when they are green, stops
+//! when one fails, and gives up after `[merge] wait_timeout_secs` (default
+//! 3600) of pending checks. When branch protection marks checks as
+//! required, only those count; otherwise every check does. A PR without
+//! checks is merged right away.
+//!
+//! Before merging or queueing a PR, the command checks its reviews against
+//! what the base branch's protection requires: enough approvals (approvals
+//! of an older commit don't count when the branch dismisses stale reviews),
+//! no requested changes, and a review from each requested code owner. The
+//! reviews come from one batched forge lookup, which also refreshes the
+//! status cache; when the forge can't be reached, and on a dry run, the
+//! reviews cached by `sync` are used. A PR that falls short stops the merge there with each unmet
+//! requirement named, instead of a bare rejection from the forge. With
+//! `--rerequest-stale`, reviewers whose approval is of an older commit are
+//! asked to review again. A forge that reports no reviews gates nothing.
+//!
+//! With `--queue`, each PR is added to GitHub's merge queue instead of
+//! merged (a trunk protected by a merge queue rejects direct merges). The
+//! queue only serves PRs that target trunk, so before enqueueing a PR
+//! stacked on another branch, the command retargets it to trunk; the PRs
+//! below it are ahead of it in the queue, so it is tested and merged on top
+//! of them. It reports each PR's queue position and state, and stops at the
+//! first PR that can't be queued.
+//!
 //! # Architecture
 //!
 //! The merge command implements `AsyncCommand` per the Phase 6 command migration.
@@ -29,21 +56,41 @@
 //!
 //! # Use squash merge
 //! lattice merge --method squash
+//!
+//! # Merge each PR as soon as its CI passes
+//! lattice merge --when-green
+//!
+//! # Add the stack to the merge queue
+//! lattice merge --queue
+//!
+//! # Ask reviewers of outdated approvals to look again
+//! lattice merge --rerequest-stale
 //! ```
 
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
 use crate::cli::args::MergeMethodArg;
-use crate::cli::commands::auth::get_github_token;
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::config::Config;
 use crate::core::metadata::schema::PrState;
 use crate::core::ops::journal::OpId;
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::command::{AsyncCommand, CommandOutput, PlanFut};
 use crate::engine::exec::ExecuteResult;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::ledger::{BranchStage, Event, EventLedger};
 use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
-use crate::forge::{create_forge, MergeMethod};
+use crate::forge::status_cache::{CachedPrStatus, ForgeStatusCache};
+use crate::forge::{
+    CheckState, CheckStatus, Forge, MergeMethod, ReviewState, Reviewers, UpdatePrRequest,
+};
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 
 /// Result of a merge operation.
 ///
@@ -59,6 +106,260 @@ pub struct MergeResult {
     pub merged_branches: Vec<BranchName>,
 }
 
+/// How `--when-green` waits for a PR's checks.
+#[derive(Debug, Clone, Copy)]
+pub struct GreenWait {
+    /// Time between polls
+    pub interval: Duration,
+    /// Time to wait for one PR before giving up
+    pub timeout: Duration,
+}
+
+impl GreenWait {
+    /// Read the polling settings from `[merge]` in the config.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            interval: Duration::from_secs(config.merge_poll_interval_secs()),
+            timeout: Duration::from_secs(config.merge_wait_timeout_secs()),
+        }
+    }
+}
+
+/// What a PR's checks say about merging it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CheckVerdict {
+    /// Every check that counts has passed
+    Green,
+    /// These checks are still running
+    Pending(Vec<String>),
+    /// These checks failed
+    Failing(Vec<String>),
+}
+
+impl CheckVerdict {
+    /// Judge a PR's checks.
+    ///
+    /// Only required checks count when branch protection marks any;
+    /// otherwise all do. Failures win over pending checks.
+    fn of(checks: &[CheckStatus]) -> Self {
+        let any_required = checks.iter().any(|c| c.required);
+        let counted: Vec<&CheckStatus> = checks
+            .iter()
+            .filter(|c| c.required || !any_required)
+            .collect();
+        let named = |state: CheckState| -> Vec<String> {
+            counted
+                .iter()
+                .filter(|c| c.state == state)
+                .map(|c| c.name.clone())
+                .collect()
+        };
+
+        let failing = named(CheckState::Failure);
+        if !failing.is_empty() {
+            return CheckVerdict::Failing(failing);
+        }
+        let pending = named(CheckState::Pending);
+        if !pending.is_empty() {
+            return CheckVerdict::Pending(pending);
+        }
+        CheckVerdict::Green
+    }
+}
+
+/// The review requirements `reviews` leaves unmet, each described.
+///
+/// Only a protected base branch that requires approving reviews makes
+/// requested changes block a merge, as on GitHub.
+fn unmet_reviews(reviews: &ReviewState) -> Vec<String> {
+    let mut unmet = Vec::new();
+    if let Some(required) = reviews.required_approvals {
+        if !reviews.changes_requested_by.is_empty() {
+            unmet.push(format!(
+                "changes requested by {}",
+                reviews.changes_requested_by.join(", ")
+            ));
+        }
+        let counted = reviews.counted_approvals();
+        if counted < required as usize {
+            let mut need = format!(
+                "{} approving review{} required, {} given",
+                required,
+                if required == 1 { "" } else { "s" },
+                counted
+            );
+            if reviews.dismisses_stale && !reviews.stale_approvals.is_empty() {
+                need.push_str(&format!(
+                    " ({} approved an older commit)",
+                    reviews.stale_approvals.join(", ")
+                ));
+            }
+            unmet.push(need);
+        }
+    }
+    if reviews.requires_code_owners && !reviews.pending_code_owners.is_empty() {
+        unmet.push(format!(
+            "code owner review required from {}",
+            reviews.pending_code_owners.join(", ")
+        ));
+    }
+    unmet
+}
+
+/// Reviews of the PRs about to merge, checked before each merge.
+#[derive(Debug, Default)]
+struct ReviewGate {
+    /// Reviews by PR number; PRs without an entry aren't gated
+    reviews: HashMap<u64, ReviewState>,
+    /// Re-request review from reviewers whose approval is stale
+    rerequest_stale: bool,
+}
+
+impl ReviewGate {
+    /// Look up the reviews of `prs` (branch and PR number) in one batch.
+    ///
+    /// The result refreshes the status cache. When the lookup fails, the
+    /// cached reviews are used instead ([`Self::cached`]).
+    async fn fetch(
+        git: &Git,
+        forge: &dyn Forge,
+        prs: &[(BranchName, u64)],
+        rerequest_stale: bool,
+        quiet: bool,
+    ) -> CliResult<Self> {
+        let paths = LatticePaths::from_repo_info(&git.info()?);
+        let numbers: Vec<u64> = prs.iter().map(|(_, number)| *number).collect();
+        let statuses = match forge.get_pr_statuses(&numbers).await {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                if !quiet {
+                    eprintln!(
+                        "{}",
+                        i18n::t_args("merging-warning-could-refresh", &[("error", &e)])
+                    );
+                }
+                return Self::cached(git, prs, rerequest_stale, quiet);
+            }
+        };
+
+        let mut cache = ForgeStatusCache::load(&paths);
+        let mut reviews = HashMap::new();
+        for status in statuses {
+            let number = status.pr.number;
+            if let Some((branch, _)) = prs.iter().find(|(_, n)| *n == number) {
+                cache.set(branch.as_str(), CachedPrStatus::from_status(&status));
+            }
+            if let Some(state) = status.reviews {
+                reviews.insert(number, state);
+            }
+        }
+        cache.save(&paths);
+        Ok(Self {
+            reviews,
+            rerequest_stale,
+        })
+    }
+
+    /// The reviews of `prs` as last cached, by `sync` or an earlier merge.
+    /// PRs with none cached aren't gated.
+    fn cached(
+        git: &Git,
+        prs: &[(BranchName, u64)],
+        rerequest_stale: bool,
+        quiet: bool,
+    ) -> CliResult<Self> {
+        let cache = ForgeStatusCache::load(&LatticePaths::from_repo_info(&git.info()?));
+        let mut reviews = HashMap::new();
+        for (branch, number) in prs {
+            let Some(entry) = cache.get(branch.as_str(), *number) else {
+                continue;
+            };
+            if let Some(state) = &entry.reviews {
+                if !quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "merging-using-reviews-pr",
+                            &[
+                                ("number", &number),
+                                ("stale", &super::cached_pr::stale_suffix(entry.fetched_at)),
+                            ],
+                        )
+                    );
+                }
+                reviews.insert(*number, state.clone());
+            }
+        }
+        Ok(Self {
+            reviews,
+            rerequest_stale,
+        })
+    }
+
+    /// Unmet review requirements of PR `number`.
+    fn unmet(&self, number: u64) -> Vec<String> {
+        self.reviews
+            .get(&number)
+            .map(unmet_reviews)
+            .unwrap_or_default()
+    }
+
+    /// Fail unless PR `number`'s reviews allow merging it, re-requesting
+    /// stale reviews first when asked to.
+    async fn check(
+        &self,
+        forge: &dyn Forge,
+        number: u64,
+        branch: &BranchName,
+        quiet: bool,
+    ) -> CliResult {
+        let unmet = self.unmet(number);
+        if unmet.is_empty() {
+            return Ok(());
+        }
+        let stale = self
+            .reviews
+            .get(&number)
+            .map(|r| r.stale_approvals.clone())
+            .unwrap_or_default();
+
+        let mut message = format!("PR #{} ({}) can't be merged yet:", number, branch);
+        for requirement in &unmet {
+            message.push_str(&format!("\n  - {}", requirement));
+        }
+        if !stale.is_empty() {
+            if self.rerequest_stale {
+                forge
+                    .request_reviewers(
+                        number,
+                        Reviewers {
+                            users: stale.clone(),
+                            teams: vec![],
+                        },
+                    )
+                    .await
+                    .with_context(|| format!("Failed to re-request review on PR #{}", number))?;
+                if !quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "merging-re-requested-review",
+                            &[("number", &number), ("reviewers", &stale.join(", "))],
+                        )
+                    );
+                }
+            } else {
+                message.push_str(&format!(
+                    "\nRun with --rerequest-stale to ask {} to review the latest commit.",
+                    stale.join(", ")
+                ));
+            }
+        }
+        message.push_str("\nNot merging it or anything above it.");
+        Err(CliError::conflict(message))
+    }
+}
+
 /// The merge command.
 ///
 /// Merges PRs in stack order from trunk to current branch via GitHub API.
@@ -67,11 +368,23 @@ pub struct MergeCommand {
     merge_method: MergeMethod,
     /// Quiet mode.
     quiet: bool,
+    /// Wait for each PR's checks to pass before merging it.
+    when_green: Option<GreenWait>,
+    /// Add the PRs to the merge queue instead of merging them.
+    queue: bool,
+    /// Re-request review from reviewers whose approval is stale.
+    rerequest_stale: bool,
 }
 
 impl MergeCommand {
     /// Create a new merge command.
-    pub fn new(method: Option<MergeMethodArg>, quiet: bool) -> Self {
+    pub fn new(
+        method: Option<MergeMethodArg>,
+        quiet: bool,
+        when_green: Option<GreenWait>,
+        queue: bool,
+        rerequest_stale: bool,
+    ) -> Self {
         let merge_method = match method {
             Some(MergeMethodArg::Merge) => MergeMethod::Merge,
             Some(MergeMethodArg::Squash) => MergeMethod::Squash,
@@ -82,6 +395,9 @@ impl MergeCommand {
         Self {
             merge_method,
             quiet,
+            when_green,
+            queue,
+            rerequest_stale,
         }
     }
 }
@@ -130,9 +446,14 @@ impl AsyncCommand for MergeCommand {
             for branch in &mergeable {
                 if let Some(scanned) = ready.snapshot.metadata.get(branch) {
                     if let PrState::Linked { number, .. } = &scanned.metadata.pr {
+                        let method = if self.queue {
+                            "merge-queue".to_string()
+                        } else {
+                            self.merge_method.to_string()
+                        };
                         plan = plan.with_step(PlanStep::ForgeMergePr {
                             number: *number,
-                            method: self.merge_method.to_string(),
+                            method,
                         });
                     }
                 }
@@ -171,15 +492,32 @@ pub fn merge(
     _confirm: bool,
     dry_run: bool,
     method: Option<MergeMethodArg>,
-) -> Result<()> {
+    when_green: bool,
+    queue: bool,
+    rerequest_stale: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let when_green = when_green.then(|| {
+        let config = Config::load(Some(&cwd))
+            .map(|r| r.config)
+            .unwrap_or_default();
+        GreenWait::from_config(&config)
+    });
 
     let rt = tokio::runtime::Runtime::new()?;
-    rt.block_on(merge_impl(&git, ctx, dry_run, method))
+    rt.block_on(merge_impl(
+        &git,
+        ctx,
+        dry_run,
+        method,
+        when_green,
+        queue,
+        rerequest_stale,
+    ))
 }
 
 /// Async implementation of merge using the engine lifecycle.
@@ -191,11 +529,14 @@ async fn merge_impl(
     ctx: &Context,
     dry_run: bool,
     method: Option<MergeMethodArg>,
-) -> Result<()> {
+    when_green: Option<GreenWait>,
+    queue: bool,
+    rerequest_stale: bool,
+) -> CliResult {
     use crate::engine::runner::run_async_command;
 
     // Create the command
-    let command = MergeCommand::new(method, ctx.quiet);
+    let command = MergeCommand::new(method, ctx.quiet, when_green, queue, rerequest_stale);
 
     // Run through the async command lifecycle for proper gating
     // This performs: Scan -> Gate -> Plan
@@ -210,15 +551,16 @@ async fn merge_impl(
             }
             CommandOutput::Paused { message } => {
                 // Should not happen for merge
-                bail!("Unexpected pause: {}", message);
-            }
-            CommandOutput::Failed { error } => {
-                bail!("{}", error);
+                Err(CliError::new(
+                    ErrorCategory::Internal,
+                    format!("Unexpected pause: {}", message),
+                ))
             }
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
         Err(e) => {
             // Gating or planning failed
-            bail!("Merge failed: {}", e);
+            Err(e.into())
         }
     }
 }
@@ -232,7 +574,7 @@ async fn execute_merge_plan(
     _ctx: &Context,
     dry_run: bool,
     command: &MergeCommand,
-) -> Result<()> {
+) -> CliResult {
     use crate::engine::scan::scan;
 
     // Re-scan to get current state (gating already validated requirements)
@@ -242,17 +584,10 @@ async fn execute_merge_plan(
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on a branch."))?;
+        .ok_or_else(|| CliError::usage("Not on a branch."))?;
 
-    // Get authentication
-    let token = get_github_token()?;
-
-    // Get forge
-    let remote_url = git
-        .remote_url("origin")?
-        .ok_or_else(|| anyhow::anyhow!("No 'origin' remote configured."))?;
-
-    let forge = create_forge(&remote_url, &token, None)?;
+    // Get an authenticated forge
+    let forge = super::origin_forge(git)?;
 
     // Get stack from trunk to current (ancestors + current)
     let mut stack = snapshot.graph.ancestors(current);
@@ -273,19 +608,77 @@ async fn execute_merge_plan(
         .collect();
 
     if mergeable.is_empty() {
-        bail!("No PRs to merge. Run 'lattice submit' first.");
+        return Err(CliError::usage(
+            "No PRs to merge. Run 'lattice submit' first.",
+        ));
+    }
+
+    let linked: Vec<(BranchName, u64)> = mergeable
+        .iter()
+        .filter_map(|branch| {
+            let number = snapshot.metadata.get(branch)?.metadata.pr.number()?;
+            Some((branch.clone(), number))
+        })
+        .collect();
+    // A dry run makes no API calls, so it judges the cached reviews
+    let gate = if dry_run {
+        ReviewGate::cached(git, &linked, command.rerequest_stale, command.quiet)?
+    } else {
+        ReviewGate::fetch(
+            git,
+            forge.as_ref(),
+            &linked,
+            command.rerequest_stale,
+            command.quiet,
+        )
+        .await?
+    };
+
+    if command.queue {
+        let trunk = snapshot
+            .trunk
+            .as_ref()
+            .ok_or_else(CliError::not_initialized)?;
+        let prs = queued_prs(&snapshot, &mergeable);
+        if dry_run {
+            println!(
+                "{}",
+                i18n::t_args("merging-would-add-pr", &[("count", &prs.len())])
+            );
+            for pr in &prs {
+                let note = if pr.retarget {
+                    format!(", retargeted to '{}'", trunk)
+                } else {
+                    String::new()
+                };
+                println!("  PR #{} ({}){}", pr.number, pr.branch, note);
+                print_unmet(&gate, pr.number);
+            }
+            return Ok(());
+        }
+        return enqueue_stack(forge.as_ref(), trunk, &prs, &gate, command.quiet).await;
     }
 
     if dry_run {
         println!(
-            "Would merge {} PR(s) using {} method:",
-            mergeable.len(),
-            command.merge_method
+            "{}",
+            i18n::t_args(
+                if command.when_green.is_some() {
+                    "merging-would-merge-when-green"
+                } else {
+                    "merging-would-merge"
+                },
+                &[
+                    ("count", &mergeable.len()),
+                    ("merge_method", &command.merge_method),
+                ],
+            )
         );
         for branch in &mergeable {
             if let Some(scanned) = snapshot.metadata.get(branch) {
                 if let PrState::Linked { number, .. } = &scanned.metadata.pr {
                     println!("  PR #{} ({})", number, branch);
+                    print_unmet(&gate, *number);
                 }
             }
         }
@@ -297,20 +690,49 @@ async fn execute_merge_plan(
     for branch in &mergeable {
         if let Some(scanned) = snapshot.metadata.get(branch) {
             if let PrState::Linked { number, .. } = &scanned.metadata.pr {
+                let ready = async {
+                    gate.check(forge.as_ref(), *number, branch, command.quiet)
+                        .await?;
+                    match command.when_green {
+                        Some(wait) => {
+                            wait_until_green(forge.as_ref(), *number, wait, command.quiet).await
+                        }
+                        None => Ok(()),
+                    }
+                };
+                if let Err(e) = ready.await {
+                    if merged_count > 0 {
+                        eprintln!(
+                            "{}",
+                            i18n::t_args(
+                                "merging-pr-merged-before",
+                                &[("merged_count", &merged_count)],
+                            )
+                        );
+                    }
+                    return Err(e);
+                }
                 if !command.quiet {
-                    println!("Merging PR #{} ({})...", number, branch);
+                    println!(
+                        "{}",
+                        i18n::t_args("merging-pr", &[("number", &number), ("branch", &branch)])
+                    );
                 }
 
                 match forge.merge_pr(*number, command.merge_method).await {
                     Ok(()) => {
                         merged_count += 1;
+                        let _ = EventLedger::new(git).append(Event::branch_lifecycle(
+                            branch.as_str(),
+                            BranchStage::Merged,
+                        ));
                         if !command.quiet {
-                            println!("  Merged successfully.");
+                            println!("  {}", i18n::t("merging-merged-successfully"));
                         }
                     }
                     Err(e) => {
-                        eprintln!("  Failed to merge: {}", e);
-                        eprintln!("Stopping. Run 'lattice sync' to update state.");
+                        eprintln!("  {}", i18n::t_args("merging-failed", &[("error", &e)]));
+                        eprintln!("{}", i18n::t("merging-stopping-run-lattice"));
                         return Err(e.into());
                     }
                 }
@@ -320,16 +742,384 @@ async fn execute_merge_plan(
 
     if !command.quiet {
         println!(
-            "\n{} PR(s) merged. Run 'lattice sync' to update local state.",
-            merged_count
+            "\n{}",
+            i18n::t_args("merging-pr-merged-run", &[("merged_count", &merged_count)])
+        );
+    }
+
+    Ok(())
+}
+
+/// Print what keeps PR `number` from merging, for a dry run.
+fn print_unmet(gate: &ReviewGate, number: u64) {
+    for requirement in gate.unmet(number) {
+        println!(
+            "    {}",
+            i18n::t_args("merging-blocked", &[("requirement", &requirement)])
         );
     }
+}
+
+/// A PR `--queue` adds to the merge queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueuedPr {
+    branch: BranchName,
+    number: u64,
+    /// Whether the PR targets a parent branch and must target trunk first
+    retarget: bool,
+}
+
+/// The linked PRs of `branches`, in order, as `--queue` adds them.
+fn queued_prs(snapshot: &RepoSnapshot, branches: &[BranchName]) -> Vec<QueuedPr> {
+    branches
+        .iter()
+        .filter_map(|branch| {
+            let metadata = &snapshot.metadata.get(branch)?.metadata;
+            Some(QueuedPr {
+                branch: branch.clone(),
+                number: metadata.pr.number()?,
+                retarget: !metadata.parent.is_trunk(),
+            })
+        })
+        .collect()
+}
+
+/// Add `prs` to the merge queue bottom-up, reporting where each one landed.
+///
+/// Stops at the first PR that can't be retargeted or queued; the PRs queued
+/// before it stay in the queue.
+async fn enqueue_stack(
+    forge: &dyn Forge,
+    trunk: &BranchName,
+    prs: &[QueuedPr],
+    gate: &ReviewGate,
+    quiet: bool,
+) -> CliResult {
+    let mut queued = 0;
+    for pr in prs {
+        let result = async {
+            gate.check(forge, pr.number, &pr.branch, quiet).await?;
+            if pr.retarget {
+                forge
+                    .update_pr(UpdatePrRequest {
+                        number: pr.number,
+                        base: Some(trunk.to_string()),
+                        ..Default::default()
+                    })
+                    .await
+                    .with_context(|| {
+                        format!("Failed to retarget PR #{} to '{}'", pr.number, trunk)
+                    })?;
+            }
+            forge
+                .enqueue_pr(pr.number)
+                .await
+                .with_context(|| format!("Failed to add PR #{} to the merge queue", pr.number))
+        }
+        .await;
 
+        match result {
+            Ok(entry) => {
+                queued += 1;
+                if !quiet {
+                    let position = entry
+                        .position
+                        .map(|p| format!(" at position {}", p))
+                        .unwrap_or_default();
+                    println!(
+                        "PR #{} ({}): {}{}",
+                        pr.number, pr.branch, entry.state, position
+                    );
+                }
+            }
+            Err(e) => {
+                if queued > 0 {
+                    eprintln!(
+                        "{}",
+                        i18n::t_args("merging-pr-queued-before", &[("queued", &queued)])
+                    );
+                }
+                return Err(e.into());
+            }
+        }
+    }
+
+    if !quiet {
+        println!(
+            "\n{}",
+            i18n::t_args("merging-pr-merge-queue", &[("queued", &queued)])
+        );
+    }
     Ok(())
 }
 
+/// Poll PR `number`'s checks until they are green.
+///
+/// Fails when a check fails, a poll fails, or the checks are still pending
+/// after `wait.timeout`.
+async fn wait_until_green(
+    forge: &dyn Forge,
+    number: u64,
+    wait: GreenWait,
+    quiet: bool,
+) -> CliResult {
+    let deadline = Instant::now() + wait.timeout;
+    let mut reported = None;
+    loop {
+        let checks = forge
+            .list_checks(number)
+            .await
+            .with_context(|| format!("Failed to read checks on PR #{}", number))?;
+        let pending = match CheckVerdict::of(&checks) {
+            CheckVerdict::Green => return Ok(()),
+            CheckVerdict::Failing(names) => {
+                return Err(CliError::new(
+                    ErrorCategory::Forge,
+                    format!(
+                        "PR #{} has failing checks: {}. Not merging it or anything above it.",
+                        number,
+                        names.join(", ")
+                    ),
+                ))
+            }
+            CheckVerdict::Pending(names) => names,
+        };
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(CliError::new(
+                ErrorCategory::Forge,
+                format!(
+                    "Gave up after {}s waiting for checks on PR #{}: {} still pending. \
+                 Raise [merge] wait_timeout_secs to wait longer.",
+                    wait.timeout.as_secs(),
+                    number,
+                    pending.join(", ")
+                ),
+            ));
+        }
+        if !quiet && reported.as_ref() != Some(&pending) {
+            println!(
+                "{}",
+                i18n::t_args(
+                    "merging-waiting-checks-pr",
+                    &[("number", &number), ("checks", &pending.join(", "))],
+                )
+            );
+            reported = Some(pending);
+        }
+        tokio::time::sleep(wait.interval.min(deadline - now)).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::forge::mock::MockForge;
+
+    fn check(name: &str, state: CheckState, required: bool) -> CheckStatus {
+        CheckStatus {
+            name: name.into(),
+            state,
+            required,
+        }
+    }
+
+    fn wait(timeout_ms: u64) -> GreenWait {
+        GreenWait {
+            interval: Duration::from_millis(5),
+            timeout: Duration::from_millis(timeout_ms),
+        }
+    }
+
+    #[test]
+    fn verdict_counts_only_required_checks_when_some_are() {
+        let checks = [
+            check("build", CheckState::Success, true),
+            check("lint", CheckState::Failure, false),
+            check("deploy-preview", CheckState::Pending, false),
+        ];
+        assert_eq!(CheckVerdict::of(&checks), CheckVerdict::Green);
+
+        let checks = [
+            check("build", CheckState::Success, false),
+            check("lint", CheckState::Pending, false),
+            check("test", CheckState::Failure, false),
+        ];
+        assert_eq!(
+            CheckVerdict::of(&checks),
+            CheckVerdict::Failing(vec!["test".into()])
+        );
+        assert_eq!(
+            CheckVerdict::of(&checks[..2]),
+            CheckVerdict::Pending(vec!["lint".into()])
+        );
+        assert_eq!(CheckVerdict::of(&[]), CheckVerdict::Green);
+    }
+
+    #[tokio::test]
+    async fn waits_until_green_or_gives_up() {
+        let forge = MockForge::new()
+            .with_checks(1, vec![check("build", CheckState::Skipped, true)])
+            .with_checks(2, vec![check("build", CheckState::Failure, true)])
+            .with_checks(3, vec![check("build", CheckState::Pending, true)]);
+
+        assert!(wait_until_green(&forge, 1, wait(1000), true).await.is_ok());
+
+        let err = wait_until_green(&forge, 2, wait(1000), true)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("failing checks: build"), "{}", err);
+
+        let err = wait_until_green(&forge, 3, wait(20), true)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("build still pending"), "{}", err);
+    }
+
+    fn protected(required: u32) -> ReviewState {
+        ReviewState {
+            required_approvals: Some(required),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unmet_reviews_name_each_requirement() {
+        let mut reviews = protected(2);
+        reviews.approved_by = vec!["alice".into()];
+        reviews.stale_approvals = vec!["bob".into()];
+        assert!(unmet_reviews(&reviews).is_empty());
+
+        reviews.dismisses_stale = true;
+        reviews.changes_requested_by = vec!["carol".into()];
+        reviews.requires_code_owners = true;
+        reviews.pending_code_owners = vec!["team:platform".into()];
+        assert_eq!(
+            unmet_reviews(&reviews),
+            vec![
+                "changes requested by carol",
+                "2 approving reviews required, 1 given (bob approved an older commit)",
+                "code owner review required from team:platform",
+            ]
+        );
+
+        // Without required reviews, requested changes don't block
+        let reviews = ReviewState {
+            changes_requested_by: vec!["carol".into()],
+            ..Default::default()
+        };
+        assert!(unmet_reviews(&reviews).is_empty());
+    }
+
+    #[tokio::test]
+    async fn review_gate_rerequests_stale_reviewers() {
+        use crate::forge::mock::MockOperation;
+
+        let mut reviews = protected(1);
+        reviews.dismisses_stale = true;
+        reviews.stale_approvals = vec!["bob".into()];
+        let forge = MockForge::with_prs(vec![open_pr(1, "a", "main")]);
+        let branch = BranchName::new("a").unwrap();
+        let mut gate = ReviewGate {
+            reviews: HashMap::from([(1, reviews)]),
+            rerequest_stale: false,
+        };
+
+        let err = gate.check(&forge, 1, &branch, true).await.unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("1 approving review required, 0 given"),
+            "{}",
+            message
+        );
+        assert!(message.contains("--rerequest-stale"), "{}", message);
+        assert!(forge.operations().is_empty());
+
+        gate.rerequest_stale = true;
+        assert!(gate.check(&forge, 1, &branch, true).await.is_err());
+        assert!(forge.operations().iter().any(|op| matches!(
+            op,
+            MockOperation::RequestReviewers { number: 1, users, .. } if users == &vec!["bob".to_string()]
+        )));
+
+        // PRs without reviews aren't gated
+        assert!(gate.check(&forge, 2, &branch, true).await.is_ok());
+    }
+
+    fn open_pr(number: u64, head: &str, base: &str) -> crate::forge::PullRequest {
+        crate::forge::PullRequest {
+            number,
+            url: format!("https://github.com/owner/repo/pull/{}", number),
+            state: crate::forge::PrState::Open,
+            is_draft: false,
+            head: head.into(),
+            base: base.into(),
+            title: head.into(),
+            body: None,
+            node_id: None,
+            author: None,
+        }
+    }
+
+    fn queued(branch: &str, number: u64, retarget: bool) -> QueuedPr {
+        QueuedPr {
+            branch: BranchName::new(branch).unwrap(),
+            number,
+            retarget,
+        }
+    }
+
+    #[tokio::test]
+    async fn queue_retargets_stacked_prs_then_enqueues_bottom_up() {
+        use crate::forge::mock::MockOperation;
+
+        let forge = MockForge::with_prs(vec![open_pr(1, "a", "main"), open_pr(2, "b", "a")]);
+        let trunk = BranchName::new("main").unwrap();
+        let prs = [queued("a", 1, false), queued("b", 2, true)];
+        enqueue_stack(&forge, &trunk, &prs, &ReviewGate::default(), true)
+            .await
+            .unwrap();
+
+        let ops: Vec<String> = forge
+            .operations()
+            .into_iter()
+            .filter_map(|op| match op {
+                MockOperation::UpdatePr { number, base, .. } => {
+                    Some(format!("retarget #{} to {}", number, base?))
+                }
+                MockOperation::EnqueuePr { number } => Some(format!("enqueue #{}", number)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ops, vec!["enqueue #1", "retarget #2 to main", "enqueue #2"]);
+        assert_eq!(forge.get_pr_sync(2).unwrap().base, "main");
+    }
+
+    #[tokio::test]
+    async fn queue_stops_at_the_first_pr_it_cannot_add() {
+        use crate::forge::mock::FailOn;
+        use crate::forge::ForgeError;
+
+        let forge = MockForge::with_prs(vec![open_pr(1, "a", "main")]).fail_on(FailOn::EnqueuePr(
+            ForgeError::ApiError {
+                status: 200,
+                message: "Merge queue is not enabled".into(),
+            },
+        ));
+        let trunk = BranchName::new("main").unwrap();
+        let err = enqueue_stack(
+            &forge,
+            &trunk,
+            &[queued("a", 1, false)],
+            &ReviewGate::default(),
+            true,
+        )
+        .await
+        .unwrap_err();
+        assert!(format!("{:#}", err).contains("Merge queue is not enabled"));
+    }
+
     #[test]
     fn merge_method_conversion() {
         use crate::forge::MergeMethod;
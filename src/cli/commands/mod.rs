@@ -17,133 +17,241 @@
 //! because they involve network I/O. The dispatch function uses
 //! `tokio::runtime::Handle` to run async commands within the sync context.
 
+mod absorb;
+mod attach;
+mod audit;
 mod auth;
+mod batch;
+mod body_generator;
+mod cached_pr;
 mod changelog;
 mod checkout;
+mod checkpoint;
+mod ci_status;
 mod completion;
 mod config_cmd;
+mod conflict_summary;
 mod create;
+mod debug_cmd;
 mod delete;
+mod edit;
+mod edit_branch;
 mod fold;
 mod freeze;
 mod get;
+mod graph_export;
+mod hints;
 mod info;
 mod init;
+mod integrations;
+mod lock;
 mod log_cmd;
+mod maintenance;
 mod merge;
 mod modify;
 mod move_cmd;
 mod navigation;
+mod ops_log;
+mod pending_ops;
 mod phase3_helpers;
+pub(crate) mod phase_budgets;
 mod pop;
 mod pr;
+mod prefetch;
 mod recovery;
 mod relationships;
+mod remote;
 mod rename;
 mod reorder;
 mod restack;
 mod revert;
 mod split;
 mod squash;
+mod stack;
 pub mod stack_comment_ops;
+mod stats;
 mod submit;
+mod submit_waves;
 mod sync;
 mod track;
 mod trunk;
+mod trunk_drift;
+mod ui_cmd;
 mod undo;
 mod unlink;
 mod untrack;
+mod workspace;
 
 // Re-export command functions for testing and direct invocation
-pub use auth::{auth, get_github_token, has_github_token};
+pub use absorb::absorb;
+pub use audit::audit;
+pub use auth::{
+    auth, get_forge_token, get_github_token, get_github_token_for, github_host, has_forge_token,
+    has_github_token,
+};
 pub use changelog::changelog;
 pub use checkout::checkout;
-pub use completion::completion;
+pub use checkpoint::{
+    delete as checkpoint_delete, list as checkpoint_list, restore as checkpoint_restore,
+    save as checkpoint_save,
+};
+pub use completion::{complete, completion};
 pub use config_cmd::{get as config_get, list as config_list, set as config_set};
 pub use create::create;
+pub use debug_cmd::{cache_stats, clear_cache};
 pub use delete::delete;
+pub use edit::edit;
 pub use fold::fold;
-pub use freeze::{freeze, unfreeze};
+pub use freeze::{freeze, set_frozen, unfreeze, FreezeRange};
 pub use get::get;
 pub use info::info;
 pub use init::init;
+pub use lock::{lock, unlock};
 pub use log_cmd::log;
 pub use merge::merge;
 pub use modify::modify;
 pub use move_cmd::move_branch;
 pub use navigation::{bottom, down, top, up};
+pub use ops_log::log_ops;
 pub use pop::pop;
 pub use pr::pr;
 pub use recovery::{abort, continue_op};
 pub use relationships::{children, parent};
+pub use remote::remote;
 pub use rename::rename;
 pub use reorder::reorder;
-pub use restack::restack;
+pub(crate) use restack::RestackCommand;
+pub use restack::{restack, restack_onto};
 pub use revert::revert;
 pub use split::split;
 pub use squash::squash;
-pub use submit::submit;
+pub use stack::create as stack_create;
+pub use stats::stats;
+pub use submit::{submit, SubmitOptions, SubmitSelection};
 pub use sync::sync;
 pub use track::track;
 pub use trunk::trunk;
-pub use undo::undo;
+pub use ui_cmd::ui;
+pub use undo::{redo, undo, undo_list, undo_to};
 pub use unlink::unlink;
 pub use untrack::untrack;
 
-use crate::cli::args::{Command, ConfigAction};
-use crate::doctor::{Doctor, FixId};
+use crate::cli::args::{
+    CheckpointAction, Command, CompletionAction, ConfigAction, DebugAction, IntegrationsAction,
+    PrAction, SeverityArg, StackAction, WsAction,
+};
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::doctor::{DiagnosisReport, Doctor, FixAllSelection, FixId};
+use crate::engine::capabilities::Capability;
 use crate::engine::exec::{ExecuteResult, Executor};
+use crate::engine::gate::RepairBundle;
+use crate::engine::health::Severity;
 use crate::engine::ledger::{Event, EventLedger};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::Result;
+use crate::ui::{i18n, prompts};
 
 /// Dispatch a command to its handler.
-pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
-    match command {
+///
+/// Handlers return [`CliResult`], building the typed
+/// [`CliError`](crate::cli::error::CliError) where they fail.
+/// `json` is the global `--json` flag.
+pub fn dispatch(command: Command, ctx: &Context, json: bool) -> CliResult {
+    // A second copy of a running command can follow the first instead
+    if attach::follow_duplicate(ctx)? {
+        return Ok(());
+    }
+
+    let result = match command {
         Command::Doctor {
             fix_ids,
+            fix_all,
+            max_severity,
             dry_run,
             list,
             deep_remote,
-        } => doctor(ctx, &fix_ids, dry_run, list, deep_remote),
+        } => {
+            let fix_all = fix_all.then_some(match max_severity {
+                SeverityArg::Info => Severity::Info,
+                SeverityArg::Warning => Severity::Warning,
+                SeverityArg::Error => Severity::Blocking,
+            });
+            doctor(ctx, &fix_ids, fix_all, dry_run, list, deep_remote, json)
+        }
 
         // Phase A: Read-Only Commands
+        Command::Log {
+            ops: true, reverse, ..
+        } => ops_log::log_ops(ctx, reverse, json),
         Command::Log {
             short,
             long,
             stack,
             all,
             reverse,
-        } => log_cmd::log(ctx, short, long, stack, all, reverse),
+            depth,
+            select,
+            export,
+            ops: false,
+        } => log_cmd::log(
+            ctx, short, long, stack, all, reverse, depth, select, export, json,
+        ),
         Command::Info {
             branch,
             diff,
             stat,
             patch,
-        } => info::info(ctx, branch.as_deref(), diff, stat, patch),
-        Command::Parent => relationships::parent(ctx),
-        Command::Children => relationships::children(ctx),
+            files,
+            stack,
+            remote_diff,
+        } => info::info(
+            ctx,
+            branch.as_deref(),
+            diff,
+            stat,
+            patch,
+            files,
+            stack,
+            remote_diff,
+            json,
+        ),
+        Command::Parent => relationships::parent(ctx, json),
+        Command::Children => relationships::children(ctx, json),
+        Command::Ui => ui_cmd::ui(ctx),
         Command::Trunk { set } => trunk::trunk(ctx, set.as_deref()),
+        Command::Stats { branch } => stats::stats(ctx, branch.as_deref(), json),
+        Command::Audit => audit::audit(ctx, json),
+        Command::Debug { action } => match action {
+            DebugAction::CacheStats => debug_cmd::cache_stats(ctx, json),
+            DebugAction::ClearCache => debug_cmd::clear_cache(ctx),
+        },
 
         // Phase B: Setup Commands
         Command::Auth {
             no_browser,
             host,
+            client_id,
             status,
             logout,
-        } => auth::auth(ctx, &host, no_browser, status, logout),
+        } => auth::auth(ctx, &host, client_id.as_deref(), no_browser, status, logout),
         Command::Init {
             trunk,
             reset,
             force,
         } => init::init(ctx, trunk.as_deref(), reset, force),
+        Command::Hints { dismiss, reset } => hints::hints(ctx, dismiss.as_deref(), reset),
         Command::Config { action } => match action {
             ConfigAction::Get { key } => config_cmd::get(ctx, &key),
             ConfigAction::Set { key, value } => config_cmd::set(ctx, &key, &value),
             ConfigAction::List => config_cmd::list(ctx),
         },
-        Command::Completion { shell } => completion::completion(shell),
+        Command::Completion { shell, action } => match (action, shell) {
+            (Some(CompletionAction::Install { shell }), _) => completion::install(ctx, shell),
+            (None, Some(shell)) => completion::completion(shell),
+            (None, None) => unreachable!("clap requires a shell without a subcommand"),
+        },
+        Command::Complete { command, prefix } => completion::complete(ctx, &command, &prefix),
         Command::Changelog => changelog::changelog(),
 
         // Phase C: Tracking Commands
@@ -154,18 +262,44 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             as_frozen,
         } => track::track(ctx, branch.as_deref(), parent.as_deref(), force, as_frozen),
         Command::Untrack { branch, force } => untrack::untrack(ctx, branch.as_deref(), force),
-        Command::Freeze { branch, only } => freeze::freeze(ctx, branch.as_deref(), only),
-        Command::Unfreeze { branch, only } => freeze::unfreeze(ctx, branch.as_deref(), only),
+        Command::Freeze {
+            branch,
+            only,
+            upstack,
+            stack,
+        } => freeze::set_frozen(
+            ctx,
+            branch.as_deref(),
+            FreezeRange::from_flags(only, upstack, stack),
+            true,
+        ),
+        Command::Unfreeze {
+            branch,
+            only,
+            upstack,
+            stack,
+        } => freeze::set_frozen(
+            ctx,
+            branch.as_deref(),
+            FreezeRange::from_flags(only, upstack, stack),
+            false,
+        ),
+        Command::Lock { branch, ttl, list } => lock::lock(ctx, branch.as_deref(), ttl, list),
+        Command::Unlock { branch, force } => lock::unlock(ctx, branch.as_deref(), force),
+        Command::Remote { branch, set, unset } => {
+            remote::remote(ctx, branch.as_deref(), set.as_deref(), unset)
+        }
 
         // Phase D: Navigation Commands
         Command::Checkout {
             branch,
             trunk,
             stack,
-        } => checkout::checkout(ctx, branch.as_deref(), trunk, stack),
-        Command::Up { steps } => navigation::up(ctx, steps),
+            worktree,
+        } => checkout::checkout(ctx, branch.as_deref(), trunk, stack, worktree.as_deref()),
+        Command::Up { steps, child } => navigation::up(ctx, steps, child.as_deref()),
         Command::Down { steps } => navigation::down(ctx, steps),
-        Command::Top => navigation::top(ctx),
+        Command::Top { child } => navigation::top(ctx, child.as_deref()),
         Command::Bottom => navigation::bottom(ctx),
 
         // Phase E: Core Mutating Commands
@@ -173,10 +307,36 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             branch,
             only,
             downstack,
-        } => restack::restack(ctx, branch.as_deref(), only, downstack),
-        Command::Continue { all } => recovery::continue_op(ctx, all),
+            onto,
+            check,
+        } => match onto {
+            Some(onto) => restack::restack_onto(ctx, branch.as_deref(), only, &onto),
+            None if check => restack::restack_check(ctx, branch.as_deref(), only, downstack),
+            None => restack::restack(ctx, branch.as_deref(), only, downstack),
+        },
+        Command::Continue { all, skip } => recovery::continue_op(ctx, all, skip),
         Command::Abort => recovery::abort(ctx),
-        Command::Undo => undo::undo(ctx),
+        Command::Undo { op_id, list } => {
+            if list {
+                undo::undo_list(ctx)
+            } else if let Some(op_id) = op_id {
+                undo::undo_to(ctx, &op_id)
+            } else {
+                undo::undo(ctx)
+            }
+        }
+        Command::Redo => undo::redo(ctx),
+        Command::Checkpoint { action } => match action {
+            CheckpointAction::Save { name, force } => checkpoint::save(ctx, &name, force),
+            CheckpointAction::Restore { name } => checkpoint::restore(ctx, &name),
+            CheckpointAction::List => checkpoint::list(ctx),
+            CheckpointAction::Delete { name } => checkpoint::delete(ctx, &name),
+        },
+        Command::Integrations { action } => match action {
+            IntegrationsAction::Install { editor, force } => {
+                integrations::install(ctx, editor, force)
+            }
+        },
         Command::Create {
             name,
             message,
@@ -184,6 +344,8 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             update,
             patch,
             insert,
+            from_issue,
+            slug_from_message,
         } => create::create(
             ctx,
             name.as_deref(),
@@ -192,6 +354,8 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             update,
             patch,
             insert,
+            from_issue,
+            slug_from_message,
         ),
 
         // Phase 3: Advanced Rewriting Commands
@@ -202,20 +366,53 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             patch,
             message,
             edit,
-        } => modify::modify(ctx, create, all, update, patch, message.as_deref(), edit),
+            no_restack,
+        } => modify::modify(
+            ctx,
+            create,
+            all,
+            update,
+            patch,
+            message.as_deref(),
+            edit,
+            no_restack,
+        ),
         Command::Move { onto, source } => move_cmd::move_branch(ctx, &onto, source.as_deref()),
-        Command::Rename { name } => rename::rename(ctx, &name),
+        Command::Rename { name, push } => rename::rename(ctx, &name, push),
         Command::Delete {
             branch,
             upstack,
             downstack,
             force,
         } => delete::delete(ctx, branch.as_deref(), upstack, downstack, force),
-        Command::Squash { message, edit } => squash::squash(ctx, message.as_deref(), edit),
+        Command::Squash {
+            message,
+            edit,
+            no_restack,
+        } => squash::squash(ctx, message.as_deref(), edit, no_restack),
         Command::Fold { keep } => fold::fold(ctx, keep),
         Command::Pop => pop::pop(ctx),
         Command::Reorder => reorder::reorder(ctx),
+        Command::Edit {
+            branch,
+            stack,
+            done,
+            patch,
+            no_restack,
+        } => match branch {
+            Some(branch) => edit_branch::start(ctx, &branch, patch, no_restack),
+            None if done => edit_branch::done(ctx, no_restack),
+            None => edit::edit(ctx, stack, no_restack),
+        },
+        Command::Absorb {
+            update,
+            dry_run,
+            no_restack,
+        } => absorb::absorb(ctx, update, dry_run, no_restack),
         Command::Split { by_commit, by_file } => split::split(ctx, by_commit, by_file),
+        Command::Stack { action } => match action {
+            StackAction::Create { from, .. } => stack::create(ctx, &from),
+        },
         Command::Revert { sha } => revert::revert(ctx, &sha),
 
         // Phase F: GitHub Integration Commands
@@ -232,26 +429,60 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             team_reviewers,
             no_restack,
             view,
-        } => submit::submit(
-            ctx,
-            stack,
-            draft,
-            publish,
-            confirm,
-            dry_run,
-            force,
-            always,
-            update_only,
-            reviewers.as_deref(),
-            team_reviewers.as_deref(),
-            no_restack,
-            view,
-        ),
+            select,
+            branches,
+            since,
+            edit,
+            all,
+        } => {
+            let selection = match (select, branches.as_deref(), since.as_deref()) {
+                (true, _, _) => Some(SubmitSelection::Pick),
+                (_, Some(list), _) => Some(SubmitSelection::Named(list)),
+                (_, _, Some(since)) => Some(SubmitSelection::Since(since)),
+                _ => None,
+            };
+            let opts = SubmitOptions {
+                stack,
+                draft,
+                publish,
+                confirm,
+                dry_run,
+                force,
+                always,
+                update_only,
+                reviewers,
+                team_reviewers,
+                no_restack,
+                view,
+                offline: ctx.offline,
+                edit,
+                all,
+                ..Default::default()
+            };
+            submit::submit(ctx, opts, selection)
+        }
         Command::Sync {
             force,
             restack,
             no_restack,
-        } => sync::sync(ctx, force, restack && !no_restack),
+            dry_run,
+            prune,
+        } => sync::sync(ctx, force, restack && !no_restack, dry_run, prune),
+        Command::Ws { file, action } => match action {
+            WsAction::Sync {
+                force,
+                restack,
+                dry_run,
+                prune,
+            } => workspace::sync(ctx, file.as_deref(), force, restack, dry_run, prune),
+            WsAction::Log {
+                short,
+                long,
+                stack,
+                all,
+                reverse,
+            } => workspace::log(ctx, file.as_deref(), short, long, stack, all, reverse, json),
+        },
         Command::Get {
             target,
             downstack,
@@ -260,6 +491,7 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             no_restack,
             unfrozen,
             no_checkout,
+            dry_run,
         } => get::get(
             ctx,
             &target,
@@ -268,15 +500,38 @@ pub fn dispatch(command: Command, ctx: &Context) -> Result<()> {
             restack && !no_restack,
             unfrozen,
             no_checkout,
+            dry_run,
         ),
         Command::Merge {
             confirm,
             dry_run,
             method,
-        } => merge::merge(ctx, confirm, dry_run, method),
-        Command::Pr { target, stack } => pr::pr(ctx, target.as_deref(), stack),
+            when_green,
+            queue,
+            rerequest_stale,
+        } => merge::merge(
+            ctx,
+            confirm,
+            dry_run,
+            method,
+            when_green,
+            queue,
+            rerequest_stale,
+        ),
+        Command::Pr {
+            target,
+            stack,
+            action,
+        } => match action {
+            Some(PrAction::Open { target, stack }) => {
+                pr::pr(ctx, target.as_deref(), stack, json, true)
+            }
+            None => pr::pr(ctx, target.as_deref(), stack, json, false),
+        },
         Command::Unlink { branch } => unlink::unlink(ctx, branch.as_deref()),
-    }
+        Command::Prefetch { branch } => prefetch::prefetch(ctx, &branch),
+    };
+    result
 }
 
 /// Surface divergence information in debug/verbose output.
@@ -295,7 +550,7 @@ pub fn surface_divergence_if_debug(ctx: &Context, health: &crate::engine::RepoHe
                 &divergence.current_fingerprint[..12.min(divergence.current_fingerprint.len())]
             );
             if !divergence.changed_refs.is_empty() {
-                eprintln!("Changed refs:");
+                eprintln!("{}", i18n::t("cmd-changed-refs"));
                 for ref_name in &divergence.changed_refs {
                     eprintln!("  - {}", ref_name);
                 }
@@ -304,6 +559,36 @@ pub fn surface_divergence_if_debug(ctx: &Context, health: &crate::engine::RepoHe
     }
 }
 
+/// Print a `--json` document to stdout.
+///
+/// Commands that support `--json` write exactly one pretty-printed
+/// document to stdout and nothing else there.
+pub(crate) fn print_json(value: &impl serde::Serialize) -> Result<(), PlanError> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| PlanError::InvalidState(format!("Failed to encode JSON: {}", e)))?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// Run a plan made after a command's own plan, once the forge or the user
+/// has answered, through the executor.
+///
+/// The steps are journaled like any other, so `lattice undo` reverts them.
+/// Anything but success is an error.
+pub(crate) fn execute_follow_up(git: &Git, ctx: &Context, plan: &Plan) -> CliResult {
+    match Executor::new(git).execute(plan, ctx)? {
+        ExecuteResult::Success { .. } => Ok(()),
+        ExecuteResult::Aborted { error, .. } => Err(CliError::failed(error.trim())),
+        ExecuteResult::Paused { branch, .. } => Err(CliError::new(
+            ErrorCategory::OperationInProgress,
+            format!(
+                "'{}' paused unexpectedly; run 'lattice continue' or 'lattice abort'",
+                branch
+            ),
+        )),
+    }
+}
+
 /// Perform Tier 2 deep analysis for synthetic stack heads.
 ///
 /// Queries the forge for closed PRs that targeted each potential synthetic
@@ -312,7 +597,7 @@ fn perform_deep_synthetic_analysis(
     ctx: &Context,
     git: &Git,
     diagnosis: &mut crate::doctor::DiagnosisReport,
-) -> Result<()> {
+) -> CliResult {
     use crate::doctor::analyze_synthetic_stack_deep;
 
     // Load config to get budget settings
@@ -328,7 +613,7 @@ fn perform_deep_synthetic_analysis(
         Some(f) => f,
         None => {
             if ctx.debug {
-                eprintln!("Note: --deep-remote requested but forge not available");
+                eprintln!("{}", i18n::t("cmd-note-deep-remote"));
             }
             return Ok(());
         }
@@ -356,10 +641,14 @@ fn perform_deep_synthetic_analysis(
 
     if skipped > 0 && !ctx.quiet {
         println!(
-            "Note: Analyzing {} of {} potential synthetic heads (budget: {})",
-            bootstrap_config.max_synthetic_heads,
-            synthetic_head_issues.len(),
-            bootstrap_config.max_synthetic_heads
+            "{}",
+            i18n::t_args(
+                "cmd-note-analyzing-potential",
+                &[
+                    ("max_synthetic_heads", &bootstrap_config.max_synthetic_heads),
+                    ("count", &synthetic_head_issues.len()),
+                ],
+            )
         );
     }
 
@@ -367,7 +656,7 @@ fn perform_deep_synthetic_analysis(
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
-        .map_err(|e| anyhow::anyhow!("failed to create runtime: {}", e))?;
+        .map_err(|e| CliError::failed(format!("failed to create runtime: {}", e)))?;
 
     // Analyze each synthetic head
     for issue in issues_to_analyze {
@@ -386,10 +675,159 @@ fn perform_deep_synthetic_analysis(
     Ok(())
 }
 
+/// Find chains of open PRs with no local branches (`doctor --deep-remote`).
+///
+/// Reads branch tips with `git ls-remote` so the import fix can base each
+/// branch on the one below it. Failures are reported in debug mode only;
+/// the rest of the diagnosis stands.
+fn perform_deep_chain_analysis(
+    ctx: &Context,
+    git: &Git,
+    snapshot: &crate::engine::scan::RepoSnapshot,
+    diagnosis: &mut crate::doctor::DiagnosisReport,
+) {
+    if snapshot.remote_prs.is_none() {
+        return;
+    }
+    let remote_heads = match git.remote_heads("origin") {
+        Ok(heads) => heads,
+        Err(e) => {
+            if ctx.debug {
+                eprintln!("{}", i18n::t_args("cmd-note-could-list", &[("error", &e)]));
+            }
+            return;
+        }
+    };
+    for issue in crate::doctor::analyze_remote_pr_chains(snapshot, &remote_heads) {
+        diagnosis.add_issue(issue, snapshot);
+    }
+}
+
+/// Resolve the forge provider for a remote URL.
+///
+/// Uses the global `default_forge` for hosts detection doesn't recognize,
+/// such as self-hosted GitLab instances. A host the user has logged in to
+/// with `lattice auth --host` is a GitHub Enterprise Server instance.
+pub(crate) fn forge_provider_for(remote_url: &str) -> Option<crate::forge::ForgeProvider> {
+    let config = crate::core::config::Config::load(None).ok();
+    let default_forge = config.as_ref().map(|c| c.config.default_forge());
+    crate::forge::resolve_provider(remote_url, default_forge).or_else(|| {
+        let remote = crate::forge::github::parse_github_remote(remote_url)?;
+        // A stored bundle counts even once expired, so the error asks for a
+        // fresh login instead of claiming the remote is unknown
+        crate::auth::get_user_info(&remote.host).map(|_| crate::forge::ForgeProvider::GitHub)
+    })
+}
+
+/// Apply a doctor repair's forge API steps, in order.
+///
+/// Stops at the first failure, before any local step has run.
+fn apply_forge_steps(git: &Git, steps: &[PlanStep]) -> CliResult {
+    let forge = origin_forge(git)?;
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| CliError::failed(format!("failed to create runtime: {}", e)))?;
+    for step in steps {
+        if let PlanStep::ForgeUpdatePr {
+            number,
+            base,
+            title,
+            body,
+        } = step
+        {
+            rt.block_on(forge.update_pr(crate::forge::UpdatePrRequest {
+                number: *number,
+                title: title.clone(),
+                body: body.clone(),
+                base: base.clone(),
+            }))
+            .map_err(|e| {
+                CliError::new(
+                    ErrorCategory::Forge,
+                    format!("Failed to update PR #{}: {}", number, e),
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Create a forge for the `origin` remote, authenticated for its provider.
+pub(crate) fn origin_forge(git: &Git) -> CliResult<Box<dyn crate::forge::Forge>> {
+    let remote_url = git
+        .remote_url("origin")?
+        .ok_or_else(|| CliError::usage("No 'origin' remote configured."))?;
+    let provider = forge_provider_for(&remote_url).ok_or_else(|| {
+        CliError::usage(format!("Could not detect a forge for remote '{}'. For GitHub Enterprise Server, run 'lattice auth --host <host>'; for self-hosted GitLab, set default_forge in your config.",
+            remote_url))
+    })?;
+    let token = get_forge_token(provider, &remote_url)?;
+    Ok(crate::forge::create_forge(
+        &remote_url,
+        &token,
+        Some(provider.name()),
+    )?)
+}
+
+/// Whether reads from `remote_url` go to GitHub without a token: the remote
+/// is on github.com and the user hasn't logged in there.
+pub(crate) fn reads_anonymously(remote_url: &str) -> bool {
+    crate::forge::github::parse_github_url(remote_url).is_some()
+        && !has_forge_token(crate::forge::ForgeProvider::GitHub, remote_url)
+}
+
+/// Create a forge for reading from the `origin` remote.
+///
+/// Like [`origin_forge`], except that a github.com remote the user hasn't
+/// logged in to is read without a token, which only sees public
+/// repositories (see [`crate::forge::github::GitHubForge::anonymous`]).
+pub(crate) fn origin_forge_for_reading(git: &Git) -> CliResult<Box<dyn crate::forge::Forge>> {
+    if let Some(remote_url) = git.remote_url("origin")? {
+        if reads_anonymously(&remote_url) {
+            if let Some(forge) =
+                crate::forge::github::GitHubForge::from_remote_url_anonymous(&remote_url)
+            {
+                return Ok(Box::new(forge));
+            }
+        }
+    }
+    origin_forge(git)
+}
+
+/// Requests left below which tokenless reads warn about GitHub's budget.
+const ANONYMOUS_QUOTA_WARNING: u64 = 10;
+
+/// Warn on stderr when little is left of GitHub's budget for requests
+/// without a token, pointing to `lattice auth login`.
+pub(crate) fn warn_anonymous_quota(quiet: bool) {
+    if quiet {
+        return;
+    }
+    let now = chrono::Utc::now();
+    for limit in crate::forge::rate_limit::budgets() {
+        if limit.resource == "core" && limit.remaining < ANONYMOUS_QUOTA_WARNING {
+            eprintln!(
+                "{}",
+                i18n::t_args(
+                    "cmd-note-reading-github",
+                    &[("limit", &limit.describe(now))],
+                )
+            );
+        }
+    }
+}
+
 /// Create a forge for deep synthetic analysis.
 ///
 /// Returns None if forge cannot be created (no auth, no remote, etc.)
 fn create_forge_for_deep_analysis(git: &Git) -> Option<Box<dyn crate::forge::Forge>> {
+    github_forge_for_deep_analysis(git).map(|forge| Box::new(forge) as Box<dyn crate::forge::Forge>)
+}
+
+/// Create the GitHub forge for `--deep-remote` analysis, if the remote is
+/// on GitHub and the user is logged in to its host.
+fn github_forge_for_deep_analysis(git: &Git) -> Option<crate::forge::github::GitHubForge> {
     use std::sync::Arc;
 
     use crate::auth::TokenProvider;
@@ -398,22 +836,102 @@ fn create_forge_for_deep_analysis(git: &Git) -> Option<Box<dyn crate::forge::For
     // Get remote URL
     let remote_url = git.remote_url("origin").ok()??;
 
-    // Parse GitHub URL
-    let (owner, repo) = crate::forge::github::parse_github_url(&remote_url)?;
+    // Only GitHub remotes, on github.com or an Enterprise Server host
+    if forge_provider_for(&remote_url)? != crate::forge::ForgeProvider::GitHub {
+        return None;
+    }
 
     // Get token
-    if !has_github_token() {
+    let host = github_host(&remote_url);
+    if !crate::auth::has_github_auth(&host) {
         return None;
     }
 
     // Create auth manager with TokenProvider for automatic refresh
     let store = crate::secrets::create_store(crate::secrets::DEFAULT_PROVIDER).ok()?;
-    let auth_manager = crate::auth::GitHubAuthManager::new("github.com", store);
+    let auth_manager = crate::auth::GitHubAuthManager::new(&host, store);
     let provider: Arc<dyn TokenProvider> = Arc::new(auth_manager);
 
-    Some(Box::new(GitHubForge::new_with_provider(
-        provider, owner, repo,
-    )))
+    GitHubForge::from_enterprise_url_with_provider(&remote_url, provider)
+}
+
+/// Print how much GitHub API quota is left (`doctor --deep-remote`).
+///
+/// Deep analysis spends quota, so the report says what remains. Failures,
+/// including Enterprise Server instances without rate limiting, are
+/// reported in debug mode only.
+fn report_remote_quota(ctx: &Context, git: &Git) {
+    let Some(forge) = github_forge_for_deep_analysis(git) else {
+        return;
+    };
+    let limits = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| crate::forge::ForgeError::NetworkError(e.to_string()))
+        .and_then(|rt| rt.block_on(forge.rate_limits()));
+    match limits {
+        Ok(limits) if !limits.is_empty() => {
+            let now = chrono::Utc::now();
+            println!("{}", i18n::t("cmd-github-api-quota"));
+            for limit in limits {
+                println!("  {}", limit.describe(now));
+            }
+            println!();
+        }
+        Ok(_) => {}
+        Err(e) => {
+            if ctx.debug {
+                eprintln!("{}", i18n::t_args("cmd-note-could-read", &[("error", &e)]));
+            }
+        }
+    }
+}
+
+/// Print what `doctor --fix-all` will apply and which issues it leaves
+/// alone.
+fn report_fix_all(diagnosis: &DiagnosisReport, selection: &FixAllSelection, max: Severity) {
+    let message = |id: &crate::engine::health::IssueId| {
+        diagnosis
+            .issues
+            .iter()
+            .find(|i| &i.id == id)
+            .map(|i| i.message.clone())
+            .unwrap_or_default()
+    };
+
+    if !selection.fixes.is_empty() {
+        println!("{}", i18n::t("cmd-fixes-apply"));
+        for id in &selection.fixes {
+            if let Some(fix) = diagnosis.find_fix(id) {
+                println!("  {} - {}", fix.id, fix.description);
+            }
+        }
+    }
+    if !selection.ambiguous.is_empty() {
+        println!("{}", i18n::t("cmd-skipped-several-fixes"));
+        for id in &selection.ambiguous {
+            println!("  {} ({})", message(id), id);
+            for fix in diagnosis.fixes_for_issue(id) {
+                println!("    {} - {}", fix.id, fix.description);
+            }
+        }
+    }
+    if !selection.unfixable.is_empty() {
+        println!("{}", i18n::t("cmd-skipped-no-fix"));
+        for id in &selection.unfixable {
+            println!("  {} ({})", message(id), id);
+        }
+    }
+    if !selection.above_max.is_empty() {
+        println!(
+            "{}",
+            i18n::t_args(
+                "cmd-left-alone-issue",
+                &[("count", &selection.above_max.len()), ("max", &max)],
+            )
+        );
+    }
+    println!();
 }
 
 /// Doctor command - diagnose and repair repository issues.
@@ -422,13 +940,17 @@ fn create_forge_for_deep_analysis(git: &Git) -> Option<Box<dyn crate::forge::For
 /// explicit confirmation:
 /// - Interactive: user selects from presented options
 /// - Non-interactive: user provides explicit `--fix` IDs
+/// - `--fix-all`: the only fix of each issue up to a severity (`fix_all`),
+///   after a confirmation that `--yes` answers
 fn doctor(
     ctx: &Context,
     fix_ids: &[String],
+    fix_all: Option<Severity>,
     dry_run: bool,
     list: bool,
     deep_remote: bool,
-) -> Result<()> {
+    json: bool,
+) -> CliResult {
     // Initialize git interface
     let cwd = ctx
         .cwd
@@ -436,29 +958,65 @@ fn doctor(
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd)?;
 
-    // Scan the repository (with remote if capabilities allow)
+    // Scan the repository (with remote if capabilities allow and online)
     // Use blocking runtime to call async scan_with_remote
-    let snapshot = {
+    let snapshot = if ctx.offline {
+        crate::engine::scan::scan_for(&git, ctx)?
+    } else {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
-            .map_err(|e| anyhow::anyhow!("failed to create runtime: {}", e))?;
-        rt.block_on(crate::engine::scan::scan_with_remote(&git))?
+            .map_err(|e| CliError::failed(format!("failed to create runtime: {}", e)))?;
+        rt.block_on(crate::engine::scan::scan_with_remote(&git, ctx))?
     };
 
     // Surface divergence info if debug mode (per ARCHITECTURE.md 7.2)
     surface_divergence_if_debug(ctx, &snapshot.health);
 
+    // Diagnosis always runs; fixes and the event ledger need writes
+    let writes_allowed = snapshot
+        .health
+        .capabilities()
+        .has(&Capability::WritesAllowed);
+
     // Create doctor and diagnose
-    let doctor = Doctor::new().interactive(!ctx.quiet && fix_ids.is_empty());
+    let doctor = Doctor::new().interactive(!ctx.quiet && fix_ids.is_empty() && fix_all.is_none());
     let mut diagnosis = doctor.diagnose(&snapshot);
 
-    // Tier 2: Deep synthetic stack analysis (if --deep-remote enabled)
+    // Tier 2: Deep synthetic stack and remote-only chain analysis (if
+    // --deep-remote enabled)
     if deep_remote {
         perform_deep_synthetic_analysis(ctx, &git, &mut diagnosis)?;
+        perform_deep_chain_analysis(ctx, &git, &snapshot, &mut diagnosis);
     }
 
     // If --list, output machine-readable format
+    if list && json {
+        let issues: Vec<_> = diagnosis
+            .issues
+            .iter()
+            .map(|issue| {
+                serde_json::json!({
+                    "id": issue.id.to_string(),
+                    "severity": issue.severity.to_string(),
+                    "message": issue.message,
+                })
+            })
+            .collect();
+        let fixes: Vec<_> = diagnosis
+            .fixes
+            .iter()
+            .map(|fix| {
+                serde_json::json!({
+                    "id": fix.id.to_string(),
+                    "issue_id": fix.issue_id.to_string(),
+                    "description": fix.description,
+                })
+            })
+            .collect();
+        print_json(&serde_json::json!({ "issues": issues, "fixes": fixes }))?;
+        return Ok(());
+    }
     if list {
         println!("# Issues");
         for issue in &diagnosis.issues {
@@ -471,20 +1029,60 @@ fn doctor(
         return Ok(());
     }
 
+    if deep_remote && !ctx.quiet {
+        report_remote_quota(ctx, &git);
+    }
+
     // If no issues, report healthy
     if diagnosis.is_healthy() {
         if !ctx.quiet {
-            println!("Repository is healthy - no issues found.");
+            println!("{}", i18n::t("cmd-repository-healthy"));
         }
         return Ok(());
     }
 
+    // --fix-all picks the fixes that need no choosing
+    let fix_ids = match fix_all {
+        Some(max) => {
+            let selection = diagnosis.unambiguous_fixes(max);
+            if !ctx.quiet {
+                report_fix_all(&diagnosis, &selection, max);
+            }
+            if selection.fixes.is_empty() {
+                if !ctx.quiet {
+                    println!("{}", i18n::t("cmd-no-fixes-apply"));
+                }
+                return Ok(());
+            }
+            let prompt = i18n::t_args(
+                "prompt-doctor-fix-all",
+                &[("count", &selection.fixes.len())],
+            );
+            if !dry_run
+                && !prompts::confirm(&prompt, prompts::Severity::Destructive, ctx.interactive)
+                    .map_err(|e| match e {
+                        prompts::PromptError::NotInteractive => CliError::usage(
+                            "doctor --fix-all needs confirmation; pass --yes to apply the fixes \
+                             without a terminal.",
+                        ),
+                        e => e.into(),
+                    })?
+            {
+                println!("{}", i18n::t("prompt-aborted"));
+                return Ok(());
+            }
+            selection.fixes.iter().map(|f| f.to_string()).collect()
+        }
+        None => fix_ids.to_vec(),
+    };
+
     // If no fixes requested, just show diagnosis
     if fix_ids.is_empty() {
         println!("{}", diagnosis.format());
 
-        // Record DoctorProposed event when fixes are available (per ARCHITECTURE.md 3.4.2)
-        if !diagnosis.fixes.is_empty() {
+        // Record DoctorProposed event when fixes are available (per ARCHITECTURE.md 3.4.2),
+        // unless read-only mode forbids writing the ledger
+        if !diagnosis.fixes.is_empty() && writes_allowed {
             let issue_ids: Vec<String> =
                 diagnosis.issues.iter().map(|i| i.id.to_string()).collect();
             let available_fix_ids: Vec<String> =
@@ -494,7 +1092,10 @@ fn doctor(
             let event = Event::doctor_proposed(issue_ids, available_fix_ids);
             if let Err(e) = ledger.append(event) {
                 if ctx.debug {
-                    eprintln!("Warning: failed to record DoctorProposed event: {}", e);
+                    eprintln!(
+                        "{}",
+                        i18n::t_args("cmd-warning-failed-record", &[("error", &e)])
+                    );
                 }
             }
         }
@@ -502,6 +1103,14 @@ fn doctor(
         return Ok(());
     }
 
+    if !writes_allowed && !dry_run {
+        return Err(CliError::needs_repair(RepairBundle {
+            command: "doctor --fix".to_string(),
+            missing_capabilities: vec![Capability::WritesAllowed],
+            blocking_issues: vec![crate::engine::health::issues::read_only_mode()],
+        }));
+    }
+
     // Parse fix IDs
     let parsed_fix_ids: Vec<FixId> = fix_ids.iter().map(|s| FixId::parse(s)).collect();
 
@@ -516,17 +1125,31 @@ fn doctor(
     let plan = doctor.plan_repairs(&parsed_fix_ids, &diagnosis, &snapshot)?;
 
     if plan.is_empty() {
-        println!("No changes needed.");
+        println!("{}", i18n::t("cmd-no-changes-needed"));
         return Ok(());
     }
 
     // Show plan and confirm
     if !ctx.quiet {
-        println!("Repair plan:");
+        println!("{}", i18n::t("cmd-repair-plan"));
         println!("{}", plan.preview());
         println!();
     }
 
+    // Forge API steps go to the forge first, so metadata only changes once
+    // the PRs have; the executor runs the rest
+    let (forge_steps, local_steps): (Vec<_>, Vec<_>) = plan
+        .steps
+        .iter()
+        .cloned()
+        .partition(|step| matches!(step, PlanStep::ForgeUpdatePr { .. }));
+    let plan = if forge_steps.is_empty() {
+        plan
+    } else {
+        apply_forge_steps(&git, &forge_steps)?;
+        Plan::new(plan.op_id.clone(), plan.command.clone()).with_steps(local_steps)
+    };
+
     // Execute the plan through the standard executor (per ARCHITECTURE.md 8.1)
     // Doctor uses the same executor as other commands - no separate repair path.
     let executor = Executor::new(&git);
@@ -541,11 +1164,38 @@ fn doctor(
             let event = Event::doctor_applied(fix_id_strings.clone(), fingerprint.to_string());
             if let Err(e) = ledger.append(event) {
                 // Event recording failure is non-fatal but should be reported
-                eprintln!("Warning: failed to record DoctorApplied event: {}", e);
+                eprintln!(
+                    "{}",
+                    i18n::t_args("cmd-warning-failed-record-doctorapplied", &[("error", &e)])
+                );
             }
 
             if !ctx.quiet {
-                println!("Successfully applied {} fix(es).", parsed_fix_ids.len());
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "cmd-successfully-applied-fix",
+                        &[("count", &parsed_fix_ids.len())],
+                    )
+                );
+
+                // Show the stacks imported from remote-only PR chains
+                for issue in diagnosis.issues.iter().filter(|issue| {
+                    parsed_fix_ids.iter().any(|id| {
+                        diagnosis
+                            .find_fix(id)
+                            .is_some_and(|fix| fix.issue_id == issue.id)
+                    })
+                }) {
+                    for evidence in &issue.evidence {
+                        if let crate::engine::health::Evidence::RemotePrChain { root_base, prs } =
+                            evidence
+                        {
+                            println!("{}", i18n::t("cmd-imported-stack"));
+                            println!("{}", crate::doctor::render_pr_chain_tree(root_base, prs));
+                        }
+                    }
+                }
             }
 
             // Post-verify: re-run diagnosis to confirm issues are resolved
@@ -567,11 +1217,11 @@ fn doctor(
                     .collect();
 
                 if remaining.is_empty() {
-                    println!("All targeted issues resolved.");
+                    println!("{}", i18n::t("cmd-all-targeted-issues"));
                 } else {
                     println!(
-                        "Warning: {} issue(s) may not be fully resolved. Run 'lattice doctor' to check.",
-                        remaining.len()
+                        "{}",
+                        i18n::t_args("cmd-warning-issue-may", &[("count", &remaining.len())])
                     );
                 }
             }
@@ -582,25 +1232,31 @@ fn doctor(
             // Conflict during repair - transition to awaiting_user op-state
             // The executor already handles op-state transition
             println!(
-                "Repair paused: conflict on branch '{}' ({:?}).",
-                branch, git_state
+                "{}",
+                i18n::t_args(
+                    "cmd-repair-paused-conflict",
+                    &[("branch", &branch), ("git_state", &git_state)],
+                )
             );
-            println!("Resolve conflicts and run 'lattice continue', or 'lattice abort' to cancel.");
+            conflict_summary::print(&git);
         }
         ExecuteResult::Aborted {
             error,
             applied_steps,
         } => {
             // Repair failed - some steps may have been applied
-            eprintln!("Repair aborted: {}", error);
+            eprintln!(
+                "{}",
+                i18n::t_args("cmd-repair-aborted", &[("error", &error)])
+            );
             if !applied_steps.is_empty() {
                 eprintln!(
-                    "Warning: {} step(s) were applied before failure.",
-                    applied_steps.len()
+                    "{}",
+                    i18n::t_args("cmd-warning-step-were", &[("count", &applied_steps.len())])
                 );
-                eprintln!("Run 'lattice doctor' to check repository state.");
+                eprintln!("{}", i18n::t("cmd-run-lattice-doctor"));
             }
-            return Err(anyhow::anyhow!("Repair failed: {}", error));
+            return Err(CliError::failed(format!("Repair failed: {}", error)));
         }
     }
 
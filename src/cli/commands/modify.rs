@@ -7,6 +7,9 @@
 //! - If branch is empty (no commits unique beyond base), creates first commit
 //! - After mutation, automatically restack descendants unless prevented by freeze
 //! - Conflicts during descendant restack pause the operation
+//! - Descendants to be restacked are previewed first, with a `merge-tree`
+//!   conflict estimate; `--no-restack` (or `restack.descendants = false`)
+//!   defers the restack entirely
 //!
 //! # Integrity Contract
 //!
@@ -20,10 +23,15 @@
 
 use std::process::Command as ProcessCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
-use crate::cli::commands::phase3_helpers::count_commits_in_range;
-use crate::cli::commands::restack::{get_descendants_inclusive, get_parent_tip, topological_sort};
+use crate::cli::commands::phase3_helpers::{
+    count_commits_in_range, estimate_restack_conflicts, format_restack_preview,
+    preview_commit_from_index, RestackEstimate,
+};
+use crate::cli::commands::restack::{get_descendants_inclusive, topological_sort};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::{BranchName, Oid, UtcTimestamp};
@@ -35,6 +43,7 @@ use crate::engine::runner::run_command;
 use crate::engine::scan::scan;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Result of modify command
 #[derive(Debug)]
@@ -74,7 +83,7 @@ pub struct DescendantRestackInfo {
     /// Current metadata ref OID for CAS
     pub metadata_ref_oid: Oid,
     /// Current metadata for cloning/updating
-    pub metadata: crate::core::metadata::schema::BranchMetadataV1,
+    pub metadata: crate::core::metadata::schema::BranchMetadataV2,
 }
 
 /// Modify command implementing Command trait
@@ -218,9 +227,7 @@ impl Command for ModifyCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Conflict while restacking '{}' ({}).\n\
-                     Resolve conflicts, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Conflict while restacking '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -241,6 +248,8 @@ impl Command for ModifyCommand {
 /// * `patch` - Interactive patch staging (git add -p)
 /// * `message` - Commit message
 /// * `edit` - Open editor for commit message
+/// * `no_restack` - Leave descendants for a later `lattice restack`
+#[allow(clippy::too_many_arguments)]
 pub fn modify(
     ctx: &Context,
     create: bool,
@@ -249,7 +258,8 @@ pub fn modify(
     patch: bool,
     message: Option<&str>,
     edit: bool,
-) -> Result<()> {
+    no_restack: bool,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -268,7 +278,7 @@ pub fn modify(
             .context("Failed to run git add -A")?;
 
         if !status.success() {
-            anyhow::bail!("git add -A failed");
+            return Err(CliError::git("git add -A failed"));
         }
     } else if update {
         let status = ProcessCommand::new("git")
@@ -278,7 +288,7 @@ pub fn modify(
             .context("Failed to run git add -u")?;
 
         if !status.success() {
-            anyhow::bail!("git add -u failed");
+            return Err(CliError::git("git add -u failed"));
         }
     } else if patch {
         let status = ProcessCommand::new("git")
@@ -288,7 +298,7 @@ pub fn modify(
             .context("Failed to run git add -p")?;
 
         if !status.success() {
-            anyhow::bail!("git add -p failed");
+            return Err(CliError::git("git add -p failed"));
         }
     }
 
@@ -298,42 +308,37 @@ pub fn modify(
 
     let snapshot = scan(&git).context("Failed to scan repository")?;
 
-    let trunk = snapshot
-        .trunk
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?
-        .clone();
+    if snapshot.trunk.is_none() {
+        return Err(CliError::not_initialized());
+    }
 
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
     if !snapshot.metadata.contains_key(&current) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            current
-        );
+        return Err(CliError::untracked(&current));
     }
 
     let scanned = snapshot
         .metadata
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(&current))?;
 
     // Check freeze on current branch
     if scanned.metadata.freeze.is_frozen() {
-        anyhow::bail!(
+        return Err(CliError::conflict(format!(
             "Cannot modify frozen branch '{}'. Use 'lattice unfreeze' first.",
             current
-        );
+        )));
     }
 
     let current_tip = snapshot
         .branches
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", current))?
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", current)))?
         .clone();
 
     let base_oid = &scanned.metadata.base.oid;
@@ -351,7 +356,9 @@ pub fn modify(
 
     // Validate staging requirements
     if (is_empty_branch || create) && !has_staged {
-        anyhow::bail!("No staged changes to commit. Use -a to stage all changes.");
+        return Err(CliError::usage(
+            "No staged changes to commit. Use -a to stage all changes.",
+        ));
     }
 
     // Get descendants and check freeze policy
@@ -373,7 +380,7 @@ pub fn modify(
         let branch_meta = snapshot
             .metadata
             .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", branch))?;
+            .ok_or_else(|| CliError::untracked(branch))?;
 
         if branch_meta.metadata.freeze.is_frozen() {
             frozen_to_skip.push(branch.clone());
@@ -386,13 +393,8 @@ pub fn modify(
             ParentInfo::Branch { name } => BranchName::new(name)?,
         };
 
-        // Get parent's current tip
-        let parent_tip = get_parent_tip(branch, &snapshot, &trunk)?;
-
-        // Check if already aligned
-        if branch_meta.metadata.base.oid.as_str() == parent_tip.to_string().as_str() {
-            continue;
-        }
+        // No alignment check: the modified branch gets a new tip, so every
+        // descendant's base goes stale even if it is aligned right now
 
         descendants_to_restack.push(DescendantRestackInfo {
             branch: branch.clone(),
@@ -403,6 +405,35 @@ pub fn modify(
         });
     }
 
+    let restack = !no_restack
+        && Config::load(Some(&cwd))
+            .map(|r| r.config.restack_descendants())
+            .unwrap_or(true);
+
+    let deferred: Vec<BranchName> = if restack {
+        Vec::new()
+    } else {
+        descendants_to_restack.drain(..).map(|d| d.branch).collect()
+    };
+
+    if !ctx.quiet && !descendants_to_restack.is_empty() {
+        let branches: Vec<BranchName> = descendants_to_restack
+            .iter()
+            .map(|d| d.branch.clone())
+            .collect();
+        let estimates: Vec<RestackEstimate> = match preview_commit_from_index(&cwd, &current_tip) {
+            Ok(preview_tip) => descendants_to_restack
+                .iter()
+                .map(|d| match snapshot.branches.get(&d.branch) {
+                    Some(tip) => estimate_restack_conflicts(&cwd, &preview_tip, tip),
+                    None => RestackEstimate::Unknown,
+                })
+                .collect(),
+            Err(_) => vec![RestackEstimate::Unknown; branches.len()],
+        };
+        print!("{}", format_restack_preview(&branches, Some(&estimates)));
+    }
+
     let precomputed = ModifyPrecomputed {
         branch: current.clone(),
         is_empty_branch,
@@ -433,46 +464,77 @@ pub fn modify(
         CommandOutput::Success(result) => {
             if !ctx.quiet {
                 if result.was_create {
-                    println!("Created commit on '{}'", result.branch);
+                    println!(
+                        "{}",
+                        i18n::t_args("modify-created-commit", &[("branch", &result.branch)])
+                    );
                 } else {
-                    println!("Amended commit on '{}'", result.branch);
+                    println!(
+                        "{}",
+                        i18n::t_args("modify-amended-commit", &[("branch", &result.branch)])
+                    );
                 }
 
                 if !result.restacked.is_empty() {
+                    let branches = result
+                        .restacked
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Restacked {} descendant(s): {}",
-                        result.restacked.len(),
-                        result
-                            .restacked
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "modify-restacked-descendant",
+                            &[("count", &result.restacked.len()), ("branches", &branches)],
+                        )
                     );
                 }
 
                 if !result.skipped_frozen.is_empty() {
+                    let branches = result
+                        .skipped_frozen
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "modify-skipped-frozen-branch",
+                            &[
+                                ("count", &result.skipped_frozen.len()),
+                                ("branches", &branches),
+                            ],
+                        )
+                    );
+                }
+
+                if !deferred.is_empty() {
+                    let branches = deferred
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Skipped {} frozen branch(es): {}",
-                        result.skipped_frozen.len(),
-                        result
-                            .skipped_frozen
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "modify-deferred-restack-descendant",
+                            &[("count", &deferred.len()), ("branches", &branches)],
+                        )
                     );
                 }
 
-                println!("Modify complete.");
+                println!("{}", i18n::t("modify-complete"));
             }
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Modify failed: {}", error);
+            return Err(CliError::failed(format!("Modify failed: {}", error)));
         }
     }
 
@@ -13,10 +13,11 @@
 //! - Must never rewrite frozen branches
 //! - Metadata updated only after refs succeed
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
 use crate::cli::commands::phase3_helpers::is_descendant_of;
 use crate::cli::commands::restack::{get_descendants_inclusive, get_parent_tip, topological_sort};
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::{BranchName, Oid, UtcTimestamp};
@@ -28,6 +29,7 @@ use crate::engine::runner::run_command;
 use crate::engine::scan::scan;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Result of move command
 #[derive(Debug)]
@@ -57,7 +59,7 @@ pub struct MovePrecomputed {
     /// Source metadata ref OID for CAS
     pub source_metadata_ref_oid: Oid,
     /// Source's current metadata
-    pub source_metadata: crate::core::metadata::schema::BranchMetadataV1,
+    pub source_metadata: crate::core::metadata::schema::BranchMetadataV2,
     /// Descendants that need restacking
     pub descendants_to_restack: Vec<DescendantRestackInfo>,
     /// Frozen branches that will be skipped
@@ -75,7 +77,7 @@ pub struct DescendantRestackInfo {
     /// Current metadata ref OID for CAS
     pub metadata_ref_oid: Oid,
     /// Current metadata
-    pub metadata: crate::core::metadata::schema::BranchMetadataV1,
+    pub metadata: crate::core::metadata::schema::BranchMetadataV2,
 }
 
 /// Move command implementing Command trait
@@ -212,9 +214,7 @@ impl Command for MoveCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Conflict while moving/restacking '{}' ({}).\n\
-                     Resolve conflicts, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Conflict while moving/restacking '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -231,7 +231,7 @@ impl Command for MoveCommand {
 /// * `ctx` - Execution context
 /// * `onto` - Target parent branch
 /// * `source` - Branch to move (defaults to current)
-pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()> {
+pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -247,7 +247,7 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
     let trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?
+        .ok_or_else(CliError::not_initialized)?
         .clone();
 
     // Resolve source branch
@@ -256,55 +256,54 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
     } else if let Some(ref current) = snapshot.current_branch {
         current.clone()
     } else {
-        anyhow::bail!("Not on any branch and no source specified");
+        return Err(CliError::usage("Not on any branch and no source specified"));
     };
 
     if !snapshot.metadata.contains_key(&source_branch) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            source_branch
-        );
+        return Err(CliError::untracked(&source_branch));
     }
 
     // Resolve onto branch
     let onto_branch = BranchName::new(onto).context("Invalid onto branch name")?;
 
     if !snapshot.branches.contains_key(&onto_branch) {
-        anyhow::bail!("Target branch '{}' does not exist", onto_branch);
+        return Err(CliError::usage(format!(
+            "Target branch '{}' does not exist",
+            onto_branch
+        )));
     }
 
     // Prevent self-move
     if source_branch == onto_branch {
-        anyhow::bail!("Cannot move a branch onto itself");
+        return Err(CliError::usage("Cannot move a branch onto itself"));
     }
 
     // Cycle detection
     if is_descendant_of(&onto_branch, &source_branch, &snapshot) {
-        anyhow::bail!(
+        return Err(CliError::usage(format!(
             "Cannot move '{}' onto '{}': would create a cycle (target is a descendant)",
-            source_branch,
-            onto_branch
-        );
+            source_branch, onto_branch
+        )));
     }
 
     // Get source metadata
     let source_meta = snapshot
         .metadata
         .get(&source_branch)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", source_branch))?;
+        .ok_or_else(|| CliError::untracked(&source_branch))?;
 
     // Check freeze on source
     if source_meta.metadata.freeze.is_frozen() {
-        anyhow::bail!(
+        return Err(CliError::conflict(format!(
             "Cannot move frozen branch '{}'. Use 'lattice unfreeze' first.",
             source_branch
-        );
+        )));
     }
 
     let onto_tip = snapshot
         .branches
         .get(&onto_branch)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", onto_branch))?;
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", onto_branch)))?;
 
     // Check if already a child and aligned
     let current_parent_name = source_meta.metadata.parent.name();
@@ -313,8 +312,14 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
     {
         if !ctx.quiet {
             println!(
-                "'{}' is already a child of '{}' and aligned.",
-                source_branch, onto_branch
+                "{}",
+                i18n::t_args(
+                    "move-already-child-aligned",
+                    &[
+                        ("source_branch", &source_branch),
+                        ("onto_branch", &onto_branch),
+                    ],
+                )
             );
         }
         return Ok(());
@@ -322,8 +327,15 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
 
     if !ctx.quiet {
         println!(
-            "Moving '{}' onto '{}' (was child of '{}')...",
-            source_branch, onto_branch, current_parent_name
+            "{}",
+            i18n::t_args(
+                "move-moving-onto-child",
+                &[
+                    ("source_branch", &source_branch),
+                    ("onto_branch", &onto_branch),
+                    ("current_parent_name", &current_parent_name),
+                ],
+            )
         );
     }
 
@@ -345,7 +357,7 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
         let branch_meta = snapshot
             .metadata
             .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", branch))?;
+            .ok_or_else(|| CliError::untracked(branch))?;
 
         if branch_meta.metadata.freeze.is_frozen() {
             frozen_to_skip.push(branch.clone());
@@ -405,43 +417,59 @@ pub fn move_branch(ctx: &Context, onto: &str, source: Option<&str>) -> Result<()
     match output {
         CommandOutput::Success(result) => {
             if !ctx.quiet {
-                println!("Moved '{}' onto '{}'", result.source, result.onto);
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "move-moved-onto",
+                        &[("source", &result.source), ("onto", &result.onto)],
+                    )
+                );
 
                 if !result.restacked.is_empty() {
+                    let branches = result
+                        .restacked
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Restacked {} descendant(s): {}",
-                        result.restacked.len(),
-                        result
-                            .restacked
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "move-restacked-descendant",
+                            &[("count", &result.restacked.len()), ("branches", &branches)],
+                        )
                     );
                 }
 
                 if !result.skipped_frozen.is_empty() {
+                    let branches = result
+                        .skipped_frozen
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Skipped {} frozen branch(es): {}",
-                        result.skipped_frozen.len(),
-                        result
-                            .skipped_frozen
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "move-skipped-frozen-branch",
+                            &[
+                                ("count", &result.skipped_frozen.len()),
+                                ("branches", &branches),
+                            ],
+                        )
                     );
                 }
 
-                println!("Move complete.");
+                println!("{}", i18n::t("move-complete"));
             }
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Move failed: {}", error);
+            return Err(CliError::failed(format!("Move failed: {}", error)));
         }
     }
 
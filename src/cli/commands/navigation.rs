@@ -4,15 +4,144 @@
 //!
 //! Uses `requirements::NAVIGATION` - these commands read stack structure
 //! and checkout branches, requiring a working directory.
+//!
+//! # Branching stacks
+//!
+//! When `up`/`top` reach a branch with several children, the child is chosen
+//! in this order:
+//! 1. `--child <name>`: the child that is, or leads to, the named branch
+//! 2. The child last navigated through from that branch (remembered in
+//!    `<common_dir>/lattice/nav.json`), so repeated navigation follows the
+//!    user's working line
+//! 3. An interactive picker, or an error listing the children when
+//!    non-interactive
+//!
+//! Every parent/child edge crossed by `up`, `down`, `top`, or `bottom` is
+//! recorded. Remembered choices are hints only; stale entries are ignored.
+//!
+//! After switching to a branch with a linked PR, navigation starts a
+//! background fetch of its PR status (see `prefetch`).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
 
+use crate::cli::error::{CliError, CliResult};
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
-use std::io::{self, Write};
-use std::process::Command;
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// Remembered traversal choices, keyed by parent branch.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct NavMemory {
+    /// Parent branch -> child last navigated through.
+    last_child: BTreeMap<String, String>,
+}
+
+impl NavMemory {
+    fn path(paths: &LatticePaths) -> std::path::PathBuf {
+        paths.repo_nav_path()
+    }
+
+    /// Load remembered choices; a missing or unreadable file is empty.
+    fn load(paths: &LatticePaths) -> Self {
+        fs::read_to_string(Self::path(paths))
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist remembered choices. Failures are ignored: this is a hint cache.
+    fn save(&self, paths: &LatticePaths) {
+        if let Ok(content) = serde_json::to_string_pretty(self) {
+            let _ = fs::create_dir_all(paths.repo_lattice_dir());
+            let _ = fs::write(Self::path(paths), content);
+        }
+    }
+
+    /// Record a walk from the lowest branch to the highest, oldest first.
+    fn remember_path(&mut self, path: &[BranchName]) {
+        for pair in path.windows(2) {
+            self.last_child
+                .insert(pair[0].to_string(), pair[1].to_string());
+        }
+    }
+
+    /// The remembered child of `parent`, if it is still one of `children`.
+    fn preferred(&self, parent: &BranchName, children: &[BranchName]) -> Option<BranchName> {
+        let remembered = self.last_child.get(parent.as_str())?;
+        children.iter().find(|c| c.as_str() == remembered).cloned()
+    }
+}
+
+fn internal(msg: String) -> RunError {
+    RunError::Scan(crate::engine::scan::ScanError::Internal(msg))
+}
+
+/// Children of a branch, sorted for stable display.
+fn sorted_children(snapshot: &RepoSnapshot, branch: &BranchName) -> Vec<BranchName> {
+    let mut kids: Vec<BranchName> = snapshot
+        .graph
+        .children(branch)
+        .map(|k| k.iter().cloned().collect())
+        .unwrap_or_default();
+    kids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    kids
+}
+
+/// Pick which child of `parent` to move to.
+///
+/// `want` is the `--child` target: the child that is, or is an ancestor of,
+/// that branch wins. Otherwise a single child, then the remembered choice,
+/// then the interactive picker are tried.
+fn choose_child(
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    memory: &NavMemory,
+    parent: &BranchName,
+    kids: &[BranchName],
+    want: Option<&BranchName>,
+) -> Result<BranchName, RunError> {
+    if let Some(want) = want {
+        return kids
+            .iter()
+            .find(|k| *k == want || snapshot.graph.descendants(k).contains(want))
+            .cloned()
+            .ok_or_else(|| internal(format!("'{}' is not above '{}'.", want, parent)));
+    }
+
+    if kids.len() == 1 {
+        return Ok(kids[0].clone());
+    }
+
+    if let Some(child) = memory.preferred(parent, kids) {
+        return Ok(child);
+    }
+
+    if ctx.interactive {
+        return select_child(ctx, kids)
+            .map_err(|e| internal(format!("Failed to select child: {}", e)));
+    }
+
+    Err(internal(format!(
+        "Multiple children from '{}': {}. Use --child <name> or run interactively to select.",
+        parent,
+        kids.iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )))
+}
 
 /// Move up to a child branch.
 ///
@@ -20,16 +149,22 @@ use std::process::Command;
 ///
 /// * `ctx` - Execution context
 /// * `steps` - Number of steps to move
+/// * `child` - Branch to head towards when the stack forks
 ///
 /// # Gating
 ///
 /// Uses `requirements::NAVIGATION`.
-pub fn up(ctx: &Context, steps: u32) -> Result<()> {
+pub fn up(ctx: &Context, steps: u32, child: Option<&str>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    let want = child
+        .map(BranchName::new)
+        .transpose()
+        .context("Invalid branch name")?;
 
     run_gated(&git, ctx, &requirements::NAVIGATION, |ready| {
         let snapshot = &ready.snapshot;
@@ -40,71 +175,46 @@ pub fn up(ctx: &Context, steps: u32) -> Result<()> {
             ))
         })?;
 
+        let mut memory = NavMemory::load(&paths);
+        let mut walked = vec![current.clone()];
         let mut target = current.clone();
 
         for _ in 0..steps {
-            let children = snapshot.graph.children(&target);
-
-            match children {
-                None => {
-                    if !ctx.quiet {
-                        println!("Already at top of stack ({})", target);
-                    }
-                    return Ok(());
-                }
-                Some(kids) if kids.is_empty() => {
-                    if !ctx.quiet {
-                        println!("Already at top of stack ({})", target);
-                    }
-                    return Ok(());
-                }
-                Some(kids) if kids.len() == 1 => {
-                    target = kids.iter().next().unwrap().clone();
-                }
-                Some(kids) => {
-                    // Multiple children - need to select
-                    if ctx.interactive {
-                        target = select_child(ctx, &kids.iter().cloned().collect::<Vec<_>>())
-                            .map_err(|e| {
-                                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                                    "Failed to select child: {}",
-                                    e
-                                )))
-                            })?;
-                    } else {
-                        return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                            format!(
-                                "Multiple children from '{}': {}. Run interactively to select.",
-                                target,
-                                kids.iter()
-                                    .map(|b| b.to_string())
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            ),
-                        )));
-                    }
+            let kids = sorted_children(snapshot, &target);
+            if kids.is_empty() {
+                if !ctx.quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args("navigation-already-top-stack", &[("target", &target)])
+                    );
                 }
+                break;
             }
+
+            // Once the --child target is reached it no longer constrains the walk
+            let want = want.as_ref().filter(|w| *w != &target);
+            target = choose_child(ctx, snapshot, &memory, &target, &kids, want)?;
+            walked.push(target.clone());
         }
 
         if &target == current {
             return Ok(());
         }
 
+        memory.remember_path(&walked);
+        memory.save(&paths);
+
         // Checkout target
         checkout_branch(&cwd, &target).map_err(|e| {
             RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                 "Failed to checkout: {}",
                 e
             )))
-        })
-    })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
+        })?;
+        super::prefetch::spawn_after_navigation(&cwd, &paths, snapshot, &target);
+        Ok(())
     })
+    .map_err(CliError::from)
 }
 
 /// Move down to the parent branch.
@@ -117,12 +227,13 @@ pub fn up(ctx: &Context, steps: u32) -> Result<()> {
 /// # Gating
 ///
 /// Uses `requirements::NAVIGATION`.
-pub fn down(ctx: &Context, steps: u32) -> Result<()> {
+pub fn down(ctx: &Context, steps: u32) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
 
     run_gated(&git, ctx, &requirements::NAVIGATION, |ready| {
         let snapshot = &ready.snapshot;
@@ -133,12 +244,14 @@ pub fn down(ctx: &Context, steps: u32) -> Result<()> {
             ))
         })?;
 
+        let mut walked = vec![current.clone()];
         let mut target = current.clone();
 
         for _ in 0..steps {
             match snapshot.graph.parent(&target) {
                 Some(parent) => {
                     target = parent.clone();
+                    walked.push(target.clone());
                 }
                 None => {
                     // Check if we're at a trunk-child or untracked
@@ -146,11 +259,15 @@ pub fn down(ctx: &Context, steps: u32) -> Result<()> {
                         // We're tracked, so parent is trunk
                         if let Some(trunk) = &snapshot.trunk {
                             target = trunk.clone();
+                            walked.push(target.clone());
                             break; // Can't go below trunk
                         }
                     }
                     if !ctx.quiet {
-                        println!("Already at bottom of stack ({})", target);
+                        println!(
+                            "{}",
+                            i18n::t_args("navigation-already-bottom-stack", &[("target", &target)])
+                        );
                     }
                     return Ok(());
                 }
@@ -161,33 +278,46 @@ pub fn down(ctx: &Context, steps: u32) -> Result<()> {
             return Ok(());
         }
 
+        // Remember where we came from so `up` returns along the same line
+        walked.reverse();
+        let mut memory = NavMemory::load(&paths);
+        memory.remember_path(&walked);
+        memory.save(&paths);
+
         // Checkout target
         checkout_branch(&cwd, &target).map_err(|e| {
             RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                 "Failed to checkout: {}",
                 e
             )))
-        })
-    })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
+        })?;
+        super::prefetch::spawn_after_navigation(&cwd, &paths, snapshot, &target);
+        Ok(())
     })
+    .map_err(CliError::from)
 }
 
 /// Move to the top of the current stack (leaf).
 ///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `child` - Branch to head towards when the stack forks
+///
 /// # Gating
 ///
 /// Uses `requirements::NAVIGATION`.
-pub fn top(ctx: &Context) -> Result<()> {
+pub fn top(ctx: &Context, child: Option<&str>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    let want = child
+        .map(BranchName::new)
+        .transpose()
+        .context("Invalid branch name")?;
 
     run_gated(&git, ctx, &requirements::NAVIGATION, |ready| {
         let snapshot = &ready.snapshot;
@@ -198,63 +328,45 @@ pub fn top(ctx: &Context) -> Result<()> {
             ))
         })?;
 
+        let mut memory = NavMemory::load(&paths);
+        let mut walked = vec![current.clone()];
         let mut target = current.clone();
 
         loop {
-            let children = snapshot.graph.children(&target);
-
-            match children {
-                None => {
-                    break;
-                }
-                Some(kids) if kids.is_empty() => {
-                    break;
-                }
-                Some(kids) if kids.len() == 1 => {
-                    target = kids.iter().next().unwrap().clone();
-                }
-                Some(kids) => {
-                    // Multiple children - need to select
-                    if ctx.interactive {
-                        target = select_child(ctx, &kids.iter().cloned().collect::<Vec<_>>())
-                            .map_err(|e| {
-                                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                                    "Failed to select child: {}",
-                                    e
-                                )))
-                            })?;
-                    } else {
-                        return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                            format!(
-                                "Multiple paths to top from '{}'. Run interactively to select.",
-                                target
-                            ),
-                        )));
-                    }
-                }
+            let kids = sorted_children(snapshot, &target);
+            if kids.is_empty() {
+                break;
             }
+
+            // Once the --child target is reached it no longer constrains the walk
+            let want = want.as_ref().filter(|w| *w != &target);
+            target = choose_child(ctx, snapshot, &memory, &target, &kids, want)?;
+            walked.push(target.clone());
         }
 
         if &target == current {
             if !ctx.quiet {
-                println!("Already at top of stack ({})", target);
+                println!(
+                    "{}",
+                    i18n::t_args("navigation-already-top-stack", &[("target", &target)])
+                );
             }
             return Ok(());
         }
 
+        memory.remember_path(&walked);
+        memory.save(&paths);
+
         checkout_branch(&cwd, &target).map_err(|e| {
             RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                 "Failed to checkout: {}",
                 e
             )))
-        })
-    })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
+        })?;
+        super::prefetch::spawn_after_navigation(&cwd, &paths, snapshot, &target);
+        Ok(())
     })
+    .map_err(CliError::from)
 }
 
 /// Move to the bottom of the current stack (trunk-child).
@@ -262,12 +374,13 @@ pub fn top(ctx: &Context) -> Result<()> {
 /// # Gating
 ///
 /// Uses `requirements::NAVIGATION`.
-pub fn bottom(ctx: &Context) -> Result<()> {
+pub fn bottom(ctx: &Context) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
 
     run_gated(&git, ctx, &requirements::NAVIGATION, |ready| {
         let snapshot = &ready.snapshot;
@@ -280,18 +393,20 @@ pub fn bottom(ctx: &Context) -> Result<()> {
 
         // If not tracked, can't navigate
         if !snapshot.metadata.contains_key(current) {
-            return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                format!("Current branch '{}' is not tracked", current),
+            return Err(RunError::Plan(PlanError::UntrackedBranch(
+                current.to_string(),
             )));
         }
 
         let mut target = current.clone();
         let mut prev = target.clone();
+        let mut walked = vec![current.clone()];
 
         // Walk down until we hit trunk or untracked
         while let Some(parent) = snapshot.graph.parent(&target) {
             prev = target.clone();
             target = parent.clone();
+            walked.push(target.clone());
         }
 
         // target is now trunk (or the root of tracking), we want prev (the trunk-child)
@@ -306,33 +421,39 @@ pub fn bottom(ctx: &Context) -> Result<()> {
 
         if &final_target == current {
             if !ctx.quiet {
-                println!("Already at bottom of stack ({})", current);
+                println!(
+                    "{}",
+                    i18n::t_args("navigation-already-bottom-stack", &[("target", &current)])
+                );
             }
             return Ok(());
         }
 
+        // Remember where we came from so `top` returns along the same line
+        walked.reverse();
+        let mut memory = NavMemory::load(&paths);
+        memory.remember_path(&walked);
+        memory.save(&paths);
+
         checkout_branch(&cwd, &final_target).map_err(|e| {
             RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                 "Failed to checkout: {}",
                 e
             )))
-        })
-    })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
+        })?;
+        super::prefetch::spawn_after_navigation(&cwd, &paths, snapshot, &final_target);
+        Ok(())
     })
+    .map_err(CliError::from)
 }
 
 /// Interactively select a child branch.
-pub fn select_child(_ctx: &Context, children: &[BranchName]) -> Result<BranchName> {
-    println!("Multiple children, select one:");
+pub fn select_child(_ctx: &Context, children: &[BranchName]) -> CliResult<BranchName> {
+    println!("{}", i18n::t("navigation-multiple-children-select"));
     for (i, child) in children.iter().enumerate() {
         println!("  {}. {}", i + 1, child);
     }
-    print!("Enter number: ");
+    print!("{} ", i18n::t("navigation-enter-number"));
     io::stdout().flush()?;
 
     let mut input = String::new();
@@ -344,14 +465,14 @@ pub fn select_child(_ctx: &Context, children: &[BranchName]) -> Result<BranchNam
         .saturating_sub(1);
 
     if idx >= children.len() {
-        bail!("Invalid selection");
+        return Err(CliError::usage("Invalid selection"));
     }
 
     Ok(children[idx].clone())
 }
 
 /// Checkout a branch using git.
-pub fn checkout_branch(cwd: &std::path::Path, branch: &BranchName) -> Result<()> {
+pub fn checkout_branch(cwd: &std::path::Path, branch: &BranchName) -> CliResult {
     let status = Command::new("git")
         .args(["checkout", branch.as_str()])
         .current_dir(cwd)
@@ -359,8 +480,56 @@ pub fn checkout_branch(cwd: &std::path::Path, branch: &BranchName) -> Result<()>
         .context("Failed to run git checkout")?;
 
     if !status.success() {
-        bail!("git checkout failed");
+        return Err(CliError::git("git checkout failed"));
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn names(names: &[&str]) -> Vec<BranchName> {
+        names.iter().map(|n| BranchName::new(*n).unwrap()).collect()
+    }
+
+    #[test]
+    fn remember_path_records_each_edge() {
+        let mut memory = NavMemory::default();
+        memory.remember_path(&names(&["main", "a", "b"]));
+
+        let kids = names(&["b", "c"]);
+        assert_eq!(
+            memory.preferred(&BranchName::new("a").unwrap(), &kids),
+            Some(BranchName::new("b").unwrap())
+        );
+        assert_eq!(memory.last_child.get("main").map(String::as_str), Some("a"));
+    }
+
+    #[test]
+    fn stale_choice_is_ignored() {
+        let mut memory = NavMemory::default();
+        memory.remember_path(&names(&["a", "gone"]));
+
+        let kids = names(&["b", "c"]);
+        assert_eq!(
+            memory.preferred(&BranchName::new("a").unwrap(), &kids),
+            None
+        );
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let git_dir = temp.path().join(".git");
+        let paths = LatticePaths::new(git_dir.clone(), git_dir);
+
+        let mut memory = NavMemory::default();
+        memory.remember_path(&names(&["a", "b"]));
+        memory.save(&paths);
+
+        assert_eq!(NavMemory::load(&paths), memory);
+    }
+}
@@ -0,0 +1,307 @@
+//! cli::commands::ops_log
+//!
+//! List what the event ledger recorded (`lattice log --ops`).
+//!
+//! # Design
+//!
+//! The ledger ([`crate::engine::ledger`]) is the audit trail of what Lattice
+//! did to a repository: operation intents, commits, and aborts, undo and
+//! redo, doctor proposals and repairs, divergence it observed, and branch
+//! lifecycle stages. This command reads it newest first (`--reverse` for
+//! oldest first) and shows, for each event, its time, type, command,
+//! operation ID, fingerprint, and the branches it affected.
+//!
+//! Ledger events don't name the branches an operation touched, so they come
+//! from the operation's journal (its ref updates and metadata writes) while
+//! the journal is still on disk. Divergence events name their changed refs;
+//! lifecycle events their branch. `command_timed` events measure commands
+//! rather than change the repository, so they are left out.
+//!
+//! With `--json` the listing is one document with every event in full plus
+//! the derived fields, so teams can archive or audit it. Nothing is written
+//! and the repository needn't be initialized.
+//!
+//! # Example
+//!
+//! ```bash
+//! lattice log --ops
+//! lattice log --ops --json > lattice-audit.json
+//! ```
+
+use std::collections::HashMap;
+
+use anyhow::Context as _;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::cli::error::CliResult;
+use crate::core::ops::journal::{Journal, OpId, StepKind};
+use crate::core::paths::LatticePaths;
+use crate::engine::ledger::{Event, EventLedger};
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+
+/// Characters of operation IDs shown in the listing, as `lattice undo`
+/// accepts any unique prefix.
+const SHORT_OP_ID: usize = 8;
+
+/// Characters of fingerprints shown in the listing.
+const SHORT_FINGERPRINT: usize = 12;
+
+/// One ledger event with the fields an audit looks for.
+#[derive(Debug, Serialize)]
+struct OpsEntry {
+    /// Ledger commit holding the event
+    commit: String,
+    timestamp: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    op_id: Option<String>,
+    /// Command that started the operation, when known
+    command: Option<String>,
+    /// Fingerprint before an intent, after a commit or repair, or observed
+    /// by a divergence check
+    fingerprint: Option<String>,
+    /// Branches the event affected, when known
+    branches: Vec<String>,
+    /// The event as stored
+    event: Event,
+}
+
+/// `lattice log --ops`: list ledger events.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `reverse` - Oldest first
+/// * `json` - Print one JSON document instead of a listing
+pub fn log_ops(ctx: &Context, reverse: bool, json: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    let stored = EventLedger::new(&git)
+        .all()
+        .context("Failed to read the event ledger")?;
+
+    // Commits and aborts don't name their command; intents do
+    let commands: HashMap<String, String> = stored
+        .iter()
+        .filter_map(|s| match &s.event {
+            Event::IntentRecorded { op_id, command, .. } => Some((op_id.clone(), command.clone())),
+            _ => None,
+        })
+        .collect();
+
+    let mut journals: HashMap<String, Option<Journal>> = HashMap::new();
+    let mut entries: Vec<OpsEntry> = stored
+        .into_iter()
+        .filter(|s| !matches!(s.event, Event::CommandTimed { .. }))
+        .map(|s| {
+            let op_id = s.event.op_id().map(str::to_string);
+            let journal = op_id.as_ref().and_then(|id| {
+                journals
+                    .entry(id.clone())
+                    .or_insert_with(|| Journal::read(&paths, &OpId::from_string(id.as_str())).ok())
+                    .as_ref()
+            });
+            OpsEntry {
+                commit: s.commit_oid.to_string(),
+                timestamp: s.event.timestamp().to_string(),
+                kind: s.event.kind(),
+                command: op_id
+                    .as_ref()
+                    .and_then(|id| commands.get(id).cloned())
+                    .or_else(|| journal.map(|j| j.command.clone())),
+                fingerprint: fingerprint(&s.event).map(str::to_string),
+                branches: affected_branches(&s.event, journal),
+                op_id,
+                event: s.event,
+            }
+        })
+        .collect();
+    if reverse {
+        entries.reverse();
+    }
+
+    if json {
+        super::print_json(&serde_json::json!({ "events": entries }))?;
+        return Ok(());
+    }
+    if entries.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("ops-log-no-operations"));
+        }
+        return Ok(());
+    }
+    for entry in &entries {
+        println!("{}", format_entry(entry));
+    }
+    Ok(())
+}
+
+/// The fingerprint an event carries, if any.
+fn fingerprint(event: &Event) -> Option<&str> {
+    match event {
+        Event::IntentRecorded {
+            fingerprint_before, ..
+        } => Some(fingerprint_before),
+        Event::DivergenceObserved {
+            current_fingerprint,
+            ..
+        } => Some(current_fingerprint),
+        other => other.fingerprint_after(),
+    }
+}
+
+/// Branches an event affected, in the order first touched.
+fn affected_branches(event: &Event, journal: Option<&Journal>) -> Vec<String> {
+    let mut branches: Vec<String> = Vec::new();
+    let mut add = |branch: &str| {
+        if !branches.iter().any(|b| b == branch) {
+            branches.push(branch.to_string());
+        }
+    };
+
+    match event {
+        Event::BranchLifecycle { branch, .. } => add(branch),
+        Event::DivergenceObserved { changed_refs, .. } => {
+            for refname in changed_refs {
+                if let Some(branch) = branch_of_ref(refname) {
+                    add(branch);
+                }
+            }
+        }
+        _ => {
+            for step in journal.map(|j| j.steps.as_slice()).unwrap_or_default() {
+                match &step.kind {
+                    StepKind::RefUpdate { refname, .. } => {
+                        if let Some(branch) = branch_of_ref(refname) {
+                            add(branch);
+                        }
+                    }
+                    StepKind::MetadataWrite { branch, .. }
+                    | StepKind::MetadataDelete { branch, .. } => add(branch),
+                    _ => {}
+                }
+            }
+        }
+    }
+    branches
+}
+
+/// The branch a branch or metadata ref belongs to.
+fn branch_of_ref(refname: &str) -> Option<&str> {
+    refname
+        .strip_prefix("refs/heads/")
+        .or_else(|| refname.strip_prefix("refs/branch-metadata/"))
+}
+
+/// Format one event: a summary line, then the affected branches.
+fn format_entry(entry: &OpsEntry) -> String {
+    let time = DateTime::parse_from_rfc3339(&entry.timestamp)
+        .map(|t| {
+            t.with_timezone(&Utc)
+                .format("%Y-%m-%d %H:%M:%S UTC")
+                .to_string()
+        })
+        .unwrap_or_else(|_| entry.timestamp.clone());
+    let mut line = format!("{}  {:<19}", time, entry.kind);
+    if let Some(command) = &entry.command {
+        line.push_str(&format!(" {}", command));
+    }
+    if let Some(op_id) = &entry.op_id {
+        line.push_str(&format!("  op {}", short(op_id, SHORT_OP_ID)));
+    }
+    if let Some(fingerprint) = &entry.fingerprint {
+        line.push_str(&format!("  fp {}", short(fingerprint, SHORT_FINGERPRINT)));
+    }
+    match &entry.event {
+        Event::Aborted { reason, .. } => line.push_str(&format!("\n    reason: {}", reason)),
+        Event::DoctorProposed { fix_ids, .. } | Event::DoctorApplied { fix_ids, .. }
+            if !fix_ids.is_empty() =>
+        {
+            line.push_str(&format!("\n    fixes: {}", fix_ids.join(", ")));
+        }
+        Event::BranchLifecycle { stage, .. } => line.push_str(&format!(" {}", stage)),
+        _ => {}
+    }
+    if !entry.branches.is_empty() {
+        line.push_str(&format!("\n    branches: {}", entry.branches.join(", ")));
+    }
+    line.trim_end().to_string()
+}
+
+fn short(id: &str, len: usize) -> &str {
+    id.get(..len).unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ops::journal::JournalStep;
+    use crate::core::types::UtcTimestamp;
+
+    #[test]
+    fn branches_come_from_journal_steps() {
+        let mut journal = Journal::new("restack");
+        let steps = [
+            StepKind::RefUpdate {
+                refname: "refs/heads/feature-a".to_string(),
+                old_oid: None,
+                new_oid: "abc".to_string(),
+            },
+            StepKind::MetadataWrite {
+                branch: "feature-a".to_string(),
+                old_ref_oid: None,
+                old_content: None,
+                new_ref_oid: "def".to_string(),
+            },
+            StepKind::RefUpdate {
+                refname: "refs/heads/feature-b".to_string(),
+                old_oid: None,
+                new_oid: "123".to_string(),
+            },
+            StepKind::MetadataDelete {
+                branch: "feature-c".to_string(),
+                old_ref_oid: "456".to_string(),
+                old_content: None,
+            },
+            StepKind::Checkpoint {
+                name: "done".to_string(),
+            },
+        ];
+        journal.steps = steps
+            .into_iter()
+            .map(|kind| JournalStep {
+                kind,
+                timestamp: UtcTimestamp::now(),
+            })
+            .collect();
+
+        let event = Event::committed("op", "fp");
+        assert_eq!(
+            affected_branches(&event, Some(&journal)),
+            vec!["feature-a", "feature-b", "feature-c"]
+        );
+        assert!(affected_branches(&event, None).is_empty());
+    }
+
+    #[test]
+    fn divergence_names_changed_branches() {
+        let event = Event::divergence_observed(
+            "fp-old",
+            "fp-new",
+            vec![
+                "refs/heads/feature".to_string(),
+                "refs/branch-metadata/feature".to_string(),
+                "refs/lattice/event-log".to_string(),
+            ],
+        );
+        assert_eq!(affected_branches(&event, None), vec!["feature"]);
+        assert_eq!(fingerprint(&event), Some("fp-new"));
+    }
+}
@@ -0,0 +1,445 @@
+//! cli::commands::pending_ops
+//!
+//! Shared helpers for queueing forge actions while offline and replaying them.
+//!
+//! # Design
+//!
+//! `submit` records pushes and PR operations in the pending queue
+//! (`core::ops::pending`) when run with `--offline` or when it detects that
+//! the remote is unreachable. `sync` flushes the queue once a fetch succeeds.
+//!
+//! Replay stops at the first action that fails for connectivity reasons,
+//! leaving it and everything after it queued. Actions that fail for any other
+//! reason are reported and dropped so a single bad entry cannot wedge the
+//! queue forever.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let summary = flush_pending_actions(forge.as_ref(), &paths, &cwd, quiet).await?;
+//! if summary.remaining > 0 {
+//!     println!("{} action(s) still queued", summary.remaining);
+//! }
+//! ```
+
+use std::path::Path;
+
+use anyhow::Context as _;
+
+use crate::cli::error::CliResult;
+use crate::core::ops::pending::{PendingAction, PendingQueue};
+use crate::core::paths::LatticePaths;
+use crate::core::types::BranchName;
+use crate::forge::{CreatePrRequest, Forge, ForgeError, Reviewers, UpdatePrRequest};
+use crate::ui::i18n;
+
+/// Fragments of `git push`/`git fetch` stderr that indicate the remote is unreachable.
+const OFFLINE_STDERR_MARKERS: &[&str] = &[
+    "Could not resolve host",
+    "Could not resolve hostname",
+    "Network is unreachable",
+    "Connection refused",
+    "Connection timed out",
+    "Operation timed out",
+    "Temporary failure in name resolution",
+    "unable to access",
+    "Could not read from remote repository",
+];
+
+/// Check whether a failed git network command looks like a connectivity problem.
+pub fn is_offline_git_failure(stderr: &str) -> bool {
+    OFFLINE_STDERR_MARKERS.iter().any(|m| stderr.contains(m))
+}
+
+/// Check whether a forge error indicates the forge is unreachable.
+pub fn is_offline_forge_error(err: &ForgeError) -> bool {
+    matches!(err, ForgeError::NetworkError(_) | ForgeError::Timeout(_))
+}
+
+/// Outcome of replaying a single queued action.
+#[derive(Debug)]
+enum ReplayOutcome {
+    /// The action was applied.
+    Applied,
+    /// The remote is still unreachable; keep the action queued.
+    Offline(String),
+    /// The action failed permanently and should be dropped.
+    Failed(String),
+}
+
+/// Result of flushing the pending queue.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FlushSummary {
+    /// Actions applied successfully.
+    pub applied: usize,
+    /// Actions dropped after a non-connectivity failure.
+    pub dropped: usize,
+    /// Actions still queued because the remote is unreachable.
+    pub remaining: usize,
+    /// Branches whose PRs were created during the flush.
+    pub created: Vec<BranchName>,
+    /// PRs opened for queued creations, to link in the branches' metadata:
+    /// the ones created during the flush and any found already open.
+    pub linked: Vec<LinkedPr>,
+}
+
+/// A PR opened for a queued creation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedPr {
+    /// The PR's head branch.
+    pub branch: BranchName,
+    /// PR number.
+    pub number: u64,
+    /// PR URL.
+    pub url: String,
+}
+
+/// Push a branch to `remote`, classifying failures.
+fn replay_push(
+    cwd: &Path,
+    remote: &str,
+    branch: &str,
+    force: bool,
+    no_verify: bool,
+) -> ReplayOutcome {
+    let mut args = vec!["push"];
+    if no_verify {
+        args.push("--no-verify");
+    }
+    if force {
+        args.push("--force-with-lease");
+    }
+    args.extend([remote, branch]);
+
+    let output = match std::process::Command::new("git")
+        .args(&args)
+        .current_dir(cwd)
+        .output()
+    {
+        Ok(o) => o,
+        Err(e) => return ReplayOutcome::Failed(e.to_string()),
+    };
+
+    if output.status.success() {
+        return ReplayOutcome::Applied;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("Everything up-to-date") {
+        ReplayOutcome::Applied
+    } else if is_offline_git_failure(&stderr) {
+        ReplayOutcome::Offline(stderr.trim().to_string())
+    } else {
+        ReplayOutcome::Failed(stderr.trim().to_string())
+    }
+}
+
+/// Map a forge result onto a replay outcome.
+fn classify<T>(result: Result<T, ForgeError>) -> ReplayOutcome {
+    match result {
+        Ok(_) => ReplayOutcome::Applied,
+        Err(e) if is_offline_forge_error(&e) => ReplayOutcome::Offline(e.to_string()),
+        Err(e) => ReplayOutcome::Failed(e.to_string()),
+    }
+}
+
+/// Replay a single action against the forge (or git, for pushes).
+async fn replay_action(
+    forge: &dyn Forge,
+    cwd: &Path,
+    action: &PendingAction,
+    summary: &mut FlushSummary,
+) -> ReplayOutcome {
+    match action {
+        PendingAction::Push {
+            branch,
+            force,
+            no_verify,
+            remote,
+        } => replay_push(
+            cwd,
+            remote.as_deref().unwrap_or("origin"),
+            branch,
+            *force,
+            *no_verify,
+        ),
+        PendingAction::CreatePr {
+            head,
+            base,
+            title,
+            body,
+            draft,
+            reviewers,
+            team_reviewers,
+        } => {
+            let link = |summary: &mut FlushSummary, number: u64, url: &str| {
+                if let Ok(branch) = BranchName::new(head) {
+                    summary.linked.push(LinkedPr {
+                        branch,
+                        number,
+                        url: url.to_string(),
+                    });
+                }
+            };
+
+            // A PR may have been opened since the action was queued
+            match forge.find_pr_by_head(head).await {
+                Ok(Some(existing)) => {
+                    link(summary, existing.number, &existing.url);
+                    return ReplayOutcome::Applied;
+                }
+                Ok(None) => {}
+                Err(e) => return classify::<()>(Err(e)),
+            }
+
+            let pr = match forge
+                .create_pr(CreatePrRequest {
+                    head: head.clone(),
+                    base: base.clone(),
+                    title: title.clone(),
+                    body: body.clone(),
+                    draft: *draft,
+                })
+                .await
+            {
+                Ok(pr) => pr,
+                Err(e) => return classify::<()>(Err(e)),
+            };
+
+            if let Ok(branch) = BranchName::new(head) {
+                summary.created.push(branch);
+            }
+            link(summary, pr.number, &pr.url);
+
+            if !reviewers.is_empty() || !team_reviewers.is_empty() {
+                let reviewers = Reviewers {
+                    users: reviewers.clone(),
+                    teams: team_reviewers.clone(),
+                };
+                if let Err(e) = forge.request_reviewers(pr.number, reviewers).await {
+                    eprintln!(
+                        "  {}",
+                        i18n::t_args(
+                            "pending-ops-failed-request-reviewers",
+                            &[("number", &pr.number), ("error", &e)],
+                        )
+                    );
+                }
+            }
+            ReplayOutcome::Applied
+        }
+        PendingAction::UpdatePr { number, base } => classify(
+            forge
+                .update_pr(UpdatePrRequest {
+                    number: *number,
+                    base: Some(base.clone()),
+                    title: None,
+                    body: None,
+                })
+                .await,
+        ),
+        PendingAction::SetDraft { number, draft } => {
+            classify(forge.set_draft(*number, *draft).await)
+        }
+        PendingAction::ClosePr { number } => classify(forge.close_pr(*number).await),
+    }
+}
+
+/// Replay queued actions in order, persisting whatever could not be applied.
+///
+/// # Arguments
+///
+/// * `forge` - The forge to replay PR operations against
+/// * `paths` - Lattice paths locating the queue file
+/// * `cwd` - Directory to run `git push` in
+/// * `quiet` - If true, suppress progress output
+pub async fn flush_pending_actions(
+    forge: &dyn Forge,
+    paths: &LatticePaths,
+    cwd: &Path,
+    quiet: bool,
+) -> CliResult<FlushSummary> {
+    let mut queue = PendingQueue::load(paths).context("Failed to read pending actions")?;
+    let mut summary = FlushSummary::default();
+    if queue.is_empty() {
+        return Ok(summary);
+    }
+
+    if !quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "pending-ops-flushing-queued-action",
+                &[("count", &queue.len())],
+            )
+        );
+    }
+
+    while let Some(entry) = queue.pop_front() {
+        match replay_action(forge, cwd, &entry.action, &mut summary).await {
+            ReplayOutcome::Applied => {
+                summary.applied += 1;
+                if !quiet {
+                    println!(
+                        "  {}",
+                        i18n::t_args(
+                            "pending-ops-applied",
+                            &[("describe", &entry.action.describe())],
+                        )
+                    );
+                }
+            }
+            ReplayOutcome::Offline(reason) => {
+                if !quiet {
+                    println!(
+                        "  {}",
+                        i18n::t_args("pending-ops-still-offline-keeping", &[("reason", &reason)])
+                    );
+                }
+                queue.push_front(entry);
+                break;
+            }
+            ReplayOutcome::Failed(reason) => {
+                summary.dropped += 1;
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "pending-ops-dropped-queued-action",
+                        &[("describe", &entry.action.describe()), ("reason", &reason)],
+                    )
+                );
+            }
+        }
+    }
+
+    summary.remaining = queue.len();
+    queue
+        .save(paths)
+        .context("Failed to write pending actions")?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::mock::{FailOn, MockForge};
+    use tempfile::TempDir;
+
+    fn test_paths(temp: &TempDir) -> LatticePaths {
+        let git_dir = temp.path().join(".git");
+        LatticePaths::new(git_dir.clone(), git_dir)
+    }
+
+    fn create(head: &str) -> PendingAction {
+        PendingAction::CreatePr {
+            head: head.to_string(),
+            base: "main".to_string(),
+            title: head.to_string(),
+            body: Some(format!("Adds {}.", head)),
+            draft: false,
+            reviewers: vec![],
+            team_reviewers: vec![],
+        }
+    }
+
+    fn queue_actions(paths: &LatticePaths, actions: Vec<PendingAction>) {
+        let mut queue = PendingQueue::default();
+        for action in actions {
+            queue.enqueue(action);
+        }
+        queue.save(paths).unwrap();
+    }
+
+    #[test]
+    fn detects_offline_git_failures() {
+        assert!(is_offline_git_failure(
+            "fatal: unable to access 'https://github.com/o/r.git/': Could not resolve host: github.com"
+        ));
+        assert!(is_offline_git_failure(
+            "ssh: connect to host github.com port 22: Network is unreachable"
+        ));
+        assert!(!is_offline_git_failure(
+            "! [rejected] feature -> feature (non-fast-forward)"
+        ));
+    }
+
+    #[test]
+    fn only_network_errors_are_offline() {
+        assert!(is_offline_forge_error(&ForgeError::NetworkError(
+            "timeout".into()
+        )));
+        assert!(is_offline_forge_error(&ForgeError::Timeout(
+            "api.github.com did not respond in time".into()
+        )));
+        assert!(!is_offline_forge_error(&ForgeError::RateLimited));
+    }
+
+    #[tokio::test]
+    async fn flush_applies_in_order_and_clears_queue() {
+        let temp = TempDir::new().unwrap();
+        let paths = test_paths(&temp);
+        queue_actions(&paths, vec![create("a"), create("b")]);
+
+        let forge = MockForge::new();
+        let summary = flush_pending_actions(&forge, &paths, temp.path(), true)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.applied, 2);
+        assert_eq!(summary.remaining, 0);
+        assert_eq!(
+            summary.created,
+            vec![BranchName::new("a").unwrap(), BranchName::new("b").unwrap()]
+        );
+        assert_eq!(
+            summary.linked[0],
+            LinkedPr {
+                branch: BranchName::new("a").unwrap(),
+                number: 1,
+                url: "https://github.com/mock/repo/pull/1".to_string(),
+            }
+        );
+        let pr = forge.get_pr(1).await.unwrap();
+        assert_eq!(pr.body.as_deref(), Some("Adds a."));
+        assert!(!PendingQueue::path(&paths).exists());
+    }
+
+    #[tokio::test]
+    async fn flush_stops_and_keeps_queue_when_offline() {
+        let temp = TempDir::new().unwrap();
+        let paths = test_paths(&temp);
+        queue_actions(&paths, vec![create("a"), create("b")]);
+
+        let forge = MockForge::new().fail_on(FailOn::FindPrByHead(ForgeError::NetworkError(
+            "offline".into(),
+        )));
+        let summary = flush_pending_actions(&forge, &paths, temp.path(), true)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.applied, 0);
+        assert_eq!(summary.remaining, 2);
+        let queue = PendingQueue::load(&paths).unwrap();
+        assert_eq!(queue.entries[0].action, create("a"));
+    }
+
+    #[tokio::test]
+    async fn flush_drops_permanent_failures() {
+        let temp = TempDir::new().unwrap();
+        let paths = test_paths(&temp);
+        queue_actions(
+            &paths,
+            vec![PendingAction::SetDraft {
+                number: 99,
+                draft: false,
+            }],
+        );
+
+        let forge = MockForge::new();
+        let summary = flush_pending_actions(&forge, &paths, temp.path(), true)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.dropped, 1);
+        assert_eq!(summary.remaining, 0);
+    }
+}
@@ -10,12 +10,15 @@
 //! - `count_commits_in_range` - Counts commits unique to a branch
 //! - `get_net_diff` - Gets the net diff between two commits
 //! - `is_descendant_of` - Checks if a branch is a descendant of another
+//! - `estimate_restack_conflicts` - Predicts whether a descendant restack will conflict
+//! - `format_restack_preview` - Renders the descendant list shown before a rewrite
 
 use std::path::Path;
 use std::process::Command;
 
-use anyhow::{bail, Context as _, Result};
+use anyhow::Context as _;
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::types::{BranchName, Oid};
 use crate::engine::scan::RepoSnapshot;
 
@@ -34,7 +37,7 @@ use crate::engine::scan::RepoSnapshot;
 ///
 /// * `Ok(())` if no branches are frozen
 /// * `Err` with descriptive message if any branch is frozen
-pub fn check_freeze_affected_set(branches: &[BranchName], snapshot: &RepoSnapshot) -> Result<()> {
+pub fn check_freeze_affected_set(branches: &[BranchName], snapshot: &RepoSnapshot) -> CliResult {
     let mut frozen_branches = Vec::new();
 
     for branch in branches {
@@ -48,23 +51,23 @@ pub fn check_freeze_affected_set(branches: &[BranchName], snapshot: &RepoSnapsho
     if frozen_branches.is_empty() {
         Ok(())
     } else if frozen_branches.len() == 1 {
-        bail!(
+        Err(CliError::conflict(format!(
             "Cannot proceed: branch '{}' is frozen. Use 'lattice unfreeze' first.",
             frozen_branches[0]
-        );
+        )))
     } else {
-        bail!(
+        Err(CliError::conflict(format!(
             "Cannot proceed: {} branches are frozen: {}. Use 'lattice unfreeze' first.",
             frozen_branches.len(),
             frozen_branches.join(", ")
-        );
+        )))
     }
 }
 
 /// Check freeze policy for a single branch.
 ///
 /// Convenience wrapper around `check_freeze_affected_set` for single branch.
-pub fn check_freeze(branch: &BranchName, snapshot: &RepoSnapshot) -> Result<()> {
+pub fn check_freeze(branch: &BranchName, snapshot: &RepoSnapshot) -> CliResult {
     check_freeze_affected_set(std::slice::from_ref(branch), snapshot)
 }
 
@@ -82,7 +85,7 @@ pub fn check_freeze(branch: &BranchName, snapshot: &RepoSnapshot) -> Result<()>
 /// # Returns
 ///
 /// * `Ok(String)` - The patch content
-pub fn get_net_diff(cwd: &Path, base: &Oid, tip: &Oid) -> Result<String> {
+pub fn get_net_diff(cwd: &Path, base: &Oid, tip: &Oid) -> CliResult<String> {
     let output = Command::new("git")
         .args(["diff", base.as_str(), tip.as_str()])
         .current_dir(cwd)
@@ -91,7 +94,7 @@ pub fn get_net_diff(cwd: &Path, base: &Oid, tip: &Oid) -> Result<String> {
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("git diff failed: {}", stderr);
+        return Err(CliError::git(format!("git diff failed: {}", stderr)));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -110,7 +113,7 @@ pub fn get_net_diff(cwd: &Path, base: &Oid, tip: &Oid) -> Result<String> {
 /// # Returns
 ///
 /// * `Ok(Vec<Oid>)` - List of commit OIDs
-pub fn get_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> Result<Vec<Oid>> {
+pub fn get_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> CliResult<Vec<Oid>> {
     let output = Command::new("git")
         .args([
             "rev-list",
@@ -123,14 +126,14 @@ pub fn get_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> Result<Vec<Oid
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("git rev-list failed: {}", stderr);
+        return Err(CliError::git(format!("git rev-list failed: {}", stderr)));
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     stdout
         .lines()
         .filter(|s| !s.is_empty())
-        .map(|s| Oid::new(s).context("Invalid OID in rev-list"))
+        .map(|s| Ok(Oid::new(s).context("Invalid OID in rev-list")?))
         .collect()
 }
 
@@ -145,7 +148,7 @@ pub fn get_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> Result<Vec<Oid
 /// # Returns
 ///
 /// * `Ok(usize)` - Number of commits
-pub fn count_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> Result<usize> {
+pub fn count_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> CliResult<usize> {
     let output = Command::new("git")
         .args([
             "rev-list",
@@ -158,13 +161,16 @@ pub fn count_commits_in_range(cwd: &Path, base: &Oid, tip: &Oid) -> Result<usize
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("git rev-list --count failed: {}", stderr);
+        return Err(CliError::git(format!(
+            "git rev-list --count failed: {}",
+            stderr
+        )));
     }
 
     let count_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    count_str
+    Ok(count_str
         .parse::<usize>()
-        .context("Failed to parse commit count")
+        .context("Failed to parse commit count")?)
 }
 
 /// Check if a branch is a descendant of another.
@@ -208,7 +214,7 @@ pub fn is_descendant_of(
 ///
 /// * `Ok(true)` if working tree is clean
 /// * `Ok(false)` if there are uncommitted changes
-pub fn is_working_tree_clean(cwd: &Path) -> Result<bool> {
+pub fn is_working_tree_clean(cwd: &Path) -> CliResult<bool> {
     // Check for staged changes
     let staged = Command::new("git")
         .args(["diff", "--cached", "--quiet"])
@@ -230,6 +236,122 @@ pub fn is_working_tree_clean(cwd: &Path) -> Result<bool> {
     Ok(unstaged.success())
 }
 
+/// Predicted outcome of restacking a descendant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestackEstimate {
+    /// `git merge-tree` found no conflicts.
+    Clean,
+    /// `git merge-tree` reported conflicts.
+    Conflicts,
+    /// The estimate could not be computed.
+    Unknown,
+}
+
+/// Create a throwaway commit from the current index, parented on `parent`.
+///
+/// The commit is not referenced by any ref; it only gives `merge-tree`
+/// something to compare against before the real amend happens.
+pub fn preview_commit_from_index(cwd: &Path, parent: &Oid) -> CliResult<Oid> {
+    let tree = Command::new("git")
+        .args(["write-tree"])
+        .current_dir(cwd)
+        .output()
+        .context("Failed to run git write-tree")?;
+
+    if !tree.status.success() {
+        return Err(CliError::git(format!(
+            "git write-tree failed: {}",
+            String::from_utf8_lossy(&tree.stderr)
+        )));
+    }
+    let tree = String::from_utf8_lossy(&tree.stdout).trim().to_string();
+
+    let commit = Command::new("git")
+        .args([
+            "commit-tree",
+            &tree,
+            "-p",
+            parent.as_str(),
+            "-m",
+            "lattice restack preview",
+        ])
+        .current_dir(cwd)
+        .output()
+        .context("Failed to run git commit-tree")?;
+
+    if !commit.status.success() {
+        return Err(CliError::git(format!(
+            "git commit-tree failed: {}",
+            String::from_utf8_lossy(&commit.stderr)
+        )));
+    }
+
+    Ok(Oid::new(String::from_utf8_lossy(&commit.stdout).trim()).context("Invalid commit OID")?)
+}
+
+/// Estimate whether restacking a descendant onto a rewritten tip will conflict.
+///
+/// Runs `git merge-tree --write-tree <new_tip> <branch_tip>`, which merges
+/// the descendant's changes with the rewrite without touching the working
+/// tree or any refs. A per-commit rebase can still conflict where a single
+/// merge does not, so this is an estimate rather than a guarantee.
+///
+/// # Arguments
+///
+/// * `cwd` - Current working directory
+/// * `new_tip` - Tip the rewritten branch will have
+/// * `branch_tip` - Current tip of the descendant
+pub fn estimate_restack_conflicts(cwd: &Path, new_tip: &Oid, branch_tip: &Oid) -> RestackEstimate {
+    let output = Command::new("git")
+        .args([
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            new_tip.as_str(),
+            branch_tip.as_str(),
+        ])
+        .current_dir(cwd)
+        .output();
+
+    match output.map(|o| o.status.code()) {
+        Ok(Some(0)) => RestackEstimate::Clean,
+        Ok(Some(1)) => RestackEstimate::Conflicts,
+        _ => RestackEstimate::Unknown,
+    }
+}
+
+/// Render the list of descendants a rewriting command is about to restack.
+///
+/// `estimates` is `None` when the rewrite cannot change file contents (for
+/// example `squash`), in which case no conflict column is shown.
+pub fn format_restack_preview(
+    branches: &[BranchName],
+    estimates: Option<&[RestackEstimate]>,
+) -> String {
+    let mut out = format!("Will restack {} descendant(s):\n", branches.len());
+    let width = branches.iter().map(|b| b.as_str().len()).max().unwrap_or(0);
+
+    for (i, branch) in branches.iter().enumerate() {
+        let note = match estimates.and_then(|e| e.get(i)) {
+            Some(RestackEstimate::Clean) => "  (clean)",
+            Some(RestackEstimate::Conflicts) => "  (likely conflicts)",
+            Some(RestackEstimate::Unknown) => "  (conflicts unknown)",
+            None => "",
+        };
+        out.push_str(&format!(
+            "  {:<width$}{}\n",
+            branch.as_str(),
+            note,
+            width = width
+        ));
+    }
+
+    if estimates.is_some_and(|e| e.contains(&RestackEstimate::Conflicts)) {
+        out.push_str("Use --no-restack to defer and run 'lattice restack' later.\n");
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     mod check_freeze {
@@ -241,4 +363,35 @@ mod tests {
         // These tests would require setting up a mock graph
         // For now, we verify the function compiles and has correct signature
     }
+
+    mod format_restack_preview {
+        use super::super::*;
+
+        fn names(names: &[&str]) -> Vec<BranchName> {
+            names.iter().map(|n| BranchName::new(*n).unwrap()).collect()
+        }
+
+        #[test]
+        fn lists_branches_without_estimates() {
+            let out = format_restack_preview(&names(&["a", "b"]), None);
+            assert_eq!(out, "Will restack 2 descendant(s):\n  a\n  b\n");
+        }
+
+        #[test]
+        fn aligns_estimates_and_hints_on_conflict() {
+            let out = format_restack_preview(
+                &names(&["short", "longer-name"]),
+                Some(&[RestackEstimate::Clean, RestackEstimate::Conflicts]),
+            );
+            assert!(out.contains("  short        (clean)\n"));
+            assert!(out.contains("  longer-name  (likely conflicts)\n"));
+            assert!(out.contains("--no-restack"));
+        }
+
+        #[test]
+        fn no_hint_when_all_clean() {
+            let out = format_restack_preview(&names(&["a"]), Some(&[RestackEstimate::Clean]));
+            assert!(!out.contains("--no-restack"));
+        }
+    }
 }
@@ -0,0 +1,85 @@
+//! cli::commands::phase_budgets
+//!
+//! Record how long a command's phases took and hint when one was slow.
+//!
+//! # Design
+//!
+//! After every command, [`report`] takes the phase durations the engine
+//! collected (see [`crate::engine::timing`]) and appends them to the event
+//! ledger as a `CommandTimed` event, best-effort. Commands that never
+//! scanned the repository have nothing to record. Read-only mode skips the
+//! ledger write, as it does for doctor's events.
+//!
+//! Each phase over its `[budgets]` limit gets one hint line on stderr,
+//! unless `--quiet` is given. `--debug` prints every phase's duration.
+
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::core::config::Config;
+use crate::core::metadata::METADATA_REF_PREFIX;
+use crate::engine::ledger::{Event, EventLedger};
+use crate::engine::timing::{self, PhaseTiming, SlowPathFacts};
+use crate::engine::{read_only, Context};
+use crate::git::Git;
+
+/// Record the finished command's phase durations and print budget hints.
+pub fn report(ctx: &Context, command: &str, started: Instant, succeeded: bool) {
+    let phases = timing::take();
+    if phases.is_empty() {
+        return;
+    }
+
+    if ctx.debug {
+        eprintln!("[debug] Phase timings: {}", describe(&phases));
+    }
+
+    let cwd = match ctx.cwd.clone() {
+        Some(cwd) => cwd,
+        None => std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+    };
+    let Ok(git) = Git::open(&cwd) else {
+        return;
+    };
+    let config = Config::load(Some(&cwd)).ok().map(|r| r.config);
+
+    if !read_only::is_active(ctx.read_only, config.as_ref()) {
+        let total_millis = started.elapsed().as_millis() as u64;
+        let event = Event::command_timed(command, phases.clone(), total_millis, succeeded);
+        if let Err(e) = EventLedger::new(&git).append(event) {
+            if ctx.debug {
+                eprintln!("[debug] Failed to record phase timings: {}", e);
+            }
+        }
+    }
+
+    if ctx.quiet {
+        return;
+    }
+    let config = config.unwrap_or_default();
+    let exceeded = config.phase_budgets().exceeded(&phases);
+    if exceeded.is_empty() {
+        return;
+    }
+    let facts = SlowPathFacts {
+        ancestry_cache: config.ancestry_cache_entries() > 0,
+        commit_graph: git.has_commit_graph(),
+        hooks: ctx.verify && git.has_commit_hooks(),
+        tracked_branches: git
+            .list_refs_by_prefix(METADATA_REF_PREFIX)
+            .map(|refs| refs.len())
+            .unwrap_or(0),
+    };
+    for (timing, budget) in exceeded {
+        eprintln!("{}", timing::budget_hint(&timing, budget, &facts));
+    }
+}
+
+/// List phases as `scan 120ms, execute 2300ms`.
+fn describe(phases: &[PhaseTiming]) -> String {
+    phases
+        .iter()
+        .map(|t| format!("{} {}ms", t.phase, t.millis))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -32,9 +32,10 @@
 use std::io::Write as IoWrite;
 use std::process::{Command as StdCommand, Stdio};
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
 use crate::cli::commands::phase3_helpers::{get_net_diff, is_working_tree_clean};
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::{BranchName, Oid};
@@ -45,13 +46,14 @@ use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Pop current branch, keeping changes uncommitted.
 ///
 /// # Arguments
 ///
 /// * `ctx` - Execution context
-pub fn pop(ctx: &Context) -> Result<()> {
+pub fn pop(ctx: &Context) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -60,7 +62,9 @@ pub fn pop(ctx: &Context) -> Result<()> {
 
     // Require clean working tree BEFORE entering command lifecycle
     if !is_working_tree_clean(&cwd)? {
-        anyhow::bail!("Working tree is not clean. Commit or stash your changes first.");
+        return Err(CliError::conflict(
+            "Working tree is not clean. Commit or stash your changes first.",
+        ));
     }
 
     // Do preliminary scan to compute the diff BEFORE the command runs
@@ -72,26 +76,23 @@ pub fn pop(ctx: &Context) -> Result<()> {
     let trunk = preliminary_snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     let current = preliminary_snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
     // Check if tracked
     if !preliminary_snapshot.metadata.contains_key(&current) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            current
-        );
+        return Err(CliError::untracked(&current));
     }
 
     let current_meta = preliminary_snapshot
         .metadata
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(&current))?;
 
     // Get parent name
     let parent_name = if current_meta.metadata.parent.is_trunk() {
@@ -106,18 +107,21 @@ pub fn pop(ctx: &Context) -> Result<()> {
     let current_tip = preliminary_snapshot
         .branches
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", current))?;
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", current)))?;
 
     // Compute the diff now, before the branch is deleted
     let diff = get_net_diff(&cwd, &base_oid, current_tip)?;
 
     if !ctx.quiet {
         println!(
-            "Popping '{}' (changes will be uncommitted on '{}')...",
-            current, parent_name
+            "{}",
+            i18n::t_args(
+                "pop-popping-changes-uncommitted",
+                &[("current", &current), ("parent_name", &parent_name)],
+            )
         );
         if diff.is_empty() {
-            println!("  No changes in branch.");
+            println!("  {}", i18n::t("pop-no-changes"));
         }
     }
 
@@ -125,12 +129,7 @@ pub fn pop(ctx: &Context) -> Result<()> {
         branch: current.clone(),
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(result) => {
@@ -141,22 +140,23 @@ pub fn pop(ctx: &Context) -> Result<()> {
 
             if !ctx.quiet {
                 println!(
-                    "Pop complete. Changes are staged on '{}'.",
-                    result.parent_name
+                    "{}",
+                    i18n::t_args("pop-complete", &[("parent_name", &result.parent_name)])
                 );
             }
             Ok(())
         }
         CommandOutput::Paused { message } => {
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
     }
 }
 
 /// Apply a diff as uncommitted changes.
-fn apply_diff(cwd: &std::path::Path, diff: &str, quiet: bool) -> Result<()> {
+fn apply_diff(cwd: &std::path::Path, diff: &str, quiet: bool) -> CliResult {
     // Try with --3way first
     let mut child = StdCommand::new("git")
         .args(["apply", "--3way"])
@@ -198,13 +198,16 @@ fn apply_diff(cwd: &std::path::Path, diff: &str, quiet: bool) -> Result<()> {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            eprintln!("Warning: Could not apply changes cleanly: {}", stderr);
-            eprintln!("The branch has been deleted but changes may be incomplete.");
+            eprintln!(
+                "{}",
+                i18n::t_args("pop-warning-could-apply", &[("stderr", &stderr)])
+            );
+            eprintln!("{}", i18n::t("pop-branch-been-deleted"));
         }
     }
 
     if !quiet {
-        println!("  Applied changes as uncommitted files.");
+        println!("  {}", i18n::t("pop-applied-changes-uncommitted"));
     }
 
     Ok(())
@@ -231,9 +234,7 @@ impl Command for PopCommand {
         let snapshot = &ctx.snapshot;
 
         // Get trunk
-        let trunk = snapshot
-            .trunk()
-            .ok_or_else(|| PlanError::MissingData("trunk not configured".to_string()))?;
+        let trunk = snapshot.trunk().ok_or(PlanError::TrunkNotConfigured)?;
 
         // Verify we're on the target branch
         let current = snapshot
@@ -250,10 +251,7 @@ impl Command for PopCommand {
 
         // Check if tracked
         if !snapshot.metadata.contains_key(&self.branch) {
-            return Err(PlanError::InvalidState(format!(
-                "Branch '{}' is not tracked",
-                self.branch
-            )));
+            return Err(PlanError::UntrackedBranch(self.branch.to_string()));
         }
 
         // Get metadata
@@ -367,7 +365,7 @@ impl Command for PopCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Paused for {} on '{}'. Resolve and run 'lattice continue', or 'lattice abort'.",
+                    "Paused for {} conflict on '{}'.",
                     git_state.description(),
                     branch
                 ),
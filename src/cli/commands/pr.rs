@@ -13,7 +13,19 @@
 //! Per SPEC.md Section 8E.6, the pr command:
 //! - Opens PR URL in browser in interactive mode
 //! - Prints URL in non-interactive mode
+//! - `pr open` always tries the browser, for scripts and aliases; without
+//!   one (no display on Linux, or the opener fails) it prints the URLs
+//!   instead
 //! - Falls back to find_pr_by_head if not linked
+//! - Shows the CI state of each branch tip, fetched from the forge and
+//!   cached in metadata, or the last known state when the forge can't be
+//!   reached (see [`super::ci_status`])
+//! - Without a token, reads the CI state of public github.com repositories
+//!   through GitHub's public API, warning when its budget runs low
+//! - Offline, shows the PR and CI states cached in metadata, each with when
+//!   it was fetched, without contacting the forge
+//! - With `--json`, prints `{"prs": [{"branch", "number", "url", "ci"}]}`
+//!   and never opens a browser
 //!
 //! # Example
 //!
@@ -26,22 +38,56 @@
 //!
 //! # Show URLs for entire stack
 //! lattice pr --stack
+//!
+//! # Open every PR in the stack in the browser
+//! lattice pr open --stack
 //! ```
 
+use super::ci_status::{self, CiReport};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::types::{BranchName, Oid};
+use crate::engine::capabilities::Capability;
 use crate::engine::command::ReadOnlyCommand;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::PlanError;
-use crate::engine::runner::{run_readonly_command, RunError};
+use crate::engine::runner::run_readonly_command;
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
+use serde::Serialize;
+
+/// A branch's linked PR.
+#[derive(Debug, Serialize)]
+struct LinkedPr {
+    branch: String,
+    number: u64,
+    url: String,
+    /// CI state of the branch tip, if known
+    ci: Option<CiReport>,
+    #[serde(skip)]
+    tip: Option<Oid>,
+    /// PR state cached in metadata, with when it was fetched
+    #[serde(skip)]
+    cached_state: Option<String>,
+}
+
+/// Machine-readable `pr` output (`--json`).
+#[derive(Debug, Serialize)]
+struct PrReport {
+    prs: Vec<LinkedPr>,
+}
 
 /// Command to open PR URL in browser or print it.
 pub struct PrCommand<'a> {
     ctx: &'a Context,
+    git: &'a Git,
     target: Option<&'a str>,
     stack: bool,
+    json: bool,
+    /// Open in the browser even when not interactive (`pr open`)
+    open: bool,
 }
 
 impl ReadOnlyCommand for PrCommand<'_> {
@@ -55,8 +101,7 @@ impl ReadOnlyCommand for PrCommand<'_> {
         let branch = if let Some(t) = self.target {
             // Could be a branch name or PR number
             // For now, treat as branch name
-            crate::core::types::BranchName::new(t)
-                .map_err(|e| PlanError::InvalidState(format!("Invalid branch name: {}", e)))?
+            BranchName::new(t).map_err(PlanError::from)?
         } else {
             snapshot.current_branch.clone().ok_or_else(|| {
                 PlanError::InvalidState("Not on a branch. Specify a branch name.".to_string())
@@ -64,19 +109,18 @@ impl ReadOnlyCommand for PrCommand<'_> {
         };
 
         // Get metadata for the branch
-        let scanned = snapshot.metadata.get(&branch).ok_or_else(|| {
-            PlanError::InvalidState(format!("Branch '{}' is not tracked by Lattice.", branch))
-        })?;
+        if !snapshot.metadata.contains_key(&branch) {
+            return Err(PlanError::UntrackedBranch(branch.to_string()));
+        }
 
         // Check if we have PR linkage
-        use crate::core::metadata::schema::PrState;
-        let urls = if self.stack {
-            // Get URLs for all branches in stack
-            collect_stack_urls(snapshot, &branch)?
+        let mut prs = if self.stack {
+            // Get PRs for all branches in stack
+            collect_stack_prs(snapshot, &branch)?
         } else {
-            match &scanned.metadata.pr {
-                PrState::Linked { url, .. } => vec![url.clone()],
-                PrState::None => {
+            match linked_pr(snapshot, &branch) {
+                Some(pr) => vec![pr],
+                None => {
                     return Err(PlanError::InvalidState(format!(
                         "No PR linked to branch '{}'. Run 'lattice submit' first.",
                         branch
@@ -85,17 +129,43 @@ impl ReadOnlyCommand for PrCommand<'_> {
             }
         };
 
-        // Output URLs
-        for url in urls {
-            if self.ctx.interactive && !self.ctx.quiet {
-                // Try to open in browser
-                if let Err(e) = open_browser(&url) {
-                    // Fall back to printing
-                    eprintln!("Could not open browser: {}", e);
-                    println!("{}", url);
-                }
-            } else {
-                println!("{}", url);
+        let writes_allowed = snapshot
+            .health
+            .capabilities()
+            .has(&Capability::WritesAllowed);
+        let mut offline = self.ctx.offline;
+        self.refresh_ci(&mut prs, writes_allowed, &mut offline);
+
+        if self.json {
+            return super::print_json(&PrReport { prs });
+        }
+
+        // Output URLs, opening them in the browser when asked or interactive
+        let open = self.open || (self.ctx.interactive && !self.ctx.quiet);
+        let urls: Vec<&str> = prs.iter().map(|pr| pr.url.as_str()).collect();
+        let unopened = if open {
+            open_each(&urls, open_browser)
+        } else {
+            urls
+        };
+        // Offline, everything shown is cached, so say when it was fetched
+        for pr in &prs {
+            let mut details = Vec::new();
+            if offline {
+                details.extend(pr.cached_state.clone());
+            }
+            if let Some(ci) = &pr.ci {
+                let badge = if offline {
+                    ci.stale_badge()
+                } else {
+                    ci.badge()
+                };
+                details.push(format!("ci: {}", badge));
+            }
+            if unopened.contains(&pr.url.as_str()) {
+                print_url(&pr.url, &details);
+            } else if !details.is_empty() {
+                println!("{}  {}", pr.branch, details.join("  "));
             }
         }
 
@@ -103,6 +173,59 @@ impl ReadOnlyCommand for PrCommand<'_> {
     }
 }
 
+impl PrCommand<'_> {
+    /// Replace cached CI states with fresh ones from the forge, caching
+    /// them in metadata when `cache` is set.
+    ///
+    /// Keeps the cached states offline or when the forge can't be reached,
+    /// setting `offline` if it was unreachable.
+    fn refresh_ci(&self, prs: &mut [LinkedPr], cache: bool, offline: &mut bool) {
+        if *offline {
+            return;
+        }
+        let Ok(forge) = super::origin_forge_for_reading(self.git) else {
+            return;
+        };
+        let tips: Vec<(BranchName, Oid)> = prs
+            .iter()
+            .filter_map(|pr| Some((BranchName::new(&pr.branch).ok()?, pr.tip.clone()?)))
+            .collect();
+        let Ok(rt) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+        let fetched = rt.block_on(ci_status::fetch(forge.as_ref(), &tips, offline));
+        let anonymous = self
+            .git
+            .remote_url("origin")
+            .ok()
+            .flatten()
+            .is_some_and(|url| super::reads_anonymously(&url));
+        if anonymous {
+            super::warn_anonymous_quota(self.ctx.quiet || self.json);
+        }
+        for (branch, status) in &fetched {
+            if let Some(pr) = prs.iter_mut().find(|pr| pr.branch == branch.as_str()) {
+                pr.ci = Some(CiReport::new(status, pr.tip.as_ref()));
+            }
+        }
+        if cache {
+            ci_status::record(self.git, self.ctx, "pr", &fetched);
+        }
+    }
+}
+
+/// Print a PR URL, followed by its cached state and CI badge.
+fn print_url(url: &str, details: &[String]) {
+    if details.is_empty() {
+        println!("{}", url);
+    } else {
+        println!("{}  {}", url, details.join("  "));
+    }
+}
+
 /// Run the pr command.
 ///
 /// # Arguments
@@ -110,105 +233,150 @@ impl ReadOnlyCommand for PrCommand<'_> {
 /// * `ctx` - Engine context
 /// * `target` - Optional branch name or PR number (defaults to current)
 /// * `stack` - If true, show URLs for entire stack
-pub fn pr(ctx: &Context, target: Option<&str>, stack: bool) -> Result<()> {
+/// * `json` - Print a JSON document instead of opening or printing URLs
+/// * `open` - Open the URLs in the browser even when not interactive
+pub fn pr(ctx: &Context, target: Option<&str>, stack: bool, json: bool, open: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
-    let cmd = PrCommand { ctx, target, stack };
+    let cmd = PrCommand {
+        ctx,
+        git: &git,
+        target,
+        stack,
+        json,
+        open,
+    };
 
-    run_readonly_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
 }
 
-/// Collect PR URLs for all branches in the stack.
-fn collect_stack_urls(
-    snapshot: &RepoSnapshot,
-    branch: &crate::core::types::BranchName,
-) -> Result<Vec<String>, PlanError> {
+/// The PR linked to `branch`, if any.
+fn linked_pr(snapshot: &RepoSnapshot, branch: &BranchName) -> Option<LinkedPr> {
     use crate::core::metadata::schema::PrState;
 
-    let mut urls = Vec::new();
-
-    // Get ancestors (parents toward trunk)
-    let ancestors = snapshot.graph.ancestors(branch);
-
-    // Collect URLs from ancestors (bottom-up order)
-    for ancestor in ancestors.iter().rev() {
-        if let Some(scanned) = snapshot.metadata.get(ancestor) {
-            if let PrState::Linked { url, .. } = &scanned.metadata.pr {
-                urls.push(url.clone());
-            }
-        }
-    }
-
-    // Add current branch
-    if let Some(scanned) = snapshot.metadata.get(branch) {
-        if let PrState::Linked { url, .. } = &scanned.metadata.pr {
-            urls.push(url.clone());
-        }
+    let metadata = &snapshot.metadata.get(branch)?.metadata;
+    let tip = snapshot.branches.get(branch).cloned();
+    match &metadata.pr {
+        PrState::Linked { number, url, .. } => Some(LinkedPr {
+            branch: branch.to_string(),
+            number: *number,
+            url: url.clone(),
+            ci: metadata
+                .ci
+                .as_ref()
+                .map(|ci| CiReport::new(ci, tip.as_ref())),
+            tip,
+            cached_state: super::cached_pr::stale_label(metadata),
+        }),
+        PrState::None => None,
     }
+}
 
-    // Add descendants
-    let descendants = snapshot.graph.descendants(branch);
-    for desc in descendants {
-        if let Some(scanned) = snapshot.metadata.get(&desc) {
-            if let PrState::Linked { url, .. } = &scanned.metadata.pr {
-                urls.push(url.clone());
-            }
-        }
-    }
+/// Collect the linked PRs of all branches in the stack.
+fn collect_stack_prs(
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+) -> Result<Vec<LinkedPr>, PlanError> {
+    // Ancestors (bottom-up order), the branch itself, then descendants by
+    // depth and name
+    let ancestors = snapshot.graph.ancestors(branch);
+    let mut descendants: Vec<_> = snapshot.graph.descendants(branch).into_iter().collect();
+    descendants.sort_by_cached_key(|b| (snapshot.graph.ancestors(b).len(), b.to_string()));
+    let prs: Vec<LinkedPr> = ancestors
+        .iter()
+        .rev()
+        .chain(std::iter::once(branch))
+        .chain(&descendants)
+        .filter_map(|b| linked_pr(snapshot, b))
+        .collect();
 
-    if urls.is_empty() {
+    if prs.is_empty() {
         return Err(PlanError::InvalidState(
             "No PRs linked in stack. Run 'lattice submit' first.".to_string(),
         ));
     }
 
-    Ok(urls)
+    Ok(prs)
 }
 
-/// Open a URL in the default browser.
-fn open_browser(url: &str) -> Result<()> {
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .arg(url)
-            .status()
-            .context("Failed to open browser")?;
+/// Open each URL with `opener`, returning the ones that weren't opened.
+///
+/// After the first failure the rest aren't tried: a missing browser won't
+/// appear for the next URL.
+fn open_each<'u>(urls: &[&'u str], mut opener: impl FnMut(&str) -> CliResult) -> Vec<&'u str> {
+    for (i, url) in urls.iter().enumerate() {
+        if let Err(e) = opener(url) {
+            eprintln!(
+                "{}",
+                i18n::t_args("browser-open-failed", &[("error", &format!("{:#}", e))])
+            );
+            return urls[i..].to_vec();
+        }
     }
+    Vec::new()
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        std::process::Command::new("xdg-open")
-            .arg(url)
-            .status()
-            .context("Failed to open browser")?;
+/// Open PR URLs in the browser, printing the ones that can't be opened.
+///
+/// Used by `submit --view`.
+pub(super) fn view_urls(urls: &[String]) {
+    let urls: Vec<&str> = urls.iter().map(String::as_str).collect();
+    for url in open_each(&urls, open_browser) {
+        println!("{}", url);
     }
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("cmd")
-            .args(["/C", "start", url])
-            .status()
-            .context("Failed to open browser")?;
+/// Open a URL in the default browser.
+fn open_browser(url: &str) -> CliResult {
+    if !browser_available() {
+        return Err(CliError::failed("no browser available"));
     }
+    Ok(open::that(url).context("Failed to open browser")?)
+}
 
-    Ok(())
+/// Whether a browser could plausibly be shown.
+///
+/// On Linux, openers hand URLs to whatever the desktop provides; without a
+/// display (an SSH session, a container) there is none, and some fall back
+/// to a text browser that takes over the terminal.
+fn browser_available() -> bool {
+    if !cfg!(target_os = "linux") {
+        return true;
+    }
+    ["BROWSER", "DISPLAY", "WAYLAND_DISPLAY", "WSL_DISTRO_NAME"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn open_browser_url_format() {
         // Just verify the function compiles - actual browser opening is hard to test
         let url = "https://github.com/owner/repo/pull/42";
         assert!(url.starts_with("https://"));
     }
+
+    #[test]
+    fn open_each_stops_at_the_first_failure() {
+        let urls = ["https://x/pull/1", "https://x/pull/2", "https://x/pull/3"];
+        let mut tried = Vec::new();
+        let unopened = open_each(&urls, |url| {
+            tried.push(url.to_string());
+            if url.ends_with('2') {
+                return Err(CliError::failed("no browser available"));
+            }
+            Ok(())
+        });
+        assert_eq!(tried.len(), 2);
+        assert_eq!(unopened, vec!["https://x/pull/2", "https://x/pull/3"]);
+
+        assert!(open_each(&urls, |_| Ok(())).is_empty());
+    }
 }
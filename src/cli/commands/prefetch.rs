@@ -0,0 +1,139 @@
+//! cli::commands::prefetch
+//!
+//! Fetch PR and check status in the background after navigation.
+//!
+//! # Design
+//!
+//! After `up`, `down`, `top`, `bottom`, or `checkout` switches to a branch
+//! with a linked PR, Lattice starts a detached `lattice prefetch <branch>`
+//! (a hidden command) and returns without waiting. The child fetches the PR
+//! and its checks and writes them to the forge status cache
+//! ([`crate::forge::status_cache`]), so the next `lattice log` or
+//! `lattice info` shows remote state without a network round trip. Outside
+//! read-only mode it also caches the CI state of the local tip in the
+//! branch's metadata for `lattice log --long` (see `ci_status`).
+//!
+//! Nothing is started when prefetching is disabled (`[prefetch] enabled`),
+//! the user isn't authenticated, the branch has no PR, its cached status is
+//! younger than `[prefetch] interval_secs`, or another prefetch started in
+//! the last few seconds. The child never prints and its failures are
+//! ignored: a missed prefetch only means the cache stays stale.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::Context as _;
+use chrono::{Duration, Utc};
+
+use crate::cli::error::CliResult;
+use crate::core::config::Config;
+use crate::core::paths::LatticePaths;
+use crate::core::types::BranchName;
+use crate::engine::capabilities::Capability;
+use crate::engine::scan::RepoSnapshot;
+use crate::engine::Context;
+use crate::forge::status_cache::{CachedPrStatus, ForgeStatusCache};
+use crate::git::Git;
+
+/// Start a background prefetch of `branch`'s PR status, if one is due.
+///
+/// Best-effort: never fails and never waits for the fetch.
+pub fn spawn_after_navigation(
+    cwd: &Path,
+    paths: &LatticePaths,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+) {
+    let config = Config::load(Some(cwd))
+        .map(|r| r.config)
+        .unwrap_or_default();
+    if !config.prefetch_enabled() {
+        return;
+    }
+    let Some(number) = snapshot
+        .metadata
+        .get(branch)
+        .and_then(|m| m.metadata.pr.number())
+    else {
+        return;
+    };
+    if !snapshot
+        .health
+        .capabilities()
+        .has(&Capability::AuthAvailable)
+    {
+        return;
+    }
+
+    let mut cache = ForgeStatusCache::load(paths);
+    let now = Utc::now();
+    let interval = Duration::seconds(config.prefetch_interval_secs() as i64);
+    cache.record_lookup(branch.as_str(), number, interval, now);
+    if !cache.prefetch_due(branch.as_str(), number, interval, now) {
+        cache.save(paths);
+        return;
+    }
+    cache.mark_prefetch(now);
+    cache.save(paths);
+
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let _ = Command::new(exe)
+        .args(["prefetch", branch.as_str()])
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+}
+
+/// Fetch `branch`'s PR and checks into the forge status cache.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch whose PR status to fetch
+pub fn prefetch(ctx: &Context, branch: &str) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    let branch = BranchName::new(branch).context("Invalid branch name")?;
+
+    let Some(number) = crate::core::metadata::store::MetadataStore::new(&git)
+        .read(&branch)?
+        .and_then(|entry| entry.metadata.pr.number())
+    else {
+        return Ok(());
+    };
+    let tip = git.resolve_ref(&format!("refs/heads/{}", branch))?;
+    let config = Config::load(Some(&cwd)).ok().map(|r| r.config);
+    let cache_ci = !crate::engine::read_only::is_active(ctx.read_only, config.as_ref());
+
+    let forge = super::origin_forge(&git)?;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let (status, ci) = rt.block_on(async {
+        let pr = forge.get_pr(number).await?;
+        let checks = forge.list_checks(number).await.ok();
+        let mut offline = false;
+        let ci =
+            super::ci_status::fetch(forge.as_ref(), &[(branch.clone(), tip)], &mut offline).await;
+        anyhow::Ok((CachedPrStatus::from_forge(&pr, checks.as_deref()), ci))
+    })?;
+
+    // Reload so concurrent prefetches of other branches aren't lost
+    let mut cache = ForgeStatusCache::load(&paths);
+    cache.set(branch.as_str(), status);
+    cache.save(&paths);
+
+    if cache_ci {
+        super::ci_status::record(&git, ctx, "prefetch", &ci);
+    }
+    Ok(())
+}
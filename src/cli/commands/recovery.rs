@@ -28,7 +28,8 @@
 //! a forge API step, `continue` will fail with a clear error message guiding
 //! the user to re-run the original command.
 //!
-//! Git-based forge steps (`ForgeFetch`, `ForgePush`) can be continued normally.
+//! Git-based forge steps (`ForgeFetch`, `ForgePush`) and pre-push checks can
+//! be continued normally.
 //!
 //! # Remote Operation Warnings
 //!
@@ -36,17 +37,19 @@
 //! command warns the user that remote branches cannot be rolled back. Local refs
 //! are restored, but manual intervention may be needed for remote state.
 
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
 use crate::core::ops::journal::{AwaitingReason, Journal, OpPhase, OpState, PLAN_SCHEMA_VERSION};
 use crate::core::ops::lock::RepoLock;
 use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::gate::requirements;
 use crate::engine::ledger::{Event, EventLedger};
-use crate::engine::plan::PlanStep;
+use crate::engine::plan::{decode_remaining_steps, PlanStep};
 use crate::engine::rollback::{rollback_journal, RollbackResult};
 use crate::engine::Context;
 use crate::git::{Git, GitState};
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 use std::path::Path;
 use std::process::Command;
 
@@ -63,7 +66,8 @@ use std::process::Command;
 ///
 /// * `ctx` - Execution context
 /// * `all` - Stage all changes before continuing
-pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
+/// * `skip` - Drop the commit git stopped on instead of applying it
+pub fn continue_op(ctx: &Context, all: bool, skip: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -77,12 +81,12 @@ pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
     }
 
     // Pre-flight gating check (RECOVERY is minimal - just RepoOpen)
-    crate::engine::runner::check_requirements(&git, &requirements::RECOVERY)
-        .map_err(|bundle| anyhow::anyhow!("Repository needs repair: {}", bundle))?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::RECOVERY)
+        .map_err(CliError::needs_repair)?;
 
     // Check for in-progress operation
     let op_state =
-        OpState::read(&paths)?.ok_or_else(|| anyhow::anyhow!("No operation in progress"))?;
+        OpState::read(&paths)?.ok_or_else(|| CliError::usage("No operation in progress"))?;
 
     if ctx.debug {
         eprintln!(
@@ -92,11 +96,10 @@ pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
     }
 
     if op_state.phase != OpPhase::Paused {
-        bail!(
+        return Err(CliError::conflict(format!(
             "Operation '{}' is not paused (phase: {:?})",
-            op_state.command,
-            op_state.phase
-        );
+            op_state.command, op_state.phase
+        )));
     }
 
     // Validate origin worktree IMMEDIATELY after loading op-state
@@ -109,17 +112,18 @@ pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
         );
     }
     if let Err(msg) = op_state.check_origin_worktree(&info.git_dir) {
-        bail!("{}", msg);
+        return Err(CliError::conflict(msg));
     }
 
-    // Verify plan schema version compatibility (SPEC.md §4.6.5)
-    if op_state.plan_schema_version != PLAN_SCHEMA_VERSION {
-        bail!(
-            "Operation created by plan schema v{}; this binary expects v{}.\n\
-             Run 'lattice abort' to cancel, or use a matching binary version to continue.",
+    // Verify plan schema version compatibility (SPEC.md §4.6.5). Older
+    // plans are migrated when their remaining steps are decoded; newer ones
+    // cannot be, so refuse before touching the git operation.
+    if op_state.plan_schema_version > PLAN_SCHEMA_VERSION {
+        return Err(CliError::failed(format!("'{}' was paused by a newer lattice (plan schema v{}); this binary understands up to v{}.\n\
+             Upgrade lattice to continue, or run 'lattice abort' to cancel.",
+            op_state.command,
             op_state.plan_schema_version,
-            PLAN_SCHEMA_VERSION
-        );
+            PLAN_SCHEMA_VERSION)));
     }
 
     // Stage all if requested
@@ -131,43 +135,71 @@ pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
             .context("Failed to run git add")?;
 
         if !status.success() {
-            bail!("git add failed");
+            return Err(CliError::git("git add failed"));
         }
     }
 
     // Check git state and continue if needed
     let git_state = git.state();
+    if skip && !git_state.is_in_progress() {
+        return Err(CliError::usage(
+            "No git operation is in progress; there is no commit to skip.",
+        ));
+    }
     if git_state.is_in_progress() {
-        // Continue the git operation
-        let continue_args = match git_state {
-            GitState::Rebase { .. } => vec!["rebase", "--continue"],
-            GitState::Merge => vec!["merge", "--continue"],
-            GitState::CherryPick => vec!["cherry-pick", "--continue"],
-            GitState::Revert => vec!["revert", "--continue"],
-            GitState::Bisect => bail!("Cannot continue a bisect operation with lattice"),
-            GitState::ApplyMailbox => vec!["am", "--continue"],
-            GitState::Clean => unreachable!(), // Already checked is_in_progress()
+        // Continue the git operation, or skip its stopped commit
+        let continue_args = if skip {
+            super::conflict_summary::skip_args(&git_state)
+                .map(Vec::from)
+                .ok_or_else(|| {
+                    CliError::usage(format!(
+                        "A {} has no commit to skip. Resolve the conflicts or run 'lattice abort'.",
+                        git_state.description()
+                    ))
+                })?
+        } else {
+            match git_state {
+                GitState::Rebase { .. } => vec!["rebase", "--continue"],
+                GitState::Merge => vec!["merge", "--continue"],
+                GitState::CherryPick => vec!["cherry-pick", "--continue"],
+                GitState::Revert => vec!["revert", "--continue"],
+                GitState::Bisect => {
+                    return Err(CliError::usage(
+                        "Cannot continue a bisect operation with lattice",
+                    ))
+                }
+                GitState::ApplyMailbox => vec!["am", "--continue"],
+                GitState::Clean => unreachable!(), // Already checked is_in_progress()
+            }
         };
 
         if !ctx.quiet {
-            println!("Continuing git operation...");
+            println!("{}", i18n::t("recovery-continuing-git-operation"));
         }
 
-        let status = Command::new("git")
-            .args(&continue_args)
-            .current_dir(&cwd)
+        // Same config as the executor's git commands, so rerere records
+        // the resolution just made
+        let mut command = Command::new("git");
+        command.args(&continue_args).current_dir(&cwd);
+        git.configure_command(&mut command);
+        let status = command
             .status()
             .context("Failed to continue git operation")?;
 
-        if !status.success() {
+        let reused = crate::engine::exec::continue_past_recorded_resolutions(&git)?;
+        if !status.success() && reused.as_ref().is_none_or(|r| !r.success) {
             // Check if still in conflict
             let new_state = git.state();
             if new_state.is_in_progress() {
                 println!();
-                println!("Conflicts remain. Resolve them and run 'lattice continue' again.");
+                println!("{}", i18n::t("recovery-conflicts-remain"));
+                super::conflict_summary::print(&git);
                 return Ok(());
             }
-            bail!("git {} failed", continue_args.join(" "));
+            return Err(CliError::git(format!(
+                "git {} failed",
+                continue_args.join(" ")
+            )));
         }
     }
 
@@ -194,7 +226,7 @@ pub fn continue_op(ctx: &Context, all: bool) -> Result<()> {
 /// 3. Roll back ref changes using journal
 /// 4. Record Aborted event in ledger
 /// 5. Clear op-state marker
-pub fn abort(ctx: &Context) -> Result<()> {
+pub fn abort(ctx: &Context) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -204,21 +236,24 @@ pub fn abort(ctx: &Context) -> Result<()> {
     let paths = LatticePaths::from_repo_info(&info);
 
     // Pre-flight gating check (RECOVERY is minimal - just RepoOpen)
-    crate::engine::runner::check_requirements(&git, &requirements::RECOVERY)
-        .map_err(|bundle| anyhow::anyhow!("Repository needs repair: {}", bundle))?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::RECOVERY)
+        .map_err(CliError::needs_repair)?;
 
     // Check for in-progress operation
     let op_state =
-        OpState::read(&paths)?.ok_or_else(|| anyhow::anyhow!("No operation in progress"))?;
+        OpState::read(&paths)?.ok_or_else(|| CliError::usage("No operation in progress"))?;
 
     // Step 1: Validate origin worktree
     // Per SPEC.md §4.6.5, abort must run from the originating worktree
     if let Err(msg) = op_state.check_origin_worktree(&info.git_dir) {
-        bail!("{}", msg);
+        return Err(CliError::conflict(msg));
     }
 
     if !ctx.quiet {
-        println!("Aborting {}...", op_state.command);
+        println!(
+            "{}",
+            i18n::t_args("recovery-aborting", &[("command", &op_state.command)])
+        );
     }
 
     // Step 2: Abort the git operation if any
@@ -234,7 +269,13 @@ pub fn abort(ctx: &Context) -> Result<()> {
     if rollback_result.complete {
         OpState::remove(&paths)?;
         if !ctx.quiet {
-            println!("Operation '{}' aborted.", op_state.command);
+            println!(
+                "{}",
+                i18n::t_args(
+                    "recovery-operation-aborted",
+                    &[("command", &op_state.command)],
+                )
+            );
         }
     } else {
         // Partial rollback - leave op-state but update phase
@@ -243,20 +284,20 @@ pub fn abort(ctx: &Context) -> Result<()> {
         updated_state.write(&paths)?;
 
         eprintln!();
-        eprintln!("Warning: Partial rollback - some refs could not be restored:");
+        eprintln!("{}", i18n::t("recovery-warning-partial-rollback"));
         for (refname, error) in &rollback_result.failed {
             eprintln!("  {}: {}", refname, error);
         }
         eprintln!();
-        eprintln!("The repository may be in an inconsistent state.");
-        eprintln!("Run 'lattice doctor' for guidance on resolving this.");
+        eprintln!("{}", i18n::t("recovery-repository-may-inconsistent"));
+        eprintln!("{}", i18n::t("recovery-run-lattice-doctor"));
     }
 
     Ok(())
 }
 
 /// Abort any in-progress Git operation.
-fn abort_git_operation(git: &Git, cwd: &Path) -> Result<()> {
+fn abort_git_operation(git: &Git, cwd: &Path) -> CliResult {
     let git_state = git.state();
     let abort_args: Option<Vec<&str>> = match git_state {
         GitState::Rebase { .. } => Some(vec!["rebase", "--abort"]),
@@ -276,7 +317,13 @@ fn abort_git_operation(git: &Git, cwd: &Path) -> Result<()> {
             .context("Failed to abort git operation")?;
 
         if !status.success() {
-            eprintln!("Warning: git {} may have failed", args.join(" "));
+            eprintln!(
+                "{}",
+                i18n::t_args(
+                    "recovery-warning-git-failed",
+                    &[("command", &args.join(" "))],
+                )
+            );
         }
     }
 
@@ -291,14 +338,17 @@ fn rollback_refs(
     paths: &LatticePaths,
     op_state: &OpState,
     ctx: &Context,
-) -> Result<RollbackResult> {
+) -> CliResult<RollbackResult> {
     // Load the journal
     let journal = match Journal::read(paths, &op_state.op_id) {
         Ok(j) => j,
         Err(e) => {
             if !ctx.quiet {
-                eprintln!("Warning: Could not load journal: {}", e);
-                eprintln!("Skipping ref rollback.");
+                eprintln!(
+                    "{}",
+                    i18n::t_args("recovery-warning-could-load", &[("error", &e)])
+                );
+                eprintln!("{}", i18n::t("recovery-skipping-ref-rollback"));
             }
             // Return an empty successful result - no refs to roll back
             return Ok(RollbackResult::new());
@@ -310,13 +360,13 @@ fn rollback_refs(
         let remote_ops = journal.remote_operation_descriptions();
         if !remote_ops.is_empty() {
             eprintln!();
-            eprintln!("Warning: This operation included remote changes that cannot be undone:");
+            eprintln!("{}", i18n::t("recovery-warning-operation-included"));
             for desc in &remote_ops {
                 eprintln!("  - {}", desc);
             }
             eprintln!();
-            eprintln!("Local refs will be restored, but remote branches remain as-is.");
-            eprintln!("You may need to force-push or manually revert changes on the remote.");
+            eprintln!("{}", i18n::t("recovery-local-refs-restored"));
+            eprintln!("{}", i18n::t("recovery-you-may-need"));
             eprintln!();
         }
     }
@@ -387,14 +437,17 @@ fn execute_remaining_steps(
     paths: &LatticePaths,
     op_state: &OpState,
     journal: &Journal,
-) -> Result<()> {
+) -> CliResult {
     // Deserialize remaining steps from JSON
-    let remaining_json = journal
-        .remaining_steps_json()
-        .ok_or_else(|| anyhow::anyhow!("No remaining steps found in journal"))?;
+    let remaining_json = journal.remaining_steps_json().ok_or_else(|| {
+        CliError::new(
+            ErrorCategory::Internal,
+            "No remaining steps found in journal",
+        )
+    })?;
 
-    let remaining_steps: Vec<PlanStep> = serde_json::from_str(remaining_json)
-        .context("Failed to deserialize remaining steps from journal")?;
+    let remaining_steps = decode_remaining_steps(remaining_json, op_state.plan_schema_version)
+        .context("Failed to read remaining steps from journal")?;
 
     if remaining_steps.is_empty() {
         // No steps to execute - complete the operation
@@ -403,9 +456,14 @@ fn execute_remaining_steps(
 
     if !ctx.quiet {
         println!(
-            "Resuming {} with {} remaining steps...",
-            op_state.command,
-            remaining_steps.len()
+            "{}",
+            i18n::t_args(
+                "recovery-resuming-remaining-steps",
+                &[
+                    ("command", &op_state.command),
+                    ("count", &remaining_steps.len()),
+                ],
+            )
         );
     }
 
@@ -451,7 +509,7 @@ fn execute_remaining_steps(
             }
             ContinueStepResult::Abort { error } => {
                 // Step failed - abort the operation
-                bail!("Step failed: {}", error);
+                return Err(CliError::failed(format!("Step failed: {}", error)));
             }
         }
     }
@@ -479,7 +537,7 @@ fn execute_single_step(
     step: &PlanStep,
     journal: &mut Journal,
     paths: &LatticePaths,
-) -> Result<ContinueStepResult> {
+) -> CliResult<ContinueStepResult> {
     use crate::core::metadata::store::MetadataStore;
     use crate::core::types::Oid;
 
@@ -499,12 +557,10 @@ fn execute_single_step(
 
             git.update_ref_cas(refname, &new, old.as_ref(), reason)
                 .map_err(|e| {
-                    anyhow::anyhow!(
-                        "CAS failed for {}: expected {:?}, repository may have changed",
-                        refname,
-                        old_oid
-                    )
-                    .context(e)
+                    CliError::conflict(format!(
+                        "{}: CAS failed for {}: expected {:?}, repository may have changed",
+                        e, refname, old_oid
+                    ))
                 })?;
 
             journal.append_ref_update(paths, refname, old_oid.clone(), new_oid)?;
@@ -519,12 +575,10 @@ fn execute_single_step(
             let old = Oid::new(old_oid).context("Invalid old OID")?;
 
             git.delete_ref_cas(refname, &old).map_err(|e| {
-                anyhow::anyhow!(
-                    "CAS failed for {}: expected {}, repository may have changed",
-                    refname,
-                    old_oid
-                )
-                .context(e)
+                CliError::conflict(format!(
+                    "{}: CAS failed for {}: expected {}, repository may have changed",
+                    e, refname, old_oid
+                ))
             })?;
 
             journal.append_ref_update(paths, refname, Some(old_oid.clone()), "")?;
@@ -536,32 +590,20 @@ fn execute_single_step(
             old_ref_oid,
             metadata,
         } => {
-            let store = MetadataStore::new(git);
-            let branch_name = BranchName::new(branch).context("Invalid branch name")?;
-
-            let old = old_ref_oid
-                .as_ref()
-                .map(Oid::new)
-                .transpose()
-                .context("Invalid old metadata OID")?;
-
-            let new_oid = store
-                .write_cas(&branch_name, old.as_ref(), metadata)
-                .map_err(|e| {
-                    anyhow::anyhow!(
-                        "Metadata CAS failed for {}: expected {:?}, repository may have changed",
-                        branch,
-                        old_ref_oid
-                    )
-                    .context(e)
-                })?;
-
-            journal.append_metadata_write(
+            crate::engine::exec::write_metadata_cas(
+                git,
+                journal,
                 paths,
                 branch,
-                old_ref_oid.clone(),
-                new_oid.to_string(),
-            )?;
+                old_ref_oid,
+                metadata,
+            )
+            .map_err(|e| {
+                CliError::conflict(format!(
+                    "{}: Metadata CAS failed for {}: expected {:?}, repository may have changed",
+                    e, branch, old_ref_oid
+                ))
+            })?;
             Ok(ContinueStepResult::Continue)
         }
 
@@ -573,16 +615,15 @@ fn execute_single_step(
             let branch_name = BranchName::new(branch).context("Invalid branch name")?;
             let old = Oid::new(old_ref_oid).context("Invalid old metadata OID")?;
 
+            let old_content = store.snapshot(&old);
             store.delete_cas(&branch_name, &old).map_err(|e| {
-                anyhow::anyhow!(
-                    "Metadata CAS failed for {}: expected {}, repository may have changed",
-                    branch,
-                    old_ref_oid
-                )
-                .context(e)
+                CliError::conflict(format!(
+                    "{}: Metadata CAS failed for {}: expected {}, repository may have changed",
+                    e, branch, old_ref_oid
+                ))
             })?;
 
-            journal.append_metadata_delete(paths, branch, old_ref_oid)?;
+            journal.append_metadata_delete(paths, branch, old_ref_oid, old_content)?;
             Ok(ContinueStepResult::Continue)
         }
 
@@ -635,6 +676,22 @@ fn execute_single_step(
             Ok(ContinueStepResult::Continue)
         }
 
+        PlanStep::RunGitInWorktree {
+            worktree,
+            args,
+            description,
+            expected_effects,
+        } => {
+            let mut journaled = vec!["-C".to_string(), worktree.clone()];
+            journaled.extend(args.iter().cloned());
+            journal.append_git_process(paths, journaled, description)?;
+
+            match crate::engine::exec::run_git_in_worktree(worktree, args, expected_effects)? {
+                Some(error) => Ok(ContinueStepResult::Abort { error }),
+                None => Ok(ContinueStepResult::Continue),
+            }
+        }
+
         PlanStep::Checkpoint { name } => {
             journal.append_checkpoint(paths, name)?;
             Ok(ContinueStepResult::Continue)
@@ -708,6 +765,15 @@ fn execute_single_step(
             Ok(ContinueStepResult::Continue)
         }
 
+        PlanStep::PrePushCheck { branch, command } => {
+            journal.append_checkpoint(paths, format!("pre-push check: {}", branch))?;
+
+            match crate::engine::exec::run_pre_push_check(git, paths, branch, command)? {
+                Some(error) => Ok(ContinueStepResult::Abort { error }),
+                None => Ok(ContinueStepResult::Continue),
+            }
+        }
+
         PlanStep::ForgePush {
             branch,
             force,
@@ -794,7 +860,7 @@ fn execute_single_step(
 ///
 /// Per ARCHITECTURE.md §6.2, we must re-check occupancy after acquiring the lock
 /// because it may have changed since the operation was paused.
-fn validate_occupancy_for_steps(git: &Git, steps: &[PlanStep]) -> Result<()> {
+fn validate_occupancy_for_steps(git: &Git, steps: &[PlanStep]) -> CliResult {
     for step in steps {
         let refname = match step {
             PlanStep::UpdateRefCas { refname, .. } => Some(refname.as_str()),
@@ -804,19 +870,25 @@ fn validate_occupancy_for_steps(git: &Git, steps: &[PlanStep]) -> Result<()> {
 
         if let Some(refname) = refname {
             if let Some(branch) = refname.strip_prefix("refs/heads/") {
-                let branch_name = BranchName::new(branch)
-                    .map_err(|e| anyhow::anyhow!("Invalid branch name '{}': {}", branch, e))?;
+                let branch_name = BranchName::new(branch).map_err(|e| {
+                    CliError::new(
+                        ErrorCategory::Internal,
+                        format!("Invalid branch name '{}': {}", branch, e),
+                    )
+                })?;
 
-                if let Some(wt_path) = git
-                    .branch_checked_out_elsewhere(&branch_name)
-                    .map_err(|e| anyhow::anyhow!("Failed to check worktree occupancy: {}", e))?
+                if let Some(wt_path) =
+                    git.branch_checked_out_elsewhere(&branch_name)
+                        .map_err(|e| {
+                            CliError::git(format!("Failed to check worktree occupancy: {}", e))
+                        })?
                 {
-                    bail!(
+                    return Err(CliError::conflict(format!(
                         "Branch '{}' is checked out in worktree at {}.\n\
                          Switch that worktree to a different branch first.",
                         branch,
                         wt_path.display()
-                    );
+                    )));
                 }
             }
         }
@@ -829,18 +901,18 @@ fn validate_occupancy_for_steps(git: &Git, steps: &[PlanStep]) -> Result<()> {
 /// When we encounter a new conflict while executing remaining steps, we need to:
 /// 1. Record the new conflict state in the journal
 /// 2. Update op-state to paused
-/// 3. Inform the user
+/// 3. Inform the user with a conflict summary
 #[allow(clippy::too_many_arguments)]
 fn pause_for_nested_conflict(
     ctx: &Context,
-    _git: &Git,
+    git: &Git,
     paths: &LatticePaths,
     op_state: &OpState,
     journal: &mut Journal,
     branch: &str,
     git_state: &GitState,
     new_remaining: Vec<PlanStep>,
-) -> Result<()> {
+) -> CliResult {
     let remaining_names: Vec<String> = new_remaining
         .iter()
         .filter_map(|s| {
@@ -864,25 +936,25 @@ fn pause_for_nested_conflict(
         paths,
         branch,
         git_state.description(),
-        remaining_names.clone(),
+        remaining_names,
         remaining_steps_json,
     )?;
     journal.pause();
     journal.write(paths)?;
 
     // Update op-state
+    // The remaining steps are now written in this binary's format
     let mut new_op_state = op_state.clone();
+    new_op_state.plan_schema_version = PLAN_SCHEMA_VERSION;
     new_op_state.pause_with_reason(AwaitingReason::RebaseConflict, paths)?;
 
     if !ctx.quiet {
         println!();
         println!(
-            "Conflict on '{}'. Resolve it and run 'lattice continue' again.",
-            branch
+            "{}",
+            i18n::t_args("recovery-conflict", &[("branch", &branch)])
         );
-        if !remaining_names.is_empty() {
-            println!("Remaining branches: {}", remaining_names.join(", "));
-        }
+        super::conflict_summary::print(git);
     }
 
     Ok(())
@@ -894,7 +966,7 @@ fn complete_operation(
     git: &Git,
     paths: &LatticePaths,
     op_state: &OpState,
-) -> Result<()> {
+) -> CliResult {
     // Record completion event
     let ledger = EventLedger::new(git);
     let _ = ledger.append(Event::committed(
@@ -914,7 +986,13 @@ fn complete_operation(
     OpState::remove(paths)?;
 
     if !ctx.quiet {
-        println!("Operation '{}' completed.", op_state.command);
+        println!(
+            "{}",
+            i18n::t_args(
+                "recovery-operation-completed",
+                &[("command", &op_state.command)],
+            )
+        );
     }
 
     Ok(())
@@ -5,18 +5,41 @@
 //! These are read-only commands that implement `ReadOnlyCommand` and use
 //! `requirements::READ_ONLY`. They flow through `run_readonly_command` to
 //! ensure proper gating.
+//!
+//! With `--json`, each prints one document instead of plain names:
+//! `{"branch", "tracked", "parent"}` for `parent` and
+//! `{"branch", "children"}` for `children` (children sorted by name).
 
+use crate::cli::error::{CliError, CliResult};
 use crate::engine::command::ReadOnlyCommand;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::PlanError;
-use crate::engine::runner::{run_readonly_command, RunError};
+use crate::engine::runner::run_readonly_command;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
+use serde::Serialize;
+
+/// Machine-readable `parent` output (`--json`).
+#[derive(Debug, Serialize)]
+struct ParentReport<'a> {
+    branch: &'a str,
+    tracked: bool,
+    parent: Option<&'a str>,
+}
+
+/// Machine-readable `children` output (`--json`).
+#[derive(Debug, Serialize)]
+struct ChildrenReport<'a> {
+    branch: &'a str,
+    children: Vec<&'a str>,
+}
 
 /// Command to print parent branch name.
 pub struct ParentCommand<'a> {
     ctx: &'a Context,
+    json: bool,
 }
 
 impl ReadOnlyCommand for ParentCommand<'_> {
@@ -33,16 +56,29 @@ impl ReadOnlyCommand for ParentCommand<'_> {
             .ok_or_else(|| PlanError::InvalidState("Not on any branch".to_string()))?;
 
         // Check if tracked
-        let metadata = snapshot.metadata.get(current);
-        if metadata.is_none() {
+        let tracked = snapshot.metadata.contains_key(current);
+        let parent = snapshot.graph.parent(current).filter(|_| tracked);
+
+        if self.json {
+            return super::print_json(&ParentReport {
+                branch: current.as_str(),
+                tracked,
+                parent: parent.map(|p| p.as_str()),
+            });
+        }
+
+        if !tracked {
             if !self.ctx.quiet {
-                eprintln!("Branch '{}' is not tracked", current);
+                eprintln!(
+                    "{}",
+                    i18n::t_args("relationships-not-tracked", &[("current", &current)])
+                );
             }
             return Ok(());
         }
 
         // Get parent from graph
-        if let Some(parent) = snapshot.graph.parent(current) {
+        if let Some(parent) = parent {
             println!("{}", parent);
         }
         // No output if no parent (trunk-child)
@@ -54,28 +90,26 @@ impl ReadOnlyCommand for ParentCommand<'_> {
 /// Print parent branch name.
 ///
 /// Outputs nothing (exit 0) if the branch has no parent (is trunk-child).
+/// With `json`, prints a JSON document instead.
 ///
 /// # Gating
 ///
 /// Uses `requirements::READ_ONLY` via `ReadOnlyCommand` trait.
-pub fn parent(ctx: &Context) -> Result<()> {
+pub fn parent(ctx: &Context, json: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
-    let cmd = ParentCommand { ctx };
-    run_readonly_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    let cmd = ParentCommand { ctx, json };
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
 }
 
 /// Command to print child branch names.
-pub struct ChildrenCommand;
+pub struct ChildrenCommand {
+    json: bool,
+}
 
 impl ReadOnlyCommand for ChildrenCommand {
     const REQUIREMENTS: &'static RequirementSet = &requirements::READ_ONLY;
@@ -91,10 +125,24 @@ impl ReadOnlyCommand for ChildrenCommand {
             .ok_or_else(|| PlanError::InvalidState("Not on any branch".to_string()))?;
 
         // Get children from graph
-        if let Some(children) = snapshot.graph.children(current) {
-            for child in children {
-                println!("{}", child);
-            }
+        let mut children: Vec<&str> = snapshot
+            .graph
+            .children(current)
+            .into_iter()
+            .flatten()
+            .map(|c| c.as_str())
+            .collect();
+        children.sort_unstable();
+
+        if self.json {
+            return super::print_json(&ChildrenReport {
+                branch: current.as_str(),
+                children,
+            });
+        }
+
+        for child in children {
+            println!("{}", child);
         }
         // No output if no children
 
@@ -105,22 +153,18 @@ impl ReadOnlyCommand for ChildrenCommand {
 /// Print child branch names.
 ///
 /// Outputs nothing (exit 0) if the branch has no children.
+/// With `json`, prints a JSON document instead.
 ///
 /// # Gating
 ///
 /// Uses `requirements::READ_ONLY` via `ReadOnlyCommand` trait.
-pub fn children(ctx: &Context) -> Result<()> {
+pub fn children(ctx: &Context, json: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
-    let cmd = ChildrenCommand;
-    run_readonly_command(&cmd, &git, ctx).map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    let cmd = ChildrenCommand { json };
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
 }
@@ -0,0 +1,187 @@
+//! remote command - Show or override the remote a branch is pushed to
+//!
+//! # Architecture
+//!
+//! Setting or clearing the override implements the `Command` trait with
+//! `requirements::MUTATING_METADATA_ONLY`, so the metadata write flows
+//! through the executor and can be undone. Showing the effective remote is
+//! read-only.
+//!
+//! The override is stored in the branch's metadata (`remote`) and is
+//! honored by `submit`, `sync`, and `get` for that branch only. Other
+//! branches keep using `origin`. Push URLs are configured on the git remote
+//! itself (`git remote set-url --push`).
+//!
+//! # Example
+//!
+//! ```bash
+//! # Push this branch to the security fork
+//! lattice remote --set security
+//!
+//! # Show the effective remote
+//! lattice remote
+//!
+//! # Go back to the default remote
+//! lattice remote --unset
+//! ```
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, UtcTimestamp};
+use crate::engine::command::{Command, CommandOutput, SimpleCommand};
+use crate::engine::exec::ExecuteResult;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::runner::{run_command, run_gated};
+use crate::engine::scan::RepoSnapshot;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+/// Remote used for branches without an override.
+const DEFAULT_REMOTE: &str = "origin";
+
+/// Resolve the target branch and require it to be tracked.
+fn resolve_target(snapshot: &RepoSnapshot, branch: Option<&str>) -> Result<BranchName, PlanError> {
+    let target = if let Some(name) = branch {
+        BranchName::new(name)?
+    } else if let Some(ref current) = snapshot.current_branch {
+        current.clone()
+    } else {
+        return Err(PlanError::InvalidState(
+            "Not on any branch and no branch specified".to_string(),
+        ));
+    };
+
+    if !snapshot.metadata.contains_key(&target) {
+        return Err(PlanError::UntrackedBranch(target.to_string()));
+    }
+    Ok(target)
+}
+
+/// Command to set or clear a branch's remote override.
+pub struct SetRemoteCommand<'a> {
+    git: &'a Git,
+    branch: Option<&'a str>,
+    remote: Option<&'a str>,
+}
+
+impl Command for SetRemoteCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING_METADATA_ONLY;
+    type Output = ();
+
+    fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
+        let snapshot = &ready.snapshot;
+        let target = resolve_target(snapshot, self.branch)?;
+
+        if let Some(remote) = self.remote {
+            let exists = self
+                .git
+                .remote_url(remote)
+                .map_err(|e| PlanError::InvalidState(e.to_string()))?
+                .is_some();
+            if !exists {
+                return Err(PlanError::InvalidState(format!(
+                    "Remote '{}' does not exist. Add it with 'git remote add {} <url>'.",
+                    remote, remote
+                )));
+            }
+        }
+
+        let scanned = snapshot.metadata.get(&target).ok_or_else(|| {
+            PlanError::InvalidState(format!("Metadata not found for '{}'", target))
+        })?;
+
+        let mut plan = Plan::new(OpId::new(), "remote");
+        if scanned.metadata.remote.as_deref() == self.remote {
+            return Ok(plan);
+        }
+
+        let mut updated = scanned.metadata.clone();
+        updated.remote = self.remote.map(str::to_string);
+        updated.timestamps.updated_at = UtcTimestamp::now();
+
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: target.to_string(),
+            old_ref_oid: Some(scanned.ref_oid.to_string()),
+            metadata: Box::new(updated),
+        });
+        Ok(plan)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        self.simple_finish(result)
+    }
+}
+
+impl SimpleCommand for SetRemoteCommand<'_> {}
+
+/// Show or change the remote a branch is pushed to and fetched from.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `branch` - Branch to inspect or update (defaults to current)
+/// * `set` - Remote to use for this branch
+/// * `unset` - Clear the override and use the default remote
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY` via `Command` trait when
+/// changing the override, `requirements::READ_ONLY` when showing it.
+pub fn remote(ctx: &Context, branch: Option<&str>, set: Option<&str>, unset: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    if set.is_none() && !unset {
+        let (target, remote) = run_gated(&git, ctx, &requirements::READ_ONLY, |ready| {
+            let target = resolve_target(&ready.snapshot, branch)?;
+            let remote = ready.snapshot.metadata[&target].metadata.remote.clone();
+            Ok((target, remote))
+        })?;
+
+        match remote {
+            Some(remote) => println!(
+                "{}",
+                i18n::t_args(
+                    "remotes-override",
+                    &[("target", &target), ("remote", &remote)],
+                )
+            ),
+            None => println!(
+                "{}",
+                i18n::t_args(
+                    "remotes-default",
+                    &[("target", &target), ("remote", &DEFAULT_REMOTE)],
+                )
+            ),
+        }
+        return Ok(());
+    }
+
+    let cmd = SetRemoteCommand {
+        git: &git,
+        branch,
+        remote: set,
+    };
+    let output = run_command(&cmd, &git, ctx)?;
+    output.into_result().map_err(CliError::failed)?;
+
+    if !ctx.quiet {
+        match set {
+            Some(remote) => println!(
+                "{}",
+                i18n::t_args("remotes-branch-pushed", &[("remote", &remote)])
+            ),
+            None => println!(
+                "{}",
+                i18n::t_args("remotes-override-cleared", &[("remote", &DEFAULT_REMOTE)])
+            ),
+        }
+    }
+    Ok(())
+}
@@ -26,10 +26,25 @@
 //! - Must update all metadata parent references atomically
 //! - Must never rename frozen branches
 //! - Metadata updated only after refs succeed
+//!
+//! # Remote (`--push`)
+//!
+//! After the local rename, `--push` carries it to the remote:
+//!
+//! 1. Push the new name
+//! 2. Move the branch's open PR: neither GitHub nor GitLab can change a
+//!    PR's head branch, so a new PR with the same base, title, body, and
+//!    draft state is opened for the new name, the old PR gets a comment
+//!    pointing to it and is closed, and the metadata links the new PR
+//! 3. Retarget the children's PRs to the new name
+//! 4. Delete the old name from the remote, unless a PR could not be moved
+//!    (deleting it would close PRs still based on or headed at it)
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
-use crate::core::metadata::schema::{BranchInfo, ParentInfo};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::metadata::schema::{BranchInfo, ParentInfo, PrState};
+use crate::core::metadata::MetadataStore;
 use crate::core::ops::journal::OpId;
 use crate::core::types::BranchName;
 use crate::engine::command::{Command, CommandOutput};
@@ -38,7 +53,9 @@ use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
 use crate::engine::Context;
+use crate::forge::{CreatePrRequest, Forge, PullRequest, UpdatePrRequest};
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Rename the current branch.
 ///
@@ -46,7 +63,8 @@ use crate::git::Git;
 ///
 /// * `ctx` - Execution context
 /// * `new_name` - New name for the branch
-pub fn rename(ctx: &Context, new_name: &str) -> Result<()> {
+/// * `push` - Also rename the branch on the remote and move its PRs
+pub fn rename(ctx: &Context, new_name: &str, push: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -54,39 +72,286 @@ pub fn rename(ctx: &Context, new_name: &str) -> Result<()> {
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
     // Validate new name before entering command lifecycle
-    let new_branch =
-        BranchName::new(new_name).map_err(|e| anyhow::anyhow!("Invalid new branch name: {}", e))?;
+    let new_branch = BranchName::new(new_name)
+        .map_err(|e| CliError::usage(format!("Invalid new branch name: {}", e)))?;
+    let old_branch = git.current_branch()?;
 
     let cmd = RenameCommand {
         new_branch: new_branch.clone(),
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(result) => {
+            let old_name = old_branch
+                .as_ref()
+                .map(|b| b.to_string())
+                .unwrap_or(result.old_name);
             if !ctx.quiet {
-                println!("Renamed '{}' to '{}'", result.old_name, result.new_name);
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "rename-renamed",
+                        &[("old_name", &old_name), ("new_name", &result.new_name)],
+                    )
+                );
                 if result.children_updated > 0 {
                     println!(
-                        "  Updated parent references in {} branch(es)",
-                        result.children_updated
+                        "  {}",
+                        i18n::t_args(
+                            "rename-updated-parent-references",
+                            &[("children_updated", &result.children_updated)],
+                        )
                     );
                 }
             }
-            Ok(())
+            match old_branch {
+                Some(old_branch) if push && old_branch != new_branch => {
+                    push_rename(&git, ctx, &old_branch, &new_branch)
+                }
+                _ => Ok(()),
+            }
         }
         CommandOutput::Paused { message } => {
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
+    }
+}
+
+/// Carry a local rename to the remote (see the module docs).
+fn push_rename(git: &Git, ctx: &Context, old: &BranchName, new: &BranchName) -> CliResult {
+    let store = MetadataStore::new(git);
+    let entry = store.read(new)?.ok_or_else(|| CliError::untracked(new))?;
+    let remote = entry.metadata.remote_or("origin").to_string();
+    let pr = match &entry.metadata.pr {
+        PrState::Linked { number, .. } => Some(*number),
+        PrState::None => None,
+    };
+
+    // Children are tracked under the new name once the rename is done
+    let mut children = Vec::new();
+    for branch in store.list()? {
+        let Some(child) = store.read(&branch)? else {
+            continue;
+        };
+        if child.metadata.parent.name() == new.as_str() {
+            if let PrState::Linked { number, .. } = child.metadata.pr {
+                children.push((branch, number));
+            }
+        }
+    }
+
+    // Fail before pushing if PRs need moving and the forge is unreachable
+    let forge = if pr.is_some() || !children.is_empty() {
+        Some(super::origin_forge(git)?)
+    } else {
+        None
+    };
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args("rename-pushing", &[("new", &new), ("remote", &remote)])
+        );
+    }
+    let mut args = vec!["push".to_string()];
+    if !ctx.verify {
+        args.push("--no-verify".to_string());
+    }
+    args.extend([remote.clone(), new.to_string()]);
+    let result = git.run_command(&args)?;
+    if !result.success {
+        return Err(CliError::git(format!(
+            "git push '{}' to '{}' failed: {}",
+            new,
+            remote,
+            result.stderr.trim()
+        )));
+    }
+
+    let moved = match &forge {
+        Some(forge) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(move_prs(forge.as_ref(), old, new, pr, &children, ctx.quiet))?
+        }
+        None => MovedPrs {
+            replacement: None,
+            complete: true,
+        },
+    };
+
+    if let (Some(forge), Some(replacement)) = (&forge, &moved.replacement) {
+        let mut metadata = entry.metadata.clone();
+        metadata.pr = PrState::linked(forge.name(), replacement.number, &replacement.url);
+        metadata.touch();
+        let plan = Plan::new(OpId::new(), "rename").with_step(PlanStep::WriteMetadataCas {
+            branch: new.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+        super::execute_follow_up(git, ctx, &plan)
+            .with_context(|| format!("Failed to link PR #{} to '{}'", replacement.number, new))?;
+    }
+
+    let old_remote_ref = format!("refs/remotes/{}/{}", remote, old);
+    if git.try_resolve_ref(&old_remote_ref)?.is_none() {
+        return Ok(());
+    }
+    if !moved.complete {
+        eprintln!(
+            "{}",
+            i18n::t_args(
+                "rename-kept-old-branch",
+                &[("old", &old), ("remote", &remote)],
+            )
+        );
+        return Ok(());
+    }
+    let args = [
+        "push".to_string(),
+        remote.clone(),
+        "--delete".to_string(),
+        old.to_string(),
+    ];
+    let result = git.run_command(&args)?;
+    if result.success {
+        if !ctx.quiet {
+            println!(
+                "{}",
+                i18n::t_args("rename-deleted", &[("old", &old), ("remote", &remote)])
+            );
+        }
+    } else {
+        eprintln!(
+            "{}",
+            i18n::t_args(
+                "rename-warning-could-delete",
+                &[
+                    ("old", &old),
+                    ("remote", &remote),
+                    ("stderr", &result.stderr.trim()),
+                ],
+            )
+        );
     }
+    Ok(())
+}
+
+/// What [`move_prs`] changed on the forge.
+#[derive(Debug)]
+struct MovedPrs {
+    /// The PR opened for the new name, replacing the old one
+    replacement: Option<PullRequest>,
+    /// Whether every PR was moved off the old name
+    complete: bool,
+}
+
+/// Move PRs off the old branch name: reopen the branch's own open PR
+/// (`pr`) for the new name, and retarget its children's PRs to it.
+async fn move_prs(
+    forge: &dyn Forge,
+    old: &BranchName,
+    new: &BranchName,
+    pr: Option<u64>,
+    children: &[(BranchName, u64)],
+    quiet: bool,
+) -> CliResult<MovedPrs> {
+    let mut moved = MovedPrs {
+        replacement: None,
+        complete: true,
+    };
+
+    if let Some(number) = pr {
+        let old_pr = forge.get_pr(number).await?;
+        if old_pr.state == crate::forge::PrState::Open {
+            let new_pr = forge
+                .create_pr(CreatePrRequest {
+                    head: new.to_string(),
+                    base: old_pr.base.clone(),
+                    title: old_pr.title.clone(),
+                    body: old_pr.body.clone(),
+                    draft: old_pr.is_draft,
+                })
+                .await
+                .with_context(|| format!("Failed to open a PR for '{}'", new))?;
+            if !quiet {
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "rename-opened-pr-replacing",
+                        &[
+                            ("new_number", &new_pr.number),
+                            ("new", &new),
+                            ("number", &number),
+                            ("url", &new_pr.url),
+                        ],
+                    )
+                );
+            }
+
+            let comment = format!(
+                "The branch `{}` was renamed to `{}`. This PR continues in #{}.",
+                old, new, new_pr.number
+            );
+            if let Err(e) = forge.comment_pr(number, &comment).await {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "rename-warning-could-comment",
+                        &[("number", &number), ("error", &e)],
+                    )
+                );
+            }
+            if let Err(e) = forge.close_pr(number).await {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "rename-warning-could-close",
+                        &[("number", &number), ("error", &e)],
+                    )
+                );
+                moved.complete = false;
+            }
+            moved.replacement = Some(new_pr);
+        }
+    }
+
+    for (child, number) in children {
+        let request = UpdatePrRequest {
+            number: *number,
+            base: Some(new.to_string()),
+            ..Default::default()
+        };
+        match forge.update_pr(request).await {
+            Ok(_) => {
+                if !quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "rename-retargeted-pr",
+                            &[("number", &number), ("child", &child), ("new", &new)],
+                        )
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "rename-warning-could-retarget",
+                        &[("number", &number), ("error", &e)],
+                    )
+                );
+                moved.complete = false;
+            }
+        }
+    }
+
+    Ok(moved)
 }
 
 /// Result from a rename operation.
@@ -114,9 +379,7 @@ impl Command for RenameCommand {
         let snapshot = &ctx.snapshot;
 
         // Get trunk
-        let trunk = snapshot
-            .trunk()
-            .ok_or_else(|| PlanError::MissingData("trunk not configured".to_string()))?;
+        let trunk = snapshot.trunk().ok_or(PlanError::TrunkNotConfigured)?;
 
         // Get current branch
         let old_branch = snapshot
@@ -126,10 +389,7 @@ impl Command for RenameCommand {
 
         // Check if tracked
         if !snapshot.metadata.contains_key(old_branch) {
-            return Err(PlanError::InvalidState(format!(
-                "Branch '{}' is not tracked. Use 'lattice track' first.",
-                old_branch
-            )));
+            return Err(PlanError::UntrackedBranch(old_branch.to_string()));
         }
 
         // Check if new name already exists
@@ -238,7 +498,7 @@ impl Command for RenameCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Paused for {} on '{}'. Resolve and run 'lattice continue', or 'lattice abort'.",
+                    "Paused for {} conflict on '{}'.",
                     git_state.description(),
                     branch
                 ),
@@ -247,3 +507,101 @@ impl Command for RenameCommand {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::mock::{FailOn, MockForge, MockOperation};
+    use crate::forge::ForgeError;
+
+    fn open_pr(number: u64, head: &str, base: &str) -> PullRequest {
+        PullRequest {
+            number,
+            url: format!("https://github.com/owner/repo/pull/{}", number),
+            state: crate::forge::PrState::Open,
+            is_draft: true,
+            head: head.into(),
+            base: base.into(),
+            title: format!("Add {}", head),
+            body: Some("Details".into()),
+            node_id: None,
+            author: None,
+        }
+    }
+
+    fn branch(name: &str) -> BranchName {
+        BranchName::new(name).unwrap()
+    }
+
+    #[tokio::test]
+    async fn moves_pr_to_new_name_and_retargets_children() {
+        let forge = MockForge::with_prs(vec![
+            open_pr(1, "temp", "main"),
+            open_pr(2, "child", "temp"),
+        ]);
+        let children = [(branch("child"), 2)];
+
+        let moved = move_prs(
+            &forge,
+            &branch("temp"),
+            &branch("auth"),
+            Some(1),
+            &children,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(moved.complete);
+        let replacement = moved.replacement.unwrap();
+        assert_eq!(replacement.head, "auth");
+        assert_eq!(replacement.base, "main");
+        assert_eq!(replacement.title, "Add temp");
+        assert_eq!(replacement.body.as_deref(), Some("Details"));
+        assert!(replacement.is_draft);
+
+        let old = forge.get_pr_sync(1).unwrap();
+        assert_eq!(old.state, crate::forge::PrState::Closed);
+        assert_eq!(forge.get_pr_sync(2).unwrap().base, "auth");
+        assert!(forge.operations().iter().any(|op| matches!(
+            op,
+            MockOperation::CommentPr { number: 1, body } if body.contains(&format!("#{}", replacement.number))
+        )));
+    }
+
+    #[tokio::test]
+    async fn closed_pr_is_left_alone() {
+        let mut closed = open_pr(1, "temp", "main");
+        closed.state = crate::forge::PrState::Closed;
+        let forge = MockForge::with_prs(vec![closed]);
+
+        let moved = move_prs(&forge, &branch("temp"), &branch("auth"), Some(1), &[], true)
+            .await
+            .unwrap();
+
+        assert!(moved.replacement.is_none());
+        assert!(moved.complete);
+        assert_eq!(forge.pr_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_retarget_keeps_old_name() {
+        let forge = MockForge::with_prs(vec![open_pr(2, "child", "temp")])
+            .fail_on(FailOn::UpdatePr(ForgeError::Timeout("slow".into())));
+        let children = [(branch("child"), 2)];
+
+        let moved = move_prs(
+            &forge,
+            &branch("temp"),
+            &branch("auth"),
+            None,
+            &children,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(!moved.complete);
+        assert_eq!(forge.get_pr_sync(2).unwrap().base, "temp");
+    }
+}
@@ -6,6 +6,7 @@
 //! - User reorders lines
 //! - Validates same set of branches, no duplicates, no missing entries
 //! - Computes required rebase sequence to realize new ordering
+//! - Previews conflicts the rebases are predicted to hit, before confirming
 //! - Journals each rebase step
 //! - Conflicts pause
 //!
@@ -16,12 +17,13 @@
 //! - Metadata updated only after refs succeed
 
 use std::fs;
-use std::io::{self, Write};
 use std::process::Command as ProcessCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
-use crate::cli::commands::restack::get_ancestors_inclusive;
+use crate::cli::commands::restack::{self, get_ancestors_inclusive};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::conflict_prediction::{self, BranchPrediction, PlannedRebase};
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::{BranchName, Oid, UtcTimestamp};
@@ -30,9 +32,11 @@ use crate::engine::exec::ExecuteResult;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
 use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
-use crate::engine::scan::scan;
+use crate::engine::scan::{scan, RepoSnapshot};
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
 
 /// Result of reorder command
 #[derive(Debug)]
@@ -54,7 +58,7 @@ pub struct BranchReorderInfo {
     /// Current metadata ref OID for CAS
     pub metadata_ref_oid: Oid,
     /// Current metadata
-    pub metadata: crate::core::metadata::schema::BranchMetadataV1,
+    pub metadata: crate::core::metadata::schema::BranchMetadataV2,
 }
 
 /// Pre-computed data for reorder command
@@ -153,9 +157,7 @@ impl Command for ReorderCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Conflict while reordering '{}' ({}).\n\
-                     Resolve conflicts, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Conflict while reordering '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -170,7 +172,7 @@ impl Command for ReorderCommand {
 /// # Arguments
 ///
 /// * `ctx` - Execution context
-pub fn reorder(ctx: &Context) -> Result<()> {
+pub fn reorder(ctx: &Context) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -188,20 +190,17 @@ pub fn reorder(ctx: &Context) -> Result<()> {
     let trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?
+        .ok_or_else(CliError::not_initialized)?
         .clone();
 
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
     if !snapshot.metadata.contains_key(&current) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            current
-        );
+        return Err(CliError::untracked(&current));
     }
 
     // Get stack from trunk to current (ancestors including current, excluding trunk)
@@ -211,8 +210,8 @@ pub fn reorder(ctx: &Context) -> Result<()> {
     if stack.len() < 2 {
         if !ctx.quiet {
             println!(
-                "Need at least 2 branches to reorder. Stack has {} tracked branch(es).",
-                stack.len()
+                "{}",
+                i18n::t_args("reorder-need-least-branches", &[("count", &stack.len())])
             );
         }
         return Ok(());
@@ -223,12 +222,12 @@ pub fn reorder(ctx: &Context) -> Result<()> {
         let meta = snapshot
             .metadata
             .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", branch))?;
+            .ok_or_else(|| CliError::untracked(branch))?;
         if meta.metadata.freeze.is_frozen() {
-            anyhow::bail!(
+            return Err(CliError::conflict(format!(
                 "Cannot reorder: branch '{}' is frozen. Use 'lattice unfreeze' first.",
                 branch
-            );
+            )));
         }
     }
 
@@ -256,7 +255,10 @@ pub fn reorder(ctx: &Context) -> Result<()> {
         .unwrap_or_else(|_| "vi".to_string());
 
     if !ctx.quiet {
-        println!("Opening editor to reorder {} branches...", stack.len());
+        println!(
+            "{}",
+            i18n::t_args("reorder-opening-editor-reorder", &[("count", &stack.len())])
+        );
     }
 
     // Open editor
@@ -267,7 +269,7 @@ pub fn reorder(ctx: &Context) -> Result<()> {
 
     if !status.success() {
         fs::remove_file(&temp_file).ok();
-        anyhow::bail!("Editor exited with error");
+        return Err(CliError::failed("Editor exited with error"));
     }
 
     // Read edited file
@@ -284,16 +286,18 @@ pub fn reorder(ctx: &Context) -> Result<()> {
 
     // Validate: same set, no duplicates
     if new_order.len() != stack.len() {
-        anyhow::bail!(
+        return Err(CliError::usage(format!(
             "Invalid edit: expected {} branches, got {}. Do not add or remove branches.",
             stack.len(),
             new_order.len()
-        );
+        )));
     }
 
     let new_set: std::collections::HashSet<_> = new_order.iter().collect();
     if new_set.len() != new_order.len() {
-        anyhow::bail!("Invalid edit: duplicate branch names detected");
+        return Err(CliError::usage(
+            "Invalid edit: duplicate branch names detected",
+        ));
     }
 
     let old_set: std::collections::HashSet<_> = stack.iter().collect();
@@ -302,48 +306,27 @@ pub fn reorder(ctx: &Context) -> Result<()> {
         let added: Vec<_> = new_set.difference(&old_set).collect();
 
         if !missing.is_empty() {
-            anyhow::bail!("Invalid edit: missing branches: {:?}", missing);
+            return Err(CliError::usage(format!(
+                "Invalid edit: missing branches: {:?}",
+                missing
+            )));
         }
         if !added.is_empty() {
-            anyhow::bail!("Invalid edit: unknown branches: {:?}", added);
+            return Err(CliError::usage(format!(
+                "Invalid edit: unknown branches: {:?}",
+                added
+            )));
         }
     }
 
     // Check if order actually changed
     if new_order == stack {
         if !ctx.quiet {
-            println!("No changes to branch order.");
+            println!("{}", i18n::t("reorder-no-changes"));
         }
         return Ok(());
     }
 
-    if !ctx.quiet {
-        println!("New order:");
-        for (i, branch) in new_order.iter().enumerate() {
-            let parent = if i == 0 {
-                trunk.to_string()
-            } else {
-                new_order[i - 1].to_string()
-            };
-            println!("  {} (parent: {})", branch, parent);
-        }
-        println!();
-    }
-
-    // Confirm
-    if ctx.interactive {
-        print!("Apply this reorder? [y/N] ");
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("Aborted.");
-            return Ok(());
-        }
-    }
-
     // =========================================================================
     // PRE-PLAN: Compute rebase sequence
     // =========================================================================
@@ -360,12 +343,12 @@ pub fn reorder(ctx: &Context) -> Result<()> {
         let branch_meta = snapshot
             .metadata
             .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", branch))?;
+            .ok_or_else(|| CliError::untracked(branch))?;
 
         let new_parent_tip = snapshot
             .branches
             .get(&new_parent)
-            .ok_or_else(|| anyhow::anyhow!("Parent '{}' not found", new_parent))?;
+            .ok_or_else(|| CliError::usage(format!("Parent '{}' not found", new_parent)))?;
 
         // Check if already in correct position
         let current_parent_name = branch_meta.metadata.parent.name();
@@ -390,8 +373,45 @@ pub fn reorder(ctx: &Context) -> Result<()> {
 
     if branches_to_reorder.is_empty() {
         if !ctx.quiet {
-            println!("All branches already in correct position.");
+            println!("{}", i18n::t("reorder-all-branches-already"));
+        }
+        return Ok(());
+    }
+
+    if !ctx.quiet {
+        println!("{}", i18n::t("reorder-new-order"));
+        for (i, branch) in new_order.iter().enumerate() {
+            let parent = if i == 0 {
+                trunk.to_string()
+            } else {
+                new_order[i - 1].to_string()
+            };
+            println!(
+                "  {}",
+                i18n::t_args(
+                    "reorder-parent",
+                    &[("branch", &branch), ("parent", &parent)],
+                )
+            );
+        }
+        println!();
+
+        // Warn about conflicts before anything is rebased
+        let predictions = predict_conflicts(&git, &snapshot, &branches_to_reorder)?;
+        if restack::count_conflicts(&predictions) > 0 {
+            println!("{}", i18n::t("reorder-predicted-conflicts"));
+            for line in restack::render_predictions(&predictions) {
+                println!("{}", line);
+            }
+            println!();
         }
+    }
+
+    // Confirm
+    if ctx.interactive
+        && !prompts::confirm(&i18n::t("prompt-reorder-apply"), Severity::Minor, true)?
+    {
+        println!("{}", i18n::t("prompt-aborted"));
         return Ok(());
     }
 
@@ -418,19 +438,51 @@ pub fn reorder(ctx: &Context) -> Result<()> {
         CommandOutput::Success(result) => {
             if !ctx.quiet {
                 println!(
-                    "Reordered {} branch(es). Reorder complete.",
-                    result.branches_reordered
+                    "{}",
+                    i18n::t_args(
+                        "reorder-complete",
+                        &[("branches_reordered", &result.branches_reordered)],
+                    )
                 );
             }
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Reorder failed: {}", error);
+            return Err(CliError::failed(format!("Reorder failed: {}", error)));
         }
     }
 
     Ok(())
 }
+
+/// Predict which rebases of a reorder will conflict, each branch replayed
+/// onto its new parent as reordered.
+fn predict_conflicts(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches_to_reorder: &[BranchReorderInfo],
+) -> CliResult<Vec<BranchPrediction>> {
+    let mut rebases = Vec::new();
+    for info in branches_to_reorder {
+        let tip = snapshot
+            .branches
+            .get(&info.branch)
+            .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", info.branch)))?;
+        let onto = snapshot
+            .branches
+            .get(&info.new_parent)
+            .ok_or_else(|| CliError::usage(format!("Parent '{}' not found", info.new_parent)))?;
+        rebases.push(PlannedRebase {
+            branch: info.branch.clone(),
+            base: Oid::new(&info.old_base)?,
+            tip: tip.clone(),
+            parent: info.new_parent.clone(),
+            onto: onto.clone(),
+        });
+    }
+    Ok(conflict_prediction::predict(git, &rebases)?)
+}
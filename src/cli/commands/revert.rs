@@ -28,10 +28,11 @@
 
 use std::process::Command as StdCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeState, ParentInfo, PrState, Timestamps,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeState, ParentInfo, PrState, Timestamps,
     METADATA_KIND, SCHEMA_VERSION,
 };
 use crate::core::ops::journal::OpId;
@@ -43,6 +44,7 @@ use crate::engine::plan::{Plan, PlanError, PlanStep};
 use crate::engine::runner::run_command;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Create a revert branch for a commit.
 ///
@@ -50,7 +52,7 @@ use crate::git::Git;
 ///
 /// * `ctx` - Execution context
 /// * `sha` - Commit SHA to revert
-pub fn revert(ctx: &Context, sha: &str) -> Result<()> {
+pub fn revert(ctx: &Context, sha: &str) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -67,7 +69,7 @@ pub fn revert(ctx: &Context, sha: &str) -> Result<()> {
         .context("Failed to verify commit")?;
 
     if !output.status.success() {
-        anyhow::bail!("'{}' is not a valid commit", sha);
+        return Err(CliError::usage(format!("'{}' is not a valid commit", sha)));
     }
 
     let full_sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -78,8 +80,11 @@ pub fn revert(ctx: &Context, sha: &str) -> Result<()> {
 
     if !ctx.quiet {
         println!(
-            "Creating revert branch '{}' for commit {}...",
-            branch_name, short_sha
+            "{}",
+            i18n::t_args(
+                "revert-creating-revert-branch",
+                &[("branch_name", &branch_name), ("short_sha", &short_sha)],
+            )
         );
     }
 
@@ -90,27 +95,29 @@ pub fn revert(ctx: &Context, sha: &str) -> Result<()> {
         verify: ctx.verify,
     };
 
-    let output = run_command(&cmd, &git, ctx).map_err(|e| match e {
-        crate::engine::runner::RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })?;
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
 
     match output {
         CommandOutput::Success(()) => {
             if !ctx.quiet {
-                println!("Revert complete.");
-                println!("  Created '{}' reverting commit {}", branch_name, short_sha);
+                println!("{}", i18n::t("revert-complete"));
+                println!(
+                    "  {}",
+                    i18n::t_args(
+                        "revert-created-reverting-commit",
+                        &[("branch_name", &branch_name), ("short_sha", &short_sha)],
+                    )
+                );
             }
             Ok(())
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
             Ok(())
         }
-        CommandOutput::Failed { error } => Err(anyhow::anyhow!("{}", error)),
+        CommandOutput::Failed { error } => Err(CliError::failed(error)),
     }
 }
 
@@ -135,7 +142,7 @@ impl Command for RevertCommand {
         let trunk = ctx
             .snapshot
             .trunk()
-            .ok_or_else(|| PlanError::MissingData("trunk not configured".to_string()))?
+            .ok_or(PlanError::TrunkNotConfigured)?
             .clone();
 
         // Check if branch already exists
@@ -193,7 +200,7 @@ impl Command for RevertCommand {
 
         // Create metadata for the new branch
         let now = UtcTimestamp::now();
-        let metadata = BranchMetadataV1 {
+        let metadata = BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -211,6 +218,14 @@ impl Command for RevertCommand {
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         };
 
         plan = plan.with_step(PlanStep::WriteMetadataCas {
@@ -15,11 +15,12 @@
 
 use std::process::Command as ProcessCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
 use crate::cli::commands::phase3_helpers::{check_freeze, get_commits_in_range};
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeState, ParentInfo, PrState, Timestamps,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeState, ParentInfo, PrState, Timestamps,
     METADATA_KIND, SCHEMA_VERSION,
 };
 use crate::core::ops::journal::OpId;
@@ -32,6 +33,7 @@ use crate::engine::runner::run_command;
 use crate::engine::scan::scan;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Result of split command
 #[derive(Debug)]
@@ -92,7 +94,7 @@ pub struct SplitByFilePrecomputed {
     /// Old metadata ref OID
     pub old_metadata_ref_oid: Oid,
     /// Old metadata
-    pub old_metadata: BranchMetadataV1,
+    pub old_metadata: BranchMetadataV2,
 }
 
 /// Split by commit command
@@ -146,7 +148,7 @@ impl Command for SplitByCommitCommand {
             };
 
             let now = UtcTimestamp::now();
-            let metadata = BranchMetadataV1 {
+            let metadata = BranchMetadataV2 {
                 kind: METADATA_KIND.to_string(),
                 schema_version: SCHEMA_VERSION,
                 branch: BranchInfo {
@@ -162,6 +164,14 @@ impl Command for SplitByCommitCommand {
                     created_at: now.clone(),
                     updated_at: now,
                 },
+                merged_empty: None,
+                remote: None,
+                description: None,
+                pr_cached_at: None,
+                submitted: None,
+                issue: None,
+                provenance: None,
+                ci: None,
             };
 
             let old_ref_oid = info.old_metadata_ref_oid.as_ref().map(|o| o.to_string());
@@ -198,9 +208,7 @@ impl Command for SplitByCommitCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Split paused at '{}' ({}).\n\
-                     Resolve issues, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Split paused at '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -277,7 +285,7 @@ impl Command for SplitByFileCommand {
         };
 
         let now = UtcTimestamp::now();
-        let new_metadata = BranchMetadataV1 {
+        let new_metadata = BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -293,6 +301,14 @@ impl Command for SplitByFileCommand {
                 created_at: now.clone(),
                 updated_at: now.clone(),
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         };
 
         plan = plan.with_step(PlanStep::WriteMetadataCas {
@@ -332,9 +348,7 @@ impl Command for SplitByFileCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Split paused at '{}' ({}).\n\
-                     Resolve issues, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Split paused at '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -351,7 +365,7 @@ impl Command for SplitByFileCommand {
 /// * `ctx` - Execution context
 /// * `by_commit` - Split each commit into its own branch
 /// * `by_file` - Extract changes to specified files into new branch
-pub fn split(ctx: &Context, by_commit: bool, by_file: Vec<String>) -> Result<()> {
+pub fn split(ctx: &Context, by_commit: bool, by_file: Vec<String>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -360,11 +374,13 @@ pub fn split(ctx: &Context, by_commit: bool, by_file: Vec<String>) -> Result<()>
 
     // Validate flags
     if !by_commit && by_file.is_empty() {
-        anyhow::bail!("Must specify --by-commit or --by-file <paths>");
+        return Err(CliError::usage(
+            "Must specify --by-commit or --by-file <paths>",
+        ));
     }
 
     if by_commit && !by_file.is_empty() {
-        anyhow::bail!("Cannot use both --by-commit and --by-file");
+        return Err(CliError::usage("Cannot use both --by-commit and --by-file"));
     }
 
     // =========================================================================
@@ -376,20 +392,17 @@ pub fn split(ctx: &Context, by_commit: bool, by_file: Vec<String>) -> Result<()>
     let trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?
+        .ok_or_else(CliError::not_initialized)?
         .clone();
 
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
     if !snapshot.metadata.contains_key(&current) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            current
-        );
+        return Err(CliError::untracked(&current));
     }
 
     check_freeze(&current, &snapshot)?;
@@ -397,13 +410,13 @@ pub fn split(ctx: &Context, by_commit: bool, by_file: Vec<String>) -> Result<()>
     let current_meta = snapshot
         .metadata
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(&current))?;
 
     let base_oid = Oid::new(&current_meta.metadata.base.oid).context("Invalid base OID")?;
     let current_tip = snapshot
         .branches
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", current))?;
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", current)))?;
 
     // Dispatch to appropriate mode
     if by_commit {
@@ -443,31 +456,43 @@ fn split_by_commit_impl(
     base_oid: &Oid,
     current_tip: &Oid,
     trunk: &BranchName,
-) -> Result<()> {
+) -> CliResult {
     let commits = get_commits_in_range(cwd, base_oid, current_tip)?;
 
     if commits.is_empty() {
         if !ctx.quiet {
-            println!("Branch '{}' has no commits to split.", current);
+            println!(
+                "{}",
+                i18n::t_args("splitting-no-commits", &[("current", &current)])
+            );
         }
         return Ok(());
     }
 
     if commits.len() == 1 {
         if !ctx.quiet {
-            println!("Branch '{}' has only 1 commit. Nothing to split.", current);
+            println!(
+                "{}",
+                i18n::t_args("splitting-branch-only-commit", &[("current", &current)])
+            );
         }
         return Ok(());
     }
 
     if !ctx.quiet {
-        println!("Splitting '{}' into {} branches...", current, commits.len());
+        println!(
+            "{}",
+            i18n::t_args(
+                "splitting-into-branches",
+                &[("current", &current), ("count", &commits.len())],
+            )
+        );
     }
 
     let current_meta = snapshot
         .metadata
         .get(current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(current))?;
 
     let original_parent = if current_meta.metadata.parent.is_trunk() {
         trunk.clone()
@@ -520,8 +545,11 @@ fn split_by_commit_impl(
         CommandOutput::Success(result) => {
             if !ctx.quiet {
                 println!(
-                    "Split complete. Created {} branches:",
-                    result.created_branches.len()
+                    "{}",
+                    i18n::t_args(
+                        "splitting-complete-created",
+                        &[("count", &result.created_branches.len())],
+                    )
                 );
                 for (i, branch) in result.created_branches.iter().enumerate() {
                     let parent = if i == 0 {
@@ -529,16 +557,23 @@ fn split_by_commit_impl(
                     } else {
                         result.created_branches[i - 1].to_string()
                     };
-                    println!("  {} (parent: {})", branch, parent);
+                    println!(
+                        "  {}",
+                        i18n::t_args(
+                            "splitting-parent",
+                            &[("branch", &branch), ("parent", &parent)],
+                        )
+                    );
                 }
             }
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Split failed: {}", error);
+            return Err(CliError::failed(format!("Split failed: {}", error)));
         }
     }
 
@@ -562,18 +597,24 @@ fn split_by_file_impl(
     current_tip: &Oid,
     trunk: &BranchName,
     files: &[String],
-) -> Result<()> {
+) -> CliResult {
     use std::io::Write;
     use std::process::Stdio;
 
     if !ctx.quiet {
-        println!("Splitting '{}' by files: {:?}...", current, files);
+        println!(
+            "{}",
+            i18n::t_args(
+                "splitting-by-files",
+                &[("current", &current), ("files", &format!("{:?}", files))],
+            )
+        );
     }
 
     let current_meta = snapshot
         .metadata
         .get(current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(current))?;
 
     // Get diff for specified files
     let mut diff_args = vec!["diff", base_oid.as_str(), current_tip.as_str(), "--"];
@@ -586,13 +627,15 @@ fn split_by_file_impl(
         .context("Failed to get file diff")?;
 
     if !output.status.success() {
-        anyhow::bail!("git diff failed");
+        return Err(CliError::git("git diff failed"));
     }
 
     let file_diff = String::from_utf8_lossy(&output.stdout).to_string();
 
     if file_diff.trim().is_empty() {
-        anyhow::bail!("No changes to specified files in this branch");
+        return Err(CliError::usage(
+            "No changes to specified files in this branch",
+        ));
     }
 
     // Get diff for remaining files
@@ -618,7 +661,10 @@ fn split_by_file_impl(
     let new_branch_name = BranchName::new(format!("{}-files", current))?;
 
     if snapshot.branches.contains_key(&new_branch_name) {
-        anyhow::bail!("Branch '{}' already exists", new_branch_name);
+        return Err(CliError::usage(format!(
+            "Branch '{}' already exists",
+            new_branch_name
+        )));
     }
 
     let parent_name = if current_meta.metadata.parent.is_trunk() {
@@ -663,7 +709,7 @@ fn split_by_file_impl(
         .context("Failed to create new branch")?;
 
     if !status.success() {
-        anyhow::bail!("git checkout -b failed");
+        return Err(CliError::git("git checkout -b failed"));
     }
 
     // Apply file diff
@@ -695,7 +741,10 @@ fn split_by_file_impl(
             .args(["branch", "-D", new_branch_name.as_str()])
             .current_dir(cwd)
             .status();
-        anyhow::bail!("Failed to apply file changes: {}", stderr);
+        return Err(CliError::git(format!(
+            "Failed to apply file changes: {}",
+            stderr
+        )));
     }
 
     // Commit the changes
@@ -713,7 +762,7 @@ fn split_by_file_impl(
         .context("Failed to commit")?;
 
     if !status.success() {
-        anyhow::bail!("git commit failed");
+        return Err(CliError::git("git commit failed"));
     }
 
     // Get new branch tip
@@ -731,7 +780,7 @@ fn split_by_file_impl(
         .status()?;
 
     if !status.success() {
-        anyhow::bail!("Failed to checkout original branch");
+        return Err(CliError::git("Failed to checkout original branch"));
     }
 
     // Reset to base
@@ -741,7 +790,7 @@ fn split_by_file_impl(
         .status()?;
 
     if !status.success() {
-        anyhow::bail!("git reset failed");
+        return Err(CliError::git("git reset failed"));
     }
 
     // Apply remaining diff if any
@@ -780,15 +829,34 @@ fn split_by_file_impl(
         CommandOutput::Success(result) => {
             if !ctx.quiet {
                 if let SplitMode::ByFile { files } = &result.mode {
-                    println!("Split complete.");
+                    println!("{}", i18n::t("splitting-complete"));
+                    println!(
+                        "  {}",
+                        i18n::t_args(
+                            "splitting-created-with-files",
+                            &[
+                                ("new_branch_name", &new_branch_name),
+                                ("files", &format!("{:?}", files)),
+                            ],
+                        )
+                    );
                     println!(
-                        "  Created '{}' with changes to {:?}",
-                        new_branch_name, files
+                        "  {}",
+                        i18n::t_args(
+                            "splitting-updated-remaining-changes",
+                            &[("current", &current)],
+                        )
                     );
-                    println!("  Updated '{}' with remaining changes", current);
                     println!(
-                        "  Stack: {} -> {} -> {}",
-                        parent_name, new_branch_name, current
+                        "  {}",
+                        i18n::t_args(
+                            "splitting-stack",
+                            &[
+                                ("parent_name", &parent_name),
+                                ("new_branch_name", &new_branch_name),
+                                ("current", &current),
+                            ],
+                        )
                     );
                 }
             }
@@ -796,9 +864,10 @@ fn split_by_file_impl(
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Split failed: {}", error);
+            return Err(CliError::failed(format!("Split failed: {}", error)));
         }
     }
 
@@ -4,7 +4,8 @@
 //!
 //! - Squash all commits unique to current branch into one
 //! - Preserve parent relation
-//! - Restack descendants
+//! - Restack descendants (listed before executing; `--no-restack` or
+//!   `restack.descendants = false` defers them)
 //! - Respect freeze
 //!
 //! # Integrity Contract
@@ -14,10 +15,12 @@
 
 use std::process::Command as ProcessCommand;
 
-use anyhow::{Context as _, Result};
+use anyhow::Context as _;
 
-use crate::cli::commands::phase3_helpers::count_commits_in_range;
-use crate::cli::commands::restack::{get_descendants_inclusive, get_parent_tip, topological_sort};
+use crate::cli::commands::phase3_helpers::{count_commits_in_range, format_restack_preview};
+use crate::cli::commands::restack::{get_descendants_inclusive, topological_sort};
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::Config;
 use crate::core::metadata::schema::{BaseInfo, ParentInfo};
 use crate::core::ops::journal::OpId;
 use crate::core::types::{BranchName, Oid, UtcTimestamp};
@@ -29,6 +32,7 @@ use crate::engine::runner::run_command;
 use crate::engine::scan::scan;
 use crate::engine::Context;
 use crate::git::Git;
+use crate::ui::i18n;
 
 /// Result of squash command
 #[derive(Debug)]
@@ -70,7 +74,7 @@ pub struct DescendantRestackInfo {
     /// Current metadata ref OID for CAS
     pub metadata_ref_oid: Oid,
     /// Current metadata for cloning/updating
-    pub metadata: crate::core::metadata::schema::BranchMetadataV1,
+    pub metadata: crate::core::metadata::schema::BranchMetadataV2,
 }
 
 /// Squash command implementing Command trait
@@ -212,9 +216,7 @@ impl Command for SquashCommand {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Conflict while restacking '{}' ({}).\n\
-                     Resolve conflicts, then run 'lattice continue'.\n\
-                     To abort, run 'lattice abort'.",
+                    "Conflict while restacking '{}' ({}).",
                     branch,
                     git_state.description()
                 ),
@@ -231,7 +233,8 @@ impl Command for SquashCommand {
 /// * `ctx` - Execution context
 /// * `message` - Commit message for squashed commit
 /// * `edit` - Open editor for commit message
-pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
+/// * `no_restack` - Leave descendants for a later `lattice restack`
+pub fn squash(ctx: &Context, message: Option<&str>, edit: bool, no_restack: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -244,36 +247,31 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
 
     let snapshot = scan(&git).context("Failed to scan repository")?;
 
-    let trunk = snapshot
-        .trunk
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?
-        .clone();
+    if snapshot.trunk.is_none() {
+        return Err(CliError::not_initialized());
+    }
 
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on any branch"))?
+        .ok_or_else(|| CliError::usage("Not on any branch"))?
         .clone();
 
     if !snapshot.metadata.contains_key(&current) {
-        anyhow::bail!(
-            "Branch '{}' is not tracked. Use 'lattice track' first.",
-            current
-        );
+        return Err(CliError::untracked(&current));
     }
 
     let scanned = snapshot
         .metadata
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", current))?;
+        .ok_or_else(|| CliError::untracked(&current))?;
 
     // Check freeze on current branch
     if scanned.metadata.freeze.is_frozen() {
-        anyhow::bail!(
+        return Err(CliError::conflict(format!(
             "Cannot squash frozen branch '{}'. Use 'lattice unfreeze' first.",
             current
-        );
+        )));
     }
 
     let base_oid = Oid::new(&scanned.metadata.base.oid).context("Invalid base OID")?;
@@ -281,22 +279,31 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
     let current_tip = snapshot
         .branches
         .get(&current)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", current))?;
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", current)))?;
 
     let commit_count = count_commits_in_range(&cwd, &base_oid, current_tip)?;
 
     if commit_count <= 1 {
         if !ctx.quiet {
             println!(
-                "Branch '{}' has {} commit(s). Nothing to squash.",
-                current, commit_count
+                "{}",
+                i18n::t_args(
+                    "squash-branch-commit-nothing",
+                    &[("current", &current), ("commit_count", &commit_count)],
+                )
             );
         }
         return Ok(());
     }
 
     if !ctx.quiet {
-        println!("Squashing {} commits on '{}'...", commit_count, current);
+        println!(
+            "{}",
+            i18n::t_args(
+                "squash-squashing-commits",
+                &[("commit_count", &commit_count), ("current", &current)],
+            )
+        );
     }
 
     // Get commit messages for squash message
@@ -330,7 +337,7 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
             .context("Failed to open editor")?;
 
         if !status.success() {
-            anyhow::bail!("Editor exited with error");
+            return Err(CliError::failed("Editor exited with error"));
         }
 
         // Read edited message
@@ -338,7 +345,9 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
             std::fs::read_to_string(&temp_msg_file).context("Failed to read edited message")?;
 
         if edited.trim().is_empty() {
-            anyhow::bail!("Aborting squash due to empty commit message");
+            return Err(CliError::usage(
+                "Aborting squash due to empty commit message",
+            ));
         }
 
         Some(edited)
@@ -364,7 +373,7 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
         let branch_meta = snapshot
             .metadata
             .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for '{}'", branch))?;
+            .ok_or_else(|| CliError::untracked(branch))?;
 
         if branch_meta.metadata.freeze.is_frozen() {
             frozen_to_skip.push(branch.clone());
@@ -376,11 +385,8 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
             ParentInfo::Branch { name } => BranchName::new(name)?,
         };
 
-        let parent_tip = get_parent_tip(branch, &snapshot, &trunk)?;
-
-        if branch_meta.metadata.base.oid.as_str() == parent_tip.to_string().as_str() {
-            continue;
-        }
+        // The squashed branch gets a new tip, so even currently aligned
+        // descendants need restacking
 
         descendants_to_restack.push(DescendantRestackInfo {
             branch: branch.clone(),
@@ -391,6 +397,26 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
         });
     }
 
+    let restack = !no_restack
+        && Config::load(Some(&cwd))
+            .map(|r| r.config.restack_descendants())
+            .unwrap_or(true);
+
+    let deferred: Vec<BranchName> = if restack {
+        Vec::new()
+    } else {
+        descendants_to_restack.drain(..).map(|d| d.branch).collect()
+    };
+
+    // Squashing keeps the tree unchanged, so there is no conflict estimate
+    if !ctx.quiet && !descendants_to_restack.is_empty() {
+        let branches: Vec<BranchName> = descendants_to_restack
+            .iter()
+            .map(|d| d.branch.clone())
+            .collect();
+        print!("{}", format_restack_preview(&branches, None));
+    }
+
     let precomputed = SquashPrecomputed {
         branch: current.clone(),
         base_oid: base_oid.to_string(),
@@ -421,45 +447,76 @@ pub fn squash(ctx: &Context, message: Option<&str>, edit: bool) -> Result<()> {
         CommandOutput::Success(result) => {
             if !ctx.quiet {
                 println!(
-                    "Squashed {} commits on '{}'",
-                    result.commits_squashed, result.branch
+                    "{}",
+                    i18n::t_args(
+                        "squash-squashed-commits",
+                        &[
+                            ("commits_squashed", &result.commits_squashed),
+                            ("branch", &result.branch),
+                        ],
+                    )
                 );
 
                 if !result.restacked.is_empty() {
+                    let branches = result
+                        .restacked
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Restacked {} descendant(s): {}",
-                        result.restacked.len(),
-                        result
-                            .restacked
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "squash-restacked-descendant",
+                            &[("count", &result.restacked.len()), ("branches", &branches)],
+                        )
                     );
                 }
 
                 if !result.skipped_frozen.is_empty() {
+                    let branches = result
+                        .skipped_frozen
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "squash-skipped-frozen-branch",
+                            &[
+                                ("count", &result.skipped_frozen.len()),
+                                ("branches", &branches),
+                            ],
+                        )
+                    );
+                }
+
+                if !deferred.is_empty() {
+                    let branches = deferred
+                        .iter()
+                        .map(|b| b.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     println!(
-                        "Skipped {} frozen branch(es): {}",
-                        result.skipped_frozen.len(),
-                        result
-                            .skipped_frozen
-                            .iter()
-                            .map(|b| b.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
+                        "{}",
+                        i18n::t_args(
+                            "squash-deferred-restack-descendant",
+                            &[("count", &deferred.len()), ("branches", &branches)],
+                        )
                     );
                 }
 
-                println!("Squash complete.");
+                println!("{}", i18n::t("squash-complete"));
             }
         }
         CommandOutput::Paused { message } => {
             println!();
             println!("{}", message);
+            super::conflict_summary::print(&git);
         }
         CommandOutput::Failed { error } => {
-            anyhow::bail!("Squash failed: {}", error);
+            return Err(CliError::failed(format!("Squash failed: {}", error)));
         }
     }
 
@@ -0,0 +1,385 @@
+//! stack command - Build a stack from existing commits
+//!
+//! # Design
+//!
+//! `lattice stack create --from <start>..<end> --per-commit` turns a linear
+//! run of local commits into a stack: one tracked branch per commit, each
+//! the parent of the next, the first one stacked on `<start>`. It is the
+//! quickest way to convert a large local branch into reviewable pieces
+//! before the first submit.
+//!
+//! - `<start>` must be trunk or a tracked branch; `<end>` is any revision
+//!   and defaults to `HEAD`.
+//! - The commits in the range must form a single line: no merges, each
+//!   commit the parent of the next.
+//! - Branches are named from their commit subjects the same way
+//!   `lattice create -m` names them, with `-2`, `-3`, ... appended when a
+//!   name is already taken.
+//! - Only refs are written. Existing branches, including the one `<end>`
+//!   may name, and the working tree are left alone.
+//!
+//! # Example
+//!
+//! ```bash
+//! # Turn the commits of a big local branch into a stack on main
+//! lattice stack create --from main..big-feature --per-commit
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command as ProcessCommand;
+
+use anyhow::Context as _;
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::metadata::schema::BranchMetadataV2;
+use crate::core::ops::journal::OpId;
+use crate::core::types::{BranchName, Oid};
+use crate::engine::command::{Command, CommandOutput};
+use crate::engine::exec::ExecuteResult;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::{Plan, PlanError, PlanStep};
+use crate::engine::runner::run_command;
+use crate::engine::Context;
+use crate::git::Git;
+use crate::ui::i18n;
+
+use super::create::slugify;
+
+/// One commit of the range, oldest first.
+#[derive(Debug, Clone)]
+struct RangeCommit {
+    oid: Oid,
+    /// First line of the commit message
+    summary: String,
+}
+
+/// Command that creates one tracked branch per commit.
+struct StackCreateCommand {
+    /// Branch the first new branch is stacked on
+    start: BranchName,
+    /// Parent of the first commit
+    base: Oid,
+    commits: Vec<RangeCommit>,
+    /// Branch to create for each commit
+    names: Vec<BranchName>,
+}
+
+impl Command for StackCreateCommand {
+    // Only refs are written, so a working tree isn't needed
+    const REQUIREMENTS: &'static RequirementSet = &requirements::MUTATING_METADATA_ONLY;
+    type Output = Vec<BranchName>;
+
+    fn plan(&self, ready: &ReadyContext) -> Result<Plan, PlanError> {
+        let snapshot = &ready.snapshot;
+        let start_is_trunk = snapshot.trunk.as_ref() == Some(&self.start);
+        if !start_is_trunk && !snapshot.metadata.contains_key(&self.start) {
+            return Err(PlanError::InvalidState(format!(
+                "'{}' is neither trunk nor a tracked branch. Start the range at one of them.",
+                self.start
+            )));
+        }
+
+        if let Some(name) = self
+            .names
+            .iter()
+            .find(|name| snapshot.branches.contains_key(*name))
+        {
+            return Err(PlanError::InvalidState(format!(
+                "Branch '{}' already exists",
+                name
+            )));
+        }
+
+        let mut plan = Plan::new(OpId::new(), "stack-create");
+        let mut parent = self.start.clone();
+        let mut base = self.base.clone();
+        for (name, commit) in self.names.iter().zip(&self.commits) {
+            plan = plan.with_step(PlanStep::UpdateRefCas {
+                refname: format!("refs/heads/{}", name),
+                old_oid: None,
+                new_oid: commit.oid.to_string(),
+                reason: format!("stack create: {}", commit.summary),
+            });
+
+            let mut builder = BranchMetadataV2::builder(name.clone(), parent.clone(), base);
+            if parent == self.start && start_is_trunk {
+                builder = builder.parent_is_trunk();
+            }
+            plan = plan.with_step(PlanStep::WriteMetadataCas {
+                branch: name.to_string(),
+                old_ref_oid: None,
+                metadata: Box::new(builder.build()),
+            });
+
+            parent = name.clone();
+            base = commit.oid.clone();
+        }
+
+        Ok(plan)
+    }
+
+    fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
+        match result {
+            ExecuteResult::Success { .. } => CommandOutput::Success(self.names.clone()),
+            ExecuteResult::Paused {
+                branch, git_state, ..
+            } => CommandOutput::Paused {
+                message: format!(
+                    "Paused for {} conflict on '{}'.",
+                    git_state.description(),
+                    branch
+                ),
+            },
+            ExecuteResult::Aborted { error, .. } => CommandOutput::Failed { error },
+        }
+    }
+}
+
+/// Create one tracked branch per commit in `range`.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `range` - `<start>..<end>`; `<end>` defaults to `HEAD`
+///
+/// # Gating
+///
+/// Uses `requirements::MUTATING_METADATA_ONLY` via `Command` trait.
+pub fn create(ctx: &Context, range: &str) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let (start, end) = parse_range(range)?;
+    let start = BranchName::new(start).context("The range must start at a branch")?;
+    let lines = rev_list(&cwd, &format!("{}..{}", start, end))?;
+    let (base, oids) = linear_commits(&lines)?;
+    let Some(base) = base else {
+        if !ctx.quiet {
+            println!(
+                "{}",
+                i18n::t_args("stacking-no-commits", &[("start", &start), ("end", &end)])
+            );
+        }
+        return Ok(());
+    };
+    let commits = oids
+        .into_iter()
+        .map(|oid| {
+            let summary = git.commit_info(&oid)?.summary;
+            Ok(RangeCommit { oid, summary })
+        })
+        .collect::<CliResult<Vec<_>>>()?;
+    let taken = git
+        .list_branches()?
+        .into_iter()
+        .map(|b| b.to_string())
+        .collect();
+    let names = branch_names(&commits, &taken)?;
+
+    let cmd = StackCreateCommand {
+        start: start.clone(),
+        base,
+        commits,
+        names,
+    };
+    let output = run_command(&cmd, &git, ctx).map_err(CliError::from)?;
+    let created = output.into_result().map_err(CliError::failed)?;
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args("stacking-created-branches", &[("count", &created.len())])
+        );
+        let mut parent = &start;
+        for branch in &created {
+            println!(
+                "  {}",
+                i18n::t_args(
+                    "stacking-parent",
+                    &[("branch", &branch), ("parent", &parent)],
+                )
+            );
+            parent = branch;
+        }
+    }
+    Ok(())
+}
+
+/// Split `<start>..<end>` into its two sides; `<end>` defaults to `HEAD`.
+fn parse_range(range: &str) -> CliResult<(&str, &str)> {
+    if range.contains("...") {
+        return Err(CliError::usage(format!(
+            "Use a two-dot range (<start>..<end>), not '{}'.",
+            range
+        )));
+    }
+    match range.split_once("..") {
+        Some(("", _)) => Err(CliError::usage(
+            "The range needs a start branch: <start>..<end>.",
+        )),
+        Some((start, "")) => Ok((start, "HEAD")),
+        Some((start, end)) => Ok((start, end)),
+        None => Err(CliError::usage(format!(
+            "Expected a commit range like main..HEAD, got '{}'.",
+            range
+        ))),
+    }
+}
+
+/// `git rev-list --reverse --parents` output for `range`, oldest first.
+fn rev_list(cwd: &Path, range: &str) -> CliResult<Vec<String>> {
+    let output = ProcessCommand::new("git")
+        .args(["rev-list", "--reverse", "--parents", range, "--"])
+        .current_dir(cwd)
+        .output()
+        .context("Failed to list commits")?;
+    if !output.status.success() {
+        return Err(CliError::usage(format!(
+            "Invalid range '{}': {}",
+            range,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Check that rev-list `lines` (`<oid> <parents...>`, oldest first) form a
+/// single line of commits.
+///
+/// Returns the parent of the first commit and the commits in order, or
+/// `None` and no commits for an empty range.
+fn linear_commits(lines: &[String]) -> CliResult<(Option<Oid>, Vec<Oid>)> {
+    let mut base = None;
+    let mut commits: Vec<Oid> = Vec::new();
+    for line in lines {
+        let mut fields = line.split_whitespace();
+        let oid = Oid::new(fields.next().unwrap_or_default())?;
+        let parents: Vec<&str> = fields.collect();
+        let [parent] = parents[..] else {
+            return Err(CliError::usage(format!(
+                "Commit {} has {} parents. Only a linear run of commits can become a stack.",
+                oid.short(7),
+                parents.len()
+            )));
+        };
+        match commits.last() {
+            Some(previous) if previous.as_str() != parent => {
+                return Err(CliError::usage(format!(
+                    "Commit {} does not follow {} in the range. Only a linear run of commits \
+                 can become a stack.",
+                    oid.short(7),
+                    previous.short(7)
+                )))
+            }
+            Some(_) => {}
+            None => base = Some(Oid::new(parent)?),
+        }
+        commits.push(oid);
+    }
+    Ok((base, commits))
+}
+
+/// A branch name per commit, from its subject, avoiding `taken` names and
+/// each other.
+fn branch_names(commits: &[RangeCommit], taken: &BTreeSet<String>) -> CliResult<Vec<BranchName>> {
+    let mut used = taken.clone();
+    commits
+        .iter()
+        .map(|commit| {
+            let slug = slugify(&commit.summary);
+            let stem = if slug.is_empty() {
+                format!("commit-{}", commit.oid.short(7))
+            } else {
+                slug
+            };
+            let mut name = stem.clone();
+            let mut n = 2;
+            while used.contains(&name) {
+                name = format!("{}-{}", stem, n);
+                n += 1;
+            }
+            used.insert(name.clone());
+            Ok(
+                BranchName::new(&name)
+                    .with_context(|| format!("Invalid branch name '{}'", name))?,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oid(c: char) -> Oid {
+        Oid::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    fn commit(c: char, summary: &str) -> RangeCommit {
+        RangeCommit {
+            oid: oid(c),
+            summary: summary.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_range_defaults_end_to_head() {
+        assert_eq!(parse_range("main..big").unwrap(), ("main", "big"));
+        assert_eq!(parse_range("main..").unwrap(), ("main", "HEAD"));
+        assert!(parse_range("..big").is_err());
+        assert!(parse_range("main...big").is_err());
+        assert!(parse_range("main").is_err());
+    }
+
+    #[test]
+    fn linear_commits_returns_base_and_order() {
+        let lines = vec![
+            format!("{} {}", oid('b'), oid('a')),
+            format!("{} {}", oid('c'), oid('b')),
+        ];
+        let (base, commits) = linear_commits(&lines).unwrap();
+        assert_eq!(base, Some(oid('a')));
+        assert_eq!(commits, vec![oid('b'), oid('c')]);
+
+        assert_eq!(linear_commits(&[]).unwrap(), (None, vec![]));
+    }
+
+    #[test]
+    fn linear_commits_rejects_merges_and_gaps() {
+        let merge = vec![format!("{} {} {}", oid('c'), oid('a'), oid('b'))];
+        assert!(linear_commits(&merge).is_err());
+
+        let gap = vec![
+            format!("{} {}", oid('b'), oid('a')),
+            format!("{} {}", oid('d'), oid('c')),
+        ];
+        assert!(linear_commits(&gap).is_err());
+    }
+
+    #[test]
+    fn branch_names_avoid_collisions() {
+        let commits = [
+            commit('a', "Add parser"),
+            commit('b', "Add parser"),
+            commit('c', "!!!"),
+        ];
+        let taken = BTreeSet::from(["add-parser".to_string()]);
+        let names: Vec<String> = branch_names(&commits, &taken)
+            .unwrap()
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["add-parser-2", "add-parser-3", "commit-ccccccc"]
+        );
+    }
+}
@@ -8,6 +8,13 @@
 //! commands to keep stack comments in PR descriptions up to date. The stack
 //! comment shows the full context of stacked PRs with visual indicators.
 //!
+//! Existing PR bodies are only ever changed between the stack comment
+//! markers. The stack comment last written to each PR is remembered
+//! ([`crate::forge::pr_body_state`]) so that edits the user made inside the
+//! stack section are merged rather than overwritten. A PR whose edits
+//! conflict with the update, or whose markers are damaged, is left alone
+//! with a warning.
+//!
 //! Per CLAUDE.md principles:
 //! - **Purity**: Core generation logic is in `ui::stack_comment` (pure functions)
 //! - **Reuse**: This module wraps that logic for use by multiple commands
@@ -21,14 +28,20 @@
 //! update_pr_stack_comment(&forge, &snapshot, &branch, quiet).await?;
 //! ```
 
-use anyhow::Result;
+use std::collections::HashMap;
 
-use crate::core::metadata::schema::PrState;
+use crate::cli::error::CliResult;
+use crate::core::config::schema::PrTemplateConfig;
+use crate::core::metadata::schema::{IssueLink, PrState};
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
 use crate::engine::scan::RepoSnapshot;
+use crate::forge::pr_body_state::PrBodyState;
 use crate::forge::{Forge, UpdatePrRequest};
+use crate::ui::i18n;
 use crate::ui::stack_comment::{
-    generate_stack_comment, merge_stack_comment, StackBranchInfo, StackCommentInput, StackPosition,
+    generate_stack_comment, merge_stack_comment, update_stack_comment, StackBranchInfo,
+    StackCommentInput, StackCommentUpdate, StackPosition,
 };
 
 /// Build stack comment input for a branch.
@@ -261,6 +274,230 @@ pub fn generate_merged_body(
     merge_stack_comment(existing_body, &stack_comment)
 }
 
+/// Template file checked into the repository, relative to its root.
+///
+/// See [`PrTemplateConfig::from_markdown`] for the format.
+pub const PR_TEMPLATE_FILE: &str = ".lattice/templates/pr.md";
+
+/// Values substituted into PR title and body templates.
+///
+/// See [`PrTemplateConfig`] for what each placeholder means.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrTemplateVars {
+    /// `{branch}`
+    pub branch: String,
+    /// `{parent}`
+    pub parent: String,
+    /// `{parent_pr}`
+    pub parent_pr: String,
+    /// `{description}`
+    pub description: String,
+    /// `{commits}`
+    pub commits: String,
+    /// `{stack_position}`
+    pub stack_position: String,
+}
+
+impl PrTemplateVars {
+    /// Collect the values for a tracked branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - Repository snapshot
+    /// * `branch` - The branch the PR is for
+    /// * `created` - PRs created earlier in this submit, which are not yet in
+    ///   the snapshot's metadata
+    /// * `commits` - Subjects of the branch's commits, oldest first
+    pub fn for_branch(
+        snapshot: &RepoSnapshot,
+        branch: &BranchName,
+        created: &HashMap<BranchName, u64>,
+        commits: &[String],
+    ) -> Option<Self> {
+        let metadata = &snapshot.metadata.get(branch)?.metadata;
+        let parent = metadata.parent.name();
+        let parent_branch = BranchName::new(parent).ok();
+        let parent_pr = parent_branch
+            .as_ref()
+            .and_then(|p| snapshot.metadata.get(p))
+            .and_then(|p| p.metadata.pr.number())
+            .or_else(|| parent_branch.as_ref().and_then(|p| created.get(p).copied()))
+            .map(|number| format!("#{}", number))
+            .unwrap_or_else(|| parent.to_string());
+
+        // Depth counts tracked branches from the bottom; the stack is as
+        // tall as the deepest branch above this one
+        let depth = |b: &BranchName| {
+            1 + snapshot
+                .graph
+                .ancestors(b)
+                .iter()
+                .filter(|a| snapshot.is_tracked(a))
+                .count()
+        };
+        let position = depth(branch);
+        let height = snapshot
+            .graph
+            .descendants(branch)
+            .iter()
+            .map(depth)
+            .max()
+            .unwrap_or(position)
+            .max(position);
+
+        Some(Self {
+            branch: branch.to_string(),
+            parent: parent.to_string(),
+            parent_pr,
+            description: metadata.description.clone().unwrap_or_default(),
+            commits: commits
+                .iter()
+                .map(|subject| format!("- {}", subject))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            stack_position: format!("{}/{}", position, height),
+        })
+    }
+
+    /// Fill in a template's placeholders.
+    ///
+    /// Substituted text is never scanned again, so descriptions and commit
+    /// subjects containing `{...}` come through verbatim. Unknown
+    /// placeholders are left as they are.
+    pub fn render(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            let value = after.find('}').and_then(|close| {
+                let value = match &after[..close] {
+                    "branch" => &self.branch,
+                    "parent" => &self.parent,
+                    "parent_pr" => &self.parent_pr,
+                    "description" => &self.description,
+                    "commits" => &self.commits,
+                    "stack_position" => &self.stack_position,
+                    _ => return None,
+                };
+                Some((value, close))
+            });
+            match value {
+                Some((value, close)) => {
+                    out.push_str(value);
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// The PR templates in effect: the repo's `pr_template`, with anything it
+/// leaves unset taken from [`PR_TEMPLATE_FILE`].
+pub fn pr_templates(snapshot: &RepoSnapshot) -> PrTemplateConfig {
+    let configured = snapshot
+        .repo_config
+        .as_ref()
+        .and_then(|config| config.pr_template.clone())
+        .unwrap_or_default();
+    let file = snapshot
+        .info
+        .work_dir
+        .as_ref()
+        .and_then(|dir| std::fs::read_to_string(dir.join(PR_TEMPLATE_FILE)).ok())
+        .map(|text| PrTemplateConfig::from_markdown(&text))
+        .unwrap_or_default();
+    configured.or(file)
+}
+
+/// Generate the title for a new PR.
+///
+/// The `title` template is filled in if there is one; its first line is the
+/// title. Without one, or if it renders empty, the title is the branch name.
+pub fn new_pr_title(
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    created: &HashMap<BranchName, u64>,
+    commits: &[String],
+) -> String {
+    pr_templates(snapshot)
+        .title
+        .zip(PrTemplateVars::for_branch(
+            snapshot, branch, created, commits,
+        ))
+        .map(|(template, vars)| vars.render(&template))
+        .and_then(|title| title.lines().next().map(|line| line.trim().to_string()))
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| branch.to_string())
+}
+
+/// Generate the body for a new PR, before its stack comment is added.
+///
+/// The body template for the branch's position is filled in: `bottom` when
+/// the parent is not a tracked branch, `stacked` otherwise. Without a
+/// template the body is the branch description.
+///
+/// # Arguments
+///
+/// * `snapshot` - Repository snapshot
+/// * `branch` - The branch the PR is for
+/// * `created` - PRs created earlier in this submit, which are not yet in
+///   the snapshot's metadata
+/// * `commits` - Subjects of the branch's commits, oldest first
+///
+/// # Returns
+///
+/// The body, or `None` if it would be empty.
+pub fn new_pr_body(
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    created: &HashMap<BranchName, u64>,
+    commits: &[String],
+) -> Option<String> {
+    let vars = PrTemplateVars::for_branch(snapshot, branch, created, commits)?;
+    let bottom = BranchName::new(&vars.parent)
+        .ok()
+        .is_none_or(|parent| !snapshot.metadata.contains_key(&parent));
+
+    let body = match pr_templates(snapshot).for_position(bottom) {
+        Some(template) => vars.render(template),
+        None => vars.description,
+    };
+
+    let body = body.trim();
+    (!body.is_empty()).then(|| body.to_string())
+}
+
+/// Append `Closes #N` for the issue a branch was created from.
+///
+/// Left unchanged when there is no issue, or the body already closes it.
+pub fn with_closing_reference(body: Option<String>, issue: Option<&IssueLink>) -> Option<String> {
+    let Some(issue) = issue else {
+        return body;
+    };
+    let reference = format!("Closes #{}", issue.number);
+    let closes = |body: &str| {
+        let lower = body.to_lowercase();
+        let reference = reference.to_lowercase();
+        lower
+            .match_indices(&reference)
+            .any(|(i, _)| !lower[i + reference.len()..].starts_with(|c: char| c.is_ascii_digit()))
+    };
+    match body {
+        Some(body) if closes(&body) => Some(body),
+        Some(body) if !body.trim().is_empty() => {
+            Some(format!("{}\n\n{}", body.trim_end(), reference))
+        }
+        _ => Some(reference),
+    }
+}
+
 /// Generate a merged PR body with updated stack comment, fetching PR info from forge.
 ///
 /// Use this variant after creating PRs when metadata hasn't been persisted yet.
@@ -285,10 +522,141 @@ pub async fn generate_merged_body_from_forge(
     merge_stack_comment(existing_body, &stack_comment)
 }
 
+/// Merge `stack_comment` into PR `number`'s body without clobbering user edits.
+///
+/// Returns the new body, or `None` if the PR must be left alone: the
+/// user's edits to the stack section conflict with the update, or its
+/// markers are damaged. Both cases print a warning; damaged markers are
+/// also recorded for `lattice doctor`.
+///
+/// Call [`record_stack_comment`] once the body has been written.
+///
+/// # Arguments
+///
+/// * `paths` - Repository paths, for the PR body state file
+/// * `branch` - The branch the PR is for
+/// * `number` - The PR number
+/// * `existing_body` - The PR's current body (may be None)
+/// * `stack_comment` - The new stack comment
+/// * `quiet` - If true, suppress warnings
+pub fn protected_body(
+    paths: &LatticePaths,
+    branch: &BranchName,
+    number: u64,
+    existing_body: Option<&str>,
+    stack_comment: &str,
+    quiet: bool,
+) -> Option<String> {
+    let mut state = PrBodyState::load(paths);
+    let base = state.written(branch.as_str(), number);
+    match update_stack_comment(existing_body, base, stack_comment) {
+        StackCommentUpdate::Updated(body) => Some(body),
+        StackCommentUpdate::Conflict => {
+            if !quiet {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "comment-warning-edits-stack",
+                        &[("number", &number), ("branch", &branch)],
+                    )
+                );
+                eprintln!("  {}", i18n::t("comment-remove-stack-section"));
+            }
+            None
+        }
+        StackCommentUpdate::CorruptMarkers(problem) => {
+            if !quiet {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "comment-warning-updating-pr",
+                        &[
+                            ("number", &number),
+                            ("branch", &branch),
+                            ("describe", &problem.describe()),
+                        ],
+                    )
+                );
+            }
+            state.record_corrupt(branch.as_str(), number, problem.describe());
+            state.save(paths);
+            None
+        }
+    }
+}
+
+/// Remember that `stack_comment` was written to `branch`'s PR `number`.
+///
+/// The next [`protected_body`] for the PR merges against it.
+pub fn record_stack_comment(
+    paths: &LatticePaths,
+    branch: &BranchName,
+    number: u64,
+    stack_comment: &str,
+) {
+    let mut state = PrBodyState::load(paths);
+    state.record_written(branch.as_str(), number, stack_comment);
+    state.save(paths);
+}
+
+/// Write `stack_comment` into PR `number`'s body, protecting user edits.
+///
+/// Returns whether the PR was updated.
+async fn write_stack_comment(
+    forge: &dyn Forge,
+    paths: &LatticePaths,
+    branch: &BranchName,
+    number: u64,
+    existing_body: Option<&str>,
+    stack_comment: &str,
+    quiet: bool,
+) -> bool {
+    let Some(new_body) = protected_body(paths, branch, number, existing_body, stack_comment, quiet)
+    else {
+        return false;
+    };
+
+    let update_req = UpdatePrRequest {
+        number,
+        title: None,
+        body: Some(new_body),
+        base: None,
+    };
+
+    match forge.update_pr(update_req).await {
+        Ok(_) => {
+            record_stack_comment(paths, branch, number, stack_comment);
+            if !quiet {
+                println!(
+                    "  {}",
+                    i18n::t_args(
+                        "comment-updated-stack-comment",
+                        &[("number", &number), ("branch", &branch)],
+                    )
+                );
+            }
+            true
+        }
+        Err(e) => {
+            if !quiet {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "comment-warning-could-update",
+                        &[("number", &number), ("error", &e)],
+                    )
+                );
+            }
+            false
+        }
+    }
+}
+
 /// Update the stack comment for a single PR.
 ///
 /// This fetches the current PR body, generates an updated stack comment,
-/// merges it with the existing body, and updates the PR.
+/// merges it with the existing body (see [`protected_body`]), and updates
+/// the PR.
 ///
 /// # Arguments
 ///
@@ -306,7 +674,7 @@ pub async fn update_pr_stack_comment(
     snapshot: &RepoSnapshot,
     branch: &BranchName,
     quiet: bool,
-) -> Result<bool> {
+) -> CliResult<bool> {
     // Get PR number from metadata
     let pr_number = match snapshot.metadata.get(branch) {
         Some(scanned) => match &scanned.metadata.pr {
@@ -322,42 +690,33 @@ pub async fn update_pr_stack_comment(
         Err(e) => {
             if !quiet {
                 eprintln!(
-                    "  Warning: Could not fetch PR #{} for '{}': {}",
-                    pr_number, branch, e
+                    "  {}",
+                    i18n::t_args(
+                        "comment-warning-could-fetch",
+                        &[
+                            ("pr_number", &pr_number),
+                            ("branch", &branch),
+                            ("error", &e),
+                        ],
+                    )
                 );
             }
             return Ok(false);
         }
     };
 
-    // Generate merged body
-    let new_body = generate_merged_body(existing_body.as_deref(), snapshot, branch);
-
-    // Update PR
-    let update_req = UpdatePrRequest {
-        number: pr_number,
-        title: None,
-        body: Some(new_body),
-        base: None,
-    };
-
-    match forge.update_pr(update_req).await {
-        Ok(_) => {
-            if !quiet {
-                println!("  Updated stack comment for PR #{} ({})", pr_number, branch);
-            }
-            Ok(true)
-        }
-        Err(e) => {
-            if !quiet {
-                eprintln!(
-                    "  Warning: Could not update stack comment for PR #{}: {}",
-                    pr_number, e
-                );
-            }
-            Ok(false)
-        }
-    }
+    let stack_comment = generate_stack_comment_for_branch(snapshot, branch);
+    let paths = LatticePaths::from_repo_info(&snapshot.info);
+    Ok(write_stack_comment(
+        forge,
+        &paths,
+        branch,
+        pr_number,
+        existing_body.as_deref(),
+        &stack_comment,
+        quiet,
+    )
+    .await)
 }
 
 /// Update stack comments for all PRs in a set of branches.
@@ -380,7 +739,7 @@ pub async fn update_stack_comments_for_branches(
     snapshot: &RepoSnapshot,
     branches: &[BranchName],
     quiet: bool,
-) -> Result<usize> {
+) -> CliResult<usize> {
     let mut updated_count = 0;
 
     for branch in branches {
@@ -412,7 +771,8 @@ pub async fn update_stack_comments_for_branches_from_forge(
     snapshot: &RepoSnapshot,
     branches: &[BranchName],
     quiet: bool,
-) -> Result<usize> {
+) -> CliResult<usize> {
+    let paths = LatticePaths::from_repo_info(&snapshot.info);
     let mut updated_count = 0;
 
     for branch in branches {
@@ -422,7 +782,13 @@ pub async fn update_stack_comments_for_branches_from_forge(
             Ok(None) => continue, // No PR for this branch
             Err(e) => {
                 if !quiet {
-                    eprintln!("  Warning: Could not find PR for '{}': {}", branch, e);
+                    eprintln!(
+                        "  {}",
+                        i18n::t_args(
+                            "comment-warning-could-find",
+                            &[("branch", &branch), ("error", &e)],
+                        )
+                    );
                 }
                 continue;
             }
@@ -434,42 +800,32 @@ pub async fn update_stack_comments_for_branches_from_forge(
             Err(e) => {
                 if !quiet {
                     eprintln!(
-                        "  Warning: Could not fetch PR #{} for '{}': {}",
-                        pr.number, branch, e
+                        "  {}",
+                        i18n::t_args(
+                            "comment-warning-could-fetch-pr",
+                            &[("number", &pr.number), ("branch", &branch), ("error", &e)],
+                        )
                     );
                 }
                 continue;
             }
         };
 
-        // Generate merged body using forge-based lookup
-        let new_body =
-            generate_merged_body_from_forge(forge, existing_body.as_deref(), snapshot, branch)
-                .await;
-
-        // Update PR
-        let update_req = UpdatePrRequest {
-            number: pr.number,
-            title: None,
-            body: Some(new_body),
-            base: None,
-        };
-
-        match forge.update_pr(update_req).await {
-            Ok(_) => {
-                if !quiet {
-                    println!("  Updated stack comment for PR #{} ({})", pr.number, branch);
-                }
-                updated_count += 1;
-            }
-            Err(e) => {
-                if !quiet {
-                    eprintln!(
-                        "  Warning: Could not update stack comment for PR #{}: {}",
-                        pr.number, e
-                    );
-                }
-            }
+        // Generate stack comment using forge-based lookup
+        let stack_comment =
+            generate_stack_comment_for_branch_from_forge(forge, snapshot, branch).await;
+        if write_stack_comment(
+            forge,
+            &paths,
+            branch,
+            pr.number,
+            existing_body.as_deref(),
+            &stack_comment,
+            quiet,
+        )
+        .await
+        {
+            updated_count += 1;
         }
     }
 
@@ -479,13 +835,13 @@ pub async fn update_stack_comments_for_branches_from_forge(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::config::schema::RepoConfig;
     use crate::core::graph::StackGraph;
-    use crate::core::metadata::schema::BranchMetadataV1;
+    use crate::core::metadata::schema::BranchMetadataV2;
     use crate::core::types::Oid;
     use crate::engine::health::RepoHealthReport;
     use crate::engine::scan::{compute_fingerprint, ScannedMetadata};
     use crate::git::{GitState, RepoInfo, WorktreeStatus};
-    use std::collections::HashMap;
 
     fn make_test_snapshot() -> RepoSnapshot {
         RepoSnapshot {
@@ -497,6 +853,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: Some(BranchName::new("current").unwrap()),
             branches: HashMap::new(),
             metadata: HashMap::new(),
@@ -520,7 +877,7 @@ mod tests {
         let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
 
         let mut metadata =
-            BranchMetadataV1::new(branch.clone(), parent_branch.clone(), oid.clone());
+            BranchMetadataV2::new(branch.clone(), parent_branch.clone(), oid.clone());
 
         if let Some(num) = pr_number {
             metadata.pr = PrState::Linked {
@@ -680,4 +1037,255 @@ mod tests {
         assert!(result.contains("feature"));
         assert!(!result.contains("old"));
     }
+
+    fn with_templates(snapshot: &mut RepoSnapshot) {
+        snapshot.repo_config = Some(RepoConfig {
+            pr_template: Some(PrTemplateConfig {
+                title: Some("[{stack_position}] {branch}".to_string()),
+                bottom: Some("## Context\n\n{description}".to_string()),
+                stacked: Some("Depends on {parent_pr} ({parent}).\n\n{description}".to_string()),
+            }),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn new_pr_body_uses_description_without_template() {
+        let mut snapshot = make_test_snapshot();
+        add_tracked_branch(&mut snapshot, "feature", "main", None);
+        let feature = BranchName::new("feature").unwrap();
+        assert_eq!(new_pr_body(&snapshot, &feature, &HashMap::new(), &[]), None);
+
+        snapshot
+            .metadata
+            .get_mut(&feature)
+            .unwrap()
+            .metadata
+            .description = Some("Adds X.".to_string());
+        assert_eq!(
+            new_pr_body(&snapshot, &feature, &HashMap::new(), &[]).as_deref(),
+            Some("Adds X.")
+        );
+    }
+
+    #[test]
+    fn new_pr_body_picks_template_by_position() {
+        let mut snapshot = make_test_snapshot();
+        with_templates(&mut snapshot);
+        add_tracked_branch(&mut snapshot, "a", "main", Some(10));
+        add_tracked_branch(&mut snapshot, "b", "a", None);
+        let a = BranchName::new("a").unwrap();
+        let b = BranchName::new("b").unwrap();
+        snapshot.metadata.get_mut(&a).unwrap().metadata.description =
+            Some("Adds {parent}.".to_string());
+
+        let bottom = new_pr_body(&snapshot, &a, &HashMap::new(), &[]).unwrap();
+        assert_eq!(bottom, "## Context\n\nAdds {parent}.");
+
+        let stacked = new_pr_body(&snapshot, &b, &HashMap::new(), &[]).unwrap();
+        assert_eq!(stacked, "Depends on #10 (a).");
+    }
+
+    #[test]
+    fn new_pr_body_uses_prs_created_this_run() {
+        let mut snapshot = make_test_snapshot();
+        with_templates(&mut snapshot);
+        add_tracked_branch(&mut snapshot, "a", "main", None);
+        add_tracked_branch(&mut snapshot, "b", "a", None);
+        let a = BranchName::new("a").unwrap();
+        let b = BranchName::new("b").unwrap();
+
+        let body = new_pr_body(&snapshot, &b, &HashMap::new(), &[]).unwrap();
+        assert_eq!(body, "Depends on a (a).");
+
+        let created = HashMap::from([(a, 12)]);
+        let body = new_pr_body(&snapshot, &b, &created, &[]).unwrap();
+        assert_eq!(body, "Depends on #12 (a).");
+    }
+
+    #[test]
+    fn new_pr_title_uses_stack_position() {
+        let mut snapshot = make_test_snapshot();
+        add_tracked_branch(&mut snapshot, "a", "main", None);
+        add_tracked_branch(&mut snapshot, "b", "a", None);
+        add_tracked_branch(&mut snapshot, "c", "b", None);
+        let a = BranchName::new("a").unwrap();
+        let b = BranchName::new("b").unwrap();
+        assert_eq!(new_pr_title(&snapshot, &b, &HashMap::new(), &[]), "b");
+
+        with_templates(&mut snapshot);
+        assert_eq!(new_pr_title(&snapshot, &a, &HashMap::new(), &[]), "[1/3] a");
+        assert_eq!(new_pr_title(&snapshot, &b, &HashMap::new(), &[]), "[2/3] b");
+    }
+
+    #[test]
+    fn template_vars_render_once() {
+        let vars = PrTemplateVars {
+            branch: "feature".to_string(),
+            description: "Uses {branch} literally".to_string(),
+            commits: "- Add {x}".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            vars.render("{branch}: {description}\n{commits}\n{unknown} {"),
+            "feature: Uses {branch} literally\n- Add {x}\n{unknown} {"
+        );
+    }
+
+    #[test]
+    fn new_pr_body_lists_commits() {
+        let mut snapshot = make_test_snapshot();
+        snapshot.repo_config = Some(RepoConfig {
+            pr_template: Some(PrTemplateConfig {
+                bottom: Some("## Changes\n\n{commits}".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        add_tracked_branch(&mut snapshot, "a", "main", None);
+        let a = BranchName::new("a").unwrap();
+
+        let commits = ["Add parser".to_string(), "Add lexer".to_string()];
+        let body = new_pr_body(&snapshot, &a, &HashMap::new(), &commits).unwrap();
+        assert_eq!(body, "## Changes\n\n- Add parser\n- Add lexer");
+    }
+
+    #[test]
+    fn template_file_fills_in_for_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let templates = dir.path().join(".lattice/templates");
+        std::fs::create_dir_all(&templates).unwrap();
+        std::fs::write(
+            templates.join("pr.md"),
+            "# {branch} ({stack_position})\n\nBody of {branch}\n",
+        )
+        .unwrap();
+
+        let mut snapshot = make_test_snapshot();
+        snapshot.info.work_dir = Some(dir.path().to_path_buf());
+        add_tracked_branch(&mut snapshot, "a", "main", None);
+        let a = BranchName::new("a").unwrap();
+        assert_eq!(new_pr_title(&snapshot, &a, &HashMap::new(), &[]), "a (1/1)");
+        assert_eq!(
+            new_pr_body(&snapshot, &a, &HashMap::new(), &[]).as_deref(),
+            Some("Body of a")
+        );
+
+        // Config templates take precedence
+        with_templates(&mut snapshot);
+        assert_eq!(new_pr_title(&snapshot, &a, &HashMap::new(), &[]), "[1/1] a");
+    }
+
+    #[test]
+    fn closing_reference_is_added_once() {
+        let issue = IssueLink {
+            number: 7,
+            url: "https://github.com/org/repo/issues/7".to_string(),
+        };
+        assert_eq!(
+            with_closing_reference(None, Some(&issue)).as_deref(),
+            Some("Closes #7")
+        );
+        assert_eq!(
+            with_closing_reference(Some("Adds X.\n".to_string()), Some(&issue)).as_deref(),
+            Some("Adds X.\n\nCloses #7")
+        );
+        assert_eq!(
+            with_closing_reference(Some("Fix.\n\ncloses #7".to_string()), Some(&issue)).as_deref(),
+            Some("Fix.\n\ncloses #7")
+        );
+        assert_eq!(
+            with_closing_reference(Some("Closes #70".to_string()), Some(&issue)).as_deref(),
+            Some("Closes #70\n\nCloses #7")
+        );
+        assert_eq!(
+            with_closing_reference(Some("Adds X.".to_string()), None).as_deref(),
+            Some("Adds X.")
+        );
+    }
+
+    // =============================================================
+    // Protected PR body updates
+    // =============================================================
+
+    fn linked_pr(number: u64, head: &str, body: &str) -> crate::forge::PullRequest {
+        crate::forge::PullRequest {
+            number,
+            url: format!("https://github.com/org/repo/pull/{}", number),
+            state: crate::forge::PrState::Open,
+            is_draft: false,
+            head: head.to_string(),
+            base: "main".to_string(),
+            title: "Feature".to_string(),
+            body: Some(body.to_string()),
+            node_id: None,
+            author: None,
+        }
+    }
+
+    fn snapshot_in(dir: &std::path::Path) -> RepoSnapshot {
+        let mut snapshot = make_test_snapshot();
+        snapshot.info.git_dir = dir.to_path_buf();
+        snapshot.info.common_dir = dir.to_path_buf();
+        snapshot
+    }
+
+    #[tokio::test]
+    async fn update_keeps_user_edits_in_stack_section() {
+        use crate::forge::mock::MockForge;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut snapshot = snapshot_in(temp.path());
+        add_tracked_branch(&mut snapshot, "feature", "main", Some(1));
+        let feature = BranchName::new("feature").unwrap();
+        let forge = MockForge::with_prs(vec![linked_pr(1, "feature", "Description")]);
+
+        assert!(update_pr_stack_comment(&forge, &snapshot, &feature, true)
+            .await
+            .unwrap());
+
+        // The user adds a note inside the stack section
+        let body = forge.get_pr_sync(1).unwrap().body.unwrap();
+        let edited = body.replace("### Stack", "### Stack\n\nReview bottom-up, please.");
+        forge
+            .update_pr(UpdatePrRequest {
+                number: 1,
+                title: None,
+                body: Some(edited),
+                base: None,
+            })
+            .await
+            .unwrap();
+
+        // A child PR appears in the stack
+        add_tracked_branch(&mut snapshot, "child", "feature", Some(2));
+        assert!(update_pr_stack_comment(&forge, &snapshot, &feature, true)
+            .await
+            .unwrap());
+
+        let body = forge.get_pr_sync(1).unwrap().body.unwrap();
+        assert!(body.starts_with("Description\n\n"));
+        assert!(body.contains("Review bottom-up, please."));
+        assert!(body.contains("`child`"));
+    }
+
+    #[tokio::test]
+    async fn update_leaves_corrupt_markers_alone() {
+        use crate::forge::mock::MockForge;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut snapshot = snapshot_in(temp.path());
+        add_tracked_branch(&mut snapshot, "feature", "main", Some(1));
+        let feature = BranchName::new("feature").unwrap();
+        let body = "Description\n\n<!-- lattice:stack:start -->\nold";
+        let forge = MockForge::with_prs(vec![linked_pr(1, "feature", body)]);
+
+        assert!(!update_pr_stack_comment(&forge, &snapshot, &feature, true)
+            .await
+            .unwrap());
+        assert_eq!(forge.get_pr_sync(1).unwrap().body.as_deref(), Some(body));
+
+        let paths = LatticePaths::from_repo_info(&snapshot.info);
+        assert!(PrBodyState::load(&paths).corrupt("feature", 1).is_some());
+    }
 }
@@ -0,0 +1,342 @@
+//! cli::commands::stats
+//!
+//! Show cycle-time breakdowns for a branch from its ledger history.
+//!
+//! # Architecture
+//!
+//! This is a read-only command that implements `ReadOnlyCommand` and uses
+//! `requirements::READ_ONLY`. It flows through `run_readonly_command` to
+//! ensure proper gating.
+//!
+//! # Design
+//!
+//! Branch lifecycle events (`created`, `submitted`, `restacked`, `merged`)
+//! are recorded in the event ledger as they happen. This command folds them
+//! into a timeline and reports:
+//! - Time from creation to first submit
+//! - Time from first submit to merge (review time)
+//! - Total cycle time from creation to merge
+//! - How many times the branch was restacked
+//!
+//! The branch does not need to be tracked (or even exist) any more, so stats
+//! remain available after a merged branch is deleted.
+//!
+//! # Example
+//!
+//! ```bash
+//! # Stats for the current branch
+//! lattice stats
+//!
+//! # Stats for a specific branch, as JSON
+//! lattice stats --branch feature-auth --json
+//! ```
+
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+
+use crate::cli::error::{CliError, CliResult};
+use crate::engine::command::ReadOnlyCommand;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::ledger::{BranchStage, Event, EventLedger, StoredEvent};
+use crate::engine::plan::PlanError;
+use crate::engine::runner::run_readonly_command;
+use crate::engine::Context;
+use crate::git::Git;
+use anyhow::Context as _;
+
+/// Lifecycle timestamps for a single branch.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct BranchTimeline {
+    /// When the branch was created or first tracked.
+    created: Option<DateTime<FixedOffset>>,
+    /// When a PR was first opened.
+    first_submitted: Option<DateTime<FixedOffset>>,
+    /// When the PR was merged.
+    merged: Option<DateTime<FixedOffset>>,
+    /// Number of restacks recorded.
+    restacks: usize,
+    /// Most recent restack.
+    last_restacked: Option<DateTime<FixedOffset>>,
+}
+
+impl BranchTimeline {
+    /// Fold chronologically ordered lifecycle events into a timeline.
+    ///
+    /// The first occurrence of `created`, `submitted`, and `merged` wins;
+    /// events with unparseable timestamps are ignored.
+    fn from_events(events: &[StoredEvent]) -> Self {
+        let mut timeline = Self::default();
+        for stored in events {
+            let Event::BranchLifecycle {
+                stage, timestamp, ..
+            } = &stored.event
+            else {
+                continue;
+            };
+            let Ok(at) = DateTime::parse_from_rfc3339(timestamp) else {
+                continue;
+            };
+            match stage {
+                BranchStage::Created => {
+                    timeline.created.get_or_insert(at);
+                }
+                BranchStage::Submitted => {
+                    timeline.first_submitted.get_or_insert(at);
+                }
+                BranchStage::Restacked => {
+                    timeline.restacks += 1;
+                    timeline.last_restacked = Some(at);
+                }
+                BranchStage::Merged => {
+                    timeline.merged.get_or_insert(at);
+                }
+            }
+        }
+        timeline
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Seconds between two optional timestamps, if both are known.
+fn span_secs(
+    from: Option<DateTime<FixedOffset>>,
+    to: Option<DateTime<FixedOffset>>,
+) -> Option<i64> {
+    Some((to? - from?).num_seconds())
+}
+
+/// Machine-readable stats report (`--json`).
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    branch: String,
+    created_at: Option<String>,
+    first_submitted_at: Option<String>,
+    merged_at: Option<String>,
+    restack_count: usize,
+    last_restacked_at: Option<String>,
+    /// Creation to first submit, in seconds.
+    time_to_submit_secs: Option<i64>,
+    /// First submit to merge, in seconds.
+    review_secs: Option<i64>,
+    /// Creation to merge, in seconds.
+    cycle_secs: Option<i64>,
+}
+
+impl StatsReport {
+    fn new(branch: &str, timeline: &BranchTimeline) -> Self {
+        let rfc3339 = |t: Option<DateTime<FixedOffset>>| t.map(|t| t.to_rfc3339());
+        Self {
+            branch: branch.to_string(),
+            created_at: rfc3339(timeline.created),
+            first_submitted_at: rfc3339(timeline.first_submitted),
+            merged_at: rfc3339(timeline.merged),
+            restack_count: timeline.restacks,
+            last_restacked_at: rfc3339(timeline.last_restacked),
+            time_to_submit_secs: span_secs(timeline.created, timeline.first_submitted),
+            review_secs: span_secs(timeline.first_submitted, timeline.merged),
+            cycle_secs: span_secs(timeline.created, timeline.merged),
+        }
+    }
+}
+
+/// Format a duration in seconds as a compact human-readable string.
+fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3_600;
+    let minutes = (secs % 3_600) / 60;
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        "<1m".to_string()
+    }
+}
+
+/// Render the human-readable report.
+fn format_report(report: &StatsReport) -> String {
+    let span = |secs: Option<i64>| secs.map_or_else(|| "-".to_string(), format_duration);
+    let at = |t: &Option<String>| t.clone().unwrap_or_else(|| "-".to_string());
+
+    let mut out = format!("Branch: {}\n", report.branch);
+    out.push_str(&format!("  Created:          {}\n", at(&report.created_at)));
+    out.push_str(&format!(
+        "  First submitted:  {}\n",
+        at(&report.first_submitted_at)
+    ));
+    out.push_str(&format!("  Merged:           {}\n", at(&report.merged_at)));
+    out.push_str(&format!("  Restacks:         {}\n", report.restack_count));
+    out.push('\n');
+    out.push_str(&format!(
+        "  Time to submit:   {}\n",
+        span(report.time_to_submit_secs)
+    ));
+    out.push_str(&format!(
+        "  Time in review:   {}\n",
+        span(report.review_secs)
+    ));
+    out.push_str(&format!(
+        "  Cycle time:       {}\n",
+        span(report.cycle_secs)
+    ));
+    out
+}
+
+/// Command to show branch cycle-time stats.
+pub struct StatsCommand<'a> {
+    git: &'a Git,
+    branch: Option<&'a str>,
+    json: bool,
+}
+
+impl ReadOnlyCommand for StatsCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::READ_ONLY;
+    type Output = ();
+
+    fn execute(&self, ready: &ReadyContext) -> Result<Self::Output, PlanError> {
+        let branch = match self.branch {
+            Some(b) => b.to_string(),
+            None => ready
+                .snapshot
+                .current_branch
+                .as_ref()
+                .map(|b| b.as_str().to_string())
+                .ok_or_else(|| {
+                    PlanError::InvalidState(
+                        "Not on a branch. Specify one with --branch.".to_string(),
+                    )
+                })?,
+        };
+
+        let events = EventLedger::new(self.git)
+            .lifecycle_events(Some(&branch))
+            .map_err(|e| PlanError::InvalidState(format!("Failed to read event ledger: {}", e)))?;
+        let timeline = BranchTimeline::from_events(&events);
+
+        if timeline.is_empty() && !self.json {
+            return Err(PlanError::InvalidState(format!(
+                "No lifecycle events recorded for branch '{}'.",
+                branch
+            )));
+        }
+
+        let report = StatsReport::new(&branch, &timeline);
+        if self.json {
+            super::print_json(&report)?;
+        } else {
+            print!("{}", format_report(&report));
+        }
+
+        Ok(())
+    }
+}
+
+/// Run the stats command.
+///
+/// # Arguments
+///
+/// * `ctx` - Engine context
+/// * `branch` - Branch to report on (defaults to current)
+/// * `json` - Emit a JSON report instead of text
+pub fn stats(ctx: &Context, branch: Option<&str>, json: bool) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let cmd = StatsCommand {
+        git: &git,
+        branch,
+        json,
+    };
+
+    run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Oid;
+
+    fn stored(stage: BranchStage, timestamp: &str) -> StoredEvent {
+        StoredEvent {
+            commit_oid: Oid::zero(),
+            event: Event::BranchLifecycle {
+                branch: "feature".to_string(),
+                stage,
+                timestamp: timestamp.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn timeline_keeps_first_milestones_and_counts_restacks() {
+        let events = vec![
+            stored(BranchStage::Created, "2026-01-01T00:00:00Z"),
+            stored(BranchStage::Submitted, "2026-01-01T06:00:00Z"),
+            stored(BranchStage::Restacked, "2026-01-02T00:00:00Z"),
+            stored(BranchStage::Submitted, "2026-01-02T01:00:00Z"),
+            stored(BranchStage::Restacked, "2026-01-03T00:00:00Z"),
+            stored(BranchStage::Merged, "2026-01-04T06:00:00Z"),
+        ];
+        let timeline = BranchTimeline::from_events(&events);
+        let report = StatsReport::new("feature", &timeline);
+
+        assert_eq!(report.restack_count, 2);
+        assert_eq!(report.time_to_submit_secs, Some(6 * 3600));
+        assert_eq!(report.review_secs, Some(3 * 86_400));
+        assert_eq!(report.cycle_secs, Some(3 * 86_400 + 6 * 3600));
+        assert_eq!(
+            report.last_restacked_at.as_deref(),
+            Some("2026-01-03T00:00:00+00:00")
+        );
+    }
+
+    #[test]
+    fn unmerged_branch_has_no_review_time() {
+        let events = vec![
+            stored(BranchStage::Created, "2026-01-01T00:00:00Z"),
+            stored(BranchStage::Submitted, "2026-01-01T00:30:00Z"),
+        ];
+        let report = StatsReport::new("feature", &BranchTimeline::from_events(&events));
+
+        assert_eq!(report.time_to_submit_secs, Some(1800));
+        assert_eq!(report.review_secs, None);
+        assert_eq!(report.cycle_secs, None);
+        assert!(format_report(&report).contains("Time in review:   -"));
+    }
+
+    #[test]
+    fn unparseable_timestamps_are_ignored() {
+        let events = vec![stored(BranchStage::Created, "not a time")];
+        assert!(BranchTimeline::from_events(&events).is_empty());
+    }
+
+    #[test]
+    fn format_duration_picks_largest_units() {
+        assert_eq!(format_duration(30), "<1m");
+        assert_eq!(format_duration(5 * 60), "5m");
+        assert_eq!(format_duration(2 * 3600 + 15 * 60), "2h 15m");
+        assert_eq!(format_duration(3 * 86_400 + 4 * 3600), "3d 4h");
+        assert_eq!(format_duration(-10), "<1m");
+    }
+
+    #[test]
+    fn json_report_uses_stable_field_names() {
+        let events = vec![stored(BranchStage::Created, "2026-01-01T00:00:00Z")];
+        let report = StatsReport::new("feature", &BranchTimeline::from_events(&events));
+        let json = serde_json::to_string(&report).unwrap();
+
+        assert!(json.contains("\"branch\":\"feature\""));
+        assert!(json.contains("\"restack_count\":0"));
+        assert!(json.contains("\"cycle_secs\":null"));
+    }
+}
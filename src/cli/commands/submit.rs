@@ -30,14 +30,14 @@
 //! 1. Gate on REMOTE requirements (auth, remote configured)
 //! 2. Check bare repo constraints (require --no-restack, check alignment)
 //! 3. Optionally restack branches
-//! 4. For each branch in stack order:
+//! 4. Push the branches, concurrently (see Parallelism)
+//! 5. For each branch, in waves:
 //!    - Determine PR base (parent branch or trunk)
-//!    - Push if changed (or --always)
 //!    - Create/update PR via forge (with stack comment)
 //!    - Handle draft toggle
 //!    - Request reviewers if specified
-//! 5. Update metadata with PR linkage
-//! 6. Update stack comments for all PRs in stack
+//! 6. Update metadata with PR linkage
+//! 7. Update stack comments for all PRs in stack
 //!
 //! # Example
 //!
@@ -56,25 +56,145 @@
 //!
 //! # Submit from bare repo (requires aligned branches)
 //! lattice submit --no-restack
+//!
+//! # Queue remote work while offline (flushed by `lattice sync`)
+//! lattice submit --offline
+//!
+//! # Pick which branches of the current stack to submit
+//! lattice submit --select
+//! lattice submit --branches feature-a,feature-c
+//!
+//! # Update only the lower part of a stack
+//! lattice submit --update-only --since feature-a
+//!
+//! # Submit every stack that changed
+//! lattice submit --all
 //! ```
-
-use crate::core::metadata::schema::{BaseInfo, FreezeState, FREEZE_REASON_SYNTHETIC_SNAPSHOT};
+//!
+//! # Branch Selection
+//!
+//! `--select` lists the tracked branches of the current stack, bottom-up,
+//! and reads which to submit; `--branches` names them instead, and `--since
+//! <branch>` takes `<branch>` up to the current branch. Either way a
+//! selected branch's PR is based on its parent's, so the tracked ancestors
+//! of every selected branch are submitted too, and the ones added that way
+//! are reported. With `--update-only` the selection is exact: only existing
+//! PRs are updated, and their bases are already on the remote.
+//!
+//! # All Stacks
+//!
+//! `--all` submits every stack in the repository rather than the current
+//! branch's, typically after a repo-wide sync and restack. A stack is
+//! included when one of its branches has no PR yet, or a tip or parent other
+//! than the ones last pushed; those branches are submitted with the tracked
+//! ancestors their PRs are based on. Stacks go one after the other, ordered
+//! by their bottom branch, in a single submit: the trunk fetch and remote
+//! checks happen once, not per stack. The plan is printed per stack first,
+//! and a per-stack summary of created, updated, queued, skipped and failed
+//! branches at the end.
+//!
+//! # Branches Pushed From Elsewhere
+//!
+//! Each push is recorded in the branch metadata (`submitted`). Before
+//! pushing, branches whose remote tip has moved since then are compared
+//! with it ([`crate::core::remote_divergence`]); when the remote has commits
+//! the local branch lacks, submit shows the per-branch counts and asks
+//! whether to keep the local branch, take the remote one, or skip it,
+//! instead of overwriting the remote commits. Without a terminal it stops
+//! before pushing.
+//!
+//! # Default Reviewers
+//!
+//! New PRs request the reviewers given with `--reviewers` and
+//! `--team-reviewers` plus those `[submit.reviewers]` assigns to the branch
+//! ([`crate::core::config::schema::ReviewerRules`]). Path rules are matched
+//! against the branch's own diff, from its recorded base to its tip, so in a
+//! stack each PR requests only the owners of what it changes.
+//!
+//! # Branch Locks
+//!
+//! Branches someone else holds a lock on ([`crate::core::branch_lock`]) are
+//! not submitted: submit stops before pushing anything. When metadata refs
+//! are shared, the remote's locks are fetched first.
+//!
+//! # PR Templates
+//!
+//! New PRs get their title and body from the repo's PR templates
+//! ([`crate::core::config::schema::PrTemplateConfig`], or
+//! `.lattice/templates/pr.md`), filled in with the branch's stack position,
+//! parent PR and commit subjects. Without a title template the title is the
+//! branch name. With `--edit`, each rendered body is opened in `$EDITOR`
+//! before the PR is created; saving it empty creates the PR without a body.
+//!
+//! # Preview
+//!
+//! With `--confirm`, submit first prints the PR graph it is about to
+//! produce, as a tree rooted at each base outside the submitted set: per
+//! branch whether its PR is created, updated or skipped, the base it will
+//! target (and the one it targeted at the last submit, if that changed),
+//! draft status and requested reviewers. Nothing is pushed until the user
+//! accepts it.
+//!
+//! # Parallelism
+//!
+//! Once the stack is restacked, each push is independent, so submit runs up
+//! to `[submit] parallelism` pushes at once (default 4). PR requests run the
+//! same way in waves: a new PR whose parent is getting a new PR too waits
+//! for the parent's wave, since its template may mention the parent's
+//! number; updates to existing PRs all go in the first wave. Output is held
+//! back and printed in stack order, so it reads the same at any
+//! parallelism. With `--edit`, PRs are created one at a time.
+//!
+//! # Offline Mode
+//!
+//! With `--offline`, or when a push or forge call fails for connectivity
+//! reasons, remaining pushes and PR operations are recorded in the pending
+//! queue (see `core::ops::pending`) rather than failing the command.
+//! `--offline` is global, so `lt --offline submit` and `lt submit --offline`
+//! are the same. An offline `--dry-run` lists each existing PR with the
+//! state cached in its metadata and when that state was fetched.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, StreamExt};
+
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::branch_lock::BranchLockStore;
+use crate::core::config::schema::ReviewerRules;
+use crate::core::metadata::schema::{
+    BaseInfo, BranchMetadataV2, FreezeState, SubmitSnapshot, FREEZE_REASON_SYNTHETIC_SNAPSHOT,
+};
 use crate::core::metadata::store::MetadataStore;
 use crate::core::ops::journal::OpId;
-use crate::core::types::{BranchName, Oid};
+use crate::core::ops::pending::{PendingAction, PendingQueue};
+use crate::core::ops::submit_claim::{ClaimGuard, SubmitClaim};
+use crate::core::paths::LatticePaths;
+use crate::core::remote_divergence::{self, Divergence};
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
 use crate::engine::command::{AsyncCommand, CommandOutput, PlanFut};
 use crate::engine::exec::ExecuteResult;
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::ledger::{BranchStage, Event, EventLedger};
 use crate::engine::modes::{ModeError, SubmitMode};
 use crate::engine::plan::{Plan, PlanStep};
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
-use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
-
+use crate::forge::{Forge, PullRequest, Reviewers};
+use crate::git::{DiffStat, Git, LfsPushProblem};
+use crate::ui::hints::{self, Hint};
+use crate::ui::i18n;
+use anyhow::Context as _;
+
+use super::body_generator::{generator_input, run_body_generator};
+use super::cached_pr;
+use super::pending_ops::{is_offline_forge_error, is_offline_git_failure};
 use super::stack_comment_ops::{
-    generate_merged_body, update_stack_comments_for_branches_from_forge,
+    generate_stack_comment_for_branch, new_pr_body, new_pr_title, protected_body,
+    record_stack_comment, update_stack_comments_for_branches_from_forge, with_closing_reference,
 };
+use super::submit_waves::{pr_waves, run_bounded};
 
 // ============================================================================
 // Snapshot Branch Exclusion (Milestone 5.10)
@@ -151,13 +271,16 @@ fn report_excluded_snapshots(excluded: &[BranchName], quiet: bool) {
     }
 
     println!(
-        "Excluding {} snapshot branch(es) from submit scope:",
-        excluded.len()
+        "{}",
+        i18n::t_args(
+            "submit-excluding-snapshot-branch",
+            &[("count", &excluded.len())],
+        )
     );
     for branch in excluded {
         println!("  {}", branch);
     }
-    println!("These branches represent historical snapshots and cannot be submitted.");
+    println!("{}", i18n::t("submit-these-branches-represent"));
     println!();
 }
 
@@ -166,9 +289,9 @@ fn report_excluded_snapshots(excluded: &[BranchName], quiet: bool) {
 /// # Errors
 ///
 /// Returns an error if the current branch is a synthetic snapshot branch.
-fn check_current_branch_not_snapshot(current: &BranchName, snapshot: &RepoSnapshot) -> Result<()> {
+fn check_current_branch_not_snapshot(current: &BranchName, snapshot: &RepoSnapshot) -> CliResult {
     if is_synthetic_snapshot(current, snapshot) {
-        bail!(
+        return Err(CliError::usage(format!(
             "Cannot submit from a snapshot branch ('{}')\n\n\
              Snapshot branches represent historical state from closed PRs and\n\
              cannot be submitted. To work on this code, create a new branch:\n\n\
@@ -176,17 +299,1477 @@ fn check_current_branch_not_snapshot(current: &BranchName, snapshot: &RepoSnapsh
                  lattice track\n\n\
              Then you can submit the new branch.",
             current
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Review Size Estimation
+// ============================================================================
+
+/// Estimated review size for a single branch in the submit set.
+///
+/// The diff is measured from the branch's recorded base to its tip, which is
+/// what reviewers will see in the PR once it targets the parent branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReviewSize {
+    branch: BranchName,
+    stat: DiffStat,
+}
+
+/// Thresholds above which a PR is flagged as painful to review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReviewSizeThresholds {
+    /// Maximum changed lines (insertions + deletions) before warning.
+    max_lines: usize,
+    /// Maximum changed files before warning.
+    max_files: usize,
+}
+
+impl ReviewSizeThresholds {
+    /// Load thresholds from config, falling back to defaults.
+    fn from_config(cwd: Option<&std::path::Path>) -> Self {
+        let config = crate::core::config::Config::load(cwd)
+            .map(|r| r.config)
+            .unwrap_or_default();
+        Self {
+            max_lines: config.submit_review_size_warn_lines(),
+            max_files: config.submit_review_size_warn_files(),
+        }
+    }
+}
+
+/// Compute review sizes for every tracked branch in the submit set.
+///
+/// Untracked branches are skipped; they are not submitted either.
+fn compute_review_sizes(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+) -> CliResult<Vec<ReviewSize>> {
+    let mut sizes = Vec::with_capacity(branches.len());
+
+    for branch in branches {
+        let (Some(entry), Some(tip)) =
+            (snapshot.metadata.get(branch), snapshot.branches.get(branch))
+        else {
+            continue;
+        };
+        let base = Oid::new(&entry.metadata.base.oid)
+            .with_context(|| format!("Invalid base OID for '{}'", branch))?;
+        let stat = git
+            .diff_stat(&base, tip)
+            .with_context(|| format!("Failed to compute diff for '{}'", branch))?;
+        sizes.push(ReviewSize {
+            branch: branch.clone(),
+            stat,
+        });
+    }
+
+    Ok(sizes)
+}
+
+/// Render review sizes as an aligned table.
+fn format_review_size_table(sizes: &[ReviewSize]) -> String {
+    let width = sizes
+        .iter()
+        .map(|s| s.branch.as_str().len())
+        .chain(std::iter::once("Branch".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut out = String::from("Review size:\n");
+    out.push_str(&format!(
+        "  {:<width$}  {:>6}  {:>8}  {:>8}\n",
+        "Branch", "Files", "Added", "Deleted"
+    ));
+    for size in sizes {
+        out.push_str(&format!(
+            "  {:<width$}  {:>6}  {:>8}  {:>8}\n",
+            size.branch.as_str(),
+            size.stat.files_changed,
+            format!("+{}", size.stat.insertions),
+            format!("-{}", size.stat.deletions),
+        ));
+    }
+    out
+}
+
+/// Produce warnings for branches whose size exceeds the thresholds.
+fn review_size_warnings(sizes: &[ReviewSize], thresholds: ReviewSizeThresholds) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for size in sizes {
+        let lines = size.stat.lines_changed();
+        if lines > thresholds.max_lines {
+            warnings.push(format!(
+                "'{}' changes {} lines (threshold {}); consider 'lattice split'",
+                size.branch, lines, thresholds.max_lines
+            ));
+        }
+        if size.stat.files_changed > thresholds.max_files {
+            warnings.push(format!(
+                "'{}' touches {} files (threshold {}); consider 'lattice split'",
+                size.branch, size.stat.files_changed, thresholds.max_files
+            ));
+        }
+    }
+    warnings
+}
+
+/// Print the review size table and any threshold warnings.
+fn report_review_sizes(sizes: &[ReviewSize], thresholds: ReviewSizeThresholds, quiet: bool) {
+    if sizes.is_empty() {
+        return;
+    }
+    if !quiet {
+        print!("{}", format_review_size_table(sizes));
+    }
+    for warning in review_size_warnings(sizes, thresholds) {
+        eprintln!(
+            "{}",
+            i18n::t_args("submit-warning", &[("warning", &warning)])
+        );
+    }
+    if !quiet {
+        println!();
+    }
+}
+
+// ============================================================================
+// Branch Locks
+// ============================================================================
+
+/// Refuse to submit branches another collaborator has locked.
+///
+/// Refreshes the locks from the remote first when metadata refs are shared,
+/// unless offline; a failed fetch falls back to the local copies.
+fn check_branch_locks(
+    git: &Git,
+    ctx: &Context,
+    branches: &[BranchName],
+    offline: bool,
+) -> CliResult {
+    let store = BranchLockStore::new(git);
+    if !offline {
+        let config = crate::core::config::Config::load(ctx.cwd.as_deref())
+            .map(|r| r.config)
+            .unwrap_or_default();
+        if config.sync_metadata_refs() {
+            if let Err(e) = store.fetch(config.remote()) {
+                eprintln!(
+                    "{}",
+                    i18n::t_args("submit-warning-couldn-fetch", &[("error", &e)])
+                );
+            }
+        }
+    }
+
+    let me = git.user_email();
+    match store.blocking(branches, me.as_deref(), &UtcTimestamp::now())? {
+        Some(lock) => Err(lock.held_error().into()),
+        None => Ok(()),
+    }
+}
+
+// ============================================================================
+// Git LFS Push Check
+// ============================================================================
+
+/// A branch whose changes include Git LFS pointer files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LfsBranch {
+    branch: BranchName,
+    /// Number of LFS-tracked files added or modified on the branch.
+    files: usize,
+}
+
+/// Find branches in the submit set that add or modify LFS-tracked files.
+fn find_lfs_branches(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+) -> CliResult<Vec<LfsBranch>> {
+    let mut found = Vec::new();
+    for branch in branches {
+        let (Some(entry), Some(tip)) =
+            (snapshot.metadata.get(branch), snapshot.branches.get(branch))
+        else {
+            continue;
+        };
+        let base = Oid::new(&entry.metadata.base.oid)
+            .with_context(|| format!("Invalid base OID for '{}'", branch))?;
+        let pointers = git
+            .lfs_pointers(&base, tip)
+            .with_context(|| format!("Failed to inspect LFS files for '{}'", branch))?;
+        if !pointers.is_empty() {
+            found.push(LfsBranch {
+                branch: branch.clone(),
+                files: pointers.len(),
+            });
+        }
+    }
+    Ok(found)
+}
+
+/// Explain why LFS objects on `branches` would not be uploaded, if they wouldn't.
+///
+/// `verify` is false for `--no-verify`, which skips the pre-push hook that
+/// uploads LFS objects.
+fn lfs_push_warning(
+    branches: &[LfsBranch],
+    problem: Option<LfsPushProblem>,
+    verify: bool,
+) -> Option<String> {
+    if branches.is_empty() {
+        return None;
+    }
+    let reason = match problem {
+        Some(problem) => problem.description(),
+        None if !verify => "--no-verify skips the pre-push hook that uploads them",
+        None => return None,
+    };
+    let listed: Vec<String> = branches
+        .iter()
+        .map(|b| format!("'{}' ({} file(s))", b.branch, b.files))
+        .collect();
+    Some(format!(
+        "{} contain Git LFS objects that will not be uploaded: {}.\n\
+         The remote may reject the push or serve pointer files instead of content.\n\
+         Run 'git lfs install' in this repository{}.",
+        listed.join(", "),
+        reason,
+        if verify {
+            ""
+        } else {
+            " and submit without --no-verify"
+        }
+    ))
+}
+
+/// Warn before pushing LFS objects that git can't upload.
+///
+/// Only repositories that use LFS are inspected, so others pay nothing.
+fn check_lfs_push(git: &Git, snapshot: &RepoSnapshot, branches: &[BranchName], verify: bool) {
+    if !git.uses_lfs() {
+        return;
+    }
+    let lfs_branches = match find_lfs_branches(git, snapshot, branches) {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!(
+                "{}",
+                i18n::t_args(
+                    "submit-warning-lfs-check",
+                    &[("error", &format!("{:#}", e))],
+                )
+            );
+            return;
+        }
+    };
+    if lfs_branches.is_empty() {
+        return;
+    }
+    if let Some(warning) = lfs_push_warning(&lfs_branches, git.lfs_push_problem(), verify) {
+        eprintln!(
+            "{}",
+            i18n::t_args("submit-warning", &[("warning", &warning)])
         );
     }
+}
+
+// ============================================================================
+// Downstack Check Gate
+// ============================================================================
+
+/// A downstack PR whose required checks are failing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FailingDownstack {
+    branch: BranchName,
+    number: u64,
+    /// Names of the failing required checks.
+    checks: Vec<String>,
+}
+
+/// Select downstack PRs to check before re-pushing.
+///
+/// A branch is re-pushed when it already has a linked PR and its local tip
+/// differs from the remote. Its ancestors with linked PRs whose tips are
+/// unchanged are returned (bottom-up, deduplicated): those PRs keep their
+/// current CI results after this submit. Ancestors that are themselves being
+/// pushed are assumed to carry a fix and are skipped.
+fn downstack_check_candidates(
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    is_changed: impl Fn(&BranchName) -> bool,
+) -> Vec<(BranchName, u64)> {
+    let linked = |branch: &BranchName| {
+        snapshot
+            .metadata
+            .get(branch)
+            .and_then(|s| s.metadata.pr.number())
+    };
+
+    let mut candidates: Vec<(BranchName, u64)> = Vec::new();
+    for target in branches {
+        if linked(target).is_none() || !is_changed(target) {
+            continue;
+        }
+        for ancestor in snapshot.graph.ancestors(target).into_iter().rev() {
+            let Some(number) = linked(&ancestor) else {
+                continue;
+            };
+            if is_changed(&ancestor) || candidates.iter().any(|(b, _)| *b == ancestor) {
+                continue;
+            }
+            candidates.push((ancestor, number));
+        }
+    }
+    candidates
+}
+
+/// Query the forge for failing required checks on each candidate PR.
+///
+/// Forge errors (including forges without check support) are ignored; this
+/// gate is advisory unless explicitly configured to block.
+async fn find_failing_downstack(
+    forge: &dyn crate::forge::Forge,
+    candidates: &[(BranchName, u64)],
+) -> Vec<FailingDownstack> {
+    let mut failing = Vec::new();
+    for (branch, number) in candidates {
+        let Ok(checks) = forge.list_checks(*number).await else {
+            continue;
+        };
+        let names: Vec<String> = checks
+            .into_iter()
+            .filter(|c| c.is_required_failure())
+            .map(|c| c.name)
+            .collect();
+        if !names.is_empty() {
+            failing.push(FailingDownstack {
+                branch: branch.clone(),
+                number: *number,
+                checks: names,
+            });
+        }
+    }
+    failing
+}
+
+/// Describe a failing downstack PR.
+fn format_failing_downstack(failing: &FailingDownstack) -> String {
+    format!(
+        "downstack PR #{} ('{}') has failing required checks: {}",
+        failing.number,
+        failing.branch,
+        failing.checks.join(", ")
+    )
+}
+
+/// Check downstack PRs before re-pushing and warn or refuse on failures.
+///
+/// Refuses (before anything is pushed) when `submit.require_green_downstack`
+/// is enabled; otherwise prints warnings and continues.
+async fn check_downstack_checks(
+    git: &Git,
+    forge: &dyn crate::forge::Forge,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    require_green: bool,
+) -> CliResult {
+    let is_changed = |branch: &BranchName| {
+        let remote_name = snapshot
+            .metadata
+            .get(branch)
+            .map_or("origin", |s| s.metadata.remote_or("origin"));
+        let remote = git
+            .try_resolve_ref(&format!("refs/remotes/{}/{}", remote_name, branch))
+            .ok()
+            .flatten();
+        remote.as_ref() != snapshot.branches.get(branch)
+    };
+    let candidates = downstack_check_candidates(snapshot, branches, is_changed);
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    let failing = find_failing_downstack(forge, &candidates).await;
+    if failing.is_empty() {
+        return Ok(());
+    }
+
+    let lines: Vec<String> = failing.iter().map(format_failing_downstack).collect();
+    if require_green {
+        return Err(CliError::conflict(format!(
+            "Refusing to re-push over failing downstack checks:\n  {}\n\n\
+             Fix the bottom of the stack first (submit.require_green_downstack is enabled).",
+            lines.join("\n  ")
+        )));
+    }
+    for line in &lines {
+        eprintln!("{}", i18n::t_args("submit-warning", &[("warning", &line)]));
+    }
+    eprintln!("  {}", i18n::t("submit-fix-bottom-stack"));
     Ok(())
 }
 
+// ============================================================================
+// Remote Divergence
+// ============================================================================
+
+/// How to reconcile a branch that was pushed from somewhere else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reconcile {
+    /// Push the local branch over the remote one
+    KeepLocal,
+    /// Reset the local branch to the remote tip and don't push it
+    TakeRemote,
+    /// Leave both alone for this submit
+    Skip,
+    /// Stop before anything is pushed
+    Abort,
+}
+
+impl Reconcile {
+    /// Parse an answer to the reconcile prompt.
+    fn parse(answer: &str) -> Option<Self> {
+        match answer.trim().to_lowercase().as_str() {
+            "k" | "keep" => Some(Reconcile::KeepLocal),
+            "t" | "take" => Some(Reconcile::TakeRemote),
+            "s" | "skip" => Some(Reconcile::Skip),
+            "a" | "abort" | "" => Some(Reconcile::Abort),
+            _ => None,
+        }
+    }
+}
+
+/// A branch whose remote copy has commits the local branch lacks.
+#[derive(Debug, Clone)]
+struct DivergedBranch {
+    branch: BranchName,
+    remote: String,
+    remote_tip: Oid,
+    divergence: Divergence,
+}
+
+/// Compare a tracked branch with its remote tip.
+///
+/// Errors count as no divergence: this check only warns, and a push that
+/// really conflicts still fails on its own.
+pub(crate) fn branch_divergence(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    remote_tip: &Oid,
+    trunk_tip: &Oid,
+) -> Option<Divergence> {
+    let scanned = snapshot.metadata.get(branch)?;
+    let local = snapshot.branches.get(branch)?;
+    let last_pushed = scanned
+        .metadata
+        .submitted
+        .as_ref()
+        .and_then(|s| Oid::new(&s.head_oid).ok())?;
+    let base = Oid::new(&scanned.metadata.base.oid).ok()?;
+    remote_divergence::changed_elsewhere(
+        git,
+        &base,
+        local,
+        remote_tip,
+        trunk_tip,
+        Some(&last_pushed),
+    )
+    .ok()
+    .flatten()
+}
+
+/// Branches in `branches` that were pushed from somewhere else since this
+/// repository last submitted them.
+///
+/// Reads the remote tips with `ls-remote` and downloads their objects
+/// without touching any ref. An unreachable remote is skipped; the push
+/// that follows reports it.
+fn find_diverged(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+) -> Vec<DivergedBranch> {
+    let mut by_remote: HashMap<&str, Vec<&BranchName>> = HashMap::new();
+    for branch in branches {
+        let Some(scanned) = snapshot.metadata.get(branch) else {
+            continue;
+        };
+        if scanned.metadata.submitted.is_some() {
+            by_remote
+                .entry(scanned.metadata.remote_or("origin"))
+                .or_default()
+                .push(branch);
+        }
+    }
+
+    let mut diverged = Vec::new();
+    for (remote, candidates) in by_remote {
+        let Ok(heads) = git.remote_heads(remote) else {
+            continue;
+        };
+        let moved: Vec<(&BranchName, &Oid)> = candidates
+            .into_iter()
+            .filter_map(|branch| {
+                let tip = heads.get(branch.as_str())?;
+                let scanned = &snapshot.metadata[branch];
+                let pushed = scanned.metadata.submitted.as_ref()?;
+                (snapshot.branches.get(branch) != Some(tip) && pushed.head_oid != tip.as_str())
+                    .then_some((branch, tip))
+            })
+            .collect();
+        if moved.is_empty() || git.fetch_objects(remote, None).is_err() {
+            continue;
+        }
+
+        let trunk_tip = snapshot
+            .trunk
+            .as_ref()
+            .and_then(|t| heads.get(t.as_str()).or_else(|| snapshot.branches.get(t)));
+        let Some(trunk_tip) = trunk_tip else {
+            continue;
+        };
+        for (branch, tip) in moved {
+            if let Some(divergence) = branch_divergence(git, snapshot, branch, tip, trunk_tip) {
+                diverged.push(DivergedBranch {
+                    branch: branch.clone(),
+                    remote: remote.to_string(),
+                    remote_tip: tip.clone(),
+                    divergence,
+                });
+            }
+        }
+    }
+    // Report in push order
+    diverged.sort_by_key(|d| branches.iter().position(|b| *b == d.branch));
+    diverged
+}
+
+/// Show branches that were pushed from somewhere else and ask how to
+/// reconcile each one.
+///
+/// Returns the choice per branch. Taking the remote side is applied here;
+/// the caller pushes kept branches over the remote tip and leaves the rest
+/// alone. Without a terminal nothing is pushed: overwriting the remote
+/// commits needs an explicit choice.
+fn reconcile_diverged(
+    git: &Git,
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    diverged: &[DivergedBranch],
+    dry_run: bool,
+) -> CliResult<HashMap<BranchName, (Reconcile, Oid)>> {
+    let mut choices = HashMap::new();
+    if diverged.is_empty() {
+        return Ok(choices);
+    }
+
+    println!("{}", i18n::t("submit-diverged-header"));
+    for d in diverged {
+        println!(
+            "{}",
+            i18n::t_args(
+                "submit-diverged-branch",
+                &[
+                    ("branch", &d.branch),
+                    ("remote_name", &d.remote),
+                    ("local", &d.divergence.local_only),
+                    ("remote", &d.divergence.remote_only),
+                ],
+            )
+        );
+    }
+    if dry_run {
+        println!("{}", i18n::t("submit-diverged-dry-run"));
+        return Ok(choices);
+    }
+    if !ctx.interactive {
+        return Err(CliError::usage(i18n::t(
+            "submit-diverged-needs-interactive",
+        )));
+    }
+
+    for d in diverged {
+        let choice = loop {
+            let answer = super::batch::read_answer(&i18n::t_args(
+                "submit-diverged-prompt",
+                &[("branch", &d.branch)],
+            ))?;
+            match Reconcile::parse(&answer) {
+                Some(choice) => break choice,
+                None => println!(
+                    "{}",
+                    i18n::t_args("submit-diverged-unknown", &[("answer", &answer.trim())])
+                ),
+            }
+        };
+        match choice {
+            Reconcile::Abort => return Err(CliError::failed(i18n::t("submit-diverged-aborted"))),
+            Reconcile::TakeRemote => {
+                take_remote(git, ctx, snapshot, d)?;
+                println!(
+                    "{}",
+                    i18n::t_args("submit-diverged-took", &[("branch", &d.branch)])
+                );
+            }
+            Reconcile::KeepLocal | Reconcile::Skip => {}
+        }
+        choices.insert(d.branch.clone(), (choice, d.remote_tip.clone()));
+    }
+    Ok(choices)
+}
+
+/// Reset a branch to its remote tip.
+///
+/// Runs as a plan through the executor, so the reset is journaled and
+/// `lattice undo` puts the local tip back. For the checked-out branch the
+/// files move first with a two-tree `git read-tree -m -u`, which, like
+/// `git reset --keep`, refuses to discard uncommitted changes. The base
+/// moves to where the remote branch forked from its parent, and the remote
+/// tip is recorded as the last push so the next submit doesn't flag it
+/// again.
+fn take_remote(
+    git: &Git,
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    diverged: &DivergedBranch,
+) -> CliResult {
+    let branch = &diverged.branch;
+    let tip = &diverged.remote_tip;
+    if let Some(path) = git.branch_checked_out_elsewhere(branch)? {
+        return Err(CliError::conflict(format!(
+            "'{}' is checked out in {}; reset it there with 'git reset --keep {}'.",
+            branch,
+            path.display(),
+            tip
+        )));
+    }
+    let local = snapshot
+        .branches
+        .get(branch)
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", branch)))?;
+
+    let mut plan = Plan::new(OpId::new(), "submit");
+    if git.current_branch()?.as_ref() == Some(branch) {
+        plan = plan
+            .with_step(PlanStep::RunGit {
+                args: vec![
+                    "update-index".to_string(),
+                    "-q".to_string(),
+                    "--refresh".to_string(),
+                ],
+                description: "Refresh the index".to_string(),
+                expected_effects: vec![],
+            })
+            .with_step(PlanStep::RunGit {
+                args: vec![
+                    "read-tree".to_string(),
+                    "-m".to_string(),
+                    "-u".to_string(),
+                    local.to_string(),
+                    tip.to_string(),
+                ],
+                description: format!("Move the files of '{}' to the remote tip", branch),
+                expected_effects: vec![],
+            });
+    }
+    plan = plan.with_step(PlanStep::UpdateRefCas {
+        refname: format!("refs/heads/{}", branch),
+        old_oid: Some(local.to_string()),
+        new_oid: tip.to_string(),
+        reason: "take the remote tip".to_string(),
+    });
+
+    if let Some(entry) = MetadataStore::new(git).read(branch)? {
+        let mut metadata = entry.metadata.clone();
+        let parent_tip = BranchName::new(metadata.parent.name())
+            .ok()
+            .and_then(|parent| snapshot.branches.get(&parent).cloned());
+        if let Some(fork) = parent_tip.and_then(|p| git.merge_base(tip, &p).ok().flatten()) {
+            metadata.base = BaseInfo {
+                oid: fork.to_string(),
+            };
+        }
+        metadata.submitted = Some(submit_snapshot(&metadata, tip));
+        metadata.touch();
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+
+    super::execute_follow_up(git, ctx, &plan).map_err(|e| {
+        CliError::git(format!(
+            "Could not reset '{}' to the remote tip: {}",
+            branch, e
+        ))
+    })
+}
+
+/// The `submitted` record for a push of `tip`.
+fn submit_snapshot(metadata: &BranchMetadataV2, tip: &Oid) -> SubmitSnapshot {
+    SubmitSnapshot {
+        head_oid: tip.to_string(),
+        base_oid: metadata.base.oid.clone(),
+        parent: metadata.parent.name().to_string(),
+        submitted_at: UtcTimestamp::now(),
+    }
+}
+
+/// Record each tip as the last push of its branch, so the next submit can
+/// tell its own pushes from pushes made elsewhere. Failures only warn.
+fn record_submitted(git: &Git, ctx: &Context, pushed: &[(BranchName, Oid)]) {
+    let store = MetadataStore::new(git);
+    let mut plan = Plan::new(OpId::new(), "submit");
+    for (branch, tip) in pushed {
+        let entry = match store.read(branch) {
+            Ok(Some(entry)) => entry,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "submit-warning-could-record",
+                        &[("branch", &branch), ("error", &e)],
+                    )
+                );
+                continue;
+            }
+        };
+        let mut metadata = entry.metadata.clone();
+        metadata.submitted = Some(submit_snapshot(&metadata, tip));
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+    if plan.is_empty() {
+        return;
+    }
+    if let Err(e) = super::execute_follow_up(git, ctx, &plan) {
+        eprintln!(
+            "  {}",
+            i18n::t_args("submit-warning-could-record-pushes", &[("error", &e)])
+        );
+    }
+}
+
+// ============================================================================
+// Submits From Other Worktrees
+// ============================================================================
+
+/// How often a claim ahead of this submit is checked.
+const CLAIM_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long to wait for submits ahead of this one before giving up.
+const CLAIM_MAX_WAIT: Duration = Duration::from_secs(600);
+
+/// Claim `branches` for this submit, waiting first for running submits
+/// (from any worktree) that claimed some of them earlier.
+///
+/// Returns the claim, held until the submit ends, and the branches the
+/// submits waited for also covered.
+async fn claim_branches(
+    git: &Git,
+    paths: &LatticePaths,
+    branches: &[BranchName],
+    quiet: bool,
+) -> CliResult<(ClaimGuard, HashSet<BranchName>)> {
+    let names: Vec<String> = branches.iter().map(|b| b.to_string()).collect();
+    let work_dir = git.info()?.work_dir.clone();
+    let claim = SubmitClaim::register(paths, work_dir, &names)
+        .context("Failed to record the branches being submitted")?;
+
+    let mut covered = HashSet::new();
+    let mut announced = HashSet::new();
+    let started = Instant::now();
+    loop {
+        let ahead = claim
+            .ahead(paths)
+            .context("Failed to read the submits in progress")?;
+        if ahead.is_empty() {
+            return Ok((claim, covered));
+        }
+        for other in &ahead {
+            let overlap = claim.claim().overlap(other);
+            if announced.insert(other.op_id.to_string()) && !quiet {
+                let location = other
+                    .work_dir
+                    .as_ref()
+                    .map(|dir| format!(" in {}", dir.display()))
+                    .unwrap_or_default();
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "submit-waiting-submit-pid",
+                        &[
+                            ("location", &location),
+                            ("pid", &other.pid),
+                            ("branches", &overlap.join(", ")),
+                        ],
+                    )
+                );
+            }
+            covered.extend(overlap.iter().filter_map(|b| BranchName::new(b).ok()));
+        }
+        if started.elapsed() >= CLAIM_MAX_WAIT {
+            return Err(CliError::conflict(format!(
+                "Gave up waiting for another submit of the same branches (pid {}).\n\n\
+                 Re-run `lattice submit` once it has finished.",
+                ahead[0].pid
+            )));
+        }
+        tokio::time::sleep(CLAIM_POLL_INTERVAL).await;
+    }
+}
+
+// ============================================================================
+// Branch Selection
+// ============================================================================
+
+/// The tracked branches of the current branch's stack, bottom-up.
+fn current_stack(snapshot: &RepoSnapshot) -> CliResult<Vec<BranchName>> {
+    let current = snapshot
+        .current_branch
+        .as_ref()
+        .ok_or_else(|| CliError::usage("Not on a branch."))?;
+    // Siblings are listed by name, so the numbering is stable between runs
+    let mut descendants: Vec<_> = snapshot.graph.descendants(current).into_iter().collect();
+    descendants.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    let mut stack = snapshot.graph.ancestors(current);
+    stack.reverse();
+    stack.push(current.clone());
+    stack.extend(descendants);
+    stack.retain(|b| snapshot.metadata.contains_key(b));
+    Ok(super::restack::topological_sort(&stack, snapshot))
+}
+
+/// Parse a `--branches` list, keeping only names in `stack`.
+///
+/// # Errors
+///
+/// Fails for a name that isn't a tracked branch of the current stack, or
+/// an empty list.
+fn parse_branch_list(list: &str, stack: &[BranchName]) -> CliResult<Vec<BranchName>> {
+    let mut selected = Vec::new();
+    for name in split_reviewer_list(Some(list)) {
+        let branch = stack.iter().find(|b| b.as_str() == name).ok_or_else(|| {
+            CliError::usage(i18n::t_args("submit-not-in-stack", &[("branch", &name)]))
+        })?;
+        selected.push(branch.clone());
+    }
+    if selected.is_empty() {
+        return Err(CliError::usage("--branches needs at least one branch name"));
+    }
+    Ok(selected)
+}
+
+/// Show the stack numbered bottom-up and read which branches to submit.
+///
+/// Returns an empty list when nothing was marked.
+fn pick_branches(snapshot: &RepoSnapshot, stack: &[BranchName]) -> CliResult<Vec<BranchName>> {
+    for (i, branch) in stack.iter().enumerate() {
+        let current = if snapshot.current_branch.as_ref() == Some(branch) {
+            " (current)"
+        } else {
+            ""
+        };
+        let pr = snapshot
+            .metadata
+            .get(branch)
+            .and_then(|s| s.metadata.pr.number())
+            .map(|n| format!(" #{}", n))
+            .unwrap_or_default();
+        println!("  {:>2}. {}{}{}", i + 1, branch, pr, current);
+    }
+    println!();
+
+    let input = super::batch::read_answer(&i18n::t("submit-select-prompt"))?;
+    let marked = super::batch::parse_marks(&input, stack.len()).map_err(|mark| {
+        CliError::usage(i18n::t_args(
+            "batch-invalid-mark",
+            &[("mark", &mark), ("count", &stack.len())],
+        ))
+    })?;
+    Ok(marked.into_iter().map(|i| stack[i].clone()).collect())
+}
+
+/// Tracked ancestors of `selected` that weren't selected themselves,
+/// bottom-up.
+fn unselected_ancestors(snapshot: &RepoSnapshot, selected: &[BranchName]) -> Vec<BranchName> {
+    let mut added: Vec<BranchName> = selected
+        .iter()
+        .flat_map(|b| snapshot.graph.ancestors(b))
+        .filter(|a| snapshot.metadata.contains_key(a) && !selected.contains(a))
+        .collect();
+    added.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    added.dedup();
+    super::restack::topological_sort(&added, snapshot)
+}
+
+/// `since` and the branches above it up to the current branch, bottom-up,
+/// plus the current branch's descendants with `--stack`.
+///
+/// # Errors
+///
+/// Fails unless `since` is the current branch or one of its tracked
+/// ancestors.
+fn since_range(snapshot: &RepoSnapshot, since: &str, stack: bool) -> CliResult<Vec<BranchName>> {
+    let current = snapshot
+        .current_branch
+        .as_ref()
+        .ok_or_else(|| CliError::usage("Not on a branch."))?;
+    let mut range = snapshot.graph.ancestors(current);
+    range.reverse();
+    range.push(current.clone());
+    let Some(start) = range
+        .iter()
+        .position(|b| b.as_str() == since && snapshot.metadata.contains_key(b))
+    else {
+        return Err(CliError::usage(i18n::t_args(
+            "submit-since-not-downstack",
+            &[("branch", &since)],
+        )));
+    };
+    let mut range = range.split_off(start);
+
+    if stack {
+        let mut descendants: Vec<_> = snapshot.graph.descendants(current).into_iter().collect();
+        descendants.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        range.extend(super::restack::topological_sort(&descendants, snapshot));
+    }
+    Ok(range)
+}
+
+/// How a submit narrows its branches.
+#[derive(Debug, Clone, Copy)]
+pub enum SubmitSelection<'a> {
+    /// `--select`: pick from the current stack
+    Pick,
+    /// `--branches`: these names
+    Named(&'a str),
+    /// `--since`: this branch up to the current one
+    Since(&'a str),
+}
+
+/// Resolve a [`SubmitSelection`] into the branches to submit.
+///
+/// Returns `None` when the picker was left empty. Ancestors the selection
+/// is stacked on are reported when they will be added, which is always
+/// except with `--update-only`.
+fn select_branches(
+    git: &Git,
+    ctx: &Context,
+    selection: SubmitSelection<'_>,
+    opts: &SubmitOptions,
+) -> CliResult<Option<Vec<BranchName>>> {
+    let snapshot = crate::engine::scan::scan(git)?;
+
+    let selected = match selection {
+        SubmitSelection::Named(list) => parse_branch_list(list, &current_stack(&snapshot)?)?,
+        SubmitSelection::Since(since) => since_range(&snapshot, since, opts.stack)?,
+        SubmitSelection::Pick => {
+            if !ctx.interactive {
+                return Err(CliError::usage(i18n::t("submit-select-needs-interactive")));
+            }
+            let picked = pick_branches(&snapshot, &current_stack(&snapshot)?)?;
+            if picked.is_empty() {
+                println!("{}", i18n::t("batch-nothing-marked"));
+                return Ok(None);
+            }
+            picked
+        }
+    };
+
+    let added = unselected_ancestors(&snapshot, &selected);
+    if !added.is_empty() && !opts.update_only && !ctx.quiet {
+        let names = added
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{}",
+            i18n::t_args("submit-select-ancestors", &[("branches", &names)])
+        );
+    }
+    Ok(Some(selected))
+}
+
+// ============================================================================
+// All Stacks
+// ============================================================================
+
+/// Whether `--all` has something to do for a tracked branch: no PR yet, or
+/// a tip or parent other than the ones last pushed.
+fn needs_submit(snapshot: &RepoSnapshot, branch: &BranchName, update_only: bool) -> bool {
+    let Some(scanned) = snapshot.metadata.get(branch) else {
+        return false;
+    };
+    if is_synthetic_snapshot(branch, snapshot) {
+        return false;
+    }
+    let metadata = &scanned.metadata;
+    if metadata.pr.number().is_none() {
+        return !update_only;
+    }
+    match (&metadata.submitted, snapshot.branches.get(branch)) {
+        (Some(pushed), Some(tip)) => {
+            pushed.head_oid != tip.as_str() || pushed.parent != metadata.parent.name()
+        }
+        _ => true,
+    }
+}
+
+/// The bottom tracked branch of the stack `branch` is in.
+fn stack_root(snapshot: &RepoSnapshot, branch: &BranchName) -> BranchName {
+    snapshot
+        .graph
+        .ancestors(branch)
+        .into_iter()
+        .rfind(|a| snapshot.metadata.contains_key(a))
+        .unwrap_or_else(|| branch.clone())
+}
+
+/// The stacks `--all` submits, each as its root and its branches bottom-up,
+/// ordered by root name.
+///
+/// A stack is included when one of its branches needs submitting; then
+/// those branches are submitted, along with the tracked ancestors their
+/// PRs are based on unless `update_only`.
+fn stacks_needing_submit(
+    snapshot: &RepoSnapshot,
+    update_only: bool,
+) -> Vec<(BranchName, Vec<BranchName>)> {
+    let mut stacks: Vec<(BranchName, Vec<BranchName>)> = Vec::new();
+    let mut pending: Vec<&BranchName> = snapshot
+        .metadata
+        .keys()
+        .filter(|b| needs_submit(snapshot, b, update_only))
+        .collect();
+    pending.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    for branch in pending {
+        let root = stack_root(snapshot, branch);
+        let index = match stacks.iter().position(|(r, _)| *r == root) {
+            Some(index) => index,
+            None => {
+                stacks.push((root, Vec::new()));
+                stacks.len() - 1
+            }
+        };
+        let members = &mut stacks[index].1;
+        if !update_only {
+            members.extend(
+                snapshot
+                    .graph
+                    .ancestors(branch)
+                    .into_iter()
+                    .filter(|a| snapshot.metadata.contains_key(a)),
+            );
+        }
+        members.push(branch.clone());
+    }
+
+    stacks.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    for (_, members) in &mut stacks {
+        members.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        members.dedup();
+        *members = super::restack::topological_sort(members, snapshot);
+    }
+    stacks
+}
+
+/// What submit did with a branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BranchOutcome {
+    Created,
+    Updated,
+    Queued,
+    Skipped,
+    Failed,
+}
+
+/// Print the `--all` plan: each stack's branches in push order, and
+/// whether their PRs are created or updated.
+fn print_stacks_plan(snapshot: &RepoSnapshot, stacks: &[(BranchName, Vec<BranchName>)]) {
+    let count: usize = stacks.iter().map(|(_, b)| b.len()).sum();
+    println!(
+        "{}",
+        i18n::t_args(
+            "submit-submitting-branches",
+            &[("count", &count), ("stacks", &stacks.len())],
+        )
+    );
+    for (root, branches) in stacks {
+        println!("  {}", root);
+        for branch in branches {
+            let action = match snapshot
+                .metadata
+                .get(branch)
+                .and_then(|s| s.metadata.pr.number())
+            {
+                Some(number) => format!("update #{}", number),
+                None => "create PR".to_string(),
+            };
+            println!("    {} - {}", branch, action);
+        }
+    }
+}
+
+/// One line per stack summarizing what happened to its branches.
+fn stack_summary_lines(
+    stacks: &[(BranchName, Vec<BranchName>)],
+    outcomes: &HashMap<BranchName, BranchOutcome>,
+) -> Vec<String> {
+    stacks
+        .iter()
+        .map(|(root, branches)| {
+            let counts: Vec<String> = [
+                (BranchOutcome::Created, "created"),
+                (BranchOutcome::Updated, "updated"),
+                (BranchOutcome::Queued, "queued"),
+                (BranchOutcome::Skipped, "skipped"),
+                (BranchOutcome::Failed, "failed"),
+            ]
+            .into_iter()
+            .filter_map(|(outcome, label)| {
+                let n = branches
+                    .iter()
+                    .filter(|b| outcomes.get(*b) == Some(&outcome))
+                    .count();
+                (n > 0).then(|| format!("{} {}", n, label))
+            })
+            .collect();
+            let counts = if counts.is_empty() {
+                "nothing done".to_string()
+            } else {
+                counts.join(", ")
+            };
+            format!("  {}: {}", root, counts)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Default Reviewers
+// ============================================================================
+
+/// Reviewers to request on each tracked branch's new PR.
+///
+/// Combines `--reviewers`/`--team-reviewers` with what `rules` assigns the
+/// branch for its name and for the files it changes since its base.
+fn requested_reviewers(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    opts: &SubmitOptions,
+    rules: Option<&ReviewerRules>,
+) -> CliResult<HashMap<BranchName, Reviewers>> {
+    let mut requested = HashMap::new();
+    for branch in branches {
+        let (Some(entry), Some(tip)) =
+            (snapshot.metadata.get(branch), snapshot.branches.get(branch))
+        else {
+            continue;
+        };
+        let matched = match rules {
+            Some(rules) => {
+                let base = Oid::new(&entry.metadata.base.oid)
+                    .with_context(|| format!("Invalid base OID for '{}'", branch))?;
+                let changed = git
+                    .changed_paths(&base, tip)
+                    .with_context(|| format!("Failed to compute diff for '{}'", branch))?;
+                rules.matching(branch.as_str(), &changed)
+            }
+            None => Vec::new(),
+        };
+        requested.insert(branch.clone(), merge_reviewers(opts, matched));
+    }
+    Ok(requested)
+}
+
+/// The command-line reviewers followed by `matched` ones not already
+/// listed; `team:<slug>` entries are teams.
+fn merge_reviewers(opts: &SubmitOptions, matched: Vec<String>) -> Reviewers {
+    let mut reviewers = Reviewers {
+        users: split_reviewer_list(opts.reviewers.as_deref()),
+        teams: split_reviewer_list(opts.team_reviewers.as_deref()),
+    };
+    for entry in matched {
+        let (list, name) = match entry.strip_prefix("team:") {
+            Some(team) => (&mut reviewers.teams, team.to_string()),
+            None => (&mut reviewers.users, entry),
+        };
+        if !list.contains(&name) {
+            list.push(name);
+        }
+    }
+    reviewers
+}
+
+// ============================================================================
+// Submit Preview
+// ============================================================================
+
+/// What submit will do with a branch's PR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PreviewAction {
+    /// Create a new PR (or link one already open for the branch)
+    Create,
+    /// Update the linked PR
+    Update(u64),
+    /// Leave the branch alone, for the given reason
+    Skip(&'static str),
+}
+
+/// One branch in the submit preview.
+#[derive(Debug, Clone)]
+struct PreviewNode {
+    branch: BranchName,
+    /// Branch the PR will target
+    base: String,
+    /// Base the PR targeted at the last submit, when different
+    previous_base: Option<String>,
+    action: PreviewAction,
+    /// Draft status to set, `None` when unchanged
+    draft: Option<bool>,
+    /// Reviewers requested on creation
+    reviewers: Vec<String>,
+}
+
+/// Work out what submitting `branches` (in stack order) will do to each PR.
+///
+/// `reconciled` holds the choices for branches pushed from elsewhere; any
+/// choice but keeping the local branch skips it.
+fn preview_submit(
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    reconciled: &HashMap<BranchName, (Reconcile, Oid)>,
+    opts: &SubmitOptions,
+    requested: &HashMap<BranchName, Reviewers>,
+) -> Vec<PreviewNode> {
+    use crate::core::metadata::schema::PrState;
+
+    branches
+        .iter()
+        .map(|branch| {
+            let Some(scanned) = snapshot.metadata.get(branch) else {
+                return PreviewNode {
+                    branch: branch.clone(),
+                    base: String::new(),
+                    previous_base: None,
+                    action: PreviewAction::Skip("untracked"),
+                    draft: None,
+                    reviewers: Vec::new(),
+                };
+            };
+            let metadata = &scanned.metadata;
+            let base = metadata.parent.name().to_string();
+
+            let action = match (reconciled.get(branch), &metadata.pr) {
+                (Some((choice, _)), _) if *choice != Reconcile::KeepLocal => {
+                    PreviewAction::Skip("pushed from elsewhere")
+                }
+                (_, PrState::Linked { number, .. }) => PreviewAction::Update(*number),
+                (_, PrState::None) if opts.update_only => PreviewAction::Skip("no existing PR"),
+                (_, PrState::None) => PreviewAction::Create,
+            };
+            let previous_base = match action {
+                PreviewAction::Update(_) => metadata
+                    .submitted
+                    .as_ref()
+                    .map(|s| s.parent.clone())
+                    .filter(|previous| *previous != base),
+                _ => None,
+            };
+            let draft = match action {
+                PreviewAction::Create => Some(opts.draft),
+                PreviewAction::Update(_) if opts.publish => Some(false),
+                PreviewAction::Update(_) if opts.draft => Some(true),
+                _ => None,
+            };
+
+            PreviewNode {
+                branch: branch.clone(),
+                base,
+                previous_base,
+                reviewers: match requested.get(branch) {
+                    Some(reviewers) if action == PreviewAction::Create => reviewers
+                        .users
+                        .iter()
+                        .cloned()
+                        .chain(reviewers.teams.iter().map(|team| format!("team:{}", team)))
+                        .collect(),
+                    _ => Vec::new(),
+                },
+                action,
+                draft,
+            }
+        })
+        .collect()
+}
+
+/// Render the preview as a tree of PRs under the bases they target.
+///
+/// Bases outside the submitted set (usually trunk) are the roots, in the
+/// order they are first targeted.
+fn render_preview(nodes: &[PreviewNode]) -> Vec<String> {
+    let submitted: std::collections::HashSet<&str> =
+        nodes.iter().map(|n| n.branch.as_str()).collect();
+    let mut roots: Vec<&str> = Vec::new();
+    for node in nodes {
+        let base = node.base.as_str();
+        if !submitted.contains(base) && !roots.contains(&base) {
+            roots.push(base);
+        }
+    }
+
+    let mut lines = Vec::new();
+    for root in roots {
+        lines.push(if root.is_empty() { "(untracked)" } else { root }.to_string());
+        render_preview_children(nodes, root, "", &mut lines);
+    }
+    lines
+}
+
+/// Append the PRs targeting `base`, and theirs, to `lines`.
+fn render_preview_children(
+    nodes: &[PreviewNode],
+    base: &str,
+    prefix: &str,
+    lines: &mut Vec<String>,
+) {
+    let kids: Vec<&PreviewNode> = nodes.iter().filter(|n| n.base == base).collect();
+    for (i, node) in kids.iter().enumerate() {
+        let last = i + 1 == kids.len();
+        lines.push(format!(
+            "{}{}{}  {}",
+            prefix,
+            if last { "└── " } else { "├── " },
+            node.branch,
+            describe_preview(node)
+        ));
+        let kid_prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+        render_preview_children(nodes, node.branch.as_str(), &kid_prefix, lines);
+    }
+}
+
+/// One-line summary of a preview node's PR changes.
+fn describe_preview(node: &PreviewNode) -> String {
+    let mut parts = vec![match &node.action {
+        PreviewAction::Create => "create PR".to_string(),
+        PreviewAction::Update(number) => format!("update PR #{}", number),
+        PreviewAction::Skip(reason) => return format!("skip ({})", reason),
+    }];
+    parts.push(match &node.previous_base {
+        Some(previous) => format!("base {} (was {})", node.base, previous),
+        None => format!("base {}", node.base),
+    });
+    match node.draft {
+        Some(true) => parts.push("draft".to_string()),
+        Some(false) if node.action != PreviewAction::Create => {
+            parts.push("ready for review".to_string())
+        }
+        _ => {}
+    }
+    if !node.reviewers.is_empty() {
+        parts.push(format!("reviewers: {}", node.reviewers.join(", ")));
+    }
+    parts.join(", ")
+}
+
+/// Show the submit preview and ask whether to go ahead.
+fn confirm_preview(ctx: &Context, nodes: &[PreviewNode]) -> CliResult<bool> {
+    use crate::ui::prompts::{self, PromptError, Severity};
+
+    println!("{}", i18n::t("submit-plan"));
+    for line in render_preview(nodes) {
+        println!("  {}", line);
+    }
+    match prompts::confirm(
+        &i18n::t("prompt-submit-apply"),
+        Severity::Minor,
+        ctx.interactive,
+    ) {
+        Ok(answer) => Ok(answer),
+        Err(PromptError::NotInteractive) => Err(CliError::usage(
+            "--confirm needs a terminal to answer the prompt. Pass --yes to submit without asking.",
+        )),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// ============================================================================
+// PR Templates
+// ============================================================================
+
+/// Subjects of a branch's own commits, oldest first, for `{commits}`.
+///
+/// Empty if the branch's commits can't be listed; the template still renders.
+fn commit_subjects(git: &Git, snapshot: &RepoSnapshot, branch: &BranchName) -> Vec<String> {
+    let range = snapshot.metadata.get(branch).and_then(|scanned| {
+        let base = Oid::new(&scanned.metadata.base.oid).ok()?;
+        Some((base, snapshot.branches.get(branch)?))
+    });
+    let Some((base, head)) = range else {
+        return Vec::new();
+    };
+    let mut subjects: Vec<String> = git
+        .commits_between(&base, head)
+        .map(|commits| commits.into_iter().map(|c| c.summary).collect())
+        .unwrap_or_default();
+    subjects.reverse();
+    subjects
+}
+
+/// Let the user edit a new PR's body in their editor (`submit --edit`).
+///
+/// Returns `None` if the body was saved empty.
+fn edit_pr_body(git: &Git, branch: &BranchName, body: Option<String>) -> CliResult<Option<String>> {
+    use std::process::Command as ProcessCommand;
+
+    let path = git.info()?.git_dir.join("LATTICE_PR_BODY.md");
+    std::fs::write(&path, body.unwrap_or_default()).context("Failed to write PR body file")?;
+
+    let editor = std::env::var("LATTICE_TEST_EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let status = ProcessCommand::new(&editor)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to open editor '{}'", editor));
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    if !status?.success() {
+        return Err(CliError::failed(format!(
+            "Editor exited with error; no PR was created for '{}'",
+            branch
+        )));
+    }
+    let edited = edited.context("Failed to read edited PR body")?;
+    let edited = edited.trim();
+    Ok((!edited.is_empty()).then(|| edited.to_string()))
+}
+
 // ============================================================================
 // Submit Command Implementation
 // ============================================================================
 
 /// Submit options parsed from CLI arguments.
-#[derive(Debug, Clone)]
+///
+/// `quiet` and `verify` are taken from the [`Context`] by [`submit`].
+#[derive(Debug, Clone, Default)]
 #[allow(dead_code)]
 pub struct SubmitOptions {
     pub stack: bool,
@@ -201,8 +1784,16 @@ pub struct SubmitOptions {
     pub team_reviewers: Option<String>,
     pub no_restack: bool,
     pub view: bool,
+    pub offline: bool,
     pub quiet: bool,
     pub verify: bool,
+    /// Open each new PR's body in the editor before creating it
+    pub edit: bool,
+    /// Submit these branches (and their ancestors) instead of the current
+    /// branch's scope
+    pub branches: Option<Vec<BranchName>>,
+    /// Submit every stack with branches needing it, not just the current one
+    pub all: bool,
 }
 
 /// Result of a submit operation.
@@ -219,14 +1810,15 @@ pub struct SubmitResult {
 
 /// The submit command for WithRestack mode.
 pub struct SubmitWithRestackCommand {
-    #[allow(dead_code)]
     opts: SubmitOptions,
+    /// Check to run on each branch before pushing (`submit.pre_push`)
+    pre_push: Option<String>,
 }
 
 impl SubmitWithRestackCommand {
     /// Create a new submit command with restack mode.
-    pub fn new(opts: SubmitOptions) -> Self {
-        Self { opts }
+    pub fn new(opts: SubmitOptions, pre_push: Option<String>) -> Self {
+        Self { opts, pre_push }
     }
 }
 
@@ -234,13 +1826,19 @@ impl AsyncCommand for SubmitWithRestackCommand {
     const REQUIREMENTS: &'static RequirementSet = &requirements::REMOTE;
     type Output = SubmitResult;
 
-    fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
+    fn plan<'a>(&'a self, ready: &'a ReadyContext) -> PlanFut<'a> {
         Box::pin(async move {
             // Build a minimal plan - the actual operations happen in execute_submit
-            let plan = Plan::new(OpId::new(), "submit").with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: None,
-            });
+            let plan = Plan::new(OpId::new(), "submit")
+                .with_step(PlanStep::ForgeFetch {
+                    remote: "origin".to_string(),
+                    refspec: None,
+                })
+                .with_steps(pre_push_checks(
+                    &ready.snapshot,
+                    &self.opts,
+                    self.pre_push.as_deref(),
+                ));
 
             Ok(plan)
         })
@@ -263,14 +1861,15 @@ impl AsyncCommand for SubmitWithRestackCommand {
 
 /// The submit command for NoRestack mode (bare repo compatible).
 pub struct SubmitNoRestackCommand {
-    #[allow(dead_code)]
     opts: SubmitOptions,
+    /// Check to run on each branch before pushing (`submit.pre_push`)
+    pre_push: Option<String>,
 }
 
 impl SubmitNoRestackCommand {
     /// Create a new submit command without restack mode.
-    pub fn new(opts: SubmitOptions) -> Self {
-        Self { opts }
+    pub fn new(opts: SubmitOptions, pre_push: Option<String>) -> Self {
+        Self { opts, pre_push }
     }
 }
 
@@ -278,13 +1877,19 @@ impl AsyncCommand for SubmitNoRestackCommand {
     const REQUIREMENTS: &'static RequirementSet = &requirements::REMOTE_BARE_ALLOWED;
     type Output = SubmitResult;
 
-    fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
+    fn plan<'a>(&'a self, ready: &'a ReadyContext) -> PlanFut<'a> {
         Box::pin(async move {
             // Build a minimal plan - the actual operations happen in execute_submit
-            let plan = Plan::new(OpId::new(), "submit").with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: None,
-            });
+            let plan = Plan::new(OpId::new(), "submit")
+                .with_step(PlanStep::ForgeFetch {
+                    remote: "origin".to_string(),
+                    refspec: None,
+                })
+                .with_steps(pre_push_checks(
+                    &ready.snapshot,
+                    &self.opts,
+                    self.pre_push.as_deref(),
+                ));
 
             Ok(plan)
         })
@@ -309,76 +1914,84 @@ impl AsyncCommand for SubmitNoRestackCommand {
 ///
 /// This is a synchronous wrapper that uses tokio to run the async implementation.
 /// It uses mode dispatch for bare repository handling per SPEC.md §4.6.7.
-#[allow(clippy::too_many_arguments)]
+/// `selection` narrows the branches as `--select`, `--branches` or `--since` do.
 pub fn submit(
     ctx: &Context,
-    stack: bool,
-    draft: bool,
-    publish: bool,
-    confirm: bool,
-    dry_run: bool,
-    force: bool,
-    always: bool,
-    update_only: bool,
-    reviewers: Option<&str>,
-    team_reviewers: Option<&str>,
-    no_restack: bool,
-    view: bool,
-) -> Result<()> {
+    mut opts: SubmitOptions,
+    selection: Option<SubmitSelection<'_>>,
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
         .unwrap_or_else(|| std::env::current_dir().unwrap());
     let git = Git::open(&cwd).context("Failed to open repository")?;
 
+    opts.quiet = ctx.quiet;
+    opts.verify = ctx.verify;
+    if let Some(selection) = selection {
+        match select_branches(&git, ctx, selection, &opts)? {
+            Some(selected) => opts.branches = Some(selected),
+            None => return Ok(()),
+        }
+    }
+    run_submit(&git, ctx, opts)
+}
+
+/// Submit `branches` and their ancestors with default options.
+///
+/// Used by batch actions (`lattice log --select`).
+pub fn submit_branches(ctx: &Context, branches: &[BranchName]) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let opts = SubmitOptions {
+        quiet: ctx.quiet,
+        verify: ctx.verify,
+        branches: Some(branches.to_vec()),
+        ..Default::default()
+    };
+    run_submit(&git, ctx, opts)
+}
+
+/// Resolve the submit mode and run it.
+fn run_submit(git: &Git, ctx: &Context, opts: SubmitOptions) -> CliResult {
     // Resolve mode from flags and repo context
     let is_bare = git.info()?.work_dir.is_none();
-    let mode = SubmitMode::resolve(no_restack, is_bare).map_err(|e| match e {
+    let mode = SubmitMode::resolve(opts.no_restack, is_bare).map_err(|e| match e {
         ModeError::BareRepoRequiresFlag { command, required_flag } => {
-            anyhow::anyhow!(
-                "This is a bare repository. The `{}` command requires a working directory for restacking.\n\n\
+            CliError::usage(format!("This is a bare repository. The `{}` command requires a working directory for restacking.\n\n\
                  To submit without restacking (branches must be properly aligned), use:\n\n\
                      lattice submit {}\n\n\
                  Note: Branches must satisfy ancestry alignment (parent tip is ancestor of branch tip).\n\
                  If alignment fails, you'll need to restack from a worktree first.",
                 command,
-                required_flag
-            )
+                required_flag))
         }
     })?;
 
-    let opts = SubmitOptions {
-        stack,
-        draft,
-        publish,
-        confirm,
-        dry_run,
-        force,
-        always,
-        update_only,
-        reviewers: reviewers.map(|s| s.to_string()),
-        team_reviewers: team_reviewers.map(|s| s.to_string()),
-        no_restack,
-        view,
-        quiet: ctx.quiet,
-        verify: ctx.verify,
-    };
-
     let rt = tokio::runtime::Runtime::new()?;
     match mode {
-        SubmitMode::WithRestack => rt.block_on(submit_with_restack_impl(&git, ctx, opts)),
-        SubmitMode::NoRestack => rt.block_on(submit_no_restack_impl(&git, ctx, opts)),
+        SubmitMode::WithRestack => rt.block_on(submit_with_restack_impl(git, ctx, opts)),
+        SubmitMode::NoRestack => rt.block_on(submit_no_restack_impl(git, ctx, opts)),
     }
 }
 
 /// Async implementation for WithRestack mode.
-async fn submit_with_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions) -> Result<()> {
-    use crate::engine::runner::run_async_command;
+async fn submit_with_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions) -> CliResult {
+    use crate::engine::runner::{run_async_command, run_async_command_with_requirements};
 
-    let command = SubmitWithRestackCommand::new(opts.clone());
+    let command = SubmitWithRestackCommand::new(opts.clone(), pre_push_command(ctx, &opts));
 
-    // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+    // Run through async command lifecycle for gating. Offline submits only
+    // touch local state, so remote and auth capabilities are not required.
+    let result = if opts.offline {
+        run_async_command_with_requirements(&command, git, ctx, &requirements::MUTATING).await
+    } else {
+        run_async_command(&command, git, ctx).await
+    };
 
     match result {
         Ok(output) => match output {
@@ -386,21 +1999,35 @@ async fn submit_with_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions)
                 // Gating passed, now execute submit operations
                 execute_submit(git, ctx, &opts).await
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Submit failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
 /// Async implementation for NoRestack mode.
-async fn submit_no_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions) -> Result<()> {
-    use crate::engine::runner::run_async_command;
-
-    let command = SubmitNoRestackCommand::new(opts.clone());
-
-    // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+async fn submit_no_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions) -> CliResult {
+    use crate::engine::runner::{run_async_command, run_async_command_with_requirements};
+
+    let command = SubmitNoRestackCommand::new(opts.clone(), pre_push_command(ctx, &opts));
+
+    // Run through async command lifecycle for gating. Offline submits only
+    // touch local state, so remote and auth capabilities are not required.
+    let result = if opts.offline {
+        run_async_command_with_requirements(
+            &command,
+            git,
+            ctx,
+            &requirements::MUTATING_METADATA_ONLY,
+        )
+        .await
+    } else {
+        run_async_command(&command, git, ctx).await
+    };
 
     match result {
         Ok(output) => match output {
@@ -408,51 +2035,58 @@ async fn submit_no_restack_impl(git: &Git, ctx: &Context, opts: SubmitOptions) -
                 // Gating passed, now execute submit operations (with alignment check)
                 execute_submit(git, ctx, &opts).await
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Submit failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
-/// Execute submit operations after gating has passed.
-///
-/// This function performs the actual submit work: pushing branches and creating/updating PRs.
-/// The gating and mode dispatch have already been handled by `submit_with_restack_impl` or
-/// `submit_no_restack_impl`.
-async fn execute_submit(git: &Git, ctx: &Context, opts: &SubmitOptions) -> Result<()> {
-    use crate::cli::commands::auth::get_github_token;
-    use crate::engine::scan::scan;
-
-    let snapshot = scan(git)?;
-
-    // Check if we're in bare repo mode (for alignment check)
-    let is_bare = git.info()?.work_dir.is_none();
-
-    // Check authentication
-    let token = match get_github_token() {
-        Ok(t) => t,
-        Err(_) => bail!("Not authenticated. Run 'lattice auth' first."),
-    };
-
-    // Get remote URL and create forge
-    let remote_url = git
-        .remote_url("origin")?
-        .ok_or_else(|| anyhow::anyhow!("No 'origin' remote configured."))?;
-
-    let forge = crate::forge::create_forge(&remote_url, &token, None)?;
+/// The branches a submit covers, bottom-up, before snapshot branches are
+/// filtered out.
+fn submit_scope(snapshot: &RepoSnapshot, opts: &SubmitOptions) -> CliResult<Vec<BranchName>> {
+    // Every stack, one after the other; the current branch doesn't matter
+    if opts.all {
+        return Ok(stacks_needing_submit(snapshot, opts.update_only)
+            .into_iter()
+            .flat_map(|(_, branches)| branches)
+            .collect());
+    }
 
     // Get current branch
     let current = snapshot
         .current_branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Not on a branch."))?;
+        .ok_or_else(|| CliError::usage("Not on a branch."))?;
 
     // Check current branch is not a snapshot (refuse early)
-    check_current_branch_not_snapshot(current, &snapshot)?;
+    check_current_branch_not_snapshot(current, snapshot)?;
 
     // Determine branches to submit
-    let branches = if opts.stack {
+    let branches = if let Some(selected) = &opts.branches {
+        // Updates go to existing PRs, whose bases are already on the remote
+        if opts.update_only {
+            return Ok(super::restack::topological_sort(selected, snapshot));
+        }
+        // Selected branches need their ancestors' PRs as bases
+        let mut all = Vec::new();
+        for branch in selected {
+            all.extend(
+                snapshot
+                    .graph
+                    .ancestors(branch)
+                    .into_iter()
+                    .filter(|a| snapshot.metadata.contains_key(a)),
+            );
+            all.push(branch.clone());
+        }
+        all.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        all.dedup();
+        super::restack::topological_sort(&all, snapshot)
+    } else if opts.stack {
         // Include ancestors and descendants
         let mut all = snapshot.graph.ancestors(current);
         all.reverse(); // Bottom-up order
@@ -467,41 +2101,210 @@ async fn execute_submit(git: &Git, ctx: &Context, opts: &SubmitOptions) -> Resul
         all.push(current.clone());
         all
     };
+    Ok(branches)
+}
+
+/// The `submit.pre_push` check to run, unless this submit can't push.
+///
+/// Dry runs push nothing, and `--no-verify` skips pre-push checks along with
+/// git's own pre-push hook.
+fn pre_push_command(ctx: &Context, opts: &SubmitOptions) -> Option<String> {
+    if opts.dry_run || !opts.verify {
+        return None;
+    }
+    crate::core::config::Config::load(ctx.cwd.as_deref())
+        .ok()
+        .and_then(|r| r.config.submit_pre_push().map(str::to_string))
+}
+
+/// Plan steps that run `command` on each tracked branch in scope, in push
+/// order, so a failure stops the submit before anything is pushed.
+fn pre_push_checks(
+    snapshot: &RepoSnapshot,
+    opts: &SubmitOptions,
+    command: Option<&str>,
+) -> Vec<PlanStep> {
+    let Some(command) = command else {
+        return Vec::new();
+    };
+    // A bad scope is reported by execute_submit
+    let Ok(scope) = submit_scope(snapshot, opts) else {
+        return Vec::new();
+    };
+    let (branches, _) = filter_snapshot_branches(scope, snapshot);
+    branches
+        .into_iter()
+        .filter(|branch| snapshot.metadata.contains_key(branch))
+        .map(|branch| PlanStep::PrePushCheck {
+            branch: branch.to_string(),
+            command: command.to_string(),
+        })
+        .collect()
+}
+
+/// Execute submit operations after gating has passed.
+///
+/// This function performs the actual submit work: pushing branches and creating/updating PRs.
+/// The gating and mode dispatch have already been handled by `submit_with_restack_impl` or
+/// `submit_no_restack_impl`.
+async fn execute_submit(git: &Git, ctx: &Context, opts: &SubmitOptions) -> CliResult {
+    use crate::engine::scan::scan;
+
+    let snapshot = scan(git)?;
+
+    // Check if we're in bare repo mode (for alignment check)
+    let is_bare = git.info()?.work_dir.is_none();
+
+    // Create the forge unless offline; queueing actions needs no auth
+    let forge = if opts.offline {
+        None
+    } else {
+        Some(super::origin_forge(git)?)
+    };
+
+    // Determine branches to submit
+    let branches = submit_scope(&snapshot, opts)?;
 
     // Filter out snapshot branches (Milestone 5.10)
     let (branches, excluded) = filter_snapshot_branches(branches, &snapshot);
     report_excluded_snapshots(&excluded, opts.quiet);
 
+    // --all groups the branches by stack for its plan and summary
+    let stacks = if opts.all {
+        stacks_needing_submit(&snapshot, opts.update_only)
+    } else {
+        Vec::new()
+    };
+    if opts.all && branches.is_empty() {
+        if !opts.quiet {
+            println!("{}", i18n::t("submit-all-stacks-up"));
+        }
+        return Ok(());
+    }
+
     // Check we have branches to submit after filtering
     if branches.is_empty() {
-        bail!(
+        return Err(CliError::usage(
             "No branches to submit after filtering.\n\n\
-             All branches in the scope were excluded (snapshot branches cannot be submitted)."
-        );
+             All branches in the scope were excluded (snapshot branches cannot be submitted).",
+        ));
     }
 
+    // Nothing is pushed if a collaborator holds one of the branches
+    check_branch_locks(git, ctx, &branches, opts.offline)?;
+
+    // A submit of the same branches from another worktree goes first, and
+    // what it left up to date is skipped
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+    let (_claim, already_submitted) = if opts.dry_run {
+        (None, HashSet::new())
+    } else {
+        let (claim, waited) = claim_branches(git, &paths, &branches, opts.quiet).await?;
+        (Some(claim), waited)
+    };
+    let snapshot = if already_submitted.is_empty() {
+        snapshot
+    } else {
+        scan(git)?
+    };
+    let already_submitted: HashSet<BranchName> = already_submitted
+        .into_iter()
+        .filter(|branch| !needs_submit(&snapshot, branch, false))
+        .collect();
+
     // Per SPEC.md §4.6.7: Even with --no-restack, check alignment in bare repos
     if is_bare && opts.no_restack {
         check_and_normalize_alignment(opts.quiet, git, &snapshot, &branches)?;
     }
 
+    // Show per-PR review size before anything is pushed
+    let review_sizes = compute_review_sizes(git, &snapshot, &branches)?;
+    let thresholds = ReviewSizeThresholds::from_config(ctx.cwd.as_deref());
+    report_review_sizes(&review_sizes, thresholds, opts.quiet);
+
+    // Catch LFS objects that won't upload before git fails mid-push
+    if !opts.offline {
+        check_lfs_push(git, &snapshot, &branches, opts.verify);
+    }
+
+    // Nudge (or block) re-pushes stacked on PRs with failing required checks
+    if let Some(forge) = forge.as_deref() {
+        let require_green = crate::core::config::Config::load(ctx.cwd.as_deref())
+            .map(|r| r.config.submit_require_green_downstack())
+            .unwrap_or(false);
+        check_downstack_checks(
+            git,
+            forge,
+            &snapshot,
+            &branches,
+            require_green && !opts.dry_run,
+        )
+        .await?;
+    }
+
+    // Stop before overwriting commits pushed from another machine
+    let reconciled = if opts.offline {
+        HashMap::new()
+    } else {
+        let diverged = find_diverged(git, &snapshot, &branches);
+        reconcile_diverged(git, ctx, &snapshot, &diverged, opts.dry_run)?
+    };
+
+    // New PRs request the command-line reviewers plus the configured ones
+    let config = crate::core::config::Config::load(ctx.cwd.as_deref())
+        .map(|r| r.config)
+        .unwrap_or_default();
+    let requested = requested_reviewers(
+        git,
+        &snapshot,
+        &branches,
+        opts,
+        config.submit_reviewer_rules(),
+    )?;
+
+    if opts.confirm && !opts.dry_run {
+        let preview = preview_submit(&snapshot, &branches, &reconciled, opts, &requested);
+        if !confirm_preview(ctx, &preview)? {
+            println!("{}", i18n::t("prompt-aborted"));
+            return Ok(());
+        }
+    }
+
+    if opts.all && !opts.confirm && (opts.dry_run || !opts.quiet) {
+        print_stacks_plan(&snapshot, &stacks);
+        println!();
+    }
+
     if opts.dry_run {
-        println!("Would submit {} branch(es):", branches.len());
+        if opts.all {
+            return Ok(());
+        }
+        println!(
+            "{}",
+            i18n::t_args("submit-would-submit-branch", &[("count", &branches.len())])
+        );
         for branch in &branches {
-            let has_pr = snapshot
-                .metadata
-                .get(branch)
-                .map(|s| !matches!(s.metadata.pr, crate::core::metadata::schema::PrState::None))
-                .unwrap_or(false);
-            let action = if has_pr { "update" } else { "create" };
-            println!("  {} - {} PR", branch, action);
+            let metadata = snapshot.metadata.get(branch).map(|s| &s.metadata);
+            let has_pr = metadata.is_some_and(|m| m.pr.is_linked());
+            // Offline, the PR's state is only known from the cache
+            let state = metadata
+                .filter(|_| opts.offline)
+                .and_then(cached_pr::stale_label);
+            let key = match (has_pr, state.is_some()) {
+                (true, true) => "submit-plan-update-state",
+                (true, false) => "submit-plan-update",
+                (false, true) => "submit-plan-create-state",
+                (false, false) => "submit-plan-create",
+            };
+            let state = state.unwrap_or_default();
+            println!(
+                "  {}",
+                i18n::t_args(key, &[("branch", branch), ("state", &state)])
+            );
         }
         return Ok(());
     }
 
-    // Submit each branch
-    use crate::forge::CreatePrRequest;
-
     // Get working directory for git push commands
     let cwd = git
         .info()?
@@ -510,195 +2313,678 @@ async fn execute_submit(git: &Git, ctx: &Context, opts: &SubmitOptions) -> Resul
         .or_else(|| ctx.cwd.clone())
         .unwrap_or_else(|| std::env::current_dir().unwrap());
 
+    // Remote steps are queued here once offline
+    let mut queue = PendingQueue::load(&paths).context("Failed to read pending actions")?;
+    let mut offline = opts.offline;
+    // PRs created so far, for templates that mention the parent's PR
+    let mut created_prs = HashMap::new();
+    let mut updated_prs = Vec::new();
+    // What happened to each branch, for the per-stack summary of --all
+    let mut outcomes: HashMap<BranchName, BranchOutcome> = HashMap::new();
+    // URLs of the PRs created or updated, for --view
+    let mut pr_urls: Vec<String> = Vec::new();
+    let body_generator = crate::core::config::Config::load(ctx.cwd.as_deref())
+        .ok()
+        .and_then(|r| r.config.submit_body_generator().map(str::to_string));
+
+    // Untracked branches and ones left to their remote copy drop out first
+    let mut to_push = Vec::new();
     for branch in &branches {
         let scanned = match snapshot.metadata.get(branch) {
             Some(s) => s,
             None => {
                 if !opts.quiet {
-                    println!("Skipping untracked branch '{}'", branch);
+                    println!(
+                        "{}",
+                        i18n::t_args("submit-skipping-untracked-branch", &[("branch", &branch)])
+                    );
                 }
                 continue;
             }
         };
 
-        // Push branch to remote before creating/updating PR
-        if !opts.quiet {
-            println!("Pushing '{}'...", branch);
+        if already_submitted.contains(branch) {
+            if !opts.quiet {
+                println!(
+                    "{}",
+                    i18n::t_args("submit-skipping-submitted-another", &[("branch", &branch)])
+                );
+            }
+            outcomes.insert(branch.clone(), BranchOutcome::Skipped);
+            continue;
         }
-        let mut push_args = vec!["push"];
-        if !opts.verify {
-            push_args.push("--no-verify");
+
+        // A kept branch is pushed over exactly the remote tip that was shown
+        let lease = match reconciled.get(branch) {
+            Some((Reconcile::KeepLocal, remote_tip)) => {
+                Some(format!("--force-with-lease={}:{}", branch, remote_tip))
+            }
+            Some(_) => {
+                if !opts.quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args("submit-skipping-pushed-elsewhere", &[("branch", &branch)])
+                    );
+                }
+                outcomes.insert(branch.clone(), BranchOutcome::Skipped);
+                continue;
+            }
+            None => None,
+        };
+        to_push.push((branch, &scanned.metadata, lease));
+    }
+
+    // Push branches to remote before creating/updating PRs. Once restacked
+    // the pushes are independent, so they run concurrently; results are
+    // reported in stack order.
+    let parallelism = config.submit_parallelism();
+    let mut pushed = Vec::new();
+    if offline {
+        for (branch, metadata, _) in &to_push {
+            queue.enqueue(push_action(opts, branch, metadata));
+            pushed.push((*branch).clone());
         }
-        if opts.force {
-            push_args.push("--force-with-lease");
+    } else {
+        if !opts.quiet {
+            for (branch, metadata, _) in &to_push {
+                let remote = metadata.remote_or("origin");
+                if remote == "origin" {
+                    println!("{}", i18n::t_args("submit-pushing", &[("branch", &branch)]));
+                } else {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "submit-pushing-to-remote",
+                            &[("branch", &branch), ("remote", &remote)],
+                        )
+                    );
+                }
+            }
         }
-        push_args.extend(["origin", branch.as_str()]);
-        let push_result = std::process::Command::new("git")
-            .args(&push_args)
-            .current_dir(&cwd)
-            .output()?;
-
-        if !push_result.status.success() {
+        let commands: Vec<Vec<String>> = to_push
+            .iter()
+            .map(|(branch, metadata, lease)| push_args(opts, branch, metadata, lease.as_deref()))
+            .collect();
+        let results = run_bounded(&commands, parallelism, |args| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&cwd)
+                .output()
+        });
+
+        let mut submitted = Vec::new();
+        for ((branch, metadata, _), push_result) in to_push.iter().zip(results) {
+            let push_result = push_result?;
             let stderr = String::from_utf8_lossy(&push_result.stderr);
-            // Check if it's just "already up to date" which is fine
-            if !stderr.contains("Everything up-to-date") {
-                eprintln!("  Failed to push '{}': {}", branch, stderr.trim());
+            if push_result.status.success() || stderr.contains("Everything up-to-date") {
+                if let Some(tip) = snapshot.branches.get(*branch) {
+                    submitted.push(((*branch).clone(), tip.clone()));
+                }
+            } else if is_offline_git_failure(&stderr) {
+                enter_offline_mode(&mut offline, stderr.trim());
+                queue.enqueue(push_action(opts, branch, metadata));
+            } else {
+                // Anything but "Everything up-to-date" skips the branch
+                eprintln!(
+                    "  {}",
+                    i18n::t_args(
+                        "submit-failed-push",
+                        &[("branch", &branch), ("stderr", &stderr.trim())],
+                    )
+                );
+                outcomes.insert((*branch).clone(), BranchOutcome::Failed);
                 continue;
             }
+            pushed.push((*branch).clone());
         }
+        record_submitted(git, ctx, &submitted);
+    }
 
-        // Determine base (parent branch or trunk)
-        let base = scanned.metadata.parent.name().to_string();
-
-        // Check if PR exists
-        use crate::core::metadata::schema::PrState;
-        match &scanned.metadata.pr {
-            PrState::Linked { number, .. } => {
-                // Update existing PR
-                if !opts.quiet {
-                    println!("Updating PR #{} for '{}'...", number, branch);
+    // PRs go up wave by wave, each wave concurrently. Editors for --edit
+    // open one at a time.
+    let pr_parallelism = if opts.edit { 1 } else { parallelism };
+    for wave in pr_waves(&snapshot, &pushed) {
+        // The forge is only absent when offline
+        let forge = match forge.as_deref() {
+            Some(forge) if !offline => forge,
+            _ => {
+                for branch in &wave {
+                    let metadata = &snapshot.metadata[branch].metadata;
+                    queue_pr_actions(
+                        &mut queue,
+                        opts,
+                        &requested,
+                        branch,
+                        metadata.parent.name(),
+                        &metadata.pr,
+                        || {
+                            new_pr_content(
+                                git,
+                                &snapshot,
+                                branch,
+                                &created_prs,
+                                body_generator.as_deref(),
+                                &cwd,
+                                |w| eprintln!("{}", w),
+                            )
+                        },
+                    );
+                    outcomes.insert(branch.clone(), BranchOutcome::Queued);
                 }
+                continue;
+            }
+        };
 
-                // Fetch existing PR body and merge with updated stack comment
-                let existing_body = forge.get_pr(*number).await.ok().and_then(|pr| pr.body);
-                let body = generate_merged_body(existing_body.as_deref(), &snapshot, branch);
+        let steps: Vec<CliResult<PrStep>> = {
+            let cx = PrContext {
+                git,
+                forge,
+                snapshot: &snapshot,
+                paths: &paths,
+                opts,
+                requested: &requested,
+                created: &created_prs,
+                cwd: &cwd,
+                body_generator: body_generator.as_deref(),
+            };
+            stream::iter(&wave)
+                .map(|branch| submit_pr(&cx, branch))
+                .buffered(pr_parallelism)
+                .collect()
+                .await
+        };
 
-                let update_req = crate::forge::UpdatePrRequest {
-                    number: *number,
-                    base: Some(base),
-                    title: None,
-                    body: Some(body),
-                };
+        for (branch, step) in wave.iter().zip(steps) {
+            let step = step?;
+            step.print();
+            if let Some(reason) = &step.offline {
+                enter_offline_mode(&mut offline, reason);
+            }
+            for entry in step.queued.entries {
+                queue.enqueue(entry.action);
+            }
+            if let Some(number) = step.created {
+                created_prs.insert(branch.clone(), number);
+            }
+            if let Some(pr) = step.updated {
+                updated_prs.push((branch.clone(), pr));
+            }
+            if let Some(url) = step.url {
+                pr_urls.push(url);
+            }
+            if let Some(outcome) = step.outcome {
+                outcomes.insert(branch.clone(), outcome);
+            }
+        }
+    }
 
-                match forge.update_pr(update_req).await {
-                    Ok(pr) => {
-                        if !opts.quiet {
-                            println!("  Updated: {}", pr.url);
-                        }
+    cached_pr::record(git, ctx, "submit", &updated_prs);
+
+    // After all PRs are created/updated, refresh stack comments for all PRs
+    // This ensures newly created PRs are reflected in existing PR descriptions
+    if let Some(forge) = forge.as_deref().filter(|_| !offline) {
+        if !opts.quiet {
+            println!("{}", i18n::t("submit-refreshing-stack-comments"));
+        }
+
+        // Use forge-based lookup since metadata may not have been persisted yet
+        let updated =
+            update_stack_comments_for_branches_from_forge(forge, &snapshot, &branches, opts.quiet)
+                .await?;
+
+        if updated > 0 && !opts.quiet {
+            println!(
+                "  {}",
+                i18n::t_args("submit-updated-pr-description", &[("updated", &updated)])
+            );
+        }
+    }
+
+    queue
+        .save(&paths)
+        .context("Failed to record pending actions")?;
+    if offline && !opts.quiet {
+        println!(
+            "{}",
+            i18n::t_args("submit-queued-remote-action", &[("count", &queue.len())])
+        );
+    }
+
+    if opts.all && !opts.quiet {
+        println!(
+            "\n{}",
+            i18n::t_args("submit-submitted-stack", &[("count", &stacks.len())])
+        );
+        for line in stack_summary_lines(&stacks, &outcomes) {
+            println!("{}", line);
+        }
+    }
+
+    if opts.view && !pr_urls.is_empty() {
+        super::pr::view_urls(&pr_urls);
+    }
+
+    if outcomes.values().any(|o| *o == BranchOutcome::Created) {
+        hints::show(Hint::Submit, &[]);
+    }
+
+    Ok(())
+}
+
+/// The queued form of a branch's push.
+fn push_action(
+    opts: &SubmitOptions,
+    branch: &BranchName,
+    metadata: &BranchMetadataV2,
+) -> PendingAction {
+    PendingAction::Push {
+        branch: branch.to_string(),
+        force: opts.force,
+        no_verify: !opts.verify,
+        remote: metadata.remote.clone(),
+    }
+}
+
+/// Arguments to `git` that push a branch to its remote.
+fn push_args(
+    opts: &SubmitOptions,
+    branch: &BranchName,
+    metadata: &BranchMetadataV2,
+    lease: Option<&str>,
+) -> Vec<String> {
+    let mut args = vec!["push".to_string()];
+    if !opts.verify {
+        args.push("--no-verify".to_string());
+    }
+    if let Some(lease) = lease {
+        args.push(lease.to_string());
+    } else if opts.force {
+        args.push("--force-with-lease".to_string());
+    }
+    args.push(metadata.remote_or("origin").to_string());
+    args.push(branch.to_string());
+    args
+}
+
+/// What a branch's PR step needs from the submit run.
+struct PrContext<'a> {
+    git: &'a Git,
+    forge: &'a dyn Forge,
+    snapshot: &'a RepoSnapshot,
+    paths: &'a LatticePaths,
+    opts: &'a SubmitOptions,
+    requested: &'a HashMap<BranchName, Reviewers>,
+    /// PRs created in earlier waves
+    created: &'a HashMap<BranchName, u64>,
+    cwd: &'a Path,
+    body_generator: Option<&'a str>,
+}
+
+impl PrContext<'_> {
+    /// Title and body for a new PR on `branch`.
+    ///
+    /// Both come from the PR templates, and the body from the body
+    /// generator when one is configured. A failing generator falls back to
+    /// the template body, reported through `warn`.
+    fn new_pr_content(
+        &self,
+        branch: &BranchName,
+        warn: impl FnOnce(String),
+    ) -> (String, Option<String>) {
+        new_pr_content(
+            self.git,
+            self.snapshot,
+            branch,
+            self.created,
+            self.body_generator,
+            self.cwd,
+            warn,
+        )
+    }
+}
+
+/// Title and body for a new PR on `branch`, with the closing reference for
+/// its issue. See [`PrContext::new_pr_content`].
+fn new_pr_content(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branch: &BranchName,
+    created: &HashMap<BranchName, u64>,
+    body_generator: Option<&str>,
+    cwd: &Path,
+    warn: impl FnOnce(String),
+) -> (String, Option<String>) {
+    let commits = commit_subjects(git, snapshot, branch);
+    let title = new_pr_title(snapshot, branch, created, &commits);
+    let mut body = new_pr_body(snapshot, branch, created, &commits);
+    if let Some(command) = body_generator {
+        match generator_input(git, snapshot, branch, created, body.as_deref())
+            .and_then(|input| run_body_generator(command, cwd, &input))
+        {
+            Ok(generated) => body = generated,
+            Err(e) => warn(format!("  {:#}; using the default body.", e)),
+        }
+    }
+    let issue = snapshot
+        .metadata
+        .get(branch)
+        .and_then(|scanned| scanned.metadata.issue.as_ref());
+    (title, with_closing_reference(body, issue))
+}
+
+/// What a branch's PR step did.
+///
+/// Output is held back so that steps running concurrently print in stack
+/// order.
+#[derive(Default)]
+struct PrStep {
+    /// Lines for stdout, or stderr when flagged, in order
+    output: Vec<(String, bool)>,
+    outcome: Option<BranchOutcome>,
+    url: Option<String>,
+    /// Number of the PR created
+    created: Option<u64>,
+    /// The PR as updated, for the state cached in metadata
+    updated: Option<PullRequest>,
+    /// Actions queued after finding the forge unreachable
+    queued: PendingQueue,
+    /// Why the forge was unreachable
+    offline: Option<String>,
+}
+
+impl PrStep {
+    fn say(&mut self, line: String) {
+        self.output.push((line, false));
+    }
+
+    fn warn(&mut self, line: String) {
+        self.output.push((line, true));
+    }
+
+    fn print(&self) {
+        for (line, to_stderr) in &self.output {
+            if *to_stderr {
+                eprintln!("{}", line);
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+
+    /// Queue the branch's PR actions for `lattice sync` instead.
+    ///
+    /// `new_pr` is the title and body already rendered for a new PR, if
+    /// any; otherwise they're rendered when a creation is queued.
+    fn queue_instead(
+        &mut self,
+        cx: &PrContext<'_>,
+        branch: &BranchName,
+        metadata: &BranchMetadataV2,
+        new_pr: Option<(String, Option<String>)>,
+        reason: String,
+    ) {
+        let mut warnings = Vec::new();
+        queue_pr_actions(
+            &mut self.queued,
+            cx.opts,
+            cx.requested,
+            branch,
+            metadata.parent.name(),
+            &metadata.pr,
+            || new_pr.unwrap_or_else(|| cx.new_pr_content(branch, |w| warnings.push(w))),
+        );
+        for warning in warnings {
+            self.warn(warning);
+        }
+        self.outcome = Some(BranchOutcome::Queued);
+        self.offline = Some(reason);
+    }
+}
+
+/// Create or update `branch`'s PR, which targets its parent.
+async fn submit_pr(cx: &PrContext<'_>, branch: &BranchName) -> CliResult<PrStep> {
+    use crate::core::metadata::schema::PrState;
+    use crate::forge::CreatePrRequest;
+
+    let opts = cx.opts;
+    let forge = cx.forge;
+    let metadata = &cx.snapshot.metadata[branch].metadata;
+    let base = metadata.parent.name().to_string();
+    let mut step = PrStep::default();
+
+    // Check if PR exists
+    match &metadata.pr {
+        PrState::Linked { number, .. } => {
+            // Update existing PR
+            if !opts.quiet {
+                step.say(format!("Updating PR #{} for '{}'...", number, branch));
+            }
+
+            // Merge the updated stack comment into the existing body. If
+            // the body can't be fetched or merged, only the base changes.
+            let stack_comment = generate_stack_comment_for_branch(cx.snapshot, branch);
+            let body = match forge.get_pr(*number).await {
+                Ok(pr) => protected_body(
+                    cx.paths,
+                    branch,
+                    *number,
+                    pr.body.as_deref(),
+                    &stack_comment,
+                    opts.quiet,
+                ),
+                Err(_) => None,
+            };
+            let wrote_body = body.is_some();
+
+            let update_req = crate::forge::UpdatePrRequest {
+                number: *number,
+                base: Some(base),
+                title: None,
+                body,
+            };
+
+            match forge.update_pr(update_req).await {
+                Ok(pr) => {
+                    if wrote_body {
+                        record_stack_comment(cx.paths, branch, *number, &stack_comment);
                     }
-                    Err(e) => {
-                        eprintln!("  Failed to update PR: {}", e);
+                    if !opts.quiet {
+                        step.say(format!("  Updated: {}", pr.url));
                     }
+                    step.outcome = Some(BranchOutcome::Updated);
+                    step.url = Some(pr.url.clone());
+                    step.updated = Some(pr);
                 }
+                Err(e) if is_offline_forge_error(&e) => {
+                    step.queue_instead(cx, branch, metadata, None, e.to_string());
+                    return Ok(step);
+                }
+                Err(e) => {
+                    step.warn(format!("  Failed to update PR: {}", e));
+                    step.outcome = Some(BranchOutcome::Failed);
+                }
+            }
 
-                // Handle draft toggle
-                if opts.publish {
-                    if let Err(e) = forge.set_draft(*number, false).await {
-                        eprintln!("  Failed to publish PR: {}", e);
-                    }
-                } else if opts.draft {
-                    if let Err(e) = forge.set_draft(*number, true).await {
-                        eprintln!("  Failed to convert to draft: {}", e);
+            // Handle draft toggle
+            if opts.publish || opts.draft {
+                let draft = !opts.publish;
+                match forge.set_draft(*number, draft).await {
+                    Ok(()) => {}
+                    Err(e) if is_offline_forge_error(&e) => {
+                        step.queued.enqueue(PendingAction::SetDraft {
+                            number: *number,
+                            draft,
+                        });
+                        step.offline = Some(e.to_string());
                     }
+                    Err(e) if draft => step.warn(format!("  Failed to convert to draft: {}", e)),
+                    Err(e) => step.warn(format!("  Failed to publish PR: {}", e)),
+                }
+            }
+        }
+        PrState::None => {
+            if opts.update_only {
+                if !opts.quiet {
+                    step.say(format!(
+                        "Skipping '{}' (no existing PR, --update-only)",
+                        branch
+                    ));
                 }
+                step.outcome = Some(BranchOutcome::Skipped);
+                return Ok(step);
             }
-            PrState::None => {
-                if opts.update_only {
+
+            // Try to find existing PR by head
+            let found = match forge.find_pr_by_head(branch.as_str()).await {
+                Ok(found) => found,
+                Err(e) if is_offline_forge_error(&e) => {
+                    step.queue_instead(cx, branch, metadata, None, e.to_string());
+                    return Ok(step);
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            match found {
+                Some(existing) => {
                     if !opts.quiet {
-                        println!("Skipping '{}' (no existing PR, --update-only)", branch);
+                        step.say(format!(
+                            "Found existing PR #{} for '{}', linking...",
+                            existing.number, branch
+                        ));
                     }
-                    continue;
+                    // Would update metadata here
+                    step.outcome = Some(BranchOutcome::Updated);
+                    step.url = Some(existing.url);
                 }
-
-                // Try to find existing PR by head
-                match forge.find_pr_by_head(branch.as_str()).await? {
-                    Some(existing) => {
-                        if !opts.quiet {
-                            println!(
-                                "Found existing PR #{} for '{}', linking...",
-                                existing.number, branch
-                            );
-                        }
-                        // Would update metadata here
+                None => {
+                    // Create new PR
+                    if !opts.quiet {
+                        step.say(format!("Creating PR for '{}'...", branch));
                     }
-                    None => {
-                        // Create new PR
-                        if !opts.quiet {
-                            println!("Creating PR for '{}'...", branch);
-                        }
 
-                        // Get commit message for title
-                        let title = format!("{}", branch);
-
-                        // Create PR initially without stack comment body
-                        // (we'll update it immediately after to include correct PR number)
-                        let create_req = CreatePrRequest {
-                            head: branch.as_str().to_string(),
-                            base,
-                            title,
-                            body: None,
-                            draft: opts.draft,
-                        };
-
-                        match forge.create_pr(create_req).await {
-                            Ok(pr) => {
-                                if !opts.quiet {
-                                    println!("  Created: {}", pr.url);
-                                }
-                                // Would update metadata with PR linkage here
-
-                                // Request reviewers if specified
-                                if opts.reviewers.is_some() || opts.team_reviewers.is_some() {
-                                    let reviewers = crate::forge::Reviewers {
-                                        users: opts
-                                            .reviewers
-                                            .as_deref()
-                                            .map(|r| {
-                                                r.split(',').map(|s| s.trim().to_string()).collect()
-                                            })
-                                            .unwrap_or_default(),
-                                        teams: opts
-                                            .team_reviewers
-                                            .as_deref()
-                                            .map(|r| {
-                                                r.split(',').map(|s| s.trim().to_string()).collect()
-                                            })
-                                            .unwrap_or_default(),
-                                    };
-                                    if let Err(e) =
-                                        forge.request_reviewers(pr.number, reviewers).await
-                                    {
-                                        eprintln!("  Failed to request reviewers: {}", e);
-                                    }
-                                }
+                    // The body goes up without the stack comment (we'll add it
+                    // immediately after with the correct PR number)
+                    let (title, mut body) = cx.new_pr_content(branch, |w| step.warn(w));
+                    if opts.edit {
+                        body = edit_pr_body(cx.git, branch, body)?;
+                    }
+                    let create_req = CreatePrRequest {
+                        head: branch.as_str().to_string(),
+                        base,
+                        title: title.clone(),
+                        body: body.clone(),
+                        draft: opts.draft,
+                    };
+
+                    match forge.create_pr(create_req).await {
+                        Ok(pr) => {
+                            if !opts.quiet {
+                                step.say(format!("  Created: {}", pr.url));
                             }
-                            Err(e) => {
-                                eprintln!("  Failed to create PR: {}", e);
+                            step.created = Some(pr.number);
+                            step.outcome = Some(BranchOutcome::Created);
+                            step.url = Some(pr.url.clone());
+                            let _ = EventLedger::new(cx.git).append(Event::branch_lifecycle(
+                                branch.as_str(),
+                                BranchStage::Submitted,
+                            ));
+                            // Would update metadata with PR linkage here
+
+                            // Request reviewers if specified or configured
+                            if let Some(reviewers) =
+                                cx.requested.get(branch).filter(|r| !r.is_empty())
+                            {
+                                if let Err(e) =
+                                    forge.request_reviewers(pr.number, reviewers.clone()).await
+                                {
+                                    step.warn(format!("  Failed to request reviewers: {}", e));
+                                }
                             }
                         }
+                        Err(e) if is_offline_forge_error(&e) => {
+                            step.queue_instead(
+                                cx,
+                                branch,
+                                metadata,
+                                Some((title, body)),
+                                e.to_string(),
+                            );
+                        }
+                        Err(e) => {
+                            step.warn(format!("  Failed to create PR: {}", e));
+                            step.outcome = Some(BranchOutcome::Failed);
+                        }
                     }
                 }
             }
         }
     }
 
-    // After all PRs are created/updated, refresh stack comments for all PRs
-    // This ensures newly created PRs are reflected in existing PR descriptions
-    if !opts.dry_run {
-        if !opts.quiet {
-            println!("Refreshing stack comments...");
-        }
-
-        // Use forge-based lookup since metadata may not have been persisted yet
-        let updated = update_stack_comments_for_branches_from_forge(
-            forge.as_ref(),
-            &snapshot,
-            &branches,
-            opts.quiet,
-        )
-        .await?;
+    Ok(step)
+}
 
-        if updated > 0 && !opts.quiet {
-            println!("  Updated {} PR description(s)", updated);
-        }
+/// Switch to offline mode, announcing it the first time.
+fn enter_offline_mode(offline: &mut bool, reason: &str) {
+    if !*offline {
+        *offline = true;
+        eprintln!(
+            "{}",
+            i18n::t_args("submit-warning-remote-unreachable", &[("reason", &reason)])
+        );
     }
+}
 
-    if opts.view {
-        // Would open PR URLs in browser
-        println!("\nUse 'lattice pr --stack' to view PRs.");
-    }
+/// Split a comma-separated reviewer list.
+fn split_reviewer_list(list: Option<&str>) -> Vec<String> {
+    list.map(|r| r.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default()
+}
 
-    Ok(())
+/// Queue the PR operations submit would perform for a branch.
+///
+/// `new_pr` renders the title and body of a PR that has to be created, so
+/// the replay opens it the way submit would have.
+fn queue_pr_actions(
+    queue: &mut PendingQueue,
+    opts: &SubmitOptions,
+    requested: &HashMap<BranchName, Reviewers>,
+    branch: &BranchName,
+    base: &str,
+    pr: &crate::core::metadata::schema::PrState,
+    new_pr: impl FnOnce() -> (String, Option<String>),
+) {
+    use crate::core::metadata::schema::PrState;
+
+    match pr {
+        PrState::Linked { number, .. } => {
+            queue.enqueue(PendingAction::UpdatePr {
+                number: *number,
+                base: base.to_string(),
+            });
+            if opts.publish || opts.draft {
+                queue.enqueue(PendingAction::SetDraft {
+                    number: *number,
+                    draft: !opts.publish,
+                });
+            }
+        }
+        PrState::None => {
+            if opts.update_only {
+                return;
+            }
+            let reviewers = requested.get(branch).cloned().unwrap_or_default();
+            let (title, body) = new_pr();
+            queue.enqueue(PendingAction::CreatePr {
+                head: branch.to_string(),
+                base: base.to_string(),
+                title,
+                body,
+                draft: opts.draft,
+                reviewers: reviewers.users,
+                team_reviewers: reviewers.teams,
+            });
+        }
+    }
 }
 
 /// Result of checking submit alignment for bare repo mode.
@@ -730,11 +3016,11 @@ fn check_submit_alignment(
     git: &Git,
     snapshot: &RepoSnapshot,
     branches: &[BranchName],
-) -> Result<AlignmentResult> {
+) -> CliResult<AlignmentResult> {
     let _trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured"))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     let mut needs_normalization = Vec::new();
 
@@ -757,16 +3043,20 @@ fn check_submit_alignment(
         }
 
         // Get branch tip
-        let branch_tip = snapshot
-            .branches
-            .get(branch)
-            .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found in branches", branch))?;
+        let branch_tip = snapshot.branches.get(branch).ok_or_else(|| {
+            CliError::new(
+                ErrorCategory::Internal,
+                format!("Branch '{}' not found in branches", branch),
+            )
+        })?;
 
         // Get parent tip
-        let parent_tip = snapshot
-            .branches
-            .get(&parent_branch)
-            .ok_or_else(|| anyhow::anyhow!("Parent branch '{}' not found", parent_name))?;
+        let parent_tip = snapshot.branches.get(&parent_branch).ok_or_else(|| {
+            CliError::new(
+                ErrorCategory::Internal,
+                format!("Parent branch '{}' not found", parent_name),
+            )
+        })?;
 
         // Check ancestry: parent.tip must be ancestor of branch.tip
         let is_ancestor = git.is_ancestor(parent_tip, branch_tip)?;
@@ -802,7 +3092,7 @@ fn normalize_base_metadata(
     git: &Git,
     snapshot: &RepoSnapshot,
     normalizations: &[BranchNormalization],
-) -> Result<()> {
+) -> CliResult {
     let store = MetadataStore::new(git);
 
     for norm in normalizations {
@@ -810,7 +3100,7 @@ fn normalize_base_metadata(
         let entry = snapshot
             .metadata
             .get(&norm.branch)
-            .ok_or_else(|| anyhow::anyhow!("Metadata not found for branch '{}'", norm.branch))?;
+            .ok_or_else(|| CliError::untracked(&norm.branch))?;
 
         // Create updated metadata with new base
         let mut updated_metadata = entry.metadata.clone();
@@ -839,7 +3129,7 @@ fn check_and_normalize_alignment(
     git: &Git,
     snapshot: &RepoSnapshot,
     branches: &[BranchName],
-) -> Result<()> {
+) -> CliResult {
     match check_submit_alignment(git, snapshot, branches)? {
         AlignmentResult::Aligned => {
             // All good, proceed with submit
@@ -852,22 +3142,19 @@ fn check_and_normalize_alignment(
 
             if !quiet {
                 println!(
-                    "Updated base metadata for {} branch(es) (no history changes).",
-                    count
+                    "{}",
+                    i18n::t_args("submit-updated-base-metadata", &[("count", &count)])
                 );
             }
             Ok(())
         }
-        AlignmentResult::NotAligned { branch, parent } => {
-            bail!(
-                "Branch '{}' is not aligned with parent '{}'.\n\n\
+        AlignmentResult::NotAligned { branch, parent } => Err(CliError::conflict(format!(
+            "Branch '{}' is not aligned with parent '{}'.\n\n\
                  The parent's tip is not an ancestor of the branch tip, which means\n\
                  the branch needs to be rebased.\n\n\
-                 Restack required. Run from a worktree and re-run `lattice submit`.",
-                branch,
-                parent
-            );
-        }
+                 Restack required. Run from a worktree and re-run `lattice submit`.",
+            branch, parent
+        ))),
     }
 }
 
@@ -890,17 +3177,406 @@ mod tests {
             team_reviewers: None,
             no_restack: false,
             view: false,
+            offline: false,
             quiet: false,
             verify: true,
+            edit: false,
+            branches: None,
+            all: false,
         };
         assert!(!opts.stack);
         assert!(!opts.draft);
     }
 
+    mod review_size {
+        use super::*;
+
+        fn size(branch: &str, files: usize, insertions: usize, deletions: usize) -> ReviewSize {
+            ReviewSize {
+                branch: BranchName::new(branch).unwrap(),
+                stat: DiffStat {
+                    files_changed: files,
+                    insertions,
+                    deletions,
+                },
+            }
+        }
+
+        const THRESHOLDS: ReviewSizeThresholds = ReviewSizeThresholds {
+            max_lines: 100,
+            max_files: 5,
+        };
+
+        #[test]
+        fn table_lists_each_branch() {
+            let table =
+                format_review_size_table(&[size("feature-a", 2, 10, 3), size("b", 1, 0, 7)]);
+            assert!(table.starts_with("Review size:"));
+            assert!(table.contains("feature-a"));
+            assert!(table.contains("+10"));
+            assert!(table.contains("-7"));
+            assert_eq!(table.lines().count(), 4);
+            // Branch column is padded so counts line up
+            assert!(table.contains("  b          "));
+        }
+
+        #[test]
+        fn no_warnings_within_thresholds() {
+            let warnings = review_size_warnings(&[size("small", 5, 60, 40)], THRESHOLDS);
+            assert!(warnings.is_empty());
+        }
+
+        #[test]
+        fn warns_on_line_threshold() {
+            let warnings = review_size_warnings(&[size("big", 1, 90, 20)], THRESHOLDS);
+            assert_eq!(warnings.len(), 1);
+            assert!(warnings[0].contains("110 lines"));
+        }
+
+        #[test]
+        fn warns_on_file_threshold() {
+            let warnings = review_size_warnings(&[size("wide", 6, 1, 0)], THRESHOLDS);
+            assert_eq!(warnings.len(), 1);
+            assert!(warnings[0].contains("6 files"));
+        }
+    }
+
+    mod lfs_push {
+        use super::*;
+
+        fn lfs(branch: &str, files: usize) -> LfsBranch {
+            LfsBranch {
+                branch: BranchName::new(branch).unwrap(),
+                files,
+            }
+        }
+
+        #[test]
+        fn no_warning_without_lfs_branches() {
+            assert!(lfs_push_warning(&[], Some(LfsPushProblem::NotInstalled), true).is_none());
+        }
+
+        #[test]
+        fn no_warning_when_push_will_upload() {
+            assert!(lfs_push_warning(&[lfs("assets", 2)], None, true).is_none());
+        }
+
+        #[test]
+        fn warns_when_lfs_is_not_installed() {
+            let warning = lfs_push_warning(
+                &[lfs("assets", 2)],
+                Some(LfsPushProblem::NotInstalled),
+                true,
+            )
+            .unwrap();
+            assert!(warning.contains("'assets' (2 file(s))"));
+            assert!(warning.contains("git-lfs is not installed"));
+            assert!(warning.contains("git lfs install"));
+        }
+
+        #[test]
+        fn warns_when_no_verify_skips_hook() {
+            let warning = lfs_push_warning(&[lfs("assets", 1)], None, false).unwrap();
+            assert!(warning.contains("--no-verify"));
+            assert!(warning.contains("without --no-verify"));
+        }
+    }
+
+    mod downstack_checks {
+        use super::*;
+        use crate::core::graph::StackGraph;
+        use crate::core::metadata::schema::{BranchMetadataV2, PrState};
+        use crate::engine::scan::ScannedMetadata;
+        use crate::forge::mock::{FailOn, MockForge};
+        use crate::forge::{CheckState, CheckStatus, ForgeError};
+        use crate::git::{GitState, RepoContext, RepoInfo};
+        use std::collections::HashMap;
+        use std::path::PathBuf;
+
+        fn name(s: &str) -> BranchName {
+            BranchName::new(s).unwrap()
+        }
+
+        /// Linear stack main <- a <- b <- c; `prs` maps branch to PR number.
+        fn stack_snapshot(prs: &[(&str, u64)]) -> RepoSnapshot {
+            let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+            let mut graph = StackGraph::new();
+            let mut metadata = HashMap::new();
+            for (child, parent) in [("a", "main"), ("b", "a"), ("c", "b")] {
+                graph.add_edge(name(child), name(parent));
+                let mut meta = BranchMetadataV2::new(name(child), name(parent), oid.clone());
+                if let Some((_, number)) = prs.iter().find(|(b, _)| *b == child) {
+                    meta.pr = PrState::linked("github", *number, "https://example.com");
+                }
+                metadata.insert(
+                    name(child),
+                    ScannedMetadata {
+                        ref_oid: oid.clone(),
+                        metadata: meta,
+                    },
+                );
+            }
+
+            RepoSnapshot {
+                info: RepoInfo {
+                    git_dir: PathBuf::from("/repo/.git"),
+                    common_dir: PathBuf::from("/repo/.git"),
+                    work_dir: Some(PathBuf::from("/repo")),
+                    context: RepoContext::Normal,
+                },
+                git_state: GitState::Clean,
+                worktree_status: Default::default(),
+                checked_out_elsewhere: Default::default(),
+                current_branch: Some(name("c")),
+                branches: HashMap::new(),
+                metadata,
+                repo_config: None,
+                trunk: Some(name("main")),
+                graph,
+                fingerprint: crate::engine::scan::compute_fingerprint(
+                    &HashMap::new(),
+                    &HashMap::new(),
+                    None,
+                ),
+                health: crate::engine::health::RepoHealthReport::new(),
+                remote_prs: None,
+            }
+        }
+
+        fn check(name: &str, state: CheckState, required: bool) -> CheckStatus {
+            CheckStatus {
+                name: name.to_string(),
+                state,
+                required,
+            }
+        }
+
+        #[test]
+        fn candidates_are_unchanged_linked_ancestors_of_repushed_prs() {
+            let snapshot = stack_snapshot(&[("a", 1), ("b", 2), ("c", 3)]);
+            let branches = vec![name("a"), name("b"), name("c")];
+
+            let candidates =
+                downstack_check_candidates(&snapshot, &branches, |b| b.as_str() == "c");
+
+            assert_eq!(candidates, vec![(name("a"), 1), (name("b"), 2)]);
+        }
+
+        #[test]
+        fn changed_ancestors_are_not_candidates() {
+            let snapshot = stack_snapshot(&[("a", 1), ("b", 2), ("c", 3)]);
+            let branches = vec![name("a"), name("b"), name("c")];
+
+            let candidates =
+                downstack_check_candidates(&snapshot, &branches, |b| b.as_str() != "a");
+
+            assert_eq!(candidates, vec![(name("a"), 1)]);
+        }
+
+        #[test]
+        fn new_prs_do_not_trigger_the_gate() {
+            let snapshot = stack_snapshot(&[("a", 1)]);
+            let branches = vec![name("a"), name("b")];
+
+            let candidates = downstack_check_candidates(&snapshot, &branches, |_| true);
+
+            assert!(candidates.is_empty());
+        }
+
+        #[tokio::test]
+        async fn reports_only_required_failures() {
+            let forge = MockForge::new()
+                .with_checks(
+                    1,
+                    vec![
+                        check("ci/test", CheckState::Failure, true),
+                        check("ci/lint", CheckState::Failure, false),
+                    ],
+                )
+                .with_checks(2, vec![check("ci/test", CheckState::Pending, true)]);
+
+            let failing = find_failing_downstack(&forge, &[(name("a"), 1), (name("b"), 2)]).await;
+
+            assert_eq!(
+                failing,
+                vec![FailingDownstack {
+                    branch: name("a"),
+                    number: 1,
+                    checks: vec!["ci/test".to_string()],
+                }]
+            );
+            assert_eq!(
+                format_failing_downstack(&failing[0]),
+                "downstack PR #1 ('a') has failing required checks: ci/test"
+            );
+        }
+
+        #[tokio::test]
+        async fn forge_errors_are_ignored() {
+            let forge = MockForge::new().fail_on(FailOn::ListChecks(ForgeError::NotImplemented(
+                "no checks".to_string(),
+            )));
+
+            assert!(find_failing_downstack(&forge, &[(name("a"), 1)])
+                .await
+                .is_empty());
+        }
+    }
+
+    mod preview {
+        use super::*;
+        use crate::core::graph::StackGraph;
+        use crate::core::metadata::schema::{BranchMetadataV2, PrState};
+        use crate::engine::scan::ScannedMetadata;
+        use crate::git::{GitState, RepoContext, RepoInfo};
+        use std::path::PathBuf;
+
+        fn name(s: &str) -> BranchName {
+            BranchName::new(s).unwrap()
+        }
+
+        fn oid() -> Oid {
+            Oid::new("abc123def4567890abc123def4567890abc12345").unwrap()
+        }
+
+        fn opts() -> SubmitOptions {
+            SubmitOptions {
+                stack: true,
+                draft: false,
+                publish: false,
+                confirm: true,
+                dry_run: false,
+                force: false,
+                always: false,
+                update_only: false,
+                reviewers: None,
+                team_reviewers: None,
+                no_restack: false,
+                view: false,
+                offline: false,
+                quiet: false,
+                verify: true,
+                edit: false,
+                branches: None,
+                all: false,
+            }
+        }
+
+        /// main <- api <- {ui, docs}. `ui` was reordered: its PR #2 last
+        /// targeted main. `api` has PR #1; the others have none.
+        fn snapshot() -> RepoSnapshot {
+            let mut metadata = HashMap::new();
+            for (child, parent) in [("api", "main"), ("ui", "api"), ("docs", "api")] {
+                let mut meta = BranchMetadataV2::new(name(child), name(parent), oid());
+                match child {
+                    "api" => meta.pr = PrState::linked("github", 1, "https://example.com/1"),
+                    "ui" => {
+                        meta.pr = PrState::linked("github", 2, "https://example.com/2");
+                        meta.submitted = Some(SubmitSnapshot {
+                            head_oid: oid().to_string(),
+                            base_oid: oid().to_string(),
+                            parent: "main".to_string(),
+                            submitted_at: UtcTimestamp::now(),
+                        });
+                    }
+                    _ => {}
+                }
+                metadata.insert(
+                    name(child),
+                    ScannedMetadata {
+                        ref_oid: oid(),
+                        metadata: meta,
+                    },
+                );
+            }
+
+            RepoSnapshot {
+                info: RepoInfo {
+                    git_dir: PathBuf::from("/repo/.git"),
+                    common_dir: PathBuf::from("/repo/.git"),
+                    work_dir: Some(PathBuf::from("/repo")),
+                    context: RepoContext::Normal,
+                },
+                git_state: GitState::Clean,
+                worktree_status: Default::default(),
+                checked_out_elsewhere: Default::default(),
+                current_branch: Some(name("ui")),
+                branches: HashMap::new(),
+                metadata,
+                repo_config: None,
+                trunk: Some(name("main")),
+                graph: StackGraph::new(),
+                fingerprint: crate::engine::scan::compute_fingerprint(
+                    &HashMap::new(),
+                    &HashMap::new(),
+                    None,
+                ),
+                health: crate::engine::health::RepoHealthReport::new(),
+                remote_prs: None,
+            }
+        }
+
+        fn branches() -> Vec<BranchName> {
+            ["api", "ui", "docs"].iter().map(|b| name(b)).collect()
+        }
+
+        /// Each branch requests the command-line reviewers plus `matched`.
+        fn requested(opts: &SubmitOptions, matched: &[&str]) -> HashMap<BranchName, Reviewers> {
+            branches()
+                .into_iter()
+                .map(|b| {
+                    let matched = matched.iter().map(|r| r.to_string()).collect();
+                    (b, merge_reviewers(opts, matched))
+                })
+                .collect()
+        }
+
+        #[test]
+        fn tree_shows_actions_and_base_changes() {
+            let mut opts = opts();
+            opts.draft = true;
+            opts.reviewers = Some("alice, bob".to_string());
+            opts.team_reviewers = Some("backend".to_string());
+
+            let requested = requested(&opts, &["bob", "carol", "team:docs"]);
+
+            let nodes =
+                preview_submit(&snapshot(), &branches(), &HashMap::new(), &opts, &requested);
+            assert_eq!(
+                render_preview(&nodes),
+                vec![
+                    "main",
+                    "└── api  update PR #1, base main, draft",
+                    "    ├── ui  update PR #2, base api (was main), draft",
+                    "    └── docs  create PR, base api, draft, reviewers: alice, bob, carol, team:backend, team:docs",
+                ]
+            );
+        }
+
+        #[test]
+        fn skipped_branches_are_marked() {
+            let mut opts = opts();
+            opts.update_only = true;
+            opts.publish = true;
+            let reconciled = HashMap::from([(name("ui"), (Reconcile::Skip, oid()))]);
+
+            let requested = requested(&opts, &[]);
+
+            let nodes = preview_submit(&snapshot(), &branches(), &reconciled, &opts, &requested);
+            let lines = render_preview(&nodes);
+            assert_eq!(
+                lines[1],
+                "└── api  update PR #1, base main, ready for review"
+            );
+            assert_eq!(lines[2], "    ├── ui  skip (pushed from elsewhere)");
+            assert_eq!(lines[3], "    └── docs  skip (no existing PR)");
+        }
+    }
+
     mod snapshot_exclusion {
         use super::*;
         use crate::core::graph::StackGraph;
-        use crate::core::metadata::schema::{BranchMetadataV1, FreezeScope};
+        use crate::core::metadata::schema::{BranchMetadataV2, FreezeScope};
         use crate::engine::scan::ScannedMetadata;
         use crate::git::{GitState, RepoContext, RepoInfo};
         use std::collections::HashMap;
@@ -929,7 +3605,7 @@ mod tests {
                 };
 
                 let mut meta =
-                    BranchMetadataV1::new(branch.clone(), main_branch.clone(), oid.clone());
+                    BranchMetadataV2::new(branch.clone(), main_branch.clone(), oid.clone());
                 meta.freeze = freeze_state;
 
                 metadata.insert(
@@ -950,6 +3626,7 @@ mod tests {
                 },
                 git_state: GitState::Clean,
                 worktree_status: Default::default(),
+                checked_out_elsewhere: Default::default(),
                 current_branch: Some(main_branch.clone()),
                 branches: HashMap::new(),
                 metadata,
@@ -1071,4 +3748,291 @@ mod tests {
             assert!(err_msg.contains("lattice/snap/pr-42"));
         }
     }
+
+    #[test]
+    fn reconcile_answers_parse() {
+        assert_eq!(Reconcile::parse("k\n"), Some(Reconcile::KeepLocal));
+        assert_eq!(Reconcile::parse("Take"), Some(Reconcile::TakeRemote));
+        assert_eq!(Reconcile::parse(" s "), Some(Reconcile::Skip));
+        // Just pressing enter is the safe choice
+        assert_eq!(Reconcile::parse(""), Some(Reconcile::Abort));
+        assert_eq!(Reconcile::parse("force"), None);
+    }
+
+    #[test]
+    fn taking_the_remote_tip_can_be_undone() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let run = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        run(&["init", "-b", "main"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test User"]);
+        run(&["commit", "--allow-empty", "-m", "base"]);
+        run(&["checkout", "-b", "feature"]);
+        std::fs::write(dir.path().join("local.txt"), "local").unwrap();
+        run(&["add", "local.txt"]);
+        run(&["commit", "-m", "local"]);
+        run(&["checkout", "-b", "elsewhere", "main"]);
+        std::fs::write(dir.path().join("remote.txt"), "remote").unwrap();
+        run(&["add", "remote.txt"]);
+        run(&["commit", "-m", "remote"]);
+        let remote_tip = Oid::new(run(&["rev-parse", "HEAD"])).unwrap();
+        run(&["checkout", "feature"]);
+        let local_tip = run(&["rev-parse", "HEAD"]);
+
+        let ctx = Context {
+            cwd: Some(dir.path().to_path_buf()),
+            quiet: true,
+            ..Default::default()
+        };
+        crate::cli::commands::init(&ctx, Some("main"), false, true).unwrap();
+        crate::cli::commands::track(&ctx, Some("feature"), Some("main"), false, false).unwrap();
+
+        let git = Git::open(dir.path()).unwrap();
+        let snapshot = crate::engine::scan::scan(&git).unwrap();
+        let diverged = DivergedBranch {
+            branch: BranchName::new("feature").unwrap(),
+            remote: "origin".to_string(),
+            remote_tip: remote_tip.clone(),
+            divergence: Divergence {
+                local_only: 1,
+                remote_only: 1,
+            },
+        };
+        take_remote(&git, &ctx, &snapshot, &diverged).unwrap();
+
+        assert_eq!(run(&["rev-parse", "feature"]), remote_tip.as_str());
+        assert!(dir.path().join("remote.txt").exists());
+        assert!(!dir.path().join("local.txt").exists());
+        let metadata = MetadataStore::new(&git)
+            .read(&diverged.branch)
+            .unwrap()
+            .unwrap()
+            .metadata;
+        assert_eq!(metadata.submitted.unwrap().head_oid, remote_tip.to_string());
+
+        crate::cli::commands::undo(&ctx).unwrap();
+        assert_eq!(run(&["rev-parse", "feature"]), local_tip);
+        let metadata = MetadataStore::new(&git)
+            .read(&diverged.branch)
+            .unwrap()
+            .unwrap()
+            .metadata;
+        assert!(metadata.submitted.is_none());
+    }
+
+    mod branch_selection {
+        use super::*;
+        use crate::core::graph::StackGraph;
+        use crate::core::metadata::schema::{BranchMetadataV2, PrState};
+        use crate::engine::scan::ScannedMetadata;
+        use crate::git::{GitState, RepoContext, RepoInfo};
+        use std::collections::HashMap;
+        use std::path::PathBuf;
+
+        fn name(s: &str) -> BranchName {
+            BranchName::new(s).unwrap()
+        }
+
+        fn names(list: &[&str]) -> Vec<BranchName> {
+            list.iter().map(|s| name(s)).collect()
+        }
+
+        /// main <- a <- b <- {c, d}, plus an unrelated main <- x; on `b`.
+        fn snapshot() -> RepoSnapshot {
+            let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+            let mut graph = StackGraph::new();
+            let mut metadata = HashMap::new();
+            for (child, parent) in [
+                ("a", "main"),
+                ("b", "a"),
+                ("d", "b"),
+                ("c", "b"),
+                ("x", "main"),
+            ] {
+                graph.add_edge(name(child), name(parent));
+                metadata.insert(
+                    name(child),
+                    ScannedMetadata {
+                        ref_oid: oid.clone(),
+                        metadata: BranchMetadataV2::new(name(child), name(parent), oid.clone()),
+                    },
+                );
+            }
+
+            RepoSnapshot {
+                info: RepoInfo {
+                    git_dir: PathBuf::from("/repo/.git"),
+                    common_dir: PathBuf::from("/repo/.git"),
+                    work_dir: Some(PathBuf::from("/repo")),
+                    context: RepoContext::Normal,
+                },
+                git_state: GitState::Clean,
+                worktree_status: Default::default(),
+                checked_out_elsewhere: Default::default(),
+                current_branch: Some(name("b")),
+                branches: HashMap::new(),
+                metadata,
+                repo_config: None,
+                trunk: Some(name("main")),
+                graph,
+                fingerprint: crate::engine::scan::compute_fingerprint(
+                    &HashMap::new(),
+                    &HashMap::new(),
+                    None,
+                ),
+                health: crate::engine::health::RepoHealthReport::new(),
+                remote_prs: None,
+            }
+        }
+
+        #[test]
+        fn stack_is_tracked_branches_bottom_up() {
+            let stack = current_stack(&snapshot()).unwrap();
+            assert_eq!(stack, names(&["a", "b", "c", "d"]));
+        }
+
+        #[test]
+        fn branch_list_must_name_stack_branches() {
+            let stack = names(&["a", "b", "c", "d"]);
+            assert_eq!(
+                parse_branch_list("c, a", &stack).unwrap(),
+                names(&["c", "a"])
+            );
+
+            let err = parse_branch_list("c,x", &stack).unwrap_err();
+            assert!(err.to_string().contains("'x'"));
+            assert!(parse_branch_list("main", &stack).is_err());
+            assert!(parse_branch_list(" , ", &stack).is_err());
+        }
+
+        #[test]
+        fn ancestors_of_the_selection_are_added() {
+            let snapshot = snapshot();
+            assert_eq!(
+                unselected_ancestors(&snapshot, &names(&["d", "c"])),
+                names(&["a", "b"])
+            );
+            assert_eq!(
+                unselected_ancestors(&snapshot, &names(&["b", "c"])),
+                names(&["a"])
+            );
+            assert!(unselected_ancestors(&snapshot, &names(&["a"])).is_empty());
+
+            // The submit scope includes them too
+            let opts = SubmitOptions {
+                stack: false,
+                draft: false,
+                publish: false,
+                confirm: false,
+                dry_run: false,
+                force: false,
+                always: false,
+                update_only: false,
+                reviewers: None,
+                team_reviewers: None,
+                no_restack: false,
+                view: false,
+                offline: false,
+                quiet: true,
+                verify: true,
+                edit: false,
+                branches: Some(names(&["c"])),
+                all: false,
+            };
+            assert_eq!(
+                submit_scope(&snapshot, &opts).unwrap(),
+                names(&["a", "b", "c"])
+            );
+
+            // Updates touch exactly the selected PRs
+            let opts = SubmitOptions {
+                update_only: true,
+                branches: Some(names(&["c", "b"])),
+                ..opts
+            };
+            assert_eq!(submit_scope(&snapshot, &opts).unwrap(), names(&["b", "c"]));
+        }
+
+        #[test]
+        fn since_ranges_up_to_the_current_branch() {
+            let snapshot = snapshot();
+            assert_eq!(
+                since_range(&snapshot, "a", false).unwrap(),
+                names(&["a", "b"])
+            );
+            assert_eq!(since_range(&snapshot, "b", false).unwrap(), names(&["b"]));
+            assert_eq!(
+                since_range(&snapshot, "b", true).unwrap(),
+                names(&["b", "c", "d"])
+            );
+
+            // Only the current branch and the tracked branches below it
+            for outside in ["c", "x", "main"] {
+                let err = since_range(&snapshot, outside, false).unwrap_err();
+                assert!(err.to_string().contains(outside));
+            }
+        }
+
+        /// Link a PR to `branch` and record it as pushed at its current tip.
+        fn mark_submitted(snapshot: &mut RepoSnapshot, branch: &str, number: u64) {
+            let tip = Oid::new("b".repeat(40)).unwrap();
+            snapshot.branches.insert(name(branch), tip.clone());
+            let metadata = &mut snapshot.metadata.get_mut(&name(branch)).unwrap().metadata;
+            metadata.pr = PrState::linked("github", number, "https://example.com");
+            metadata.submitted = Some(submit_snapshot(metadata, &tip));
+        }
+
+        #[test]
+        fn all_stacks_are_grouped_by_root() {
+            let mut snapshot = snapshot();
+            let stacks = stacks_needing_submit(&snapshot, false);
+            assert_eq!(
+                stacks,
+                vec![
+                    (name("a"), names(&["a", "b", "c", "d"])),
+                    (name("x"), names(&["x"])),
+                ]
+            );
+            assert!(stacks_needing_submit(&snapshot, true).is_empty());
+
+            // Up-to-date stacks drop out; ancestors are kept as bases
+            for (branch, number) in [("a", 1), ("b", 2), ("c", 3), ("x", 4)] {
+                mark_submitted(&mut snapshot, branch, number);
+            }
+            assert_eq!(
+                stacks_needing_submit(&snapshot, false),
+                vec![(name("a"), names(&["a", "b", "d"]))]
+            );
+
+            // A moved tip needs an update
+            snapshot
+                .branches
+                .insert(name("x"), Oid::new("c".repeat(40)).unwrap());
+            assert_eq!(
+                stacks_needing_submit(&snapshot, true),
+                vec![(name("x"), names(&["x"]))]
+            );
+        }
+
+        #[test]
+        fn stack_summary_counts_outcomes() {
+            let stacks = vec![(name("a"), names(&["a", "b"])), (name("x"), names(&["x"]))];
+            let outcomes = HashMap::from([
+                (name("a"), BranchOutcome::Updated),
+                (name("b"), BranchOutcome::Created),
+            ]);
+            assert_eq!(
+                stack_summary_lines(&stacks, &outcomes),
+                vec!["  a: 1 created, 1 updated", "  x: nothing done"]
+            );
+        }
+    }
 }
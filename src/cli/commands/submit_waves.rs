@@ -0,0 +1,194 @@
+//! cli::commands::submit_waves
+//!
+//! Scheduling for `submit`'s concurrent pushes and PR requests.
+//!
+//! # Design
+//!
+//! Submit pushes every branch first, then creates or updates PRs. Both
+//! phases run through [`run_bounded`], which caps how many jobs are in
+//! flight at `submit.parallelism` and returns results in input order so
+//! output stays in stack order. PR requests are further split by
+//! [`pr_waves`]: a new PR whose parent is also getting a new PR has to wait
+//! for that parent's number.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::core::types::BranchName;
+use crate::engine::scan::RepoSnapshot;
+
+/// Run `job` on every item, at most `parallelism` at a time, returning the
+/// results in the order of `items`.
+pub(super) fn run_bounded<T: Sync, R: Send>(
+    items: &[T],
+    parallelism: usize,
+    job: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..parallelism.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = job(item);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item runs"))
+        .collect()
+}
+
+/// Group `branches`, in stack order, into waves of PRs that can be
+/// submitted concurrently.
+///
+/// A branch without a PR waits for its parent's wave when the parent is
+/// getting a new PR too, since the new PR's title and body may mention the
+/// parent's number. Everything else goes in the first wave.
+pub(super) fn pr_waves(snapshot: &RepoSnapshot, branches: &[BranchName]) -> Vec<Vec<BranchName>> {
+    let mut waves: Vec<Vec<BranchName>> = Vec::new();
+    // Waves of the branches getting new PRs
+    let mut new_prs: HashMap<&str, usize> = HashMap::new();
+    for branch in branches {
+        let Some(scanned) = snapshot.metadata.get(branch) else {
+            continue;
+        };
+        let metadata = &scanned.metadata;
+        let wave = if metadata.pr.is_linked() {
+            0
+        } else {
+            let wave = new_prs
+                .get(metadata.parent.name())
+                .map_or(0, |parent| parent + 1);
+            new_prs.insert(branch.as_str(), wave);
+            wave
+        };
+        if waves.len() <= wave {
+            waves.push(Vec::new());
+        }
+        waves[wave].push(branch.clone());
+    }
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::graph::StackGraph;
+    use crate::core::metadata::schema::{BranchMetadataV2, PrState};
+    use crate::core::types::Oid;
+    use crate::engine::scan::ScannedMetadata;
+    use crate::git::{GitState, RepoContext, RepoInfo};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn name(s: &str) -> BranchName {
+        BranchName::new(s).unwrap()
+    }
+
+    /// main <- a <- b <- c, and main <- d; `prs` lists the linked ones.
+    fn snapshot(prs: &[&str]) -> RepoSnapshot {
+        let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+        let mut graph = StackGraph::new();
+        let mut metadata = HashMap::new();
+        for (child, parent) in [("a", "main"), ("b", "a"), ("c", "b"), ("d", "main")] {
+            graph.add_edge(name(child), name(parent));
+            let mut meta = BranchMetadataV2::new(name(child), name(parent), oid.clone());
+            if prs.contains(&child) {
+                meta.pr = PrState::linked("github", 1, "https://example.com");
+            }
+            metadata.insert(
+                name(child),
+                ScannedMetadata {
+                    ref_oid: oid.clone(),
+                    metadata: meta,
+                },
+            );
+        }
+
+        RepoSnapshot {
+            info: RepoInfo {
+                git_dir: PathBuf::from("/repo/.git"),
+                common_dir: PathBuf::from("/repo/.git"),
+                work_dir: Some(PathBuf::from("/repo")),
+                context: RepoContext::Normal,
+            },
+            git_state: GitState::Clean,
+            worktree_status: Default::default(),
+            checked_out_elsewhere: Default::default(),
+            current_branch: Some(name("c")),
+            branches: HashMap::new(),
+            metadata,
+            repo_config: None,
+            trunk: Some(name("main")),
+            graph,
+            fingerprint: crate::engine::scan::compute_fingerprint(
+                &HashMap::new(),
+                &HashMap::new(),
+                None,
+            ),
+            health: crate::engine::health::RepoHealthReport::new(),
+            remote_prs: None,
+        }
+    }
+
+    fn waves(snapshot: &RepoSnapshot, branches: &[&str]) -> Vec<Vec<String>> {
+        let branches: Vec<BranchName> = branches.iter().map(|b| name(b)).collect();
+        pr_waves(snapshot, &branches)
+            .into_iter()
+            .map(|wave| wave.iter().map(|b| b.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn updates_go_up_together() {
+        let snapshot = snapshot(&["a", "b", "c", "d"]);
+        assert_eq!(
+            waves(&snapshot, &["a", "b", "c", "d"]),
+            vec![vec!["a", "b", "c", "d"]]
+        );
+    }
+
+    #[test]
+    fn new_prs_wait_for_new_parents() {
+        let snapshot = snapshot(&["a"]);
+        assert_eq!(
+            waves(&snapshot, &["a", "b", "c", "d"]),
+            vec![vec!["a", "b", "d"], vec!["c"]]
+        );
+        // A parent outside the submitted set doesn't hold anything up
+        assert_eq!(waves(&snapshot, &["c", "d"]), vec![vec!["c", "d"]]);
+    }
+
+    #[test]
+    fn bounded_runs_keep_order_and_limit() {
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        let items: Vec<u64> = (0..12).collect();
+        let results = run_bounded(&items, 3, |item| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most.fetch_max(now, Ordering::SeqCst);
+            // Later items finish first
+            std::thread::sleep(Duration::from_millis(12 - item));
+            running.fetch_sub(1, Ordering::SeqCst);
+            item * 2
+        });
+        assert_eq!(results, items.iter().map(|i| i * 2).collect::<Vec<_>>());
+        assert!(most.load(Ordering::SeqCst) <= 3);
+        assert!(run_bounded(&[] as &[u64], 4, |i| *i).is_empty());
+    }
+
+    #[test]
+    fn bounded_runs_with_zero_parallelism_still_run() {
+        let items = ["a", "b"];
+        assert_eq!(run_bounded(&items, 0, |item| item.len()), vec![1, 1]);
+    }
+}
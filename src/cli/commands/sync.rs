@@ -5,11 +5,40 @@
 //! # Design
 //!
 //! Per SPEC.md Section 8E.3, the sync command:
-//! - Fetches from remote
-//! - Fast-forwards trunk (or errors if diverged without --force)
-//! - Detects merged/closed PRs and prompts to delete local branches
+//! - Fetches from remote (plus any per-branch remote overrides), pruning
+//!   remote-tracking refs for deleted remote branches
+//! - Reports submitted branches whose remote branch was deleted, merged or
+//!   not, which `lattice doctor` can clean up
+//! - Reports submitted branches pushed from another machine with commits
+//!   this repository lacks ([`crate::core::remote_divergence`]), which
+//!   `lattice submit` reconciles
+//! - Updates trunk per `[sync] trunk_strategy` ([`TrunkStrategy`]): fast-forward
+//!   only (erroring if diverged without --force), rebase local trunk commits
+//!   onto the remote trunk, or reset to the remote trunk
+//! - Detects merged/closed PRs and prompts to delete local branches. Every
+//!   linked PR is looked up in one batch ([`crate::forge::Forge::get_pr_statuses`]),
+//!   which also refreshes the status cache `log` and `info` read
+//! - With `--prune`, offers to delete or untrack branches whose PRs were
+//!   closed without merging ([`PruneAction`]); `--force` deletes them all
+//!   without asking
+//! - Detects downstack branches squash- or rebase-merged into trunk
+//!   ([`crate::core::merge_detect`]), records them as merged, and offers to
+//!   delete them, moving their children onto trunk
 //! - Updates stack comments in PR descriptions
+//! - Offers to pull edited PR descriptions into branch description metadata
+//! - Replays forge actions queued by an offline submit
 //! - Optionally restacks after syncing
+//! - Starts background `git maintenance` when the fetch moved many refs
+//!
+//! With `--dry-run`, sync downloads objects and reads remote tips and PR
+//! states, then prints the ref updates it would make as a plan preview
+//! instead of applying them.
+//!
+//! Offline (`--offline`, or when the fetch or a PR check finds the remote
+//! unreachable), sync lists the PR states cached in metadata with when they
+//! were fetched, offers to delete branches merged into the last fetched
+//! trunk, and restacks if asked; trunk and PRs are left alone. PR states
+//! read from the forge are cached in metadata for this (see `cached_pr`).
 //!
 //! # Architecture
 //!
@@ -38,20 +67,49 @@
 //!
 //! # Sync from bare repo (no restack)
 //! lattice sync --no-restack
+//!
+//! # Show what sync would change
+//! lattice sync --dry-run
+//!
+//! # Clean up branches whose PRs were closed without merging
+//! lattice sync --prune
 //! ```
 
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::branch_lock::BranchLockStore;
+use crate::core::config::Config;
+use crate::core::merge_detect::{self, MergeEvidence};
+use crate::core::metadata::store::MetadataStore;
 use crate::core::ops::journal::OpId;
-use crate::core::types::BranchName;
+use crate::core::paths::LatticePaths;
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
 use crate::engine::command::{AsyncCommand, CommandOutput, PlanFut};
-use crate::engine::exec::ExecuteResult;
+use crate::engine::exec::{ExecuteResult, Executor};
 use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::ledger::{BranchStage, Event, EventLedger};
 use crate::engine::modes::{ModeError, SyncMode};
 use crate::engine::plan::{Plan, PlanStep};
+use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
+use crate::forge::status_cache::{CachedPrStatus, ForgeStatusCache};
+use crate::forge::{Forge, ForgeError, PrStatus};
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use crate::ui::prompts::{self, PromptError, Severity};
+use crate::ui::stack_comment::strip_stack_comment;
+use anyhow::Context as _;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
 
-use super::stack_comment_ops::update_stack_comments_for_branches;
+use super::batch::{parse_marks, read_answer};
+use super::cached_pr;
+use super::pending_ops::{
+    flush_pending_actions, is_offline_forge_error, is_offline_git_failure, LinkedPr,
+};
+use super::stack_comment_ops::{
+    update_stack_comments_for_branches, update_stack_comments_for_branches_from_forge,
+};
 
 /// Result of a sync operation.
 #[derive(Debug)]
@@ -76,11 +134,167 @@ pub struct SyncArgs {
     pub quiet: bool,
     /// Verify commits with hooks.
     pub verify: bool,
+    /// Print the planned changes without making them.
+    pub dry_run: bool,
+    /// Offer to prune branches whose PRs were closed without merging.
+    pub prune: bool,
+    /// Don't contact the remote or forge; use cached PR state.
+    pub offline: bool,
+    /// How to update a trunk that diverged from the remote.
+    pub trunk_strategy: TrunkStrategy,
+}
+
+/// What `--prune` does to a branch whose PR was closed without merging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneAction {
+    /// Delete the branch and its metadata, moving children onto its parent
+    Delete,
+    /// Stop tracking the branch, keeping it in git
+    Untrack,
+}
+
+impl PruneAction {
+    /// Parse an action name, accepting prefixes (`d`, `un`).
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+        [Self::Delete, Self::Untrack]
+            .into_iter()
+            .find(|action| action.name().starts_with(&input))
+    }
+
+    /// The action's name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Untrack => "untrack",
+        }
+    }
+}
+
+/// How sync updates a local trunk that has commits the remote doesn't.
+///
+/// Set with `[sync] trunk_strategy`. A trunk that is only behind the remote
+/// is fast-forwarded under every strategy, and `--force` always resets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrunkStrategy {
+    /// Refuse to touch a diverged trunk (the default)
+    #[default]
+    FfOnly,
+    /// Replay the local commits on top of the remote trunk
+    RebaseLocal,
+    /// Discard the local commits and match the remote trunk
+    Reset,
+}
+
+impl TrunkStrategy {
+    /// Parse a `[sync] trunk_strategy` value.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ff-only" => Some(Self::FfOnly),
+            "rebase-local" => Some(Self::RebaseLocal),
+            "reset" => Some(Self::Reset),
+            _ => None,
+        }
+    }
+}
+
+/// The trunk update a sync will make, decided before anything changes.
+///
+/// A real sync applies it and `--dry-run` previews it, so both always agree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TrunkUpdate {
+    /// Local trunk is behind the remote
+    FastForward,
+    /// Local trunk has `local` commits the remote doesn't; replay them
+    RebaseLocal { local: usize },
+    /// Local trunk has `local` commits the remote doesn't; drop them
+    Reset { local: usize },
+}
+
+impl TrunkUpdate {
+    /// Decide how to bring `local` up to `remote`; `None` when there is
+    /// nothing to do.
+    ///
+    /// # Errors
+    ///
+    /// Fails for a diverged trunk under `ff-only` without `--force`.
+    fn decide(
+        git: &Git,
+        trunk: &BranchName,
+        local: &Oid,
+        remote: &Oid,
+        strategy: TrunkStrategy,
+        force: bool,
+    ) -> CliResult<Option<Self>> {
+        if local == remote {
+            return Ok(None);
+        }
+        if git.is_ancestor(local, remote)? {
+            return Ok(Some(Self::FastForward));
+        }
+
+        let base = git.merge_base(local, remote)?.ok_or_else(|| {
+            CliError::conflict(format!("Trunk '{}' shares no history with origin.", trunk))
+        })?;
+        let local_commits = git.commit_count(&base, local)?;
+        let strategy = if force {
+            TrunkStrategy::Reset
+        } else {
+            strategy
+        };
+        match strategy {
+            TrunkStrategy::FfOnly => Err(CliError::conflict(format!(
+                "Trunk '{}' has diverged from origin ({} local commit(s)). Use --force to reset, \
+                 or set [sync] trunk_strategy to \"rebase-local\" to keep them.",
+                trunk, local_commits
+            ))),
+            // Already contains everything the remote has
+            TrunkStrategy::RebaseLocal if base == *remote => Ok(None),
+            TrunkStrategy::RebaseLocal => Ok(Some(Self::RebaseLocal {
+                local: local_commits,
+            })),
+            TrunkStrategy::Reset => Ok(Some(Self::Reset {
+                local: local_commits,
+            })),
+        }
+    }
+
+    /// The update as a plan step, for `--dry-run` previews.
+    fn step(&self, trunk: &BranchName, local: &Oid, remote: &Oid) -> PlanStep {
+        let refname = format!("refs/heads/{}", trunk);
+        let ref_update = |reason: String| PlanStep::UpdateRefCas {
+            refname: refname.clone(),
+            old_oid: Some(local.to_string()),
+            new_oid: remote.to_string(),
+            reason,
+        };
+        match self {
+            Self::FastForward => ref_update(format!("fast-forward to origin/{}", trunk)),
+            Self::Reset { local } => ref_update(format!(
+                "reset to origin/{} (diverged, drops {} local commit(s))",
+                trunk, local
+            )),
+            Self::RebaseLocal { local } => PlanStep::RunGit {
+                args: vec![
+                    "rebase".to_string(),
+                    format!("origin/{}", trunk),
+                    trunk.to_string(),
+                ],
+                description: format!(
+                    "rebase {} local commit(s) on {} onto origin/{}",
+                    local, trunk, trunk
+                ),
+                expected_effects: vec![refname.clone()],
+            },
+        }
+    }
 }
 
 /// The sync command for WithRestack mode.
 pub struct SyncWithRestackCommand {
-    #[allow(dead_code)]
     args: SyncArgs,
 }
 
@@ -96,15 +310,7 @@ impl AsyncCommand for SyncWithRestackCommand {
     type Output = SyncResult;
 
     fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
-        Box::pin(async move {
-            // Build a plan with ForgeFetch step
-            let plan = Plan::new(OpId::new(), "sync").with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: None,
-            });
-
-            Ok(plan)
-        })
+        Box::pin(async move { Ok(fetch_plan(&self.args)) })
     }
 
     fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
@@ -124,7 +330,6 @@ impl AsyncCommand for SyncWithRestackCommand {
 
 /// The sync command for NoRestack mode (bare repo compatible).
 pub struct SyncNoRestackCommand {
-    #[allow(dead_code)]
     args: SyncArgs,
 }
 
@@ -140,15 +345,7 @@ impl AsyncCommand for SyncNoRestackCommand {
     type Output = SyncResult;
 
     fn plan<'a>(&'a self, _ready: &'a ReadyContext) -> PlanFut<'a> {
-        Box::pin(async move {
-            // Build a plan with ForgeFetch step
-            let plan = Plan::new(OpId::new(), "sync").with_step(PlanStep::ForgeFetch {
-                remote: "origin".to_string(),
-                refspec: None,
-            });
-
-            Ok(plan)
-        })
+        Box::pin(async move { Ok(fetch_plan(&self.args)) })
     }
 
     fn finish(&self, result: ExecuteResult) -> CommandOutput<Self::Output> {
@@ -166,11 +363,27 @@ impl AsyncCommand for SyncNoRestackCommand {
     }
 }
 
+/// Build the gating plan: a `ForgeFetch` step, or nothing for a dry run or
+/// offline.
+///
+/// A dry run must not update remote-tracking refs, so it only downloads
+/// objects later, in `preview_sync`.
+fn fetch_plan(args: &SyncArgs) -> Plan {
+    let plan = Plan::new(OpId::new(), "sync");
+    if args.dry_run || args.offline {
+        return plan;
+    }
+    plan.with_step(PlanStep::ForgeFetch {
+        remote: "origin".to_string(),
+        refspec: None,
+    })
+}
+
 /// Run the sync command.
 ///
 /// This is a synchronous wrapper that uses tokio to run the async implementation.
 /// It uses mode dispatch for bare repository handling per SPEC.md §4.6.7.
-pub fn sync(ctx: &Context, force: bool, restack: bool) -> Result<()> {
+pub fn sync(ctx: &Context, force: bool, restack: bool, dry_run: bool, prune: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -182,21 +395,26 @@ pub fn sync(ctx: &Context, force: bool, restack: bool) -> Result<()> {
     let is_bare = git.info()?.work_dir.is_none();
     let mode = SyncMode::resolve(!restack, is_bare).map_err(|e| match e {
         ModeError::BareRepoRequiresFlag { command, required_flag } => {
-            anyhow::anyhow!(
-                "This is a bare repository. The `{}` command cannot restack without a working directory.\n\n\
+            CliError::usage(format!("This is a bare repository. The `{}` command cannot restack without a working directory.\n\n\
                  To sync without restacking (fetch, trunk FF, PR checks only), use:\n\n\
                      lattice sync {}",
                 command,
-                required_flag
-            )
+                required_flag))
         }
     })?;
 
+    let config = Config::load(Some(&cwd))
+        .map(|r| r.config)
+        .unwrap_or_default();
     let args = SyncArgs {
         force,
         restack,
         quiet: ctx.quiet,
         verify: ctx.verify,
+        dry_run,
+        prune,
+        offline: ctx.offline,
+        trunk_strategy: TrunkStrategy::parse(config.sync_trunk_strategy()).unwrap_or_default(),
     };
 
     let rt = tokio::runtime::Runtime::new()?;
@@ -207,13 +425,18 @@ pub fn sync(ctx: &Context, force: bool, restack: bool) -> Result<()> {
 }
 
 /// Async implementation for WithRestack mode.
-async fn sync_with_restack_impl(git: &Git, ctx: &Context, args: SyncArgs) -> Result<()> {
-    use crate::engine::runner::run_async_command;
+async fn sync_with_restack_impl(git: &Git, ctx: &Context, mut args: SyncArgs) -> CliResult {
+    use crate::engine::runner::{run_async_command, run_async_command_with_requirements};
 
     let command = SyncWithRestackCommand::new(args.clone());
 
-    // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+    // Run through async command lifecycle for gating. Offline syncs only
+    // touch local state, so remote and auth capabilities are not required.
+    let result = if args.offline {
+        run_async_command_with_requirements(&command, git, ctx, &requirements::MUTATING).await
+    } else {
+        run_async_command(&command, git, ctx).await
+    };
 
     match result {
         Ok(output) => match output {
@@ -221,21 +444,39 @@ async fn sync_with_restack_impl(git: &Git, ctx: &Context, args: SyncArgs) -> Res
                 // Gating passed, now execute sync operations
                 execute_sync(git, ctx, &args).await
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } if is_offline_git_failure(&error) => {
+                cached_pr::go_offline(&mut args.offline, error.trim());
+                execute_sync(git, ctx, &args).await
+            }
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Sync failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
 /// Async implementation for NoRestack mode.
-async fn sync_no_restack_impl(git: &Git, ctx: &Context, args: SyncArgs) -> Result<()> {
-    use crate::engine::runner::run_async_command;
+async fn sync_no_restack_impl(git: &Git, ctx: &Context, mut args: SyncArgs) -> CliResult {
+    use crate::engine::runner::{run_async_command, run_async_command_with_requirements};
 
     let command = SyncNoRestackCommand::new(args.clone());
 
-    // Run through async command lifecycle for gating
-    let result = run_async_command(&command, git, ctx).await;
+    // Run through async command lifecycle for gating. Offline syncs only
+    // touch local state, so remote and auth capabilities are not required.
+    let result = if args.offline {
+        run_async_command_with_requirements(
+            &command,
+            git,
+            ctx,
+            &requirements::MUTATING_METADATA_ONLY,
+        )
+        .await
+    } else {
+        run_async_command(&command, git, ctx).await
+    };
 
     match result {
         Ok(output) => match output {
@@ -243,20 +484,24 @@ async fn sync_no_restack_impl(git: &Git, ctx: &Context, args: SyncArgs) -> Resul
                 // Gating passed, now execute sync operations (without restack)
                 execute_sync(git, ctx, &args).await
             }
-            CommandOutput::Paused { message } => bail!("Unexpected pause: {}", message),
-            CommandOutput::Failed { error } => bail!("{}", error),
+            CommandOutput::Paused { message } => Err(CliError::new(
+                ErrorCategory::Internal,
+                format!("Unexpected pause: {}", message),
+            )),
+            CommandOutput::Failed { error } if is_offline_git_failure(&error) => {
+                cached_pr::go_offline(&mut args.offline, error.trim());
+                execute_sync(git, ctx, &args).await
+            }
+            CommandOutput::Failed { error } => Err(CliError::failed(error)),
         },
-        Err(e) => bail!("Sync failed: {}", e),
+        Err(e) => Err(e.into()),
     }
 }
 
 /// Execute the sync operations after gating succeeds.
-async fn execute_sync(git: &Git, ctx: &Context, args: &SyncArgs) -> Result<()> {
-    use crate::cli::commands::auth::get_github_token;
-    use crate::core::metadata::schema::PrState;
+async fn execute_sync(git: &Git, ctx: &Context, args: &SyncArgs) -> CliResult {
     use crate::engine::scan::scan;
     use crate::forge::PrState as ForgePrState;
-    use std::process::Command;
 
     let cwd = git
         .info()?
@@ -270,22 +515,68 @@ async fn execute_sync(git: &Git, ctx: &Context, args: &SyncArgs) -> Result<()> {
     let trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured. Run 'lattice init' first."))?;
+        .ok_or_else(CliError::not_initialized)?;
+
+    if args.offline {
+        return sync_offline(git, ctx, &snapshot, trunk, args);
+    }
+    if args.dry_run {
+        return preview_sync(git, &snapshot, trunk, args).await;
+    }
 
     // Fetch from remote
     if !args.quiet {
-        println!("Fetching from origin...");
+        println!("{}", i18n::t("sync-fetching-origin"));
     }
+    let remote_refs_before = super::maintenance::ref_tips(git, "refs/remotes/");
 
     let fetch_status = Command::new("git")
         .current_dir(&cwd)
-        .args(["fetch", "origin"])
+        .args(["fetch", "--prune", "origin"])
         .status()?;
 
     if !fetch_status.success() {
-        bail!("git fetch failed");
+        return Err(CliError::git("git fetch failed"));
+    }
+
+    // Branches with a remote override are fetched from that remote too
+    for remote in override_remotes(&snapshot) {
+        if !args.quiet {
+            println!("{}", i18n::t_args("sync-fetching", &[("remote", &remote)]));
+        }
+        let status = Command::new("git")
+            .current_dir(&cwd)
+            .args(["fetch", "--prune", &remote])
+            .status()?;
+        if !status.success() {
+            eprintln!(
+                "{}",
+                i18n::t_args("sync-warning-git-fetch", &[("remote", &remote)])
+            );
+        }
+    }
+
+    // Collaborators' branch locks travel with shared metadata refs
+    let config = Config::load(ctx.cwd.as_deref())
+        .map(|r| r.config)
+        .unwrap_or_default();
+    if config.sync_metadata_refs() {
+        if let Err(e) = BranchLockStore::new(git).fetch(config.remote()) {
+            eprintln!(
+                "{}",
+                i18n::t_args("sync-warning-couldn-fetch", &[("error", &e)])
+            );
+        }
     }
 
+    // A fetch that moved many refs brought in many objects
+    let remote_refs_after = super::maintenance::ref_tips(git, "refs/remotes/");
+    super::maintenance::after_large_operation(
+        ctx,
+        git,
+        super::maintenance::changed_refs(&remote_refs_before, &remote_refs_after),
+    );
+
     // Check trunk state
     let local_trunk = format!("refs/heads/{}", trunk);
     let remote_trunk = format!("refs/remotes/origin/{}", trunk);
@@ -295,147 +586,190 @@ async fn execute_sync(git: &Git, ctx: &Context, args: &SyncArgs) -> Result<()> {
         Ok(oid) => oid,
         Err(_) => {
             if !ctx.quiet {
-                println!("Remote trunk not found. Nothing to sync.");
+                println!("{}", i18n::t("sync-no-remote-trunk"));
             }
             return Ok(());
         }
     };
 
-    if local_oid != remote_oid {
-        // Check if we can fast-forward
-        let is_ancestor = git.is_ancestor(&local_oid, &remote_oid)?;
-
-        if is_ancestor {
-            // Fast-forward
-            if !args.quiet {
-                println!("Fast-forwarding {} to origin/{}...", trunk, trunk);
-            }
-
-            let ff_status = Command::new("git")
-                .current_dir(&cwd)
-                .args(["checkout", trunk.as_str()])
-                .status()?;
-
-            if !ff_status.success() {
-                bail!("git checkout failed");
-            }
-
-            let remote_ref = format!("origin/{}", trunk);
-            let mut merge_args = vec!["merge"];
-            if !args.verify {
-                merge_args.push("--no-verify");
-            }
-            merge_args.extend(["--ff-only", &remote_ref]);
-            let merge_status = Command::new("git")
-                .current_dir(&cwd)
-                .args(&merge_args)
-                .status()?;
-
-            if !merge_status.success() {
-                bail!("git merge --ff-only failed");
-            }
-        } else if args.force {
-            // Force reset
+    match TrunkUpdate::decide(
+        git,
+        trunk,
+        &local_oid,
+        &remote_oid,
+        args.trunk_strategy,
+        args.force,
+    )? {
+        None => {
             if !args.quiet {
                 println!(
-                    "Force resetting {} to origin/{} (diverged)...",
-                    trunk, trunk
+                    "{}",
+                    i18n::t_args("sync-trunk-up-date", &[("trunk", &trunk)])
                 );
             }
+        }
+        Some(update) => apply_trunk_update(&cwd, trunk, &update, args)?,
+    }
 
-            let checkout_status = Command::new("git")
-                .current_dir(&cwd)
-                .args(["checkout", trunk.as_str()])
-                .status()?;
-
-            if !checkout_status.success() {
-                bail!("git checkout failed");
-            }
-
-            let reset_status = Command::new("git")
-                .current_dir(&cwd)
-                .args(["reset", "--hard", &format!("origin/{}", trunk)])
-                .status()?;
+    // After trunk moved, so branches merged remotely are recognized as such
+    if !args.quiet {
+        let fetched = scan(git)?;
+        report_remote_branches_gone(&fetched);
+        report_pushed_elsewhere(git, &fetched, trunk)?;
+    }
 
-            if !reset_status.success() {
-                bail!("git reset --hard failed");
-            }
-        } else {
-            bail!(
-                "Trunk '{}' has diverged from origin. Use --force to reset.",
-                trunk
+    // Check PR states for tracked branches and update stack comments (requires auth)
+    if let Ok(forge) = super::origin_forge(git) {
+        // The fetch succeeded, so replay anything queued while offline
+        let paths = LatticePaths::from_repo_info(&git.info()?);
+        let flushed = flush_pending_actions(forge.as_ref(), &paths, &cwd, args.quiet).await?;
+        if flushed.remaining > 0 && !args.quiet {
+            println!(
+                "{}",
+                i18n::t_args(
+                    "sync-queued-action-remain",
+                    &[("remaining", &flushed.remaining)],
+                )
             );
         }
-    } else if !args.quiet {
-        println!("Trunk '{}' is up to date.", trunk);
-    }
+        link_replayed_prs(git, ctx, forge.name(), &flushed.linked);
+        let ledger = EventLedger::new(git);
+        for branch in &flushed.created {
+            let _ = ledger.append(Event::branch_lifecycle(
+                branch.as_str(),
+                BranchStage::Submitted,
+            ));
+        }
+        if !flushed.created.is_empty() {
+            update_stack_comments_for_branches_from_forge(
+                forge.as_ref(),
+                &snapshot,
+                &flushed.created,
+                args.quiet,
+            )
+            .await?;
+        }
 
-    // Check PR states for tracked branches and update stack comments (requires auth)
-    if let Ok(token) = get_github_token() {
-        let remote_url = git.remote_url("origin")?;
-        if let Some(url) = remote_url {
-            if let Ok(forge) = crate::forge::create_forge(&url, &token, None) {
-                let mut open_branches = Vec::new();
-
-                for (branch, scanned) in &snapshot.metadata {
-                    if let PrState::Linked { number, .. } = &scanned.metadata.pr {
-                        match forge.get_pr(*number).await {
-                            Ok(pr) => {
-                                if pr.state == ForgePrState::Merged
-                                    || pr.state == ForgePrState::Closed
-                                {
-                                    if !args.quiet {
-                                        println!(
-                                            "PR #{} for '{}' is {}.",
-                                            number, branch, pr.state
-                                        );
-                                        // Would prompt to delete in interactive mode
-                                    }
-                                } else {
-                                    // PR is still open, track for stack comment update
-                                    open_branches.push(branch.clone());
-                                }
-                            }
-                            Err(e) => {
-                                if !args.quiet {
-                                    eprintln!(
-                                        "Warning: Could not check PR #{} for '{}': {}",
-                                        number, branch, e
-                                    );
-                                }
-                            }
+        let mut open_branches = Vec::new();
+        let mut edited = Vec::new();
+        let mut closed = Vec::new();
+        let mut fetched = Vec::new();
+
+        // One batched lookup for every linked PR, rather than one per branch
+        let linked = linked_prs(&snapshot);
+        let mut offline = false;
+        let statuses = if linked.is_empty() {
+            Ok(HashMap::new())
+        } else {
+            fetch_pr_statuses(forge.as_ref(), &linked).await
+        };
+        match statuses {
+            Ok(statuses) => {
+                let mut status_cache = ForgeStatusCache::load(&paths);
+                for (branch, number) in &linked {
+                    let Some(status) = statuses.get(number) else {
+                        if !args.quiet {
+                            eprintln!(
+                                "{}",
+                                i18n::t_args(
+                                    "sync-warning-pr-not-found",
+                                    &[("number", &number), ("branch", &branch)],
+                                )
+                            );
+                        }
+                        continue;
+                    };
+                    let pr = &status.pr;
+                    fetched.push(((*branch).clone(), pr.clone()));
+                    status_cache.set(branch.as_str(), CachedPrStatus::from_status(status));
+                    if pr.state == ForgePrState::Merged || pr.state == ForgePrState::Closed {
+                        if pr.state == ForgePrState::Merged {
+                            record_merged_once(&ledger, &snapshot, branch);
+                        } else {
+                            closed.push(((*branch).clone(), *number));
                         }
+                        if !args.quiet {
+                            println!(
+                                "{}",
+                                i18n::t_args(
+                                    "sync-pr-state",
+                                    &[
+                                        ("number", &number),
+                                        ("branch", &branch),
+                                        ("state", &pr.state),
+                                    ],
+                                )
+                            );
+                            // Would prompt to delete in interactive mode
+                        }
+                    } else {
+                        if let Some(description) = edited_description(
+                            snapshot.metadata[*branch].metadata.description.as_deref(),
+                            pr.body.as_deref(),
+                        ) {
+                            edited.push(((*branch).clone(), *number, description));
+                        }
+                        // PR is still open, track for stack comment update
+                        open_branches.push((*branch).clone());
                     }
                 }
+                status_cache.save(&paths);
+            }
+            Err(e) if is_offline_forge_error(&e) => {
+                cached_pr::go_offline(&mut offline, &e.to_string());
+                report_cached_prs(&snapshot, args.quiet);
+            }
+            Err(e) => {
+                if !args.quiet {
+                    eprintln!(
+                        "{}",
+                        i18n::t_args("sync-warning-could-check-prs", &[("error", &e)])
+                    );
+                }
+            }
+        }
 
-                // Update stack comments for all open PRs
-                // This keeps PR descriptions in sync after merges/changes
-                if !open_branches.is_empty() {
-                    if !args.quiet {
-                        println!("Updating stack comments...");
-                    }
+        pull_descriptions(git, ctx, &snapshot, &edited, args.quiet)?;
+        closed.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        prune_closed(git, ctx, &closed, args)?;
+        cached_pr::record(git, ctx, "sync", &fetched);
 
-                    let updated = update_stack_comments_for_branches(
-                        forge.as_ref(),
-                        &snapshot,
-                        &open_branches,
-                        args.quiet,
-                    )
-                    .await?;
+        // Update stack comments for all open PRs
+        // This keeps PR descriptions in sync after merges/changes
+        if !open_branches.is_empty() && !offline {
+            if !args.quiet {
+                println!("{}", i18n::t("sync-updating-stack-comments"));
+            }
 
-                    if updated > 0 && !args.quiet {
-                        println!("  Updated {} PR description(s)", updated);
-                    }
-                }
+            let updated = update_stack_comments_for_branches(
+                forge.as_ref(),
+                &snapshot,
+                &open_branches,
+                args.quiet,
+            )
+            .await?;
+
+            if updated > 0 && !args.quiet {
+                println!(
+                    "  {}",
+                    i18n::t_args("sync-updated-pr-description", &[("updated", &updated)])
+                );
             }
         }
     }
 
+    // Squash merges rewrite commits, so look for the changes themselves
+    let trunk_tip = git
+        .try_resolve_ref(&remote_trunk)?
+        .unwrap_or(git.resolve_ref(&local_trunk)?);
+    let merged = merged_into_trunk(git, &snapshot, trunk, &trunk_tip);
+    offer_merged_deletions(git, ctx, &snapshot, trunk, &merged, args.quiet)?;
+
     // Restack if requested (per SPEC.md 8E.3)
     // "If --restack enabled: restack all restackable branches; skip those that conflict and report"
     if args.restack {
         if !args.quiet {
-            println!("Restacking branches...");
+            println!("{}", i18n::t("sync-restacking-branches"));
         }
 
         // Restack from trunk to catch all branches that may need realignment
@@ -449,16 +783,950 @@ async fn execute_sync(git: &Git, ctx: &Context, args: &SyncArgs) -> Result<()> {
     }
 
     if !args.quiet {
-        println!("Sync complete.");
+        println!("{}", i18n::t("sync-complete"));
+    }
+
+    Ok(())
+}
+
+/// Sync without contacting the remote or forge.
+///
+/// Shows the PR states cached in metadata, offers to delete branches merged
+/// into the last fetched trunk, and restacks if asked. Trunk is left alone.
+fn sync_offline(
+    git: &Git,
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    trunk: &BranchName,
+    args: &SyncArgs,
+) -> CliResult {
+    if !args.quiet || args.dry_run {
+        println!("{}", i18n::t("sync-offline-fetching-updating"));
+    }
+    report_cached_prs(snapshot, args.quiet && !args.dry_run);
+
+    let trunk_tip = git
+        .try_resolve_ref(&format!("refs/remotes/origin/{}", trunk))?
+        .unwrap_or(git.resolve_ref(&format!("refs/heads/{}", trunk))?);
+    let merged = merged_into_trunk(git, snapshot, trunk, &trunk_tip);
+
+    if args.dry_run {
+        for (branch, evidence) in merged {
+            println!(
+                "{}",
+                i18n::t_args(
+                    "sync-would-offer-delete",
+                    &[
+                        ("branch", &branch),
+                        ("describe", &evidence.describe()),
+                        ("trunk", &trunk),
+                    ],
+                )
+            );
+        }
+        if args.restack {
+            println!("{}", i18n::t("sync-would-restack-branches"));
+        }
+        println!("{}", i18n::t("sync-dry-run-nothing"));
+        return Ok(());
+    }
+
+    offer_merged_deletions(git, ctx, snapshot, trunk, &merged, args.quiet)?;
+    if args.restack {
+        if !args.quiet {
+            println!("{}", i18n::t("sync-restacking-branches"));
+        }
+        super::restack::restack(ctx, Some(trunk.as_str()), false, false)?;
+    }
+    if !args.quiet {
+        println!("{}", i18n::t("sync-complete-offline"));
+    }
+    Ok(())
+}
+
+/// List the PR state cached in metadata for each linked branch, with when
+/// it was fetched.
+fn report_cached_prs(snapshot: &RepoSnapshot, quiet: bool) {
+    if quiet {
+        return;
+    }
+    let mut linked: Vec<_> = snapshot
+        .metadata
+        .iter()
+        .filter_map(|(branch, scanned)| {
+            let number = scanned.metadata.pr.number()?;
+            let label = cached_pr::stale_label(&scanned.metadata)
+                .unwrap_or_else(|| format!("#{} (state never fetched)", number));
+            Some((branch.as_str(), label))
+        })
+        .collect();
+    if linked.is_empty() {
+        return;
+    }
+    linked.sort();
+    println!("{}", i18n::t("sync-cached-pr-states"));
+    for (branch, label) in linked {
+        println!("  {}: {}", branch, label);
+    }
+}
+
+/// Downstack branches whose changes are already on `trunk_tip`, bottom-up.
+///
+/// Walks up from trunk and stops at the first unmerged branch of each
+/// stack: a branch above it can't have landed without it. Detection errors
+/// count as unmerged.
+fn merged_into_trunk(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    trunk: &BranchName,
+    trunk_tip: &Oid,
+) -> Vec<(BranchName, MergeEvidence)> {
+    let sorted_children = |branch: &BranchName| {
+        let mut children: Vec<BranchName> = snapshot
+            .graph
+            .children(branch)
+            .map(|c| c.iter().cloned().collect())
+            .unwrap_or_default();
+        children.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        children
+    };
+
+    let mut merged = Vec::new();
+    let mut queue = sorted_children(trunk);
+    while !queue.is_empty() {
+        let branch = queue.remove(0);
+        let (Some(scanned), Some(tip)) = (
+            snapshot.metadata.get(&branch),
+            snapshot.branches.get(&branch),
+        ) else {
+            continue;
+        };
+        let Ok(base) = Oid::new(&scanned.metadata.base.oid) else {
+            continue;
+        };
+        if let Ok(Some(evidence)) = merge_detect::detect(git, &base, tip, trunk_tip) {
+            queue.extend(sorted_children(&branch));
+            merged.push((branch, evidence));
+        }
+    }
+    merged
+}
+
+/// Record branches merged into trunk and offer to delete each one.
+///
+/// Deleting a branch moves its children onto its parent, so deleting merged
+/// branches bottom-up moves the first unmerged ones onto trunk. Without a
+/// terminal to prompt on, or without a working directory to delete from,
+/// the merged branches are only reported.
+fn offer_merged_deletions(
+    git: &Git,
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    trunk: &BranchName,
+    merged: &[(BranchName, MergeEvidence)],
+    quiet: bool,
+) -> CliResult {
+    let ledger = EventLedger::new(git);
+    let can_delete = git.info()?.work_dir.is_some();
+    for (branch, evidence) in merged {
+        record_merged_once(&ledger, snapshot, branch);
+
+        let how = evidence.describe();
+        let args: [(&str, &dyn std::fmt::Display); 3] =
+            [("branch", branch), ("how", &how), ("trunk", trunk)];
+        let report = || {
+            if !quiet {
+                println!("{}", i18n::t_args("sync-merged-found", &args));
+            }
+        };
+        if !can_delete {
+            report();
+            continue;
+        }
+        match prompts::confirm(
+            &i18n::t_args("sync-merged-prompt", &args),
+            Severity::Destructive,
+            ctx.interactive,
+        ) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(PromptError::NotInteractive) => {
+                report();
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        }
+        if let Err(e) = super::delete::delete(ctx, Some(branch.as_str()), false, false, true) {
+            eprintln!(
+                "{}",
+                i18n::t_args(
+                    "sync-warning-could-delete",
+                    &[("branch", &branch), ("error", &format!("{:#}", e))],
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Offer to prune branches whose PRs were closed without merging.
+///
+/// Without `--prune`, only points at it. With it, lists the branches and
+/// asks which to prune and whether to delete or untrack them; `--force` (or
+/// `--yes`) deletes them all without asking. Without a terminal to prompt
+/// on, or without a working directory to delete from, nothing is pruned.
+fn prune_closed(
+    git: &Git,
+    ctx: &Context,
+    closed: &[(BranchName, u64)],
+    args: &SyncArgs,
+) -> CliResult {
+    if closed.is_empty() {
+        return Ok(());
+    }
+    if !args.prune {
+        if !args.quiet {
+            println!(
+                "{}",
+                i18n::t_args("sync-closed-hint", &[("count", &closed.len())])
+            );
+        }
+        return Ok(());
+    }
+    if git.info()?.work_dir.is_none() {
+        eprintln!("{}", i18n::t("sync-warning-can-prune"));
+        return Ok(());
+    }
+
+    println!("{}", i18n::t("sync-closed-list"));
+    for (i, (branch, number)) in closed.iter().enumerate() {
+        println!("  {:>2}. {} (PR #{})", i + 1, branch, number);
+    }
+
+    let (selected, action): (Vec<&BranchName>, PruneAction) = if args.force || prompts::assume_yes()
+    {
+        (closed.iter().map(|(b, _)| b).collect(), PruneAction::Delete)
+    } else if !ctx.interactive {
+        println!("{}", i18n::t("sync-closed-needs-force"));
+        return Ok(());
+    } else {
+        let input = read_answer(&i18n::t("sync-closed-mark-prompt"))?;
+        let marked = parse_marks(&input, closed.len()).map_err(|mark| {
+            CliError::usage(i18n::t_args(
+                "batch-invalid-mark",
+                &[("mark", &mark), ("count", &closed.len())],
+            ))
+        })?;
+        if marked.is_empty() {
+            println!("{}", i18n::t("batch-nothing-marked"));
+            return Ok(());
+        }
+        let input = read_answer(&i18n::t("sync-closed-action-prompt"))?;
+        let Some(action) = PruneAction::parse(&input) else {
+            return Err(CliError::usage(i18n::t_args(
+                "sync-closed-unknown-action",
+                &[("action", &input.trim())],
+            )));
+        };
+        (marked.into_iter().map(|i| &closed[i].0).collect(), action)
+    };
+
+    for branch in selected {
+        let result = match action {
+            PruneAction::Delete => {
+                super::delete::delete(ctx, Some(branch.as_str()), false, false, true)
+            }
+            PruneAction::Untrack => super::untrack::untrack(ctx, Some(branch.as_str()), false),
+        };
+        match result {
+            Ok(()) => {
+                if !args.quiet {
+                    println!(
+                        "{}",
+                        i18n::t_args(
+                            "sync-pruned",
+                            &[("branch", &branch), ("name", &action.name())],
+                        )
+                    );
+                }
+            }
+            Err(e) => eprintln!(
+                "{}",
+                i18n::t_args(
+                    "sync-warning-could-prune",
+                    &[
+                        ("action", &action.name()),
+                        ("branch", &branch),
+                        ("error", &format!("{:#}", e)),
+                    ],
+                )
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Check out trunk and apply a decided trunk update with git.
+fn apply_trunk_update(
+    cwd: &Path,
+    trunk: &BranchName,
+    update: &TrunkUpdate,
+    args: &SyncArgs,
+) -> CliResult {
+    let git_in_cwd = |git_args: &[&str]| -> CliResult<bool> {
+        Ok(Command::new("git")
+            .current_dir(cwd)
+            .args(git_args)
+            .status()?
+            .success())
+    };
+    let remote_ref = format!("origin/{}", trunk);
+
+    if !args.quiet {
+        match update {
+            TrunkUpdate::FastForward => {
+                println!(
+                    "{}",
+                    i18n::t_args(
+                        "sync-fast-forwarding",
+                        &[("trunk", &trunk), ("remote_ref", &remote_ref)],
+                    )
+                )
+            }
+            TrunkUpdate::RebaseLocal { local } => println!(
+                "{}",
+                i18n::t_args(
+                    "sync-rebasing-local-commit",
+                    &[
+                        ("local", &local),
+                        ("trunk", &trunk),
+                        ("remote_ref", &remote_ref),
+                    ],
+                )
+            ),
+            TrunkUpdate::Reset { local } => println!(
+                "{}",
+                i18n::t_args(
+                    "sync-resetting-diverged-dropping",
+                    &[
+                        ("trunk", &trunk),
+                        ("remote_ref", &remote_ref),
+                        ("local", &local),
+                    ],
+                )
+            ),
+        }
+    }
+
+    if !git_in_cwd(&["checkout", trunk.as_str()])? {
+        return Err(CliError::git("git checkout failed"));
     }
 
+    let no_verify = (!args.verify).then_some("--no-verify");
+    match update {
+        TrunkUpdate::FastForward => {
+            let merge_args: Vec<&str> = ["merge"]
+                .into_iter()
+                .chain(no_verify)
+                .chain(["--ff-only", &remote_ref])
+                .collect();
+            if !git_in_cwd(&merge_args)? {
+                return Err(CliError::git("git merge --ff-only failed"));
+            }
+        }
+        TrunkUpdate::RebaseLocal { .. } => {
+            let rebase_args: Vec<&str> = ["rebase"]
+                .into_iter()
+                .chain(no_verify)
+                .chain([remote_ref.as_str()])
+                .collect();
+            if !git_in_cwd(&rebase_args)? {
+                // Leave trunk exactly as it was rather than mid-rebase
+                let _ = git_in_cwd(&["rebase", "--abort"]);
+                return Err(CliError::conflict(format!(
+                    "Rebasing the local commits on '{}' onto {} stopped on conflicts; \
+                     trunk was left unchanged. Rebase it by hand, or run \
+                     'lattice sync --force' to drop the local commits.",
+                    trunk, remote_ref
+                )));
+            }
+        }
+        TrunkUpdate::Reset { .. } => {
+            if !git_in_cwd(&["reset", "--hard", &remote_ref])? {
+                return Err(CliError::git("git reset --hard failed"));
+            }
+        }
+    }
     Ok(())
 }
 
+/// Print what a sync would change without changing anything.
+///
+/// Performs the network reads a real sync does (remote branch tips, their
+/// objects, PR states) and prints the resulting ref updates as a plan
+/// preview. Remote-tracking refs, trunk, metadata, and PRs are untouched.
+async fn preview_sync(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    trunk: &BranchName,
+    args: &SyncArgs,
+) -> CliResult {
+    use crate::core::ops::pending::PendingQueue;
+    use crate::forge::PrState as ForgePrState;
+
+    let mut plan = Plan::new(OpId::new(), "sync");
+    let mut origin_heads = Default::default();
+
+    let mut remotes = vec!["origin".to_string()];
+    remotes.extend(override_remotes(snapshot));
+    for remote in &remotes {
+        let heads = match git
+            .fetch_objects(remote, None)
+            .and_then(|()| git.remote_heads(remote))
+        {
+            Ok(heads) => heads,
+            Err(e) if remote == "origin" => {
+                return Err(CliError::git(format!("git fetch failed: {}", e)))
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    i18n::t_args(
+                        "sync-warning-git-fetch-failed",
+                        &[("remote", &remote), ("error", &e)],
+                    )
+                );
+                continue;
+            }
+        };
+
+        for (name, tip) in &heads {
+            let refname = format!("refs/remotes/{}/{}", remote, name);
+            let current = git.try_resolve_ref(&refname)?;
+            if current.as_ref() != Some(tip) {
+                plan = plan.with_step(PlanStep::UpdateRefCas {
+                    refname,
+                    old_oid: current.map(|oid| oid.to_string()),
+                    new_oid: tip.to_string(),
+                    reason: format!("fetch from {}", remote),
+                });
+            }
+        }
+        if remote == "origin" {
+            origin_heads = heads;
+        }
+    }
+
+    // Same trunk decision as a real sync
+    let local_trunk = format!("refs/heads/{}", trunk);
+    let local_oid = git.resolve_ref(&local_trunk)?;
+    let mut notes = Vec::new();
+    match origin_heads.get(trunk.as_str()) {
+        None => notes.push("Remote trunk not found.".to_string()),
+        Some(remote_oid) => {
+            if let Some(update) = TrunkUpdate::decide(
+                git,
+                trunk,
+                &local_oid,
+                remote_oid,
+                args.trunk_strategy,
+                args.force,
+            )? {
+                plan = plan.with_step(update.step(trunk, &local_oid, remote_oid));
+            }
+        }
+    }
+
+    if let Some(trunk_tip) = origin_heads.get(trunk.as_str()) {
+        for (branch, evidence) in merged_into_trunk(git, snapshot, trunk, trunk_tip) {
+            notes.push(format!(
+                "'{}' was {} into {}; would offer to delete it.",
+                branch,
+                evidence.describe(),
+                trunk
+            ));
+        }
+    }
+
+    // PR states are read, but nothing is replayed or edited
+    if let Ok(forge) = super::origin_forge(git) {
+        let paths = LatticePaths::from_repo_info(&git.info()?);
+        let queued = PendingQueue::load(&paths).map(|q| q.len()).unwrap_or(0);
+        if queued > 0 {
+            notes.push(format!("Would replay {} queued action(s).", queued));
+        }
+
+        let mut open = 0;
+        let linked = linked_prs(snapshot);
+        let statuses = if linked.is_empty() {
+            HashMap::new()
+        } else {
+            fetch_pr_statuses(forge.as_ref(), &linked)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!(
+                        "{}",
+                        i18n::t_args("sync-warning-could-check-prs", &[("error", &e)])
+                    );
+                    HashMap::new()
+                })
+        };
+        for (branch, number) in &linked {
+            let Some(pr) = statuses.get(number).map(|s| &s.pr) else {
+                continue;
+            };
+            if pr.state == ForgePrState::Merged || pr.state == ForgePrState::Closed {
+                notes.push(format!("PR #{} for '{}' is {}.", number, branch, pr.state));
+                if args.prune && pr.state == ForgePrState::Closed {
+                    notes.push(format!("Would offer to prune '{}'.", branch));
+                }
+            } else {
+                if edited_description(
+                    snapshot.metadata[*branch].metadata.description.as_deref(),
+                    pr.body.as_deref(),
+                )
+                .is_some()
+                {
+                    notes.push(format!(
+                        "PR #{} for '{}' has an edited description to pull.",
+                        number, branch
+                    ));
+                }
+                open += 1;
+            }
+        }
+        if open > 0 {
+            notes.push(format!(
+                "Would update stack comments on {} open PR(s).",
+                open
+            ));
+        }
+    }
+
+    if args.restack {
+        notes.push("Would restack branches after syncing.".to_string());
+    }
+
+    println!("{}", plan.preview());
+    for note in notes {
+        println!("{}", note);
+    }
+    println!("{}", i18n::t("sync-dry-run-nothing"));
+    Ok(())
+}
+
+/// Tracked branches linked to a PR, with the PR's number.
+fn linked_prs(snapshot: &RepoSnapshot) -> Vec<(&BranchName, u64)> {
+    snapshot
+        .metadata
+        .iter()
+        .filter_map(|(branch, scanned)| Some((branch, scanned.metadata.pr.number()?)))
+        .collect()
+}
+
+/// Fetch the status of every linked PR in one batch, keyed by PR number.
+async fn fetch_pr_statuses(
+    forge: &dyn Forge,
+    linked: &[(&BranchName, u64)],
+) -> Result<HashMap<u64, PrStatus>, ForgeError> {
+    let mut numbers: Vec<u64> = linked.iter().map(|(_, number)| *number).collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+    Ok(forge
+        .get_pr_statuses(&numbers)
+        .await?
+        .into_iter()
+        .map(|status| (status.pr.number, status))
+        .collect())
+}
+
+/// The PR's description when it differs from the branch's local one.
+///
+/// Only the body outside the stack comment markers is compared, so a
+/// refreshed stack comment never counts as an edit. An empty description
+/// and a missing one are the same.
+fn edited_description(local: Option<&str>, body: Option<&str>) -> Option<String> {
+    let remote = strip_stack_comment(body.unwrap_or(""));
+    (remote != local.unwrap_or("").trim()).then_some(remote)
+}
+
+/// Offer to pull edited PR descriptions into branch metadata.
+///
+/// Each entry is a branch, its PR number, and the PR's description. Without
+/// a terminal to prompt on, the edits are only reported.
+fn pull_descriptions(
+    git: &Git,
+    ctx: &Context,
+    snapshot: &RepoSnapshot,
+    edited: &[(BranchName, u64, String)],
+    quiet: bool,
+) -> CliResult {
+    let mut plan = Plan::new(OpId::new(), "sync");
+    for (branch, number, description) in edited {
+        let Some(scanned) = snapshot.metadata.get(branch) else {
+            continue;
+        };
+        let args: [(&str, &dyn std::fmt::Display); 2] = [("branch", branch), ("pr", number)];
+
+        match prompts::confirm(
+            &i18n::t_args("sync-description-prompt", &args),
+            Severity::Minor,
+            ctx.interactive,
+        ) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(PromptError::NotInteractive) => {
+                if !quiet {
+                    println!("{}", i18n::t_args("sync-description-edited", &args));
+                }
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut metadata = scanned.metadata.clone();
+        metadata.description = (!description.is_empty()).then(|| description.clone());
+        metadata.timestamps.updated_at = UtcTimestamp::now();
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: branch.to_string(),
+            old_ref_oid: Some(scanned.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+    if plan.is_empty() {
+        return Ok(());
+    }
+    Ok(super::execute_follow_up(git, ctx, &plan).context("Failed to update the descriptions")?)
+}
+
+/// Link the PRs opened for queued creations in their branches' metadata.
+///
+/// The writes go through the executor as one plan. Failures only warn: the
+/// PRs exist either way, and the next submit finds them by head.
+fn link_replayed_prs(git: &Git, ctx: &Context, forge: &str, linked: &[LinkedPr]) {
+    use crate::core::metadata::schema::PrState;
+
+    let store = MetadataStore::new(git);
+    let mut plan = Plan::new(OpId::new(), "sync");
+    for pr in linked {
+        let Ok(Some(entry)) = store.read(&pr.branch) else {
+            continue;
+        };
+        let mut metadata = entry.metadata.clone();
+        metadata.pr = PrState::linked(forge, pr.number, &pr.url);
+        metadata.touch();
+        plan = plan.with_step(PlanStep::WriteMetadataCas {
+            branch: pr.branch.to_string(),
+            old_ref_oid: Some(entry.ref_oid.to_string()),
+            metadata: Box::new(metadata),
+        });
+    }
+    if plan.is_empty() {
+        return;
+    }
+
+    let error = match Executor::new(git).execute(&plan, ctx) {
+        Ok(ExecuteResult::Success { .. }) => return,
+        Ok(ExecuteResult::Aborted { error, .. }) => error,
+        Ok(ExecuteResult::Paused { branch, .. }) => format!("paused at '{}'", branch),
+        Err(e) => e.to_string(),
+    };
+    eprintln!(
+        "{}",
+        i18n::t_args("sync-warning-could-link", &[("error", &error.trim())])
+    );
+}
+
+/// Print submitted branches whose remote branch the fetch pruned.
+fn report_remote_branches_gone(snapshot: &RepoSnapshot) {
+    let gone: Vec<_> = snapshot
+        .health
+        .issues()
+        .iter()
+        .filter(|issue| {
+            issue.id.as_str().starts_with("remote-branch-merged:")
+                || issue.id.as_str().starts_with("remote-branch-deleted:")
+        })
+        .collect();
+
+    for issue in &gone {
+        println!("{}.", issue.message);
+    }
+    if !gone.is_empty() {
+        println!("{}", i18n::t("sync-remote-branches-gone"));
+    }
+}
+
+/// Print submitted branches whose fetched remote copy has commits this
+/// repository lacks, before a later push overwrites them.
+fn report_pushed_elsewhere(git: &Git, snapshot: &RepoSnapshot, trunk: &BranchName) -> CliResult {
+    let mut branches: Vec<_> = snapshot.metadata.iter().collect();
+    branches.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+
+    let mut lines = Vec::new();
+    for (branch, scanned) in branches {
+        let remote = scanned.metadata.remote_or("origin");
+        let Some(remote_tip) =
+            git.try_resolve_ref(&format!("refs/remotes/{}/{}", remote, branch))?
+        else {
+            continue;
+        };
+        let Some(trunk_tip) = git
+            .try_resolve_ref(&format!("refs/remotes/{}/{}", remote, trunk))?
+            .or_else(|| snapshot.branches.get(trunk).cloned())
+        else {
+            continue;
+        };
+        if let Some(d) =
+            super::submit::branch_divergence(git, snapshot, branch, &remote_tip, &trunk_tip)
+        {
+            lines.push(i18n::t_args(
+                "submit-diverged-branch",
+                &[
+                    ("branch", branch),
+                    ("remote_name", &remote),
+                    ("local", &d.local_only),
+                    ("remote", &d.remote_only),
+                ],
+            ));
+        }
+    }
+
+    if !lines.is_empty() {
+        println!("{}", i18n::t("submit-diverged-header"));
+        for line in &lines {
+            println!("{}", line);
+        }
+        println!("{}", i18n::t("sync-remote-diverged"));
+    }
+    Ok(())
+}
+
+/// Distinct remote overrides used by tracked branches, other than `origin`.
+fn override_remotes(snapshot: &RepoSnapshot) -> Vec<String> {
+    let mut remotes: Vec<String> = snapshot
+        .metadata
+        .values()
+        .filter_map(|s| s.metadata.remote.clone())
+        .filter(|r| r != "origin")
+        .collect();
+    remotes.sort();
+    remotes.dedup();
+    remotes
+}
+
+/// Record a `merged` lifecycle event unless the branch already has one.
+///
+/// `sync` sees the same merged PR on every run until the branch is deleted,
+/// so only the first observation is recorded. The ledger is only searched
+/// back to the branch's creation.
+fn record_merged_once(ledger: &EventLedger<'_>, snapshot: &RepoSnapshot, branch: &BranchName) {
+    let Some(scanned) = snapshot.metadata.get(branch) else {
+        return;
+    };
+    let since = scanned.metadata.timestamps.created_at.as_datetime();
+    let already_merged = ledger
+        .has_lifecycle_event_since(branch.as_str(), BranchStage::Merged, since)
+        .unwrap_or(false);
+
+    if !already_merged {
+        let _ = ledger.append(Event::branch_lifecycle(
+            branch.as_str(),
+            BranchStage::Merged,
+        ));
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn sync_command_compiles() {
         // Basic compilation test - verifies module structure
     }
+
+    #[test]
+    fn dry_run_plans_no_fetch() {
+        let mut args = SyncArgs {
+            force: false,
+            restack: false,
+            quiet: true,
+            verify: true,
+            dry_run: false,
+            prune: false,
+            offline: false,
+            trunk_strategy: TrunkStrategy::FfOnly,
+        };
+        assert!(matches!(
+            fetch_plan(&args).steps.as_slice(),
+            [PlanStep::ForgeFetch { .. }]
+        ));
+
+        args.dry_run = true;
+        assert!(fetch_plan(&args).is_empty());
+
+        args.dry_run = false;
+        args.offline = true;
+        assert!(fetch_plan(&args).is_empty());
+    }
+
+    #[test]
+    fn edited_description_ignores_stack_comment() {
+        let body = "Adds X.\n\n<!-- lattice:stack:start -->\nstack\n<!-- lattice:stack:end -->";
+        assert_eq!(edited_description(Some("Adds X."), Some(body)), None);
+        assert_eq!(
+            edited_description(Some("Adds X."), Some("Adds X and Y.")),
+            Some("Adds X and Y.".to_string())
+        );
+    }
+
+    #[test]
+    fn edited_description_treats_empty_as_absent() {
+        let body = "<!-- lattice:stack:start -->\nstack\n<!-- lattice:stack:end -->";
+        assert_eq!(edited_description(None, Some(body)), None);
+        assert_eq!(edited_description(None, None), None);
+        assert_eq!(edited_description(Some("Old."), None), Some(String::new()));
+    }
+
+    #[test]
+    fn trunk_strategy_parses_config_values() {
+        assert_eq!(TrunkStrategy::parse("ff-only"), Some(TrunkStrategy::FfOnly));
+        assert_eq!(
+            TrunkStrategy::parse("rebase-local"),
+            Some(TrunkStrategy::RebaseLocal)
+        );
+        assert_eq!(TrunkStrategy::parse("reset"), Some(TrunkStrategy::Reset));
+        assert_eq!(TrunkStrategy::parse("merge"), None);
+    }
+
+    #[test]
+    fn prune_action_accepts_prefixes() {
+        assert_eq!(PruneAction::parse("d"), Some(PruneAction::Delete));
+        assert_eq!(PruneAction::parse(" Un "), Some(PruneAction::Untrack));
+        assert_eq!(PruneAction::parse("untrack"), Some(PruneAction::Untrack));
+        assert_eq!(PruneAction::parse(""), None);
+        assert_eq!(PruneAction::parse("x"), None);
+    }
+
+    #[test]
+    fn trunk_update_depends_on_strategy() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-b", "main"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test User"]);
+        run(&["commit", "--allow-empty", "-m", "base"]);
+        run(&["branch", "upstream"]);
+        run(&["commit", "--allow-empty", "-m", "local"]);
+        run(&["checkout", "upstream"]);
+        run(&["commit", "--allow-empty", "-m", "remote"]);
+
+        let git = Git::open(dir.path()).unwrap();
+        let trunk = BranchName::new("main").unwrap();
+        let local = git.resolve_ref("refs/heads/main").unwrap();
+        let remote = git.resolve_ref("refs/heads/upstream").unwrap();
+        let base = git.merge_base(&local, &remote).unwrap().unwrap();
+        let decide = |local: &Oid, remote: &Oid, strategy, force| {
+            TrunkUpdate::decide(&git, &trunk, local, remote, strategy, force)
+        };
+
+        // Behind only: every strategy fast-forwards
+        for strategy in [
+            TrunkStrategy::FfOnly,
+            TrunkStrategy::RebaseLocal,
+            TrunkStrategy::Reset,
+        ] {
+            assert_eq!(
+                decide(&base, &remote, strategy, false).unwrap(),
+                Some(TrunkUpdate::FastForward)
+            );
+        }
+
+        // Diverged
+        let err = decide(&local, &remote, TrunkStrategy::FfOnly, false).unwrap_err();
+        assert!(err.to_string().contains("has diverged"));
+        assert_eq!(
+            decide(&local, &remote, TrunkStrategy::FfOnly, true).unwrap(),
+            Some(TrunkUpdate::Reset { local: 1 })
+        );
+        assert_eq!(
+            decide(&local, &remote, TrunkStrategy::RebaseLocal, false).unwrap(),
+            Some(TrunkUpdate::RebaseLocal { local: 1 })
+        );
+        assert_eq!(
+            decide(&local, &remote, TrunkStrategy::Reset, false).unwrap(),
+            Some(TrunkUpdate::Reset { local: 1 })
+        );
+
+        // Ahead only: rebasing has nothing to do
+        assert_eq!(
+            decide(&local, &base, TrunkStrategy::RebaseLocal, false).unwrap(),
+            None
+        );
+
+        let step = TrunkUpdate::RebaseLocal { local: 1 }.step(&trunk, &local, &remote);
+        assert!(matches!(step, PlanStep::RunGit { .. }));
+        assert_eq!(
+            step.description(),
+            "rebase 1 local commit(s) on main onto origin/main"
+        );
+    }
+
+    #[test]
+    fn replayed_prs_are_linked() {
+        use crate::core::metadata::schema::PrState;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-b", "main"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test User"]);
+        run(&["commit", "--allow-empty", "-m", "base"]);
+        run(&["checkout", "-b", "feature"]);
+        run(&["commit", "--allow-empty", "-m", "feature"]);
+
+        let ctx = Context {
+            cwd: Some(dir.path().to_path_buf()),
+            quiet: true,
+            ..Default::default()
+        };
+        crate::cli::commands::init(&ctx, Some("main"), false, true).unwrap();
+        crate::cli::commands::track(&ctx, Some("feature"), Some("main"), false, false).unwrap();
+
+        let git = Git::open(dir.path()).unwrap();
+        let feature = BranchName::new("feature").unwrap();
+        let linked = [LinkedPr {
+            branch: feature.clone(),
+            number: 7,
+            url: "https://github.com/o/r/pull/7".to_string(),
+        }];
+        link_replayed_prs(&git, &ctx, "github", &linked);
+
+        let metadata = MetadataStore::new(&git)
+            .read(&feature)
+            .unwrap()
+            .unwrap()
+            .metadata;
+        assert_eq!(
+            metadata.pr,
+            PrState::linked("github", 7, "https://github.com/o/r/pull/7")
+        );
+    }
 }
@@ -5,18 +5,21 @@
 //! Uses `requirements::MUTATING_METADATA_ONLY` - this command only creates
 //! metadata refs and does not require a working directory.
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeScope, FreezeState, ParentInfo, PrState,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeScope, FreezeState, ParentInfo, PrState,
     Timestamps, METADATA_KIND, SCHEMA_VERSION,
 };
 use crate::core::metadata::store::MetadataStore;
 use crate::core::types::BranchName;
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
+use crate::ui::i18n;
+use anyhow::Context as _;
 use std::io::{self, Write};
 
 /// Start tracking a branch.
@@ -38,7 +41,7 @@ pub fn track(
     parent: Option<&str>,
     force: bool,
     as_frozen: bool,
-) -> Result<()> {
+) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -49,20 +52,14 @@ pub fn track(
         let snapshot = &ready.snapshot;
 
         // Ensure trunk is configured
-        let trunk = snapshot.trunk.as_ref().ok_or_else(|| {
-            RunError::Scan(crate::engine::scan::ScanError::Internal(
-                "Trunk not configured. Run 'lattice init' first.".to_string(),
-            ))
-        })?;
+        let trunk = snapshot
+            .trunk
+            .as_ref()
+            .ok_or(PlanError::TrunkNotConfigured)?;
 
         // Resolve target branch
         let target = if let Some(name) = branch {
-            BranchName::new(name).map_err(|e| {
-                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                    "Invalid branch name: {}",
-                    e
-                )))
-            })?
+            BranchName::new(name).map_err(PlanError::from)?
         } else if let Some(ref current) = snapshot.current_branch {
             current.clone()
         } else {
@@ -81,7 +78,10 @@ pub fn track(
         // Check if already tracked
         if snapshot.metadata.contains_key(&target) {
             if !ctx.quiet {
-                println!("Branch '{}' is already tracked", target);
+                println!(
+                    "{}",
+                    i18n::t_args("track-branch-already-tracked", &[("target", &target)])
+                );
             }
             return Ok(());
         }
@@ -103,12 +103,7 @@ pub fn track(
             })?;
             // Parent must be tracked or trunk
             if &p != trunk && !snapshot.metadata.contains_key(&p) {
-                return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                    format!(
-                        "Parent '{}' is not tracked. Track it first or use trunk.",
-                        p
-                    ),
-                )));
+                return Err(RunError::Plan(PlanError::UntrackedBranch(p.to_string())));
             }
             p
         } else if force {
@@ -126,12 +121,15 @@ pub fn track(
             candidates.sort_by(|a, b| a.as_str().cmp(b.as_str()));
             candidates.dedup();
 
-            println!("Select parent branch for '{}':", target);
+            println!(
+                "{}",
+                i18n::t_args("track-select-parent-branch", &[("target", &target)])
+            );
             for (i, b) in candidates.iter().enumerate() {
                 let trunk_marker = if *b == trunk { " (trunk)" } else { "" };
                 println!("  {}. {}{}", i + 1, b, trunk_marker);
             }
-            print!("Enter number: ");
+            print!("{} ", i18n::t("track-enter-number"));
             io::stdout().flush().map_err(|e| {
                 RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
                     "Failed to flush stdout: {}",
@@ -231,7 +229,7 @@ pub fn track(
         };
 
         let now = crate::core::types::UtcTimestamp::now();
-        let metadata = BranchMetadataV1 {
+        let metadata = BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -247,6 +245,14 @@ pub fn track(
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         };
 
         // Write metadata (new branch, no expected old value)
@@ -260,24 +266,24 @@ pub fn track(
 
         if !ctx.quiet {
             println!(
-                "Tracking '{}' with parent '{}' (base: {})",
-                target,
-                parent_branch,
-                &base_oid.as_str()[..7]
+                "{}",
+                i18n::t_args(
+                    "track-tracking-parent-base",
+                    &[
+                        ("target", &target),
+                        ("parent_branch", &parent_branch),
+                        ("base", &(&base_oid.as_str()[..7])),
+                    ],
+                )
             );
             if as_frozen {
-                println!("  (frozen)");
+                println!("  {}", i18n::t("track-frozen"));
             }
         }
 
         Ok(())
     })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    .map_err(CliError::from)
 }
 
 /// Find the nearest tracked ancestor of a branch.
@@ -285,16 +291,16 @@ pub fn find_nearest_tracked_ancestor(
     git: &Git,
     branch: &BranchName,
     snapshot: &RepoSnapshot,
-) -> Result<BranchName> {
+) -> CliResult<BranchName> {
     let trunk = snapshot
         .trunk
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Trunk not configured"))?;
+        .ok_or_else(CliError::not_initialized)?;
 
     let branch_oid = snapshot
         .branches
         .get(branch)
-        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", branch))?;
+        .ok_or_else(|| CliError::usage(format!("Branch '{}' not found", branch)))?;
 
     // Check all tracked branches plus trunk
     let mut candidates: Vec<(&BranchName, &crate::core::types::Oid)> = snapshot
@@ -323,7 +329,7 @@ pub fn find_nearest_tracked_ancestor(
     }
 
     best.map(|(b, _)| b)
-        .ok_or_else(|| anyhow::anyhow!("No tracked ancestor found"))
+        .ok_or_else(|| CliError::usage("No tracked ancestor found"))
 }
 
 /// Count commits between two OIDs.
@@ -331,7 +337,7 @@ pub fn count_commits(
     git: &Git,
     from: &crate::core::types::Oid,
     to: &crate::core::types::Oid,
-) -> Result<i32> {
+) -> CliResult<i32> {
     // Use rev-list to count
     let output = std::process::Command::new("git")
         .args(["rev-list", "--count", &format!("{}..{}", from, to)])
@@ -340,8 +346,8 @@ pub fn count_commits(
         .context("Failed to run git rev-list")?;
 
     let count_str = String::from_utf8_lossy(&output.stdout);
-    count_str
+    Ok(count_str
         .trim()
         .parse()
-        .context("Failed to parse commit count")
+        .context("Failed to parse commit count")?)
 }
@@ -4,11 +4,13 @@
 //!
 //! This is a read-only command that uses `requirements::READ_ONLY`.
 
+use crate::cli::error::{CliError, CliResult};
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use anyhow::Context as _;
 
 /// Display or set the trunk branch.
 ///
@@ -20,7 +22,7 @@ use anyhow::{bail, Context as _, Result};
 /// # Gating
 ///
 /// Uses `requirements::READ_ONLY` for display mode.
-pub fn trunk(ctx: &Context, set: Option<&str>) -> Result<()> {
+pub fn trunk(ctx: &Context, set: Option<&str>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -30,11 +32,10 @@ pub fn trunk(ctx: &Context, set: Option<&str>) -> Result<()> {
     if let Some(new_trunk) = set {
         // Setting trunk - this requires init or config set
         // For now, redirect to init or config
-        bail!(
+        return Err(CliError::usage(format!(
             "Use 'lattice init --trunk {}' or 'lattice config set trunk.branch {}' to set trunk",
-            new_trunk,
-            new_trunk
-        );
+            new_trunk, new_trunk
+        )));
     }
 
     // Display current trunk - run through gating
@@ -45,16 +46,8 @@ pub fn trunk(ctx: &Context, set: Option<&str>) -> Result<()> {
             println!("{}", trunk);
             Ok(())
         } else {
-            Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                "Trunk not configured. Run 'lattice init --trunk <branch>' to configure."
-                    .to_string(),
-            )))
+            Err(RunError::Plan(PlanError::TrunkNotConfigured))
         }
     })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    .map_err(CliError::from)
 }
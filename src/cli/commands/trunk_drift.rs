@@ -0,0 +1,197 @@
+//! cli::commands::trunk_drift
+//!
+//! Warn when a stack has fallen far behind trunk.
+//!
+//! # Design
+//!
+//! Drift is measured at a stack's root, the branch whose parent is trunk:
+//! the commits on the remote trunk (`refs/remotes/<remote>/<trunk>`, or the
+//! local trunk when there is no remote-tracking ref) that the root's base
+//! doesn't contain. The base last matched trunk just before the oldest of
+//! those commits landed, so its author date dates the drift. This is derived
+//! from history on every read; nothing is stored.
+//!
+//! `lt log` and `lt info` warn about stacks past either configured
+//! threshold (`[drift] warn_days`, `[drift] warn_commits`), so users restack
+//! before conflicts pile up.
+
+use chrono::{DateTime, Utc};
+
+use crate::core::config::Config;
+use crate::core::types::{BranchName, Oid};
+use crate::engine::scan::RepoSnapshot;
+use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::output::{self, Verbosity};
+
+/// Limits past which a stack counts as drifted. Zero disables a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriftThresholds {
+    /// Days since the stack's base last matched trunk
+    pub days: u64,
+    /// Trunk commits missing from the stack's base
+    pub commits: usize,
+}
+
+impl DriftThresholds {
+    /// Thresholds from config, with defaults when it can't be loaded.
+    pub fn load(cwd: &std::path::Path) -> Self {
+        let config = Config::load(Some(cwd))
+            .map(|r| r.config)
+            .unwrap_or_default();
+        Self {
+            days: config.drift_warn_days(),
+            commits: config.drift_warn_commits(),
+        }
+    }
+}
+
+/// How far a stack's base is behind trunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackDrift {
+    /// The stack's root branch
+    pub root: BranchName,
+    /// Trunk commits missing from the base
+    pub behind: usize,
+    /// When trunk first moved past the base
+    pub since: DateTime<Utc>,
+}
+
+impl StackDrift {
+    /// Whole days the base has been behind, as of `now`.
+    pub fn days(&self, now: DateTime<Utc>) -> u64 {
+        (now - self.since).num_days().max(0) as u64
+    }
+
+    /// Check whether the drift passes either threshold.
+    pub fn exceeds(&self, thresholds: DriftThresholds, now: DateTime<Utc>) -> bool {
+        (thresholds.commits > 0 && self.behind > thresholds.commits)
+            || (thresholds.days > 0 && self.days(now) > thresholds.days)
+    }
+}
+
+/// Roots of the stacks containing `branches`, sorted and deduplicated.
+pub fn stack_roots(snapshot: &RepoSnapshot, branches: &[BranchName]) -> Vec<BranchName> {
+    let Some(trunk) = snapshot.trunk.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut roots: Vec<BranchName> = branches
+        .iter()
+        .filter_map(|branch| {
+            let mut current = branch;
+            loop {
+                match snapshot.graph.parent(current) {
+                    Some(parent) if parent == trunk => return Some(current.clone()),
+                    Some(parent) => current = parent,
+                    None => return None,
+                }
+            }
+        })
+        .collect();
+    roots.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    roots.dedup();
+    roots
+}
+
+/// Measure how far the stack rooted at `root` is behind trunk.
+///
+/// Returns `None` when the stack is up to date or drift can't be determined.
+pub fn stack_drift(git: &Git, snapshot: &RepoSnapshot, root: &BranchName) -> Option<StackDrift> {
+    let trunk = snapshot.trunk.as_ref()?;
+    let base = Oid::new(&snapshot.metadata.get(root)?.metadata.base.oid).ok()?;
+
+    let remote = snapshot
+        .repo_config
+        .as_ref()
+        .and_then(|c| c.remote.as_deref())
+        .unwrap_or("origin");
+    let trunk_tip = git
+        .try_resolve_ref(&format!("refs/remotes/{}/{}", remote, trunk))
+        .ok()
+        .flatten()
+        .or_else(|| snapshot.branches.get(trunk).cloned())?;
+
+    let missing = git.commits_between(&base, &trunk_tip).ok()?;
+    let oldest = missing.iter().map(|c| c.author_time).min()?;
+    Some(StackDrift {
+        root: root.clone(),
+        behind: missing.len(),
+        since: oldest,
+    })
+}
+
+/// Warn about each stack containing `branches` that has drifted too far.
+pub fn warn_drifted_stacks(
+    git: &Git,
+    snapshot: &RepoSnapshot,
+    branches: &[BranchName],
+    thresholds: DriftThresholds,
+    verbosity: Verbosity,
+) {
+    if verbosity == Verbosity::Quiet || (thresholds.days == 0 && thresholds.commits == 0) {
+        return;
+    }
+    let Some(trunk) = snapshot.trunk.as_ref() else {
+        return;
+    };
+
+    let now = Utc::now();
+    for root in stack_roots(snapshot, branches) {
+        let Some(drift) = stack_drift(git, snapshot, &root) else {
+            continue;
+        };
+        if drift.exceeds(thresholds, now) {
+            output::warn(
+                i18n::t_args(
+                    "drift-stack-behind",
+                    &[
+                        ("branch", &drift.root),
+                        ("trunk", trunk),
+                        ("commits", &drift.behind),
+                        ("days", &drift.days(now)),
+                    ],
+                ),
+                verbosity,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drift(behind: usize, days_ago: i64, now: DateTime<Utc>) -> StackDrift {
+        StackDrift {
+            root: BranchName::new("feature").unwrap(),
+            behind,
+            since: now - chrono::Duration::days(days_ago),
+        }
+    }
+
+    #[test]
+    fn exceeds_either_threshold() {
+        let now = Utc::now();
+        let thresholds = DriftThresholds {
+            days: 14,
+            commits: 50,
+        };
+
+        assert!(!drift(50, 14, now).exceeds(thresholds, now));
+        assert!(drift(51, 0, now).exceeds(thresholds, now));
+        assert!(drift(1, 15, now).exceeds(thresholds, now));
+    }
+
+    #[test]
+    fn zero_disables_threshold() {
+        let now = Utc::now();
+        let commits_only = DriftThresholds {
+            days: 0,
+            commits: 10,
+        };
+
+        assert!(!drift(5, 365, now).exceeds(commits_only, now));
+        assert!(drift(11, 0, now).exceeds(commits_only, now));
+    }
+}
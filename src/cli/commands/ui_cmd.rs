@@ -0,0 +1,112 @@
+//! ui command - Browse the stack in a full-screen terminal UI
+//!
+//! # Architecture
+//!
+//! Laying out the tree is a read-only command that implements
+//! `ReadOnlyCommand` with `requirements::READ_ONLY`, so it flows through
+//! `run_readonly_command` like `log`. The browser itself (`ui::tui`) only
+//! picks a branch and an action; the action then runs through the ordinary
+//! `checkout`, `restack`, or `submit` entry point, with the same gating and
+//! journaling as typing that command.
+//!
+//! All tracked branches are shown, drawn as `lattice log --all` draws them,
+//! with the current branch selected. The line under the tree describes the
+//! selected branch as accessible output does (parent, commits, restack,
+//! freeze, and PR state).
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::paths::LatticePaths;
+use crate::core::types::BranchName;
+use crate::engine::command::ReadOnlyCommand;
+use crate::engine::gate::{requirements, ReadyContext, RequirementSet};
+use crate::engine::plan::PlanError;
+use crate::engine::runner::run_readonly_command;
+use crate::engine::Context;
+use crate::forge::status_cache::ForgeStatusCache;
+use crate::git::Git;
+use crate::ui::i18n;
+use crate::ui::tui::{self, Action, Row};
+use anyhow::Context as _;
+
+use super::log_cmd::{describe_branch, tree_rows};
+
+/// Command to lay out the rows the browser shows.
+pub struct UiCommand<'a> {
+    git: &'a Git,
+    forge_status: ForgeStatusCache,
+    offline: bool,
+}
+
+impl ReadOnlyCommand for UiCommand<'_> {
+    const REQUIREMENTS: &'static RequirementSet = &requirements::READ_ONLY;
+    /// The rows, and the current branch
+    type Output = (Vec<Row>, Option<BranchName>);
+
+    fn execute(&self, ready: &ReadyContext) -> Result<Self::Output, PlanError> {
+        let snapshot = &ready.snapshot;
+        if snapshot.metadata.is_empty() {
+            return Err(PlanError::InvalidState(i18n::t("tui-nothing-tracked")));
+        }
+
+        let mut branches: Vec<BranchName> = snapshot.graph.branches().cloned().collect();
+        branches.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let rows = tree_rows(snapshot, &branches, &self.forge_status, self.offline)
+            .into_iter()
+            .map(|(branch, line)| {
+                let detail = branch
+                    .as_ref()
+                    .map(|b| {
+                        let is_current = snapshot.current_branch.as_ref() == Some(b);
+                        describe_branch(self.git, snapshot, b, is_current)
+                    })
+                    .unwrap_or_default();
+                Row {
+                    branch,
+                    line,
+                    detail,
+                }
+            })
+            .collect();
+        Ok((rows, snapshot.current_branch.clone()))
+    }
+}
+
+/// Browse tracked branches and check out, restack, or submit one.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+///
+/// # Gating
+///
+/// Laying out the tree uses `requirements::READ_ONLY`; the picked action
+/// is gated by its own command. Needs an interactive terminal.
+pub fn ui(ctx: &Context) -> CliResult {
+    if !ctx.interactive {
+        return Err(CliError::usage(i18n::t("tui-needs-interactive")));
+    }
+
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+
+    let cmd = UiCommand {
+        git: &git,
+        forge_status: ForgeStatusCache::load(&LatticePaths::from_repo_info(&git.info()?)),
+        offline: ctx.offline,
+    };
+    let (rows, current) = run_readonly_command(&cmd, &git, ctx).map_err(CliError::from)?;
+
+    let Some((action, branch)) = tui::run(rows, current.as_ref())? else {
+        return Ok(());
+    };
+    match action {
+        Action::Checkout => {
+            super::checkout::checkout(ctx, Some(branch.as_str()), false, false, None)
+        }
+        Action::Restack => super::restack::restack_branches(ctx, &[branch]),
+        Action::Submit => super::submit::submit_branches(ctx, &[branch]),
+    }
+}
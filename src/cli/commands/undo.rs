@@ -1,13 +1,23 @@
-//! undo command - Undo the last completed operation
+//! undo command - Undo completed operations, and redo them
 //!
-//! Per SPEC.md §8F.3, undo reverses the most recent committed Lattice operation:
+//! Per SPEC.md §8F.3, undo reverses committed Lattice operations:
 //! - Ref moves are restored to their previous values
 //! - Metadata changes are reverted
 //!
+//! `lattice undo` reverses the most recent operation that hasn't been undone
+//! yet, so repeating it walks further back. `lattice undo --list` shows the
+//! recent history, and `lattice undo <op-id>` reverses every operation back
+//! to and including the given one, newest first.
+//!
+//! `lattice redo` reapplies the operation most recently undone, setting each
+//! ref to the value the operation left, and refuses if any of those refs
+//! moved since the undo.
+//!
 //! # Limitations
 //!
 //! - Cannot undo remote operations (push, PR creation)
-//! - Metadata updates where the old content wasn't stored may not be fully restorable
+//! - Metadata changes journaled before snapshots were recorded can't be undone
+//!   once `git gc` has pruned the old blob
 //! - Cannot undo while another operation is in progress (use `abort` first)
 //!
 //! # Remote Operation Warnings
@@ -17,18 +27,36 @@
 //! are restored, but remote branches remain as-is and may require manual cleanup
 //! (e.g., force-push to revert remote state).
 //!
+//! # Multi-Step Validation
+//!
+//! Before undoing several operations, each older operation is checked against
+//! the state the newer ones restore: every ref it changed must still hold the
+//! value it left there. A ref moved outside Lattice in between (a manual
+//! commit or reset) would otherwise be silently overwritten, so undo refuses
+//! and lists the refs instead. Nothing is changed unless every check passes.
+//!
 //! # Implementation Notes
 //!
 //! Undo uses force ref updates (`Git::update_ref_force`) rather than CAS updates
 //! because the repository state may have changed since the operation was recorded.
 //! The goal is unconditional restoration to the known good state from the journal.
+//! The newest operation being undone is therefore never validated, matching
+//! single-step undo.
 //!
 //! # Ledger Integration (Phase 7)
 //!
 //! After a successful undo, an `UndoApplied` event is recorded in the event ledger
-//! for audit purposes. This includes the operation ID that was undone and the number
-//! of refs that were restored.
+//! for each operation undone, with the number of refs that were restored. Redo
+//! records a `RedoApplied` event, which pairs with the newest `UndoApplied` for
+//! the same operation. These events are also how undo and redo tell which
+//! operations are currently undone: one whose latest undo/redo event is an
+//! `UndoApplied`. Toggling back and forth is therefore safe, and each step
+//! is on record.
+
+use std::collections::{BTreeSet, HashMap};
 
+use crate::cli::error::{CliError, CliResult, ErrorCategory};
+use crate::core::metadata::store::MetadataStore;
 use crate::core::ops::journal::{Journal, OpPhase, OpState, StepKind};
 use crate::core::paths::LatticePaths;
 use crate::core::types::Oid;
@@ -36,12 +64,20 @@ use crate::engine::gate::requirements;
 use crate::engine::ledger::{Event, EventLedger};
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{bail, Context as _, Result};
+use crate::ui::i18n;
+use crate::ui::prompts::{self, Severity};
+use anyhow::Context as _;
+
+/// Number of operations shown by `undo --list`.
+const HISTORY_LIMIT: usize = 20;
+
+/// Length of the operation ID prefix shown by `undo --list`.
+const SHORT_ID_LEN: usize = 8;
 
 /// Undo the last completed operation.
 ///
 /// Per SPEC.md §8F.3:
-/// - Undoes the most recent committed Lattice operation
+/// - Undoes the most recent committed Lattice operation not already undone
 /// - Cannot undo remote PR creation or pushes
 /// - Uses stored journal snapshots for ref restoration
 ///
@@ -50,7 +86,191 @@ use anyhow::{bail, Context as _, Result};
 /// - Records `UndoApplied` event in ledger
 /// - Warns about remote operations that cannot be undone
 /// - Uses Git interface instead of raw commands
-pub fn undo(ctx: &Context) -> Result<()> {
+pub fn undo(ctx: &Context) -> CliResult {
+    let (git, paths) = open_for_undo(ctx, "undo")?;
+    let history = History::load(&git, &paths)?;
+
+    let journal = history
+        .undoable()
+        .next()
+        .ok_or_else(|| CliError::usage("No committed operations to undo"))?;
+
+    apply(ctx, &git, &[journal])
+}
+
+/// Undo every operation back to and including `op_id`.
+///
+/// `op_id` may be any unique prefix of an operation ID, as shown by
+/// [`undo_list`]. Operations already undone are skipped.
+pub fn undo_to(ctx: &Context, op_id: &str) -> CliResult {
+    let (git, paths) = open_for_undo(ctx, "undo")?;
+    let history = History::load(&git, &paths)?;
+
+    let target = find_operation(&history.journals, op_id)?;
+    if target.phase != OpPhase::Committed {
+        return Err(CliError::usage(format!(
+            "Operation {} is {}; only committed operations can be undone",
+            short_id(target),
+            history.status(target)
+        )));
+    }
+    if history.is_undone(target) {
+        return Err(CliError::usage(format!(
+            "Operation {} has already been undone",
+            short_id(target)
+        )));
+    }
+
+    let mut chain = Vec::new();
+    for journal in history.undoable() {
+        chain.push(journal);
+        if journal.op_id == target.op_id {
+            break;
+        }
+    }
+
+    let conflicts = check_reversible(&chain, |refname| {
+        Ok(git
+            .try_resolve_ref_to_object(refname)?
+            .map(|oid| oid.to_string()))
+    })?;
+    if !conflicts.is_empty() {
+        let mut message = format!(
+            "Cannot undo back to {}: these refs changed outside Lattice since the operations that set them:",
+            short_id(target)
+        );
+        for conflict in &conflicts {
+            message.push_str(&format!("\n  {}", conflict));
+        }
+        message.push_str("\nUndo fewer operations, or restore these refs first.");
+        return Err(CliError::conflict(message));
+    }
+
+    if chain.len() > 1 {
+        if !ctx.quiet {
+            println!(
+                "{}",
+                i18n::t_args("undo-undoing-operations", &[("count", &chain.len())])
+            );
+            for journal in &chain {
+                println!("  {}  {}", short_id(journal), journal.command);
+            }
+        }
+        if ctx.interactive
+            && !prompts::confirm(&i18n::t("prompt-undo-apply"), Severity::Minor, true)?
+        {
+            println!("{}", i18n::t("prompt-aborted"));
+            return Ok(());
+        }
+    }
+
+    apply(ctx, &git, &chain)
+}
+
+/// Reapply the operation most recently reverted by undo.
+///
+/// Refuses when any ref the operation changed no longer holds the value the
+/// undo restored, since reapplying would then discard later work.
+pub fn redo(ctx: &Context) -> CliResult {
+    let (git, paths) = open_for_undo(ctx, "redo")?;
+    let history = History::load(&git, &paths)?;
+
+    let op_id = history
+        .undone
+        .first()
+        .ok_or_else(|| CliError::usage("Nothing to redo"))?;
+    let journal = history
+        .journals
+        .iter()
+        .find(|j| j.op_id.as_str() == op_id)
+        .ok_or_else(|| CliError::failed(format!("The journal for operation {} is gone", op_id)))?;
+
+    let conflicts = check_reappliable(journal, |refname| {
+        Ok(git
+            .try_resolve_ref_to_object(refname)?
+            .map(|oid| oid.to_string()))
+    })?;
+    if !conflicts.is_empty() {
+        let mut message = format!(
+            "Cannot redo {} ({}): these refs changed since it was undone:",
+            journal.command,
+            short_id(journal)
+        );
+        for conflict in &conflicts {
+            message.push_str(&format!("\n  {}", conflict));
+        }
+        return Err(CliError::conflict(message));
+    }
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args(
+                "undo-redoing",
+                &[("command", &journal.command), ("op_id", &journal.op_id)],
+            )
+        );
+    }
+    let refs_reapplied = reapply(ctx, &git, &ref_changes(journal))?;
+
+    let event = Event::redo_applied(journal.op_id.as_str(), refs_reapplied);
+    if let Err(e) = EventLedger::new(&git).append(event) {
+        if ctx.debug {
+            eprintln!(
+                "[debug] Warning: Could not record redo event in ledger: {}",
+                e
+            );
+        }
+    }
+
+    if !ctx.quiet {
+        println!(
+            "{}",
+            i18n::t_args("undo-redo-complete", &[("refs_reapplied", &refs_reapplied)])
+        );
+    }
+    Ok(())
+}
+
+/// Show recent operations and whether they can still be undone.
+pub fn undo_list(ctx: &Context) -> CliResult {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let git = Git::open(&cwd).context("Failed to open repository")?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::RECOVERY)
+        .map_err(CliError::needs_repair)?;
+    let paths = LatticePaths::from_repo_info(&git.info()?);
+
+    let history = History::load(&git, &paths)?;
+    let shown: Vec<&Journal> = history.journals.iter().take(HISTORY_LIMIT).collect();
+
+    if shown.is_empty() {
+        if !ctx.quiet {
+            println!("{}", i18n::t("undo-no-operations"));
+        }
+        return Ok(());
+    }
+
+    let width = shown.iter().map(|j| j.command.len()).max().unwrap_or(0);
+    for journal in shown {
+        let time = journal.finished_at.as_ref().unwrap_or(&journal.started_at);
+        println!(
+            "{}  {}  {:<width$}  {:<11}  {}",
+            short_id(journal),
+            time.as_datetime().format("%Y-%m-%d %H:%M:%S UTC"),
+            journal.command,
+            history.status(journal),
+            summarize(journal),
+        );
+    }
+    Ok(())
+}
+
+/// Open the repository and refuse to undo or redo while an operation is in
+/// progress.
+fn open_for_undo(ctx: &Context, action: &str) -> CliResult<(Git, LatticePaths)> {
     let cwd = ctx
         .cwd
         .clone()
@@ -60,213 +280,470 @@ pub fn undo(ctx: &Context) -> Result<()> {
     let paths = LatticePaths::from_repo_info(&info);
 
     if ctx.debug {
-        eprintln!("[debug] undo: opening repository at {:?}", cwd);
+        eprintln!("[debug] {}: opening repository at {:?}", action, cwd);
     }
 
     // Pre-flight gating check (RECOVERY is minimal - just RepoOpen)
-    crate::engine::runner::check_requirements(&git, &requirements::RECOVERY)
-        .map_err(|bundle| anyhow::anyhow!("Repository needs repair: {}", bundle))?;
+    crate::engine::runner::check_requirements(&git, ctx, &requirements::RECOVERY)
+        .map_err(CliError::needs_repair)?;
 
     // Check for in-progress operation
     if let Some(op_state) = OpState::read(&paths)? {
-        bail!(
-            "Cannot undo while operation '{}' is in progress. Use 'lattice abort' first.",
-            op_state.command
-        );
+        return Err(CliError::new(
+            ErrorCategory::OperationInProgress,
+            format!(
+                "Cannot {} while operation '{}' is in progress. Use 'lattice abort' first.",
+                action, op_state.command
+            ),
+        ));
     }
 
-    // Find most recent committed journal
-    let ops_dir = paths.repo_ops_dir();
-    if !ops_dir.exists() {
-        bail!("No operations to undo");
+    Ok((git, paths))
+}
+
+/// Recorded operations, newest first, and which of them are undone.
+struct History {
+    journals: Vec<Journal>,
+    /// Undone operation IDs, most recently undone first
+    undone: Vec<String>,
+}
+
+impl History {
+    fn load(git: &Git, paths: &LatticePaths) -> CliResult<Self> {
+        let mut journals = Vec::new();
+        for id in Journal::list(paths).context("Failed to read ops directory")? {
+            journals.push(Journal::read(paths, &id).context("Failed to read journal")?);
+        }
+        // File times can tie for operations run in quick succession
+        journals.sort_by(|a, b| b.started_at.as_datetime().cmp(a.started_at.as_datetime()));
+
+        // Without a readable ledger nothing counts as undone, as before
+        let undone = EventLedger::new(git).undone_op_ids().unwrap_or_default();
+
+        Ok(Self { journals, undone })
     }
 
-    let mut journals: Vec<_> = std::fs::read_dir(&ops_dir)
-        .context("Failed to read ops directory")?
-        .filter_map(|e| e.ok())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .map(|ext| ext == "json")
-                .unwrap_or(false)
-        })
-        .collect();
+    fn is_undone(&self, journal: &Journal) -> bool {
+        self.undone.iter().any(|id| id == journal.op_id.as_str())
+    }
 
-    if journals.is_empty() {
-        bail!("No operations to undo");
+    /// Committed operations that haven't been undone, newest first.
+    fn undoable(&self) -> impl Iterator<Item = &Journal> {
+        self.journals
+            .iter()
+            .filter(|j| j.phase == OpPhase::Committed && !self.is_undone(j))
     }
 
-    // Sort by modification time (most recent first)
-    journals.sort_by(|a, b| {
-        let a_time = a.metadata().and_then(|m| m.modified()).ok();
-        let b_time = b.metadata().and_then(|m| m.modified()).ok();
-        b_time.cmp(&a_time)
-    });
-
-    // Find most recent committed journal
-    let mut last_committed: Option<Journal> = None;
-    for entry in journals {
-        let path = entry.path();
-        let content = std::fs::read_to_string(&path).context("Failed to read journal")?;
-        let journal: Journal = serde_json::from_str(&content).context("Failed to parse journal")?;
-
-        if journal.phase == OpPhase::Committed {
-            last_committed = Some(journal);
-            break;
+    fn status(&self, journal: &Journal) -> &'static str {
+        match journal.phase {
+            OpPhase::Committed if self.is_undone(journal) => "undone",
+            OpPhase::Committed => "committed",
+            OpPhase::RolledBack => "rolled back",
+            OpPhase::Paused => "paused",
+            OpPhase::InProgress => "in progress",
         }
     }
+}
 
-    let journal =
-        last_committed.ok_or_else(|| anyhow::anyhow!("No committed operations to undo"))?;
+/// Find the operation whose ID starts with `prefix`.
+fn find_operation<'a>(journals: &'a [Journal], prefix: &str) -> CliResult<&'a Journal> {
+    let matches: Vec<&Journal> = journals
+        .iter()
+        .filter(|j| j.op_id.as_str().starts_with(prefix))
+        .collect();
+    match matches.as_slice() {
+        [journal] => Ok(journal),
+        [] => Err(CliError::usage(format!(
+            "No operation matches '{}'. Run 'lattice undo --list' to see recent operations.",
+            prefix
+        ))),
+        _ => Err(CliError::usage(format!(
+            "'{}' matches {} operations; use more characters of the ID",
+            prefix,
+            matches.len()
+        ))),
+    }
+}
 
-    if !ctx.quiet {
-        println!("Undoing: {} ({})", journal.command, journal.op_id);
+fn short_id(journal: &Journal) -> &str {
+    let id = journal.op_id.as_str();
+    &id[..SHORT_ID_LEN.min(id.len())]
+}
+
+/// Branches an operation touched, e.g. `feature, fix (3 refs)`.
+fn summarize(journal: &Journal) -> String {
+    let summary = journal.rollback_summary();
+    if summary.total_items() == 0 {
+        return "no ref changes".to_string();
     }
+    let branches: BTreeSet<&str> = summary
+        .ref_updates
+        .iter()
+        .map(|r| r.strip_prefix("refs/heads/").unwrap_or(r))
+        .chain(summary.metadata_creates.iter().map(String::as_str))
+        .chain(summary.metadata_updates.iter().map(String::as_str))
+        .chain(summary.metadata_deletes.iter().map(String::as_str))
+        .collect();
+    let names: Vec<&str> = branches.into_iter().collect();
+    format!("{} ({} refs)", names.join(", "), summary.total_items())
+}
+
+/// One ref change to reverse: the ref, its value before the operation, and
+/// its value after. `None` means the ref didn't exist.
+struct RefChange {
+    refname: String,
+    old: Option<String>,
+    /// Journaled content of the old metadata blob, if any.
+    old_content: Option<String>,
+    new: Option<String>,
+}
+
+/// The ref changes of an operation, newest first.
+fn ref_changes(journal: &Journal) -> Vec<RefChange> {
+    journal
+        .ref_updates_for_rollback()
+        .into_iter()
+        .filter_map(|step| match step {
+            StepKind::RefUpdate {
+                refname,
+                old_oid,
+                new_oid,
+            } => Some(RefChange {
+                refname: refname.clone(),
+                old: old_oid.clone(),
+                old_content: None,
+                // Deletions are journaled with an empty new OID
+                new: Some(new_oid.clone()).filter(|oid| !oid.is_empty()),
+            }),
+            StepKind::MetadataWrite {
+                branch,
+                old_ref_oid,
+                old_content,
+                new_ref_oid,
+            } => Some(RefChange {
+                refname: format!("refs/branch-metadata/{}", branch),
+                old: old_ref_oid.clone(),
+                old_content: old_content.clone(),
+                new: Some(new_ref_oid.clone()),
+            }),
+            StepKind::MetadataDelete {
+                branch,
+                old_ref_oid,
+                old_content,
+            } => Some(RefChange {
+                refname: format!("refs/branch-metadata/{}", branch),
+                old: Some(old_ref_oid.clone()),
+                old_content: old_content.clone(),
+                new: None,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check that undoing `chain` (newest first) in order is safe.
+///
+/// Simulates the undo on top of the current ref values from `current`.
+/// Every operation after the first must find each ref it changed at the
+/// value it left there. Returns a line per mismatch, empty when safe.
+fn check_reversible(
+    chain: &[&Journal],
+    current: impl Fn(&str) -> CliResult<Option<String>>,
+) -> CliResult<Vec<String>> {
+    let mut simulated: HashMap<String, Option<String>> = HashMap::new();
+    let mut conflicts = Vec::new();
 
-    // Warn about remote operations that cannot be undone (Phase 7)
-    if journal.has_remote_operations() {
-        let remote_ops = journal.remote_operation_descriptions();
-        if !remote_ops.is_empty() {
-            eprintln!();
-            eprintln!("Warning: This operation included remote changes that cannot be undone:");
-            for desc in &remote_ops {
-                eprintln!("  - {}", desc);
+    for (i, journal) in chain.iter().enumerate() {
+        for change in ref_changes(journal) {
+            if i > 0 {
+                let value = match simulated.get(&change.refname) {
+                    Some(value) => value.clone(),
+                    None => current(&change.refname)?,
+                };
+                if value != change.new {
+                    conflicts.push(format!(
+                        "{} (set by {} {}, now {})",
+                        change.refname,
+                        short_id(journal),
+                        journal.command,
+                        value.as_deref().map_or("missing", |v| &v[..7.min(v.len())])
+                    ));
+                }
             }
-            eprintln!();
-            eprintln!("Local refs will be restored, but remote branches remain as-is.");
-            eprintln!("You may need to force-push or manually revert changes on the remote.");
-            eprintln!();
+            simulated.insert(change.refname, change.old);
         }
     }
+    Ok(conflicts)
+}
 
-    // Get rollback operations from journal
-    let rollbacks = journal.ref_updates_for_rollback();
+/// Check that `journal`, once undone, can be applied again.
+///
+/// Every ref must still hold the value from before the operation. Returns
+/// a line per mismatch, empty when safe.
+fn check_reappliable(
+    journal: &Journal,
+    current: impl Fn(&str) -> CliResult<Option<String>>,
+) -> CliResult<Vec<String>> {
+    let mut simulated: HashMap<String, Option<String>> = HashMap::new();
+    let mut conflicts = Vec::new();
 
-    if rollbacks.is_empty() {
+    for change in ref_changes(journal).into_iter().rev() {
+        let value = match simulated.get(&change.refname) {
+            Some(value) => value.clone(),
+            None => current(&change.refname)?,
+        };
+        if value != change.old {
+            conflicts.push(format!(
+                "{} (now {})",
+                change.refname,
+                value.as_deref().map_or("missing", |v| &v[..7.min(v.len())])
+            ));
+        }
+        simulated.insert(change.refname, change.new);
+    }
+    Ok(conflicts)
+}
+
+/// Undo each journal in `chain`, newest first.
+fn apply(ctx: &Context, git: &Git, chain: &[&Journal]) -> CliResult {
+    let ledger = EventLedger::new(git);
+    let mut total_restored = 0;
+
+    for journal in chain {
         if !ctx.quiet {
-            println!("No ref changes to undo.");
+            println!(
+                "{}",
+                i18n::t_args(
+                    "undo-undoing",
+                    &[("command", &journal.command), ("op_id", &journal.op_id)],
+                )
+            );
+        }
+
+        // Warn about remote operations that cannot be undone (Phase 7)
+        if journal.has_remote_operations() {
+            let remote_ops = journal.remote_operation_descriptions();
+            if !remote_ops.is_empty() {
+                eprintln!();
+                eprintln!("{}", i18n::t("undo-warning-operation-included"));
+                for desc in &remote_ops {
+                    eprintln!("  - {}", desc);
+                }
+                eprintln!();
+                eprintln!("{}", i18n::t("undo-local-refs-restored"));
+                eprintln!("{}", i18n::t("undo-you-may-need"));
+                eprintln!();
+            }
+        }
+
+        let changes = ref_changes(journal);
+        if changes.is_empty() && !ctx.quiet {
+            println!("{}", i18n::t("undo-no-ref-changes"));
+        }
+
+        if ctx.debug {
+            eprintln!("[debug] undo: {} ref changes to restore", changes.len());
+        }
+
+        let refs_restored = restore(ctx, git, &changes)?;
+        total_restored += refs_restored;
+
+        // Record UndoApplied event in ledger (Phase 7), even with nothing
+        // restored, so the next undo moves on to an older operation
+        let event = Event::undo_applied(journal.op_id.as_str(), refs_restored);
+        if let Err(e) = ledger.append(event) {
+            if ctx.debug {
+                eprintln!(
+                    "[debug] Warning: Could not record undo event in ledger: {}",
+                    e
+                );
+            }
+            // Don't fail the undo just because ledger append failed
         }
-        return Ok(());
     }
 
-    if ctx.debug {
-        eprintln!("[debug] undo: {} ref changes to restore", rollbacks.len());
+    if !ctx.quiet && total_restored > 0 {
+        println!(
+            "{}",
+            i18n::t_args("undo-complete", &[("total_restored", &total_restored)])
+        );
     }
 
-    // Track successful restorations for ledger event
-    let mut refs_restored: usize = 0;
+    Ok(())
+}
 
-    // Apply rollbacks using Git interface (Phase 7 improvement)
-    for step in &rollbacks {
-        match step {
-            StepKind::RefUpdate {
-                refname,
-                old_oid,
-                new_oid: _,
-            } => {
-                if let Some(old) = old_oid {
-                    // Restore old value using Git interface
-                    let old_oid_obj = Oid::new(old).context("Invalid old OID in journal")?;
-
-                    // For undo, we use force update since the repo may have changed
-                    // and we want to restore to the known good state unconditionally.
-                    git.update_ref_force(
-                        refname,
-                        &old_oid_obj,
-                        &format!("undo: restore {}", refname),
-                    )
-                    .with_context(|| format!("Failed to restore ref {}", refname))?;
-
-                    if ctx.debug {
-                        eprintln!("[debug] Restored {} to {}", refname, old);
-                    }
-                    refs_restored += 1;
-                } else {
-                    // Delete ref (was created by the operation)
-                    // Use force delete since CAS may fail if ref changed
-                    git.delete_ref_force(refname)
-                        .with_context(|| format!("Failed to delete ref {}", refname))?;
-
-                    if ctx.debug {
-                        eprintln!("[debug] Deleted {}", refname);
-                    }
-                    refs_restored += 1;
+/// Put every ref in `changes` back to its old value.
+///
+/// For undo, we use force updates since the repo may have changed and we
+/// want to restore to the known good state unconditionally.
+fn restore(ctx: &Context, git: &Git, changes: &[RefChange]) -> CliResult<usize> {
+    for change in changes {
+        match &change.old {
+            Some(old) => {
+                let old_oid = Oid::new(old).context("Invalid old OID in journal")?;
+                if change.refname.starts_with("refs/branch-metadata/") {
+                    MetadataStore::new(git)
+                        .ensure_blob(&old_oid, change.old_content.as_deref())
+                        .with_context(|| format!("Failed to restore ref {}", change.refname))?;
+                }
+                git.update_ref_force(
+                    &change.refname,
+                    &old_oid,
+                    &format!("undo: restore {}", change.refname),
+                )
+                .with_context(|| format!("Failed to restore ref {}", change.refname))?;
+
+                if ctx.debug {
+                    eprintln!("[debug] Restored {} to {}", change.refname, old);
                 }
             }
-            StepKind::MetadataWrite {
-                branch,
-                old_ref_oid,
-                new_ref_oid: _,
-            } => {
-                // For metadata, restore the old ref or delete if it was created
-                let refname = format!("refs/branch-metadata/{}", branch);
-                if let Some(old) = old_ref_oid {
-                    let old_oid_obj = Oid::new(old).context("Invalid old metadata OID")?;
-
-                    git.update_ref_force(
-                        &refname,
-                        &old_oid_obj,
-                        &format!("undo: restore metadata for {}", branch),
-                    )
-                    .with_context(|| format!("Failed to restore metadata ref {}", refname))?;
-
-                    if ctx.debug {
-                        eprintln!("[debug] Restored metadata for {}", branch);
-                    }
-                    refs_restored += 1;
-                } else {
-                    // Delete metadata ref (was created by the operation)
-                    git.delete_ref_force(&refname)
-                        .with_context(|| format!("Failed to delete metadata ref {}", refname))?;
-
-                    if ctx.debug {
-                        eprintln!("[debug] Deleted metadata for {}", branch);
-                    }
-                    refs_restored += 1;
+            None => {
+                // Created by the operation
+                git.delete_ref_force(&change.refname)
+                    .with_context(|| format!("Failed to delete ref {}", change.refname))?;
+
+                if ctx.debug {
+                    eprintln!("[debug] Deleted {}", change.refname);
                 }
             }
-            StepKind::MetadataDelete {
-                branch,
-                old_ref_oid,
-            } => {
-                // Restore the deleted metadata ref
-                let refname = format!("refs/branch-metadata/{}", branch);
-                let old_oid_obj = Oid::new(old_ref_oid).context("Invalid old metadata OID")?;
+        }
+    }
+    Ok(changes.len())
+}
 
+/// Set every ref in `changes` (newest first) back to its new value, oldest
+/// change first.
+fn reapply(ctx: &Context, git: &Git, changes: &[RefChange]) -> CliResult<usize> {
+    for change in changes.iter().rev() {
+        match &change.new {
+            Some(new) => {
+                let new_oid = Oid::new(new).context("Invalid new OID in journal")?;
                 git.update_ref_force(
-                    &refname,
-                    &old_oid_obj,
-                    &format!("undo: restore deleted metadata for {}", branch),
+                    &change.refname,
+                    &new_oid,
+                    &format!("redo: reapply {}", change.refname),
                 )
-                .with_context(|| format!("Failed to restore metadata ref {}", refname))?;
+                .with_context(|| format!("Failed to update ref {}", change.refname))?;
 
                 if ctx.debug {
-                    eprintln!("[debug] Restored deleted metadata for {}", branch);
+                    eprintln!("[debug] Set {} to {}", change.refname, new);
                 }
-                refs_restored += 1;
             }
-            _ => {
-                // Skip other step kinds (checkpoints, conflict paused, etc.)
+            None => {
+                git.delete_ref_force(&change.refname)
+                    .with_context(|| format!("Failed to delete ref {}", change.refname))?;
+
+                if ctx.debug {
+                    eprintln!("[debug] Deleted {}", change.refname);
+                }
             }
         }
     }
+    Ok(changes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ops::journal::{JournalStep, OpId};
+    use crate::core::types::UtcTimestamp;
+
+    fn journal(id: &str, steps: Vec<StepKind>) -> Journal {
+        let mut journal = Journal::new("restack");
+        journal.op_id = OpId::from_string(id);
+        journal.phase = OpPhase::Committed;
+        journal.steps = steps
+            .into_iter()
+            .map(|kind| JournalStep {
+                kind,
+                timestamp: UtcTimestamp::now(),
+            })
+            .collect();
+        journal
+    }
 
-    // Record UndoApplied event in ledger (Phase 7)
-    let ledger = EventLedger::new(&git);
-    let event = Event::undo_applied(journal.op_id.as_str(), refs_restored);
-    if let Err(e) = ledger.append(event) {
-        if ctx.debug {
-            eprintln!(
-                "[debug] Warning: Could not record undo event in ledger: {}",
-                e
-            );
+    fn moved(refname: &str, old: Option<&str>, new: &str) -> StepKind {
+        StepKind::RefUpdate {
+            refname: refname.to_string(),
+            old_oid: old.map(str::to_string),
+            new_oid: new.to_string(),
         }
-        // Don't fail the undo just because ledger append failed
     }
 
-    if !ctx.quiet {
-        println!("Undo complete. {} ref(s) restored.", refs_restored);
+    #[test]
+    fn find_operation_matches_unique_prefixes() {
+        let journals = vec![journal("abc123", vec![]), journal("abd456", vec![])];
+        assert_eq!(
+            find_operation(&journals, "abd").unwrap().op_id.as_str(),
+            "abd456"
+        );
+        assert!(find_operation(&journals, "ab").is_err());
+        assert!(find_operation(&journals, "xyz").is_err());
     }
 
-    Ok(())
+    #[test]
+    fn chain_is_reversible_when_refs_match() {
+        // older: feature a -> b; newer: feature b -> c, other created
+        let older = journal("older", vec![moved("refs/heads/feature", Some("a"), "b")]);
+        let newer = journal(
+            "newer",
+            vec![
+                moved("refs/heads/feature", Some("b"), "c"),
+                moved("refs/heads/other", None, "d"),
+            ],
+        );
+        let current: HashMap<&str, &str> = [("refs/heads/feature", "c")].into();
+
+        let conflicts = check_reversible(&[&newer, &older], |r| {
+            Ok(current.get(r).map(|v| v.to_string()))
+        })
+        .unwrap();
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn chain_with_outside_changes_is_refused() {
+        // feature was committed to by hand between the two operations
+        let older = journal("older", vec![moved("refs/heads/feature", Some("a"), "b")]);
+        let newer = journal("newer", vec![moved("refs/heads/other", Some("x"), "y")]);
+        let current: HashMap<&str, &str> =
+            [("refs/heads/feature", "manual"), ("refs/heads/other", "z")].into();
+
+        let conflicts = check_reversible(&[&newer, &older], |r| {
+            Ok(current.get(r).map(|v| v.to_string()))
+        })
+        .unwrap();
+        // The newest operation is never checked, like single-step undo
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].starts_with("refs/heads/feature"));
+    }
+
+    #[test]
+    fn redo_requires_the_state_undo_left() {
+        // feature a -> b -> c, then other deleted
+        let op = journal(
+            "op",
+            vec![
+                moved("refs/heads/feature", Some("a"), "b"),
+                moved("refs/heads/feature", Some("b"), "c"),
+                moved("refs/heads/other", Some("x"), ""),
+            ],
+        );
+        let undone: HashMap<&str, &str> =
+            [("refs/heads/feature", "a"), ("refs/heads/other", "x")].into();
+        let conflicts =
+            check_reappliable(&op, |r| Ok(undone.get(r).map(|v| v.to_string()))).unwrap();
+        assert!(conflicts.is_empty());
+
+        let moved_since: HashMap<&str, &str> = [("refs/heads/feature", "later")].into();
+        let conflicts =
+            check_reappliable(&op, |r| Ok(moved_since.get(r).map(|v| v.to_string()))).unwrap();
+        assert_eq!(
+            conflicts,
+            vec![
+                "refs/heads/feature (now later)",
+                "refs/heads/other (now missing)"
+            ]
+        );
+    }
 }
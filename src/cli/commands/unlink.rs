@@ -24,13 +24,15 @@
 //! lattice unlink feature-branch
 //! ```
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::schema::PrState;
 use crate::core::metadata::store::MetadataStore;
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::Result;
+use crate::ui::i18n;
 
 /// Run the unlink command.
 ///
@@ -42,7 +44,7 @@ use anyhow::Result;
 /// # Gating
 ///
 /// Uses `requirements::MUTATING_METADATA_ONLY`.
-pub fn unlink(ctx: &Context, branch: Option<&str>) -> Result<()> {
+pub fn unlink(ctx: &Context, branch: Option<&str>) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -54,12 +56,7 @@ pub fn unlink(ctx: &Context, branch: Option<&str>) -> Result<()> {
 
         // Resolve target branch
         let target = if let Some(b) = branch {
-            crate::core::types::BranchName::new(b).map_err(|e| {
-                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                    "Invalid branch name: {}",
-                    e
-                )))
-            })?
+            crate::core::types::BranchName::new(b).map_err(PlanError::from)?
         } else {
             snapshot.current_branch.clone().ok_or_else(|| {
                 RunError::Scan(crate::engine::scan::ScanError::Internal(
@@ -69,17 +66,15 @@ pub fn unlink(ctx: &Context, branch: Option<&str>) -> Result<()> {
         };
 
         // Get metadata for the branch
-        let scanned = snapshot.metadata.get(&target).ok_or_else(|| {
-            RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                "Branch '{}' is not tracked by Lattice.",
-                target
-            )))
-        })?;
+        let scanned = snapshot
+            .metadata
+            .get(&target)
+            .ok_or_else(|| RunError::Plan(PlanError::UntrackedBranch(target.to_string())))?;
 
         // Check if already unlinked
         if matches!(scanned.metadata.pr, PrState::None) {
             if !ctx.quiet {
-                println!("Branch '{}' has no PR linkage.", target);
+                println!("{}", i18n::t_args("unlink-no-pr", &[("target", &target)]));
             }
             return Ok(());
         }
@@ -101,17 +96,15 @@ pub fn unlink(ctx: &Context, branch: Option<&str>) -> Result<()> {
             })?;
 
         if !ctx.quiet {
-            println!("Unlinked PR from branch '{}'.", target);
+            println!(
+                "{}",
+                i18n::t_args("unlink-unlinked-pr-branch", &[("target", &target)])
+            );
         }
 
         Ok(())
     })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    .map_err(CliError::from)
 }
 
 #[cfg(test)]
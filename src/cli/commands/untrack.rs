@@ -5,15 +5,18 @@
 //! Uses `requirements::MUTATING_METADATA_ONLY` - this command only modifies
 //! metadata refs and does not require a working directory.
 
+use crate::cli::error::{CliError, CliResult};
 use crate::core::metadata::store::MetadataStore;
 use crate::core::types::BranchName;
 use crate::engine::gate::requirements;
+use crate::engine::plan::PlanError;
 use crate::engine::runner::{run_gated, RunError};
 use crate::engine::scan::RepoSnapshot;
 use crate::engine::Context;
 use crate::git::Git;
-use anyhow::{Context as _, Result};
-use std::io::{self, Write};
+use crate::ui::i18n;
+use crate::ui::prompts::{self, PromptError, Severity};
+use anyhow::Context as _;
 
 /// Stop tracking a branch.
 ///
@@ -26,7 +29,7 @@ use std::io::{self, Write};
 /// # Gating
 ///
 /// Uses `requirements::MUTATING_METADATA_ONLY`.
-pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
+pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> CliResult {
     let cwd = ctx
         .cwd
         .clone()
@@ -38,12 +41,7 @@ pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
 
         // Resolve target branch
         let target = if let Some(name) = branch {
-            BranchName::new(name).map_err(|e| {
-                RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                    "Invalid branch name: {}",
-                    e
-                )))
-            })?
+            BranchName::new(name).map_err(PlanError::from)?
         } else if let Some(ref current) = snapshot.current_branch {
             current.clone()
         } else {
@@ -55,7 +53,10 @@ pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
         // Check if tracked
         if !snapshot.metadata.contains_key(&target) {
             if !ctx.quiet {
-                println!("Branch '{}' is not tracked", target);
+                println!(
+                    "{}",
+                    i18n::t_args("untrack-not-tracked", &[("target", &target)])
+                );
             }
             return Ok(());
         }
@@ -66,40 +67,40 @@ pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
         if !descendants.is_empty() && !force {
             if ctx.interactive {
                 println!(
-                    "Branch '{}' has {} descendant(s) that will also be untracked:",
-                    target,
-                    descendants.len()
+                    "{}",
+                    i18n::t_args(
+                        "untrack-branch-descendant-also",
+                        &[("target", &target), ("count", &descendants.len())],
+                    )
                 );
                 for d in &descendants {
                     println!("  - {}", d);
                 }
-                print!("Continue? [y/N] ");
-                io::stdout().flush().map_err(|e| {
-                    RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                        "Failed to flush stdout: {}",
-                        e
-                    )))
-                })?;
-
-                let mut input = String::new();
-                io::stdin().read_line(&mut input).map_err(|e| {
-                    RunError::Scan(crate::engine::scan::ScanError::Internal(format!(
-                        "Failed to read input: {}",
-                        e
-                    )))
-                })?;
-                if !input.trim().eq_ignore_ascii_case("y") {
-                    println!("Aborted.");
+            }
+            match prompts::confirm(
+                &i18n::t("prompt-continue"),
+                Severity::Destructive,
+                ctx.interactive,
+            ) {
+                Ok(true) => {}
+                Ok(false) => {
+                    println!("{}", i18n::t("prompt-aborted"));
                     return Ok(());
                 }
-            } else {
-                return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
-                    format!(
-                        "Branch '{}' has {} descendant(s). Use --force to untrack all.",
-                        target,
-                        descendants.len()
-                    ),
-                )));
+                Err(PromptError::NotInteractive) => {
+                    return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
+                        format!(
+                            "Branch '{}' has {} descendant(s). Use --force to untrack all.",
+                            target,
+                            descendants.len()
+                        ),
+                    )));
+                }
+                Err(e) => {
+                    return Err(RunError::Scan(crate::engine::scan::ScanError::Internal(
+                        format!("Failed to read input: {}", e),
+                    )));
+                }
             }
         }
 
@@ -119,13 +120,22 @@ pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
                         )))
                     })?;
                     if !ctx.quiet {
-                        println!("Untracked '{}'", branch);
+                        println!(
+                            "{}",
+                            i18n::t_args("untrack-untracked", &[("branch", &branch)])
+                        );
                     }
                 }
                 Ok(None) => {
                     // Already deleted
                     if !ctx.quiet {
-                        println!("Branch '{}' was already untracked", branch);
+                        println!(
+                            "{}",
+                            i18n::t_args(
+                                "untrack-branch-already-untracked",
+                                &[("branch", &branch)],
+                            )
+                        );
                     }
                 }
                 Err(e) => {
@@ -138,12 +148,7 @@ pub fn untrack(ctx: &Context, branch: Option<&str>, force: bool) -> Result<()> {
 
         Ok(())
     })
-    .map_err(|e| match e {
-        RunError::NeedsRepair(bundle) => {
-            anyhow::anyhow!("Repository needs repair: {}", bundle)
-        }
-        other => anyhow::anyhow!("{}", other),
-    })
+    .map_err(CliError::from)
 }
 
 /// Get all descendants of a branch (recursive).
@@ -0,0 +1,145 @@
+//! cli::commands::workspace
+//!
+//! Run `sync` and `log` across the repositories of a workspace
+//! (`lattice ws sync`, `lattice ws log`).
+//!
+//! # Design
+//!
+//! A workspace file ([`crate::core::config::workspace`]) lists related
+//! repositories whose stacks are maintained together. Each `ws` command
+//! runs the single-repository command in every member, in the order listed,
+//! as if lattice had been started there with `--cwd`: the same gating,
+//! prompts, and output, under a heading naming the member.
+//!
+//! A member that fails doesn't stop the others. Its error is reported as it
+//! happens, and the command ends with a summary and fails if any member did.
+//!
+//! Output is one section per member, so `--json` isn't supported; run
+//! `lattice log --json --cwd <repo>` per member instead.
+//!
+//! # Example
+//!
+//! ```bash
+//! # Start of day across every repository in the workspace
+//! lattice ws sync --restack
+//!
+//! # See each repository's stacks
+//! lattice ws log
+//!
+//! # Use a workspace file somewhere else
+//! lattice ws --file ~/work/platform.toml log
+//! ```
+
+use std::path::Path;
+
+use crate::cli::error::{CliError, CliResult};
+use crate::core::config::workspace::Workspace;
+use crate::engine::Context;
+use crate::ui::i18n;
+
+/// `lattice ws sync`: sync every member repository.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `file` - Workspace file, instead of searching for one
+/// * `force`, `restack`, `dry_run`, `prune` - As for `lattice sync`
+pub fn sync(
+    ctx: &Context,
+    file: Option<&Path>,
+    force: bool,
+    restack: bool,
+    dry_run: bool,
+    prune: bool,
+) -> CliResult {
+    let workspace = load(ctx, file)?;
+    for_each_member(ctx, &workspace, Some("workspace-synced"), |member_ctx| {
+        super::sync(member_ctx, force, restack, dry_run, prune)
+    })
+}
+
+/// `lattice ws log`: show every member repository's stacks.
+///
+/// # Arguments
+///
+/// * `ctx` - Execution context
+/// * `file` - Workspace file, instead of searching for one
+/// * `short`, `long`, `stack`, `all`, `reverse` - As for `lattice log`
+/// * `json` - Rejected; output is one section per member
+#[allow(clippy::too_many_arguments)]
+pub fn log(
+    ctx: &Context,
+    file: Option<&Path>,
+    short: bool,
+    long: bool,
+    stack: bool,
+    all: bool,
+    reverse: bool,
+    json: bool,
+) -> CliResult {
+    if json {
+        return Err(CliError::usage(
+            "'lattice ws log' doesn't support --json; run 'lattice log --json' in each repository",
+        ));
+    }
+    let workspace = load(ctx, file)?;
+    for_each_member(ctx, &workspace, None, |member_ctx| {
+        super::log(
+            member_ctx, short, long, stack, all, reverse, None, false, None, false,
+        )
+    })
+}
+
+/// Find and load the workspace for the current directory.
+fn load(ctx: &Context, file: Option<&Path>) -> CliResult<Workspace> {
+    let cwd = ctx
+        .cwd
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let path = Workspace::locate(file, &cwd)?;
+    Ok(Workspace::load(&path)?)
+}
+
+/// Run `run` in each member; fails if any member failed.
+///
+/// When all succeed, `done` names the catalog message for a closing
+/// summary line; it is given the member count as `total`.
+fn for_each_member(
+    ctx: &Context,
+    workspace: &Workspace,
+    done: Option<&str>,
+    run: impl Fn(&Context) -> CliResult,
+) -> CliResult {
+    let mut failed = Vec::new();
+    for (i, member) in workspace.members.iter().enumerate() {
+        if !ctx.quiet {
+            if i > 0 {
+                println!();
+            }
+            println!("== {} ({}) ==", member.name, member.path.display());
+        }
+        let member_ctx = Context {
+            cwd: Some(member.path.clone()),
+            ..ctx.clone()
+        };
+        if let Err(e) = run(&member_ctx) {
+            eprintln!("error: {}: {:#}", member.name, e);
+            failed.push(member.name.as_str());
+        }
+    }
+
+    let total = workspace.members.len();
+    if !failed.is_empty() {
+        return Err(CliError::failed(format!(
+            "{} of {} repositories failed: {}",
+            failed.len(),
+            total,
+            failed.join(", ")
+        )));
+    }
+    if let (Some(done), false) = (done, ctx.quiet) {
+        println!();
+        println!("{}", i18n::t_args(done, &[("total", &total)]));
+    }
+    Ok(())
+}
@@ -0,0 +1,602 @@
+//! cli::error
+//!
+//! Typed errors at the CLI boundary.
+//!
+//! # Architecture
+//!
+//! Command handlers return [`CliResult`]. A handler that knows exactly what
+//! went wrong builds the [`CliError`] where it fails (see
+//! [`CliError::usage`], [`CliError::conflict`], [`CliError::not_initialized`]
+//! and [`CliError::untracked`]), so the category and hint are decided there.
+//! Errors propagated with `?` convert: a [`RunError`] directly, the engine's
+//! typed errors (`ExecuteError`, `PlanError`, `GitError`, `ForgeError`,
+//! `AuthError`, `io::Error`, ...) by their type, and an `anyhow::Error` by
+//! the typed errors in its cause chain, recovering a `CliError` built
+//! further down by downcasting. An error with no typed cause is
+//! [`ErrorCategory::Failed`]. The message text is never inspected. `cli::run`
+//! renders the error through [`crate::ui::output::error_report`], or as JSON
+//! with `--json`, and hints come from the [`i18n`] catalog.
+//!
+//! # Exit Codes
+//!
+//! Per SPEC.md §6.3: internal errors exit with `2`, errors caused by an
+//! in-progress Lattice operation exit with `3`, everything else with `1`.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::auth::AuthError;
+use crate::core::branch_lock::BranchLockError;
+use crate::core::checkpoint::CheckpointError;
+use crate::core::config::ConfigError;
+use crate::core::metadata::store::StoreError;
+use crate::core::ops::journal::JournalError;
+use crate::core::types::TypeError;
+use crate::doctor::{diagnose_from_gate_bundle, DoctorError};
+use crate::engine::exec::ExecuteError;
+use crate::engine::gate::RepairBundle;
+use crate::engine::plan::PlanError;
+use crate::engine::runner::RunError;
+use crate::engine::scan::ScanError;
+use crate::engine::Context;
+use crate::forge::ForgeError;
+use crate::git::{Git, GitError};
+use crate::secrets::SecretError;
+use crate::ui::i18n;
+use crate::ui::prompts::PromptError;
+
+/// Result type for the CLI boundary.
+pub type CliResult<T = ()> = Result<T, CliError>;
+
+/// Broad classification of a CLI failure.
+///
+/// The serialized names are stable for tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Invalid arguments or an unusable target (e.g. untracked branch).
+    Usage,
+    /// Lattice has not been initialized in this repository.
+    NotInitialized,
+    /// Gating found issues that `lattice doctor` must repair.
+    NeedsRepair,
+    /// A Lattice or Git operation is in progress.
+    OperationInProgress,
+    /// The repository changed underneath the command.
+    Conflict,
+    /// A Git or metadata operation failed.
+    Git,
+    /// The remote forge rejected the request.
+    Forge,
+    /// Authentication is missing or invalid.
+    Auth,
+    /// The network is unreachable.
+    Network,
+    /// A filesystem operation failed.
+    Io,
+    /// The command failed for a reason it reported.
+    Failed,
+    /// A Lattice bug or broken invariant.
+    Internal,
+}
+
+impl ErrorCategory {
+    /// Stable name, as serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Usage => "usage",
+            ErrorCategory::NotInitialized => "not_initialized",
+            ErrorCategory::NeedsRepair => "needs_repair",
+            ErrorCategory::OperationInProgress => "operation_in_progress",
+            ErrorCategory::Conflict => "conflict",
+            ErrorCategory::Git => "git",
+            ErrorCategory::Forge => "forge",
+            ErrorCategory::Auth => "auth",
+            ErrorCategory::Network => "network",
+            ErrorCategory::Io => "io",
+            ErrorCategory::Failed => "failed",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+
+    /// Process exit code for this category (SPEC.md §6.3).
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            ErrorCategory::Internal => 2,
+            ErrorCategory::OperationInProgress => 3,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An error returned from a CLI command.
+#[derive(Debug, Serialize)]
+pub struct CliError {
+    /// Broad classification.
+    pub category: ErrorCategory,
+    /// Human-readable message, including its causes.
+    pub message: String,
+    /// Suggested next step, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// Blocking issues behind a `needs_repair` error.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<String>,
+    /// `lattice doctor --fix` IDs that resolve the blocking issues.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fix_ids: Vec<String>,
+    /// Gating bundle, kept to compute fix IDs.
+    #[serde(skip)]
+    bundle: Option<Box<RepairBundle>>,
+}
+
+impl CliError {
+    /// Create an error with a category and message.
+    pub fn new(category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            message: message.into(),
+            hint: None,
+            issues: Vec::new(),
+            fix_ids: Vec::new(),
+            bundle: None,
+        }
+    }
+
+    /// Attach a remediation hint.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Error for invalid arguments or a target the command can't act on.
+    pub fn usage(message: impl Into<String>) -> Self {
+        CliError::new(ErrorCategory::Usage, message)
+    }
+
+    /// Error for repository state that rules the command out right now,
+    /// such as an edit session already in progress or a dirty worktree.
+    pub fn conflict(message: impl Into<String>) -> Self {
+        CliError::new(ErrorCategory::Conflict, message)
+    }
+
+    /// Error for a git command or ref update that failed.
+    pub fn git(message: impl Into<String>) -> Self {
+        CliError::new(ErrorCategory::Git, message)
+    }
+
+    /// Error for a command that failed for a reason it reports.
+    pub fn failed(message: impl Into<String>) -> Self {
+        CliError::new(ErrorCategory::Failed, message)
+    }
+
+    /// Error for a repository whose trunk is not configured.
+    pub fn not_initialized() -> Self {
+        CliError::new(ErrorCategory::NotInitialized, "Trunk not configured")
+            .with_hint(i18n::t("hint-not-initialized"))
+    }
+
+    /// Error for a branch Lattice does not track.
+    pub fn untracked(branch: impl fmt::Display) -> Self {
+        CliError::new(
+            ErrorCategory::Usage,
+            format!("Branch '{}' is not tracked", branch),
+        )
+        .with_hint(i18n::t("hint-untracked"))
+    }
+
+    /// Error for a command refused by gating.
+    ///
+    /// An in-progress Lattice operation is reported as
+    /// [`ErrorCategory::OperationInProgress`] so it gets its own exit code.
+    pub fn needs_repair(bundle: RepairBundle) -> Self {
+        let has_issue = |prefix: &str| {
+            bundle
+                .blocking_issues
+                .iter()
+                .any(|i| i.id.as_str().starts_with(prefix))
+        };
+
+        let (category, hint) = if has_issue("lattice-op-in-progress") {
+            (
+                ErrorCategory::OperationInProgress,
+                "hint-lattice-op-in-progress",
+            )
+        } else if has_issue("trunk-not-configured") {
+            (ErrorCategory::NotInitialized, "hint-not-initialized")
+        } else if has_issue("repo-identity-mismatch") {
+            (ErrorCategory::NeedsRepair, "hint-repo-identity-mismatch")
+        } else {
+            (ErrorCategory::NeedsRepair, "hint-needs-repair")
+        };
+
+        let mut err = CliError::new(
+            category,
+            format!("Repository needs repair: {}", bundle.summary()),
+        )
+        .with_hint(i18n::t(hint));
+        err.issues = bundle
+            .blocking_issues
+            .iter()
+            .map(|i| i.message.clone())
+            .collect();
+        err.bundle = Some(Box::new(bundle));
+        err
+    }
+
+    /// Fill in doctor fix IDs for a gating failure.
+    ///
+    /// Rescans the repository so fixes are generated against current state.
+    /// Does nothing if the error did not come from gating or the rescan
+    /// fails.
+    pub fn attach_doctor_fixes(&mut self, ctx: &Context) {
+        let Some(bundle) = &self.bundle else {
+            return;
+        };
+        let cwd = ctx
+            .cwd
+            .clone()
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+        let Ok(git) = Git::open(&cwd) else {
+            return;
+        };
+        let Ok(snapshot) = crate::engine::scan::scan(&git) else {
+            return;
+        };
+
+        let report = diagnose_from_gate_bundle(bundle, &snapshot);
+        self.fix_ids = report.fixes.iter().map(|f| f.id.to_string()).collect();
+    }
+
+    /// Process exit code for this error.
+    pub fn exit_code(&self) -> u8 {
+        self.category.exit_code()
+    }
+
+    /// JSON document emitted in `--json` mode.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "error": self })
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<anyhow::Error> for CliError {
+    fn from(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<CliError>() {
+            Ok(cli) => return cli,
+            Err(err) => err,
+        };
+        let err = match err.downcast::<RunError>() {
+            Ok(run) => return CliError::from(run),
+            Err(err) => err,
+        };
+
+        let classification = err
+            .chain()
+            .find_map(classify_source)
+            .unwrap_or((ErrorCategory::Failed, None));
+        CliError::classified(format!("{:#}", err), classification)
+    }
+}
+
+impl From<RunError> for CliError {
+    fn from(err: RunError) -> Self {
+        match err {
+            RunError::NeedsRepair(bundle) => CliError::needs_repair(bundle),
+            other => {
+                let classification = classify_run(&other);
+                CliError::classified(other.to_string(), classification)
+            }
+        }
+    }
+}
+
+/// Typed errors handlers propagate with `?`, classified as they would be in
+/// an `anyhow` cause chain.
+macro_rules! from_typed {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for CliError {
+                fn from(err: $ty) -> Self {
+                    anyhow::Error::from(err).into()
+                }
+            }
+        )*
+    };
+}
+
+from_typed!(
+    ExecuteError,
+    PlanError,
+    ScanError,
+    GitError,
+    ForgeError,
+    AuthError,
+    BranchLockError,
+    TypeError,
+    CheckpointError,
+    ConfigError,
+    DoctorError,
+    JournalError,
+    PromptError,
+    SecretError,
+    StoreError,
+    serde_json::Error,
+    std::io::Error,
+);
+
+impl CliError {
+    fn classified(message: String, (category, hint): Classification) -> Self {
+        let mut cli = CliError::new(category, message);
+        // Handlers usually spell out the next step already; don't repeat it.
+        if let Some(hint) = hint {
+            if !cli.message.contains("'lattice ") {
+                cli.hint = Some(i18n::t(hint));
+            }
+        }
+        cli
+    }
+}
+
+/// Category and the catalog key of its hint.
+type Classification = (ErrorCategory, Option<&'static str>);
+
+/// Classify a typed error from a cause chain.
+fn classify_source(source: &(dyn std::error::Error + 'static)) -> Option<Classification> {
+    if let Some(e) = source.downcast_ref::<RunError>() {
+        return Some(classify_run(e));
+    }
+    if let Some(e) = source.downcast_ref::<ExecuteError>() {
+        return Some(classify_execute(e));
+    }
+    if let Some(e) = source.downcast_ref::<PlanError>() {
+        return Some(classify_plan(e));
+    }
+    if let Some(e) = source.downcast_ref::<GitError>() {
+        return Some(classify_git(e));
+    }
+    if let Some(e) = source.downcast_ref::<ForgeError>() {
+        return Some(classify_forge(e));
+    }
+    if let Some(e) = source.downcast_ref::<AuthError>() {
+        return Some(classify_auth(e));
+    }
+    if let Some(BranchLockError::Held { .. }) = source.downcast_ref::<BranchLockError>() {
+        return Some((ErrorCategory::Conflict, Some("hint-branch-locked")));
+    }
+    if source.downcast_ref::<TypeError>().is_some() {
+        return Some((ErrorCategory::Usage, None));
+    }
+    if source.downcast_ref::<std::io::Error>().is_some() {
+        return Some((ErrorCategory::Io, None));
+    }
+    None
+}
+
+fn classify_run(e: &RunError) -> Classification {
+    match e {
+        RunError::NeedsRepair(_) => (ErrorCategory::NeedsRepair, Some("hint-needs-repair")),
+        RunError::Scan(ScanError::RepoOpen(git)) => classify_git(git),
+        RunError::Scan(ScanError::Internal(_)) => (ErrorCategory::Failed, None),
+        RunError::Scan(_) => (ErrorCategory::Git, None),
+        RunError::Plan(plan) => classify_plan(plan),
+        RunError::Execute(exec) => classify_execute(exec),
+        RunError::Verify(_) => (ErrorCategory::Internal, Some("hint-internal")),
+    }
+}
+
+fn classify_execute(e: &ExecuteError) -> Classification {
+    match e {
+        ExecuteError::LockFailed(_) => (ErrorCategory::Conflict, Some("hint-lock-failed")),
+        ExecuteError::CasFailed { .. } => (ErrorCategory::Conflict, Some("hint-repo-changed")),
+        ExecuteError::Git(git) => classify_git(git),
+        ExecuteError::OperationInProgress { .. } | ExecuteError::Interrupted { .. } => (
+            ErrorCategory::OperationInProgress,
+            Some("hint-lattice-op-in-progress"),
+        ),
+        ExecuteError::OccupancyViolation { .. } => {
+            (ErrorCategory::Conflict, Some("hint-worktree-occupied"))
+        }
+        ExecuteError::BranchLocked { .. } => (ErrorCategory::Conflict, Some("hint-branch-locked")),
+        ExecuteError::WorktreeDirty { .. } => (
+            ErrorCategory::Conflict,
+            Some("hint-worktree-occupied-dirty"),
+        ),
+        ExecuteError::InvalidPlan(_)
+        | ExecuteError::Internal(_)
+        | ExecuteError::VerificationFailed { .. } => {
+            (ErrorCategory::Internal, Some("hint-internal"))
+        }
+        _ => (ErrorCategory::Git, None),
+    }
+}
+
+fn classify_plan(e: &PlanError) -> Classification {
+    match e {
+        PlanError::TrunkNotConfigured => {
+            (ErrorCategory::NotInitialized, Some("hint-not-initialized"))
+        }
+        PlanError::UntrackedBranch(_) => (ErrorCategory::Usage, Some("hint-untracked")),
+        PlanError::InvalidName(_) => (ErrorCategory::Usage, None),
+        _ => (ErrorCategory::Failed, None),
+    }
+}
+
+fn classify_git(e: &GitError) -> Classification {
+    match e {
+        GitError::NotARepo { .. } => (ErrorCategory::Usage, Some("hint-not-a-repo")),
+        GitError::CasFailed { .. } => (ErrorCategory::Conflict, Some("hint-repo-changed")),
+        GitError::OperationInProgress { .. } => (
+            ErrorCategory::OperationInProgress,
+            Some("hint-git-op-in-progress"),
+        ),
+        GitError::DirtyWorktree { .. } => (ErrorCategory::Conflict, Some("hint-dirty-worktree")),
+        GitError::InvalidRefName { .. } => (ErrorCategory::Usage, None),
+        _ => (ErrorCategory::Git, None),
+    }
+}
+
+fn classify_forge(e: &ForgeError) -> Classification {
+    match e {
+        ForgeError::AuthRequired | ForgeError::AuthFailed(_) => {
+            (ErrorCategory::Auth, Some("hint-auth"))
+        }
+        ForgeError::ApiError { status, .. } if *status == 401 || *status == 403 => {
+            (ErrorCategory::Auth, Some("hint-auth"))
+        }
+        ForgeError::NetworkError(_) => (ErrorCategory::Network, Some("hint-network")),
+        ForgeError::Timeout(_) => (ErrorCategory::Network, Some("hint-timeout")),
+        ForgeError::RateLimited => (ErrorCategory::Forge, Some("hint-rate-limited")),
+        _ => (ErrorCategory::Forge, None),
+    }
+}
+
+fn classify_auth(e: &AuthError) -> Classification {
+    match e {
+        AuthError::NotAuthenticated(_) | AuthError::Expired(_) => {
+            (ErrorCategory::Auth, Some("hint-auth"))
+        }
+        AuthError::Network(_) => (ErrorCategory::Network, Some("hint-network")),
+        _ => (ErrorCategory::Auth, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::health::issues;
+
+    fn bundle(issue: crate::engine::health::Issue) -> RepairBundle {
+        RepairBundle {
+            command: "restack".to_string(),
+            missing_capabilities: vec![],
+            blocking_issues: vec![issue],
+        }
+    }
+
+    #[test]
+    fn cli_error_survives_anyhow_round_trip() {
+        let original = CliError::new(ErrorCategory::Usage, "bad input").with_hint("try again");
+        let err: CliError = anyhow::Error::from(original).into();
+        assert_eq!(err.category, ErrorCategory::Usage);
+        assert_eq!(err.hint.as_deref(), Some("try again"));
+    }
+
+    #[test]
+    fn needs_repair_lists_issues() {
+        let err = CliError::needs_repair(bundle(issues::missing_branch("feature")));
+        assert_eq!(err.category, ErrorCategory::NeedsRepair);
+        assert_eq!(err.exit_code(), 1);
+        assert!(err.message.starts_with("Repository needs repair"));
+        assert_eq!(err.issues, vec!["Tracked branch 'feature' does not exist"]);
+    }
+
+    #[test]
+    fn needs_repair_for_op_in_progress_exits_3() {
+        let err = CliError::needs_repair(bundle(issues::lattice_operation_in_progress(
+            "restack", "op-1",
+        )));
+        assert_eq!(err.category, ErrorCategory::OperationInProgress);
+        assert_eq!(err.exit_code(), 3);
+    }
+
+    #[test]
+    fn interrupt_points_to_continue() {
+        let run = RunError::Execute(ExecuteError::Interrupted {
+            command: "restack".to_string(),
+            applied: 1,
+            total: 3,
+        });
+        let err: CliError = anyhow::Error::from(run).into();
+        assert_eq!(err.category, ErrorCategory::OperationInProgress);
+        assert!(err.hint.unwrap().contains("lattice continue"));
+    }
+
+    #[test]
+    fn untracked_branch_is_a_usage_error() {
+        let err = CliError::from(RunError::Plan(PlanError::UntrackedBranch(
+            "feature".to_string(),
+        )));
+        assert_eq!(err.category, ErrorCategory::Usage);
+        assert_eq!(
+            err.hint.as_deref(),
+            Some("Track it with 'lattice track <branch>'.")
+        );
+
+        let err: CliError = anyhow::Error::from(CliError::untracked("feature")).into();
+        assert_eq!(err.category, ErrorCategory::Usage);
+        assert_eq!(err.message, "Branch 'feature' is not tracked");
+    }
+
+    #[test]
+    fn invalid_branch_name_is_a_usage_error() {
+        let name = crate::core::types::BranchName::new("bad..name").unwrap_err();
+        let err: CliError = anyhow::Error::from(name)
+            .context("Invalid branch name")
+            .into();
+        assert_eq!(err.category, ErrorCategory::Usage);
+    }
+
+    #[test]
+    fn run_error_needs_repair_is_recovered() {
+        let run = RunError::NeedsRepair(bundle(issues::missing_branch("feature")));
+        let err: CliError = anyhow::Error::from(run).into();
+        assert_eq!(err.category, ErrorCategory::NeedsRepair);
+        assert_eq!(err.issues.len(), 1);
+    }
+
+    #[test]
+    fn forge_auth_error_is_classified_through_context() {
+        let err = anyhow::Error::from(ForgeError::AuthRequired).context("Failed to submit");
+        let err: CliError = err.into();
+        assert_eq!(err.category, ErrorCategory::Auth);
+        assert_eq!(err.message, "Failed to submit: authentication required");
+        assert_eq!(err.hint.as_deref(), Some("Run 'lattice auth login'."));
+    }
+
+    #[test]
+    fn message_hint_is_not_repeated() {
+        let err = anyhow::Error::from(ForgeError::AuthRequired)
+            .context("Run 'lattice auth login' to submit");
+        let err: CliError = err.into();
+        assert_eq!(err.category, ErrorCategory::Auth);
+        assert_eq!(err.hint, None);
+    }
+
+    #[test]
+    fn unclassified_error_is_failed() {
+        // The wording is never inspected, however familiar it looks
+        let err: CliError = anyhow::anyhow!("Branch 'feature' is not tracked").into();
+        assert_eq!(err.category, ErrorCategory::Failed);
+        assert_eq!(err.exit_code(), 1);
+    }
+
+    #[test]
+    fn json_shape_is_stable() {
+        let err = CliError::new(ErrorCategory::Conflict, "changed").with_hint("run again");
+        assert_eq!(
+            err.to_json(),
+            serde_json::json!({
+                "error": {
+                    "category": "conflict",
+                    "message": "changed",
+                    "hint": "run again",
+                }
+            })
+        );
+    }
+}
@@ -13,21 +13,62 @@
 //! The CLI layer is thin. It parses arguments via clap and dispatches to the
 //! [`crate::engine`] for execution. All repository state changes flow through
 //! the engine's validated execution model.
+//!
+//! Command errors cross this boundary as a typed [`CliError`] (see
+//! [`error`]), so every command reports failures the same way.
 
 pub mod args;
 pub mod commands;
+pub mod error;
 
 pub use args::{Cli, Shell};
+pub use error::{CliError, CliResult, ErrorCategory};
+
+use std::io::IsTerminal;
+use std::process::ExitCode;
 
 use crate::engine;
-use anyhow::Result;
+use crate::ui::{hints, i18n, output, prompts};
 
 /// Run the CLI application.
 ///
-/// This is the main entry point called from `main.rs`.
-pub fn run() -> Result<()> {
+/// This is the main entry point called from `main.rs`. Failures are
+/// rendered by [`output::error_report`] (as JSON with `--json`) and mapped
+/// to the exit code of their [`ErrorCategory`].
+pub fn run() -> ExitCode {
     let cli = Cli::parse_args();
 
+    // Select the message locale, confirmation policy, network timeouts,
+    // cache size, resolution reuse, read-only mode, and hints before any
+    // output.
+    // A broken config is reported by the command itself; here it only means
+    // the defaults apply.
+    let config = crate::core::config::Config::load(None).ok();
+    i18n::init(config.as_ref().and_then(|c| c.config.locale()));
+    let confirm_level = config
+        .as_ref()
+        .and_then(|c| prompts::ConfirmLevel::parse(c.config.confirm_level()))
+        .unwrap_or_default();
+    prompts::init(confirm_level, cli.yes);
+    crate::core::net::init(
+        config
+            .as_ref()
+            .map(|c| c.config.network_timeouts())
+            .unwrap_or_default(),
+    );
+    if let Some(c) = &config {
+        crate::git::ancestry_cache::init(c.config.ancestry_cache_entries());
+    }
+    crate::git::rerere::init(config.as_ref().is_none_or(|c| c.config.reuse_resolutions()));
+    hints::init(
+        config.as_ref().is_none_or(|c| c.config.hints())
+            && !cli.quiet
+            && !cli.json
+            && std::io::stderr().is_terminal(),
+    );
+    // Ctrl-C while a plan executes pauses it at the next step boundary.
+    engine::interrupt::init();
+
     // Create context from CLI flags.
     // Note: verify defaults to true (hooks honored) per ARCHITECTURE.md §10.2.
     // Config-based defaults could be added later, but CLI flag always takes precedence.
@@ -37,8 +78,25 @@ pub fn run() -> Result<()> {
         quiet: cli.quiet,
         interactive: cli.interactive(),
         verify: cli.verify_flag().unwrap_or(true),
+        offline: cli.offline,
+        read_only: cli.read_only,
     };
 
-    // Dispatch to command handler
-    commands::dispatch(cli.command, &ctx)
+    // Dispatch to command handler, then record how long its phases took
+    let started = std::time::Instant::now();
+    let result = commands::dispatch(cli.command, &ctx, cli.json);
+    commands::phase_budgets::report(&ctx, &cli.command_name, started, result.is_ok());
+    if ctx.debug {
+        if let Some(summary) = crate::forge::rate_limit::debug_summary(chrono::Utc::now()) {
+            eprintln!("[debug] {}", summary);
+        }
+    }
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(mut err) => {
+            err.attach_doctor_fixes(&ctx);
+            output::error_report(&err, cli.json);
+            ExitCode::from(err.exit_code())
+        }
+    }
 }
@@ -0,0 +1,184 @@
+//! core::absorb
+//!
+//! Attribution of staged hunks to the commits of a stack.
+//!
+//! # Design
+//!
+//! `lattice absorb` moves each staged hunk into the commit it belongs to,
+//! decided by blame at HEAD:
+//!
+//! - A hunk that replaces lines belongs to the newest commit that last
+//!   changed any of them. No later commit touches those lines, so the hunk
+//!   applies on top of that commit.
+//! - A hunk that only inserts lines belongs to the commit that last changed
+//!   the lines on both sides of it.
+//!
+//! A hunk stays staged when the lines it touches come from outside the
+//! stack, or when its neighbours were changed by different commits. The
+//! caller blames; this module only decides, and applies hunks to file
+//! content when building the fixups.
+
+use crate::git::DiffHunk;
+
+/// Why a hunk stays staged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unattributed {
+    /// It touches lines no commit of the stack changed
+    OutsideStack,
+    /// Its neighbours were changed by different commits
+    Ambiguous,
+}
+
+impl Unattributed {
+    /// Short explanation for listings.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Unattributed::OutsideStack => "touches lines from outside the stack",
+            Unattributed::Ambiguous => "could belong to more than one commit",
+        }
+    }
+}
+
+/// Decide which commit a hunk belongs to.
+///
+/// `blame` holds, for each line of the file at HEAD (line 1 first), the
+/// index of the stack commit that last changed it, oldest commit first, or
+/// `None` for lines from before the stack. Returns the index of the target.
+pub fn attribute(hunk: &DiffHunk, blame: &[Option<usize>]) -> Result<usize, Unattributed> {
+    let line = |n: usize| -> Option<Option<usize>> {
+        n.checked_sub(1).and_then(|i| blame.get(i)).copied()
+    };
+
+    if hunk.old_lines > 0 {
+        let mut target = None;
+        for n in hunk.old_start..hunk.old_start + hunk.old_lines {
+            match line(n) {
+                Some(Some(commit)) => target = target.max(Some(commit)),
+                _ => return Err(Unattributed::OutsideStack),
+            }
+        }
+        return target.ok_or(Unattributed::OutsideStack);
+    }
+
+    // Pure insertion after line `old_start`: look at both neighbours
+    let neighbours: Vec<Option<usize>> = [line(hunk.old_start), line(hunk.old_start + 1)]
+        .into_iter()
+        .flatten()
+        .collect();
+    match neighbours.as_slice() {
+        [] => Err(Unattributed::Ambiguous),
+        [Some(a)] => Ok(*a),
+        [Some(a), Some(b)] if a == b => Ok(*a),
+        _ if neighbours.iter().all(Option::is_none) => Err(Unattributed::OutsideStack),
+        _ => Err(Unattributed::Ambiguous),
+    }
+}
+
+/// Apply hunks to file content.
+///
+/// The hunks must come from the same zero-context diff against `original`;
+/// any subset of them applies, in any order.
+pub fn apply_hunks(original: &[u8], hunks: &[&DiffHunk]) -> Vec<u8> {
+    let lines: Vec<&[u8]> = original.split_inclusive(|b| *b == b'\n').collect();
+    let mut sorted = hunks.to_vec();
+    sorted.sort_by_key(|h| h.old_start);
+
+    let mut out = Vec::with_capacity(original.len());
+    let mut next = 0;
+    for hunk in sorted {
+        // Index of the first line the hunk replaces, or inserts before
+        let start = if hunk.old_lines == 0 {
+            hunk.old_start
+        } else {
+            hunk.old_start - 1
+        };
+        for line in &lines[next..start] {
+            out.extend_from_slice(line);
+        }
+        for line in &hunk.new_lines {
+            out.extend_from_slice(line);
+        }
+        next = start + hunk.old_lines;
+    }
+    for line in &lines[next.min(lines.len())..] {
+        out.extend_from_slice(line);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hunk(old_start: usize, old_lines: usize, new_lines: &[&str]) -> DiffHunk {
+        DiffHunk {
+            old_start,
+            old_lines,
+            new_lines: new_lines.iter().map(|l| l.as_bytes().to_vec()).collect(),
+        }
+    }
+
+    #[test]
+    fn replacement_goes_to_newest_commit() {
+        let blame = [None, Some(0), Some(1), Some(0)];
+        assert_eq!(attribute(&hunk(2, 1, &["x\n"]), &blame), Ok(0));
+        assert_eq!(attribute(&hunk(2, 3, &[]), &blame), Ok(1));
+        assert_eq!(
+            attribute(&hunk(1, 2, &["x\n"]), &blame),
+            Err(Unattributed::OutsideStack)
+        );
+    }
+
+    #[test]
+    fn insertion_needs_agreeing_neighbours() {
+        let blame = [Some(0), Some(0), Some(1), None];
+        assert_eq!(attribute(&hunk(1, 0, &["x\n"]), &blame), Ok(0));
+        assert_eq!(
+            attribute(&hunk(2, 0, &["x\n"]), &blame),
+            Err(Unattributed::Ambiguous)
+        );
+        assert_eq!(
+            attribute(&hunk(3, 0, &["x\n"]), &blame),
+            Err(Unattributed::Ambiguous)
+        );
+        // Top and bottom of the file have one neighbour
+        assert_eq!(attribute(&hunk(0, 0, &["x\n"]), &blame), Ok(0));
+        assert_eq!(
+            attribute(&hunk(4, 0, &["x\n"]), &blame),
+            Err(Unattributed::OutsideStack)
+        );
+        assert_eq!(
+            attribute(&hunk(0, 0, &["x\n"]), &[]),
+            Err(Unattributed::Ambiguous)
+        );
+    }
+
+    #[test]
+    fn applies_any_subset_of_hunks() {
+        let original = b"a\nb\nc\nd\n";
+        let replace = hunk(2, 1, &["B\n"]);
+        let insert = hunk(3, 0, &["c2\n"]);
+        let delete = hunk(4, 1, &[]);
+
+        assert_eq!(
+            apply_hunks(original, &[&delete, &replace, &insert]),
+            b"a\nB\nc\nc2\n"
+        );
+        assert_eq!(apply_hunks(original, &[&insert]), b"a\nb\nc\nc2\nd\n");
+        assert_eq!(apply_hunks(original, &[]), original);
+        assert_eq!(
+            apply_hunks(original, &[&hunk(0, 0, &["top\n"])]),
+            b"top\na\nb\nc\nd\n"
+        );
+    }
+
+    #[test]
+    fn keeps_missing_final_newline() {
+        let original = b"a\nb";
+        assert_eq!(apply_hunks(original, &[&hunk(1, 1, &["A\n"])]), b"A\nb");
+        assert_eq!(
+            apply_hunks(original, &[&hunk(2, 1, &["b\n", "c"])]),
+            b"a\nb\nc"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! core::atomic
+//!
+//! Crash-safe file replacement.
+//!
+//! # Design
+//!
+//! Config, journals, op-state, the pending queue, and the secrets file are
+//! all replaced with [`write`] (or [`write_private`]):
+//!
+//! 1. Write the contents to `<name>.tmp` beside the target and `fsync` it.
+//! 2. Rename it over the target.
+//! 3. `fsync` the containing directory, so the rename itself survives a
+//!    crash. Without this, ext4 (and NFS) may come back with the old file,
+//!    or none at all, after a power loss.
+//! 4. Read the target back and compare it with what was written, which
+//!    catches filesystems that silently drop or truncate writes.
+//!
+//! Readers therefore see either the complete old contents or the complete
+//! new ones. On failure the temporary file is removed and the target is
+//! left as it was, except after a failed read-back.
+//!
+//! Directory `fsync` is skipped where the platform has no such operation
+//! (Windows, where `MoveFileEx` already flushes the rename).
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Atomically replace `path` with `contents`.
+///
+/// The parent directory must exist.
+pub fn write(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_with(path, contents, None)
+}
+
+/// Like [`write`], but the file is readable only by its owner (mode 0600 on
+/// Unix), including while it is being written.
+pub fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_with(path, contents, Some(0o600))
+}
+
+/// Flush a directory's entries to disk.
+///
+/// Makes renames, creations, and removals inside `dir` durable.
+pub fn sync_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        File::open(dir)?.sync_all()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+        Ok(())
+    }
+}
+
+fn write_with(path: &Path, contents: &[u8], mode: Option<u32>) -> io::Result<()> {
+    let temp = temp_path(path);
+    let result = write_temp(&temp, contents, mode).and_then(|()| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+        return result;
+    }
+
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        sync_dir(dir)?;
+    }
+
+    if fs::read(path)? != contents {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} does not contain what was written", path.display()),
+        ));
+    }
+    Ok(())
+}
+
+fn write_temp(temp: &Path, contents: &[u8], mode: Option<u32>) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if let Some(mode) = mode {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(mode);
+    }
+    let mut file = options.open(temp)?;
+
+    // A leftover temp file keeps its old mode, so set it explicitly
+    #[cfg(unix)]
+    if let Some(mode) = mode {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(mode))?;
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// `<name>.tmp` in the same directory as `path`, so the rename stays on one
+/// filesystem.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_replaces_contents_and_cleans_up() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+
+        write(&path, b"first").unwrap();
+        write(&path, b"second").unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+        assert!(!dir.path().join("state.json.tmp").exists());
+    }
+
+    #[test]
+    fn failed_write_leaves_target_alone() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        write(&path, b"old").unwrap();
+
+        // A directory where the temp file should go makes the write fail
+        fs::create_dir(dir.path().join("config.toml.tmp")).unwrap();
+        assert!(write(&path, b"new").is_err());
+        assert_eq!(fs::read(&path).unwrap(), b"old");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn private_files_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("secrets.toml");
+        // A stale temp file with wider permissions is not reused as is
+        fs::write(dir.path().join("secrets.toml.tmp"), "stale").unwrap();
+
+        write_private(&path, b"token").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
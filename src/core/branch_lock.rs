@@ -0,0 +1,392 @@
+//! core::branch_lock
+//!
+//! Advisory per-branch locks for collaborators sharing a stack.
+//!
+//! # Architecture
+//!
+//! A lock is a JSON blob at `refs/lattice/locks/<branch>` naming its owner
+//! (git `user.email`) and when it expires. Like branch metadata, it lives in
+//! the metadata ref space, so when `sync_metadata_refs` is on it travels
+//! with the remote: [`BranchLockStore::fetch`] mirrors the remote's locks
+//! and [`BranchLockStore::push`] publishes a change with a lease, so two
+//! collaborators racing for the same lock can't both win.
+//!
+//! Locks are advisory. They stop Lattice commands run by anyone but the
+//! owner from rewriting or submitting the branch; plain git ignores them.
+//! An expired lock holds nothing and may be taken over by anyone; doctor
+//! reports expired locks still on disk and offers to delete them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use latticework::core::branch_lock::{BranchLock, BranchLockStore};
+//!
+//! let store = BranchLockStore::new(&git);
+//! let lock = BranchLock::new(branch, "alice@example.com", chrono::Duration::hours(2));
+//! store.acquire(&lock, &UtcTimestamp::now())?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
+use crate::git::{Git, GitError};
+
+/// Prefix for branch lock refs.
+pub const BRANCH_LOCK_REF_PREFIX: &str = "refs/lattice/locks/";
+
+/// Errors from branch lock operations.
+#[derive(Debug, Error)]
+pub enum BranchLockError {
+    /// Someone else holds an unexpired lock on the branch.
+    #[error("branch '{branch}' is locked by {owner} until {expires_at}")]
+    Held {
+        /// Locked branch
+        branch: String,
+        /// Owner of the lock
+        owner: String,
+        /// When the lock expires
+        expires_at: UtcTimestamp,
+    },
+
+    /// The branch has no lock.
+    #[error("branch '{0}' is not locked")]
+    NotLocked(String),
+
+    /// The remote refused the update because its copy of the lock changed.
+    #[error("the lock on '{0}' changed on the remote; fetch locks and try again")]
+    RemoteChanged(String),
+
+    /// The lock record could not be encoded or decoded.
+    #[error("lock on '{branch}' is corrupted: {message}")]
+    Corrupted {
+        /// Locked branch
+        branch: String,
+        /// What went wrong
+        message: String,
+    },
+
+    /// Git operation failed.
+    #[error("git error: {0}")]
+    Git(#[from] GitError),
+}
+
+/// A lock record.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BranchLock {
+    /// Locked branch.
+    pub branch: BranchName,
+    /// Who holds the lock (git `user.email`).
+    pub owner: String,
+    /// When the lock was taken.
+    pub acquired_at: UtcTimestamp,
+    /// When the lock stops holding.
+    pub expires_at: UtcTimestamp,
+}
+
+impl BranchLock {
+    /// Create a lock on `branch` for `owner`, expiring `ttl` from now.
+    pub fn new(branch: BranchName, owner: impl Into<String>, ttl: chrono::Duration) -> Self {
+        let now = UtcTimestamp::now();
+        let expires_at = UtcTimestamp::from_datetime(*now.as_datetime() + ttl);
+        Self {
+            branch,
+            owner: owner.into(),
+            acquired_at: now,
+            expires_at,
+        }
+    }
+
+    /// Whether the lock no longer holds at `now`.
+    pub fn is_expired(&self, now: &UtcTimestamp) -> bool {
+        self.expires_at.as_datetime() <= now.as_datetime()
+    }
+
+    /// Whether the lock stops `who` at `now`: unexpired and owned by
+    /// someone else. Without an identity, every unexpired lock applies.
+    pub fn blocks(&self, who: Option<&str>, now: &UtcTimestamp) -> bool {
+        !self.is_expired(now) && who != Some(self.owner.as_str())
+    }
+
+    /// The error reporting this lock as held.
+    pub fn held_error(&self) -> BranchLockError {
+        BranchLockError::Held {
+            branch: self.branch.to_string(),
+            owner: self.owner.clone(),
+            expires_at: self.expires_at.clone(),
+        }
+    }
+}
+
+/// A lock as stored, with the OID its ref points at.
+#[derive(Debug, Clone)]
+pub struct StoredLock {
+    /// Blob the lock ref points at.
+    pub oid: Oid,
+    /// The lock record.
+    pub lock: BranchLock,
+}
+
+/// Branch lock store backed by Git refs.
+pub struct BranchLockStore<'a> {
+    git: &'a Git,
+}
+
+impl<'a> BranchLockStore<'a> {
+    /// Create a new lock store using the given Git interface.
+    pub fn new(git: &'a Git) -> Self {
+        Self { git }
+    }
+
+    /// Get the lock ref name for a branch.
+    pub fn ref_name(branch: &BranchName) -> String {
+        format!("{}{}", BRANCH_LOCK_REF_PREFIX, branch)
+    }
+
+    /// Read the lock on a branch, if any.
+    pub fn read(&self, branch: &BranchName) -> Result<Option<StoredLock>, BranchLockError> {
+        let Some(oid) = self
+            .git
+            .try_resolve_ref_to_object(&Self::ref_name(branch))?
+        else {
+            return Ok(None);
+        };
+        let json = self.git.read_blob_as_string(&oid)?;
+        let lock = serde_json::from_str(&json).map_err(|e| BranchLockError::Corrupted {
+            branch: branch.to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(Some(StoredLock { oid, lock }))
+    }
+
+    /// List all readable locks, sorted by branch.
+    ///
+    /// Corrupted records are skipped: they can't name an owner, so they
+    /// hold nothing.
+    pub fn list(&self) -> Result<Vec<StoredLock>, BranchLockError> {
+        let mut locks = Vec::new();
+        for entry in self.git.list_refs_by_prefix(BRANCH_LOCK_REF_PREFIX)? {
+            let Some(branch) = entry
+                .name
+                .as_str()
+                .strip_prefix(BRANCH_LOCK_REF_PREFIX)
+                .and_then(|name| BranchName::new(name).ok())
+            else {
+                continue;
+            };
+            if let Ok(Some(stored)) = self.read(&branch) {
+                locks.push(stored);
+            }
+        }
+        locks.sort_by(|a, b| a.lock.branch.as_str().cmp(b.lock.branch.as_str()));
+        Ok(locks)
+    }
+
+    /// The first lock among `branches` that stops `who` at `now`.
+    pub fn blocking(
+        &self,
+        branches: &[BranchName],
+        who: Option<&str>,
+        now: &UtcTimestamp,
+    ) -> Result<Option<BranchLock>, BranchLockError> {
+        for branch in branches {
+            if let (_, Some(lock)) = self.current(branch)? {
+                if lock.blocks(who, now) {
+                    return Ok(Some(lock));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Take or renew the lock on `lock.branch`.
+    ///
+    /// Replaces an existing lock only if it is the owner's own or has
+    /// expired at `now`. Returns the lock's previous OID, if any.
+    pub fn acquire(
+        &self,
+        lock: &BranchLock,
+        now: &UtcTimestamp,
+    ) -> Result<Option<Oid>, BranchLockError> {
+        let (old, existing) = self.current(&lock.branch)?;
+        if let Some(existing) = existing {
+            if existing.blocks(Some(&lock.owner), now) {
+                return Err(existing.held_error());
+            }
+        }
+
+        let json = serde_json::to_string_pretty(lock).map_err(|e| BranchLockError::Corrupted {
+            branch: lock.branch.to_string(),
+            message: e.to_string(),
+        })?;
+        let oid = self.git.write_blob(json.as_bytes())?;
+        self.git.update_ref_cas(
+            &Self::ref_name(&lock.branch),
+            &oid,
+            old.as_ref(),
+            &format!("lattice: lock {}", lock.branch),
+        )?;
+        Ok(old)
+    }
+
+    /// Release the lock on `branch`.
+    ///
+    /// Only `who` may release an unexpired lock, unless `force` is set.
+    /// Returns the released lock's OID.
+    pub fn release(
+        &self,
+        branch: &BranchName,
+        who: Option<&str>,
+        force: bool,
+        now: &UtcTimestamp,
+    ) -> Result<Oid, BranchLockError> {
+        let (oid, existing) = self.current(branch)?;
+        let oid = oid.ok_or_else(|| BranchLockError::NotLocked(branch.to_string()))?;
+        if let Some(existing) = existing {
+            if !force && existing.blocks(who, now) {
+                return Err(existing.held_error());
+            }
+        }
+        self.git.delete_ref_cas(&Self::ref_name(branch), &oid)?;
+        Ok(oid)
+    }
+
+    /// The lock ref's OID and, if it is readable, the lock it records.
+    ///
+    /// A corrupted record holds nothing, so it may be replaced or released.
+    fn current(
+        &self,
+        branch: &BranchName,
+    ) -> Result<(Option<Oid>, Option<BranchLock>), BranchLockError> {
+        match self.read(branch) {
+            Ok(Some(stored)) => Ok((Some(stored.oid), Some(stored.lock))),
+            Ok(None) => Ok((None, None)),
+            Err(BranchLockError::Corrupted { .. }) => Ok((
+                self.git
+                    .try_resolve_ref_to_object(&Self::ref_name(branch))?,
+                None,
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Point the lock ref for `branch` back at `oid`, or delete it.
+    ///
+    /// Undoes a local change the remote refused.
+    pub fn restore(&self, branch: &BranchName, oid: Option<&Oid>) -> Result<(), BranchLockError> {
+        let refname = Self::ref_name(branch);
+        let current = self.git.try_resolve_ref_to_object(&refname)?;
+        match (oid, current) {
+            (Some(oid), current) => {
+                self.git
+                    .update_ref_cas(&refname, oid, current.as_ref(), "lattice: restore lock")?
+            }
+            (None, Some(current)) => self.git.delete_ref_cas(&refname, &current)?,
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Replace local locks with the remote's.
+    ///
+    /// Locks deleted on the remote are deleted locally.
+    pub fn fetch(&self, remote: &str) -> Result<(), BranchLockError> {
+        let result = self.git.run_command(&[
+            "fetch".to_string(),
+            "--quiet".to_string(),
+            "--prune".to_string(),
+            remote.to_string(),
+            format!("+{0}*:{0}*", BRANCH_LOCK_REF_PREFIX),
+        ])?;
+        if !result.success {
+            return Err(GitError::Internal {
+                message: format!("git fetch {} failed: {}", remote, result.stderr.trim()),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Publish the local lock on `branch` (or its absence) to `remote`.
+    ///
+    /// `expected` is the remote's OID as last fetched; the push is refused
+    /// with [`BranchLockError::RemoteChanged`] if the remote has moved on.
+    pub fn push(
+        &self,
+        remote: &str,
+        branch: &BranchName,
+        expected: Option<&Oid>,
+    ) -> Result<(), BranchLockError> {
+        let refname = Self::ref_name(branch);
+        let local = self.git.try_resolve_ref_to_object(&refname)?;
+        let refspec = match local {
+            Some(_) => format!("{0}:{0}", refname),
+            None => format!(":{}", refname),
+        };
+        let lease = format!(
+            "--force-with-lease={}:{}",
+            refname,
+            expected.map(Oid::to_string).unwrap_or_default()
+        );
+        let result = self.git.run_command(&[
+            "push".to_string(),
+            "--quiet".to_string(),
+            lease,
+            remote.to_string(),
+            refspec,
+        ])?;
+        if result.success {
+            Ok(())
+        } else if result.stderr.contains("stale info") || result.stderr.contains("rejected") {
+            Err(BranchLockError::RemoteChanged(branch.to_string()))
+        } else {
+            Err(GitError::Internal {
+                message: format!("git push {} failed: {}", remote, result.stderr.trim()),
+            }
+            .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock(owner: &str, ttl_minutes: i64) -> BranchLock {
+        BranchLock::new(
+            BranchName::new("feature").unwrap(),
+            owner,
+            chrono::Duration::minutes(ttl_minutes),
+        )
+    }
+
+    #[test]
+    fn ref_name_uses_lock_prefix() {
+        assert_eq!(
+            BranchLockStore::ref_name(&BranchName::new("feature/auth").unwrap()),
+            "refs/lattice/locks/feature/auth"
+        );
+    }
+
+    #[test]
+    fn lock_blocks_others_until_expiry() {
+        let now = UtcTimestamp::now();
+        let held = lock("alice@example.com", 60);
+        assert!(!held.is_expired(&now));
+        assert!(held.blocks(Some("bob@example.com"), &now));
+        assert!(held.blocks(None, &now));
+        assert!(!held.blocks(Some("alice@example.com"), &now));
+
+        let expired = lock("alice@example.com", -1);
+        assert!(expired.is_expired(&now));
+        assert!(!expired.blocks(Some("bob@example.com"), &now));
+    }
+
+    #[test]
+    fn lock_roundtrips_through_json() {
+        let held = lock("alice@example.com", 120);
+        let json = serde_json::to_string(&held).unwrap();
+        let parsed: BranchLock = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, held);
+    }
+}
@@ -0,0 +1,57 @@
+//! core::cache_stats
+//!
+//! Hit and miss counts for Lattice's on-disk caches.
+//!
+//! # Design
+//!
+//! Each cache under `<common_dir>/lattice/cache/` keeps its own counts in
+//! its file, next to the entries they describe, so clearing a cache resets
+//! its counts too. `lattice debug cache-stats` reads them back to show how
+//! well each cache works for a repository, which is what its size limits
+//! should be tuned against.
+
+use serde::{Deserialize, Serialize};
+
+/// Lookups a cache answered, and lookups it couldn't.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HitStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that had to compute or fetch the answer
+    pub misses: u64,
+}
+
+impl HitStats {
+    /// Count one lookup.
+    pub fn record(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+    }
+
+    /// Fraction of lookups answered from the cache, or `None` before any.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        (total > 0).then(|| self.hits as f64 / total as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_rate_counts_both_outcomes() {
+        let mut stats = HitStats::default();
+        assert_eq!(stats.hit_rate(), None);
+
+        stats.record(true);
+        stats.record(true);
+        stats.record(true);
+        stats.record(false);
+        assert_eq!(stats, HitStats { hits: 3, misses: 1 });
+        assert_eq!(stats.hit_rate(), Some(0.75));
+    }
+}
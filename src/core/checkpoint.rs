@@ -0,0 +1,287 @@
+//! core::checkpoint
+//!
+//! Named, user-controlled restore points for the whole stack.
+//!
+//! # Architecture
+//!
+//! A checkpoint is stored as a commit at `refs/lattice/checkpoints/<name>`.
+//! The commit's tree contains a single `checkpoint.json` blob describing
+//! every tracked branch (tip OID plus full metadata). The commit's parents
+//! are the recorded branch tips, which keeps those commits reachable (and
+//! safe from `git gc`) for as long as the checkpoint exists.
+//!
+//! Unlike the operation journal, checkpoints are never created implicitly
+//! and never expire: they exist until the user deletes them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use latticework::core::checkpoint::CheckpointStore;
+//!
+//! let store = CheckpointStore::new(&git);
+//! store.save(&checkpoint, false)?;
+//!
+//! let restored = store.load("before-big-refactor")?;
+//! for branch in &restored.branches {
+//!     println!("{} -> {}", branch.name, branch.tip);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::metadata::schema::{BranchMetadataV2, SCHEMA_VERSION};
+use crate::core::types::{BranchName, Oid, UtcTimestamp};
+use crate::git::{Git, GitError, TreeEntry};
+
+/// Prefix for checkpoint refs.
+pub const CHECKPOINT_REF_PREFIX: &str = "refs/lattice/checkpoints/";
+
+/// Name of the checkpoint record inside the checkpoint commit's tree.
+const CHECKPOINT_FILE: &str = "checkpoint.json";
+
+/// Errors from checkpoint storage operations.
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    /// The checkpoint name is not a valid ref component.
+    #[error("invalid checkpoint name: {0}")]
+    InvalidName(String),
+
+    /// A checkpoint with this name already exists.
+    #[error("checkpoint '{0}' already exists")]
+    AlreadyExists(String),
+
+    /// No checkpoint with this name exists.
+    #[error("checkpoint '{0}' not found")]
+    NotFound(String),
+
+    /// The checkpoint record could not be encoded or decoded.
+    #[error("checkpoint '{name}' is corrupted: {message}")]
+    Corrupted {
+        /// Checkpoint name
+        name: String,
+        /// What went wrong
+        message: String,
+    },
+
+    /// Git operation failed.
+    #[error("git error: {0}")]
+    Git(#[from] GitError),
+}
+
+/// A tracked branch as recorded in a checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CheckpointBranch {
+    /// Branch name.
+    pub name: String,
+    /// Branch tip at save time.
+    pub tip: String,
+    /// Full branch metadata at save time.
+    pub metadata: BranchMetadataV2,
+}
+
+/// A saved stack state.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Checkpoint {
+    /// Checkpoint name.
+    pub name: String,
+    /// When the checkpoint was saved.
+    pub created_at: UtcTimestamp,
+    /// Trunk branch at save time (informational; trunk is never restored).
+    pub trunk: Option<String>,
+    /// Branch checked out at save time.
+    pub current_branch: Option<String>,
+    /// Every tracked branch, sorted by name.
+    pub branches: Vec<CheckpointBranch>,
+}
+
+impl Checkpoint {
+    /// Find a recorded branch by name.
+    pub fn branch(&self, name: &str) -> Option<&CheckpointBranch> {
+        self.branches.iter().find(|b| b.name == name)
+    }
+}
+
+/// Checkpoint store backed by Git refs.
+pub struct CheckpointStore<'a> {
+    git: &'a Git,
+}
+
+impl<'a> CheckpointStore<'a> {
+    /// Create a new checkpoint store using the given Git interface.
+    pub fn new(git: &'a Git) -> Self {
+        Self { git }
+    }
+
+    /// Get the ref name for a checkpoint, validating the name.
+    ///
+    /// Checkpoint names follow the same rules as branch names.
+    pub fn ref_name(name: &str) -> Result<String, CheckpointError> {
+        BranchName::new(name).map_err(|e| CheckpointError::InvalidName(e.to_string()))?;
+        Ok(format!("{}{}", CHECKPOINT_REF_PREFIX, name))
+    }
+
+    /// Save a checkpoint.
+    ///
+    /// Fails with `AlreadyExists` unless `overwrite` is set.
+    pub fn save(&self, checkpoint: &Checkpoint, overwrite: bool) -> Result<Oid, CheckpointError> {
+        let refname = Self::ref_name(&checkpoint.name)?;
+        let existing = self.git.try_resolve_ref(&refname)?;
+        if existing.is_some() && !overwrite {
+            return Err(CheckpointError::AlreadyExists(checkpoint.name.clone()));
+        }
+
+        let json =
+            serde_json::to_string_pretty(checkpoint).map_err(|e| CheckpointError::Corrupted {
+                name: checkpoint.name.clone(),
+                message: e.to_string(),
+            })?;
+        let blob_oid = self.git.write_blob(json.as_bytes())?;
+        let tree_oid = self.git.write_tree(&[TreeEntry {
+            name: CHECKPOINT_FILE,
+            oid: &blob_oid,
+            mode: 0o100644,
+        }])?;
+
+        // Parents keep the recorded tips reachable
+        let mut tips: Vec<Oid> = Vec::new();
+        for branch in &checkpoint.branches {
+            let tip = Oid::new(&branch.tip).map_err(|e| CheckpointError::Corrupted {
+                name: checkpoint.name.clone(),
+                message: e.to_string(),
+            })?;
+            if !tips.contains(&tip) {
+                tips.push(tip);
+            }
+        }
+        let parents: Vec<&Oid> = tips.iter().collect();
+
+        let commit_oid = self.git.write_commit(
+            &tree_oid,
+            &parents,
+            &format!("lattice checkpoint: {}", checkpoint.name),
+        )?;
+        self.git.update_ref_cas(
+            &refname,
+            &commit_oid,
+            existing.as_ref(),
+            &format!("lattice: save checkpoint {}", checkpoint.name),
+        )?;
+
+        Ok(commit_oid)
+    }
+
+    /// Load a checkpoint by name.
+    pub fn load(&self, name: &str) -> Result<Checkpoint, CheckpointError> {
+        let refname = Self::ref_name(name)?;
+        let commit_oid = self
+            .git
+            .try_resolve_ref(&refname)?
+            .ok_or_else(|| CheckpointError::NotFound(name.to_string()))?;
+
+        let corrupted = |message: String| CheckpointError::Corrupted {
+            name: name.to_string(),
+            message,
+        };
+
+        let tree_oid = self.git.commit_tree(&commit_oid)?;
+        let blob_oid = self
+            .git
+            .tree_entry_oid(&tree_oid, CHECKPOINT_FILE)?
+            .ok_or_else(|| corrupted(format!("missing {}", CHECKPOINT_FILE)))?;
+        let json = self.git.read_blob_as_string(&blob_oid)?;
+        let mut checkpoint: Checkpoint =
+            serde_json::from_str(&json).map_err(|e| corrupted(e.to_string()))?;
+
+        // Checkpoints saved before schema v2 hold v1 metadata, which has the
+        // same shape minus the optional v2 fields
+        for branch in &mut checkpoint.branches {
+            branch.metadata.schema_version = SCHEMA_VERSION;
+        }
+        Ok(checkpoint)
+    }
+
+    /// List checkpoint names, sorted.
+    pub fn list(&self) -> Result<Vec<String>, CheckpointError> {
+        let mut names: Vec<String> = self
+            .git
+            .list_refs_by_prefix(CHECKPOINT_REF_PREFIX)?
+            .into_iter()
+            .filter_map(|entry| {
+                entry
+                    .name
+                    .as_str()
+                    .strip_prefix(CHECKPOINT_REF_PREFIX)
+                    .map(str::to_string)
+            })
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// Delete a checkpoint.
+    pub fn delete(&self, name: &str) -> Result<(), CheckpointError> {
+        let refname = Self::ref_name(name)?;
+        let oid = self
+            .git
+            .try_resolve_ref(&refname)?
+            .ok_or_else(|| CheckpointError::NotFound(name.to_string()))?;
+        self.git.delete_ref_cas(&refname, &oid)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ref_name_uses_checkpoint_prefix() {
+        assert_eq!(
+            CheckpointStore::ref_name("before-refactor").unwrap(),
+            "refs/lattice/checkpoints/before-refactor"
+        );
+        assert_eq!(
+            CheckpointStore::ref_name("wip/auth").unwrap(),
+            "refs/lattice/checkpoints/wip/auth"
+        );
+    }
+
+    #[test]
+    fn ref_name_rejects_invalid_names() {
+        assert!(matches!(
+            CheckpointStore::ref_name("bad..name"),
+            Err(CheckpointError::InvalidName(_))
+        ));
+        assert!(matches!(
+            CheckpointStore::ref_name(""),
+            Err(CheckpointError::InvalidName(_))
+        ));
+    }
+
+    #[test]
+    fn checkpoint_roundtrips_through_json() {
+        let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+        let checkpoint = Checkpoint {
+            name: "cp".to_string(),
+            created_at: UtcTimestamp::now(),
+            trunk: Some("main".to_string()),
+            current_branch: Some("feature".to_string()),
+            branches: vec![CheckpointBranch {
+                name: "feature".to_string(),
+                tip: oid.to_string(),
+                metadata: BranchMetadataV2::new(
+                    BranchName::new("feature").unwrap(),
+                    BranchName::new("main").unwrap(),
+                    oid,
+                ),
+            }],
+        };
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let parsed: Checkpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, checkpoint);
+        assert!(parsed.branch("feature").is_some());
+        assert!(parsed.branch("other").is_none());
+    }
+}
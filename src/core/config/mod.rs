@@ -49,13 +49,14 @@
 //! ```
 
 pub mod schema;
+pub mod workspace;
 
 pub use schema::{GlobalConfig, RepoConfig};
 
+use crate::core::atomic;
 use crate::core::paths::LatticePaths;
 use crate::git::Git;
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -361,26 +362,7 @@ impl Config {
         let contents =
             toml::to_string_pretty(config).map_err(|e| ConfigError::InvalidValue(e.to_string()))?;
 
-        // Write to temp file in same directory (for atomic rename)
-        let temp_path = path.with_extension("toml.tmp");
-        let mut file = fs::File::create(&temp_path).map_err(|e| ConfigError::WriteError {
-            path: temp_path.clone(),
-            source: e,
-        })?;
-
-        file.write_all(contents.as_bytes())
-            .map_err(|e| ConfigError::WriteError {
-                path: temp_path.clone(),
-                source: e,
-            })?;
-
-        file.sync_all().map_err(|e| ConfigError::WriteError {
-            path: temp_path.clone(),
-            source: e,
-        })?;
-
-        // Atomic rename
-        fs::rename(&temp_path, path).map_err(|e| ConfigError::WriteError {
+        atomic::write(path, contents.as_bytes()).map_err(|e| ConfigError::WriteError {
             path: path.to_path_buf(),
             source: e,
         })?;
@@ -416,6 +398,13 @@ impl Config {
         self.global.interactive.unwrap_or(true)
     }
 
+    /// Get the configured message locale, if any.
+    ///
+    /// Environment variables can override it; see [`crate::ui::i18n`].
+    pub fn locale(&self) -> Option<&str> {
+        self.global.locale.as_deref()
+    }
+
     /// Check if hook verification is enabled by default.
     ///
     /// Defaults to `true` if not configured.
@@ -463,6 +452,286 @@ impl Config {
             .unwrap_or(true)
     }
 
+    /// Get the line-count threshold above which submit warns about review size.
+    ///
+    /// Repo config overrides global config. Defaults to 400 if not configured.
+    pub fn submit_review_size_warn_lines(&self) -> usize {
+        self.repo
+            .as_ref()
+            .and_then(|r| r.submit.as_ref())
+            .and_then(|s| s.review_size_warn_lines)
+            .or_else(|| {
+                self.global
+                    .submit
+                    .as_ref()
+                    .and_then(|s| s.review_size_warn_lines)
+            })
+            .unwrap_or(400)
+    }
+
+    /// Get the file-count threshold above which submit warns about review size.
+    ///
+    /// Repo config overrides global config. Defaults to 20 if not configured.
+    pub fn submit_review_size_warn_files(&self) -> usize {
+        self.repo
+            .as_ref()
+            .and_then(|r| r.submit.as_ref())
+            .and_then(|s| s.review_size_warn_files)
+            .or_else(|| {
+                self.global
+                    .submit
+                    .as_ref()
+                    .and_then(|s| s.review_size_warn_files)
+            })
+            .unwrap_or(20)
+    }
+
+    /// Check if submit should refuse to re-push over failing downstack checks.
+    ///
+    /// When `false`, failing downstack checks only produce a warning.
+    /// Defaults to `false` if not configured.
+    pub fn submit_require_green_downstack(&self) -> bool {
+        self.global
+            .submit
+            .as_ref()
+            .and_then(|s| s.require_green_downstack)
+            .unwrap_or(false)
+    }
+
+    /// Get the command that generates bodies for new PRs, if configured.
+    ///
+    /// Run through the shell from the repository root; see
+    /// `cli::commands::body_generator`.
+    pub fn submit_body_generator(&self) -> Option<&str> {
+        self.global
+            .submit
+            .as_ref()
+            .and_then(|s| s.body_generator.as_deref())
+            .filter(|command| !command.trim().is_empty())
+    }
+
+    /// Get the rules assigning default reviewers to new PRs.
+    pub fn submit_reviewer_rules(&self) -> Option<&schema::ReviewerRules> {
+        self.global
+            .submit
+            .as_ref()
+            .and_then(|s| s.reviewers.as_ref())
+    }
+
+    /// Get the check that must pass on each branch before submit pushes it.
+    ///
+    /// Run by the executor in a temporary worktree at the branch tip; see
+    /// `engine::exec::run_pre_push_check`.
+    pub fn submit_pre_push(&self) -> Option<&str> {
+        self.global
+            .submit
+            .as_ref()
+            .and_then(|s| s.pre_push.as_deref())
+            .filter(|command| !command.trim().is_empty())
+    }
+
+    /// Get how many pushes and PR requests submit runs at once.
+    ///
+    /// Defaults to 4 if not configured.
+    pub fn submit_parallelism(&self) -> usize {
+        self.global
+            .submit
+            .as_ref()
+            .and_then(|s| s.parallelism)
+            .unwrap_or(4)
+    }
+
+    /// Check if rewriting commands should restack descendants automatically.
+    ///
+    /// Defaults to `true` if not configured.
+    pub fn restack_descendants(&self) -> bool {
+        self.global
+            .restack
+            .as_ref()
+            .and_then(|r| r.descendants)
+            .unwrap_or(true)
+    }
+
+    /// Check if conflict resolutions are recorded and reused (git rerere).
+    ///
+    /// Defaults to `true` if not configured.
+    pub fn reuse_resolutions(&self) -> bool {
+        self.global
+            .restack
+            .as_ref()
+            .and_then(|r| r.reuse_resolutions)
+            .unwrap_or(true)
+    }
+
+    /// Get which confirmations interactive sessions ask.
+    ///
+    /// Defaults to "always" if not configured.
+    pub fn confirm_level(&self) -> &str {
+        self.global.confirm.as_deref().unwrap_or("always")
+    }
+
+    /// Get how `sync` updates a trunk that diverged from the remote.
+    ///
+    /// Defaults to "ff-only" if not configured.
+    pub fn sync_trunk_strategy(&self) -> &str {
+        self.global
+            .sync
+            .as_ref()
+            .and_then(|s| s.trunk_strategy.as_deref())
+            .unwrap_or("ff-only")
+    }
+
+    /// Get the template for branch names `create` derives.
+    ///
+    /// Repo config overrides global config. `None` (the default) uses the
+    /// bare slug.
+    pub fn create_branch_template(&self) -> Option<&str> {
+        self.repo
+            .as_ref()
+            .and_then(|r| r.create.as_ref())
+            .and_then(|c| c.branch_template.as_deref())
+            .or_else(|| {
+                self.global
+                    .create
+                    .as_ref()
+                    .and_then(|c| c.branch_template.as_deref())
+            })
+    }
+
+    /// Check if screen-reader-friendly output is enabled.
+    ///
+    /// Defaults to `false` if not configured.
+    pub fn output_accessible(&self) -> bool {
+        self.global
+            .output
+            .as_ref()
+            .and_then(|o| o.accessible)
+            .unwrap_or(false)
+    }
+
+    /// Get the number of days behind trunk after which a stack is flagged.
+    ///
+    /// Defaults to 14 if not configured; 0 disables the check.
+    pub fn drift_warn_days(&self) -> u64 {
+        self.global
+            .drift
+            .as_ref()
+            .and_then(|d| d.warn_days)
+            .unwrap_or(14)
+    }
+
+    /// Get the number of trunk commits behind after which a stack is flagged.
+    ///
+    /// Defaults to 50 if not configured; 0 disables the check.
+    pub fn drift_warn_commits(&self) -> usize {
+        self.global
+            .drift
+            .as_ref()
+            .and_then(|d| d.warn_commits)
+            .unwrap_or(50)
+    }
+
+    /// Check if navigation commands prefetch forge status in the background.
+    ///
+    /// Defaults to `true` if not configured.
+    pub fn prefetch_enabled(&self) -> bool {
+        self.global
+            .prefetch
+            .as_ref()
+            .and_then(|p| p.enabled)
+            .unwrap_or(true)
+    }
+
+    /// Get the number of seconds before a branch's cached forge status is
+    /// prefetched again.
+    ///
+    /// Defaults to 60 if not configured.
+    pub fn prefetch_interval_secs(&self) -> u64 {
+        self.global
+            .prefetch
+            .as_ref()
+            .and_then(|p| p.interval_secs)
+            .unwrap_or(60)
+    }
+
+    /// Get the number of ancestry answers cached across runs (default
+    /// 10000; 0 turns the cache off).
+    pub fn ancestry_cache_entries(&self) -> usize {
+        self.global
+            .cache
+            .as_ref()
+            .and_then(|c| c.ancestry_entries)
+            .unwrap_or(crate::git::ancestry_cache::DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Get the seconds between CI polls in `merge --when-green` (default 30).
+    pub fn merge_poll_interval_secs(&self) -> u64 {
+        self.global
+            .merge
+            .as_ref()
+            .and_then(|m| m.poll_interval_secs)
+            .unwrap_or(30)
+    }
+
+    /// Get the seconds `merge --when-green` waits for one PR's checks
+    /// (default 3600).
+    pub fn merge_wait_timeout_secs(&self) -> u64 {
+        self.global
+            .merge
+            .as_ref()
+            .and_then(|m| m.wait_timeout_secs)
+            .unwrap_or(3600)
+    }
+
+    /// Get the HTTP timeouts for forge and auth requests.
+    ///
+    /// Each limit not configured keeps its default (see
+    /// [`crate::core::net::Timeouts`]).
+    pub fn network_timeouts(&self) -> crate::core::net::Timeouts {
+        let defaults = crate::core::net::Timeouts::default();
+        let network = self.global.network.as_ref();
+        let secs = |value: Option<u64>, default: std::time::Duration| {
+            value.map(std::time::Duration::from_secs).unwrap_or(default)
+        };
+        crate::core::net::Timeouts {
+            connect: secs(
+                network.and_then(|n| n.connect_timeout_secs),
+                defaults.connect,
+            ),
+            read: secs(network.and_then(|n| n.read_timeout_secs), defaults.read),
+            total: secs(network.and_then(|n| n.timeout_secs), defaults.total),
+        }
+    }
+
+    /// Get the per-phase duration budgets (`[budgets]`, 0 turns one off).
+    pub fn phase_budgets(&self) -> crate::engine::timing::Budgets {
+        let defaults = crate::engine::timing::Budgets::default();
+        let budgets = self.global.budgets.as_ref();
+        let millis = |value: Option<u64>, default: Option<std::time::Duration>| match value {
+            Some(0) => None,
+            Some(ms) => Some(std::time::Duration::from_millis(ms)),
+            None => default,
+        };
+        crate::engine::timing::Budgets {
+            scan: millis(budgets.and_then(|b| b.scan_ms), defaults.scan),
+            gate: millis(budgets.and_then(|b| b.gate_ms), defaults.gate),
+            plan: millis(budgets.and_then(|b| b.plan_ms), defaults.plan),
+            execute: millis(budgets.and_then(|b| b.execute_ms), defaults.execute),
+        }
+    }
+
+    /// Check if background git maintenance may run after large operations.
+    ///
+    /// Defaults to `true` if not configured. Git's own `maintenance.auto`
+    /// is checked separately.
+    pub fn maintenance_auto(&self) -> bool {
+        self.global
+            .maintenance
+            .as_ref()
+            .and_then(|m| m.auto)
+            .unwrap_or(true)
+    }
+
     /// Check if metadata refs should be synced.
     ///
     /// Defaults to `false` if not configured.
@@ -473,6 +742,35 @@ impl Config {
             .unwrap_or(false)
     }
 
+    /// Check if metadata blobs should be signed and verified.
+    ///
+    /// Defaults to `false` if not configured.
+    pub fn sign_metadata(&self) -> bool {
+        self.repo
+            .as_ref()
+            .and_then(|r| r.sign_metadata)
+            .unwrap_or(false)
+    }
+
+    /// Check if read-only mode is on (see [`crate::engine::read_only`]).
+    ///
+    /// Repo config overrides global config. Defaults to `false` if not
+    /// configured.
+    pub fn read_only(&self) -> bool {
+        self.repo
+            .as_ref()
+            .and_then(|r| r.read_only)
+            .or(self.global.read_only)
+            .unwrap_or(false)
+    }
+
+    /// Check if hints suggesting the next command are shown.
+    ///
+    /// Defaults to `true` if not configured.
+    pub fn hints(&self) -> bool {
+        self.global.hints.unwrap_or(true)
+    }
+
     /// Get the path to the loaded global config file.
     pub fn global_config_loaded_from(&self) -> Option<&Path> {
         self.global_path.as_deref()
@@ -516,6 +814,10 @@ mod tests {
         assert!(config.verify_hooks());
         assert_eq!(config.default_forge(), "github");
         assert_eq!(config.secrets_provider(), "file");
+        assert!(config.restack_descendants());
+        assert!(config.reuse_resolutions());
+        assert!(!config.output_accessible());
+        assert!(config.maintenance_auto());
     }
 
     #[test]
@@ -663,4 +965,58 @@ mod tests {
         // Repo remote overrides the default "origin"
         assert_eq!(config.remote(), "upstream");
     }
+
+    #[test]
+    fn read_only_repo_overrides_global() {
+        let mut config = Config {
+            global: GlobalConfig {
+                read_only: Some(true),
+                ..Default::default()
+            },
+            repo: Some(RepoConfig::default()),
+            global_path: None,
+            repo_path: None,
+        };
+        assert!(config.read_only());
+
+        config.repo = Some(RepoConfig {
+            read_only: Some(false),
+            ..Default::default()
+        });
+        assert!(!config.read_only());
+
+        assert!(!Config::default().read_only());
+    }
+
+    #[test]
+    fn review_size_thresholds_repo_overrides_global() {
+        let mut config = Config {
+            global: GlobalConfig {
+                submit: Some(schema::SubmitDefaults {
+                    review_size_warn_lines: Some(1000),
+                    review_size_warn_files: Some(50),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            repo: Some(RepoConfig::default()),
+            global_path: None,
+            repo_path: None,
+        };
+        assert_eq!(config.submit_review_size_warn_lines(), 1000);
+        assert_eq!(config.submit_review_size_warn_files(), 50);
+
+        config.repo = Some(RepoConfig {
+            submit: Some(schema::RepoSubmitConfig {
+                review_size_warn_lines: Some(200),
+                review_size_warn_files: None,
+            }),
+            ..Default::default()
+        });
+        assert_eq!(config.submit_review_size_warn_lines(), 200);
+        assert_eq!(config.submit_review_size_warn_files(), 50);
+
+        assert_eq!(Config::default().submit_review_size_warn_lines(), 400);
+        assert_eq!(Config::default().submit_review_size_warn_files(), 20);
+    }
 }
@@ -18,6 +18,8 @@
 //! Config values are validated after parsing to ensure they conform to
 //! expected formats (e.g., trunk must be a valid branch name).
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use super::ConfigError;
@@ -31,10 +33,65 @@ use crate::core::types::BranchName;
 /// default_forge = "github"
 /// interactive = true
 /// verify_hooks = true
+/// locale = "en"
+/// confirm = "destructive-only"
+/// read_only = false
+/// hints = true
 ///
 /// [submit]
 /// draft = false
 /// restack = true
+/// review_size_warn_lines = 400
+/// review_size_warn_files = 20
+/// require_green_downstack = false
+/// body_generator = "scripts/pr-body.sh"
+/// pre_push = "cargo test"
+/// parallelism = 4
+///
+/// [submit.reviewers.paths]
+/// "src/forge/**" = ["alice", "team:platform"]
+///
+/// [restack]
+/// descendants = true
+/// reuse_resolutions = true
+///
+/// [output]
+/// accessible = false
+///
+/// [maintenance]
+/// auto = true
+///
+/// [drift]
+/// warn_days = 14
+/// warn_commits = 50
+///
+/// [prefetch]
+/// enabled = true
+/// interval_secs = 60
+///
+/// [network]
+/// connect_timeout_secs = 10
+/// read_timeout_secs = 30
+/// timeout_secs = 60
+///
+/// [cache]
+/// ancestry_entries = 10000
+///
+/// [merge]
+/// poll_interval_secs = 30
+/// wait_timeout_secs = 3600
+///
+/// [sync]
+/// trunk_strategy = "ff-only"
+///
+/// [create]
+/// branch_template = "{user}/{slug}"
+///
+/// [budgets]
+/// scan_ms = 2000
+/// gate_ms = 200
+/// plan_ms = 1000
+/// execute_ms = 30000
 ///
 /// [secrets]
 /// provider = "file"
@@ -54,14 +111,65 @@ pub struct GlobalConfig {
     /// Submit defaults
     pub submit: Option<SubmitDefaults>,
 
+    /// Restack defaults
+    pub restack: Option<RestackDefaults>,
+
     /// Secret storage settings
     pub secrets: Option<SecretsConfig>,
 
     /// Doctor command settings
     pub doctor: Option<DoctorConfig>,
+
+    /// Locale for messages (e.g., "en", "pt-BR")
+    pub locale: Option<String>,
+
+    /// Output rendering settings
+    pub output: Option<OutputConfig>,
+
+    /// Background git maintenance settings
+    pub maintenance: Option<MaintenanceConfig>,
+
+    /// Which confirmations interactive sessions ask ("never",
+    /// "destructive-only", or "always")
+    pub confirm: Option<String>,
+
+    /// Trunk drift warnings
+    pub drift: Option<DriftConfig>,
+
+    /// Background forge prefetch on navigation
+    pub prefetch: Option<PrefetchConfig>,
+
+    /// HTTP timeouts for forge and auth requests
+    pub network: Option<NetworkConfig>,
+
+    /// On-disk cache limits
+    pub cache: Option<CacheConfig>,
+
+    /// Waiting for CI in `merge --when-green`
+    pub merge: Option<MergeConfig>,
+
+    /// How `sync` updates a trunk that diverged from the remote
+    pub sync: Option<SyncConfig>,
+
+    /// Branch naming for `create`
+    pub create: Option<CreateConfig>,
+
+    /// Per-phase duration budgets for slow-path hints
+    pub budgets: Option<BudgetsConfig>,
+
+    /// Disable every command that changes the repository (for CI jobs and
+    /// bots that only observe)
+    pub read_only: Option<bool>,
+
+    /// Show hints suggesting the next command
+    pub hints: Option<bool>,
 }
 
 impl GlobalConfig {
+    /// Valid confirmation levels.
+    pub const VALID_CONFIRM_LEVELS: &'static [&'static str] =
+        &["never", "destructive-only", "always"];
+
     /// Validate the configuration values.
     ///
     /// # Errors
@@ -80,11 +188,61 @@ impl GlobalConfig {
             }
         }
 
+        // Validate locale tag if specified
+        if let Some(locale) = &self.locale {
+            let valid = !locale.is_empty()
+                && locale
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                return Err(ConfigError::InvalidValue(format!(
+                    "invalid locale '{}', expected a tag like 'en' or 'pt-BR'",
+                    locale
+                )));
+            }
+        }
+
+        // Validate confirmation level if specified
+        if let Some(confirm) = &self.confirm {
+            if !Self::VALID_CONFIRM_LEVELS.contains(&confirm.as_str()) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "invalid confirm level '{}', must be one of: {}",
+                    confirm,
+                    Self::VALID_CONFIRM_LEVELS.join(", ")
+                )));
+            }
+        }
+
         // Validate secrets provider if specified
         if let Some(secrets) = &self.secrets {
             secrets.validate()?;
         }
 
+        // Validate submit parallelism if specified
+        if let Some(submit) = &self.submit {
+            submit.validate()?;
+        }
+
+        // Validate network timeouts if specified
+        if let Some(network) = &self.network {
+            network.validate()?;
+        }
+
+        // Validate merge polling if specified
+        if let Some(merge) = &self.merge {
+            merge.validate()?;
+        }
+
+        // Validate trunk strategy if specified
+        if let Some(sync) = &self.sync {
+            sync.validate()?;
+        }
+
+        // Validate branch template if specified
+        if let Some(create) = &self.create {
+            create.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -97,10 +255,23 @@ impl GlobalConfig {
 /// trunk = "main"
 /// remote = "origin"
 /// sync_metadata_refs = false
+/// sign_metadata = false
+/// read_only = false
 ///
 /// [forge_repo]
 /// owner = "myorg"
 /// repo = "myrepo"
+///
+/// [pr_template]
+/// bottom = "## Context\n\n{description}"
+/// stacked = "Depends on {parent_pr}.\n\n{description}"
+///
+/// [create]
+/// branch_template = "{user}/{date}/{slug}"
+///
+/// [submit]
+/// review_size_warn_lines = 800
+/// review_size_warn_files = 40
 /// ```
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(default, deny_unknown_fields)]
@@ -114,8 +285,28 @@ pub struct RepoConfig {
     /// Whether to sync metadata refs
     pub sync_metadata_refs: Option<bool>,
 
+    /// Whether to sign metadata blobs and verify their signatures on scan
+    pub sign_metadata: Option<bool>,
+
     /// Forge-specific repository identification
     pub forge_repo: Option<ForgeRepoConfig>,
+
+    /// Body templates for new PRs, by position in the stack
+    pub pr_template: Option<PrTemplateConfig>,
+
+    /// Branch naming for `create` (overrides the global setting)
+    pub create: Option<CreateConfig>,
+
+    /// Review-size thresholds for `submit` (override the global settings)
+    pub submit: Option<RepoSubmitConfig>,
+
+    /// Disable every command that changes this repository (overrides the
+    /// global setting)
+    pub read_only: Option<bool>,
+
+    /// URL or `owner/repo` slug the remote must match before commands may
+    /// change this repository
+    pub identity: Option<String>,
 }
 
 impl RepoConfig {
@@ -141,10 +332,35 @@ impl RepoConfig {
             }
         }
 
+        // Validate identity is non-empty if specified
+        if let Some(identity) = &self.identity {
+            if identity.trim().is_empty() {
+                return Err(ConfigError::InvalidValue(
+                    "identity cannot be empty".to_string(),
+                ));
+            }
+        }
+
+        // Validate branch template if specified
+        if let Some(create) = &self.create {
+            create.validate()?;
+        }
+
         Ok(())
     }
 }
 
+/// Per-repository submit settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RepoSubmitConfig {
+    /// Warn when a PR changes more than this many lines (additions + deletions)
+    pub review_size_warn_lines: Option<usize>,
+
+    /// Warn when a PR touches more than this many files
+    pub review_size_warn_files: Option<usize>,
+}
+
 /// Submit command defaults.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(default, deny_unknown_fields)]
@@ -155,8 +371,416 @@ pub struct SubmitDefaults {
     /// Default to restack before submit
     pub restack: Option<bool>,
 
-    /// Default reviewers
-    pub reviewers: Option<Vec<String>>,
+    /// Default reviewers for new PRs, by what each PR changes
+    pub reviewers: Option<ReviewerRules>,
+
+    /// Warn when a PR changes more than this many lines (additions + deletions)
+    pub review_size_warn_lines: Option<usize>,
+
+    /// Warn when a PR touches more than this many files
+    pub review_size_warn_files: Option<usize>,
+
+    /// Block re-pushes while downstack PRs have failing required checks
+    pub require_green_downstack: Option<bool>,
+
+    /// Shell command that prints new PR bodies, given branch JSON on stdin
+    pub body_generator: Option<String>,
+
+    /// Shell command that must pass on each branch before submit pushes
+    pub pre_push: Option<String>,
+
+    /// How many pushes and PR requests submit runs at once
+    pub parallelism: Option<usize>,
+}
+
+impl SubmitDefaults {
+    /// Validate the submit defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if `parallelism` is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.parallelism == Some(0) {
+            return Err(ConfigError::InvalidValue(
+                "submit.parallelism must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Default reviewers for new PRs, CODEOWNERS-style.
+///
+/// `paths` maps globs over the files a PR changes, and `branches` maps
+/// patterns over its branch name, to reviewers. Entries of the form
+/// `team:<slug>` request a team. In patterns, `*` and `?` match within one
+/// path segment and `**` matches across segments, so `**/*.md` matches
+/// Markdown files anywhere.
+///
+/// # Example
+///
+/// ```toml
+/// [submit.reviewers.paths]
+/// "src/forge/**" = ["alice", "team:platform"]
+/// "**/*.md" = ["team:docs"]
+///
+/// [submit.reviewers.branches]
+/// "release/*" = ["bob"]
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReviewerRules {
+    /// Reviewers by changed-file glob
+    pub paths: BTreeMap<String, Vec<String>>,
+
+    /// Reviewers by branch-name pattern
+    pub branches: BTreeMap<String, Vec<String>>,
+}
+
+impl ReviewerRules {
+    /// Reviewers the rules assign to `branch`, given the files it changes.
+    ///
+    /// Branch rules come first, then path rules, each in pattern order;
+    /// duplicates are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::core::config::schema::ReviewerRules;
+    ///
+    /// let rules: ReviewerRules = toml::from_str(r#"
+    ///     [paths]
+    ///     "src/forge/**" = ["alice"]
+    ///     [branches]
+    ///     "release/*" = ["bob", "alice"]
+    /// "#).unwrap();
+    ///
+    /// let changed = vec!["src/forge/github.rs".to_string()];
+    /// assert_eq!(rules.matching("release/1.2", &changed), vec!["bob", "alice"]);
+    /// assert!(rules.matching("feature", &["README.md".to_string()]).is_empty());
+    /// ```
+    pub fn matching(&self, branch: &str, changed_paths: &[String]) -> Vec<String> {
+        let by_branch = self
+            .branches
+            .iter()
+            .filter(|(pattern, _)| glob_match(pattern, branch));
+        let by_path = self
+            .paths
+            .iter()
+            .filter(|(pattern, _)| changed_paths.iter().any(|path| glob_match(pattern, path)));
+
+        let mut reviewers: Vec<String> = Vec::new();
+        for reviewer in by_branch.chain(by_path).flat_map(|(_, names)| names) {
+            if !reviewers.contains(reviewer) {
+                reviewers.push(reviewer.clone());
+            }
+        }
+        reviewers
+    }
+}
+
+/// Match `text` against a glob where `*` and `?` stay within a `/`-separated
+/// segment and `**` spans segments (`**/` also matches no directory).
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    fn matches(p: &[char], t: &[char]) -> bool {
+        match p {
+            [] => t.is_empty(),
+            ['*', '*', rest @ ..] => {
+                if let ['/', after @ ..] = rest {
+                    if matches(after, t) {
+                        return true;
+                    }
+                }
+                (0..=t.len()).any(|i| matches(rest, &t[i..]))
+            }
+            ['*', rest @ ..] => {
+                for i in 0..=t.len() {
+                    if matches(rest, &t[i..]) {
+                        return true;
+                    }
+                    if t.get(i) == Some(&'/') {
+                        break;
+                    }
+                }
+                false
+            }
+            ['?', rest @ ..] => matches!(t, [c, ..] if *c != '/') && matches(rest, &t[1..]),
+            [c, rest @ ..] => t.first() == Some(c) && matches(rest, &t[1..]),
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    matches(&pattern, &text)
+}
+
+/// Restack defaults for rewriting commands.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RestackDefaults {
+    /// Automatically restack descendants after modify/squash
+    pub descendants: Option<bool>,
+
+    /// Record conflict resolutions and reuse them on later rebases (git rerere)
+    pub reuse_resolutions: Option<bool>,
+}
+
+/// Output rendering settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutputConfig {
+    /// Describe branches in words instead of symbols, for screen readers
+    pub accessible: Option<bool>,
+}
+
+/// Background git maintenance settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MaintenanceConfig {
+    /// Run incremental `git maintenance` tasks after large operations
+    pub auto: Option<bool>,
+}
+
+/// Trunk drift warning thresholds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DriftConfig {
+    /// Warn when a stack's base has been behind trunk for more days than this
+    pub warn_days: Option<u64>,
+
+    /// Warn when a stack's base is missing more trunk commits than this
+    pub warn_commits: Option<usize>,
+}
+
+/// Background forge prefetch settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrefetchConfig {
+    /// Fetch PR and check status in the background after navigating
+    pub enabled: Option<bool>,
+
+    /// Seconds before a branch's cached status is fetched again
+    pub interval_secs: Option<u64>,
+}
+
+/// On-disk cache limits.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Ancestry answers kept across runs (0 turns the cache off)
+    pub ancestry_entries: Option<usize>,
+}
+
+/// How long each command phase may take before a hint is printed.
+///
+/// A budget of 0 turns off the hint for that phase.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct BudgetsConfig {
+    /// Milliseconds allowed for reading repository state
+    pub scan_ms: Option<u64>,
+
+    /// Milliseconds allowed for checking a command's requirements
+    pub gate_ms: Option<u64>,
+
+    /// Milliseconds allowed for building a plan
+    pub plan_ms: Option<u64>,
+
+    /// Milliseconds allowed for applying a plan
+    pub execute_ms: Option<u64>,
+}
+
+/// HTTP timeouts for forge and auth requests.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// Seconds allowed for establishing a connection
+    pub connect_timeout_secs: Option<u64>,
+
+    /// Seconds allowed between reads of a response
+    pub read_timeout_secs: Option<u64>,
+
+    /// Seconds allowed for a whole request
+    pub timeout_secs: Option<u64>,
+}
+
+impl NetworkConfig {
+    /// Validate the timeouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if a timeout is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, value) in [
+            ("connect_timeout_secs", self.connect_timeout_secs),
+            ("read_timeout_secs", self.read_timeout_secs),
+            ("timeout_secs", self.timeout_secs),
+        ] {
+            if value == Some(0) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "network.{} must be at least 1",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How `merge --when-green` waits for CI.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MergeConfig {
+    /// Seconds between checks of a PR's CI status
+    pub poll_interval_secs: Option<u64>,
+
+    /// Seconds to wait for one PR's checks before giving up
+    pub wait_timeout_secs: Option<u64>,
+}
+
+impl MergeConfig {
+    /// Validate the polling settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if a setting is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, value) in [
+            ("poll_interval_secs", self.poll_interval_secs),
+            ("wait_timeout_secs", self.wait_timeout_secs),
+        ] {
+            if value == Some(0) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "merge.{} must be at least 1",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How `sync` handles local trunk commits the remote doesn't have.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SyncConfig {
+    /// `ff-only`, `rebase-local`, or `reset`
+    pub trunk_strategy: Option<String>,
+}
+
+impl SyncConfig {
+    /// Valid trunk strategies.
+    pub const VALID_TRUNK_STRATEGIES: &'static [&'static str] =
+        &["ff-only", "rebase-local", "reset"];
+
+    /// Validate the trunk strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` for an unknown strategy.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(strategy) = &self.trunk_strategy {
+            if !Self::VALID_TRUNK_STRATEGIES.contains(&strategy.as_str()) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "invalid sync.trunk_strategy '{}', must be one of: {}",
+                    strategy,
+                    Self::VALID_TRUNK_STRATEGIES.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How `create` names branches it derives a name for.
+///
+/// `branch_template` applies when no name is given and the name comes from
+/// the commit message or an issue. It may use these placeholders:
+///
+/// - `{slug}`: the slugified commit message subject (or `<N>-<title>` for
+///   `--from-issue`); required
+/// - `{user}`: the local part of git's `user.email`, slugified
+/// - `{date}`: today's date as `YYYY-MM-DD`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CreateConfig {
+    /// Template for derived branch names, e.g. `{user}/{date}/{slug}`
+    pub branch_template: Option<String>,
+}
+
+impl CreateConfig {
+    /// Placeholders a branch template may use.
+    pub const PLACEHOLDERS: &'static [&'static str] = &["{slug}", "{user}", "{date}"];
+
+    /// Validate the branch template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if the template lacks `{slug}` or
+    /// uses an unknown placeholder.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let Some(template) = &self.branch_template else {
+            return Ok(());
+        };
+        if !template.contains("{slug}") {
+            return Err(ConfigError::InvalidValue(format!(
+                "create.branch_template '{}' must contain {{slug}}",
+                template
+            )));
+        }
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(placeholder) = Self::PLACEHOLDERS
+                .iter()
+                .find(|p| rest[start..].starts_with(*p))
+            else {
+                return Err(ConfigError::InvalidValue(format!(
+                    "create.branch_template '{}' has an unknown placeholder, must use only: {}",
+                    template,
+                    Self::PLACEHOLDERS.join(", ")
+                )));
+            };
+            rest = &rest[start + placeholder.len()..];
+        }
+        Ok(())
+    }
+}
+
+/// Values for the placeholders of a branch template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchTemplateVars {
+    /// Slug of the commit message or issue.
+    pub slug: String,
+    /// Slug of the local part of `user.email`, if set.
+    pub user: Option<String>,
+    /// Today's date as `YYYY-MM-DD`.
+    pub date: String,
+}
+
+/// Render a `create.branch_template`.
+///
+/// `{user}` falls back to `me` when git has no `user.email`.
+///
+/// # Example
+///
+/// ```
+/// use latticework::core::config::schema::{render_branch_template, BranchTemplateVars};
+///
+/// let vars = BranchTemplateVars {
+///     slug: "fix-login".to_string(),
+///     user: Some("alice".to_string()),
+///     date: "2024-05-01".to_string(),
+/// };
+/// assert_eq!(
+///     render_branch_template("{user}/{date}/{slug}", &vars),
+///     "alice/2024-05-01/fix-login"
+/// );
+/// ```
+pub fn render_branch_template(template: &str, vars: &BranchTemplateVars) -> String {
+    template
+        .replace("{user}", vars.user.as_deref().unwrap_or("me"))
+        .replace("{date}", &vars.date)
+        .replace("{slug}", &vars.slug)
 }
 
 /// Secrets configuration.
@@ -197,6 +821,97 @@ pub struct ForgeRepoConfig {
     pub repo: Option<String>,
 }
 
+/// Title and body templates for new PRs.
+///
+/// `bottom` applies to PRs whose parent is trunk and `stacked` to PRs
+/// based on another tracked branch; `title` applies to every PR. Templates
+/// may use these placeholders:
+///
+/// - `{branch}`: the PR's branch
+/// - `{parent}`: the parent branch
+/// - `{parent_pr}`: the parent's PR as `#N`, or the parent branch name
+///   before it has one
+/// - `{description}`: the branch description
+/// - `{commits}`: the branch's commit subjects, oldest first, as a
+///   Markdown list
+/// - `{stack_position}`: the branch's place in its stack as `N/M`, counting
+///   from the bottom
+///
+/// Templates can also live in `.lattice/templates/pr.md` (see
+/// [`PrTemplateConfig::from_markdown`]); templates set here take precedence.
+///
+/// # Example
+///
+/// ```toml
+/// [pr_template]
+/// title = "[{stack_position}] {branch}"
+/// bottom = "## Context\n\n{description}"
+/// stacked = "Depends on {parent_pr}.\n\n{description}"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrTemplateConfig {
+    /// Template for PR titles
+    pub title: Option<String>,
+
+    /// Template for the PR at the bottom of a stack
+    pub bottom: Option<String>,
+
+    /// Template for PRs stacked on another branch
+    pub stacked: Option<String>,
+}
+
+impl PrTemplateConfig {
+    /// Get the template for a PR at the given position.
+    pub fn for_position(&self, bottom: bool) -> Option<&str> {
+        if bottom {
+            self.bottom.as_deref()
+        } else {
+            self.stacked.as_deref()
+        }
+    }
+
+    /// Parse a template file.
+    ///
+    /// The file is the body template for every position. If its first line
+    /// is a `# ` heading, the heading text is the title template and the
+    /// rest of the file is the body.
+    ///
+    /// ```
+    /// use latticework::core::config::schema::PrTemplateConfig;
+    ///
+    /// let templates = PrTemplateConfig::from_markdown("# {branch}\n\n{commits}\n");
+    /// assert_eq!(templates.title.as_deref(), Some("{branch}"));
+    /// assert_eq!(templates.for_position(true), Some("{commits}"));
+    /// ```
+    pub fn from_markdown(text: &str) -> Self {
+        let (title, body) = match text.split_once('\n') {
+            Some((first, rest)) if first.starts_with("# ") => (Some(first), rest),
+            None if text.starts_with("# ") => (Some(text), ""),
+            _ => (None, text),
+        };
+        let title = title
+            .map(|line| line[2..].trim().to_string())
+            .filter(|t| !t.is_empty());
+        let body = body.trim();
+        let body = (!body.is_empty()).then(|| body.to_string());
+        Self {
+            title,
+            bottom: body.clone(),
+            stacked: body,
+        }
+    }
+
+    /// Fill templates this one doesn't set from `fallback`.
+    pub fn or(self, fallback: PrTemplateConfig) -> Self {
+        Self {
+            title: self.title.or(fallback.title),
+            bottom: self.bottom.or(fallback.bottom),
+            stacked: self.stacked.or(fallback.stacked),
+        }
+    }
+}
+
 /// Doctor command configuration.
 ///
 /// # Example
@@ -300,6 +1015,124 @@ mod tests {
             assert!(config.validate().is_ok());
         }
 
+        #[test]
+        fn locale_tags() {
+            let config = GlobalConfig {
+                locale: Some("pt-BR".to_string()),
+                ..Default::default()
+            };
+            assert!(config.validate().is_ok());
+
+            let config = GlobalConfig {
+                locale: Some("en US".to_string()),
+                ..Default::default()
+            };
+            assert!(config.validate().is_err());
+        }
+
+        #[test]
+        fn confirm_levels() {
+            for level in GlobalConfig::VALID_CONFIRM_LEVELS {
+                let config = GlobalConfig {
+                    confirm: Some(level.to_string()),
+                    ..Default::default()
+                };
+                assert!(config.validate().is_ok());
+            }
+
+            let config = GlobalConfig {
+                confirm: Some("sometimes".to_string()),
+                ..Default::default()
+            };
+            assert!(config.validate().is_err());
+        }
+
+        #[test]
+        fn submit_parallelism_must_be_positive() {
+            let config = GlobalConfig {
+                submit: Some(SubmitDefaults {
+                    parallelism: Some(0),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            assert!(config.validate().is_err());
+        }
+
+        #[test]
+        fn network_timeouts_must_be_positive() {
+            let config = GlobalConfig {
+                network: Some(NetworkConfig {
+                    connect_timeout_secs: Some(5),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            assert!(config.validate().is_ok());
+
+            let config = GlobalConfig {
+                network: Some(NetworkConfig {
+                    timeout_secs: Some(0),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("network.timeout_secs"));
+        }
+
+        #[test]
+        fn merge_polling_must_be_positive() {
+            let config = GlobalConfig {
+                merge: Some(MergeConfig {
+                    poll_interval_secs: Some(0),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("merge.poll_interval_secs"));
+        }
+
+        #[test]
+        fn trunk_strategies() {
+            for strategy in SyncConfig::VALID_TRUNK_STRATEGIES {
+                let config = GlobalConfig {
+                    sync: Some(SyncConfig {
+                        trunk_strategy: Some(strategy.to_string()),
+                    }),
+                    ..Default::default()
+                };
+                assert!(config.validate().is_ok(), "{} should be valid", strategy);
+            }
+
+            let config = GlobalConfig {
+                sync: Some(SyncConfig {
+                    trunk_strategy: Some("merge".to_string()),
+                }),
+                ..Default::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains("sync.trunk_strategy"));
+        }
+
+        #[test]
+        fn branch_templates() {
+            let template = |t: &str| GlobalConfig {
+                create: Some(CreateConfig {
+                    branch_template: Some(t.to_string()),
+                }),
+                ..Default::default()
+            };
+            assert!(template("{user}/{date}/{slug}").validate().is_ok());
+
+            let err = template("{user}/{date}").validate().unwrap_err();
+            assert!(err.to_string().contains("{slug}"));
+
+            let err = template("{team}/{slug}").validate().unwrap_err();
+            assert!(err.to_string().contains("unknown placeholder"));
+        }
+
         #[test]
         fn invalid_forge() {
             let config = GlobalConfig {
@@ -309,6 +1142,15 @@ mod tests {
             assert!(config.validate().is_err());
         }
 
+        #[test]
+        fn empty_identity_invalid() {
+            let config = RepoConfig {
+                identity: Some(" ".to_string()),
+                ..Default::default()
+            };
+            assert!(config.validate().is_err());
+        }
+
         #[test]
         fn roundtrip() {
             let config = GlobalConfig {
@@ -318,12 +1160,68 @@ mod tests {
                 submit: Some(SubmitDefaults {
                     draft: Some(true),
                     restack: Some(true),
-                    reviewers: Some(vec!["alice".to_string()]),
+                    reviewers: Some(ReviewerRules {
+                        paths: BTreeMap::from([(
+                            "src/forge/**".to_string(),
+                            vec!["alice".to_string(), "team:platform".to_string()],
+                        )]),
+                        branches: BTreeMap::new(),
+                    }),
+                    review_size_warn_lines: Some(400),
+                    review_size_warn_files: None,
+                    require_green_downstack: Some(true),
+                    body_generator: Some("scripts/pr-body.sh".to_string()),
+                    pre_push: Some("cargo test".to_string()),
+                    parallelism: Some(4),
+                }),
+                restack: Some(RestackDefaults {
+                    descendants: Some(false),
+                    reuse_resolutions: Some(true),
                 }),
                 secrets: Some(SecretsConfig {
                     provider: Some("file".to_string()),
                 }),
                 doctor: None,
+                locale: Some("en".to_string()),
+                output: Some(OutputConfig {
+                    accessible: Some(true),
+                }),
+                maintenance: Some(MaintenanceConfig { auto: Some(false) }),
+                confirm: Some("destructive-only".to_string()),
+                drift: Some(DriftConfig {
+                    warn_days: Some(7),
+                    warn_commits: Some(0),
+                }),
+                prefetch: Some(PrefetchConfig {
+                    enabled: Some(false),
+                    interval_secs: Some(300),
+                }),
+                network: Some(NetworkConfig {
+                    connect_timeout_secs: Some(5),
+                    read_timeout_secs: None,
+                    timeout_secs: Some(120),
+                }),
+                cache: Some(CacheConfig {
+                    ancestry_entries: Some(500),
+                }),
+                merge: Some(MergeConfig {
+                    poll_interval_secs: Some(10),
+                    wait_timeout_secs: None,
+                }),
+                sync: Some(SyncConfig {
+                    trunk_strategy: Some("rebase-local".to_string()),
+                }),
+                create: Some(CreateConfig {
+                    branch_template: Some("{user}/{slug}".to_string()),
+                }),
+                budgets: Some(BudgetsConfig {
+                    scan_ms: Some(5000),
+                    gate_ms: None,
+                    plan_ms: Some(0),
+                    execute_ms: None,
+                }),
+                read_only: Some(false),
+                hints: Some(true),
             };
 
             let toml = toml::to_string_pretty(&config).unwrap();
@@ -375,10 +1273,25 @@ mod tests {
                 trunk: Some("main".to_string()),
                 remote: Some("origin".to_string()),
                 sync_metadata_refs: Some(false),
+                sign_metadata: Some(true),
                 forge_repo: Some(ForgeRepoConfig {
                     owner: Some("myorg".to_string()),
                     repo: Some("myrepo".to_string()),
                 }),
+                pr_template: Some(PrTemplateConfig {
+                    title: Some("{branch}".to_string()),
+                    bottom: Some("{description}".to_string()),
+                    stacked: Some("Depends on {parent_pr}.".to_string()),
+                }),
+                create: Some(CreateConfig {
+                    branch_template: Some("{user}/{date}/{slug}".to_string()),
+                }),
+                submit: Some(RepoSubmitConfig {
+                    review_size_warn_lines: Some(800),
+                    review_size_warn_files: None,
+                }),
+                read_only: Some(true),
+                identity: Some("myorg/myrepo".to_string()),
             };
 
             let toml = toml::to_string_pretty(&config).unwrap();
@@ -386,6 +1299,42 @@ mod tests {
             assert_eq!(config, parsed);
         }
 
+        #[test]
+        fn pr_template_by_position() {
+            let templates = PrTemplateConfig {
+                bottom: Some("bottom".to_string()),
+                ..Default::default()
+            };
+            assert_eq!(templates.for_position(true), Some("bottom"));
+            assert_eq!(templates.for_position(false), None);
+        }
+
+        #[test]
+        fn pr_template_file_without_title_is_all_body() {
+            let templates = PrTemplateConfig::from_markdown("## Summary\n\n{description}\n");
+            assert_eq!(templates.title, None);
+            assert_eq!(
+                templates.for_position(false),
+                Some("## Summary\n\n{description}")
+            );
+
+            let title_only = PrTemplateConfig::from_markdown("# {branch}");
+            assert_eq!(title_only.title.as_deref(), Some("{branch}"));
+            assert_eq!(title_only.bottom, None);
+        }
+
+        #[test]
+        fn pr_template_config_overrides_file() {
+            let config = PrTemplateConfig {
+                stacked: Some("config".to_string()),
+                ..Default::default()
+            };
+            let merged = config.or(PrTemplateConfig::from_markdown("# file title\nfile body"));
+            assert_eq!(merged.title.as_deref(), Some("file title"));
+            assert_eq!(merged.for_position(true), Some("file body"));
+            assert_eq!(merged.for_position(false), Some("config"));
+        }
+
         #[test]
         fn reject_unknown_fields() {
             let toml = r#"
@@ -398,6 +1347,39 @@ mod tests {
         }
     }
 
+    mod reviewer_rules {
+        use super::*;
+
+        #[test]
+        fn globs_respect_segments() {
+            assert!(glob_match("src/*.rs", "src/lib.rs"));
+            assert!(!glob_match("src/*.rs", "src/forge/github.rs"));
+            assert!(glob_match("src/**", "src/forge/github.rs"));
+            assert!(glob_match("**/*.md", "README.md"));
+            assert!(glob_match("**/*.md", "docs/guide/intro.md"));
+            assert!(glob_match("release/v?", "release/v2"));
+            assert!(!glob_match("release/v?", "release/v10"));
+        }
+
+        #[test]
+        fn parses_submit_section() {
+            let toml = r#"
+                [submit.reviewers.paths]
+                "src/forge/**" = ["alice", "team:platform"]
+
+                [submit.reviewers.branches]
+                "release/*" = ["bob"]
+            "#;
+
+            let config: GlobalConfig = toml::from_str(toml).unwrap();
+            let rules = config.submit.unwrap().reviewers.unwrap();
+            assert_eq!(
+                rules.matching("release/1.0", &["src/forge/mock.rs".to_string()]),
+                vec!["bob", "alice", "team:platform"]
+            );
+        }
+    }
+
     mod secrets_config {
         use super::*;
 
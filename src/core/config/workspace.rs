@@ -0,0 +1,231 @@
+//! core::config::workspace
+//!
+//! Workspaces: related repositories whose stacks are worked on together.
+//!
+//! # Overview
+//!
+//! A workspace file lists member repositories. `lattice ws sync` and
+//! `lattice ws log` run `sync` and `log` in each member, in the order
+//! listed, so a change that spans several repositories can be kept up to
+//! date and reviewed from one place.
+//!
+//! # Location
+//!
+//! Searched in order:
+//! 1. The path given with `lattice ws --file`
+//! 2. `$LATTICE_WORKSPACE` if set
+//! 3. `lattice-workspace.toml` in the current directory or any parent
+//!
+//! Relative member paths are resolved against the directory holding the
+//! workspace file, so the file can be checked in beside the repositories.
+//!
+//! # Example
+//!
+//! ```toml
+//! [[repo]]
+//! path = "api"
+//!
+//! [[repo]]
+//! path = "../shared/proto"
+//! name = "proto"
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::ConfigError;
+
+/// File name searched for in the current directory and its parents.
+pub const WORKSPACE_FILE: &str = "lattice-workspace.toml";
+
+/// Workspace file contents.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkspaceConfig {
+    /// Member repositories, in the order commands visit them.
+    #[serde(rename = "repo")]
+    pub repos: Vec<WorkspaceRepo>,
+}
+
+/// One member repository in the workspace file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceRepo {
+    /// Path to the repository, absolute or relative to the workspace file.
+    pub path: PathBuf,
+    /// Name shown in output; defaults to the directory name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A loaded workspace, with member paths resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    /// The workspace file.
+    pub file: PathBuf,
+    /// Member repositories, in the order listed.
+    pub members: Vec<WorkspaceMember>,
+}
+
+/// A member repository of a loaded workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// Name shown in output.
+    pub name: String,
+    /// Path to the repository.
+    pub path: PathBuf,
+}
+
+impl Workspace {
+    /// Find the workspace file for `cwd`.
+    ///
+    /// `file` (from `--file`) wins, then `$LATTICE_WORKSPACE`, then the
+    /// nearest `lattice-workspace.toml` at or above `cwd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if no workspace file is found.
+    pub fn locate(file: Option<&Path>, cwd: &Path) -> Result<PathBuf, ConfigError> {
+        if let Some(file) = file {
+            return Ok(cwd.join(file));
+        }
+        if let Ok(path) = std::env::var("LATTICE_WORKSPACE") {
+            return Ok(cwd.join(path));
+        }
+        cwd.ancestors()
+            .map(|dir| dir.join(WORKSPACE_FILE))
+            .find(|path| path.is_file())
+            .ok_or_else(|| {
+                ConfigError::InvalidValue(format!(
+                    "no {} found in '{}' or its parents (pass --file or set LATTICE_WORKSPACE)",
+                    WORKSPACE_FILE,
+                    cwd.display()
+                ))
+            })
+    }
+
+    /// Load and validate the workspace file at `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed, lists no
+    /// repositories, or gives two members the same name.
+    pub fn load(file: &Path) -> Result<Self, ConfigError> {
+        let contents = fs::read_to_string(file).map_err(|e| ConfigError::ReadError {
+            path: file.to_path_buf(),
+            source: e,
+        })?;
+        let config: WorkspaceConfig =
+            toml::from_str(&contents).map_err(|e| ConfigError::ParseError {
+                path: file.to_path_buf(),
+                message: e.to_string(),
+            })?;
+        Self::resolve(file, config)
+    }
+
+    /// Resolve member paths and names against the workspace file.
+    fn resolve(file: &Path, config: WorkspaceConfig) -> Result<Self, ConfigError> {
+        if config.repos.is_empty() {
+            return Err(ConfigError::InvalidValue(format!(
+                "workspace '{}' lists no repositories; add a [[repo]] entry",
+                file.display()
+            )));
+        }
+
+        let root = file.parent().unwrap_or(Path::new("."));
+        let mut members: Vec<WorkspaceMember> = Vec::new();
+        for repo in config.repos {
+            let path = root.join(&repo.path);
+            let name = match repo.name {
+                Some(name) if name.trim().is_empty() => {
+                    return Err(ConfigError::InvalidValue(format!(
+                        "workspace repo '{}' has an empty name",
+                        repo.path.display()
+                    )));
+                }
+                Some(name) => name,
+                None => repo
+                    .path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| repo.path.display().to_string()),
+            };
+            if members.iter().any(|m| m.name == name) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "workspace lists two repos named '{}'; give one a different name",
+                    name
+                )));
+            }
+            members.push(WorkspaceMember { name, path });
+        }
+        Ok(Self {
+            file: file.to_path_buf(),
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn members_resolve_against_the_workspace_file() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join(WORKSPACE_FILE);
+        fs::write(
+            &file,
+            "[[repo]]\npath = \"api\"\n\n[[repo]]\npath = \"../shared/proto\"\nname = \"schemas\"\n",
+        )
+        .unwrap();
+
+        let workspace = Workspace::load(&file).unwrap();
+        assert_eq!(
+            workspace.members,
+            vec![
+                WorkspaceMember {
+                    name: "api".to_string(),
+                    path: dir.path().join("api"),
+                },
+                WorkspaceMember {
+                    name: "schemas".to_string(),
+                    path: dir.path().join("../shared/proto"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_workspaces_are_rejected() {
+        let file = Path::new("/ws/lattice-workspace.toml");
+        assert!(Workspace::resolve(file, WorkspaceConfig::default()).is_err());
+
+        let config: WorkspaceConfig =
+            toml::from_str("[[repo]]\npath = \"a/api\"\n\n[[repo]]\npath = \"b/api\"\n").unwrap();
+        let err = Workspace::resolve(file, config).unwrap_err();
+        assert!(err.to_string().contains("two repos named 'api'"), "{}", err);
+
+        assert!(
+            toml::from_str::<WorkspaceConfig>("[[repo]]\npath = \"a\"\nbranch = \"x\"\n").is_err()
+        );
+    }
+
+    #[test]
+    fn locate_searches_parent_directories() {
+        let dir = TempDir::new().unwrap();
+        let nested = dir.path().join("api").join("src");
+        fs::create_dir_all(&nested).unwrap();
+        assert!(Workspace::locate(None, &nested).is_err());
+
+        let file = dir.path().join(WORKSPACE_FILE);
+        fs::write(&file, "[[repo]]\npath = \"api\"\n").unwrap();
+        assert_eq!(Workspace::locate(None, &nested).unwrap(), file);
+        assert_eq!(
+            Workspace::locate(Some(Path::new("other.toml")), &nested).unwrap(),
+            nested.join("other.toml")
+        );
+    }
+}
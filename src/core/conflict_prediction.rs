@@ -0,0 +1,227 @@
+//! core::conflict_prediction
+//!
+//! Prediction of which branches a restack or reorder will stop on.
+//!
+//! # Design
+//!
+//! Each planned rebase is replayed in memory with [`Git::replay_commits`],
+//! so nothing in the working tree, index, or refs changes. Rebases are
+//! given parents first. A branch whose parent is rebased earlier in the
+//! same plan is replayed onto the tree the parent would end with, so the
+//! whole stack is checked as it will be after the operation, not as it is
+//! now.
+//!
+//! When a parent is predicted to conflict, what its children apply onto
+//! depends on how that conflict gets resolved. They are reported as
+//! [`Prediction::Blocked`] rather than guessed at.
+//!
+//! The replay uses the same three-way merge as `git rebase`, so textual
+//! conflicts are predicted exactly. Resolutions recorded by `git rerere`
+//! and commits that rebase drops because they are already upstream are
+//! not taken into account; both can only make the real rebase go more
+//! smoothly than predicted.
+
+use std::collections::HashMap;
+
+use crate::core::types::{BranchName, Oid};
+use crate::git::{ConflictedFile, Git, GitError, ReplayOutcome};
+
+/// A rebase an operation is about to perform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedRebase {
+    /// Branch being rebased
+    pub branch: BranchName,
+    /// Where the branch's own commits start (its recorded base)
+    pub base: Oid,
+    /// The branch's tip
+    pub tip: Oid,
+    /// Parent the branch ends up on
+    pub parent: BranchName,
+    /// The parent's tip now, used unless the parent is also rebased
+    pub onto: Oid,
+}
+
+/// Predicted outcome of one rebase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Prediction {
+    /// The rebase applies cleanly
+    Clean,
+    /// The rebase stops on a conflict
+    Conflict {
+        /// First commit that conflicts
+        commit: Oid,
+        /// Its summary line
+        summary: String,
+        /// Paths that conflict, sorted by path
+        files: Vec<ConflictedFile>,
+    },
+    /// An ancestor's rebase conflicts first, so this one can't be predicted
+    Blocked {
+        /// The conflicting ancestor
+        by: BranchName,
+    },
+}
+
+/// Prediction for one branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchPrediction {
+    /// The branch
+    pub branch: BranchName,
+    /// What its rebase will do
+    pub outcome: Prediction,
+}
+
+/// Predict the outcome of `rebases`, which must be ordered parents first.
+///
+/// Returns one prediction per rebase, in the same order.
+pub fn predict(git: &Git, rebases: &[PlannedRebase]) -> Result<Vec<BranchPrediction>, GitError> {
+    // Tree each rebased branch would end with, or the branch it's blocked by
+    let mut results: HashMap<&BranchName, Result<Oid, BranchName>> = HashMap::new();
+    let mut predictions = Vec::with_capacity(rebases.len());
+
+    for rebase in rebases {
+        let onto_tree = match results.get(&rebase.parent) {
+            Some(Ok(tree)) => Ok(tree.clone()),
+            Some(Err(by)) => Err(by.clone()),
+            None => Ok(git.commit_tree(&rebase.onto)?),
+        };
+
+        let (outcome, result) = match onto_tree {
+            Err(by) => (Prediction::Blocked { by: by.clone() }, Err(by)),
+            Ok(onto_tree) => match git.replay_commits(&rebase.base, &rebase.tip, &onto_tree)? {
+                ReplayOutcome::Clean { tree } => (Prediction::Clean, Ok(tree)),
+                ReplayOutcome::Conflict { commit, files } => {
+                    let summary = git.commit_info(&commit)?.summary;
+                    (
+                        Prediction::Conflict {
+                            commit,
+                            summary,
+                            files,
+                        },
+                        Err(rebase.branch.clone()),
+                    )
+                }
+            },
+        };
+        results.insert(&rebase.branch, result);
+        predictions.push(BranchPrediction {
+            branch: rebase.branch.clone(),
+            outcome,
+        });
+    }
+
+    Ok(predictions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::ConflictKind;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    fn commit_file(dir: &Path, file: &str, content: &str) -> Oid {
+        std::fs::write(dir.join(file), content).unwrap();
+        run_git(dir, &["add", file]);
+        run_git(dir, &["commit", "-q", "-m", file]);
+        Oid::new(run_git(dir, &["rev-parse", "HEAD"])).unwrap()
+    }
+
+    fn branch(name: &str) -> BranchName {
+        BranchName::new(name).unwrap()
+    }
+
+    /// `main` with `a` (editing shared.txt) and `b` on `a` (adding b.txt),
+    /// then main moves on by editing `main_file`.
+    fn repo(main_file: &str) -> (TempDir, Vec<PlannedRebase>) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path();
+        run_git(path, &["init", "-q", "-b", "main"]);
+        run_git(path, &["config", "user.email", "test@example.com"]);
+        run_git(path, &["config", "user.name", "Test User"]);
+        commit_file(path, "other.txt", "original\n");
+        let base = commit_file(path, "shared.txt", "original\n");
+        run_git(path, &["checkout", "-q", "-b", "a"]);
+        let a_tip = commit_file(path, "shared.txt", "from a\n");
+        run_git(path, &["checkout", "-q", "-b", "b"]);
+        let b_tip = commit_file(path, "b.txt", "b\n");
+        run_git(path, &["checkout", "-q", "main"]);
+        let main_tip = commit_file(path, main_file, "from main\n");
+
+        let rebases = vec![
+            PlannedRebase {
+                branch: branch("a"),
+                base,
+                tip: a_tip.clone(),
+                parent: branch("main"),
+                onto: main_tip,
+            },
+            PlannedRebase {
+                branch: branch("b"),
+                base: a_tip.clone(),
+                tip: b_tip,
+                parent: branch("a"),
+                onto: a_tip,
+            },
+        ];
+        (dir, rebases)
+    }
+
+    #[test]
+    fn conflicts_are_reported_with_their_files() {
+        let (dir, rebases) = repo("shared.txt");
+        let git = Git::open(dir.path()).unwrap();
+        let status = run_git(dir.path(), &["status", "--porcelain"]);
+
+        let predictions = predict(&git, &rebases).unwrap();
+        match &predictions[0].outcome {
+            Prediction::Conflict { summary, files, .. } => {
+                assert_eq!(summary, "shared.txt");
+                assert_eq!(files.len(), 1);
+                assert_eq!(files[0].path, "shared.txt");
+                assert_eq!(files[0].kind, ConflictKind::BothModified);
+            }
+            other => panic!("expected a conflict, got {:?}", other),
+        }
+        assert_eq!(
+            predictions[1].outcome,
+            Prediction::Blocked { by: branch("a") }
+        );
+
+        // Nothing was touched
+        assert_eq!(run_git(dir.path(), &["status", "--porcelain"]), status);
+        assert_eq!(run_git(dir.path(), &["branch", "--show-current"]), "main");
+    }
+
+    #[test]
+    fn children_replay_onto_their_parents_result() {
+        let (dir, mut rebases) = repo("other.txt");
+        let git = Git::open(dir.path()).unwrap();
+        assert!(predict(&git, &rebases)
+            .unwrap()
+            .iter()
+            .all(|p| p.outcome == Prediction::Clean));
+
+        // b now edits the file main changed: fine on a as it is, but not
+        // on a once rebased onto main
+        run_git(dir.path(), &["checkout", "-q", "b"]);
+        rebases[1].tip = commit_file(dir.path(), "other.txt", "from b\n");
+        let predictions = predict(&git, &rebases).unwrap();
+        assert_eq!(predictions[0].outcome, Prediction::Clean);
+        match &predictions[1].outcome {
+            Prediction::Conflict { files, .. } => assert_eq!(files[0].path, "other.txt"),
+            other => panic!("expected a conflict, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! core::merge_detect
+//!
+//! Detection of branches whose changes already landed on trunk.
+//!
+//! # Design
+//!
+//! A branch merged with a merge commit or fast-forward is easy to spot: its
+//! tip is reachable from trunk. Squash and rebase merges rewrite the
+//! commits, so the tip never reaches trunk even though its changes did.
+//! [`detect`] looks for those changes instead, cheapest check first:
+//!
+//! 1. [`MergeEvidence::Reachable`]: the tip is an ancestor of trunk.
+//! 2. [`MergeEvidence::SquashCommit`]: a trunk commit since the branch
+//!    forked has the same patch ID as the branch's whole diff, which is what
+//!    a squash merge produces. Only the newest [`MAX_TRUNK_COMMITS`] trunk
+//!    commits are compared.
+//! 3. [`MergeEvidence::ContentsPresent`]: merging the branch into trunk
+//!    leaves trunk's tree unchanged, so every change is already there. This
+//!    catches squash merges that were edited or conflicted on the way in.
+//!
+//! A branch with no changes is never reported: trivially, all of its
+//! (nonexistent) changes are on trunk.
+
+use crate::core::types::Oid;
+use crate::git::{Git, GitError};
+
+/// Number of trunk commits compared by patch ID before falling back to the
+/// tree comparison.
+pub const MAX_TRUNK_COMMITS: usize = 500;
+
+/// Why a branch counts as merged into trunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeEvidence {
+    /// The branch tip is reachable from trunk
+    Reachable,
+    /// This trunk commit applies the same changes as the whole branch
+    SquashCommit(Oid),
+    /// Trunk already contains every change on the branch
+    ContentsPresent,
+}
+
+impl MergeEvidence {
+    /// Short description for reports, e.g. `squash-merged as abc1234`.
+    pub fn describe(&self) -> String {
+        match self {
+            MergeEvidence::Reachable => "merged".to_string(),
+            MergeEvidence::SquashCommit(oid) => format!("squash-merged as {}", oid.short(7)),
+            MergeEvidence::ContentsPresent => "changes already on trunk".to_string(),
+        }
+    }
+}
+
+/// Decide whether the changes from `base` to `tip` are on `trunk_tip`.
+///
+/// `base` is where the branch starts (its recorded base), so only the
+/// branch's own changes are compared, not its parent's.
+pub fn detect(
+    git: &Git,
+    base: &Oid,
+    tip: &Oid,
+    trunk_tip: &Oid,
+) -> Result<Option<MergeEvidence>, GitError> {
+    let Some(branch_patch) = git.patch_id(base, tip)? else {
+        return Ok(None);
+    };
+    if git.is_ancestor(tip, trunk_tip)? {
+        return Ok(Some(MergeEvidence::Reachable));
+    }
+
+    if let Some(fork) = git.merge_base(tip, trunk_tip)? {
+        for commit in git
+            .commits_between(&fork, trunk_tip)?
+            .into_iter()
+            .take(MAX_TRUNK_COMMITS)
+        {
+            let parents = git.commit_parents(&commit.oid)?;
+            let [parent] = parents.as_slice() else {
+                continue;
+            };
+            if git.patch_id(parent, &commit.oid)?.as_ref() == Some(&branch_patch) {
+                return Ok(Some(MergeEvidence::SquashCommit(commit.oid)));
+            }
+        }
+    }
+
+    let trunk_tree = git.commit_tree(trunk_tip)?;
+    if git.merge_trees(base, trunk_tip, tip)?.as_ref() == Some(&trunk_tree) {
+        return Ok(Some(MergeEvidence::ContentsPresent));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    fn commit_file(dir: &Path, file: &str, content: &str) -> Oid {
+        std::fs::write(dir.join(file), content).unwrap();
+        run_git(dir, &["add", file]);
+        run_git(dir, &["commit", "-q", "-m", file]);
+        Oid::new(run_git(dir, &["rev-parse", "HEAD"])).unwrap()
+    }
+
+    /// A repo with `main` at a base commit and `feature` adding two files.
+    fn repo() -> (TempDir, Oid, Oid) {
+        let dir = TempDir::new().unwrap();
+        run_git(dir.path(), &["init", "-q", "-b", "main"]);
+        run_git(dir.path(), &["config", "user.email", "test@example.com"]);
+        run_git(dir.path(), &["config", "user.name", "Test User"]);
+        let base = commit_file(dir.path(), "README.md", "# Test\n");
+        run_git(dir.path(), &["checkout", "-q", "-b", "feature"]);
+        commit_file(dir.path(), "a.txt", "a\n");
+        let tip = commit_file(dir.path(), "b.txt", "b\n");
+        run_git(dir.path(), &["checkout", "-q", "main"]);
+        (dir, base, tip)
+    }
+
+    #[test]
+    fn squash_merge_is_found_by_patch_id() {
+        let (dir, base, tip) = repo();
+        commit_file(dir.path(), "other.txt", "other\n");
+        run_git(dir.path(), &["merge", "-q", "--squash", "feature"]);
+        run_git(dir.path(), &["commit", "-q", "-m", "Feature (#1)"]);
+        let squash = Oid::new(run_git(dir.path(), &["rev-parse", "HEAD"])).unwrap();
+        commit_file(dir.path(), "later.txt", "later\n");
+        let trunk = Oid::new(run_git(dir.path(), &["rev-parse", "HEAD"])).unwrap();
+
+        let git = Git::open(dir.path()).unwrap();
+        assert_eq!(
+            detect(&git, &base, &tip, &trunk).unwrap(),
+            Some(MergeEvidence::SquashCommit(squash))
+        );
+    }
+
+    #[test]
+    fn edited_squash_is_found_by_contents() {
+        let (dir, base, tip) = repo();
+        // Landed in two commits, together with an unrelated change
+        commit_file(dir.path(), "a.txt", "a\n");
+        std::fs::write(dir.path().join("unrelated.txt"), "x\n").unwrap();
+        run_git(dir.path(), &["add", "unrelated.txt"]);
+        let trunk = commit_file(dir.path(), "b.txt", "b\n");
+
+        let git = Git::open(dir.path()).unwrap();
+        assert_eq!(
+            detect(&git, &base, &tip, &trunk).unwrap(),
+            Some(MergeEvidence::ContentsPresent)
+        );
+    }
+
+    #[test]
+    fn unmerged_and_empty_branches_are_not_merged() {
+        let (dir, base, tip) = repo();
+        let trunk = commit_file(dir.path(), "a.txt", "a\n");
+
+        let git = Git::open(dir.path()).unwrap();
+        // Only half of the branch landed
+        assert_eq!(detect(&git, &base, &tip, &trunk).unwrap(), None);
+        // No changes at all
+        assert_eq!(detect(&git, &base, &base, &trunk).unwrap(), None);
+        // Fast-forwarded
+        assert_eq!(
+            detect(&git, &base, &tip, &tip).unwrap(),
+            Some(MergeEvidence::Reachable)
+        );
+    }
+}
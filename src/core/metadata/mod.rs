@@ -4,13 +4,14 @@
 //!
 //! # Modules
 //!
-//! - [`schema`] - Metadata schema types (v1)
+//! - [`schema`] - Metadata schema types (v2, reading v1)
 //! - [`store`] - Metadata storage in refs
 //!
 //! # Architecture
 //!
 //! Metadata is stored as Git refs under `refs/branch-metadata/<branch>`.
-//! Each ref points to a blob containing JSON.
+//! Each ref points to a blob containing JSON, optionally followed by a
+//! signature (see [`store`]).
 //!
 //! # Schema Design
 //!
@@ -21,14 +22,14 @@
 //! # Example
 //!
 //! ```
-//! use latticework::core::metadata::schema::{BranchMetadataV1, parse_metadata};
+//! use latticework::core::metadata::schema::{BranchMetadataV2, parse_metadata};
 //! use latticework::core::types::{BranchName, Oid};
 //!
 //! let branch = BranchName::new("feature").unwrap();
 //! let parent = BranchName::new("main").unwrap();
 //! let base = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
 //!
-//! let meta = BranchMetadataV1::new(branch, parent, base);
+//! let meta = BranchMetadataV2::new(branch, parent, base);
 //! let json = serde_json::to_string(&meta).unwrap();
 //! let parsed = parse_metadata(&json).unwrap();
 //! ```
@@ -38,7 +39,11 @@ pub mod store;
 
 // Re-export commonly used types
 pub use schema::{
-    parse_metadata, BranchMetadataV1, FreezeScope, FreezeState, MetadataError, ParentInfo, PrState,
-    StructuralMetadata, METADATA_KIND, SCHEMA_VERSION,
+    parse_metadata, parse_metadata_versioned, BranchMetadataV1, BranchMetadataV2, FreezeScope,
+    FreezeState, MetadataError, ParentInfo, PrState, StructuralMetadata, SubmitSnapshot,
+    METADATA_KIND, MIN_SCHEMA_VERSION, SCHEMA_VERSION,
+};
+pub use store::{
+    MetadataEntry, MetadataSignature, MetadataStore, StoreError, METADATA_REF_PREFIX,
+    SIGNATURE_NAMESPACE,
 };
-pub use store::{MetadataEntry, MetadataStore, StoreError, METADATA_REF_PREFIX};
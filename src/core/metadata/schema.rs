@@ -1,6 +1,6 @@
 //! core::metadata::schema
 //!
-//! Branch metadata schema (v1).
+//! Branch metadata schema (v2).
 //!
 //! # Schema Design
 //!
@@ -15,10 +15,17 @@
 //! - **Structural**: parent, base, frozen (correctness-critical)
 //! - **Cached**: PR linkage (may be stale, never justifies structural changes)
 //!
+//! # Versions
+//!
+//! v2 adds optional fields for newer features: a PR cache timestamp, freeze
+//! conditions and the last submit snapshot. v1 metadata is still read:
+//! [`parse_metadata`] upgrades it in memory, and scan rewrites it as v2 in a
+//! journaled operation (see SPEC.md Appendix A). Lattice only writes v2.
+//!
 //! # Example
 //!
 //! ```
-//! use latticework::core::metadata::schema::{BranchMetadataV1, parse_metadata, METADATA_KIND};
+//! use latticework::core::metadata::schema::{BranchMetadataV2, parse_metadata, METADATA_KIND};
 //! use latticework::core::types::{BranchName, Oid};
 //!
 //! // Create metadata for a new branch
@@ -26,7 +33,7 @@
 //! let parent = BranchName::new("main").unwrap();
 //! let base = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
 //!
-//! let meta = BranchMetadataV1::new(branch, parent, base);
+//! let meta = BranchMetadataV2::new(branch, parent, base);
 //! assert_eq!(meta.kind, METADATA_KIND);
 //!
 //! // Serialize and parse back
@@ -43,8 +50,11 @@ use crate::core::types::{BranchName, Oid, TypeError, UtcTimestamp};
 /// The kind identifier for branch metadata.
 pub const METADATA_KIND: &str = "lattice.branch-metadata";
 
-/// Current schema version.
-pub const SCHEMA_VERSION: u32 = 1;
+/// Current schema version, written by all metadata updates.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Oldest schema version that can still be read and upgraded.
+pub const MIN_SCHEMA_VERSION: u32 = 1;
 
 /// Freeze reason for teammate branches fetched from remote PRs.
 ///
@@ -62,6 +72,13 @@ pub const FREEZE_REASON_TEAMMATE_BRANCH: &str = "teammate_branch";
 /// - Future tooling to filter/manage snapshot branches
 pub const FREEZE_REASON_SYNTHETIC_SNAPSHOT: &str = "remote_synthetic_snapshot";
 
+/// Freeze reason for branches whose remote branch moved on without them.
+///
+/// Set by doctor when the local branch is behind or diverged from its
+/// remote-tracking ref, so nothing is restacked or pushed over the remote
+/// commits until the branch is refreshed with `lattice get <branch> --force`.
+pub const FREEZE_REASON_REMOTE_DIVERGED: &str = "remote_diverged";
+
 /// Errors from metadata operations.
 #[derive(Debug, Error)]
 pub enum MetadataError {
@@ -71,7 +88,7 @@ pub enum MetadataError {
     #[error("invalid kind '{found}', expected '{}'", METADATA_KIND)]
     InvalidKind { found: String },
 
-    #[error("unsupported schema version {0}, supported: {SCHEMA_VERSION}")]
+    #[error("unsupported schema version {0}, supported: {MIN_SCHEMA_VERSION}-{SCHEMA_VERSION}")]
     UnsupportedVersion(u32),
 
     #[error("invalid metadata value: {0}")]
@@ -94,7 +111,7 @@ struct MetadataEnvelope {
 /// Parse metadata JSON with version dispatch.
 ///
 /// This function checks the schema version and dispatches to the
-/// appropriate parser. Currently only v1 is supported.
+/// appropriate parser. v1 metadata is upgraded to v2.
 ///
 /// # Errors
 ///
@@ -125,8 +142,17 @@ struct MetadataEnvelope {
 ///
 /// let meta = parse_metadata(json).unwrap();
 /// assert_eq!(meta.branch.name, "feature");
+/// assert_eq!(meta.schema_version, 2);
 /// ```
-pub fn parse_metadata(json: &str) -> Result<BranchMetadataV1, MetadataError> {
+pub fn parse_metadata(json: &str) -> Result<BranchMetadataV2, MetadataError> {
+    parse_metadata_versioned(json).map(|(meta, _)| meta)
+}
+
+/// Parse metadata JSON, also returning the schema version it was stored as.
+///
+/// The metadata is always returned as v2; the stored version tells callers
+/// whether the blob still needs rewriting.
+pub fn parse_metadata_versioned(json: &str) -> Result<(BranchMetadataV2, u32), MetadataError> {
     // First, extract envelope to check version
     let envelope: MetadataEnvelope =
         serde_json::from_str(json).map_err(|e| MetadataError::ParseError(e.to_string()))?;
@@ -143,17 +169,27 @@ pub fn parse_metadata(json: &str) -> Result<BranchMetadataV1, MetadataError> {
         1 => {
             let meta: BranchMetadataV1 =
                 serde_json::from_str(json).map_err(|e| MetadataError::ParseError(e.to_string()))?;
+            if meta.schema_version != 1 {
+                return Err(MetadataError::UnsupportedVersion(meta.schema_version));
+            }
+            let meta = meta.upgrade();
             meta.validate()?;
-            Ok(meta)
+            Ok((meta, 1))
+        }
+        2 => {
+            let meta: BranchMetadataV2 =
+                serde_json::from_str(json).map_err(|e| MetadataError::ParseError(e.to_string()))?;
+            meta.validate()?;
+            Ok((meta, 2))
         }
         v => Err(MetadataError::UnsupportedVersion(v)),
     }
 }
 
-/// Branch metadata (v1).
+/// Branch metadata as stored by schema v1.
 ///
-/// This is the complete metadata stored for each tracked branch.
-/// Use [`parse_metadata`] to parse from JSON with validation.
+/// Only read, never written: [`parse_metadata`] upgrades it to
+/// [`BranchMetadataV2`].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct BranchMetadataV1 {
@@ -175,14 +211,121 @@ pub struct BranchMetadataV1 {
     /// Freeze state
     pub freeze: FreezeState,
 
-    /// PR linkage state (cached, not structural)
+    /// PR linkage state
     pub pr: PrState,
 
     /// Timestamps
     pub timestamps: Timestamps,
+
+    /// Restack emptied the branch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_empty: Option<MergedEmpty>,
+
+    /// Remote override
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+
+    /// Branch description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 impl BranchMetadataV1 {
+    /// Convert to v2. All v1 fields carry over; v2-only fields start empty.
+    pub fn upgrade(self) -> BranchMetadataV2 {
+        BranchMetadataV2 {
+            kind: self.kind,
+            schema_version: SCHEMA_VERSION,
+            branch: self.branch,
+            parent: self.parent,
+            base: self.base,
+            freeze: self.freeze,
+            pr: self.pr,
+            timestamps: self.timestamps,
+            merged_empty: self.merged_empty,
+            remote: self.remote,
+            description: self.description,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
+        }
+    }
+}
+
+/// Branch metadata (v2).
+///
+/// This is the complete metadata stored for each tracked branch.
+/// Use [`parse_metadata`] to parse from JSON with validation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BranchMetadataV2 {
+    /// Kind identifier (always "lattice.branch-metadata")
+    pub kind: String,
+
+    /// Schema version (always 2 for this struct)
+    pub schema_version: u32,
+
+    /// The branch this metadata describes
+    pub branch: BranchInfo,
+
+    /// Parent branch information
+    pub parent: ParentInfo,
+
+    /// Base commit information
+    pub base: BaseInfo,
+
+    /// Freeze state
+    pub freeze: FreezeState,
+
+    /// PR linkage state (cached, not structural)
+    pub pr: PrState,
+
+    /// Timestamps
+    pub timestamps: Timestamps,
+
+    /// Set when a restack left the branch with no commits because its
+    /// changes already landed upstream (omitted when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_empty: Option<MergedEmpty>,
+
+    /// Remote this branch is pushed to and fetched from, overriding the
+    /// repository default (omitted when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+
+    /// Branch description, kept in step with the PR body outside the stack
+    /// comment markers (omitted when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// When `pr.last_known` was last refreshed from the forge (omitted when
+    /// absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_cached_at: Option<UtcTimestamp>,
+
+    /// The branch as it was last pushed by submit (omitted when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submitted: Option<SubmitSnapshot>,
+
+    /// Forge issue the branch was created from, closed by its PR (omitted
+    /// when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueLink>,
+
+    /// Where the branch came from and whose it is, for branches Lattice
+    /// brought in rather than created (omitted when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+
+    /// CI status of the branch tip as last fetched from the forge (omitted
+    /// when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<CiStatus>,
+}
+
+impl BranchMetadataV2 {
     /// Create new metadata for a branch.
     ///
     /// The parent is set as a regular branch. Use [`BranchMetadataBuilder`]
@@ -207,9 +350,22 @@ impl BranchMetadataV1 {
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         }
     }
 
+    /// Remote to push and fetch this branch with, falling back to `default`.
+    pub fn remote_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.remote.as_deref().unwrap_or(default)
+    }
+
     /// Create a builder for constructing metadata with more options.
     pub fn builder(branch: BranchName, parent: BranchName, base_oid: Oid) -> BranchMetadataBuilder {
         BranchMetadataBuilder::new(branch, parent, base_oid)
@@ -336,7 +492,7 @@ impl BranchMetadataBuilder {
     }
 
     /// Build the metadata.
-    pub fn build(self) -> BranchMetadataV1 {
+    pub fn build(self) -> BranchMetadataV2 {
         let now = UtcTimestamp::now();
 
         let parent = if self.parent_is_trunk {
@@ -349,7 +505,7 @@ impl BranchMetadataBuilder {
             }
         };
 
-        BranchMetadataV1 {
+        BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -365,6 +521,14 @@ impl BranchMetadataBuilder {
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         }
     }
 }
@@ -389,7 +553,7 @@ pub struct StructuralMetadata {
 /// Reference view of structural metadata.
 ///
 /// This provides quick access to structural fields without validation
-/// or cloning. Use [`BranchMetadataV1::into_structural`] when you need
+/// or cloning. Use [`BranchMetadataV2::into_structural`] when you need
 /// validated types.
 #[derive(Debug)]
 pub struct StructuralView<'a> {
@@ -538,6 +702,126 @@ pub struct PrStatusCache {
     pub is_draft: bool,
 }
 
+/// The branch as it was last pushed by submit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitSnapshot {
+    /// Branch tip that was pushed
+    pub head_oid: String,
+    /// Base commit at the time
+    pub base_oid: String,
+    /// Parent branch the PR targeted
+    pub parent: String,
+    /// When the push happened
+    pub submitted_at: UtcTimestamp,
+}
+
+/// A forge issue a branch was created for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct IssueLink {
+    /// Issue number
+    pub number: u64,
+    /// Issue URL
+    pub url: String,
+}
+
+/// Where a branch came from.
+///
+/// Recorded when a branch is brought in from a remote, such as a teammate's
+/// PR, so it can later be explained why the branch is frozen and whose it is.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Provenance {
+    /// How the branch was brought in
+    pub source: ProvenanceSource,
+    /// Remote it was fetched from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// PR it was fetched for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr: Option<u64>,
+    /// Forge login of the branch's owner (the PR author)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// When the branch was brought in
+    pub recorded_at: UtcTimestamp,
+}
+
+/// How a branch was brought in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceSource {
+    /// Fetched with `lattice get`
+    Get,
+    /// Imported from an open PR by `lattice doctor`
+    DoctorImport,
+}
+
+impl Provenance {
+    /// One-line description, e.g. "fetched by `lattice get` from origin,
+    /// PR #12 by alice".
+    pub fn describe(&self) -> String {
+        let mut out = match self.source {
+            ProvenanceSource::Get => "fetched by `lattice get`",
+            ProvenanceSource::DoctorImport => "imported by `lattice doctor`",
+        }
+        .to_string();
+        if let Some(remote) = &self.remote {
+            out.push_str(&format!(" from {}", remote));
+        }
+        match (self.pr, &self.owner) {
+            (Some(pr), Some(owner)) => out.push_str(&format!(", PR #{} by {}", pr, owner)),
+            (Some(pr), None) => out.push_str(&format!(", PR #{}", pr)),
+            (None, Some(owner)) => out.push_str(&format!(", owned by {}", owner)),
+            (None, None) => {}
+        }
+        out
+    }
+}
+
+/// CI status of a commit, cached so offline commands can still show it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CiStatus {
+    /// Overall outcome of the checks
+    pub state: CiState,
+    /// Commit the checks ran on
+    pub oid: String,
+    /// When the status was fetched
+    pub checked_at: UtcTimestamp,
+}
+
+/// Overall outcome of a commit's CI checks.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CiState {
+    /// At least one check is still running, none failed
+    Pending,
+    /// Every check passed or was skipped
+    Passing,
+    /// At least one check failed
+    Failing,
+}
+
+impl std::fmt::Display for CiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CiState::Pending => write!(f, "pending"),
+            CiState::Passing => write!(f, "passing"),
+            CiState::Failing => write!(f, "failing"),
+        }
+    }
+}
+
+/// Marker for a branch emptied by restack.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MergedEmpty {
+    /// When the branch was found empty
+    pub detected_at: UtcTimestamp,
+}
+
 /// Timestamps.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
@@ -580,6 +864,56 @@ mod tests {
             assert!(meta.parent.is_trunk());
         }
 
+        #[test]
+        fn v1_is_upgraded() {
+            let json = r#"{
+                "kind": "lattice.branch-metadata",
+                "schema_version": 1,
+                "branch": { "name": "feature" },
+                "parent": { "kind": "branch", "name": "base" },
+                "base": { "oid": "abc123def4567890abc123def4567890abc12345" },
+                "freeze": { "state": "unfrozen" },
+                "pr": { "state": "none" },
+                "timestamps": {
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                },
+                "remote": "fork"
+            }"#;
+
+            let (meta, stored) = parse_metadata_versioned(json).unwrap();
+            assert_eq!(stored, 1);
+            assert_eq!(meta.schema_version, SCHEMA_VERSION);
+            assert_eq!(meta.parent.name(), "base");
+            assert_eq!(meta.remote.as_deref(), Some("fork"));
+            assert!(meta.submitted.is_none());
+
+            // Serializing writes v2
+            let json = meta.to_canonical_json().unwrap();
+            assert_eq!(parse_metadata_versioned(&json).unwrap(), (meta, 2));
+        }
+
+        #[test]
+        fn v2_fields_rejected_in_v1() {
+            let json = r#"{
+                "kind": "lattice.branch-metadata",
+                "schema_version": 1,
+                "branch": { "name": "feature" },
+                "parent": { "kind": "trunk", "name": "main" },
+                "base": { "oid": "abc123def4567890abc123def4567890abc12345" },
+                "freeze": { "state": "unfrozen" },
+                "pr": { "state": "none" },
+                "timestamps": {
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                },
+                "pr_cached_at": "2024-01-01T00:00:00Z"
+            }"#;
+
+            let result = parse_metadata(json);
+            assert!(matches!(result, Err(MetadataError::ParseError(_))));
+        }
+
         #[test]
         fn invalid_kind() {
             let json = r#"{
@@ -664,7 +998,7 @@ mod tests {
         }
     }
 
-    mod branch_metadata_v1 {
+    mod branch_metadata_v2 {
         use super::*;
 
         #[test]
@@ -672,7 +1006,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::new(branch, parent, sample_oid());
+            let meta = BranchMetadataV2::new(branch, parent, sample_oid());
 
             assert_eq!(meta.kind, METADATA_KIND);
             assert_eq!(meta.schema_version, SCHEMA_VERSION);
@@ -686,20 +1020,126 @@ mod tests {
             let branch = BranchName::new("feature-a").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::new(branch, parent, sample_oid());
+            let meta = BranchMetadataV2::new(branch, parent, sample_oid());
 
             let json = serde_json::to_string_pretty(&meta).unwrap();
-            let parsed: BranchMetadataV1 = serde_json::from_str(&json).unwrap();
+            let parsed: BranchMetadataV2 = serde_json::from_str(&json).unwrap();
 
             assert_eq!(meta, parsed);
         }
 
+        #[test]
+        fn merged_empty_omitted_when_absent() {
+            let branch = BranchName::new("feature").unwrap();
+            let parent = BranchName::new("main").unwrap();
+
+            let mut meta = BranchMetadataV2::new(branch, parent, sample_oid());
+            let json = serde_json::to_string(&meta).unwrap();
+            assert!(!json.contains("merged_empty"));
+
+            meta.merged_empty = Some(MergedEmpty {
+                detected_at: UtcTimestamp::now(),
+            });
+            let json = serde_json::to_string(&meta).unwrap();
+            let parsed: BranchMetadataV2 = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, meta);
+        }
+
+        #[test]
+        fn description_omitted_when_absent() {
+            let branch = BranchName::new("feature").unwrap();
+            let parent = BranchName::new("main").unwrap();
+
+            let mut meta = BranchMetadataV2::new(branch, parent, sample_oid());
+            let json = serde_json::to_string(&meta).unwrap();
+            assert!(!json.contains("description"));
+
+            meta.description = Some("Adds the feature.".to_string());
+            let json = serde_json::to_string(&meta).unwrap();
+            let parsed: BranchMetadataV2 = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, meta);
+        }
+
+        #[test]
+        fn v2_fields_roundtrip() {
+            let branch = BranchName::new("feature").unwrap();
+            let parent = BranchName::new("main").unwrap();
+
+            let mut meta = BranchMetadataV2::new(branch, parent, sample_oid());
+            meta.pr_cached_at = Some(UtcTimestamp::now());
+            meta.submitted = Some(SubmitSnapshot {
+                head_oid: sample_oid().to_string(),
+                base_oid: sample_oid().to_string(),
+                parent: "main".to_string(),
+                submitted_at: UtcTimestamp::now(),
+            });
+            meta.issue = Some(IssueLink {
+                number: 123,
+                url: "https://github.com/o/r/issues/123".to_string(),
+            });
+            meta.provenance = Some(Provenance {
+                source: ProvenanceSource::Get,
+                remote: Some("origin".to_string()),
+                pr: Some(12),
+                owner: Some("alice".to_string()),
+                recorded_at: UtcTimestamp::now(),
+            });
+            meta.ci = Some(CiStatus {
+                state: CiState::Failing,
+                oid: sample_oid().to_string(),
+                checked_at: UtcTimestamp::now(),
+            });
+
+            let json = serde_json::to_string(&meta).unwrap();
+            assert!(json.contains(r#""source":"get""#), "{}", json);
+            assert!(json.contains(r#""state":"failing""#), "{}", json);
+            assert_eq!(parse_metadata(&json).unwrap(), meta);
+        }
+
+        #[test]
+        fn provenance_describe() {
+            let mut provenance = Provenance {
+                source: ProvenanceSource::Get,
+                remote: Some("origin".to_string()),
+                pr: Some(12),
+                owner: Some("alice".to_string()),
+                recorded_at: UtcTimestamp::now(),
+            };
+            assert_eq!(
+                provenance.describe(),
+                "fetched by `lattice get` from origin, PR #12 by alice"
+            );
+
+            provenance.source = ProvenanceSource::DoctorImport;
+            provenance.owner = None;
+            assert_eq!(
+                provenance.describe(),
+                "imported by `lattice doctor` from origin, PR #12"
+            );
+
+            provenance.remote = None;
+            provenance.pr = None;
+            assert_eq!(provenance.describe(), "imported by `lattice doctor`");
+        }
+
+        #[test]
+        fn remote_or_prefers_override() {
+            let branch = BranchName::new("feature").unwrap();
+            let parent = BranchName::new("main").unwrap();
+
+            let mut meta = BranchMetadataV2::new(branch, parent, sample_oid());
+            assert_eq!(meta.remote_or("origin"), "origin");
+
+            meta.remote = Some("security".to_string());
+            assert_eq!(meta.remote_or("origin"), "security");
+        }
+
         #[test]
         fn validate_catches_bad_kind() {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let mut meta = BranchMetadataV1::new(branch, parent, sample_oid());
+            let mut meta = BranchMetadataV2::new(branch, parent, sample_oid());
             meta.kind = "wrong".to_string();
 
             assert!(meta.validate().is_err());
@@ -711,7 +1151,7 @@ mod tests {
             let parent = BranchName::new("main").unwrap();
             let oid = sample_oid();
 
-            let meta = BranchMetadataV1::new(branch, parent.clone(), oid.clone());
+            let meta = BranchMetadataV2::new(branch, parent.clone(), oid.clone());
             let structural = meta.into_structural().unwrap();
 
             assert_eq!(structural.parent, parent);
@@ -724,7 +1164,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta1 = BranchMetadataV1::new(branch.clone(), parent.clone(), sample_oid());
+            let meta1 = BranchMetadataV2::new(branch.clone(), parent.clone(), sample_oid());
 
             // Create another with same data but different timestamp
             // Since we can't control the timestamp, we'll just verify the format is stable
@@ -743,7 +1183,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid()).build();
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid()).build();
 
             assert_eq!(meta.branch.name, "feature");
             assert!(!meta.parent.is_trunk());
@@ -754,7 +1194,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid())
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid())
                 .parent_is_trunk()
                 .build();
 
@@ -766,7 +1206,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid())
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid())
                 .freeze_state(FreezeState::frozen(
                     FreezeScope::Single,
                     Some("testing".to_string()),
@@ -781,7 +1221,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid())
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid())
                 .pr_state(PrState::linked(
                     "github",
                     42,
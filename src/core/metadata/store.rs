@@ -14,11 +14,19 @@
 //! to prevent race conditions and ensure correctness when the repository
 //! is modified by external processes.
 //!
+//! # Signatures
+//!
+//! With `sign_metadata = true` in the repo config, each blob is the
+//! canonical JSON on one line followed by an ASCII-armored signature over
+//! that line, the way signed tags carry their signature. Signing uses
+//! git's own settings (`gpg.format`, `user.signingKey`). Readers ignore the
+//! signature; [`MetadataStore::verify_signature`] checks it.
+//!
 //! # Example
 //!
 //! ```ignore
 //! use latticework::core::metadata::store::MetadataStore;
-//! use latticework::core::metadata::schema::BranchMetadataV1;
+//! use latticework::core::metadata::schema::BranchMetadataV2;
 //! use latticework::core::types::{BranchName, Oid};
 //! use latticework::git::Git;
 //!
@@ -36,15 +44,22 @@
 //! }
 //! ```
 
+use std::cell::OnceCell;
+
 use thiserror::Error;
 
-use super::schema::{parse_metadata, BranchMetadataV1, MetadataError};
+use super::schema::{parse_metadata_versioned, BranchMetadataV2, MetadataError};
+use crate::core::config::Config;
 use crate::core::types::{BranchName, Oid, RefName};
-use crate::git::{Git, GitError};
+use crate::git::{Git, GitError, SignatureCheck};
 
 /// Prefix for metadata refs.
 pub const METADATA_REF_PREFIX: &str = "refs/branch-metadata/";
 
+/// SSH signature namespace for metadata, so signatures can't be reused
+/// as commit or tag signatures.
+pub const SIGNATURE_NAMESPACE: &str = "lattice-metadata";
+
 /// Errors from metadata storage operations.
 #[derive(Debug, Error)]
 pub enum StoreError {
@@ -80,6 +95,58 @@ pub enum StoreError {
     /// Metadata validation failed.
     #[error("metadata error: {0}")]
     MetadataError(#[from] MetadataError),
+
+    /// Signing the metadata blob failed.
+    #[error("failed to sign metadata: {0}")]
+    SigningError(String),
+
+    /// A metadata blob is gone and can't be recreated from a snapshot.
+    #[error("cannot recreate metadata blob {oid}: {reason}")]
+    BlobUnavailable {
+        /// The missing blob OID
+        oid: String,
+        /// Why it can't be recreated
+        reason: String,
+    },
+}
+
+/// Signature state of a metadata blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataSignature {
+    /// The blob carries no signature.
+    Unsigned,
+    /// The signature is valid.
+    Valid {
+        /// Who signed the metadata.
+        signer: String,
+    },
+    /// The signature doesn't verify: the metadata was changed after
+    /// signing, or was signed by an untrusted key.
+    Invalid {
+        /// Why verification failed.
+        reason: String,
+    },
+}
+
+/// Split a metadata blob into its JSON and optional trailing signature.
+///
+/// # Example
+///
+/// ```
+/// use latticework::core::metadata::store::split_signature;
+///
+/// assert_eq!(split_signature("{}"), ("{}", None));
+/// assert_eq!(
+///     split_signature("{}\n-----BEGIN SSH SIGNATURE-----\n"),
+///     ("{}", Some("-----BEGIN SSH SIGNATURE-----\n"))
+/// );
+/// ```
+pub fn split_signature(content: &str) -> (&str, Option<&str>) {
+    match content.split_once('\n') {
+        Some((json, signature)) if !signature.trim().is_empty() => (json, Some(signature)),
+        Some((json, _)) => (json, None),
+        None => (content, None),
+    }
 }
 
 /// Result of reading metadata.
@@ -95,7 +162,13 @@ pub struct MetadataEntry {
     pub ref_oid: Oid,
 
     /// The parsed and validated metadata.
-    pub metadata: BranchMetadataV1,
+    pub metadata: BranchMetadataV2,
+
+    /// Schema version the blob is stored as.
+    ///
+    /// Older versions are upgraded on read; the blob keeps its version
+    /// until the metadata is written again.
+    pub stored_version: u32,
 }
 
 /// Metadata store backed by Git refs.
@@ -123,12 +196,31 @@ pub struct MetadataEntry {
 /// ```
 pub struct MetadataStore<'a> {
     git: &'a Git,
+    /// Whether writes are signed, loaded from config on first write.
+    sign: OnceCell<bool>,
 }
 
 impl<'a> MetadataStore<'a> {
     /// Create a new metadata store using the given Git interface.
     pub fn new(git: &'a Git) -> Self {
-        Self { git }
+        Self {
+            git,
+            sign: OnceCell::new(),
+        }
+    }
+
+    /// Whether the repo config asks for signed metadata.
+    fn signing_enabled(&self) -> bool {
+        *self.sign.get_or_init(|| {
+            let path = self
+                .git
+                .info()
+                .ok()
+                .map(|info| info.work_dir.unwrap_or(info.git_dir));
+            Config::load(path.as_deref())
+                .map(|r| r.config.sign_metadata())
+                .unwrap_or(false)
+        })
     }
 
     /// Get the ref name for a branch's metadata.
@@ -180,12 +272,17 @@ impl<'a> MetadataStore<'a> {
         };
 
         // Read the blob content as UTF-8 string
-        let json = self.git.read_blob_as_string(&ref_oid)?;
+        let content = self.git.read_blob_as_string(&ref_oid)?;
+        let (json, _signature) = split_signature(&content);
 
         // Parse with strict validation
-        let metadata = parse_metadata(&json)?;
+        let (metadata, stored_version) = parse_metadata_versioned(json)?;
 
-        Ok(Some(MetadataEntry { ref_oid, metadata }))
+        Ok(Some(MetadataEntry {
+            ref_oid,
+            metadata,
+            stored_version,
+        }))
     }
 
     /// Write metadata for a branch with CAS semantics.
@@ -225,7 +322,7 @@ impl<'a> MetadataStore<'a> {
         &self,
         branch: &BranchName,
         expected_old: Option<&Oid>,
-        metadata: &BranchMetadataV1,
+        metadata: &BranchMetadataV2,
     ) -> Result<Oid, StoreError> {
         let refname = Self::ref_name(branch);
 
@@ -234,8 +331,19 @@ impl<'a> MetadataStore<'a> {
             .to_canonical_json()
             .map_err(|e| StoreError::SerializeError(e.to_string()))?;
 
+        // Append a signature over the JSON if configured
+        let content = if self.signing_enabled() {
+            let signature = self
+                .git
+                .sign_data(json.as_bytes(), SIGNATURE_NAMESPACE)
+                .map_err(|e| StoreError::SigningError(e.to_string()))?;
+            format!("{}\n{}", json, signature)
+        } else {
+            json
+        };
+
         // Write blob to repository
-        let blob_oid = self.git.write_blob(json.as_bytes())?;
+        let blob_oid = self.git.write_blob(content.as_bytes())?;
 
         // Update ref with CAS semantics
         self.git
@@ -341,6 +449,75 @@ impl<'a> MetadataStore<'a> {
         Ok(self.git.ref_exists(refname.as_str()))
     }
 
+    /// Raw content of the metadata blob `ref_oid`, for journaling.
+    ///
+    /// Journals store this next to the blob OID so that an undo or abort can
+    /// still restore the metadata after `git gc` pruned the unreferenced
+    /// blob. Returns `None` if the blob can't be read.
+    pub fn snapshot(&self, ref_oid: &Oid) -> Option<String> {
+        self.git.read_blob_as_string(ref_oid).ok()
+    }
+
+    /// Make sure the metadata blob `ref_oid` exists, recreating it from
+    /// `snapshot` if it was pruned.
+    ///
+    /// # Errors
+    ///
+    /// - [`StoreError::BlobUnavailable`] if the blob is missing and there is
+    ///   no snapshot, or the snapshot isn't the content of `ref_oid`
+    /// - [`StoreError::GitError`] if writing the blob fails
+    pub fn ensure_blob(&self, ref_oid: &Oid, snapshot: Option<&str>) -> Result<(), StoreError> {
+        if self.git.read_blob(ref_oid).is_ok() {
+            return Ok(());
+        }
+        let Some(content) = snapshot else {
+            return Err(StoreError::BlobUnavailable {
+                oid: ref_oid.to_string(),
+                reason: "the blob is gone and no snapshot was journaled".to_string(),
+            });
+        };
+        let written = self.git.write_blob(content.as_bytes())?;
+        if written != *ref_oid {
+            return Err(StoreError::BlobUnavailable {
+                oid: ref_oid.to_string(),
+                reason: format!("the journaled snapshot hashes to {}", written),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the signature of the metadata blob `ref_oid`.
+    ///
+    /// # Errors
+    ///
+    /// - [`StoreError::GitError`] if the blob can't be read or the
+    ///   verification program can't be run
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// match store.verify_signature(&entry.ref_oid)? {
+    ///     MetadataSignature::Valid { signer } => println!("signed by {}", signer),
+    ///     MetadataSignature::Unsigned => println!("unsigned"),
+    ///     MetadataSignature::Invalid { reason } => println!("tampered: {}", reason),
+    /// }
+    /// ```
+    pub fn verify_signature(&self, ref_oid: &Oid) -> Result<MetadataSignature, StoreError> {
+        let content = self.git.read_blob_as_string(ref_oid)?;
+        let (json, signature) = split_signature(&content);
+        let Some(signature) = signature else {
+            return Ok(MetadataSignature::Unsigned);
+        };
+
+        match self
+            .git
+            .verify_data(json.as_bytes(), signature, SIGNATURE_NAMESPACE)?
+        {
+            SignatureCheck::Valid { signer } => Ok(MetadataSignature::Valid { signer }),
+            SignatureCheck::Invalid { reason } => Ok(MetadataSignature::Invalid { reason }),
+        }
+    }
+
     /// Read multiple branch metadata entries at once.
     ///
     /// Returns a vector of `(branch, Option<MetadataEntry>)` pairs.
@@ -412,6 +589,20 @@ mod tests {
         assert!(err.to_string().contains("serialize"));
     }
 
+    #[test]
+    fn split_signature_separates_trailing_signature() {
+        let json = r#"{"kind":"lattice.branch-metadata"}"#;
+        assert_eq!(split_signature(json), (json, None));
+        assert_eq!(split_signature(&format!("{}\n", json)), (json, None));
+
+        let signed = format!("{}\n-----BEGIN PGP SIGNATURE-----\nabc\n", json);
+        let (payload, signature) = split_signature(&signed);
+        assert_eq!(payload, json);
+        assert!(signature
+            .unwrap()
+            .starts_with("-----BEGIN PGP SIGNATURE-----"));
+    }
+
     #[test]
     fn metadata_entry_debug() {
         let branch = BranchName::new("test").unwrap();
@@ -420,7 +611,8 @@ mod tests {
 
         let entry = MetadataEntry {
             ref_oid: oid.clone(),
-            metadata: BranchMetadataV1::new(branch, parent, oid),
+            metadata: BranchMetadataV2::new(branch, parent, oid),
+            stored_version: 2,
         };
 
         let debug_str = format!("{:?}", entry);
@@ -431,6 +623,7 @@ mod tests {
 
     mod metadata_serialization {
         use super::*;
+        use crate::core::metadata::schema::parse_metadata;
 
         fn sample_oid() -> Oid {
             Oid::new("abc123def4567890abc123def4567890abc12345").unwrap()
@@ -441,7 +634,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::new(branch, parent, sample_oid());
+            let meta = BranchMetadataV2::new(branch, parent, sample_oid());
 
             let json = meta.to_canonical_json().unwrap();
             let parsed = parse_metadata(&json).unwrap();
@@ -455,7 +648,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid())
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid())
                 .freeze_state(FreezeState::frozen(
                     FreezeScope::DownstackInclusive,
                     Some("teammate branch".into()),
@@ -473,7 +666,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let parent = BranchName::new("main").unwrap();
 
-            let meta = BranchMetadataV1::builder(branch, parent, sample_oid())
+            let meta = BranchMetadataV2::builder(branch, parent, sample_oid())
                 .pr_state(PrState::linked(
                     "github",
                     42,
@@ -12,6 +12,15 @@
 //! - [`metadata`] - Branch metadata schema and storage
 //! - [`config`] - Configuration schema and loading
 //! - [`paths`] - Centralized path routing for Lattice storage
+//! - [`atomic`] - Crash-safe file replacement
+//! - [`checkpoint`] - Named stack restore points
+//! - [`branch_lock`] - Advisory per-branch locks for collaborators
+//! - [`net`] - Timeouts for HTTP requests
+//! - [`absorb`] - Attribution of staged hunks to stack commits
+//! - [`cache_stats`] - Hit and miss counts for on-disk caches
+//! - [`merge_detect`] - Detection of branches already merged into trunk
+//! - [`remote_divergence`] - Detection of branches pushed from another machine
+//! - [`conflict_prediction`] - In-memory prediction of rebase conflicts
 //!
 //! # Design Principles
 //!
@@ -19,11 +28,20 @@
 //! - Schemas are strict and self-describing
 //! - All verification is deterministic
 
+pub mod absorb;
+pub mod atomic;
+pub mod branch_lock;
+pub mod cache_stats;
+pub mod checkpoint;
 pub mod config;
+pub mod conflict_prediction;
 pub mod graph;
+pub mod merge_detect;
 pub mod metadata;
 pub mod naming;
+pub mod net;
 pub mod ops;
 pub mod paths;
+pub mod remote_divergence;
 pub mod types;
 pub mod verify;
@@ -0,0 +1,139 @@
+//! core::net
+//!
+//! Timeouts for HTTP requests to forges and auth endpoints.
+//!
+//! # Design
+//!
+//! Every HTTP client Lattice builds comes from [`client`], so one set of
+//! limits covers the forge API, the device flow, and installation checks.
+//! The limits come from `[network]` in the global config and are set once
+//! per process by [`init`], like the confirmation policy; library use and
+//! tests get the defaults.
+//!
+//! - `connect_timeout_secs` (default 10): establishing a connection
+//! - `read_timeout_secs` (default 30): waiting for the next bytes of a response
+//! - `timeout_secs` (default 60): one whole request, from connect to body
+//!
+//! A request that hits a limit fails instead of hanging; [`describe`] words
+//! the error so it names the limit. Forge timeouts count as connectivity
+//! failures, so commands that queue work while offline queue it then too.
+//!
+//! Offline mode is not kept here: `--offline` sets
+//! [`Context::offline`](crate::engine::Context::offline), and a command
+//! that finds the remote unreachable partway through tracks that itself
+//! for the rest of its run.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Limits applied to every HTTP request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Limit on establishing a connection
+    pub connect: Duration,
+    /// Limit on waiting for more of a response
+    pub read: Duration,
+    /// Limit on a whole request
+    pub total: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            connect: Duration::from_secs(10),
+            read: Duration::from_secs(30),
+            total: Duration::from_secs(60),
+        }
+    }
+}
+
+static TIMEOUTS: OnceLock<Timeouts> = OnceLock::new();
+
+/// Set the timeouts for this process.
+///
+/// Only the first call has an effect; until it runs, the defaults apply.
+pub fn init(timeouts: Timeouts) {
+    let _ = TIMEOUTS.set(timeouts);
+}
+
+/// The timeouts in effect.
+pub fn timeouts() -> Timeouts {
+    TIMEOUTS.get().copied().unwrap_or_default()
+}
+
+/// Build an HTTP client that enforces [`timeouts`].
+pub fn client() -> reqwest::Client {
+    let timeouts = timeouts();
+    reqwest::Client::builder()
+        .connect_timeout(timeouts.connect)
+        .read_timeout(timeouts.read)
+        .timeout(timeouts.total)
+        .build()
+        .unwrap_or_default()
+}
+
+/// Describe a request error, naming the limit when it timed out.
+pub fn describe(err: &reqwest::Error) -> String {
+    if !err.is_timeout() {
+        return err.to_string();
+    }
+    let host = err
+        .url()
+        .and_then(|url| url.host_str())
+        .unwrap_or("the server")
+        .to_string();
+    let timeouts = timeouts();
+    if err.is_connect() {
+        format!(
+            "could not connect to {} within {}s (network.connect_timeout_secs)",
+            host,
+            timeouts.connect.as_secs()
+        )
+    } else {
+        format!(
+            "{} did not respond in time (network.read_timeout_secs = {}, network.timeout_secs = {})",
+            host,
+            timeouts.read.as_secs(),
+            timeouts.total.as_secs()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn slow_server_times_out_with_named_limit() {
+        use std::io::Read;
+
+        // Accepts the connection and reads the request, but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf);
+            std::thread::sleep(Duration::from_secs(5));
+        });
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let err = client
+            .get(format!("http://{}/", addr))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(err.is_timeout());
+        let message = describe(&err);
+        assert!(
+            message.contains("127.0.0.1 did not respond in time"),
+            "{}",
+            message
+        );
+        assert!(message.contains("network.timeout_secs"), "{}", message);
+    }
+}
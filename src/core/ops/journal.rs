@@ -12,12 +12,16 @@
 //!
 //! 1. **Per-step persistence:** Every `append_*` method writes to disk with fsync
 //!    before returning. A crash at any point leaves the journal in a consistent state.
+//!    The file is replaced atomically ([`crate::core::atomic`]), so a crash
+//!    mid-write leaves the previous version rather than a truncated one.
 //!
 //! 2. **Recoverability:** After a crash, `Journal::read()` returns the journal as
 //!    it was after the last successful `append_*` call.
 //!
 //! 3. **Rollback support:** The journal records enough information to reverse all
-//!    ref updates via `ref_updates_for_rollback()`.
+//!    ref updates via `ref_updates_for_rollback()`. Metadata steps also carry a
+//!    snapshot of the previous metadata blob, so it can be recreated even after
+//!    `git gc` pruned it.
 //!
 //! # Architecture
 //!
@@ -53,7 +57,7 @@
 //!
 //! // Each append_* method persists immediately with fsync
 //! journal.append_ref_update(&paths, "refs/heads/feature", None, "abc123...")?;
-//! journal.append_metadata_write(&paths, "feature", None, "meta-oid")?;
+//! journal.append_metadata_write(&paths, "feature", None, None, "meta-oid")?;
 //!
 //! // Phase transitions also persist
 //! journal.commit();
@@ -75,14 +79,14 @@
 //! | `record_git_process()` | `append_git_process()` |
 //! | `record_conflict_paused()` | `append_conflict_paused()` |
 
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::core::atomic;
 use crate::core::paths::LatticePaths;
 use crate::core::types::UtcTimestamp;
 
@@ -208,6 +212,12 @@ pub enum StepKind {
         branch: String,
         /// Metadata ref OID before the write, or None if created.
         old_ref_oid: Option<String>,
+        /// Content of the metadata blob before the write.
+        ///
+        /// None when the metadata was created, or for journals written
+        /// before snapshots were recorded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        old_content: Option<String>,
         /// Metadata ref OID after the write.
         new_ref_oid: String,
     },
@@ -218,6 +228,11 @@ pub enum StepKind {
         branch: String,
         /// Metadata ref OID that was deleted.
         old_ref_oid: String,
+        /// Content of the deleted metadata blob.
+        ///
+        /// None for journals written before snapshots were recorded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        old_content: Option<String>,
     },
 
     /// A checkpoint marker.
@@ -243,6 +258,9 @@ pub enum StepKind {
     /// Records the state when the operation was paused for user intervention.
     /// Per Milestone 0.5, this now includes serialized remaining plan steps
     /// so that `continue` can resume multi-step operations.
+    ///
+    /// A Ctrl-C pause is recorded the same way, with `git_state` set to
+    /// [`INTERRUPTED_STATE`] and `branch` naming the next branch to process.
     ConflictPaused {
         /// The branch where the conflict occurred.
         branch: String,
@@ -262,6 +280,39 @@ pub enum StepKind {
     },
 }
 
+impl StepKind {
+    /// One-line, human-readable summary of the step.
+    pub fn describe(&self) -> String {
+        match self {
+            StepKind::RefUpdate {
+                refname, new_oid, ..
+            } => format!(
+                "Updated {} to {}",
+                refname,
+                &new_oid[..7.min(new_oid.len())]
+            ),
+            StepKind::MetadataWrite { branch, .. } => format!("Wrote metadata for {}", branch),
+            StepKind::MetadataDelete { branch, .. } => format!("Deleted metadata for {}", branch),
+            StepKind::Checkpoint { name } => format!("Checkpoint: {}", name),
+            StepKind::GitProcess { description, .. } => description.clone(),
+            StepKind::ConflictPaused {
+                branch, git_state, ..
+            } if git_state == INTERRUPTED_STATE => {
+                format!("Paused: interrupted before {}", branch)
+            }
+            StepKind::ConflictPaused {
+                branch, git_state, ..
+            } => {
+                format!("Paused: {} conflict on {}", git_state, branch)
+            }
+        }
+    }
+}
+
+/// `git_state` of a [`StepKind::ConflictPaused`] step recorded for a Ctrl-C
+/// rather than a conflict.
+pub const INTERRUPTED_STATE: &str = "interrupt";
+
 /// An operation journal.
 ///
 /// Records all state changes during a Lattice operation for crash
@@ -365,12 +416,14 @@ impl Journal {
         paths: &LatticePaths,
         branch: impl Into<String>,
         old_ref_oid: Option<String>,
+        old_content: Option<String>,
         new_ref_oid: impl Into<String>,
     ) -> Result<(), JournalError> {
         self.steps.push(JournalStep {
             kind: StepKind::MetadataWrite {
                 branch: branch.into(),
                 old_ref_oid,
+                old_content,
                 new_ref_oid: new_ref_oid.into(),
             },
             timestamp: UtcTimestamp::now(),
@@ -391,11 +444,13 @@ impl Journal {
         paths: &LatticePaths,
         branch: impl Into<String>,
         old_ref_oid: impl Into<String>,
+        old_content: Option<String>,
     ) -> Result<(), JournalError> {
         self.steps.push(JournalStep {
             kind: StepKind::MetadataDelete {
                 branch: branch.into(),
                 old_ref_oid: old_ref_oid.into(),
+                old_content,
             },
             timestamp: UtcTimestamp::now(),
         });
@@ -531,6 +586,7 @@ impl Journal {
         self.add_step(StepKind::MetadataWrite {
             branch: branch.into(),
             old_ref_oid,
+            old_content: None,
             new_ref_oid: new_ref_oid.into(),
         });
     }
@@ -553,6 +609,7 @@ impl Journal {
         self.add_step(StepKind::MetadataDelete {
             branch: branch.into(),
             old_ref_oid: old_ref_oid.into(),
+            old_content: None,
         });
     }
 
@@ -650,7 +707,9 @@ impl Journal {
 
     /// Write the journal to disk with fsync.
     ///
-    /// This should be called after each step to ensure crash safety.
+    /// This should be called after each step to ensure crash safety. The
+    /// file is replaced atomically and its directory synced (see
+    /// [`atomic::write`]).
     /// Per SPEC.md §4.6.5, journals are written to `<common_dir>/lattice/ops/`.
     ///
     /// # Fault Injection
@@ -673,14 +732,7 @@ impl Journal {
         let path = self.file_path(paths);
         let content = serde_json::to_string_pretty(self)?;
 
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&path)?;
-
-        file.write_all(content.as_bytes())?;
-        file.sync_all()?;
+        atomic::write(&path, content.as_bytes())?;
 
         Ok(())
     }
@@ -721,7 +773,7 @@ impl Journal {
             .collect();
 
         // Sort by modification time (newest first)
-        entries.sort_by(|a, b| b.1.cmp(&a.1));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.1));
 
         Ok(entries.into_iter().map(|(id, _)| id).collect())
     }
@@ -765,23 +817,19 @@ impl Journal {
     /// Check if this journal can be fully rolled back.
     ///
     /// Returns `true` if all ref updates can be reversed. Returns `false` if
-    /// any step cannot be undone (e.g., metadata deletion where content wasn't stored).
-    ///
-    /// # Known Limitations
-    ///
-    /// - Metadata updates cannot be fully rolled back (old content not stored)
-    /// - Metadata deletes cannot be restored (deleted content not stored)
+    /// any metadata update or delete lacks a snapshot of the old content,
+    /// which only happens for journals written by older versions. Those can
+    /// still be rolled back as long as the old metadata blob hasn't been
+    /// pruned by `git gc`.
     pub fn can_fully_rollback(&self) -> bool {
         self.steps.iter().all(|step| match &step.kind {
             StepKind::RefUpdate { .. } => true,
-            StepKind::MetadataWrite { old_ref_oid, .. } => {
-                // Can only rollback if metadata was created (not modified)
-                old_ref_oid.is_none()
-            }
-            StepKind::MetadataDelete { .. } => {
-                // Cannot restore deleted metadata without content
-                false
-            }
+            StepKind::MetadataWrite {
+                old_ref_oid,
+                old_content,
+                ..
+            } => old_ref_oid.is_none() || old_content.is_some(),
+            StepKind::MetadataDelete { old_content, .. } => old_content.is_some(),
             StepKind::Checkpoint { .. }
             | StepKind::GitProcess { .. }
             | StepKind::ConflictPaused { .. } => true,
@@ -803,16 +851,27 @@ impl Journal {
                 StepKind::MetadataWrite {
                     branch,
                     old_ref_oid,
+                    old_content,
                     ..
                 } => {
                     if old_ref_oid.is_none() {
                         summary.metadata_creates.push(branch.clone());
                     } else {
                         summary.metadata_updates.push(branch.clone());
+                        if old_content.is_none() {
+                            summary.without_snapshot.push(branch.clone());
+                        }
                     }
                 }
-                StepKind::MetadataDelete { branch, .. } => {
+                StepKind::MetadataDelete {
+                    branch,
+                    old_content,
+                    ..
+                } => {
                     summary.metadata_deletes.push(branch.clone());
+                    if old_content.is_none() {
+                        summary.without_snapshot.push(branch.clone());
+                    }
                 }
                 _ => {}
             }
@@ -967,19 +1026,23 @@ pub struct RollbackSummary {
     pub ref_updates: Vec<String>,
     /// Metadata that was created and can be deleted.
     pub metadata_creates: Vec<String>,
-    /// Metadata that was updated (cannot fully restore).
+    /// Metadata that was updated and can be restored.
     pub metadata_updates: Vec<String>,
-    /// Metadata that was deleted (cannot restore).
+    /// Metadata that was deleted and can be restored.
     pub metadata_deletes: Vec<String>,
+    /// Updated or deleted metadata whose old content wasn't snapshotted
+    /// (journals from older versions). Restorable only while the old blob
+    /// still exists.
+    pub without_snapshot: Vec<String>,
 }
 
 impl RollbackSummary {
     /// Check if rollback would be complete.
     ///
-    /// Returns `true` if there are no metadata updates or deletes that
-    /// cannot be fully restored.
+    /// Returns `true` if every metadata update and delete has a snapshot
+    /// of the old content.
     pub fn is_complete(&self) -> bool {
-        self.metadata_updates.is_empty() && self.metadata_deletes.is_empty()
+        self.without_snapshot.is_empty()
     }
 
     /// Get the total number of items that would be rolled back.
@@ -997,9 +1060,14 @@ impl RollbackSummary {
 
 /// Current plan schema version.
 ///
-/// Increment this when the plan format changes in incompatible ways.
-/// Used by `continue` to detect version mismatches.
-pub const PLAN_SCHEMA_VERSION: u32 = 1;
+/// Increment this when the plan format changes in incompatible ways, and
+/// teach `engine::plan::decode_remaining_steps` to migrate the old format.
+/// Used by `continue` to resume operations paused by an older binary and
+/// to refuse ones paused by a newer one.
+///
+/// - v1: `write_metadata_cas` may carry v1 branch metadata
+/// - v2: `write_metadata_cas` always carries v2 branch metadata
+pub const PLAN_SCHEMA_VERSION: u32 = 2;
 
 /// Reason why an operation is awaiting user action.
 ///
@@ -1022,6 +1090,9 @@ pub enum AwaitingReason {
         /// Description of what verification failed.
         evidence: String,
     },
+
+    /// The user pressed Ctrl-C; the executor stopped at a step boundary.
+    UserInterrupt,
 }
 
 /// A ref that will be touched by an operation, with its expected old OID.
@@ -1046,6 +1117,33 @@ impl TouchedRef {
     }
 }
 
+/// The process running an operation.
+///
+/// Recorded in the op-state so that a second, identical invocation can
+/// recognize the first one and follow its progress instead of failing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpHolder {
+    /// Process id.
+    pub pid: u32,
+    /// Command-line arguments, without the program name.
+    pub args: Vec<String>,
+}
+
+impl OpHolder {
+    /// Describe the current process.
+    pub fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            args: std::env::args().skip(1).collect(),
+        }
+    }
+
+    /// Whether `other` is a different process started with the same arguments.
+    pub fn is_duplicate_of(&self, other: &OpHolder) -> bool {
+        self.pid != other.pid && self.args == other.args
+    }
+}
+
 /// The op-state marker indicating an operation is in progress.
 ///
 /// This file exists only while a Lattice operation is executing or
@@ -1109,6 +1207,12 @@ pub struct OpState {
     /// Set when `phase == Paused`. Helps the user understand what action is
     /// needed (resolve conflict, acknowledge rollback failure, etc.).
     pub awaiting_reason: Option<AwaitingReason>,
+
+    /// The process that started the operation.
+    ///
+    /// None for op-states written by older binaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<OpHolder>,
 }
 
 impl OpState {
@@ -1151,6 +1255,7 @@ impl OpState {
             plan_schema_version: PLAN_SCHEMA_VERSION,
             touched_refs,
             awaiting_reason: None,
+            holder: Some(OpHolder::current()),
         }
     }
 
@@ -1163,7 +1268,8 @@ impl OpState {
 
     /// Write the op-state marker to disk.
     ///
-    /// Per SPEC.md §4.6.5, op-state is written to `<common_dir>/lattice/op-state.json`.
+    /// Per SPEC.md §4.6.5, op-state is written to `<common_dir>/lattice/op-state.json`,
+    /// replaced atomically (see [`atomic::write`]).
     pub fn write(&self, paths: &LatticePaths) -> Result<(), JournalError> {
         let dir = paths.repo_lattice_dir();
         fs::create_dir_all(&dir)?;
@@ -1171,14 +1277,7 @@ impl OpState {
         let path = Self::path(paths);
         let content = serde_json::to_string_pretty(self)?;
 
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&path)?;
-
-        file.write_all(content.as_bytes())?;
-        file.sync_all()?;
+        atomic::write(&path, content.as_bytes())?;
 
         Ok(())
     }
@@ -1267,6 +1366,7 @@ impl OpState {
             plan_schema_version: PLAN_SCHEMA_VERSION,
             touched_refs: vec![],
             awaiting_reason: None,
+            holder: Some(OpHolder::current()),
         }
     }
 
@@ -1546,9 +1646,9 @@ mod tests {
         use super::*;
 
         #[test]
-        fn version_is_one() {
-            // Current schema version should be 1
-            assert_eq!(PLAN_SCHEMA_VERSION, 1);
+        fn version_is_two() {
+            // Current schema version should be 2
+            assert_eq!(PLAN_SCHEMA_VERSION, 2);
         }
     }
 
@@ -1596,6 +1696,7 @@ mod tests {
                     branch,
                     old_ref_oid,
                     new_ref_oid,
+                    ..
                 } => {
                     assert_eq!(branch, "feature");
                     assert_eq!(old_ref_oid, &Some("old-oid".to_string()));
@@ -1615,6 +1716,7 @@ mod tests {
                 StepKind::MetadataDelete {
                     branch,
                     old_ref_oid,
+                    ..
                 } => {
                     assert_eq!(branch, "feature");
                     assert_eq!(old_ref_oid, "deleted-oid");
@@ -2004,6 +2106,33 @@ mod tests {
             assert!(!journal.can_fully_rollback());
         }
 
+        #[test]
+        fn can_fully_rollback_with_metadata_snapshots() {
+            let temp = create_test_dir();
+            let paths = create_test_paths(&temp);
+            let mut journal = Journal::new("test");
+            journal
+                .append_metadata_write(
+                    &paths,
+                    "updated",
+                    Some("old".to_string()),
+                    Some("{}".to_string()),
+                    "new",
+                )
+                .unwrap();
+            journal
+                .append_metadata_delete(&paths, "deleted", "old-oid", Some("{}".to_string()))
+                .unwrap();
+
+            assert!(journal.can_fully_rollback());
+            let summary = journal.rollback_summary();
+            assert!(summary.is_complete());
+            assert!(summary.without_snapshot.is_empty());
+
+            let loaded = Journal::read(&paths, &journal.op_id).unwrap();
+            assert!(loaded.can_fully_rollback());
+        }
+
         #[test]
         fn rollback_summary_categorizes_correctly() {
             let mut journal = Journal::new("test");
@@ -2021,6 +2150,7 @@ mod tests {
             assert_eq!(summary.metadata_updates.len(), 1);
             assert_eq!(summary.metadata_deletes.len(), 1);
             assert!(!summary.is_complete()); // Has updates and deletes
+            assert_eq!(summary.without_snapshot, vec!["updated", "deleted"]);
             assert_eq!(summary.total_items(), 5);
         }
 
@@ -2145,6 +2275,30 @@ mod tests {
             assert_eq!(loaded.origin_work_dir, state.origin_work_dir);
         }
 
+        #[test]
+        fn records_holder_process() {
+            let temp = create_test_dir();
+            let paths = create_test_paths(&temp);
+
+            let journal = Journal::new("test");
+            let state = OpState::from_journal(&journal, &paths, None, "sha256:t".into(), vec![]);
+            let holder = state.holder.clone().expect("holder");
+            assert_eq!(holder.pid, std::process::id());
+            assert!(!holder.is_duplicate_of(&OpHolder::current()));
+
+            let other = OpHolder {
+                pid: holder.pid + 1,
+                args: holder.args.clone(),
+            };
+            assert!(holder.is_duplicate_of(&other));
+
+            // Op-states written before holders were recorded still load
+            let mut json = serde_json::to_value(&state).unwrap();
+            json.as_object_mut().unwrap().remove("holder");
+            let old: OpState = serde_json::from_value(json).unwrap();
+            assert!(old.holder.is_none());
+        }
+
         #[test]
         fn read_nonexistent_returns_none() {
             let temp = create_test_dir();
@@ -2515,11 +2669,11 @@ mod tests {
                 ),
                 (
                     "append_metadata_write",
-                    Box::new(|j, p| j.append_metadata_write(p, "branch", None, "oid")),
+                    Box::new(|j, p| j.append_metadata_write(p, "branch", None, None, "oid")),
                 ),
                 (
                     "append_metadata_delete",
-                    Box::new(|j, p| j.append_metadata_delete(p, "branch", "oid")),
+                    Box::new(|j, p| j.append_metadata_delete(p, "branch", "oid", None)),
                 ),
                 (
                     "append_checkpoint",
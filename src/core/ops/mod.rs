@@ -6,6 +6,8 @@
 //!
 //! - [`journal`] - Operation journal for crash safety and undo
 //! - [`lock`] - Exclusive repository lock
+//! - [`pending`] - Forge actions queued while offline
+//! - [`submit_claim`] - Branches claimed by running submits
 //!
 //! # Architecture
 //!
@@ -44,6 +46,8 @@
 
 pub mod journal;
 pub mod lock;
+pub mod pending;
+pub mod submit_claim;
 
 // Re-export main types for convenience
 pub use journal::{Journal, JournalError, OpId, OpPhase, OpState, StepKind};
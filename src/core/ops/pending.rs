@@ -0,0 +1,352 @@
+//! core::ops::pending
+//!
+//! Queue of forge actions deferred while offline.
+//!
+//! # Architecture
+//!
+//! When the forge or remote is unreachable (or `--offline` is passed), remote
+//! steps of `lattice submit` are recorded here instead of failing. The queue
+//! is flushed by `lattice sync` once connectivity returns, replaying actions
+//! in the order they were recorded.
+//!
+//! The queue is repo-scoped so that actions queued from any worktree are
+//! flushed together.
+//!
+//! # Storage
+//!
+//! - `<common_dir>/lattice/pending-actions.json`
+//!
+//! # Invariants
+//!
+//! - Actions are replayed strictly in insertion order
+//! - The file is written atomically (temp file + rename)
+//! - An empty queue is represented by the absence of the file
+//!
+//! # Example
+//!
+//! ```ignore
+//! use latticework::core::ops::pending::{PendingAction, PendingQueue};
+//!
+//! let mut queue = PendingQueue::load(&paths)?;
+//! queue.enqueue(PendingAction::Push {
+//!     branch: "feature".to_string(),
+//!     force: false,
+//!     no_verify: false,
+//!     remote: None,
+//! });
+//! queue.save(&paths)?;
+//! ```
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::atomic;
+use crate::core::paths::LatticePaths;
+
+/// Errors from pending queue operations.
+#[derive(Debug, Error)]
+pub enum PendingError {
+    /// I/O error reading or writing the queue file.
+    #[error("pending queue i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// JSON serialization/deserialization error.
+    #[error("pending queue json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// A forge-dependent action deferred until connectivity returns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PendingAction {
+    /// Push a branch to `origin` (or its remote override).
+    Push {
+        /// Branch to push.
+        branch: String,
+        /// Use `--force-with-lease`.
+        force: bool,
+        /// Skip pre-push hooks.
+        no_verify: bool,
+        /// Remote to push to when the branch overrides `origin`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        remote: Option<String>,
+    },
+
+    /// Create a PR for a branch (skipped if one already exists for the head).
+    CreatePr {
+        /// Head branch.
+        head: String,
+        /// Base branch.
+        base: String,
+        /// PR title.
+        title: String,
+        /// PR body, rendered from the templates or body generator when the
+        /// action was queued.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+        /// Create as draft.
+        draft: bool,
+        /// User reviewers to request after creation.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        reviewers: Vec<String>,
+        /// Team reviewers to request after creation.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        team_reviewers: Vec<String>,
+    },
+
+    /// Retarget an existing PR and refresh its stack comment.
+    UpdatePr {
+        /// PR number.
+        number: u64,
+        /// New base branch.
+        base: String,
+    },
+
+    /// Toggle the draft state of an existing PR.
+    SetDraft {
+        /// PR number.
+        number: u64,
+        /// Desired draft state.
+        draft: bool,
+    },
+
+    /// Close an existing PR without merging it.
+    ClosePr {
+        /// PR number.
+        number: u64,
+    },
+}
+
+impl PendingAction {
+    /// Short human-readable description for status output.
+    pub fn describe(&self) -> String {
+        match self {
+            PendingAction::Push { branch, .. } => format!("push '{}'", branch),
+            PendingAction::CreatePr { head, base, .. } => {
+                format!("create PR for '{}' onto '{}'", head, base)
+            }
+            PendingAction::UpdatePr { number, base } => {
+                format!("update PR #{} (base '{}')", number, base)
+            }
+            PendingAction::SetDraft {
+                number,
+                draft: true,
+            } => {
+                format!("convert PR #{} to draft", number)
+            }
+            PendingAction::SetDraft {
+                number,
+                draft: false,
+            } => format!("publish PR #{}", number),
+            PendingAction::ClosePr { number } => format!("close PR #{}", number),
+        }
+    }
+}
+
+/// A queued action with the time it was recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingEntry {
+    /// The deferred action.
+    pub action: PendingAction,
+    /// When the action was queued (RFC 3339).
+    pub queued_at: String,
+}
+
+/// Ordered queue of pending forge actions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingQueue {
+    /// Queued entries, oldest first.
+    pub entries: Vec<PendingEntry>,
+}
+
+impl PendingQueue {
+    /// Path to the queue file.
+    pub fn path(paths: &LatticePaths) -> PathBuf {
+        paths.repo_pending_actions_path()
+    }
+
+    /// Load the queue, returning an empty queue if none exists.
+    pub fn load(paths: &LatticePaths) -> Result<Self, PendingError> {
+        let path = Self::path(paths);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Persist the queue atomically, removing the file if the queue is empty.
+    pub fn save(&self, paths: &LatticePaths) -> Result<(), PendingError> {
+        let path = Self::path(paths);
+        if self.entries.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path)?;
+            }
+            return Ok(());
+        }
+
+        fs::create_dir_all(paths.repo_lattice_dir())?;
+        let content = serde_json::to_string_pretty(self)?;
+        atomic::write(&path, content.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Append an action to the end of the queue.
+    ///
+    /// An action identical to one already queued is not added twice, so
+    /// repeated offline submits do not replay the same push repeatedly.
+    pub fn enqueue(&mut self, action: PendingAction) {
+        if self.entries.iter().any(|e| e.action == action) {
+            return;
+        }
+        self.entries.push(PendingEntry {
+            action,
+            queued_at: Utc::now().to_rfc3339(),
+        });
+    }
+
+    /// Remove and return the oldest entry.
+    pub fn pop_front(&mut self) -> Option<PendingEntry> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.entries.remove(0))
+        }
+    }
+
+    /// Put an entry back at the front of the queue.
+    pub fn push_front(&mut self, entry: PendingEntry) {
+        self.entries.insert(0, entry);
+    }
+
+    /// Number of queued actions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_paths(temp: &TempDir) -> LatticePaths {
+        let git_dir = temp.path().join(".git");
+        LatticePaths::new(git_dir.clone(), git_dir)
+    }
+
+    fn push(branch: &str) -> PendingAction {
+        PendingAction::Push {
+            branch: branch.to_string(),
+            force: false,
+            no_verify: false,
+            remote: None,
+        }
+    }
+
+    #[test]
+    fn load_missing_returns_empty() {
+        let temp = TempDir::new().unwrap();
+        let queue = PendingQueue::load(&test_paths(&temp)).unwrap();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_order() {
+        let temp = TempDir::new().unwrap();
+        let paths = test_paths(&temp);
+
+        let mut queue = PendingQueue::default();
+        queue.enqueue(push("a"));
+        queue.enqueue(PendingAction::CreatePr {
+            head: "a".to_string(),
+            base: "main".to_string(),
+            title: "a".to_string(),
+            body: Some("Adds a.".to_string()),
+            draft: true,
+            reviewers: vec!["alice".to_string()],
+            team_reviewers: vec![],
+        });
+        queue.enqueue(PendingAction::UpdatePr {
+            number: 7,
+            base: "a".to_string(),
+        });
+        queue.save(&paths).unwrap();
+
+        let loaded = PendingQueue::load(&paths).unwrap();
+        assert_eq!(loaded, queue);
+        assert_eq!(loaded.entries[0].action, push("a"));
+        assert!(matches!(
+            loaded.entries[2].action,
+            PendingAction::UpdatePr { number: 7, .. }
+        ));
+    }
+
+    #[test]
+    fn push_remote_is_optional_in_json() {
+        let json = serde_json::to_string(&push("a")).unwrap();
+        assert!(!json.contains("remote"));
+
+        let legacy = r#"{"type":"push","branch":"a","force":false,"no_verify":false}"#;
+        let parsed: PendingAction = serde_json::from_str(legacy).unwrap();
+        assert_eq!(parsed, push("a"));
+    }
+
+    #[test]
+    fn enqueue_skips_duplicates() {
+        let mut queue = PendingQueue::default();
+        queue.enqueue(push("a"));
+        queue.enqueue(push("a"));
+        queue.enqueue(push("b"));
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn saving_empty_queue_removes_file() {
+        let temp = TempDir::new().unwrap();
+        let paths = test_paths(&temp);
+
+        let mut queue = PendingQueue::default();
+        queue.enqueue(push("a"));
+        queue.save(&paths).unwrap();
+        assert!(PendingQueue::path(&paths).exists());
+
+        queue.pop_front();
+        queue.save(&paths).unwrap();
+        assert!(!PendingQueue::path(&paths).exists());
+    }
+
+    #[test]
+    fn push_front_restores_order() {
+        let mut queue = PendingQueue::default();
+        queue.enqueue(push("a"));
+        queue.enqueue(push("b"));
+
+        let first = queue.pop_front().unwrap();
+        queue.push_front(first);
+        assert_eq!(queue.entries[0].action, push("a"));
+    }
+
+    #[test]
+    fn describe_mentions_target() {
+        assert_eq!(push("feat").describe(), "push 'feat'");
+        let publish = PendingAction::SetDraft {
+            number: 3,
+            draft: false,
+        };
+        assert_eq!(publish.describe(), "publish PR #3");
+        let close = PendingAction::ClosePr { number: 4 };
+        assert_eq!(close.describe(), "close PR #4");
+    }
+}
@@ -0,0 +1,290 @@
+//! core::ops::submit_claim
+//!
+//! Claims on the branches an in-flight `lattice submit` is pushing.
+//!
+//! # Architecture
+//!
+//! Submit pushes and updates PRs after the repo lock is released, so two
+//! worktrees of one repository can submit overlapping stacks at the same
+//! time and race each other's pushes and PR updates. Each submit therefore
+//! registers the branches it is about to submit here before pushing. A
+//! submit whose branches overlap an earlier claim waits for that submit to
+//! finish, then skips the branches it left up to date.
+//!
+//! Claims are repo-scoped, like the op-state, so submits from every worktree
+//! see each other. Claims are ordered by start time (then operation ID), so
+//! of two overlapping submits exactly one waits for the other.
+//!
+//! # Storage
+//!
+//! - `<common_dir>/lattice/submits/<op_id>.json` - The claimed branches
+//! - `<common_dir>/lattice/submits/<op_id>.lock` - Held while the submit runs
+//!
+//! # Invariants
+//!
+//! - The lock file is locked before the claim is written, so a claim whose
+//!   lock can be taken belongs to a submit that died; it is removed
+//! - A claim is removed when its guard is dropped
+//!
+//! # Example
+//!
+//! ```ignore
+//! use latticework::core::ops::submit_claim::SubmitClaim;
+//!
+//! let guard = SubmitClaim::register(&paths, work_dir, &branches)?;
+//! for earlier in guard.ahead(&paths)? {
+//!     // wait for `earlier` to finish
+//! }
+//! // push and update PRs; the claim is released when `guard` drops
+//! ```
+
+use std::cmp::Ordering;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::atomic;
+use crate::core::ops::journal::OpId;
+use crate::core::paths::LatticePaths;
+use crate::core::types::UtcTimestamp;
+
+/// Errors from submit claims.
+#[derive(Debug, Error)]
+pub enum ClaimError {
+    /// I/O error reading or writing a claim.
+    #[error("submit claim i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// JSON serialization/deserialization error.
+    #[error("submit claim json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The branches one running submit is pushing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitClaim {
+    /// Identifies the submit.
+    pub op_id: OpId,
+    /// Process id of the submit.
+    pub pid: u32,
+    /// Worktree the submit runs in; None for bare repositories.
+    pub work_dir: Option<PathBuf>,
+    /// When the claim was registered.
+    pub started_at: UtcTimestamp,
+    /// Branches being submitted, bottom-up.
+    pub branches: Vec<String>,
+}
+
+impl SubmitClaim {
+    /// Directory holding the claims.
+    pub fn dir(paths: &LatticePaths) -> PathBuf {
+        paths.repo_submits_dir()
+    }
+
+    /// Claim `branches` for the current process until the guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the claim cannot be written or locked.
+    pub fn register(
+        paths: &LatticePaths,
+        work_dir: Option<PathBuf>,
+        branches: &[String],
+    ) -> Result<ClaimGuard, ClaimError> {
+        let claim = SubmitClaim {
+            op_id: OpId::new(),
+            pid: std::process::id(),
+            work_dir,
+            started_at: UtcTimestamp::now(),
+            branches: branches.to_vec(),
+        };
+        let dir = Self::dir(paths);
+        fs::create_dir_all(&dir)?;
+
+        // Lock first: an unlocked claim is taken for a dead one
+        let lock_path = dir.join(format!("{}.lock", claim.op_id));
+        let file = open_lock(&lock_path)?;
+        file.lock_exclusive()?;
+
+        let path = dir.join(format!("{}.json", claim.op_id));
+        atomic::write(&path, serde_json::to_string_pretty(&claim)?.as_bytes())?;
+        Ok(ClaimGuard {
+            claim,
+            path,
+            lock_path,
+            file: Some(file),
+        })
+    }
+
+    /// Claims of submits still running, oldest first.
+    ///
+    /// Claims left behind by submits that died are removed.
+    pub fn live(paths: &LatticePaths) -> Result<Vec<SubmitClaim>, ClaimError> {
+        let dir = Self::dir(paths);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut claims = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            // A claim removed while listing has finished
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let Ok(claim) = serde_json::from_str::<SubmitClaim>(&content) else {
+                continue;
+            };
+            let lock_path = path.with_extension("lock");
+            if is_running(&lock_path) {
+                claims.push(claim);
+            } else {
+                let _ = fs::remove_file(&path);
+                let _ = fs::remove_file(&lock_path);
+            }
+        }
+        claims.sort_by(Self::order);
+        Ok(claims)
+    }
+
+    /// Branches claimed by both `self` and `other`, in `self`'s order.
+    pub fn overlap(&self, other: &SubmitClaim) -> Vec<String> {
+        self.branches
+            .iter()
+            .filter(|b| other.branches.contains(b))
+            .cloned()
+            .collect()
+    }
+
+    /// Order claims by start time, then operation ID.
+    fn order(a: &SubmitClaim, b: &SubmitClaim) -> Ordering {
+        a.started_at
+            .as_datetime()
+            .cmp(b.started_at.as_datetime())
+            .then_with(|| a.op_id.as_str().cmp(b.op_id.as_str()))
+    }
+}
+
+/// Holds a submit's claim; dropping it releases the branches.
+#[derive(Debug)]
+pub struct ClaimGuard {
+    claim: SubmitClaim,
+    path: PathBuf,
+    lock_path: PathBuf,
+    file: Option<File>,
+}
+
+impl ClaimGuard {
+    /// The claim held.
+    pub fn claim(&self) -> &SubmitClaim {
+        &self.claim
+    }
+
+    /// Running submits that claimed some of the same branches first.
+    pub fn ahead(&self, paths: &LatticePaths) -> Result<Vec<SubmitClaim>, ClaimError> {
+        Ok(SubmitClaim::live(paths)?
+            .into_iter()
+            .filter(|other| {
+                other.op_id != self.claim.op_id
+                    && SubmitClaim::order(other, &self.claim) == Ordering::Less
+                    && !self.claim.overlap(other).is_empty()
+            })
+            .collect())
+    }
+}
+
+impl Drop for ClaimGuard {
+    fn drop(&mut self) {
+        // Best-effort: a claim left behind is cleaned up as stale
+        let _ = fs::remove_file(&self.path);
+        if let Some(file) = self.file.take() {
+            let _ = fs::remove_file(&self.lock_path);
+            let _ = file.unlock();
+        }
+    }
+}
+
+/// Whether the submit holding the lock at `lock_path` is still running.
+fn is_running(lock_path: &Path) -> bool {
+    let Ok(file) = open_lock(lock_path) else {
+        return false;
+    };
+    match file.try_lock_exclusive() {
+        Ok(()) => {
+            let _ = file.unlock();
+            false
+        }
+        Err(_) => true,
+    }
+}
+
+fn open_lock(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn paths(dir: &TempDir) -> LatticePaths {
+        LatticePaths::new(dir.path().to_path_buf(), dir.path().to_path_buf())
+    }
+
+    fn branches(names: &[&str]) -> Vec<String> {
+        names.iter().map(|b| b.to_string()).collect()
+    }
+
+    #[test]
+    fn later_overlapping_claim_waits_for_earlier() {
+        let dir = TempDir::new().unwrap();
+        let paths = paths(&dir);
+        let first = SubmitClaim::register(&paths, None, &branches(&["a", "b"])).unwrap();
+        let second = SubmitClaim::register(&paths, None, &branches(&["b", "c"])).unwrap();
+        let other = SubmitClaim::register(&paths, None, &branches(&["d"])).unwrap();
+
+        assert!(first.ahead(&paths).unwrap().is_empty());
+        let ahead = second.ahead(&paths).unwrap();
+        assert_eq!(ahead, vec![first.claim().clone()]);
+        assert_eq!(second.claim().overlap(&ahead[0]), branches(&["b"]));
+        assert!(other.ahead(&paths).unwrap().is_empty());
+
+        drop(first);
+        assert!(second.ahead(&paths).unwrap().is_empty());
+        assert_eq!(SubmitClaim::live(&paths).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn claims_of_dead_submits_are_removed() {
+        let dir = TempDir::new().unwrap();
+        let paths = paths(&dir);
+        let guard = SubmitClaim::register(&paths, None, &branches(&["a"])).unwrap();
+
+        // A submit that died leaves its claim with nothing holding the lock
+        let claim = guard.claim().clone();
+        let path = guard.path.clone();
+        let mut guard = guard;
+        if let Some(file) = guard.file.take() {
+            file.unlock().unwrap();
+        }
+        std::mem::forget(guard);
+        assert!(path.exists());
+
+        assert!(SubmitClaim::live(&paths).unwrap().is_empty());
+        assert!(!path.exists());
+        assert!(!SubmitClaim::dir(&paths)
+            .join(format!("{}.lock", claim.op_id))
+            .exists());
+    }
+}
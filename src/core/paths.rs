@@ -21,6 +21,9 @@
 //! - `op-state.json` - Current operation marker
 //! - `ops/` - Operation journals
 //! - `cache/` - Optional cached data
+//! - `pre-push/` - Temporary worktree for pre-push checks
+//! - `edit/` - Todo and message files for `lattice edit`
+//! - `submits/` - Branches claimed by running submits
 //!
 //! # Example
 //!
@@ -173,6 +176,48 @@ impl LatticePaths {
         self.repo_ops_dir().join(format!("{}.json", op_id))
     }
 
+    /// Get the path to the queue of forge actions deferred while offline.
+    ///
+    /// This is `<common_dir>/lattice/pending-actions.json`.
+    pub fn repo_pending_actions_path(&self) -> PathBuf {
+        self.repo_lattice_dir().join("pending-actions.json")
+    }
+
+    /// Get the path to remembered navigation choices for branching stacks.
+    ///
+    /// This is `<common_dir>/lattice/nav.json`.
+    pub fn repo_nav_path(&self) -> PathBuf {
+        self.repo_lattice_dir().join("nav.json")
+    }
+
+    /// Get the path of the temporary worktree pre-push checks run in.
+    ///
+    /// This is `<common_dir>/lattice/pre-push/`.
+    pub fn repo_pre_push_worktree_path(&self) -> PathBuf {
+        self.repo_lattice_dir().join("pre-push")
+    }
+
+    /// Get the directory for the todo and message files of `lattice edit`.
+    ///
+    /// This is `<common_dir>/lattice/edit/`.
+    pub fn repo_edit_dir(&self) -> PathBuf {
+        self.repo_lattice_dir().join("edit")
+    }
+
+    /// Get the path to the guided branch edit in progress, if any.
+    ///
+    /// This is `<common_dir>/lattice/edit-session.json`.
+    pub fn repo_edit_session_path(&self) -> PathBuf {
+        self.repo_lattice_dir().join("edit-session.json")
+    }
+
+    /// Get the directory for the branch claims of running submits.
+    ///
+    /// This is `<common_dir>/lattice/submits/`.
+    pub fn repo_submits_dir(&self) -> PathBuf {
+        self.repo_lattice_dir().join("submits")
+    }
+
     /// Get the directory for cached data.
     ///
     /// This is `<common_dir>/lattice/cache/`.
@@ -293,6 +338,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn repo_pending_actions_path() {
+        let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
+        assert_eq!(
+            paths.repo_pending_actions_path(),
+            PathBuf::from("/repo/.git/lattice/pending-actions.json")
+        );
+    }
+
+    #[test]
+    fn repo_nav_path() {
+        let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
+        assert_eq!(
+            paths.repo_nav_path(),
+            PathBuf::from("/repo/.git/lattice/nav.json")
+        );
+    }
+
     #[test]
     fn repo_cache_dir() {
         let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
@@ -0,0 +1,231 @@
+//! core::remote_divergence
+//!
+//! Detection of branches that were pushed from somewhere else.
+//!
+//! # Design
+//!
+//! Working on one stack from two machines goes wrong quietly: each machine
+//! restacks and force-pushes its own copy, and whichever pushes last wins.
+//! Submit records the tip it pushed (`submitted.head_oid` in the branch
+//! metadata), so a remote tip that differs from it was pushed by someone
+//! else. That alone is not a problem when the local branch already has
+//! those changes, so [`changed_elsewhere`] compares the commits themselves:
+//!
+//! 1. A remote tip equal to the local tip, to the last pushed tip, or
+//!    reachable from the local tip loses nothing when overwritten.
+//! 2. Otherwise each remote commit since the fork point, apart from those
+//!    already on trunk, is looked up by patch ID among the local commits,
+//!    so commits that were only rebased (by a restack on either machine)
+//!    still match. Merge commits never match: a merge made on the other
+//!    machine is a change of its own.
+//!
+//! Only the newest [`MAX_COMPARED_COMMITS`] commits on each side are
+//! compared. Branches never pushed by submit, and remote tips with no
+//! history in common with the local branch, are not reported.
+
+use std::collections::HashSet;
+
+use crate::core::types::Oid;
+use crate::git::{Git, GitError};
+
+/// Number of commits per side compared by patch ID.
+pub const MAX_COMPARED_COMMITS: usize = 500;
+
+/// How far a branch and its remote copy have drifted apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// The branch's own commits that are not on the remote
+    pub local_only: usize,
+    /// Remote commits that are not on the local branch
+    pub remote_only: usize,
+}
+
+/// Decide whether `remote` has changes that pushing `local` would discard.
+///
+/// `base` is the branch's recorded base, so only the branch's own commits
+/// count as local-only, not commits it gained from a restack. Remote commits
+/// reachable from `trunk_tip` came from a restack on the other machine and
+/// don't count either. `last_pushed` is the tip this repository last
+/// pushed, if known.
+pub fn changed_elsewhere(
+    git: &Git,
+    base: &Oid,
+    local: &Oid,
+    remote: &Oid,
+    trunk_tip: &Oid,
+    last_pushed: Option<&Oid>,
+) -> Result<Option<Divergence>, GitError> {
+    let Some(last_pushed) = last_pushed else {
+        return Ok(None);
+    };
+    if remote == local || remote == last_pushed || git.is_ancestor(remote, local)? {
+        return Ok(None);
+    }
+    let Some(fork) = git.merge_base(local, remote)? else {
+        return Ok(None);
+    };
+
+    let local_ids: HashSet<_> = patch_ids(git, &fork, local, None)?.into_iter().collect();
+    let remote_ids = patch_ids(git, &fork, remote, Some(trunk_tip))?;
+    let remote_only = remote_ids
+        .iter()
+        .filter(|id| match id {
+            PatchId::Single(_) => !local_ids.contains(id),
+            PatchId::Empty => false,
+            PatchId::Merge => true,
+        })
+        .count();
+    if remote_only == 0 {
+        return Ok(None);
+    }
+
+    // Commits below the base came from the parent, not the branch
+    let own = if git.is_ancestor(base, local)? {
+        Some(base)
+    } else {
+        None
+    };
+    let remote_set: HashSet<_> = remote_ids.into_iter().collect();
+    let local_only = patch_ids(git, &fork, local, own)?
+        .into_iter()
+        .filter(|id| *id != PatchId::Empty && !remote_set.contains(id))
+        .count();
+
+    Ok(Some(Divergence {
+        local_only,
+        remote_only,
+    }))
+}
+
+/// What a single commit changes, for matching across rebases.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PatchId {
+    /// A non-merge commit with changes
+    Single(Oid),
+    /// A non-merge commit without changes
+    Empty,
+    /// A merge commit, which never matches another commit
+    Merge,
+}
+
+/// Patch IDs of the newest commits in `base..tip`, newest first, leaving
+/// out commits reachable from `skip`.
+fn patch_ids(
+    git: &Git,
+    base: &Oid,
+    tip: &Oid,
+    skip: Option<&Oid>,
+) -> Result<Vec<PatchId>, GitError> {
+    let mut ids = Vec::new();
+    for commit in git
+        .commits_between(base, tip)?
+        .into_iter()
+        .take(MAX_COMPARED_COMMITS)
+    {
+        if let Some(skip) = skip {
+            if git.is_ancestor(&commit.oid, skip)? {
+                continue;
+            }
+        }
+        let parents = git.commit_parents(&commit.oid)?;
+        let [parent] = parents.as_slice() else {
+            ids.push(PatchId::Merge);
+            continue;
+        };
+        ids.push(match git.patch_id(parent, &commit.oid)? {
+            Some(id) => PatchId::Single(id),
+            None => PatchId::Empty,
+        });
+    }
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    fn commit_file(dir: &Path, file: &str, content: &str) -> Oid {
+        std::fs::write(dir.join(file), content).unwrap();
+        run_git(dir, &["add", file]);
+        run_git(dir, &["commit", "-q", "-m", file]);
+        Oid::new(run_git(dir, &["rev-parse", "HEAD"])).unwrap()
+    }
+
+    /// A repo with `main` at a base commit and `feature` pushed at one
+    /// commit above it. Returns the base and the pushed tip; `feature` is
+    /// checked out.
+    fn repo() -> (TempDir, Oid, Oid) {
+        let dir = TempDir::new().unwrap();
+        run_git(dir.path(), &["init", "-q", "-b", "main"]);
+        run_git(dir.path(), &["config", "user.email", "test@example.com"]);
+        run_git(dir.path(), &["config", "user.name", "Test User"]);
+        let base = commit_file(dir.path(), "README.md", "# Test\n");
+        run_git(dir.path(), &["checkout", "-q", "-b", "feature"]);
+        let pushed = commit_file(dir.path(), "a.txt", "a\n");
+        (dir, base, pushed)
+    }
+
+    #[test]
+    fn commits_added_on_both_sides_diverge() {
+        let (dir, base, pushed) = repo();
+        let remote = commit_file(dir.path(), "remote.txt", "remote\n");
+        run_git(dir.path(), &["reset", "-q", "--hard", pushed.as_str()]);
+        commit_file(dir.path(), "b.txt", "b\n");
+        let local = commit_file(dir.path(), "c.txt", "c\n");
+
+        let git = Git::open(dir.path()).unwrap();
+        assert_eq!(
+            changed_elsewhere(&git, &base, &local, &remote, &base, Some(&pushed)).unwrap(),
+            Some(Divergence {
+                local_only: 2,
+                remote_only: 1
+            })
+        );
+        // Without a record of the last push there is nothing to compare to
+        assert_eq!(
+            changed_elsewhere(&git, &base, &local, &remote, &base, None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn rebased_or_own_pushes_are_not_divergence() {
+        let (dir, base, pushed) = repo();
+        // The other machine rebased the same commit onto a newer main
+        run_git(dir.path(), &["checkout", "-q", "main"]);
+        let trunk = commit_file(dir.path(), "main.txt", "main\n");
+        run_git(dir.path(), &["checkout", "-q", "-b", "rebased"]);
+        run_git(dir.path(), &["cherry-pick", pushed.as_str()]);
+        let remote = Oid::new(run_git(dir.path(), &["rev-parse", "HEAD"])).unwrap();
+        run_git(dir.path(), &["checkout", "-q", "feature"]);
+        let local = commit_file(dir.path(), "b.txt", "b\n");
+
+        let git = Git::open(dir.path()).unwrap();
+        assert_eq!(
+            changed_elsewhere(&git, &base, &local, &remote, &trunk, Some(&pushed)).unwrap(),
+            None
+        );
+        // The remote is what we pushed, or local already builds on it
+        assert_eq!(
+            changed_elsewhere(&git, &base, &local, &remote, &trunk, Some(&remote)).unwrap(),
+            None
+        );
+        assert_eq!(
+            changed_elsewhere(&git, &base, &local, &pushed, &trunk, Some(&base)).unwrap(),
+            None
+        );
+    }
+}
@@ -247,6 +247,62 @@ impl fmt::Display for ConfigChange {
     }
 }
 
+/// A change to a branch on a remote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteChange {
+    /// Overwrite the remote branch with the local branch.
+    ///
+    /// The push is leased on the remote-tracking ref, so it fails if the
+    /// remote branch moved since it was last fetched.
+    ForcePush {
+        /// Remote name (e.g., "origin").
+        remote: String,
+        /// Branch name.
+        branch: String,
+        /// OID the remote branch is expected to have.
+        old_oid: String,
+        /// Local OID being pushed.
+        new_oid: String,
+    },
+
+    /// Change the branch a PR targets, through the forge API.
+    RetargetPr {
+        /// PR number.
+        number: u64,
+        /// Base the PR targets now.
+        old_base: String,
+        /// Base to target instead.
+        new_base: String,
+    },
+}
+
+impl fmt::Display for RemoteChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteChange::ForcePush {
+                remote,
+                branch,
+                old_oid,
+                new_oid,
+            } => {
+                write!(
+                    f,
+                    "force-push {}/{} {} -> {}",
+                    remote,
+                    branch,
+                    &old_oid[..8.min(old_oid.len())],
+                    &new_oid[..8.min(new_oid.len())]
+                )
+            }
+            RemoteChange::RetargetPr {
+                number,
+                old_base,
+                new_base,
+            } => write!(f, "retarget PR #{} {} -> {}", number, old_base, new_base),
+        }
+    }
+}
+
 /// A preview of what a fix will do.
 ///
 /// This is shown to the user before they confirm applying a fix.
@@ -258,6 +314,8 @@ pub struct FixPreview {
     pub metadata_changes: Vec<MetadataChange>,
     /// Config changes that will be made.
     pub config_changes: Vec<ConfigChange>,
+    /// Remote changes that will be made.
+    pub remote_changes: Vec<RemoteChange>,
     /// Human-readable summary of the fix.
     pub summary: String,
 }
@@ -294,16 +352,26 @@ impl FixPreview {
         self
     }
 
+    /// Add a remote change to the preview.
+    pub fn add_remote_change(mut self, change: RemoteChange) -> Self {
+        self.remote_changes.push(change);
+        self
+    }
+
     /// Check if this preview has any changes.
     pub fn has_changes(&self) -> bool {
         !self.ref_changes.is_empty()
             || !self.metadata_changes.is_empty()
             || !self.config_changes.is_empty()
+            || !self.remote_changes.is_empty()
     }
 
     /// Get the total number of changes.
     pub fn change_count(&self) -> usize {
-        self.ref_changes.len() + self.metadata_changes.len() + self.config_changes.len()
+        self.ref_changes.len()
+            + self.metadata_changes.len()
+            + self.config_changes.len()
+            + self.remote_changes.len()
     }
 
     /// Format the preview for display.
@@ -335,6 +403,13 @@ impl FixPreview {
             }
         }
 
+        if !self.remote_changes.is_empty() {
+            lines.push("  Remote changes:".to_string());
+            for change in &self.remote_changes {
+                lines.push(format!("    - {}", change));
+            }
+        }
+
         lines.join("\n")
     }
 }
@@ -582,6 +657,34 @@ mod tests {
         }
     }
 
+    mod remote_change {
+        use super::*;
+
+        #[test]
+        fn force_push_display() {
+            let change = RemoteChange::ForcePush {
+                remote: "origin".to_string(),
+                branch: "feature".to_string(),
+                old_oid: "abc123def456".to_string(),
+                new_oid: "fed654cba321".to_string(),
+            };
+            assert_eq!(
+                format!("{}", change),
+                "force-push origin/feature abc123de -> fed654cb"
+            );
+        }
+
+        #[test]
+        fn retarget_pr_display() {
+            let change = RemoteChange::RetargetPr {
+                number: 42,
+                old_base: "feature-a".to_string(),
+                new_base: "main".to_string(),
+            };
+            assert_eq!(format!("{}", change), "retarget PR #42 feature-a -> main");
+        }
+    }
+
     mod fix_preview {
         use super::*;
 
@@ -640,6 +743,12 @@ mod tests {
                 .add_config_change(ConfigChange::Set {
                     key: "key".to_string(),
                     value: "value".to_string(),
+                })
+                .add_remote_change(RemoteChange::ForcePush {
+                    remote: "origin".to_string(),
+                    branch: "test".to_string(),
+                    old_oid: "abc123".to_string(),
+                    new_oid: "def456".to_string(),
                 });
 
             let formatted = preview.format();
@@ -647,6 +756,8 @@ mod tests {
             assert!(formatted.contains("Ref changes"));
             assert!(formatted.contains("Metadata changes"));
             assert!(formatted.contains("Config changes"));
+            assert!(formatted.contains("Remote changes"));
+            assert_eq!(preview.change_count(), 4);
         }
     }
 
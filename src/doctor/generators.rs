@@ -13,11 +13,18 @@
 //! - A plan preview (what changes will be made)
 //! - A concrete repair plan (generated when the fix is selected)
 
+use crate::core::branch_lock::BRANCH_LOCK_REF_PREFIX;
+use crate::core::metadata::SCHEMA_VERSION;
 use crate::engine::capabilities::Capability;
-use crate::engine::health::{Evidence, Issue};
+use std::collections::BTreeMap;
+
+use crate::core::types::Oid;
+use crate::engine::health::{ChainedPrInfo, Evidence, Issue};
 use crate::engine::scan::RepoSnapshot;
 
-use super::fixes::{ConfigChange, FixId, FixOption, FixPreview, MetadataChange, RefChange};
+use super::fixes::{
+    ConfigChange, FixId, FixOption, FixPreview, MetadataChange, RefChange, RemoteChange,
+};
 
 /// Generate fix options for an issue.
 ///
@@ -49,6 +56,19 @@ pub fn generate_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption>
         "lattice-op-in-progress" => generate_lattice_op_fixes(issue, snapshot),
         "git-op-in-progress" => generate_git_op_fixes(issue, snapshot),
         "config-migration" => generate_config_migration_fixes(issue, snapshot),
+        "branch-remote-missing" => generate_branch_remote_missing_fixes(issue, snapshot),
+        "remote-branch-merged" | "remote-branch-deleted" => {
+            generate_remote_branch_gone_fixes(issue, snapshot)
+        }
+        "metadata-unsigned" | "metadata-signature-invalid" => {
+            generate_metadata_sign_fixes(issue, snapshot)
+        }
+        "metadata-schema-outdated" => generate_metadata_upgrade_fixes(issue, snapshot),
+        "stale-branch-lock" => generate_stale_lock_fixes(issue, snapshot),
+        "remote-tracking-behind" | "remote-tracking-diverged" => {
+            generate_remote_tracking_fixes(issue, snapshot)
+        }
+        "pr-base-deleted" => generate_pr_base_deleted_fixes(issue, snapshot),
         // Bootstrap fix generators (Milestone 5.4)
         "remote-pr-branch-untracked" => generate_track_existing_from_pr_fixes(issue, snapshot),
         "remote-pr-branch-missing" => generate_fetch_and_track_pr_fixes(issue, snapshot),
@@ -57,6 +77,8 @@ pub fn generate_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption>
         "untracked-branch" => generate_import_local_topology_fixes(issue, snapshot),
         // Synthetic stack snapshot materialization (Milestone 5.9)
         "synthetic-stack-head" => generate_materialize_snapshot_fixes(issue, snapshot),
+        // Remote-only stacks (deep remote analysis)
+        "remote-pr-chain-missing" => generate_import_pr_chain_fixes(issue, snapshot),
         _ => Vec::new(), // Unknown issue type
     }
 }
@@ -439,6 +461,452 @@ fn generate_orphaned_metadata_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> V
     fixes
 }
 
+/// Generate fixes for a branch remote override that names a missing remote.
+///
+/// Fix options:
+/// 1. Clear the override so the branch uses the default remote
+fn generate_branch_remote_missing_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let _ = snapshot;
+
+    let branch = issue
+        .evidence
+        .iter()
+        .find_map(|e| match e {
+            Evidence::Ref { name, .. } => name.strip_prefix("refs/branch-metadata/"),
+            _ => None,
+        })
+        .unwrap_or("unknown");
+    let remote = issue
+        .evidence
+        .iter()
+        .find_map(|e| match e {
+            Evidence::Config { key, .. } => key
+                .strip_prefix("remote.")
+                .and_then(|k| k.strip_suffix(".url")),
+            _ => None,
+        })
+        .unwrap_or("unknown");
+
+    vec![FixOption::new(
+        FixId::new("branch-remote-missing", "clear", branch),
+        issue.id.clone(),
+        format!("Clear remote override for '{}'", branch),
+        FixPreview::with_summary(format!(
+            "Push '{}' to the default remote instead of '{}'",
+            branch, remote
+        ))
+        .add_metadata_change(MetadataChange::Update {
+            branch: branch.to_string(),
+            field: "remote".to_string(),
+            old_value: Some(remote.to_string()),
+            new_value: String::new(),
+        }),
+    )
+    .with_precondition(Capability::RepoOpen)]
+}
+
+/// Generate fixes for a submitted branch whose remote branch is gone.
+///
+/// Fix options:
+/// 1. Unlink the PR so the next submit pushes the branch again
+///    (only when it was deleted without merging)
+/// 2. Delete the local branch and its metadata, reparenting its children
+///    (not offered for the current branch)
+fn generate_remote_branch_gone_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let mut fixes = Vec::new();
+
+    let branch = issue
+        .evidence
+        .iter()
+        .find_map(|e| match e {
+            Evidence::Ref { name, .. } => name.strip_prefix("refs/branch-metadata/"),
+            _ => None,
+        })
+        .unwrap_or("unknown");
+    let issue_type = extract_issue_type(issue.id.as_str());
+    let Ok(branch_name) = crate::core::types::BranchName::new(branch) else {
+        return fixes;
+    };
+    let Some(scanned) = snapshot.metadata.get(&branch_name) else {
+        return fixes;
+    };
+
+    if issue_type == "remote-branch-deleted" {
+        if let crate::core::metadata::schema::PrState::Linked { number, .. } = &scanned.metadata.pr
+        {
+            fixes.push(
+                FixOption::new(
+                    FixId::new(issue_type, "unlink", branch),
+                    issue.id.clone(),
+                    format!("Unlink PR #{} from '{}'", number, branch),
+                    FixPreview::with_summary(format!(
+                        "Forget PR #{} so the next submit pushes '{}' again and opens a new PR",
+                        number, branch
+                    ))
+                    .add_metadata_change(MetadataChange::Update {
+                        branch: branch.to_string(),
+                        field: "pr".to_string(),
+                        old_value: Some(format!("linked(#{})", number)),
+                        new_value: "none".to_string(),
+                    }),
+                )
+                .with_precondition(Capability::RepoOpen),
+            );
+        }
+    }
+
+    let Some(tip) = snapshot.branches.get(&branch_name) else {
+        return fixes;
+    };
+    if snapshot.current_branch.as_ref() == Some(&branch_name) {
+        return fixes;
+    }
+
+    let parent = scanned.metadata.parent.name();
+    let mut children: Vec<&str> = snapshot
+        .metadata
+        .iter()
+        .filter(|(_, child)| child.metadata.parent.name() == branch)
+        .map(|(name, _)| name.as_str())
+        .collect();
+    children.sort();
+
+    let mut preview = FixPreview::with_summary(if children.is_empty() {
+        format!("Delete '{}' and its metadata", branch)
+    } else {
+        format!(
+            "Delete '{}' and its metadata, moving {} onto '{}'",
+            branch,
+            children.join(", "),
+            parent
+        )
+    })
+    .add_ref_change(RefChange::Delete {
+        ref_name: format!("refs/heads/{}", branch),
+        old_oid: tip.as_str().to_string(),
+    })
+    .add_metadata_change(MetadataChange::Delete {
+        branch: branch.to_string(),
+    });
+    for child in children {
+        preview = preview.add_metadata_change(MetadataChange::Update {
+            branch: child.to_string(),
+            field: "parent".to_string(),
+            old_value: Some(branch.to_string()),
+            new_value: parent.to_string(),
+        });
+    }
+
+    fixes.push(
+        FixOption::new(
+            FixId::new(issue_type, "delete", branch),
+            issue.id.clone(),
+            format!("Delete local branch '{}'", branch),
+            preview,
+        )
+        .with_preconditions([Capability::RepoOpen, Capability::WorkingDirectoryAvailable]),
+    );
+
+    fixes
+}
+
+/// Generate fixes for unsigned or badly signed metadata.
+///
+/// Fix options:
+/// 1. Sign the current metadata with the user's key
+///
+/// For an invalid signature this vouches for metadata someone else may
+/// have changed, so the description asks for a review first.
+fn generate_metadata_sign_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let _ = snapshot;
+
+    let branch = issue
+        .evidence
+        .iter()
+        .find_map(|e| match e {
+            Evidence::Ref { name, .. } => name.strip_prefix("refs/branch-metadata/"),
+            _ => None,
+        })
+        .unwrap_or("unknown");
+    let issue_type = extract_issue_type(issue.id.as_str());
+
+    let summary = if issue_type == "metadata-signature-invalid" {
+        format!(
+            "Re-sign metadata for '{}' as it is now; review it with 'lattice info {}' first",
+            branch, branch
+        )
+    } else {
+        format!("Sign metadata for '{}' with your signing key", branch)
+    };
+
+    vec![FixOption::new(
+        FixId::new(issue_type, "sign", branch),
+        issue.id.clone(),
+        format!("Sign metadata for '{}'", branch),
+        FixPreview::with_summary(summary).add_metadata_change(MetadataChange::Update {
+            branch: branch.to_string(),
+            field: "signature".to_string(),
+            old_value: None,
+            new_value: "signed".to_string(),
+        }),
+    )
+    .with_precondition(Capability::RepoOpen)]
+}
+
+/// Generate fixes for metadata stored by an older schema version.
+///
+/// Fix options:
+/// 1. Rewrite the metadata at the current schema version
+fn generate_metadata_upgrade_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let _ = snapshot;
+
+    let branch = issue
+        .evidence
+        .iter()
+        .find_map(|e| match e {
+            Evidence::Ref { name, .. } => name.strip_prefix("refs/branch-metadata/"),
+            _ => None,
+        })
+        .unwrap_or("unknown");
+
+    vec![FixOption::new(
+        FixId::new("metadata-schema-outdated", "upgrade", branch),
+        issue.id.clone(),
+        format!("Upgrade metadata for '{}'", branch),
+        FixPreview::with_summary(format!(
+            "Rewrite metadata for '{}' as schema v{}",
+            branch, SCHEMA_VERSION
+        ))
+        .add_metadata_change(MetadataChange::Update {
+            branch: branch.to_string(),
+            field: "schema_version".to_string(),
+            old_value: None,
+            new_value: SCHEMA_VERSION.to_string(),
+        }),
+    )
+    .with_precondition(Capability::MetadataReadable)]
+}
+
+/// Generate fixes for an expired branch lock.
+///
+/// Fix options:
+/// 1. Delete the lock ref
+fn generate_stale_lock_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let _ = snapshot;
+
+    let Some((ref_name, oid)) = issue.evidence.iter().find_map(|e| match e {
+        Evidence::Ref {
+            name,
+            oid: Some(oid),
+        } if name.starts_with(BRANCH_LOCK_REF_PREFIX) => Some((name, oid)),
+        _ => None,
+    }) else {
+        return Vec::new();
+    };
+    let branch = &ref_name[BRANCH_LOCK_REF_PREFIX.len()..];
+
+    vec![FixOption::new(
+        FixId::new("stale-branch-lock", "delete", branch),
+        issue.id.clone(),
+        format!("Delete the expired lock on '{}'", branch),
+        FixPreview::with_summary(format!("Remove the expired lock ref for '{}'", branch))
+            .add_ref_change(RefChange::Delete {
+                ref_name: ref_name.clone(),
+                old_oid: oid.clone(),
+            }),
+    )
+    .with_precondition(Capability::RepoOpen)]
+}
+
+/// Generate fixes for a branch behind or diverged from its remote branch.
+///
+/// Fix options:
+/// 1. Fast-forward the local branch to the remote tip
+///    (behind only; not offered for a checked-out branch)
+/// 2. Force-push the local branch over the remote commits
+///    (diverged only; not offered for a frozen branch)
+/// 3. Freeze the branch until it is refreshed with `lattice get`
+///
+/// No fixes are offered once the local branch has moved since the scan.
+fn generate_remote_tracking_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    use crate::core::metadata::schema::FREEZE_REASON_REMOTE_DIVERGED;
+
+    let mut fixes = Vec::new();
+
+    let refs: Vec<(&str, &str)> = issue
+        .evidence
+        .iter()
+        .filter_map(|e| match e {
+            Evidence::Ref {
+                name,
+                oid: Some(oid),
+            } => Some((name.as_str(), oid.as_str())),
+            _ => None,
+        })
+        .collect();
+    let Some((branch, local_oid)) = refs
+        .iter()
+        .find_map(|(name, oid)| Some((name.strip_prefix("refs/heads/")?, *oid)))
+    else {
+        return fixes;
+    };
+    let Some((remote, remote_oid)) = refs.iter().find_map(|(name, oid)| {
+        let remote = name
+            .strip_prefix("refs/remotes/")?
+            .strip_suffix(branch)?
+            .strip_suffix('/')?;
+        Some((remote, *oid))
+    }) else {
+        return fixes;
+    };
+    let issue_type = extract_issue_type(issue.id.as_str());
+    let Ok(branch_name) = crate::core::types::BranchName::new(branch) else {
+        return fixes;
+    };
+    let Some(scanned) = snapshot.metadata.get(&branch_name) else {
+        return fixes;
+    };
+    if snapshot.branches.get(&branch_name).map(|o| o.as_str()) != Some(local_oid) {
+        return fixes;
+    }
+    let frozen = scanned.metadata.freeze.is_frozen();
+
+    let checked_out = snapshot.current_branch.as_ref() == Some(&branch_name)
+        || snapshot.checked_out_elsewhere.contains_key(&branch_name);
+    if issue_type == "remote-tracking-behind" && !checked_out {
+        fixes.push(
+            FixOption::new(
+                FixId::new(issue_type, "fast-forward", branch),
+                issue.id.clone(),
+                format!("Fast-forward '{}' to '{}/{}'", branch, remote, branch),
+                FixPreview::with_summary(format!(
+                    "Move '{}' to the commits pushed to '{}'",
+                    branch, remote
+                ))
+                .add_ref_change(RefChange::Update {
+                    ref_name: format!("refs/heads/{}", branch),
+                    old_oid: Some(local_oid.to_string()),
+                    new_oid: remote_oid.to_string(),
+                }),
+            )
+            .with_precondition(Capability::RepoOpen),
+        );
+    }
+
+    if issue_type == "remote-tracking-diverged" && !frozen {
+        fixes.push(
+            FixOption::new(
+                FixId::new(issue_type, "force-push", branch),
+                issue.id.clone(),
+                format!("Force-push '{}' to '{}'", branch, remote),
+                FixPreview::with_summary(format!(
+                    "Overwrite '{}/{}' with the local branch, discarding the remote-only commits",
+                    remote, branch
+                ))
+                .add_remote_change(RemoteChange::ForcePush {
+                    remote: remote.to_string(),
+                    branch: branch.to_string(),
+                    old_oid: remote_oid.to_string(),
+                    new_oid: local_oid.to_string(),
+                }),
+            )
+            .with_precondition(Capability::RepoOpen),
+        );
+    }
+
+    fixes.push(
+        FixOption::new(
+            FixId::new(issue_type, "mark-get", branch),
+            issue.id.clone(),
+            format!("Mark '{}' as needing 'lattice get'", branch),
+            FixPreview::with_summary(format!(
+                "Freeze '{}' so it isn't restacked or pushed over the remote commits; refresh it with 'lattice get {} --force'",
+                branch, branch
+            ))
+            .add_metadata_change(MetadataChange::Update {
+                branch: branch.to_string(),
+                field: "freeze".to_string(),
+                old_value: Some(if frozen { "frozen" } else { "unfrozen" }.to_string()),
+                new_value: FREEZE_REASON_REMOTE_DIVERGED.to_string(),
+            }),
+        )
+        .with_precondition(Capability::RepoOpen),
+    );
+
+    fixes
+}
+
+/// Generate fixes for an open PR whose base branch was deleted.
+///
+/// Fix options:
+/// 1. Retarget the PR to the nearest ancestor still on the remote
+///    (when that isn't trunk)
+/// 2. Retarget the PR to trunk
+///
+/// When the PR's head branch is tracked, its parent is updated to match,
+/// so the next restack moves it onto the new base.
+fn generate_pr_base_deleted_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let Some((number, head_ref, base_ref, surviving_base)) =
+        issue.evidence.iter().find_map(|e| match e {
+            Evidence::DeletedPrBase {
+                number,
+                head_ref,
+                base_ref,
+                surviving_base,
+            } => Some((*number, head_ref, base_ref, surviving_base)),
+            _ => None,
+        })
+    else {
+        return Vec::new();
+    };
+    let Some(trunk) = snapshot.trunk.as_ref() else {
+        return Vec::new();
+    };
+    let issue_type = extract_issue_type(issue.id.as_str());
+    let tracked = crate::core::types::BranchName::new(head_ref)
+        .ok()
+        .and_then(|branch| snapshot.metadata.get(&branch));
+
+    let mut targets = Vec::new();
+    if surviving_base != trunk.as_str() {
+        targets.push(("retarget-ancestor", surviving_base.as_str()));
+    }
+    targets.push(("retarget-trunk", trunk.as_str()));
+
+    targets
+        .into_iter()
+        .map(|(action, new_base)| {
+            let mut preview = FixPreview::with_summary(format!(
+                "Change PR #{} to target '{}' instead of the deleted '{}'",
+                number, new_base, base_ref
+            ))
+            .add_remote_change(RemoteChange::RetargetPr {
+                number,
+                old_base: base_ref.clone(),
+                new_base: new_base.to_string(),
+            });
+            if let Some(scanned) = tracked {
+                let old_parent = scanned.metadata.parent.name();
+                if old_parent != new_base {
+                    preview = preview.add_metadata_change(MetadataChange::Update {
+                        branch: head_ref.clone(),
+                        field: "parent".to_string(),
+                        old_value: Some(old_parent.to_string()),
+                        new_value: new_base.to_string(),
+                    });
+                }
+            }
+            FixOption::new(
+                FixId::new(issue_type, action, head_ref),
+                issue.id.clone(),
+                format!("Retarget PR #{} to '{}'", number, new_base),
+                preview,
+            )
+            .with_precondition(Capability::RepoOpen)
+        })
+        .collect()
+}
+
 /// Generate fixes for Lattice operation in progress.
 ///
 /// Fix options:
@@ -1275,6 +1743,208 @@ pub fn generate_materialize_snapshot_fixes(
     ])]
 }
 
+// =============================================================================
+// Remote-Only Stack Import (Deep Remote Analysis)
+// =============================================================================
+
+/// Find chains of open PRs none of whose branches exist locally.
+///
+/// This is Tier 2 analysis over the open PRs the remote scan found. A chain
+/// starts at a PR targeting trunk or a tracked branch and follows every open
+/// PR based on a chain member's head. Chains of a single PR are left to the
+/// per-PR `remote-pr-branch-missing` fix.
+///
+/// # Arguments
+///
+/// * `snapshot` - Snapshot from `scan_with_remote` (with `remote_prs`)
+/// * `remote_heads` - Branch tips on the remote, from `Git::remote_heads`;
+///   PRs whose head isn't there are skipped
+///
+/// # Returns
+///
+/// One `remote-pr-chain-missing` issue per chain.
+pub fn analyze_remote_pr_chains(
+    snapshot: &RepoSnapshot,
+    remote_heads: &BTreeMap<String, Oid>,
+) -> Vec<Issue> {
+    use crate::core::types::BranchName;
+    use crate::engine::health::issues;
+
+    let Some(evidence) = &snapshot.remote_prs else {
+        return Vec::new();
+    };
+
+    // Open PRs whose head exists only on the remote, by head branch
+    let mut missing: BTreeMap<&str, ChainedPrInfo> = BTreeMap::new();
+    for pr in &evidence.prs {
+        if pr.is_fork() {
+            continue;
+        }
+        let Ok(branch) = BranchName::new(&pr.head_ref) else {
+            continue;
+        };
+        if snapshot.branches.contains_key(&branch) {
+            continue;
+        }
+        let Some(tip) = remote_heads.get(&pr.head_ref) else {
+            continue;
+        };
+        missing
+            .entry(pr.head_ref.as_str())
+            .or_insert(ChainedPrInfo {
+                number: pr.number,
+                head_ref: pr.head_ref.clone(),
+                base_ref: pr.base_ref.clone(),
+                url: pr.url.clone(),
+                tip: tip.as_str().to_string(),
+            });
+    }
+
+    let anchored = |base: &str| {
+        snapshot.trunk.as_ref().map(|t| t.as_str()) == Some(base)
+            || BranchName::new(base).is_ok_and(|b| snapshot.metadata.contains_key(&b))
+    };
+
+    let mut found = Vec::new();
+    for root in missing.values() {
+        if missing.contains_key(root.base_ref.as_str()) || !anchored(&root.base_ref) {
+            continue;
+        }
+
+        // Walk the chain parents first, children in PR number order
+        let mut chain = Vec::new();
+        let mut queue = std::collections::VecDeque::from([root.clone()]);
+        while let Some(pr) = queue.pop_front() {
+            let mut children: Vec<_> = missing
+                .values()
+                .filter(|child| child.base_ref == pr.head_ref)
+                .cloned()
+                .collect();
+            children.sort_by_key(|child| child.number);
+            queue.extend(children);
+            chain.push(pr);
+        }
+
+        if chain.len() > 1 {
+            found.push(issues::remote_pr_chain_missing(&root.base_ref, chain));
+        }
+    }
+    found
+}
+
+/// Render a remote PR chain as a tree under the branch it's based on.
+///
+/// # Example
+///
+/// ```
+/// use latticework::doctor::render_pr_chain_tree;
+/// use latticework::engine::health::ChainedPrInfo;
+///
+/// let pr = |number, head: &str, base: &str| ChainedPrInfo {
+///     number,
+///     head_ref: head.to_string(),
+///     base_ref: base.to_string(),
+///     url: String::new(),
+///     tip: String::new(),
+/// };
+/// let tree = render_pr_chain_tree("main", &[pr(1, "a", "main"), pr(2, "b", "a")]);
+/// assert_eq!(tree, "main\n└── a (#1)\n    └── b (#2)");
+/// ```
+pub fn render_pr_chain_tree(root_base: &str, prs: &[ChainedPrInfo]) -> String {
+    fn render(base: &str, prefix: &str, prs: &[ChainedPrInfo], lines: &mut Vec<String>) {
+        let children: Vec<_> = prs.iter().filter(|pr| pr.base_ref == base).collect();
+        for (i, pr) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            lines.push(format!(
+                "{}{}{} (#{})",
+                prefix, branch, pr.head_ref, pr.number
+            ));
+            render(&pr.head_ref, &format!("{}{}", prefix, indent), prs, lines);
+        }
+    }
+
+    let mut lines = vec![root_base.to_string()];
+    render(root_base, "", prs, &mut lines);
+    lines.join("\n")
+}
+
+/// Generate the fix importing a remote-only PR chain.
+///
+/// This handles `remote-pr-chain-missing` issues found by deep remote
+/// analysis. The single fix fetches every branch in the chain and tracks
+/// each one frozen (teammate branches), with its PR's base as parent and
+/// linked to its PR. A branch's base is its parent's remote tip, so the
+/// imported stack is consistent before any restack.
+fn generate_import_pr_chain_fixes(issue: &Issue, snapshot: &RepoSnapshot) -> Vec<FixOption> {
+    let Some((root_base, prs)) = issue.evidence.iter().find_map(|ev| match ev {
+        Evidence::RemotePrChain { root_base, prs } => Some((root_base, prs)),
+        _ => None,
+    }) else {
+        return Vec::new();
+    };
+    let Some(root) = prs.first() else {
+        return Vec::new();
+    };
+
+    // The chain may have been fetched since the analysis ran
+    if prs.iter().any(|pr| {
+        crate::core::types::BranchName::new(&pr.head_ref)
+            .map_or(true, |b| snapshot.branches.contains_key(&b))
+    }) {
+        return Vec::new();
+    }
+
+    let mut preview = FixPreview::with_summary(format!(
+        "Fetch {} branches from open PRs and track them as a frozen stack:\n{}",
+        prs.len(),
+        render_pr_chain_tree(root_base, prs)
+    ));
+    for pr in prs {
+        preview = preview.add_ref_change(RefChange::Create {
+            ref_name: format!("refs/heads/{}", pr.head_ref),
+            new_oid: "(fetched from remote)".to_string(),
+        });
+    }
+    for pr in prs {
+        // The bottom branch is based on its parent's local tip, the rest on
+        // the fetched tip of the chain member below them
+        let base = prs
+            .iter()
+            .find(|parent| parent.head_ref == pr.base_ref)
+            .map(|parent| format!(", base={}", parent.tip))
+            .unwrap_or_default();
+        preview = preview.add_metadata_change(MetadataChange::Create {
+            branch: pr.head_ref.clone(),
+            description: format!(
+                "parent={}{}, pr=#{}, frozen (teammate_branch)",
+                pr.base_ref, base, pr.number
+            ),
+        });
+    }
+
+    vec![FixOption::new(
+        FixId::new("remote-pr-chain-missing", "import-chain", &root.head_ref),
+        issue.id.clone(),
+        format!(
+            "Fetch and track the {} stacked PRs on '{}' (frozen)",
+            prs.len(),
+            root_base
+        ),
+        preview,
+    )
+    .with_preconditions([
+        Capability::RepoOpen,
+        Capability::TrunkKnown,
+        Capability::AuthAvailable,
+        Capability::RemoteResolved,
+    ])]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1304,6 +1974,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: Some(BranchName::new("main").unwrap()),
             branches,
             metadata: HashMap::new(),
@@ -1354,6 +2025,112 @@ mod tests {
         assert!(fixes.iter().any(|f| f.description.contains("Delete")));
     }
 
+    #[test]
+    fn branch_remote_missing_fixes() {
+        let issue = issues::branch_remote_missing("feature", "security");
+        let snapshot = minimal_snapshot();
+
+        let fixes = generate_fixes(&issue, &snapshot);
+        assert_eq!(fixes.len(), 1);
+        assert!(fixes[0].description.contains("feature"));
+        assert!(fixes[0].preview.summary.contains("security"));
+    }
+
+    #[test]
+    fn remote_branch_gone_fixes() {
+        use crate::core::metadata::schema::{BranchMetadataV2, PrState};
+        use crate::core::types::{BranchName, Oid};
+        use crate::engine::scan::ScannedMetadata;
+
+        let mut snapshot = minimal_snapshot();
+        let oid = Oid::new("def456def4567890def456def4567890def45678").unwrap();
+        for (name, parent) in [("feature", "main"), ("child", "feature")] {
+            let branch = BranchName::new(name).unwrap();
+            let parent = BranchName::new(parent).unwrap();
+            let mut metadata = BranchMetadataV2::new(branch.clone(), parent, oid.clone());
+            metadata.pr = PrState::linked("github", 7, "");
+            snapshot.branches.insert(branch.clone(), oid.clone());
+            snapshot.metadata.insert(
+                branch,
+                ScannedMetadata {
+                    ref_oid: oid.clone(),
+                    metadata,
+                },
+            );
+        }
+
+        let merged = generate_fixes(
+            &issues::remote_branch_merged("feature", "origin"),
+            &snapshot,
+        );
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0]
+            .id
+            .to_string()
+            .starts_with("remote-branch-merged:delete"));
+        assert!(merged[0]
+            .preview
+            .summary
+            .contains("moving child onto 'main'"));
+        assert_eq!(merged[0].preview.metadata_changes.len(), 2);
+
+        let deleted = generate_fixes(
+            &issues::remote_branch_deleted("feature", "origin"),
+            &snapshot,
+        );
+        assert_eq!(deleted.len(), 2);
+        assert!(deleted[0].description.contains("PR #7"));
+
+        // The checked-out branch can't be deleted from under the user
+        snapshot.current_branch = Some(BranchName::new("feature").unwrap());
+        let current = generate_fixes(
+            &issues::remote_branch_deleted("feature", "origin"),
+            &snapshot,
+        );
+        assert_eq!(current.len(), 1);
+        assert!(current[0].id.to_string().contains(":unlink:"));
+    }
+
+    #[test]
+    fn metadata_signature_fixes_offer_signing() {
+        let snapshot = minimal_snapshot();
+
+        let unsigned = generate_fixes(&issues::metadata_unsigned("feature"), &snapshot);
+        assert_eq!(unsigned.len(), 1);
+        assert!(unsigned[0]
+            .id
+            .to_string()
+            .starts_with("metadata-unsigned:sign"));
+
+        let invalid = generate_fixes(
+            &issues::metadata_signature_invalid("feature", "signature does not match content"),
+            &snapshot,
+        );
+        assert_eq!(invalid.len(), 1);
+        assert!(invalid[0].preview.summary.contains("review"));
+    }
+
+    #[test]
+    fn metadata_upgrade_fix() {
+        let snapshot = minimal_snapshot();
+
+        let fixes = generate_fixes(&issues::metadata_schema_outdated("feature", 1), &snapshot);
+        assert_eq!(fixes.len(), 1);
+        assert!(fixes[0]
+            .id
+            .to_string()
+            .starts_with("metadata-schema-outdated:upgrade"));
+        assert_eq!(
+            fixes[0].preview.metadata_changes[0],
+            MetadataChange::Update {
+                branch: "feature".to_string(),
+                field: "schema_version".to_string(),
+                old_value: None,
+                new_value: SCHEMA_VERSION.to_string(),
+            }
+        );
+    }
+
     #[test]
     fn orphaned_metadata_fixes() {
         let issue = issues::orphaned_metadata("old-branch");
@@ -1365,6 +2142,168 @@ mod tests {
         assert!(fixes[0].description.contains("Delete"));
     }
 
+    #[test]
+    fn stale_lock_fix_deletes_the_lock_ref() {
+        let oid = "abc123def4567890abc123def4567890abc12345";
+        let issue = issues::stale_branch_lock("feature", "alice@example.com", "then", oid);
+        let snapshot = minimal_snapshot();
+
+        let fixes = generate_fixes(&issue, &snapshot);
+
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            fixes[0].preview.ref_changes,
+            vec![RefChange::Delete {
+                ref_name: "refs/lattice/locks/feature".to_string(),
+                old_oid: oid.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn remote_tracking_fixes() {
+        use crate::core::metadata::schema::BranchMetadataV2;
+        use crate::core::types::{BranchName, Oid};
+        use crate::engine::scan::ScannedMetadata;
+
+        let local = "def456def4567890def456def4567890def45678";
+        let remote = "fed654fed6547890fed654fed6547890fed65478";
+        let mut snapshot = minimal_snapshot();
+        let branch = BranchName::new("feature").unwrap();
+        let oid = Oid::new(local).unwrap();
+        let metadata = BranchMetadataV2::new(
+            branch.clone(),
+            BranchName::new("main").unwrap(),
+            oid.clone(),
+        );
+        snapshot.branches.insert(branch.clone(), oid.clone());
+        snapshot.metadata.insert(
+            branch.clone(),
+            ScannedMetadata {
+                ref_oid: oid,
+                metadata,
+            },
+        );
+
+        let behind = generate_fixes(
+            &issues::remote_tracking_behind("feature", "origin", 2, local, remote),
+            &snapshot,
+        );
+        let ids: Vec<String> = behind.iter().map(|f| f.id.to_string()).collect();
+        assert_eq!(
+            ids,
+            [
+                "remote-tracking-behind:fast-forward:feature",
+                "remote-tracking-behind:mark-get:feature"
+            ]
+        );
+        assert_eq!(
+            behind[0].preview.ref_changes,
+            vec![RefChange::Update {
+                ref_name: "refs/heads/feature".to_string(),
+                old_oid: Some(local.to_string()),
+                new_oid: remote.to_string(),
+            }]
+        );
+
+        let diverged = generate_fixes(
+            &issues::remote_tracking_diverged("feature", "origin", 1, 1, local, remote),
+            &snapshot,
+        );
+        assert_eq!(diverged.len(), 2);
+        assert_eq!(
+            diverged[0].preview.remote_changes,
+            vec![RemoteChange::ForcePush {
+                remote: "origin".to_string(),
+                branch: "feature".to_string(),
+                old_oid: remote.to_string(),
+                new_oid: local.to_string(),
+            }]
+        );
+        assert!(diverged[1].id.to_string().contains(":mark-get:"));
+
+        // A checked-out branch isn't moved from under the user
+        snapshot.current_branch = Some(branch);
+        let current = generate_fixes(
+            &issues::remote_tracking_behind("feature", "origin", 2, local, remote),
+            &snapshot,
+        );
+        assert_eq!(current.len(), 1);
+        assert!(current[0].id.to_string().contains(":mark-get:"));
+
+        // Nothing is offered once the branch moved since the scan
+        let stale = generate_fixes(
+            &issues::remote_tracking_diverged("feature", "origin", 1, 1, remote, local),
+            &snapshot,
+        );
+        assert!(stale.is_empty());
+    }
+
+    #[test]
+    fn pr_base_deleted_fixes() {
+        use crate::core::metadata::schema::BranchMetadataV2;
+        use crate::core::types::{BranchName, Oid};
+        use crate::engine::scan::ScannedMetadata;
+
+        let mut snapshot = minimal_snapshot();
+        let url = "https://github.com/o/r/pull/7";
+
+        // Head not tracked: only the PR is retargeted
+        let fixes = generate_fixes(
+            &issues::pr_base_deleted(7, "feature-c", "feature-b", "origin", "feature-a", url),
+            &snapshot,
+        );
+        let ids: Vec<String> = fixes.iter().map(|f| f.id.to_string()).collect();
+        assert_eq!(
+            ids,
+            [
+                "pr-base-deleted:retarget-ancestor:feature-c",
+                "pr-base-deleted:retarget-trunk:feature-c"
+            ]
+        );
+        assert_eq!(
+            fixes[0].preview.remote_changes,
+            vec![RemoteChange::RetargetPr {
+                number: 7,
+                old_base: "feature-b".to_string(),
+                new_base: "feature-a".to_string(),
+            }]
+        );
+        assert!(fixes[0].preview.metadata_changes.is_empty());
+
+        // Tracked head: its parent follows the PR
+        let branch = BranchName::new("feature-c").unwrap();
+        let oid = Oid::new("def456def4567890def456def4567890def45678").unwrap();
+        let metadata = BranchMetadataV2::new(
+            branch.clone(),
+            BranchName::new("feature-b").unwrap(),
+            oid.clone(),
+        );
+        snapshot.branches.insert(branch.clone(), oid.clone());
+        snapshot.metadata.insert(
+            branch,
+            ScannedMetadata {
+                ref_oid: oid,
+                metadata,
+            },
+        );
+        let fixes = generate_fixes(
+            &issues::pr_base_deleted(7, "feature-c", "feature-b", "origin", "main", url),
+            &snapshot,
+        );
+        assert_eq!(fixes.len(), 1);
+        assert!(fixes[0].id.to_string().contains(":retarget-trunk:"));
+        assert_eq!(
+            fixes[0].preview.metadata_changes,
+            vec![MetadataChange::Update {
+                branch: "feature-c".to_string(),
+                field: "parent".to_string(),
+                old_value: Some("feature-b".to_string()),
+                new_value: "main".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn lattice_op_fixes_offers_continue_and_abort() {
         let issue = issues::lattice_operation_in_progress("restack", "op-123");
@@ -1455,7 +2394,7 @@ mod tests {
 
     #[test]
     fn track_existing_returns_empty_if_already_tracked() {
-        use crate::core::metadata::schema::BranchMetadataV1;
+        use crate::core::metadata::schema::BranchMetadataV2;
         use crate::engine::scan::ScannedMetadata;
 
         let issue = issues::remote_pr_branch_untracked(
@@ -1471,7 +2410,7 @@ mod tests {
         snapshot.branches.insert(branch.clone(), oid.clone());
         // Also add metadata (already tracked)
         let parent = crate::core::types::BranchName::new("main").unwrap();
-        let metadata = BranchMetadataV1::new(branch.clone(), parent, oid.clone());
+        let metadata = BranchMetadataV2::new(branch.clone(), parent, oid.clone());
         snapshot.metadata.insert(
             branch,
             ScannedMetadata {
@@ -1528,7 +2467,7 @@ mod tests {
 
     #[test]
     fn link_pr_generates_fix() {
-        use crate::core::metadata::schema::BranchMetadataV1;
+        use crate::core::metadata::schema::BranchMetadataV2;
         use crate::engine::scan::ScannedMetadata;
 
         let issue =
@@ -1539,7 +2478,7 @@ mod tests {
         let oid = crate::core::types::Oid::new("def456def4567890def456def4567890def45678").unwrap();
         snapshot.branches.insert(branch.clone(), oid.clone());
         let parent = crate::core::types::BranchName::new("main").unwrap();
-        let metadata = BranchMetadataV1::new(branch.clone(), parent, oid.clone());
+        let metadata = BranchMetadataV2::new(branch.clone(), parent, oid.clone());
         snapshot.metadata.insert(
             branch,
             ScannedMetadata {
@@ -1577,7 +2516,7 @@ mod tests {
 
     #[test]
     fn parent_selection_uses_tracked_branch() {
-        use crate::core::metadata::schema::BranchMetadataV1;
+        use crate::core::metadata::schema::BranchMetadataV2;
         use crate::engine::scan::ScannedMetadata;
 
         let mut snapshot = minimal_snapshot();
@@ -1586,7 +2525,7 @@ mod tests {
         let oid = crate::core::types::Oid::new("def456def4567890def456def4567890def45678").unwrap();
         snapshot.branches.insert(branch.clone(), oid.clone());
         let parent = crate::core::types::BranchName::new("main").unwrap();
-        let metadata = BranchMetadataV1::new(branch.clone(), parent, oid.clone());
+        let metadata = BranchMetadataV2::new(branch.clone(), parent, oid.clone());
         snapshot.metadata.insert(
             branch,
             ScannedMetadata {
@@ -1798,7 +2737,7 @@ mod tests {
 
     #[test]
     fn local_bootstrap_returns_empty_if_already_tracked() {
-        use crate::core::metadata::schema::BranchMetadataV1;
+        use crate::core::metadata::schema::BranchMetadataV2;
         use crate::engine::health::ParentCandidate;
         use crate::engine::scan::ScannedMetadata;
 
@@ -1819,7 +2758,7 @@ mod tests {
 
         // Also add metadata (already tracked)
         let parent = crate::core::types::BranchName::new("main").unwrap();
-        let metadata = BranchMetadataV1::new(branch.clone(), parent, oid.clone());
+        let metadata = BranchMetadataV2::new(branch.clone(), parent, oid.clone());
         snapshot.metadata.insert(
             branch,
             ScannedMetadata {
@@ -2232,4 +3171,104 @@ mod tests {
     fn snapshot_prefix_constant() {
         assert_eq!(SNAPSHOT_PREFIX, "lattice/snap/pr-");
     }
+
+    fn open_pr(number: u64, head: &str, base: &str) -> crate::forge::PullRequestSummary {
+        crate::forge::PullRequestSummary {
+            number,
+            head_ref: head.to_string(),
+            head_repo_owner: None,
+            base_ref: base.to_string(),
+            is_draft: false,
+            url: format!("https://github.com/org/repo/pull/{}", number),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            author: None,
+        }
+    }
+
+    fn chain_snapshot() -> (RepoSnapshot, BTreeMap<String, Oid>) {
+        let mut snapshot = minimal_snapshot();
+        snapshot.remote_prs = Some(crate::engine::scan::RemotePrEvidence {
+            prs: vec![
+                open_pr(3, "c", "b"),
+                open_pr(1, "a", "main"),
+                open_pr(2, "b", "a"),
+                open_pr(4, "lonely", "main"),
+            ],
+            truncated: false,
+        });
+        let heads = ["a", "b", "c", "lonely"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                (
+                    name.to_string(),
+                    Oid::new(format!("{:040}", i + 1)).unwrap(),
+                )
+            })
+            .collect();
+        (snapshot, heads)
+    }
+
+    #[test]
+    fn remote_pr_chain_found_parents_first() {
+        let (snapshot, heads) = chain_snapshot();
+        let issues = analyze_remote_pr_chains(&snapshot, &heads);
+
+        // The single PR is left to the per-PR fix
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .id
+            .as_str()
+            .starts_with("remote-pr-chain-missing:"));
+        match &issues[0].evidence[0] {
+            Evidence::RemotePrChain { root_base, prs } => {
+                assert_eq!(root_base, "main");
+                let numbers: Vec<_> = prs.iter().map(|pr| pr.number).collect();
+                assert_eq!(numbers, vec![1, 2, 3]);
+            }
+            other => panic!("unexpected evidence {:?}", other),
+        }
+    }
+
+    #[test]
+    fn remote_pr_chain_skipped_when_a_branch_exists_locally() {
+        let (mut snapshot, heads) = chain_snapshot();
+        snapshot.branches.insert(
+            crate::core::types::BranchName::new("a").unwrap(),
+            Oid::new(format!("{:040}", 1)).unwrap(),
+        );
+        // "b" now hangs off an untracked local branch, so no parent link
+        assert!(analyze_remote_pr_chains(&snapshot, &heads).is_empty());
+    }
+
+    #[test]
+    fn import_chain_fix_fetches_and_tracks_frozen() {
+        let (snapshot, heads) = chain_snapshot();
+        let issue = analyze_remote_pr_chains(&snapshot, &heads).remove(0);
+
+        let fixes = generate_fixes(&issue, &snapshot);
+        assert_eq!(fixes.len(), 1);
+        let fix = &fixes[0];
+        assert!(fix.id.as_str().contains("import-chain"));
+        assert_eq!(fix.preview.ref_changes.len(), 3);
+        assert!(fix.preview.summary.contains("└── a (#1)"));
+
+        let descriptions: Vec<_> = fix
+            .preview
+            .metadata_changes
+            .iter()
+            .map(|change| match change {
+                MetadataChange::Create { description, .. } => description.clone(),
+                other => panic!("unexpected change {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            descriptions[0],
+            "parent=main, pr=#1, frozen (teammate_branch)"
+        );
+        assert_eq!(
+            descriptions[1],
+            format!("parent=a, base={:040}, pr=#2, frozen (teammate_branch)", 1)
+        );
+    }
 }
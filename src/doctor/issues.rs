@@ -14,7 +14,7 @@
 
 use thiserror::Error;
 
-use crate::engine::health::{Issue, IssueId, Severity};
+use crate::engine::health::{ChainedPrInfo, Issue, IssueId, Severity};
 
 /// Known issue types that Doctor can diagnose and repair.
 ///
@@ -105,6 +105,84 @@ pub enum KnownIssue {
         new_path: String,
     },
 
+    /// A branch's remote override names a remote that doesn't exist.
+    #[error("remote '{remote}' for branch '{branch}' does not exist")]
+    BranchRemoteMissing {
+        /// The branch with the override.
+        branch: String,
+        /// The missing remote name.
+        remote: String,
+    },
+
+    /// A submitted branch's remote branch was deleted after it was merged.
+    #[error("remote branch for '{branch}' on '{remote}' was deleted after merging")]
+    RemoteBranchMerged {
+        /// The local branch.
+        branch: String,
+        /// The remote the branch was pushed to.
+        remote: String,
+    },
+
+    /// A submitted branch's remote branch was deleted without being merged.
+    #[error("remote branch for '{branch}' on '{remote}' was deleted without merging")]
+    RemoteBranchDeleted {
+        /// The local branch.
+        branch: String,
+        /// The remote the branch was pushed to.
+        remote: String,
+    },
+
+    /// A PR description's stack section markers are damaged.
+    #[error("stack section markers in PR #{number} for '{branch}' are damaged: {problem}")]
+    PrBodyMarkersCorrupt {
+        /// The branch the PR is for.
+        branch: String,
+        /// PR number.
+        number: u64,
+        /// PR URL.
+        url: String,
+        /// What is wrong with the markers.
+        problem: String,
+    },
+
+    /// Metadata is still stored by an older schema version.
+    #[error("metadata for branch '{branch}' is stored as schema v{version}")]
+    MetadataSchemaOutdated {
+        /// The branch with outdated metadata.
+        branch: String,
+        /// The schema version the metadata is stored as.
+        version: u32,
+    },
+
+    /// Metadata is unsigned in a repository that signs metadata.
+    #[error("metadata for branch '{branch}' is not signed")]
+    MetadataUnsigned {
+        /// The branch with unsigned metadata.
+        branch: String,
+    },
+
+    /// Metadata signature doesn't verify (possible tampering).
+    #[error("metadata signature for branch '{branch}' is invalid: {reason}")]
+    MetadataSignatureInvalid {
+        /// The branch whose metadata failed verification.
+        branch: String,
+        /// Why verification failed.
+        reason: String,
+    },
+
+    /// A branch lock expired without being released.
+    #[error("lock on '{branch}' held by {owner} expired at {expired_at}")]
+    StaleBranchLock {
+        /// The locked branch.
+        branch: String,
+        /// Who held the lock.
+        owner: String,
+        /// When the lock expired.
+        expired_at: String,
+        /// Blob the lock ref points at.
+        oid: String,
+    },
+
     // --- Bootstrap Issues (Remote Evidence) ---
     /// Remote forge reports open pull requests for this repository.
     /// This is informational - indicates bootstrap opportunity.
@@ -158,6 +236,17 @@ pub enum KnownIssue {
         url: String,
     },
 
+    /// Stacked open PRs exist on the remote but none of their branches do
+    /// locally (found by deep remote analysis).
+    /// User should import the whole chain as a frozen stack.
+    #[error("{} stacked open PRs on '{root_base}' have no local branches", prs.len())]
+    RemoteOpenPrChainMissingLocally {
+        /// Branch the bottom PR targets.
+        root_base: String,
+        /// The chain's PRs, parents first.
+        prs: Vec<ChainedPrInfo>,
+    },
+
     // --- Synthetic Stack Detection (Milestone 5.8) ---
     /// A PR targeting trunk may be a synthetic stack head.
     /// This indicates prior work may have been merged into the branch.
@@ -203,6 +292,26 @@ impl KnownIssue {
             KnownIssue::ConfigMigrationNeeded { old_path, .. } => {
                 IssueId::new("config-migration", old_path)
             }
+            KnownIssue::BranchRemoteMissing { branch, .. } => {
+                IssueId::new("branch-remote-missing", branch)
+            }
+            KnownIssue::RemoteBranchMerged { branch, .. } => {
+                IssueId::new("remote-branch-merged", branch)
+            }
+            KnownIssue::RemoteBranchDeleted { branch, .. } => {
+                IssueId::new("remote-branch-deleted", branch)
+            }
+            KnownIssue::PrBodyMarkersCorrupt { number, .. } => {
+                IssueId::new("pr-body-markers-corrupt", &number.to_string())
+            }
+            KnownIssue::MetadataSchemaOutdated { branch, .. } => {
+                IssueId::new("metadata-schema-outdated", branch)
+            }
+            KnownIssue::MetadataUnsigned { branch } => IssueId::new("metadata-unsigned", branch),
+            KnownIssue::MetadataSignatureInvalid { branch, .. } => {
+                IssueId::new("metadata-signature-invalid", branch)
+            }
+            KnownIssue::StaleBranchLock { branch, .. } => IssueId::new("stale-branch-lock", branch),
             KnownIssue::RemoteOpenPullRequestsDetected { .. } => {
                 IssueId::singleton("remote-open-prs-detected")
             }
@@ -215,6 +324,12 @@ impl KnownIssue {
             KnownIssue::RemoteOpenPrNotLinkedInMetadata { branch, .. } => {
                 IssueId::new("remote-pr-not-linked", branch)
             }
+            KnownIssue::RemoteOpenPrChainMissingLocally { prs, .. } => IssueId::new(
+                "remote-pr-chain-missing",
+                prs.first()
+                    .map(|pr| pr.head_ref.as_str())
+                    .unwrap_or_default(),
+            ),
             KnownIssue::PotentialSyntheticStackHead { pr_number, .. } => {
                 IssueId::new("synthetic-stack-head", &pr_number.to_string())
             }
@@ -234,10 +349,19 @@ impl KnownIssue {
             KnownIssue::LatticeOpInProgress { .. } => Severity::Blocking,
             KnownIssue::ExternalGitOpInProgress { .. } => Severity::Blocking,
             KnownIssue::ConfigMigrationNeeded { .. } => Severity::Warning,
+            KnownIssue::BranchRemoteMissing { .. } => Severity::Warning,
+            KnownIssue::RemoteBranchMerged { .. } => Severity::Warning,
+            KnownIssue::RemoteBranchDeleted { .. } => Severity::Warning,
+            KnownIssue::PrBodyMarkersCorrupt { .. } => Severity::Warning,
+            KnownIssue::MetadataSchemaOutdated { .. } => Severity::Warning,
+            KnownIssue::MetadataUnsigned { .. } => Severity::Warning,
+            KnownIssue::MetadataSignatureInvalid { .. } => Severity::Blocking,
+            KnownIssue::StaleBranchLock { .. } => Severity::Warning,
             KnownIssue::RemoteOpenPullRequestsDetected { .. } => Severity::Info,
             KnownIssue::RemoteOpenPrBranchMissingLocally { .. } => Severity::Warning,
             KnownIssue::RemoteOpenPrBranchUntracked { .. } => Severity::Warning,
             KnownIssue::RemoteOpenPrNotLinkedInMetadata { .. } => Severity::Info,
+            KnownIssue::RemoteOpenPrChainMissingLocally { .. } => Severity::Warning,
             KnownIssue::PotentialSyntheticStackHead { .. } => Severity::Info,
         }
     }
@@ -271,6 +395,34 @@ impl KnownIssue {
             KnownIssue::ConfigMigrationNeeded { old_path, new_path } => {
                 issues::config_migration_needed(old_path, new_path)
             }
+            KnownIssue::BranchRemoteMissing { branch, remote } => {
+                issues::branch_remote_missing(branch, remote)
+            }
+            KnownIssue::RemoteBranchMerged { branch, remote } => {
+                issues::remote_branch_merged(branch, remote)
+            }
+            KnownIssue::RemoteBranchDeleted { branch, remote } => {
+                issues::remote_branch_deleted(branch, remote)
+            }
+            KnownIssue::PrBodyMarkersCorrupt {
+                branch,
+                number,
+                url,
+                problem,
+            } => issues::pr_body_markers_corrupt(branch, *number, url, problem),
+            KnownIssue::MetadataSchemaOutdated { branch, version } => {
+                issues::metadata_schema_outdated(branch, *version)
+            }
+            KnownIssue::MetadataUnsigned { branch } => issues::metadata_unsigned(branch),
+            KnownIssue::MetadataSignatureInvalid { branch, reason } => {
+                issues::metadata_signature_invalid(branch, reason)
+            }
+            KnownIssue::StaleBranchLock {
+                branch,
+                owner,
+                expired_at,
+                oid,
+            } => issues::stale_branch_lock(branch, owner, expired_at, oid),
             KnownIssue::RemoteOpenPullRequestsDetected { count, truncated } => {
                 issues::remote_open_prs_detected(*count, *truncated)
             }
@@ -291,6 +443,9 @@ impl KnownIssue {
                 number,
                 url,
             } => issues::remote_pr_not_linked(branch, *number, url),
+            KnownIssue::RemoteOpenPrChainMissingLocally { root_base, prs } => {
+                issues::remote_pr_chain_missing(root_base, prs.clone())
+            }
             KnownIssue::PotentialSyntheticStackHead {
                 branch,
                 pr_number,
@@ -353,6 +508,108 @@ mod tests {
         assert_eq!(issue.severity(), Severity::Warning);
     }
 
+    #[test]
+    fn branch_remote_missing_severity() {
+        let issue = KnownIssue::BranchRemoteMissing {
+            branch: "feature".to_string(),
+            remote: "security".to_string(),
+        };
+        assert!(issue
+            .issue_id()
+            .as_str()
+            .starts_with("branch-remote-missing:"));
+        assert_eq!(issue.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn remote_branch_gone_severities() {
+        let merged = KnownIssue::RemoteBranchMerged {
+            branch: "feature".to_string(),
+            remote: "origin".to_string(),
+        };
+        assert!(merged
+            .issue_id()
+            .as_str()
+            .starts_with("remote-branch-merged:"));
+        assert_eq!(merged.severity(), Severity::Warning);
+
+        let deleted = KnownIssue::RemoteBranchDeleted {
+            branch: "feature".to_string(),
+            remote: "origin".to_string(),
+        };
+        assert!(deleted
+            .issue_id()
+            .as_str()
+            .starts_with("remote-branch-deleted:"));
+        assert_eq!(deleted.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn stale_branch_lock_is_a_warning() {
+        let issue = KnownIssue::StaleBranchLock {
+            branch: "feature".to_string(),
+            owner: "alice@example.com".to_string(),
+            expired_at: "2026-01-01T00:00:00+00:00".to_string(),
+            oid: "abc123def4567890abc123def4567890abc12345".to_string(),
+        };
+        assert!(issue.issue_id().as_str().starts_with("stale-branch-lock:"));
+        assert_eq!(issue.severity(), Severity::Warning);
+        assert!(issue.to_issue().message.contains("alice@example.com"));
+    }
+
+    #[test]
+    fn pr_body_markers_corrupt_severity() {
+        let issue = KnownIssue::PrBodyMarkersCorrupt {
+            branch: "feature".to_string(),
+            number: 42,
+            url: "https://github.com/org/repo/pull/42".to_string(),
+            problem: "stack section markers appear more than once".to_string(),
+        };
+        assert!(issue
+            .issue_id()
+            .as_str()
+            .starts_with("pr-body-markers-corrupt:"));
+        assert_eq!(issue.severity(), Severity::Warning);
+        assert_eq!(issue.to_issue().severity, Severity::Warning);
+    }
+
+    #[test]
+    fn metadata_signature_issue_severities() {
+        let unsigned = KnownIssue::MetadataUnsigned {
+            branch: "feature".to_string(),
+        };
+        assert!(unsigned
+            .issue_id()
+            .as_str()
+            .starts_with("metadata-unsigned:"));
+        assert_eq!(unsigned.severity(), Severity::Warning);
+
+        let invalid = KnownIssue::MetadataSignatureInvalid {
+            branch: "feature".to_string(),
+            reason: "signature does not match content".to_string(),
+        };
+        assert!(invalid
+            .issue_id()
+            .as_str()
+            .starts_with("metadata-signature-invalid:"));
+        assert_eq!(invalid.severity(), Severity::Blocking);
+        assert!(invalid.to_issue().is_blocking());
+    }
+
+    #[test]
+    fn metadata_schema_outdated_issue() {
+        let issue = KnownIssue::MetadataSchemaOutdated {
+            branch: "feature".to_string(),
+            version: 1,
+        };
+        assert!(issue
+            .issue_id()
+            .as_str()
+            .starts_with("metadata-schema-outdated:"));
+        assert_eq!(issue.severity(), Severity::Warning);
+        assert!(issue.to_issue().message.contains("schema v1"));
+    }
+
     #[test]
     fn untracked_branch_severity() {
         let issue = KnownIssue::UntrackedBranch {
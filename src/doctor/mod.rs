@@ -31,6 +31,11 @@
 //! - Doctor applies fixes only when fix IDs are provided explicitly
 //! - Doctor never auto-selects fixes
 //!
+//! `--fix-all` is the exception for automation: it applies each issue's fix
+//! only where the issue has exactly one, up to a maximum severity, and asks
+//! for confirmation (`--yes` without a terminal). Issues with several fixes
+//! are reported for the user to choose rather than guessed.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -61,13 +66,15 @@ mod issues;
 mod planner;
 
 pub use fixes::*;
-pub use generators::{analyze_synthetic_stack_deep, generate_fixes};
+pub use generators::{
+    analyze_remote_pr_chains, analyze_synthetic_stack_deep, generate_fixes, render_pr_chain_tree,
+};
 pub use issues::*;
 pub use planner::{generate_repair_plan, RepairPlanError};
 
 use thiserror::Error;
 
-use crate::engine::health::{Issue, IssueId};
+use crate::engine::health::{Issue, IssueId, Severity};
 use crate::engine::plan::Plan;
 use crate::engine::scan::RepoSnapshot;
 
@@ -145,11 +152,47 @@ impl DiagnosisReport {
         self.fixes.iter().find(|f| &f.id == fix_id)
     }
 
+    /// Choose the fixes `doctor --fix-all` applies.
+    ///
+    /// Each issue at or below `max` severity with exactly one fix gets it.
+    /// Issues with several fixes are left for the user to choose; guessing
+    /// could apply the wrong repair.
+    pub fn unambiguous_fixes(&self, max: Severity) -> FixAllSelection {
+        let mut selection = FixAllSelection::default();
+        for issue in &self.issues {
+            if !issue.severity.at_most(max) {
+                selection.above_max.push(issue.id.clone());
+                continue;
+            }
+            match self.fixes_for_issue(&issue.id).as_slice() {
+                [] => selection.unfixable.push(issue.id.clone()),
+                [fix] => selection.fixes.push(fix.id.clone()),
+                _ => selection.ambiguous.push(issue.id.clone()),
+            }
+        }
+        selection
+    }
+
     /// Get all blocking issues.
     pub fn blocking_issues(&self) -> impl Iterator<Item = &Issue> {
         self.issues.iter().filter(|i| i.is_blocking())
     }
 
+    /// Add an issue found after diagnosis (e.g. by deep remote analysis),
+    /// along with its fixes.
+    pub fn add_issue(&mut self, issue: Issue, snapshot: &RepoSnapshot) {
+        let fixes = generate_fixes(&issue, snapshot);
+        self.summary.issue_count += 1;
+        match issue.severity {
+            crate::engine::health::Severity::Blocking => self.summary.blocking_count += 1,
+            crate::engine::health::Severity::Warning => self.summary.warning_count += 1,
+            crate::engine::health::Severity::Info => self.summary.info_count += 1,
+        }
+        self.summary.fix_count += fixes.len();
+        self.issues.push(issue);
+        self.fixes.extend(fixes);
+    }
+
     /// Format the diagnosis for display.
     pub fn format(&self) -> String {
         let mut lines = Vec::new();
@@ -190,6 +233,19 @@ impl DiagnosisReport {
     }
 }
 
+/// The fixes `doctor --fix-all` applies, and the issues it leaves alone.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FixAllSelection {
+    /// The only fix of each selected issue, in issue order.
+    pub fixes: Vec<FixId>,
+    /// Issues with more than one fix.
+    pub ambiguous: Vec<IssueId>,
+    /// Issues with no fix.
+    pub unfixable: Vec<IssueId>,
+    /// Issues more severe than the maximum.
+    pub above_max: Vec<IssueId>,
+}
+
 /// Outcome of applying repairs.
 #[derive(Debug)]
 pub struct RepairOutcome {
@@ -416,6 +472,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: Some(BranchName::new("main").unwrap()),
             branches,
             metadata: HashMap::new(),
@@ -523,6 +580,43 @@ mod tests {
             assert!(!fixes.is_empty());
         }
 
+        #[test]
+        fn unambiguous_fixes_skips_choices_and_severe_issues() {
+            let fix = |issue: &str, name: &str| {
+                FixOption::new(
+                    FixId::simple(issue, name),
+                    IssueId::singleton(issue),
+                    name,
+                    FixPreview::with_summary(name),
+                )
+            };
+            let diagnosis = DiagnosisReport {
+                issues: vec![
+                    Issue::new("one-fix", Severity::Warning, "one fix"),
+                    Issue::new("two-fixes", Severity::Info, "two fixes"),
+                    Issue::new("no-fix", Severity::Info, "no fix"),
+                    Issue::new("blocking", Severity::Blocking, "blocking"),
+                ],
+                fixes: vec![
+                    fix("one-fix", "apply"),
+                    fix("two-fixes", "keep"),
+                    fix("two-fixes", "drop"),
+                    fix("blocking", "repair"),
+                ],
+                summary: DiagnosisSummary::default(),
+            };
+
+            let selection = diagnosis.unambiguous_fixes(Severity::Warning);
+            assert_eq!(selection.fixes, vec![FixId::simple("one-fix", "apply")]);
+            assert_eq!(selection.ambiguous, vec![IssueId::singleton("two-fixes")]);
+            assert_eq!(selection.unfixable, vec![IssueId::singleton("no-fix")]);
+            assert_eq!(selection.above_max, vec![IssueId::singleton("blocking")]);
+
+            let selection = diagnosis.unambiguous_fixes(Severity::Blocking);
+            assert_eq!(selection.fixes.len(), 2);
+            assert!(selection.above_max.is_empty());
+        }
+
         #[test]
         fn format_healthy() {
             let diagnosis = DiagnosisReport {
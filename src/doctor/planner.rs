@@ -17,7 +17,7 @@ use crate::core::ops::journal::OpId;
 use crate::engine::plan::{Plan, PlanStep};
 use crate::engine::scan::RepoSnapshot;
 
-use super::fixes::{ConfigChange, FixOption, MetadataChange, RefChange};
+use super::fixes::{ConfigChange, FixOption, MetadataChange, RefChange, RemoteChange};
 
 /// Context for snapshot branch creation.
 ///
@@ -158,6 +158,10 @@ fn add_fix_steps(
         plan = plan.with_step(config_change_to_step(change)?);
     }
 
+    for change in &fix.preview.remote_changes {
+        plan = plan.with_step(remote_change_to_step(change));
+    }
+
     Ok(plan)
 }
 
@@ -284,10 +288,17 @@ fn metadata_change_to_step(
         } => {
             // Parse the description to extract parent, frozen, and PR info.
             // Format: "parent=<name>, pr=#<num>, unfrozen|frozen (reason)"
-            let (parent_name, frozen, pr_info) = parse_create_description(description, snapshot);
-
-            let metadata =
-                create_minimal_metadata(branch, &parent_name, snapshot, frozen, pr_info)?;
+            let (parent_name, base, frozen, pr_info) =
+                parse_create_description(description, snapshot);
+
+            let metadata = create_minimal_metadata(
+                branch,
+                &parent_name,
+                base.as_deref(),
+                snapshot,
+                frozen,
+                pr_info,
+            )?;
 
             Ok(PlanStep::WriteMetadataCas {
                 branch: branch.clone(),
@@ -310,9 +321,13 @@ fn metadata_change_to_step(
                     match field.as_str() {
                         "parent" => {
                             use crate::core::metadata::schema::ParentInfo;
-                            metadata.parent = ParentInfo::Branch {
-                                name: new_value.clone(),
-                            };
+                            let name = new_value.clone();
+                            metadata.parent =
+                                if snapshot.trunk.as_ref().map(|t| t.as_str()) == Some(new_value) {
+                                    ParentInfo::Trunk { name }
+                                } else {
+                                    ParentInfo::Branch { name }
+                                };
                         }
                         "base" => {
                             use crate::core::metadata::schema::BaseInfo;
@@ -320,10 +335,29 @@ fn metadata_change_to_step(
                                 oid: new_value.clone(),
                             };
                         }
+                        "remote" => {
+                            // An empty value clears the override
+                            metadata.remote = (!new_value.is_empty()).then(|| new_value.clone());
+                        }
+                        "freeze" => {
+                            // The new value is the freeze reason
+                            use crate::core::metadata::schema::{FreezeScope, FreezeState};
+                            metadata.freeze =
+                                FreezeState::frozen(FreezeScope::Single, Some(new_value.clone()));
+                        }
+                        "signature" => {
+                            // Content is unchanged; rewriting the blob signs it
+                        }
+                        "schema_version" => {
+                            // Older versions were upgraded when read;
+                            // rewriting stores the current version
+                        }
                         "pr" => {
-                            // Parse PR linkage from new_value format: "linked(#42)"
+                            // Parse PR linkage from new_value format: "linked(#42)" or "none"
                             use crate::core::metadata::schema::PrState;
-                            if let Some(num_str) = new_value
+                            if new_value == "none" {
+                                metadata.pr = PrState::None;
+                            } else if let Some(num_str) = new_value
                                 .strip_prefix("linked(#")
                                 .and_then(|s| s.strip_suffix(')'))
                             {
@@ -411,17 +445,47 @@ fn config_change_to_step(change: &ConfigChange) -> Result<PlanStep, RepairPlanEr
     }
 }
 
-/// Parse a MetadataChange::Create description to extract parent, frozen, and PR info.
+/// Convert a RemoteChange to a PlanStep.
+fn remote_change_to_step(change: &RemoteChange) -> PlanStep {
+    match change {
+        RemoteChange::ForcePush { remote, branch, .. } => PlanStep::ForgePush {
+            branch: branch.clone(),
+            force: true,
+            remote: remote.clone(),
+            reason: format!("doctor: force-push '{}' to '{}'", branch, remote),
+        },
+        // Run through the forge by the doctor command, before the executor
+        RemoteChange::RetargetPr {
+            number, new_base, ..
+        } => PlanStep::ForgeUpdatePr {
+            number: *number,
+            base: Some(new_base.clone()),
+            title: None,
+            body: None,
+        },
+    }
+}
+
+/// Parse a MetadataChange::Create description to extract parent, base,
+/// frozen, and PR info.
 ///
 /// Expected formats:
 /// - "parent=main, pr=#42, unfrozen"
 /// - "parent=feature-a, pr=#42, frozen (teammate_branch)"
+/// - "parent=feature-a, base=<oid>, pr=#42, frozen (teammate_branch)"
 ///
-/// Returns (parent_name, frozen, pr_info) where pr_info is Option<(&str, u64, &str)>.
+/// Returns (parent_name, base, frozen, pr_info) where pr_info is
+/// Option<(&str, u64, &str)>.
+#[allow(clippy::type_complexity)]
 fn parse_create_description<'a>(
     description: &str,
     snapshot: &'a RepoSnapshot,
-) -> (String, bool, Option<(&'a str, u64, &'a str)>) {
+) -> (
+    String,
+    Option<String>,
+    bool,
+    Option<(&'a str, u64, &'a str)>,
+) {
     let mut parent_name = snapshot
         .trunk
         .as_ref()
@@ -439,6 +503,14 @@ fn parse_create_description<'a>(
         }
     }
 
+    // Parse "base=<oid>" (when the parent isn't local yet)
+    let base = description.find(", base=").map(|start| {
+        let rest = &description[start + 7..];
+        rest[..rest.find(',').unwrap_or(rest.len())]
+            .trim()
+            .to_string()
+    });
+
     // Parse "frozen" or "unfrozen"
     // frozen if contains "frozen (" (with reason) or ends with "frozen", but not "unfrozen"
     let frozen = (description.contains("frozen (") || description.ends_with("frozen"))
@@ -457,7 +529,7 @@ fn parse_create_description<'a>(
     // The URL is empty because we don't have it in the description.
     let pr_info = pr_number.map(|num| ("github", num, ""));
 
-    (parent_name, frozen, pr_info)
+    (parent_name, base, frozen, pr_info)
 }
 
 /// Create minimal metadata for a new branch tracking.
@@ -472,18 +544,23 @@ fn parse_create_description<'a>(
 ///
 /// * `branch` - Branch name to track
 /// * `parent_name` - Parent branch name
+/// * `base` - Base OID to use instead of the parent's tip, for parents
+///   created by the same repair
 /// * `snapshot` - Repository snapshot for looking up branch OIDs
 /// * `frozen` - Whether to create as frozen (teammate branch)
 /// * `pr_info` - Optional (forge, pr_number, url) for PR linkage
 fn create_minimal_metadata(
     branch: &str,
     parent_name: &str,
+    base: Option<&str>,
     snapshot: &RepoSnapshot,
     frozen: bool,
     pr_info: Option<(&str, u64, &str)>,
-) -> Result<crate::core::metadata::schema::BranchMetadataV1, RepairPlanError> {
-    use crate::core::metadata::schema::{BranchMetadataV1, FreezeState, PrState};
-    use crate::core::types::{BranchName, Oid};
+) -> Result<crate::core::metadata::schema::BranchMetadataV2, RepairPlanError> {
+    use crate::core::metadata::schema::{
+        BranchMetadataV2, FreezeState, PrState, Provenance, ProvenanceSource,
+    };
+    use crate::core::types::{BranchName, Oid, UtcTimestamp};
 
     // Validate branch name
     let branch_name = BranchName::new(branch).map_err(|e| {
@@ -499,17 +576,21 @@ fn create_minimal_metadata(
     // Note: For strict correctness, we'd compute merge-base here, but that
     // requires Git access which the planner doesn't have. The base will be
     // refined during sync/rebase operations.
-    let base_oid_str = snapshot
-        .branches
-        .get(&parent_branch)
-        .map(|o| o.as_str().to_string())
+    let base_oid_str = base
+        .map(str::to_string)
+        .or_else(|| {
+            snapshot
+                .branches
+                .get(&parent_branch)
+                .map(|o| o.as_str().to_string())
+        })
         .unwrap_or_else(|| "0000000000000000000000000000000000000000".to_string());
 
     let base_oid = Oid::new(&base_oid_str)
         .map_err(|e| RepairPlanError::CannotGeneratePlan(format!("invalid base oid: {}", e)))?;
 
     // Build metadata with appropriate freeze and PR states
-    let mut builder = BranchMetadataV1::builder(branch_name, parent_branch, base_oid);
+    let mut builder = BranchMetadataV2::builder(branch_name, parent_branch, base_oid);
 
     // Check if parent is trunk
     if let Some(trunk) = &snapshot.trunk {
@@ -532,7 +613,28 @@ fn create_minimal_metadata(
         builder = builder.pr_state(PrState::linked(forge, number, url));
     }
 
-    Ok(builder.build())
+    let mut metadata = builder.build();
+
+    // A frozen import of a PR is a teammate's branch; record whose it is,
+    // taking the author from the open PRs the remote scan found
+    if frozen {
+        if let Some((_, number, _)) = pr_info {
+            let owner = snapshot
+                .remote_prs
+                .as_ref()
+                .and_then(|evidence| evidence.prs.iter().find(|pr| pr.number == number))
+                .and_then(|pr| pr.author.clone());
+            metadata.provenance = Some(Provenance {
+                source: ProvenanceSource::DoctorImport,
+                remote: Some("origin".to_string()),
+                pr: Some(number),
+                owner,
+                recorded_at: UtcTimestamp::now(),
+            });
+        }
+    }
+
+    Ok(metadata)
 }
 
 /// Combine multiple repair plans into one.
@@ -586,6 +688,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: Some(BranchName::new("main").unwrap()),
             branches,
             metadata: HashMap::new(),
@@ -727,6 +830,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn frozen_import_records_pr_author() {
+        use crate::core::metadata::schema::ProvenanceSource;
+        use crate::engine::scan::RemotePrEvidence;
+        use crate::forge::PullRequestSummary;
+
+        let mut snapshot = minimal_snapshot();
+        snapshot.remote_prs = Some(RemotePrEvidence {
+            prs: vec![PullRequestSummary {
+                number: 42,
+                head_ref: "teammate-feature".to_string(),
+                head_repo_owner: None,
+                base_ref: "main".to_string(),
+                is_draft: false,
+                url: "https://github.com/org/repo/pull/42".to_string(),
+                updated_at: "2024-01-01T00:00:00Z".to_string(),
+                author: Some("bob".to_string()),
+            }],
+            truncated: false,
+        });
+
+        let frozen = create_minimal_metadata(
+            "teammate-feature",
+            "main",
+            None,
+            &snapshot,
+            true,
+            Some(("github", 42, "")),
+        )
+        .unwrap();
+        let provenance = frozen.provenance.unwrap();
+        assert_eq!(provenance.source, ProvenanceSource::DoctorImport);
+        assert_eq!(provenance.pr, Some(42));
+        assert_eq!(provenance.owner.as_deref(), Some("bob"));
+
+        let unfrozen = create_minimal_metadata(
+            "teammate-feature",
+            "main",
+            None,
+            &snapshot,
+            false,
+            Some(("github", 42, "")),
+        )
+        .unwrap();
+        assert!(unfrozen.provenance.is_none());
+    }
+
+    #[test]
+    fn create_description_base_overrides_parent_tip() {
+        let snapshot = minimal_snapshot();
+        let base = "1".repeat(40);
+        let (parent, parsed_base, frozen, pr) = parse_create_description(
+            &format!("parent=a, base={}, pr=#2, frozen (teammate_branch)", base),
+            &snapshot,
+        );
+        assert_eq!(parent, "a");
+        assert_eq!(parsed_base.as_deref(), Some(base.as_str()));
+        assert!(frozen);
+        assert_eq!(pr.map(|(_, n, _)| n), Some(2));
+
+        let metadata =
+            create_minimal_metadata("b", &parent, parsed_base.as_deref(), &snapshot, frozen, pr)
+                .unwrap();
+        assert_eq!(metadata.base.oid, base);
+    }
+
     #[test]
     fn combine_empty_plans() {
         let combined = combine_plans(vec![]);
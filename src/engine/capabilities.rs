@@ -97,6 +97,16 @@ pub enum Capability {
     /// - Category C: Working-copy mutations (require this capability)
     /// - Category D: Remote/API-only (may work in bare with restrictions)
     WorkingDirectoryAvailable,
+    /// Commands may change the repository.
+    ///
+    /// Withheld in read-only mode (see [`super::read_only`]), so every
+    /// requirement set except `READ_ONLY` fails at the gate.
+    WritesAllowed,
+    /// The configured remote is the one the repo config names.
+    ///
+    /// Withheld when the repo config's `identity` doesn't match the remote
+    /// (see [`super::repo_identity`]); established when no identity is set.
+    RepoIdentityVerified,
 }
 
 impl Capability {
@@ -124,6 +134,8 @@ impl Capability {
             Capability::RepoAuthorized => "repository authorization verified",
             Capability::FrozenPolicySatisfied => "frozen policy is satisfied",
             Capability::WorkingDirectoryAvailable => "working directory is available",
+            Capability::WritesAllowed => "read-only mode is off",
+            Capability::RepoIdentityVerified => "repository matches its configured identity",
         }
     }
 }
@@ -282,6 +294,8 @@ mod tests {
                 Capability::RepoAuthorized,
                 Capability::FrozenPolicySatisfied,
                 Capability::WorkingDirectoryAvailable,
+                Capability::WritesAllowed,
+                Capability::RepoIdentityVerified,
             ];
 
             for cap in caps {
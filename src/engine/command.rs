@@ -203,7 +203,7 @@ pub trait SimpleCommand: Command<Output = ()> {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Paused for {} on '{}'. Resolve and run 'lattice continue', or 'lattice abort'.",
+                    "Paused for {} conflict on '{}'.",
                     git_state.description(),
                     branch
                 ),
@@ -403,7 +403,7 @@ pub trait SimpleAsyncCommand: AsyncCommand<Output = ()> {
                 branch, git_state, ..
             } => CommandOutput::Paused {
                 message: format!(
-                    "Paused for {} on '{}'. Resolve and run 'lattice continue', or 'lattice abort'.",
+                    "Paused for {} conflict on '{}'.",
                     git_state.description(),
                     branch
                 ),
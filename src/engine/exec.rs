@@ -16,7 +16,8 @@
 //! 3. Record `IntentRecorded` event before mutations
 //! 4. Apply all ref updates with CAS semantics
 //! 5. If CAS fails: abort without continuing, record `Aborted`
-//! 6. If conflict pauses: transition to `awaiting_user` and stop
+//! 6. If conflict pauses: transition to `awaiting_user` and stop, unless
+//!    git rerere resolved every conflict from recorded resolutions
 //! 7. After success: re-scan, verify invariants, record `Committed`
 //! 8. Clear op-state marker and release lock
 //!
@@ -47,18 +48,26 @@
 //! }
 //! ```
 
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
 use thiserror::Error;
 
-use super::ledger::{Event, EventLedger, LedgerError};
+use super::interrupt;
+use super::ledger::{BranchStage, Event, EventLedger, LedgerError};
 use super::plan::{Plan, PlanStep};
 use super::scan::compute_fingerprint;
 use super::Context;
+use crate::core::branch_lock::BranchLockStore;
+use crate::core::metadata::schema::BranchMetadataV2;
 use crate::core::metadata::store::{MetadataStore, StoreError};
-use crate::core::ops::journal::{AwaitingReason, Journal, JournalError, OpState};
+use crate::core::ops::journal::{
+    AwaitingReason, Journal, JournalError, OpState, INTERRUPTED_STATE,
+};
 use crate::core::ops::lock::{LockError, RepoLock};
 use crate::core::paths::LatticePaths;
-use crate::core::types::{BranchName, Fingerprint, Oid};
-use crate::git::{Git, GitError, GitState};
+use crate::core::types::{BranchName, Fingerprint, Oid, UtcTimestamp};
+use crate::git::{rerere, Git, GitCommandResult, GitError, GitState};
 
 /// Errors from execution.
 #[derive(Debug, Error)]
@@ -111,6 +120,21 @@ pub enum ExecuteError {
     #[error("internal error: {0}")]
     Internal(String),
 
+    /// A branch the plan rewrites in its own worktree has uncommitted changes
+    /// there.
+    ///
+    /// Rewriting a branch inside the worktree that has it checked out (see
+    /// [`PlanStep::RunGitInWorktree`]) needs that worktree to be clean.
+    #[error(
+        "branch '{branch}' is checked out in worktree at {worktree_path}, which has uncommitted changes"
+    )]
+    WorktreeDirty {
+        /// The branch that is checked out elsewhere.
+        branch: String,
+        /// Path to the worktree where it's checked out.
+        worktree_path: String,
+    },
+
     /// Branch is checked out in another worktree.
     ///
     /// Per SPEC.md §4.6.8, operations that would rewrite a branch checked out
@@ -128,6 +152,34 @@ pub enum ExecuteError {
         worktree_path: String,
     },
 
+    /// A branch the plan rewrites is locked by another collaborator.
+    ///
+    /// See [`crate::core::branch_lock`]. The lock's owner can release it
+    /// with `lattice unlock`; anyone can once it expires.
+    #[error("branch '{branch}' is locked by {owner} until {expires_at}")]
+    BranchLocked {
+        /// The locked branch.
+        branch: String,
+        /// Owner of the lock.
+        owner: String,
+        /// When the lock expires.
+        expires_at: String,
+    },
+
+    /// Ctrl-C stopped execution between steps.
+    ///
+    /// The operation is paused, not rolled back: `lattice continue` resumes
+    /// it and `lattice abort` undoes the steps already applied.
+    #[error("interrupted: '{command}' paused after {applied} of {total} steps")]
+    Interrupted {
+        /// Command of the paused operation
+        command: String,
+        /// Steps applied before the interrupt
+        applied: usize,
+        /// Steps in the plan
+        total: usize,
+    },
+
     /// Post-execution verification failed.
     ///
     /// Per ARCHITECTURE.md §6.2, the executor must verify invariants after
@@ -217,6 +269,12 @@ impl<'a> Executor<'a> {
     /// - `ExecuteResult::Paused` if waiting for conflict resolution
     /// - `ExecuteResult::Aborted` if an error occurred
     pub fn execute(&self, plan: &Plan, ctx: &Context) -> Result<ExecuteResult, ExecuteError> {
+        super::timing::time(super::timing::Phase::Execute, || {
+            self.execute_untimed(plan, ctx)
+        })
+    }
+
+    fn execute_untimed(&self, plan: &Plan, ctx: &Context) -> Result<ExecuteResult, ExecuteError> {
         let info = self
             .git
             .info()
@@ -253,9 +311,12 @@ impl<'a> Executor<'a> {
             eprintln!("[debug] Revalidating worktree occupancy under lock");
         }
         self.revalidate_occupancy(plan)?;
+        self.check_branch_locks(plan)?;
 
-        // Create journal
+        // Create journal under the plan's operation ID, so the ledger's
+        // events for this operation find it
         let mut journal = Journal::new(&plan.command);
+        journal.op_id = plan.op_id.clone();
 
         // Write op-state marker
         if ctx.debug {
@@ -283,7 +344,8 @@ impl<'a> Executor<'a> {
             current_fp.as_str(),
         ));
 
-        // Execute steps
+        // Execute steps. Ctrl-C from here on is deferred to step boundaries.
+        let _shield = interrupt::Shield::raise();
         let mut applied_steps = Vec::new();
         let mut step_iter = plan.steps.iter().enumerate().peekable();
 
@@ -296,53 +358,43 @@ impl<'a> Executor<'a> {
                 StepResult::Continue => {
                     applied_steps.push(step.clone());
                     // Journal already persisted by append_* methods per SPEC.md §4.2.2
+
+                    if interrupt::interrupted() && step_iter.peek().is_some() {
+                        let remaining: Vec<PlanStep> = step_iter.map(|(_, s)| s.clone()).collect();
+                        let next_branch = remaining_branches(&remaining)
+                            .into_iter()
+                            .next()
+                            .unwrap_or_else(|| plan.command.clone());
+                        self.pause(
+                            &mut journal,
+                            &paths,
+                            &info.work_dir,
+                            plan,
+                            &next_branch,
+                            INTERRUPTED_STATE,
+                            &remaining,
+                            AwaitingReason::UserInterrupt,
+                        )?;
+
+                        return Err(ExecuteError::Interrupted {
+                            command: plan.command.clone(),
+                            applied: applied_steps.len(),
+                            total: plan.steps.len(),
+                        });
+                    }
                 }
                 StepResult::Pause { branch, git_state } => {
-                    // Record conflict in journal
                     let remaining: Vec<PlanStep> = step_iter.map(|(_, s)| s.clone()).collect();
-                    let remaining_names: Vec<String> = remaining
-                        .iter()
-                        .filter_map(|s| {
-                            if let PlanStep::WriteMetadataCas { branch, .. } = s {
-                                Some(branch.clone())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    // Serialize remaining steps for continuation (Milestone 0.5)
-                    let remaining_steps_json = if remaining.is_empty() {
-                        None
-                    } else {
-                        Some(serde_json::to_string(&remaining).map_err(|e| {
-                            ExecuteError::Internal(format!(
-                                "failed to serialize remaining steps: {}",
-                                e
-                            ))
-                        })?)
-                    };
-
-                    // Use append_* method per SPEC.md §4.2.2
-                    journal.append_conflict_paused(
+                    self.pause(
+                        &mut journal,
                         &paths,
+                        &info.work_dir,
+                        plan,
                         &branch,
                         git_state.description(),
-                        remaining_names,
-                        remaining_steps_json,
+                        &remaining,
+                        AwaitingReason::RebaseConflict,
                     )?;
-                    journal.pause();
-                    journal.write(&paths)?; // Still need write() for phase change
-
-                    // Update op-state to paused with reason
-                    let mut op_state = OpState::from_journal(
-                        &journal,
-                        &paths,
-                        info.work_dir.clone(),
-                        plan.digest(),
-                        plan.touched_refs_with_oids(),
-                    );
-                    op_state.pause_with_reason(AwaitingReason::RebaseConflict, &paths)?;
 
                     return Ok(ExecuteResult::Paused {
                         branch,
@@ -350,7 +402,15 @@ impl<'a> Executor<'a> {
                         remaining_steps: remaining,
                     });
                 }
-                StepResult::Abort { error } => {
+                StepResult::Abort { mut error } => {
+                    // A failed check vetoes the whole plan, so undo what it
+                    // already applied
+                    if matches!(step, PlanStep::PrePushCheck { .. }) {
+                        if let Err(rollback_error) = self.attempt_rollback(&journal) {
+                            error = format!("{}\nRollback failed: {}", error, rollback_error);
+                        }
+                    }
+
                     // Record abort in journal
                     journal.rollback();
                     journal.write(&paths)?;
@@ -447,6 +507,11 @@ impl<'a> Executor<'a> {
         }
         let _ = ledger.append(Event::committed(plan.op_id.as_str(), new_fp.as_str()));
 
+        // Record branch lifecycle stages for time tracking (best-effort)
+        for (branch, stage) in lifecycle_stages(plan) {
+            let _ = ledger.append(Event::branch_lifecycle(branch, stage));
+        }
+
         // Mark journal as committed
         journal.commit();
         journal.write(&paths)?;
@@ -462,6 +527,52 @@ impl<'a> Executor<'a> {
         })
     }
 
+    /// Journal the remaining steps and mark the operation paused, so that
+    /// `continue` resumes it.
+    #[allow(clippy::too_many_arguments)]
+    fn pause(
+        &self,
+        journal: &mut Journal,
+        paths: &LatticePaths,
+        work_dir: &Option<PathBuf>,
+        plan: &Plan,
+        branch: &str,
+        git_state: &str,
+        remaining: &[PlanStep],
+        reason: AwaitingReason,
+    ) -> Result<(), ExecuteError> {
+        // Serialize remaining steps for continuation (Milestone 0.5)
+        let remaining_steps_json = if remaining.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(remaining).map_err(|e| {
+                ExecuteError::Internal(format!("failed to serialize remaining steps: {}", e))
+            })?)
+        };
+
+        // Use append_* method per SPEC.md §4.2.2
+        journal.append_conflict_paused(
+            paths,
+            branch,
+            git_state,
+            remaining_branches(remaining),
+            remaining_steps_json,
+        )?;
+        journal.pause();
+        journal.write(paths)?; // Still need write() for phase change
+
+        // Update op-state to paused with reason
+        let mut op_state = OpState::from_journal(
+            journal,
+            paths,
+            work_dir.clone(),
+            plan.digest(),
+            plan.touched_refs_with_oids(),
+        );
+        op_state.pause_with_reason(reason, paths)?;
+        Ok(())
+    }
+
     /// Execute a single step.
     fn execute_step(
         &self,
@@ -531,34 +642,7 @@ impl<'a> Executor<'a> {
                 old_ref_oid,
                 metadata,
             } => {
-                let store = MetadataStore::new(self.git);
-                let branch_name =
-                    BranchName::new(branch).map_err(|e| ExecuteError::Internal(e.to_string()))?;
-
-                let old = old_ref_oid
-                    .as_ref()
-                    .map(Oid::new)
-                    .transpose()
-                    .map_err(|e| ExecuteError::Internal(e.to_string()))?;
-
-                let new_oid = store
-                    .write_cas(&branch_name, old.as_ref(), metadata)
-                    .map_err(|e| match e {
-                        StoreError::CasFailed { expected, actual } => ExecuteError::CasFailed {
-                            refname: format!("refs/branch-metadata/{}", branch),
-                            expected,
-                            actual,
-                        },
-                        other => ExecuteError::Metadata(other),
-                    })?;
-
-                // Use append_* per SPEC.md §4.2.2 - persists immediately with fsync
-                journal.append_metadata_write(
-                    paths,
-                    branch,
-                    old_ref_oid.clone(),
-                    new_oid.to_string(),
-                )?;
+                write_metadata_cas(self.git, journal, paths, branch, old_ref_oid, metadata)?;
                 Ok(StepResult::Continue)
             }
 
@@ -572,6 +656,7 @@ impl<'a> Executor<'a> {
                 let old =
                     Oid::new(old_ref_oid).map_err(|e| ExecuteError::Internal(e.to_string()))?;
 
+                let old_content = store.snapshot(&old);
                 store.delete_cas(&branch_name, &old).map_err(|e| match e {
                     StoreError::CasFailed { expected, actual } => ExecuteError::CasFailed {
                         refname: format!("refs/branch-metadata/{}", branch),
@@ -582,7 +667,7 @@ impl<'a> Executor<'a> {
                 })?;
 
                 // Use append_* per SPEC.md §4.2.2 - persists immediately with fsync
-                journal.append_metadata_delete(paths, branch, old_ref_oid)?;
+                journal.append_metadata_delete(paths, branch, old_ref_oid, old_content)?;
                 Ok(StepResult::Continue)
             }
 
@@ -594,8 +679,10 @@ impl<'a> Executor<'a> {
                 // Record intent in journal before executing (append_* persists immediately)
                 journal.append_git_process(paths, args.clone(), description)?;
 
-                // Execute the git command
-                let result = self.git.run_command(args)?;
+                // Execute the git command, continuing past conflicts that
+                // recorded resolutions already settled
+                let result = run_git(self.git, args)?;
+                let result = continue_past_recorded_resolutions(self.git)?.unwrap_or(result);
 
                 // Check for conflicts BEFORE checking success status.
                 // Git rebase/merge/cherry-pick return non-zero exit codes when
@@ -639,6 +726,23 @@ impl<'a> Executor<'a> {
                 Ok(StepResult::Continue)
             }
 
+            PlanStep::RunGitInWorktree {
+                worktree,
+                args,
+                description,
+                expected_effects,
+            } => {
+                // Journaled as the equivalent `git -C <worktree> ...` invocation
+                let mut journaled = vec!["-C".to_string(), worktree.clone()];
+                journaled.extend(args.iter().cloned());
+                journal.append_git_process(paths, journaled, description)?;
+
+                match run_git_in_worktree(worktree, args, expected_effects)? {
+                    Some(error) => Ok(StepResult::Abort { error }),
+                    None => Ok(StepResult::Continue),
+                }
+            }
+
             PlanStep::Checkpoint { name } => {
                 // Use append_* per SPEC.md §4.2.2 - persists immediately with fsync
                 journal.append_checkpoint(paths, name)?;
@@ -780,6 +884,15 @@ impl<'a> Executor<'a> {
                 Ok(StepResult::Continue)
             }
 
+            PlanStep::PrePushCheck { branch, command } => {
+                journal.append_checkpoint(paths, format!("pre-push check: {}", branch))?;
+
+                match run_pre_push_check(self.git, paths, branch, command)? {
+                    Some(error) => Ok(StepResult::Abort { error }),
+                    None => Ok(StepResult::Continue),
+                }
+            }
+
             PlanStep::ForgePush {
                 branch,
                 force,
@@ -931,6 +1044,16 @@ impl<'a> Executor<'a> {
                     ExecuteError::Internal(format!("failed to check worktree occupancy: {}", e))
                 })?
             {
+                // Branches rewritten inside their own worktree only need it clean
+                if plan.worktree_for(branch).map(Path::new) == Some(worktree_path.as_path()) {
+                    if worktree_is_clean(&worktree_path)? {
+                        continue;
+                    }
+                    return Err(ExecuteError::WorktreeDirty {
+                        branch: branch.to_string(),
+                        worktree_path: worktree_path.display().to_string(),
+                    });
+                }
                 return Err(ExecuteError::OccupancyViolation {
                     branch: branch.to_string(),
                     worktree_path: worktree_path.display().to_string(),
@@ -941,6 +1064,31 @@ impl<'a> Executor<'a> {
         Ok(())
     }
 
+    /// Refuse plans that rewrite a branch someone else has locked.
+    ///
+    /// Checked under the repo lock alongside occupancy. Locks are compared
+    /// against the local copies of the lock refs; `lattice sync` refreshes
+    /// them when metadata refs are shared.
+    fn check_branch_locks(&self, plan: &Plan) -> Result<(), ExecuteError> {
+        let touched = plan.touched_branches();
+        if touched.is_empty() {
+            return Ok(());
+        }
+
+        let me = self.git.user_email();
+        let blocking = BranchLockStore::new(self.git)
+            .blocking(&touched, me.as_deref(), &UtcTimestamp::now())
+            .map_err(|e| ExecuteError::Internal(format!("failed to read branch locks: {}", e)))?;
+        match blocking {
+            Some(lock) => Err(ExecuteError::BranchLocked {
+                branch: lock.branch.to_string(),
+                owner: lock.owner,
+                expires_at: lock.expires_at.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Compute current fingerprint from repository state.
     fn compute_current_fingerprint(&self) -> Result<Fingerprint, ExecuteError> {
         use std::collections::HashMap;
@@ -963,7 +1111,7 @@ impl<'a> Executor<'a> {
                 branch,
                 super::scan::ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: crate::core::metadata::schema::BranchMetadataV1::new(
+                    metadata: crate::core::metadata::schema::BranchMetadataV2::new(
                         BranchName::new("placeholder").unwrap(),
                         BranchName::new("placeholder").unwrap(),
                         oid,
@@ -987,6 +1135,80 @@ enum StepResult {
     Abort { error: String },
 }
 
+/// Run a git step, out of Ctrl-C's reach while an [`interrupt::Shield`] is
+/// up so the step completes.
+fn run_git(git: &Git, args: &[String]) -> Result<GitCommandResult, GitError> {
+    if interrupt::shielded() {
+        git.run_command_shielded(args)
+    } else {
+        git.run_command(args)
+    }
+}
+
+/// Continue a rebase for as long as it stops only on conflicts that git
+/// rerere resolved from recorded resolutions.
+///
+/// Returns the result of the last `git rebase --continue`, or `None` if
+/// the rebase wasn't continued. Stops when the rebase finishes, stops on a
+/// conflict that still needs the user, or fails to make progress.
+pub(crate) fn continue_past_recorded_resolutions(
+    git: &Git,
+) -> Result<Option<GitCommandResult>, GitError> {
+    let args: Vec<String> = ["-c", "core.editor=true", "rebase", "--continue"]
+        .map(String::from)
+        .to_vec();
+    let mut last = None;
+    while rerere::enabled()
+        && matches!(git.state(), GitState::Rebase { .. })
+        && git.resolved_by_rerere()?
+    {
+        let head = git.try_resolve_ref("HEAD")?;
+        let result = run_git(git, &args)?;
+        let stuck = !result.success && git.try_resolve_ref("HEAD")? == head;
+        last = Some(result);
+        if stuck {
+            break;
+        }
+    }
+    Ok(last)
+}
+
+/// Branches whose metadata the remaining steps write, in order (for display).
+fn remaining_branches(remaining: &[PlanStep]) -> Vec<String> {
+    remaining
+        .iter()
+        .filter_map(|s| {
+            if let PlanStep::WriteMetadataCas { branch, .. } = s {
+                Some(branch.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Branch lifecycle stages implied by a successfully applied plan.
+///
+/// New metadata marks a branch as created (or newly tracked); a rebase
+/// conflict point marks it as restacked.
+fn lifecycle_stages(plan: &Plan) -> Vec<(String, BranchStage)> {
+    plan.steps
+        .iter()
+        .filter_map(|step| match step {
+            PlanStep::WriteMetadataCas {
+                branch,
+                old_ref_oid: None,
+                ..
+            } => Some((branch.clone(), BranchStage::Created)),
+            PlanStep::PotentialConflictPause {
+                branch,
+                git_operation,
+            } if git_operation == "rebase" => Some((branch.clone(), BranchStage::Restacked)),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Execute a plan (convenience function).
 ///
 /// This is a simpler interface when you just need to execute a plan
@@ -996,6 +1218,231 @@ pub fn execute(plan: &Plan, git: &Git, ctx: &Context) -> Result<ExecuteResult, E
     executor.execute(plan, ctx)
 }
 
+/// Apply a [`PlanStep::WriteMetadataCas`] step and journal it.
+///
+/// Shared by the executor and `lattice continue`, which finishes the steps
+/// of a paused plan.
+pub(crate) fn write_metadata_cas(
+    git: &Git,
+    journal: &mut Journal,
+    paths: &LatticePaths,
+    branch: &str,
+    old_ref_oid: &Option<String>,
+    metadata: &BranchMetadataV2,
+) -> Result<(), ExecuteError> {
+    let store = MetadataStore::new(git);
+    let branch_name = BranchName::new(branch).map_err(|e| ExecuteError::Internal(e.to_string()))?;
+
+    let old = old_ref_oid
+        .as_ref()
+        .map(Oid::new)
+        .transpose()
+        .map_err(|e| ExecuteError::Internal(e.to_string()))?;
+
+    let metadata = resolve_deferred_base(git, metadata)?;
+    let old_content = old.as_ref().and_then(|oid| store.snapshot(oid));
+    let new_oid = store
+        .write_cas(&branch_name, old.as_ref(), &metadata)
+        .map_err(|e| match e {
+            StoreError::CasFailed { expected, actual } => ExecuteError::CasFailed {
+                refname: format!("refs/branch-metadata/{}", branch),
+                expected,
+                actual,
+            },
+            other => ExecuteError::Metadata(other),
+        })?;
+
+    // Use append_* per SPEC.md §4.2.2 - persists immediately with fsync
+    journal.append_metadata_write(
+        paths,
+        branch,
+        old_ref_oid.clone(),
+        old_content,
+        new_oid.to_string(),
+    )?;
+    Ok(())
+}
+
+/// Resolve a base recorded as a branch name to that branch's current tip.
+///
+/// A plan that rebases a branch onto a parent rebased earlier in the same
+/// plan can't know the parent's new tip while planning, so it records the
+/// parent's name as the base; the name is resolved when the metadata of a
+/// [`PlanStep::WriteMetadataCas`] step is written.
+pub(crate) fn resolve_deferred_base<'m>(
+    git: &Git,
+    metadata: &'m BranchMetadataV2,
+) -> Result<Cow<'m, BranchMetadataV2>, GitError> {
+    if Oid::new(&metadata.base.oid).is_ok() {
+        return Ok(Cow::Borrowed(metadata));
+    }
+    let tip = git.resolve_ref(&format!("refs/heads/{}", metadata.base.oid))?;
+    let mut resolved = metadata.clone();
+    resolved.base.oid = tip.to_string();
+    Ok(Cow::Owned(resolved))
+}
+
+/// Whether the worktree at `path` has no uncommitted changes.
+pub(crate) fn worktree_is_clean(path: &Path) -> Result<bool, GitError> {
+    Ok(Git::open(path)?.worktree_status(false)?.is_clean())
+}
+
+/// Run the command of a [`PlanStep::RunGitInWorktree`] step.
+///
+/// Returns `Ok(None)` on success, or `Ok(Some(error))` when the operation
+/// must abort. A command that stops on conflicts is aborted in that
+/// worktree first, so the worktree is left as it was.
+pub(crate) fn run_git_in_worktree(
+    worktree: &str,
+    args: &[String],
+    expected_effects: &[String],
+) -> Result<Option<String>, GitError> {
+    let git = Git::open(Path::new(worktree))?;
+    let operation = args.first().cloned().unwrap_or_default();
+    let branch = expected_effects
+        .first()
+        .and_then(|r| r.strip_prefix("refs/heads/"))
+        .unwrap_or("unknown");
+
+    let result = run_git(&git, args)?;
+
+    if git.state().is_in_progress() {
+        let _ = git.run_command(&[operation.clone(), "--abort".to_string()]);
+        return Ok(Some(format!(
+            "git {} of '{}' in worktree {} stopped on conflicts and was undone.\n\
+             Re-run the command from {} to resolve them there.",
+            operation, branch, worktree, worktree
+        )));
+    }
+
+    if !result.success {
+        return Ok(Some(format!(
+            "git {} failed in worktree {} (exit code {}): {}",
+            operation,
+            worktree,
+            result.exit_code,
+            result.stderr.trim()
+        )));
+    }
+
+    for effect in expected_effects {
+        if git.try_resolve_ref(effect)?.is_none() {
+            return Ok(Some(format!(
+                "git command succeeded but expected ref '{}' was not created",
+                effect
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+/// How much of a failed pre-push check's output to show.
+const PRE_PUSH_OUTPUT_LINES: usize = 20;
+
+/// Run the command of a [`PlanStep::PrePushCheck`] step.
+///
+/// The branch tip is checked out, detached, in a temporary worktree, so the
+/// user's worktrees are left alone and bare repositories work too. The
+/// command runs there through the shell with `LATTICE_BRANCH` set to the
+/// branch, and `LATTICE_REPO_ROOT` to the repository's work tree if it has
+/// one (for reusing build caches). The worktree is removed afterwards.
+///
+/// Returns `Ok(None)` when the check passes, or `Ok(Some(error))` naming the
+/// branch and command, with the tail of the command's output, when it fails.
+pub(crate) fn run_pre_push_check(
+    git: &Git,
+    paths: &LatticePaths,
+    branch: &str,
+    command: &str,
+) -> Result<Option<String>, GitError> {
+    let tip = git.resolve_ref(&format!("refs/heads/{}", branch))?;
+    let worktree = paths.repo_pre_push_worktree_path();
+    let worktree_arg = worktree.display().to_string();
+
+    // Clear out a worktree left behind by an interrupted check
+    if worktree.exists() {
+        let _ = git.run_command(&[
+            "worktree".to_string(),
+            "remove".to_string(),
+            "--force".to_string(),
+            worktree_arg.clone(),
+        ]);
+        let _ = std::fs::remove_dir_all(&worktree);
+    }
+    let _ = git.run_command(&["worktree".to_string(), "prune".to_string()]);
+
+    let added = git.run_command(&[
+        "worktree".to_string(),
+        "add".to_string(),
+        "--detach".to_string(),
+        worktree_arg.clone(),
+        tip.to_string(),
+    ])?;
+    if !added.success {
+        return Ok(Some(format!(
+            "could not check out '{}' to run the pre-push check: {}",
+            branch,
+            added.stderr.trim()
+        )));
+    }
+
+    let mut shell = if cfg!(windows) {
+        let mut c = std::process::Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    } else {
+        let mut c = std::process::Command::new("sh");
+        c.args(["-c", command]);
+        c
+    };
+    shell.current_dir(&worktree).env("LATTICE_BRANCH", branch);
+    if let Some(root) = git.info().ok().and_then(|info| info.work_dir) {
+        shell.env("LATTICE_REPO_ROOT", root);
+    }
+    let output = shell.stdin(std::process::Stdio::null()).output();
+
+    let _ = git.run_command(&[
+        "worktree".to_string(),
+        "remove".to_string(),
+        "--force".to_string(),
+        worktree_arg,
+    ]);
+
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            return Ok(Some(format!(
+                "pre-push check for '{}' could not run `{}`: {}",
+                branch, command, e
+            )))
+        }
+    };
+    if output.status.success() {
+        return Ok(None);
+    }
+
+    let mut combined = String::from_utf8_lossy(&output.stdout).into_owned();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    let lines: Vec<&str> = combined.trim_end().lines().collect();
+    let tail = lines[lines.len().saturating_sub(PRE_PUSH_OUTPUT_LINES)..].join("\n");
+    let status = output.status.code().map_or_else(
+        || "killed by a signal".to_string(),
+        |c| format!("exit code {}", c),
+    );
+    Ok(Some(format!(
+        "pre-push check failed on '{}' ({}): `{}`\nNothing was pushed.{}",
+        branch,
+        status,
+        command,
+        if tail.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n{}", tail)
+        }
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1126,4 +1573,122 @@ mod tests {
             };
         }
     }
+
+    mod lifecycle {
+        use super::*;
+        use crate::core::metadata::schema::BranchMetadataV2;
+        use crate::core::ops::journal::OpId;
+
+        #[test]
+        fn new_metadata_and_rebase_are_recorded() {
+            let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+            let metadata = BranchMetadataV2::new(
+                BranchName::new("feature").unwrap(),
+                BranchName::new("main").unwrap(),
+                oid,
+            );
+            let plan = Plan::new(OpId::new(), "test").with_steps([
+                PlanStep::WriteMetadataCas {
+                    branch: "feature".to_string(),
+                    old_ref_oid: None,
+                    metadata: Box::new(metadata.clone()),
+                },
+                PlanStep::WriteMetadataCas {
+                    branch: "other".to_string(),
+                    old_ref_oid: Some("abc123def4567890abc123def4567890abc12345".to_string()),
+                    metadata: Box::new(metadata),
+                },
+                PlanStep::PotentialConflictPause {
+                    branch: "child".to_string(),
+                    git_operation: "rebase".to_string(),
+                },
+            ]);
+
+            assert_eq!(
+                lifecycle_stages(&plan),
+                vec![
+                    ("feature".to_string(), BranchStage::Created),
+                    ("child".to_string(), BranchStage::Restacked),
+                ]
+            );
+        }
+    }
+
+    mod interrupt {
+        use super::*;
+        use crate::core::ops::journal::{OpId, OpPhase};
+        use crate::engine::interrupt as ctrl_c;
+        use std::process::Command;
+        use tempfile::TempDir;
+
+        fn run_git(dir: &Path, args: &[&str]) -> String {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .expect("failed to run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+
+        fn context() -> Context {
+            Context {
+                cwd: None,
+                interactive: false,
+                quiet: true,
+                debug: false,
+                verify: true,
+                offline: false,
+                read_only: false,
+            }
+        }
+
+        #[test]
+        fn ctrl_c_pauses_at_the_next_step_boundary() {
+            let dir = TempDir::new().unwrap();
+            run_git(dir.path(), &["init", "-q", "-b", "main"]);
+            run_git(dir.path(), &["config", "user.email", "test@example.com"]);
+            run_git(dir.path(), &["config", "user.name", "Test User"]);
+            run_git(
+                dir.path(),
+                &["commit", "-q", "--allow-empty", "-m", "Initial"],
+            );
+            let head = run_git(dir.path(), &["rev-parse", "HEAD"]);
+            let git = Git::open(dir.path()).unwrap();
+
+            let plan = Plan::new(OpId::new(), "test").with_steps(["a", "b"].map(|name| {
+                PlanStep::UpdateRefCas {
+                    refname: format!("refs/heads/{}", name),
+                    old_oid: None,
+                    new_oid: head.clone(),
+                    reason: "test".to_string(),
+                }
+            }));
+
+            ctrl_c::simulate();
+            let result = Executor::new(&git).execute(&plan, &context());
+            ctrl_c::clear_simulated();
+
+            assert!(matches!(
+                result,
+                Err(ExecuteError::Interrupted {
+                    applied: 1,
+                    total: 2,
+                    ..
+                })
+            ));
+            assert!(git.ref_exists("refs/heads/a"));
+            assert!(!git.ref_exists("refs/heads/b"));
+
+            let paths = LatticePaths::from_repo_info(&git.info().unwrap());
+            let op_state = OpState::read(&paths).unwrap().expect("op-state");
+            assert_eq!(op_state.phase, OpPhase::Paused);
+            assert_eq!(
+                op_state.awaiting_reason,
+                Some(AwaitingReason::UserInterrupt)
+            );
+            let journal = Journal::read(&paths, &op_state.op_id).unwrap();
+            assert!(journal.has_remaining_steps());
+        }
+    }
 }
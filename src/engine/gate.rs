@@ -74,6 +74,19 @@ impl RequirementSet {
     pub fn missing(&self, caps: &CapabilitySet) -> Vec<Capability> {
         caps.missing(self.capabilities)
     }
+    /// Check whether commands with these requirements may run while a
+    /// Lattice or Git operation is paused.
+    ///
+    /// Such commands are scoped to reading state, so they are allowed to
+    /// look around mid-operation instead of forcing `continue`/`abort`.
+    pub fn allows_paused_operation(&self) -> bool {
+        !self
+            .capabilities
+            .contains(&Capability::NoLatticeOpInProgress)
+            && !self
+                .capabilities
+                .contains(&Capability::NoExternalGitOpInProgress)
+    }
 }
 
 /// Predefined requirement sets for common command categories.
@@ -83,13 +96,18 @@ pub mod requirements {
     /// Requirements for read-only commands (log, info, parent, children).
     ///
     /// These commands only need the repository to be accessible.
-    /// Works in bare repositories.
+    /// Works in bare repositories and in read-only mode; every other
+    /// requirement set needs `WritesAllowed`.
+    ///
+    /// Does not require the absence of in-progress operations, so these
+    /// commands keep working while a restack or other operation is paused.
     pub const READ_ONLY: RequirementSet = RequirementSet::new("read-only", &[Capability::RepoOpen]);
 
     /// Requirements for commands that read stack structure (checkout, up, down).
     ///
     /// These need metadata and graph to be valid but don't require
-    /// absence of in-progress operations.
+    /// absence of in-progress operations. They move HEAD and the working
+    /// tree, so read-only mode disables them.
     ///
     /// Per SPEC.md §4.6.6 Category C, navigation commands require a working
     /// directory and do NOT work in bare repositories.
@@ -97,6 +115,8 @@ pub mod requirements {
         "navigation",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             Capability::TrunkKnown,
             Capability::MetadataReadable,
             Capability::GraphValid,
@@ -115,6 +135,8 @@ pub mod requirements {
         "mutating",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             Capability::TrunkKnown,
             Capability::NoLatticeOpInProgress,
             Capability::NoExternalGitOpInProgress,
@@ -134,6 +156,8 @@ pub mod requirements {
         "mutating-metadata-only",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             Capability::TrunkKnown,
             Capability::NoLatticeOpInProgress,
             Capability::NoExternalGitOpInProgress,
@@ -157,6 +181,8 @@ pub mod requirements {
         "remote",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             Capability::TrunkKnown,
             Capability::NoLatticeOpInProgress,
             Capability::NoExternalGitOpInProgress,
@@ -181,6 +207,8 @@ pub mod requirements {
         "remote-bare-allowed",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             Capability::TrunkKnown,
             Capability::NoLatticeOpInProgress,
             Capability::NoExternalGitOpInProgress,
@@ -194,6 +222,49 @@ pub mod requirements {
         ],
     );
 
+    /// Requirements for reading a public repository without a token.
+    ///
+    /// Like REMOTE, without AuthAvailable and RepoAuthorized: `get` uses
+    /// this when the remote is on github.com and the user hasn't logged in,
+    /// and reads the PR through GitHub's public API.
+    pub const REMOTE_PUBLIC: RequirementSet = RequirementSet::new(
+        "remote-public",
+        &[
+            Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
+            Capability::TrunkKnown,
+            Capability::NoLatticeOpInProgress,
+            Capability::NoExternalGitOpInProgress,
+            Capability::MetadataReadable,
+            Capability::GraphValid,
+            Capability::FrozenPolicySatisfied,
+            Capability::WorkingDirectoryAvailable,
+            Capability::RemoteResolved,
+        ],
+    );
+
+    /// Requirements for reading a public repository without a token in
+    /// bare repo mode.
+    ///
+    /// REMOTE_PUBLIC without WorkingDirectoryAvailable, for
+    /// `get --no-checkout`.
+    pub const REMOTE_PUBLIC_BARE_ALLOWED: RequirementSet = RequirementSet::new(
+        "remote-public-bare-allowed",
+        &[
+            Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
+            Capability::TrunkKnown,
+            Capability::NoLatticeOpInProgress,
+            Capability::NoExternalGitOpInProgress,
+            Capability::MetadataReadable,
+            Capability::GraphValid,
+            Capability::FrozenPolicySatisfied,
+            Capability::RemoteResolved,
+        ],
+    );
+
     /// Requirements for continue/abort commands.
     ///
     /// These specifically require a Lattice op to be in progress.
@@ -201,12 +272,21 @@ pub mod requirements {
         "recovery",
         &[
             Capability::RepoOpen,
+            Capability::WritesAllowed,
+            Capability::RepoIdentityVerified,
             // Note: Does NOT require NoLatticeOpInProgress
         ],
     );
 
-    /// Minimal requirements (just repo access).
-    pub const MINIMAL: RequirementSet = RequirementSet::new("minimal", &[Capability::RepoOpen]);
+    /// Minimal requirements for commands that write (just repo access,
+    /// outside read-only mode).
+    ///
+    /// Doesn't require `RepoIdentityVerified`, so `init` and `config set`
+    /// can correct a mismatched identity.
+    pub const MINIMAL: RequirementSet = RequirementSet::new(
+        "minimal",
+        &[Capability::RepoOpen, Capability::WritesAllowed],
+    );
 }
 
 /// Result of gating check.
@@ -375,6 +455,12 @@ impl std::fmt::Display for RepairBundle {
 /// }
 /// ```
 pub fn gate(snapshot: RepoSnapshot, requirements: &RequirementSet) -> GateResult {
+    super::timing::time(super::timing::Phase::Gate, || {
+        gate_untimed(snapshot, requirements)
+    })
+}
+
+fn gate_untimed(snapshot: RepoSnapshot, requirements: &RequirementSet) -> GateResult {
     let caps = snapshot.health.capabilities();
 
     if requirements.satisfied_by(caps) {
@@ -404,6 +490,16 @@ pub fn gate_with_scope(
     snapshot: RepoSnapshot,
     requirements: &RequirementSet,
     target: Option<&BranchName>,
+) -> GateResult {
+    super::timing::time(super::timing::Phase::Gate, || {
+        gate_with_scope_untimed(snapshot, requirements, target)
+    })
+}
+
+fn gate_with_scope_untimed(
+    snapshot: RepoSnapshot,
+    requirements: &RequirementSet,
+    target: Option<&BranchName>,
 ) -> GateResult {
     // First do basic gating
     let caps = snapshot.health.capabilities();
@@ -610,6 +706,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: Some(BranchName::new("main").unwrap()),
             branches: HashMap::new(),
             metadata: HashMap::new(),
@@ -758,6 +855,38 @@ mod tests {
                 .capabilities
                 .contains(&Capability::WorkingDirectoryAvailable));
         }
+
+        #[test]
+        fn remote_public_needs_no_auth() {
+            for set in [
+                &requirements::REMOTE_PUBLIC,
+                &requirements::REMOTE_PUBLIC_BARE_ALLOWED,
+            ] {
+                assert!(set.capabilities.contains(&Capability::RemoteResolved));
+                assert!(!set.capabilities.contains(&Capability::AuthAvailable));
+                assert!(!set.capabilities.contains(&Capability::RepoAuthorized));
+            }
+            assert!(requirements::REMOTE_PUBLIC
+                .capabilities
+                .contains(&Capability::WorkingDirectoryAvailable));
+            assert!(!requirements::REMOTE_PUBLIC_BARE_ALLOWED
+                .capabilities
+                .contains(&Capability::WorkingDirectoryAvailable));
+        }
+
+        #[test]
+        fn read_only_allows_paused_operation() {
+            assert!(requirements::READ_ONLY.allows_paused_operation());
+            assert!(requirements::NAVIGATION.allows_paused_operation());
+            assert!(requirements::RECOVERY.allows_paused_operation());
+        }
+
+        #[test]
+        fn mutating_blocks_paused_operation() {
+            assert!(!requirements::MUTATING.allows_paused_operation());
+            assert!(!requirements::MUTATING_METADATA_ONLY.allows_paused_operation());
+            assert!(!requirements::REMOTE.allows_paused_operation());
+        }
     }
 
     mod gate_result {
@@ -802,6 +931,8 @@ mod tests {
         fn passes_when_all_caps_present() {
             let snapshot = make_snapshot_with_caps(&[
                 Capability::RepoOpen,
+                Capability::WritesAllowed,
+                Capability::RepoIdentityVerified,
                 Capability::TrunkKnown,
                 Capability::MetadataReadable,
                 Capability::GraphValid,
@@ -880,6 +1011,8 @@ mod tests {
             // Bare repo should pass metadata-only requirements
             let snapshot = make_snapshot_with_caps(&[
                 Capability::RepoOpen,
+                Capability::WritesAllowed,
+                Capability::RepoIdentityVerified,
                 Capability::TrunkKnown,
                 Capability::NoLatticeOpInProgress,
                 Capability::NoExternalGitOpInProgress,
@@ -892,6 +1025,79 @@ mod tests {
             let result = gate(snapshot, &requirements::MUTATING_METADATA_ONLY);
             assert!(result.is_ready());
         }
+
+        #[test]
+        fn read_only_mode_passes_only_read_only() {
+            // Everything but WritesAllowed
+            let snapshot = || {
+                make_snapshot_with_caps(&[
+                    Capability::RepoOpen,
+                    Capability::TrunkKnown,
+                    Capability::NoLatticeOpInProgress,
+                    Capability::NoExternalGitOpInProgress,
+                    Capability::MetadataReadable,
+                    Capability::GraphValid,
+                    Capability::FrozenPolicySatisfied,
+                    Capability::WorkingDirectoryAvailable,
+                    Capability::RemoteResolved,
+                    Capability::AuthAvailable,
+                    Capability::RepoAuthorized,
+                    Capability::RepoIdentityVerified,
+                ])
+            };
+
+            assert!(gate(snapshot(), &requirements::READ_ONLY).is_ready());
+            for requirements in [
+                &requirements::NAVIGATION,
+                &requirements::MUTATING,
+                &requirements::MUTATING_METADATA_ONLY,
+                &requirements::REMOTE,
+                &requirements::REMOTE_BARE_ALLOWED,
+                &requirements::RECOVERY,
+                &requirements::MINIMAL,
+            ] {
+                let bundle = gate(snapshot(), requirements).unwrap_repair();
+                assert_eq!(bundle.missing_capabilities, [Capability::WritesAllowed]);
+            }
+        }
+
+        #[test]
+        fn identity_mismatch_leaves_init_and_config_usable() {
+            // Everything but RepoIdentityVerified
+            let snapshot = || {
+                make_snapshot_with_caps(&[
+                    Capability::RepoOpen,
+                    Capability::WritesAllowed,
+                    Capability::TrunkKnown,
+                    Capability::NoLatticeOpInProgress,
+                    Capability::NoExternalGitOpInProgress,
+                    Capability::MetadataReadable,
+                    Capability::GraphValid,
+                    Capability::FrozenPolicySatisfied,
+                    Capability::WorkingDirectoryAvailable,
+                    Capability::RemoteResolved,
+                    Capability::AuthAvailable,
+                    Capability::RepoAuthorized,
+                ])
+            };
+
+            assert!(gate(snapshot(), &requirements::READ_ONLY).is_ready());
+            assert!(gate(snapshot(), &requirements::MINIMAL).is_ready());
+            for requirements in [
+                &requirements::NAVIGATION,
+                &requirements::MUTATING,
+                &requirements::MUTATING_METADATA_ONLY,
+                &requirements::REMOTE,
+                &requirements::REMOTE_BARE_ALLOWED,
+                &requirements::RECOVERY,
+            ] {
+                let bundle = gate(snapshot(), requirements).unwrap_repair();
+                assert_eq!(
+                    bundle.missing_capabilities,
+                    [Capability::RepoIdentityVerified]
+                );
+            }
+        }
     }
 
     mod repair_bundle {
@@ -1108,7 +1314,7 @@ mod tests {
         #[test]
         fn check_frozen_policy_fails_when_frozen() {
             use crate::core::metadata::schema::{
-                BranchMetadataV1, FreezeScope as FreezeScopeEnum, FreezeState,
+                BranchMetadataV2, FreezeScope as FreezeScopeEnum, FreezeState,
             };
             use crate::core::types::Oid;
 
@@ -1119,7 +1325,7 @@ mod tests {
             // Create metadata with 'a' frozen
             let mut metadata = HashMap::new();
             let mut meta_a =
-                BranchMetadataV1::new(a.clone(), BranchName::new("main").unwrap(), Oid::zero());
+                BranchMetadataV2::new(a.clone(), BranchName::new("main").unwrap(), Oid::zero());
             meta_a.freeze = FreezeState::frozen(FreezeScopeEnum::Single, Some("test".to_string()));
             metadata.insert(
                 a.clone(),
@@ -70,6 +70,21 @@ impl Severity {
     pub fn is_blocking(&self) -> bool {
         matches!(self, Severity::Blocking)
     }
+
+    /// Check if this severity is no more severe than `max`.
+    ///
+    /// Info is below warning, which is below blocking.
+    pub fn at_most(&self, max: Severity) -> bool {
+        self.rank() <= max.rank()
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Severity::Info => 0,
+            Severity::Warning => 1,
+            Severity::Blocking => 2,
+        }
+    }
 }
 
 impl std::fmt::Display for Severity {
@@ -165,6 +180,24 @@ pub struct ClosedPrInfo {
     pub url: String,
 }
 
+/// An open PR in a chain whose branches don't exist locally.
+///
+/// Used in Tier 2 deep analysis to describe each PR of a remote-only stack,
+/// listed parents first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainedPrInfo {
+    /// PR number.
+    pub number: u64,
+    /// Head branch of the PR.
+    pub head_ref: String,
+    /// Base branch of the PR (trunk, a tracked branch, or another PR's head).
+    pub base_ref: String,
+    /// PR URL.
+    pub url: String,
+    /// Tip of the head branch on the remote.
+    pub tip: String,
+}
+
 /// Evidence supporting an issue.
 ///
 /// Evidence provides concrete details about what was found during
@@ -265,6 +298,28 @@ pub enum Evidence {
         /// Whether the result was truncated due to budget limits.
         truncated: bool,
     },
+
+    /// An open PR whose base branch was deleted on the remote.
+    DeletedPrBase {
+        /// PR number.
+        number: u64,
+        /// The PR's head branch.
+        head_ref: String,
+        /// The deleted base branch.
+        base_ref: String,
+        /// Nearest tracked ancestor of the base whose remote branch still
+        /// exists, or trunk.
+        surviving_base: String,
+    },
+
+    /// A chain of open PRs none of whose branches exist locally (Tier 2
+    /// deep analysis).
+    RemotePrChain {
+        /// Branch the bottom PR targets (trunk or a tracked branch).
+        root_base: String,
+        /// The chain's PRs, parents before children.
+        prs: Vec<ChainedPrInfo>,
+    },
 }
 
 impl Evidence {
@@ -284,6 +339,11 @@ impl Evidence {
             Evidence::ParentCandidates { branch, .. } => branch.clone(),
             Evidence::PrReference { number, .. } => number.to_string(),
             Evidence::SyntheticStackChildren { head_branch, .. } => head_branch.clone(),
+            Evidence::DeletedPrBase { number, .. } => number.to_string(),
+            Evidence::RemotePrChain { prs, .. } => prs
+                .first()
+                .map(|pr| pr.head_ref.clone())
+                .unwrap_or_default(),
         }
     }
 }
@@ -683,6 +743,216 @@ pub mod issues {
         .blocks(Capability::GraphValid)
     }
 
+    /// Create an issue for a branch whose remote override does not exist.
+    pub fn branch_remote_missing(branch: &str, remote: &str) -> Issue {
+        Issue::new(
+            "branch-remote-missing",
+            Severity::Warning,
+            format!(
+                "Branch '{}' is configured to push to remote '{}', which does not exist",
+                branch, remote
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+        .with_evidence(Evidence::Config {
+            key: format!("remote.{}.url", remote),
+            problem: "not configured".to_string(),
+        })
+    }
+
+    /// Create an issue for a submitted branch whose remote branch was
+    /// deleted after its commits reached trunk.
+    pub fn remote_branch_merged(branch: &str, remote: &str) -> Issue {
+        Issue::new(
+            "remote-branch-merged",
+            Severity::Warning,
+            format!(
+                "Branch '{}' was deleted from '{}' after being merged into trunk",
+                branch, remote
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/remotes/{}/{}", remote, branch),
+            oid: None,
+        })
+    }
+
+    /// Create an issue for a submitted branch whose remote branch was
+    /// deleted without its commits reaching trunk.
+    pub fn remote_branch_deleted(branch: &str, remote: &str) -> Issue {
+        Issue::new(
+            "remote-branch-deleted",
+            Severity::Warning,
+            format!(
+                "Branch '{}' was deleted from '{}' without being merged",
+                branch, remote
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/remotes/{}/{}", remote, branch),
+            oid: None,
+        })
+    }
+
+    /// Create an issue for a branch behind its remote-tracking ref.
+    ///
+    /// Someone pushed to the remote branch; pushing the local branch would
+    /// be rejected as non-fast-forward.
+    pub fn remote_tracking_behind(
+        branch: &str,
+        remote: &str,
+        behind: usize,
+        local_oid: &str,
+        remote_oid: &str,
+    ) -> Issue {
+        Issue::new(
+            "remote-tracking-behind",
+            Severity::Warning,
+            format!(
+                "Branch '{}' is {} commit(s) behind '{}/{}'",
+                branch, behind, remote, branch
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/heads/{}", branch),
+            oid: Some(local_oid.to_string()),
+        })
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/remotes/{}/{}", remote, branch),
+            oid: Some(remote_oid.to_string()),
+        })
+    }
+
+    /// Create an issue for a branch that diverged from its remote-tracking
+    /// ref: each side has commits the other lacks.
+    pub fn remote_tracking_diverged(
+        branch: &str,
+        remote: &str,
+        local_only: usize,
+        remote_only: usize,
+        local_oid: &str,
+        remote_oid: &str,
+    ) -> Issue {
+        Issue::new(
+            "remote-tracking-diverged",
+            Severity::Warning,
+            format!(
+                "Branch '{}' diverged from '{}/{}': {} local and {} remote commit(s) differ",
+                branch, remote, branch, local_only, remote_only
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/heads/{}", branch),
+            oid: Some(local_oid.to_string()),
+        })
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/remotes/{}/{}", remote, branch),
+            oid: Some(remote_oid.to_string()),
+        })
+    }
+
+    /// Create an issue for a PR whose stack section markers are damaged.
+    ///
+    /// Lattice won't update the PR's description until they are repaired.
+    pub fn pr_body_markers_corrupt(branch: &str, number: u64, url: &str, problem: &str) -> Issue {
+        Issue::new(
+            "pr-body-markers-corrupt",
+            Severity::Warning,
+            format!(
+                "Stack section of PR #{} for '{}' can't be updated: {}. Fix or remove the lattice:stack markers in the PR description",
+                number, branch, problem
+            ),
+        )
+        .with_evidence(Evidence::PrReference {
+            number,
+            url: url.to_string(),
+            context: format!("Description of the PR for branch '{}'", branch),
+        })
+    }
+
+    /// Create an issue for a branch lock that expired and was never released.
+    ///
+    /// It holds nothing, but is still listed and still shared.
+    pub fn stale_branch_lock(branch: &str, owner: &str, expired_at: &str, oid: &str) -> Issue {
+        Issue::new(
+            "stale-branch-lock",
+            Severity::Warning,
+            format!(
+                "Lock on '{}' held by {} expired at {}",
+                branch, owner, expired_at
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/lattice/locks/{}", branch),
+            oid: Some(oid.to_string()),
+        })
+    }
+
+    /// Create an issue for unsigned metadata in a repo that signs metadata.
+    pub fn metadata_unsigned(branch: &str) -> Issue {
+        Issue::new(
+            "metadata-unsigned",
+            Severity::Warning,
+            format!("Metadata for branch '{}' is not signed", branch),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+    }
+
+    /// Create an issue for metadata still stored by an older schema version.
+    ///
+    /// Scan normally rewrites such metadata itself; this is reported when
+    /// that migration couldn't run, leaving the repo with mixed versions.
+    pub fn metadata_schema_outdated(branch: &str, version: u32) -> Issue {
+        Issue::new(
+            "metadata-schema-outdated",
+            Severity::Warning,
+            format!(
+                "Metadata for branch '{}' is stored as schema v{} (current: v{})",
+                branch,
+                version,
+                crate::core::metadata::SCHEMA_VERSION
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+    }
+
+    /// Create an issue for metadata whose signature doesn't verify.
+    ///
+    /// The metadata may have been edited after signing, so commands refuse
+    /// to rely on it until it is reviewed and re-signed.
+    pub fn metadata_signature_invalid(branch: &str, reason: &str) -> Issue {
+        Issue::new(
+            "metadata-signature-invalid",
+            Severity::Blocking,
+            format!(
+                "Metadata signature for branch '{}' is invalid: {}",
+                branch, reason
+            ),
+        )
+        .with_evidence(Evidence::Ref {
+            name: format!("refs/branch-metadata/{}", branch),
+            oid: None,
+        })
+        .blocks(Capability::MetadataReadable)
+    }
+
     /// Create an issue for config file needing migration.
     pub fn config_migration_needed(old_path: &str, new_path: &str) -> Issue {
         Issue::new(
@@ -733,19 +1003,24 @@ pub mod issues {
     /// Per ARCHITECTURE.md Section 8.2, this is a blocking issue requiring
     /// user action - the user must install the GitHub App for the repository.
     pub fn app_not_installed(host: &str, owner: &str, repo: &str) -> Issue {
-        Issue::new(
-            "app-not-installed",
-            Severity::Blocking,
+        let message = if host == "github.com" {
             format!(
                 "GitHub App not installed for {}/{}. Install at: https://github.com/apps/lattice/installations/new",
                 owner, repo
-            ),
-        )
-        .with_evidence(Evidence::Config {
-            key: format!("forge.github.{}/{}/{}", host, owner, repo),
-            problem: "GitHub App not installed or not authorized".to_string(),
-        })
-        .blocks(Capability::RepoAuthorized)
+            )
+        } else {
+            // Enterprise Server instances register their own app
+            format!(
+                "GitHub App not installed for {}/{} on {}. Ask an administrator of {} to install the app you logged in with.",
+                owner, repo, host, host
+            )
+        };
+        Issue::new("app-not-installed", Severity::Blocking, message)
+            .with_evidence(Evidence::Config {
+                key: format!("forge.github.{}/{}/{}", host, owner, repo),
+                problem: "GitHub App not installed or not authorized".to_string(),
+            })
+            .blocks(Capability::RepoAuthorized)
     }
 
     /// Create an issue for failed repository authorization check.
@@ -790,6 +1065,48 @@ pub mod issues {
         .blocks(Capability::WorkingDirectoryAvailable)
     }
 
+    /// Create an issue for read-only mode.
+    ///
+    /// Blocks every command that could change the repository; see
+    /// [`crate::engine::read_only`].
+    pub fn read_only_mode() -> Issue {
+        Issue::new(
+            "read-only-mode",
+            Severity::Blocking,
+            "Lattice is in read-only mode, so commands that change the repository are disabled.\n\
+             \n\
+             Read-only mode is on because --read-only was given or `read_only = true` is set\n\
+             in the global or repo config. Run without it to make changes.",
+        )
+        .blocks(Capability::WritesAllowed)
+    }
+
+    /// Create an issue for a repository that doesn't match the repo config's
+    /// `identity`.
+    ///
+    /// Blocks commands that change the repository; see
+    /// [`crate::engine::repo_identity`]. The CLI's hint says how to correct
+    /// the identity.
+    pub fn repo_identity_mismatch(expected: &str, remote: &str, actual: Option<&str>) -> Issue {
+        let found = match actual {
+            Some(url) => format!("remote '{}' is {}", remote, url),
+            None => format!("there is no remote '{}'", remote),
+        };
+        Issue::new(
+            "repo-identity-mismatch",
+            Severity::Blocking,
+            format!(
+                "This repository doesn't match the Lattice config: it expects {}, but {}.",
+                expected, found
+            ),
+        )
+        .with_evidence(Evidence::Config {
+            key: "identity".to_string(),
+            problem: format!("expected {}, but {}", expected, found),
+        })
+        .blocks(Capability::RepoIdentityVerified)
+    }
+
     /// Create an issue for branches checked out in other worktrees.
     ///
     /// Per SPEC.md §4.6.8, operations that would rewrite a branch checked out
@@ -837,6 +1154,44 @@ pub mod issues {
         issue
     }
 
+    /// Create an issue for branches that would be rewritten inside another
+    /// worktree that has uncommitted changes.
+    ///
+    /// Commands may rewrite a branch checked out elsewhere by running git in
+    /// that worktree, which is only safe when it is clean.
+    ///
+    /// # Arguments
+    ///
+    /// * `dirty` - List of (branch name, worktree path) pairs
+    pub fn occupied_worktrees_dirty(
+        dirty: Vec<(crate::core::types::BranchName, std::path::PathBuf)>,
+    ) -> Issue {
+        let branch_list: Vec<String> = dirty
+            .iter()
+            .map(|(b, p)| format!("'{}' (in {})", b, p.display()))
+            .collect();
+
+        let message = format!(
+            "Checked out in a worktree with uncommitted changes: {}. \
+             Commit or stash them there, then re-run the command.",
+            branch_list.join(", ")
+        );
+
+        let mut issue = Issue::new("occupied-worktree-dirty", Severity::Blocking, message);
+        for (branch, path) in &dirty {
+            issue = issue.with_evidence(Evidence::Ref {
+                name: format!("refs/heads/{}", branch),
+                oid: None,
+            });
+            issue = issue.with_evidence(Evidence::Config {
+                key: format!("worktree:{}", branch),
+                problem: format!("uncommitted changes at {}", path.display()),
+            });
+        }
+
+        issue
+    }
+
     // --- Bootstrap Issues (Remote Evidence) ---
 
     /// Create an issue for detecting open PRs on the remote.
@@ -886,6 +1241,41 @@ pub mod issues {
         })
     }
 
+    /// Create an issue for an open PR whose base branch was deleted on the
+    /// remote.
+    ///
+    /// The PR can no longer be merged where it points; it should be
+    /// retargeted to `surviving_base` (the nearest ancestor still on the
+    /// remote) or trunk.
+    pub fn pr_base_deleted(
+        number: u64,
+        head_ref: &str,
+        base_ref: &str,
+        remote: &str,
+        surviving_base: &str,
+        url: &str,
+    ) -> Issue {
+        Issue::new(
+            "pr-base-deleted",
+            Severity::Warning,
+            format!(
+                "Open PR #{} from '{}' targets '{}', which was deleted from '{}'",
+                number, head_ref, base_ref, remote
+            ),
+        )
+        .with_evidence(Evidence::DeletedPrBase {
+            number,
+            head_ref: head_ref.to_string(),
+            base_ref: base_ref.to_string(),
+            surviving_base: surviving_base.to_string(),
+        })
+        .with_evidence(Evidence::PrReference {
+            number,
+            url: url.to_string(),
+            context: format!("base '{}' deleted", base_ref),
+        })
+    }
+
     /// Create an issue for a local branch matching an open PR but not tracked.
     ///
     /// The user should track the branch to link it with the PR.
@@ -964,6 +1354,35 @@ pub mod issues {
         })
     }
 
+    /// Create an issue for a chain of open PRs missing locally.
+    ///
+    /// Found by `doctor --deep-remote` when stacked open PRs have no local
+    /// branches at all; the whole chain can be imported in one fix.
+    ///
+    /// # Arguments
+    ///
+    /// * `root_base` - Branch the bottom PR targets
+    /// * `prs` - The chain's PRs, parents first
+    pub fn remote_pr_chain_missing(root_base: &str, prs: Vec<ChainedPrInfo>) -> Issue {
+        let numbers = prs
+            .iter()
+            .map(|pr| format!("#{}", pr.number))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Issue::new(
+            "remote-pr-chain-missing",
+            Severity::Warning,
+            format!(
+                "Open PRs {} form a stack on '{}' but none of their branches exist locally",
+                numbers, root_base
+            ),
+        )
+        .with_evidence(Evidence::RemotePrChain {
+            root_base: root_base.to_string(),
+            prs,
+        })
+    }
+
     // --- Rollback Issues ---
 
     /// Create an issue for partial rollback failure.
@@ -1367,6 +1786,26 @@ mod tests {
             assert_eq!(issue.evidence.len(), 1);
         }
 
+        #[test]
+        fn branch_remote_missing() {
+            let issue = issues::branch_remote_missing("feature", "security");
+            assert!(!issue.is_blocking()); // Warning severity
+            assert!(issue.id.as_str().starts_with("branch-remote-missing:"));
+            assert_eq!(issue.evidence.len(), 2);
+        }
+
+        #[test]
+        fn remote_branch_gone() {
+            let merged = issues::remote_branch_merged("feature", "origin");
+            assert!(!merged.is_blocking()); // Warning severity
+            assert!(merged.id.as_str().starts_with("remote-branch-merged:"));
+            assert_eq!(merged.evidence.len(), 2);
+
+            let deleted = issues::remote_branch_deleted("feature", "origin");
+            assert!(deleted.id.as_str().starts_with("remote-branch-deleted:"));
+            assert!(deleted.message.contains("without being merged"));
+        }
+
         #[test]
         fn config_migration_needed() {
             let issue = issues::config_migration_needed(
@@ -1392,6 +1831,24 @@ mod tests {
             assert_eq!(issue.evidence.len(), 1);
         }
 
+        #[test]
+        fn repo_identity_mismatch() {
+            let issue = issues::repo_identity_mismatch(
+                "octo/app",
+                "origin",
+                Some("git@github.com:octo/fork.git"),
+            );
+            assert!(issue.is_blocking());
+            assert!(issue.blocks_capability(&Capability::RepoIdentityVerified));
+            assert!(issue
+                .message
+                .contains("expects octo/app, but remote 'origin' is git@github.com:octo/fork.git"));
+            assert!(!issue.message.contains('\n'));
+
+            let issue = issues::repo_identity_mismatch("octo/app", "upstream", None);
+            assert!(issue.message.contains("there is no remote 'upstream'"));
+        }
+
         #[test]
         fn app_not_installed() {
             let issue = issues::app_not_installed("github.com", "myorg", "myrepo");
@@ -1402,6 +1859,10 @@ mod tests {
                 .message
                 .contains("https://github.com/apps/lattice/installations/new"));
             assert_eq!(issue.evidence.len(), 1);
+
+            let issue = issues::app_not_installed("github.example.com", "myorg", "myrepo");
+            assert!(issue.message.contains("on github.example.com"));
+            assert!(!issue.message.contains("https://github.com/apps"));
         }
 
         #[test]
@@ -1421,6 +1882,13 @@ mod tests {
             assert!(issue.message.contains("bare repository"));
         }
 
+        #[test]
+        fn read_only_mode() {
+            let issue = issues::read_only_mode();
+            assert!(issue.is_blocking());
+            assert!(issue.blocks_capability(&Capability::WritesAllowed));
+        }
+
         #[test]
         fn branches_checked_out_elsewhere_single() {
             use crate::core::types::BranchName;
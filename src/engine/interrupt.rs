@@ -0,0 +1,100 @@
+//! engine::interrupt
+//!
+//! Ctrl-C handling while a plan is executing.
+//!
+//! # Design
+//!
+//! Killing Lattice halfway through a plan leaves whatever state the git call
+//! it was running happened to reach: a rebase stopped between commits, a ref
+//! moved without its metadata. Instead, once [`init`] has installed the
+//! handler, Ctrl-C during execution only raises a flag. The executor finishes
+//! the step it is on, then pauses the operation the way a conflict does, with
+//! [`AwaitingReason::UserInterrupt`](crate::core::ops::journal::AwaitingReason),
+//! so `lattice continue` resumes it and `lattice abort` rolls it back.
+//!
+//! - Git processes started for a step run in their own process group, so
+//!   the terminal's SIGINT doesn't reach them and they complete.
+//! - A second Ctrl-C exits at once, for a step that hangs.
+//! - Outside execution, Ctrl-C exits immediately, as it would without the
+//!   handler: nothing has been written yet.
+//!
+//! Exits use status 130, the shell convention for SIGINT.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static INSTALLED: Once = Once::new();
+static SHIELDED: AtomicBool = AtomicBool::new(false);
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Install the Ctrl-C handler for this process.
+///
+/// Only the first call has an effect. Until it runs (library use, tests),
+/// Ctrl-C keeps its default behavior.
+pub fn init() {
+    INSTALLED.call_once(|| {
+        let _ = ctrlc::set_handler(|| {
+            if SHIELDED.load(Ordering::SeqCst) && !INTERRUPTED.swap(true, Ordering::SeqCst) {
+                eprintln!(
+                    "\nInterrupted. Finishing the current step; press Ctrl-C again to stop now."
+                );
+            } else {
+                std::process::exit(130);
+            }
+        });
+    });
+}
+
+/// Whether Ctrl-C was pressed since the current [`Shield`] was raised.
+pub fn interrupted() -> bool {
+    #[cfg(test)]
+    if SIMULATED.get() {
+        return true;
+    }
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Whether a [`Shield`] is up, so child processes should be kept out of the
+/// terminal's process group.
+pub fn shielded() -> bool {
+    SHIELDED.load(Ordering::SeqCst)
+}
+
+/// Defers Ctrl-C to step boundaries while alive.
+///
+/// Raised by the executor around its step loop.
+pub struct Shield(());
+
+impl Shield {
+    /// Start deferring Ctrl-C.
+    pub fn raise() -> Self {
+        INTERRUPTED.store(false, Ordering::SeqCst);
+        SHIELDED.store(true, Ordering::SeqCst);
+        Shield(())
+    }
+}
+
+impl Drop for Shield {
+    fn drop(&mut self) {
+        SHIELDED.store(false, Ordering::SeqCst);
+        INTERRUPTED.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    // Per thread, so a simulated Ctrl-C only reaches the test that made it
+    static SIMULATED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Simulate a Ctrl-C on this thread until [`clear_simulated`] is called.
+#[cfg(test)]
+pub(crate) fn simulate() {
+    SIMULATED.set(true);
+}
+
+/// Stop simulating Ctrl-C on this thread.
+#[cfg(test)]
+pub(crate) fn clear_simulated() {
+    SIMULATED.set(false);
+}
@@ -25,6 +25,9 @@
 //! - `DivergenceObserved`: Out-of-band changes detected
 //! - `DoctorProposed`: Doctor proposed a repair
 //! - `DoctorApplied`: Doctor applied a repair
+//! - `UndoApplied` / `RedoApplied`: An operation was undone, or redone after an undo
+//! - `BranchLifecycle`: A branch was created, submitted, restacked, or merged
+//! - `CommandTimed`: How long each phase of a command took
 //!
 //! # Example
 //!
@@ -47,10 +50,13 @@
 //! }
 //! ```
 
+use std::collections::HashSet;
+
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use super::timing::PhaseTiming;
 use crate::core::types::Oid;
 use crate::git::{Git, GitError};
 
@@ -177,6 +183,73 @@ pub enum Event {
         /// Timestamp.
         timestamp: String,
     },
+
+    /// Redo was applied.
+    ///
+    /// Recorded after reapplying an operation that was undone. Pairs with
+    /// the newest `UndoApplied` for the same operation.
+    RedoApplied {
+        /// Operation ID of the operation that was redone.
+        redone_op_id: String,
+        /// Number of refs that were set back to the operation's result.
+        refs_reapplied: usize,
+        /// Timestamp.
+        timestamp: String,
+    },
+
+    /// A branch reached a lifecycle stage.
+    ///
+    /// Recorded best-effort for time tracking (`lattice stats`).
+    BranchLifecycle {
+        /// Branch name.
+        branch: String,
+        /// Stage reached.
+        stage: BranchStage,
+        /// Timestamp.
+        timestamp: String,
+    },
+
+    /// A command finished, with the time spent in each engine phase.
+    ///
+    /// Recorded best-effort after every command that scanned the repository.
+    CommandTimed {
+        /// Command that ran.
+        command: String,
+        /// Time per phase, in lifecycle order.
+        phases: Vec<PhaseTiming>,
+        /// Wall-clock milliseconds for the whole command.
+        total_millis: u64,
+        /// Whether the command succeeded.
+        succeeded: bool,
+        /// Timestamp.
+        timestamp: String,
+    },
+}
+
+/// Lifecycle stages recorded for a branch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchStage {
+    /// Branch was created or started being tracked.
+    Created,
+    /// A PR was opened for the branch.
+    Submitted,
+    /// Branch was rebased onto its parent.
+    Restacked,
+    /// Branch's PR was merged.
+    Merged,
+}
+
+impl std::fmt::Display for BranchStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BranchStage::Created => "created",
+            BranchStage::Submitted => "submitted",
+            BranchStage::Restacked => "restacked",
+            BranchStage::Merged => "merged",
+        };
+        write!(f, "{}", s)
+    }
 }
 
 impl Event {
@@ -255,6 +328,40 @@ impl Event {
         }
     }
 
+    /// Create a RedoApplied event.
+    pub fn redo_applied(redone_op_id: impl Into<String>, refs_reapplied: usize) -> Self {
+        Event::RedoApplied {
+            redone_op_id: redone_op_id.into(),
+            refs_reapplied,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Create a BranchLifecycle event.
+    pub fn branch_lifecycle(branch: impl Into<String>, stage: BranchStage) -> Self {
+        Event::BranchLifecycle {
+            branch: branch.into(),
+            stage,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Create a CommandTimed event.
+    pub fn command_timed(
+        command: impl Into<String>,
+        phases: Vec<PhaseTiming>,
+        total_millis: u64,
+        succeeded: bool,
+    ) -> Self {
+        Event::CommandTimed {
+            command: command.into(),
+            phases,
+            total_millis,
+            succeeded,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
     /// Get the operation ID if this event has one.
     pub fn op_id(&self) -> Option<&str> {
         match self {
@@ -262,9 +369,44 @@ impl Event {
             Event::Committed { op_id, .. } => Some(op_id),
             Event::Aborted { op_id, .. } => Some(op_id),
             Event::UndoApplied { undone_op_id, .. } => Some(undone_op_id),
+            Event::RedoApplied { redone_op_id, .. } => Some(redone_op_id),
             Event::DivergenceObserved { .. } => None,
             Event::DoctorProposed { .. } => None,
             Event::DoctorApplied { .. } => None,
+            Event::BranchLifecycle { .. } => None,
+            Event::CommandTimed { .. } => None,
+        }
+    }
+
+    /// The event's type, as stored in its `type` field.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Event::IntentRecorded { .. } => "intent_recorded",
+            Event::Committed { .. } => "committed",
+            Event::Aborted { .. } => "aborted",
+            Event::DivergenceObserved { .. } => "divergence_observed",
+            Event::DoctorProposed { .. } => "doctor_proposed",
+            Event::DoctorApplied { .. } => "doctor_applied",
+            Event::UndoApplied { .. } => "undo_applied",
+            Event::RedoApplied { .. } => "redo_applied",
+            Event::BranchLifecycle { .. } => "branch_lifecycle",
+            Event::CommandTimed { .. } => "command_timed",
+        }
+    }
+
+    /// When the event was recorded (RFC 3339).
+    pub fn timestamp(&self) -> &str {
+        match self {
+            Event::IntentRecorded { timestamp, .. }
+            | Event::Committed { timestamp, .. }
+            | Event::Aborted { timestamp, .. }
+            | Event::DivergenceObserved { timestamp, .. }
+            | Event::DoctorProposed { timestamp, .. }
+            | Event::DoctorApplied { timestamp, .. }
+            | Event::UndoApplied { timestamp, .. }
+            | Event::RedoApplied { timestamp, .. }
+            | Event::BranchLifecycle { timestamp, .. }
+            | Event::CommandTimed { timestamp, .. } => timestamp,
         }
     }
 
@@ -420,6 +562,27 @@ impl<'a> EventLedger<'a> {
         Ok(events)
     }
 
+    /// Read every event (most recent first).
+    ///
+    /// Note: This walks the entire chain, so may be slow for long histories.
+    pub fn all(&self) -> Result<Vec<StoredEvent>, LedgerError> {
+        let mut events = Vec::new();
+        let mut current_oid = self.git.try_resolve_ref_to_object(LEDGER_REF)?;
+
+        while let Some(oid) = current_oid {
+            let event = self.read_event_from_commit(&oid)?;
+            events.push(StoredEvent {
+                commit_oid: oid.clone(),
+                event,
+            });
+
+            let parents = self.git.commit_parents(&oid)?;
+            current_oid = parents.into_iter().next();
+        }
+
+        Ok(events)
+    }
+
     /// Get the fingerprint from the last Committed event.
     ///
     /// This is used for divergence detection. Returns `None` if no
@@ -488,6 +651,106 @@ impl<'a> EventLedger<'a> {
         Ok(events)
     }
 
+    /// Collect the IDs of every operation that is currently undone.
+    ///
+    /// Returned newest undo first. An operation counts as undone when its
+    /// latest undo or redo event is an `UndoApplied`.
+    ///
+    /// Note: This walks the entire chain, so may be slow for long histories.
+    pub fn undone_op_ids(&self) -> Result<Vec<String>, LedgerError> {
+        let mut seen = HashSet::new();
+        let mut undone = Vec::new();
+        let mut current_oid = self.git.try_resolve_ref_to_object(LEDGER_REF)?;
+
+        while let Some(oid) = current_oid {
+            match self.read_event_from_commit(&oid)? {
+                Event::UndoApplied { undone_op_id, .. } if seen.insert(undone_op_id.clone()) => {
+                    undone.push(undone_op_id);
+                }
+                Event::RedoApplied { redone_op_id, .. } => {
+                    seen.insert(redone_op_id);
+                }
+                _ => {}
+            }
+
+            let parents = self.git.commit_parents(&oid)?;
+            current_oid = parents.into_iter().next();
+        }
+
+        Ok(undone)
+    }
+
+    /// Find lifecycle events, optionally restricted to one branch.
+    ///
+    /// Returns events in chronological order (oldest first).
+    pub fn lifecycle_events(&self, branch: Option<&str>) -> Result<Vec<StoredEvent>, LedgerError> {
+        let mut events = Vec::new();
+        let mut current_oid = self.git.try_resolve_ref_to_object(LEDGER_REF)?;
+
+        while let Some(oid) = current_oid {
+            let event = self.read_event_from_commit(&oid)?;
+
+            if let Event::BranchLifecycle { branch: b, .. } = &event {
+                if branch.is_none_or(|wanted| wanted == b) {
+                    events.push(StoredEvent {
+                        commit_oid: oid.clone(),
+                        event,
+                    });
+                }
+            }
+
+            let parents = self.git.commit_parents(&oid)?;
+            current_oid = parents.into_iter().next();
+        }
+
+        events.reverse(); // Chronological order
+        Ok(events)
+    }
+
+    /// Check whether `branch` reached `stage` at or after `since`.
+    ///
+    /// Events are appended in time order, so the walk stops at the first
+    /// event older than `since`, or at the branch's own `Created` event.
+    /// Passing the branch's creation time bounds the walk by the branch's
+    /// lifetime rather than the whole ledger.
+    pub fn has_lifecycle_event_since(
+        &self,
+        branch: &str,
+        stage: BranchStage,
+        since: &chrono::DateTime<Utc>,
+    ) -> Result<bool, LedgerError> {
+        let mut current_oid = self.git.try_resolve_ref_to_object(LEDGER_REF)?;
+
+        while let Some(oid) = current_oid {
+            let event = self.read_event_from_commit(&oid)?;
+            let older =
+                chrono::DateTime::parse_from_rfc3339(event.timestamp()).is_ok_and(|t| t < *since);
+            if older {
+                return Ok(false);
+            }
+            if let Event::BranchLifecycle {
+                branch: b,
+                stage: s,
+                ..
+            } = &event
+            {
+                if b == branch {
+                    if *s == stage {
+                        return Ok(true);
+                    }
+                    if *s == BranchStage::Created {
+                        return Ok(false);
+                    }
+                }
+            }
+
+            let parents = self.git.commit_parents(&oid)?;
+            current_oid = parents.into_iter().next();
+        }
+
+        Ok(false)
+    }
+
     // =========================================================================
     // Internal helpers
     // =========================================================================
@@ -708,6 +971,16 @@ mod tests {
                 Event::divergence_observed("old", "new", vec!["ref".to_string()]),
                 Event::doctor_proposed(vec!["i".to_string()], vec!["f".to_string()]),
                 Event::doctor_applied(vec!["f".to_string()], "fp"),
+                Event::branch_lifecycle("feature", BranchStage::Submitted),
+                Event::command_timed(
+                    "restack",
+                    vec![PhaseTiming {
+                        phase: crate::engine::timing::Phase::Scan,
+                        millis: 120,
+                    }],
+                    450,
+                    true,
+                ),
             ];
 
             for event in events {
@@ -724,6 +997,32 @@ mod tests {
             assert!(json.contains("\"type\""));
             assert!(json.contains("\"committed\""));
         }
+
+        #[test]
+        fn kind_matches_type_tag() {
+            let events = [
+                Event::intent_recorded("op", "restack", "digest", "fp"),
+                Event::aborted("op", "conflict"),
+                Event::doctor_proposed(vec![], vec![]),
+                Event::undo_applied("op", 1),
+                Event::branch_lifecycle("feature", BranchStage::Merged),
+            ];
+            for event in events {
+                let value: serde_json::Value =
+                    serde_json::from_str(&event.to_json().unwrap()).unwrap();
+                assert_eq!(value["type"], event.kind());
+                assert_eq!(value["timestamp"], event.timestamp());
+            }
+        }
+
+        #[test]
+        fn branch_lifecycle_stage_is_snake_case() {
+            let event = Event::branch_lifecycle("feature", BranchStage::Restacked);
+            let json = event.to_json().unwrap();
+            assert!(json.contains("\"branch_lifecycle\""));
+            assert!(json.contains("\"restacked\""));
+            assert!(event.op_id().is_none());
+        }
     }
 
     mod ledger_error {
@@ -1049,5 +1348,108 @@ mod tests {
             assert!(matches!(events[0].event, Event::IntentRecorded { .. }));
             assert!(matches!(events[1].event, Event::Committed { .. }));
         }
+
+        #[test]
+        fn undone_op_ids_pairs_undo_and_redo() {
+            let repo = TestRepo::new();
+            let git = repo.git();
+            let ledger = EventLedger::new(&git);
+
+            ledger
+                .append(Event::committed("op-1", "fp-1"))
+                .expect("append");
+            ledger
+                .append(Event::undo_applied("op-1", 2))
+                .expect("append");
+            ledger
+                .append(Event::committed("op-2", "fp-2"))
+                .expect("append");
+            ledger
+                .append(Event::undo_applied("op-2", 1))
+                .expect("append");
+
+            let undone = ledger.undone_op_ids().expect("undone_op_ids");
+            assert_eq!(undone, vec!["op-2", "op-1"]);
+
+            // Redo pairs with the newest undo; undoing again re-pairs
+            ledger
+                .append(Event::redo_applied("op-2", 1))
+                .expect("append");
+            assert_eq!(ledger.undone_op_ids().unwrap(), vec!["op-1"]);
+            ledger
+                .append(Event::undo_applied("op-2", 1))
+                .expect("append");
+            assert_eq!(ledger.undone_op_ids().unwrap(), vec!["op-2", "op-1"]);
+        }
+
+        #[test]
+        fn lifecycle_events_filters_by_branch() {
+            let repo = TestRepo::new();
+            let git = repo.git();
+            let ledger = EventLedger::new(&git);
+
+            ledger
+                .append(Event::branch_lifecycle("a", BranchStage::Created))
+                .expect("append");
+            ledger
+                .append(Event::committed("op-1", "fp-1"))
+                .expect("append");
+            ledger
+                .append(Event::branch_lifecycle("b", BranchStage::Created))
+                .expect("append");
+            ledger
+                .append(Event::branch_lifecycle("a", BranchStage::Submitted))
+                .expect("append");
+
+            let all = ledger.lifecycle_events(None).expect("lifecycle_events");
+            assert_eq!(all.len(), 3);
+
+            let a = ledger
+                .lifecycle_events(Some("a"))
+                .expect("lifecycle_events");
+            assert_eq!(a.len(), 2);
+            assert!(matches!(
+                a[1].event,
+                Event::BranchLifecycle {
+                    stage: BranchStage::Submitted,
+                    ..
+                }
+            ));
+        }
+
+        #[test]
+        fn lifecycle_lookup_stops_at_creation() {
+            let repo = TestRepo::new();
+            let git = repo.git();
+            let ledger = EventLedger::new(&git);
+            let long_ago = Utc::now() - chrono::Duration::days(1);
+
+            ledger
+                .append(Event::branch_lifecycle("a", BranchStage::Merged))
+                .expect("append");
+            ledger
+                .append(Event::branch_lifecycle("a", BranchStage::Created))
+                .expect("append");
+            ledger
+                .append(Event::committed("op-1", "fp-1"))
+                .expect("append");
+
+            // The merge belongs to an earlier branch of the same name
+            assert!(!ledger
+                .has_lifecycle_event_since("a", BranchStage::Merged, &long_ago)
+                .unwrap());
+
+            ledger
+                .append(Event::branch_lifecycle("a", BranchStage::Merged))
+                .expect("append");
+            assert!(ledger
+                .has_lifecycle_event_since("a", BranchStage::Merged, &long_ago)
+                .unwrap());
+            // Nothing is newer than a time in the future
+            let later = Utc::now() + chrono::Duration::days(1);
+            assert!(!ledger
+                .has_lifecycle_event_since("a", BranchStage::Merged, &later)
+                .unwrap());
+        }
     }
 }
@@ -56,12 +56,16 @@ pub mod command;
 pub mod exec;
 pub mod gate;
 pub mod health;
+pub mod interrupt;
 pub mod ledger;
 pub mod modes;
 pub mod plan;
+pub mod read_only;
+pub mod repo_identity;
 pub mod rollback;
 pub mod runner;
 pub mod scan;
+pub mod timing;
 pub mod verify;
 
 // Test-only hooks for fault injection and drift testing.
@@ -79,7 +83,7 @@ pub use gate::{
     RepairBundle, RequirementSet, ValidatedData,
 };
 pub use health::{Issue, IssueId, RepoHealthReport, Severity};
-pub use ledger::{Event, EventLedger, LedgerError};
+pub use ledger::{BranchStage, Event, EventLedger, LedgerError};
 pub use modes::{GetMode, ModeError, SubmitMode, SyncMode};
 pub use plan::{Plan, PlanError, PlanStep};
 pub use rollback::{rollback_journal, RollbackError, RollbackResult};
@@ -88,7 +92,7 @@ pub use runner::{
     run_command_with_requirements_and_scope, run_command_with_scope, run_gated,
     run_readonly_command, RunError,
 };
-pub use scan::{scan, DivergenceInfo, RepoSnapshot, ScanError, ScannedMetadata};
+pub use scan::{scan, scan_for, DivergenceInfo, RepoSnapshot, ScanError, ScannedMetadata};
 pub use verify::{fast_verify, VerifyError};
 
 use std::path::PathBuf;
@@ -113,6 +117,11 @@ pub struct Context {
     /// Git hook verification enabled.
     /// When false, git commands are invoked with --no-verify.
     pub verify: bool,
+    /// Offline mode: don't contact the remote or forge.
+    pub offline: bool,
+    /// Read-only mode forced by `--read-only`.
+    /// The config can also turn it on; see [`read_only`].
+    pub read_only: bool,
 }
 
 impl Default for Context {
@@ -123,6 +132,8 @@ impl Default for Context {
             quiet: false,
             interactive: true,
             verify: true,
+            offline: false,
+            read_only: false,
         }
     }
 }
@@ -183,7 +194,7 @@ where
     F: FnOnce(&ReadyContext) -> Result<(Plan, T), PlanError>,
 {
     // 1. Scan
-    let snapshot = scan::scan(git)?;
+    let snapshot = scan::scan_for(git, ctx)?;
 
     // 2. Gate
     let ready = match gate::gate(snapshot, requirements) {
@@ -214,7 +225,7 @@ where
     }
 
     // 5. Verify (re-scan and verify)
-    let post_snapshot = scan::scan(git)?;
+    let post_snapshot = scan::scan_for(git, ctx)?;
     verify::fast_verify(git, &post_snapshot)?;
 
     Ok(output)
@@ -245,6 +256,8 @@ mod tests {
                 quiet: true,
                 interactive: false,
                 verify: false,
+                offline: false,
+                read_only: false,
             };
             assert_eq!(ctx.cwd, Some(PathBuf::from("/custom")));
             assert!(ctx.debug);
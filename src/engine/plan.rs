@@ -44,9 +44,9 @@
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use crate::core::metadata::schema::BranchMetadataV1;
-use crate::core::ops::journal::{OpId, TouchedRef};
-use crate::core::types::BranchName;
+use crate::core::metadata::schema::{parse_metadata, BranchMetadataV2};
+use crate::core::ops::journal::{OpId, TouchedRef, PLAN_SCHEMA_VERSION};
+use crate::core::types::{BranchName, TypeError};
 
 /// A typed plan step.
 ///
@@ -90,7 +90,7 @@ pub enum PlanStep {
         /// Expected current metadata ref OID, or None if creating.
         old_ref_oid: Option<String>,
         /// The metadata to write (boxed to reduce enum size).
-        metadata: Box<BranchMetadataV1>,
+        metadata: Box<BranchMetadataV2>,
     },
 
     /// Delete metadata with CAS semantics.
@@ -113,6 +113,24 @@ pub enum PlanStep {
         expected_effects: Vec<String>,
     },
 
+    /// Run a git command inside another worktree.
+    ///
+    /// Used to rebase a branch that is checked out in a different worktree:
+    /// running the rebase there moves that worktree's HEAD, index, and files
+    /// along with the branch. The worktree must be clean. A rebase that stops
+    /// on conflicts is undone there and the operation aborts with
+    /// instructions instead of pausing.
+    RunGitInWorktree {
+        /// Path of the worktree that has the branch checked out.
+        worktree: String,
+        /// Git command arguments (excluding "git" itself).
+        args: Vec<String>,
+        /// Human-readable description of what the command does.
+        description: String,
+        /// Refs that are expected to change.
+        expected_effects: Vec<String>,
+    },
+
     /// Checkpoint marker for recovery.
     ///
     /// Used to mark significant points in multi-step operations.
@@ -181,6 +199,18 @@ pub enum PlanStep {
         refspec: Option<String>,
     },
 
+    /// Run a user-configured check on a branch before it is pushed.
+    ///
+    /// The command runs through the shell in a temporary worktree detached
+    /// at the branch tip. A non-zero exit aborts the plan and rolls back the
+    /// steps applied before it, so the branch is never pushed.
+    PrePushCheck {
+        /// Branch to check.
+        branch: String,
+        /// Shell command to run (`submit.pre_push`).
+        command: String,
+    },
+
     /// Push a branch to a remote.
     ///
     /// This step pushes a local branch to the remote. It uses
@@ -279,6 +309,9 @@ impl PlanStep {
             }
             PlanStep::RunGit {
                 expected_effects, ..
+            }
+            | PlanStep::RunGitInWorktree {
+                expected_effects, ..
             } => expected_effects.iter().map(|s| s.as_str()).collect(),
             PlanStep::Checkpoint { .. } => vec![],
             PlanStep::PotentialConflictPause { .. } => vec![],
@@ -293,6 +326,8 @@ impl PlanStep {
             // Forge steps don't touch local refs directly (except ForgePush which
             // pushes existing refs). The effects are remote-side.
             PlanStep::ForgeFetch { .. } => vec![],
+            // Checks read the branch in a worktree of their own
+            PlanStep::PrePushCheck { .. } => vec![],
             PlanStep::ForgePush { branch, .. } => {
                 // Push reads the local branch ref
                 vec![branch.as_str()]
@@ -314,6 +349,7 @@ impl PlanStep {
                 | PlanStep::WriteMetadataCas { .. }
                 | PlanStep::DeleteMetadataCas { .. }
                 | PlanStep::RunGit { .. }
+                | PlanStep::RunGitInWorktree { .. }
                 | PlanStep::CreateSnapshotBranch { .. }
                 | PlanStep::Checkout { .. }
                 // Forge steps that perform mutations (remote-side effects)
@@ -347,6 +383,11 @@ impl PlanStep {
                 format!("Delete metadata for {}", branch)
             }
             PlanStep::RunGit { description, .. } => description.clone(),
+            PlanStep::RunGitInWorktree {
+                worktree,
+                description,
+                ..
+            } => format!("{} (in worktree {})", description, worktree),
             PlanStep::Checkpoint { name } => format!("Checkpoint: {}", name),
             PlanStep::PotentialConflictPause {
                 branch,
@@ -375,6 +416,9 @@ impl PlanStep {
                     format!("Fetch from {}", remote)
                 }
             }
+            PlanStep::PrePushCheck { branch, command } => {
+                format!("Check '{}' before pushing: {}", branch, command)
+            }
             PlanStep::ForgePush {
                 branch,
                 force,
@@ -575,17 +619,13 @@ impl Plan {
             match step {
                 PlanStep::UpdateRefCas {
                     refname, old_oid, ..
-                } => {
-                    if seen.insert(refname.clone()) {
-                        result.push(TouchedRef::new(refname.clone(), old_oid.clone()));
-                    }
+                } if seen.insert(refname.clone()) => {
+                    result.push(TouchedRef::new(refname.clone(), old_oid.clone()));
                 }
                 PlanStep::DeleteRefCas {
                     refname, old_oid, ..
-                } => {
-                    if seen.insert(refname.clone()) {
-                        result.push(TouchedRef::new(refname.clone(), Some(old_oid.clone())));
-                    }
+                } if seen.insert(refname.clone()) => {
+                    result.push(TouchedRef::new(refname.clone(), Some(old_oid.clone())));
                 }
                 PlanStep::WriteMetadataCas {
                     branch,
@@ -654,6 +694,22 @@ impl Plan {
             .collect()
     }
 
+    /// Worktree in which this plan rewrites `branch`, if any.
+    ///
+    /// Set when the branch is rewritten by a [`PlanStep::RunGitInWorktree`]
+    /// step. Occupancy checks accept the branch being checked out there.
+    pub fn worktree_for(&self, branch: &BranchName) -> Option<&str> {
+        let refname = format!("refs/heads/{}", branch);
+        self.steps.iter().find_map(|step| match step {
+            PlanStep::RunGitInWorktree {
+                worktree,
+                expected_effects,
+                ..
+            } if expected_effects.contains(&refname) => Some(worktree.as_str()),
+            _ => None,
+        })
+    }
+
     /// Check if this plan touches any branch refs.
     ///
     /// Plans that only touch metadata refs don't need occupancy checks.
@@ -730,6 +786,118 @@ pub enum PlanError {
     /// Conflict with frozen branch.
     #[error("cannot modify frozen branch: {0}")]
     FrozenBranch(String),
+
+    /// The trunk branch is not configured.
+    #[error("trunk not configured")]
+    TrunkNotConfigured,
+
+    /// The branch is not tracked by Lattice.
+    #[error("branch '{0}' is not tracked")]
+    UntrackedBranch(String),
+
+    /// A name given to the command is not a valid branch name.
+    #[error(transparent)]
+    InvalidName(#[from] TypeError),
+}
+
+/// Errors from decoding plan steps persisted by another binary.
+#[derive(Debug, thiserror::Error)]
+pub enum PlanDecodeError {
+    /// The steps were written by a newer binary.
+    #[error(
+        "this operation was started by a newer lattice (plan schema v{found}); \
+         this binary understands up to v{supported}"
+    )]
+    NewerSchema {
+        /// Schema version recorded with the operation.
+        found: u32,
+        /// Newest schema version this binary reads.
+        supported: u32,
+    },
+
+    /// The schema version predates any format this binary knows.
+    #[error("unknown plan schema v{0}")]
+    UnknownSchema(u32),
+
+    /// The steps could not be read at their recorded schema version.
+    #[error("failed to decode remaining steps (plan schema v{version}): {message}")]
+    Malformed {
+        /// Schema version the steps were read as.
+        version: u32,
+        /// What went wrong.
+        message: String,
+    },
+}
+
+/// Decode remaining plan steps recorded at `schema_version`.
+///
+/// Steps written by an older binary are migrated forward one schema
+/// version at a time before being parsed, so `continue` keeps working
+/// across upgrades. Steps written by a newer binary are refused rather
+/// than half-understood.
+///
+/// # Example
+///
+/// ```
+/// use latticework::engine::plan::{decode_remaining_steps, PlanStep};
+/// use latticework::core::ops::journal::PLAN_SCHEMA_VERSION;
+///
+/// let json = r#"[{"type": "checkpoint", "name": "resume"}]"#;
+/// let steps = decode_remaining_steps(json, 1).unwrap();
+/// assert_eq!(steps, vec![PlanStep::Checkpoint { name: "resume".to_string() }]);
+///
+/// assert!(decode_remaining_steps(json, PLAN_SCHEMA_VERSION + 1).is_err());
+/// ```
+pub fn decode_remaining_steps(
+    json: &str,
+    schema_version: u32,
+) -> Result<Vec<PlanStep>, PlanDecodeError> {
+    if schema_version > PLAN_SCHEMA_VERSION {
+        return Err(PlanDecodeError::NewerSchema {
+            found: schema_version,
+            supported: PLAN_SCHEMA_VERSION,
+        });
+    }
+    if schema_version == 0 {
+        return Err(PlanDecodeError::UnknownSchema(schema_version));
+    }
+
+    let malformed = |version: u32, message: String| PlanDecodeError::Malformed { version, message };
+
+    let mut steps: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|e| malformed(schema_version, e.to_string()))?;
+
+    for version in schema_version..PLAN_SCHEMA_VERSION {
+        for step in &mut steps {
+            migrate_step(step, version).map_err(|message| malformed(version, message))?;
+        }
+    }
+
+    steps
+        .into_iter()
+        .map(serde_json::from_value)
+        .collect::<Result<_, _>>()
+        .map_err(|e| malformed(PLAN_SCHEMA_VERSION, e.to_string()))
+}
+
+/// Rewrite one serialized step from schema `from` to `from + 1`.
+fn migrate_step(step: &mut serde_json::Value, from: u32) -> Result<(), String> {
+    match from {
+        // v1 -> v2: `write_metadata_cas` may carry v1 branch metadata.
+        // Upgrade it the way the metadata store does on read.
+        1 => {
+            if step.get("type").and_then(|t| t.as_str()) != Some("write_metadata_cas") {
+                return Ok(());
+            }
+            let Some(metadata) = step.get_mut("metadata") else {
+                return Err("write_metadata_cas step has no metadata".to_string());
+            };
+            let upgraded = parse_metadata(&metadata.to_string()).map_err(|e| e.to_string())?;
+            *metadata = serde_json::to_value(upgraded).map_err(|e| e.to_string())?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -791,6 +959,28 @@ mod tests {
             assert!(refs.contains(&"refs/heads/feature"));
         }
 
+        #[test]
+        fn run_git_in_worktree() {
+            let step = PlanStep::RunGitInWorktree {
+                worktree: "/work/feature".to_string(),
+                args: vec!["rebase".to_string()],
+                description: "Rebase feature".to_string(),
+                expected_effects: vec!["refs/heads/feature".to_string()],
+            };
+
+            assert!(step.is_mutation());
+            assert_eq!(
+                step.description(),
+                "Rebase feature (in worktree /work/feature)"
+            );
+
+            let plan = Plan::new(OpId::new(), "restack").with_step(step);
+            let feature = BranchName::new("feature").unwrap();
+            assert_eq!(plan.worktree_for(&feature), Some("/work/feature"));
+            assert_eq!(plan.touched_branches(), vec![feature]);
+            assert_eq!(plan.worktree_for(&BranchName::new("other").unwrap()), None);
+        }
+
         #[test]
         fn serialization_roundtrip() {
             let steps = vec![
@@ -1135,10 +1325,10 @@ mod tests {
 
         #[test]
         fn touched_refs_with_oids_includes_metadata_refs() {
-            use crate::core::metadata::schema::BranchMetadataV1;
+            use crate::core::metadata::schema::BranchMetadataV2;
             use crate::core::types::{BranchName, Oid};
 
-            let meta = BranchMetadataV1::new(
+            let meta = BranchMetadataV2::new(
                 BranchName::new("feature").unwrap(),
                 BranchName::new("main").unwrap(),
                 Oid::new("abc123abc123abc123abc123abc123abc123abc1").unwrap(),
@@ -1186,4 +1376,65 @@ mod tests {
             assert!(err.to_string().contains("frozen"));
         }
     }
+
+    mod decode_remaining_steps_fn {
+        use super::*;
+
+        fn v1_write_metadata_step() -> serde_json::Value {
+            serde_json::json!({
+                "type": "write_metadata_cas",
+                "branch": "feature",
+                "old_ref_oid": null,
+                "metadata": {
+                    "kind": "lattice.branch-metadata",
+                    "schema_version": 1,
+                    "branch": { "name": "feature" },
+                    "parent": { "kind": "trunk", "name": "main" },
+                    "base": { "oid": "abc123def4567890abc123def4567890abc12345" },
+                    "freeze": { "state": "unfrozen" },
+                    "pr": { "state": "none" },
+                    "timestamps": {
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "updated_at": "2024-01-01T00:00:00Z"
+                    }
+                }
+            })
+        }
+
+        #[test]
+        fn current_version_roundtrips() {
+            let steps = vec![PlanStep::Checkpoint {
+                name: "resume".to_string(),
+            }];
+            let json = serde_json::to_string(&steps).unwrap();
+            let decoded = decode_remaining_steps(&json, PLAN_SCHEMA_VERSION).unwrap();
+            assert_eq!(decoded, steps);
+        }
+
+        #[test]
+        fn v1_metadata_is_upgraded() {
+            let json = serde_json::json!([v1_write_metadata_step()]).to_string();
+            let decoded = decode_remaining_steps(&json, 1).unwrap();
+            match &decoded[0] {
+                PlanStep::WriteMetadataCas { metadata, .. } => {
+                    assert_eq!(metadata.schema_version, 2);
+                    assert_eq!(metadata.branch.name, "feature");
+                }
+                other => panic!("unexpected step {other:?}"),
+            }
+        }
+
+        #[test]
+        fn newer_schema_refused() {
+            let err = decode_remaining_steps("[]", PLAN_SCHEMA_VERSION + 1).unwrap_err();
+            assert!(matches!(err, PlanDecodeError::NewerSchema { .. }));
+            assert!(err.to_string().contains("newer lattice"));
+        }
+
+        #[test]
+        fn malformed_steps_name_the_version() {
+            let err = decode_remaining_steps("not json", 1).unwrap_err();
+            assert!(err.to_string().contains("plan schema v1"));
+        }
+    }
 }
@@ -0,0 +1,31 @@
+//! engine::read_only
+//!
+//! Read-only mode for observers that must never change the repository.
+//!
+//! # Design
+//!
+//! CI jobs and shared bots run Lattice to look at stacks, not to change
+//! them. Read-only mode is on when `--read-only` is given or `read_only =
+//! true` is set in the global or repo config. The scanner then withholds
+//! [`Capability::WritesAllowed`](super::Capability::WritesAllowed), which
+//! every requirement set except `READ_ONLY` requires, and records a blocking
+//! issue naming the mode. Mutating commands therefore stop at the gate like
+//! any other unmet requirement, before planning.
+//!
+//! Read-only commands and doctor's diagnosis run as usual. `doctor --fix` is
+//! refused, and the side writes read-only commands make in passing (the CI
+//! state `pr` and background prefetches cache in metadata, doctor's and
+//! scan's event ledger entries, the fetches behind `lock --list` and
+//! `info --remote-diff`) are skipped. Caches outside the repository's refs, such as
+//! the forge status cache, are still written.
+//!
+//! The flag travels in [`Context::read_only`](super::Context::read_only);
+//! [`scan_for`](super::scan::scan_for) combines it with the config.
+
+use crate::core::config::Config;
+
+/// Whether read-only mode is on, given the `--read-only` flag and the
+/// loaded config.
+pub fn is_active(forced: bool, config: Option<&Config>) -> bool {
+    forced || config.is_some_and(Config::read_only)
+}
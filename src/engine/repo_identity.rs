@@ -0,0 +1,133 @@
+//! engine::repo_identity
+//!
+//! Guard against changing the wrong clone.
+//!
+//! # Design
+//!
+//! A `.git/lattice` directory copied into another clone, or a `--cwd` that
+//! points somewhere unexpected, would otherwise let Lattice rewrite branches
+//! and metadata in a repository the config was never meant for. The repo
+//! config may record an `identity`: the URL of the configured remote, or
+//! its `owner/repo` slug. The scanner compares it with the remote's URL and
+//! withholds [`Capability::RepoIdentityVerified`](super::Capability::RepoIdentityVerified)
+//! on a mismatch, recording a blocking issue that names both, so commands
+//! that change the repository stop at the gate before planning.
+//!
+//! Without an `identity`, nothing is checked. `init` and `config set` don't
+//! require the capability, so a mismatch can be corrected in place.
+//!
+//! # Matching
+//!
+//! URLs are compared after normalization: the scheme, user, SSH port,
+//! trailing `/`, and `.git` are dropped, scp-style `host:path` becomes
+//! `host/path`, and case is ignored. So `git@github.com:o/r.git` matches
+//! `https://github.com/o/r`. An identity without a host (`o/r`) matches any
+//! remote whose path is `o/r`.
+
+/// Check a configured identity against the remote's URL.
+///
+/// # Errors
+///
+/// Returns the remote's URL, or `None` if the remote doesn't exist, when it
+/// doesn't match.
+///
+/// # Example
+///
+/// ```
+/// use latticework::engine::repo_identity::verify;
+///
+/// assert!(verify("octo/app", Some("git@github.com:octo/app.git")).is_ok());
+/// assert!(verify("https://github.com/octo/app", Some("ssh://git@github.com:22/octo/app")).is_ok());
+/// assert!(verify("octo/app", Some("https://github.com/octo/fork.git")).is_err());
+/// assert!(verify("octo/app", None).is_err());
+/// ```
+pub fn verify(expected: &str, remote_url: Option<&str>) -> Result<(), Option<String>> {
+    match remote_url {
+        Some(url) if matches(expected, url) => Ok(()),
+        other => Err(other.map(String::from)),
+    }
+}
+
+/// Whether `url` is the remote `expected` names.
+fn matches(expected: &str, url: &str) -> bool {
+    let actual = normalize(url);
+    let expected = normalize(expected);
+    if actual == expected {
+        return true;
+    }
+    // A bare slug names the path on any host
+    let (_, path) = actual.split_once('/').unwrap_or(("", &actual));
+    path == expected
+}
+
+/// Reduce a remote URL or slug to `host/path`, or `path` without a host.
+fn normalize(url: &str) -> String {
+    let url = url.trim();
+    let rest = if let Some((_, rest)) = url.split_once("://") {
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        // Ports say nothing about which repository this is
+        let host = host.split(':').next().unwrap_or(host);
+        if host.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}/{}", host, path)
+        }
+    } else if let Some((user_host, path)) = url.split_once(':').filter(|(h, _)| !h.contains('/')) {
+        // scp-style `git@host:owner/repo`
+        let host = user_host.rsplit('@').next().unwrap_or(user_host);
+        format!("{}/{}", host, path)
+    } else {
+        url.to_string()
+    };
+
+    let rest = rest.trim_end_matches('/');
+    let rest = rest.strip_suffix(".git").unwrap_or(rest);
+    rest.trim_end_matches('/').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls_match_across_transports() {
+        for url in [
+            "git@github.com:Octo/App.git",
+            "https://github.com/octo/app",
+            "https://token@github.com/octo/app.git/",
+            "ssh://git@github.com:22/octo/app.git",
+        ] {
+            assert!(matches("https://github.com/octo/app.git", url), "{}", url);
+            assert!(matches("octo/app", url), "{}", url);
+        }
+    }
+
+    #[test]
+    fn other_repos_and_hosts_do_not_match() {
+        assert!(!matches("octo/app", "git@github.com:octo/app-fork.git"));
+        assert!(!matches("octo/app", "git@github.com:team/octo/app.git"));
+        assert!(!matches(
+            "github.com/octo/app",
+            "git@gitlab.com:octo/app.git"
+        ));
+        assert!(!matches("app", "git@github.com:octo/app.git"));
+    }
+
+    #[test]
+    fn local_paths_compare_whole() {
+        assert!(matches("/srv/git/app.git", "/srv/git/app"));
+        assert!(matches("file:///srv/git/app.git", "file:///srv/git/app"));
+        assert!(!matches("/srv/git/app", "/srv/git/other"));
+    }
+
+    #[test]
+    fn verify_reports_the_remote_url() {
+        assert_eq!(verify("octo/app", Some("git@github.com:octo/app")), Ok(()));
+        assert_eq!(
+            verify("octo/app", Some("git@github.com:octo/other")),
+            Err(Some("git@github.com:octo/other".to_string()))
+        );
+        assert_eq!(verify("octo/app", None), Err(None));
+    }
+}
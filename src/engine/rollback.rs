@@ -18,13 +18,13 @@
 //! has been modified out-of-band since the journal was written, the rollback
 //! for that ref will fail. Other refs will still be rolled back.
 //!
-//! # Known Limitations
+//! # Metadata Snapshots
 //!
-//! - Metadata updates cannot be fully rolled back (old content not stored)
-//! - Metadata deletes cannot be restored (deleted content not stored)
-//!
-//! These limitations are acceptable until the journal is extended to store
-//! content for full rollback support.
+//! Metadata updates and deletes journal the prior blob's content alongside
+//! its OID. Rollback points the ref back at the old blob, recreating the
+//! blob from that snapshot first if `git gc` pruned it. Journals written
+//! before snapshots existed can still roll back a metadata update or delete
+//! as long as the old blob is in the object database.
 
 use crate::core::metadata::store::{MetadataStore, StoreError};
 use crate::core::ops::journal::{Journal, StepKind};
@@ -168,10 +168,21 @@ pub fn rollback_journal(git: &Git, journal: &Journal) -> RollbackResult {
             StepKind::MetadataWrite {
                 branch,
                 old_ref_oid,
+                old_content,
                 new_ref_oid,
             } => {
                 let refname = format!("refs/branch-metadata/{}", branch);
-                match rollback_metadata_write(git, branch, old_ref_oid.as_deref(), new_ref_oid) {
+                let outcome = match old_ref_oid {
+                    Some(old) => restore_metadata(
+                        git,
+                        &refname,
+                        old,
+                        old_content.as_deref(),
+                        Some(new_ref_oid),
+                    ),
+                    None => rollback_metadata_create(git, branch, new_ref_oid),
+                };
+                match outcome {
                     Ok(()) => result.record_success(refname),
                     Err(e) => result.record_failure(refname, e),
                 }
@@ -179,20 +190,13 @@ pub fn rollback_journal(git: &Git, journal: &Journal) -> RollbackResult {
             StepKind::MetadataDelete {
                 branch,
                 old_ref_oid,
+                old_content,
             } => {
-                // Metadata was deleted - we can't restore without content
-                // Record as a known limitation
                 let refname = format!("refs/branch-metadata/{}", branch);
-                result.record_failure(
-                    refname.clone(),
-                    RollbackError::CannotRestore {
-                        refname,
-                        reason: format!(
-                            "deleted metadata content not stored in journal (old_oid: {})",
-                            old_ref_oid
-                        ),
-                    },
-                );
+                match restore_metadata(git, &refname, old_ref_oid, old_content.as_deref(), None) {
+                    Ok(()) => result.record_success(refname),
+                    Err(e) => result.record_failure(refname, e),
+                }
             }
             StepKind::Checkpoint { .. }
             | StepKind::GitProcess { .. }
@@ -245,27 +249,55 @@ fn rollback_ref_update(
     }
 }
 
-/// Roll back a metadata write.
-fn rollback_metadata_write(
+/// Point a metadata ref back at its old blob.
+///
+/// `current` is the value the operation left the ref at, or `None` if the
+/// operation deleted it. The old blob is recreated from `old_content` if it
+/// is no longer in the object database.
+fn restore_metadata(
+    git: &Git,
+    refname: &str,
+    old_ref_oid: &str,
+    old_content: Option<&str>,
+    current: Option<&str>,
+) -> Result<(), RollbackError> {
+    let store = MetadataStore::new(git);
+    let old = Oid::new(old_ref_oid).map_err(|e| RollbackError::Internal(e.to_string()))?;
+    let expected = current
+        .map(Oid::new)
+        .transpose()
+        .map_err(|e| RollbackError::Internal(e.to_string()))?;
+
+    store
+        .ensure_blob(&old, old_content)
+        .map_err(|e| RollbackError::CannotRestore {
+            refname: refname.to_string(),
+            reason: e.to_string(),
+        })?;
+
+    git.update_ref_cas(refname, &old, expected.as_ref(), "lattice rollback")
+        .map_err(|e| match e {
+            GitError::CasFailed {
+                expected, actual, ..
+            } => RollbackError::CasFailed {
+                refname: refname.to_string(),
+                expected,
+                actual,
+            },
+            other => RollbackError::GitError(other.to_string()),
+        })
+}
+
+/// Roll back the creation of a metadata ref by deleting it.
+fn rollback_metadata_create(
     git: &Git,
     branch: &str,
-    old_ref_oid: Option<&str>,
     new_ref_oid: &str,
 ) -> Result<(), RollbackError> {
     let store = MetadataStore::new(git);
     let branch_name =
         BranchName::new(branch).map_err(|e| RollbackError::Internal(e.to_string()))?;
 
-    if old_ref_oid.is_some() {
-        // Metadata existed before - we can't restore content
-        // This is a known limitation until we store content in journal
-        return Err(RollbackError::CannotRestore {
-            refname: format!("refs/branch-metadata/{}", branch),
-            reason: "previous metadata content not stored in journal".to_string(),
-        });
-    }
-
-    // Metadata was created - delete it
     let expected = Oid::new(new_ref_oid).map_err(|e| RollbackError::Internal(e.to_string()))?;
 
     store
@@ -37,10 +37,16 @@ use super::command::{Command, CommandOutput};
 use super::exec::{ExecuteResult, Executor};
 use super::gate::{gate, gate_with_scope, GateResult, RepairBundle, RequirementSet};
 use super::plan::Plan;
-use super::scan::{scan, scan_with_remote};
+use super::scan::{scan_for, scan_with_remote, RepoSnapshot};
+use super::timing::{self, Phase};
 use super::Context;
+use crate::core::ops::journal::OpState;
+use crate::core::paths::LatticePaths;
 use crate::core::types::BranchName;
-use crate::git::Git;
+use crate::git::{Git, GitState};
+use crate::ui::i18n;
+use std::path::Path;
+use std::time::Instant;
 use thiserror::Error;
 
 #[cfg(any(test, feature = "fault_injection", feature = "test_hooks"))]
@@ -208,7 +214,7 @@ fn run_command_internal<C: Command>(
     if ctx.debug {
         eprintln!("[debug] Step 1: Scan");
     }
-    let snapshot = scan(git)?;
+    let snapshot = scan_for(git, ctx)?;
 
     // Step 2: Gate
     if ctx.debug {
@@ -246,7 +252,7 @@ fn run_command_internal<C: Command>(
     if ctx.debug {
         eprintln!("[debug] Step 3: Plan");
     }
-    let plan = command.plan(&ready)?;
+    let plan = timing::time(Phase::Plan, || command.plan(&ready))?;
 
     if ctx.debug {
         eprintln!("[debug] Plan has {} steps", plan.step_count());
@@ -327,7 +333,7 @@ fn run_command_internal<C: Command>(
 /// ```
 pub fn run_gated<T, F>(
     git: &Git,
-    _ctx: &Context,
+    ctx: &Context,
     requirements: &RequirementSet,
     f: F,
 ) -> Result<T, RunError>
@@ -335,7 +341,11 @@ where
     F: FnOnce(&super::gate::ReadyContext) -> Result<T, RunError>,
 {
     // Scan
-    let snapshot = scan(git)?;
+    let snapshot = scan_for(git, ctx)?;
+
+    if requirements.allows_paused_operation() && !ctx.quiet {
+        report_paused_operation(&snapshot);
+    }
 
     // Gate
     let ready = match gate(snapshot, requirements) {
@@ -349,6 +359,40 @@ where
     f(&ready)
 }
 
+/// Format a banner describing a paused Lattice or Git operation.
+///
+/// Returns `None` when nothing is in progress. A paused Lattice operation
+/// takes precedence, since its own conflict is usually the Git state.
+fn paused_operation_banner(op_state: Option<&OpState>, git_state: &GitState) -> Option<String> {
+    if let Some(op) = op_state {
+        return Some(i18n::t_args(
+            "paused-lattice-operation",
+            &[("command", &op.command), ("op_id", &op.op_id)],
+        ));
+    }
+    if git_state.is_in_progress() {
+        return Some(i18n::t_args(
+            "paused-git-operation",
+            &[("git_state", git_state)],
+        ));
+    }
+    None
+}
+
+/// Print the paused-operation banner to stderr, if any.
+fn report_paused_operation(snapshot: &RepoSnapshot) {
+    let paths = LatticePaths::from_repo_info(&snapshot.info);
+    let op_state = if snapshot.has_lattice_op_in_progress() {
+        OpState::read(&paths).unwrap_or(None)
+    } else {
+        None
+    };
+    if let Some(banner) = paused_operation_banner(op_state.as_ref(), &snapshot.git_state) {
+        eprintln!("{}", banner);
+        eprintln!();
+    }
+}
+
 /// Run a read-only command through the engine lifecycle.
 ///
 /// This is the entry point for commands that implement `ReadOnlyCommand`.
@@ -409,7 +453,12 @@ pub fn run_readonly_command<C: super::command::ReadOnlyCommand>(
     if ctx.debug {
         eprintln!("[debug] Step 1: Scan");
     }
-    let snapshot = scan(git)?;
+    let snapshot = scan_for(git, ctx)?;
+
+    // Read-only commands may run mid-operation; make the paused state obvious
+    if C::REQUIREMENTS.allows_paused_operation() && !ctx.quiet {
+        report_paused_operation(&snapshot);
+    }
 
     // Step 2: Gate
     if ctx.debug {
@@ -576,7 +625,12 @@ async fn run_async_command_internal<C: super::command::AsyncCommand>(
     if ctx.debug {
         eprintln!("[debug] Step 1: Async Scan (with remote capabilities)");
     }
-    let snapshot = scan_with_remote(git).await?;
+    // Offline, nothing remote is known beyond what is cached
+    let snapshot = if ctx.offline {
+        scan_for(git, ctx)?
+    } else {
+        scan_with_remote(git, ctx).await?
+    };
 
     // Step 2: Gate
     if ctx.debug {
@@ -614,7 +668,10 @@ async fn run_async_command_internal<C: super::command::AsyncCommand>(
     if ctx.debug {
         eprintln!("[debug] Step 3: Async Plan");
     }
-    let plan = command.plan(&ready).await?;
+    let planning = Instant::now();
+    let plan = command.plan(&ready).await;
+    timing::record(Phase::Plan, planning.elapsed());
+    let plan = plan?;
 
     if ctx.debug {
         eprintln!("[debug] Plan has {} steps", plan.step_count());
@@ -692,19 +749,35 @@ fn check_occupancy_for_plan(git: &Git, plan: &Plan) -> Result<(), RunError> {
     }
 
     let mut conflicts = Vec::new();
+    let mut dirty = Vec::new();
 
     for branch in touched {
         if let Ok(Some(worktree_path)) = git.branch_checked_out_elsewhere(&branch) {
+            // Branches rewritten inside their own worktree only need it clean
+            if plan.worktree_for(&branch).map(Path::new) == Some(worktree_path.as_path()) {
+                if !super::exec::worktree_is_clean(&worktree_path).unwrap_or(false) {
+                    dirty.push((branch, worktree_path));
+                }
+                continue;
+            }
             conflicts.push((branch, worktree_path));
         }
     }
 
+    let mut blocking_issues = Vec::new();
     if !conflicts.is_empty() {
-        let issue = super::health::issues::branches_checked_out_elsewhere(conflicts);
+        blocking_issues.push(super::health::issues::branches_checked_out_elsewhere(
+            conflicts,
+        ));
+    }
+    if !dirty.is_empty() {
+        blocking_issues.push(super::health::issues::occupied_worktrees_dirty(dirty));
+    }
+    if !blocking_issues.is_empty() {
         let bundle = RepairBundle {
             command: plan.command.clone(),
             missing_capabilities: vec![],
-            blocking_issues: vec![issue],
+            blocking_issues,
         };
         return Err(RunError::NeedsRepair(bundle));
     }
@@ -720,13 +793,18 @@ fn check_occupancy_for_plan(git: &Git, plan: &Plan) -> Result<(), RunError> {
 /// # Arguments
 ///
 /// * `git` - Git interface
+/// * `ctx` - Execution context
 /// * `requirements` - Requirements to check
 ///
 /// # Returns
 ///
 /// `Ok(())` if requirements are satisfied, or the `RepairBundle` if not.
-pub fn check_requirements(git: &Git, requirements: &RequirementSet) -> Result<(), RepairBundle> {
-    let snapshot = scan(git).map_err(|e| RepairBundle {
+pub fn check_requirements(
+    git: &Git,
+    ctx: &Context,
+    requirements: &RequirementSet,
+) -> Result<(), RepairBundle> {
+    let snapshot = scan_for(git, ctx).map_err(|e| RepairBundle {
         command: requirements.name.to_string(),
         missing_capabilities: vec![],
         blocking_issues: vec![super::health::Issue::new(
@@ -792,4 +870,40 @@ mod tests {
             assert!(msg.contains("gating failed"));
         }
     }
+
+    mod paused_banner {
+        use super::*;
+        use crate::core::ops::journal::Journal;
+        use std::path::PathBuf;
+
+        fn paused_op(command: &str) -> OpState {
+            let journal = Journal::new(command);
+            let paths = LatticePaths::new(PathBuf::from("/repo/.git"), PathBuf::from("/repo/.git"));
+            OpState::from_journal(&journal, &paths, None, "sha256:test".to_string(), vec![])
+        }
+
+        #[test]
+        fn none_when_clean() {
+            assert!(paused_operation_banner(None, &GitState::Clean).is_none());
+        }
+
+        #[test]
+        fn names_paused_lattice_operation() {
+            let op = paused_op("restack");
+            let rebase = GitState::Rebase {
+                current: Some(1),
+                total: Some(2),
+            };
+            let banner = paused_operation_banner(Some(&op), &rebase).unwrap();
+            assert!(banner.contains("'restack' operation is paused"));
+            assert!(banner.contains(op.op_id.as_str()));
+            assert!(banner.contains("lattice continue"));
+        }
+
+        #[test]
+        fn mentions_external_git_operation() {
+            let banner = paused_operation_banner(None, &GitState::Merge).unwrap();
+            assert!(banner.contains("git merge"));
+        }
+    }
 }
@@ -25,18 +25,19 @@
 //! - Scan is deterministic given the same repository state
 //! - Capabilities are binary: present or absent (no partial)
 
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
 use super::capabilities::Capability;
 use super::health::{issues, Evidence, Issue, RepoHealthReport, Severity};
+use super::Context;
 use crate::core::config::schema::RepoConfig;
 use crate::core::config::{Config, ConfigError};
 use crate::core::graph::StackGraph;
-use crate::core::metadata::schema::BranchMetadataV1;
-use crate::core::metadata::store::{MetadataStore, StoreError};
+use crate::core::metadata::schema::{BranchMetadataV2, SCHEMA_VERSION};
+use crate::core::metadata::store::{MetadataSignature, MetadataStore, StoreError};
 use crate::core::ops::journal::OpState;
 use crate::core::paths::LatticePaths;
 use crate::core::types::{BranchName, Fingerprint, Oid, RefName};
@@ -68,7 +69,7 @@ pub struct ScannedMetadata {
     /// The metadata ref OID (blob pointer, for CAS).
     pub ref_oid: Oid,
     /// The parsed metadata.
-    pub metadata: BranchMetadataV1,
+    pub metadata: BranchMetadataV2,
 }
 
 /// Evidence of remote pull requests collected during scan.
@@ -104,6 +105,14 @@ pub struct RepoSnapshot {
     /// Working tree status (staged/unstaged/conflicts).
     pub worktree_status: WorktreeStatus,
 
+    /// Branches checked out in other worktrees of this repository, with
+    /// the path of each worktree.
+    ///
+    /// Planners use it to rebase such a branch inside its worktree instead
+    /// of here (see [`PlanStep::RunGitInWorktree`](super::plan::PlanStep)),
+    /// or to refuse. The executor revalidates it under the lock.
+    pub checked_out_elsewhere: HashMap<BranchName, PathBuf>,
+
     /// Current branch, if on a branch (None if detached HEAD).
     pub current_branch: Option<BranchName>,
 
@@ -211,6 +220,20 @@ impl RepoSnapshot {
 /// }
 /// ```
 pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
+    super::timing::time(super::timing::Phase::Scan, || scan_untimed(git, false))
+}
+
+/// Scan a repository for a command run with `ctx`.
+///
+/// Like [`scan`], but `--read-only` in the context withholds
+/// [`Capability::WritesAllowed`] on top of the config setting.
+pub fn scan_for(git: &Git, ctx: &Context) -> Result<RepoSnapshot, ScanError> {
+    super::timing::time(super::timing::Phase::Scan, || {
+        scan_untimed(git, ctx.read_only)
+    })
+}
+
+fn scan_untimed(git: &Git, read_only: bool) -> Result<RepoSnapshot, ScanError> {
     let mut health = RepoHealthReport::new();
 
     // Get repository info
@@ -250,15 +273,42 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
     let worktree_status = git.worktree_status(false).unwrap_or_default();
     health.add_capability(Capability::WorkingCopyStateKnown);
 
+    // Branches checked out in other worktrees (repo-scoped, like the journal)
+    let checked_out_elsewhere = git.branches_checked_out_elsewhere().unwrap_or_default();
+
     // Get current branch
     let current_branch = git.current_branch().unwrap_or(None);
 
-    // Load repo config (using work_dir if available, otherwise None for bare repos)
-    let repo_config = info
-        .work_dir
-        .as_ref()
-        .and_then(|wd| Config::load(Some(wd)).ok())
-        .and_then(|r| r.config.repo);
+    // Load config (repo config only with a work_dir, global alone for bare repos)
+    let config = Config::load(info.work_dir.as_deref())
+        .ok()
+        .map(|r| r.config);
+    let repo_config = config.as_ref().and_then(|c| c.repo.clone());
+
+    // Read-only mode withholds writes from every command
+    if super::read_only::is_active(read_only, config.as_ref()) {
+        health.add_issue(issues::read_only_mode());
+    } else {
+        health.add_capability(Capability::WritesAllowed);
+    }
+
+    // A configured identity must match the remote before anything changes
+    let identity = repo_config.as_ref().and_then(|c| c.identity.as_deref());
+    match identity {
+        Some(expected) => {
+            let remote = config.as_ref().map_or("origin", Config::remote);
+            let url = git.remote_url(remote).unwrap_or(None);
+            match super::repo_identity::verify(expected, url.as_deref()) {
+                Ok(()) => health.add_capability(Capability::RepoIdentityVerified),
+                Err(actual) => health.add_issue(issues::repo_identity_mismatch(
+                    expected,
+                    remote,
+                    actual.as_deref(),
+                )),
+            }
+        }
+        None => health.add_capability(Capability::RepoIdentityVerified),
+    }
 
     // Get trunk from config
     let trunk = repo_config
@@ -287,6 +337,7 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
 
     let mut metadata = HashMap::new();
     let mut all_metadata_readable = true;
+    let mut legacy = Vec::new();
 
     for (branch, ref_oid) in metadata_refs {
         match store.read(&branch) {
@@ -295,6 +346,9 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
                 if !branches.contains_key(&branch) {
                     health.add_issue(issues::missing_branch(branch.as_str()));
                 }
+                if entry.stored_version < SCHEMA_VERSION {
+                    legacy.push((branch.clone(), entry.stored_version));
+                }
 
                 metadata.insert(
                     branch,
@@ -348,10 +402,40 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
         }
     }
 
+    // Verify signatures in repos that sign metadata
+    let verify_signatures = repo_config
+        .as_ref()
+        .and_then(|c| c.sign_metadata)
+        .unwrap_or(false);
+    if verify_signatures {
+        for (branch, scanned) in &metadata {
+            let reason = match store.verify_signature(&scanned.ref_oid) {
+                Ok(MetadataSignature::Valid { .. }) => continue,
+                Ok(MetadataSignature::Unsigned) => {
+                    health.add_issue(issues::metadata_unsigned(branch.as_str()));
+                    continue;
+                }
+                Ok(MetadataSignature::Invalid { reason }) => reason,
+                Err(e) => e.to_string(),
+            };
+            health.add_issue(issues::metadata_signature_invalid(branch.as_str(), &reason));
+            all_metadata_readable = false;
+        }
+    }
+
     if all_metadata_readable {
         health.add_capability(Capability::MetadataReadable);
     }
 
+    // Check that per-branch remote overrides name existing remotes
+    for (branch, scanned) in &metadata {
+        if let Some(remote) = &scanned.metadata.remote {
+            if !matches!(git.remote_url(remote), Ok(Some(_))) {
+                health.add_issue(issues::branch_remote_missing(branch.as_str(), remote));
+            }
+        }
+    }
+
     // Build stack graph from metadata
     let mut graph = StackGraph::new();
     for (branch, scanned) in &metadata {
@@ -384,19 +468,28 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
     // Default to FrozenPolicySatisfied (will be refined by gating for specific operations)
     health.add_capability(Capability::FrozenPolicySatisfied);
 
-    // Check for RemoteResolved capability (GitHub remote configured)
-    if let Ok(Some(remote_url)) = git.remote_url("origin") {
-        if crate::forge::github::parse_github_url(&remote_url).is_some() {
+    // Check for RemoteResolved capability (remote on a supported forge)
+    let origin = git.remote_url("origin").ok().flatten();
+    let mut provider = None;
+    if let Some(remote_url) = &origin {
+        provider = crate::cli::commands::forge_provider_for(remote_url);
+        if provider.is_some() {
             health.add_capability(Capability::RemoteResolved);
         } else {
-            health.add_issue(issues::remote_not_github(&remote_url));
+            health.add_issue(issues::remote_not_github(remote_url));
         }
     } else {
         health.add_issue(issues::no_remote_configured());
     }
 
-    // Check for AuthAvailable capability (GitHub token present)
-    if crate::cli::commands::has_github_token() {
+    // Check for AuthAvailable capability (token present for the remote's forge and host)
+    let has_token = match (provider, &origin) {
+        (Some(provider), Some(remote_url)) => {
+            crate::cli::commands::has_forge_token(provider, remote_url)
+        }
+        _ => crate::cli::commands::has_github_token(),
+    };
+    if has_token {
         health.add_capability(Capability::AuthAvailable);
     }
     // Note: Missing auth is not an issue - it's just a missing capability.
@@ -410,15 +503,25 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
     // Detect and record divergence per ARCHITECTURE.md Section 7.2
     // "On each command invocation, the engine compares the current fingerprint
     // with the last recorded Committed event fingerprint. If they differ, the
-    // engine records a DivergenceObserved event."
-    if let Some(divergence) = detect_and_record_divergence(git, &fingerprint)? {
+    // engine records a DivergenceObserved event." Read-only mode still
+    // reports it but leaves the ledger alone.
+    let record = health.capabilities().has(&Capability::WritesAllowed);
+    if let Some(divergence) = detect_and_record_divergence(git, &fingerprint, record)? {
         health.set_divergence(divergence);
     }
 
+    // Metadata stored by an older schema version was upgraded in memory
+    // when it was read; storing it back is the doctor's `upgrade` fix, so
+    // scan never writes.
+    for (branch, version) in &legacy {
+        health.add_issue(issues::metadata_schema_outdated(branch.as_str(), *version));
+    }
+
     let mut snapshot = RepoSnapshot {
         info,
         git_state,
         worktree_status,
+        checked_out_elsewhere,
         current_branch,
         branches,
         metadata,
@@ -434,6 +537,18 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
     // This adds issues with parent candidate evidence for fix generation.
     detect_local_untracked_branches(git, &mut snapshot);
 
+    // Submitted branches whose remote branch was pruned by a fetch
+    detect_remote_branches_gone(git, &mut snapshot);
+
+    // Branches whose remote branch moved on without them
+    detect_remote_tracking_drift(git, &mut snapshot);
+
+    // PRs whose stack section submit or sync refused to touch
+    detect_corrupt_pr_bodies(&mut snapshot);
+
+    // Branch locks nobody released before they expired
+    detect_stale_branch_locks(git, &mut snapshot);
+
     Ok(snapshot)
 }
 
@@ -444,14 +559,16 @@ pub fn scan(git: &Git) -> Result<RepoSnapshot, ScanError> {
 /// fingerprint. If they differ, the engine records a DivergenceObserved event."
 ///
 /// This is a best-effort operation - ledger write failures are logged but do
-/// not fail the scan. Divergence is informational, not blocking.
+/// not fail the scan. Divergence is informational, not blocking. With
+/// `record` false (read-only mode) the divergence is returned but not written.
 fn detect_and_record_divergence(
     git: &Git,
     current_fingerprint: &Fingerprint,
+    record: bool,
 ) -> Result<Option<DivergenceInfo>, ScanError> {
     let divergence = detect_divergence(git, current_fingerprint)?;
 
-    if let Some(ref info) = divergence {
+    if let Some(info) = divergence.as_ref().filter(|_| record) {
         // Record DivergenceObserved event
         use super::ledger::{Event, EventLedger};
 
@@ -609,6 +726,7 @@ pub fn scan_from_path(path: &Path) -> Result<RepoSnapshot, ScanError> {
 /// # Arguments
 ///
 /// * `git` - The Git interface for the repository
+/// * `ctx` - Execution context (see [`scan_for`])
 ///
 /// # Returns
 ///
@@ -625,9 +743,9 @@ pub fn scan_from_path(path: &Path) -> Result<RepoSnapshot, ScanError> {
 ///     println!("Found {} open PRs", evidence.prs.len());
 /// }
 /// ```
-pub async fn scan_with_remote(git: &Git) -> Result<RepoSnapshot, ScanError> {
+pub async fn scan_with_remote(git: &Git, ctx: &Context) -> Result<RepoSnapshot, ScanError> {
     // Perform the basic scan first
-    let mut snapshot = scan(git)?;
+    let mut snapshot = scan_for(git, ctx)?;
 
     // Check for RepoAuthorized capability (GitHub App installed for repo)
     // This is done here (async context) rather than in scan() to avoid nested runtime panics.
@@ -642,17 +760,21 @@ pub async fn scan_with_remote(git: &Git) -> Result<RepoSnapshot, ScanError> {
             .has(&Capability::RemoteResolved)
     {
         if let Ok(Some(remote_url)) = git.remote_url("origin") {
-            if let Some((owner, repo)) = crate::forge::github::parse_github_url(&remote_url) {
+            if let Some(remote) = github_remote(&remote_url) {
                 let paths = LatticePaths::from_repo_info(&snapshot.info);
-                check_repo_authorization_async(&mut snapshot.health, &paths, &owner, &repo).await;
+                check_repo_authorization_async(&mut snapshot.health, &paths, &remote).await;
+            } else {
+                // Other forges have no app installation; the token's own
+                // access decides, and the API reports it per request
+                snapshot.health.add_capability(Capability::RepoAuthorized);
             }
         }
     }
 
     // Try to query remote PRs if capabilities allow
     if let Ok(Some(remote_url)) = git.remote_url("origin") {
-        if let Some((owner, repo)) = crate::forge::github::parse_github_url(&remote_url) {
-            snapshot.remote_prs = query_remote_prs(&snapshot.health, &owner, &repo).await;
+        if let Some(remote) = github_remote(&remote_url) {
+            snapshot.remote_prs = query_remote_prs(&snapshot.health, &remote).await;
         }
     }
 
@@ -660,11 +782,22 @@ pub async fn scan_with_remote(git: &Git) -> Result<RepoSnapshot, ScanError> {
     // Clone the evidence to avoid borrow conflict
     if let Some(evidence) = snapshot.remote_prs.clone() {
         generate_bootstrap_issues(&mut snapshot, &evidence);
+        detect_deleted_pr_bases(git, &mut snapshot, &evidence);
     }
 
     Ok(snapshot)
 }
 
+/// The origin remote as a GitHub repository, on github.com or an
+/// Enterprise Server host, if that is the forge it resolved to.
+fn github_remote(remote_url: &str) -> Option<crate::forge::github::GitHubRemote> {
+    if crate::cli::commands::forge_provider_for(remote_url)? != crate::forge::ForgeProvider::GitHub
+    {
+        return None;
+    }
+    crate::forge::github::parse_github_remote(remote_url)
+}
+
 /// Check repository authorization asynchronously.
 ///
 /// Per SPEC.md Section 8E.0.1, this queries the GitHub API to verify that
@@ -675,14 +808,17 @@ pub async fn scan_with_remote(git: &Git) -> Result<RepoSnapshot, ScanError> {
 async fn check_repo_authorization_async(
     health: &mut RepoHealthReport,
     paths: &LatticePaths,
-    owner: &str,
-    repo: &str,
+    remote: &crate::forge::github::GitHubRemote,
 ) {
     use crate::auth::installations::check_repo_authorization;
     use crate::auth::GitHubAuthManager;
     use crate::secrets;
 
-    let host = "github.com"; // v1: only github.com supported
+    let (host, owner, repo) = (
+        remote.host.as_str(),
+        remote.owner.as_str(),
+        remote.repo.as_str(),
+    );
 
     // Load authorization cache
     let mut cache = crate::auth::cache::AuthCache::load(paths);
@@ -742,8 +878,7 @@ async fn check_repo_authorization_async(
 /// - Forge query fails (logged as warning)
 async fn query_remote_prs(
     health: &RepoHealthReport,
-    owner: &str,
-    repo: &str,
+    remote: &crate::forge::github::GitHubRemote,
 ) -> Option<RemotePrEvidence> {
     // Check required capabilities
     let caps = health.capabilities();
@@ -757,7 +892,7 @@ async fn query_remote_prs(
     }
 
     // Create forge and query
-    match create_forge_and_query(owner, repo).await {
+    match create_forge_and_query(remote).await {
         Ok(result) => Some(result),
         Err(e) => {
             // Log warning but continue - API failures shouldn't block scanning
@@ -769,8 +904,7 @@ async fn query_remote_prs(
 
 /// Create a forge and query for open PRs.
 async fn create_forge_and_query(
-    owner: &str,
-    repo: &str,
+    remote: &crate::forge::github::GitHubRemote,
 ) -> Result<RemotePrEvidence, crate::forge::ForgeError> {
     use std::sync::Arc;
 
@@ -781,11 +915,16 @@ async fn create_forge_and_query(
 
     let store = secrets::create_store(secrets::DEFAULT_PROVIDER)
         .map_err(|e| crate::forge::ForgeError::AuthFailed(e.to_string()))?;
-    let auth_manager = GitHubAuthManager::new("github.com", store);
+    let auth_manager = GitHubAuthManager::new(&remote.host, store);
 
     // Create forge with TokenProvider for automatic refresh
     let provider: Arc<dyn TokenProvider> = Arc::new(auth_manager);
-    let forge = GitHubForge::new_with_provider(provider, owner, repo);
+    let forge = GitHubForge::new_with_provider_and_api_base(
+        provider,
+        &remote.owner,
+        &remote.repo,
+        crate::auth::installations::api_base_url(&remote.host),
+    );
     let opts = ListPullsOpts::default(); // 200 limit
 
     let result = forge.list_open_prs(opts).await?;
@@ -908,6 +1047,84 @@ fn generate_bootstrap_issues(snapshot: &mut RepoSnapshot, evidence: &RemotePrEvi
     }
 }
 
+/// Detect open PRs whose base branch was deleted on `origin`.
+///
+/// A base counts as deleted when `origin` has been fetched but has no
+/// remote-tracking ref for it (fetches prune deleted branches). Remote
+/// branch names are read here so the matching itself, in
+/// [`deleted_pr_base_issues`], needs no repository.
+fn detect_deleted_pr_bases(git: &Git, snapshot: &mut RepoSnapshot, evidence: &RemotePrEvidence) {
+    let prefix = "refs/remotes/origin/";
+    let Ok(refs) = git.list_refs_by_prefix(prefix) else {
+        return;
+    };
+    if refs.is_empty() {
+        return;
+    }
+    let remote_branches: HashSet<String> = refs
+        .iter()
+        .filter_map(|r| r.name.as_str().strip_prefix(prefix).map(String::from))
+        .collect();
+
+    for issue in deleted_pr_base_issues(snapshot, &evidence.prs, "origin", &remote_branches) {
+        snapshot.health.add_issue(issue);
+    }
+}
+
+/// Issues for open PRs targeting a branch missing from `remote_branches`.
+///
+/// Each names the nearest tracked ancestor of the deleted base that is
+/// still on the remote, falling back to trunk, as the branch to retarget
+/// to. Fork PRs and PRs targeting trunk are skipped.
+fn deleted_pr_base_issues(
+    snapshot: &RepoSnapshot,
+    prs: &[crate::forge::PullRequestSummary],
+    remote: &str,
+    remote_branches: &HashSet<String>,
+) -> Vec<Issue> {
+    let Some(trunk) = snapshot.trunk.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut found = Vec::new();
+    for pr in prs {
+        if pr.is_fork() || pr.base_ref == trunk.as_str() || remote_branches.contains(&pr.base_ref) {
+            continue;
+        }
+
+        // Walk up from the deleted base; the depth bound guards against cycles
+        let mut surviving = trunk.to_string();
+        let mut current = BranchName::new(&pr.base_ref).ok();
+        for _ in 0..snapshot.metadata.len() {
+            let Some(parent) = current
+                .as_ref()
+                .and_then(|b| snapshot.metadata.get(b))
+                .map(|scanned| scanned.metadata.parent.clone())
+            else {
+                break;
+            };
+            if parent.is_trunk() {
+                break;
+            }
+            if remote_branches.contains(parent.name()) {
+                surviving = parent.name().to_string();
+                break;
+            }
+            current = BranchName::new(parent.name()).ok();
+        }
+
+        found.push(issues::pr_base_deleted(
+            pr.number,
+            &pr.head_ref,
+            &pr.base_ref,
+            remote,
+            &surviving,
+            &pr.url,
+        ));
+    }
+    found
+}
+
 /// Compute parent candidates for an untracked branch.
 ///
 /// Returns candidates ranked by merge-base distance (closest first).
@@ -1050,6 +1267,248 @@ fn detect_local_untracked_branches(git: &Git, snapshot: &mut RepoSnapshot) {
     }
 }
 
+/// Detect submitted branches whose remote-tracking ref no longer exists.
+///
+/// A branch with a linked PR was pushed, so a missing
+/// `refs/remotes/<remote>/<branch>` means a fetch pruned it after the branch
+/// was deleted remotely. The issue says whether the branch's changes reached
+/// the remote trunk, by merge, squash, or rebase
+/// ([`crate::core::merge_detect`]), or not (deleted outright). Remotes with no
+/// remote-tracking refs at all were never fetched and are skipped.
+fn detect_remote_branches_gone(git: &Git, snapshot: &mut RepoSnapshot) {
+    use crate::core::metadata::schema::PrState;
+
+    let default_remote = snapshot
+        .repo_config
+        .as_ref()
+        .and_then(|c| c.remote.clone())
+        .unwrap_or_else(|| "origin".to_string());
+    let mut fetched: HashMap<String, bool> = HashMap::new();
+    let mut found = Vec::new();
+
+    for (branch, scanned) in &snapshot.metadata {
+        if !matches!(scanned.metadata.pr, PrState::Linked { .. }) {
+            continue;
+        }
+        let Some(tip) = snapshot.branches.get(branch) else {
+            continue;
+        };
+        let remote = scanned
+            .metadata
+            .remote
+            .as_deref()
+            .unwrap_or(&default_remote);
+        let was_fetched = *fetched.entry(remote.to_string()).or_insert_with(|| {
+            git.list_refs_by_prefix(&format!("refs/remotes/{}/", remote))
+                .map(|refs| !refs.is_empty())
+                .unwrap_or(false)
+        });
+        if !was_fetched || git.ref_exists(&format!("refs/remotes/{}/{}", remote, branch)) {
+            continue;
+        }
+
+        let trunk_tip = snapshot.trunk.as_ref().and_then(|trunk| {
+            git.try_resolve_ref(&format!("refs/remotes/{}/{}", remote, trunk))
+                .ok()
+                .flatten()
+                .or_else(|| snapshot.branches.get(trunk).cloned())
+        });
+        let merged = trunk_tip
+            .map(|trunk_tip| {
+                git.is_ancestor(tip, &trunk_tip).unwrap_or(false)
+                    || Oid::new(&scanned.metadata.base.oid).is_ok_and(|base| {
+                        matches!(
+                            crate::core::merge_detect::detect(git, &base, tip, &trunk_tip),
+                            Ok(Some(_))
+                        )
+                    })
+            })
+            .unwrap_or(false);
+
+        found.push(if merged {
+            issues::remote_branch_merged(branch.as_str(), remote)
+        } else {
+            issues::remote_branch_deleted(branch.as_str(), remote)
+        });
+    }
+
+    for issue in found {
+        snapshot.health.add_issue(issue);
+    }
+}
+
+/// Detect tracked branches behind or diverged from their remote-tracking ref.
+///
+/// Pushing such a branch is rejected as non-fast-forward. A remote tip that
+/// is the one submit last pushed, or that the local tip already contains, is
+/// the normal state after a local restack or new commits and is not
+/// reported. For submitted branches, remote commits whose changes the local
+/// branch already has (matched by patch ID, see
+/// [`crate::core::remote_divergence`]) don't count as divergence. Branches
+/// doctor already marked as needing `lattice get` are skipped.
+fn detect_remote_tracking_drift(git: &Git, snapshot: &mut RepoSnapshot) {
+    use crate::core::metadata::schema::{FreezeState, FREEZE_REASON_REMOTE_DIVERGED};
+    use crate::core::remote_divergence::changed_elsewhere;
+
+    let default_remote = snapshot
+        .repo_config
+        .as_ref()
+        .and_then(|c| c.remote.clone())
+        .unwrap_or_else(|| "origin".to_string());
+    let mut found = Vec::new();
+
+    for (branch, scanned) in &snapshot.metadata {
+        if let FreezeState::Frozen {
+            reason: Some(reason),
+            ..
+        } = &scanned.metadata.freeze
+        {
+            if reason == FREEZE_REASON_REMOTE_DIVERGED {
+                continue;
+            }
+        }
+        let Some(local) = snapshot.branches.get(branch) else {
+            continue;
+        };
+        let remote = scanned
+            .metadata
+            .remote
+            .as_deref()
+            .unwrap_or(&default_remote);
+        let Ok(Some(remote_tip)) =
+            git.try_resolve_ref(&format!("refs/remotes/{}/{}", remote, branch))
+        else {
+            continue;
+        };
+        let last_pushed = scanned
+            .metadata
+            .submitted
+            .as_ref()
+            .and_then(|s| Oid::new(&s.head_oid).ok());
+        if &remote_tip == local
+            || last_pushed.as_ref() == Some(&remote_tip)
+            || git.is_ancestor(&remote_tip, local).unwrap_or(true)
+        {
+            continue;
+        }
+
+        if git.is_ancestor(local, &remote_tip).unwrap_or(false) {
+            let behind = git
+                .commits_between(local, &remote_tip)
+                .map(|c| c.len())
+                .unwrap_or(0);
+            found.push(issues::remote_tracking_behind(
+                branch.as_str(),
+                remote,
+                behind,
+                local.as_str(),
+                remote_tip.as_str(),
+            ));
+            continue;
+        }
+
+        let counts = if last_pushed.is_some() {
+            let trunk_tip = snapshot.trunk.as_ref().and_then(|trunk| {
+                git.try_resolve_ref(&format!("refs/remotes/{}/{}", remote, trunk))
+                    .ok()
+                    .flatten()
+                    .or_else(|| snapshot.branches.get(trunk).cloned())
+            });
+            match (Oid::new(&scanned.metadata.base.oid), trunk_tip) {
+                (Ok(base), Some(trunk_tip)) => changed_elsewhere(
+                    git,
+                    &base,
+                    local,
+                    &remote_tip,
+                    &trunk_tip,
+                    last_pushed.as_ref(),
+                )
+                .ok()
+                .flatten()
+                .map(|d| (d.local_only, d.remote_only)),
+                _ => None,
+            }
+        } else {
+            git.merge_base(local, &remote_tip)
+                .ok()
+                .flatten()
+                .and_then(|fork| {
+                    let local_only = git.commits_between(&fork, local).ok()?.len();
+                    let remote_only = git.commits_between(&fork, &remote_tip).ok()?.len();
+                    Some((local_only, remote_only))
+                })
+        };
+        if let Some((local_only, remote_only)) = counts {
+            found.push(issues::remote_tracking_diverged(
+                branch.as_str(),
+                remote,
+                local_only,
+                remote_only,
+                local.as_str(),
+                remote_tip.as_str(),
+            ));
+        }
+    }
+
+    for issue in found {
+        snapshot.health.add_issue(issue);
+    }
+}
+
+/// Detect linked PRs whose stack section markers were found damaged.
+///
+/// Submit and sync record such PRs in the PR body state file instead of
+/// rewriting their descriptions; the record clears on the next successful
+/// update. Nothing here touches the network.
+fn detect_corrupt_pr_bodies(snapshot: &mut RepoSnapshot) {
+    use crate::core::metadata::schema::PrState;
+    use crate::forge::pr_body_state::PrBodyState;
+
+    let state = PrBodyState::load(&LatticePaths::from_repo_info(&snapshot.info));
+    let mut found = Vec::new();
+    for (branch, scanned) in &snapshot.metadata {
+        let PrState::Linked { number, url, .. } = &scanned.metadata.pr else {
+            continue;
+        };
+        if let Some(problem) = state.corrupt(branch.as_str(), *number) {
+            found.push(issues::pr_body_markers_corrupt(
+                branch.as_str(),
+                *number,
+                url,
+                problem,
+            ));
+        }
+    }
+
+    for issue in found {
+        snapshot.health.add_issue(issue);
+    }
+}
+
+/// Detect branch locks that expired without being released.
+///
+/// Expired locks hold nothing ([`crate::core::branch_lock`]); this only
+/// surfaces them so doctor can delete the refs.
+fn detect_stale_branch_locks(git: &Git, snapshot: &mut RepoSnapshot) {
+    use crate::core::branch_lock::BranchLockStore;
+    use crate::core::types::UtcTimestamp;
+
+    let Ok(locks) = BranchLockStore::new(git).list() else {
+        return;
+    };
+    let now = UtcTimestamp::now();
+    for stored in locks {
+        if stored.lock.is_expired(&now) {
+            snapshot.health.add_issue(issues::stale_branch_lock(
+                stored.lock.branch.as_str(),
+                &stored.lock.owner,
+                &stored.lock.expires_at.to_string(),
+                stored.oid.as_str(),
+            ));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1131,6 +1590,7 @@ mod tests {
                 },
                 git_state: GitState::Clean,
                 worktree_status: WorktreeStatus::default(),
+                checked_out_elsewhere: Default::default(),
                 current_branch: Some(BranchName::new("main").unwrap()),
                 branches: HashMap::new(),
                 metadata: HashMap::new(),
@@ -1161,7 +1621,7 @@ mod tests {
                 branch.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch.clone(),
                         BranchName::new("main").unwrap(),
                         oid,
@@ -1183,7 +1643,7 @@ mod tests {
                 branch1.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch1.clone(),
                         BranchName::new("main").unwrap(),
                         oid.clone(),
@@ -1194,7 +1654,7 @@ mod tests {
                 branch2.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch2.clone(),
                         BranchName::new("main").unwrap(),
                         oid,
@@ -1246,6 +1706,7 @@ mod tests {
                 is_draft: false,
                 url: format!("https://github.com/owner/repo/pull/{}", number),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
+                author: None,
             }
         }
 
@@ -1259,6 +1720,7 @@ mod tests {
                 },
                 git_state: GitState::Clean,
                 worktree_status: WorktreeStatus::default(),
+                checked_out_elsewhere: Default::default(),
                 current_branch: Some(BranchName::new("main").unwrap()),
                 branches: HashMap::new(),
                 metadata: HashMap::new(),
@@ -1346,7 +1808,7 @@ mod tests {
             let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
             snapshot.branches.insert(branch.clone(), oid.clone());
 
-            let metadata = BranchMetadataV1::new(
+            let metadata = BranchMetadataV2::new(
                 branch.clone(),
                 BranchName::new("main").unwrap(),
                 oid.clone(),
@@ -1386,7 +1848,7 @@ mod tests {
             let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
             snapshot.branches.insert(branch.clone(), oid.clone());
 
-            let mut metadata = BranchMetadataV1::new(
+            let mut metadata = BranchMetadataV2::new(
                 branch.clone(),
                 BranchName::new("main").unwrap(),
                 oid.clone(),
@@ -1488,6 +1950,59 @@ mod tests {
             assert_eq!(issues.len(), 1);
             assert!(issues[0].message.contains("truncated"));
         }
+
+        #[test]
+        fn deleted_pr_base_retargets_to_surviving_ancestor() {
+            let mut snapshot = make_test_snapshot();
+            // main <- a <- b <- c, with b deleted from the remote
+            let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
+            for (branch, parent) in [("a", "main"), ("b", "a"), ("c", "b")] {
+                let branch = BranchName::new(branch).unwrap();
+                snapshot.metadata.insert(
+                    branch.clone(),
+                    ScannedMetadata {
+                        ref_oid: oid.clone(),
+                        metadata: BranchMetadataV2::new(
+                            branch,
+                            BranchName::new(parent).unwrap(),
+                            oid.clone(),
+                        ),
+                    },
+                );
+            }
+            let prs = vec![
+                make_pr_summary(1, "a", "main"),
+                make_pr_summary(3, "c", "b"),
+                make_pr_summary(4, "d", "gone"),
+            ];
+
+            let remote: HashSet<String> = ["main", "a", "c"].map(String::from).into();
+            let found = deleted_pr_base_issues(&snapshot, &prs, "origin", &remote);
+            let bases: Vec<_> = found
+                .iter()
+                .flat_map(|issue| &issue.evidence)
+                .filter_map(|e| match e {
+                    Evidence::DeletedPrBase {
+                        number,
+                        surviving_base,
+                        ..
+                    } => Some((*number, surviving_base.as_str())),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(bases, vec![(3, "a"), (4, "main")]);
+
+            // With a deleted too, only trunk survives
+            let remote: HashSet<String> = ["main", "c"].map(String::from).into();
+            let found = deleted_pr_base_issues(&snapshot, &prs, "origin", &remote);
+            assert!(found[0]
+                .message
+                .contains("'b', which was deleted from 'origin'"));
+            assert!(matches!(
+                &found[0].evidence[0],
+                Evidence::DeletedPrBase { surviving_base, .. } if surviving_base == "main"
+            ));
+        }
     }
 
     // --- Synthetic Stack Detection Tests (Milestone 5.8) ---
@@ -1505,6 +2020,7 @@ mod tests {
                 is_draft: false,
                 url: format!("https://github.com/org/repo/pull/{}", number),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
+                author: None,
             }
         }
 
@@ -1518,6 +2034,7 @@ mod tests {
                 },
                 git_state: GitState::Clean,
                 worktree_status: WorktreeStatus::default(),
+                checked_out_elsewhere: Default::default(),
                 current_branch: Some(BranchName::new("main").unwrap()),
                 branches: HashMap::new(),
                 metadata: HashMap::new(),
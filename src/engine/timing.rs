@@ -0,0 +1,283 @@
+//! engine::timing
+//!
+//! How long each phase of the current command took, checked against budgets.
+//!
+//! # Design
+//!
+//! The engine times its phases where they run: [`scan`](super::scan::scan)
+//! and [`Executor::execute`](super::exec::Executor::execute) time themselves,
+//! and the runner times gating and planning. A phase that runs more than once
+//! in a command (a rescan after executing, say) adds up, and execution
+//! includes the rescan that verifies it. Durations are kept per thread, so
+//! concurrent tests don't see each other's phases.
+//!
+//! When the command returns, the CLI [`take`]s the durations, records them in
+//! the event ledger, and prints a one-line hint for each phase over its
+//! budget (`[budgets]` in the global config). The hint names the fix most
+//! likely to help given what the repository looks like ([`SlowPathFacts`]):
+//! turning the ancestry cache back on, writing a commit-graph, skipping
+//! hooks, or tracking fewer branches.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// A timed phase of a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    /// Reading repository state
+    Scan,
+    /// Checking the command's requirements
+    Gate,
+    /// Building the plan
+    Plan,
+    /// Applying the plan
+    Execute,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Phase::Scan => "scan",
+            Phase::Gate => "gate",
+            Phase::Plan => "plan",
+            Phase::Execute => "execute",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Total time a command spent in one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseTiming {
+    /// The phase
+    pub phase: Phase,
+    /// Milliseconds spent in it
+    pub millis: u64,
+}
+
+thread_local! {
+    static TIMINGS: RefCell<Vec<PhaseTiming>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f`, adding the time it takes to `phase`.
+pub fn time<T>(phase: Phase, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    record(phase, start.elapsed());
+    result
+}
+
+/// Add `elapsed` to `phase`.
+pub fn record(phase: Phase, elapsed: Duration) {
+    let millis = elapsed.as_millis() as u64;
+    TIMINGS.with(|timings| {
+        let mut timings = timings.borrow_mut();
+        match timings.iter_mut().find(|t| t.phase == phase) {
+            Some(timing) => timing.millis += millis,
+            None => timings.push(PhaseTiming { phase, millis }),
+        }
+    });
+}
+
+/// Remove and return the phases timed so far, in lifecycle order.
+pub fn take() -> Vec<PhaseTiming> {
+    let mut timings = TIMINGS.with(|timings| std::mem::take(&mut *timings.borrow_mut()));
+    timings.sort_by_key(|t| t.phase);
+    timings
+}
+
+/// Time allowed per phase before a hint is printed.
+///
+/// `None` turns the hint off for that phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budgets {
+    /// Budget for reading repository state
+    pub scan: Option<Duration>,
+    /// Budget for checking requirements
+    pub gate: Option<Duration>,
+    /// Budget for building the plan
+    pub plan: Option<Duration>,
+    /// Budget for applying the plan
+    pub execute: Option<Duration>,
+}
+
+impl Default for Budgets {
+    fn default() -> Self {
+        Self {
+            scan: Some(Duration::from_secs(2)),
+            gate: Some(Duration::from_millis(200)),
+            plan: Some(Duration::from_secs(1)),
+            execute: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl Budgets {
+    /// The budget for `phase`.
+    pub fn for_phase(&self, phase: Phase) -> Option<Duration> {
+        match phase {
+            Phase::Scan => self.scan,
+            Phase::Gate => self.gate,
+            Phase::Plan => self.plan,
+            Phase::Execute => self.execute,
+        }
+    }
+
+    /// The phases in `timings` that took longer than their budget, with
+    /// the budget each exceeded.
+    pub fn exceeded(&self, timings: &[PhaseTiming]) -> Vec<(PhaseTiming, Duration)> {
+        timings
+            .iter()
+            .filter_map(|timing| {
+                let budget = self.for_phase(timing.phase)?;
+                (Duration::from_millis(timing.millis) > budget).then_some((*timing, budget))
+            })
+            .collect()
+    }
+}
+
+/// What the repository looks like, for choosing a remediation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlowPathFacts {
+    /// The ancestry cache keeps answers between runs
+    pub ancestry_cache: bool,
+    /// A commit-graph file exists
+    pub commit_graph: bool,
+    /// Git hooks run for commits Lattice makes
+    pub hooks: bool,
+    /// Number of tracked branches
+    pub tracked_branches: usize,
+}
+
+/// The remediation most likely to speed up `phase`.
+pub fn remediation(phase: Phase, facts: &SlowPathFacts) -> String {
+    match phase {
+        Phase::Scan | Phase::Plan if !facts.ancestry_cache => {
+            "set cache.ancestry_entries above 0 to reuse ancestry answers between runs".to_string()
+        }
+        _ if !facts.commit_graph => {
+            "run 'git commit-graph write --reachable' to speed up history walks".to_string()
+        }
+        Phase::Execute if facts.hooks => {
+            "git hooks run for each commit; --no-verify skips them".to_string()
+        }
+        _ => format!(
+            "{} branches are tracked; 'lattice untrack' the ones you no longer work on",
+            facts.tracked_branches
+        ),
+    }
+}
+
+/// Format the hint for a phase that went over its budget.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use latticework::engine::timing::{budget_hint, Phase, PhaseTiming, SlowPathFacts};
+///
+/// let facts = SlowPathFacts {
+///     ancestry_cache: true,
+///     commit_graph: false,
+///     hooks: true,
+///     tracked_branches: 40,
+/// };
+/// let timing = PhaseTiming { phase: Phase::Scan, millis: 3400 };
+/// assert_eq!(
+///     budget_hint(&timing, Duration::from_secs(2), &facts),
+///     "Hint: scan took 3.4s (budget 2.0s); \
+///      run 'git commit-graph write --reachable' to speed up history walks"
+/// );
+/// ```
+pub fn budget_hint(timing: &PhaseTiming, budget: Duration, facts: &SlowPathFacts) -> String {
+    format!(
+        "Hint: {} took {:.1}s (budget {:.1}s); {}",
+        timing.phase,
+        timing.millis as f64 / 1000.0,
+        budget.as_secs_f64(),
+        remediation(timing.phase, facts)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts() -> SlowPathFacts {
+        SlowPathFacts {
+            ancestry_cache: true,
+            commit_graph: true,
+            hooks: false,
+            tracked_branches: 12,
+        }
+    }
+
+    #[test]
+    fn repeated_phases_add_up_and_take_drains() {
+        record(Phase::Execute, Duration::from_millis(5));
+        record(Phase::Scan, Duration::from_millis(10));
+        record(Phase::Scan, Duration::from_millis(15));
+
+        assert_eq!(
+            take(),
+            vec![
+                PhaseTiming {
+                    phase: Phase::Scan,
+                    millis: 25
+                },
+                PhaseTiming {
+                    phase: Phase::Execute,
+                    millis: 5
+                },
+            ]
+        );
+        assert!(take().is_empty());
+    }
+
+    #[test]
+    fn only_phases_over_budget_are_exceeded() {
+        let budgets = Budgets {
+            gate: None,
+            ..Budgets::default()
+        };
+        let timings = [
+            PhaseTiming {
+                phase: Phase::Scan,
+                millis: 2500,
+            },
+            PhaseTiming {
+                phase: Phase::Gate,
+                millis: 9000,
+            },
+            PhaseTiming {
+                phase: Phase::Plan,
+                millis: 1000,
+            },
+        ];
+
+        let exceeded = budgets.exceeded(&timings);
+        assert_eq!(exceeded, vec![(timings[0], Duration::from_secs(2))]);
+    }
+
+    #[test]
+    fn remediation_prefers_the_cheapest_fix() {
+        let no_cache = SlowPathFacts {
+            ancestry_cache: false,
+            commit_graph: false,
+            ..facts()
+        };
+        assert!(remediation(Phase::Scan, &no_cache).contains("cache.ancestry_entries"));
+        assert!(remediation(Phase::Gate, &no_cache).contains("commit-graph"));
+
+        let hooks = SlowPathFacts {
+            hooks: true,
+            ..facts()
+        };
+        assert!(remediation(Phase::Execute, &hooks).contains("--no-verify"));
+        assert!(remediation(Phase::Scan, &hooks).contains("12 branches are tracked"));
+    }
+}
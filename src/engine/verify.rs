@@ -344,6 +344,12 @@ pub fn verify_branch(
     snapshot: &RepoSnapshot,
     branch: &BranchName,
 ) -> Result<(), VerifyError> {
+    // Check metadata exists and is valid
+    let scanned = match snapshot.metadata.get(branch) {
+        Some(s) => s,
+        None => return Ok(()), // Not tracked (or deleted with its metadata) - nothing to verify
+    };
+
     // Check branch exists
     if !snapshot.branches.contains_key(branch) {
         return Err(VerifyError::BranchMissing {
@@ -351,12 +357,6 @@ pub fn verify_branch(
         });
     }
 
-    // Check metadata exists and is valid
-    let scanned = match snapshot.metadata.get(branch) {
-        Some(s) => s,
-        None => return Ok(()), // Not tracked - nothing to verify
-    };
-
     // Check base ancestry
     let base_oid_str = &scanned.metadata.base.oid;
     let base_oid = Oid::new(base_oid_str).map_err(|e| VerifyError::MetadataUnparseable {
@@ -381,7 +381,7 @@ pub fn verify_branch(
 mod tests {
     use super::*;
     use crate::core::graph::StackGraph;
-    use crate::core::metadata::schema::BranchMetadataV1;
+    use crate::core::metadata::schema::BranchMetadataV2;
     use crate::core::types::Fingerprint;
     use crate::engine::health::RepoHealthReport;
     use crate::engine::scan::ScannedMetadata;
@@ -405,6 +405,7 @@ mod tests {
             },
             git_state: GitState::Clean,
             worktree_status: WorktreeStatus::default(),
+            checked_out_elsewhere: Default::default(),
             current_branch: None,
             branches: HashMap::new(),
             metadata: HashMap::new(),
@@ -470,7 +471,7 @@ mod tests {
                 branch.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch.clone(),
                         BranchName::new("main").unwrap(),
                         oid,
@@ -493,7 +494,7 @@ mod tests {
                 branch.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch.clone(),
                         BranchName::new("main").unwrap(),
                         oid,
@@ -521,7 +522,7 @@ mod tests {
                 branch.clone(),
                 ScannedMetadata {
                     ref_oid: oid.clone(),
-                    metadata: BranchMetadataV1::new(
+                    metadata: BranchMetadataV2::new(
                         branch.clone(),
                         BranchName::new("main").unwrap(),
                         oid,
@@ -539,7 +540,7 @@ mod tests {
             let branch = BranchName::new("feature").unwrap();
             let oid = make_oid("abc");
 
-            let meta = BranchMetadataV1::builder(
+            let meta = BranchMetadataV2::builder(
                 branch.clone(),
                 BranchName::new("main").unwrap(),
                 oid.clone(),
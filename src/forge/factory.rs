@@ -18,6 +18,13 @@
 //! - GitHub URLs (`github.com`) → `GitHubForge`
 //! - GitLab URLs (`gitlab.com`) → `GitLabForge` (when feature enabled)
 //!
+//! Self-hosted instances can't be recognized by host. [`resolve_provider`]
+//! falls back to the configured `default_forge` for them, and the GitLab
+//! forge accepts a remote on any host when selected that way. GitHub
+//! Enterprise Server hosts are recognized by the CLI from the hosts the user
+//! has logged in to; selected explicitly, the GitHub forge accepts a remote
+//! on any host and talks to its `/api/v3`.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -38,11 +45,11 @@
 //! )?;
 //! ```
 
-use super::github::{parse_github_url, GitHubForge};
+use super::github::{api_base_for_url, parse_github_remote, parse_github_url, GitHubForge};
 use super::traits::{Forge, ForgeError};
 
 #[cfg(feature = "gitlab")]
-use super::gitlab::{parse_gitlab_url, GitLabForge};
+use super::gitlab::{parse_gitlab_remote, parse_gitlab_url, GitLabForge};
 
 /// Supported forge providers.
 ///
@@ -157,6 +164,36 @@ pub fn detect_provider(remote_url: &str) -> Option<ForgeProvider> {
     None
 }
 
+/// Resolve the forge provider for a remote URL, falling back to a default.
+///
+/// Detection by host wins. Otherwise `default_forge` (the `default_forge`
+/// config key) is used if it names an enabled provider that can read the
+/// URL, which is how self-hosted GitLab instances are selected.
+///
+/// # Example
+///
+/// ```
+/// use latticework::forge::{resolve_provider, ForgeProvider};
+///
+/// assert_eq!(
+///     resolve_provider("git@github.com:owner/repo.git", Some("gitlab")),
+///     Some(ForgeProvider::GitHub)
+/// );
+/// assert_eq!(resolve_provider("git@git.example.com:team/app.git", None), None);
+/// ```
+pub fn resolve_provider(remote_url: &str, default_forge: Option<&str>) -> Option<ForgeProvider> {
+    if let Some(provider) = detect_provider(remote_url) {
+        return Some(provider);
+    }
+    match ForgeProvider::parse(default_forge?)? {
+        // GitHub is the default default; which other hosts run GitHub
+        // Enterprise is known to the caller, from the hosts it has logged in to
+        ForgeProvider::GitHub => None,
+        #[cfg(feature = "gitlab")]
+        ForgeProvider::GitLab => parse_gitlab_remote(remote_url).map(|_| ForgeProvider::GitLab),
+    }
+}
+
 /// Create a forge from a remote URL and token.
 ///
 /// This is the primary entry point for creating forge instances in commands.
@@ -261,24 +298,38 @@ fn create_forge_for_provider(
 ) -> Result<Box<dyn Forge>, ForgeError> {
     match provider {
         ForgeProvider::GitHub => {
-            let forge = GitHubForge::from_remote_url(remote_url, token).ok_or_else(|| {
-                ForgeError::NotFound(format!(
-                    "Could not parse '{}' as a GitHub URL. \
-                     Expected format: git@github.com:owner/repo.git or https://github.com/owner/repo.git",
-                    remote_url
-                ))
-            })?;
+            let forge = GitHubForge::from_remote_url(remote_url, token)
+                .or_else(|| {
+                    // GitHub Enterprise Server: same API under the host's /api/v3
+                    let remote = parse_github_remote(remote_url)?;
+                    let api_base = api_base_for_url(remote_url)?;
+                    Some(GitHubForge::with_api_base(
+                        token,
+                        remote.owner,
+                        remote.repo,
+                        api_base,
+                    ))
+                })
+                .ok_or_else(|| {
+                    ForgeError::NotFound(format!(
+                        "Could not parse '{}' as a GitHub URL. \
+                         Expected format: git@github.example.com:owner/repo.git or https://github.example.com/owner/repo.git",
+                        remote_url
+                    ))
+                })?;
             Ok(Box::new(forge))
         }
         #[cfg(feature = "gitlab")]
         ForgeProvider::GitLab => {
-            let forge = GitLabForge::from_remote_url(remote_url, token).ok_or_else(|| {
-                ForgeError::NotFound(format!(
-                    "Could not parse '{}' as a GitLab URL. \
-                     Expected format: git@gitlab.com:owner/project.git or https://gitlab.com/owner/project.git",
-                    remote_url
-                ))
-            })?;
+            let forge = GitLabForge::from_remote_url(remote_url, token)
+                .or_else(|| GitLabForge::from_self_hosted_url(remote_url, token))
+                .ok_or_else(|| {
+                    ForgeError::NotFound(format!(
+                        "Could not parse '{}' as a GitLab URL. \
+                         Expected format: git@gitlab.example.com:group/project.git or https://gitlab.example.com/group/project.git",
+                        remote_url
+                    ))
+                })?;
             Ok(Box::new(forge))
         }
     }
@@ -414,6 +465,16 @@ mod tests {
             assert_eq!(result.unwrap().name(), "github");
         }
 
+        #[test]
+        fn github_override_accepts_enterprise_url() {
+            let result = create_forge(
+                "git@github.example.com:team/app.git",
+                "token",
+                Some("github"),
+            );
+            assert_eq!(result.unwrap().name(), "github");
+        }
+
         #[test]
         fn unknown_url_returns_error() {
             let result = create_forge("git@unknown.com:owner/repo.git", "token", None);
@@ -457,6 +518,43 @@ mod tests {
             assert!(result.is_ok());
             assert_eq!(result.unwrap().name(), "gitlab");
         }
+
+        #[cfg(feature = "gitlab")]
+        #[test]
+        fn gitlab_override_accepts_self_hosted_url() {
+            let result = create_forge("git@git.example.com:team/app.git", "token", Some("gitlab"));
+            assert_eq!(result.unwrap().name(), "gitlab");
+        }
+    }
+
+    mod resolve_provider {
+        use super::*;
+
+        #[test]
+        fn detection_wins_over_default() {
+            assert_eq!(
+                resolve_provider("https://github.com/owner/repo", Some("gitlab")),
+                Some(ForgeProvider::GitHub)
+            );
+        }
+
+        #[test]
+        fn unknown_host_without_usable_default() {
+            let url = "git@git.example.com:team/app.git";
+            assert_eq!(resolve_provider(url, None), None);
+            assert_eq!(resolve_provider(url, Some("github")), None);
+            assert_eq!(resolve_provider(url, Some("nonsense")), None);
+        }
+
+        #[cfg(feature = "gitlab")]
+        #[test]
+        fn gitlab_default_selects_self_hosted() {
+            assert_eq!(
+                resolve_provider("git@git.example.com:team/app.git", Some("gitlab")),
+                Some(ForgeProvider::GitLab)
+            );
+            assert_eq!(resolve_provider("/srv/git/app.git", Some("gitlab")), None);
+        }
     }
 
     mod valid_forge_names {
@@ -6,7 +6,22 @@
 //!
 //! This module implements the `Forge` trait for GitHub. It uses:
 //! - REST API for most operations (create/update/get/merge PRs, request reviewers)
-//! - GraphQL API for draft status toggling (required by GitHub)
+//! - GraphQL API for draft status toggling (required by GitHub), merge
+//!   queues, check rollups, and batched PR status lookups
+//!
+//! # Batched Lookups
+//!
+//! [`Forge::get_pr_statuses`] asks for up to 50 PRs per GraphQL request,
+//! each an aliased `pullRequest` field with its state, mergeability, review
+//! decision, and checks, so `sync` on a big stack costs a request or two
+//! instead of one per branch.
+//!
+//! # GitHub Enterprise Server
+//!
+//! Remotes on hosts other than github.com are treated as GitHub Enterprise
+//! Server: [`parse_github_remote`] reads them, and [`api_base_for_url`]
+//! points the forge at `https://<host>/api/v3`, with GraphQL at
+//! `https://<host>/api/graphql`. Tokens are per host (`lattice auth --host`).
 //!
 //! # Authentication
 //!
@@ -19,11 +34,24 @@
 //! - Auth lock prevents concurrent refresh races
 //! - 401/403 errors trigger one retry with fresh token
 //!
+//! Public github.com repositories can also be read without a token
+//! ([`GitHubForge::anonymous`]), so `get` and `pr` work before the user has
+//! logged in. GitHub allows such requests a much smaller hourly budget, and
+//! GraphQL not at all, so anonymous forges refuse GraphQL calls with
+//! `ForgeError::AuthRequired` instead of spending a request on them.
+//!
 //! # Rate Limiting
 //!
-//! GitHub has rate limits. This implementation:
-//! - Returns `ForgeError::RateLimited` when limits are hit
-//! - Does not implement automatic retry for rate limits (caller's responsibility)
+//! Every request goes through one send path that records the budget GitHub
+//! reports in its `x-ratelimit-*` headers ([`rate_limit`]). When GitHub
+//! refuses a request with 429, or with 403 for a spent budget or a
+//! secondary rate limit, it waits (`retry-after`, the reset time, or an
+//! exponential backoff) and retries, holding off the other requests of the
+//! process meanwhile. When the retries run out or the wait would be too
+//! long, the request fails with `ForgeError::RateLimited` instead of a
+//! misleading permission error.
+//!
+//! [`rate_limit`]: super::rate_limit
 //!
 //! # Example
 //!
@@ -48,24 +76,33 @@
 //!
 //! [`TokenProvider`]: crate::auth::TokenProvider
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT};
-use reqwest::{Client, Response, StatusCode};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 
+use super::rate_limit::{self, RateLimit};
 use super::traits::{
-    CreatePrRequest, Forge, ForgeError, ListPullsOpts, ListPullsResult, MergeMethod, PrState,
-    PullRequest, PullRequestSummary, Reviewers, UpdatePrRequest,
+    CheckState, CheckStatus, CreatePrRequest, Forge, ForgeError, Issue, ListPullsOpts,
+    ListPullsResult, MergeMethod, MergeQueueEntry, MergeQueueState, PrState, PrStatus, PullRequest,
+    PullRequestSummary, ReviewDecision, ReviewState, Reviewers, UpdatePrRequest,
 };
 use crate::auth::TokenProvider;
+use crate::core::net;
 
 /// Default GitHub API base URL.
 const DEFAULT_API_BASE: &str = "https://api.github.com";
 
-/// Default GitHub GraphQL endpoint.
-const GRAPHQL_ENDPOINT: &str = "https://api.github.com/graphql";
+/// Host of the public GitHub instance.
+const GITHUB_COM: &str = "github.com";
+
+/// Most PRs looked up in one GraphQL request. Each brings up to 100 check
+/// nodes, so this keeps a batch well under GitHub's node limit.
+const PR_STATUS_BATCH: usize = 50;
 
 /// User-Agent header value for API requests.
 const USER_AGENT_VALUE: &str = "lattice-cli";
@@ -143,7 +180,7 @@ impl GitHubForge {
         repo: impl Into<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: net::client(),
             token_provider: Some(provider),
             static_token: None,
             owner: owner.into(),
@@ -169,7 +206,7 @@ impl GitHubForge {
         api_base: impl Into<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: net::client(),
             token_provider: Some(provider),
             static_token: None,
             owner: owner.into(),
@@ -213,7 +250,7 @@ impl GitHubForge {
         repo: impl Into<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: net::client(),
             token_provider: None,
             static_token: Some(token.into()),
             owner: owner.into(),
@@ -241,7 +278,7 @@ impl GitHubForge {
         api_base: impl Into<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: net::client(),
             token_provider: None,
             static_token: Some(token.into()),
             owner: owner.into(),
@@ -284,6 +321,63 @@ impl GitHubForge {
         Some(Self::new_with_provider(provider, owner, repo))
     }
 
+    /// Create a GitHub forge for a remote on any host, with a TokenProvider.
+    ///
+    /// Unlike [`from_remote_url_with_provider`], the remote may live on a
+    /// GitHub Enterprise Server instance; the API base comes from its host
+    /// (see [`api_base_for_url`]). The provider must hold tokens for that host.
+    ///
+    /// [`from_remote_url_with_provider`]: GitHubForge::from_remote_url_with_provider
+    pub fn from_enterprise_url_with_provider(
+        url: &str,
+        provider: Arc<dyn TokenProvider>,
+    ) -> Option<Self> {
+        let remote = parse_github_remote(url)?;
+        let api_base = api_base_for_url(url)?;
+        Some(Self::new_with_provider_and_api_base(
+            provider,
+            remote.owner,
+            remote.repo,
+            api_base,
+        ))
+    }
+
+    /// Create a GitHub forge that reads a public repository without a token.
+    ///
+    /// Requests carry no `Authorization` header, so they only see public
+    /// repositories and count against GitHub's budget for unauthenticated
+    /// requests (60 an hour per IP address on github.com). GraphQL calls
+    /// fail with `ForgeError::AuthRequired`.
+    pub fn anonymous(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            client: net::client(),
+            token_provider: None,
+            static_token: None,
+            owner: owner.into(),
+            repo: repo.into(),
+            api_base: DEFAULT_API_BASE.to_string(),
+        }
+    }
+
+    /// Create a tokenless GitHub forge from a github.com remote URL.
+    ///
+    /// See [`anonymous`](GitHubForge::anonymous). Remotes on other hosts
+    /// return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::forge::github::GitHubForge;
+    ///
+    /// let forge = GitHubForge::from_remote_url_anonymous("https://github.com/o/r.git").unwrap();
+    /// assert!(forge.is_anonymous());
+    /// assert!(GitHubForge::from_remote_url_anonymous("git@github.example.com:o/r.git").is_none());
+    /// ```
+    pub fn from_remote_url_anonymous(url: &str) -> Option<Self> {
+        let (owner, repo) = parse_github_url(url)?;
+        Some(Self::anonymous(owner, repo))
+    }
+
     /// Create a GitHub forge from a remote URL with a static token.
     ///
     /// # Deprecated
@@ -322,11 +416,32 @@ impl GitHubForge {
         &self.repo
     }
 
+    /// Get the REST API base URL.
+    pub fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    /// The GraphQL endpoint that goes with the REST API base.
+    ///
+    /// GitHub Enterprise Server serves REST under `/api/v3` and GraphQL
+    /// under `/api/graphql`; api.github.com serves GraphQL at `/graphql`.
+    fn graphql_url(&self) -> String {
+        match self.api_base.strip_suffix("/api/v3") {
+            Some(root) => format!("{}/api/graphql", root),
+            None => format!("{}/graphql", self.api_base),
+        }
+    }
+
     /// Check if this forge has a TokenProvider (enables retry on auth failure).
     pub fn has_token_provider(&self) -> bool {
         self.token_provider.is_some()
     }
 
+    /// Check if this forge sends requests without a token.
+    pub fn is_anonymous(&self) -> bool {
+        self.token_provider.is_none() && self.static_token.is_none()
+    }
+
     /// Get the current bearer token, refreshing if needed.
     ///
     /// If a TokenProvider is configured, calls `bearer_token()` which may
@@ -350,13 +465,17 @@ impl GitHubForge {
     }
 
     /// Build common headers for API requests (async to allow token refresh).
+    ///
+    /// Anonymous forges send no `Authorization` header.
     async fn headers(&self) -> Result<HeaderMap, ForgeError> {
-        let token = self.get_bearer_token().await?;
         let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", token)).expect("Invalid token format"),
-        );
+        if !self.is_anonymous() {
+            let token = self.get_bearer_token().await?;
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", token)).expect("Invalid token format"),
+            );
+        }
         headers.insert(
             ACCEPT,
             HeaderValue::from_static("application/vnd.github+json"),
@@ -369,6 +488,78 @@ impl GitHubForge {
         Ok(headers)
     }
 
+    /// Send a request, backing off and retrying while GitHub refuses it for
+    /// rate limits, and record the budget each response reports.
+    ///
+    /// Returns `ForgeError::RateLimited` once [`rate_limit::MAX_RETRIES`]
+    /// retries are spent or the wait would exceed [`rate_limit::MAX_WAIT`].
+    /// Any other response, successful or not, is returned for the caller to
+    /// handle.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ForgeError> {
+        let mut attempt = 0;
+        loop {
+            if let Some(wait) = rate_limit::paused_for() {
+                tokio::time::sleep(wait).await;
+            }
+            let this_try = request
+                .try_clone()
+                .expect("GitHub API requests have buffered bodies");
+            let response = this_try.send().await.map_err(request_error)?;
+            if let Some(limit) = RateLimit::from_headers(response.headers()) {
+                rate_limit::record(limit);
+            }
+
+            let status = response.status();
+            if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+
+            // The body says whether a 403 is a secondary rate limit, so read
+            // it, then rebuild the response for the caller if it isn't one.
+            let headers = response.headers().clone();
+            let bytes = response.bytes().await.map_err(request_error)?;
+            let message = serde_json::from_slice::<GitHubErrorResponse>(&bytes)
+                .map(|e| e.message)
+                .unwrap_or_default();
+            let Some(delay) =
+                rate_limit::retry_delay(status, &headers, &message, attempt, Utc::now())
+            else {
+                let mut rebuilt = http::Response::new(bytes);
+                *rebuilt.status_mut() = status;
+                *rebuilt.headers_mut() = headers;
+                return Ok(Response::from(rebuilt));
+            };
+            if attempt >= rate_limit::MAX_RETRIES || delay > rate_limit::MAX_WAIT {
+                return Err(ForgeError::RateLimited);
+            }
+            rate_limit::pause(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Ask GitHub for the budget of each API resource.
+    ///
+    /// Queries `/rate_limit`, which doesn't count against any budget, and
+    /// returns the `core` (REST) and `graphql` budgets. GitHub Enterprise
+    /// Server with rate limiting off answers 404, reported as `NotFound`.
+    pub async fn rate_limits(&self) -> Result<Vec<RateLimit>, ForgeError> {
+        let url = format!("{}/rate_limit", self.api_base);
+        let response: RateLimitResponse = self.get_json(&url).await?;
+        Ok(["core", "graphql"]
+            .into_iter()
+            .filter_map(|name| {
+                let budget = response.resources.get(name)?;
+                Some(RateLimit {
+                    resource: name.to_string(),
+                    limit: budget.limit,
+                    remaining: budget.remaining,
+                    used: budget.used,
+                    reset: Utc.timestamp_opt(budget.reset as i64, 0).single()?,
+                })
+            })
+            .collect())
+    }
+
     /// Check if an error is an auth failure that might be resolved by token refresh.
     fn is_retryable_auth_error(err: &ForgeError) -> bool {
         matches!(err, ForgeError::AuthFailed(_))
@@ -390,10 +581,10 @@ impl GitHubForge {
         let status = response.status();
 
         if status.is_success() {
-            response.json().await.map_err(|e| ForgeError::ApiError {
-                status: status.as_u16(),
-                message: format!("Failed to parse response: {}", e),
-            })
+            response
+                .json()
+                .await
+                .map_err(|e| body_error(e, status, "response"))
         } else {
             self.handle_error_response(response, status).await
         }
@@ -448,7 +639,11 @@ impl GitHubForge {
                     }
                 }
 
-                ForgeError::AuthFailed(err_msg)
+                if message.to_lowercase().contains("rate limit") {
+                    ForgeError::RateLimited
+                } else {
+                    ForgeError::AuthFailed(err_msg)
+                }
             }
             StatusCode::NOT_FOUND => ForgeError::NotFound(message),
             StatusCode::UNPROCESSABLE_ENTITY => ForgeError::ApiError {
@@ -493,21 +688,16 @@ impl GitHubForge {
             let client = &self.client;
             let body = &body;
             async move {
-                let response = client
-                    .post(GRAPHQL_ENDPOINT)
-                    .headers(headers)
-                    .json(body)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                let response = self
+                    .send(client.post(self.graphql_url()).headers(headers).json(body))
+                    .await?;
 
                 let status = response.status();
                 if status.is_success() {
-                    let result: GraphQLResponse =
-                        response.json().await.map_err(|e| ForgeError::ApiError {
-                            status: status.as_u16(),
-                            message: format!("Failed to parse GraphQL response: {}", e),
-                        })?;
+                    let result: GraphQLResponse = response
+                        .json()
+                        .await
+                        .map_err(|e| body_error(e, status, "GraphQL response"))?;
 
                     if let Some(errors) = result.errors {
                         if !errors.is_empty() {
@@ -556,6 +746,266 @@ impl GitHubForge {
             other => other,
         }
     }
+
+    /// Add a PR to its base branch's merge queue via GraphQL.
+    ///
+    /// Merge queues have no REST API.
+    async fn graphql_enqueue(&self, node_id: &str) -> Result<MergeQueueEntry, ForgeError> {
+        let mutation = r#"mutation($id: ID!) {
+            enqueuePullRequest(input: {pullRequestId: $id}) {
+                mergeQueueEntry { position state }
+            }
+        }"#;
+
+        let body = serde_json::json!({
+            "query": mutation,
+            "variables": { "id": node_id }
+        });
+
+        let execute_graphql = |headers: HeaderMap| {
+            let client = &self.client;
+            let body = &body;
+            async move {
+                let response = self
+                    .send(client.post(self.graphql_url()).headers(headers).json(body))
+                    .await?;
+
+                let status = response.status();
+                match status {
+                    s if s.is_success() => {}
+                    StatusCode::UNAUTHORIZED => {
+                        return Err(ForgeError::AuthFailed("Invalid or expired token".into()))
+                    }
+                    StatusCode::FORBIDDEN => {
+                        return Err(ForgeError::AuthFailed("Permission denied".into()))
+                    }
+                    _ => {
+                        let message = response
+                            .json::<GitHubErrorResponse>()
+                            .await
+                            .map(|e| e.message)
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        return Err(ForgeError::ApiError {
+                            status: status.as_u16(),
+                            message,
+                        });
+                    }
+                }
+
+                let result: EnqueueResponse = response
+                    .json()
+                    .await
+                    .map_err(|e| body_error(e, status, "GraphQL response"))?;
+                result.into_entry()
+            }
+        };
+
+        // First attempt
+        let result = execute_graphql(self.headers().await?).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                execute_graphql(self.headers().await?).await
+            }
+            other => other,
+        }
+    }
+
+    /// Fetch the check rollup for a PR's head commit via GraphQL.
+    ///
+    /// The REST API has no per-PR notion of "required"; GraphQL exposes
+    /// `isRequired` on both check runs and status contexts.
+    async fn graphql_list_checks(&self, number: u64) -> Result<Vec<CheckStatus>, ForgeError> {
+        let query = r#"query($owner: String!, $repo: String!, $number: Int!) {
+            repository(owner: $owner, name: $repo) {
+                pullRequest(number: $number) {
+                    commits(last: 1) {
+                        nodes {
+                            commit {
+                                statusCheckRollup {
+                                    contexts(first: 100) {
+                                        nodes {
+                                            __typename
+                                            ... on CheckRun {
+                                                name
+                                                status
+                                                conclusion
+                                                isRequired(pullRequestNumber: $number)
+                                            }
+                                            ... on StatusContext {
+                                                context
+                                                state
+                                                isRequired(pullRequestNumber: $number)
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let body = serde_json::json!({
+            "query": query,
+            "variables": { "owner": self.owner, "repo": self.repo, "number": number }
+        });
+
+        let execute_graphql = |headers: HeaderMap| {
+            let client = &self.client;
+            let body = &body;
+            async move {
+                let response = self
+                    .send(client.post(self.graphql_url()).headers(headers).json(body))
+                    .await?;
+
+                let status = response.status();
+                match status {
+                    s if s.is_success() => {}
+                    StatusCode::UNAUTHORIZED => {
+                        return Err(ForgeError::AuthFailed("Invalid or expired token".into()))
+                    }
+                    StatusCode::FORBIDDEN => {
+                        return Err(ForgeError::AuthFailed("Permission denied".into()))
+                    }
+                    _ => {
+                        let message = response
+                            .json::<GitHubErrorResponse>()
+                            .await
+                            .map(|e| e.message)
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        return Err(ForgeError::ApiError {
+                            status: status.as_u16(),
+                            message,
+                        });
+                    }
+                }
+
+                let result: ChecksQueryResponse = response
+                    .json()
+                    .await
+                    .map_err(|e| body_error(e, status, "GraphQL response"))?;
+
+                if let Some(error) = result.errors.and_then(|e| e.into_iter().next()) {
+                    return Err(ForgeError::ApiError {
+                        status: 200,
+                        message: error.message,
+                    });
+                }
+
+                let pr = result
+                    .data
+                    .and_then(|d| d.repository)
+                    .and_then(|r| r.pull_request)
+                    .ok_or_else(|| ForgeError::NotFound(format!("PR #{}", number)))?;
+
+                Ok(pr
+                    .commits
+                    .nodes
+                    .into_iter()
+                    .filter_map(|n| n.commit.status_check_rollup)
+                    .flat_map(|rollup| rollup.contexts.nodes)
+                    .filter_map(CheckContext::into_status)
+                    .collect())
+            }
+        };
+
+        // First attempt
+        let result = execute_graphql(self.headers().await?).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                execute_graphql(self.headers().await?).await
+            }
+            other => other,
+        }
+    }
+
+    /// POST a GraphQL request and parse the response, retrying once on auth
+    /// failure.
+    async fn post_graphql<T: for<'de> Deserialize<'de>>(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<T, ForgeError> {
+        // GitHub serves GraphQL to authenticated requests only
+        if self.is_anonymous() {
+            return Err(ForgeError::AuthRequired);
+        }
+
+        let execute_graphql = |headers: HeaderMap| async move {
+            let response = self
+                .send(
+                    self.client
+                        .post(self.graphql_url())
+                        .headers(headers)
+                        .json(body),
+                )
+                .await?;
+
+            let status = response.status();
+            match status {
+                s if s.is_success() => {}
+                StatusCode::UNAUTHORIZED => {
+                    return Err(ForgeError::AuthFailed("Invalid or expired token".into()))
+                }
+                StatusCode::FORBIDDEN => {
+                    return Err(ForgeError::AuthFailed("Permission denied".into()))
+                }
+                _ => {
+                    let message = response
+                        .json::<GitHubErrorResponse>()
+                        .await
+                        .map(|e| e.message)
+                        .unwrap_or_else(|_| "Unknown error".to_string());
+                    return Err(ForgeError::ApiError {
+                        status: status.as_u16(),
+                        message,
+                    });
+                }
+            }
+
+            response
+                .json::<T>()
+                .await
+                .map_err(|e| body_error(e, status, "GraphQL response"))
+        };
+
+        // First attempt
+        let result = execute_graphql(self.headers().await?).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                execute_graphql(self.headers().await?).await
+            }
+            other => other,
+        }
+    }
+
+    /// GET a REST endpoint and parse the response, retrying once on auth
+    /// failure.
+    async fn get_json<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, ForgeError> {
+        // First attempt
+        let response = self
+            .send(self.client.get(url).headers(self.headers().await?))
+            .await?;
+
+        let result: Result<T, ForgeError> = self.handle_response(response).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                let response = self
+                    .send(self.client.get(url).headers(self.headers().await?))
+                    .await?;
+                self.handle_response(response).await
+            }
+            other => other,
+        }
+    }
 }
 
 #[async_trait]
@@ -577,13 +1027,13 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .post(&url)
-            .headers(self.headers().await?)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(
+                self.client
+                    .post(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
 
         let result: Result<GitHubPullRequest, ForgeError> = self.handle_response(response).await;
 
@@ -591,13 +1041,13 @@ impl Forge for GitHubForge {
         let pr: GitHubPullRequest = match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .post(&url)
-                    .headers(self.headers().await?)
-                    .json(&body)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(
+                        self.client
+                            .post(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
                 self.handle_response(response).await?
             }
             other => other?,
@@ -617,13 +1067,13 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .patch(&url)
-            .headers(self.headers().await?)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(
+                self.client
+                    .patch(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
 
         let result: Result<GitHubPullRequest, ForgeError> = self.handle_response(response).await;
 
@@ -631,13 +1081,13 @@ impl Forge for GitHubForge {
         let pr: GitHubPullRequest = match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .patch(&url)
-                    .headers(self.headers().await?)
-                    .json(&body)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(
+                        self.client
+                            .patch(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
                 self.handle_response(response).await?
             }
             other => other?,
@@ -651,12 +1101,8 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .get(&url)
-            .headers(self.headers().await?)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(self.client.get(&url).headers(self.headers().await?))
+            .await?;
 
         let result: Result<GitHubPullRequest, ForgeError> = self.handle_response(response).await;
 
@@ -664,12 +1110,8 @@ impl Forge for GitHubForge {
         let pr: GitHubPullRequest = match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .get(&url)
-                    .headers(self.headers().await?)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(self.client.get(&url).headers(self.headers().await?))
+                    .await?;
                 self.handle_response(response).await?
             }
             other => other?,
@@ -678,6 +1120,19 @@ impl Forge for GitHubForge {
         Ok(pr.into())
     }
 
+    async fn get_pr_statuses(&self, numbers: &[u64]) -> Result<Vec<PrStatus>, ForgeError> {
+        let mut statuses = Vec::with_capacity(numbers.len());
+        for batch in numbers.chunks(PR_STATUS_BATCH) {
+            let body = serde_json::json!({
+                "query": pr_status_query(batch),
+                "variables": { "owner": self.owner, "repo": self.repo }
+            });
+            let response: PrStatusResponse = self.post_graphql(&body).await?;
+            statuses.extend(response.into_statuses(batch)?);
+        }
+        Ok(statuses)
+    }
+
     async fn find_pr_by_head(&self, head: &str) -> Result<Option<PullRequest>, ForgeError> {
         // GitHub API requires owner:branch format for cross-fork PRs
         // For same-repo, just the branch name works
@@ -694,12 +1149,8 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .get(&url)
-            .headers(self.headers().await?)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(self.client.get(&url).headers(self.headers().await?))
+            .await?;
 
         let result: Result<Vec<GitHubPullRequest>, ForgeError> =
             self.handle_response(response).await;
@@ -708,12 +1159,8 @@ impl Forge for GitHubForge {
         let prs: Vec<GitHubPullRequest> = match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .get(&url)
-                    .headers(self.headers().await?)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(self.client.get(&url).headers(self.headers().await?))
+                    .await?;
                 self.handle_response(response).await?
             }
             other => other?,
@@ -735,6 +1182,15 @@ impl Forge for GitHubForge {
         self.graphql_set_draft(&node_id, draft).await
     }
 
+    async fn enqueue_pr(&self, number: u64) -> Result<MergeQueueEntry, ForgeError> {
+        let pr = self.get_pr(number).await?;
+        let node_id = pr.node_id.ok_or_else(|| ForgeError::ApiError {
+            status: 0,
+            message: "PR is missing node_id required for the merge queue".into(),
+        })?;
+        self.graphql_enqueue(&node_id).await
+    }
+
     async fn request_reviewers(&self, number: u64, reviewers: Reviewers) -> Result<(), ForgeError> {
         if reviewers.is_empty() {
             return Ok(());
@@ -749,13 +1205,13 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .post(&url)
-            .headers(self.headers().await?)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(
+                self.client
+                    .post(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
 
         let status = response.status();
         let result: Result<(), ForgeError> = if status.is_success() {
@@ -768,13 +1224,13 @@ impl Forge for GitHubForge {
         match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .post(&url)
-                    .headers(self.headers().await?)
-                    .json(&body)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(
+                        self.client
+                            .post(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
                 let status = response.status();
                 if status.is_success() {
                     Ok(())
@@ -799,13 +1255,13 @@ impl Forge for GitHubForge {
 
         // First attempt
         let response = self
-            .client
-            .put(&url)
-            .headers(self.headers().await?)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+            .send(
+                self.client
+                    .put(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
 
         let status = response.status();
         let result: Result<(), ForgeError> = if status.is_success() {
@@ -818,13 +1274,13 @@ impl Forge for GitHubForge {
         match result {
             Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                 let response = self
-                    .client
-                    .put(&url)
-                    .headers(self.headers().await?)
-                    .json(&body)
-                    .send()
-                    .await
-                    .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                    .send(
+                        self.client
+                            .put(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
                 let status = response.status();
                 if status.is_success() {
                     Ok(())
@@ -853,12 +1309,8 @@ impl Forge for GitHubForge {
 
             // First attempt
             let response = self
-                .client
-                .get(&url)
-                .headers(self.headers().await?)
-                .send()
-                .await
-                .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                .send(self.client.get(&url).headers(self.headers().await?))
+                .await?;
 
             let result: Result<Vec<GitHubPullRequestListItem>, ForgeError> =
                 self.handle_response(response).await;
@@ -867,12 +1319,8 @@ impl Forge for GitHubForge {
             let page_prs: Vec<GitHubPullRequestListItem> = match result {
                 Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                     let response = self
-                        .client
-                        .get(&url)
-                        .headers(self.headers().await?)
-                        .send()
-                        .await
-                        .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                        .send(self.client.get(&url).headers(self.headers().await?))
+                        .await?;
                     self.handle_response(response).await?
                 }
                 other => other?,
@@ -936,12 +1384,8 @@ impl Forge for GitHubForge {
 
             // First attempt
             let response = self
-                .client
-                .get(&url)
-                .headers(self.headers().await?)
-                .send()
-                .await
-                .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                .send(self.client.get(&url).headers(self.headers().await?))
+                .await?;
 
             let result: Result<Vec<GitHubPullRequestListItem>, ForgeError> =
                 self.handle_response(response).await;
@@ -950,12 +1394,8 @@ impl Forge for GitHubForge {
             let page_prs: Vec<GitHubPullRequestListItem> = match result {
                 Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
                     let response = self
-                        .client
-                        .get(&url)
-                        .headers(self.headers().await?)
-                        .send()
-                        .await
-                        .map_err(|e| ForgeError::NetworkError(e.to_string()))?;
+                        .send(self.client.get(&url).headers(self.headers().await?))
+                        .await?;
                     self.handle_response(response).await?
                 }
                 other => other?,
@@ -984,32 +1424,241 @@ impl Forge for GitHubForge {
             truncated,
         })
     }
-}
 
-// --------------------------------------------------------------------------
-// API Request/Response Types
-// --------------------------------------------------------------------------
+    async fn close_pr(&self, number: u64) -> Result<(), ForgeError> {
+        let url = self.repo_url(&format!("pulls/{}", number));
+        let body = ClosePrBody { state: "closed" };
 
-/// Request body for creating a PR.
-#[derive(Serialize)]
-struct CreatePrBody<'a> {
-    head: &'a str,
-    base: &'a str,
-    title: &'a str,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    body: Option<&'a str>,
-    draft: bool,
-}
+        // First attempt
+        let response = self
+            .send(
+                self.client
+                    .patch(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
 
-/// Request body for updating a PR.
-#[derive(Serialize)]
-struct UpdatePrBody<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    title: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    body: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    base: Option<&'a str>,
+        let result: Result<GitHubPullRequest, ForgeError> = self.handle_response(response).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                let response = self
+                    .send(
+                        self.client
+                            .patch(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
+                let _: GitHubPullRequest = self.handle_response(response).await?;
+                Ok(())
+            }
+            other => other.map(|_| ()),
+        }
+    }
+
+    async fn comment_pr(&self, number: u64, body: &str) -> Result<(), ForgeError> {
+        // PR conversation comments are issue comments
+        let url = self.repo_url(&format!("issues/{}/comments", number));
+        let body = CommentBody { body };
+
+        // First attempt
+        let response = self
+            .send(
+                self.client
+                    .post(&url)
+                    .headers(self.headers().await?)
+                    .json(&body),
+            )
+            .await?;
+
+        let status = response.status();
+        let result: Result<(), ForgeError> = if status.is_success() {
+            Ok(())
+        } else {
+            self.handle_error_response(response, status).await
+        };
+
+        // Retry once on auth failure if we have a TokenProvider
+        match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                let response = self
+                    .send(
+                        self.client
+                            .post(&url)
+                            .headers(self.headers().await?)
+                            .json(&body),
+                    )
+                    .await?;
+                let status = response.status();
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    self.handle_error_response(response, status).await
+                }
+            }
+            other => other,
+        }
+    }
+
+    async fn list_checks(&self, number: u64) -> Result<Vec<CheckStatus>, ForgeError> {
+        self.graphql_list_checks(number).await
+    }
+
+    async fn check_runs_for_ref(&self, git_ref: &str) -> Result<Vec<CheckStatus>, ForgeError> {
+        // Check runs and legacy statuses live behind separate endpoints
+        let runs: GitHubCheckRuns = self
+            .get_json(&self.repo_url(&format!("commits/{}/check-runs?per_page=100", git_ref)))
+            .await?;
+        let combined: GitHubCombinedStatus = self
+            .get_json(&self.repo_url(&format!("commits/{}/status", git_ref)))
+            .await?;
+
+        Ok(runs
+            .check_runs
+            .into_iter()
+            .map(CheckContext::from)
+            .chain(combined.statuses.into_iter().map(CheckContext::from))
+            .filter_map(CheckContext::into_status)
+            .collect())
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<Issue, ForgeError> {
+        let url = self.repo_url(&format!("issues/{}", number));
+
+        // First attempt
+        let response = self
+            .send(self.client.get(&url).headers(self.headers().await?))
+            .await?;
+
+        let result: Result<GitHubIssue, ForgeError> = self.handle_response(response).await;
+
+        // Retry once on auth failure if we have a TokenProvider
+        let issue: GitHubIssue = match result {
+            Err(ref e) if Self::is_retryable_auth_error(e) && self.has_token_provider() => {
+                let response = self
+                    .send(self.client.get(&url).headers(self.headers().await?))
+                    .await?;
+                self.handle_response(response).await?
+            }
+            other => other?,
+        };
+
+        // The issues API also serves pull requests
+        if issue.pull_request.is_some() {
+            return Err(ForgeError::NotFound(format!(
+                "#{} is a pull request, not an issue",
+                number
+            )));
+        }
+
+        Ok(Issue {
+            number: issue.number,
+            url: issue.html_url,
+            title: issue.title,
+        })
+    }
+}
+
+/// Build one GraphQL query for the status of every PR in `numbers`.
+///
+/// Each PR is an aliased `pullRequest` field (`pr0`, `pr1`, ...). The
+/// number is spelled out in each rather than passed as a variable because
+/// `isRequired` needs it too, and fragments can't take arguments.
+fn pr_status_query(numbers: &[u64]) -> String {
+    let mut query = String::from(
+        "query($owner: String!, $repo: String!) {\n  repository(owner: $owner, name: $repo) {\n",
+    );
+    for (i, number) in numbers.iter().enumerate() {
+        query.push_str(&format!(
+            r#"    pr{i}: pullRequest(number: {number}) {{
+      id number url state isDraft headRefName baseRefName title body
+      author {{ login }}
+      mergeable reviewDecision headRefOid
+      baseRef {{ branchProtectionRule {{
+        requiresApprovingReviews requiredApprovingReviewCount
+        requiresCodeOwnerReviews dismissesStaleReviews
+      }} }}
+      latestOpinionatedReviews(first: 100) {{ nodes {{ state author {{ login }} commit {{ oid }} }} }}
+      reviewRequests(first: 100) {{ nodes {{ asCodeOwner requestedReviewer {{
+        __typename
+        ... on User {{ login }}
+        ... on Team {{ slug }}
+      }} }} }}
+      commits(last: 1) {{ nodes {{ commit {{ statusCheckRollup {{ contexts(first: 100) {{ nodes {{
+        __typename
+        ... on CheckRun {{ name status conclusion isRequired(pullRequestNumber: {number}) }}
+        ... on StatusContext {{ context state isRequired(pullRequestNumber: {number}) }}
+      }} }} }} }} }} }}
+    }}
+"#
+        ));
+    }
+    query.push_str("  }\n}");
+    query
+}
+
+/// Convert a failed request into a forge error, keeping timeouts apart from
+/// other network failures.
+fn request_error(e: reqwest::Error) -> ForgeError {
+    if e.is_timeout() {
+        ForgeError::Timeout(net::describe(&e))
+    } else {
+        ForgeError::NetworkError(e.to_string())
+    }
+}
+
+/// Convert a failure reading a successful response's body into a forge
+/// error; `what` names the body for parse errors.
+fn body_error(e: reqwest::Error, status: StatusCode, what: &str) -> ForgeError {
+    if e.is_timeout() {
+        request_error(e)
+    } else {
+        ForgeError::ApiError {
+            status: status.as_u16(),
+            message: format!("Failed to parse {}: {}", what, e),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+// API Request/Response Types
+// --------------------------------------------------------------------------
+
+/// Request body for creating a PR.
+#[derive(Serialize)]
+struct CreatePrBody<'a> {
+    head: &'a str,
+    base: &'a str,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'a str>,
+    draft: bool,
+}
+
+/// Request body for updating a PR.
+#[derive(Serialize)]
+struct UpdatePrBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<&'a str>,
+}
+
+/// Request body for closing a PR.
+#[derive(Serialize)]
+struct ClosePrBody {
+    state: &'static str,
+}
+
+/// Request body for commenting on a PR.
+#[derive(Serialize)]
+struct CommentBody<'a> {
+    body: &'a str,
 }
 
 /// Request body for requesting reviewers.
@@ -1031,6 +1680,22 @@ struct GitHubErrorResponse {
     message: String,
 }
 
+/// `/rate_limit` response format.
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    resources: HashMap<String, RateLimitBudget>,
+}
+
+/// One resource's budget in a `/rate_limit` response.
+#[derive(Deserialize)]
+struct RateLimitBudget {
+    limit: u64,
+    remaining: u64,
+    #[serde(default)]
+    used: u64,
+    reset: u64,
+}
+
 /// GitHub PR response format.
 #[derive(Deserialize)]
 struct GitHubPullRequest {
@@ -1044,6 +1709,19 @@ struct GitHubPullRequest {
     body: Option<String>,
     node_id: String,
     merged: Option<bool>,
+    /// Absent for deleted accounts
+    #[serde(default)]
+    user: Option<GitHubOwnerInfo>,
+}
+
+/// GitHub issue response format.
+#[derive(Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    html_url: String,
+    title: String,
+    /// Present when the issue is a pull request
+    pull_request: Option<serde_json::Value>,
 }
 
 /// GitHub ref (head/base) format.
@@ -1064,6 +1742,9 @@ struct GitHubPullRequestListItem {
     head: GitHubHeadRefWithRepo,
     base: GitHubRef,
     updated_at: String,
+    /// Absent for deleted accounts
+    #[serde(default)]
+    user: Option<GitHubOwnerInfo>,
 }
 
 /// GitHub head ref with repository info (for fork detection).
@@ -1099,6 +1780,7 @@ impl From<GitHubPullRequestListItem> for PullRequestSummary {
             is_draft: gh.draft,
             url: gh.html_url,
             updated_at: gh.updated_at,
+            author: gh.user.map(|u| u.login),
         }
     }
 }
@@ -1117,6 +1799,441 @@ struct GraphQLError {
     message: String,
 }
 
+/// GraphQL response for the `enqueuePullRequest` mutation.
+#[derive(Deserialize)]
+struct EnqueueResponse {
+    data: Option<EnqueueData>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Deserialize)]
+struct EnqueueData {
+    #[serde(rename = "enqueuePullRequest")]
+    enqueue_pull_request: Option<EnqueuePayload>,
+}
+
+#[derive(Deserialize)]
+struct EnqueuePayload {
+    #[serde(rename = "mergeQueueEntry")]
+    merge_queue_entry: Option<GitHubMergeQueueEntry>,
+}
+
+#[derive(Deserialize)]
+struct GitHubMergeQueueEntry {
+    position: Option<u64>,
+    state: String,
+}
+
+impl EnqueueResponse {
+    /// The queue entry, or the first GraphQL error.
+    ///
+    /// GitHub reports a base branch without a merge queue, or a PR that
+    /// can't be queued, as a GraphQL error.
+    fn into_entry(self) -> Result<MergeQueueEntry, ForgeError> {
+        if let Some(error) = self.errors.and_then(|e| e.into_iter().next()) {
+            return Err(ForgeError::ApiError {
+                status: 200,
+                message: error.message,
+            });
+        }
+        let entry = self
+            .data
+            .and_then(|d| d.enqueue_pull_request)
+            .and_then(|p| p.merge_queue_entry)
+            .ok_or_else(|| ForgeError::ApiError {
+                status: 200,
+                message: "GitHub returned no merge queue entry".into(),
+            })?;
+        let state = match entry.state.as_str() {
+            "AWAITING_CHECKS" => MergeQueueState::AwaitingChecks,
+            "MERGEABLE" => MergeQueueState::Mergeable,
+            "UNMERGEABLE" => MergeQueueState::Unmergeable,
+            "LOCKED" => MergeQueueState::Locked,
+            _ => MergeQueueState::Queued,
+        };
+        Ok(MergeQueueEntry {
+            position: entry.position,
+            state,
+        })
+    }
+}
+
+/// GraphQL response for the PR check rollup query.
+#[derive(Deserialize)]
+struct ChecksQueryResponse {
+    data: Option<ChecksData>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Deserialize)]
+struct ChecksData {
+    repository: Option<ChecksRepository>,
+}
+
+#[derive(Deserialize)]
+struct ChecksRepository {
+    #[serde(rename = "pullRequest")]
+    pull_request: Option<ChecksPullRequest>,
+}
+
+#[derive(Deserialize)]
+struct ChecksPullRequest {
+    commits: GraphQLNodes<ChecksCommitNode>,
+}
+
+/// GraphQL connection `nodes` list.
+#[derive(Deserialize)]
+struct GraphQLNodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct ChecksCommitNode {
+    commit: ChecksCommit,
+}
+
+#[derive(Deserialize)]
+struct ChecksCommit {
+    #[serde(rename = "statusCheckRollup")]
+    status_check_rollup: Option<ChecksRollup>,
+}
+
+#[derive(Deserialize)]
+struct ChecksRollup {
+    contexts: GraphQLNodes<CheckContext>,
+}
+
+/// GraphQL response for a batch of PR statuses.
+#[derive(Deserialize)]
+struct PrStatusResponse {
+    data: Option<PrStatusData>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Deserialize)]
+struct PrStatusData {
+    /// Aliased `pullRequest` fields; `null` for PRs that don't exist
+    repository: Option<HashMap<String, Option<PrStatusNode>>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrStatusNode {
+    id: String,
+    number: u64,
+    url: String,
+    state: String,
+    is_draft: bool,
+    head_ref_name: String,
+    base_ref_name: String,
+    title: String,
+    body: String,
+    /// Absent for deleted accounts
+    author: Option<GitHubOwnerInfo>,
+    mergeable: String,
+    review_decision: Option<String>,
+    #[serde(default)]
+    head_ref_oid: Option<String>,
+    /// Absent when the base branch was deleted
+    #[serde(default)]
+    base_ref: Option<BaseRefNode>,
+    #[serde(default)]
+    latest_opinionated_reviews: Option<GraphQLNodes<ReviewNode>>,
+    #[serde(default)]
+    review_requests: Option<GraphQLNodes<ReviewRequestNode>>,
+    commits: GraphQLNodes<ChecksCommitNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BaseRefNode {
+    /// Absent for unprotected branches
+    branch_protection_rule: Option<ProtectionRuleNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProtectionRuleNode {
+    requires_approving_reviews: bool,
+    required_approving_review_count: Option<u32>,
+    requires_code_owner_reviews: bool,
+    dismisses_stale_reviews: bool,
+}
+
+#[derive(Deserialize)]
+struct ReviewNode {
+    /// `APPROVED`, `CHANGES_REQUESTED`, `COMMENTED`, or `DISMISSED`
+    state: String,
+    author: Option<GitHubOwnerInfo>,
+    commit: Option<ReviewCommit>,
+}
+
+#[derive(Deserialize)]
+struct ReviewCommit {
+    oid: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewRequestNode {
+    as_code_owner: bool,
+    requested_reviewer: Option<RequestedReviewer>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum RequestedReviewer {
+    User {
+        login: String,
+    },
+    Team {
+        slug: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl PrStatusNode {
+    /// Sort the PR's reviews into a [`ReviewState`], or `None` when the
+    /// response left them out.
+    fn review_state(&mut self) -> Option<ReviewState> {
+        let reviews = self.latest_opinionated_reviews.take()?;
+        let mut state = ReviewState::default();
+        for review in reviews.nodes {
+            let Some(author) = review.author.map(|a| a.login) else {
+                continue;
+            };
+            match review.state.as_str() {
+                "APPROVED" => {
+                    let current = match (&review.commit, &self.head_ref_oid) {
+                        (Some(commit), Some(head)) => &commit.oid == head,
+                        _ => true,
+                    };
+                    if current {
+                        state.approved_by.push(author);
+                    } else {
+                        state.stale_approvals.push(author);
+                    }
+                }
+                "CHANGES_REQUESTED" => state.changes_requested_by.push(author),
+                _ => {}
+            }
+        }
+        if let Some(rule) = self.base_ref.take().and_then(|b| b.branch_protection_rule) {
+            state.required_approvals = rule
+                .requires_approving_reviews
+                .then(|| rule.required_approving_review_count.unwrap_or(1));
+            state.requires_code_owners = rule.requires_code_owner_reviews;
+            state.dismisses_stale = rule.dismisses_stale_reviews;
+        }
+        state.pending_code_owners = self
+            .review_requests
+            .take()
+            .map(|r| r.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| r.as_code_owner)
+            .filter_map(|r| match r.requested_reviewer? {
+                RequestedReviewer::User { login } => Some(login),
+                RequestedReviewer::Team { slug } => Some(format!("team:{}", slug)),
+                RequestedReviewer::Unknown => None,
+            })
+            .collect();
+        Some(state)
+    }
+}
+
+impl PrStatusResponse {
+    /// The statuses of the PRs in `numbers`, in order.
+    ///
+    /// A PR that doesn't exist comes back as `null` with an error of its
+    /// own, so errors only fail the batch when there is no data at all.
+    fn into_statuses(self, numbers: &[u64]) -> Result<Vec<PrStatus>, ForgeError> {
+        let Some(mut prs) = self.data.and_then(|d| d.repository) else {
+            let message = self
+                .errors
+                .and_then(|e| e.into_iter().next())
+                .map(|e| e.message)
+                .unwrap_or_else(|| "GitHub returned no repository".to_string());
+            return Err(ForgeError::ApiError {
+                status: 200,
+                message,
+            });
+        };
+        Ok((0..numbers.len())
+            .filter_map(|i| prs.remove(&format!("pr{}", i)).flatten())
+            .map(PrStatus::from)
+            .collect())
+    }
+}
+
+impl From<PrStatusNode> for PrStatus {
+    fn from(mut node: PrStatusNode) -> Self {
+        let reviews = node.review_state();
+        let state = match node.state.as_str() {
+            "MERGED" => PrState::Merged,
+            "CLOSED" => PrState::Closed,
+            _ => PrState::Open,
+        };
+        let mergeable = match node.mergeable.as_str() {
+            "MERGEABLE" => Some(true),
+            "CONFLICTING" => Some(false),
+            _ => None,
+        };
+        let review_decision = match node.review_decision.as_deref() {
+            Some("APPROVED") => Some(ReviewDecision::Approved),
+            Some("CHANGES_REQUESTED") => Some(ReviewDecision::ChangesRequested),
+            Some("REVIEW_REQUIRED") => Some(ReviewDecision::ReviewRequired),
+            _ => None,
+        };
+        let checks = node
+            .commits
+            .nodes
+            .into_iter()
+            .filter_map(|n| n.commit.status_check_rollup)
+            .flat_map(|rollup| rollup.contexts.nodes)
+            .filter_map(CheckContext::into_status)
+            .collect();
+
+        PrStatus {
+            pr: PullRequest {
+                number: node.number,
+                url: node.url,
+                state,
+                is_draft: node.is_draft,
+                head: node.head_ref_name,
+                base: node.base_ref_name,
+                title: node.title,
+                // GraphQL spells a missing body as an empty one
+                body: Some(node.body).filter(|b| !b.is_empty()),
+                node_id: Some(node.id),
+                author: node.author.map(|a| a.login),
+            },
+            mergeable,
+            review_decision,
+            reviews,
+            checks,
+        }
+    }
+}
+
+/// A single entry in a status check rollup.
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum CheckContext {
+    CheckRun {
+        name: String,
+        status: String,
+        conclusion: Option<String>,
+        #[serde(rename = "isRequired")]
+        is_required: bool,
+    },
+    StatusContext {
+        context: String,
+        state: String,
+        #[serde(rename = "isRequired")]
+        is_required: bool,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl CheckContext {
+    /// Normalize a rollup entry into a [`CheckStatus`].
+    fn into_status(self) -> Option<CheckStatus> {
+        match self {
+            CheckContext::CheckRun {
+                name,
+                status,
+                conclusion,
+                is_required,
+            } => {
+                let state = if status != "COMPLETED" {
+                    CheckState::Pending
+                } else {
+                    match conclusion.as_deref() {
+                        Some("SUCCESS") => CheckState::Success,
+                        Some("NEUTRAL") | Some("SKIPPED") => CheckState::Skipped,
+                        Some(_) => CheckState::Failure,
+                        None => CheckState::Pending,
+                    }
+                };
+                Some(CheckStatus {
+                    name,
+                    state,
+                    required: is_required,
+                })
+            }
+            CheckContext::StatusContext {
+                context,
+                state,
+                is_required,
+            } => {
+                let state = match state.as_str() {
+                    "SUCCESS" => CheckState::Success,
+                    "FAILURE" | "ERROR" => CheckState::Failure,
+                    _ => CheckState::Pending,
+                };
+                Some(CheckStatus {
+                    name: context,
+                    state,
+                    required: is_required,
+                })
+            }
+            CheckContext::Unknown => None,
+        }
+    }
+}
+
+/// Check runs on a commit, from the REST API.
+#[derive(Deserialize)]
+struct GitHubCheckRuns {
+    check_runs: Vec<GitHubCheckRun>,
+}
+
+#[derive(Deserialize)]
+struct GitHubCheckRun {
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+}
+
+/// Combined legacy status of a commit, from the REST API.
+#[derive(Deserialize)]
+struct GitHubCombinedStatus {
+    statuses: Vec<GitHubCommitStatus>,
+}
+
+#[derive(Deserialize)]
+struct GitHubCommitStatus {
+    context: String,
+    state: String,
+}
+
+// REST spells GraphQL's enum values in lowercase; normalize them so one
+// mapping serves both APIs.
+
+impl From<GitHubCheckRun> for CheckContext {
+    fn from(run: GitHubCheckRun) -> Self {
+        CheckContext::CheckRun {
+            name: run.name,
+            status: run.status.to_uppercase(),
+            conclusion: run.conclusion.map(|c| c.to_uppercase()),
+            is_required: false,
+        }
+    }
+}
+
+impl From<GitHubCommitStatus> for CheckContext {
+    fn from(status: GitHubCommitStatus) -> Self {
+        CheckContext::StatusContext {
+            context: status.context,
+            state: status.state.to_uppercase(),
+            is_required: false,
+        }
+    }
+}
+
 impl From<GitHubPullRequest> for PullRequest {
     fn from(pr: GitHubPullRequest) -> Self {
         let state = if pr.merged.unwrap_or(false) {
@@ -1137,6 +2254,7 @@ impl From<GitHubPullRequest> for PullRequest {
             title: pr.title,
             body: pr.body,
             node_id: Some(pr.node_id),
+            author: pr.user.map(|u| u.login),
         }
     }
 }
@@ -1145,13 +2263,27 @@ impl From<GitHubPullRequest> for PullRequest {
 // URL Parsing
 // --------------------------------------------------------------------------
 
-/// Parse a GitHub remote URL to extract owner and repo.
+/// A GitHub repository remote on any host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubRemote {
+    /// Host name, with port if the remote gives one for HTTP(S)
+    pub host: String,
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+}
+
+/// Parse a github.com remote URL to extract owner and repo.
 ///
 /// Supports both SSH and HTTPS formats:
 /// - `git@github.com:owner/repo.git`
 /// - `https://github.com/owner/repo.git`
 /// - `https://github.com/owner/repo`
 ///
+/// Remotes on GitHub Enterprise Server hosts are not matched; use
+/// [`parse_github_remote`] for those.
+///
 /// # Returns
 ///
 /// `Some((owner, repo))` if the URL is a valid GitHub URL, `None` otherwise.
@@ -1166,34 +2298,337 @@ impl From<GitHubPullRequest> for PullRequest {
 /// assert_eq!(repo, "hello-world");
 /// ```
 pub fn parse_github_url(url: &str) -> Option<(String, String)> {
-    // SSH format: git@github.com:owner/repo.git
-    if let Some(rest) = url.strip_prefix("git@github.com:") {
-        let rest = rest.strip_suffix(".git").unwrap_or(rest);
-        let parts: Vec<&str> = rest.splitn(2, '/').collect();
-        if parts.len() == 2 {
-            return Some((parts[0].to_string(), parts[1].to_string()));
-        }
-    }
+    let remote = parse_github_remote(url)?;
+    (remote.host == GITHUB_COM).then_some((remote.owner, remote.repo))
+}
 
-    // HTTPS format: https://github.com/owner/repo.git
-    if let Some(rest) = url
-        .strip_prefix("https://github.com/")
-        .or_else(|| url.strip_prefix("http://github.com/"))
+/// Parse a remote URL on any host as a GitHub repository.
+///
+/// Supports `git@host:owner/repo`, `ssh://git@host[:port]/owner/repo`, and
+/// `http(s)://[user@]host[:port]/owner/repo`, with or without `.git`.
+///
+/// # Example
+///
+/// ```
+/// use latticework::forge::github::parse_github_remote;
+///
+/// let remote = parse_github_remote("git@github.example.com:team/app.git").unwrap();
+/// assert_eq!(remote.host, "github.example.com");
+/// assert_eq!(remote.owner, "team");
+/// assert_eq!(remote.repo, "app");
+/// ```
+pub fn parse_github_remote(url: &str) -> Option<GitHubRemote> {
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
     {
-        let rest = rest.strip_suffix(".git").unwrap_or(rest);
-        let parts: Vec<&str> = rest.splitn(2, '/').collect();
-        if parts.len() == 2 && !parts[1].is_empty() {
-            return Some((parts[0].to_string(), parts[1].to_string()));
-        }
+        let (authority, path) = rest.split_once('/')?;
+        (authority.rsplit('@').next()?, path)
+    } else if let Some(rest) = url.strip_prefix("ssh://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next()?;
+        // The SSH port says nothing about where the API is served
+        (host.split(':').next()?, path)
+    } else {
+        let (user_host, path) = url.split_once(':')?;
+        let (_, host) = user_host.split_once('@')?;
+        (host, path)
+    };
+    if host.is_empty() || host.contains('/') {
+        return None;
+    }
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
     }
+    Some(GitHubRemote {
+        host: host.to_string(),
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+    })
+}
 
-    None
+/// Parse the web URL of a pull request, on any host.
+///
+/// Accepts `https://<host>/<owner>/<repo>/pull/<number>`, optionally
+/// followed by a tab (`/files`, `/commits`), a query, or a fragment, as
+/// copied from the browser.
+///
+/// # Example
+///
+/// ```
+/// use latticework::forge::github::parse_github_pr_url;
+///
+/// let (remote, number) =
+///     parse_github_pr_url("https://github.com/octocat/hello-world/pull/42/files").unwrap();
+/// assert_eq!(remote.owner, "octocat");
+/// assert_eq!(remote.repo, "hello-world");
+/// assert_eq!(number, 42);
+/// ```
+pub fn parse_github_pr_url(url: &str) -> Option<(GitHubRemote, u64)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.split(['?', '#']).next()?;
+    let mut segments = rest.split('/');
+    let host = segments.next()?.rsplit('@').next()?;
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    if segments.next()? != "pull" {
+        return None;
+    }
+    let number = segments.next()?.parse().ok()?;
+    if host.is_empty() || owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+    Some((
+        GitHubRemote {
+            host: host.to_string(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        },
+        number,
+    ))
+}
+
+/// The REST API base URL for a GitHub remote.
+///
+/// github.com remotes use `https://api.github.com`. Any other host is taken
+/// to be GitHub Enterprise Server, served at `https://<host>/api/v3` (or
+/// `http` when the remote itself is plain HTTP).
+///
+/// # Example
+///
+/// ```
+/// use latticework::forge::github::api_base_for_url;
+///
+/// assert_eq!(
+///     api_base_for_url("git@github.com:owner/repo.git").as_deref(),
+///     Some("https://api.github.com")
+/// );
+/// assert_eq!(
+///     api_base_for_url("https://github.example.com/owner/repo.git").as_deref(),
+///     Some("https://github.example.com/api/v3")
+/// );
+/// ```
+pub fn api_base_for_url(url: &str) -> Option<String> {
+    let remote = parse_github_remote(url)?;
+    if remote.host == GITHUB_COM {
+        return Some(DEFAULT_API_BASE.to_string());
+    }
+    let scheme = if url.starts_with("http://") {
+        "http"
+    } else {
+        "https"
+    };
+    Some(format!("{}://{}/api/v3", scheme, remote.host))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod enqueue_response {
+        use super::*;
+
+        fn parse(json: &str) -> Result<MergeQueueEntry, ForgeError> {
+            serde_json::from_str::<EnqueueResponse>(json)
+                .unwrap()
+                .into_entry()
+        }
+
+        #[test]
+        fn entry_maps_position_and_state() {
+            let entry = parse(
+                r#"{"data":{"enqueuePullRequest":{"mergeQueueEntry":{"position":3,"state":"AWAITING_CHECKS"}}}}"#,
+            )
+            .unwrap();
+            assert_eq!(entry.position, Some(3));
+            assert_eq!(entry.state, MergeQueueState::AwaitingChecks);
+        }
+
+        #[test]
+        fn graphql_error_is_reported() {
+            let err = parse(
+                r#"{"data":{"enqueuePullRequest":null},"errors":[{"message":"Merge queue is not enabled"}]}"#,
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("Merge queue is not enabled"));
+        }
+    }
+
+    mod pr_status_response {
+        use super::*;
+
+        fn node(number: u64, state: &str) -> serde_json::Value {
+            serde_json::json!({
+                "id": format!("PR_{}", number),
+                "number": number,
+                "url": format!("https://github.com/o/r/pull/{}", number),
+                "state": state,
+                "isDraft": false,
+                "headRefName": format!("branch-{}", number),
+                "baseRefName": "main",
+                "title": "Title",
+                "body": "",
+                "author": { "login": "octocat" },
+                "mergeable": "CONFLICTING",
+                "reviewDecision": "APPROVED",
+                "commits": { "nodes": [{ "commit": { "statusCheckRollup": { "contexts": { "nodes": [
+                    { "__typename": "StatusContext", "context": "ci", "state": "SUCCESS", "isRequired": true }
+                ] } } } }] }
+            })
+        }
+
+        #[test]
+        fn statuses_follow_requested_order_and_skip_missing() {
+            let json = serde_json::json!({
+                "data": { "repository": {
+                    "pr0": node(7, "MERGED"),
+                    "pr1": null,
+                    "pr2": node(3, "OPEN"),
+                } },
+                "errors": [{ "message": "Could not resolve to a PullRequest with the number of 99." }]
+            });
+            let statuses = serde_json::from_value::<PrStatusResponse>(json)
+                .unwrap()
+                .into_statuses(&[7, 99, 3])
+                .unwrap();
+
+            assert_eq!(statuses.len(), 2);
+            assert_eq!(statuses[0].pr.number, 7);
+            assert_eq!(statuses[0].pr.state, PrState::Merged);
+            let open = &statuses[1];
+            assert_eq!(open.pr.state, PrState::Open);
+            assert_eq!(open.pr.body, None);
+            assert_eq!(open.mergeable, Some(false));
+            assert_eq!(open.review_decision, Some(ReviewDecision::Approved));
+            assert_eq!(open.checks.len(), 1);
+            assert!(open.checks[0].required);
+        }
+
+        #[test]
+        fn reviews_split_current_stale_and_code_owners() {
+            let mut pr = node(3, "OPEN");
+            pr["headRefOid"] = "head".into();
+            pr["baseRef"] = serde_json::json!({ "branchProtectionRule": {
+                "requiresApprovingReviews": true,
+                "requiredApprovingReviewCount": 2,
+                "requiresCodeOwnerReviews": true,
+                "dismissesStaleReviews": false
+            } });
+            pr["latestOpinionatedReviews"] = serde_json::json!({ "nodes": [
+                { "state": "APPROVED", "author": { "login": "alice" }, "commit": { "oid": "head" } },
+                { "state": "APPROVED", "author": { "login": "bob" }, "commit": { "oid": "older" } },
+                { "state": "CHANGES_REQUESTED", "author": { "login": "carol" }, "commit": { "oid": "head" } },
+                { "state": "COMMENTED", "author": { "login": "dave" }, "commit": { "oid": "head" } }
+            ] });
+            pr["reviewRequests"] = serde_json::json!({ "nodes": [
+                { "asCodeOwner": true, "requestedReviewer": { "__typename": "Team", "slug": "platform" } },
+                { "asCodeOwner": false, "requestedReviewer": { "__typename": "User", "login": "erin" } }
+            ] });
+            let json = serde_json::json!({ "data": { "repository": { "pr0": pr } } });
+            let statuses = serde_json::from_value::<PrStatusResponse>(json)
+                .unwrap()
+                .into_statuses(&[3])
+                .unwrap();
+
+            let reviews = statuses[0].reviews.clone().unwrap();
+            assert_eq!(reviews.approved_by, vec!["alice"]);
+            assert_eq!(reviews.stale_approvals, vec!["bob"]);
+            assert_eq!(reviews.changes_requested_by, vec!["carol"]);
+            assert_eq!(reviews.required_approvals, Some(2));
+            assert!(reviews.requires_code_owners);
+            assert_eq!(reviews.pending_code_owners, vec!["team:platform"]);
+            assert_eq!(reviews.counted_approvals(), 2);
+        }
+
+        #[test]
+        fn missing_repository_reports_error() {
+            let json = r#"{"data":{"repository":null},"errors":[{"message":"Could not resolve to a Repository"}]}"#;
+            let err = serde_json::from_str::<PrStatusResponse>(json)
+                .unwrap()
+                .into_statuses(&[1])
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("Could not resolve to a Repository"));
+        }
+
+        #[test]
+        fn query_aliases_each_pr() {
+            let query = pr_status_query(&[12, 40]);
+            assert!(query.contains("pr0: pullRequest(number: 12)"));
+            assert!(query.contains("pr1: pullRequest(number: 40)"));
+            assert!(query.contains("isRequired(pullRequestNumber: 40)"));
+        }
+    }
+
+    mod check_context {
+        use super::*;
+
+        fn parse(json: &str) -> Option<CheckStatus> {
+            serde_json::from_str::<CheckContext>(json)
+                .unwrap()
+                .into_status()
+        }
+
+        #[test]
+        fn completed_check_run_maps_conclusion() {
+            let status = parse(
+                r#"{"__typename":"CheckRun","name":"test","status":"COMPLETED","conclusion":"TIMED_OUT","isRequired":true}"#,
+            )
+            .unwrap();
+            assert_eq!(status.name, "test");
+            assert_eq!(status.state, CheckState::Failure);
+            assert!(status.required);
+        }
+
+        #[test]
+        fn running_check_run_is_pending() {
+            let status = parse(
+                r#"{"__typename":"CheckRun","name":"test","status":"IN_PROGRESS","conclusion":null,"isRequired":false}"#,
+            )
+            .unwrap();
+            assert_eq!(status.state, CheckState::Pending);
+        }
+
+        #[test]
+        fn status_context_maps_state() {
+            let status = parse(
+                r#"{"__typename":"StatusContext","context":"ci/legacy","state":"ERROR","isRequired":true}"#,
+            )
+            .unwrap();
+            assert_eq!(status.name, "ci/legacy");
+            assert_eq!(status.state, CheckState::Failure);
+        }
+
+        #[test]
+        fn unknown_typename_is_skipped() {
+            assert_eq!(parse(r#"{"__typename":"SomethingNew"}"#), None);
+        }
+
+        #[test]
+        fn rest_check_run_and_status_map_like_graphql() {
+            let run: GitHubCheckRun = serde_json::from_str(
+                r#"{"name":"build","status":"completed","conclusion":"skipped"}"#,
+            )
+            .unwrap();
+            let status = CheckContext::from(run).into_status().unwrap();
+            assert_eq!(status.state, CheckState::Skipped);
+            assert!(!status.required);
+
+            let legacy: GitHubCommitStatus =
+                serde_json::from_str(r#"{"context":"ci/legacy","state":"pending"}"#).unwrap();
+            let status = CheckContext::from(legacy).into_status().unwrap();
+            assert_eq!(status.name, "ci/legacy");
+            assert_eq!(status.state, CheckState::Pending);
+        }
+    }
+
     mod parse_github_url {
         use super::*;
 
@@ -1268,11 +2703,112 @@ mod tests {
             let result = parse_github_url("git@github.com:my-org/my-repo.git");
             assert_eq!(result, Some(("my-org".to_string(), "my-repo".to_string())));
         }
+
+        #[test]
+        fn enterprise_host_is_not_github_com() {
+            assert!(parse_github_url("git@github.example.com:owner/repo.git").is_none());
+        }
+    }
+
+    mod parse_github_remote {
+        use super::*;
+
+        fn remote(host: &str, owner: &str, repo: &str) -> Option<GitHubRemote> {
+            Some(GitHubRemote {
+                host: host.to_string(),
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+            })
+        }
+
+        #[test]
+        fn enterprise_formats() {
+            let expected = remote("github.example.com", "team", "app");
+            assert_eq!(
+                parse_github_remote("git@github.example.com:team/app.git"),
+                expected
+            );
+            assert_eq!(
+                parse_github_remote("https://github.example.com/team/app.git"),
+                expected
+            );
+            assert_eq!(
+                parse_github_remote("ssh://git@github.example.com:2222/team/app"),
+                expected
+            );
+            assert_eq!(
+                parse_github_remote("https://ci-bot@github.example.com/team/app/"),
+                expected
+            );
+        }
+
+        #[test]
+        fn http_port_is_kept() {
+            assert_eq!(
+                parse_github_remote("http://localhost:8080/team/app.git"),
+                remote("localhost:8080", "team", "app")
+            );
+        }
+
+        #[test]
+        fn invalid_paths() {
+            assert!(parse_github_remote("git@github.example.com:team.git").is_none());
+            assert!(parse_github_remote("https://github.example.com/a/b/c").is_none());
+            assert!(parse_github_remote("github.example.com/team/app").is_none());
+        }
+
+        #[test]
+        fn pr_urls() {
+            assert_eq!(
+                parse_github_pr_url("https://github.com/octocat/hello/pull/42"),
+                Some((remote("github.com", "octocat", "hello").unwrap(), 42))
+            );
+            assert_eq!(
+                parse_github_pr_url("https://github.example.com/team/app/pull/7/files?w=1"),
+                Some((remote("github.example.com", "team", "app").unwrap(), 7))
+            );
+            assert_eq!(
+                parse_github_pr_url("https://github.com/o/r/pull/9#discussion_r1").map(|(_, n)| n),
+                Some(9)
+            );
+            assert!(parse_github_pr_url("https://github.com/o/r/issues/9").is_none());
+            assert!(parse_github_pr_url("https://github.com/o/r/pull/abc").is_none());
+            assert!(parse_github_pr_url("git@github.com:o/r.git").is_none());
+        }
+
+        #[test]
+        fn api_base_follows_host() {
+            assert_eq!(
+                api_base_for_url("https://github.com/owner/repo").as_deref(),
+                Some("https://api.github.com")
+            );
+            assert_eq!(
+                api_base_for_url("git@github.example.com:team/app.git").as_deref(),
+                Some("https://github.example.com/api/v3")
+            );
+            assert_eq!(
+                api_base_for_url("http://localhost:8080/team/app.git").as_deref(),
+                Some("http://localhost:8080/api/v3")
+            );
+            assert_eq!(api_base_for_url("not a url"), None);
+        }
     }
 
     mod github_forge {
         use super::*;
 
+        #[tokio::test]
+        async fn anonymous_forge_sends_no_token_and_skips_graphql() {
+            let forge = GitHubForge::anonymous("owner", "repo");
+            assert!(forge.is_anonymous());
+            let headers = forge.headers().await.unwrap();
+            assert!(headers.get(AUTHORIZATION).is_none());
+            assert!(matches!(
+                forge.get_pr_statuses(&[1]).await,
+                Err(ForgeError::AuthRequired)
+            ));
+        }
+
         #[test]
         #[allow(deprecated)]
         fn new_creates_forge() {
@@ -1324,6 +2860,16 @@ mod tests {
             assert!(!forge.has_token_provider());
         }
 
+        #[test]
+        #[allow(deprecated)]
+        fn graphql_url_follows_api_base() {
+            let forge = GitHubForge::new("token", "owner", "repo");
+            assert_eq!(forge.graphql_url(), "https://api.github.com/graphql");
+
+            let forge = GitHubForge::with_api_base("token", "owner", "repo", "http://127.0.0.1:9");
+            assert_eq!(forge.graphql_url(), "http://127.0.0.1:9/graphql");
+        }
+
         #[test]
         #[allow(deprecated)]
         fn repo_url_format() {
@@ -1426,6 +2972,24 @@ mod tests {
             assert!(forge.has_token_provider());
         }
 
+        #[test]
+        fn from_enterprise_url_with_provider() {
+            let provider: Arc<dyn crate::auth::TokenProvider> =
+                Arc::new(MockTokenProvider::new("test_token", "github.example.com"));
+            let forge = GitHubForge::from_enterprise_url_with_provider(
+                "git@github.example.com:team/app.git",
+                provider,
+            )
+            .unwrap();
+
+            assert_eq!(forge.owner(), "team");
+            assert_eq!(forge.api_base(), "https://github.example.com/api/v3");
+            assert_eq!(
+                forge.graphql_url(),
+                "https://github.example.com/api/graphql"
+            );
+        }
+
         #[test]
         fn from_remote_url_with_provider_invalid_url() {
             let provider: Arc<dyn crate::auth::TokenProvider> =
@@ -1495,6 +3059,26 @@ mod tests {
         }
     }
 
+    mod github_issue {
+        use super::*;
+
+        #[test]
+        fn pull_request_marker_is_optional() {
+            let issue: GitHubIssue = serde_json::from_str(
+                r#"{"number": 7, "html_url": "https://github.com/o/r/issues/7", "title": "Bug"}"#,
+            )
+            .unwrap();
+            assert!(issue.pull_request.is_none());
+
+            let pr: GitHubIssue = serde_json::from_str(
+                r#"{"number": 8, "html_url": "https://github.com/o/r/pull/8", "title": "Fix",
+                    "pull_request": {"url": "https://api.github.com/repos/o/r/pulls/8"}}"#,
+            )
+            .unwrap();
+            assert!(pr.pull_request.is_some());
+        }
+    }
+
     mod github_pull_request {
         use super::*;
 
@@ -1515,9 +3099,13 @@ mod tests {
                 body: Some("PR description".to_string()),
                 node_id: "PR_123".to_string(),
                 merged: Some(false),
+                user: Some(GitHubOwnerInfo {
+                    login: "octocat".to_string(),
+                }),
             };
 
             let pr: PullRequest = gh_pr.into();
+            assert_eq!(pr.author.as_deref(), Some("octocat"));
             assert_eq!(pr.number, 42);
             assert_eq!(pr.url, "https://github.com/owner/repo/pull/42");
             assert_eq!(pr.state, PrState::Open);
@@ -1546,6 +3134,7 @@ mod tests {
                 body: None,
                 node_id: "PR_123".to_string(),
                 merged: None,
+                user: None,
             };
 
             let pr: PullRequest = gh_pr.into();
@@ -1571,6 +3160,7 @@ mod tests {
                 body: Some("Merged!".to_string()),
                 node_id: "PR_123".to_string(),
                 merged: Some(true),
+                user: None,
             };
 
             let pr: PullRequest = gh_pr.into();
@@ -1594,6 +3184,7 @@ mod tests {
                 body: None,
                 node_id: "PR_123".to_string(),
                 merged: Some(false),
+                user: None,
             };
 
             let pr: PullRequest = gh_pr.into();
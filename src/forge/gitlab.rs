@@ -1,17 +1,36 @@
 //! forge::gitlab
 //!
-//! GitLab forge stub implementation.
+//! GitLab forge implementation using the REST API (v4).
 //!
 //! # Design
 //!
-//! This module provides a stub implementation of the `Forge` trait for GitLab.
-//! All operations return `ForgeError::NotImplemented` to demonstrate the
-//! multi-forge architecture without implementing actual GitLab API calls.
+//! This module implements the `Forge` trait for GitLab merge requests. The
+//! trait speaks in pull requests; here a "PR number" is the merge request's
+//! project-scoped IID (`!12` is number 12).
 //!
-//! Per ROADMAP.md Milestone 10, this stub:
-//! - Compiles and is selectable in config
-//! - Returns stable, actionable errors
-//! - Proves the architecture boundary (core depends on Forge trait, not GitHub)
+//! GitLab differs from GitHub in a few places the trait hides:
+//! - Drafts are marked by a `Draft:` title prefix. `create_pr` and
+//!   `set_draft` add or remove it, `update_pr` keeps it, and titles returned
+//!   by this forge have it stripped.
+//! - Reviewers are user IDs; usernames are looked up first, and requesting
+//!   adds to the existing reviewers. GitLab has no team reviewers.
+//! - Merging uses the project's merge method, optionally squashing. Rebase
+//!   merges are not offered through the API in one step and are refused.
+//! - Checks are the jobs of the merge request's head pipeline; jobs that
+//!   are allowed to fail are not required.
+//! - There is no batch lookup: `get_pr_statuses` fetches each merge request
+//!   and its jobs in turn, and reports no review decision.
+//!
+//! # Authentication
+//!
+//! Requests carry a personal access token with the `api` scope as a bearer
+//! token. Tokens are static; there is no refresh.
+//!
+//! # Self-Hosted Instances
+//!
+//! [`GitLabForge::from_remote_url`] recognizes `gitlab.com` remotes.
+//! [`GitLabForge::from_self_hosted_url`] accepts a remote on any host and
+//! talks to `https://<host>/api/v4`.
 //!
 //! # Feature Flag
 //!
@@ -28,26 +47,50 @@
 //! use latticework::forge::gitlab::GitLabForge;
 //! use latticework::forge::{Forge, CreatePrRequest};
 //!
-//! let forge = GitLabForge::new("token", "owner", "repo");
+//! let forge = GitLabForge::new("glpat-xxx", "group", "project");
 //!
-//! // All operations return NotImplemented
-//! let result = forge.create_pr(request).await;
-//! assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+//! let mr = forge.create_pr(CreatePrRequest {
+//!     head: "feature".to_string(),
+//!     base: "main".to_string(),
+//!     title: "Add feature".to_string(),
+//!     body: None,
+//!     draft: true,
+//! }).await?;
 //! ```
 
 use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
 use super::traits::{
-    CreatePrRequest, Forge, ForgeError, MergeMethod, PullRequest, Reviewers, UpdatePrRequest,
+    CheckState, CheckStatus, CreatePrRequest, Forge, ForgeError, Issue, ListClosedPrsOpts,
+    ListPullsOpts, ListPullsResult, MergeMethod, MergeQueueEntry, PrState, PrStatus, PullRequest,
+    PullRequestSummary, Reviewers, UpdatePrRequest,
 };
+use crate::core::net;
 
-/// GitLab forge stub implementation.
-///
-/// This is a placeholder that returns `NotImplemented` for all operations.
-/// It exists to demonstrate the multi-forge architecture and validate that
-/// commands work correctly with forge selection.
-#[derive(Debug, Clone)]
+/// Default GitLab API base URL.
+const DEFAULT_API_BASE: &str = "https://gitlab.com/api/v4";
+
+/// Host of GitLab's hosted service.
+const GITLAB_COM: &str = "gitlab.com";
+
+/// User agent for API requests.
+const USER_AGENT_VALUE: &str = "lattice-cli";
+
+/// Title prefix GitLab uses to mark a draft.
+const DRAFT_PREFIX: &str = "Draft: ";
+
+/// Largest page GitLab serves.
+const PER_PAGE: usize = 100;
+
+/// GitLab forge implementation.
+#[derive(Clone)]
 pub struct GitLabForge {
+    /// HTTP client for making requests
+    client: Client,
     /// Personal access token for authentication
     token: String,
     /// Project owner (user or group)
@@ -58,11 +101,20 @@ pub struct GitLabForge {
     api_base: String,
 }
 
-/// Default GitLab API base URL.
-const DEFAULT_API_BASE: &str = "https://gitlab.com/api/v4";
+// Custom Debug to avoid exposing the token
+impl std::fmt::Debug for GitLabForge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitLabForge")
+            .field("has_token", &self.has_token())
+            .field("owner", &self.owner)
+            .field("project", &self.project)
+            .field("api_base", &self.api_base)
+            .finish()
+    }
+}
 
 impl GitLabForge {
-    /// Create a new GitLab forge.
+    /// Create a new GitLab forge for gitlab.com.
     ///
     /// # Arguments
     ///
@@ -82,12 +134,7 @@ impl GitLabForge {
         owner: impl Into<String>,
         project: impl Into<String>,
     ) -> Self {
-        Self {
-            token: token.into(),
-            owner: owner.into(),
-            project: project.into(),
-            api_base: DEFAULT_API_BASE.to_string(),
-        }
+        Self::with_api_base(token, owner, project, DEFAULT_API_BASE)
     }
 
     /// Create a GitLab forge with a custom API base URL.
@@ -107,14 +154,15 @@ impl GitLabForge {
         api_base: impl Into<String>,
     ) -> Self {
         Self {
+            client: net::client(),
             token: token.into(),
             owner: owner.into(),
             project: project.into(),
-            api_base: api_base.into(),
+            api_base: api_base.into().trim_end_matches('/').to_string(),
         }
     }
 
-    /// Create a GitLab forge from a remote URL.
+    /// Create a GitLab forge from a gitlab.com remote URL.
     ///
     /// Parses the remote URL to extract owner and project.
     ///
@@ -149,6 +197,37 @@ impl GitLabForge {
         Some(Self::new(token, owner, project))
     }
 
+    /// Create a GitLab forge from a remote URL on any host.
+    ///
+    /// The host is taken to be a GitLab instance serving its API at
+    /// `https://<host>/api/v4` (`http` when the remote itself is `http`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::forge::gitlab::GitLabForge;
+    ///
+    /// let forge =
+    ///     GitLabForge::from_self_hosted_url("git@git.example.com:team/app.git", "token").unwrap();
+    /// assert_eq!(forge.api_base(), "https://git.example.com/api/v4");
+    /// assert_eq!(forge.owner(), "team");
+    /// ```
+    pub fn from_self_hosted_url(url: &str, token: impl Into<String>) -> Option<Self> {
+        let remote = parse_gitlab_remote(url)?;
+        let scheme = if url.starts_with("http://") {
+            "http"
+        } else {
+            "https"
+        };
+        let api_base = format!("{}://{}/api/v4", scheme, remote.host);
+        Some(Self::with_api_base(
+            token,
+            remote.owner,
+            remote.project,
+            api_base,
+        ))
+    }
+
     /// Get the project owner.
     pub fn owner(&self) -> &str {
         &self.owner
@@ -165,12 +244,153 @@ impl GitLabForge {
     }
 
     /// Check if the forge has a token configured.
-    ///
-    /// Note: The token is stored but not used since all operations
-    /// return NotImplemented.
     pub fn has_token(&self) -> bool {
         !self.token.is_empty()
     }
+
+    /// Build common headers for API requests.
+    fn headers(&self) -> Result<HeaderMap, ForgeError> {
+        if !self.has_token() {
+            return Err(ForgeError::AuthRequired);
+        }
+        let mut headers = HeaderMap::new();
+        let bearer = HeaderValue::from_str(&format!("Bearer {}", self.token))
+            .map_err(|_| ForgeError::AuthFailed("token is not a valid header value".into()))?;
+        headers.insert(AUTHORIZATION, bearer);
+        headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_VALUE));
+        Ok(headers)
+    }
+
+    /// Build URL for a project endpoint.
+    ///
+    /// The project is addressed by its URL-encoded path (`group%2Fproject`).
+    fn project_url(&self, path: &str) -> String {
+        format!(
+            "{}/projects/{}%2F{}/{}",
+            self.api_base,
+            self.owner.replace('/', "%2F"),
+            self.project,
+            path
+        )
+    }
+
+    /// Send a request and decode the JSON response.
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, ForgeError> {
+        let response = request
+            .headers(self.headers()?)
+            .send()
+            .await
+            .map_err(request_error)?;
+        let status = response.status();
+
+        if status.is_success() {
+            response.json().await.map_err(|e| {
+                if e.is_timeout() {
+                    request_error(e)
+                } else {
+                    ForgeError::ApiError {
+                        status: status.as_u16(),
+                        message: format!("Failed to parse response: {}", e),
+                    }
+                }
+            })
+        } else {
+            let message = response
+                .json::<serde_json::Value>()
+                .await
+                .ok()
+                .and_then(|body| error_message(&body))
+                .unwrap_or_else(|| "Unknown error".to_string());
+            Err(status_error(status, message))
+        }
+    }
+
+    /// Get a merge request as GitLab returns it.
+    async fn get_mr(&self, number: u64) -> Result<GitLabMergeRequest, ForgeError> {
+        let url = self.project_url(&format!("merge_requests/{}", number));
+        self.send(self.client.get(&url)).await
+    }
+
+    /// The jobs of a merge request's head pipeline, as checks.
+    async fn pipeline_jobs(&self, mr: &GitLabMergeRequest) -> Result<Vec<CheckStatus>, ForgeError> {
+        let Some(pipeline) = &mr.head_pipeline else {
+            return Ok(Vec::new());
+        };
+        let url = self.project_url(&format!("pipelines/{}/jobs", pipeline.id));
+        let per_page = PER_PAGE.to_string();
+        let jobs: Vec<GitLabJob> = self
+            .send(
+                self.client
+                    .get(&url)
+                    .query(&[("per_page", per_page.as_str())]),
+            )
+            .await?;
+        Ok(jobs.into_iter().map(Into::into).collect())
+    }
+
+    /// Update a merge request's fields.
+    async fn put_mr(
+        &self,
+        number: u64,
+        body: &UpdateMrBody<'_>,
+    ) -> Result<GitLabMergeRequest, ForgeError> {
+        let url = self.project_url(&format!("merge_requests/{}", number));
+        self.send(self.client.put(&url).json(body)).await
+    }
+
+    /// List merge requests page by page, keeping those `keep` accepts, up
+    /// to `limit`.
+    async fn list_mrs(
+        &self,
+        query: &[(&str, &str)],
+        limit: usize,
+        keep: impl Fn(&GitLabMergeRequest) -> bool,
+    ) -> Result<ListPullsResult, ForgeError> {
+        let url = self.project_url("merge_requests");
+        let per_page = PER_PAGE.to_string();
+        let mut pulls = Vec::with_capacity(limit.min(PER_PAGE));
+        let mut truncated = false;
+        let mut page = 1;
+
+        while pulls.len() < limit {
+            let page_param = page.to_string();
+            let request = self.client.get(&url).query(query).query(&[
+                ("order_by", "updated_at"),
+                ("sort", "desc"),
+                ("per_page", per_page.as_str()),
+                ("page", page_param.as_str()),
+            ]);
+            let mrs: Vec<GitLabMergeRequest> = self.send(request).await?;
+            let page_count = mrs.len();
+
+            for mr in mrs.into_iter().filter(|mr| keep(mr)) {
+                if pulls.len() >= limit {
+                    truncated = true;
+                    break;
+                }
+                pulls.push(mr.into());
+            }
+
+            if page_count < PER_PAGE {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(ListPullsResult { pulls, truncated })
+    }
+
+    /// Look up a user's ID by username.
+    async fn user_id(&self, username: &str) -> Result<u64, ForgeError> {
+        let url = format!("{}/users", self.api_base);
+        let users: Vec<GitLabUser> = self
+            .send(self.client.get(&url).query(&[("username", username)]))
+            .await?;
+        users
+            .first()
+            .map(|u| u.id)
+            .ok_or_else(|| ForgeError::NotFound(format!("GitLab user '{}'", username)))
+    }
 }
 
 #[async_trait]
@@ -179,94 +399,455 @@ impl Forge for GitLabForge {
         "gitlab"
     }
 
-    async fn create_pr(&self, _request: CreatePrRequest) -> Result<PullRequest, ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab merge request creation is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn create_pr(&self, request: CreatePrRequest) -> Result<PullRequest, ForgeError> {
+        let url = self.project_url("merge_requests");
+        let title = with_draft(&request.title, request.draft);
+        let body = CreateMrBody {
+            source_branch: &request.head,
+            target_branch: &request.base,
+            title: &title,
+            description: request.body.as_deref(),
+        };
+        let mr: GitLabMergeRequest = self.send(self.client.post(&url).json(&body)).await?;
+        Ok(mr.into())
     }
 
-    async fn update_pr(&self, _request: UpdatePrRequest) -> Result<PullRequest, ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab merge request updates are not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn update_pr(&self, request: UpdatePrRequest) -> Result<PullRequest, ForgeError> {
+        // A new title must keep the draft marker the current one has
+        let title = match &request.title {
+            Some(title) => {
+                let current = self.get_mr(request.number).await?;
+                Some(with_draft(title, current.is_draft()))
+            }
+            None => None,
+        };
+        let body = UpdateMrBody {
+            title: title.as_deref(),
+            description: request.body.as_deref(),
+            target_branch: request.base.as_deref(),
+            ..Default::default()
+        };
+        Ok(self.put_mr(request.number, &body).await?.into())
     }
 
-    async fn get_pr(&self, _number: u64) -> Result<PullRequest, ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab merge request retrieval is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn get_pr(&self, number: u64) -> Result<PullRequest, ForgeError> {
+        Ok(self.get_mr(number).await?.into())
     }
 
-    async fn find_pr_by_head(&self, _head: &str) -> Result<Option<PullRequest>, ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab merge request search is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn get_pr_statuses(&self, numbers: &[u64]) -> Result<Vec<PrStatus>, ForgeError> {
+        let mut statuses = Vec::with_capacity(numbers.len());
+        for &number in numbers {
+            let mr = match self.get_mr(number).await {
+                Ok(mr) => mr,
+                Err(ForgeError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            let checks = self.pipeline_jobs(&mr).await?;
+            statuses.push(PrStatus {
+                mergeable: Some(!mr.has_conflicts),
+                review_decision: None,
+                reviews: None,
+                checks,
+                pr: mr.into(),
+            });
+        }
+        Ok(statuses)
     }
 
-    async fn set_draft(&self, _number: u64, _draft: bool) -> Result<(), ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab draft status toggling is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn find_pr_by_head(&self, head: &str) -> Result<Option<PullRequest>, ForgeError> {
+        let url = self.project_url("merge_requests");
+        let request = self
+            .client
+            .get(&url)
+            .query(&[("state", "opened"), ("source_branch", head)]);
+        let mrs: Vec<GitLabMergeRequest> = self.send(request).await?;
+        Ok(mrs.into_iter().next().map(Into::into))
     }
 
-    async fn request_reviewers(
-        &self,
-        _number: u64,
-        _reviewers: Reviewers,
-    ) -> Result<(), ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab reviewer requests are not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn set_draft(&self, number: u64, draft: bool) -> Result<(), ForgeError> {
+        let current = self.get_mr(number).await?;
+        if current.is_draft() == draft {
+            return Ok(());
+        }
+        let title = with_draft(&current.title, draft);
+        let body = UpdateMrBody {
+            title: Some(&title),
+            ..Default::default()
+        };
+        self.put_mr(number, &body).await.map(|_| ())
     }
 
-    async fn merge_pr(&self, _number: u64, _method: MergeMethod) -> Result<(), ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab merge request merging is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+    async fn request_reviewers(&self, number: u64, reviewers: Reviewers) -> Result<(), ForgeError> {
+        if !reviewers.teams.is_empty() {
+            return Err(ForgeError::NotImplemented(format!(
+                "GitLab has no team reviewers; request members of {} by username instead",
+                reviewers.teams.join(", ")
+            )));
+        }
+        if reviewers.users.is_empty() {
+            return Ok(());
+        }
+
+        // Setting reviewer_ids replaces the list, so start from the current one
+        let current = self.get_mr(number).await?;
+        let mut ids: Vec<u64> = current.reviewers.iter().map(|u| u.id).collect();
+        for username in &reviewers.users {
+            let id = self.user_id(username).await?;
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        let body = UpdateMrBody {
+            reviewer_ids: Some(&ids),
+            ..Default::default()
+        };
+        self.put_mr(number, &body).await.map(|_| ())
     }
 
-    async fn list_open_prs(
-        &self,
-        _opts: super::traits::ListPullsOpts,
-    ) -> Result<super::traits::ListPullsResult, ForgeError> {
+    async fn merge_pr(&self, number: u64, method: MergeMethod) -> Result<(), ForgeError> {
+        let squash = match method {
+            MergeMethod::Merge => false,
+            MergeMethod::Squash => true,
+            MergeMethod::Rebase => {
+                return Err(ForgeError::NotImplemented(
+                    "GitLab merges with the project's merge method; \
+                     use the merge or squash method instead of rebase"
+                        .to_string(),
+                ))
+            }
+        };
+        let url = self.project_url(&format!("merge_requests/{}/merge", number));
+        let _: GitLabMergeRequest = self
+            .send(self.client.put(&url).json(&MergeMrBody { squash }))
+            .await?;
+        Ok(())
+    }
+
+    async fn enqueue_pr(&self, _number: u64) -> Result<MergeQueueEntry, ForgeError> {
         Err(ForgeError::NotImplemented(
-            "GitLab open merge request listing is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
+            "merge queues are GitHub-only; merge trains aren't supported yet".to_string(),
         ))
     }
 
+    async fn close_pr(&self, number: u64) -> Result<(), ForgeError> {
+        let body = UpdateMrBody {
+            state_event: Some("close"),
+            ..Default::default()
+        };
+        self.put_mr(number, &body).await.map(|_| ())
+    }
+
+    async fn comment_pr(&self, number: u64, body: &str) -> Result<(), ForgeError> {
+        let url = self.project_url(&format!("merge_requests/{}/notes", number));
+        let _: serde_json::Value = self
+            .send(self.client.post(&url).json(&NoteBody { body }))
+            .await?;
+        Ok(())
+    }
+
+    async fn list_open_prs(&self, opts: ListPullsOpts) -> Result<ListPullsResult, ForgeError> {
+        self.list_mrs(&[("state", "opened")], opts.effective_limit(), |_| true)
+            .await
+    }
+
     async fn list_closed_prs_targeting(
         &self,
-        _opts: super::traits::ListClosedPrsOpts,
-    ) -> Result<super::traits::ListPullsResult, ForgeError> {
-        Err(ForgeError::NotImplemented(
-            "GitLab closed merge request listing is not yet implemented. \
-             See https://github.com/lattice-cli/lattice for updates."
-                .to_string(),
-        ))
+        opts: ListClosedPrsOpts,
+    ) -> Result<ListPullsResult, ForgeError> {
+        // GitLab filters by one state at a time; closed and merged are both wanted
+        self.list_mrs(
+            &[("target_branch", opts.base.as_str())],
+            opts.effective_limit(),
+            |mr| matches!(mr.state.as_str(), "closed" | "merged"),
+        )
+        .await
+    }
+
+    async fn list_checks(&self, number: u64) -> Result<Vec<CheckStatus>, ForgeError> {
+        let mr = self.get_mr(number).await?;
+        self.pipeline_jobs(&mr).await
+    }
+
+    async fn check_runs_for_ref(&self, git_ref: &str) -> Result<Vec<CheckStatus>, ForgeError> {
+        // Commit statuses cover pipeline jobs and external CI alike
+        let url = self.project_url(&format!("repository/commits/{}/statuses", git_ref));
+        let per_page = PER_PAGE.to_string();
+        let statuses: Vec<GitLabJob> = self
+            .send(
+                self.client
+                    .get(&url)
+                    .query(&[("per_page", per_page.as_str())]),
+            )
+            .await?;
+        Ok(statuses
+            .into_iter()
+            .map(|status| CheckStatus {
+                required: false,
+                ..status.into()
+            })
+            .collect())
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<Issue, ForgeError> {
+        let url = self.project_url(&format!("issues/{}", number));
+        let issue: GitLabIssue = self.send(self.client.get(&url)).await?;
+        Ok(Issue {
+            number: issue.iid,
+            url: issue.web_url,
+            title: issue.title,
+        })
+    }
+}
+
+/// Convert a failed request into a forge error, keeping timeouts apart from
+/// other network failures.
+fn request_error(e: reqwest::Error) -> ForgeError {
+    if e.is_timeout() {
+        ForgeError::Timeout(net::describe(&e))
+    } else {
+        ForgeError::NetworkError(e.to_string())
+    }
+}
+
+/// Map an unsuccessful status to a forge error.
+fn status_error(status: StatusCode, message: String) -> ForgeError {
+    match status {
+        StatusCode::UNAUTHORIZED => ForgeError::AuthFailed("Invalid or expired token".into()),
+        StatusCode::FORBIDDEN => ForgeError::AuthFailed(format!("Permission denied: {}", message)),
+        StatusCode::NOT_FOUND => ForgeError::NotFound(message),
+        StatusCode::TOO_MANY_REQUESTS => ForgeError::RateLimited,
+        _ if status.is_server_error() => ForgeError::ApiError {
+            status: status.as_u16(),
+            message: format!("GitLab server error: {}", message),
+        },
+        _ => ForgeError::ApiError {
+            status: status.as_u16(),
+            message,
+        },
+    }
+}
+
+/// Extract the message from a GitLab error body.
+///
+/// GitLab reports errors as `{"message": ...}`, where the message may be a
+/// string, a list, or a map of field errors, or as `{"error": "..."}`.
+fn error_message(body: &serde_json::Value) -> Option<String> {
+    let value = body.get("message").or_else(|| body.get("error"))?;
+    Some(match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    })
+}
+
+/// Strip a draft marker from a merge request title.
+///
+/// GitLab treats `Draft:`, `[Draft]`, and `(Draft)` at the start of the
+/// title, in any case, as marking a draft.
+fn strip_draft(title: &str) -> Option<&str> {
+    ["draft:", "[draft]", "(draft)"].iter().find_map(|marker| {
+        let head = title.get(..marker.len())?;
+        head.eq_ignore_ascii_case(marker)
+            .then(|| title[marker.len()..].trim_start())
+    })
+}
+
+/// A title with the draft marker added or removed.
+fn with_draft(title: &str, draft: bool) -> String {
+    let bare = strip_draft(title).unwrap_or(title);
+    if draft {
+        format!("{}{}", DRAFT_PREFIX, bare)
+    } else {
+        bare.to_string()
+    }
+}
+
+// --------------------------------------------------------------------------
+// API Request/Response Types
+// --------------------------------------------------------------------------
+
+/// Request body for creating a merge request.
+#[derive(Serialize)]
+struct CreateMrBody<'a> {
+    source_branch: &'a str,
+    target_branch: &'a str,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+/// Request body for updating a merge request.
+#[derive(Serialize, Default)]
+struct UpdateMrBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_branch: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviewer_ids: Option<&'a [u64]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_event: Option<&'a str>,
+}
+
+/// Request body for commenting on a merge request.
+#[derive(Serialize)]
+struct NoteBody<'a> {
+    body: &'a str,
+}
+
+/// Request body for merging a merge request.
+#[derive(Serialize)]
+struct MergeMrBody {
+    squash: bool,
+}
+
+/// GitLab merge request response format.
+#[derive(Deserialize)]
+struct GitLabMergeRequest {
+    iid: u64,
+    web_url: String,
+    /// `opened`, `closed`, `locked`, or `merged`
+    state: String,
+    #[serde(default)]
+    draft: bool,
+    source_branch: String,
+    target_branch: String,
+    title: String,
+    description: Option<String>,
+    #[serde(default)]
+    updated_at: String,
+    source_project_id: Option<u64>,
+    target_project_id: Option<u64>,
+    #[serde(default)]
+    reviewers: Vec<GitLabUser>,
+    author: Option<GitLabAuthor>,
+    /// Only present on single merge request responses
+    head_pipeline: Option<GitLabPipeline>,
+    #[serde(default)]
+    has_conflicts: bool,
+}
+
+impl GitLabMergeRequest {
+    /// Whether the merge request is a draft, by flag or title marker.
+    fn is_draft(&self) -> bool {
+        self.draft || strip_draft(&self.title).is_some()
+    }
+}
+
+impl From<GitLabMergeRequest> for PullRequest {
+    fn from(mr: GitLabMergeRequest) -> Self {
+        let state = match mr.state.as_str() {
+            "merged" => PrState::Merged,
+            "closed" => PrState::Closed,
+            _ => PrState::Open,
+        };
+        let is_draft = mr.is_draft();
+        let title = strip_draft(&mr.title).unwrap_or(&mr.title).to_string();
+
+        PullRequest {
+            number: mr.iid,
+            url: mr.web_url,
+            state,
+            is_draft,
+            head: mr.source_branch,
+            base: mr.target_branch,
+            title,
+            body: mr.description,
+            node_id: None,
+            author: mr.author.map(|a| a.username),
+        }
+    }
+}
+
+impl From<GitLabMergeRequest> for PullRequestSummary {
+    fn from(mr: GitLabMergeRequest) -> Self {
+        // GitLab names the source project only by ID; any value marks a fork
+        let head_repo_owner = match (mr.source_project_id, mr.target_project_id) {
+            (Some(source), Some(target)) if source != target => Some(format!("project {}", source)),
+            _ => None,
+        };
+
+        PullRequestSummary {
+            number: mr.iid,
+            head_ref: mr.source_branch.clone(),
+            head_repo_owner,
+            base_ref: mr.target_branch.clone(),
+            is_draft: mr.is_draft(),
+            url: mr.web_url,
+            updated_at: mr.updated_at,
+            author: mr.author.map(|a| a.username),
+        }
+    }
+}
+
+/// GitLab user reference.
+#[derive(Deserialize)]
+struct GitLabUser {
+    id: u64,
+}
+
+/// GitLab merge request author.
+#[derive(Deserialize)]
+struct GitLabAuthor {
+    username: String,
+}
+
+/// GitLab pipeline reference.
+#[derive(Deserialize)]
+struct GitLabPipeline {
+    id: u64,
+}
+
+/// GitLab pipeline job or commit status; both report the same fields.
+#[derive(Deserialize)]
+struct GitLabJob {
+    name: String,
+    status: String,
+    #[serde(default)]
+    allow_failure: bool,
+}
+
+impl From<GitLabJob> for CheckStatus {
+    fn from(job: GitLabJob) -> Self {
+        let state = match job.status.as_str() {
+            "success" => CheckState::Success,
+            "failed" | "canceled" => CheckState::Failure,
+            "skipped" | "manual" => CheckState::Skipped,
+            _ => CheckState::Pending,
+        };
+        CheckStatus {
+            name: job.name,
+            state,
+            required: !job.allow_failure,
+        }
     }
 }
 
+/// GitLab issue response format.
+#[derive(Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    web_url: String,
+    title: String,
+}
+
 // --------------------------------------------------------------------------
 // URL Parsing
 // --------------------------------------------------------------------------
 
-/// Parse a GitLab remote URL to extract owner and project.
+/// A GitLab remote, split into host and project path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitLabRemote {
+    /// Host name, with port if the remote gives one for HTTP(S)
+    pub host: String,
+    /// Project owner (user or group path)
+    pub owner: String,
+    /// Project name
+    pub project: String,
+}
+
+/// Parse a gitlab.com remote URL to extract owner and project.
 ///
 /// Supports both SSH and HTTPS formats:
 /// - `git@gitlab.com:owner/project.git`
@@ -294,20 +875,52 @@ impl Forge for GitLabForge {
 /// assert_eq!(project, "project");
 /// ```
 pub fn parse_gitlab_url(url: &str) -> Option<(String, String)> {
-    // SSH format: git@gitlab.com:owner/project.git
-    if let Some(rest) = url.strip_prefix("git@gitlab.com:") {
-        return parse_gitlab_path(rest);
-    }
+    let remote = parse_gitlab_remote(url)?;
+    (remote.host == GITLAB_COM).then_some((remote.owner, remote.project))
+}
 
-    // HTTPS format: https://gitlab.com/owner/project.git
-    if let Some(rest) = url
-        .strip_prefix("https://gitlab.com/")
-        .or_else(|| url.strip_prefix("http://gitlab.com/"))
+/// Parse a remote URL on any host as a GitLab project.
+///
+/// Supports `git@host:path`, `ssh://git@host[:port]/path`, and
+/// `http(s)://host[:port]/path`, with nested groups as for
+/// [`parse_gitlab_url`].
+///
+/// # Example
+///
+/// ```
+/// use latticework::forge::gitlab::parse_gitlab_remote;
+///
+/// let remote = parse_gitlab_remote("https://git.example.com/team/app.git").unwrap();
+/// assert_eq!(remote.host, "git.example.com");
+/// assert_eq!(remote.owner, "team");
+/// assert_eq!(remote.project, "app");
+/// ```
+pub fn parse_gitlab_remote(url: &str) -> Option<GitLabRemote> {
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
     {
-        return parse_gitlab_path(rest);
+        rest.split_once('/')?
+    } else if let Some(rest) = url.strip_prefix("ssh://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next()?;
+        // The SSH port says nothing about where the API is served
+        (host.split(':').next()?, path)
+    } else {
+        let (user_host, path) = url.split_once(':')?;
+        let (_, host) = user_host.split_once('@')?;
+        (host, path)
+    };
+    if host.is_empty() || host.contains('/') {
+        return None;
     }
 
-    None
+    let (owner, project) = parse_gitlab_path(path)?;
+    Some(GitLabRemote {
+        host: host.to_string(),
+        owner,
+        project,
+    })
 }
 
 /// Parse the path portion of a GitLab URL.
@@ -437,6 +1050,41 @@ mod tests {
         }
     }
 
+    mod parse_gitlab_remote {
+        use super::*;
+
+        fn remote(host: &str, owner: &str, project: &str) -> Option<GitLabRemote> {
+            Some(GitLabRemote {
+                host: host.to_string(),
+                owner: owner.to_string(),
+                project: project.to_string(),
+            })
+        }
+
+        #[test]
+        fn self_hosted_formats() {
+            assert_eq!(
+                parse_gitlab_remote("git@git.example.com:team/app.git"),
+                remote("git.example.com", "team", "app")
+            );
+            assert_eq!(
+                parse_gitlab_remote("https://git.example.com:8443/team/sub/app"),
+                remote("git.example.com:8443", "team/sub", "app")
+            );
+            assert_eq!(
+                parse_gitlab_remote("ssh://git@git.example.com:2222/team/app.git"),
+                remote("git.example.com", "team", "app")
+            );
+        }
+
+        #[test]
+        fn rejects_paths_without_project() {
+            assert!(parse_gitlab_remote("git@git.example.com:app.git").is_none());
+            assert!(parse_gitlab_remote("https://git.example.com/").is_none());
+            assert!(parse_gitlab_remote("/srv/git/app.git").is_none());
+        }
+    }
+
     mod gitlab_forge {
         use super::*;
 
@@ -474,6 +1122,19 @@ mod tests {
             assert!(forge.is_none());
         }
 
+        #[test]
+        fn from_self_hosted_url_derives_api_base() {
+            let forge =
+                GitLabForge::from_self_hosted_url("http://git.local/group/sub/app.git", "token")
+                    .unwrap();
+            assert_eq!(forge.api_base(), "http://git.local/api/v4");
+            assert_eq!(forge.owner(), "group/sub");
+            assert_eq!(
+                forge.project_url("merge_requests"),
+                "http://git.local/api/v4/projects/group%2Fsub%2Fapp/merge_requests"
+            );
+        }
+
         #[test]
         fn with_api_base() {
             let forge = GitLabForge::with_api_base(
@@ -490,101 +1151,359 @@ mod tests {
             let forge = GitLabForge::new("", "owner", "project");
             assert!(!forge.has_token());
         }
+
+        #[test]
+        fn debug_hides_token() {
+            let forge = GitLabForge::new("glpat-secret", "owner", "project");
+            assert!(!format!("{:?}", forge).contains("glpat-secret"));
+        }
+    }
+
+    mod drafts {
+        use super::*;
+
+        #[test]
+        fn recognizes_draft_markers() {
+            assert_eq!(strip_draft("Draft: Add x"), Some("Add x"));
+            assert_eq!(strip_draft("draft:Add x"), Some("Add x"));
+            assert_eq!(strip_draft("[Draft] Add x"), Some("Add x"));
+            assert_eq!(strip_draft("(DRAFT) Add x"), Some("Add x"));
+            assert_eq!(strip_draft("Drafting docs"), None);
+        }
+
+        #[test]
+        fn with_draft_adds_and_removes_one_marker() {
+            assert_eq!(with_draft("Add x", true), "Draft: Add x");
+            assert_eq!(with_draft("[Draft] Add x", true), "Draft: Add x");
+            assert_eq!(with_draft("Draft: Add x", false), "Add x");
+            assert_eq!(with_draft("Add x", false), "Add x");
+        }
     }
 
-    mod forge_trait {
+    mod gitlab_job {
         use super::*;
 
+        #[test]
+        fn maps_status_and_allow_failure() {
+            let job = |status: &str, allow_failure| GitLabJob {
+                name: "test".to_string(),
+                status: status.to_string(),
+                allow_failure,
+            };
+            let check: CheckStatus = job("failed", false).into();
+            assert_eq!(check.state, CheckState::Failure);
+            assert!(check.is_required_failure());
+
+            let check: CheckStatus = job("failed", true).into();
+            assert!(!check.is_required_failure());
+
+            let states: Vec<CheckState> = ["success", "running", "manual", "canceled"]
+                .iter()
+                .map(|s| CheckStatus::from(job(s, false)).state)
+                .collect();
+            assert_eq!(
+                states,
+                [
+                    CheckState::Success,
+                    CheckState::Pending,
+                    CheckState::Skipped,
+                    CheckState::Failure
+                ]
+            );
+        }
+    }
+
+    mod api {
+        use super::*;
+        use serde_json::json;
+        use wiremock::matchers::{body_json, header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        const MR_PATH: &str = "/projects/group%2Fapp/merge_requests";
+
+        fn mr(iid: u64, title: &str, state: &str) -> serde_json::Value {
+            json!({
+                "iid": iid,
+                "web_url": format!("https://gitlab.example.com/group/app/-/merge_requests/{}", iid),
+                "state": state,
+                "draft": title.starts_with("Draft:"),
+                "source_branch": "feature",
+                "target_branch": "main",
+                "title": title,
+                "description": null,
+                "updated_at": "2026-01-01T00:00:00Z",
+                "source_project_id": 1,
+                "target_project_id": 1,
+                "reviewers": [{"id": 5}]
+            })
+        }
+
+        async fn forge(server: &MockServer) -> GitLabForge {
+            GitLabForge::with_api_base("glpat", "group", "app", server.uri())
+        }
+
         #[tokio::test]
-        async fn create_pr_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge
+        async fn create_marks_drafts_in_the_title() {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path(MR_PATH))
+                .and(header("authorization", "Bearer glpat"))
+                .and(body_json(json!({
+                    "source_branch": "feature",
+                    "target_branch": "main",
+                    "title": "Draft: Add x",
+                    "description": "Body"
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(mr(
+                    3,
+                    "Draft: Add x",
+                    "opened",
+                )))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let pr = forge(&server)
+                .await
                 .create_pr(CreatePrRequest {
                     head: "feature".into(),
                     base: "main".into(),
-                    title: "Test".into(),
-                    body: None,
-                    draft: false,
+                    title: "Add x".into(),
+                    body: Some("Body".into()),
+                    draft: true,
                 })
+                .await
+                .unwrap();
+
+            assert_eq!(pr.number, 3);
+            assert!(pr.is_draft);
+            assert_eq!(pr.title, "Add x");
+            assert_eq!(pr.state, PrState::Open);
+        }
+
+        #[tokio::test]
+        async fn find_by_head_filters_open_source_branch() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(MR_PATH))
+                .and(query_param("state", "opened"))
+                .and(query_param("source_branch", "feature"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+                .mount(&server)
                 .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let found = forge(&server).await.find_pr_by_head("feature").await;
+            assert!(found.unwrap().is_none());
         }
 
         #[tokio::test]
-        async fn update_pr_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge
+        async fn update_keeps_draft_marker_and_retargets() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(format!("{}/3", MR_PATH)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(mr(
+                    3,
+                    "Draft: Old",
+                    "opened",
+                )))
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("{}/3", MR_PATH)))
+                .and(body_json(
+                    json!({"title": "Draft: New", "target_branch": "develop"}),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(mr(
+                    3,
+                    "Draft: New",
+                    "opened",
+                )))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let pr = forge(&server)
+                .await
                 .update_pr(UpdatePrRequest {
-                    number: 1,
-                    title: Some("New title".into()),
+                    number: 3,
+                    title: Some("New".into()),
+                    base: Some("develop".into()),
                     ..Default::default()
                 })
-                .await;
-
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+                .await
+                .unwrap();
+            assert_eq!(pr.title, "New");
         }
 
         #[tokio::test]
-        async fn get_pr_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge.get_pr(1).await;
+        async fn reviewers_are_added_by_user_id() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(format!("{}/3", MR_PATH)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(mr(3, "Add x", "opened")))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/users"))
+                .and(query_param("username", "alice"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"id": 9}])))
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("{}/3", MR_PATH)))
+                .and(body_json(json!({"reviewer_ids": [5, 9]})))
+                .respond_with(ResponseTemplate::new(200).set_body_json(mr(3, "Add x", "opened")))
+                .expect(1)
+                .mount(&server)
+                .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let reviewers = Reviewers {
+                users: vec!["alice".into()],
+                teams: vec![],
+            };
+            forge(&server)
+                .await
+                .request_reviewers(3, reviewers)
+                .await
+                .unwrap();
         }
 
         #[tokio::test]
-        async fn find_pr_by_head_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge.find_pr_by_head("feature").await;
+        async fn merge_squashes_and_maps_conflicts() {
+            let server = MockServer::start().await;
+            Mock::given(method("PUT"))
+                .and(path(format!("{}/3/merge", MR_PATH)))
+                .and(body_json(json!({"squash": true})))
+                .respond_with(ResponseTemplate::new(200).set_body_json(mr(3, "Add x", "merged")))
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("{}/4/merge", MR_PATH)))
+                .respond_with(
+                    ResponseTemplate::new(405)
+                        .set_body_json(json!({"message": "405 Method Not Allowed"})),
+                )
+                .mount(&server)
+                .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let forge = forge(&server).await;
+            forge.merge_pr(3, MergeMethod::Squash).await.unwrap();
+            assert!(matches!(
+                forge.merge_pr(4, MergeMethod::Merge).await,
+                Err(ForgeError::ApiError { status: 405, .. })
+            ));
+            assert!(matches!(
+                forge.merge_pr(3, MergeMethod::Rebase).await,
+                Err(ForgeError::NotImplemented(_))
+            ));
         }
 
         #[tokio::test]
-        async fn set_draft_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge.set_draft(1, true).await;
+        async fn closed_listing_keeps_closed_and_merged() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(MR_PATH))
+                .and(query_param("target_branch", "feature"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                    mr(1, "Open", "opened"),
+                    mr(2, "Merged", "merged"),
+                    mr(3, "Closed", "closed"),
+                ])))
+                .mount(&server)
+                .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let result = forge(&server)
+                .await
+                .list_closed_prs_targeting(ListClosedPrsOpts::for_base("feature"))
+                .await
+                .unwrap();
+            let numbers: Vec<u64> = result.pulls.iter().map(|p| p.number).collect();
+            assert_eq!(numbers, [2, 3]);
+            assert!(!result.truncated);
         }
 
         #[tokio::test]
-        async fn request_reviewers_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge
-                .request_reviewers(
-                    1,
-                    Reviewers {
-                        users: vec!["alice".into()],
-                        teams: vec![],
-                    },
-                )
+        async fn checks_come_from_head_pipeline_jobs() {
+            let server = MockServer::start().await;
+            let mut with_pipeline = mr(3, "Add x", "opened");
+            with_pipeline["head_pipeline"] = json!({"id": 77});
+            Mock::given(method("GET"))
+                .and(path(format!("{}/3", MR_PATH)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(with_pipeline))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/projects/group%2Fapp/pipelines/77/jobs"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                    {"name": "test", "status": "success", "allow_failure": false},
+                    {"name": "lint", "status": "failed", "allow_failure": true}
+                ])))
+                .mount(&server)
                 .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let checks = forge(&server).await.list_checks(3).await.unwrap();
+            assert_eq!(checks.len(), 2);
+            assert_eq!(checks[0].state, CheckState::Success);
+            assert!(checks[0].required);
+            assert!(!checks[1].is_required_failure());
         }
 
         #[tokio::test]
-        async fn merge_pr_returns_not_implemented() {
-            let forge = GitLabForge::new("token", "owner", "project");
-            let result = forge.merge_pr(1, MergeMethod::Squash).await;
+        async fn ref_checks_come_from_commit_statuses() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(
+                    "/projects/group%2Fapp/repository/commits/abc123/statuses",
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                    {"name": "test", "status": "running", "allow_failure": false},
+                    {"name": "deploy", "status": "manual", "allow_failure": true}
+                ])))
+                .mount(&server)
+                .await;
 
-            assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            let checks = forge(&server)
+                .await
+                .check_runs_for_ref("abc123")
+                .await
+                .unwrap();
+            assert_eq!(checks.len(), 2);
+            assert_eq!(checks[0].state, CheckState::Pending);
+            assert!(!checks[0].required);
+            assert_eq!(checks[1].state, CheckState::Skipped);
         }
 
-        #[test]
-        fn error_messages_are_actionable() {
-            // Verify error messages include guidance
-            let err = ForgeError::NotImplemented(
-                "GitLab merge request creation is not yet implemented. \
-                 See https://github.com/lattice-cli/lattice for updates."
-                    .to_string(),
-            );
+        #[tokio::test]
+        async fn errors_map_to_forge_errors() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(format!("{}/1", MR_PATH)))
+                .respond_with(ResponseTemplate::new(401))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path(format!("{}/2", MR_PATH)))
+                .respond_with(
+                    ResponseTemplate::new(404).set_body_json(json!({"message": "404 Not found"})),
+                )
+                .mount(&server)
+                .await;
 
-            let msg = format!("{}", err);
-            assert!(msg.contains("not yet implemented"));
-            assert!(msg.contains("https://"));
+            let forge = forge(&server).await;
+            assert!(matches!(
+                forge.get_pr(1).await,
+                Err(ForgeError::AuthFailed(_))
+            ));
+            match forge.get_pr(2).await {
+                Err(ForgeError::NotFound(message)) => assert_eq!(message, "404 Not found"),
+                other => panic!("expected NotFound, got {:?}", other.map(|p| p.number)),
+            }
+
+            let anonymous = GitLabForge::with_api_base("", "group", "app", server.uri());
+            assert!(matches!(
+                anonymous.get_pr(1).await,
+                Err(ForgeError::AuthRequired)
+            ));
         }
     }
 }
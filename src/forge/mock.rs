@@ -40,8 +40,9 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use super::traits::{
-    CreatePrRequest, Forge, ForgeError, ListPullsOpts, ListPullsResult, MergeMethod, PrState,
-    PullRequest, PullRequestSummary, Reviewers, UpdatePrRequest,
+    CheckStatus, CreatePrRequest, Forge, ForgeError, Issue, ListPullsOpts, ListPullsResult,
+    MergeMethod, MergeQueueEntry, MergeQueueState, PrState, PrStatus, PullRequest,
+    PullRequestSummary, ReviewDecision, ReviewState, Reviewers, UpdatePrRequest,
 };
 
 /// Mock forge for testing.
@@ -58,6 +59,18 @@ pub struct MockForge {
 struct MockForgeInner {
     /// Stored PRs by number.
     prs: HashMap<u64, PullRequest>,
+    /// CI checks by PR number.
+    checks: HashMap<u64, Vec<CheckStatus>>,
+    /// CI checks by commit ref.
+    ref_checks: HashMap<String, Vec<CheckStatus>>,
+    /// Review decisions by PR number.
+    review_decisions: HashMap<u64, ReviewDecision>,
+    /// Reviews by PR number.
+    reviews: HashMap<u64, ReviewState>,
+    /// Stored issues by number.
+    issues: HashMap<u64, Issue>,
+    /// PRs in the merge queue, front first.
+    merge_queue: Vec<u64>,
     /// Next PR number to assign.
     next_pr_number: u64,
     /// Method to fail on (for testing error paths).
@@ -75,6 +88,8 @@ pub enum FailOn {
     UpdatePr(ForgeError),
     /// Fail get_pr with the given error.
     GetPr(ForgeError),
+    /// Fail get_pr_statuses with the given error.
+    GetPrStatuses(ForgeError),
     /// Fail find_pr_by_head with the given error.
     FindPrByHead(ForgeError),
     /// Fail set_draft with the given error.
@@ -83,10 +98,22 @@ pub enum FailOn {
     RequestReviewers(ForgeError),
     /// Fail merge_pr with the given error.
     MergePr(ForgeError),
+    /// Fail enqueue_pr with the given error.
+    EnqueuePr(ForgeError),
+    /// Fail close_pr with the given error.
+    ClosePr(ForgeError),
+    /// Fail comment_pr with the given error.
+    CommentPr(ForgeError),
     /// Fail list_open_prs with the given error.
     ListOpenPrs(ForgeError),
     /// Fail list_closed_prs_targeting with the given error.
     ListClosedPrsTargeting(ForgeError),
+    /// Fail list_checks with the given error.
+    ListChecks(ForgeError),
+    /// Fail check_runs_for_ref with the given error.
+    CheckRunsForRef(ForgeError),
+    /// Fail get_issue with the given error.
+    GetIssue(ForgeError),
 }
 
 /// Recorded operation for test verification.
@@ -107,6 +134,9 @@ pub enum MockOperation {
     GetPr {
         number: u64,
     },
+    GetPrStatuses {
+        numbers: Vec<u64>,
+    },
     FindPrByHead {
         head: String,
     },
@@ -123,6 +153,16 @@ pub enum MockOperation {
         number: u64,
         method: MergeMethod,
     },
+    EnqueuePr {
+        number: u64,
+    },
+    ClosePr {
+        number: u64,
+    },
+    CommentPr {
+        number: u64,
+        body: String,
+    },
     ListOpenPrs {
         max_results: Option<usize>,
     },
@@ -130,6 +170,15 @@ pub enum MockOperation {
         base: String,
         max_results: Option<usize>,
     },
+    ListChecks {
+        number: u64,
+    },
+    CheckRunsForRef {
+        git_ref: String,
+    },
+    GetIssue {
+        number: u64,
+    },
 }
 
 impl MockForge {
@@ -138,6 +187,12 @@ impl MockForge {
         Self {
             inner: Arc::new(Mutex::new(MockForgeInner {
                 prs: HashMap::new(),
+                checks: HashMap::new(),
+                ref_checks: HashMap::new(),
+                review_decisions: HashMap::new(),
+                reviews: HashMap::new(),
+                issues: HashMap::new(),
+                merge_queue: Vec::new(),
                 next_pr_number: 1,
                 fail_on: None,
                 operations: Vec::new(),
@@ -163,6 +218,7 @@ impl MockForge {
     ///     title: "Existing PR".to_string(),
     ///     body: Some("PR description".to_string()),
     ///     node_id: Some("PR_123".to_string()),
+    ///     author: None,
     /// };
     ///
     /// let forge = MockForge::with_prs(vec![pr]);
@@ -174,6 +230,12 @@ impl MockForge {
         Self {
             inner: Arc::new(Mutex::new(MockForgeInner {
                 prs: prs_map,
+                checks: HashMap::new(),
+                ref_checks: HashMap::new(),
+                review_decisions: HashMap::new(),
+                reviews: HashMap::new(),
+                issues: HashMap::new(),
+                merge_queue: Vec::new(),
                 next_pr_number: max_number + 1,
                 fail_on: None,
                 operations: Vec::new(),
@@ -181,6 +243,86 @@ impl MockForge {
         }
     }
 
+    /// Set the CI checks reported for a PR.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::forge::mock::MockForge;
+    /// use latticework::forge::{CheckState, CheckStatus};
+    ///
+    /// let forge = MockForge::new().with_checks(
+    ///     1,
+    ///     vec![CheckStatus {
+    ///         name: "ci/test".to_string(),
+    ///         state: CheckState::Failure,
+    ///         required: true,
+    ///     }],
+    /// );
+    /// ```
+    pub fn with_checks(self, number: u64, checks: Vec<CheckStatus>) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.checks.insert(number, checks);
+        }
+        self
+    }
+
+    /// Set the review decision reported for a PR.
+    ///
+    /// PRs without one report none.
+    pub fn with_review_decision(self, number: u64, decision: ReviewDecision) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.review_decisions.insert(number, decision);
+        }
+        self
+    }
+
+    /// Set the reviews reported for a PR.
+    ///
+    /// PRs without them report none, as a forge without review data would.
+    pub fn with_reviews(self, number: u64, reviews: ReviewState) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.reviews.insert(number, reviews);
+        }
+        self
+    }
+
+    /// Set the CI checks reported for a commit ref.
+    ///
+    /// Refs without configured checks report none.
+    pub fn with_ref_checks(self, git_ref: &str, checks: Vec<CheckStatus>) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.ref_checks.insert(git_ref.to_string(), checks);
+        }
+        self
+    }
+
+    /// Add an issue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::forge::mock::MockForge;
+    /// use latticework::forge::Issue;
+    ///
+    /// let forge = MockForge::new().with_issue(Issue {
+    ///     number: 7,
+    ///     url: "https://github.com/owner/repo/issues/7".to_string(),
+    ///     title: "Login fails".to_string(),
+    /// });
+    /// ```
+    pub fn with_issue(self, issue: Issue) -> Self {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.issues.insert(issue.number, issue);
+        }
+        self
+    }
+
     /// Configure the mock to fail on a specific operation.
     ///
     /// # Example
@@ -251,6 +393,9 @@ impl MockForge {
             Some(FailOn::CreatePr(e)) if expected == "create_pr" => Some(Err(clone_error(e))),
             Some(FailOn::UpdatePr(e)) if expected == "update_pr" => Some(Err(clone_error(e))),
             Some(FailOn::GetPr(e)) if expected == "get_pr" => Some(Err(clone_error(e))),
+            Some(FailOn::GetPrStatuses(e)) if expected == "get_pr_statuses" => {
+                Some(Err(clone_error(e)))
+            }
             Some(FailOn::FindPrByHead(e)) if expected == "find_pr_by_head" => {
                 Some(Err(clone_error(e)))
             }
@@ -259,12 +404,20 @@ impl MockForge {
                 Some(Err(clone_error(e)))
             }
             Some(FailOn::MergePr(e)) if expected == "merge_pr" => Some(Err(clone_error(e))),
+            Some(FailOn::EnqueuePr(e)) if expected == "enqueue_pr" => Some(Err(clone_error(e))),
+            Some(FailOn::ClosePr(e)) if expected == "close_pr" => Some(Err(clone_error(e))),
+            Some(FailOn::CommentPr(e)) if expected == "comment_pr" => Some(Err(clone_error(e))),
             Some(FailOn::ListOpenPrs(e)) if expected == "list_open_prs" => {
                 Some(Err(clone_error(e)))
             }
             Some(FailOn::ListClosedPrsTargeting(e)) if expected == "list_closed_prs_targeting" => {
                 Some(Err(clone_error(e)))
             }
+            Some(FailOn::ListChecks(e)) if expected == "list_checks" => Some(Err(clone_error(e))),
+            Some(FailOn::CheckRunsForRef(e)) if expected == "check_runs_for_ref" => {
+                Some(Err(clone_error(e)))
+            }
+            Some(FailOn::GetIssue(e)) if expected == "get_issue" => Some(Err(clone_error(e))),
             _ => None,
         }
     }
@@ -288,6 +441,7 @@ fn clone_error(e: &ForgeError) -> ForgeError {
             message: message.clone(),
         },
         ForgeError::NetworkError(s) => ForgeError::NetworkError(s.clone()),
+        ForgeError::Timeout(s) => ForgeError::Timeout(s.clone()),
         ForgeError::NotImplemented(s) => ForgeError::NotImplemented(s.clone()),
     }
 }
@@ -324,6 +478,7 @@ impl Forge for MockForge {
             title: request.title,
             body: request.body,
             node_id: Some(format!("PR_{}", number)),
+            author: None,
         };
 
         inner.prs.insert(number, pr.clone());
@@ -376,6 +531,31 @@ impl Forge for MockForge {
             .ok_or_else(|| ForgeError::NotFound(format!("PR #{}", number)))
     }
 
+    async fn get_pr_statuses(&self, numbers: &[u64]) -> Result<Vec<PrStatus>, ForgeError> {
+        self.record(MockOperation::GetPrStatuses {
+            numbers: numbers.to_vec(),
+        });
+
+        if let Some(result) = self.check_fail("get_pr_statuses") {
+            return result;
+        }
+
+        let inner = self.inner.lock().unwrap();
+        Ok(numbers
+            .iter()
+            .filter_map(|number| {
+                let pr = inner.prs.get(number)?.clone();
+                Some(PrStatus {
+                    pr,
+                    mergeable: Some(true),
+                    review_decision: inner.review_decisions.get(number).copied(),
+                    reviews: inner.reviews.get(number).cloned(),
+                    checks: inner.checks.get(number).cloned().unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+
     async fn find_pr_by_head(&self, head: &str) -> Result<Option<PullRequest>, ForgeError> {
         self.record(MockOperation::FindPrByHead {
             head: head.to_string(),
@@ -456,6 +636,76 @@ impl Forge for MockForge {
         Ok(())
     }
 
+    async fn enqueue_pr(&self, number: u64) -> Result<MergeQueueEntry, ForgeError> {
+        self.record(MockOperation::EnqueuePr { number });
+
+        if let Some(result) = self.check_fail("enqueue_pr") {
+            return result;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let pr = inner
+            .prs
+            .get(&number)
+            .ok_or_else(|| ForgeError::NotFound(format!("PR #{}", number)))?;
+        if pr.state != PrState::Open {
+            return Err(ForgeError::ApiError {
+                status: 200,
+                message: "Pull request is not open".into(),
+            });
+        }
+
+        if !inner.merge_queue.contains(&number) {
+            inner.merge_queue.push(number);
+        }
+        let position = inner.merge_queue.iter().position(|n| *n == number);
+        Ok(MergeQueueEntry {
+            position: position.map(|i| i as u64 + 1),
+            state: MergeQueueState::Queued,
+        })
+    }
+
+    async fn close_pr(&self, number: u64) -> Result<(), ForgeError> {
+        self.record(MockOperation::ClosePr { number });
+
+        if let Some(result) = self.check_fail::<()>("close_pr") {
+            return result;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let pr = inner
+            .prs
+            .get_mut(&number)
+            .ok_or_else(|| ForgeError::NotFound(format!("PR #{}", number)))?;
+
+        if pr.state == PrState::Merged {
+            return Err(ForgeError::ApiError {
+                status: 422,
+                message: "Pull request is already merged".into(),
+            });
+        }
+
+        pr.state = PrState::Closed;
+        Ok(())
+    }
+
+    async fn comment_pr(&self, number: u64, body: &str) -> Result<(), ForgeError> {
+        self.record(MockOperation::CommentPr {
+            number,
+            body: body.to_string(),
+        });
+
+        if let Some(result) = self.check_fail::<()>("comment_pr") {
+            return result;
+        }
+
+        let inner = self.inner.lock().unwrap();
+        if !inner.prs.contains_key(&number) {
+            return Err(ForgeError::NotFound(format!("PR #{}", number)));
+        }
+        Ok(())
+    }
+
     async fn list_open_prs(&self, opts: ListPullsOpts) -> Result<ListPullsResult, ForgeError> {
         self.record(MockOperation::ListOpenPrs {
             max_results: opts.max_results,
@@ -475,7 +725,7 @@ impl Forge for MockForge {
             .filter(|p| p.state == PrState::Open)
             .collect();
 
-        open_prs.sort_by(|a, b| b.number.cmp(&a.number));
+        open_prs.sort_by_key(|pr| std::cmp::Reverse(pr.number));
 
         let truncated = open_prs.len() > limit;
         let pulls: Vec<PullRequestSummary> = open_prs
@@ -489,6 +739,7 @@ impl Forge for MockForge {
                 is_draft: pr.is_draft,
                 url: pr.url.clone(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(), // Mock timestamp
+                author: pr.author.clone(),
             })
             .collect();
 
@@ -521,7 +772,7 @@ impl Forge for MockForge {
             .collect();
 
         // Sort by number descending (simulating updated_at sort)
-        closed_prs.sort_by(|a, b| b.number.cmp(&a.number));
+        closed_prs.sort_by_key(|pr| std::cmp::Reverse(pr.number));
 
         let truncated = closed_prs.len() > limit;
         let pulls: Vec<PullRequestSummary> = closed_prs
@@ -535,11 +786,54 @@ impl Forge for MockForge {
                 is_draft: pr.is_draft,
                 url: pr.url.clone(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(), // Mock timestamp
+                author: pr.author.clone(),
             })
             .collect();
 
         Ok(ListPullsResult { pulls, truncated })
     }
+
+    async fn list_checks(&self, number: u64) -> Result<Vec<CheckStatus>, ForgeError> {
+        self.record(MockOperation::ListChecks { number });
+
+        if let Some(result) = self.check_fail("list_checks") {
+            return result;
+        }
+
+        let inner = self.inner.lock().unwrap();
+        if !inner.prs.contains_key(&number) && !inner.checks.contains_key(&number) {
+            return Err(ForgeError::NotFound(format!("PR #{}", number)));
+        }
+        Ok(inner.checks.get(&number).cloned().unwrap_or_default())
+    }
+
+    async fn check_runs_for_ref(&self, git_ref: &str) -> Result<Vec<CheckStatus>, ForgeError> {
+        self.record(MockOperation::CheckRunsForRef {
+            git_ref: git_ref.to_string(),
+        });
+
+        if let Some(result) = self.check_fail("check_runs_for_ref") {
+            return result;
+        }
+
+        let inner = self.inner.lock().unwrap();
+        Ok(inner.ref_checks.get(git_ref).cloned().unwrap_or_default())
+    }
+
+    async fn get_issue(&self, number: u64) -> Result<Issue, ForgeError> {
+        self.record(MockOperation::GetIssue { number });
+
+        if let Some(result) = self.check_fail("get_issue") {
+            return result;
+        }
+
+        let inner = self.inner.lock().unwrap();
+        inner
+            .issues
+            .get(&number)
+            .cloned()
+            .ok_or_else(|| ForgeError::NotFound(format!("Issue #{}", number)))
+    }
 }
 
 #[cfg(test)]
@@ -811,6 +1105,142 @@ mod tests {
         assert!(matches!(ops[0], MockOperation::CreatePr { .. }));
     }
 
+    #[tokio::test]
+    async fn close_pr_closes_open_pr() {
+        let forge = MockForge::new();
+        let pr = forge
+            .create_pr(CreatePrRequest {
+                head: "feature".into(),
+                base: "main".into(),
+                title: "Test".into(),
+                body: None,
+                draft: false,
+            })
+            .await
+            .unwrap();
+
+        forge.close_pr(pr.number).await.unwrap();
+        assert_eq!(forge.get_pr_sync(pr.number).unwrap().state, PrState::Closed);
+        assert!(matches!(
+            forge.close_pr(99).await,
+            Err(ForgeError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn comment_pr_records_comment() {
+        let forge = MockForge::new();
+        let pr = forge
+            .create_pr(CreatePrRequest {
+                head: "feature".into(),
+                base: "main".into(),
+                title: "Test".into(),
+                body: None,
+                draft: false,
+            })
+            .await
+            .unwrap();
+
+        forge.comment_pr(pr.number, "Moved").await.unwrap();
+        assert!(forge.operations().iter().any(|op| matches!(
+            op,
+            MockOperation::CommentPr { number, body } if *number == pr.number && body == "Moved"
+        )));
+        assert!(matches!(
+            forge.comment_pr(99, "Moved").await,
+            Err(ForgeError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn list_checks_returns_configured_checks() {
+        let check = CheckStatus {
+            name: "ci/test".into(),
+            state: crate::forge::CheckState::Failure,
+            required: true,
+        };
+        let forge = MockForge::new().with_checks(7, vec![check.clone()]);
+
+        assert_eq!(forge.list_checks(7).await.unwrap(), vec![check]);
+        assert!(matches!(
+            forge.list_checks(8).await,
+            Err(ForgeError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_pr_statuses_skips_missing_prs() {
+        let forge = MockForge::new()
+            .with_review_decision(1, ReviewDecision::Approved)
+            .with_checks(
+                1,
+                vec![CheckStatus {
+                    name: "ci/test".into(),
+                    state: crate::forge::CheckState::Success,
+                    required: false,
+                }],
+            );
+        forge
+            .create_pr(CreatePrRequest {
+                head: "a".into(),
+                base: "main".into(),
+                title: "A".into(),
+                body: None,
+                draft: false,
+            })
+            .await
+            .unwrap();
+        forge
+            .create_pr(CreatePrRequest {
+                head: "b".into(),
+                base: "a".into(),
+                title: "B".into(),
+                body: None,
+                draft: false,
+            })
+            .await
+            .unwrap();
+
+        let statuses = forge.get_pr_statuses(&[2, 9, 1]).await.unwrap();
+        let numbers: Vec<u64> = statuses.iter().map(|s| s.pr.number).collect();
+        assert_eq!(numbers, vec![2, 1]);
+        assert_eq!(statuses[1].review_decision, Some(ReviewDecision::Approved));
+        assert_eq!(statuses[1].checks.len(), 1);
+        assert!(statuses[0].checks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_runs_for_ref_returns_configured_checks() {
+        let check = CheckStatus {
+            name: "ci/test".into(),
+            state: crate::forge::CheckState::Pending,
+            required: false,
+        };
+        let forge = MockForge::new().with_ref_checks("abc123", vec![check.clone()]);
+
+        assert_eq!(
+            forge.check_runs_for_ref("abc123").await.unwrap(),
+            vec![check]
+        );
+        assert!(forge.check_runs_for_ref("def456").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_issue_returns_configured_issue() {
+        let issue = Issue {
+            number: 7,
+            url: "https://github.com/owner/repo/issues/7".into(),
+            title: "Login fails".into(),
+        };
+        let forge = MockForge::new().with_issue(issue.clone());
+
+        assert_eq!(forge.get_issue(7).await.unwrap(), issue);
+        assert!(matches!(
+            forge.get_issue(8).await,
+            Err(ForgeError::NotFound(_))
+        ));
+    }
+
     #[tokio::test]
     async fn with_prs_starts_with_existing() {
         let existing = PullRequest {
@@ -823,6 +1253,7 @@ mod tests {
             title: "Existing PR".into(),
             body: Some("Description".into()),
             node_id: Some("PR_42".into()),
+            author: None,
         };
 
         let forge = MockForge::with_prs(vec![existing]);
@@ -864,6 +1295,7 @@ mod tests {
                 title: "A".into(),
                 body: None,
                 node_id: None,
+                author: None,
             },
             PullRequest {
                 number: 2,
@@ -875,6 +1307,7 @@ mod tests {
                 title: "B".into(),
                 body: None,
                 node_id: None,
+                author: None,
             },
         ]);
 
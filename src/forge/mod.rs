@@ -17,9 +17,11 @@
 //!
 //! - `traits`: Core `Forge` trait and request/response types
 //! - [`github`]: GitHub implementation using REST and GraphQL APIs
-//! - `gitlab`: GitLab stub (requires `gitlab` feature)
+//! - `gitlab`: GitLab implementation using the REST API (requires `gitlab` feature)
 //! - [`mock`]: Mock implementation for deterministic testing
 //! - `factory`: Forge selection and creation
+//! - [`rate_limit`]: GitHub API rate-limit budgets and backoff
+//! - [`status_cache`]: Cached PR and check status for offline-fast rendering
 //!
 //! # Example
 //!
@@ -50,7 +52,12 @@ pub mod github;
 #[cfg(feature = "gitlab")]
 pub mod gitlab;
 pub mod mock;
+pub mod pr_body_state;
+pub mod rate_limit;
+pub mod status_cache;
 mod traits;
 
-pub use factory::{create_forge, detect_provider, valid_forge_names, ForgeProvider};
+pub use factory::{
+    create_forge, detect_provider, resolve_provider, valid_forge_names, ForgeProvider,
+};
 pub use traits::*;
@@ -0,0 +1,142 @@
+//! forge::pr_body_state
+//!
+//! What Lattice last wrote into each PR's stack section.
+//!
+//! Stored at `<common_dir>/lattice/cache/pr_bodies.json`.
+//!
+//! # Design
+//!
+//! Only the region between the stack comment markers belongs to Lattice.
+//! Remembering the stack comment last written to a PR lets the next update
+//! tell the user's edits inside that region from Lattice's own, and merge
+//! rather than overwrite them (see
+//! [`crate::ui::stack_comment::update_stack_comment`]).
+//!
+//! Entries are keyed by branch and record the PR number they describe. A
+//! PR whose markers were found damaged is recorded too, so `lattice doctor`
+//! can report it; the record clears on the next successful update.
+//!
+//! Like the forge status cache, this file is a hint: load and save failures
+//! are ignored. Without it, the stack section is simply replaced, as it was
+//! before this file existed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::paths::LatticePaths;
+
+/// One PR's stack section state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrBodyEntry {
+    /// PR number the entry describes
+    pub number: u64,
+    /// Stack comment Lattice last wrote to the PR
+    pub written: Option<String>,
+    /// Why the PR's markers were last found damaged
+    pub corrupt: Option<String>,
+}
+
+/// PR body state stored at `<common_dir>/lattice/cache/pr_bodies.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PrBodyState {
+    /// Branch name to its PR's stack section state
+    entries: HashMap<String, PrBodyEntry>,
+}
+
+impl PrBodyState {
+    /// Load the state; a missing or unreadable file is empty.
+    pub fn load(paths: &LatticePaths) -> Self {
+        fs::read_to_string(Self::state_path(paths))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the state. Best-effort; errors are ignored.
+    pub fn save(&self, paths: &LatticePaths) {
+        let path = Self::state_path(paths);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(content) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(&path, content);
+        }
+    }
+
+    /// The stack comment last written to `branch`'s PR `number`.
+    pub fn written(&self, branch: &str, number: u64) -> Option<&str> {
+        self.get(branch, number)?.written.as_deref()
+    }
+
+    /// Why `branch`'s PR `number` has damaged markers, if it does.
+    pub fn corrupt(&self, branch: &str, number: u64) -> Option<&str> {
+        self.get(branch, number)?.corrupt.as_deref()
+    }
+
+    /// Record a successful write of `stack_comment` to `branch`'s PR.
+    pub fn record_written(&mut self, branch: &str, number: u64, stack_comment: &str) {
+        let entry = self.entry(branch, number);
+        entry.written = Some(stack_comment.to_string());
+        entry.corrupt = None;
+    }
+
+    /// Record that `branch`'s PR has damaged markers.
+    pub fn record_corrupt(&mut self, branch: &str, number: u64, problem: &str) {
+        self.entry(branch, number).corrupt = Some(problem.to_string());
+    }
+
+    /// The entry for `branch`, if it describes PR `number`.
+    fn get(&self, branch: &str, number: u64) -> Option<&PrBodyEntry> {
+        self.entries.get(branch).filter(|e| e.number == number)
+    }
+
+    /// The entry for `branch`, reset if it described another PR.
+    fn entry(&mut self, branch: &str, number: u64) -> &mut PrBodyEntry {
+        let entry = self.entries.entry(branch.to_string()).or_default();
+        if entry.number != number {
+            *entry = PrBodyEntry {
+                number,
+                ..Default::default()
+            };
+        }
+        entry
+    }
+
+    /// Get the state file path.
+    fn state_path(paths: &LatticePaths) -> PathBuf {
+        paths.repo_cache_dir().join("pr_bodies.json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_for_other_prs_are_ignored() {
+        let mut state = PrBodyState::default();
+        state.record_written("feature", 12, "stack");
+        assert_eq!(state.written("feature", 12), Some("stack"));
+        assert_eq!(state.written("feature", 13), None);
+
+        // Relinking to another PR starts over
+        state.record_corrupt("feature", 13, "broken");
+        assert_eq!(state.written("feature", 13), None);
+        assert_eq!(state.corrupt("feature", 13), Some("broken"));
+        assert_eq!(state.corrupt("feature", 12), None);
+    }
+
+    #[test]
+    fn writing_clears_corruption() {
+        let mut state = PrBodyState::default();
+        state.record_written("feature", 12, "old");
+        state.record_corrupt("feature", 12, "broken");
+        assert_eq!(state.written("feature", 12), Some("old"));
+
+        state.record_written("feature", 12, "new");
+        assert_eq!(state.corrupt("feature", 12), None);
+        assert_eq!(state.written("feature", 12), Some("new"));
+    }
+}
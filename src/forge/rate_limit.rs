@@ -0,0 +1,347 @@
+//! forge::rate_limit
+//!
+//! GitHub API rate-limit budgets, and how long to back off when GitHub
+//! refuses a request.
+//!
+//! # Design
+//!
+//! Every GitHub response carries `x-ratelimit-*` headers naming a resource
+//! (`core` for REST, `graphql`), its request limit, what is left of it, and
+//! when it resets. [`RateLimit::from_headers`] reads them and [`record`]
+//! keeps the latest per resource in a process-wide tracker, shared by every
+//! forge instance and by `submit`'s concurrent requests, so `--debug` and
+//! `lattice doctor --deep-remote` can say how much quota is left.
+//!
+//! GitHub refuses a request with 429, or with 403 and either a spent budget,
+//! a `retry-after` header, or a message about a secondary rate limit (too
+//! many requests at once, or too many writes in a short time).
+//! [`retry_delay`] tells those apart from permission errors and picks the
+//! wait: `retry-after` when given, the reset time when the budget is spent,
+//! and otherwise a minute, doubling with each attempt. A backoff also calls
+//! [`pause`], so requests running alongside wait it out instead of adding
+//! to the burst.
+//!
+//! The forge retries at most [`MAX_RETRIES`] times and never waits longer
+//! than [`MAX_WAIT`] at once; past that it fails with
+//! `ForgeError::RateLimited` rather than hang until an hourly budget resets.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+
+/// Most retries of one request after GitHub refuses it for rate limits.
+pub const MAX_RETRIES: u32 = 3;
+
+/// Longest single wait before a retry.
+pub const MAX_WAIT: Duration = Duration::from_secs(90);
+
+/// Wait before the first retry of a secondary rate limit without
+/// `retry-after`, per GitHub's guidance.
+const SECONDARY_BACKOFF: Duration = Duration::from_secs(60);
+
+static BUDGETS: Mutex<BTreeMap<String, RateLimit>> = Mutex::new(BTreeMap::new());
+static PAUSED_UNTIL: Mutex<Option<Instant>> = Mutex::new(None);
+static RETRIES: AtomicU32 = AtomicU32::new(0);
+
+/// The request budget of one API resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Resource the budget covers, e.g. `core` or `graphql`
+    pub resource: String,
+    /// Requests allowed per window
+    pub limit: u64,
+    /// Requests left in the current window
+    pub remaining: u64,
+    /// Requests used in the current window
+    pub used: u64,
+    /// When the window resets
+    pub reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// Read the budget from a response's `x-ratelimit-*` headers.
+    ///
+    /// Returns `None` when the limit, remaining count, or reset time is
+    /// missing, as on GitHub Enterprise Server with rate limiting off.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::forge::rate_limit::RateLimit;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-ratelimit-limit", HeaderValue::from_static("5000"));
+    /// headers.insert("x-ratelimit-remaining", HeaderValue::from_static("4990"));
+    /// headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000000"));
+    ///
+    /// let limit = RateLimit::from_headers(&headers).unwrap();
+    /// assert_eq!(limit.resource, "core");
+    /// assert_eq!(limit.used, 10);
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let limit = header_u64(headers, "x-ratelimit-limit")?;
+        let remaining = header_u64(headers, "x-ratelimit-remaining")?;
+        let reset = header_u64(headers, "x-ratelimit-reset")?;
+        Some(Self {
+            resource: headers
+                .get("x-ratelimit-resource")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("core")
+                .to_string(),
+            limit,
+            remaining,
+            used: header_u64(headers, "x-ratelimit-used")
+                .unwrap_or(limit.saturating_sub(remaining)),
+            reset: Utc.timestamp_opt(reset as i64, 0).single()?,
+        })
+    }
+
+    /// Describe what is left, e.g. `core: 4990 of 5000 requests left,
+    /// resets in 42 min`.
+    pub fn describe(&self, now: DateTime<Utc>) -> String {
+        let minutes = (self.reset - now).num_minutes().max(0);
+        format!(
+            "{}: {} of {} requests left, resets in {} min",
+            self.resource, self.remaining, self.limit, minutes
+        )
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// How long to wait before retrying a refused request, or `None` if it
+/// wasn't refused for rate limits.
+///
+/// `message` is the error message from the response body and `attempt`
+/// counts retries already made.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use chrono::Utc;
+/// use latticework::forge::rate_limit::retry_delay;
+/// use reqwest::header::{HeaderMap, HeaderValue};
+/// use reqwest::StatusCode;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("retry-after", HeaderValue::from_static("5"));
+/// let delay = retry_delay(StatusCode::FORBIDDEN, &headers, "", 0, Utc::now());
+/// assert_eq!(delay, Some(Duration::from_secs(5)));
+///
+/// let denied = retry_delay(
+///     StatusCode::FORBIDDEN,
+///     &HeaderMap::new(),
+///     "Resource not accessible by integration",
+///     0,
+///     Utc::now(),
+/// );
+/// assert_eq!(denied, None);
+/// ```
+pub fn retry_delay(
+    status: StatusCode,
+    headers: &HeaderMap,
+    message: &str,
+    attempt: u32,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let retry_after = header_u64(headers, RETRY_AFTER.as_str());
+    let spent = header_u64(headers, "x-ratelimit-remaining") == Some(0);
+    let throttled = match status {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::FORBIDDEN => {
+            retry_after.is_some() || spent || message.to_lowercase().contains("rate limit")
+        }
+        _ => false,
+    };
+    if !throttled {
+        return None;
+    }
+
+    if let Some(secs) = retry_after {
+        return Some(Duration::from_secs(secs));
+    }
+    if spent {
+        if let Some(limit) = RateLimit::from_headers(headers) {
+            let secs = (limit.reset - now).num_seconds().max(0) as u64;
+            return Some(Duration::from_secs(secs + 1));
+        }
+    }
+    Some(SECONDARY_BACKOFF * 2u32.saturating_pow(attempt))
+}
+
+/// Remember the latest budget of its resource.
+pub fn record(limit: RateLimit) {
+    if let Ok(mut budgets) = BUDGETS.lock() {
+        budgets.insert(limit.resource.clone(), limit);
+    }
+}
+
+/// The latest budget of each resource seen in this process, by resource
+/// name.
+pub fn budgets() -> Vec<RateLimit> {
+    BUDGETS
+        .lock()
+        .map(|budgets| budgets.values().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Hold off every request in this process for `delay`, and count a retry.
+pub fn pause(delay: Duration) {
+    RETRIES.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut until) = PAUSED_UNTIL.lock() {
+        let end = Instant::now() + delay;
+        if until.is_none_or(|current| current < end) {
+            *until = Some(end);
+        }
+    }
+}
+
+/// How much longer requests are held off, if at all.
+pub fn paused_for() -> Option<Duration> {
+    let until = (*PAUSED_UNTIL.lock().ok()?)?;
+    until
+        .checked_duration_since(Instant::now())
+        .filter(|left| !left.is_zero())
+}
+
+/// Retries made after rate-limit refusals in this process.
+pub fn retries() -> u32 {
+    RETRIES.load(Ordering::Relaxed)
+}
+
+/// One line for `--debug` output summarizing the budgets seen and the
+/// retries made, or `None` if no GitHub request was made.
+pub fn debug_summary(now: DateTime<Utc>) -> Option<String> {
+    let budgets = budgets();
+    if budgets.is_empty() && retries() == 0 {
+        return None;
+    }
+    let mut parts: Vec<String> = budgets.iter().map(|b| b.describe(now)).collect();
+    parts.push(format!("{} rate-limit retries", retries()));
+    Some(format!("GitHub API quota: {}", parts.join("; ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn parses_budget_headers() {
+        let limit = RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "12"),
+            ("x-ratelimit-used", "4988"),
+            ("x-ratelimit-reset", "1700000600"),
+            ("x-ratelimit-resource", "graphql"),
+        ]))
+        .unwrap();
+        assert_eq!(limit.resource, "graphql");
+        assert_eq!(limit.remaining, 12);
+        assert_eq!(limit.used, 4988);
+
+        let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        assert_eq!(
+            limit.describe(now),
+            "graphql: 12 of 5000 requests left, resets in 10 min"
+        );
+    }
+
+    #[test]
+    fn missing_headers_mean_no_budget() {
+        assert!(RateLimit::from_headers(&headers(&[("x-ratelimit-limit", "60")])).is_none());
+        assert!(RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-limit", "60"),
+            ("x-ratelimit-remaining", "lots"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]))
+        .is_none());
+    }
+
+    #[test]
+    fn spent_budget_waits_for_reset() {
+        let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let spent = headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1700000030"),
+        ]);
+        assert_eq!(
+            retry_delay(
+                StatusCode::FORBIDDEN,
+                &spent,
+                "API rate limit exceeded",
+                0,
+                now
+            ),
+            Some(Duration::from_secs(31))
+        );
+    }
+
+    #[test]
+    fn secondary_limit_backs_off_exponentially() {
+        let now = Utc::now();
+        let message = "You have exceeded a secondary rate limit. Please wait a few minutes.";
+        let none = HeaderMap::new();
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &none, message, 0, now),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &none, message, 2, now),
+            Some(Duration::from_secs(240))
+        );
+        assert_eq!(
+            retry_delay(StatusCode::TOO_MANY_REQUESTS, &none, "", 1, now),
+            Some(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn other_refusals_are_not_throttling() {
+        let now = Utc::now();
+        let budget = headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "4000"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]);
+        assert_eq!(
+            retry_delay(
+                StatusCode::FORBIDDEN,
+                &budget,
+                "Must have admin rights",
+                0,
+                now
+            ),
+            None
+        );
+        assert_eq!(
+            retry_delay(
+                StatusCode::NOT_FOUND,
+                &headers(&[("retry-after", "1")]),
+                "",
+                0,
+                now
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! forge::status_cache
+//!
+//! Cached PR and check status, for rendering remote data without waiting on
+//! the forge.
+//!
+//! Stored at `<common_dir>/lattice/cache/forge_status.json`.
+//!
+//! # Design
+//!
+//! Entries are keyed by branch and record the PR number they describe, so a
+//! branch linked to a different PR since the fetch shows nothing rather than
+//! stale data. Navigation commands fill the cache in the background
+//! (`lattice prefetch`); `lattice log` and `lattice info` read it.
+//!
+//! Each navigation that considers a prefetch counts as a lookup: a hit when
+//! the branch's entry is fresh enough to skip it. `lattice debug
+//! cache-stats` reports the counts.
+//!
+//! Prefetching is rate-limited twice over: a branch is refetched only once
+//! its entry is older than the configured interval, and background fetches
+//! start at most once per [`MIN_PREFETCH_GAP_SECS`] however fast the user
+//! navigates.
+//!
+//! Like the authorization cache, this file is a hint: load and save failures
+//! are ignored, and nothing here affects local correctness.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::{CheckState, CheckStatus, PrStatus, PullRequest, ReviewState};
+use crate::core::cache_stats::HitStats;
+use crate::core::metadata::schema::CiState;
+use crate::core::paths::LatticePaths;
+
+/// Minimum time between two background prefetches, in seconds.
+pub const MIN_PREFETCH_GAP_SECS: i64 = 5;
+
+/// Check results summarized by outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckCounts {
+    /// Checks that succeeded or were skipped
+    pub passed: usize,
+    /// Checks that failed
+    pub failed: usize,
+    /// Checks still running
+    pub pending: usize,
+}
+
+impl CheckCounts {
+    /// Summarize a list of checks.
+    pub fn from_checks(checks: &[CheckStatus]) -> Self {
+        let mut counts = Self::default();
+        for check in checks {
+            match check.state {
+                CheckState::Success | CheckState::Skipped => counts.passed += 1,
+                CheckState::Failure => counts.failed += 1,
+                CheckState::Pending => counts.pending += 1,
+            }
+        }
+        counts
+    }
+
+    /// Overall outcome, or `None` when there are no checks.
+    ///
+    /// Any failure fails the commit; otherwise any running check keeps it
+    /// pending.
+    pub fn ci_state(&self) -> Option<CiState> {
+        if self.failed > 0 {
+            Some(CiState::Failing)
+        } else if self.pending > 0 {
+            Some(CiState::Pending)
+        } else if self.passed > 0 {
+            Some(CiState::Passing)
+        } else {
+            None
+        }
+    }
+}
+
+/// A branch's PR status as last fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedPrStatus {
+    /// PR number the status describes
+    pub number: u64,
+    /// PR state (open, closed, merged)
+    pub state: String,
+    /// Whether the PR is a draft
+    pub is_draft: bool,
+    /// CI checks, if they could be listed
+    pub checks: Option<CheckCounts>,
+    /// Whether the PR merges cleanly, if known
+    #[serde(default)]
+    pub mergeable: Option<bool>,
+    /// The reviewers' verdict (e.g. `approved`), if known
+    #[serde(default)]
+    pub review: Option<String>,
+    /// Individual reviews and what the base branch requires, if known
+    #[serde(default)]
+    pub reviews: Option<ReviewState>,
+    /// When this was fetched
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedPrStatus {
+    /// Build an entry from a fetched PR and its checks.
+    pub fn from_forge(pr: &PullRequest, checks: Option<&[CheckStatus]>) -> Self {
+        Self {
+            number: pr.number,
+            state: pr.state.to_string(),
+            is_draft: pr.is_draft,
+            checks: checks.map(CheckCounts::from_checks),
+            mergeable: None,
+            review: None,
+            reviews: None,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    /// Build an entry from a batched status lookup, which also knows the
+    /// PR's mergeability and review decision.
+    pub fn from_status(status: &PrStatus) -> Self {
+        Self {
+            mergeable: status.mergeable,
+            review: status.review_decision.map(|d| d.to_string()),
+            reviews: status.reviews.clone(),
+            ..Self::from_forge(&status.pr, Some(&status.checks))
+        }
+    }
+
+    /// Short description, e.g. `#12 draft, 1 check failing`.
+    pub fn label(&self) -> String {
+        let state = if self.is_draft && self.state == "open" {
+            "draft"
+        } else {
+            self.state.as_str()
+        };
+        let mut label = format!("#{} {}", self.number, state);
+        match self.checks {
+            Some(c) if c.failed > 0 => {
+                label.push_str(&format!(
+                    ", {} check{} failing",
+                    c.failed,
+                    if c.failed == 1 { "" } else { "s" }
+                ));
+            }
+            Some(c) if c.pending > 0 => label.push_str(", checks pending"),
+            Some(c) if c.passed > 0 => label.push_str(", checks passed"),
+            _ => {}
+        }
+        if self.state == "open" {
+            if self.mergeable == Some(false) {
+                label.push_str(", has conflicts");
+            }
+            if let Some(review) = &self.review {
+                label.push_str(&format!(", {}", review));
+            }
+        }
+        label
+    }
+}
+
+/// Forge status cache stored at `<common_dir>/lattice/cache/forge_status.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ForgeStatusCache {
+    /// Branch name to its PR status
+    entries: HashMap<String, CachedPrStatus>,
+    /// When the last background prefetch started
+    last_prefetch: Option<DateTime<Utc>>,
+    /// Navigation lookups of the entries
+    #[serde(default)]
+    stats: HitStats,
+}
+
+impl ForgeStatusCache {
+    /// Load the cache; a missing or unreadable file is empty.
+    pub fn load(paths: &LatticePaths) -> Self {
+        fs::read_to_string(Self::cache_path(paths))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the cache. Best-effort; errors are ignored.
+    pub fn save(&self, paths: &LatticePaths) {
+        let path = Self::cache_path(paths);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(content) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(&path, content);
+        }
+    }
+
+    /// The cached status of `branch`'s PR, if it describes PR `number`.
+    pub fn get(&self, branch: &str, number: u64) -> Option<&CachedPrStatus> {
+        self.entries.get(branch).filter(|e| e.number == number)
+    }
+
+    /// Store the status of `branch`'s PR.
+    pub fn set(&mut self, branch: &str, status: CachedPrStatus) {
+        self.entries.insert(branch.to_string(), status);
+    }
+
+    /// Check whether a background prefetch of `branch` (PR `number`) is due.
+    ///
+    /// It is due when the branch's entry is missing or older than
+    /// `interval`, and no other prefetch started in the last
+    /// [`MIN_PREFETCH_GAP_SECS`].
+    pub fn prefetch_due(
+        &self,
+        branch: &str,
+        number: u64,
+        interval: Duration,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let fresh = self.is_fresh(branch, number, interval, now);
+        let throttled = self
+            .last_prefetch
+            .is_some_and(|at| now - at < Duration::seconds(MIN_PREFETCH_GAP_SECS));
+        !fresh && !throttled
+    }
+
+    /// Count a navigation's lookup of `branch`'s status (PR `number`).
+    ///
+    /// It is a hit when the entry is younger than `interval`.
+    pub fn record_lookup(
+        &mut self,
+        branch: &str,
+        number: u64,
+        interval: Duration,
+        now: DateTime<Utc>,
+    ) {
+        let fresh = self.is_fresh(branch, number, interval, now);
+        self.stats.record(fresh);
+    }
+
+    /// Record that a background prefetch started at `now`.
+    pub fn mark_prefetch(&mut self, now: DateTime<Utc>) {
+        self.last_prefetch = Some(now);
+    }
+
+    /// Get the number of branches with a cached status.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Navigation lookups counted so far.
+    pub fn stats(&self) -> HitStats {
+        self.stats
+    }
+
+    /// Get the cache file path.
+    pub fn cache_path(paths: &LatticePaths) -> PathBuf {
+        paths.repo_cache_dir().join("forge_status.json")
+    }
+
+    /// Whether `branch`'s entry describes PR `number` and is younger than
+    /// `interval`.
+    fn is_fresh(&self, branch: &str, number: u64, interval: Duration, now: DateTime<Utc>) -> bool {
+        self.get(branch, number)
+            .is_some_and(|e| now - e.fetched_at < interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(number: u64, fetched_at: DateTime<Utc>) -> CachedPrStatus {
+        CachedPrStatus {
+            number,
+            state: "open".to_string(),
+            is_draft: false,
+            checks: Some(CheckCounts {
+                passed: 2,
+                failed: 1,
+                pending: 0,
+            }),
+            mergeable: None,
+            review: None,
+            reviews: None,
+            fetched_at,
+        }
+    }
+
+    #[test]
+    fn label_prefers_failures() {
+        let mut s = status(12, Utc::now());
+        assert_eq!(s.label(), "#12 open, 1 check failing");
+
+        s.is_draft = true;
+        s.checks = Some(CheckCounts {
+            passed: 3,
+            failed: 0,
+            pending: 0,
+        });
+        assert_eq!(s.label(), "#12 draft, checks passed");
+    }
+
+    #[test]
+    fn label_shows_conflicts_and_review_while_open() {
+        let mut s = status(12, Utc::now());
+        s.checks = None;
+        s.mergeable = Some(false);
+        s.review = Some("changes requested".to_string());
+        assert_eq!(s.label(), "#12 open, has conflicts, changes requested");
+
+        s.state = "merged".to_string();
+        assert_eq!(s.label(), "#12 merged");
+    }
+
+    #[test]
+    fn ci_state_prefers_failures_then_pending() {
+        let counts = |passed, failed, pending| CheckCounts {
+            passed,
+            failed,
+            pending,
+        };
+        assert_eq!(counts(2, 1, 1).ci_state(), Some(CiState::Failing));
+        assert_eq!(counts(2, 0, 1).ci_state(), Some(CiState::Pending));
+        assert_eq!(counts(2, 0, 0).ci_state(), Some(CiState::Passing));
+        assert_eq!(counts(0, 0, 0).ci_state(), None);
+    }
+
+    #[test]
+    fn get_ignores_other_pr_numbers() {
+        let mut cache = ForgeStatusCache::default();
+        cache.set("feature", status(12, Utc::now()));
+        assert!(cache.get("feature", 12).is_some());
+        assert!(cache.get("feature", 13).is_none());
+    }
+
+    #[test]
+    fn prefetch_is_rate_limited() {
+        let now = Utc::now();
+        let interval = Duration::seconds(60);
+        let mut cache = ForgeStatusCache::default();
+        assert!(cache.prefetch_due("feature", 12, interval, now));
+
+        // Fresh entries aren't refetched
+        cache.set("feature", status(12, now - Duration::seconds(30)));
+        assert!(!cache.prefetch_due("feature", 12, interval, now));
+        cache.set("feature", status(12, now - Duration::seconds(90)));
+        assert!(cache.prefetch_due("feature", 12, interval, now));
+
+        // Nor does anything start right after another prefetch
+        cache.mark_prefetch(now - Duration::seconds(1));
+        assert!(!cache.prefetch_due("feature", 12, interval, now));
+        assert!(!cache.prefetch_due("other", 7, interval, now));
+    }
+
+    #[test]
+    fn lookups_hit_fresh_entries() {
+        let now = Utc::now();
+        let interval = Duration::seconds(60);
+        let mut cache = ForgeStatusCache::default();
+        cache.record_lookup("feature", 12, interval, now);
+
+        cache.set("feature", status(12, now - Duration::seconds(30)));
+        cache.record_lookup("feature", 12, interval, now);
+        cache.record_lookup("feature", 13, interval, now);
+        assert_eq!(cache.stats(), HitStats { hits: 1, misses: 2 });
+    }
+}
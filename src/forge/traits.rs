@@ -32,6 +32,7 @@
 //! ```
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Errors from forge operations.
@@ -69,6 +70,10 @@ pub enum ForgeError {
     #[error("network error: {0}")]
     NetworkError(String),
 
+    /// The request exceeded a configured network timeout.
+    #[error("timed out: {0}")]
+    Timeout(String),
+
     /// The operation is not supported by this forge.
     #[error("not implemented: {0}")]
     NotImplemented(String),
@@ -123,6 +128,8 @@ pub struct PullRequest {
     pub body: Option<String>,
     /// GraphQL node ID (for draft toggle mutations)
     pub node_id: Option<String>,
+    /// Login of the PR's author, when the forge reports it
+    pub author: Option<String>,
 }
 
 /// PR state.
@@ -146,6 +153,17 @@ impl std::fmt::Display for PrState {
     }
 }
 
+/// Issue information returned from the forge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    /// Issue number
+    pub number: u64,
+    /// Issue URL (web URL for viewing)
+    pub url: String,
+    /// Issue title
+    pub title: String,
+}
+
 /// Reviewers to request on a PR.
 #[derive(Debug, Clone, Default)]
 pub struct Reviewers {
@@ -184,6 +202,156 @@ impl std::fmt::Display for MergeMethod {
     }
 }
 
+/// State of a PR in a merge queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeQueueState {
+    /// Waiting for its turn
+    Queued,
+    /// Its merge group is being tested
+    AwaitingChecks,
+    /// Checks passed; it will be merged when it reaches the front
+    Mergeable,
+    /// It can't be merged and will be removed from the queue
+    Unmergeable,
+    /// The queue is blocked on it
+    Locked,
+}
+
+impl std::fmt::Display for MergeQueueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeQueueState::Queued => write!(f, "queued"),
+            MergeQueueState::AwaitingChecks => write!(f, "awaiting checks"),
+            MergeQueueState::Mergeable => write!(f, "mergeable"),
+            MergeQueueState::Unmergeable => write!(f, "unmergeable"),
+            MergeQueueState::Locked => write!(f, "locked"),
+        }
+    }
+}
+
+/// A PR's place in its base branch's merge queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeQueueEntry {
+    /// 1-based position, if the forge reports one
+    pub position: Option<u64>,
+    /// State of the entry
+    pub state: MergeQueueState,
+}
+
+/// State of a CI check on a PR's head commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckState {
+    /// Check is queued or still running
+    Pending,
+    /// Check completed successfully
+    Success,
+    /// Check failed, errored, timed out, or was cancelled
+    Failure,
+    /// Check completed without a verdict (neutral or skipped)
+    Skipped,
+}
+
+impl std::fmt::Display for CheckState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckState::Pending => write!(f, "pending"),
+            CheckState::Success => write!(f, "success"),
+            CheckState::Failure => write!(f, "failure"),
+            CheckState::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// A CI check (check run or commit status) reported on a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckStatus {
+    /// Check name (check run name or status context)
+    pub name: String,
+    /// Current state of the check
+    pub state: CheckState,
+    /// Whether branch protection requires this check to pass
+    pub required: bool,
+}
+
+impl CheckStatus {
+    /// Whether this is a required check that has failed.
+    pub fn is_required_failure(&self) -> bool {
+        self.required && self.state == CheckState::Failure
+    }
+}
+
+/// The reviewers' overall verdict on a pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    /// The required reviews approve the PR
+    Approved,
+    /// A reviewer requested changes
+    ChangesRequested,
+    /// Reviews are required and not yet given
+    ReviewRequired,
+}
+
+impl std::fmt::Display for ReviewDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewDecision::Approved => write!(f, "approved"),
+            ReviewDecision::ChangesRequested => write!(f, "changes requested"),
+            ReviewDecision::ReviewRequired => write!(f, "review required"),
+        }
+    }
+}
+
+/// Who reviewed a pull request, and what reviews its base branch requires.
+///
+/// Each reviewer appears under their latest approving or change-requesting
+/// review only.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewState {
+    /// Reviewers whose approval is of the PR's head commit
+    pub approved_by: Vec<String>,
+    /// Reviewers who approved an older commit of the PR
+    pub stale_approvals: Vec<String>,
+    /// Reviewers who requested changes
+    pub changes_requested_by: Vec<String>,
+    /// Approving reviews the base branch requires, if it is protected
+    pub required_approvals: Option<u32>,
+    /// Whether approvals of older commits are dismissed and so don't count
+    pub dismisses_stale: bool,
+    /// Whether the base branch requires a review from a code owner
+    pub requires_code_owners: bool,
+    /// Code owners (users, or teams as `team:<slug>`) asked to review who
+    /// haven't yet
+    pub pending_code_owners: Vec<String>,
+}
+
+impl ReviewState {
+    /// Approvals that count toward the required number.
+    pub fn counted_approvals(&self) -> usize {
+        if self.dismisses_stale {
+            self.approved_by.len()
+        } else {
+            self.approved_by.len() + self.stale_approvals.len()
+        }
+    }
+}
+
+/// A pull request together with what stands between it and a merge.
+#[derive(Debug, Clone)]
+pub struct PrStatus {
+    /// The pull request
+    pub pr: PullRequest,
+    /// Whether the PR merges cleanly into its base, if the forge has
+    /// computed it
+    pub mergeable: Option<bool>,
+    /// The reviewers' verdict, if the forge reports one
+    pub review_decision: Option<ReviewDecision>,
+    /// Individual reviews and review requirements, if the forge reports them
+    pub reviews: Option<ReviewState>,
+    /// CI checks on the PR's head commit
+    pub checks: Vec<CheckStatus>,
+}
+
 /// Options for listing pull requests.
 ///
 /// Controls pagination and filtering for bulk PR queries.
@@ -308,6 +476,7 @@ impl ListClosedPrsOpts {
 ///     is_draft: false,
 ///     url: "https://github.com/owner/repo/pull/42".to_string(),
 ///     updated_at: "2024-01-15T10:30:00Z".to_string(),
+///     author: Some("octocat".to_string()),
 /// };
 ///
 /// assert!(!summary.is_fork());
@@ -328,6 +497,8 @@ pub struct PullRequestSummary {
     pub url: String,
     /// Last updated timestamp (ISO 8601)
     pub updated_at: String,
+    /// Login of the PR's author, when the forge reports it
+    pub author: Option<String>,
 }
 
 impl PullRequestSummary {
@@ -370,7 +541,7 @@ pub struct ListPullsResult {
 /// - `NotFound`: Resource doesn't exist
 /// - `RateLimited`: Back off and retry
 /// - `ApiError`: Display error message to user
-/// - `NetworkError`: Check connectivity
+/// - `NetworkError` / `Timeout`: Check connectivity
 ///
 /// # Example
 ///
@@ -455,6 +626,28 @@ pub trait Forge: Send + Sync {
     /// - `NotFound` if the PR doesn't exist
     async fn get_pr(&self, number: u64) -> Result<PullRequest, ForgeError>;
 
+    /// Get the status of several pull requests at once.
+    ///
+    /// Fetches each PR with its mergeability, review decision, reviews, and
+    /// checks.
+    /// Forges that can batch do so in as few requests as they allow, which
+    /// keeps `sync` on a large stack from making one request per branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `numbers` - The PR numbers
+    ///
+    /// # Returns
+    ///
+    /// The statuses, in the order of `numbers`. PRs that don't exist are
+    /// left out rather than failing the whole lookup.
+    ///
+    /// # Errors
+    ///
+    /// - `AuthFailed` if the token is invalid or lacks permissions
+    /// - `NetworkError` if the request fails
+    async fn get_pr_statuses(&self, numbers: &[u64]) -> Result<Vec<PrStatus>, ForgeError>;
+
     /// Find a pull request by head branch.
     ///
     /// This searches for an open PR with the given head branch.
@@ -510,6 +703,48 @@ pub trait Forge: Send + Sync {
     /// - `ApiError` if merge fails (e.g., conflicts, required checks failing)
     async fn merge_pr(&self, number: u64, method: MergeMethod) -> Result<(), ForgeError>;
 
+    /// Add a pull request to its base branch's merge queue.
+    ///
+    /// The queue merges it, with the queue's own merge method, once its
+    /// merge group passes checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The PR number
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the PR doesn't exist
+    /// - `ApiError` if the PR can't be queued (e.g., its base branch has no
+    ///   merge queue, or it isn't open)
+    /// - `NotImplemented` if the forge has no merge queues
+    async fn enqueue_pr(&self, number: u64) -> Result<MergeQueueEntry, ForgeError>;
+
+    /// Close a pull request without merging it.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The PR number
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the PR doesn't exist
+    /// - `ApiError` if the PR cannot be closed (e.g., already merged)
+    async fn close_pr(&self, number: u64) -> Result<(), ForgeError>;
+
+    /// Add a comment to a pull request's conversation.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The PR number
+    /// * `body` - Comment text (Markdown)
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the PR doesn't exist
+    /// - `AuthFailed` if lacking permissions to comment
+    async fn comment_pr(&self, number: u64, body: &str) -> Result<(), ForgeError>;
+
     /// List open pull requests.
     ///
     /// Returns open PRs up to the configured limit, ordered by most recently
@@ -561,6 +796,52 @@ pub trait Forge: Send + Sync {
         &self,
         opts: ListClosedPrsOpts,
     ) -> Result<ListPullsResult, ForgeError>;
+
+    /// List CI checks on a pull request's head commit.
+    ///
+    /// Includes both check runs and legacy commit statuses, each flagged
+    /// with whether branch protection requires it.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The PR number
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the PR doesn't exist
+    /// - `AuthFailed` if the token is invalid or lacks permissions
+    /// - `NetworkError` if the request fails
+    async fn list_checks(&self, number: u64) -> Result<Vec<CheckStatus>, ForgeError>;
+
+    /// List CI checks on a commit, whether or not a PR points at it.
+    ///
+    /// Includes both check runs and legacy commit statuses. Without a PR
+    /// there is no branch protection to consult, so no check is flagged as
+    /// required. A commit CI never saw has no checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - Commit SHA (or branch name) to look up
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the forge doesn't know the commit
+    /// - `AuthFailed` if the token is invalid or lacks permissions
+    /// - `NetworkError` if the request fails
+    async fn check_runs_for_ref(&self, git_ref: &str) -> Result<Vec<CheckStatus>, ForgeError>;
+
+    /// Get an issue by number.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The issue number
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the issue doesn't exist, or the number is a PR
+    /// - `AuthFailed` if the token is invalid or lacks permissions
+    /// - `NetworkError` if the request fails
+    async fn get_issue(&self, number: u64) -> Result<Issue, ForgeError>;
 }
 
 #[cfg(test)]
@@ -574,6 +855,15 @@ mod tests {
         assert_eq!(format!("{}", PrState::Merged), "merged");
     }
 
+    #[test]
+    fn review_decision_display() {
+        assert_eq!(ReviewDecision::Approved.to_string(), "approved");
+        assert_eq!(
+            ReviewDecision::ChangesRequested.to_string(),
+            "changes requested"
+        );
+    }
+
     #[test]
     fn merge_method_display() {
         assert_eq!(format!("{}", MergeMethod::Merge), "merge");
@@ -581,6 +871,27 @@ mod tests {
         assert_eq!(format!("{}", MergeMethod::Rebase), "rebase");
     }
 
+    #[test]
+    fn required_failure_ignores_optional_checks() {
+        let check = |state, required| CheckStatus {
+            name: "ci".to_string(),
+            state,
+            required,
+        };
+        assert!(check(CheckState::Failure, true).is_required_failure());
+        assert!(!check(CheckState::Failure, false).is_required_failure());
+        assert!(!check(CheckState::Pending, true).is_required_failure());
+    }
+
+    #[test]
+    fn merge_queue_state_display() {
+        assert_eq!(MergeQueueState::Queued.to_string(), "queued");
+        assert_eq!(
+            MergeQueueState::AwaitingChecks.to_string(),
+            "awaiting checks"
+        );
+    }
+
     #[test]
     fn merge_method_default_is_squash() {
         assert_eq!(MergeMethod::default(), MergeMethod::Squash);
@@ -716,6 +1027,7 @@ mod tests {
                 is_draft: false,
                 url: "https://example.com".into(),
                 updated_at: "2024-01-01T00:00:00Z".into(),
+                author: None,
             };
             assert!(summary.is_fork());
         }
@@ -730,6 +1042,7 @@ mod tests {
                 is_draft: false,
                 url: "https://example.com".into(),
                 updated_at: "2024-01-01T00:00:00Z".into(),
+                author: None,
             };
             assert!(!summary.is_fork());
         }
@@ -759,6 +1072,7 @@ mod tests {
                     is_draft: false,
                     url: "https://example.com".into(),
                     updated_at: "2024-01-01T00:00:00Z".into(),
+                    author: None,
                 }],
                 truncated: true,
             };
@@ -0,0 +1,302 @@
+//! git::ancestry_cache
+//!
+//! Persistent cache of ancestry answers: merge bases, is-ancestor checks,
+//! and commit counts.
+//!
+//! Stored at `<common_dir>/lattice/cache/ancestry.json`.
+//!
+//! # Design
+//!
+//! Commits never change, so neither do the answers to ancestry queries
+//! between two of them. Walking history for them is most of the time a scan
+//! takes in a large repository, so [`Git`](super::Git) remembers them across
+//! runs: it loads the cache on the first query and saves it when dropped.
+//! Saving reloads the file first, so handles open at the same time don't
+//! lose each other's answers.
+//!
+//! Shallow repositories are never cached, since deepening one changes the
+//! answers.
+//!
+//! The cache holds at most `[cache] ancestry_entries` answers (global
+//! config, default [`DEFAULT_MAX_ENTRIES`]; `0` turns it off), dropping the
+//! oldest first. Like the other caches, the file is a hint: load and save
+//! failures are ignored.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::core::cache_stats::HitStats;
+use crate::core::paths::LatticePaths;
+use crate::core::types::Oid;
+
+/// Number of answers kept when the config doesn't say.
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+static MAX_ENTRIES: OnceLock<usize> = OnceLock::new();
+
+/// Set the cache size for this process.
+///
+/// Only the first call has an effect; until it runs, the default applies.
+pub fn init(max_entries: usize) {
+    let _ = MAX_ENTRIES.set(max_entries);
+}
+
+/// The number of answers the cache may hold; `0` means it is off.
+pub fn max_entries() -> usize {
+    MAX_ENTRIES.get().copied().unwrap_or(DEFAULT_MAX_ENTRIES)
+}
+
+/// An ancestry query between two commits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query<'a> {
+    /// Whether `ancestor` is an ancestor of `descendant`
+    IsAncestor {
+        /// Possible ancestor
+        ancestor: &'a Oid,
+        /// Possible descendant
+        descendant: &'a Oid,
+    },
+    /// The merge base of two commits, in either order
+    MergeBase(&'a Oid, &'a Oid),
+    /// The number of commits reachable from `tip` but not from `base`
+    CommitCount {
+        /// Excluded commit
+        base: &'a Oid,
+        /// Included commit
+        tip: &'a Oid,
+    },
+}
+
+impl Query<'_> {
+    fn key(&self) -> String {
+        match self {
+            Query::IsAncestor {
+                ancestor,
+                descendant,
+            } => format!("is-ancestor {} {}", ancestor, descendant),
+            Query::MergeBase(a, b) => {
+                let (a, b) = if a.as_str() <= b.as_str() {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                format!("merge-base {} {}", a, b)
+            }
+            Query::CommitCount { base, tip } => format!("commit-count {} {}", base, tip),
+        }
+    }
+}
+
+/// The answer to a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Answer {
+    /// Answer to [`Query::IsAncestor`]
+    IsAncestor(bool),
+    /// Answer to [`Query::MergeBase`]; `None` for unrelated commits
+    MergeBase(Option<Oid>),
+    /// Answer to [`Query::CommitCount`]
+    CommitCount(usize),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    answer: Answer,
+    /// Insertion order; lower entries are evicted first
+    seq: u64,
+}
+
+/// Ancestry cache stored at `<common_dir>/lattice/cache/ancestry.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AncestryCache {
+    /// Query key to its answer
+    entries: HashMap<String, Entry>,
+    /// Sequence number of the next entry
+    next_seq: u64,
+    /// Lookups counted in earlier runs
+    stats: HitStats,
+    /// Answers computed since loading
+    #[serde(skip)]
+    added: HashMap<String, Answer>,
+    /// Lookups counted since loading
+    #[serde(skip)]
+    counted: HitStats,
+}
+
+impl AncestryCache {
+    /// Load the cache; a missing or unreadable file is empty.
+    pub fn load(paths: &LatticePaths) -> Self {
+        fs::read_to_string(Self::cache_path(paths))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save what changed since loading. Best-effort; errors are ignored.
+    ///
+    /// Merges into the file as it is now and evicts the oldest answers
+    /// beyond [`max_entries`].
+    pub fn save(&self, paths: &LatticePaths) {
+        if self.added.is_empty() && self.counted == HitStats::default() {
+            return;
+        }
+        let mut merged = Self::load(paths);
+        for (key, answer) in &self.added {
+            if !merged.entries.contains_key(key) {
+                let seq = merged.next_seq;
+                merged.next_seq += 1;
+                merged.entries.insert(
+                    key.clone(),
+                    Entry {
+                        answer: answer.clone(),
+                        seq,
+                    },
+                );
+            }
+        }
+        merged.stats.hits += self.counted.hits;
+        merged.stats.misses += self.counted.misses;
+        merged.evict(max_entries());
+
+        let path = Self::cache_path(paths);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(content) = serde_json::to_string(&merged) {
+            let _ = fs::write(&path, content);
+        }
+    }
+
+    /// Look up the answer to `query`, counting a hit or miss.
+    pub fn lookup(&mut self, query: Query<'_>) -> Option<Answer> {
+        let key = query.key();
+        let answer = self
+            .added
+            .get(&key)
+            .or_else(|| self.entries.get(&key).map(|e| &e.answer))
+            .cloned();
+        self.counted.record(answer.is_some());
+        answer
+    }
+
+    /// Remember the answer to `query`.
+    pub fn store(&mut self, query: Query<'_>, answer: Answer) {
+        self.added.insert(query.key(), answer);
+    }
+
+    /// Get the number of answers in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len() + self.added.len()
+    }
+
+    /// Check if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hits and misses over the life of the cache file.
+    pub fn stats(&self) -> HitStats {
+        HitStats {
+            hits: self.stats.hits + self.counted.hits,
+            misses: self.stats.misses + self.counted.misses,
+        }
+    }
+
+    /// Get the cache file path.
+    pub fn cache_path(paths: &LatticePaths) -> PathBuf {
+        paths.repo_cache_dir().join("ancestry.json")
+    }
+
+    /// Drop the oldest entries until at most `max` remain.
+    fn evict(&mut self, max: usize) {
+        if self.entries.len() <= max {
+            return;
+        }
+        if max == 0 {
+            self.entries.clear();
+            return;
+        }
+        let mut seqs: Vec<u64> = self.entries.values().map(|e| e.seq).collect();
+        seqs.sort_unstable();
+        let cutoff = seqs[seqs.len() - max];
+        self.entries.retain(|_, e| e.seq >= cutoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn oid(c: char) -> Oid {
+        Oid::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    #[test]
+    fn merge_base_ignores_argument_order() {
+        let (a, b) = (oid('a'), oid('b'));
+        let mut cache = AncestryCache::default();
+        cache.store(Query::MergeBase(&a, &b), Answer::MergeBase(Some(oid('c'))));
+
+        assert_eq!(
+            cache.lookup(Query::MergeBase(&b, &a)),
+            Some(Answer::MergeBase(Some(oid('c'))))
+        );
+        assert_eq!(
+            cache.lookup(Query::IsAncestor {
+                ancestor: &a,
+                descendant: &b
+            }),
+            None
+        );
+        assert_eq!(cache.stats(), HitStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn save_merges_with_the_file() {
+        let dir = TempDir::new().unwrap();
+        let paths = LatticePaths::new(dir.path().to_path_buf(), dir.path().to_path_buf());
+        let (a, b, c) = (oid('a'), oid('b'), oid('c'));
+        let query = |tip| Query::CommitCount { base: &a, tip };
+
+        // Two handles loaded before either saved
+        let mut first = AncestryCache::load(&paths);
+        let mut second = AncestryCache::load(&paths);
+        first.lookup(query(&b));
+        first.store(query(&b), Answer::CommitCount(2));
+        first.save(&paths);
+        second.lookup(query(&c));
+        second.store(query(&c), Answer::CommitCount(3));
+        second.save(&paths);
+
+        let mut loaded = AncestryCache::load(&paths);
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.stats(), HitStats { hits: 0, misses: 2 });
+        assert_eq!(loaded.lookup(query(&b)), Some(Answer::CommitCount(2)));
+    }
+
+    #[test]
+    fn evicts_oldest_first() {
+        let base = oid('0');
+        let tips: Vec<Oid> = "abc".chars().map(oid).collect();
+        let mut cache = AncestryCache::default();
+        for (seq, tip) in tips.iter().enumerate() {
+            let key = Query::CommitCount { base: &base, tip }.key();
+            let entry = Entry {
+                answer: Answer::CommitCount(seq),
+                seq: seq as u64,
+            };
+            cache.entries.insert(key, entry);
+        }
+
+        cache.evict(2);
+        let query = |tip| Query::CommitCount { base: &base, tip };
+        assert_eq!(cache.lookup(query(&tips[0])), None);
+        assert!(cache.lookup(query(&tips[1])).is_some());
+        assert!(cache.lookup(query(&tips[2])).is_some());
+    }
+}
@@ -36,10 +36,14 @@
 //! println!("main is at {}", oid.short(7));
 //! ```
 
+use std::cell::{RefCell, RefMut};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
+use super::ancestry_cache::{self, AncestryCache, Answer, Query};
+use crate::core::paths::LatticePaths;
 use crate::core::types::{BranchName, Oid, RefName, TypeError};
 
 /// Errors from Git operations.
@@ -507,6 +511,153 @@ pub struct CommitInfo {
     pub author_time: chrono::DateTime<chrono::Utc>,
 }
 
+/// Summary of changes between two commits.
+///
+/// Produced by [`Git::diff_stat`]. Mirrors the totals line of
+/// `git diff --stat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    /// Number of files changed
+    pub files_changed: usize,
+    /// Number of inserted lines
+    pub insertions: usize,
+    /// Number of deleted lines
+    pub deletions: usize,
+}
+
+impl DiffStat {
+    /// Total number of changed lines (insertions + deletions).
+    pub fn lines_changed(&self) -> usize {
+        self.insertions + self.deletions
+    }
+}
+
+/// How a file changed between two commits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChange {
+    /// The file is new
+    Added,
+    /// The file's content or mode changed
+    Modified,
+    /// The file was removed
+    Deleted,
+}
+
+impl FileChange {
+    /// One-letter marker, as `git diff --name-status` prints it.
+    pub fn marker(self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Modified => 'M',
+            Self::Deleted => 'D',
+        }
+    }
+}
+
+/// A file that differs between two commits.
+///
+/// Produced by [`Git::changed_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// Path relative to the repository root
+    pub path: String,
+    /// How it changed
+    pub change: FileChange,
+}
+
+/// A staged file, as zero-context hunks against HEAD.
+///
+/// Produced by [`Git::staged_hunks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedFile {
+    /// Path relative to the repository root
+    pub path: String,
+    /// Whether the file is a text file modified in place; added, deleted,
+    /// renamed, and binary files have no hunks
+    pub modified: bool,
+    /// Hunks in file order
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// One hunk of a zero-context diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// First replaced line (1-based). When nothing is replaced, the line
+    /// the new lines go after (0 for the top of the file).
+    pub old_start: usize,
+    /// Number of replaced lines
+    pub old_lines: usize,
+    /// Lines that replace them, each with its line ending
+    pub new_lines: Vec<Vec<u8>>,
+}
+
+/// How a path conflicts in the index, in `git status` terms.
+///
+/// "Us" is the side being applied onto (HEAD), "them" the commit being
+/// applied. During a rebase that means "us" is the new base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// Changed on both sides
+    BothModified,
+    /// Added on both sides with different content
+    BothAdded,
+    /// Deleted on both sides (a rename conflict)
+    BothDeleted,
+    /// Added by us only (the other side renamed it)
+    AddedByUs,
+    /// Added by them only (our side renamed it)
+    AddedByThem,
+    /// Deleted by us, modified by them
+    DeletedByUs,
+    /// Modified by us, deleted by them
+    DeletedByThem,
+}
+
+impl ConflictKind {
+    /// The `git status` label for this kind of conflict.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictKind::BothModified => "both modified",
+            ConflictKind::BothAdded => "both added",
+            ConflictKind::BothDeleted => "both deleted",
+            ConflictKind::AddedByUs => "added by us",
+            ConflictKind::AddedByThem => "added by them",
+            ConflictKind::DeletedByUs => "deleted by us",
+            ConflictKind::DeletedByThem => "deleted by them",
+        }
+    }
+}
+
+/// A path with an unresolved conflict.
+///
+/// Produced by [`Git::conflicted_files`] and [`Git::replay_commits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictedFile {
+    /// Path relative to the repository root
+    pub path: String,
+    /// How the two sides conflict
+    pub kind: ConflictKind,
+}
+
+/// Result of replaying a branch's commits in memory.
+///
+/// Produced by [`Git::replay_commits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayOutcome {
+    /// Every commit applied; the tree the branch would end with
+    Clean {
+        /// Resulting tree
+        tree: Oid,
+    },
+    /// A commit didn't apply cleanly
+    Conflict {
+        /// The first commit that conflicts
+        commit: Oid,
+        /// Paths that conflict when applying it, sorted by path
+        files: Vec<ConflictedFile>,
+    },
+}
+
 /// Entry for building a tree object.
 ///
 /// Used with [`Git::write_tree`] to create tree objects containing
@@ -546,6 +697,71 @@ pub struct GitCommandResult {
     pub exit_code: i32,
 }
 
+/// An incremental `git maintenance` task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceTask {
+    /// Update the commit-graph file with newly reachable commits.
+    CommitGraph,
+    /// Pack loose objects into a new pack-file.
+    LooseObjects,
+}
+
+impl MaintenanceTask {
+    /// Task name as understood by `git maintenance run --task`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaintenanceTask::CommitGraph => "commit-graph",
+            MaintenanceTask::LooseObjects => "loose-objects",
+        }
+    }
+}
+
+/// Why pushing Git LFS objects from this repository would fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfsPushProblem {
+    /// The `git-lfs` binary is not installed.
+    NotInstalled,
+    /// No `pre-push` hook runs `git lfs pre-push`, so objects won't upload.
+    NoPrePushHook,
+}
+
+impl LfsPushProblem {
+    /// Human-readable explanation.
+    pub fn description(&self) -> &'static str {
+        match self {
+            LfsPushProblem::NotInstalled => "git-lfs is not installed",
+            LfsPushProblem::NoPrePushHook => "the pre-push hook does not run 'git lfs pre-push'",
+        }
+    }
+}
+
+/// Outcome of verifying a signature with git's signing configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureCheck {
+    /// The signature matches the data and was made by a trusted key.
+    Valid {
+        /// Who made the signature (SSH principal or GPG user ID).
+        signer: String,
+    },
+    /// The signature does not match the data, or the key is not trusted.
+    Invalid {
+        /// Why verification failed.
+        reason: String,
+    },
+}
+
+/// Armor header of SSH signatures (`gpg.format = ssh`).
+const SSH_SIGNATURE_HEADER: &str = "-----BEGIN SSH SIGNATURE-----";
+
+/// Armor header of OpenPGP signatures (`gpg.format = openpgp`).
+const PGP_SIGNATURE_HEADER: &str = "-----BEGIN PGP SIGNATURE-----";
+
+/// First line of every Git LFS pointer file.
+const LFS_POINTER_PREFIX: &[u8] = b"version https://git-lfs.github.com/spec/v1";
+
+/// Pointer files are tiny; anything larger is real content.
+const LFS_POINTER_MAX_SIZE: usize = 1024;
+
 /// The primary Git interface.
 ///
 /// # CAS Semantics
@@ -579,6 +795,8 @@ pub struct GitCommandResult {
 pub struct Git {
     /// The underlying git2 repository
     repo: git2::Repository,
+    /// Ancestry answers, loaded on the first query
+    ancestry: RefCell<Option<AncestryCache>>,
 }
 
 impl std::fmt::Debug for Git {
@@ -589,6 +807,14 @@ impl std::fmt::Debug for Git {
     }
 }
 
+impl Drop for Git {
+    fn drop(&mut self) {
+        if let Some(cache) = self.ancestry.get_mut().take() {
+            cache.save(&self.lattice_paths());
+        }
+    }
+}
+
 impl Git {
     // =========================================================================
     // Repository Opening and Info
@@ -625,7 +851,10 @@ impl Git {
             path: path.to_path_buf(),
         })?;
 
-        Ok(Self { repo })
+        Ok(Self {
+            repo,
+            ancestry: RefCell::new(None),
+        })
     }
 
     /// Get repository information (git_dir, common_dir, work_dir, context).
@@ -774,6 +1003,31 @@ impl Git {
         Ok(index.has_conflicts())
     }
 
+    /// List the paths with unresolved conflicts in the index, sorted by path.
+    pub fn conflicted_files(&self) -> Result<Vec<ConflictedFile>, GitError> {
+        let mut index = self.repo.index().map_err(|e| GitError::Internal {
+            message: e.message().to_string(),
+        })?;
+        // A git subprocess may have rewritten the index since it was loaded
+        index.read(true).map_err(|e| GitError::Internal {
+            message: e.message().to_string(),
+        })?;
+        index_conflicts(&index)
+    }
+
+    /// The commit an in-progress rebase, cherry-pick, or merge stopped on.
+    ///
+    /// Reads `REBASE_HEAD`, `CHERRY_PICK_HEAD`, or `MERGE_HEAD`, whichever
+    /// exists. Returns `None` when no operation is applying a commit.
+    pub fn stopped_commit(&self) -> Result<Option<Oid>, GitError> {
+        for pseudo_ref in ["REBASE_HEAD", "CHERRY_PICK_HEAD", "MERGE_HEAD"] {
+            if let Some(oid) = self.try_resolve_ref(pseudo_ref)? {
+                return Ok(Some(oid));
+            }
+        }
+        Ok(None)
+    }
+
     // =========================================================================
     // Working Tree Status
     // =========================================================================
@@ -1417,18 +1671,27 @@ impl Git {
     /// }
     /// ```
     pub fn merge_base(&self, oid1: &Oid, oid2: &Oid) -> Result<Option<Oid>, GitError> {
+        let query = Query::MergeBase(oid1, oid2);
+        if let Some(Answer::MergeBase(answer)) = self.cached_answer(query) {
+            return Ok(answer);
+        }
+
         let git_oid1 = git2::Oid::from_str(oid1.as_str())
             .map_err(|e| GitError::from_git2(e, oid1.as_str()))?;
         let git_oid2 = git2::Oid::from_str(oid2.as_str())
             .map_err(|e| GitError::from_git2(e, oid2.as_str()))?;
 
-        match self.repo.merge_base(git_oid1, git_oid2) {
-            Ok(oid) => Ok(Some(Oid::new(oid.to_string())?)),
-            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
-            Err(e) => Err(GitError::Internal {
-                message: e.message().to_string(),
-            }),
-        }
+        let answer = match self.repo.merge_base(git_oid1, git_oid2) {
+            Ok(oid) => Some(Oid::new(oid.to_string())?),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => None,
+            Err(e) => {
+                return Err(GitError::Internal {
+                    message: e.message().to_string(),
+                })
+            }
+        };
+        self.remember_answer(query, Answer::MergeBase(answer.clone()));
+        Ok(answer)
     }
 
     /// Check if `ancestor` is an ancestor of `descendant`.
@@ -1448,16 +1711,27 @@ impl Git {
             return Ok(true);
         }
 
+        let query = Query::IsAncestor {
+            ancestor,
+            descendant,
+        };
+        if let Some(Answer::IsAncestor(answer)) = self.cached_answer(query) {
+            return Ok(answer);
+        }
+
         let ancestor_oid = git2::Oid::from_str(ancestor.as_str())
             .map_err(|e| GitError::from_git2(e, ancestor.as_str()))?;
         let descendant_oid = git2::Oid::from_str(descendant.as_str())
             .map_err(|e| GitError::from_git2(e, descendant.as_str()))?;
 
-        self.repo
+        let answer = self
+            .repo
             .graph_descendant_of(descendant_oid, ancestor_oid)
             .map_err(|e| GitError::Internal {
                 message: e.message().to_string(),
-            })
+            })?;
+        self.remember_answer(query, Answer::IsAncestor(answer));
+        Ok(answer)
     }
 
     /// Count commits between two OIDs.
@@ -1474,6 +1748,11 @@ impl Git {
     /// }
     /// ```
     pub fn commit_count(&self, base: &Oid, tip: &Oid) -> Result<usize, GitError> {
+        let query = Query::CommitCount { base, tip };
+        if let Some(Answer::CommitCount(answer)) = self.cached_answer(query) {
+            return Ok(answer);
+        }
+
         let base_oid = git2::Oid::from_str(base.as_str())
             .map_err(|e| GitError::from_git2(e, base.as_str()))?;
         let tip_oid =
@@ -1491,9 +1770,569 @@ impl Git {
         })?;
 
         let count = revwalk.count();
+        self.remember_answer(query, Answer::CommitCount(count));
         Ok(count)
     }
 
+    /// The ancestry cache, unless it is off or the repository is shallow.
+    fn ancestry_cache(&self) -> Option<RefMut<'_, AncestryCache>> {
+        if ancestry_cache::max_entries() == 0 || self.repo.is_shallow() {
+            return None;
+        }
+        let mut slot = self.ancestry.borrow_mut();
+        if slot.is_none() {
+            *slot = Some(AncestryCache::load(&self.lattice_paths()));
+        }
+        RefMut::filter_map(slot, Option::as_mut).ok()
+    }
+
+    /// Look up a cached answer to `query`.
+    fn cached_answer(&self, query: Query<'_>) -> Option<Answer> {
+        self.ancestry_cache()?.lookup(query)
+    }
+
+    /// Cache the answer to `query`.
+    fn remember_answer(&self, query: Query<'_>, answer: Answer) {
+        if let Some(mut cache) = self.ancestry_cache() {
+            cache.store(query, answer);
+        }
+    }
+
+    /// Lattice's storage paths for this repository.
+    fn lattice_paths(&self) -> LatticePaths {
+        LatticePaths::new(
+            self.repo.path().to_path_buf(),
+            self.repo.commondir().to_path_buf(),
+        )
+    }
+
+    /// List commits reachable from `tip` but not from `base`.
+    ///
+    /// Same set as [`Git::commit_count`], newest first (like `git log
+    /// base..tip`).
+    pub fn commits_between(&self, base: &Oid, tip: &Oid) -> Result<Vec<CommitInfo>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let base_oid = git2::Oid::from_str(base.as_str())
+            .map_err(|e| GitError::from_git2(e, base.as_str()))?;
+        let tip_oid =
+            git2::Oid::from_str(tip.as_str()).map_err(|e| GitError::from_git2(e, tip.as_str()))?;
+
+        let mut revwalk = self.repo.revwalk().map_err(internal)?;
+        revwalk
+            .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(internal)?;
+        revwalk.push(tip_oid).map_err(internal)?;
+        revwalk.hide(base_oid).map_err(internal)?;
+
+        revwalk
+            .map(|oid| {
+                let oid = oid.map_err(internal)?;
+                self.commit_info(&Oid::new(oid.to_string())?)
+            })
+            .collect()
+    }
+
+    /// Compute a diff summary between two commits.
+    ///
+    /// Compares the trees of `base` and `tip`, returning file, insertion,
+    /// and deletion counts equivalent to `git diff --stat base tip`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let stat = git.diff_stat(&base, &tip)?;
+    /// println!("{} files, +{} -{}", stat.files_changed, stat.insertions, stat.deletions);
+    /// ```
+    pub fn diff_stat(&self, base: &Oid, tip: &Oid) -> Result<DiffStat, GitError> {
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(|e| GitError::Internal {
+                message: e.message().to_string(),
+            })
+        };
+
+        let base_tree = tree_of(base)?;
+        let tip_tree = tree_of(tip)?;
+
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&tip_tree), None)
+            .map_err(|e| GitError::Internal {
+                message: e.message().to_string(),
+            })?;
+        let stats = diff.stats().map_err(|e| GitError::Internal {
+            message: e.message().to_string(),
+        })?;
+
+        Ok(DiffStat {
+            files_changed: stats.files_changed(),
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+        })
+    }
+
+    /// List the files that differ between two commits.
+    ///
+    /// Paths are relative to the repository root, like `git diff --name-only
+    /// base tip`; a renamed file is listed under both names.
+    pub fn changed_paths(&self, base: &Oid, tip: &Oid) -> Result<Vec<String>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(internal)
+        };
+
+        let base_tree = tree_of(base)?;
+        let tip_tree = tree_of(tip)?;
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&tip_tree), None)
+            .map_err(internal)?;
+
+        let mut paths = Vec::new();
+        for delta in diff.deltas() {
+            for file in [delta.old_file(), delta.new_file()] {
+                if let Some(path) = file.path().and_then(|p| p.to_str()) {
+                    if !paths.iter().any(|known| known == path) {
+                        paths.push(path.to_string());
+                    }
+                }
+            }
+        }
+        Ok(paths)
+    }
+
+    /// List the files that differ between two commits, with how each
+    /// changed, sorted by path.
+    ///
+    /// Like `git diff --name-status --no-renames base tip`: a renamed file
+    /// is listed as deleted under its old name and added under its new one.
+    pub fn changed_files(&self, base: &Oid, tip: &Oid) -> Result<Vec<ChangedFile>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(internal)
+        };
+
+        let base_tree = tree_of(base)?;
+        let tip_tree = tree_of(tip)?;
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&tip_tree), None)
+            .map_err(internal)?;
+
+        let mut files = Vec::new();
+        let mut push = |file: git2::DiffFile<'_>, change: FileChange| {
+            if let Some(path) = file.path().and_then(|p| p.to_str()) {
+                files.push(ChangedFile {
+                    path: path.to_string(),
+                    change,
+                });
+            }
+        };
+        for delta in diff.deltas() {
+            match delta.status() {
+                git2::Delta::Added | git2::Delta::Copied => {
+                    push(delta.new_file(), FileChange::Added)
+                }
+                git2::Delta::Deleted => push(delta.old_file(), FileChange::Deleted),
+                git2::Delta::Renamed => {
+                    push(delta.old_file(), FileChange::Deleted);
+                    push(delta.new_file(), FileChange::Added);
+                }
+                git2::Delta::Unmodified => {}
+                _ => push(delta.new_file(), FileChange::Modified),
+            }
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+
+    /// Compute the patch ID of the changes from `base` to `tip`.
+    ///
+    /// Equivalent to `git diff base tip | git patch-id --stable`: the same
+    /// changes have the same ID wherever they are applied. Returns `None`
+    /// when the two trees are identical.
+    pub fn patch_id(&self, base: &Oid, tip: &Oid) -> Result<Option<Oid>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(internal)
+        };
+
+        let base_tree = tree_of(base)?;
+        let tip_tree = tree_of(tip)?;
+        if base_tree.id() == tip_tree.id() {
+            return Ok(None);
+        }
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&tip_tree), None)
+            .map_err(internal)?;
+        let id = diff.patchid(None).map_err(internal)?;
+        Ok(Some(Oid::new(id.to_string())?))
+    }
+
+    /// Three-way merge the trees of `ours` and `theirs` over `ancestor`, in
+    /// memory.
+    ///
+    /// Returns the merged tree, or `None` if the changes conflict. No ref,
+    /// index, or working tree is touched.
+    pub fn merge_trees(
+        &self,
+        ancestor: &Oid,
+        ours: &Oid,
+        theirs: &Oid,
+    ) -> Result<Option<Oid>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(internal)
+        };
+
+        let mut index = self
+            .repo
+            .merge_trees(
+                &tree_of(ancestor)?,
+                &tree_of(ours)?,
+                &tree_of(theirs)?,
+                None,
+            )
+            .map_err(internal)?;
+        if index.has_conflicts() {
+            return Ok(None);
+        }
+        let tree = index.write_tree_to(&self.repo).map_err(internal)?;
+        Ok(Some(Oid::new(tree.to_string())?))
+    }
+
+    /// Staged changes, as zero-context hunks per file.
+    ///
+    /// Compares the index with HEAD (an empty tree on an unborn branch).
+    pub fn staged_hunks(&self) -> Result<Vec<StagedFile>, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+
+        let head_tree = match self.repo.head() {
+            Ok(head) => Some(head.peel_to_tree().map_err(internal)?),
+            Err(_) => None,
+        };
+        let index = self.repo.index().map_err(internal)?;
+
+        let mut opts = git2::DiffOptions::new();
+        opts.context_lines(0).interhunk_lines(0);
+        let diff = self
+            .repo
+            .diff_tree_to_index(head_tree.as_ref(), Some(&index), Some(&mut opts))
+            .map_err(internal)?;
+
+        let mut files = Vec::new();
+        for idx in 0..diff.deltas().len() {
+            let Some(patch) = git2::Patch::from_diff(&diff, idx).map_err(internal)? else {
+                continue;
+            };
+            let delta = patch.delta();
+            let path = delta
+                .new_file()
+                .path()
+                .or_else(|| delta.old_file().path())
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let modified = delta.status() == git2::Delta::Modified && !delta.flags().is_binary();
+
+            let mut hunks = Vec::new();
+            if modified {
+                for h in 0..patch.num_hunks() {
+                    let (hunk, line_count) = patch.hunk(h).map_err(internal)?;
+                    let mut new_lines = Vec::new();
+                    for l in 0..line_count {
+                        let line = patch.line_in_hunk(h, l).map_err(internal)?;
+                        if line.origin() == '+' {
+                            new_lines.push(line.content().to_vec());
+                        }
+                    }
+                    hunks.push(DiffHunk {
+                        old_start: hunk.old_start() as usize,
+                        old_lines: hunk.old_lines() as usize,
+                        new_lines,
+                    });
+                }
+            }
+
+            files.push(StagedFile {
+                path,
+                modified,
+                hunks,
+            });
+        }
+
+        Ok(files)
+    }
+
+    /// Blame a file line by line.
+    ///
+    /// Returns, for each line of `path` at `newest`, the commit that last
+    /// changed it. Blame stops at `oldest`: lines it did not change since
+    /// come back as `oldest`.
+    pub fn blame_lines(
+        &self,
+        path: &str,
+        newest: &Oid,
+        oldest: &Oid,
+    ) -> Result<Vec<Oid>, GitError> {
+        let newest_git = git2::Oid::from_str(newest.as_str())
+            .map_err(|e| GitError::from_git2(e, newest.as_str()))?;
+        let oldest_git = git2::Oid::from_str(oldest.as_str())
+            .map_err(|e| GitError::from_git2(e, oldest.as_str()))?;
+
+        let mut opts = git2::BlameOptions::new();
+        opts.newest_commit(newest_git).oldest_commit(oldest_git);
+        let blame = self
+            .repo
+            .blame_file(Path::new(path), Some(&mut opts))
+            .map_err(|e| GitError::Internal {
+                message: format!("failed to blame '{}': {}", path, e.message()),
+            })?;
+
+        let mut lines = Vec::new();
+        for hunk in blame.iter() {
+            let commit = if hunk.is_boundary() {
+                oldest.clone()
+            } else {
+                Oid::new(hunk.final_commit_id().to_string())?
+            };
+            lines.extend(std::iter::repeat_n(commit, hunk.lines_in_hunk()));
+        }
+
+        Ok(lines)
+    }
+
+    /// Read a file from a commit's tree.
+    ///
+    /// Returns `None` if the path does not exist there.
+    pub fn file_content(&self, commit: &Oid, path: &str) -> Result<Option<Vec<u8>>, GitError> {
+        let git_oid = git2::Oid::from_str(commit.as_str())
+            .map_err(|e| GitError::from_git2(e, commit.as_str()))?;
+        let tree = self
+            .repo
+            .find_commit(git_oid)
+            .and_then(|c| c.tree())
+            .map_err(|e| GitError::from_git2(e, commit.as_str()))?;
+
+        let Ok(entry) = tree.get_path(Path::new(path)) else {
+            return Ok(None);
+        };
+        let blob = self
+            .repo
+            .find_blob(entry.id())
+            .map_err(|e| GitError::from_git2(e, path))?;
+        Ok(Some(blob.content().to_vec()))
+    }
+
+    /// Replace the content of files in a tree, keeping their modes.
+    ///
+    /// `path` entries are relative to the repository root and must already
+    /// exist in `tree`. Returns the new tree.
+    pub fn update_tree_files(
+        &self,
+        tree: &Oid,
+        files: &[(String, Vec<u8>)],
+    ) -> Result<Oid, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let git_oid = git2::Oid::from_str(tree.as_str())
+            .map_err(|e| GitError::from_git2(e, tree.as_str()))?;
+        let base = self
+            .repo
+            .find_tree(git_oid)
+            .map_err(|e| GitError::from_git2(e, tree.as_str()))?;
+
+        let mut builder = git2::build::TreeUpdateBuilder::new();
+        for (path, content) in files {
+            let entry = base
+                .get_path(Path::new(path))
+                .map_err(|e| GitError::from_git2(e, path))?;
+            let mode = match entry.filemode() {
+                0o100755 => git2::FileMode::BlobExecutable,
+                _ => git2::FileMode::Blob,
+            };
+            let blob = self.repo.blob(content).map_err(internal)?;
+            builder.upsert(path.as_str(), blob, mode);
+        }
+
+        let new_tree = builder
+            .create_updated(&self.repo, &base)
+            .map_err(internal)?;
+        Oid::new(new_tree.to_string()).map_err(|e| e.into())
+    }
+
+    /// Apply a commit's changes onto another commit, in memory.
+    ///
+    /// Returns the resulting tree, or `None` if the changes conflict. No
+    /// ref, index, or working tree is touched.
+    pub fn cherry_pick_tree(&self, commit: &Oid, onto: &Oid) -> Result<Option<Oid>, GitError> {
+        let find = |oid: &Oid| -> Result<git2::Commit<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            self.repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))
+        };
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+
+        let mut index = self
+            .repo
+            .cherrypick_commit(&find(commit)?, &find(onto)?, 0, None)
+            .map_err(internal)?;
+        if index.has_conflicts() {
+            return Ok(None);
+        }
+        let tree = index.write_tree_to(&self.repo).map_err(internal)?;
+        Ok(Some(Oid::new(tree.to_string())?))
+    }
+
+    /// Replay the commits from `base` to `tip` onto `onto_tree`, in memory.
+    ///
+    /// Applies each commit oldest first, as `git rebase --onto` would, by
+    /// three-way merging its changes into the tree built so far. Merge
+    /// commits are skipped, as rebase drops them. Stops at the first
+    /// commit that conflicts and reports the conflicting paths. No ref,
+    /// index, or working tree is touched.
+    pub fn replay_commits(
+        &self,
+        base: &Oid,
+        tip: &Oid,
+        onto_tree: &Oid,
+    ) -> Result<ReplayOutcome, GitError> {
+        let internal = |e: git2::Error| GitError::Internal {
+            message: e.message().to_string(),
+        };
+        let find_tree = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            self.repo
+                .find_tree(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))
+        };
+
+        let mut current = find_tree(onto_tree)?;
+        for info in self.commits_between(base, tip)?.into_iter().rev() {
+            let git_oid = git2::Oid::from_str(info.oid.as_str())
+                .map_err(|e| GitError::from_git2(e, info.oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, info.oid.as_str()))?;
+            if commit.parent_count() != 1 {
+                continue;
+            }
+            let parent_tree = commit
+                .parent(0)
+                .map_err(internal)?
+                .tree()
+                .map_err(internal)?;
+            let commit_tree = commit.tree().map_err(internal)?;
+
+            let mut index = self
+                .repo
+                .merge_trees(&parent_tree, &current, &commit_tree, None)
+                .map_err(internal)?;
+            if index.has_conflicts() {
+                return Ok(ReplayOutcome::Conflict {
+                    commit: info.oid,
+                    files: index_conflicts(&index)?,
+                });
+            }
+            let tree = index.write_tree_to(&self.repo).map_err(internal)?;
+            current = self.repo.find_tree(tree).map_err(internal)?;
+        }
+        Ok(ReplayOutcome::Clean {
+            tree: Oid::new(current.id().to_string())?,
+        })
+    }
+
+    /// Create a copy of a commit with a new tree and parent.
+    ///
+    /// Keeps the original author and message; the committer is the current
+    /// user, as with `git rebase`. No ref is updated.
+    pub fn recommit(&self, original: &Oid, tree: &Oid, parent: &Oid) -> Result<Oid, GitError> {
+        let find_commit = |oid: &Oid| -> Result<git2::Commit<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            self.repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))
+        };
+        let original_commit = find_commit(original)?;
+        let parent_commit = find_commit(parent)?;
+        let tree_git_oid = git2::Oid::from_str(tree.as_str())
+            .map_err(|e| GitError::from_git2(e, tree.as_str()))?;
+        let tree_obj = self
+            .repo
+            .find_tree(tree_git_oid)
+            .map_err(|e| GitError::from_git2(e, tree.as_str()))?;
+
+        let committer = self.repo.signature().unwrap_or_else(|_| {
+            git2::Signature::now("Lattice", "lattice@localhost")
+                .expect("failed to create default signature")
+        });
+
+        let commit_oid = self
+            .repo
+            .commit(
+                None,
+                &original_commit.author(),
+                &committer,
+                original_commit.message().unwrap_or(""),
+                &tree_obj,
+                &[&parent_commit],
+            )
+            .map_err(|e| GitError::Internal {
+                message: format!("failed to create commit: {}", e.message()),
+            })?;
+
+        Oid::new(commit_oid.to_string()).map_err(|e| e.into())
+    }
+
     // =========================================================================
     // Blob Operations
     // =========================================================================
@@ -1938,6 +2777,56 @@ impl Git {
         self.resolve_ref(target_ref)
     }
 
+    /// List the branch tips on a remote (`git ls-remote --heads`).
+    ///
+    /// Reads the remote without touching any local ref. Returns branch
+    /// names (without `refs/heads/`) mapped to their tips, sorted by name.
+    pub fn remote_heads(&self, remote: &str) -> Result<BTreeMap<String, Oid>, GitError> {
+        let result = self.run_command(&[
+            "ls-remote".to_string(),
+            "--heads".to_string(),
+            remote.to_string(),
+        ])?;
+        if !result.success {
+            return Err(GitError::Internal {
+                message: format!("git ls-remote {} failed: {}", remote, result.stderr.trim()),
+            });
+        }
+
+        let mut heads = BTreeMap::new();
+        for line in result.stdout.lines() {
+            let Some((oid, refname)) = line.split_once('\t') else {
+                continue;
+            };
+            if let Some(name) = refname.strip_prefix("refs/heads/") {
+                heads.insert(name.to_string(), Oid::new(oid)?);
+            }
+        }
+        Ok(heads)
+    }
+
+    /// Download objects from a remote without updating any ref.
+    ///
+    /// Runs `git fetch --dry-run`, so `refs/remotes/*` and `FETCH_HEAD`
+    /// are left alone. With `refspec`, only that ref's objects are fetched.
+    pub fn fetch_objects(&self, remote: &str, refspec: Option<&str>) -> Result<(), GitError> {
+        let mut args = vec![
+            "fetch".to_string(),
+            "--dry-run".to_string(),
+            "--quiet".to_string(),
+            remote.to_string(),
+        ];
+        args.extend(refspec.map(str::to_string));
+
+        let result = self.run_command(&args)?;
+        if !result.success {
+            return Err(GitError::Internal {
+                message: format!("git fetch {} failed: {}", remote, result.stderr.trim()),
+            });
+        }
+        Ok(())
+    }
+
     /// Run a git command with the given arguments.
     ///
     /// This is a low-level method for executing arbitrary git commands.
@@ -1975,23 +2864,47 @@ impl Git {
     /// ])?;
     /// ```
     pub fn run_command(&self, args: &[String]) -> Result<GitCommandResult, GitError> {
+        self.run_command_in(args, false)
+    }
+
+    /// Run a git command that the terminal's Ctrl-C does not interrupt.
+    ///
+    /// Like [`run_command`](Self::run_command), but on Unix the child runs in
+    /// its own process group, so SIGINT from the terminal reaches only
+    /// Lattice and the command runs to completion.
+    pub fn run_command_shielded(&self, args: &[String]) -> Result<GitCommandResult, GitError> {
+        self.run_command_in(args, true)
+    }
+
+    fn run_command_in(
+        &self,
+        args: &[String],
+        own_process_group: bool,
+    ) -> Result<GitCommandResult, GitError> {
         use std::process::Command;
 
         // Determine the working directory for the command
         let work_dir = self.info().ok().and_then(|i| i.work_dir);
         let run_dir = work_dir.as_deref().unwrap_or_else(|| self.repo.path());
 
-        let output = Command::new("git")
-            .args(args)
-            .current_dir(run_dir)
-            .output()
-            .map_err(|e| GitError::Internal {
-                message: format!(
-                    "failed to run git {}: {}",
-                    args.first().unwrap_or(&String::new()),
-                    e
-                ),
-            })?;
+        let mut command = Command::new("git");
+        command.args(args).current_dir(run_dir);
+        self.configure_command(&mut command);
+        #[cfg(unix)]
+        if own_process_group {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+        #[cfg(not(unix))]
+        let _ = own_process_group;
+
+        let output = command.output().map_err(|e| GitError::Internal {
+            message: format!(
+                "failed to run git {}: {}",
+                args.first().unwrap_or(&String::new()),
+                e
+            ),
+        })?;
 
         Ok(GitCommandResult {
             success: output.status.success(),
@@ -2001,6 +2914,435 @@ impl Git {
         })
     }
 
+    /// Check whether automatic maintenance is allowed for this repository.
+    ///
+    /// Honors git's `maintenance.auto` setting (default `true`). Repositories
+    /// registered with `git maintenance register`/`start` have it set to
+    /// `false` because scheduled maintenance already covers them.
+    pub fn auto_maintenance_enabled(&self) -> Result<bool, GitError> {
+        let config = self.repo.config().map_err(|e| GitError::Internal {
+            message: e.message().to_string(),
+        })?;
+        match config.get_bool("maintenance.auto") {
+            Ok(enabled) => Ok(enabled),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(true),
+            Err(e) => Err(GitError::Internal {
+                message: e.message().to_string(),
+            }),
+        }
+    }
+
+    /// Start `git maintenance run --auto` for `tasks` in the background.
+    ///
+    /// Returns as soon as the process is spawned; its outcome is ignored.
+    /// With `--auto`, git only does work when its own thresholds are met
+    /// (e.g. enough loose objects), so calling this is cheap when the
+    /// repository is already in good shape.
+    pub fn spawn_maintenance(&self, tasks: &[MaintenanceTask]) -> Result<(), GitError> {
+        use std::process::{Command, Stdio};
+
+        let work_dir = self.info().ok().and_then(|i| i.work_dir);
+        let run_dir = work_dir.as_deref().unwrap_or_else(|| self.repo.path());
+
+        let mut args = vec![
+            "maintenance".to_string(),
+            "run".to_string(),
+            "--auto".to_string(),
+            "--quiet".to_string(),
+        ];
+        args.extend(tasks.iter().map(|t| format!("--task={}", t.as_str())));
+
+        Command::new("git")
+            .args(&args)
+            .current_dir(run_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| GitError::Internal {
+                message: format!("failed to run git maintenance: {}", e),
+            })?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Signing
+    // =========================================================================
+
+    /// Sign `data` using git's signing configuration.
+    ///
+    /// Follows the same settings as `git commit -S`: `gpg.format` selects
+    /// OpenPGP (default) or SSH, `user.signingKey` names the key, and
+    /// `gpg.program`/`gpg.ssh.program` override the signing program. SSH
+    /// signatures are bound to `namespace` so they can't be replayed as
+    /// commit or tag signatures. Returns the ASCII-armored signature.
+    ///
+    /// # Errors
+    ///
+    /// - [`GitError::Internal`] if signing isn't configured or the signing
+    ///   program fails
+    pub fn sign_data(&self, data: &[u8], namespace: &str) -> Result<String, GitError> {
+        let key = self.config_string("user.signingkey");
+        let format = self
+            .config_string("gpg.format")
+            .unwrap_or_else(|| "openpgp".to_string());
+
+        let output = match format.as_str() {
+            "ssh" => {
+                let key = key.ok_or_else(|| GitError::Internal {
+                    message: "user.signingKey must be set to sign with SSH".to_string(),
+                })?;
+                // A literal public key signs through ssh-agent
+                let literal = key
+                    .strip_prefix("key::")
+                    .or_else(|| key.starts_with("ssh-").then_some(key.as_str()));
+                let key_file = match literal {
+                    Some(public_key) => TempFile::write(format!("{}\n", public_key).as_bytes())?,
+                    None => TempFile::existing(expand_home(&key)),
+                };
+                let program = self
+                    .config_string("gpg.ssh.program")
+                    .unwrap_or_else(|| "ssh-keygen".to_string());
+                let key_path = key_file.path.to_string_lossy().into_owned();
+                run_with_input(
+                    &program,
+                    &["-Y", "sign", "-n", namespace, "-f", key_path.as_str()],
+                    data,
+                )?
+            }
+            "openpgp" => {
+                let program = self
+                    .config_string("gpg.program")
+                    .unwrap_or_else(|| "gpg".to_string());
+                let mut args = vec!["--status-fd=2", "-bsa"];
+                if let Some(key) = key.as_deref() {
+                    args.extend(["-u", key]);
+                }
+                run_with_input(&program, &args, data)?
+            }
+            other => {
+                return Err(GitError::Internal {
+                    message: format!("unsupported signature format '{}'", other),
+                })
+            }
+        };
+
+        if !output.status.success() {
+            return Err(GitError::Internal {
+                message: format!(
+                    "signing failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            });
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Verify an armored `signature` over `data`.
+    ///
+    /// The format is taken from the signature itself. SSH signatures are
+    /// checked against `gpg.ssh.allowedSignersFile` for `namespace`;
+    /// OpenPGP signatures against the GnuPG keyring.
+    ///
+    /// # Errors
+    ///
+    /// - [`GitError::Internal`] if the verification program can't be run
+    pub fn verify_data(
+        &self,
+        data: &[u8],
+        signature: &str,
+        namespace: &str,
+    ) -> Result<SignatureCheck, GitError> {
+        let signature_file = TempFile::write(signature.as_bytes())?;
+        let signature_path = signature_file.path.to_string_lossy().into_owned();
+
+        if signature.trim_start().starts_with(SSH_SIGNATURE_HEADER) {
+            let Some(allowed) = self.config_string("gpg.ssh.allowedSignersFile") else {
+                return Ok(SignatureCheck::Invalid {
+                    reason: "gpg.ssh.allowedSignersFile is not configured".to_string(),
+                });
+            };
+            let allowed = expand_home(&allowed).to_string_lossy().into_owned();
+            let program = self
+                .config_string("gpg.ssh.program")
+                .unwrap_or_else(|| "ssh-keygen".to_string());
+
+            let principals = run_with_input(
+                &program,
+                &[
+                    "-Y",
+                    "find-principals",
+                    "-f",
+                    allowed.as_str(),
+                    "-s",
+                    signature_path.as_str(),
+                ],
+                &[],
+            )?;
+            let stdout = String::from_utf8_lossy(&principals.stdout);
+            let Some(principal) = stdout
+                .lines()
+                .next()
+                .filter(|_| principals.status.success())
+            else {
+                return Ok(SignatureCheck::Invalid {
+                    reason: "signed by a key not in the allowed signers file".to_string(),
+                });
+            };
+
+            let output = run_with_input(
+                &program,
+                &[
+                    "-Y",
+                    "verify",
+                    "-f",
+                    allowed.as_str(),
+                    "-I",
+                    principal,
+                    "-n",
+                    namespace,
+                    "-s",
+                    signature_path.as_str(),
+                ],
+                data,
+            )?;
+            if output.status.success() {
+                Ok(SignatureCheck::Valid {
+                    signer: principal.to_string(),
+                })
+            } else {
+                Ok(SignatureCheck::Invalid {
+                    reason: "signature does not match content".to_string(),
+                })
+            }
+        } else if signature.trim_start().starts_with(PGP_SIGNATURE_HEADER) {
+            let program = self
+                .config_string("gpg.program")
+                .unwrap_or_else(|| "gpg".to_string());
+            let output = run_with_input(
+                &program,
+                &["--status-fd=1", "--verify", signature_path.as_str(), "-"],
+                data,
+            )?;
+            let status = String::from_utf8_lossy(&output.stdout);
+            let good = status
+                .lines()
+                .find_map(|line| line.strip_prefix("[GNUPG:] GOODSIG "));
+            match good {
+                Some(rest) if output.status.success() => Ok(SignatureCheck::Valid {
+                    // "<key id> <user id>"
+                    signer: rest
+                        .split_once(' ')
+                        .map_or(rest, |(_, uid)| uid)
+                        .to_string(),
+                }),
+                _ => Ok(SignatureCheck::Invalid {
+                    reason: "signature does not match content or key is unknown".to_string(),
+                }),
+            }
+        } else {
+            Ok(SignatureCheck::Invalid {
+                reason: "unrecognized signature format".to_string(),
+            })
+        }
+    }
+
+    // =========================================================================
+    // Git LFS
+    // =========================================================================
+
+    /// Check whether this repository uses Git LFS.
+    ///
+    /// True when `.gitattributes` in the working tree assigns `filter=lfs`
+    /// to any path, or the LFS clean/smudge filters are configured.
+    pub fn uses_lfs(&self) -> bool {
+        let attributes = self
+            .repo
+            .workdir()
+            .and_then(|dir| std::fs::read_to_string(dir.join(".gitattributes")).ok())
+            .unwrap_or_default();
+        attributes.contains("filter=lfs") || self.config_string("filter.lfs.smudge").is_some()
+    }
+
+    /// Add the config Lattice runs git commands with to `command`.
+    ///
+    /// Passed through `GIT_CONFIG_COUNT` so it applies to hooks and nested
+    /// git processes as well. An explicit `GIT_CONFIG_COUNT` in the
+    /// environment is left alone.
+    pub fn configure_command(&self, command: &mut std::process::Command) {
+        if std::env::var_os("GIT_CONFIG_COUNT").is_some() {
+            return;
+        }
+        let mut config = Vec::new();
+        if self.lfs_needs_process_filter() {
+            // Rebases check out every replayed commit; without the
+            // long-running filter, git spawns one git-lfs process per file.
+            config.push(("filter.lfs.process", "git-lfs filter-process"));
+        }
+        if super::rerere::enabled() {
+            for key in ["rerere.enabled", "rerere.autoUpdate"] {
+                if self.config_string(key).is_none() {
+                    config.push((key, "true"));
+                }
+            }
+        }
+        if config.is_empty() {
+            return;
+        }
+        command.env("GIT_CONFIG_COUNT", config.len().to_string());
+        for (i, (key, value)) in config.into_iter().enumerate() {
+            command
+                .env(format!("GIT_CONFIG_KEY_{}", i), key)
+                .env(format!("GIT_CONFIG_VALUE_{}", i), value);
+        }
+    }
+
+    /// Whether git rerere resolved every conflict of the stopped operation.
+    ///
+    /// True when rerere handled this stop (it keeps `MERGE_RR` while an
+    /// operation is stopped on conflicts) and no path is left unmerged in
+    /// the index, which with `rerere.autoUpdate` means every conflict was
+    /// replayed from a recorded resolution and staged.
+    pub fn resolved_by_rerere(&self) -> Result<bool, GitError> {
+        Ok(self.repo.path().join("MERGE_RR").exists() && self.conflicted_files()?.is_empty())
+    }
+
+    /// Whether git-lfs is configured per file but not as a process filter.
+    ///
+    /// `git lfs install` sets `filter.lfs.process`, but older installs and
+    /// hand-written configs only set `clean`/`smudge`.
+    fn lfs_needs_process_filter(&self) -> bool {
+        self.config_string("filter.lfs.smudge")
+            .is_some_and(|smudge| smudge.starts_with("git-lfs"))
+            && self.config_string("filter.lfs.process").is_none()
+    }
+
+    /// Paths of Git LFS pointer files added or modified between two commits.
+    ///
+    /// Compares the trees of `base` and `tip`, like [`Git::diff_stat`], and
+    /// reports new-side blobs that are LFS pointers. Sorted by path.
+    pub fn lfs_pointers(&self, base: &Oid, tip: &Oid) -> Result<Vec<String>, GitError> {
+        let tree_of = |oid: &Oid| -> Result<git2::Tree<'_>, GitError> {
+            let git_oid = git2::Oid::from_str(oid.as_str())
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            let commit = self
+                .repo
+                .find_commit(git_oid)
+                .map_err(|e| GitError::from_git2(e, oid.as_str()))?;
+            commit.tree().map_err(|e| GitError::Internal {
+                message: e.message().to_string(),
+            })
+        };
+
+        let base_tree = tree_of(base)?;
+        let tip_tree = tree_of(tip)?;
+
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&tip_tree), None)
+            .map_err(|e| GitError::Internal {
+                message: e.message().to_string(),
+            })?;
+
+        let mut paths = Vec::new();
+        for delta in diff.deltas() {
+            if !matches!(
+                delta.status(),
+                git2::Delta::Added
+                    | git2::Delta::Modified
+                    | git2::Delta::Renamed
+                    | git2::Delta::Copied
+            ) {
+                continue;
+            }
+            let file = delta.new_file();
+            let Ok(blob) = self.repo.find_blob(file.id()) else {
+                continue;
+            };
+            let content = blob.content();
+            if content.len() <= LFS_POINTER_MAX_SIZE && content.starts_with(LFS_POINTER_PREFIX) {
+                if let Some(path) = file.path() {
+                    paths.push(path.to_string_lossy().into_owned());
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Check whether a push would upload LFS objects.
+    ///
+    /// Git only transfers LFS objects through the `git lfs pre-push` hook,
+    /// so a missing binary or hook makes the remote reject (or silently
+    /// lack) the objects. Returns `None` when pushing is expected to work.
+    pub fn lfs_push_problem(&self) -> Option<LfsPushProblem> {
+        let installed = std::process::Command::new("git")
+            .args(["lfs", "version"])
+            .current_dir(self.repo.workdir().unwrap_or_else(|| self.repo.path()))
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+        if !installed {
+            return Some(LfsPushProblem::NotInstalled);
+        }
+
+        let hook = std::fs::read_to_string(self.hooks_dir().join("pre-push")).unwrap_or_default();
+        if hook.contains("git lfs") || hook.contains("git-lfs") {
+            None
+        } else {
+            Some(LfsPushProblem::NoPrePushHook)
+        }
+    }
+
+    /// The directory git runs hooks from (`core.hooksPath`, or `hooks/`).
+    pub fn hooks_dir(&self) -> PathBuf {
+        match self.config_string("core.hooksPath") {
+            Some(dir) => {
+                let dir = PathBuf::from(dir);
+                match self.repo.workdir() {
+                    Some(work_dir) if dir.is_relative() => work_dir.join(dir),
+                    _ => dir,
+                }
+            }
+            None => self.common_dir().join("hooks"),
+        }
+    }
+
+    /// Whether any hook that runs when committing or rebasing is installed.
+    pub fn has_commit_hooks(&self) -> bool {
+        let dir = self.hooks_dir();
+        [
+            "pre-commit",
+            "prepare-commit-msg",
+            "commit-msg",
+            "post-commit",
+            "post-rewrite",
+        ]
+        .iter()
+        .any(|hook| dir.join(hook).is_file())
+    }
+
+    /// Whether the repository has a commit-graph file, which speeds up
+    /// history walks.
+    pub fn has_commit_graph(&self) -> bool {
+        let info = self.common_dir().join("objects").join("info");
+        info.join("commit-graph").is_file()
+            || info
+                .join("commit-graphs")
+                .join("commit-graph-chain")
+                .is_file()
+    }
+
+    /// The configured `user.email`, if any.
+    pub fn user_email(&self) -> Option<String> {
+        self.config_string("user.email")
+    }
+
+    /// Read a string value from the repository's git config.
+    fn config_string(&self, key: &str) -> Option<String> {
+        self.repo.config().ok()?.get_string(key).ok()
+    }
+
     /// Read the OID from FETCH_HEAD after a fetch operation.
     ///
     /// Uses git2's `fetchhead_foreach()` API for type-safe access
@@ -2034,6 +3376,46 @@ impl Git {
     }
 }
 
+/// The conflicted paths in `index`, sorted by path.
+fn index_conflicts(index: &git2::Index) -> Result<Vec<ConflictedFile>, GitError> {
+    let conflicts = index.conflicts().map_err(|e| GitError::Internal {
+        message: e.message().to_string(),
+    })?;
+
+    let mut files = Vec::new();
+    for conflict in conflicts {
+        let conflict = conflict.map_err(|e| GitError::Internal {
+            message: e.message().to_string(),
+        })?;
+        let kind = match (
+            conflict.ancestor.is_some(),
+            conflict.our.is_some(),
+            conflict.their.is_some(),
+        ) {
+            (true, true, true) => ConflictKind::BothModified,
+            (false, true, true) => ConflictKind::BothAdded,
+            (true, false, false) => ConflictKind::BothDeleted,
+            (true, true, false) => ConflictKind::DeletedByThem,
+            (true, false, true) => ConflictKind::DeletedByUs,
+            (false, true, false) => ConflictKind::AddedByUs,
+            (false, false, true) => ConflictKind::AddedByThem,
+            (false, false, false) => continue,
+        };
+        let entry = conflict
+            .our
+            .as_ref()
+            .or(conflict.their.as_ref())
+            .or(conflict.ancestor.as_ref())
+            .expect("conflict has at least one side");
+        files.push(ConflictedFile {
+            path: String::from_utf8_lossy(&entry.path).to_string(),
+            kind,
+        });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
 /// Parse the output of `git worktree list --porcelain`.
 ///
 /// The porcelain format outputs one worktree per block, separated by blank lines.
@@ -2106,6 +3488,78 @@ fn parse_worktree_list_porcelain(output: &str) -> Result<Vec<WorktreeEntry>, Git
     Ok(entries)
 }
 
+/// Run `program` with `input` on stdin and collect its output.
+fn run_with_input(
+    program: &str,
+    args: &[&str],
+    input: &[u8],
+) -> Result<std::process::Output, GitError> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::Internal {
+            message: format!("failed to run {}: {}", program, e),
+        })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A program that exits early closes the pipe; its status reports why
+        let _ = stdin.write_all(input);
+    }
+    child.wait_with_output().map_err(|e| GitError::Internal {
+        message: format!("failed to run {}: {}", program, e),
+    })
+}
+
+/// Expand a leading `~/` to the home directory, as git does for paths.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// A file handed to an external program, removed on drop if we created it.
+struct TempFile {
+    path: PathBuf,
+    owned: bool,
+}
+
+impl TempFile {
+    /// Write `contents` to a new file in the system temp directory.
+    fn write(contents: &[u8]) -> Result<Self, GitError> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "lattice-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::write(&path, contents).map_err(|e| GitError::Internal {
+            message: format!("failed to write {}: {}", path.display(), e),
+        })?;
+        Ok(Self { path, owned: true })
+    }
+
+    /// Refer to a file that already exists; it is left in place.
+    fn existing(path: PathBuf) -> Self {
+        Self { path, owned: false }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if self.owned {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,8 +16,9 @@
 //! - Repository discovery and opening
 //! - Ref operations (read, CAS update, delete)
 //! - Object operations (read blob, write blob)
-//! - Ancestry queries (merge-base, is-ancestor)
+//! - Ancestry queries (merge-base, is-ancestor), cached across runs
 //! - Status and state detection
+//! - Reuse of recorded conflict resolutions (rerere)
 //! - Remote URL parsing
 //!
 //! # Invariants
@@ -47,9 +48,13 @@
 //! )?;
 //! ```
 
+pub mod ancestry_cache;
 mod interface;
+pub mod rerere;
 
 pub use interface::{
-    CommitInfo, Git, GitCommandResult, GitError, GitState, RefEntry, RepoContext, RepoInfo,
-    TreeEntry, WorktreeEntry, WorktreeStatus, WorktreeUnavailableReason,
+    ChangedFile, CommitInfo, ConflictKind, ConflictedFile, DiffHunk, DiffStat, FileChange, Git,
+    GitCommandResult, GitError, GitState, LfsPushProblem, MaintenanceTask, RefEntry, ReplayOutcome,
+    RepoContext, RepoInfo, SignatureCheck, StagedFile, TreeEntry, WorktreeEntry, WorktreeStatus,
+    WorktreeUnavailableReason,
 };
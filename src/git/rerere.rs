@@ -0,0 +1,36 @@
+//! git::rerere
+//!
+//! Reuse of recorded conflict resolutions across restacks.
+//!
+//! # Design
+//!
+//! Restacking the same stack again tends to stop on the same conflicts.
+//! Git's rerere ("reuse recorded resolution") records how each conflict
+//! was resolved and replays that resolution the next time the same hunks
+//! conflict. Lattice turns it on, with `rerere.autoUpdate` so replayed
+//! resolutions are also staged, for the git commands it runs. A user's own
+//! `rerere.enabled` or `rerere.autoUpdate` setting always wins.
+//!
+//! When rerere resolved every conflict of a stopped rebase, there is
+//! nothing left for the user to do, so the executor continues the rebase
+//! instead of pausing (see [`Git::resolved_by_rerere`]). A pause only
+//! happens for conflicts that haven't been resolved before.
+//!
+//! [`Git::resolved_by_rerere`]: super::Git::resolved_by_rerere
+
+use std::sync::OnceLock;
+
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Set whether this process reuses recorded resolutions.
+///
+/// Only the first call has an effect; until it runs, resolutions are
+/// neither recorded nor reused.
+pub fn init(enabled: bool) {
+    let _ = ENABLED.set(enabled);
+}
+
+/// Whether this process records and reuses conflict resolutions.
+pub fn enabled() -> bool {
+    ENABLED.get().copied().unwrap_or(false)
+}
@@ -17,6 +17,13 @@
 //! - [`secrets`] - Secret storage abstraction
 //! - [`doctor`] - Explicit repair framework
 //! - [`ui`] - User interaction utilities
+//! - `test_util` - Repository fixtures for integration tests (`test-util` feature)
+//!
+//! # Library Use
+//!
+//! [`api`] is the stable, semver-governed surface for embedding Lattice in
+//! other tools. The modules above are public for the `lt` binary and tests
+//! but carry no compatibility guarantees.
 //!
 //! # Correctness Invariants
 //!
@@ -27,6 +34,7 @@
 //! 3. Repository state is never silently corrupted
 //! 4. Repairs are explicit and require user confirmation
 
+pub mod api;
 pub mod auth;
 pub mod cli;
 pub mod core;
@@ -35,4 +43,6 @@ pub mod engine;
 pub mod forge;
 pub mod git;
 pub mod secrets;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod ui;
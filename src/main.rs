@@ -1,7 +1,9 @@
 //! Lattice CLI entry point.
 
+use std::process::ExitCode;
+
 use latticework::cli;
 
-fn main() -> anyhow::Result<()> {
+fn main() -> ExitCode {
     cli::run()
 }
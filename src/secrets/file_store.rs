@@ -6,7 +6,8 @@
 //!
 //! - Secrets are stored in `~/.lattice/secrets.toml`
 //! - File permissions are set to 0600 on Unix (owner read/write only)
-//! - All writes are atomic (write to temp file, then rename)
+//! - All writes are atomic (write to temp file, rename, sync the directory;
+//!   see [`crate::core::atomic`])
 //! - Secrets are NEVER logged, printed, or included in error messages
 //!
 //! # Example
@@ -23,14 +24,14 @@
 //! ```
 
 use std::collections::HashMap;
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use std::fs;
 use std::path::PathBuf;
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
 use super::traits::{SecretError, SecretStore};
+use crate::core::atomic;
 
 /// File-based secret storage.
 ///
@@ -122,39 +123,9 @@ impl FileSecretStore {
         let content = toml::to_string_pretty(secrets)
             .map_err(|e| SecretError::WriteError(format!("cannot serialize secrets: {}", e)))?;
 
-        // Write to a temp file first for atomicity
-        let temp_path = self.path.with_extension("tmp");
-
-        // Create and write to temp file
-        {
-            let mut file = OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(&temp_path)
-                .map_err(|e| SecretError::WriteError(format!("cannot create temp file: {}", e)))?;
-
-            // Set restrictive permissions BEFORE writing content (Unix only)
-            #[cfg(unix)]
-            {
-                let permissions = fs::Permissions::from_mode(0o600);
-                file.set_permissions(permissions).map_err(|e| {
-                    SecretError::WriteError(format!("cannot set permissions: {}", e))
-                })?;
-            }
-
-            // Write content
-            file.write_all(content.as_bytes())
-                .map_err(|e| SecretError::WriteError(format!("cannot write secrets: {}", e)))?;
-
-            // Sync to disk
-            file.sync_all()
-                .map_err(|e| SecretError::WriteError(format!("cannot sync to disk: {}", e)))?;
-        }
-
-        // Atomic rename
-        fs::rename(&temp_path, &self.path)
-            .map_err(|e| SecretError::WriteError(format!("cannot rename temp file: {}", e)))?;
+        // Atomic replace, owner-only from the first byte
+        atomic::write_private(&self.path, content.as_bytes())
+            .map_err(|e| SecretError::WriteError(format!("cannot write secrets: {}", e)))?;
 
         Ok(())
     }
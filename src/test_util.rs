@@ -0,0 +1,239 @@
+//! test_util
+//!
+//! Repository fixtures for integration tests, behind the `test-util`
+//! feature.
+//!
+//! # Design
+//!
+//! Lattice's own integration tests each build throwaway repositories the
+//! same way: a temporary directory, `git init`, an initial commit, Lattice
+//! initialized on `main`, and a few stacked branches. [`TestRepo`] packages
+//! that up for plugin authors and tools embedding `latticework`, so their
+//! tests don't have to reimplement it. [`mock_forge_for_stack`] pairs it with
+//! a [`MockForge`] holding one open PR per branch.
+//!
+//! Fixtures panic on failure, like assertions: they are for tests only and
+//! carry no stability guarantees beyond those of the modules they wrap.
+//!
+//! # Example
+//!
+//! ```
+//! use latticework::test_util::TestRepo;
+//!
+//! let repo = TestRepo::with_lattice();
+//! repo.seed_stack(&["auth", "auth-ui"]);
+//!
+//! let stack = repo.repository().stack().unwrap();
+//! assert_eq!(stack.branch("auth-ui").unwrap().parent, "auth");
+//! ```
+
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::api::Repository;
+use crate::cli::commands;
+use crate::core::types::Oid;
+use crate::engine::Context;
+use crate::forge::{PrState, PullRequest};
+use crate::git::Git;
+
+pub use crate::forge::mock::MockForge;
+
+/// Name of the trunk branch in every fixture repository.
+pub const TRUNK: &str = "main";
+
+/// A temporary Git repository, deleted when dropped.
+///
+/// Starts with a single commit on [`TRUNK`]. Commit identity is set and
+/// editors are disabled, so Git never waits for input.
+pub struct TestRepo {
+    dir: TempDir,
+    origin: Option<TempDir>,
+}
+
+impl TestRepo {
+    /// Create a repository with an initial commit on `main`.
+    pub fn new() -> Self {
+        let dir = TempDir::new().expect("failed to create temp dir");
+        run_git(dir.path(), &["init", "-q", "-b", TRUNK]);
+        run_git(dir.path(), &["config", "user.email", "test@example.com"]);
+        run_git(dir.path(), &["config", "user.name", "Test User"]);
+        run_git(dir.path(), &["config", "core.editor", "true"]);
+        run_git(dir.path(), &["config", "sequence.editor", "true"]);
+
+        let repo = Self { dir, origin: None };
+        repo.commit("README.md", "# Test Repo\n", "Initial commit");
+        repo
+    }
+
+    /// Create a repository with Lattice initialized on `main`.
+    pub fn with_lattice() -> Self {
+        let repo = Self::new();
+        repo.init_lattice();
+        repo
+    }
+
+    /// Path to the working tree.
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Open a Git interface to this repository.
+    pub fn git(&self) -> Git {
+        Git::open(self.path()).expect("failed to open test repo")
+    }
+
+    /// Open the repository through the stable [`crate::api`].
+    pub fn repository(&self) -> Repository {
+        Repository::open(self.path()).expect("failed to open test repo")
+    }
+
+    /// A quiet, non-interactive context for calling commands directly.
+    pub fn context(&self) -> Context {
+        Context {
+            cwd: Some(self.path().to_path_buf()),
+            interactive: false,
+            quiet: true,
+            debug: false,
+            verify: true,
+            offline: false,
+            read_only: false,
+        }
+    }
+
+    /// Initialize Lattice with `main` as trunk.
+    pub fn init_lattice(&self) {
+        commands::init(&self.context(), Some(TRUNK), false, true).expect("init failed");
+    }
+
+    /// Run a Git command in the repository and return its trimmed stdout.
+    pub fn git_cmd(&self, args: &[&str]) -> String {
+        run_git(self.path(), args)
+    }
+
+    /// Write `content` to `file`, commit it, and return the new commit.
+    pub fn commit(&self, file: &str, content: &str, message: &str) -> Oid {
+        std::fs::write(self.path().join(file), content).expect("failed to write file");
+        run_git(self.path(), &["add", file]);
+        run_git(self.path(), &["commit", "-q", "-m", message]);
+        self.head_oid()
+    }
+
+    /// Create a branch at HEAD without checking it out.
+    pub fn create_branch(&self, name: &str) {
+        run_git(self.path(), &["branch", name]);
+    }
+
+    /// Check out a branch.
+    pub fn checkout(&self, name: &str) {
+        run_git(self.path(), &["checkout", "-q", name]);
+    }
+
+    /// Name of the checked-out branch.
+    pub fn current_branch(&self) -> String {
+        run_git(self.path(), &["branch", "--show-current"])
+    }
+
+    /// The commit HEAD points at.
+    pub fn head_oid(&self) -> Oid {
+        Oid::new(run_git(self.path(), &["rev-parse", "HEAD"])).expect("invalid HEAD oid")
+    }
+
+    /// Track an existing branch with Lattice.
+    pub fn track_branch(&self, branch: &str, parent: &str) {
+        commands::track(&self.context(), Some(branch), Some(parent), false, false)
+            .expect("track failed");
+    }
+
+    /// Build a linear stack on top of trunk: each branch is created from
+    /// the previous one (the first from `main`), gets one commit adding
+    /// `<branch>.txt`, and is tracked with the previous one as parent.
+    ///
+    /// Leaves the last branch checked out. Lattice must be initialized.
+    pub fn seed_stack(&self, branches: &[&str]) {
+        let mut parent = TRUNK;
+        for branch in branches {
+            self.checkout(parent);
+            self.create_branch(branch);
+            self.checkout(branch);
+            self.commit(
+                &format!("{}.txt", branch),
+                &format!("{}\n", branch),
+                &format!("Add {}", branch),
+            );
+            self.track_branch(branch, parent);
+            parent = branch;
+        }
+    }
+
+    /// Add a bare repository as `origin` and push `main` to it.
+    ///
+    /// The remote lives in its own temporary directory and is deleted with
+    /// the fixture. Returns its path.
+    pub fn add_origin(&mut self) -> &Path {
+        let origin = TempDir::new().expect("failed to create temp dir");
+        run_git(origin.path(), &["init", "-q", "--bare", "-b", TRUNK]);
+        let url = origin.path().to_str().expect("non-UTF-8 temp dir");
+        run_git(self.path(), &["remote", "add", "origin", url]);
+        run_git(self.path(), &["push", "-q", "-u", "origin", TRUNK]);
+        self.origin.insert(origin).path()
+    }
+
+    /// Path to the bare `origin` repository, if [`add_origin`](Self::add_origin)
+    /// was called.
+    pub fn origin_path(&self) -> Option<&Path> {
+        self.origin.as_ref().map(TempDir::path)
+    }
+}
+
+impl Default for TestRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A mock forge with one open PR per branch of a linear stack.
+///
+/// PR `n` (numbered from 1) has `branches[n - 1]` as head and the previous
+/// branch, or `main`, as base, matching [`TestRepo::seed_stack`].
+pub fn mock_forge_for_stack(branches: &[&str]) -> MockForge {
+    let mut base = TRUNK;
+    let mut prs = Vec::new();
+    for (i, branch) in branches.iter().enumerate() {
+        let number = i as u64 + 1;
+        prs.push(PullRequest {
+            number,
+            url: format!("https://github.com/example/repo/pull/{}", number),
+            state: PrState::Open,
+            is_draft: false,
+            head: branch.to_string(),
+            base: base.to_string(),
+            title: format!("Add {}", branch),
+            body: None,
+            node_id: None,
+            author: None,
+        });
+        base = branch;
+    }
+    MockForge::with_prs(prs)
+}
+
+/// Run a Git command in `dir`, panicking on failure, and return its
+/// trimmed stdout.
+fn run_git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    if !output.status.success() {
+        panic!(
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
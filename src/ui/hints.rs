@@ -0,0 +1,238 @@
+//! ui::hints
+//!
+//! Contextual hints that suggest the next command.
+//!
+//! # Design
+//!
+//! After common situations, commands call [`show`] with a [`Hint`]: after
+//! `init`, point to `create`; after `create`, to `submit`; after `submit`
+//! opens PRs, to `sync`; after a conflict pause, to `continue` rather than
+//! git's own `rebase --continue`. Hints are aimed at users coming from plain
+//! git, so each is shown at most [`MAX_SHOWS`] times and can be dismissed
+//! for good with `lattice hints --dismiss <name>`. What was shown and
+//! dismissed is kept in `~/.lattice/hints.toml`, across repositories.
+//!
+//! Hints go to stderr, and only when [`init`] turned them on: `hints = false`
+//! in the global config, `--quiet`, `--json`, or a stderr that isn't a
+//! terminal keep them off. Until [`init`] runs (library use, tests), no
+//! hint is shown. Failing to read or write the state file never fails a
+//! command.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::i18n;
+use crate::core::atomic;
+
+/// How many times a hint is shown before it retires on its own.
+pub const MAX_SHOWS: u32 = 3;
+
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Turn hints on or off for this process. Only the first call has an
+/// effect.
+pub fn init(enabled: bool) {
+    let _ = ENABLED.set(enabled);
+}
+
+/// Whether hints are shown in this process.
+pub fn enabled() -> bool {
+    ENABLED.get().copied().unwrap_or(false)
+}
+
+/// A situation with a suggested next step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    /// Lattice was just initialized
+    Init,
+    /// A branch was just created; takes `branch`
+    Create,
+    /// Submit just opened PRs
+    Submit,
+    /// An operation paused on a conflict
+    Conflict,
+}
+
+impl Hint {
+    /// Every hint, in the order `lattice hints` lists them.
+    pub const ALL: &'static [Hint] = &[Hint::Init, Hint::Create, Hint::Submit, Hint::Conflict];
+
+    /// Name used to dismiss the hint.
+    pub fn name(self) -> &'static str {
+        match self {
+            Hint::Init => "init",
+            Hint::Create => "create",
+            Hint::Submit => "submit",
+            Hint::Conflict => "conflict",
+        }
+    }
+
+    /// Look up a hint by name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::ui::hints::Hint;
+    ///
+    /// assert_eq!(Hint::parse("create"), Some(Hint::Create));
+    /// assert_eq!(Hint::parse("nope"), None);
+    /// ```
+    pub fn parse(name: &str) -> Option<Hint> {
+        Self::ALL.iter().copied().find(|hint| hint.name() == name)
+    }
+
+    /// Catalog key of the hint's text.
+    pub fn message_key(self) -> &'static str {
+        match self {
+            Hint::Init => "hint-init",
+            Hint::Create => "hint-create",
+            Hint::Submit => "hint-submit",
+            Hint::Conflict => "hint-conflict",
+        }
+    }
+}
+
+/// Which hints were shown and dismissed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HintState {
+    /// Hints the user turned off, by name
+    pub dismissed: BTreeSet<String>,
+    /// Times each hint was shown, by name
+    pub shown: BTreeMap<String, u32>,
+}
+
+impl HintState {
+    /// Location of the state file, `~/.lattice/hints.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".lattice").join("hints.toml"))
+    }
+
+    /// Load the state, starting fresh if the file is missing or unreadable.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the state atomically.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        atomic::write(path, content.as_bytes())
+    }
+
+    /// Times `hint` was shown.
+    pub fn times_shown(&self, hint: Hint) -> u32 {
+        self.shown.get(hint.name()).copied().unwrap_or(0)
+    }
+
+    /// Whether `hint` was dismissed.
+    pub fn is_dismissed(&self, hint: Hint) -> bool {
+        self.dismissed.contains(hint.name())
+    }
+
+    /// Whether `hint` should be shown again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use latticework::ui::hints::{Hint, HintState, MAX_SHOWS};
+    ///
+    /// let mut state = HintState::default();
+    /// for _ in 0..MAX_SHOWS {
+    ///     assert!(state.should_show(Hint::Create));
+    ///     state.record_shown(Hint::Create);
+    /// }
+    /// assert!(!state.should_show(Hint::Create));
+    ///
+    /// state.dismiss(Hint::Init);
+    /// assert!(!state.should_show(Hint::Init));
+    /// ```
+    pub fn should_show(&self, hint: Hint) -> bool {
+        !self.is_dismissed(hint) && self.times_shown(hint) < MAX_SHOWS
+    }
+
+    /// Count a showing of `hint`.
+    pub fn record_shown(&mut self, hint: Hint) {
+        *self.shown.entry(hint.name().to_string()).or_default() += 1;
+    }
+
+    /// Stop showing `hint`.
+    pub fn dismiss(&mut self, hint: Hint) {
+        self.dismissed.insert(hint.name().to_string());
+    }
+}
+
+/// Show `hint` on stderr, unless hints are off or it was dismissed or
+/// retired. `args` fill the hint text's placeholders.
+pub fn show(hint: Hint, args: &[(&str, &dyn Display)]) {
+    if !enabled() {
+        return;
+    }
+    let Some(path) = HintState::default_path() else {
+        return;
+    };
+    let mut state = HintState::load(&path);
+    if !state.should_show(hint) {
+        return;
+    }
+
+    let text = i18n::t_args(hint.message_key(), args);
+    eprintln!("{}", i18n::t_args("output-hint", &[("hint", &text)]));
+    eprintln!(
+        "  {}",
+        i18n::t_args("hint-dismiss", &[("name", &hint.name())])
+    );
+
+    state.record_shown(hint);
+    let _ = state.save(&path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn state_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("hints.toml");
+        assert_eq!(HintState::load(&path), HintState::default());
+
+        let mut state = HintState::default();
+        state.record_shown(Hint::Create);
+        state.record_shown(Hint::Create);
+        state.dismiss(Hint::Conflict);
+        state.save(&path).unwrap();
+
+        let loaded = HintState::load(&path);
+        assert_eq!(loaded, state);
+        assert_eq!(loaded.times_shown(Hint::Create), 2);
+        assert!(loaded.should_show(Hint::Create));
+        assert!(!loaded.should_show(Hint::Conflict));
+    }
+
+    #[test]
+    fn unreadable_state_starts_fresh() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hints.toml");
+        std::fs::write(&path, "dismissed = 3").unwrap();
+        assert_eq!(HintState::load(&path), HintState::default());
+    }
+
+    #[test]
+    fn names_parse_back() {
+        for hint in Hint::ALL {
+            assert_eq!(Hint::parse(hint.name()), Some(*hint));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! ui::i18n
+//!
+//! Message catalog for user-facing strings.
+//!
+//! # Design
+//!
+//! Strings live in Fluent-style catalogs under `locales/` (one file per
+//! locale, see `locales/en.ftl` for the syntax) and are compiled into the
+//! binary. Code refers to messages by key:
+//!
+//! ```
+//! use latticework::ui::i18n;
+//!
+//! let text = i18n::t_args("restack-emptied-hint", &[("branch", &"feature")]);
+//! assert_eq!(text, "Run 'lattice delete feature' to remove it.");
+//! ```
+//!
+//! English is the reference catalog. A key missing from the active locale
+//! falls back to English, and a key missing from English renders as the
+//! key itself, so a gap never hides output.
+//!
+//! Command output, prompts, and hints go through the catalog. Machine-readable
+//! output, `--debug` lines, and error messages stay in English (SPEC §6.7).
+//!
+//! # Locale Selection
+//!
+//! [`init`] picks the first locale that is set, in this order:
+//! 1. `$LATTICE_LANG`
+//! 2. `locale` in the global config
+//! 3. `$LC_ALL`, `$LC_MESSAGES`, `$LANG`
+//!
+//! Tags are matched exactly (`pt-BR`), then by language (`pt`), and
+//! otherwise English is used. Until [`init`] runs (library use, tests),
+//! messages are English.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Locale used when nothing else matches.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Compiled-in catalogs, keyed by locale tag.
+///
+/// Register new translations here.
+const CATALOGS: &[(&str, &str)] = &[("en", include_str!("../../locales/en.ftl"))];
+
+/// A parsed message catalog.
+#[derive(Debug)]
+struct Catalog {
+    locale: &'static str,
+    messages: HashMap<String, String>,
+}
+
+static ENGLISH: OnceLock<Catalog> = OnceLock::new();
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+/// Select the active locale for this process.
+///
+/// `configured` is the `locale` value from the global config. Only the
+/// first call has an effect.
+pub fn init(configured: Option<&str>) {
+    let candidates = [
+        std::env::var("LATTICE_LANG").ok(),
+        configured.map(str::to_string),
+        std::env::var("LC_ALL").ok(),
+        std::env::var("LC_MESSAGES").ok(),
+        std::env::var("LANG").ok(),
+    ];
+    let locale = select_locale(candidates.iter().flatten().map(String::as_str));
+    let _ = ACTIVE.set(load(locale));
+}
+
+/// Tag of the active locale.
+pub fn locale() -> &'static str {
+    active().locale
+}
+
+/// Look up a message with no placeholders.
+pub fn t(key: &str) -> String {
+    t_args(key, &[])
+}
+
+/// Look up a message and fill in its `{ $name }` placeholders.
+pub fn t_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let template = active()
+        .messages
+        .get(key)
+        .or_else(|| english().messages.get(key));
+    match template {
+        Some(template) => format_message(template, args),
+        None => key.to_string(),
+    }
+}
+
+/// Whether a prompt answer means "yes" in the active locale.
+pub fn is_yes(answer: &str) -> bool {
+    let answer = answer.trim();
+    t("prompt-yes")
+        .split(',')
+        .any(|yes| yes.trim().eq_ignore_ascii_case(answer))
+}
+
+fn english() -> &'static Catalog {
+    ENGLISH.get_or_init(|| load(DEFAULT_LOCALE))
+}
+
+fn active() -> &'static Catalog {
+    ACTIVE.get().unwrap_or_else(english)
+}
+
+fn load(locale: &'static str) -> Catalog {
+    let source = CATALOGS
+        .iter()
+        .find(|(tag, _)| *tag == locale)
+        .map(|(_, source)| *source)
+        .unwrap_or_default();
+    Catalog {
+        locale,
+        messages: parse(source),
+    }
+}
+
+/// Pick the best available catalog for the first usable candidate.
+///
+/// Candidates are POSIX (`de_DE.UTF-8`) or BCP 47 (`de-DE`) tags; `C` and
+/// `POSIX` are skipped.
+fn select_locale<'a>(candidates: impl IntoIterator<Item = &'a str>) -> &'static str {
+    for candidate in candidates {
+        let tag = candidate
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+        if tag.is_empty() || tag == "C" || tag == "POSIX" {
+            continue;
+        }
+
+        let language = tag.split('-').next().unwrap_or_default();
+        let found = CATALOGS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                CATALOGS
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(language))
+            });
+        return found.map(|(name, _)| *name).unwrap_or(DEFAULT_LOCALE);
+    }
+    DEFAULT_LOCALE
+}
+
+/// Parse catalog source into key/value pairs.
+fn parse(source: &str) -> HashMap<String, String> {
+    let mut messages = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = current.as_mut() {
+                value.push('\n');
+                value.push_str(trimmed);
+            }
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            if let Some((key, value)) = current.take() {
+                messages.insert(key, value);
+            }
+            current = Some((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    if let Some((key, value)) = current {
+        messages.insert(key, value);
+    }
+    messages
+}
+
+/// Replace `{ $name }` placeholders with their arguments.
+///
+/// Unknown placeholders are left in place.
+fn format_message(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let placeholder = &rest[start..start + len + 1];
+        let name = placeholder[1..placeholder.len() - 1]
+            .trim()
+            .trim_start_matches('$');
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => out.push_str(&value.to_string()),
+            None => out.push_str(placeholder),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_messages_comments_and_continuations() {
+        let messages = parse("# comment\n\nfirst = one\nsecond = two\n    lines\n");
+        assert_eq!(messages["first"], "one");
+        assert_eq!(messages["second"], "two\nlines");
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        let text = format_message(
+            "Delete '{ $branch }' (PR #{$pr})? { $missing }",
+            &[("branch", &"feature"), ("pr", &42)],
+        );
+        assert_eq!(text, "Delete 'feature' (PR #42)? { $missing }");
+    }
+
+    #[test]
+    fn missing_key_renders_key() {
+        assert_eq!(t("no-such-message"), "no-such-message");
+    }
+
+    #[test]
+    fn english_messages_resolve() {
+        assert_eq!(t("prompt-aborted"), "Aborted.");
+        assert_eq!(
+            t_args("output-error", &[("message", &"boom")]),
+            "error: boom"
+        );
+        assert!(is_yes("Y"));
+        assert!(!is_yes("n"));
+    }
+
+    #[test]
+    fn selects_locale_from_first_usable_candidate() {
+        assert_eq!(select_locale([]), "en");
+        assert_eq!(select_locale(["C", "en_US.UTF-8"]), "en");
+        assert_eq!(select_locale(["POSIX"]), "en");
+        // Unknown locales fall back to English rather than trying later
+        // candidates, so an explicit choice is never overridden.
+        assert_eq!(select_locale(["xx_YY", "en"]), "en");
+    }
+
+    #[test]
+    fn translations_match_english_keys_and_placeholders() {
+        let english = parse(CATALOGS[0].1);
+        for (locale, source) in CATALOGS {
+            for (key, value) in parse(source) {
+                let reference = english
+                    .get(&key)
+                    .unwrap_or_else(|| panic!("{}: unknown key '{}'", locale, key));
+                assert_eq!(
+                    placeholders(&value),
+                    placeholders(reference),
+                    "{}: placeholders differ for '{}'",
+                    locale,
+                    key
+                );
+            }
+        }
+    }
+
+    fn placeholders(template: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|s| s.split_once('}'))
+            .map(|(name, _)| name.trim())
+            .collect();
+        names.sort();
+        names
+    }
+}
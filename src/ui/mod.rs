@@ -4,16 +4,23 @@
 //!
 //! # Modules
 //!
+//! - [`i18n`] - Message catalog and locale selection
+//! - [`hints`] - Contextual hints suggesting the next command
 //! - [`prompts`] - Interactive prompts and confirmations
 //! - [`output`] - Output formatting and display
 //! - [`stack_comment`] - Stack comment generation for PR descriptions
+//! - [`tui`] - Full-screen stack browser (`lattice ui`)
 //!
 //! # Design
 //!
 //! The UI module provides a consistent interface for user interaction.
 //! All output and prompts go through this module to ensure consistent
 //! formatting and proper handling of interactive vs non-interactive modes.
+//! User-facing text is looked up in the [`i18n`] catalog by key.
 
+pub mod hints;
+pub mod i18n;
 pub mod output;
 pub mod prompts;
 pub mod stack_comment;
+pub mod tui;
@@ -8,6 +8,10 @@
 //! When `--json` is enabled, output is machine-readable JSON.
 
 use std::fmt::Display;
+use std::io::IsTerminal;
+
+use super::i18n;
+use crate::cli::error::CliError;
 
 /// Output verbosity level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,13 +53,39 @@ pub fn debug(message: impl Display, verbosity: Verbosity) {
 
 /// Print an error message (always shown).
 pub fn error(message: impl Display) {
-    eprintln!("error: {}", message);
+    eprintln!("{}", i18n::t_args("output-error", &[("message", &message)]));
+}
+
+/// Print a command failure (always shown).
+///
+/// Text mode writes the message, blocking issues, hint, and doctor fix
+/// commands to stderr. JSON mode writes `{"error": {...}}` to stdout so it
+/// lands in the same stream as the command's other JSON output.
+pub fn error_report(err: &CliError, json: bool) {
+    if json {
+        println!("{}", err.to_json());
+        return;
+    }
+
+    error(&err.message);
+    for issue in &err.issues {
+        eprintln!("  - {}", issue);
+    }
+    if let Some(hint) = &err.hint {
+        eprintln!("{}", i18n::t_args("output-hint", &[("hint", hint)]));
+    }
+    for fix_id in &err.fix_ids {
+        eprintln!("{}", i18n::t_args("output-fix", &[("fix_id", fix_id)]));
+    }
 }
 
 /// Print a warning message (respects quiet mode).
 pub fn warn(message: impl Display, verbosity: Verbosity) {
     if verbosity != Verbosity::Quiet {
-        eprintln!("warning: {}", message);
+        eprintln!(
+            "{}",
+            i18n::t_args("output-warning", &[("message", &message)])
+        );
     }
 }
 
@@ -66,6 +96,30 @@ pub fn success(message: impl Display, verbosity: Verbosity) {
     }
 }
 
+/// Colors for status text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Green,
+    Yellow,
+    Red,
+}
+
+/// Color text for stdout.
+///
+/// Returns the text unchanged when stdout is not a terminal or `NO_COLOR`
+/// is set, so piped output stays free of escape codes.
+pub fn paint(text: &str, color: Color) -> String {
+    if std::env::var_os("NO_COLOR").is_some() || !std::io::stdout().is_terminal() {
+        return text.to_string();
+    }
+    let code = match color {
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Red => 31,
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
 /// Format a branch name for display.
 pub fn format_branch(name: &str) -> String {
     name.to_string()
@@ -7,9 +7,29 @@
 //! Prompts are only shown in interactive mode. In non-interactive mode,
 //! operations requiring user input must either have defaults or fail
 //! with a clear error message.
+//!
+//! # Confirmation policy
+//!
+//! Confirmations are tagged with a [`Severity`] and filtered by the policy
+//! set once per process with [`init`]:
+//!
+//! - `--yes` answers every confirmation with yes, interactive or not.
+//! - The `confirm` config level picks which confirmations interactive
+//!   sessions ask; the rest are taken as yes. `never` asks none,
+//!   `destructive-only` asks only before destructive actions, and `always`
+//!   (the default) asks all of them.
+//!
+//! Non-interactive sessions without `--yes` get
+//! [`PromptError::NotInteractive`] and keep their command's usual
+//! non-interactive behavior.
+
+use std::io::{self, Write};
+use std::sync::OnceLock;
 
 use thiserror::Error;
 
+use super::i18n;
+
 /// Errors from prompts.
 #[derive(Debug, Error)]
 pub enum PromptError {
@@ -23,18 +43,100 @@ pub enum PromptError {
     IoError(String),
 }
 
-/// Prompt for confirmation (yes/no).
+/// How much a confirmed action can lose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Easily undone, or only changes local bookkeeping
+    Minor,
+    /// Deletes branches, metadata, or PRs
+    Destructive,
+}
+
+/// Which confirmations interactive sessions ask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfirmLevel {
+    /// Never ask; every confirmation is taken as yes
+    Never,
+    /// Ask only before destructive actions
+    DestructiveOnly,
+    /// Ask before every confirmed action
+    #[default]
+    Always,
+}
+
+impl ConfirmLevel {
+    /// Parse a `confirm` config value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "never" => Some(Self::Never),
+            "destructive-only" => Some(Self::DestructiveOnly),
+            "always" => Some(Self::Always),
+            _ => None,
+        }
+    }
+
+    /// Whether a confirmation of this severity is asked.
+    pub fn asks(self, severity: Severity) -> bool {
+        match self {
+            Self::Never => false,
+            Self::DestructiveOnly => severity == Severity::Destructive,
+            Self::Always => true,
+        }
+    }
+}
+
+/// Confirmation policy for this process.
+#[derive(Debug, Clone, Copy, Default)]
+struct Policy {
+    level: ConfirmLevel,
+    yes: bool,
+}
+
+static POLICY: OnceLock<Policy> = OnceLock::new();
+
+/// Set the confirmation policy for this process.
 ///
-/// Returns `Ok(true)` if the user confirms, `Ok(false)` if they decline.
-/// Returns `Err(PromptError::NotInteractive)` if not in interactive mode.
+/// `yes` is the `--yes` flag. Only the first call has an effect; until it
+/// runs (library use, tests), every confirmation is asked.
+pub fn init(level: ConfirmLevel, yes: bool) {
+    let _ = POLICY.set(Policy { level, yes });
+}
+
+/// Whether `--yes` was given.
+pub fn assume_yes() -> bool {
+    policy().yes
+}
+
+fn policy() -> Policy {
+    POLICY.get().copied().unwrap_or_default()
+}
+
+/// Prompt for confirmation (yes/no), subject to the confirmation policy.
 ///
-/// This is a stub implementation for Milestone 0.
-pub fn confirm(_message: &str, _default: bool, interactive: bool) -> Result<bool, PromptError> {
+/// Returns `Ok(true)` if the user confirms or the policy skips the prompt,
+/// `Ok(false)` if they decline. Returns `Err(PromptError::NotInteractive)`
+/// if the prompt would be needed but the session is not interactive.
+pub fn confirm(message: &str, severity: Severity, interactive: bool) -> Result<bool, PromptError> {
+    let policy = policy();
+    if policy.yes {
+        return Ok(true);
+    }
     if !interactive {
         return Err(PromptError::NotInteractive);
     }
-    // Stub: always return true
-    Ok(true)
+    if !policy.level.asks(severity) {
+        return Ok(true);
+    }
+
+    print!("{} ", message);
+    io::stdout()
+        .flush()
+        .map_err(|e| PromptError::IoError(e.to_string()))?;
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| PromptError::IoError(e.to_string()))?;
+    Ok(i18n::is_yes(&input))
 }
 
 /// Prompt for text input.
@@ -84,3 +186,37 @@ pub fn password(_message: &str, interactive: bool) -> Result<String, PromptError
     // Stub: return empty string
     Ok(String::new())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confirm_levels_by_severity() {
+        assert!(!ConfirmLevel::Never.asks(Severity::Destructive));
+        assert!(!ConfirmLevel::DestructiveOnly.asks(Severity::Minor));
+        assert!(ConfirmLevel::DestructiveOnly.asks(Severity::Destructive));
+        assert!(ConfirmLevel::Always.asks(Severity::Minor));
+    }
+
+    #[test]
+    fn parse_config_values() {
+        assert_eq!(
+            ConfirmLevel::parse("destructive-only"),
+            Some(ConfirmLevel::DestructiveOnly)
+        );
+        assert_eq!(ConfirmLevel::parse("sometimes"), None);
+        for level in crate::core::config::schema::GlobalConfig::VALID_CONFIRM_LEVELS {
+            assert!(ConfirmLevel::parse(level).is_some());
+        }
+    }
+
+    #[test]
+    fn non_interactive_confirm_needs_yes() {
+        // The policy is never initialized in unit tests
+        assert!(matches!(
+            confirm("Continue?", Severity::Minor, false),
+            Err(PromptError::NotInteractive)
+        ));
+    }
+}
@@ -13,6 +13,10 @@
 //! that allow it to be regenerated on subsequent submits while preserving
 //! any user-provided description above the markers.
 //!
+//! Only the region between the markers belongs to Lattice.
+//! [`update_stack_comment`] rewrites it without losing edits the user made
+//! inside it, and refuses to touch a body whose markers are damaged.
+//!
 //! # Example Output
 //!
 //! ```markdown
@@ -211,6 +215,226 @@ pub fn merge_stack_comment(existing_body: Option<&str>, stack_comment: &str) ->
     }
 }
 
+/// Extract the user-written description from a PR body.
+///
+/// This is the inverse of [`merge_stack_comment`]: the stack comment section
+/// (markers included) is removed and the remaining content before and after
+/// it is joined and trimmed. A body without markers is returned trimmed.
+///
+/// # Example
+///
+/// ```
+/// use latticework::ui::stack_comment::strip_stack_comment;
+///
+/// let body = "Adds a feature.\n\n<!-- lattice:stack:start -->\nstack\n<!-- lattice:stack:end -->";
+/// assert_eq!(strip_stack_comment(body), "Adds a feature.");
+/// ```
+pub fn strip_stack_comment(body: &str) -> String {
+    match find_marker_bounds(body) {
+        Some((before, after)) => {
+            let before = before.trim();
+            let after = after.trim();
+            if before.is_empty() || after.is_empty() {
+                format!("{}{}", before, after)
+            } else {
+                format!("{}\n\n{}", before, after)
+            }
+        }
+        None => body.trim().to_string(),
+    }
+}
+
+/// Why the stack comment markers in a PR body can't be trusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerProblem {
+    /// A start marker with no end marker
+    MissingEnd,
+    /// An end marker with no start marker
+    MissingStart,
+    /// More than one start or end marker
+    Duplicated,
+    /// The end marker comes before the start marker
+    Reversed,
+}
+
+impl MarkerProblem {
+    /// Short description of the problem.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::MissingEnd => "stack section start marker has no end marker",
+            Self::MissingStart => "stack section end marker has no start marker",
+            Self::Duplicated => "stack section markers appear more than once",
+            Self::Reversed => "stack section end marker comes before its start marker",
+        }
+    }
+}
+
+/// Result of updating the stack comment in a PR body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackCommentUpdate {
+    /// The new body
+    Updated(String),
+    /// The user and Lattice both changed the same lines of the stack section
+    Conflict,
+    /// The markers are damaged, so the Lattice-owned region can't be found
+    CorruptMarkers(MarkerProblem),
+}
+
+/// Update the stack comment in a PR body without clobbering user edits.
+///
+/// Unlike [`merge_stack_comment`], this never guesses. `base` is the stack
+/// comment Lattice last wrote to this PR, if known:
+///
+/// - A body without markers gets the stack comment appended
+/// - A stack section unchanged since `base` (or with no `base`) is replaced
+/// - A stack section the user edited is kept if Lattice has nothing new,
+///   and otherwise three-way merged line by line with `stack_comment`
+/// - Overlapping edits give [`StackCommentUpdate::Conflict`]
+/// - Unpaired, duplicated, or reversed markers give
+///   [`StackCommentUpdate::CorruptMarkers`]
+///
+/// Content outside the markers is always kept as is.
+///
+/// # Example
+///
+/// ```
+/// use latticework::ui::stack_comment::{update_stack_comment, StackCommentUpdate};
+///
+/// let base = "<!-- lattice:stack:start -->\nold\n<!-- lattice:stack:end -->";
+/// let body = format!("Description\n\n{}", base);
+/// let new = "<!-- lattice:stack:start -->\nnew\n<!-- lattice:stack:end -->";
+/// assert_eq!(
+///     update_stack_comment(Some(&body), Some(base), new),
+///     StackCommentUpdate::Updated(format!("Description\n\n{}", new))
+/// );
+/// ```
+pub fn update_stack_comment(
+    existing_body: Option<&str>,
+    base: Option<&str>,
+    stack_comment: &str,
+) -> StackCommentUpdate {
+    let body = existing_body.unwrap_or("");
+    let (start, end) = match check_markers(body) {
+        Ok(Some(bounds)) => bounds,
+        Ok(None) => {
+            return StackCommentUpdate::Updated(merge_stack_comment(existing_body, stack_comment))
+        }
+        Err(problem) => return StackCommentUpdate::CorruptMarkers(problem),
+    };
+
+    let current = &body[start..end + STACK_MARKER_END.len()];
+    let region = match base {
+        None => stack_comment.to_string(),
+        Some(base) if current.trim() == base.trim() => stack_comment.to_string(),
+        Some(base) if stack_comment.trim() == base.trim() => current.to_string(),
+        Some(_) if current.trim() == stack_comment.trim() => current.to_string(),
+        Some(base) => match merge_lines(base, current, stack_comment) {
+            Some(merged) => merged,
+            None => return StackCommentUpdate::Conflict,
+        },
+    };
+    StackCommentUpdate::Updated(merge_stack_comment(Some(body), &region))
+}
+
+/// Check that a body has at most one well-formed pair of markers.
+///
+/// Returns the byte offsets of the start and end markers, `None` if the body
+/// has no markers, or the problem with them. Markers in code blocks are
+/// ignored.
+pub fn check_markers(body: &str) -> Result<Option<(usize, usize)>, MarkerProblem> {
+    let starts = find_markers_outside_code_blocks(body, STACK_MARKER_START);
+    let ends = find_markers_outside_code_blocks(body, STACK_MARKER_END);
+    match (starts.as_slice(), ends.as_slice()) {
+        ([], []) => Ok(None),
+        ([start], [end]) if start < end => Ok(Some((*start, *end))),
+        ([_], [_]) => Err(MarkerProblem::Reversed),
+        ([_], []) => Err(MarkerProblem::MissingEnd),
+        ([], [_]) => Err(MarkerProblem::MissingStart),
+        _ => Err(MarkerProblem::Duplicated),
+    }
+}
+
+/// Find every occurrence of a marker that isn't inside a code block.
+fn find_markers_outside_code_blocks(text: &str, marker: &str) -> Vec<usize> {
+    let mut found = Vec::new();
+    let mut in_code_block = false;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            found.extend(line.match_indices(marker).map(|(i, _)| offset + i));
+        }
+        offset += line.len();
+    }
+    found
+}
+
+/// Three-way merge `ours` and `theirs` against `base`, line by line.
+///
+/// Returns `None` when both sides changed the same stretch of `base`
+/// differently.
+fn merge_lines(base: &str, ours: &str, theirs: &str) -> Option<String> {
+    let base: Vec<&str> = base.trim().lines().collect();
+    let ours: Vec<&str> = ours.trim().lines().collect();
+    let theirs: Vec<&str> = theirs.trim().lines().collect();
+    let in_ours = match_lines(&base, &ours);
+    let in_theirs = match_lines(&base, &theirs);
+
+    let mut merged: Vec<&str> = Vec::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    loop {
+        // The next base line both sides kept, or the end of all three
+        let next = (b..base.len()).find_map(|i| Some((i, in_ours[i]?, in_theirs[i]?)));
+        let (nb, no, nt) = next.unwrap_or((base.len(), ours.len(), theirs.len()));
+
+        let (base_chunk, ours_chunk, theirs_chunk) = (&base[b..nb], &ours[o..no], &theirs[t..nt]);
+        if ours_chunk == base_chunk || ours_chunk == theirs_chunk {
+            merged.extend(theirs_chunk);
+        } else if theirs_chunk == base_chunk {
+            merged.extend(ours_chunk);
+        } else {
+            return None;
+        }
+
+        if next.is_none() {
+            return Some(merged.join("\n"));
+        }
+        merged.push(base[nb]);
+        (b, o, t) = (nb + 1, no + 1, nt + 1);
+    }
+}
+
+/// For each line of `base`, its index in `other` under a longest common
+/// subsequence of the two.
+fn match_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut lcs = vec![vec![0usize; other.len() + 1]; base.len() + 1];
+    for i in (0..base.len()).rev() {
+        for j in (0..other.len()).rev() {
+            lcs[i][j] = if base[i] == other[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut matches = vec![None; base.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < base.len() && j < other.len() {
+        if base[i] == other[j] {
+            matches[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
 /// Find the bounds of the stack comment section, excluding markers inside code blocks.
 ///
 /// Returns `Some((before, after))` where `before` is content before the start marker
@@ -675,4 +899,150 @@ mod tests {
         assert!(result.contains("User deleted the stack section"));
         assert!(result.contains("new"));
     }
+
+    // =============================================================
+    // Strip tests
+    // =============================================================
+
+    #[test]
+    fn strip_removes_stack_section() {
+        let body =
+            "Header\n\n<!-- lattice:stack:start -->\nstack\n<!-- lattice:stack:end -->\n\nFooter";
+        assert_eq!(strip_stack_comment(body), "Header\n\nFooter");
+    }
+
+    #[test]
+    fn strip_of_stack_only_body_is_empty() {
+        let body = "<!-- lattice:stack:start -->\nstack\n<!-- lattice:stack:end -->";
+        assert_eq!(strip_stack_comment(body), "");
+    }
+
+    #[test]
+    fn strip_inverts_merge() {
+        let comment = "<!-- lattice:stack:start -->\nnew\n<!-- lattice:stack:end -->";
+        let merged = merge_stack_comment(Some("My description.\n"), comment);
+        assert_eq!(strip_stack_comment(&merged), "My description.");
+    }
+
+    #[test]
+    fn strip_keeps_markers_in_code_blocks() {
+        let body = "```\n<!-- lattice:stack:start -->\n<!-- lattice:stack:end -->\n```";
+        assert_eq!(strip_stack_comment(body), body);
+    }
+
+    // =============================================================
+    // Protected updates
+    // =============================================================
+
+    const BASE: &str = "<!-- lattice:stack:start -->\n\n### Stack\n\n| | Branch | PR |\n|---|--------|-----|\n| 👉 | `a` | #1 |\n| ⬇️ | `b` | ❓ |\n\n<!-- lattice:stack:end -->";
+
+    fn with_row(region: &str, from: &str, to: &str) -> String {
+        region.replace(from, to)
+    }
+
+    #[test]
+    fn update_replaces_unedited_section() {
+        let body = format!("Description\n\n{}\n\nFooter", BASE);
+        let new = with_row(BASE, "`b` | ❓", "`b` | #2");
+        assert_eq!(
+            update_stack_comment(Some(&body), Some(BASE), &new),
+            StackCommentUpdate::Updated(format!("Description\n\n{}\n\nFooter", new))
+        );
+    }
+
+    #[test]
+    fn update_without_base_replaces_section() {
+        let body = format!(
+            "Description\n\n{}",
+            "<!-- lattice:stack:start -->\nold\n<!-- lattice:stack:end -->"
+        );
+        assert_eq!(
+            update_stack_comment(Some(&body), None, BASE),
+            StackCommentUpdate::Updated(format!("Description\n\n{}", BASE))
+        );
+    }
+
+    #[test]
+    fn update_appends_when_no_markers() {
+        assert_eq!(
+            update_stack_comment(Some("Description"), Some(BASE), BASE),
+            StackCommentUpdate::Updated(format!("Description\n\n{}", BASE))
+        );
+    }
+
+    #[test]
+    fn update_keeps_user_edit_when_nothing_changed() {
+        let edited = with_row(BASE, "### Stack", "### Stack\n\nReview bottom-up, please.");
+        let body = format!("Description\n\n{}", edited);
+        assert_eq!(
+            update_stack_comment(Some(&body), Some(BASE), BASE),
+            StackCommentUpdate::Updated(body.clone())
+        );
+    }
+
+    #[test]
+    fn update_merges_separate_edits() {
+        let edited = with_row(BASE, "### Stack", "### Stack\n\nReview bottom-up, please.");
+        let body = format!("Description\n\n{}", edited);
+        let new = with_row(BASE, "`b` | ❓", "`b` | #2");
+        let StackCommentUpdate::Updated(result) =
+            update_stack_comment(Some(&body), Some(BASE), &new)
+        else {
+            panic!("expected a clean merge");
+        };
+        assert!(result.starts_with("Description\n\n"));
+        assert!(result.contains("Review bottom-up, please."));
+        assert!(result.contains("`b` | #2"));
+        assert!(!result.contains("`b` | ❓"));
+    }
+
+    #[test]
+    fn update_reports_overlapping_edits() {
+        let edited = with_row(BASE, "`b` | ❓", "`b` | not yet");
+        let body = format!("Description\n\n{}", edited);
+        let new = with_row(BASE, "`b` | ❓", "`b` | #2");
+        assert_eq!(
+            update_stack_comment(Some(&body), Some(BASE), &new),
+            StackCommentUpdate::Conflict
+        );
+    }
+
+    #[test]
+    fn update_refuses_corrupt_markers() {
+        let cases = [
+            ("Text\n<!-- lattice:stack:start -->\nstack", MarkerProblem::MissingEnd),
+            ("Text\n<!-- lattice:stack:end -->", MarkerProblem::MissingStart),
+            (
+                "<!-- lattice:stack:end -->\nstack\n<!-- lattice:stack:start -->",
+                MarkerProblem::Reversed,
+            ),
+            (
+                "<!-- lattice:stack:start -->\n<!-- lattice:stack:end -->\n<!-- lattice:stack:start -->\n<!-- lattice:stack:end -->",
+                MarkerProblem::Duplicated,
+            ),
+        ];
+        for (body, problem) in cases {
+            assert_eq!(
+                update_stack_comment(Some(body), Some(BASE), BASE),
+                StackCommentUpdate::CorruptMarkers(problem),
+                "{}",
+                body
+            );
+        }
+    }
+
+    #[test]
+    fn update_ignores_markers_in_code_blocks() {
+        let body = format!(
+            "```\n<!-- lattice:stack:start -->\n```\n\n{}",
+            "<!-- lattice:stack:start -->\nold\n<!-- lattice:stack:end -->"
+        );
+        assert_eq!(
+            update_stack_comment(Some(&body), None, BASE),
+            StackCommentUpdate::Updated(format!(
+                "```\n<!-- lattice:stack:start -->\n```\n\n{}",
+                BASE
+            ))
+        );
+    }
 }
@@ -0,0 +1,278 @@
+//! ui::tui
+//!
+//! Full-screen stack browser behind `lattice ui`.
+//!
+//! # Design
+//!
+//! The TUI only browses and picks; it never mutates the repository. The
+//! caller lays out the rows (the same tree `lattice log` draws), and [`run`]
+//! returns the branch and action the user chose after restoring the
+//! terminal. The caller then runs that action through the ordinary command,
+//! so it is gated, journaled, and undoable like any other.
+//!
+//! Keys:
+//!
+//! - `↑`/`k`, `↓`/`j`, `Home`/`g`, `End`/`G` move between branches
+//! - `Enter`/`c` checks out the selected branch
+//! - `r` restacks it, `s` submits it
+//! - `q`/`Esc` quits without doing anything
+//!
+//! [`App`] holds the selection and key handling without touching the
+//! terminal, so it can be tested directly; [`view`] draws it.
+
+pub mod view;
+
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+use crate::core::types::BranchName;
+
+/// One line of the stack tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    /// The branch the line draws; `None` for trunk and collapsed counts,
+    /// which can't be selected
+    pub branch: Option<BranchName>,
+    /// The tree line, as `lattice log` prints it
+    pub line: String,
+    /// Details shown while the branch is selected
+    pub detail: String,
+}
+
+/// An action the user picked for a branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Check out the branch
+    Checkout,
+    /// Restack the branch
+    Restack,
+    /// Submit the branch
+    Submit,
+}
+
+/// How a key press left the browser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Keep browsing
+    Continue,
+    /// Leave without an action
+    Quit,
+    /// Leave and apply `Action` to the branch
+    Run(Action, BranchName),
+}
+
+/// Browser state: the rows and which one is selected.
+#[derive(Debug)]
+pub struct App {
+    rows: Vec<Row>,
+    /// Index into `rows` of the selected branch, if there is any branch
+    selected: Option<usize>,
+}
+
+impl App {
+    /// Create the browser, selecting `initial` if it is drawn, else the
+    /// first branch.
+    pub fn new(rows: Vec<Row>, initial: Option<&BranchName>) -> Self {
+        let selected = initial
+            .and_then(|b| rows.iter().position(|r| r.branch.as_ref() == Some(b)))
+            .or_else(|| rows.iter().position(|r| r.branch.is_some()));
+        Self { rows, selected }
+    }
+
+    /// All rows, in display order.
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Index of the selected row.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// The selected branch.
+    pub fn selected_branch(&self) -> Option<&BranchName> {
+        self.rows[self.selected?].branch.as_ref()
+    }
+
+    /// Apply one key press.
+    pub fn handle_key(&mut self, key: KeyCode) -> Outcome {
+        let action = match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Outcome::Quit,
+            KeyCode::Up | KeyCode::Char('k') => {
+                let candidates = self.branch_rows().into_iter().rev();
+                self.select_next(candidates);
+                return Outcome::Continue;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let candidates = self.branch_rows();
+                self.select_next(candidates);
+                return Outcome::Continue;
+            }
+            KeyCode::Home | KeyCode::Char('g') => {
+                self.selected = self.branch_rows().first().copied();
+                return Outcome::Continue;
+            }
+            KeyCode::End | KeyCode::Char('G') => {
+                self.selected = self.branch_rows().last().copied();
+                return Outcome::Continue;
+            }
+            KeyCode::Enter | KeyCode::Char('c') => Action::Checkout,
+            KeyCode::Char('r') => Action::Restack,
+            KeyCode::Char('s') => Action::Submit,
+            _ => return Outcome::Continue,
+        };
+        match self.selected_branch() {
+            Some(branch) => Outcome::Run(action, branch.clone()),
+            None => Outcome::Continue,
+        }
+    }
+
+    /// Indices of rows that draw a branch.
+    fn branch_rows(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.branch.is_some())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Select the first of `candidates` past the current selection, in the
+    /// direction they run; stay put at either end.
+    fn select_next(&mut self, candidates: impl IntoIterator<Item = usize>) {
+        let Some(current) = self.selected else {
+            return;
+        };
+        let mut seen_current = false;
+        for i in candidates {
+            if seen_current {
+                self.selected = Some(i);
+                return;
+            }
+            seen_current = i == current;
+        }
+    }
+}
+
+/// Show the browser until the user picks an action or quits.
+///
+/// The terminal is restored before returning, on success or error.
+///
+/// # Returns
+///
+/// The action and branch picked, or `None` if the user quit.
+pub fn run(
+    rows: Vec<Row>,
+    initial: Option<&BranchName>,
+) -> io::Result<Option<(Action, BranchName)>> {
+    let mut app = App::new(rows, initial);
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::try_restore()?;
+    result
+}
+
+/// Draw and read keys until the browser is left.
+fn event_loop(
+    terminal: &mut ratatui::DefaultTerminal,
+    app: &mut App,
+) -> io::Result<Option<(Action, BranchName)>> {
+    loop {
+        terminal.draw(|frame| view::draw(frame, app))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key.code) {
+            Outcome::Continue => {}
+            Outcome::Quit => return Ok(None),
+            Outcome::Run(action, branch) => return Ok(Some((action, branch))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        let row = |branch: Option<&str>, line: &str| Row {
+            branch: branch.map(|b| BranchName::new(b).unwrap()),
+            line: line.to_string(),
+            detail: String::new(),
+        };
+        vec![
+            row(None, "  main"),
+            row(Some("a"), "  ├── a"),
+            row(Some("b"), "  │   └── b"),
+            row(None, "  └── … 2 branches"),
+            row(Some("c"), "  └── c"),
+        ]
+    }
+
+    fn selected(app: &App) -> &str {
+        app.selected_branch().unwrap().as_str()
+    }
+
+    #[test]
+    fn starts_on_initial_branch_or_first_branch() {
+        let b = BranchName::new("b").unwrap();
+        assert_eq!(selected(&App::new(rows(), Some(&b))), "b");
+        assert_eq!(selected(&App::new(rows(), None)), "a");
+
+        let missing = BranchName::new("gone").unwrap();
+        assert_eq!(selected(&App::new(rows(), Some(&missing))), "a");
+    }
+
+    #[test]
+    fn movement_skips_rows_without_branches() {
+        let mut app = App::new(rows(), None);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(selected(&app), "b");
+        app.handle_key(KeyCode::Char('j'));
+        assert_eq!(selected(&app), "c");
+        app.handle_key(KeyCode::Down);
+        assert_eq!(selected(&app), "c", "stays on the last branch");
+
+        app.handle_key(KeyCode::Char('k'));
+        assert_eq!(selected(&app), "b");
+        app.handle_key(KeyCode::Char('g'));
+        assert_eq!(selected(&app), "a");
+        app.handle_key(KeyCode::Up);
+        assert_eq!(selected(&app), "a", "stays on the first branch");
+        app.handle_key(KeyCode::End);
+        assert_eq!(selected(&app), "c");
+    }
+
+    #[test]
+    fn action_keys_pick_the_selected_branch() {
+        let mut app = App::new(rows(), None);
+        app.handle_key(KeyCode::Down);
+        let b = BranchName::new("b").unwrap();
+        assert_eq!(
+            app.handle_key(KeyCode::Enter),
+            Outcome::Run(Action::Checkout, b.clone())
+        );
+        assert_eq!(
+            app.handle_key(KeyCode::Char('r')),
+            Outcome::Run(Action::Restack, b.clone())
+        );
+        assert_eq!(
+            app.handle_key(KeyCode::Char('s')),
+            Outcome::Run(Action::Submit, b)
+        );
+        assert_eq!(app.handle_key(KeyCode::Char('x')), Outcome::Continue);
+        assert_eq!(app.handle_key(KeyCode::Esc), Outcome::Quit);
+    }
+
+    #[test]
+    fn nothing_to_pick_without_branches() {
+        let mut app = App::new(vec![rows().remove(0)], None);
+        assert_eq!(app.selected(), None);
+        assert_eq!(app.handle_key(KeyCode::Down), Outcome::Continue);
+        assert_eq!(app.handle_key(KeyCode::Enter), Outcome::Continue);
+    }
+}
@@ -0,0 +1,89 @@
+//! ui::tui::view
+//!
+//! Drawing for the stack browser: the tree, a detail line for the selected
+//! branch, and a key reminder.
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use super::App;
+use crate::ui::i18n;
+
+/// Draw `app` over the whole frame.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [tree_area, detail_area, help_area] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let items: Vec<ListItem> = app
+        .rows()
+        .iter()
+        .map(|row| {
+            let item = ListItem::new(row.line.as_str());
+            if row.branch.is_some() {
+                item
+            } else {
+                item.dim()
+            }
+        })
+        .collect();
+    let tree = List::new(items)
+        .block(Block::bordered().title(i18n::t("tui-title")))
+        .highlight_style(Style::new().reversed());
+    let mut state = ListState::default().with_selected(app.selected());
+    frame.render_stateful_widget(tree, tree_area, &mut state);
+
+    let detail = app
+        .selected()
+        .map(|i| app.rows()[i].detail.as_str())
+        .unwrap_or_default();
+    frame.render_widget(Paragraph::new(Line::from(detail)), detail_area);
+    frame.render_widget(
+        Paragraph::new(Line::from(i18n::t("tui-keys")).dim()),
+        help_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::BranchName;
+    use crate::ui::tui::Row;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn draws_tree_and_selected_detail() {
+        let rows = vec![
+            Row {
+                branch: None,
+                line: "  main".to_string(),
+                detail: String::new(),
+            },
+            Row {
+                branch: Some(BranchName::new("feature").unwrap()),
+                line: "* └── feature".to_string(),
+                detail: "branch feature, child of main".to_string(),
+            },
+        ];
+        let app = App::new(rows, None);
+        let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("* └── feature"), "{}", text);
+        assert!(text.contains("branch feature, child of main"), "{}", text);
+    }
+}
@@ -0,0 +1,73 @@
+//! Tests for screen-reader-friendly output (`output.accessible = true`).
+
+mod common;
+
+use tempfile::TempDir;
+
+use common::{commit, lattice_stdout, run_git, setup_repo, write_config};
+
+/// Repository with `feature-a` on `main` and `feature-b` on `feature-a`,
+/// where `feature-a` has moved since `feature-b` was tracked.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    write_config(path, "[output]\naccessible = true\n");
+
+    lattice_stdout(path, &["init", "--trunk", "main"]);
+    run_git(path, &["checkout", "-b", "feature-a"]);
+    commit(path, "a.txt", "a");
+    lattice_stdout(path, &["track", "--parent", "main"]);
+    run_git(path, &["checkout", "-b", "feature-b"]);
+    commit(path, "b.txt", "b");
+    lattice_stdout(path, &["track", "--parent", "feature-a"]);
+    run_git(path, &["checkout", "feature-a"]);
+    commit(path, "a2.txt", "a2");
+    run_git(path, &["checkout", "feature-b"]);
+
+    repo
+}
+
+#[test]
+fn log_describes_branches_in_words() {
+    let repo = setup();
+
+    let out = lattice_stdout(repo.path(), &["log"]);
+    assert!(
+        out.contains(
+            "branch feature-b, current branch, child of feature-a, 1 commit, needs restack"
+        ),
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("branch feature-a, child of main, 2 commits\n"),
+        "{}",
+        out
+    );
+    assert!(!out.contains("* "), "{}", out);
+}
+
+#[test]
+fn log_short_names_current_branch() {
+    let repo = setup();
+
+    let out = lattice_stdout(repo.path(), &["log", "--short"]);
+    assert!(
+        out.contains("branch feature-b, current branch\n"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn info_adds_summary() {
+    let repo = setup();
+
+    let out = lattice_stdout(repo.path(), &["info", "feature-a"]);
+    assert!(
+        out.contains("Summary: branch feature-a, child of main, 2 commits\n"),
+        "{}",
+        out
+    );
+}
@@ -0,0 +1,106 @@
+//! Integration tests for the stable library API (`latticework::api`).
+//!
+//! These tests drive real git repositories through the facade only, the way
+//! an embedding tool would. Setup uses the CLI command functions.
+
+mod common;
+
+use tempfile::TempDir;
+
+use latticework::api::{ApiError, Repository, RestackOutcome, SubmitOptions};
+use latticework::cli::commands;
+use latticework::engine::Context;
+
+use common::{commit, run_git, setup_repo};
+
+/// Create a repository with Lattice initialized and `feature` tracked on `main`.
+fn setup() -> TempDir {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    let ctx = Context {
+        cwd: Some(path.to_path_buf()),
+        interactive: false,
+        quiet: true,
+        debug: false,
+        verify: true,
+        offline: false,
+        read_only: false,
+    };
+    commands::init(&ctx, Some("main"), false, true).expect("init failed");
+
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit(path, "feature.txt", "Add feature");
+    commands::track(&ctx, Some("feature"), Some("main"), false, false).expect("track failed");
+
+    dir
+}
+
+#[test]
+fn open_rejects_non_repository() {
+    let dir = TempDir::new().unwrap();
+    assert!(matches!(
+        Repository::open(dir.path()),
+        Err(ApiError::Open(_))
+    ));
+}
+
+#[test]
+fn stack_lists_tracked_branches() {
+    let dir = setup();
+    let repo = Repository::open(dir.path()).unwrap();
+
+    let stack = repo.stack().unwrap();
+    assert_eq!(stack.trunk.as_deref(), Some("main"));
+    assert_eq!(stack.current_branch.as_deref(), Some("feature"));
+
+    let feature = stack.branch("feature").expect("feature tracked");
+    assert_eq!(feature.parent, "main");
+    assert!(!feature.frozen);
+    assert!(feature.tip.is_some());
+    assert_eq!(stack.children("main").len(), 1);
+}
+
+#[test]
+fn plan_restack_previews_then_restack_applies() {
+    let dir = setup();
+    run_git(dir.path(), &["checkout", "main"]);
+    commit(dir.path(), "main.txt", "Update main");
+    run_git(dir.path(), &["checkout", "feature"]);
+
+    let repo = Repository::open(dir.path()).unwrap();
+    let plan = repo.plan_restack(Some("feature")).unwrap();
+    assert_eq!(plan.steps.len(), 1);
+    assert_eq!(plan.steps[0].branch, "feature");
+
+    assert_eq!(
+        repo.restack(Some("feature")).unwrap(),
+        RestackOutcome::Completed
+    );
+    assert!(repo.plan_restack(Some("feature")).unwrap().is_empty());
+
+    let stack = repo.stack().unwrap();
+    assert_eq!(
+        stack.branch("feature").unwrap().base,
+        plan.steps[0].new_base
+    );
+}
+
+#[test]
+fn plan_restack_rejects_invalid_branch() {
+    let dir = setup();
+    let repo = Repository::open(dir.path()).unwrap();
+    assert!(matches!(
+        repo.plan_restack(Some("bad..name")),
+        Err(ApiError::InvalidBranch(_))
+    ));
+}
+
+#[test]
+fn submit_options_builder_sets_flags() {
+    let options = SubmitOptions::new().stack(true).draft(true).offline(true);
+    assert!(options.stack);
+    assert!(options.draft);
+    assert!(options.offline);
+    assert!(!options.dry_run);
+}
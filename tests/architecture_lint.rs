@@ -98,12 +98,15 @@ const ASYNC_WITH_INTERNAL_SCAN: &[&str] = &[
 /// 2. The preliminary scan is only for UX/pre-computation, not for mutations
 /// 3. The command lifecycle re-scans and validates state properly
 const COMMAND_WITH_PRE_SCAN: &[&str] = &[
+    "absorb.rs",   // Preliminary scan for hunk attribution and in-memory rewrite
     "create.rs",   // Preliminary scan for interactive prompts and validation
     "delete.rs",   // Preliminary scan for confirmation prompt
+    "edit.rs",     // Preliminary scan for editor interaction and validation
     "modify.rs",   // Preliminary scan for interactive staging and descendant detection
     "move_cmd.rs", // Preliminary scan for cycle detection and descendant info
     "pop.rs",      // Preliminary scan to compute diff before branch deletion
     "reorder.rs",  // Preliminary scan for editor interaction and validation
+    "restack.rs",  // Scans before and after to detect branches emptied by the rebase
     "split.rs",    // Preliminary scan for commit listing and file diff extraction
     "squash.rs",   // Preliminary scan to gather commit messages and descendant info
 ];
@@ -122,6 +125,8 @@ const READONLY_COMMANDS: &[(&str, &str)] = &[
     ("relationships.rs", "ParentCommand"),
     ("relationships.rs", "ChildrenCommand"),
     ("pr.rs", "PrCommand"),
+    ("ui_cmd.rs", "UiCommand"),
+    ("audit.rs", "AuditCommand"),
 ];
 
 /// Commands that must implement `Command`.
@@ -111,6 +111,8 @@ fn test_context(path: &Path) -> Context {
         quiet: true,
         debug: false,
         verify: true,
+        offline: false,
+        read_only: false,
     }
 }
 
@@ -144,18 +146,12 @@ mod submit_bare_repo {
         // Attempt submit without --no-restack in bare repo
         let bare_ctx = test_context(bare_dir.path());
         let result = commands::submit(
-            &bare_ctx, false, // stack
-            false, // draft
-            false, // publish
-            false, // confirm
-            true,  // dry_run (for testing)
-            false, // force
-            false, // always
-            false, // update_only
-            None,  // reviewers
-            None,  // team_reviewers
-            false, // no_restack - NOT set
-            false, // view
+            &bare_ctx,
+            commands::SubmitOptions {
+                dry_run: true,
+                ..Default::default()
+            },
+            None,
         );
 
         // Should fail with bare repo error (either explicit message or gating failure)
@@ -198,18 +194,13 @@ mod submit_bare_repo {
         // Try submit with --no-restack and --dry-run
         // Note: This may still fail due to auth requirements, but we're testing the bare repo path
         let result = commands::submit(
-            &bare_ctx, false, // stack
-            false, // draft
-            false, // publish
-            false, // confirm
-            true,  // dry_run
-            false, // force
-            false, // always
-            false, // update_only
-            None,  // reviewers
-            None,  // team_reviewers
-            true,  // no_restack - SET
-            false, // view
+            &bare_ctx,
+            commands::SubmitOptions {
+                dry_run: true,
+                no_restack: true,
+                ..Default::default()
+            },
+            None,
         );
 
         // Should either succeed (dry run) or fail for auth reasons, not bare repo reasons
@@ -247,7 +238,7 @@ mod sync_bare_repo {
         let bare_ctx = test_context(bare_dir.path());
 
         // Attempt sync with --restack in bare repo
-        let result = commands::sync(&bare_ctx, false, true); // restack=true
+        let result = commands::sync(&bare_ctx, false, true, false, false); // restack=true
 
         // Should fail
         assert!(result.is_err(), "sync --restack should fail in bare repo");
@@ -277,7 +268,7 @@ mod sync_bare_repo {
 
         // sync without restack should work (just fetch)
         // Note: May fail due to auth if trying to check PR status, but bare repo path should be OK
-        let result = commands::sync(&bare_ctx, false, false); // restack=false
+        let result = commands::sync(&bare_ctx, false, false, false, false); // restack=false
 
         // Either succeeds or fails for non-bare-repo reasons
         if let Err(e) = result {
@@ -330,6 +321,7 @@ mod get_bare_repo {
             false, // restack
             false, // unfrozen
             false, // no_checkout - NOT set
+            false, // dry_run
         );
 
         // Should fail
@@ -382,6 +374,7 @@ mod get_bare_repo {
             false, // restack
             false, // unfrozen (should default to frozen)
             true,  // no_checkout - SET
+            false, // dry_run
         );
 
         // This may fail for auth/remote reasons but should not fail due to bare repo
@@ -418,6 +411,14 @@ mod get_bare_repo {
                 !entry.metadata.base.oid.is_empty(),
                 "Base OID should be set"
             );
+
+            // Where the branch came from is recorded
+            let provenance = entry
+                .metadata
+                .provenance
+                .as_ref()
+                .expect("provenance should be recorded");
+            assert_eq!(provenance.remote.as_deref(), Some("origin"));
         }
     }
 
@@ -455,6 +456,7 @@ mod get_bare_repo {
             false, // restack
             true,  // unfrozen - SET
             true,  // no_checkout - SET
+            false, // dry_run
         );
 
         if result.is_ok() {
@@ -520,18 +522,13 @@ mod alignment_checks {
 
         // Try submit --no-restack - should fail due to alignment
         let result = commands::submit(
-            &bare_ctx, false, // stack
-            false, // draft
-            false, // publish
-            false, // confirm
-            true,  // dry_run
-            false, // force
-            false, // always
-            false, // update_only
-            None,  // reviewers
-            None,  // team_reviewers
-            true,  // no_restack
-            false, // view
+            &bare_ctx,
+            commands::SubmitOptions {
+                dry_run: true,
+                no_restack: true,
+                ..Default::default()
+            },
+            None,
         );
 
         // The alignment check should detect the issue
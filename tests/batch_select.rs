@@ -0,0 +1,127 @@
+//! Tests for batch actions on branches marked in `lt log --select`.
+
+mod common;
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use tempfile::TempDir;
+
+use common::{commit, lattice, run_git, run_lattice, setup_repo};
+
+/// Repository with `a` and `c` on `main`, `b` on `a`, and `main` moved on
+/// since, so every branch needs a restack.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    for (branch, parent) in [("a", "main"), ("b", "a"), ("c", "main")] {
+        run_git(path, &["checkout", "-q", parent]);
+        run_git(path, &["checkout", "-q", "-b", branch]);
+        commit(path, &format!("{}.txt", branch), branch);
+        assert!(run_lattice(path, &["track", "--parent", parent])
+            .status
+            .success());
+    }
+    run_git(path, &["checkout", "-q", "main"]);
+    commit(path, "main.txt", "main moved");
+    repo
+}
+
+/// Run lattice interactively, answering its prompts with `input`.
+fn run_interactive(path: &Path, args: &[&str], input: &str) -> Output {
+    let mut child = lattice(path)
+        .args(args)
+        .arg("--interactive-flag")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("run lattice");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().expect("run lattice")
+}
+
+fn is_on_main(path: &Path, branch: &str) -> bool {
+    Command::new("git")
+        .args(["merge-base", "--is-ancestor", "main", branch])
+        .current_dir(path)
+        .status()
+        .expect("run git")
+        .success()
+}
+
+#[test]
+fn restack_marked_branches_only() {
+    let repo = setup();
+
+    let output = run_interactive(
+        repo.path(),
+        &["log", "--all", "--select"],
+        "1 3\nrestack\ny\n",
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("  1   ├── a"), "{}", stdout);
+    assert!(stdout.contains("  3   └── c"), "{}", stdout);
+    assert!(stdout.contains("Restack a, c?"), "{}", stdout);
+
+    assert!(is_on_main(repo.path(), "a"));
+    assert!(is_on_main(repo.path(), "c"));
+    assert!(!is_on_main(repo.path(), "b"), "b was not marked");
+}
+
+#[test]
+fn freeze_marked_branches_only() {
+    let repo = setup();
+
+    let output = run_interactive(repo.path(), &["log", "--all", "--select"], "2-3\nf\ny\n");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let log = run_lattice(repo.path(), &["log", "--all"]);
+    let log = String::from_utf8_lossy(&log.stdout);
+    assert!(log.contains("b [frozen]"), "{}", log);
+    assert!(log.contains("c [frozen]"), "{}", log);
+    assert!(!log.contains("a [frozen]"), "{}", log);
+}
+
+#[test]
+fn declining_changes_nothing() {
+    let repo = setup();
+    let before = run_git(repo.path(), &["rev-parse", "a"]);
+
+    let output = run_interactive(
+        repo.path(),
+        &["log", "--all", "--select"],
+        "1\nrestack\nn\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(run_git(repo.path(), &["rev-parse", "a"]), before);
+}
+
+#[test]
+fn select_needs_interactive_session() {
+    let repo = setup();
+
+    let output = run_lattice(repo.path(), &["log", "--select"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("interactively"));
+}
@@ -76,6 +76,8 @@ impl TestRepo {
             quiet: true,
             debug: false,
             verify: true,
+            offline: false,
+            read_only: false,
         }
     }
 
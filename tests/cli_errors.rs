@@ -0,0 +1,304 @@
+//! Tests for how the `lt` binary reports errors.
+//!
+//! Failures are rendered as text on stderr by default and as a
+//! `{"error": {...}}` document on stdout with `--json`.
+
+mod common;
+
+use common::{run_git, run_lattice, setup_repo};
+
+#[test]
+fn gating_failure_suggests_doctor_fix() {
+    let dir = setup_repo();
+
+    let output = run_lattice(dir.path(), &["up"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("error: Repository needs repair"),
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("hint: Run 'lattice init' first."),
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("fix: lattice doctor --fix trunk-not-configured:"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn json_mode_reports_error_on_stdout() {
+    let dir = setup_repo();
+
+    let output = run_lattice(dir.path(), &["--json", "up"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    let error = &report["error"];
+    assert_eq!(error["category"], "not_initialized");
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Repository needs repair"));
+    assert!(!error["issues"].as_array().unwrap().is_empty());
+    assert!(error["fix_ids"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("trunk-not-configured:"));
+}
+
+#[test]
+fn outside_repository_is_a_usage_error() {
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let output = run_lattice(dir.path(), &["--json", "log"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    assert_eq!(report["error"]["category"], "usage");
+    assert!(report["error"]["hint"].as_str().unwrap().contains("--cwd"));
+}
+
+#[test]
+fn handler_failures_carry_their_category() {
+    let dir = setup_repo();
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+
+    let output = run_lattice(dir.path(), &["--json", "create"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    assert_eq!(report["error"]["category"], "usage");
+
+    assert!(run_lattice(dir.path(), &["create", "feature"])
+        .status
+        .success());
+    assert!(run_lattice(dir.path(), &["edit", "feature"])
+        .status
+        .success());
+    let output = run_lattice(dir.path(), &["--json", "edit", "feature"]);
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout is JSON");
+    assert_eq!(report["error"]["category"], "conflict");
+    assert!(report["error"]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Already editing 'feature'"));
+}
+
+#[test]
+fn read_only_mode_blocks_mutating_commands() {
+    let dir = setup_repo();
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+
+    let output = run_lattice(dir.path(), &["--read-only", "create", "feature"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("read-only mode"), "{}", stderr);
+
+    assert!(run_lattice(dir.path(), &["--read-only", "log"])
+        .status
+        .success());
+    assert!(run_lattice(dir.path(), &["--read-only", "doctor"])
+        .status
+        .success());
+
+    // The repo config turns it on without the flag
+    let config_path = dir.path().join(".git/lattice/config.toml");
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    std::fs::write(&config_path, format!("read_only = true\n{}", config)).unwrap();
+    let output = run_lattice(dir.path(), &["create", "feature"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(run_lattice(dir.path(), &["log"]).status.success());
+}
+
+#[test]
+fn read_only_mode_blocks_checkpoint_delete() {
+    let dir = setup_repo();
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+    assert!(run_lattice(dir.path(), &["checkpoint", "save", "before"])
+        .status
+        .success());
+
+    let output = run_lattice(
+        dir.path(),
+        &["--read-only", "checkpoint", "delete", "before"],
+    );
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("read-only mode"), "{}", stderr);
+
+    let output = run_lattice(dir.path(), &["checkpoint", "list"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("before"));
+}
+
+#[test]
+fn identity_mismatch_blocks_mutating_commands() {
+    let dir = setup_repo();
+    run_git(
+        dir.path(),
+        &["remote", "add", "origin", "git@github.com:octo/fork.git"],
+    );
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+    assert!(
+        run_lattice(dir.path(), &["config", "set", "identity", "octo/app"])
+            .status
+            .success()
+    );
+
+    let output = run_lattice(dir.path(), &["create", "feature"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("expects octo/app, but remote 'origin' is git@github.com:octo/fork.git"),
+        "{}",
+        stderr
+    );
+    assert!(stderr.contains("lattice config set identity"), "{}", stderr);
+    assert!(run_lattice(dir.path(), &["log"]).status.success());
+
+    // The right remote passes
+    run_git(
+        dir.path(),
+        &[
+            "remote",
+            "set-url",
+            "origin",
+            "https://github.com/octo/app.git",
+        ],
+    );
+    assert!(run_lattice(dir.path(), &["create", "feature"])
+        .status
+        .success());
+}
+
+#[test]
+fn get_refuses_another_repositorys_pr_url() {
+    let dir = setup_repo();
+    run_git(
+        dir.path(),
+        &["remote", "add", "origin", "https://github.com/octo/app.git"],
+    );
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+
+    let output = run_lattice(dir.path(), &["get", "https://github.com/octo/fork/pull/7"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("is for octo/fork, not the 'origin' remote"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn offline_mode_falls_back_to_cached_pr_state() {
+    use latticework::core::metadata::schema::{PrState, PrStatusCache};
+    use latticework::core::metadata::store::MetadataStore;
+    use latticework::core::types::{BranchName, UtcTimestamp};
+    use latticework::git::Git;
+
+    let dir = setup_repo();
+    run_git(
+        dir.path(),
+        &["remote", "add", "origin", "/nonexistent/app.git"],
+    );
+    assert!(run_lattice(dir.path(), &["init", "--trunk", "main"])
+        .status
+        .success());
+    assert!(run_lattice(dir.path(), &["create", "feature"])
+        .status
+        .success());
+
+    let git = Git::open(dir.path()).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().unwrap();
+    let mut metadata = entry.metadata;
+    metadata.pr = PrState::Linked {
+        forge: "github".to_string(),
+        number: 12,
+        url: "https://github.com/octo/app/pull/12".to_string(),
+        last_known: Some(PrStatusCache {
+            state: "open".to_string(),
+            is_draft: false,
+        }),
+    };
+    metadata.pr_cached_at = Some(UtcTimestamp::from_datetime(
+        "2026-03-01T09:30:00Z".parse().unwrap(),
+    ));
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+
+    let output = run_lattice(dir.path(), &["--offline", "log"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("#12 open (cached 2026-03-01 09:30 UTC)"),
+        "{}",
+        stdout
+    );
+
+    // No auth and an unreachable remote: sync still runs on cached state
+    let output = run_lattice(dir.path(), &["--offline", "sync"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("feature: #12 open (cached"), "{}", stdout);
+    assert!(stdout.contains("Sync complete (offline)."), "{}", stdout);
+}
+
+#[test]
+fn doctor_fix_all_needs_yes_and_respects_max_severity() {
+    let dir = setup_repo();
+
+    // The missing trunk is blocking and has exactly one fix
+    let output = run_lattice(dir.path(), &["doctor", "--fix-all"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("pass --yes"), "{}", stderr);
+
+    let output = run_lattice(
+        dir.path(),
+        &["doctor", "--fix-all", "--max-severity", "warning", "--yes"],
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Left alone: 1 issue(s) above severity 'warning'."),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("No fixes to apply."), "{}", stdout);
+
+    // Without a maximum the fix is chosen; --dry-run only previews it
+    let output = run_lattice(dir.path(), &["doctor", "--fix-all", "--dry-run"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("Fixes to apply:\n  trunk-not-configured:set-trunk:main"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Skipped (no fix available):"), "{}", stdout);
+}
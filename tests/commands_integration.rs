@@ -10,11 +10,15 @@ use tempfile::TempDir;
 
 use latticework::cli::{commands, Shell};
 use latticework::core::metadata::schema::{
-    BaseInfo, BranchInfo, BranchMetadataV1, FreezeScope, FreezeState, ParentInfo, PrState,
-    Timestamps, METADATA_KIND, SCHEMA_VERSION,
+    BaseInfo, BranchInfo, BranchMetadataV2, FreezeScope, FreezeState, MergedEmpty, ParentInfo,
+    PrState, Timestamps, METADATA_KIND, SCHEMA_VERSION,
 };
 use latticework::core::metadata::store::MetadataStore;
+use latticework::core::ops::pending::{PendingAction, PendingQueue};
+use latticework::core::paths::LatticePaths;
 use latticework::core::types::{BranchName, UtcTimestamp};
+use latticework::doctor::Doctor;
+use latticework::engine::exec::Executor;
 use latticework::engine::scan::scan;
 use latticework::engine::Context;
 use latticework::git::Git;
@@ -67,6 +71,8 @@ impl TestRepo {
             quiet: true,
             debug: false,
             verify: true,
+            offline: false,
+            read_only: false,
         }
     }
 
@@ -137,7 +143,7 @@ impl TestRepo {
         };
 
         let now = UtcTimestamp::now();
-        let metadata = BranchMetadataV1 {
+        let metadata = BranchMetadataV2 {
             kind: METADATA_KIND.to_string(),
             schema_version: SCHEMA_VERSION,
             branch: BranchInfo {
@@ -153,6 +159,14 @@ impl TestRepo {
                 created_at: now.clone(),
                 updated_at: now,
             },
+            merged_empty: None,
+            remote: None,
+            description: None,
+            pr_cached_at: None,
+            submitted: None,
+            issue: None,
+            provenance: None,
+            ci: None,
         };
 
         store
@@ -426,6 +440,61 @@ fn unfreeze_clears_frozen_state() {
     assert!(!scanned.metadata.freeze.is_frozen());
 }
 
+#[test]
+fn freeze_selects_by_stack_upstack_and_pattern() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    // main -> a -> b -> c, plus release/1 and release/2 off main
+    for (branch, parent) in [
+        ("a", "main"),
+        ("b", "a"),
+        ("c", "b"),
+        ("release/1", "main"),
+        ("release/2", "main"),
+    ] {
+        repo.checkout(parent);
+        repo.create_branch(branch);
+        repo.checkout(branch);
+        repo.commit(&format!("{}.txt", branch.replace('/', "-")), branch, branch);
+        repo.track_branch(branch, parent);
+    }
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let frozen = |name: &str| {
+        let branch = BranchName::new(name).unwrap();
+        store
+            .read(&branch)
+            .unwrap()
+            .expect("metadata")
+            .metadata
+            .freeze
+            .is_frozen()
+    };
+
+    let ctx = repo.context();
+    commands::set_frozen(&ctx, Some("b"), commands::FreezeRange::Upstack, true)
+        .expect("freeze upstack");
+    assert!(!frozen("a") && frozen("b") && frozen("c"));
+
+    commands::set_frozen(&ctx, Some("b"), commands::FreezeRange::Stack, false)
+        .expect("unfreeze stack");
+    assert!(!frozen("a") && !frozen("b") && !frozen("c"));
+
+    commands::set_frozen(&ctx, Some("release/*"), commands::FreezeRange::Only, true)
+        .expect("freeze glob");
+    assert!(frozen("release/1") && frozen("release/2") && !frozen("a"));
+
+    let err = commands::set_frozen(&ctx, Some("hotfix/*"), commands::FreezeRange::Only, true)
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("No tracked branch matches"),
+        "{}",
+        err
+    );
+}
+
 // =============================================================================
 // Navigation Command Tests
 // =============================================================================
@@ -441,7 +510,7 @@ fn checkout_switches_branch() {
     repo.checkout("main");
 
     let ctx = repo.context();
-    commands::checkout(&ctx, Some("feature"), false, false).expect("checkout failed");
+    commands::checkout(&ctx, Some("feature"), false, false, None).expect("checkout failed");
 
     assert_eq!(repo.current_branch(), "feature");
 }
@@ -455,7 +524,7 @@ fn checkout_trunk() {
     repo.checkout("feature");
 
     let ctx = repo.context();
-    commands::checkout(&ctx, None, true, false).expect("checkout trunk");
+    commands::checkout(&ctx, None, true, false, None).expect("checkout trunk");
 
     assert_eq!(repo.current_branch(), "main");
 }
@@ -492,7 +561,7 @@ fn up_navigates_to_child() {
     repo.checkout("main");
 
     let ctx = repo.context();
-    commands::up(&ctx, 1).expect("up failed");
+    commands::up(&ctx, 1, None).expect("up failed");
 
     assert_eq!(repo.current_branch(), "feature");
 }
@@ -540,7 +609,7 @@ fn top_navigates_to_leaf() {
     repo.checkout("main");
 
     let ctx = repo.context();
-    commands::top(&ctx).expect("top failed");
+    commands::top(&ctx, None).expect("top failed");
 
     assert_eq!(repo.current_branch(), "feature-child");
 }
@@ -583,6 +652,129 @@ fn restack_updates_branch_base() {
     assert_eq!(after.metadata.base.oid, new_main_oid);
 }
 
+#[test]
+fn restack_onto_pins_base_to_parent_history() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    let pinned_oid = repo.head_oid();
+    repo.commit("main-update.txt", "update", "Update main");
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    // Land on the older trunk commit instead of trunk's tip
+    let ctx = repo.context();
+    commands::restack_onto(&ctx, Some("feature"), true, "main~1").expect("restack --onto");
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert_eq!(entry.metadata.base.oid, pinned_oid);
+    assert!(!repo.dir.path().join("main-update.txt").exists());
+    assert!(repo.dir.path().join("feature.txt").exists());
+
+    // Commits outside the parent's history are refused
+    let err = commands::restack_onto(&ctx, Some("feature"), true, "feature")
+        .expect_err("not in main's history");
+    assert!(err.to_string().contains("not in the history of 'main'"));
+}
+
+#[test]
+fn restack_marks_branch_emptied_by_upstream_as_merged_empty() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    // The same change lands on main independently
+    repo.checkout("main");
+    repo.commit("feature.txt", "feature", "Add feature upstream");
+    let main_oid = repo.head_oid();
+
+    repo.checkout("feature");
+    let ctx = repo.context();
+    commands::restack(&ctx, Some("feature"), true, false).expect("restack failed");
+
+    assert_eq!(repo.head_oid(), main_oid);
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert!(entry.metadata.merged_empty.is_some());
+
+    // The mark is journaled like any other metadata change
+    commands::undo(&ctx).expect("undo failed");
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert!(entry.metadata.merged_empty.is_none());
+}
+
+#[test]
+fn restack_does_not_mark_branch_with_remaining_commits() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    repo.checkout("main");
+    repo.commit("main-update.txt", "update", "Update main");
+
+    repo.checkout("feature");
+    let ctx = repo.context();
+    commands::restack(&ctx, Some("feature"), true, false).expect("restack failed");
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert!(entry.metadata.merged_empty.is_none());
+}
+
+#[test]
+fn delete_merged_empty_branch_queues_pr_close() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.pr = PrState::linked("github", 42, "https://github.com/o/r/pull/42");
+    metadata.merged_empty = Some(MergedEmpty {
+        detected_at: UtcTimestamp::now(),
+    });
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+
+    repo.checkout("main");
+    let ctx = repo.context();
+    commands::delete(&ctx, Some("feature"), false, false, true).expect("delete failed");
+
+    let paths = LatticePaths::from_repo_info(&git.info().unwrap());
+    let queue = PendingQueue::load(&paths).unwrap();
+    assert_eq!(queue.entries.len(), 1);
+    assert_eq!(
+        queue.entries[0].action,
+        PendingAction::ClosePr { number: 42 }
+    );
+}
+
 #[test]
 fn restack_skips_frozen_branches() {
     let repo = TestRepo::new();
@@ -677,21 +869,14 @@ fn restack_chain_updates_all() {
         .expect("feature metadata");
     assert_eq!(feature.metadata.base.oid, new_main_oid);
 
-    // Note: In the current implementation, child's base is only updated if
-    // feature-child was already misaligned with feature at the start.
-    // Since feature-child's base was feature's tip at track time, and we only
-    // changed main (not feature directly), feature-child's base still matches
-    // the OLD feature tip. After feature rebases onto new main, feature-child
-    // would need a second restack to align with the new feature tip.
-    //
-    // This is a known limitation - a full restack would need to re-scan after
-    // each rebase to catch cascading updates. For now, verify the child exists.
+    // The child is moved onto feature's new tip in the same run
     let child = store
         .read(&BranchName::new("feature-child").unwrap())
         .unwrap()
         .expect("child metadata");
-    // Child's base is still the old feature tip (before restack)
-    assert_eq!(child.metadata.base.oid, feature_oid);
+    let new_feature_oid = git.resolve_ref("refs/heads/feature").unwrap();
+    assert_ne!(new_feature_oid.to_string(), feature_oid);
+    assert_eq!(child.metadata.base.oid, new_feature_oid.to_string());
 }
 
 // =============================================================================
@@ -710,7 +895,44 @@ fn info_shows_branch_details() {
 
     // Info should not error
     let ctx = repo.context();
-    commands::info(&ctx, Some("feature"), false, false, false).expect("info failed");
+    commands::info(
+        &ctx,
+        Some("feature"),
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+    )
+    .expect("info failed");
+}
+
+#[test]
+fn info_patch_lists_commits_with_patches() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("one.txt", "one", "Add one");
+    repo.commit("two.txt", "two", "Add two");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    commands::info(
+        &ctx,
+        Some("feature"),
+        false,
+        false,
+        true,
+        false,
+        false,
+        false,
+        false,
+    )
+    .expect("info --patch failed");
 }
 
 #[test]
@@ -722,8 +944,64 @@ fn info_on_untracked_branch() {
     repo.checkout("untracked");
 
     let ctx = repo.context();
-    commands::info(&ctx, Some("untracked"), false, false, false)
-        .expect("info on untracked should work");
+    commands::info(
+        &ctx,
+        Some("untracked"),
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+    )
+    .expect("info on untracked should work");
+}
+
+#[test]
+fn info_stack_stat_covers_whole_stack() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    repo.create_branch("feature-child");
+    repo.checkout("feature-child");
+    repo.commit("child.txt", "child", "Add child");
+    repo.track_branch("feature-child", "feature");
+
+    // From the bottom of the stack, descendants are included too
+    let ctx = repo.context();
+    commands::info(
+        &ctx,
+        Some("feature"),
+        false,
+        true,
+        false,
+        false,
+        true,
+        false,
+        false,
+    )
+    .expect("info --stack --stat failed");
+
+    // Untracked branches have no stack
+    repo.create_branch("untracked");
+    let result = commands::info(
+        &ctx,
+        Some("untracked"),
+        false,
+        true,
+        false,
+        false,
+        true,
+        false,
+        false,
+    );
+    assert!(result.is_err());
 }
 
 #[test]
@@ -743,7 +1021,10 @@ fn log_shows_stack() {
     repo.track_branch("feature-child", "feature");
 
     let ctx = repo.context();
-    commands::log(&ctx, false, false, false, false, false).expect("log failed");
+    commands::log(
+        &ctx, false, false, false, false, false, None, false, None, false,
+    )
+    .expect("log failed");
 }
 
 #[test]
@@ -759,7 +1040,7 @@ fn parent_returns_parent_name() {
     let ctx = repo.context();
     // parent() uses current branch, so checkout first
     repo.checkout("feature");
-    commands::parent(&ctx).expect("parent failed");
+    commands::parent(&ctx, false).expect("parent failed");
 }
 
 #[test]
@@ -775,7 +1056,7 @@ fn children_returns_child_names() {
     let ctx = repo.context();
     // children() uses current branch, so checkout first
     repo.checkout("main");
-    commands::children(&ctx).expect("children failed");
+    commands::children(&ctx, false).expect("children failed");
 }
 
 #[test]
@@ -787,6 +1068,15 @@ fn trunk_returns_trunk_name() {
     commands::trunk(&ctx, None).expect("trunk failed");
 }
 
+#[test]
+fn ui_needs_interactive_terminal() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    let err = commands::ui(&repo.context()).unwrap_err();
+    assert!(err.to_string().contains("interactive terminal"), "{}", err);
+}
+
 // =============================================================================
 // Create Command Tests
 // =============================================================================
@@ -805,6 +1095,8 @@ fn create_makes_tracked_branch() {
         false, // no update
         false, // no patch
         false, // no insert
+        None,  // no issue
+        false, // no slug from message
     )
     .expect("create failed");
 
@@ -818,6 +1110,38 @@ fn create_makes_tracked_branch() {
     assert!(store.read(&branch).unwrap().is_some());
 }
 
+#[test]
+fn create_names_branch_from_template() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let config_path = repo.git().git_dir().join("lattice/config.toml");
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    std::fs::write(
+        &config_path,
+        format!(
+            "{}\n[create]\nbranch_template = \"{{user}}/{{slug}}\"\n",
+            config
+        ),
+    )
+    .unwrap();
+
+    let ctx = repo.context();
+    commands::create(
+        &ctx,
+        None,
+        Some("Add login page\n\nWith a form."),
+        false,
+        false,
+        false,
+        false,
+        None,
+        false,
+    )
+    .expect("create failed");
+
+    assert_eq!(repo.current_branch(), "test/add-login-page");
+}
+
 #[test]
 fn create_with_explicit_parent() {
     let repo = TestRepo::new();
@@ -839,6 +1163,8 @@ fn create_with_explicit_parent() {
         false,
         false,
         false,
+        None,
+        false,
     )
     .expect("create failed");
 
@@ -850,6 +1176,192 @@ fn create_with_explicit_parent() {
     assert_eq!(metadata.metadata.parent.name(), "feature");
 }
 
+// =============================================================================
+// Stack Command Tests
+// =============================================================================
+
+#[test]
+fn stack_create_makes_branch_per_commit() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    let base = repo.head_oid();
+    repo.create_branch("big");
+    repo.checkout("big");
+    repo.commit("a.txt", "a", "Add parser");
+    repo.commit("b.txt", "b", "Add lexer");
+    repo.commit("c.txt", "c", "Wire up CLI");
+
+    let ctx = repo.context();
+    commands::stack_create(&ctx, "main..big").expect("stack create failed");
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let mut expected_parent = "main".to_string();
+    let mut expected_base = base;
+    for name in ["add-parser", "add-lexer", "wire-up-cli"] {
+        let branch = BranchName::new(name).unwrap();
+        let metadata = store.read(&branch).unwrap().expect("metadata").metadata;
+        assert_eq!(metadata.parent.name(), expected_parent);
+        assert_eq!(metadata.base.oid, expected_base);
+        expected_parent = name.to_string();
+        expected_base = git
+            .resolve_ref(&format!("refs/heads/{}", name))
+            .unwrap()
+            .to_string();
+    }
+
+    // The source branch and working tree are untouched
+    assert_eq!(repo.current_branch(), "big");
+    assert_eq!(repo.head_oid(), expected_base);
+    assert!(store
+        .read(&BranchName::new("big").unwrap())
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn stack_create_refuses_merges() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("side");
+    repo.create_branch("big");
+    repo.checkout("side");
+    repo.commit("side.txt", "side", "Side change");
+    repo.checkout("big");
+    repo.commit("big.txt", "big", "Big change");
+    run_git(repo.path(), &["merge", "--no-edit", "side"]);
+
+    let ctx = repo.context();
+    let err = commands::stack_create(&ctx, "main..big").unwrap_err();
+    assert!(err.to_string().contains("linear"), "{}", err);
+    assert!(!repo.git().ref_exists("refs/heads/big-change"));
+}
+
+// =============================================================================
+// Lock Command Tests
+// =============================================================================
+
+#[test]
+fn lock_refuses_restack_for_other_collaborators() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+    repo.checkout("main");
+    repo.commit("main-update.txt", "update", "Update main");
+    repo.checkout("feature");
+
+    let ctx = repo.context();
+    commands::lock(&ctx, Some("feature"), 30, false).expect("lock failed");
+    assert!(repo.git().ref_exists("refs/lattice/locks/feature"));
+
+    // Someone else can't restack it or take the lock
+    run_git(repo.path(), &["config", "user.email", "bob@example.com"]);
+    let err = commands::restack(&ctx, Some("feature"), true, false).unwrap_err();
+    assert!(
+        err.to_string().contains("locked by test@example.com"),
+        "{}",
+        err
+    );
+    assert!(commands::lock(&ctx, Some("feature"), 30, false).is_err());
+    assert!(commands::unlock(&ctx, Some("feature"), false).is_err());
+
+    // The owner can, and releases it afterwards
+    run_git(repo.path(), &["config", "user.email", "test@example.com"]);
+    commands::restack(&ctx, Some("feature"), true, false).expect("restack failed");
+    commands::unlock(&ctx, Some("feature"), false).expect("unlock failed");
+    assert!(!repo.git().ref_exists("refs/lattice/locks/feature"));
+}
+
+#[test]
+fn shared_locks_travel_through_the_remote() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let remote = TempDir::new().unwrap();
+    run_git(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    run_git(
+        repo.path(),
+        &["remote", "add", "origin", remote.path().to_str().unwrap()],
+    );
+    let config_path = repo.git().git_dir().join("lattice/config.toml");
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    std::fs::write(
+        &config_path,
+        format!("sync_metadata_refs = true\n{}", config),
+    )
+    .unwrap();
+    repo.create_branch("feature");
+    repo.track_branch("feature", "main");
+
+    let remote_locks = || {
+        let output = Command::new("git")
+            .args(["for-each-ref", "refs/lattice/locks/"])
+            .current_dir(remote.path())
+            .output()
+            .expect("git for-each-ref failed");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let ctx = repo.context();
+    commands::lock(&ctx, Some("feature"), 30, false).expect("lock failed");
+    assert!(remote_locks().contains("refs/lattice/locks/feature"));
+
+    // A collaborator without a local copy still sees the lock
+    run_git(
+        repo.path(),
+        &["update-ref", "-d", "refs/lattice/locks/feature"],
+    );
+    run_git(repo.path(), &["config", "user.email", "bob@example.com"]);
+    let err = commands::lock(&ctx, Some("feature"), 30, false).unwrap_err();
+    assert!(err.to_string().contains("test@example.com"), "{}", err);
+
+    run_git(repo.path(), &["config", "user.email", "test@example.com"]);
+    commands::unlock(&ctx, Some("feature"), false).expect("unlock failed");
+    assert!(remote_locks().is_empty());
+}
+
+#[test]
+fn doctor_deletes_expired_locks() {
+    use latticework::core::branch_lock::{BranchLock, BranchLockStore};
+
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.track_branch("feature", "main");
+
+    let git = repo.git();
+    let expired = BranchLock::new(
+        BranchName::new("feature").unwrap(),
+        "alice@example.com",
+        chrono::Duration::minutes(-5),
+    );
+    BranchLockStore::new(&git)
+        .acquire(&expired, &UtcTimestamp::now())
+        .expect("write lock");
+
+    let snapshot = scan(&git).expect("scan");
+    let doctor = Doctor::new();
+    let diagnosis = doctor.diagnose(&snapshot);
+    let fix = diagnosis
+        .fixes
+        .iter()
+        .find(|f| f.id.as_str().starts_with("stale-branch-lock:delete"))
+        .expect("delete fix");
+    let plan = doctor
+        .plan_repairs(std::slice::from_ref(&fix.id), &diagnosis, &snapshot)
+        .expect("plan");
+    Executor::new(&git)
+        .execute(&plan, &repo.context())
+        .expect("execute");
+
+    assert!(!git.ref_exists("refs/lattice/locks/feature"));
+}
+
 // =============================================================================
 // Config Command Tests
 // =============================================================================
@@ -883,6 +1395,26 @@ fn completion_generates_scripts() {
     }
 }
 
+#[test]
+fn complete_lists_tracked_branches_and_tolerates_non_repos() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    for command in ["checkout", "co", "delete", "move", "track", "unknown"] {
+        commands::complete(&ctx, command, "f").expect("complete failed");
+    }
+
+    let outside = TempDir::new().unwrap();
+    let ctx = Context {
+        cwd: Some(outside.path().to_path_buf()),
+        ..repo.context()
+    };
+    commands::complete(&ctx, "checkout", "").expect("complete outside repo failed");
+}
+
 // =============================================================================
 // Changelog Command Tests
 // =============================================================================
@@ -917,13 +1449,77 @@ fn track_trunk_fails() {
 }
 
 #[test]
-fn checkout_nonexistent_branch_fails() {
+fn checkout_nonexistent_branch_fails() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    let ctx = repo.context();
+    let result = commands::checkout(&ctx, Some("nonexistent"), false, false, None);
+    assert!(result.is_err());
+}
+
+/// Build main -> base -> {left, right}, leaving HEAD on `base`.
+fn forked_stack(repo: &TestRepo) {
+    repo.create_branch("base");
+    repo.checkout("base");
+    repo.commit("base.txt", "base", "Add base");
+    repo.track_branch("base", "main");
+
+    for side in ["left", "right"] {
+        repo.checkout("base");
+        repo.create_branch(side);
+        repo.checkout(side);
+        repo.commit(&format!("{}.txt", side), side, &format!("Add {}", side));
+        repo.track_branch(side, "base");
+    }
+    repo.checkout("base");
+}
+
+#[test]
+fn up_at_fork_requires_choice_when_non_interactive() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    forked_stack(&repo);
+
+    let ctx = repo.context();
+    let err = commands::up(&ctx, 1, None).unwrap_err().to_string();
+    assert!(err.contains("--child"), "Should suggest --child: {}", err);
+    assert_eq!(repo.current_branch(), "base");
+}
+
+#[test]
+fn up_child_flag_picks_branch() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    forked_stack(&repo);
+
+    let ctx = repo.context();
+    commands::up(&ctx, 1, Some("right")).expect("up --child");
+    assert_eq!(repo.current_branch(), "right");
+
+    // --child may name a branch further up the stack
+    repo.checkout("main");
+    commands::top(&ctx, Some("left")).expect("top --child");
+    assert_eq!(repo.current_branch(), "left");
+}
+
+#[test]
+fn up_follows_last_traversed_line() {
     let repo = TestRepo::new();
     repo.init_lattice();
+    forked_stack(&repo);
 
+    repo.checkout("right");
     let ctx = repo.context();
-    let result = commands::checkout(&ctx, Some("nonexistent"), false, false);
-    assert!(result.is_err());
+    commands::down(&ctx, 1).expect("down");
+    assert_eq!(repo.current_branch(), "base");
+
+    commands::up(&ctx, 1, None).expect("up follows remembered child");
+    assert_eq!(repo.current_branch(), "right");
+
+    commands::bottom(&ctx).expect("bottom");
+    commands::top(&ctx, None).expect("top follows remembered line");
+    assert_eq!(repo.current_branch(), "right");
 }
 
 #[test]
@@ -933,7 +1529,7 @@ fn up_with_no_children_is_noop() {
 
     // main has no tracked children - up is a no-op, not an error
     let ctx = repo.context();
-    commands::up(&ctx, 1).expect("up with no children should succeed as no-op");
+    commands::up(&ctx, 1, None).expect("up with no children should succeed as no-op");
     assert_eq!(repo.current_branch(), "main");
 }
 
@@ -968,7 +1564,17 @@ fn info_nonexistent_branch_fails() {
     repo.init_lattice();
 
     let ctx = repo.context();
-    let result = commands::info(&ctx, Some("nonexistent"), false, false, false);
+    let result = commands::info(
+        &ctx,
+        Some("nonexistent"),
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        false,
+    );
     assert!(result.is_err());
 }
 
@@ -1067,7 +1673,7 @@ fn multiple_independent_stacks() {
 
     // Navigate within stack 2
     repo.checkout("stack2-base");
-    commands::top(&ctx).expect("top");
+    commands::top(&ctx, None).expect("top");
     assert_eq!(repo.current_branch(), "stack2-top");
 }
 
@@ -1193,7 +1799,7 @@ fn continue_resumes_after_conflict_resolution() {
     run_git(repo.path(), &["add", "shared.txt"]);
 
     // Continue the operation
-    let result = commands::continue_op(&ctx, false);
+    let result = commands::continue_op(&ctx, false, false);
     assert!(
         result.is_ok(),
         "continue should succeed after resolving conflict"
@@ -1280,7 +1886,7 @@ fn continue_without_paused_op_fails() {
     repo.init_lattice();
 
     let ctx = repo.context();
-    let result = commands::continue_op(&ctx, false);
+    let result = commands::continue_op(&ctx, false, false);
 
     // Should fail because there's no operation in progress
     assert!(result.is_err(), "continue without paused op should fail");
@@ -1535,3 +2141,624 @@ fn undo_restores_metadata_refs() {
         "metadata base should be restored to original after undo"
     );
 }
+
+/// Restack `feature` onto a new main commit, returning the operation ID.
+fn restack_onto_new_main(repo: &TestRepo, file: &str) -> String {
+    use latticework::core::ops::journal::Journal;
+
+    let paths = LatticePaths::from_repo_info(&repo.git().info().unwrap());
+    let before = Journal::list(&paths).unwrap();
+
+    repo.checkout("main");
+    repo.commit(file, "update", "Update main");
+    repo.checkout("feature");
+    commands::restack(&repo.context(), Some("feature"), true, false)
+        .expect("restack should succeed");
+
+    let after = Journal::list(&paths).unwrap();
+    let new: Vec<_> = after.iter().filter(|id| !before.contains(id)).collect();
+    assert_eq!(new.len(), 1, "restack should record one operation");
+    new[0].to_string()
+}
+
+/// The recorded base of `feature`.
+fn feature_base(repo: &TestRepo) -> String {
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let scanned = store.read(&branch).unwrap().expect("metadata");
+    scanned.metadata.base.oid.to_string()
+}
+
+#[test]
+fn undo_to_operation_reverts_every_later_operation() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature content", "Add feature");
+    repo.track_branch("feature", "main");
+    let original_base = feature_base(&repo);
+
+    let first = restack_onto_new_main(&repo, "one.txt");
+    let second = restack_onto_new_main(&repo, "two.txt");
+    assert_ne!(feature_base(&repo), original_base);
+
+    let ctx = repo.context();
+    commands::undo_list(&ctx).expect("list should succeed");
+    commands::undo_to(&ctx, &first[..8]).expect("undo should succeed");
+    assert_eq!(feature_base(&repo), original_base);
+
+    // Both operations are undone, so neither can be undone again
+    assert!(commands::undo_to(&ctx, &second).is_err());
+    assert!(commands::undo_to(&ctx, &first).is_err());
+}
+
+#[test]
+fn undo_to_refuses_when_refs_moved_in_between() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature content", "Add feature");
+    repo.track_branch("feature", "main");
+    let original_metadata = repo
+        .git()
+        .try_resolve_ref_to_object("refs/branch-metadata/feature")
+        .unwrap()
+        .expect("metadata ref")
+        .to_string();
+
+    let first = restack_onto_new_main(&repo, "one.txt");
+    // Metadata put back by hand between the two operations
+    run_git(
+        repo.path(),
+        &[
+            "update-ref",
+            "refs/branch-metadata/feature",
+            &original_metadata,
+        ],
+    );
+    restack_onto_new_main(&repo, "two.txt");
+    let base = feature_base(&repo);
+
+    let ctx = repo.context();
+    let err = commands::undo_to(&ctx, &first).unwrap_err();
+    assert!(err.to_string().contains("refs/branch-metadata/feature"));
+    // Nothing was undone
+    assert_eq!(feature_base(&repo), base);
+}
+
+#[test]
+fn repeated_undo_walks_back_through_history() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature content", "Add feature");
+    repo.track_branch("feature", "main");
+    let original_base = feature_base(&repo);
+
+    restack_onto_new_main(&repo, "one.txt");
+    let first_base = feature_base(&repo);
+    restack_onto_new_main(&repo, "two.txt");
+
+    let ctx = repo.context();
+    commands::undo(&ctx).expect("first undo should succeed");
+    assert_eq!(feature_base(&repo), first_base);
+    commands::undo(&ctx).expect("second undo should succeed");
+    assert_eq!(feature_base(&repo), original_base);
+}
+
+#[test]
+fn redo_reapplies_undone_operations_in_turn() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature content", "Add feature");
+    repo.track_branch("feature", "main");
+    let original_base = feature_base(&repo);
+
+    restack_onto_new_main(&repo, "one.txt");
+    let first_base = feature_base(&repo);
+    restack_onto_new_main(&repo, "two.txt");
+    let second_base = feature_base(&repo);
+
+    let ctx = repo.context();
+    assert!(commands::redo(&ctx).is_err(), "nothing undone yet");
+
+    commands::undo(&ctx).expect("undo");
+    commands::undo(&ctx).expect("undo");
+    assert_eq!(feature_base(&repo), original_base);
+
+    commands::redo(&ctx).expect("redo first restack");
+    assert_eq!(feature_base(&repo), first_base);
+    commands::redo(&ctx).expect("redo second restack");
+    assert_eq!(feature_base(&repo), second_base);
+    assert!(commands::redo(&ctx).is_err(), "nothing left to redo");
+
+    // Undo after redo reverts the operation again
+    commands::undo(&ctx).expect("undo again");
+    assert_eq!(feature_base(&repo), first_base);
+}
+
+#[test]
+fn redo_refuses_after_refs_moved() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature content", "Add feature");
+    repo.track_branch("feature", "main");
+
+    restack_onto_new_main(&repo, "one.txt");
+    let ctx = repo.context();
+    commands::undo(&ctx).expect("undo");
+
+    // A new restack moves the metadata the undo restored
+    restack_onto_new_main(&repo, "two.txt");
+    let base = feature_base(&repo);
+
+    let err = commands::redo(&ctx).unwrap_err();
+    assert!(err.to_string().contains("refs/branch-metadata/feature"));
+    assert_eq!(feature_base(&repo), base);
+}
+
+#[test]
+fn checkpoint_restore_rewinds_stack() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "v1", "Add feature");
+    repo.track_branch("feature", "main");
+    let saved_tip = repo.head_oid();
+
+    let ctx = repo.context();
+    commands::checkpoint_save(&ctx, "before-more", false).expect("save should succeed");
+
+    // Keep working: new commit on feature and a newly tracked branch
+    repo.commit("more.txt", "more", "More work");
+    repo.create_branch("extra");
+    repo.track_branch("extra", "feature");
+    assert_ne!(repo.head_oid(), saved_tip);
+
+    commands::checkpoint_restore(&ctx, "before-more").expect("restore should succeed");
+
+    // Checked-out branch and working tree are back at the saved tip
+    assert_eq!(repo.current_branch(), "feature");
+    assert_eq!(repo.head_oid(), saved_tip);
+    assert!(!repo.path().join("more.txt").exists());
+
+    // The branch tracked after the checkpoint is untracked but kept
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    assert!(store
+        .read(&BranchName::new("extra").unwrap())
+        .unwrap()
+        .is_none());
+    assert!(git.ref_exists("refs/heads/extra"));
+    assert!(store
+        .read(&BranchName::new("feature").unwrap())
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn checkpoint_restore_can_be_undone() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "v1", "Add feature");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    commands::checkpoint_save(&ctx, "cp", false).expect("save should succeed");
+    repo.commit("more.txt", "more", "More work");
+    let latest_tip = repo.head_oid();
+
+    commands::checkpoint_restore(&ctx, "cp").expect("restore should succeed");
+    assert_ne!(repo.head_oid(), latest_tip);
+
+    commands::undo(&ctx).expect("undo should succeed");
+    let git = repo.git();
+    assert_eq!(
+        git.resolve_ref("refs/heads/feature").unwrap().to_string(),
+        latest_tip
+    );
+}
+
+#[test]
+fn checkpoint_save_refuses_existing_name_without_force() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let ctx = repo.context();
+
+    commands::checkpoint_save(&ctx, "cp", false).expect("first save should succeed");
+    assert!(commands::checkpoint_save(&ctx, "cp", false).is_err());
+    commands::checkpoint_save(&ctx, "cp", true).expect("forced save should succeed");
+
+    commands::checkpoint_delete(&ctx, "cp").expect("delete should succeed");
+    assert!(commands::checkpoint_restore(&ctx, "cp").is_err());
+    assert!(commands::checkpoint_delete(&ctx, "cp").is_err());
+}
+
+// =============================================================================
+// Remote Override Tests
+// =============================================================================
+
+#[test]
+fn remote_set_and_unset_updates_metadata() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    run_git(
+        repo.path(),
+        &["remote", "add", "security", "/tmp/security.git"],
+    );
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    commands::remote(&ctx, Some("feature"), Some("security"), false).expect("set remote");
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert_eq!(entry.metadata.remote.as_deref(), Some("security"));
+
+    commands::remote(&ctx, Some("feature"), None, true).expect("unset remote");
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    assert_eq!(entry.metadata.remote, None);
+}
+
+#[test]
+fn remote_set_rejects_unknown_remote() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    let result = commands::remote(&ctx, Some("feature"), Some("nowhere"), false);
+    assert!(result.is_err());
+}
+
+#[test]
+fn scan_reports_missing_branch_remote() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    run_git(
+        repo.path(),
+        &["remote", "add", "security", "/tmp/security.git"],
+    );
+
+    repo.create_branch("feature");
+    repo.checkout("feature");
+    repo.commit("feature.txt", "feature", "Add feature");
+    repo.track_branch("feature", "main");
+
+    let ctx = repo.context();
+    commands::remote(&ctx, Some("feature"), Some("security"), false).expect("set remote");
+    run_git(repo.path(), &["remote", "remove", "security"]);
+
+    let git = repo.git();
+    let snapshot = scan(&git).expect("scan");
+    assert!(snapshot
+        .health
+        .issues()
+        .iter()
+        .any(|i| i.id.as_str().starts_with("branch-remote-missing:")));
+}
+
+/// Push `branch` to a bare `origin`, link a PR, then delete it remotely and
+/// prune it locally.
+fn push_and_prune(repo: &TestRepo, remote: &Path, branch: &str) {
+    run_git(repo.path(), &["push", "-q", "origin", branch]);
+
+    let git = repo.git();
+    let store = MetadataStore::new(&git);
+    let name = BranchName::new(branch).unwrap();
+    let entry = store.read(&name).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.pr = PrState::linked("github", 42, "https://github.com/o/r/pull/42");
+    store
+        .write_cas(&name, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+
+    run_git(remote, &["branch", "-D", branch]);
+    run_git(repo.path(), &["fetch", "-q", "--prune", "origin"]);
+}
+
+#[test]
+fn scan_recognizes_squash_merged_remote_branches() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let remote = TempDir::new().unwrap();
+    run_git(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    run_git(
+        repo.path(),
+        &["remote", "add", "origin", remote.path().to_str().unwrap()],
+    );
+    run_git(repo.path(), &["push", "-q", "origin", "main"]);
+
+    repo.create_branch("squashed");
+    repo.checkout("squashed");
+    repo.commit("one.txt", "one", "Add one");
+    repo.commit("two.txt", "two", "Add two");
+    repo.track_branch("squashed", "main");
+
+    // The forge squashes the branch into one new commit on trunk
+    run_git(repo.path(), &["checkout", "-q", "-b", "landing", "main"]);
+    run_git(repo.path(), &["merge", "-q", "--squash", "squashed"]);
+    run_git(repo.path(), &["commit", "-q", "-m", "Squashed (#42)"]);
+    run_git(repo.path(), &["push", "-q", "origin", "landing:main"]);
+    repo.checkout("main");
+    run_git(repo.path(), &["branch", "-q", "-D", "landing"]);
+    push_and_prune(&repo, remote.path(), "squashed");
+
+    let git = repo.git();
+    let snapshot = scan(&git).expect("scan");
+    let ids: Vec<&str> = snapshot
+        .health
+        .issues()
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    assert!(ids.iter().any(|id| id.starts_with("remote-branch-merged:")));
+    assert!(!ids
+        .iter()
+        .any(|id| id.starts_with("remote-branch-deleted:")));
+}
+
+#[test]
+fn scan_reports_pruned_remote_branches() {
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let remote = TempDir::new().unwrap();
+    run_git(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    run_git(
+        repo.path(),
+        &["remote", "add", "origin", remote.path().to_str().unwrap()],
+    );
+    run_git(repo.path(), &["push", "-q", "origin", "main"]);
+
+    repo.create_branch("landed");
+    repo.checkout("landed");
+    repo.commit("landed.txt", "landed", "Add landed");
+    repo.track_branch("landed", "main");
+    repo.create_branch("dropped");
+    repo.checkout("dropped");
+    repo.commit("dropped.txt", "dropped", "Add dropped");
+    repo.track_branch("dropped", "landed");
+    repo.checkout("main");
+
+    // 'landed' reaches the remote trunk before its branch is deleted
+    run_git(repo.path(), &["push", "-q", "origin", "landed:main"]);
+    push_and_prune(&repo, remote.path(), "landed");
+    push_and_prune(&repo, remote.path(), "dropped");
+
+    let git = repo.git();
+    let snapshot = scan(&git).expect("scan");
+    let ids: Vec<&str> = snapshot
+        .health
+        .issues()
+        .iter()
+        .map(|i| i.id.as_str())
+        .collect();
+    assert!(ids.iter().any(|id| id.starts_with("remote-branch-merged:")));
+    assert!(ids
+        .iter()
+        .any(|id| id.starts_with("remote-branch-deleted:")));
+
+    // Deleting the merged branch moves its child onto trunk
+    let doctor = Doctor::new();
+    let diagnosis = doctor.diagnose(&snapshot);
+    let fix = diagnosis
+        .fixes
+        .iter()
+        .find(|f| f.id.as_str().starts_with("remote-branch-merged:delete"))
+        .expect("delete fix");
+    let plan = doctor
+        .plan_repairs(std::slice::from_ref(&fix.id), &diagnosis, &snapshot)
+        .expect("plan");
+    Executor::new(&git)
+        .execute(&plan, &repo.context())
+        .expect("execute");
+
+    assert!(!git.ref_exists("refs/heads/landed"));
+    let store = MetadataStore::new(&git);
+    let dropped = store
+        .read(&BranchName::new("dropped").unwrap())
+        .unwrap()
+        .expect("metadata");
+    assert_eq!(
+        dropped.metadata.parent,
+        ParentInfo::Trunk {
+            name: "main".to_string()
+        }
+    );
+}
+
+#[test]
+fn doctor_repairs_branches_that_drifted_from_remote() {
+    use latticework::core::metadata::schema::FREEZE_REASON_REMOTE_DIVERGED;
+
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    let remote = TempDir::new().unwrap();
+    run_git(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    run_git(
+        repo.path(),
+        &["remote", "add", "origin", remote.path().to_str().unwrap()],
+    );
+    run_git(repo.path(), &["push", "-q", "origin", "main"]);
+
+    // 'behind' misses a commit pushed to the remote
+    repo.create_branch("behind");
+    repo.checkout("behind");
+    repo.commit("behind.txt", "one", "Add behind");
+    repo.track_branch("behind", "main");
+    repo.commit("behind.txt", "two", "Update behind");
+    run_git(repo.path(), &["push", "-q", "origin", "behind"]);
+
+    // 'diverged' was amended after it was pushed
+    repo.create_branch("diverged");
+    repo.checkout("diverged");
+    repo.commit("diverged.txt", "one", "Add diverged");
+    repo.track_branch("diverged", "behind");
+    run_git(repo.path(), &["push", "-q", "origin", "diverged"]);
+    run_git(
+        repo.path(),
+        &["commit", "-q", "--amend", "-m", "Add diverged (amended)"],
+    );
+    repo.checkout("main");
+    run_git(repo.path(), &["branch", "-f", "behind", "behind~1"]);
+
+    let git = repo.git();
+    let snapshot = scan(&git).expect("scan");
+    let doctor = Doctor::new();
+    let diagnosis = doctor.diagnose(&snapshot);
+    let fix_ids: Vec<_> = diagnosis
+        .fixes
+        .iter()
+        .filter(|f| {
+            f.id.as_str() == "remote-tracking-behind:fast-forward:behind"
+                || f.id.as_str() == "remote-tracking-diverged:force-push:diverged"
+        })
+        .map(|f| f.id.clone())
+        .collect();
+    assert_eq!(fix_ids.len(), 2, "{:?}", diagnosis.fixes);
+    let plan = doctor
+        .plan_repairs(&fix_ids, &diagnosis, &snapshot)
+        .expect("plan");
+    Executor::new(&git)
+        .execute(&plan, &repo.context())
+        .expect("execute");
+
+    let tip = |refname: &str| git.try_resolve_ref(refname).unwrap();
+    assert_eq!(tip("refs/heads/behind"), tip("refs/remotes/origin/behind"));
+    let remote_git = Git::open(remote.path()).unwrap();
+    assert_eq!(
+        remote_git.try_resolve_ref("refs/heads/diverged").unwrap(),
+        tip("refs/heads/diverged")
+    );
+    let snapshot = scan(&git).expect("scan");
+    assert!(!snapshot
+        .health
+        .issues()
+        .iter()
+        .any(|i| i.id.as_str().starts_with("remote-tracking-")));
+
+    // Marking the branch freezes it and quiets the issue
+    repo.checkout("diverged");
+    run_git(
+        repo.path(),
+        &["commit", "-q", "--amend", "-m", "Add diverged (again)"],
+    );
+    repo.checkout("main");
+    let snapshot = scan(&git).expect("scan");
+    let diagnosis = doctor.diagnose(&snapshot);
+    let fix = diagnosis
+        .fixes
+        .iter()
+        .find(|f| f.id.as_str() == "remote-tracking-diverged:mark-get:diverged")
+        .expect("mark fix");
+    let plan = doctor
+        .plan_repairs(std::slice::from_ref(&fix.id), &diagnosis, &snapshot)
+        .expect("plan");
+    Executor::new(&git)
+        .execute(&plan, &repo.context())
+        .expect("execute");
+
+    let entry = MetadataStore::new(&git)
+        .read(&BranchName::new("diverged").unwrap())
+        .unwrap()
+        .expect("metadata");
+    assert!(matches!(
+        &entry.metadata.freeze,
+        FreezeState::Frozen { reason: Some(reason), .. } if reason == FREEZE_REASON_REMOTE_DIVERGED
+    ));
+    let snapshot = scan(&git).expect("scan");
+    assert!(!snapshot
+        .health
+        .issues()
+        .iter()
+        .any(|i| i.id.as_str().starts_with("remote-tracking-")));
+}
+
+#[test]
+fn failed_pre_push_check_aborts_and_rolls_back() {
+    use latticework::core::ops::journal::OpId;
+    use latticework::engine::exec::ExecuteResult;
+    use latticework::engine::plan::{Plan, PlanStep};
+
+    let repo = TestRepo::new();
+    repo.init_lattice();
+    for branch in ["ok", "broken"] {
+        repo.create_branch(branch);
+        repo.checkout(branch);
+        repo.commit(
+            &format!("{}.txt", branch),
+            branch,
+            &format!("Add {}", branch),
+        );
+        repo.track_branch(branch, "main");
+        repo.checkout("main");
+    }
+    let main_oid = repo.head_oid();
+
+    let check = |branch: &str, command: &str| PlanStep::PrePushCheck {
+        branch: branch.to_string(),
+        command: command.to_string(),
+    };
+    let plan = Plan::new(OpId::new(), "submit")
+        .with_step(PlanStep::UpdateRefCas {
+            refname: "refs/heads/scratch".to_string(),
+            old_oid: None,
+            new_oid: main_oid,
+            reason: "applied before the checks".to_string(),
+        })
+        .with_step(check(
+            "ok",
+            "test -f ok.txt && test \"$LATTICE_BRANCH\" = ok",
+        ))
+        .with_step(check(
+            "broken",
+            "echo tests failed on $LATTICE_BRANCH; exit 3",
+        ));
+
+    let git = repo.git();
+    let result = Executor::new(&git)
+        .execute(&plan, &repo.context())
+        .expect("execute");
+
+    match result {
+        ExecuteResult::Aborted { error, .. } => {
+            assert!(
+                error.contains("pre-push check failed on 'broken' (exit code 3)"),
+                "{}",
+                error
+            );
+            assert!(error.contains("tests failed on broken"), "{}", error);
+        }
+        other => panic!("expected abort, got {:?}", other),
+    }
+
+    // The ref created earlier in the plan is rolled back
+    assert!(!git.ref_exists("refs/heads/scratch"));
+    // The temporary worktree is gone and the user's checkout is untouched
+    let paths = LatticePaths::from_repo_info(&git.info().unwrap());
+    assert!(!paths.repo_pre_push_worktree_path().exists());
+    assert_eq!(repo.current_branch(), "main");
+}
@@ -0,0 +1,115 @@
+//! Helpers shared by the integration tests that drive `git` and the `lt`
+//! binary in throwaway repositories.
+//!
+//! `lt` runs with its global config at `.git/global.toml` and `HOME` inside
+//! `.git`, so a developer's own config and stored credentials never leak
+//! into a test run.
+
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use tempfile::TempDir;
+
+/// Create a repository on `main` with a committed `README.md`.
+pub fn setup_repo() -> TempDir {
+    let repo = TempDir::new().expect("create repo dir");
+    init_repo(repo.path());
+    commit(repo.path(), "README.md", "init");
+    repo
+}
+
+/// Initialize an empty repository on `main` with a test identity.
+pub fn init_repo(path: &Path) {
+    run_git(path, &["init", "-b", "main"]);
+    run_git(path, &["config", "user.email", "test@example.com"]);
+    run_git(path, &["config", "user.name", "Test User"]);
+}
+
+/// Run git in `path`, asserting success, and return trimmed stdout.
+pub fn run_git(path: &Path, args: &[&str]) -> String {
+    run_git_with_env(path, args, &[])
+}
+
+/// Like [`run_git`], with extra environment variables.
+pub fn run_git_with_env(path: &Path, args: &[&str], env: &[(&str, &str)]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .envs(env.iter().copied())
+        .output()
+        .expect("run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Commit `file` with `message` as both its content and commit message.
+pub fn commit(path: &Path, file: &str, message: &str) {
+    commit_file(path, file, message, message);
+}
+
+/// Commit `file` with the given content.
+pub fn commit_file(path: &Path, file: &str, content: &str, message: &str) {
+    std::fs::write(path.join(file), content).unwrap();
+    run_git(path, &["add", file]);
+    run_git(path, &["commit", "-q", "-m", message]);
+}
+
+/// Path of the global config the `lt` helpers point at.
+pub fn config_path(path: &Path) -> PathBuf {
+    path.join(".git").join("global.toml")
+}
+
+/// Replace the repository's global config.
+pub fn write_config(path: &Path, contents: &str) {
+    std::fs::write(config_path(path), contents).unwrap();
+}
+
+/// An `lt` command running in `path` with English output.
+///
+/// Inside a repository it also gets the repository's own global config and
+/// no stored credentials.
+pub fn lattice(path: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_lt"));
+    command
+        .current_dir(path)
+        .env("LATTICE_LANG", "en")
+        .env_remove("LATTICE_WORKSPACE");
+    if path.join(".git").is_dir() {
+        let config = config_path(path);
+        if !config.exists() {
+            std::fs::write(&config, "").unwrap();
+        }
+        command
+            .env("HOME", path.join(".git"))
+            .env("LATTICE_CONFIG", config);
+    }
+    command
+}
+
+/// Run `lt` non-interactively in `path`.
+pub fn run_lattice(path: &Path, args: &[&str]) -> Output {
+    lattice(path)
+        .args(args)
+        .arg("--no-interactive")
+        .output()
+        .expect("run lattice")
+}
+
+/// Run `lt` non-interactively, asserting success, and return stdout.
+pub fn lattice_stdout(path: &Path, args: &[&str]) -> String {
+    let output = run_lattice(path, args);
+    assert!(
+        output.status.success(),
+        "lt {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
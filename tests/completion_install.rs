@@ -0,0 +1,79 @@
+//! Tests for `lt completion install`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use tempfile::TempDir;
+
+fn run_lattice(home: &Path, shell: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lt"))
+        .args(args)
+        .arg("--no-interactive")
+        .current_dir(home)
+        .env("HOME", home)
+        .env("SHELL", shell)
+        .env_remove("XDG_CONFIG_HOME")
+        .env_remove("XDG_DATA_HOME")
+        .env("LATTICE_LANG", "en")
+        .output()
+        .expect("run lattice")
+}
+
+#[test]
+fn install_detects_shell_and_needs_confirmation() {
+    let home = TempDir::new().unwrap();
+    let script = home.path().join(".config/fish/completions/lt.fish");
+
+    // Without --yes there is no one to confirm the write
+    let output = run_lattice(home.path(), "/usr/bin/fish", &["completion", "install"]);
+    assert!(!output.status.success());
+    assert!(!script.exists());
+
+    let output = run_lattice(
+        home.path(),
+        "/usr/bin/fish",
+        &["completion", "install", "--yes"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(std::fs::read_to_string(&script)
+        .unwrap()
+        .contains("lt __complete"));
+}
+
+#[test]
+fn install_named_shell_prints_rc_line() {
+    let home = TempDir::new().unwrap();
+    let output = run_lattice(
+        home.path(),
+        "/bin/sh",
+        &["completion", "install", "zsh", "--yes"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let script = home.path().join(".zfunc/_lt");
+    assert!(std::fs::read_to_string(&script)
+        .unwrap()
+        .contains("compdef _lt_dynamic lt"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(&format!("source {}", script.display())));
+}
+
+#[test]
+fn install_without_detectable_shell_asks_for_one() {
+    let home = TempDir::new().unwrap();
+    let output = run_lattice(
+        home.path(),
+        "/bin/tcsh",
+        &["completion", "install", "--yes"],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("lt completion install <shell>"));
+}
@@ -0,0 +1,64 @@
+//! Tests for the `--yes` flag and the `confirm` config level.
+
+mod common;
+
+use tempfile::TempDir;
+
+use common::{commit, run_git, run_lattice, setup_repo, write_config};
+
+/// Repository with `feature-a` on `main` and `feature-b` on `feature-a`.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    write_config(path, "confirm = \"never\"\n");
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature-a"]);
+    commit(path, "a.txt", "a");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature-b"]);
+    commit(path, "b.txt", "b");
+    assert!(run_lattice(path, &["track", "--parent", "feature-a"])
+        .status
+        .success());
+
+    repo
+}
+
+#[test]
+fn non_interactive_confirmation_fails_without_yes() {
+    let repo = setup();
+
+    // The config level only applies to interactive sessions
+    let output = run_lattice(repo.path(), &["untrack", "feature-a"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("descendant(s)"), "{}", stderr);
+}
+
+#[test]
+fn yes_confirms_without_terminal() {
+    let repo = setup();
+
+    let output = run_lattice(repo.path(), &["--yes", "untrack", "feature-a"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let refs = run_git(
+        repo.path(),
+        &[
+            "for-each-ref",
+            "--format=%(refname)",
+            "refs/branch-metadata/",
+        ],
+    );
+    assert!(refs.is_empty());
+}
@@ -0,0 +1,152 @@
+//! Tests for the summary printed when an operation pauses on a conflict.
+
+mod common;
+
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use common::{commit_file, init_repo, run_git, run_lattice, write_config};
+
+/// Repository where restacking `feature` onto `main` conflicts in `shared.txt`.
+fn setup() -> TempDir {
+    let repo = TempDir::new().expect("create repo dir");
+    let path = repo.path();
+    init_repo(path);
+    commit_file(path, "shared.txt", "base\n", "init");
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit_file(path, "shared.txt", "feature\n", "Change shared on feature");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+
+    run_git(path, &["checkout", "main"]);
+    commit_file(path, "shared.txt", "main\n", "Change shared on main");
+    run_git(path, &["checkout", "feature"]);
+
+    repo
+}
+
+#[test]
+fn pause_prints_stack_aware_summary() {
+    let repo = setup();
+
+    let output = run_lattice(repo.path(), &["restack"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("Conflict while restacking 'feature'"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("feature (on main)"), "{}", stdout);
+    assert!(stdout.contains("Change shared on feature"), "{}", stdout);
+    assert!(stdout.contains("both modified: shared.txt"), "{}", stdout);
+    assert!(stdout.contains("\"ours\" (HEAD) is 'main'"), "{}", stdout);
+    assert!(stdout.contains("lattice continue --skip"), "{}", stdout);
+    assert!(stdout.contains("lattice abort"), "{}", stdout);
+}
+
+#[test]
+fn continue_skip_drops_the_conflicting_commit() {
+    let repo = setup();
+    run_lattice(repo.path(), &["restack"]);
+
+    let output = run_lattice(repo.path(), &["continue", "--skip"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        run_git(repo.path(), &["rev-parse", "feature"]),
+        run_git(repo.path(), &["rev-parse", "main"])
+    );
+    assert!(
+        !run_lattice(repo.path(), &["continue"]).status.success(),
+        "operation should be finished"
+    );
+}
+
+#[test]
+fn restack_check_predicts_the_conflict_without_rebasing() {
+    let repo = setup();
+    let before = run_git(repo.path(), &["rev-parse", "feature"]);
+
+    let output = run_lattice(repo.path(), &["restack", "--check"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("feature: conflicts at") && stdout.contains("Change shared on feature"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("both modified: shared.txt"), "{}", stdout);
+    assert!(
+        stdout.contains("1 of 1 branch(es) would stop on a conflict"),
+        "{}",
+        stdout
+    );
+
+    // Nothing moved and no rebase was started
+    assert_eq!(run_git(repo.path(), &["rev-parse", "feature"]), before);
+    assert_eq!(run_git(repo.path(), &["status", "--porcelain"]), "");
+    assert!(!repo.path().join(".git/rebase-merge").exists());
+}
+
+/// Restack `feature`, resolve its conflict, and continue, then branch
+/// `again` from the original `feature` commit so it conflicts the same way.
+fn resolve_once_and_branch_again(path: &Path) {
+    let before = run_git(path, &["rev-parse", "feature"]);
+    run_lattice(path, &["restack"]);
+    std::fs::write(path.join("shared.txt"), "resolved\n").unwrap();
+    run_git(path, &["add", "shared.txt"]);
+    let output = run_lattice(path, &["continue"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    run_git(path, &["checkout", "-b", "again", &before]);
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+}
+
+#[test]
+fn restack_reuses_a_recorded_resolution() {
+    let repo = setup();
+    let path = repo.path();
+    resolve_once_and_branch_again(path);
+
+    let output = run_lattice(path, &["restack"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(!stdout.contains("Conflict while restacking"), "{}", stdout);
+    assert_eq!(run_git(path, &["status", "--porcelain"]), "");
+    assert_eq!(run_git(path, &["branch", "--show-current"]), "again");
+    assert_eq!(run_git(path, &["show", "again:shared.txt"]), "resolved");
+    assert_eq!(
+        run_git(path, &["rev-parse", "again~1"]),
+        run_git(path, &["rev-parse", "main"])
+    );
+}
+
+#[test]
+fn resolutions_are_not_reused_when_turned_off() {
+    let repo = setup();
+    let path = repo.path();
+    write_config(path, "[restack]\nreuse_resolutions = false\n");
+    resolve_once_and_branch_again(path);
+
+    let output = run_lattice(path, &["restack"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Conflict while restacking"), "{}", stdout);
+    assert!(!path.join(".git/rr-cache").exists());
+}
@@ -0,0 +1,79 @@
+//! Tests for branches created from forge issues (`lt create --from-issue`).
+
+mod common;
+
+use latticework::core::metadata::schema::IssueLink;
+use latticework::core::metadata::store::MetadataStore;
+use latticework::core::types::BranchName;
+use latticework::git::Git;
+use tempfile::TempDir;
+
+use common::{commit, run_git, run_lattice, setup_repo};
+
+/// Repository with `feature` tracked on `main`.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit(path, "feature.txt", "feature");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+    repo
+}
+
+#[test]
+fn unavailable_issue_creates_nothing() {
+    let repo = setup();
+    run_git(
+        repo.path(),
+        &["remote", "add", "origin", "https://github.com/o/r.git"],
+    );
+
+    let output = run_lattice(repo.path(), &["create", "--from-issue", "5"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Not authenticated"), "{}", stderr);
+
+    assert_eq!(
+        run_git(repo.path(), &["branch", "--show-current"]),
+        "feature"
+    );
+    assert_eq!(
+        run_git(repo.path(), &["branch", "--list"]).lines().count(),
+        2
+    );
+}
+
+#[test]
+fn info_shows_linked_issue() {
+    let repo = setup();
+    let git = Git::open(repo.path()).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.issue = Some(IssueLink {
+        number: 5,
+        url: "https://github.com/o/r/issues/5".to_string(),
+    });
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+
+    let output = run_lattice(repo.path(), &["info"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Issue: #5 (https://github.com/o/r/issues/5)"),
+        "{}",
+        stdout
+    );
+
+    let output = run_lattice(repo.path(), &["info", "--json"]);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issue"]["number"], 5);
+}
@@ -0,0 +1,110 @@
+//! Tests for showing prefetched PR status in `lt log` and `lt info`.
+
+mod common;
+
+use std::path::Path;
+
+use latticework::core::metadata::schema::PrState;
+use latticework::core::metadata::store::MetadataStore;
+use latticework::core::types::BranchName;
+use latticework::git::Git;
+use tempfile::TempDir;
+
+use common::{commit, lattice_stdout, run_git, run_lattice, setup_repo};
+
+/// Repository with `feature` tracked on `main` and linked to PR #42.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit(path, "feature.txt", "feature");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+
+    let git = Git::open(path).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.pr = PrState::linked("github", 42, "https://github.com/o/r/pull/42");
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+    repo
+}
+
+/// Write a cached status for `feature`'s PR `number`.
+fn seed_cache(path: &Path, number: u64) {
+    let dir = path.join(".git").join("lattice").join("cache");
+    std::fs::create_dir_all(&dir).unwrap();
+    let cache = serde_json::json!({
+        "entries": {
+            "feature": {
+                "number": number,
+                "state": "open",
+                "is_draft": false,
+                "checks": { "passed": 2, "failed": 1, "pending": 0 },
+                "fetched_at": chrono::Utc::now().to_rfc3339(),
+            }
+        },
+        "last_prefetch": null,
+    });
+    std::fs::write(dir.join("forge_status.json"), cache.to_string()).unwrap();
+}
+
+#[test]
+fn log_and_info_show_cached_status() {
+    let repo = setup();
+    seed_cache(repo.path(), 42);
+
+    let log = lattice_stdout(repo.path(), &["log"]);
+    assert!(
+        log.contains("feature (#42 open, 1 check failing)"),
+        "{}",
+        log
+    );
+
+    let long = lattice_stdout(repo.path(), &["log", "--long"]);
+    assert!(long.contains("pr: #42 open, 1 check failing"), "{}", long);
+
+    let info = lattice_stdout(repo.path(), &["info"]);
+    assert!(
+        info.contains("PR status: #42 open, 1 check failing (fetched just now)"),
+        "{}",
+        info
+    );
+}
+
+#[test]
+fn status_for_another_pr_is_ignored() {
+    let repo = setup();
+    seed_cache(repo.path(), 7);
+
+    let log = lattice_stdout(repo.path(), &["log"]);
+    assert!(!log.contains("#7"), "{}", log);
+    let info = lattice_stdout(repo.path(), &["info"]);
+    assert!(!info.contains("PR status"), "{}", info);
+}
+
+#[test]
+fn navigation_succeeds_without_forge_access() {
+    let repo = setup();
+
+    assert!(run_lattice(repo.path(), &["down"]).status.success());
+    let output = run_lattice(repo.path(), &["up"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        run_git(repo.path(), &["branch", "--show-current"]),
+        "feature"
+    );
+}
@@ -26,7 +26,7 @@ use tempfile::TempDir;
 use latticework::cli::commands;
 use latticework::engine::capabilities::{Capability, CapabilitySet};
 use latticework::engine::gate::{gate, requirements, GateResult};
-use latticework::engine::scan::scan;
+use latticework::engine::scan::{scan, scan_for};
 use latticework::engine::Context;
 use latticework::git::Git;
 
@@ -88,6 +88,8 @@ fn test_context(path: &Path) -> Context {
         quiet: true,
         debug: false,
         verify: true,
+        offline: false,
+        read_only: false,
     }
 }
 
@@ -290,6 +292,34 @@ mod mutating_gating {
             GateResult::NeedsRepair(_) => {} // Expected
         }
     }
+
+    /// `--read-only` in the context blocks mutating commands for that
+    /// context only.
+    #[test]
+    fn mutating_fails_for_read_only_context() {
+        let dir = create_initialized_repo();
+        let git = Git::open(dir.path()).expect("failed to open git");
+        let ctx = Context {
+            read_only: true,
+            ..test_context(dir.path())
+        };
+
+        let snapshot = scan_for(&git, &ctx).expect("failed to scan");
+        match gate(snapshot, &requirements::MUTATING) {
+            GateResult::Ready(_) => panic!("Expected NeedsRepair in read-only mode, got Ready"),
+            GateResult::NeedsRepair(bundle) => {
+                assert!(bundle
+                    .missing_capabilities
+                    .contains(&Capability::WritesAllowed));
+            }
+        }
+
+        let snapshot = scan_for(&git, &test_context(dir.path())).expect("failed to scan");
+        assert!(snapshot
+            .health
+            .capabilities()
+            .has(&Capability::WritesAllowed));
+    }
 }
 
 // =============================================================================
@@ -390,11 +420,18 @@ mod recovery_gating {
     /// Recovery commands have minimal requirements.
     #[test]
     fn recovery_has_minimal_requirements() {
-        // RECOVERY only requires RepoOpen
-        assert_eq!(requirements::RECOVERY.capabilities.len(), 1);
+        // RECOVERY only requires RepoOpen, outside read-only mode and in
+        // the repository the config names
+        assert_eq!(requirements::RECOVERY.capabilities.len(), 3);
         assert!(requirements::RECOVERY
             .capabilities
             .contains(&Capability::RepoOpen));
+        assert!(requirements::RECOVERY
+            .capabilities
+            .contains(&Capability::WritesAllowed));
+        assert!(requirements::RECOVERY
+            .capabilities
+            .contains(&Capability::RepoIdentityVerified));
     }
 
     /// Recovery commands do NOT require no-op-in-progress.
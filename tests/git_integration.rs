@@ -9,7 +9,7 @@ use std::process::Command;
 use tempfile::TempDir;
 
 use latticework::core::types::Oid;
-use latticework::git::{Git, GitError, GitState};
+use latticework::git::{FileChange, Git, GitError, GitState, LfsPushProblem, MaintenanceTask};
 
 /// Test fixture that creates a real git repository.
 struct TestRepo {
@@ -524,6 +524,22 @@ fn commit_count_linear_history() {
     assert_eq!(count, 3);
 }
 
+#[test]
+fn commits_between_lists_newest_first() {
+    let repo = TestRepo::new();
+    let base = repo.git().head_oid().unwrap();
+    let first = repo.commit_file("a.txt", "a", "First");
+    let second = repo.commit_file("b.txt", "b", "Second");
+
+    let commits = repo.git().commits_between(&base, &second).unwrap();
+    let oids: Vec<_> = commits.iter().map(|c| c.oid.clone()).collect();
+    assert_eq!(oids, vec![second, first]);
+    assert_eq!(commits[0].summary, "Second");
+    assert_eq!(commits[0].author_name, "Test User");
+
+    assert!(repo.git().commits_between(&base, &base).unwrap().is_empty());
+}
+
 #[test]
 fn commit_count_same_commit_is_zero() {
     let repo = TestRepo::new();
@@ -534,6 +550,53 @@ fn commit_count_same_commit_is_zero() {
     assert_eq!(count, 0);
 }
 
+#[test]
+fn diff_stat_counts_files_and_lines() {
+    let repo = TestRepo::new();
+    let git = repo.git();
+
+    let base = git.head_oid().unwrap();
+    repo.commit_file("a.txt", "one\ntwo\n", "add a");
+    let tip = repo.commit_file("README.md", "# Renamed\n", "edit readme");
+
+    let stat = git.diff_stat(&base, &tip).unwrap();
+    assert_eq!(stat.files_changed, 2);
+    assert_eq!(stat.insertions, 3);
+    assert_eq!(stat.deletions, 1);
+    assert_eq!(stat.lines_changed(), 4);
+}
+
+#[test]
+fn changed_files_mark_additions_modifications_and_deletions() {
+    let repo = TestRepo::new();
+    let git = repo.git();
+
+    repo.commit_file("old.txt", "old\n", "add old");
+    let base = git.head_oid().unwrap();
+    repo.commit_file("new.txt", "new\n", "add new");
+    repo.commit_file("README.md", "# Changed\n", "edit readme");
+    run_git(repo.path(), &["rm", "-q", "old.txt"]);
+    run_git(repo.path(), &["commit", "-m", "remove old"]);
+    let tip = git.head_oid().unwrap();
+
+    let files: Vec<(String, FileChange)> = git
+        .changed_files(&base, &tip)
+        .unwrap()
+        .into_iter()
+        .map(|f| (f.path, f.change))
+        .collect();
+    assert_eq!(
+        files,
+        vec![
+            ("README.md".to_string(), FileChange::Modified),
+            ("new.txt".to_string(), FileChange::Added),
+            ("old.txt".to_string(), FileChange::Deleted),
+        ]
+    );
+    assert_eq!(FileChange::Deleted.marker(), 'D');
+    assert!(git.changed_files(&tip, &tip).unwrap().is_empty());
+}
+
 // =============================================================================
 // Blob Operations Tests
 // =============================================================================
@@ -779,3 +842,124 @@ fn default_remote_prefers_origin() {
     let remote = git.default_remote().unwrap();
     assert_eq!(remote, Some("origin".to_string()));
 }
+
+#[test]
+fn remote_heads_and_fetch_objects_leave_refs_untouched() {
+    let upstream = TestRepo::new();
+    upstream.create_branch("feature");
+    upstream.checkout("feature");
+    let feature_tip = upstream.commit_file("feature.txt", "feature", "Add feature");
+
+    let repo = TestRepo::new();
+    let url = upstream.path().to_str().unwrap();
+    run_git(repo.path(), &["remote", "add", "origin", url]);
+    let git = repo.git();
+
+    let heads = git.remote_heads("origin").unwrap();
+    assert_eq!(heads.get("feature"), Some(&feature_tip));
+
+    git.fetch_objects("origin", Some("feature")).unwrap();
+    assert!(git.commit_info(&feature_tip).is_ok());
+    assert!(git
+        .try_resolve_ref("refs/remotes/origin/feature")
+        .unwrap()
+        .is_none());
+    assert!(!repo.path().join(".git/FETCH_HEAD").exists());
+}
+
+#[test]
+fn remote_heads_fails_for_unknown_remote() {
+    let repo = TestRepo::new();
+    assert!(repo.git().remote_heads("nowhere").is_err());
+}
+
+// =============================================================================
+// Maintenance
+// =============================================================================
+
+#[test]
+fn auto_maintenance_enabled_by_default() {
+    let repo = TestRepo::new();
+    assert!(repo.git().auto_maintenance_enabled().unwrap());
+}
+
+#[test]
+fn auto_maintenance_honors_git_config() {
+    let repo = TestRepo::new();
+    run_git(repo.path(), &["config", "maintenance.auto", "false"]);
+    assert!(!repo.git().auto_maintenance_enabled().unwrap());
+}
+
+#[test]
+fn spawn_maintenance_starts_in_background() {
+    let repo = TestRepo::new();
+    repo.git()
+        .spawn_maintenance(&[MaintenanceTask::CommitGraph, MaintenanceTask::LooseObjects])
+        .unwrap();
+}
+
+// =============================================================================
+// Git LFS
+// =============================================================================
+
+/// Content of a Git LFS pointer file as committed by `git lfs track`.
+const LFS_POINTER: &str = "version https://git-lfs.github.com/spec/v1\n\
+oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+size 12345\n";
+
+#[test]
+fn uses_lfs_detects_gitattributes() {
+    let repo = TestRepo::new();
+    repo.commit_file(
+        ".gitattributes",
+        "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        "Track binaries with LFS",
+    );
+    assert!(repo.git().uses_lfs());
+}
+
+#[test]
+fn lfs_pointers_lists_added_pointer_files() {
+    let repo = TestRepo::new();
+    let base = repo.git().head_oid().unwrap();
+    repo.commit_file("model.bin", LFS_POINTER, "Add model");
+    let tip = repo.commit_file("notes.txt", "plain text\n", "Add notes");
+
+    let pointers = repo.git().lfs_pointers(&base, &tip).unwrap();
+    assert_eq!(pointers, vec!["model.bin".to_string()]);
+    assert!(repo.git().lfs_pointers(&tip, &tip).unwrap().is_empty());
+}
+
+#[test]
+fn lfs_push_problem_without_pre_push_hook() {
+    let repo = TestRepo::new();
+    // Either git-lfs is missing here, or it is but `git lfs install` never ran
+    assert!(matches!(
+        repo.git().lfs_push_problem(),
+        Some(LfsPushProblem::NotInstalled | LfsPushProblem::NoPrePushHook)
+    ));
+}
+
+#[test]
+fn run_command_uses_lfs_process_filter() {
+    let repo = TestRepo::new();
+    run_git(
+        repo.path(),
+        &["config", "filter.lfs.smudge", "git-lfs smudge -- %f"],
+    );
+    run_git(
+        repo.path(),
+        &["config", "filter.lfs.clean", "git-lfs clean -- %f"],
+    );
+
+    let result = repo
+        .git()
+        .run_command(&[
+            "config".to_string(),
+            "--get".to_string(),
+            "filter.lfs.process".to_string(),
+        ])
+        .unwrap();
+    assert!(result.success);
+    assert_eq!(result.stdout.trim(), "git-lfs filter-process");
+}
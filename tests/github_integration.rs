@@ -369,6 +369,7 @@ mod failure_injection_tests {
             base: "main".to_string(),
             title: "PR".to_string(),
             node_id: None,
+            author: None,
             body: None,
         }])
         .fail_on(FailOn::UpdatePr(ForgeError::RateLimited));
@@ -411,6 +412,7 @@ mod failure_injection_tests {
             base: "main".to_string(),
             title: "PR".to_string(),
             node_id: None,
+            author: None,
             body: None,
         }])
         .fail_on(FailOn::MergePr(ForgeError::RateLimited));
@@ -741,6 +743,7 @@ mod merge_flow_tests {
                 base: "main".to_string(),
                 title: "PR 1".to_string(),
                 node_id: None,
+                author: None,
                 body: None,
             },
             PullRequest {
@@ -752,6 +755,7 @@ mod merge_flow_tests {
                 base: "feature1".to_string(),
                 title: "PR 2".to_string(),
                 node_id: None,
+                author: None,
                 body: None,
             },
         ])
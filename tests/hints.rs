@@ -0,0 +1,50 @@
+//! Tests for `lt hints`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use tempfile::TempDir;
+
+fn run_lattice(home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lt"))
+        .args(args)
+        .current_dir(home)
+        .env("HOME", home)
+        .env_remove("XDG_CONFIG_HOME")
+        .env("LATTICE_LANG", "en")
+        .output()
+        .expect("run lattice")
+}
+
+#[test]
+fn dismiss_and_reset_persist_outside_repositories() {
+    let home = TempDir::new().unwrap();
+    let state = home.path().join(".lattice/hints.toml");
+
+    let output = run_lattice(home.path(), &["hints"]);
+    assert!(output.status.success());
+    let listing = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        listing.contains("create    shown 0 of 3 times"),
+        "{}",
+        listing
+    );
+    assert!(listing.contains("push '<branch>'"), "{}", listing);
+
+    let output = run_lattice(home.path(), &["hints", "--dismiss", "create"]);
+    assert!(output.status.success());
+    assert!(std::fs::read_to_string(&state)
+        .unwrap()
+        .contains("\"create\""));
+    let output = run_lattice(home.path(), &["hints"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("create    dismissed"));
+
+    let output = run_lattice(home.path(), &["hints", "--dismiss", "nope"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown hint 'nope'"));
+
+    let output = run_lattice(home.path(), &["hints", "--reset"]);
+    assert!(output.status.success());
+    let output = run_lattice(home.path(), &["hints"]);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("dismissed"));
+}
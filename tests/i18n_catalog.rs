@@ -0,0 +1,71 @@
+//! Checks that source code and the English message catalog agree.
+//!
+//! A key missing from the catalog renders as the raw key at runtime, so
+//! typos would otherwise only show up in front of users.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Keys defined in `locales/en.ftl`.
+fn catalog_keys() -> BTreeSet<String> {
+    let source = fs::read_to_string("locales/en.ftl").expect("read en.ftl");
+    source
+        .lines()
+        .filter(|line| !line.starts_with(['#', ' ', '\t']))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().to_string())
+        .collect()
+}
+
+/// String literals in `src/` that look like keys with one of `prefixes`.
+fn referenced_keys(dir: &Path, prefixes: &BTreeSet<String>, keys: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).expect("read src dir") {
+        let path = entry.expect("dir entry").path();
+        if path.is_dir() {
+            referenced_keys(&path, prefixes, keys);
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+
+        // Comments mention branch names and examples that look like keys
+        let source: String = fs::read_to_string(&path)
+            .expect("read source")
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        for literal in source.split('"').skip(1).step_by(2) {
+            let is_key = literal.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+                && literal.contains('-');
+            let prefix = literal.split('-').next().unwrap_or_default();
+            if is_key && prefixes.contains(prefix) {
+                keys.insert(literal.to_string());
+            }
+        }
+    }
+}
+
+#[test]
+fn every_referenced_key_is_in_the_catalog_and_vice_versa() {
+    let catalog = catalog_keys();
+    let prefixes: BTreeSet<String> = catalog
+        .iter()
+        .map(|key| key.split('-').next().unwrap().to_string())
+        .collect();
+
+    let mut referenced = BTreeSet::new();
+    referenced_keys(Path::new("src"), &prefixes, &mut referenced);
+
+    let missing: Vec<_> = referenced.difference(&catalog).collect();
+    assert!(
+        missing.is_empty(),
+        "keys missing from en.ftl: {:?}",
+        missing
+    );
+
+    let unused: Vec<_> = catalog.difference(&referenced).collect();
+    assert!(unused.is_empty(), "unused keys in en.ftl: {:?}", unused);
+}
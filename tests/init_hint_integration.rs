@@ -84,6 +84,8 @@ impl TestRepo {
             quiet,
             debug: false,
             verify: true,
+            offline: false,
+            read_only: false,
         }
     }
 }
@@ -0,0 +1,95 @@
+//! Tests for `lt integrations install`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use tempfile::TempDir;
+
+fn setup() -> TempDir {
+    let repo = TempDir::new().expect("create repo dir");
+    let output = Command::new("git")
+        .args(["init", "-b", "main"])
+        .current_dir(repo.path())
+        .output()
+        .expect("run git");
+    assert!(output.status.success());
+    repo
+}
+
+fn run_lattice(path: &Path, config_home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lt"))
+        .args(args)
+        .arg("--no-interactive")
+        .current_dir(path)
+        .env("XDG_CONFIG_HOME", config_home)
+        .env("LATTICE_LANG", "en")
+        .output()
+        .expect("run lattice")
+}
+
+#[test]
+fn vscode_tasks_are_merged() {
+    let repo = setup();
+    let config = TempDir::new().unwrap();
+    let tasks = repo.path().join(".vscode").join("tasks.json");
+    std::fs::create_dir_all(tasks.parent().unwrap()).unwrap();
+    std::fs::write(
+        &tasks,
+        r#"{ "version": "2.0.0", "tasks": [{ "label": "build", "command": "make" }] }"#,
+    )
+    .unwrap();
+
+    let output = run_lattice(
+        repo.path(),
+        config.path(),
+        &["integrations", "install", "vscode"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let content = std::fs::read_to_string(&tasks).unwrap();
+    assert!(content.contains("\"build\""));
+    assert!(content.contains("lt submit --stack"));
+    assert!(content.contains("lt stats --json"));
+}
+
+#[test]
+fn nvim_plugin_respects_foreign_files() {
+    let repo = setup();
+    let config = TempDir::new().unwrap();
+    let plugin = config
+        .path()
+        .join("nvim")
+        .join("plugin")
+        .join("lattice.lua");
+
+    let args = ["integrations", "install", "nvim"];
+    assert!(run_lattice(repo.path(), config.path(), &args)
+        .status
+        .success());
+    assert!(std::fs::read_to_string(&plugin)
+        .unwrap()
+        .contains("\"LtSubmit\""));
+
+    // Regenerating our own file is fine, replacing a hand-written one is not
+    assert!(run_lattice(repo.path(), config.path(), &args)
+        .status
+        .success());
+    std::fs::write(&plugin, "-- mine\n").unwrap();
+    let output = run_lattice(repo.path(), config.path(), &args);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--force"));
+
+    let output = run_lattice(
+        repo.path(),
+        config.path(),
+        &["integrations", "install", "nvim", "--force"],
+    );
+    assert!(output.status.success());
+    assert!(std::fs::read_to_string(&plugin)
+        .unwrap()
+        .contains("\"LtLog\""));
+}
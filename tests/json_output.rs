@@ -0,0 +1,338 @@
+//! Tests for `--json` output from read-only commands.
+
+mod common;
+
+use std::path::Path;
+
+use latticework::core::metadata::schema::PrState;
+use latticework::core::metadata::store::MetadataStore;
+use latticework::core::types::BranchName;
+use latticework::git::Git;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use common::{commit, run_git, run_lattice, setup_repo};
+
+/// Repository with `a` on `main` (linked to PR #10), `b` and `c` on `a`,
+/// and `b` checked out.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    for (branch, parent) in [("a", "main"), ("c", "a"), ("b", "a")] {
+        run_git(path, &["checkout", "-q", parent]);
+        run_git(path, &["checkout", "-q", "-b", branch]);
+        commit(path, &format!("{}.txt", branch), &format!("Add {}", branch));
+        assert!(run_lattice(path, &["track", "--parent", parent])
+            .status
+            .success());
+    }
+
+    let git = Git::open(path).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("a").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.pr = PrState::linked("github", 10, "https://github.com/o/r/pull/10");
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+    repo
+}
+
+/// Run a command with `--json` and parse its stdout.
+fn json(path: &Path, args: &[&str]) -> Value {
+    let mut args = args.to_vec();
+    args.push("--json");
+    let output = run_lattice(path, &args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("stdout is one JSON document")
+}
+
+#[test]
+fn log_lists_branches_parents_first() {
+    let repo = setup();
+    let log = json(repo.path(), &["log", "--all"]);
+
+    assert_eq!(log["trunk"], "main");
+    assert_eq!(log["current"], "b");
+    let names: Vec<&str> = log["branches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["a", "b", "c"]);
+
+    let a = &log["branches"][0];
+    assert_eq!(a["parent"], "main");
+    assert_eq!(a["children"], serde_json::json!(["b", "c"]));
+    assert_eq!(a["frozen"], false);
+    assert_eq!(a["needs_restack"], false);
+    assert_eq!(a["pr"]["number"], 10);
+    assert_eq!(a["pr"]["url"], "https://github.com/o/r/pull/10");
+    assert_eq!(log["branches"][1]["current"], true);
+    assert_eq!(log["branches"][1]["pr"], Value::Null);
+    assert_eq!(log["untracked"], serde_json::json!([]));
+}
+
+#[test]
+fn log_exports_stack_graph() {
+    let repo = setup();
+    let output = run_lattice(repo.path(), &["log", "--all", "--export", "json-graph"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let graph: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let graph = &graph["graph"];
+    assert_eq!(graph["nodes"]["main"]["metadata"]["trunk"], true);
+    assert_eq!(graph["nodes"]["a"]["metadata"]["pr"]["number"], 10);
+    assert_eq!(graph["nodes"]["b"]["metadata"]["current"], true);
+    assert_eq!(graph["edges"].as_array().unwrap().len(), 3);
+    assert_eq!(graph["edges"][0]["source"], "main");
+
+    let output = run_lattice(repo.path(), &["log", "--all", "--export", "dot"]);
+    let dot = String::from_utf8_lossy(&output.stdout);
+    assert!(dot.contains("\"a\" -> \"b\";"), "{}", dot);
+    assert!(
+        dot.contains("URL=\"https://github.com/o/r/pull/10\""),
+        "{}",
+        dot
+    );
+}
+
+#[test]
+fn log_ops_exports_ledger_events() {
+    let repo = setup();
+    assert!(run_lattice(repo.path(), &["freeze", "c", "--only"])
+        .status
+        .success());
+
+    let events = json(repo.path(), &["log", "--ops"]);
+    let events = events["events"].as_array().unwrap();
+    let committed = events
+        .iter()
+        .find(|e| e["type"] == "committed" && e["command"] == "freeze")
+        .expect("freeze commit");
+    assert_eq!(committed["branches"], serde_json::json!(["c"]));
+    assert!(committed["fingerprint"].is_string());
+    assert_eq!(committed["event"]["op_id"], committed["op_id"]);
+    assert!(events.iter().all(|e| e["type"] != "command_timed"));
+
+    // Oldest first: the freeze came before the unfreeze
+    assert!(run_lattice(repo.path(), &["unfreeze", "c", "--only"])
+        .status
+        .success());
+    let output = run_lattice(repo.path(), &["log", "--ops", "--reverse"]);
+    let listing = String::from_utf8_lossy(&output.stdout);
+    let freeze = listing.find(" freeze ").expect("freeze listed");
+    let unfreeze = listing.find(" unfreeze ").expect("unfreeze listed");
+    assert!(freeze < unfreeze, "{}", listing);
+    assert!(listing.contains("branches: c"), "{}", listing);
+}
+
+#[test]
+fn info_describes_branch() {
+    let repo = setup();
+    let info = json(repo.path(), &["info", "a"]);
+
+    assert_eq!(info["branch"], "a");
+    assert_eq!(info["tracked"], true);
+    assert_eq!(info["current"], false);
+    assert_eq!(info["parent"], "main");
+    assert_eq!(info["parent_is_trunk"], true);
+    assert_eq!(info["pr"]["number"], 10);
+    assert_eq!(info["commits"].as_array().unwrap().len(), 1);
+    assert_eq!(info["commits"][0]["summary"], "Add a");
+}
+
+#[test]
+fn info_lists_changed_files() {
+    let repo = setup();
+    let info = json(repo.path(), &["info", "b", "--files"]);
+    assert_eq!(
+        info["files"],
+        serde_json::json!([{ "path": "b.txt", "change": "added" }])
+    );
+    assert!(json(repo.path(), &["info", "b"]).get("files").is_none());
+
+    // --stack lists every branch, and which files overlap
+    let output = run_lattice(repo.path(), &["info", "a", "--stack", "--files"]);
+    let listing = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        listing.contains("a (on main)\n  A a.txt\nb (on a)\n  A b.txt\n"),
+        "{}",
+        listing
+    );
+    assert!(!listing.contains("more than one branch"), "{}", listing);
+    let output = run_lattice(repo.path(), &["info", "a", "--stack"]);
+    assert!(!output.status.success());
+
+    let output = run_lattice(repo.path(), &["log", "--long"]);
+    let log = String::from_utf8_lossy(&output.stdout);
+    assert!(log.contains("    files:\n      A b.txt\n"), "{}", log);
+}
+
+#[test]
+fn info_rejects_diff_with_json() {
+    let repo = setup();
+    let output = run_lattice(repo.path(), &["info", "--diff", "--json"]);
+    assert!(!output.status.success());
+    let error: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(error["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("--json"));
+}
+
+#[test]
+fn parent_and_children() {
+    let repo = setup();
+    assert_eq!(
+        json(repo.path(), &["parent"]),
+        serde_json::json!({ "branch": "b", "tracked": true, "parent": "a" })
+    );
+
+    run_git(repo.path(), &["checkout", "-q", "a"]);
+    assert_eq!(
+        json(repo.path(), &["children"]),
+        serde_json::json!({ "branch": "a", "children": ["b", "c"] })
+    );
+}
+
+#[test]
+fn pr_lists_stack_prs() {
+    let repo = setup();
+    assert_eq!(
+        json(repo.path(), &["pr", "--stack"]),
+        serde_json::json!({
+            "prs": [{
+                "branch": "a",
+                "number": 10,
+                "url": "https://github.com/o/r/pull/10",
+                "ci": null
+            }]
+        })
+    );
+}
+
+#[test]
+fn pr_shows_ci_state_cached_in_metadata() {
+    use latticework::core::metadata::schema::{CiState, CiStatus};
+    use latticework::core::types::UtcTimestamp;
+
+    let repo = setup();
+    let git = Git::open(repo.path()).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("a").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.ci = Some(CiStatus {
+        state: CiState::Failing,
+        oid: git.resolve_ref("refs/heads/a").unwrap().to_string(),
+        checked_at: UtcTimestamp::now(),
+    });
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+
+    let pr = json(repo.path(), &["--offline", "pr", "--stack"]);
+    let ci = &pr["prs"][0]["ci"];
+    assert_eq!(ci["state"], "failing");
+    assert_eq!(ci["outdated"], false);
+}
+
+#[test]
+fn doctor_list_reports_issues_and_fixes() {
+    let repo = setup();
+    run_git(repo.path(), &["branch", "loose"]);
+
+    let doctor = json(repo.path(), &["doctor", "--list"]);
+    let issues = doctor["issues"].as_array().unwrap();
+    assert!(
+        issues
+            .iter()
+            .any(|i| i["message"].as_str().unwrap().contains("loose")),
+        "{}",
+        doctor
+    );
+    assert!(doctor["fixes"].is_array());
+}
+
+#[test]
+fn audit_reports_without_changing_anything() {
+    let repo = setup();
+    run_git(repo.path(), &["branch", "loose"]);
+    assert!(run_lattice(repo.path(), &["freeze", "c"]).status.success());
+
+    let audit = json(repo.path(), &["audit"]);
+    assert_eq!(audit["trunk"], "main");
+    assert_eq!(audit["tracked"], serde_json::json!(["a", "b", "c"]));
+    assert_eq!(audit["untracked"], serde_json::json!(["loose"]));
+    assert_eq!(audit["prs"][0]["number"], 10);
+    assert_eq!(audit["prs"][1]["number"], Value::Null);
+    assert_eq!(audit["stale_stacks"], serde_json::json!([]));
+    // Freezing c freezes its downstack too
+    let frozen: Vec<&str> = audit["frozen"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["branch"].as_str().unwrap())
+        .collect();
+    assert_eq!(frozen, ["a", "c"]);
+    assert_eq!(
+        audit["frozen"][1]["tip_author"],
+        "Test User <test@example.com>"
+    );
+    assert!(audit.get("fixes").is_none());
+
+    // Markdown by default, and the untracked branch is still untracked
+    let output = run_lattice(repo.path(), &["audit"]);
+    let markdown = String::from_utf8_lossy(&output.stdout);
+    assert!(markdown.starts_with("# Lattice audit"), "{}", markdown);
+    assert!(markdown.contains("- PR linkage: 1 of 3 tracked branches (33%)"));
+    assert_eq!(
+        json(repo.path(), &["audit"])["untracked"],
+        audit["untracked"]
+    );
+}
+
+#[test]
+fn debug_cache_stats_reports_each_cache() {
+    let repo = setup();
+    // Moving trunk makes the scan check each stack's ancestry
+    run_git(repo.path(), &["checkout", "-q", "main"]);
+    commit(repo.path(), "main.txt", "Move trunk");
+    assert!(run_lattice(repo.path(), &["log"]).status.success());
+    assert!(run_lattice(repo.path(), &["log"]).status.success());
+
+    let stats = json(repo.path(), &["debug", "cache-stats"]);
+    let names: Vec<&str> = stats["caches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["ancestry", "forge-status", "authorization"]);
+    let ancestry = &stats["caches"][0];
+    assert!(ancestry["entries"].as_u64().unwrap() > 0, "{}", ancestry);
+    assert!(ancestry["hits"].as_u64().unwrap() > 0, "{}", ancestry);
+    assert!(ancestry["bytes"].as_u64().unwrap() > 0);
+    assert_eq!(stats["caches"][2]["hit_rate"], Value::Null);
+
+    assert!(run_lattice(repo.path(), &["debug", "clear-cache"])
+        .status
+        .success());
+    let stats = json(repo.path(), &["debug", "cache-stats"]);
+    assert_eq!(stats["caches"][0]["entries"], 0);
+    assert_eq!(stats["caches"][0]["hits"], 0);
+}
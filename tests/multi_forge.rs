@@ -3,7 +3,7 @@
 //! These tests verify:
 //! - Forge provider detection from URLs
 //! - Forge factory error handling
-//! - GitLab stub behavior (when feature enabled)
+//! - GitLab forge behavior (when feature enabled)
 //! - Configuration-driven forge selection
 
 use latticework::forge::{
@@ -172,101 +172,251 @@ mod valid_forges {
     }
 }
 
-#[cfg(feature = "gitlab")]
-mod gitlab_stub {
-    use latticework::forge::gitlab::GitLabForge;
-    use latticework::forge::{
-        CreatePrRequest, Forge, ForgeError, MergeMethod, Reviewers, UpdatePrRequest,
-    };
+mod github_enterprise {
+    use latticework::forge::{create_forge, CreatePrRequest};
+    use serde_json::json;
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn pr(draft: bool) -> serde_json::Value {
+        json!({
+            "number": 12,
+            "html_url": "http://ghe.internal/team/app/pull/12",
+            "state": "open",
+            "draft": draft,
+            "head": { "ref": "feature" },
+            "base": { "ref": "main" },
+            "title": "Add feature",
+            "body": null,
+            "node_id": "PR_node12",
+            "merged": false
+        })
+    }
 
     #[tokio::test]
-    async fn create_pr_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge
+    async fn enterprise_remote_uses_host_api_and_graphql() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/team/app/pulls"))
+            .and(header("authorization", "Bearer ghu_enterprise"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pr(false)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/team/app/pulls/12"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pr(false)))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": {} })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The remote's host serves the API, as on an Enterprise Server instance
+        let remote = format!("{}/team/app.git", server.uri());
+        let forge = create_forge(&remote, "ghu_enterprise", Some("github")).unwrap();
+        assert_eq!(forge.name(), "github");
+
+        let created = forge
             .create_pr(CreatePrRequest {
                 head: "feature".into(),
                 base: "main".into(),
-                title: "Test MR".into(),
+                title: "Add feature".into(),
                 body: None,
                 draft: false,
             })
-            .await;
-
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+            .await
+            .unwrap();
+        assert_eq!(created.number, 12);
 
-        // Verify error message is actionable
-        if let Err(ForgeError::NotImplemented(msg)) = result {
-            assert!(
-                msg.contains("not yet implemented"),
-                "Should mention not implemented: {}",
-                msg
-            );
-            assert!(
-                msg.contains("http"),
-                "Should include link for updates: {}",
-                msg
-            );
-        }
+        forge.set_draft(12, true).await.unwrap();
     }
 
     #[tokio::test]
-    async fn update_pr_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge
-            .update_pr(UpdatePrRequest {
-                number: 1,
-                title: Some("New title".into()),
-                ..Default::default()
+    async fn pr_statuses_are_fetched_in_one_request() {
+        let server = MockServer::start().await;
+        let node = |number: u64| {
+            json!({
+                "id": format!("PR_{}", number),
+                "number": number,
+                "url": format!("http://ghe.internal/team/app/pull/{}", number),
+                "state": "OPEN",
+                "isDraft": false,
+                "headRefName": format!("feature-{}", number),
+                "baseRefName": "main",
+                "title": "Add feature",
+                "body": "",
+                "author": null,
+                "mergeable": "MERGEABLE",
+                "reviewDecision": null,
+                "commits": { "nodes": [] }
             })
+        };
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .and(body_string_contains("pr2: pullRequest(number: 14)"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "repository": { "pr0": node(12), "pr1": node(13), "pr2": node(14) } }
+            })))
+            .expect(1)
+            .mount(&server)
             .await;
 
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+        let remote = format!("{}/team/app.git", server.uri());
+        let forge = create_forge(&remote, "ghu_enterprise", Some("github")).unwrap();
+        let statuses = forge.get_pr_statuses(&[12, 13, 14]).await.unwrap();
+
+        let numbers: Vec<u64> = statuses.iter().map(|s| s.pr.number).collect();
+        assert_eq!(numbers, vec![12, 13, 14]);
+        assert!(statuses.iter().all(|s| s.mergeable == Some(true)));
     }
 
     #[tokio::test]
-    async fn get_pr_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge.get_pr(1).await;
+    async fn secondary_rate_limit_is_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/team/app/pulls/12"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("retry-after", "0")
+                    .set_body_json(json!({
+                        "message": "You have exceeded a secondary rate limit."
+                    })),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/team/app/pulls/12"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pr(false)))
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+        let remote = format!("{}/team/app.git", server.uri());
+        let forge = create_forge(&remote, "ghu_enterprise", Some("github")).unwrap();
+        assert_eq!(forge.get_pr(12).await.unwrap().number, 12);
     }
 
     #[tokio::test]
-    async fn find_pr_by_head_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge.find_pr_by_head("feature").await;
+    async fn spent_budget_fails_as_rate_limited() {
+        use latticework::forge::ForgeError;
+
+        let server = MockServer::start().await;
+        let reset = chrono::Utc::now().timestamp() + 3600;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/team/app/pulls/12"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("x-ratelimit-limit", "5000")
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", reset.to_string().as_str())
+                    .set_body_json(json!({ "message": "API rate limit exceeded" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+        let remote = format!("{}/team/app.git", server.uri());
+        let forge = create_forge(&remote, "ghu_enterprise", Some("github")).unwrap();
+        let err = forge.get_pr(12).await.unwrap_err();
+        assert!(matches!(err, ForgeError::RateLimited), "{:?}", err);
     }
 
-    #[tokio::test]
-    async fn set_draft_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge.set_draft(1, true).await;
+    #[test]
+    fn enterprise_host_is_not_detected_by_name() {
+        use latticework::forge::detect_provider;
 
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+        assert_eq!(detect_provider("git@github.example.com:team/app.git"), None);
+    }
+}
+
+#[cfg(feature = "gitlab")]
+mod gitlab {
+    use latticework::forge::gitlab::GitLabForge;
+    use latticework::forge::{create_forge, CreatePrRequest, Forge, ForgeError, MergeMethod};
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const MR_PATH: &str = "/api/v4/projects/team%2Fapp/merge_requests";
+
+    fn mr(state: &str) -> serde_json::Value {
+        json!({
+            "iid": 7,
+            "web_url": "http://gitlab.internal/team/app/-/merge_requests/7",
+            "state": state,
+            "draft": false,
+            "source_branch": "feature",
+            "target_branch": "main",
+            "title": "Add feature",
+            "description": null,
+            "updated_at": "2026-01-01T00:00:00Z",
+            "source_project_id": 1,
+            "target_project_id": 1
+        })
     }
 
     #[tokio::test]
-    async fn request_reviewers_returns_not_implemented() {
-        let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge
-            .request_reviewers(
-                1,
-                Reviewers {
-                    users: vec!["alice".into()],
-                    teams: vec![],
-                },
-            )
+    async fn self_hosted_remote_creates_finds_and_merges() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(MR_PATH))
+            .respond_with(ResponseTemplate::new(201).set_body_json(mr("opened")))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(MR_PATH))
+            .and(query_param("source_branch", "feature"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([mr("opened")])))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/7/merge", MR_PATH)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mr("merged")))
+            .expect(1)
+            .mount(&server)
             .await;
 
-        assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
+        // The remote's host serves the API, as on a self-hosted instance
+        let remote = format!("{}/team/app.git", server.uri());
+        let forge = create_forge(&remote, "glpat", Some("gitlab")).unwrap();
+
+        let created = forge
+            .create_pr(CreatePrRequest {
+                head: "feature".into(),
+                base: "main".into(),
+                title: "Add feature".into(),
+                body: None,
+                draft: false,
+            })
+            .await
+            .unwrap();
+        assert_eq!(created.number, 7);
+
+        let found = forge.find_pr_by_head("feature").await.unwrap().unwrap();
+        assert_eq!(found.base, "main");
+
+        forge.merge_pr(7, MergeMethod::Merge).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn missing_token_requires_auth() {
+        let forge = GitLabForge::new("", "owner", "project");
+        let result = forge.get_pr(1).await;
+
+        assert!(matches!(result, Err(ForgeError::AuthRequired)));
     }
 
     #[tokio::test]
-    async fn merge_pr_returns_not_implemented() {
+    async fn rebase_merge_is_not_offered() {
         let forge = GitLabForge::new("token", "owner", "project");
-        let result = forge.merge_pr(1, MergeMethod::Squash).await;
+        let result = forge.merge_pr(1, MergeMethod::Rebase).await;
 
         assert!(matches!(result, Err(ForgeError::NotImplemented(_))));
     }
@@ -183,6 +183,8 @@ impl TestRepo {
             quiet: true,
             debug: false,
             verify: true,
+            offline: false,
+            read_only: false,
         }
     }
 
@@ -480,9 +482,17 @@ impl OobFuzzHarness {
             }
             LatticeOp::Untrack { branch } => commands::untrack(&ctx, Some(branch), true),
             LatticeOp::Restack { branch } => commands::restack(&ctx, Some(branch), true, false),
-            LatticeOp::Create { name } => {
-                commands::create(&ctx, Some(name), None, false, false, false, false)
-            }
+            LatticeOp::Create { name } => commands::create(
+                &ctx,
+                Some(name),
+                None,
+                false,
+                false,
+                false,
+                false,
+                None,
+                false,
+            ),
             LatticeOp::Freeze { branch } => commands::freeze(&ctx, Some(branch), false),
             LatticeOp::Unfreeze { branch } => commands::unfreeze(&ctx, Some(branch), false),
         };
@@ -1285,7 +1295,9 @@ mod engine_hooks_verification {
 
         // Log is read-only and should NOT fire hook
         // Signature: log(ctx, short, long, stack, all, reverse)
-        let _ = commands::log(&ctx, true, false, false, false, false);
+        let _ = commands::log(
+            &ctx, true, false, false, false, false, None, false, None, false,
+        );
         let after = counter.get();
 
         // Read-only commands should not increment the hook counter
@@ -1318,7 +1330,7 @@ mod engine_hooks_verification {
         let initial = counter.get();
 
         // Info is read-only (args: ctx, branch, diff, stat, patch)
-        let _ = commands::info(&ctx, None, false, false, false);
+        let _ = commands::info(&ctx, None, false, false, false, false, false, false, false);
         let after = counter.get();
 
         assert_eq!(
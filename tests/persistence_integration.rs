@@ -12,7 +12,7 @@ use std::process::Command;
 
 use tempfile::TempDir;
 
-use latticework::core::metadata::schema::{BranchMetadataV1, FreezeScope, FreezeState, PrState};
+use latticework::core::metadata::schema::{BranchMetadataV2, FreezeScope, FreezeState, PrState};
 use latticework::core::metadata::store::{MetadataStore, StoreError};
 use latticework::core::ops::journal::{Journal, OpPhase, OpState, StepKind};
 use latticework::core::ops::lock::{LockError, RepoLock};
@@ -149,7 +149,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create (expected_old = None)
         let new_oid = store
@@ -170,7 +170,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let mut meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let mut meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create
         let first_oid = store.write_cas(&branch, None, &meta).expect("create");
@@ -196,7 +196,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create
         store.write_cas(&branch, None, &meta).expect("create");
@@ -216,7 +216,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create
         store.write_cas(&branch, None, &meta).expect("create");
@@ -235,7 +235,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create
         let oid = store.write_cas(&branch, None, &meta).expect("create");
@@ -256,7 +256,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         // Create
         store.write_cas(&branch, None, &meta).expect("create");
@@ -303,7 +303,7 @@ mod metadata_store {
         // Create metadata for several branches
         for name in ["feature-a", "feature-b", "feature-c"] {
             let branch = BranchName::new(name).unwrap();
-            let meta = BranchMetadataV1::new(branch.clone(), main.clone(), sample_oid());
+            let meta = BranchMetadataV2::new(branch.clone(), main.clone(), sample_oid());
             store.write_cas(&branch, None, &meta).expect("create");
         }
 
@@ -327,7 +327,7 @@ mod metadata_store {
 
         assert!(!store.exists(&branch).expect("exists before"));
 
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
         store.write_cas(&branch, None, &meta).expect("create");
 
         assert!(store.exists(&branch).expect("exists after"));
@@ -342,7 +342,7 @@ mod metadata_store {
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
 
-        let meta = BranchMetadataV1::builder(branch.clone(), parent, sample_oid())
+        let meta = BranchMetadataV2::builder(branch.clone(), parent, sample_oid())
             .freeze_state(FreezeState::frozen(
                 FreezeScope::DownstackInclusive,
                 Some("teammate branch".into()),
@@ -364,7 +364,7 @@ mod metadata_store {
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
 
-        let meta = BranchMetadataV1::builder(branch.clone(), parent, sample_oid())
+        let meta = BranchMetadataV2::builder(branch.clone(), parent, sample_oid())
             .pr_state(PrState::linked(
                 "github",
                 42,
@@ -387,7 +387,7 @@ mod metadata_store {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         let expected_oid = store.write_cas(&branch, None, &meta).expect("create");
 
@@ -400,6 +400,308 @@ mod metadata_store {
     }
 }
 
+// =============================================================================
+// Metadata Signing Tests
+// =============================================================================
+
+mod metadata_signing {
+    use super::*;
+    use latticework::core::metadata::store::{split_signature, MetadataSignature};
+    use latticework::engine::scan::scan;
+
+    fn run(dir: &Path, program: &str, args: &[&str]) {
+        let output = Command::new(program)
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap_or_else(|e| panic!("{} failed: {}", program, e));
+        assert!(
+            output.status.success(),
+            "{} {:?} failed: {}",
+            program,
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Sign metadata with a fresh SSH key that the repo trusts.
+    fn enable_signing(repo: &TestRepo) {
+        let key = repo.git_dir().join("signing-key");
+        let key_path = key.to_str().unwrap();
+        run(
+            repo.path(),
+            "ssh-keygen",
+            &["-q", "-t", "ed25519", "-N", "", "-f", key_path],
+        );
+
+        let public_key = std::fs::read_to_string(key.with_extension("pub")).unwrap();
+        let allowed = repo.git_dir().join("allowed_signers");
+        std::fs::write(&allowed, format!("test@example.com {}", public_key)).unwrap();
+
+        run(repo.path(), "git", &["config", "gpg.format", "ssh"]);
+        run(repo.path(), "git", &["config", "user.signingkey", key_path]);
+        run(
+            repo.path(),
+            "git",
+            &[
+                "config",
+                "gpg.ssh.allowedSignersFile",
+                allowed.to_str().unwrap(),
+            ],
+        );
+
+        let lattice_dir = repo.git_dir().join("lattice");
+        std::fs::create_dir_all(&lattice_dir).unwrap();
+        std::fs::write(
+            lattice_dir.join("config.toml"),
+            "trunk = \"main\"\nsign_metadata = true\n",
+        )
+        .unwrap();
+    }
+
+    /// Write metadata for `feature` and return its blob OID.
+    fn write_feature(git: &Git) -> Oid {
+        let branch = BranchName::new("feature").unwrap();
+        let parent = BranchName::new("main").unwrap();
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
+        MetadataStore::new(git)
+            .write_cas(&branch, None, &meta)
+            .expect("write_cas")
+    }
+
+    /// Rewrite the parent in a signed blob, keeping the old signature.
+    fn tamper(git: &Git, ref_oid: &Oid) -> Oid {
+        let content = git.read_blob_as_string(ref_oid).unwrap();
+        let tampered = content.replace("\"main\"", "\"release\"");
+        assert_ne!(content, tampered);
+        let tampered_oid = git.write_blob(tampered.as_bytes()).unwrap();
+        git.update_ref_cas(
+            "refs/branch-metadata/feature",
+            &tampered_oid,
+            Some(ref_oid),
+            "test: tamper",
+        )
+        .unwrap();
+        tampered_oid
+    }
+
+    #[test]
+    fn unsigned_by_default() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+        let oid = write_feature(&git);
+
+        let content = git.read_blob_as_string(&oid).unwrap();
+        assert_eq!(split_signature(&content).1, None);
+        assert_eq!(
+            MetadataStore::new(&git).verify_signature(&oid).unwrap(),
+            MetadataSignature::Unsigned
+        );
+    }
+
+    #[test]
+    fn signed_metadata_verifies_and_reads() {
+        let repo = TestRepo::new();
+        enable_signing(&repo);
+        let git = repo.git();
+        let oid = write_feature(&git);
+
+        let store = MetadataStore::new(&git);
+        assert_eq!(
+            store.verify_signature(&oid).unwrap(),
+            MetadataSignature::Valid {
+                signer: "test@example.com".to_string()
+            }
+        );
+
+        let branch = BranchName::new("feature").unwrap();
+        let entry = store.read(&branch).unwrap().expect("should exist");
+        assert_eq!(entry.metadata.parent.name(), "main");
+    }
+
+    #[test]
+    fn tampered_metadata_fails_verification() {
+        let repo = TestRepo::new();
+        enable_signing(&repo);
+        let git = repo.git();
+        let oid = write_feature(&git);
+        let tampered = tamper(&git, &oid);
+
+        assert!(matches!(
+            MetadataStore::new(&git)
+                .verify_signature(&tampered)
+                .unwrap(),
+            MetadataSignature::Invalid { .. }
+        ));
+    }
+
+    #[test]
+    fn scan_reports_tampered_and_unsigned_metadata() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+
+        // Written before signing was enabled
+        write_feature(&git);
+        enable_signing(&repo);
+        let snapshot = scan(&git).unwrap();
+        assert!(snapshot
+            .health
+            .issues()
+            .iter()
+            .any(|i| i.id.as_str().starts_with("metadata-unsigned:")));
+        assert!(!snapshot
+            .health
+            .issues()
+            .iter()
+            .any(|i| i.id.as_str().starts_with("metadata-signature-invalid:")));
+
+        // Re-sign, then tamper
+        let branch = BranchName::new("feature").unwrap();
+        let store = MetadataStore::new(&git);
+        let entry = store.read(&branch).unwrap().unwrap();
+        let signed = store
+            .write_cas(&branch, Some(&entry.ref_oid), &entry.metadata)
+            .unwrap();
+        tamper(&git, &signed);
+
+        let snapshot = scan(&git).unwrap();
+        let invalid = snapshot
+            .health
+            .issues()
+            .iter()
+            .find(|i| i.id.as_str().starts_with("metadata-signature-invalid:"))
+            .expect("tampering reported");
+        assert!(invalid.is_blocking());
+        assert!(!snapshot
+            .health
+            .capabilities()
+            .has(&latticework::engine::capabilities::Capability::MetadataReadable));
+    }
+}
+
+// =============================================================================
+// Schema Migration Tests
+// =============================================================================
+
+mod schema_migration {
+    use super::*;
+    use latticework::doctor::Doctor;
+    use latticework::engine::exec::Executor;
+    use latticework::engine::scan::scan;
+    use latticework::engine::Context;
+
+    /// Create `feature` with v1 metadata stored directly, as an older
+    /// Lattice would.
+    fn write_v1_feature(repo: &TestRepo) -> Oid {
+        let git = repo.git();
+        repo.create_branch("feature");
+        let json = format!(
+            r#"{{"kind":"lattice.branch-metadata","schema_version":1,"branch":{{"name":"feature"}},"parent":{{"kind":"trunk","name":"main"}},"base":{{"oid":"{}"}},"freeze":{{"state":"unfrozen"}},"pr":{{"state":"none"}},"timestamps":{{"created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}}}}"#,
+            git.head_oid().unwrap()
+        );
+        let oid = git.write_blob(json.as_bytes()).unwrap();
+        git.update_ref_cas("refs/branch-metadata/feature", &oid, None, "test: v1")
+            .unwrap();
+        oid
+    }
+
+    fn stored_version(git: &Git) -> u32 {
+        let branch = BranchName::new("feature").unwrap();
+        MetadataStore::new(git)
+            .read(&branch)
+            .unwrap()
+            .unwrap()
+            .stored_version
+    }
+
+    fn has_outdated_issue(git: &Git) -> bool {
+        scan(git)
+            .unwrap()
+            .health
+            .issues()
+            .iter()
+            .any(|i| i.id.as_str().starts_with("metadata-schema-outdated:"))
+    }
+
+    #[test]
+    fn scan_upgrades_v1_metadata_in_memory_only() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+        let old_oid = write_v1_feature(&repo);
+
+        let snapshot = scan(&git).unwrap();
+        let scanned = &snapshot.metadata[&BranchName::new("feature").unwrap()];
+        assert_eq!(scanned.ref_oid, old_oid);
+        assert_eq!(scanned.metadata.parent.name(), "main");
+        assert!(has_outdated_issue(&git));
+
+        // Scan never writes: the blob and the journal directory are untouched
+        assert_eq!(stored_version(&git), 1);
+        assert!(Journal::list(&repo.paths()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn doctor_upgrade_fix_rewrites_v1_metadata() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+        let old_oid = write_v1_feature(&repo);
+
+        let snapshot = scan(&git).unwrap();
+        let doctor = Doctor::new();
+        let diagnosis = doctor.diagnose(&snapshot);
+        let fix = diagnosis
+            .fixes
+            .iter()
+            .find(|f| f.id.as_str() == "metadata-schema-outdated:upgrade:feature")
+            .expect("upgrade fix");
+        let plan = doctor
+            .plan_repairs(std::slice::from_ref(&fix.id), &diagnosis, &snapshot)
+            .unwrap();
+        let ctx = Context {
+            cwd: Some(repo.path().to_path_buf()),
+            interactive: false,
+            quiet: true,
+            debug: false,
+            verify: true,
+            offline: false,
+            read_only: false,
+        };
+        Executor::new(&git).execute(&plan, &ctx).unwrap();
+
+        assert_eq!(stored_version(&git), 2);
+        assert!(!has_outdated_issue(&git));
+
+        // The rewrite is journaled like any other metadata write
+        let journals = Journal::list(&repo.paths()).unwrap();
+        assert_eq!(journals.len(), 1);
+        let journal = Journal::read(&repo.paths(), &journals[0]).unwrap();
+        assert_eq!(journal.phase, OpPhase::Committed);
+        assert!(journal.steps.iter().any(|step| matches!(
+            &step.kind,
+            StepKind::MetadataWrite { old_ref_oid: Some(old), .. } if *old == old_oid.to_string()
+        )));
+    }
+
+    #[test]
+    fn read_only_scan_leaves_v1_metadata_alone() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+        let old_oid = write_v1_feature(&repo);
+        let paths = repo.paths();
+        std::fs::create_dir_all(paths.repo_lattice_dir()).unwrap();
+        std::fs::write(paths.repo_config_path(), "read_only = true\n").unwrap();
+
+        assert!(has_outdated_issue(&git));
+        assert_eq!(stored_version(&git), 1);
+        let ref_oid = git
+            .try_resolve_ref_to_object("refs/branch-metadata/feature")
+            .unwrap()
+            .unwrap();
+        assert_eq!(ref_oid, old_oid);
+        assert!(Journal::list(&paths).unwrap().is_empty());
+    }
+}
+
 // =============================================================================
 // RepoLock Tests
 // =============================================================================
@@ -662,7 +964,7 @@ mod integration {
         let store = MetadataStore::new(&git);
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         let meta_oid = store
             .write_cas(&branch, None, &meta)
@@ -682,6 +984,80 @@ mod integration {
         assert!(store.exists(&branch).expect("branch exists"));
     }
 
+    #[test]
+    fn rollback_restores_metadata_from_journaled_snapshots() {
+        let repo = TestRepo::new();
+        let git = repo.git();
+        let paths = repo.paths();
+        let store = MetadataStore::new(&git);
+        let mut journal = Journal::new("move");
+
+        // Update "feature" and delete "other", journaling snapshots
+        let feature = BranchName::new("feature").unwrap();
+        let other = BranchName::new("other").unwrap();
+        let main = BranchName::new("main").unwrap();
+        let feature_old = store
+            .write_cas(
+                &feature,
+                None,
+                &BranchMetadataV2::new(feature.clone(), main.clone(), sample_oid()),
+            )
+            .expect("write feature");
+        let other_old = store
+            .write_cas(
+                &other,
+                None,
+                &BranchMetadataV2::new(other.clone(), main.clone(), sample_oid()),
+            )
+            .expect("write other");
+
+        let snapshot = store.snapshot(&feature_old);
+        let feature_new = store
+            .write_cas(
+                &feature,
+                Some(&feature_old),
+                &BranchMetadataV2::new(feature.clone(), other.clone(), sample_oid()),
+            )
+            .expect("update feature");
+        journal
+            .append_metadata_write(
+                &paths,
+                "feature",
+                Some(feature_old.to_string()),
+                snapshot,
+                feature_new.to_string(),
+            )
+            .expect("journal write");
+
+        let snapshot = store.snapshot(&other_old);
+        store.delete_cas(&other, &other_old).expect("delete other");
+        journal
+            .append_metadata_delete(&paths, "other", other_old.to_string(), snapshot)
+            .expect("journal delete");
+        assert!(journal.can_fully_rollback());
+
+        // Simulate `git gc` pruning the now-unreferenced old blobs
+        for oid in [&feature_old, &other_old] {
+            let hex = oid.as_str();
+            let object = repo
+                .git_dir()
+                .join("objects")
+                .join(&hex[..2])
+                .join(&hex[2..]);
+            std::fs::remove_file(object).expect("remove loose blob");
+        }
+
+        let git = repo.git();
+        let result = latticework::engine::rollback::rollback_journal(&git, &journal);
+        assert!(result.complete, "rollback failed: {:?}", result.failed);
+
+        let store = MetadataStore::new(&git);
+        let restored = store.read(&feature).expect("read").expect("feature");
+        assert_eq!(restored.ref_oid, feature_old);
+        let restored = store.read(&other).expect("read").expect("other");
+        assert_eq!(restored.ref_oid, other_old);
+    }
+
     #[test]
     fn simulated_crash_recovery() {
         let repo = TestRepo::new();
@@ -720,7 +1096,7 @@ mod integration {
 
         let branch = BranchName::new("feature").unwrap();
         let parent = BranchName::new("main").unwrap();
-        let meta = BranchMetadataV1::new(branch.clone(), parent, sample_oid());
+        let meta = BranchMetadataV2::new(branch.clone(), parent, sample_oid());
 
         store.write_cas(&branch, None, &meta).expect("write");
 
@@ -1,6 +1,6 @@
 //! Integration tests for Phase 3 advanced rewriting commands.
 //!
-//! Tests cover: modify, move, rename, delete, squash, fold, pop, reorder, split, revert
+//! Tests cover: modify, move, rename, delete, squash, fold, pop, reorder, edit, absorb, split, revert
 //!
 //! Per ROADMAP.md Milestone 9, each command must have:
 //! - Happy path integration test
@@ -93,6 +93,44 @@ fn run_lattice_expect_fail(path: &Path, args: &[&str]) -> String {
     String::from_utf8_lossy(&output.stderr).to_string()
 }
 
+/// Run a lattice command whose editor replaces the file with `todo`.
+fn run_lattice_with_todo(path: &Path, args: &[&str], todo: &str) -> std::process::Output {
+    use std::os::unix::fs::PermissionsExt;
+
+    let scratch = TempDir::new().expect("create temp dir");
+    let todo_path = scratch.path().join("todo");
+    fs::write(&todo_path, todo).expect("write todo");
+    let editor = scratch.path().join("editor.sh");
+    fs::write(
+        &editor,
+        format!("#!/bin/sh\ncp '{}' \"$1\"\n", todo_path.display()),
+    )
+    .expect("write editor");
+    fs::set_permissions(&editor, fs::Permissions::from_mode(0o755)).expect("chmod editor");
+
+    Command::new(env!("CARGO_BIN_EXE_lt"))
+        .args(args)
+        .env("LATTICE_TEST_EDITOR", &editor)
+        .current_dir(path)
+        .output()
+        .expect("run lattice")
+}
+
+/// Abbreviated oid of a revision.
+fn short_oid(path: &Path, rev: &str) -> String {
+    run_git(path, &["rev-parse", "--short=7", rev])
+        .trim()
+        .to_string()
+}
+
+/// Commit subjects in a range, oldest first.
+fn subjects(path: &Path, range: &str) -> Vec<String> {
+    run_git(path, &["log", "--reverse", "--format=%s", range])
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
 /// Get current branch name.
 fn current_branch(path: &Path) -> String {
     run_git(path, &["rev-parse", "--abbrev-ref", "HEAD"])
@@ -164,6 +202,85 @@ fn modify_frozen_branch_fails() {
     );
 }
 
+#[test]
+fn modify_previews_and_restacks_descendants() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "parent", "parent content");
+    create_branch(path, "child", "child content");
+    run_git(path, &["checkout", "parent"]);
+
+    fs::write(path.join("parent.txt"), "amended").expect("write");
+    let out = run_lattice(path, &["modify", "-a"]);
+
+    assert!(
+        out.contains("Will restack 1 descendant(s):"),
+        "Should preview restack: {}",
+        out
+    );
+    assert!(out.contains("child  (clean)"), "Should estimate: {}", out);
+
+    // child now sits on the amended parent
+    run_git(path, &["merge-base", "--is-ancestor", "parent", "child"]);
+}
+
+#[test]
+fn modify_no_restack_defers_descendants() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "parent", "parent content");
+    create_branch(path, "child", "child content");
+    run_git(path, &["checkout", "parent"]);
+    let child_before = run_git(path, &["rev-parse", "child"]);
+
+    fs::write(path.join("parent.txt"), "amended").expect("write");
+    let out = run_lattice(path, &["modify", "-a", "--no-restack"]);
+
+    assert!(
+        out.contains("Deferred restack of 1 descendant(s): child"),
+        "Should report deferral: {}",
+        out
+    );
+    assert_eq!(run_git(path, &["rev-parse", "child"]), child_before);
+
+    // A later restack picks it up
+    run_lattice(path, &["restack"]);
+    run_git(path, &["merge-base", "--is-ancestor", "parent", "child"]);
+}
+
+#[test]
+fn modify_shows_up_in_remote_diff_until_pushed() {
+    let dir = setup_repo();
+    let path = dir.path();
+    let remote = TempDir::new().expect("create remote dir");
+    run_git(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    run_git(
+        path,
+        &["remote", "add", "origin", remote.path().to_str().unwrap()],
+    );
+
+    let content: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+    create_branch(path, "feature", &content);
+    run_git(path, &["push", "-q", "origin", "feature"]);
+    let out = run_lattice(path, &["info", "--remote-diff"]);
+    assert!(out.contains("matches origin/feature"), "{}", out);
+
+    let amended = content.replace("line 10\n", "amended\n");
+    fs::write(path.join("feature.txt"), amended).expect("write");
+    run_lattice(path, &["modify", "-a"]);
+    let out = run_lattice(path, &["info", "--remote-diff"]);
+    assert!(
+        out.contains("Local changes not on origin/feature"),
+        "{}",
+        out
+    );
+    // The amended commit pairs with the pushed one and shows the edit
+    assert!(out.contains(" ! 1:  "), "{}", out);
+    assert!(out.contains("amended"), "{}", out);
+}
+
 // ========== MOVE TESTS ==========
 
 #[test]
@@ -501,6 +618,312 @@ fn reorder_needs_multiple_branches() {
     );
 }
 
+// ========== EDIT TESTS ==========
+
+#[test]
+fn edit_stack_moves_commit_to_child_branch() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    fs::write(path.join("extra.txt"), "extra").expect("write");
+    run_git(path, &["add", "."]);
+    run_git(path, &["commit", "-m", "Extra on a"]);
+    create_branch(path, "b", "b");
+
+    let todo = format!(
+        "branch a\npick {} Commit on a\nbranch b\npick {} Extra on a\npick {} Commit on b\n",
+        short_oid(path, "a~1"),
+        short_oid(path, "a"),
+        short_oid(path, "b"),
+    );
+    let output = run_lattice_with_todo(path, &["edit", "--stack", "--yes"], &todo);
+    assert!(
+        output.status.success(),
+        "edit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(subjects(path, "main..a"), vec!["Commit on a"]);
+    assert_eq!(subjects(path, "a..b"), vec!["Extra on a", "Commit on b"]);
+    assert_eq!(current_branch(path), "b");
+
+    // The child's recorded base follows the rewritten parent
+    let a_tip = run_git(path, &["rev-parse", "a"]);
+    let meta = run_git(path, &["cat-file", "-p", "refs/branch-metadata/b"]);
+    assert!(meta.contains(a_tip.trim()), "base not updated: {}", meta);
+}
+
+#[test]
+fn edit_rewords_and_drops_commits() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "solo", "one");
+    fs::write(path.join("two.txt"), "two").expect("write");
+    run_git(path, &["add", "."]);
+    run_git(path, &["commit", "-m", "Second commit"]);
+
+    let todo = format!(
+        "branch solo\nreword {} Better subject\ndrop {} Second commit\n",
+        short_oid(path, "solo~1"),
+        short_oid(path, "solo"),
+    );
+    let output = run_lattice_with_todo(path, &["edit", "--yes"], &todo);
+    assert!(
+        output.status.success(),
+        "edit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(subjects(path, "main..solo"), vec!["Better subject"]);
+    assert!(!path.join("two.txt").exists());
+}
+
+#[test]
+fn edit_rejects_reordered_branches() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    create_branch(path, "b", "b");
+    let before = run_git(path, &["rev-parse", "a", "b"]);
+
+    let todo = format!(
+        "branch b\npick {} Commit on b\nbranch a\npick {} Commit on a\n",
+        short_oid(path, "b"),
+        short_oid(path, "a"),
+    );
+    let output = run_lattice_with_todo(path, &["edit", "--stack", "--yes"], &todo);
+    assert!(!output.status.success(), "edit should fail");
+    assert_eq!(run_git(path, &["rev-parse", "a", "b"]), before);
+}
+
+#[test]
+fn edit_frozen_branch_fails() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "frozen-edit", "content");
+    run_lattice(path, &["freeze"]);
+
+    let stderr = run_lattice_expect_fail(path, &["edit"]);
+    assert!(
+        stderr.contains("frozen"),
+        "Should fail for frozen: {}",
+        stderr
+    );
+}
+
+#[test]
+fn edit_branch_then_done_restacks_and_returns() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    create_branch(path, "b", "b");
+    create_branch(path, "c", "c");
+
+    let output = run_lattice(path, &["edit", "a"]);
+    assert!(output.contains("lattice edit --done"), "{}", output);
+    assert_eq!(current_branch(path), "a");
+
+    // Amend with plain git; --done does the restack
+    fs::write(path.join("a.txt"), "fixed").expect("write");
+    run_git(path, &["commit", "-a", "--amend", "--no-edit"]);
+    run_lattice(path, &["edit", "--done"]);
+
+    assert_eq!(current_branch(path), "c");
+    assert_eq!(run_git(path, &["show", "c:a.txt"]), "fixed");
+    run_git(path, &["merge-base", "--is-ancestor", "a", "b"]);
+    run_git(path, &["merge-base", "--is-ancestor", "b", "c"]);
+
+    let stderr = run_lattice_expect_fail(path, &["edit", "--done"]);
+    assert!(stderr.contains("No branch edit in progress"), "{}", stderr);
+}
+
+#[test]
+fn edit_branch_patch_amends_staged_changes_and_returns() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    create_branch(path, "b", "b");
+    let commits_on_a = subjects(path, "main..a");
+
+    fs::write(path.join("a.txt"), "fixed").expect("write");
+    fs::write(path.join("b.txt"), "work in progress").expect("write");
+    run_git(path, &["add", "a.txt"]);
+    run_lattice(path, &["edit", "a", "--patch"]);
+
+    assert_eq!(current_branch(path), "b");
+    assert_eq!(subjects(path, "main..a"), commits_on_a);
+    assert_eq!(run_git(path, &["show", "a:a.txt"]), "fixed");
+    assert_eq!(run_git(path, &["show", "b:a.txt"]), "fixed");
+    // The unpicked change is back in the working tree
+    assert_eq!(run_git(path, &["status", "--porcelain"]), " M b.txt\n");
+    assert_eq!(run_git(path, &["stash", "list"]), "");
+}
+
+#[test]
+fn edit_branch_refused_in_read_only_mode() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    create_branch(path, "b", "b");
+
+    run_lattice_expect_fail(path, &["--read-only", "edit", "a"]);
+    assert_eq!(current_branch(path), "b");
+
+    // Nor can a session started earlier be finished
+    run_lattice(path, &["edit", "a"]);
+    run_lattice_expect_fail(path, &["--read-only", "edit", "--done"]);
+    assert_eq!(current_branch(path), "a");
+}
+
+#[test]
+fn edit_branch_patch_puts_changes_back_when_amend_fails() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "a", "a");
+    create_branch(path, "b", "b");
+    let tip_of_a = short_oid(path, "a");
+
+    // The amend's commit hook refuses it
+    let hook = path.join(".git/hooks/pre-commit");
+    fs::write(&hook, "#!/bin/sh\nexit 1\n").expect("write hook");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).expect("chmod");
+    }
+
+    fs::write(path.join("a.txt"), "fixed").expect("write");
+    fs::write(path.join("b.txt"), "work in progress").expect("write");
+    run_git(path, &["add", "a.txt"]);
+    run_lattice_expect_fail(path, &["edit", "a", "--patch"]);
+
+    assert_eq!(current_branch(path), "b");
+    assert_eq!(short_oid(path, "a"), tip_of_a);
+    assert_eq!(
+        run_git(path, &["status", "--porcelain"]),
+        "M  a.txt\n M b.txt\n"
+    );
+    assert_eq!(run_git(path, &["stash", "list"]), "");
+    let stderr = run_lattice_expect_fail(path, &["edit", "--done"]);
+    assert!(stderr.contains("No branch edit in progress"), "{}", stderr);
+}
+
+#[test]
+fn edit_branch_frozen_fails() {
+    let dir = setup_repo();
+    let path = dir.path();
+
+    create_branch(path, "frozen-base", "content");
+    run_lattice(path, &["freeze"]);
+    create_branch(path, "child", "content");
+
+    let stderr = run_lattice_expect_fail(path, &["edit", "frozen-base"]);
+    assert!(stderr.contains("frozen"), "{}", stderr);
+    assert_eq!(current_branch(path), "child");
+}
+
+// ========== ABSORB TESTS ==========
+
+/// Stack of `a` and `b`, each adding a three-line file, with `b` checked out.
+fn setup_absorb_stack(path: &Path) {
+    for name in ["a", "b"] {
+        run_lattice(path, &["create", name, "-m", &format!("Add {}", name)]);
+        fs::write(path.join(format!("{}.txt", name)), "one\ntwo\nthree\n").expect("write");
+        run_git(path, &["add", "."]);
+        run_git(path, &["commit", "-m", &format!("Commit on {}", name)]);
+    }
+}
+
+#[test]
+fn absorb_folds_hunks_into_their_commits() {
+    let dir = setup_repo();
+    let path = dir.path();
+    setup_absorb_stack(path);
+
+    fs::write(path.join("a.txt"), "one\nTWO\nthree\n").expect("write");
+    fs::write(path.join("b.txt"), "one\ntwo\nthree\nfour\n").expect("write");
+    run_git(path, &["add", "."]);
+
+    let output = run_lattice(path, &["absorb"]);
+    assert!(output.contains("into 2 commit(s)"), "{}", output);
+
+    assert_eq!(subjects(path, "main..a"), vec!["Commit on a"]);
+    assert_eq!(subjects(path, "a..b"), vec!["Commit on b"]);
+    assert_eq!(run_git(path, &["show", "a:a.txt"]), "one\nTWO\nthree\n");
+    assert_eq!(
+        run_git(path, &["show", "b:b.txt"]),
+        "one\ntwo\nthree\nfour\n"
+    );
+    assert_eq!(current_branch(path), "b");
+    assert!(run_git(path, &["status", "--porcelain"]).is_empty());
+}
+
+#[test]
+fn absorb_leaves_trunk_lines_staged() {
+    let dir = setup_repo();
+    let path = dir.path();
+    setup_absorb_stack(path);
+
+    fs::write(path.join("README.md"), "# Renamed Repo\n").expect("write");
+    fs::write(path.join("b.txt"), "ONE\ntwo\nthree\n").expect("write");
+    run_git(path, &["add", "."]);
+
+    let output = run_lattice(path, &["absorb"]);
+    assert!(output.contains("Left staged"), "{}", output);
+    assert!(output.contains("README.md"), "{}", output);
+
+    assert_eq!(run_git(path, &["show", "b:b.txt"]), "ONE\ntwo\nthree\n");
+    assert_eq!(
+        run_git(path, &["diff", "--cached", "--name-only"]),
+        "README.md\n"
+    );
+}
+
+#[test]
+fn absorb_dry_run_changes_nothing() {
+    let dir = setup_repo();
+    let path = dir.path();
+    setup_absorb_stack(path);
+
+    fs::write(path.join("a.txt"), "one\nTWO\nthree\n").expect("write");
+    run_git(path, &["add", "."]);
+    let before = run_git(path, &["rev-parse", "a", "b"]);
+
+    let output = run_lattice(path, &["absorb", "--dry-run"]);
+    assert!(output.contains("Commit on a (a): a.txt"), "{}", output);
+    assert_eq!(run_git(path, &["rev-parse", "a", "b"]), before);
+    assert_eq!(
+        run_git(path, &["diff", "--cached", "--name-only"]),
+        "a.txt\n"
+    );
+}
+
+#[test]
+fn absorb_frozen_branch_fails() {
+    let dir = setup_repo();
+    let path = dir.path();
+    setup_absorb_stack(path);
+    run_lattice(path, &["freeze"]);
+
+    fs::write(path.join("b.txt"), "ONE\ntwo\nthree\n").expect("write");
+    run_git(path, &["add", "."]);
+
+    let stderr = run_lattice_expect_fail(path, &["absorb"]);
+    assert!(
+        stderr.contains("frozen"),
+        "Should fail for frozen: {}",
+        stderr
+    );
+}
+
 // ========== SPLIT TESTS ==========
 
 #[test]
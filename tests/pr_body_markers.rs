@@ -0,0 +1,91 @@
+//! Tests for reporting PRs with damaged stack section markers in `lt doctor`.
+
+mod common;
+
+use std::path::Path;
+
+use latticework::core::metadata::schema::PrState;
+use latticework::core::metadata::store::MetadataStore;
+use latticework::core::types::BranchName;
+use latticework::git::Git;
+use tempfile::TempDir;
+
+use common::{commit, run_git, run_lattice, setup_repo};
+
+/// Repository with `feature` tracked on `main` and linked to PR #42.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit(path, "feature.txt", "feature");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+
+    let git = Git::open(path).unwrap();
+    let store = MetadataStore::new(&git);
+    let branch = BranchName::new("feature").unwrap();
+    let entry = store.read(&branch).unwrap().expect("metadata");
+    let mut metadata = entry.metadata.clone();
+    metadata.pr = PrState::linked("github", 42, "https://github.com/o/r/pull/42");
+    store
+        .write_cas(&branch, Some(&entry.ref_oid), &metadata)
+        .unwrap();
+    repo
+}
+
+/// Record that submit found damaged markers in `feature`'s PR `number`.
+fn seed_state(path: &Path, number: u64) {
+    let dir = path.join(".git").join("lattice").join("cache");
+    std::fs::create_dir_all(&dir).unwrap();
+    let state = serde_json::json!({
+        "entries": {
+            "feature": {
+                "number": number,
+                "written": null,
+                "corrupt": "stack section markers appear more than once",
+            }
+        }
+    });
+    std::fs::write(dir.join("pr_bodies.json"), state.to_string()).unwrap();
+}
+
+fn doctor_output(path: &Path) -> String {
+    let output = run_lattice(path, &["doctor"]);
+    format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
+
+#[test]
+fn doctor_reports_damaged_markers() {
+    let repo = setup();
+    seed_state(repo.path(), 42);
+
+    let output = doctor_output(repo.path());
+    assert!(
+        output.contains("Stack section of PR #42 for 'feature' can't be updated"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("markers appear more than once"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn doctor_ignores_records_for_another_pr() {
+    let repo = setup();
+    seed_state(repo.path(), 7);
+
+    let output = doctor_output(repo.path());
+    assert!(!output.contains("Stack section of PR"), "{}", output);
+}
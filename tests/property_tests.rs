@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use proptest::prelude::*;
 
 use latticework::core::graph::StackGraph;
-use latticework::core::metadata::schema::{parse_metadata, BranchMetadataV1};
+use latticework::core::metadata::schema::{parse_metadata, BranchMetadataV2};
 use latticework::core::types::{BranchName, Fingerprint, Oid, RefName};
 
 /// Strategy for generating valid branch name characters.
@@ -183,7 +183,7 @@ proptest! {
         let parent = BranchName::new(&parent_name).unwrap();
         let oid = Oid::new(&oid_str).unwrap();
 
-        let meta = BranchMetadataV1::new(branch, parent, oid);
+        let meta = BranchMetadataV2::new(branch, parent, oid);
         let json = serde_json::to_string(&meta).unwrap();
         let parsed = parse_metadata(&json).unwrap();
 
@@ -223,7 +223,7 @@ mod determinism_tests {
         let parent = BranchName::new("main").unwrap();
         let oid = Oid::new("abc123def4567890abc123def4567890abc12345").unwrap();
 
-        let meta = BranchMetadataV1::new(branch, parent, oid);
+        let meta = BranchMetadataV2::new(branch, parent, oid);
 
         // Serialize multiple times
         let json1 = meta.to_canonical_json().unwrap();
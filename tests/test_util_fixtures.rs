@@ -0,0 +1,56 @@
+//! Tests for the `test-util` repository fixtures.
+//!
+//! Run with `cargo test --features test-util`.
+
+#![cfg(feature = "test-util")]
+
+use latticework::forge::{Forge, PrState};
+use latticework::test_util::{mock_forge_for_stack, TestRepo, TRUNK};
+
+#[test]
+fn seeded_stack_is_tracked_in_order() {
+    let repo = TestRepo::with_lattice();
+    repo.seed_stack(&["auth", "auth-ui", "auth-tests"]);
+
+    assert_eq!(repo.current_branch(), "auth-tests");
+    let stack = repo.repository().stack().unwrap();
+    assert_eq!(stack.trunk.as_deref(), Some(TRUNK));
+    assert_eq!(stack.branch("auth").unwrap().parent, TRUNK);
+    assert_eq!(stack.branch("auth-ui").unwrap().parent, "auth");
+    assert_eq!(stack.branch("auth-tests").unwrap().parent, "auth-ui");
+    assert!(repo.repository().plan_restack(None).unwrap().is_empty());
+}
+
+#[test]
+fn origin_receives_pushes_and_is_cleaned_up() {
+    let mut repo = TestRepo::new();
+    let origin = repo.add_origin().to_path_buf();
+    let head = repo.head_oid();
+
+    let remote_main = repo.git_cmd(&["ls-remote", "origin", TRUNK]);
+    assert!(remote_main.starts_with(head.as_str()));
+
+    drop(repo);
+    assert!(!origin.exists());
+}
+
+#[test]
+fn mock_forge_has_a_pr_per_branch() {
+    let forge = mock_forge_for_stack(&["auth", "auth-ui"]);
+
+    let prs = tokio_test::block_on(async {
+        vec![
+            forge.get_pr(1).await.unwrap(),
+            forge.get_pr(2).await.unwrap(),
+        ]
+    });
+    assert_eq!(
+        (prs[0].head.as_str(), prs[0].base.as_str()),
+        ("auth", TRUNK)
+    );
+    assert_eq!(
+        (prs[1].head.as_str(), prs[1].base.as_str()),
+        ("auth-ui", "auth")
+    );
+    assert!(prs.iter().all(|pr| pr.state == PrState::Open));
+}
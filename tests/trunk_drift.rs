@@ -0,0 +1,76 @@
+//! Tests for trunk drift warnings in `lt log` and `lt info`.
+
+mod common;
+
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use common::{commit, run_git, run_git_with_env, run_lattice, setup_repo, write_config};
+
+/// Repository with `feature` tracked on `main`.
+fn setup() -> TempDir {
+    let repo = setup_repo();
+    let path = repo.path();
+
+    assert!(run_lattice(path, &["init", "--trunk", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "-b", "feature"]);
+    commit(path, "feature.txt", "feature");
+    assert!(run_lattice(path, &["track", "--parent", "main"])
+        .status
+        .success());
+    run_git(path, &["checkout", "main"]);
+    repo
+}
+
+fn drift_warning(path: &Path, config: &str, args: &[&str]) -> Option<String> {
+    write_config(path, config);
+    let output = run_lattice(path, args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .find(|l| l.contains("behind 'main'"))
+        .map(str::to_string)
+}
+
+#[test]
+fn warns_when_too_many_commits_behind() {
+    let repo = setup();
+    for i in 0..3 {
+        commit(repo.path(), "main.txt", &format!("main {}", i));
+    }
+
+    let config = "[drift]\nwarn_days = 0\nwarn_commits = 2\n";
+    let warning = drift_warning(repo.path(), config, &["log", "--all"]).expect("warned");
+    assert!(warning.contains("Stack 'feature' is 3 commit(s) behind"));
+    assert!(drift_warning(repo.path(), config, &["info", "feature"]).is_some());
+
+    let config = "[drift]\nwarn_days = 0\nwarn_commits = 3\n";
+    assert!(drift_warning(repo.path(), config, &["log", "--all"]).is_none());
+}
+
+#[test]
+fn warns_when_behind_for_too_long() {
+    let repo = setup();
+    let month_ago = (chrono::Utc::now() - chrono::Duration::days(30)).to_rfc3339();
+    std::fs::write(repo.path().join("main.txt"), "old change").unwrap();
+    run_git(repo.path(), &["add", "main.txt"]);
+    run_git_with_env(
+        repo.path(),
+        &["commit", "-m", "old change"],
+        &[("GIT_AUTHOR_DATE", month_ago.as_str())],
+    );
+
+    let warning = drift_warning(repo.path(), "", &["log", "--all"]).expect("warned");
+    assert!(warning.contains("(30 day(s))"), "{}", warning);
+    assert!(drift_warning(repo.path(), "[drift]\nwarn_days = 0\n", &["log", "--all"]).is_none());
+
+    // Quiet output skips the warning
+    assert!(drift_warning(repo.path(), "", &["--quiet", "log", "--all"]).is_none());
+}
@@ -0,0 +1,80 @@
+//! Tests for `lt ws`, which runs commands across a workspace's repositories.
+
+mod common;
+
+use tempfile::TempDir;
+
+use common::{init_repo, run_git, run_lattice};
+
+/// Workspace directory holding repositories `api` and `web`, each with one
+/// tracked branch named after it.
+fn setup() -> TempDir {
+    let workspace = TempDir::new().expect("create workspace dir");
+    for name in ["api", "web"] {
+        let path = workspace.path().join(name);
+        std::fs::create_dir(&path).unwrap();
+        init_repo(&path);
+        run_git(&path, &["commit", "--allow-empty", "-m", "init"]);
+        assert!(run_lattice(&path, &["init", "--trunk", "main"])
+            .status
+            .success());
+        let branch = format!("{}-feature", name);
+        run_git(&path, &["checkout", "-q", "-b", &branch]);
+        run_git(&path, &["commit", "--allow-empty", "-m", &branch]);
+        assert!(run_lattice(&path, &["track", "--parent", "main"])
+            .status
+            .success());
+    }
+    workspace
+}
+
+#[test]
+fn ws_log_shows_each_repository() {
+    let workspace = setup();
+    std::fs::write(
+        workspace.path().join("lattice-workspace.toml"),
+        "[[repo]]\npath = \"api\"\n\n[[repo]]\npath = \"web\"\nname = \"frontend\"\n",
+    )
+    .unwrap();
+
+    // Found from inside a member repository
+    let output = run_lattice(&workspace.path().join("web"), &["ws", "log", "--short"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let api = stdout.find("== api (").expect("api heading");
+    let frontend = stdout.find("== frontend (").expect("frontend heading");
+    assert!(api < frontend, "{}", stdout);
+    assert!(stdout[api..frontend].contains("api-feature"), "{}", stdout);
+    assert!(stdout[frontend..].contains("web-feature"), "{}", stdout);
+}
+
+#[test]
+fn ws_reports_failing_members_after_running_the_rest() {
+    let workspace = setup();
+    std::fs::write(
+        workspace.path().join("team.toml"),
+        "[[repo]]\npath = \"gone\"\n\n[[repo]]\npath = \"api\"\n",
+    )
+    .unwrap();
+
+    let output = run_lattice(workspace.path(), &["ws", "--file", "team.toml", "log"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("api-feature"), "{}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error: gone: "), "{}", stderr);
+    assert!(
+        stderr.contains("1 of 2 repositories failed: gone"),
+        "{}",
+        stderr
+    );
+
+    // Without a workspace file there is nothing to run
+    let output = run_lattice(&workspace.path().join("api"), &["ws", "log"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no lattice-workspace.toml found"));
+}
@@ -646,6 +646,27 @@ mod branch_occupancy {
         assert!(conflicts.contains_key(&BranchName::new("main").unwrap()));
         assert!(conflicts.contains_key(&BranchName::new("feature2").unwrap()));
     }
+
+    #[test]
+    fn scan_records_branches_checked_out_elsewhere() {
+        let main_dir = TempDir::new().expect("failed to create temp dir");
+        create_normal_repo(main_dir.path());
+        run_git_ok(main_dir.path(), &["branch", "-M", "main"]);
+
+        let worktree = TempDir::new().expect("failed to create worktree temp dir");
+        create_worktree(main_dir.path(), worktree.path(), "feature");
+
+        let snapshot =
+            latticework::engine::scan::scan_from_path(main_dir.path()).expect("failed to scan");
+        let feature = BranchName::new("feature").unwrap();
+        assert_eq!(snapshot.checked_out_elsewhere.len(), 1);
+        assert_eq!(
+            snapshot.checked_out_elsewhere[&feature]
+                .canonicalize()
+                .unwrap(),
+            worktree.path().canonicalize().unwrap()
+        );
+    }
 }
 
 // =============================================================================
@@ -1043,3 +1064,163 @@ mod config_bare_and_worktree {
         );
     }
 }
+
+// =============================================================================
+// Stack Commands Across Worktrees
+// =============================================================================
+
+mod stack_across_worktrees {
+    use super::*;
+
+    fn run_lattice(dir: &Path, args: &[&str]) -> std::process::Output {
+        Command::new(env!("CARGO_BIN_EXE_lt"))
+            .args(args)
+            .arg("--no-interactive")
+            .current_dir(dir)
+            .env("LATTICE_LANG", "en")
+            .output()
+            .expect("failed to run lattice")
+    }
+
+    fn commit(dir: &Path, file: &str, message: &str) {
+        std::fs::write(dir.join(file), message).unwrap();
+        run_git_ok(dir, &["add", file]);
+        run_git_ok(dir, &["commit", "-m", message]);
+    }
+
+    fn rev_parse(dir: &Path, rev: &str) -> String {
+        let output = run_git(dir, &["rev-parse", rev]);
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Tracked `feature` on `main` with `main` advanced since, and `feature`
+    /// checked out in a linked worktree.
+    fn setup() -> (TempDir, TempDir) {
+        let repo = TempDir::new().expect("failed to create temp dir");
+        let path = repo.path();
+        run_git_ok(path, &["init", "-b", "main"]);
+        run_git_ok(path, &["config", "user.email", "test@example.com"]);
+        run_git_ok(path, &["config", "user.name", "Test User"]);
+        commit(path, "README.md", "init");
+        assert!(run_lattice(path, &["init", "--trunk", "main"])
+            .status
+            .success());
+
+        run_git_ok(path, &["checkout", "-b", "feature"]);
+        commit(path, "feature.txt", "feature");
+        assert!(run_lattice(path, &["track", "--parent", "main"])
+            .status
+            .success());
+        run_git_ok(path, &["checkout", "main"]);
+        commit(path, "main.txt", "main");
+
+        let worktree = TempDir::new().expect("failed to create worktree temp dir");
+        run_git_ok(
+            path,
+            &[
+                "worktree",
+                "add",
+                worktree.path().to_str().unwrap(),
+                "feature",
+            ],
+        );
+
+        (repo, worktree)
+    }
+
+    #[test]
+    fn restack_rebases_branch_inside_its_worktree() {
+        let (repo, worktree) = setup();
+
+        let output = run_lattice(repo.path(), &["restack", "--branch", "feature"]);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // The branch now sits on main, and the other worktree moved with it
+        assert_eq!(
+            rev_parse(repo.path(), "feature~1"),
+            rev_parse(repo.path(), "main")
+        );
+        assert_eq!(
+            rev_parse(worktree.path(), "HEAD"),
+            rev_parse(repo.path(), "feature")
+        );
+        assert!(worktree.path().join("main.txt").exists());
+
+        let status = run_git(worktree.path(), &["status", "--porcelain"]);
+        assert!(String::from_utf8_lossy(&status.stdout).trim().is_empty());
+    }
+
+    #[test]
+    fn restack_refuses_dirty_worktree() {
+        let (repo, worktree) = setup();
+        let before = rev_parse(repo.path(), "feature");
+        std::fs::write(worktree.path().join("feature.txt"), "edited").unwrap();
+
+        let output = run_lattice(repo.path(), &["restack", "--branch", "feature"]);
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("blocking restack"), "{}", stderr);
+
+        assert_eq!(rev_parse(repo.path(), "feature"), before);
+        assert_eq!(
+            std::fs::read_to_string(worktree.path().join("feature.txt")).unwrap(),
+            "edited"
+        );
+    }
+
+    #[test]
+    fn checkout_names_the_worktree_holding_the_branch() {
+        let (repo, worktree) = setup();
+
+        let output = run_lattice(repo.path(), &["checkout", "feature"]);
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains(&worktree.path().display().to_string()),
+            "{}",
+            stderr
+        );
+        assert!(stderr.contains("--worktree"), "{}", stderr);
+
+        // --worktree uses the existing worktree instead of making another
+        let output = run_lattice(
+            repo.path(),
+            &["checkout", "feature", "--worktree", "elsewhere"],
+        );
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(String::from_utf8_lossy(&output.stdout).contains("already checked out"));
+        assert!(!repo.path().join("elsewhere").exists());
+    }
+
+    #[test]
+    fn checkout_worktree_creates_one_for_the_branch() {
+        let (repo, _worktree) = setup();
+        run_git_ok(repo.path(), &["branch", "other"]);
+        let parent = TempDir::new().expect("failed to create temp dir");
+        let path = parent.path().join("other");
+
+        let output = run_lattice(
+            repo.path(),
+            &["checkout", "other", "--worktree", path.to_str().unwrap()],
+        );
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let head = run_git(&path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+        assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "other");
+        // This worktree stays where it was
+        let head = run_git(repo.path(), &["rev-parse", "--abbrev-ref", "HEAD"]);
+        assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "main");
+    }
+}